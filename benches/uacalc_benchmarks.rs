@@ -22,18 +22,142 @@ fn benchmark_lattice_operations(c: &mut Criterion) {
 }
 
 fn benchmark_term_evaluation(c: &mut Criterion) {
+    use std::collections::{HashMap, HashSet};
+    use uacalc::alg::op::{operations, OperationSymbol};
+
+    let sym = OperationSymbol::new("add", 2, false);
+    let table = (0..25).map(|k| (k % 5 + k / 5) % 5).collect();
+    let op = operations::make_int_operation(sym, 5, table).unwrap();
+    let alg = BasicAlgebra::new("Z5".to_string(), (0..5).collect::<HashSet<i32>>(), vec![op]);
+    let term = string_to_term("add(add(x,add(y,z)),add(add(z,y),add(x,add(y,y))))").unwrap();
+    let vars = vec!["x".to_string(), "y".to_string(), "z".to_string()];
+
     c.bench_function("term_evaluation", |b| {
         b.iter(|| {
-            // TODO: Benchmark term evaluation
-            black_box(())
+            let mut map = HashMap::new();
+            let mut sum = 0;
+            for k in 0..125 {
+                map.insert("x".to_string(), k % 5);
+                map.insert("y".to_string(), (k / 5) % 5);
+                map.insert("z".to_string(), k / 25);
+                sum += term.eval(&alg, &map).unwrap();
+            }
+            black_box(sum)
+        })
+    });
+
+    let compiled = CompiledTerm::compile(term.as_ref(), &alg, &vars).unwrap();
+    c.bench_function("compiled_term_evaluation", |b| {
+        b.iter(|| {
+            let mut stack = Vec::with_capacity(compiled.max_stack());
+            let mut sum = 0;
+            for k in 0..125 {
+                sum += compiled.eval_with_stack(&[k % 5, (k / 5) % 5, k / 25], &mut stack);
+            }
+            black_box(sum)
         })
     });
 }
 
+fn benchmark_product_cg(c: &mut Criterion) {
+    use std::collections::HashSet;
+    use uacalc::alg::conlat::CongruenceLattice;
+    use uacalc::alg::op::{operations, OperationSymbol};
+
+    // The eight element Boolean lattice 2^3, on bit vectors.
+    let lattice = || {
+        let meet = OperationSymbol::new("meet", 2, false);
+        let join = OperationSymbol::new("join", 2, false);
+        let ops = vec![
+            operations::make_int_operation(meet, 8, (0..64).map(|k| (k % 8) & (k / 8)).collect()).unwrap(),
+            operations::make_int_operation(join, 8, (0..64).map(|k| (k % 8) | (k / 8)).collect()).unwrap(),
+        ];
+        Box::new(BasicAlgebra::new("2^3".to_string(), (0..8).collect::<HashSet<i32>>(), ops))
+            as Box<dyn SmallAlgebra<UniverseItem = i32>>
+    };
+    let product = ProductAlgebra::new("L^3".to_string(), vec![lattice(), lattice(), lattice()]);
+    let pairs = [(0, 1), (0, 73), (5, 300), (17, 511)];
+
+    let mut group = c.benchmark_group("product_cg");
+    group.sample_size(10);
+    group.bench_function("flat", |b| {
+        b.iter(|| {
+            let mut con = CongruenceLattice::new(Box::new(product.clone()) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+            for &(x, y) in &pairs {
+                black_box(con.cg(x, y));
+            }
+        })
+    });
+
+    group.bench_function("coordinatewise", |b| {
+        b.iter(|| {
+            for &(x, y) in &pairs {
+                black_box(product.cg(x, y).unwrap());
+            }
+        })
+    });
+    group.finish();
+}
+
+fn benchmark_product_skew_free(c: &mut Criterion) {
+    use std::collections::HashSet;
+    use uacalc::alg::conlat::{product_conlat, CongruenceLattice, Partition};
+    use uacalc::alg::op::{operations, OperationSymbol};
+
+    // The Boolean lattice 2^bits, on bit vectors.
+    let lattice = |bits: u32| {
+        let n = 1 << bits;
+        let meet = OperationSymbol::new("meet", 2, false);
+        let join = OperationSymbol::new("join", 2, false);
+        let ops = vec![
+            operations::make_int_operation(meet, n, (0..n * n).map(|k| (k % n) & (k / n)).collect()).unwrap(),
+            operations::make_int_operation(join, n, (0..n * n).map(|k| (k % n) | (k / n)).collect()).unwrap(),
+        ];
+        Box::new(BasicAlgebra::new(format!("2^{}", bits), (0..n).collect::<HashSet<i32>>(), ops))
+            as Box<dyn SmallAlgebra<UniverseItem = i32>>
+    };
+    let product = ProductAlgebra::new("2^2x2^3".to_string(), vec![lattice(2), lattice(3)]);
+    let product_conlat = || {
+        product_conlat(&mut CongruenceLattice::new(lattice(2)), &mut CongruenceLattice::new(lattice(3)), &product).unwrap()
+    };
+
+    let mut group = c.benchmark_group("product_skew_free");
+    group.sample_size(10);
+    // Every principal congruence computed in full on the flat product.
+    let products: HashSet<Partition> = product_conlat().product_congruences().iter().cloned().collect();
+    group.bench_function("flat", |b| {
+        b.iter(|| {
+            let mut con = CongruenceLattice::new(Box::new(product.clone()) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+            let n = con.alg_size();
+            black_box((0..n).all(|p| (p + 1..n).all(|q| products.contains(&con.cg(p, q)))))
+        })
+    });
+
+    group.bench_function("factor_bounds", |b| b.iter(|| black_box(product_conlat().is_skew_free().unwrap())));
+    group.finish();
+}
+
+fn benchmark_suite(c: &mut Criterion) {
+    use uacalc::bench::{bench_suite, BenchTask};
+
+    let suite = bench_suite();
+    for task in BenchTask::ALL {
+        let mut group = c.benchmark_group(format!("suite_{}", task.name()));
+        group.sample_size(10);
+        for bench in &suite {
+            group.bench_function(bench.algebra.name(), |b| b.iter(|| black_box(task.run(&bench.algebra).unwrap())));
+        }
+        group.finish();
+    }
+}
+
 criterion_group!(
     benches,
     benchmark_algebra_creation,
     benchmark_lattice_operations,
-    benchmark_term_evaluation
+    benchmark_term_evaluation,
+    benchmark_product_cg,
+    benchmark_product_skew_free,
+    benchmark_suite
 );
 criterion_main!(benches);
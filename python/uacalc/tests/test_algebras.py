@@ -1345,3 +1345,65 @@ class TestMakeRandomAlgebra:
         # Should process both arities
         assert len(result) >= 0
 
+
+
+class TestGroupRepresentations:
+    """Tests for algebra_from_permutation_group and cayley_algebra."""
+
+    def test_algebra_from_permutation_group(self):
+        """Test the G-set of S3 acting on 3 points."""
+        import uacalc_lib
+
+        alg = uacalc_lib.alg.algebra_from_permutation_group([[1, 0, 2], [1, 2, 0]])
+        assert alg.name() == "GSet"
+        assert alg.cardinality() == 3
+        assert len(alg.operations()) == 2
+
+        with pytest.raises(ValueError):
+            uacalc_lib.alg.algebra_from_permutation_group([[0, 0]])
+
+    def test_cayley_algebra(self):
+        """Test the left regular representation of Z3."""
+        import uacalc_lib
+
+        table = [[(g + x) % 3 for x in range(3)] for g in range(3)]
+        alg = uacalc_lib.alg.cayley_algebra(table)
+        assert alg.name() == "Cayley"
+        assert alg.cardinality() == 3
+        assert len(alg.operations()) == 3
+
+        with pytest.raises(ValueError):
+            uacalc_lib.alg.cayley_algebra([[0, 0], [0, 0]])
+
+    def test_digraph_algebra(self):
+        """Test the graph algebra of a directed 3-cycle and the digraph of a unary algebra."""
+        import uacalc_lib
+
+        edges = [(0, 1), (1, 2), (2, 0)]
+        alg = uacalc_lib.alg.digraph_algebra(edges, 3)
+        assert alg.cardinality() == 4
+        assert uacalc_lib.alg.algebra_to_digraph(alg) == edges
+
+        table = [[(g + x) % 3 for x in range(3)] for g in range(3)]
+        cayley = uacalc_lib.alg.cayley_algebra(table)
+        assert uacalc_lib.alg.algebra_to_digraph(cayley) == [(x, y) for x in range(3) for y in range(3)]
+
+        with pytest.raises(ValueError):
+            uacalc_lib.alg.digraph_algebra([(0, 3)], 3)
+
+    def test_boolean_and_heyting_algebras(self):
+        """Test the Boolean and Heyting algebra factories and recognizers."""
+        import uacalc_lib
+
+        b3 = uacalc_lib.alg.boolean_algebra(3)
+        assert b3.cardinality() == 8
+        assert uacalc_lib.alg.is_boolean_algebra(b3) == [7 - x for x in range(8)]
+
+        chain = uacalc_lib.lat.Poset([[1], [2], []])
+        heyting = uacalc_lib.alg.heyting_algebra_from_poset(chain)
+        assert uacalc_lib.alg.is_boolean_algebra(heyting) is None
+        assert uacalc_lib.alg.is_heyting_algebra(heyting) == [[2, 2, 2], [0, 2, 2], [0, 1, 2]]
+
+        m3 = uacalc_lib.lat.Poset([[1, 2, 3], [4], [4], [4], []])
+        with pytest.raises(ValueError):
+            uacalc_lib.alg.heyting_algebra_from_poset(m3)
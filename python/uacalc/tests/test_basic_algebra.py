@@ -225,6 +225,19 @@ class TestBasicAlgebra(unittest.TestCase):
         repr_str = repr(alg)
         self.assertIsInstance(repr_str, str)
         self.assertIn("BasicAlgebra", repr_str)
+        self.assertEqual(repr_str, "BasicAlgebra(name='test', size=3, operations=[])")
+    
+    def test_describe(self):
+        """Test the summary with operation tables."""
+
+        op = IntOperation.from_int_value_at("max", 2, 2, lambda args: max(args[0], args[1]))
+        alg = BasicAlgebra("L", [0, 1] ,[op])
+        self.assertEqual(str(alg), "Algebra L of size 2\n\nmax | 0 1\n----+----\n  0 | 0 1\n  1 | 1 1\n")
+        self.assertEqual(alg.describe(), str(alg))
+        self.assertTrue(alg.describe("markdown").startswith("**Algebra L of size 2**"))
+        self.assertIn("\\begin{tabular}{c|cc}", alg.to_latex())
+        with self.assertRaises(ValueError):
+            alg.describe("html")
     
     def test_equality(self):
         """Test equality comparison."""
@@ -232,12 +245,16 @@ class TestBasicAlgebra(unittest.TestCase):
         alg1 = BasicAlgebra("test", [0, 1, 2] ,[])
         alg2 = BasicAlgebra("test", [0, 1, 2] ,[])
         alg3 = BasicAlgebra("different", [0, 1, 2] ,[])
+        op = IntOperation.from_int_value_at("max", 2, 3, lambda args: max(args[0], args[1]))
+        alg4 = BasicAlgebra("test", [0, 1, 2] ,[op])
         
-        # Same name and cardinality should be equal
+        # Equality is structural: the same universe and operations
         self.assertEqual(alg1, alg2)
-        
-        # Different name should not be equal
-        self.assertNotEqual(alg1, alg3)
+        self.assertEqual(alg1, alg3)
+        self.assertEqual(hash(alg1), hash(alg3))
+        self.assertNotEqual(alg1, alg4)
+        self.assertNotEqual(alg1, BasicAlgebra("test", [0, 1] ,[]))
+        self.assertEqual(len({alg1, alg2, alg3, alg4}), 2)
 
     def test_operations(self):
         """Test operations."""
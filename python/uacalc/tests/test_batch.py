@@ -0,0 +1,58 @@
+#!/usr/bin/env python3
+"""
+Tests for running analyses over many algebras in parallel.
+"""
+
+import unittest
+
+import uacalc_lib
+
+alg = uacalc_lib.alg
+OperationSymbol = uacalc_lib.alg.OperationSymbol
+BasicAlgebra = uacalc_lib.alg.BasicAlgebra
+Operations = uacalc_lib.alg.Operations
+
+
+def cyclic_group(n):
+    plus = OperationSymbol("+", 2, False)
+    table = [(k % n + k // n) % n for k in range(n * n)]
+    return BasicAlgebra("Z%d" % n, list(range(n)), [Operations.make_int_operation(plus, n, table)])
+
+
+class TestAnalyzeBatch(unittest.TestCase):
+    """Test cases for analyze_batch."""
+
+    def setUp(self):
+        meet = OperationSymbol("meet", 2, False)
+        op = Operations.make_int_operation(meet, 2, [0, 0, 0, 1])
+        self.semilattice = BasicAlgebra("S", [0, 1], [op])
+
+    def test_analyze_batch(self):
+        groups = [cyclic_group(n) for n in range(2, 7)]
+        results = alg.analyze_batch(groups, ["simple", "con_cardinality", "malcev_term"], n_threads=2)
+        self.assertEqual([r["algebra"] for r in results], ["Z2", "Z3", "Z4", "Z5", "Z6"])
+        self.assertEqual([r["simple"] for r in results], [True, True, False, True, False])
+        self.assertEqual([r["con_cardinality"] for r in results], [2, 2, 3, 2, 4])
+        self.assertTrue(all(r["malcev_term"] for r in results))
+
+    def test_equations(self):
+        """An equation using a missing operation fails for that algebra only."""
+        idempotent = uacalc_lib.eq.Equation(
+            uacalc_lib.terms.string_to_term("meet(x,x)"),
+            uacalc_lib.terms.string_to_term("x"),
+        )
+        results = alg.analyze_batch([self.semilattice, cyclic_group(2)], [idempotent, "taylor_term"])
+        key = str(idempotent)
+        self.assertTrue(results[0][key])
+        self.assertNotIn("errors", results[0])
+        self.assertIsNone(results[1][key])
+        self.assertIn(key, results[1]["errors"])
+        self.assertTrue(results[1]["taylor_term"])
+
+    def test_unknown_analysis(self):
+        with self.assertRaises(ValueError):
+            alg.analyze_batch([self.semilattice], ["nonsense"])
+
+
+if __name__ == "__main__":
+    unittest.main()
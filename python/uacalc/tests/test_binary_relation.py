@@ -333,6 +333,22 @@ class TestBasicBinaryRelation:
         assert hash(relation1) == hash(relation2)
         assert hash(relation1) != hash(relation3)
     
+    def test_ordering(self):
+        """Test that ordering agrees with equality and sorts relations."""
+        import uacalc_lib
+        BasicBinaryRelation = uacalc_lib.alg.BasicBinaryRelation
+        
+        r = BasicBinaryRelation(3)
+        r.add(0, 1)
+        s = BasicBinaryRelation(3)
+        s.add(1, 0)
+        
+        assert r != s
+        assert r < s and s > r
+        assert r <= r and r >= r
+        assert sorted([s, r]) == [r, s]
+        assert len({r, s, r}) == 2
+    
     def test_iterator(self):
         """Test iterator support."""
         import uacalc_lib
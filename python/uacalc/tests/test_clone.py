@@ -0,0 +1,80 @@
+"""
+Tests for relations and the Pol-Inv functions.
+"""
+
+import unittest
+import uacalc_lib
+
+alg = uacalc_lib.alg
+Relation = alg.Relation
+OperationSymbol = alg.OperationSymbol
+Operations = alg.Operations
+
+
+def table_op(name, arity, table):
+    return Operations.make_int_operation(OperationSymbol(name, arity, False), 2, table)
+
+
+class TestClone(unittest.TestCase):
+    """Test the Galois connection between operations and relations."""
+
+    def setUp(self):
+        self.order = Relation(2, 2, [[0, 0], [0, 1], [1, 1], [0, 1]])
+        self.meet = table_op("meet", 2, [0, 0, 0, 1])
+        self.join = table_op("join", 2, [0, 1, 1, 1])
+
+    def test_relation(self):
+        """Relations dedupe and sort their tuples and check entries."""
+        self.assertEqual(len(self.order), 3)
+        self.assertEqual(self.order.tuples(), [[0, 0], [0, 1], [1, 1]])
+        self.assertIn([0, 1], self.order)
+        self.assertFalse(self.order.contains([1, 0]))
+        self.assertEqual(self.order, Relation(2, 2, [[1, 1], [0, 1], [0, 0]]))
+        with self.assertRaises(ValueError):
+            Relation(2, 2, [[0, 2]])
+
+    def test_preserves(self):
+        """Meet preserves the order; negation does not."""
+        self.assertTrue(alg.preserves(self.meet, self.order))
+        self.assertFalse(alg.preserves(table_op("neg", 1, [1, 0]), self.order))
+
+    def test_pol_inv(self):
+        """Pol(Inv(F)) is the clone generated by F."""
+        rels = alg.inv(2, [self.meet, self.join], 2)
+        self.assertEqual(len([r for r in rels if r.arity() == 2]), 13)
+        binary = [op.get_table() for op in alg.pol(2, rels, 2) if op.arity() == 2]
+        self.assertEqual(binary, [[0, 0, 0, 1], [0, 0, 1, 1], [0, 1, 0, 1], [0, 1, 1, 1]])
+        monotone = alg.pol(2, [self.order], 1)
+        self.assertEqual([op.symbol().name() for op in monotone], ["p1_0", "p1_1", "p1_2"])
+        with self.assertRaises(ValueError):
+            alg.pol(3, [self.order], 1)
+
+    def test_pp_definable(self):
+        """Equality is pp-definable from the order; disequality is not."""
+        eq = Relation(2, 2, [[0, 0], [1, 1]])
+        neq = Relation(2, 2, [[0, 1], [1, 0]])
+        self.assertTrue(alg.pp_definable(eq, [self.order]))
+        self.assertFalse(alg.pp_definable(neq, [self.order]))
+        self.assertEqual(len(alg.pp_closure(neq, [self.order])), 4)
+        w = alg.pp_counterexample(neq, [self.order])
+        self.assertTrue(alg.preserves(w, self.order))
+        self.assertFalse(alg.preserves(w, neq))
+        self.assertIsNone(alg.pp_counterexample(eq, [self.order]))
+        with self.assertRaises(ValueError):
+            alg.pp_definable(eq, [Relation(3, 1, [[2]])])
+
+    def test_post_lattice(self):
+        """Boolean operations are placed in Post's lattice."""
+        lattice = alg.post_lattice()
+        self.assertEqual(len(lattice), 38 + 8 * 2)
+        for clone in lattice:
+            self.assertEqual(alg.classify_boolean_clone(clone.basis()), clone.name())
+        names = {clone.name(): clone for clone in lattice}
+        self.assertTrue(names["M2"].is_subclone_of(names["M"]))
+        self.assertTrue(names["L"].contains(table_op("xor", 2, [0, 1, 1, 0])))
+        self.assertEqual(alg.classify_boolean_clone([self.meet, self.join]), "M2")
+        self.assertEqual(alg.classify_boolean_clone([]), "I2")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,50 @@
+"""
+Tests for the Java-style compatibility layer.
+"""
+
+import os
+import tempfile
+import unittest
+import uacalc_lib
+
+alg = uacalc_lib.alg
+compat = uacalc_lib.compat
+
+
+def lattice():
+    meet = alg.Operations.make_int_operation(alg.OperationSymbol("meet", 2, False), 2, [0, 0, 0, 1])
+    join = alg.Operations.make_int_operation(alg.OperationSymbol("join", 2, False), 2, [0, 1, 1, 1])
+    return alg.BasicAlgebra("2", [0, 1], [meet, join])
+
+
+class TestCompat(unittest.TestCase):
+    """Test that the Java names delegate to the new API."""
+
+    def test_malcev(self):
+        """The Java names give the same answers as the snake_case ones."""
+        two = lattice()
+        self.assertEqual(compat.Malcev.majorityTerm(two), alg.majority_term(two))
+        self.assertIsNone(compat.Malcev.malcevTerm(two))
+        self.assertEqual(compat.Malcev.jonssonLevel(two), 2)
+        self.assertTrue(compat.Malcev.congruenceModularVariety(two))
+
+    def test_algebras(self):
+        """Algebras methods take and return the usual algebra objects."""
+        two = lattice()
+        square = compat.Algebras.matrixPower(two, 2)
+        self.assertEqual(square.cardinality(), 4)
+        self.assertTrue(compat.Algebras.isHomomorphism([0, 1], two, two))
+        self.assertIsNotNone(compat.Algebras.memberOfQuasivariety(square, two))
+
+    def test_algebra_io(self):
+        """An algebra written by writeAlgebraFile reads back."""
+        alg3 = compat.Algebras.ternaryDiscriminatorAlgebra(3)
+        with tempfile.TemporaryDirectory() as tmp:
+            path = os.path.join(tmp, "disc.xml")
+            compat.AlgebraIO.writeAlgebraFile(alg3, path)
+            self.assertEqual(compat.AlgebraIO.readAlgebraFile(path).cardinality(), 3)
+        self.assertEqual(compat.AlgebraIO.parseLine("7"), 7)
+
+
+if __name__ == "__main__":
+    unittest.main()
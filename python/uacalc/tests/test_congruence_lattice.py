@@ -86,6 +86,36 @@ def test_is_distributive():
     assert isinstance(is_dist, bool)
 
 
+def test_identify_lattice():
+    """Test identify_lattice on partition lattices."""
+    assert CongruenceLattice(BasicAlgebra("Set2", [0, 1], [])).identify_lattice() == "C2"
+    assert CongruenceLattice(BasicAlgebra("Set3", [0, 1, 2], [])).identify_lattice() == "M3"
+    assert CongruenceLattice(BasicAlgebra("Set4", [0, 1, 2, 3], [])).identify_lattice() == "unknown"
+
+
+def test_lattice_properties():
+    """Test the order-theoretic properties of a partition lattice."""
+    con_lat = CongruenceLattice(BasicAlgebra("Set4", [0, 1, 2, 3], []))
+    assert con_lat.is_geometric()
+    assert con_lat.is_relatively_complemented()
+    assert con_lat.is_upper_semimodular()
+    assert not con_lat.is_lower_semimodular()
+    assert not con_lat.is_modular()
+    assert not con_lat.is_lower_bounded_lattice()
+    assert not con_lat.is_meet_semidistributive()
+    assert not con_lat.is_join_semidistributive()
+
+
+def test_semidistributivity_witness():
+    """The partition lattice M3 fails meet semidistributivity."""
+    con_lat = CongruenceLattice(BasicAlgebra("Set3", [0, 1, 2], []))
+    a, b, c = con_lat.meet_semidistributivity_failure()
+    assert a.meet(b) == a.meet(c)
+    assert a.meet(b.join(c)) != a.meet(b)
+    assert con_lat.join_semidistributivity_failure() is not None
+    assert CongruenceLattice(BasicAlgebra("Set2", [0, 1], [])).meet_semidistributivity_failure() is None
+
+
 def test_get_description():
     """Test get_description method."""
     alg = BasicAlgebra("TestAlg", [0, 1, 2] ,[])
@@ -357,6 +387,32 @@ def test_universe():
         assert isinstance(con, Partition)
 
 
+def test_congruences_iter():
+    """Test streaming the congruences"""
+    alg = BasicAlgebra('TestAlg', [0, 1, 2, 3], [])
+    conlat = CongruenceLattice(alg)
+
+    first = next(conlat.congruences_iter())
+    assert first == conlat.zero()
+
+    streamed = [p.to_array() for p in conlat.congruences_iter()]
+    assert len(streamed) == 15
+    assert sorted(streamed) == sorted(p.to_array() for p in conlat.universe())
+    assert len(conlat) == 15
+
+
+def test_iteration_order_is_canonical():
+    """Iterating over the lattice gives the order of universe()"""
+    fresh = CongruenceLattice(BasicAlgebra('TestAlg', [0, 1, 2, 3], []))
+    iterated = [p.to_array() for p in fresh]
+    built = CongruenceLattice(BasicAlgebra('TestAlg', [0, 1, 2, 3], []))
+    universe = [p.to_array() for p in built.universe()]
+    assert iterated == universe
+    assert [p.to_array() for p in built] == universe
+    assert universe[0] == fresh.zero().to_array()
+    assert universe[-1] == fresh.one().to_array()
+
+
 def test_permutability_level():
     """Test getting permutability level"""
     alg = BasicAlgebra('TestAlg', [0, 1, 2] ,[])
@@ -459,6 +515,62 @@ def test_join_irreducibles():
         assert isinstance(ji, Partition)
 
 
+def test_lattice_maps():
+    """Test embeddings, duality and isomorphisms between congruence lattices"""
+    # Con of a 3-element set is M3; Con of Z2 is the 2-element chain.
+    m3 = CongruenceLattice(BasicAlgebra('S3', [0, 1, 2], []))
+    s = uacalc_lib.alg.OperationSymbol("s", 1, False)
+    z2 = BasicAlgebra('Z2', [0, 1], [uacalc_lib.alg.Operations.make_int_operation(s, 2, [1, 0])])
+    two = CongruenceLattice(z2)
+
+    assert len(m3.all_isomorphisms(m3)) == 6
+    assert m3.is_dual_to(m3)
+    assert two.embeds_in(m3)
+    assert not m3.embeds_in(two)
+    assert m3.all_isomorphisms(two) == []
+
+
+def test_to_latex():
+    """Test drawing the lattice as a TikZ picture"""
+    alg = BasicAlgebra('TestAlg', [0, 1, 2] ,[])
+    conlat = CongruenceLattice(alg)
+
+    latex = conlat.to_latex()
+
+    assert latex.startswith("\\begin{tikzpicture}")
+    # The partition lattice on three elements has five nodes and six edges
+    assert latex.count("\\node") == 5
+    assert latex.count("\\draw") == 6
+    assert "$|0|1|2|$" in latex
+    assert "$|012|$" in latex
+
+
+def test_product_conlat():
+    """Test assembling Con(A x B) from Con(A) and Con(B)"""
+    plus = uacalc_lib.alg.OperationSymbol("+", 2, False)
+    def zn(n):
+        table = [(k % n + k // n) % n for k in range(n * n)]
+        return BasicAlgebra('Z%d' % n, list(range(n)), [uacalc_lib.alg.Operations.make_int_operation(plus, n, table)])
+
+    # Z2 x Z3 has no skew congruences; Z2 x Z2 has the diagonal.
+    z6 = uacalc_lib.alg.ProductAlgebra("Z2xZ3", [zn(2), zn(3)])
+    con = uacalc_lib.alg.product_conlat(CongruenceLattice(zn(2)), CongruenceLattice(zn(3)), z6)
+    assert con.is_skew_free()
+    assert con.cardinality() == 4
+    assert con.product_congruence(1, 1).number_of_blocks() == 1
+
+    z2 = CongruenceLattice(zn(2))
+    klein = uacalc_lib.alg.ProductAlgebra("Z2xZ2", [zn(2), zn(2)])
+    con = uacalc_lib.alg.product_conlat(z2, z2, klein)
+    assert len(con.product_congruences()) == 4
+    assert not con.is_skew_free()
+    assert len(con.universe()) == 5
+    assert len(con.skew_congruences()) == 1
+
+    with pytest.raises(ValueError):
+        uacalc_lib.alg.product_conlat(z2, z2, z6)
+
+
 def find_all_algebras():
     """Find all .ua algebra files in resources/algebras/ and subdirectories."""
     algebras = []
@@ -0,0 +1,53 @@
+"""
+Tests for congruence regularity, uniformity and coherence.
+"""
+
+import unittest
+import uacalc_lib
+
+alg = uacalc_lib.alg
+
+
+def chain(n):
+    sym = alg.OperationSymbol
+    join = alg.Operations.make_int_operation(sym("join", 2, False), n, [max(k % n, k // n) for k in range(n * n)])
+    meet = alg.Operations.make_int_operation(sym("meet", 2, False), n, [min(k % n, k // n) for k in range(n * n)])
+    return alg.BasicAlgebra("C%d" % n, list(range(n)), [join, meet])
+
+
+class TestCongruenceRegularity(unittest.TestCase):
+    """Test the checks for single algebras and for varieties."""
+
+    def test_ring(self):
+        """Rings are regular, uniform and coherent."""
+        ring = alg.zn_ring(4)
+        self.assertTrue(alg.is_congruence_regular(ring))
+        self.assertTrue(alg.is_congruence_uniform(ring))
+        self.assertTrue(alg.is_congruence_coherent(ring))
+        self.assertTrue(alg.variety_is_congruence_regular(alg.zn_ring(2)))
+        self.assertTrue(alg.variety_is_congruence_coherent(alg.zn_ring(2)))
+        self.assertIsNone(alg.check_variety_congruence_uniform(alg.zn_ring(2)).verdict)
+
+    def test_chain_witnesses(self):
+        """The 3-element chain has witnesses in the algebra itself."""
+        report = alg.check_congruence_regular(chain(3))
+        self.assertFalse(report)
+        self.assertEqual(report.witness_kind, "shared block")
+        self.assertEqual(len(report.congruences), 2)
+        self.assertIsNone(report.free_generators)
+        report = alg.check_congruence_coherent(chain(3))
+        self.assertEqual(report.witness_kind, "not a union of blocks")
+        self.assertTrue(set(report.block) <= set(report.subuniverse))
+
+    def test_lattice_variety(self):
+        """The 2-element lattice is regular but its variety is not."""
+        self.assertTrue(alg.is_congruence_regular(chain(2)))
+        report = alg.check_variety_congruence_regular(chain(2))
+        self.assertEqual(report.verdict, False)
+        self.assertEqual(len(report.free_generators), 3)
+        self.assertIn("in F(3)", str(report))
+        self.assertFalse(alg.variety_is_congruence_coherent(chain(2)))
+
+
+if __name__ == "__main__":
+    unittest.main()
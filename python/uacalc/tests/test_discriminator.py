@@ -0,0 +1,57 @@
+"""
+Tests for the arithmetical and discriminator variety tests.
+"""
+
+import unittest
+import uacalc_lib
+
+alg = uacalc_lib.alg
+
+
+def binary_algebra(name, size, *ops):
+    ops = [alg.Operations.make_int_operation(alg.OperationSymbol(op, 2, False), size,
+                                             [f(k % size, k // size) for k in range(size * size)])
+           for op, f in ops]
+    return alg.BasicAlgebra(name, list(range(size)), ops)
+
+
+class TestDiscriminator(unittest.TestCase):
+    """Test Pixley term and discriminator searches."""
+
+    def test_discriminator_algebra(self):
+        """The ternary discriminator algebra generates a discriminator variety."""
+        a = alg.ternary_discriminator_algebra(3)
+        self.assertTrue(alg.is_arithmetical(a))
+        analysis = alg.is_discriminator_variety(a)
+        self.assertTrue(analysis.holds())
+        self.assertIsNotNone(analysis.discriminator_term)
+        self.assertIsNone(analysis.non_quasiprimal)
+        self.assertIsNotNone(alg.quasiprimal_term(a))
+
+    def test_lattice(self):
+        """Lattices are congruence distributive but not permutable."""
+        a = binary_algebra("2", 2, ("join", max), ("meet", min))
+        self.assertIsNone(alg.is_arithmetical(a).pixley_term)
+        analysis = alg.is_discriminator_variety(a)
+        self.assertFalse(analysis)
+        self.assertFalse(analysis.arithmetical())
+
+    def test_heyting_chain(self):
+        """The 3-element Heyting chain is SI but not simple."""
+        a = binary_algebra("H3", 3, ("join", max), ("meet", min),
+                           ("imp", lambda x, y: 2 if x <= y else y))
+        analysis = alg.is_discriminator_variety(a)
+        self.assertTrue(analysis.arithmetical())
+        self.assertFalse(analysis.holds())
+        self.assertEqual(analysis.non_quasiprimal.cardinality(), 3)
+
+    def test_report_properties(self):
+        """The Mal'cev analysis records both properties."""
+        a = alg.ternary_discriminator_algebra(2)
+        report = alg.malcev_analysis(a, ["pixley", "discriminator"])
+        self.assertTrue(report.properties()["arithmetical"])
+        self.assertTrue(report.properties()["discriminator_variety"])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,40 @@
+"""
+Tests for the definable principal congruences probe.
+"""
+
+import unittest
+import uacalc_lib
+
+alg = uacalc_lib.alg
+
+
+def two_element(*tables):
+    ops = [alg.Operations.make_int_operation(alg.OperationSymbol(name, 2, False), 2, table)
+           for name, table in tables]
+    return alg.BasicAlgebra("A", [0, 1], ops)
+
+
+class TestDpc(unittest.TestCase):
+    """Test the bounded DPC search."""
+
+    def test_semilattice(self):
+        """Semilattices need chains of length 2 built from terms of depth 1."""
+        report = alg.dpc_probe(two_element(("meet", [0, 0, 0, 1])), 3)
+        self.assertTrue(report.found())
+        self.assertEqual(report.scheme, (2, 1))
+        self.assertEqual([level.size for level in report.levels], [2, 4, 8])
+        self.assertIn("Cg(u, v)", report.formula)
+
+    def test_lattice(self):
+        """The term depth for the 2-element lattice still grows at 2^3."""
+        lattice = two_element(("join", [0, 1, 1, 1]), ("meet", [0, 0, 0, 1]))
+        report = alg.dpc_probe(lattice, 3)
+        self.assertFalse(report.found())
+        self.assertIsNone(report.scheme)
+        level = alg.dpc_level(lattice, 2)
+        self.assertEqual(level.chain_length, 2)
+        self.assertEqual(len(level.witness), 4)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,41 @@
+"""
+Tests for the natural duality probe.
+"""
+
+import unittest
+import uacalc_lib
+
+alg = uacalc_lib.alg
+
+
+def binary_algebra(name, size, *ops):
+    ops = [alg.Operations.make_int_operation(alg.OperationSymbol(op, 2, False), size,
+                                             [f(k % size, k // size) for k in range(size * size)])
+           for op, f in ops]
+    return alg.BasicAlgebra(name, list(range(size)), ops)
+
+
+class TestDuality(unittest.TestCase):
+    """Test the interpolation condition search."""
+
+    def test_lattice(self):
+        """Binary relations dualize the two element lattice."""
+        a = binary_algebra("2", 2, ("join", max), ("meet", min))
+        probe = alg.probe_duality(a)
+        self.assertTrue(probe.holds())
+        self.assertIsNone(probe.failure)
+        self.assertTrue(probe.candidates)
+
+    def test_group(self):
+        """Z2 needs the ternary graph of +."""
+        a = binary_algebra("Z2", 2, ("+", lambda x, y: (x + y) % 2))
+        probe = alg.probe_duality(a, max_n=2)
+        self.assertFalse(probe)
+        self.assertEqual(probe.failure.arity(), 2)
+        probe = alg.probe_duality(a, relation_arity=3, max_n=2, max_relations=1)
+        self.assertTrue(probe)
+        self.assertTrue(any(c[0].arity() == 3 for c in probe.candidates))
+
+
+if __name__ == "__main__":
+    unittest.main()
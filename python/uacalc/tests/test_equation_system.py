@@ -0,0 +1,80 @@
+#!/usr/bin/env python3
+"""
+Tests for EquationSystem class.
+
+This module tests checking several equations at once against an algebra.
+"""
+
+import unittest
+
+import uacalc_lib
+
+EquationSystem = uacalc_lib.eq.EquationSystem
+Equation = uacalc_lib.eq.Equation
+VariableImp = uacalc_lib.terms.VariableImp
+NonVariableTerm = uacalc_lib.terms.NonVariableTerm
+OperationSymbol = uacalc_lib.alg.OperationSymbol
+BasicAlgebra = uacalc_lib.alg.BasicAlgebra
+Operations = uacalc_lib.alg.Operations
+
+
+class TestEquationSystem(unittest.TestCase):
+    """Test cases for EquationSystem class."""
+
+    def setUp(self):
+        """Set up a two element meet semilattice and some equations."""
+        self.f = OperationSymbol("f", 2, False)
+        op = Operations.make_int_operation(self.f, 2, [0, 0, 0, 1])
+        self.alg = BasicAlgebra("Meet", [0, 1], [op])
+
+        x = VariableImp("x")
+        y = VariableImp("y")
+        self.commutative = Equation(NonVariableTerm(self.f, [x, y]), NonVariableTerm(self.f, [y, x]))
+        self.idempotent = Equation(NonVariableTerm(self.f, [x, x]), x)
+        self.left_proj = Equation(NonVariableTerm(self.f, [x, y]), x)
+
+    def test_satisfied_in(self):
+        """Each equation gets its own verdict."""
+        system = EquationSystem([self.commutative, self.idempotent, self.left_proj])
+        self.assertEqual(len(system), 3)
+        verdicts = system.satisfied_in(self.alg)
+        self.assertIsNone(verdicts[0])
+        self.assertIsNone(verdicts[1])
+        self.assertIsNotNone(verdicts[2])
+        self.assertFalse(system.all_satisfied_in(self.alg))
+
+    def test_failure_maps(self):
+        """Failures are reported as variable maps."""
+        system = EquationSystem()
+        system.add(self.left_proj)
+        failure = system.failure_maps(self.alg)[0]
+        self.assertEqual(set(failure.keys()), {"x", "y"})
+        self.assertNotEqual(min(failure["x"], failure["y"]), failure["x"])
+
+    def test_matches_single_equations(self):
+        """Verdicts agree with checking the equations one at a time."""
+        equations = [self.commutative, self.idempotent, self.left_proj]
+        verdicts = EquationSystem(equations).satisfied_in(self.alg)
+        for eq, verdict in zip(equations, verdicts):
+            self.assertEqual(verdict is None, eq.is_satisfied_in(self.alg))
+
+    def test_sides_with_different_variables(self):
+        """Equations are checked over the variables of both sides."""
+        x = VariableImp("x")
+        y = VariableImp("y")
+        z = VariableImp("z")
+        meet_xy_is_z = Equation(NonVariableTerm(self.f, [x, y]), z)
+        x_is_y = Equation(x, y)
+        system = EquationSystem([self.idempotent, meet_xy_is_z, x_is_y])
+        self.assertEqual(system.get_variable_list(), ["x", "y", "z"])
+        verdicts = system.satisfied_in(self.alg)
+        self.assertIsNone(verdicts[0])
+        self.assertEqual(len(verdicts[1]), 3)
+        self.assertEqual(len(verdicts[2]), 2)
+        maps = system.failure_maps(self.alg)
+        self.assertNotEqual(min(maps[1]["x"], maps[1]["y"]), maps[1]["z"])
+        self.assertNotEqual(maps[2]["x"], maps[2]["y"])
+
+
+if __name__ == '__main__':
+    unittest.main()
@@ -303,6 +303,63 @@ class TestEquations(unittest.TestCase):
         self.assertIn(left_side, equation_str)
         self.assertIn(right_side, equation_str)
     
+    def test_equation_from_terms(self):
+        """Test building an equation directly from term objects."""
+        import uacalc_lib
+        
+        OperationSymbol = uacalc_lib.alg.OperationSymbol
+        VariableImp = uacalc_lib.terms.VariableImp
+        NonVariableTerm = uacalc_lib.terms.NonVariableTerm
+        f = OperationSymbol("f", 2, False)
+        x, y = VariableImp("x"), VariableImp("y")
+        equation = uacalc_lib.eq.Equation.from_terms(NonVariableTerm(f, [x, y]), NonVariableTerm(f, [y, x]))
+        self.assertEqual(str(equation), "f(x,y) = f(y,x)")
+        self.assertEqual(equation.get_variable_list(), ["x", "y"])
+        with self.assertRaises(ValueError):
+            uacalc_lib.eq.Equation.from_terms(x, "y")
+    
+    def test_terms_equal_in(self):
+        """Test comparing terms in an algebra and in its variety."""
+        import uacalc_lib
+        
+        z4 = uacalc_lib.alg.zn_ring(4)
+        to_term = uacalc_lib.terms.string_to_term
+        eq = uacalc_lib.eq
+        
+        for left, right, expected in [
+            ("mul(x,add(y,z))", "add(mul(x,y),mul(x,z))", True),
+            ("mul(x,x)", "x", False),
+        ]:
+            self.assertEqual(eq.terms_equal_in(z4, to_term(left), to_term(right)), expected)
+            self.assertEqual(eq.terms_equal_in_variety(z4, to_term(left), to_term(right)), expected)
+        z2 = uacalc_lib.alg.zn_ring(2)
+        self.assertTrue(eq.terms_equal_in(z2, to_term("mul(x,x)"), to_term("x")))
+        product = uacalc_lib.alg.ProductAlgebra("Z2xZ4", [z2, z4])
+        self.assertFalse(eq.terms_equal_in(product, to_term("mul(x,x)"), to_term("x")))
+        with self.assertRaises(ValueError):
+            eq.terms_equal_in(z4, to_term("f(x)"), to_term("x"))
+    
+    def test_equation_complexity(self):
+        """Test the structural complexity of equations and sorting by it."""
+        import uacalc_lib
+        
+        to_term = uacalc_lib.terms.string_to_term
+        Equation = uacalc_lib.eq.Equation
+        assoc = Equation(to_term("f(f(x,y),z)"), to_term("f(x,f(y,z))"))
+        idem = Equation(to_term("f(x,x)"), to_term("x"))
+        
+        c = assoc.complexity()
+        self.assertEqual((c.size, c.depth, c.variable_count), (4, 2, 3))
+        self.assertTrue(c.is_linear() and c.is_balanced() and c.is_regular() and c.is_normal())
+        c = idem.complexity()
+        self.assertEqual(c.left_variables, {"x": 2})
+        self.assertFalse(c.is_linear() or c.is_balanced() or c.is_normal())
+        self.assertTrue(c.is_regular())
+        
+        system = uacalc_lib.eq.EquationSystem([assoc, idem])
+        system.sort_by_complexity()
+        self.assertEqual([str(e) for e in system.equations()], [str(idem), str(assoc)])
+    
     def test_java_wrapper_test_command(self):
         """Test the Java wrapper test command to verify all functions work."""
         java_result = run_java_wrapper(
@@ -347,5 +404,40 @@ class TestEquations(unittest.TestCase):
         self.assertEqual(set(vars3), {"x0", "x1"})
 
 
+    def test_theories(self):
+        """Test the named axiom sets against algebras that satisfy them."""
+        import uacalc_lib
+        eq, alg = uacalc_lib.eq, uacalc_lib.alg
+
+        z6 = alg.zn_ring(6)
+        self.assertTrue(all(e.is_satisfied_in(z6) for e in eq.ring_theory()))
+        self.assertTrue(all(e.is_satisfied_in(z6) for e in eq.semiring_theory()))
+        self.assertFalse(all(e.is_satisfied_in(z6) for e in eq.group_theory(inv="neg")))
+        b2 = alg.boolean_algebra(2)
+        self.assertEqual(len(eq.boolean_algebra_theory()), 11)
+        self.assertTrue(all(e.is_satisfied_in(b2) for e in eq.boolean_algebra_theory()))
+        self.assertEqual(str(eq.semigroup_theory("f")[0]), "f(x,f(y,z)) = f(f(x,y),z)")
+        with self.assertRaises(ValueError):
+            eq.lattice_theory("join", "join")
+
+    def test_check_theory_and_classify(self):
+        """Test theory reports with counterexamples and classification."""
+        import uacalc_lib
+        eq, alg = uacalc_lib.eq, uacalc_lib.alg
+
+        z4 = alg.zn_ring(4)
+        report = eq.check_theory(z4, "monoid", ["add", "one"])
+        self.assertFalse(report.holds())
+        self.assertEqual(report.failure_count(), 2)
+        self.assertEqual(report.axioms[1][1], {"x": 0})
+        self.assertTrue(eq.check_theory(z4, "ring").holds())
+        with self.assertRaises(ValueError):
+            eq.check_theory(z4, "loop")
+
+        classification = eq.classify(z4)
+        self.assertEqual([r.theory for r in classification.satisfied], ["ring"])
+        self.assertIn("lattice", [r.theory for r in classification.nearest])
+        self.assertEqual([r.theory for r in eq.classify(alg.boolean_algebra(2)).satisfied], ["boolean_algebra"])
+
 if __name__ == '__main__':
     unittest.main()
@@ -0,0 +1,85 @@
+#!/usr/bin/env python3
+"""
+Tests for the lifecycle events of computations.
+"""
+
+import unittest
+
+import uacalc_lib
+
+alg = uacalc_lib.alg
+events = uacalc_lib.events
+ProgressReporter = uacalc_lib.progress.ProgressReporter
+CancelledError = uacalc_lib.progress.CancelledError
+OperationSymbol = uacalc_lib.alg.OperationSymbol
+BasicAlgebra = uacalc_lib.alg.BasicAlgebra
+Operations = uacalc_lib.alg.Operations
+
+
+def two_element_lattice():
+    """The two element lattice."""
+    join = Operations.make_int_operation(OperationSymbol("join", 2, False), 2, [0, 1, 1, 1])
+    meet = Operations.make_int_operation(OperationSymbol("meet", 2, False), 2, [0, 0, 0, 1])
+    return BasicAlgebra("L", [0, 1], [join, meet])
+
+
+class TestEvents(unittest.TestCase):
+    """Test cases for events.subscribe."""
+
+    def setUp(self):
+        self.lattice = two_element_lattice()
+        self.handles = []
+
+    def tearDown(self):
+        for handle in self.handles:
+            events.unsubscribe(handle)
+
+    def listen(self, listener):
+        self.handles.append(events.subscribe(listener))
+
+    def test_lifecycle(self):
+        """A computation starts, reports progress and finishes."""
+        seen = []
+        self.listen(seen.append)
+        alg.majority_term(self.lattice)
+        kinds = [e.kind for e in seen]
+        self.assertEqual(kinds[0], "started")
+        self.assertEqual(kinds[-1], "finished")
+        self.assertEqual(seen[0].name, "majority_term")
+        self.assertEqual({e.id for e in seen}, {seen[0].id})
+        self.assertIsNone(seen[-1].error)
+        self.assertGreaterEqual(seen[-1].elapsed, 0.0)
+
+    def test_listeners_coexist(self):
+        """Several listeners and a reporter callback all see the computation."""
+        first, second, reported = [], [], []
+        self.listen(first.append)
+        self.listen(second.append)
+        reporter = ProgressReporter(lambda phase, message: reported.append(message))
+        alg.majority_term(self.lattice, progress=reporter)
+        self.assertEqual([str(e) for e in first], [str(e) for e in second])
+        progress = [e.message for e in first if e.kind == "progress"]
+        self.assertEqual(progress, reported)
+
+    def test_listener_cancels(self):
+        """A listener returning True cancels the computation."""
+        seen = []
+        self.listen(lambda e: seen.append(e) or e.kind == "started")
+        with self.assertRaises(CancelledError):
+            alg.FreeAlgebra(self.lattice, 3)
+        self.assertEqual(seen[-1].kind, "cancelled")
+
+    def test_unsubscribe(self):
+        """An unsubscribed listener sees nothing."""
+        seen = []
+        count = events.listener_count()
+        handle = events.subscribe(seen.append)
+        self.assertEqual(events.listener_count(), count + 1)
+        self.assertTrue(events.unsubscribe(handle))
+        self.assertFalse(events.unsubscribe(handle))
+        alg.majority_term(self.lattice)
+        self.assertEqual(seen, [])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,47 @@
+"""
+Tests for the finite basis probes.
+"""
+
+import unittest
+import uacalc_lib
+
+alg = uacalc_lib.alg
+
+
+def binary_algebra(name, n, f):
+    op = alg.Operations.make_int_operation(
+        alg.OperationSymbol("f", 2, False), n, [f(k % n, k // n) for k in range(n * n)]
+    )
+    return alg.BasicAlgebra(name, list(range(n)), [op])
+
+
+class TestFiniteBasis(unittest.TestCase):
+    """Test the verdicts and the criteria recorded."""
+
+    def test_ring(self):
+        """Z6 is finitely based by the Kruse-L'vov theorem."""
+        report = alg.finite_basis_probe(alg.zn_ring(6))
+        self.assertTrue(report.is_finitely_based())
+        self.assertEqual(report.verdict, "finitely based")
+        self.assertIsNone(report.condition)
+        criterion = report.applicable()[0]
+        self.assertEqual(criterion.name, "ring")
+        self.assertIn("Kruse", criterion.reference)
+
+    def test_two_elements(self):
+        """Every two element algebra is finitely based."""
+        report = alg.finite_basis_probe(binary_algebra("2", 2, min))
+        self.assertEqual(len(report.criteria), 1)
+        self.assertIn("Lyndon", report.criteria[0].reference)
+
+    def test_unknown(self):
+        """No probe applies to the left zero semigroup on 3 elements."""
+        algebra = binary_algebra("L3", 3, lambda x, y: x)
+        report = alg.finite_basis_probe(algebra)
+        self.assertEqual(report.verdict, "unknown")
+        self.assertEqual(report.applicable(), [])
+        self.assertFalse(alg.is_known_finitely_based(algebra))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,42 @@
+#!/usr/bin/env python3
+"""
+Tests for finitely presented lattices: the word problem and covers.
+"""
+
+import unittest
+
+import uacalc_lib
+
+FinitelyPresentedLattice = uacalc_lib.fplat.FinitelyPresentedLattice
+Presentation = uacalc_lib.eq.Presentation
+
+
+class TestFinitelyPresentedLattice(unittest.TestCase):
+
+    def test_word_problem(self):
+        lat = FinitelyPresentedLattice("L", ["x", "y", "z"], ["x <= y"])
+        self.assertTrue(lat.leq("meet(y,join(x,z))", "join(x,y)"))
+        self.assertFalse(lat.leq("join(x,y)", "meet(y,join(x,z))"))
+        self.assertTrue(lat.equal("join(x,y)", "y"))
+        with self.assertRaises(ValueError):
+            lat.leq("w", "x")
+
+    def test_covers(self):
+        lat = FinitelyPresentedLattice("C3", ["x", "y", "z"], ["x <= y", "y <= z"])
+        self.assertEqual(lat.universe(), ["x", "y", "z"])
+        self.assertEqual(lat.upper_covers(), [[1], [2], []])
+
+    def test_infinite(self):
+        lat = FinitelyPresentedLattice("FL3", ["x", "y", "z"], [])
+        with self.assertRaises(ValueError):
+            lat.universe(max_size=50)
+
+    def test_from_presentation(self):
+        pres = Presentation(["x", "y"], [])
+        lat = FinitelyPresentedLattice.from_presentation("FL2", pres)
+        self.assertEqual(len(lat.universe()), 4)
+        self.assertEqual(lat.generators(), ["x", "y"])
+
+
+if __name__ == "__main__":
+    unittest.main()
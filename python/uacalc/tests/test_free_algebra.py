@@ -279,6 +279,27 @@ class TestFreeAlgebra:
         java_data = get_java_data(java_result)
         assert isinstance(java_data["universe"], list)
 
+    def test_with_identities(self):
+        """Test the free algebra modulo extra identities."""
+        alg_module = uacalc_lib.alg
+        FreeAlgebra = getattr(alg_module, 'FreeAlgebra')
+        BasicAlgebra = getattr(alg_module, 'BasicAlgebra')
+        terms = uacalc_lib.terms
+        Equation = uacalc_lib.eq.Equation
+
+        add = alg_module.OperationSymbol("add", 2, False)
+        table = [(k % 4 + k // 4) % 4 for k in range(16)]
+        z4 = BasicAlgebra("Z4", [0, 1, 2, 3], [alg_module.Operations.make_int_operation(add, 4, table)])
+        x = terms.VariableImp("x")
+        y = terms.VariableImp("y")
+        two_x_is_two_y = Equation(terms.NonVariableTerm(add, [x, x]), terms.NonVariableTerm(add, [y, y]))
+
+        # F(2) over Z4 is Z4^2; modulo 2x = 2y it is (Z2)^2.
+        assert FreeAlgebra(z4, 2).cardinality() == 16
+        free_alg = FreeAlgebra.new_with_identities(z4, 2, [two_x_is_two_y])
+        assert free_alg.cardinality() == 4
+        assert FreeAlgebra(z4, 2).with_identities([two_x_is_two_y]).cardinality() == 4
+
     @pytest.mark.parametrize("num_gens", [1, 2, 3, 4])
     def test_different_generator_counts(self, test_config, base_algebra, num_gens):
         """Test FreeAlgebra with different generator counts."""
@@ -0,0 +1,35 @@
+#!/usr/bin/env python3
+"""
+Tests for free lattice terms: Whitman's condition, canonical forms and
+generation of free lattice elements.
+"""
+
+import unittest
+
+import uacalc_lib
+
+lat = uacalc_lib.lat
+
+
+class TestFreeLattice(unittest.TestCase):
+
+    def test_free_lattice_leq(self):
+        self.assertTrue(lat.free_lattice_leq("meet(x,y)", "join(x,z)"))
+        self.assertTrue(lat.free_lattice_leq("join(meet(x,y),meet(x,z))", "meet(x,join(y,z))"))
+        self.assertFalse(lat.free_lattice_leq("meet(x,join(y,z))", "join(meet(x,y),meet(x,z))"))
+        with self.assertRaises(ValueError):
+            lat.free_lattice_leq("f(x,y)", "x")
+
+    def test_lattice_canonical_form(self):
+        self.assertEqual(lat.lattice_canonical_form("meet(x,join(x,y))"), "x")
+        self.assertEqual(lat.lattice_canonical_form("join(y,join(x,y))"), "join(x,y)")
+
+    def test_free_lattice(self):
+        self.assertEqual(lat.free_lattice(2, 100), ["x0", "x1", "join(x0,x1)", "meet(x0,x1)"])
+        elems = lat.free_lattice(3, 60)
+        self.assertEqual(len(elems), 60)
+        self.assertEqual(len(set(elems)), 60)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,78 @@
+#!/usr/bin/env python3
+"""
+Tests for the free spectrum and p_n sequence.
+"""
+
+import unittest
+
+import uacalc_lib
+
+alg = uacalc_lib.alg
+OperationSymbol = uacalc_lib.alg.OperationSymbol
+BasicAlgebra = uacalc_lib.alg.BasicAlgebra
+Operations = uacalc_lib.alg.Operations
+ProgressReporter = uacalc_lib.progress.ProgressReporter
+CancelledError = uacalc_lib.progress.CancelledError
+
+
+class TestFreeSpectrum(unittest.TestCase):
+    """Test cases for free_spectrum and pn_sequence."""
+
+    def setUp(self):
+        """The two element meet semilattice."""
+        meet = OperationSymbol("meet", 2, False)
+        op = Operations.make_int_operation(meet, 2, [0, 0, 0, 1])
+        self.alg = BasicAlgebra("S", [0, 1], [op])
+
+    def test_free_spectrum(self):
+        """|F(k)| = 2^k - 1 for semilattices."""
+        self.assertEqual(alg.free_spectrum(self.alg, 4), [0, 1, 3, 7, 15])
+
+    def test_pn_sequence(self):
+        """Semilattices have exactly one essentially n-ary term for n >= 1."""
+        self.assertEqual(alg.pn_sequence(self.alg, 4), [0, 1, 1, 1, 1])
+
+    def test_cancellation(self):
+        """Returning True from the callback stops the computation."""
+        messages = []
+
+        def callback(message):
+            messages.append(message)
+            return message.startswith("|F(1)|")
+
+        self.assertEqual(alg.free_spectrum(self.alg, 4, callback), [0, 1])
+        self.assertTrue(messages)
+
+    def test_callback_exception(self):
+        """An exception raised by the callback propagates."""
+        def callback(message):
+            raise RuntimeError("stop")
+
+        with self.assertRaises(RuntimeError):
+            alg.free_spectrum(self.alg, 2, callback)
+
+    def test_idempotent_reduct(self):
+        """A semilattice has one essentially n-ary idempotent term for each n >= 2."""
+        reduct = alg.idempotent_reduct(self.alg, 3)
+        self.assertEqual(reduct.name(), "Id(S)")
+        self.assertEqual(sorted(op.arity() for op in reduct.operations()), [2, 3])
+
+        with self.assertRaises(ValueError):
+            alg.idempotent_reduct(self.alg, 3, lambda message: True)
+
+        reporter = ProgressReporter()
+        reporter.cancel()
+        with self.assertRaises(CancelledError):
+            alg.idempotent_reduct(self.alg, 3, progress=reporter)
+
+    def test_term_operations_up_to_arity(self):
+        """The binary term operations of a semilattice are x0, x1 and their meet."""
+        ops = list(alg.term_operations_up_to_arity(self.alg, 2))
+        self.assertEqual([op.arity() for op in ops], [1, 2, 2, 2])
+        self.assertEqual(ops[-1].get_table(), [0, 0, 0, 1])
+        self.assertEqual(ops[-1].get_term(), "meet(x0,x1)")
+        self.assertEqual(len(list(alg.term_operations_up_to_arity(self.alg, 3, 2))), 2)
+
+
+if __name__ == '__main__':
+    unittest.main()
@@ -0,0 +1,58 @@
+"""
+Tests for minimal generating sets and the generating rank.
+"""
+
+import itertools
+import unittest
+import uacalc_lib
+
+alg = uacalc_lib.alg
+
+
+class TestGeneratingSets(unittest.TestCase):
+    """Test generating sets of the group Z6 and of rings."""
+
+    def setUp(self):
+        table = [(k % 6 + k // 6) % 6 for k in range(36)]
+        add = alg.Operations.make_int_operation(alg.OperationSymbol("add", 2, False), 6, table)
+        self.z6 = alg.BasicAlgebra("Z6", list(range(6)), [add])
+
+    def test_minimal_generating_sets(self):
+        """Z6 is generated by 1 or 5, or by elements of orders 2 and 3."""
+        self.assertEqual(alg.minimal_generating_sets(self.z6, 2), [[1], [5], [2, 3], [3, 4]])
+        self.assertEqual(alg.minimal_generating_sets(self.z6, 1), [[1], [5]])
+        self.assertEqual(alg.minimum_generating_set(self.z6), [1])
+        self.assertEqual(alg.generating_rank(self.z6), 1)
+
+    def test_constants_generate(self):
+        """The ring Z2 x Z3 is generated by its constants."""
+        product = alg.ProductAlgebra("Z2xZ3", [alg.zn_ring(2), alg.zn_ring(3)])
+        self.assertEqual(alg.generating_rank(product), 0)
+        self.assertEqual(alg.minimal_generating_sets(product, 3), [[]])
+
+    def test_matches_subsets(self):
+        """The same sets are found by checking every subset."""
+        sub_lat = alg.SubalgebraLattice(self.z6)
+        expected = [
+            list(s) for k in range(3) for s in itertools.combinations(range(6), k)
+            if len(sub_lat.sg(list(s)).elements()) == 6
+            and all(len(sub_lat.sg(list(s[:i] + s[i + 1:])).elements()) < 6 for i in range(k))
+        ]
+        self.assertEqual(alg.minimal_generating_sets(self.z6, 2), expected)
+
+    def test_independent_sets(self):
+        """Independent sets of Z6 and the generating ones among them."""
+        sets = alg.independent_sets(self.z6, 2)
+        self.assertEqual(sets[0], [])
+        self.assertIn([2, 3], sets)
+        self.assertNotIn([2, 4], sets)
+        generating = [s for s in sets if alg.is_independent_generating_set(self.z6, s)]
+        self.assertEqual(generating, alg.minimal_generating_sets(self.z6, 2))
+        self.assertTrue(alg.is_independent(self.z6, [2, 3]))
+        self.assertFalse(alg.is_independent(self.z6, [1, 3]))
+        with self.assertRaises(ValueError):
+            alg.is_independent(self.z6, [1, 1])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,50 @@
+"""
+Tests for the group theory of algebras that are groups.
+"""
+
+import itertools
+import unittest
+import uacalc_lib
+
+alg = uacalc_lib.alg
+group = uacalc_lib.group
+
+
+def permutation_group(name, degree):
+    """The symmetric group on degree points, by its multiplication table."""
+    elems = list(itertools.permutations(range(degree)))
+    index = {p: i for i, p in enumerate(elems)}
+    n = len(elems)
+    table = [index[tuple(elems[i % n][x] for x in elems[i // n])] for i in range(n * n)]
+    mul = alg.Operations.make_int_operation(alg.OperationSymbol("mul", 2, False), n, table)
+    return alg.BasicAlgebra(name, list(range(n)), [mul])
+
+
+class TestGroupAlgebra(unittest.TestCase):
+    """Test center, commutator subgroup, normal subgroups and the series."""
+
+    def test_symmetric_groups(self):
+        s3 = permutation_group("S3", 3)
+        self.assertTrue(group.is_group(s3))
+        self.assertEqual(group.center(s3), [0])
+        self.assertEqual(len(group.commutator_subgroup(s3)), 3)
+        self.assertEqual([len(h) for h in group.normal_subgroups(s3)], [1, 3, 6])
+        self.assertFalse(group.is_abelian(s3))
+        self.assertFalse(group.is_nilpotent(s3))
+        self.assertTrue(group.is_solvable(s3))
+
+        s4 = permutation_group("S4", 4)
+        self.assertEqual([len(h) for h in group.derived_series(s4)], [24, 12, 4, 1])
+        self.assertEqual([len(h) for h in group.normal_subgroups(s4)], [1, 4, 12, 24])
+        self.assertEqual(group.upper_central_series(s4), [[0]])
+
+    def test_not_a_group(self):
+        first = alg.Operations.make_int_operation(alg.OperationSymbol("first", 2, False), 2, [0, 1, 0, 1])
+        a = alg.BasicAlgebra("F", [0, 1], [first])
+        self.assertFalse(group.is_group(a))
+        with self.assertRaises(ValueError):
+            group.center(a)
+
+
+if __name__ == "__main__":
+    unittest.main()
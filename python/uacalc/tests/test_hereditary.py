@@ -0,0 +1,65 @@
+"""
+Tests for the hereditary properties runner.
+"""
+
+import unittest
+import uacalc_lib
+
+alg = uacalc_lib.alg
+
+
+def chain_semilattice(n):
+    table = [min(k % n, k // n) for k in range(n * n)]
+    op = alg.Operations.make_int_operation(alg.OperationSymbol("meet", 2, False), n, table)
+    return alg.BasicAlgebra("S", list(range(n)), [op])
+
+
+class TestHereditary(unittest.TestCase):
+    """Test check_hereditary over S, H and P."""
+
+    def test_subalgebras(self):
+        """Every subalgebra of a chain semilattice has a distributive congruence lattice."""
+        report = alg.check_hereditary(chain_semilattice(3), alg.HereditaryProperty.CON_DISTRIBUTIVE,
+                                      alg.ClosureOperator.S)
+        self.assertEqual(len(report), 7)
+        self.assertTrue(report.holds())
+        self.assertEqual(report.closure, "S")
+
+    def test_quotients(self):
+        """Only the 2-element quotients of a 3-element chain are simple.
+
+        There is one row per congruence, so the two isomorphic 2-element
+        quotients are both counted.
+        """
+        report = alg.check_hereditary(chain_semilattice(3), "simple", alg.ClosureOperator.H)
+        self.assertFalse(report.holds())
+        self.assertEqual(len(report), 4)
+        self.assertEqual(sorted(row.size for row in report.rows if row.value), [2, 2])
+
+    def test_powers(self):
+        """Counts are reported per power."""
+        report = alg.check_hereditary(chain_semilattice(2), alg.HereditaryProperty.CON_CARDINALITY,
+                                      alg.ClosureOperator.P, max_power=3)
+        self.assertEqual([row.size for row in report.rows], [2, 4, 8])
+        self.assertIsNone(report.holds())
+        self.assertEqual(report.closure, "P(3)")
+
+
+class TestClassOperators(unittest.TestCase):
+    """Test the lazy H, S and P iterators."""
+
+    def test_subalgebras_and_quotients(self):
+        """The 3-element chain has 3 subalgebras and 3 quotients up to isomorphism."""
+        a = chain_semilattice(3)
+        self.assertEqual(sorted(s.cardinality() for s in alg.all_subalgebras(a)), [1, 2, 3])
+        self.assertEqual(sorted(q.cardinality() for q in alg.all_quotients(a)), [1, 2, 3])
+
+    def test_powers(self):
+        """Powers are built one at a time."""
+        powers = alg.powers_up_to(chain_semilattice(2), 3)
+        self.assertEqual(next(powers).cardinality(), 2)
+        self.assertEqual([p.cardinality() for p in powers], [4, 8])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -298,5 +298,97 @@ class TestHomomorphism:
         assert result[1].get(0) == 1  # Second element maps to 1
 
 
+    def test_homomorphism_find_image_and_compose(self):
+        """Test finding, composing and taking images of homomorphisms."""
+        s = uacalc_lib.alg.OperationSymbol("s", 1, False)
+        make = uacalc_lib.alg.Operations.make_int_operation
+        z4 = BasicAlgebra("Z4", [0, 1, 2, 3], [make(s, 4, [1, 2, 3, 0])])
+        z2 = BasicAlgebra("Z2", [0, 1], [make(s, 2, [1, 0])])
+
+        homo = Homomorphism.find(z4, z2)
+        assert homo is not None
+        assert homo.get_map() == {0: 0, 1: 1, 2: 0, 3: 1}
+        assert homo.is_surjective()
+        assert not homo.is_injective()
+        assert homo.image() == [0, 1]
+        assert homo.kernel().number_of_blocks() == 2
+        assert homo.image_subalgebra().cardinality() == 2
+        assert Homomorphism.find(z2, z4) is None
+
+        swap = Homomorphism(z2, z2, {0: 1, 1: 0})
+        composite = homo.compose(swap)
+        assert composite.get_map() == {0: 1, 1: 0, 2: 1, 3: 0}
+        assert composite.get_domain().name() == "Z4"
+        with pytest.raises(ValueError):
+            swap.compose(homo)
+
+
+    def test_natural_maps(self):
+        """Test the canonical projection, inclusion and first isomorphism theorem."""
+        s = uacalc_lib.alg.OperationSymbol("s", 1, False)
+        make = uacalc_lib.alg.Operations.make_int_operation
+        z4 = BasicAlgebra("Z4", [0, 1, 2, 3], [make(s, 4, [1, 2, 3, 0])])
+        theta = uacalc_lib.alg.Partition("|0 2|1 3|")
+
+        pi = Homomorphism.canonical_projection(z4, theta)
+        assert pi.get_map() == {0: 0, 1: 1, 2: 0, 3: 1}
+        assert pi.kernel() == theta
+        assert pi.is_surjective()
+        with pytest.raises(ValueError):
+            Homomorphism.canonical_projection(z4, uacalc_lib.alg.Partition("|0 1|2|3|"))
+
+        sub = uacalc_lib.alg.Subalgebra("S", z4, [0, 1, 2, 3])
+        inclusion = Homomorphism.inclusion(sub)
+        assert inclusion.is_injective()
+        assert inclusion.get_map() == {0: 0, 1: 1, 2: 2, 3: 3}
+
+        iso = Homomorphism.first_isomorphism_theorem(pi)
+        assert iso.is_injective()
+        assert iso.is_surjective()
+        assert iso.get_map() == {0: 0, 1: 1}
+
+    def test_create_subdirect_product(self):
+        """Test the subdirect product induced by a family of homomorphisms."""
+        s = uacalc_lib.alg.OperationSymbol("s", 1, False)
+        make = uacalc_lib.alg.Operations.make_int_operation
+        z4 = BasicAlgebra("Z4", [0, 1, 2, 3], [make(s, 4, [1, 2, 3, 0])])
+        z2 = BasicAlgebra("Z2", [0, 1], [make(s, 2, [1, 0])])
+        pi = Homomorphism(z4, z2, {0: 0, 1: 1, 2: 0, 3: 1})
+        identity = Homomorphism(z4, z4, {0: 0, 1: 1, 2: 2, 3: 3})
+
+        sub = uacalc_lib.alg.create_subdirect_product([identity, pi])
+        assert len(sub) == 4
+        assert sub.number_of_factors() == 2
+        assert sorted(e.to_array() for e in sub.get_universe_list()) == [[0, 0], [1, 1], [2, 0], [3, 1]]
+        assert sub.to_basic_algebra().cardinality() == 4
+
+        # The image of pi into Z4 misses two elements.
+        into_z4 = Homomorphism(z2, z4, {0: 0, 1: 2})
+        with pytest.raises(ValueError):
+            uacalc_lib.alg.create_subdirect_product([into_z4])
+        with pytest.raises(ValueError):
+            uacalc_lib.alg.create_subdirect_product([])
+
+    def test_fiber_product(self):
+        """Test the fiber product of two homomorphisms into Z2."""
+        s = uacalc_lib.alg.OperationSymbol("s", 1, False)
+        make = uacalc_lib.alg.Operations.make_int_operation
+        z4 = BasicAlgebra("Z4", [0, 1, 2, 3], [make(s, 4, [1, 2, 3, 0])])
+        z2 = BasicAlgebra("Z2", [0, 1], [make(s, 2, [1, 0])])
+        pi = Homomorphism(z4, z2, {0: 0, 1: 1, 2: 0, 3: 1})
+
+        pullback = uacalc_lib.alg.fiber_product(pi, pi)
+        assert len(pullback) == 8
+        assert sorted(pullback.pairs()) == [(a, b) for a in range(4) for b in range(4) if a % 2 == b % 2]
+        first = pullback.first_projection()
+        assert first.is_surjective()
+        assert [first.get_map()[i] for i in range(8)] == [a for a, _ in pullback.pairs()]
+        assert pullback.algebra().cardinality() == 8
+
+        z3 = BasicAlgebra("Z3", [0, 1, 2], [make(s, 3, [1, 2, 0])])
+        with pytest.raises(ValueError):
+            uacalc_lib.alg.fiber_product(pi, Homomorphism(z3, z3, {0: 0, 1: 1, 2: 2}))
+
+
 if __name__ == "__main__":
     pytest.main([__file__])
@@ -329,3 +329,16 @@ class TestHorner:
         # Just verify it completed successfully
         assert "message" in java_result["data"]
         assert java_result["data"]["message"] == "Test completed successfully"
+
+
+def test_encode_decode_tuple():
+    """Mixed radix codes agree with Horner and go past 64 bits."""
+    util = uacalc_lib.util
+    assert util.encode_tuple([1, 2, 3], [4, 5, 6]) == Horner.horner([1, 2, 3], [4, 5, 6])
+    assert util.decode_tuple(5, [2, 3]) == [1, 2]
+    assert util.encode_tuple([1] * 100, [2] * 100) == 2 ** 100 - 1
+    assert util.decode_tuple(2 ** 100 - 1, [2] * 100) == [1] * 100
+    with pytest.raises(ValueError):
+        util.encode_tuple([2, 0], [2, 3])
+    with pytest.raises(ValueError):
+        util.decode_tuple(6, [2, 3])
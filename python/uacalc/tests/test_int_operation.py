@@ -250,6 +250,71 @@ class TestIntOperation(unittest.TestCase):
                 expected = xor_matrix[i][j]
                 self.assertEqual(result, expected)
 
+    def test_table_editing(self):
+        """Test editing the table in place."""
+        import uacalc_lib
+        IntOperation = uacalc_lib.alg.IntOperation
+
+        op = IntOperation.binary_and("and")
+        self.assertTrue(op.is_associative())
+        op[1, 0] = 1
+        self.assertEqual(op[1, 0], 1)
+        self.assertEqual(op.int_value_at([1, 0]), 1)
+        self.assertFalse(op.is_associative())
+        op.update_from_matrix([[0, 0], [0, 1]])
+        self.assertTrue(op.is_associative())
+        self.assertEqual(op.get_table(), [0, 0, 0, 1])
+
+        with self.assertRaises(ValueError):
+            op[1, 0] = 2
+        with self.assertRaises(ValueError):
+            op[1] = 0
+        with self.assertRaises(ValueError):
+            op.update_from_matrix([[0, 0]])
+        self.assertEqual(op.get_table(), [0, 0, 0, 1])
+
+        neg = IntOperation.unary_not("not")
+        neg[0] = 0
+        self.assertTrue(neg.is_idempotent())
+
+        # Editable operations cannot be used as set members or dict keys.
+        with self.assertRaises(TypeError):
+            hash(op)
+
+    def test_essential_arity(self):
+        """Test essential arity and unary decomposition."""
+        import uacalc_lib
+        IntOperation = uacalc_lib.alg.IntOperation
+
+        # f(x, y) = not y, with the first argument varying fastest
+        OperationSymbol = uacalc_lib.alg.OperationSymbol
+        op = IntOperation(OperationSymbol("f", 2, False), 2, [1, 1, 0, 0])
+        self.assertEqual(op.essential_arity(), 1)
+        self.assertFalse(op.depends_on_argument(0))
+        self.assertTrue(op.depends_on_argument(1))
+        self.assertEqual(op.essentially_unary_decomposition(), (1, [1, 0]))
+        with self.assertRaises(ValueError):
+            op.depends_on_argument(2)
+
+        xor = IntOperation.binary_xor("xor")
+        self.assertEqual(xor.essential_arity(), 2)
+        self.assertIsNone(xor.essentially_unary_decomposition())
+
+    def test_format_table(self):
+        """Test Cayley table formatting."""
+        import uacalc_lib
+        IntOperation = uacalc_lib.alg.IntOperation
+
+        xor = IntOperation.binary_xor("xor")
+        self.assertEqual(xor.format_table(), "xor | 0 1\n----+----\n  0 | 0 1\n  1 | 1 0\n")
+        markdown = xor.format_table("markdown", ["a", "b"])
+        self.assertIn("| b | b | a |", markdown)
+        self.assertTrue(xor.to_latex().startswith("\\begin{tabular}{c|cc}"))
+        with self.assertRaises(ValueError):
+            xor.format_table("html")
+        with self.assertRaises(ValueError):
+            xor.format_table("text", ["a"])
+
 
 def run_java_wrapper(wrapper_class: str, args):
     """Run Java wrapper and return JSON output."""
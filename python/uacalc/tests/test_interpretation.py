@@ -0,0 +1,45 @@
+"""
+Tests for the pp-interpretation search.
+"""
+
+import unittest
+import uacalc_lib
+
+alg = uacalc_lib.alg
+
+
+def binary_algebra(name, size, f):
+    op = alg.Operations.make_int_operation(alg.OperationSymbol("f", 2, False), size,
+                                           [f(k % size, k // size) for k in range(size * size)])
+    return alg.BasicAlgebra(name, list(range(size)), [op])
+
+
+class TestInterpretation(unittest.TestCase):
+    """Test pp-interpretations between small algebras."""
+
+    def test_subgroup(self):
+        """Z4 interprets Z2 on its subgroup {0, 2}."""
+        z2 = binary_algebra("Z2", 2, lambda x, y: (x + y) % 2)
+        z4 = binary_algebra("Z4", 4, lambda x, y: (x + y) % 4)
+        interpretation = alg.pp_interprets(z4, z2, 1)
+        self.assertEqual(interpretation.dimension, 1)
+        self.assertEqual(interpretation.domain, [[0], [2]])
+        self.assertEqual(interpretation.coordinate([2]), 1)
+        self.assertIsNone(interpretation.coordinate([1]))
+
+    def test_no_interpretation(self):
+        """An abelian group does not interpret a semilattice."""
+        z2 = binary_algebra("Z2", 2, lambda x, y: (x + y) % 2)
+        meet = binary_algebra("S", 2, min)
+        self.assertIsNone(alg.pp_interprets(z2, meet))
+
+    def test_dimension(self):
+        """A two element set needs two coordinates for three elements."""
+        two = alg.BasicAlgebra("2", [0, 1], [])
+        z3 = binary_algebra("Z3", 3, lambda x, y: (x + y) % 3)
+        self.assertIsNone(alg.pp_interprets(two, z3, 1))
+        self.assertEqual(alg.pp_interprets(two, z3).dimension, 2)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,62 @@
+#!/usr/bin/env python3
+"""
+Tests for the versioned JSON representations.
+"""
+
+import json
+import unittest
+
+import uacalc_lib
+
+io = uacalc_lib.io
+OperationSymbol = uacalc_lib.alg.OperationSymbol
+BasicAlgebra = uacalc_lib.alg.BasicAlgebra
+Operations = uacalc_lib.alg.Operations
+Partition = uacalc_lib.alg.Partition
+
+
+def two_element_lattice():
+    """The two element lattice."""
+    join = Operations.make_int_operation(OperationSymbol("join", 2, False), 2, [0, 1, 1, 1])
+    meet = Operations.make_int_operation(OperationSymbol("meet", 2, False), 2, [0, 0, 0, 1])
+    return BasicAlgebra("L", [0, 1], [join, meet])
+
+
+class TestJsonSchema(unittest.TestCase):
+    """Test cases for the JSON helpers of the io module."""
+
+    def test_algebra_round_trip(self):
+        """Algebras survive a round trip and carry the envelope."""
+        doc = json.loads(io.algebra_to_json(two_element_lattice()))
+        self.assertEqual(doc["schema_version"], io.SCHEMA_VERSION)
+        self.assertEqual(doc["kind"], "algebra")
+        self.assertEqual(doc["operations"][0]["table"], [0, 1, 1, 1])
+        alg = io.algebra_from_json(json.dumps(doc))
+        self.assertEqual(alg.cardinality(), 2)
+        self.assertEqual(json.loads(io.algebra_to_json(alg)), doc)
+        with self.assertRaises(ValueError):
+            io.algebra_from_json('{"kind": "partition", "size": 2}')
+
+    def test_partition_round_trip(self):
+        """Partitions are written as blocks."""
+        par = Partition([-2, 0, -1])
+        doc = json.loads(io.partition_to_json(par))
+        self.assertEqual(doc["blocks"], [[0, 1], [2]])
+        self.assertEqual(str(io.partition_from_json(json.dumps(doc))), str(par))
+
+    def test_reports(self):
+        """Analysis reports are JSON documents."""
+        report = json.loads(io.malcev_report(two_element_lattice(), ["majority", "malcev"]))
+        self.assertEqual(report["kind"], "malcev_report")
+        self.assertIsNotNone(report["terms"]["majority"])
+        self.assertIsNone(report["terms"]["malcev"])
+        with self.assertRaises(ValueError):
+            io.malcev_report(two_element_lattice(), ["unknown"])
+
+        report = json.loads(io.tct_report(two_element_lattice()))
+        self.assertEqual(report["type_set"], [4])
+        self.assertEqual(report["join_irreducibles"][0]["type"], 4)
+
+
+if __name__ == '__main__':
+    unittest.main()
@@ -384,6 +384,115 @@ class TestMalcevPython(unittest.TestCase):
         else:
             self.skipTest(f"Algebra too small (size {alg.cardinality()})")
 
+    def test_has_taylor_term(self):
+        """Z3 under x - y has a Taylor term; a set with a permutation does not."""
+        BasicAlgebra = uacalc_lib.alg.BasicAlgebra
+        OperationSymbol = uacalc_lib.alg.OperationSymbol
+        Operations = uacalc_lib.alg.Operations
+
+        d = OperationSymbol("d", 2, False)
+        table = [(k % 3 + 2 * (k // 3)) % 3 for k in range(9)]
+        z3 = BasicAlgebra("Z3", [0, 1, 2], [Operations.make_int_operation(d, 3, table)])
+        analysis = uacalc_lib.alg.has_taylor_term(z3)
+        self.assertTrue(analysis.has_taylor_term())
+        self.assertIsNotNone(analysis.term())
+        self.assertEqual(len(analysis.identities()), 3)
+
+        f = OperationSymbol("f", 1, False)
+        s2 = BasicAlgebra("S", [0, 1], [Operations.make_int_operation(f, 2, [1, 0])])
+        analysis = uacalc_lib.alg.has_taylor_term(s2)
+        self.assertFalse(analysis)
+        self.assertIsNone(analysis.term())
+        self.assertEqual(analysis.identities(), [])
+
+    def test_cyclic_terms(self):
+        """2x + 2y on Z3 has a cyclic term of arity 2 but not of arity 3."""
+        BasicAlgebra = uacalc_lib.alg.BasicAlgebra
+        OperationSymbol = uacalc_lib.alg.OperationSymbol
+        Operations = uacalc_lib.alg.Operations
+
+        d = OperationSymbol("d", 2, False)
+        table = [(2 * (k % 3) + 2 * (k // 3)) % 3 for k in range(9)]
+        z3 = BasicAlgebra("Z3", [0, 1, 2], [Operations.make_int_operation(d, 3, table)])
+        self.assertTrue(uacalc_lib.alg.has_cyclic_term(z3, 2))
+        self.assertFalse(uacalc_lib.alg.has_cyclic_term(z3, 3))
+        self.assertEqual(uacalc_lib.alg.find_cyclic_term(z3, 2), "d(x0,x1)")
+        self.assertIsNone(uacalc_lib.alg.find_cyclic_term(z3, 3))
+        with self.assertRaises(ValueError):
+            uacalc_lib.alg.has_cyclic_term(z3, 1)
+
+    def test_malcev_analysis(self):
+        """Z2 under x + y + z has a Mal'cev term but no majority term."""
+        BasicAlgebra = uacalc_lib.alg.BasicAlgebra
+        OperationSymbol = uacalc_lib.alg.OperationSymbol
+        Operations = uacalc_lib.alg.Operations
+
+        m = OperationSymbol("m", 3, False)
+        table = [(k % 2 + k // 2 % 2 + k // 4) % 2 for k in range(8)]
+        z2 = BasicAlgebra("Z2", [0, 1], [Operations.make_int_operation(m, 2, table)])
+        analysis = uacalc_lib.alg.malcev_analysis(z2, kinds=["malcev", "majority"])
+        self.assertEqual(analysis.algebra(), "Z2")
+        self.assertEqual(analysis.size(), 2)
+        terms = analysis.terms()
+        self.assertEqual(len(terms["malcev"]), 1)
+        self.assertIsNone(terms["majority"])
+        self.assertIn("malcev", repr(analysis))
+        self.assertEqual(json.loads(analysis.to_json())["kind"], "malcev_report")
+
+        latex = analysis.to_latex()
+        self.assertIn("\\begin{tabular}{ll}", latex)
+        self.assertIn("majority & none \\\\", latex)
+
+        with self.assertRaises(ValueError):
+            uacalc_lib.alg.malcev_analysis(z2, kinds=["nonsense"])
+
+    def test_semidistributivity(self):
+        """Z2 under x + y + z is affine: neither it nor its variety is SD-meet."""
+        BasicAlgebra = uacalc_lib.alg.BasicAlgebra
+        OperationSymbol = uacalc_lib.alg.OperationSymbol
+        Operations = uacalc_lib.alg.Operations
+
+        m = OperationSymbol("m", 3, False)
+        table = [(k % 2 + k // 2 % 2 + k // 4) % 2 for k in range(8)]
+        z2 = BasicAlgebra("Z2", [0, 1], [Operations.make_int_operation(m, 2, table)])
+        analysis = uacalc_lib.alg.variety_is_sd_meet(z2)
+        self.assertFalse(analysis)
+        self.assertIsNone(analysis.terms())
+        self.assertIsNotNone(analysis.failure())
+
+        report = uacalc_lib.alg.malcev_analysis(z2, kinds=["sd-meet"])
+        self.assertFalse(report.properties()["variety_sd_meet"])
+        # Con(Z2) is the 2 element chain
+        self.assertTrue(report.properties()["con_sd_meet"])
+        self.assertTrue(report.properties()["con_sd_join"])
+        self.assertEqual(uacalc_lib.alg.malcev_analysis(z2, kinds=["malcev"]).properties(), {})
+
+    def test_omitted_types(self):
+        """Z2 under x + y + z has only type 2: it omits 1, 4 and 5 but not 2."""
+        BasicAlgebra = uacalc_lib.alg.BasicAlgebra
+        OperationSymbol = uacalc_lib.alg.OperationSymbol
+        Operations = uacalc_lib.alg.Operations
+
+        m = OperationSymbol("m", 3, False)
+        table = [(k % 2 + k // 2 % 2 + k // 4) % 2 for k in range(8)]
+        z2 = BasicAlgebra("Z2", [0, 1], [Operations.make_int_operation(m, 2, table)])
+        omissions = {tuple(o.types()): o for o in uacalc_lib.alg.omitted_types(z2)}
+        self.assertTrue(omissions[(1,)].omitted())
+        self.assertFalse(omissions[(1, 2)].omitted())
+        self.assertEqual(omissions[(1, 2)].reasons(), ["no SD-meet terms"])
+        self.assertTrue(omissions[(1, 4, 5)].omitted())
+
+        analysis = uacalc_lib.alg.malcev_analysis(z2, kinds=["taylor", "hagemann-mitschke"])
+        self.assertEqual(len(analysis.omitted_types()), 6)
+        self.assertIn("omits=[{1}, {1,5}, {1,4,5}]", repr(analysis))
+        self.assertEqual(uacalc_lib.alg.malcev_analysis(z2, kinds=["malcev"]).omitted_types(), [])
+
+    def test_equation_to_latex(self):
+        """Equations render with \\approx and join/meet as \\vee/\\wedge."""
+        string_to_term = uacalc_lib.terms.string_to_term
+        equation = uacalc_lib.eq.Equation(string_to_term("join(x,y)"), string_to_term("join(y,x)"))
+        self.assertEqual(equation.to_latex(), "$x \\vee y \\approx y \\vee x$")
+
 
 class TestMalcevJavaComparison(unittest.TestCase):
     """Test Malcev functions against Java implementation."""
@@ -0,0 +1,65 @@
+#!/usr/bin/env python3
+"""
+Tests for memory accounting and limits.
+"""
+
+import unittest
+
+import uacalc_lib
+
+memory = uacalc_lib.memory
+alg = uacalc_lib.alg
+OperationSymbol = uacalc_lib.alg.OperationSymbol
+BasicAlgebra = uacalc_lib.alg.BasicAlgebra
+Operations = uacalc_lib.alg.Operations
+
+
+def two_element_lattice():
+    """The two element lattice."""
+    join = Operations.make_int_operation(OperationSymbol("join", 2, False), 2, [0, 1, 1, 1])
+    meet = Operations.make_int_operation(OperationSymbol("meet", 2, False), 2, [0, 0, 0, 1])
+    return BasicAlgebra("L", [0, 1], [join, meet])
+
+
+class TestMemory(unittest.TestCase):
+    """Test cases for the memory module."""
+
+    def setUp(self):
+        self.lattice = two_element_lattice()
+
+    def tearDown(self):
+        memory.set_memory_limit(None)
+
+    @unittest.skipIf(memory.get_allocated_memory() is None, "needs the memory-tracking feature")
+    def test_allocated_memory(self):
+        """Allocations are counted and recorded per computation."""
+        self.assertGreater(memory.get_allocated_memory(), 0)
+        alg.FreeAlgebra(self.lattice, 3)
+        usage = memory.last_memory_usage(memory.FREE_ALGEBRA)
+        self.assertGreater(usage["peak"], 0)
+        self.assertLessEqual(usage["retained"], usage["peak"])
+        self.assertIsNone(memory.last_memory_usage("no such computation"))
+
+    def test_memory_limit(self):
+        """Passing the soft limit raises MemoryError."""
+        memory.set_memory_limit(1)
+        self.assertEqual(memory.get_memory_limit(), 1)
+        with self.assertRaises(MemoryError):
+            alg.FreeAlgebra(self.lattice, 3)
+        memory.set_memory_limit(None)
+        self.assertIsNone(memory.get_memory_limit())
+        alg.FreeAlgebra(self.lattice, 3)
+
+    def test_estimates(self):
+        """Estimates grow with the size of the computation."""
+        self.assertGreater(memory.estimate_conlat_memory(self.lattice), 0)
+        self.assertLess(memory.estimate_conlat_memory(self.lattice),
+                        memory.estimate_conlat_memory(self.lattice, con_size=100))
+        self.assertLess(memory.estimate_free_algebra_memory(self.lattice, 2),
+                        memory.estimate_free_algebra_memory(self.lattice, 3))
+        # two binary operations on a 4 element product
+        self.assertEqual(memory.estimate_product_table_memory([self.lattice, self.lattice]), 2 * 16 * 4)
+
+
+if __name__ == '__main__':
+    unittest.main()
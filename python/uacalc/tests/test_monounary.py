@@ -0,0 +1,43 @@
+"""
+Tests for monounary algebras.
+"""
+
+import unittest
+import uacalc_lib
+
+alg = uacalc_lib.alg
+Monounary = alg.Monounary
+
+
+class TestMonounary(unittest.TestCase):
+    """Test the cycle structure and congruences of monounary algebras."""
+
+    def setUp(self):
+        # A 2-cycle, a 3-cycle and a fixed point 5 with the leaf 6.
+        self.map = [1, 0, 3, 4, 2, 5, 5]
+        self.mono = Monounary(self.map)
+
+    def test_structure(self):
+        """Cycles, depths and components are read off the map."""
+        self.assertEqual(self.mono.cycles(), [[0, 1], [2, 3, 4], [5]])
+        self.assertEqual(self.mono.cycle_lengths(), [2, 3, 1])
+        self.assertEqual(self.mono.components(), [[0, 1], [2, 3, 4], [5, 6]])
+        self.assertEqual(self.mono.depths(), [0, 0, 0, 0, 0, 0, 1])
+        self.assertEqual(self.mono.height(), 1)
+        with self.assertRaises(ValueError):
+            Monounary([0, 3])
+
+    def test_congruences_match_con(self):
+        """The congruences agree with the general algorithm."""
+        op = alg.Operations.make_int_operation(alg.OperationSymbol("f", 1, False), 7, self.map)
+        algebra = alg.BasicAlgebra("A", list(range(7)), [op])
+        self.assertTrue(Monounary.is_monounary(algebra))
+        mono = Monounary.from_algebra(algebra)
+        congruences = mono.congruences()
+        self.assertEqual(len(congruences), algebra.con().cardinality())
+        self.assertTrue(all(mono.is_congruence(p) for p in congruences))
+        self.assertEqual(mono.principal_congruence(5, 6).number_of_blocks(), 6)
+
+
+if __name__ == "__main__":
+    unittest.main()
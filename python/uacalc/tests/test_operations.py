@@ -163,6 +163,24 @@ class TestOperations:
         
         assert result == java_result["data"]["isMaltsev"]
     
+    def test_recognize_structure(self):
+        """Test recognize_structure on Z3 and on a left zero band."""
+        plus = BasicOperation(OperationSymbol("f", 2), 3, [0, 1, 2, 1, 2, 0, 2, 0, 1])
+        result = Operations.recognize_structure(plus)
+        assert "group" in result["kinds"]
+        assert result["identity"] == 0
+        assert result["inverses"] == [0, 2, 1]
+        assert result["non_associative"] is None
+
+        left = BasicOperation(OperationSymbol("g", 2), 2, [0, 1, 0, 1])
+        result = Operations.recognize_structure(left)
+        assert result["kinds"] == ["semigroup", "band"]
+        assert result["non_commutative"] == (0, 1)
+        assert result["identity"] is None
+
+        with pytest.raises(ValueError):
+            Operations.recognize_structure(BasicOperation(OperationSymbol("h", 1), 2, [1, 0]))
+
     def test_find_difference(self):
         """Test findDifference method."""
         symbol1 = OperationSymbol("f", 2)
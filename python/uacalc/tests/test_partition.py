@@ -614,6 +614,45 @@ class TestPartition:
         # Different partitions should have different hashes (with high probability)
         assert hash(partition1) != hash(partition3)
     
+    def test_bar_notation(self):
+        """Test constructing partitions in bar notation and from block indices."""
+        partition = Partition("|0 1|2|3 4|")
+        assert partition.number_of_blocks() == 3
+        assert partition.to_bar_string() == "|0 1|2|3 4|"
+        assert Partition(partition.to_bar_string()) == partition
+        assert Partition.from_block_index_vector([0, 0, 1, 2, 2]) == partition
+        assert Partition.from_block_index_vector([7, 3, 7]).to_bar_string() == "|0 2|1|"
+        with pytest.raises(ValueError):
+            Partition("|0 1|1 2|")
+        with pytest.raises(ValueError):
+            Partition.from_block_index_vector([])
+    
+    def test_lattice_operations(self):
+        """Test covers, complements and permutability of two partitions."""
+        a = Partition("|0 1|2|3|")
+        b = Partition("|0|1|2 3|")
+        assert a.covers(Partition.zero(4))
+        assert not Partition.one(4).covers(a)
+        assert a.common_upper_covers(b) == [a.join(b)]
+        assert len(a.common_upper_covers(a)) == 3
+        assert a.is_complement(Partition("|0 2|1 3|"))
+        assert not a.is_complement(b)
+        assert a.permutes_with(b)
+        
+        d = Partition("|0 1|2 3|")
+        e = Partition("|0|1 2|3|")
+        de = d.relational_composition(e)
+        assert de.is_related(0, 2)
+        assert not de.is_related(0, 3)
+        assert not d.permutes_with(e)
+        with pytest.raises(ValueError):
+            d.relational_composition(Partition.zero(3))
+    
+    def test_to_latex(self):
+        """Test LaTeX bar notation."""
+        assert Partition([-2, 0, -1, -1]).to_latex() == "$|01|2|3|$"
+        assert Partition([-1]).to_latex() == "$|0|$"
+    
     def test_error_handling(self):
         """Test error handling for invalid inputs."""
         # Test invalid array
@@ -209,3 +209,33 @@ class TestPermutationGroup:
         assert 0 in universe
         assert 1 in universe
         assert 2 in universe
+
+    def test_orbits_and_constituents(self):
+        """Test orbits and transitive constituents of an intransitive group."""
+        pg = uacalc_lib.group.PermutationGroup("G", [[1, 0, 3, 2, 4]])
+        assert pg.orbits() == [[0, 1], [2, 3], [4]]
+        assert not pg.is_transitive()
+        constituents = pg.transitive_constituents()
+        assert [c.get_underlying_set_size() for c in constituents] == [2, 2, 1]
+        assert pg.imprimitive_decomposition() is None
+
+    def test_block_systems(self):
+        """Test block systems and primitivity."""
+        s3 = uacalc_lib.group.PermutationGroup("S3", [[1, 0, 2], [1, 2, 0]])
+        assert s3.is_primitive()
+        assert len(s3.block_systems()) == 2
+
+        d4 = uacalc_lib.group.PermutationGroup("D4", [[1, 2, 3, 0], [0, 3, 2, 1]])
+        assert not d4.is_primitive()
+        assert len(d4.block_systems()) == 3
+        assert d4.minimal_block_system(0, 2).get_blocks() == [[0, 2], [1, 3]]
+        assert d4.to_g_set().cardinality() == 4
+
+    def test_imprimitive_decomposition(self):
+        """Test the wreath decomposition of the dihedral group of the square."""
+        d4 = uacalc_lib.group.PermutationGroup("D4", [[1, 2, 3, 0], [0, 3, 2, 1]])
+        blocks, block_action, stabilizer = d4.imprimitive_decomposition()
+        assert blocks.get_blocks() == [[0, 2], [1, 3]]
+        assert block_action.get_underlying_set_size() == 2
+        assert [1, 0] in stabilizer.get_generators()
+        assert d4.block_action(blocks).get_generators() == block_action.get_generators()
@@ -0,0 +1,115 @@
+#!/usr/bin/env python3
+"""
+Tests for pickling algebras, operations, partitions, relations, posets,
+homomorphisms, equations and congruence lattices.
+
+Views such as BasicLattice are not picklable; pickle what they were built
+from instead.
+"""
+
+import pickle
+import unittest
+
+import uacalc_lib
+
+alg = uacalc_lib.alg
+OperationSymbol = uacalc_lib.alg.OperationSymbol
+BasicAlgebra = uacalc_lib.alg.BasicAlgebra
+Operations = uacalc_lib.alg.Operations
+
+
+def round_trip(obj):
+    return pickle.loads(pickle.dumps(obj))
+
+
+class TestPickle(unittest.TestCase):
+    """Test cases for pickle support."""
+
+    def setUp(self):
+        plus = OperationSymbol("+", 2, False)
+        table = [(k % 4 + k // 4) % 4 for k in range(16)]
+        self.z4 = BasicAlgebra("Z4", list(range(4)), [Operations.make_int_operation(plus, 4, table)])
+
+    def test_algebra(self):
+        back = round_trip(self.z4)
+        self.assertEqual(back.name(), "Z4")
+        self.assertEqual(back.cardinality(), 4)
+        self.assertEqual(uacalc_lib.io.algebra_to_json(back), uacalc_lib.io.algebra_to_json(self.z4))
+
+    def test_operations(self):
+        sym = OperationSymbol("*", 2, True)
+        self.assertEqual(round_trip(sym), sym)
+
+        op = alg.IntOperation(sym, 2, [0, 0, 0, 1])
+        back = round_trip(op)
+        self.assertEqual(back, op)
+        self.assertTrue(back.symbol().is_associative())
+
+        basic = alg.BasicOperation(OperationSymbol("f", 1, False), 3, [1, 2, 0])
+        self.assertEqual(round_trip(basic), basic)
+
+    def test_partition(self):
+        par = alg.Partition([-2, 0, -1])
+        back = round_trip(par)
+        self.assertEqual(back, par)
+        self.assertEqual(back.number_of_blocks(), 2)
+
+    def test_relation(self):
+        rel = alg.BasicBinaryRelation(3)
+        rel.add(0, 1)
+        rel.add(2, 2)
+        back = round_trip(rel)
+        self.assertEqual(back, rel)
+        self.assertEqual(back.universe_size(), 3)
+        with self.assertRaises(ValueError):
+            back.__setstate__([[0, 1, 2]])
+
+    def test_poset(self):
+        poset = uacalc_lib.lat.Poset([[1, 2], [3], [3], []], ["0", "a", "b", "1"], "N")
+        back = round_trip(poset)
+        self.assertEqual(back.upper_covers(), poset.upper_covers())
+        self.assertEqual(back.labels(), ["0", "a", "b", "1"])
+        self.assertEqual(back.name(), "N")
+
+    def test_homomorphism(self):
+        hom = alg.Homomorphism(self.z4, self.z4, {k: 2 * k % 4 for k in range(4)})
+        back = round_trip(hom)
+        self.assertEqual(back.get_map(), hom.get_map())
+        self.assertEqual(back.get_domain().name(), "Z4")
+        self.assertEqual(back.kernel(), hom.kernel())
+
+    def test_product(self):
+        product = alg.ProductAlgebra("Z4^2", [self.z4, self.z4])
+        back = round_trip(product)
+        self.assertEqual(back.name(), "Z4^2")
+        self.assertEqual(back.cardinality(), 16)
+        self.assertEqual(back.number_of_factors(), 2)
+
+    def test_basic_lattice(self):
+        lattice = uacalc_lib.lat.BasicLattice("Con", alg.CongruenceLattice(self.z4))
+        with self.assertRaises(TypeError):
+            pickle.dumps(lattice)
+
+    def test_equation(self):
+        equation = uacalc_lib.eq.Equation(
+            uacalc_lib.terms.string_to_term("f(x,y)"),
+            uacalc_lib.terms.string_to_term("f(y,x)"),
+        )
+        self.assertEqual(str(round_trip(equation)), str(equation))
+
+    def test_congruence_lattice(self):
+        con = alg.CongruenceLattice(self.z4)
+        # A fresh lattice and one whose universe is built both round trip.
+        self.assertEqual(round_trip(con).con_cardinality(), 3)
+        self.assertEqual(con.con_cardinality(), 3)
+        back = round_trip(con)
+        self.assertEqual(len(back), 3)
+        self.assertEqual(back.alg_size(), 4)
+
+    def test_bad_state(self):
+        with self.assertRaises(ValueError):
+            self.z4.__setstate__("{\"kind\": \"partition\", \"array\": [-1]}")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,107 @@
+#!/usr/bin/env python3
+"""
+Tests for the Poset type: construction, chains and antichains, the Mobius
+function, duals, products and conversion to and from lattices.
+"""
+
+import unittest
+
+import uacalc_lib
+
+Poset = uacalc_lib.lat.Poset
+
+
+def pentagon():
+    """N5: 0 < a < b < 1 and 0 < c < 1."""
+    return Poset([[1, 3], [2], [4], [4], []], labels=["0", "a", "b", "c", "1"], name="N5")
+
+
+class TestPoset(unittest.TestCase):
+
+    def test_construction(self):
+        n5 = pentagon()
+        self.assertEqual(len(n5), 5)
+        self.assertTrue(n5.leq(0, 2))
+        self.assertFalse(n5.leq(3, 2))
+        self.assertEqual(n5.minimals(), [0])
+        self.assertEqual(n5.lower_covers()[4], [2, 3])
+        with self.assertRaises(ValueError):
+            Poset([[1], [0]])
+        with self.assertRaises(ValueError):
+            n5.leq(0, 5)
+
+    def test_from_leq(self):
+        divisors = [1, 2, 3, 4, 6, 12]
+        poset = Poset.from_leq(6, lambda i, j: divisors[j] % divisors[i] == 0)
+        self.assertEqual(poset.height(), 4)
+        self.assertEqual(poset.width(), 2)
+        self.assertEqual(poset.mobius(0, 5), 0)
+        self.assertEqual(poset.mobius(0, 4), 1)
+        with self.assertRaises(ValueError):
+            Poset.from_leq(2, lambda i, j: True)
+
+    def test_chains_and_antichains(self):
+        n5 = pentagon()
+        self.assertEqual(n5.maximal_chains(), [[0, 1, 2, 4], [0, 3, 4]])
+        self.assertEqual(n5.maximal_antichains(), [[0], [1, 3], [2, 3], [4]])
+        self.assertEqual(len(n5.linear_extensions()), 3)
+
+    def test_dual_and_product(self):
+        two = Poset([[1], []])
+        square = two.product(two)
+        self.assertEqual(len(square), 4)
+        self.assertEqual(square.width(), 2)
+        self.assertTrue(pentagon().dual().leq(4, 0))
+
+    def test_lattice_conversion(self):
+        n5 = pentagon()
+        self.assertTrue(n5.is_lattice())
+        lat = n5.to_lattice("N5")
+        self.assertEqual(lat.cardinality(), 5)
+        self.assertEqual(Poset.from_lattice(lat).height(), 4)
+        with self.assertRaises(ValueError):
+            Poset([[], []]).to_lattice("A")
+
+    def test_sums(self):
+        two = Poset([[1], []])
+        self.assertEqual(len(two.ordinal_sum(pentagon())), 7)
+        glued = two.vertical_sum(pentagon())
+        self.assertEqual(len(glued), 6)
+        self.assertEqual(glued.height(), 5)
+        with self.assertRaises(ValueError):
+            Poset([[], []]).vertical_sum(two)
+
+    def test_lattice_combinators(self):
+        two = Poset([[1], []]).to_lattice("2")
+        n5 = pentagon().to_lattice("N5")
+        square = two.direct_product(two)
+        self.assertEqual(square.name(), "2 x 2")
+        self.assertEqual(square.cardinality(), 4)
+        self.assertEqual(n5.dual().name(), "N5^d")
+        self.assertEqual(two.ordinal_sum(n5).cardinality(), 7)
+        glued = square.vertical_sum(n5)
+        self.assertEqual(glued.name(), "2 x 2 ++ N5")
+        self.assertEqual(glued.cardinality(), 8)
+        self.assertEqual(Poset.from_lattice(glued).height(), 6)
+
+    def test_orders_from_operations(self):
+        alg = uacalc_lib.alg
+        ops = alg.Operations
+        join = ops.make_int_operation(alg.OperationSymbol("join", 2, False), 3, [0, 1, 2, 1, 1, 2, 2, 2, 2])
+        meet = ops.make_int_operation(alg.OperationSymbol("meet", 2, False), 3, [0, 0, 0, 0, 1, 1, 0, 1, 2])
+        chain = uacalc_lib.lat.as_semilattice(join)
+        self.assertTrue(chain.leq(0, 2))
+        self.assertEqual(uacalc_lib.lat.as_lattice(join, meet).height(), 3)
+        with self.assertRaises(ValueError):
+            uacalc_lib.lat.as_lattice(join, join)
+        first = ops.make_int_operation(alg.OperationSymbol("first", 2, False), 3, [0, 1, 2] * 3)
+        with self.assertRaises(ValueError):
+            uacalc_lib.lat.as_semilattice(first)
+        algebra = alg.BasicAlgebra("L", list(range(3)), [join, meet])
+        self.assertEqual(uacalc_lib.lat.find_lattice_reduct(algebra), (0, 1))
+        self.assertTrue(uacalc_lib.lat.is_lattice_ordered(algebra))
+        self.assertFalse(uacalc_lib.lat.is_lattice_ordered(alg.BasicAlgebra("F", list(range(3)), [first])))
+
+
+if __name__ == "__main__":
+    unittest.main()
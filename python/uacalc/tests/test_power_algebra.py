@@ -385,6 +385,51 @@ def test_large_power(algebra_classes):
     assert power.get_root_size() == 3
 
 
+def test_exact_cardinality(algebra_classes):
+    """Test exact cardinalities of powers too big for an i32."""
+    BasicAlgebra = algebra_classes['BasicAlgebra']
+    PowerAlgebra = algebra_classes['PowerAlgebra']
+
+    root_alg = BasicAlgebra("TestRoot", [0, 1, 2], [])
+    assert PowerAlgebra(root_alg, 3).exact_cardinality() == 27
+
+    power = PowerAlgebra(root_alg, 50)
+    assert power.cardinality() == -1
+    assert power.exact_cardinality() == 3 ** 50
+
+    st = uacalc_lib.alg.SimilarityType([algebra_classes['OperationSymbol']("f", 2)])
+    assert st.exact_input_size(3 ** 50) == 3 ** 100
+    with pytest.raises(ValueError):
+        st.exact_input_size(-1)
+
+
+def test_lazy_elements(algebra_classes):
+    """Test streaming and sampling the elements of a large power."""
+    BasicAlgebra = algebra_classes['BasicAlgebra']
+    PowerAlgebra = algebra_classes['PowerAlgebra']
+
+    root_alg = BasicAlgebra("TestRoot", [0, 1, 2], [])
+    small = PowerAlgebra(root_alg, 2)
+    assert list(small) == [(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1), (0, 2), (1, 2), (2, 2)]
+
+    # 3^5 elements streamed one at a time.
+    power = PowerAlgebra(root_alg, 5)
+    count = 0
+    for elem in power.elements():
+        assert len(elem) == 5
+        count += 1
+    assert count == 243
+
+    sample = power.sample(20, 42)
+    assert len(sample) == 20
+    assert all(all(0 <= a < 3 for a in elem) for elem in sample)
+    assert sample == power.sample(20, 42)
+
+    product = uacalc_lib.alg.ProductAlgebra("P", [root_alg, BasicAlgebra("Two", [0, 1], [])])
+    assert list(product) == [(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]
+    assert len(product.sample(3, 1)) == 3
+
+
 def test_power_algebra_with_operations(algebra_classes):
     """Test PowerAlgebra with operations from root."""
     BasicAlgebra = algebra_classes['BasicAlgebra']
@@ -409,3 +454,48 @@ def test_power_algebra_with_operations(algebra_classes):
     for op in ops:
         assert hasattr(op, 'symbol')
         assert hasattr(op, 'arity')
+
+
+def test_product_cg(algebra_classes):
+    """Test principal congruences of a product computed through the coordinates."""
+    BasicAlgebra = algebra_classes['BasicAlgebra']
+    OperationSymbol = algebra_classes['OperationSymbol']
+    IntOperation = algebra_classes['IntOperation']
+
+    z2 = BasicAlgebra("Z2", [0, 1], [IntOperation(OperationSymbol("+", 2), 2, [0, 1, 1, 0])])
+    product = uacalc_lib.alg.ProductAlgebra("Z2xZ2", [z2, z2])
+    # (0, 0) and (1, 1) generate the skew diagonal congruence.
+    cg = product.cg(0, 3)
+    assert cg.number_of_blocks() == 2
+    assert cg.is_related(1, 2)
+    assert not cg.is_related(0, 1)
+    with pytest.raises(ValueError):
+        product.cg(0, 4)
+
+
+
+def test_skew_congruences(algebra_classes):
+    """Test projection kernels and skew congruence detection on products."""
+    BasicAlgebra = algebra_classes['BasicAlgebra']
+    OperationSymbol = algebra_classes['OperationSymbol']
+    IntOperation = algebra_classes['IntOperation']
+
+    def zn(n):
+        table = [(k % n + k // n) % n for k in range(n * n)]
+        return BasicAlgebra(f"Z{n}", list(range(n)), [IntOperation(OperationSymbol("+", 2), n, table)])
+
+    product = uacalc_lib.alg.ProductAlgebra("Z2xZ3", [zn(2), zn(3)])
+    kernels = product.projection_kernels()
+    assert [k.number_of_blocks() for k in kernels] == [2, 3]
+    assert product.projection_kernel(1).is_related(0, 1)
+    with pytest.raises(ValueError):
+        product.projection_kernel(2)
+    assert product.is_skew_free()
+    assert product.skew_congruences() == []
+
+    # The diagonal of Z2 x Z2 is skew.
+    product = uacalc_lib.alg.ProductAlgebra("Z2xZ2", [zn(2), zn(2)])
+    assert not product.is_skew_free()
+    skew = product.skew_congruences()
+    assert len(skew) == 1
+    assert skew[0].is_related(0, 3)
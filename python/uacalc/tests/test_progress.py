@@ -0,0 +1,182 @@
+#!/usr/bin/env python3
+"""
+Tests for progress reporting and cancellation of long-running computations.
+"""
+
+import os
+import tempfile
+import threading
+import unittest
+
+import uacalc_lib
+
+alg = uacalc_lib.alg
+ProgressReporter = uacalc_lib.progress.ProgressReporter
+CancelledError = uacalc_lib.progress.CancelledError
+Budget = uacalc_lib.progress.Budget
+BudgetExceededError = uacalc_lib.progress.BudgetExceededError
+OperationSymbol = uacalc_lib.alg.OperationSymbol
+BasicAlgebra = uacalc_lib.alg.BasicAlgebra
+Operations = uacalc_lib.alg.Operations
+
+
+def two_element_lattice():
+    """The two element lattice."""
+    join = Operations.make_int_operation(OperationSymbol("join", 2, False), 2, [0, 1, 1, 1])
+    meet = Operations.make_int_operation(OperationSymbol("meet", 2, False), 2, [0, 0, 0, 1])
+    return BasicAlgebra("L", [0, 1], [join, meet])
+
+
+class TestProgressReporter(unittest.TestCase):
+    """Test cases for ProgressReporter."""
+
+    def setUp(self):
+        self.lattice = two_element_lattice()
+
+    def test_reports_progress(self):
+        """The callback sees progress messages and the result is unchanged."""
+        events = []
+        reporter = ProgressReporter(lambda phase, message: events.append((phase, message)))
+        term = alg.majority_term(self.lattice, progress=reporter)
+        self.assertEqual(term, alg.majority_term(self.lattice))
+        self.assertTrue(len(events) > 0)
+        self.assertFalse(reporter.is_cancelled())
+
+    def test_callback_cancels(self):
+        """Returning True from the callback cancels the computation."""
+        reporter = ProgressReporter(lambda phase, message: True)
+        with self.assertRaises(CancelledError):
+            alg.majority_term(self.lattice, progress=reporter)
+        self.assertTrue(reporter.is_cancelled())
+
+    def test_cancel_before_start(self):
+        """A cancelled reporter stops the computation immediately."""
+        reporter = ProgressReporter()
+        reporter.cancel()
+        with self.assertRaises(CancelledError):
+            alg.FreeAlgebra(self.lattice, 3, progress=reporter)
+
+    def test_callback_exception_propagates(self):
+        """An exception raised by the callback is re-raised."""
+        def callback(phase, message):
+            raise KeyError("stop")
+        with self.assertRaises(KeyError):
+            alg.majority_term(self.lattice, progress=ProgressReporter(callback))
+
+    def test_cancelled_congruence_lattice_resumes(self):
+        """A cancelled universe build keeps its partial result and resumes."""
+        ops = [Operations.make_int_operation(OperationSymbol("f", 1, False), 4, [0, 1, 2, 3])]
+        con = alg.CongruenceLattice(BasicAlgebra("A", [0, 1, 2, 3], ops))
+        steps = []
+
+        def callback(phase, message):
+            if phase == "universe" and message.startswith("size"):
+                steps.append(message)
+                return len(steps) == 2
+
+        with self.assertRaises(CancelledError):
+            con.con_cardinality(progress=ProgressReporter(callback))
+        self.assertTrue(len(con.partial_universe()) >= 6)
+        self.assertEqual(con.con_cardinality(), 15)
+        self.assertIsNone(con.partial_universe())
+
+    def test_checkpoint_resumes_in_new_lattice(self):
+        """A saved checkpoint resumes the build in another lattice."""
+        ops = [Operations.make_int_operation(OperationSymbol("f", 1, False), 4, [0, 1, 2, 3])]
+        con = alg.CongruenceLattice(BasicAlgebra("A", [0, 1, 2, 3], ops))
+        steps = []
+
+        def callback(phase, message):
+            if phase == "universe" and message.startswith("size"):
+                steps.append(message)
+                return len(steps) == 2
+
+        with self.assertRaises(CancelledError):
+            con.con_cardinality(progress=ProgressReporter(callback))
+        found = len(con.partial_universe())
+        with tempfile.TemporaryDirectory() as tmp:
+            path = os.path.join(tmp, "con.json")
+            con.save_checkpoint(path)
+            resumed = alg.CongruenceLattice(BasicAlgebra("A", [0, 1, 2, 3], ops))
+            resumed.resume_from(path)
+            self.assertEqual(len(resumed.partial_universe()), found)
+            self.assertEqual(resumed.con_cardinality(), 15)
+
+            # The lower covers of the join irreducibles come back too
+            fresh = alg.CongruenceLattice(BasicAlgebra("A", [0, 1, 2, 3], ops))
+            restored = alg.CongruenceLattice(BasicAlgebra("A", [0, 1, 2, 3], ops))
+            restored.resume_from(path)
+            for beta in fresh.join_irreducibles():
+                self.assertTrue(restored.join_irreducible(beta))
+                self.assertEqual(restored.lower_star(beta), fresh.lower_star(beta))
+            self.assertFalse(restored.join_irreducible(restored.one()))
+            self.assertIsNotNone(restored.get_basic_lattice())
+
+            small = alg.CongruenceLattice(BasicAlgebra("B", [0, 1], []))
+            with self.assertRaises(ValueError):
+                small.resume_from(path)
+            renamed = alg.CongruenceLattice(BasicAlgebra("B", [0, 1, 2, 3], ops))
+            with self.assertRaises(ValueError):
+                renamed.resume_from(path)
+            succ = [Operations.make_int_operation(OperationSymbol("s", 1, False), 4, [1, 2, 3, 0])]
+            cyclic = alg.CongruenceLattice(BasicAlgebra("A", [0, 1, 2, 3], succ))
+            with self.assertRaises(ValueError):
+                cyclic.resume_from(path)
+            with self.assertRaises(OSError):
+                small.resume_from(os.path.join(tmp, "missing.json"))
+
+    def test_element_budget(self):
+        """Passing the element limit raises with the partial universe."""
+        ops = [Operations.make_int_operation(OperationSymbol("f", 1, False), 4, [0, 1, 2, 3])]
+        con = alg.CongruenceLattice(BasicAlgebra("A", [0, 1, 2, 3], ops))
+        reporter = ProgressReporter(budget=Budget(max_elements=8))
+        with self.assertRaises(BudgetExceededError) as cm:
+            con.con_cardinality(progress=reporter)
+        self.assertEqual(cm.exception.resource, "elements")
+        self.assertEqual(cm.exception.limit, 8)
+        self.assertTrue(len(cm.exception.partial) > 8)
+        self.assertEqual(con.con_cardinality(), 15)
+
+    def test_budget_applies_to_each_computation(self):
+        """A reporter whose budget stopped one computation can run the next."""
+        ops = [Operations.make_int_operation(OperationSymbol("f", 1, False), 4, [0, 1, 2, 3])]
+        reporter = ProgressReporter(budget=Budget(max_elements=8))
+        with self.assertRaises(BudgetExceededError):
+            alg.CongruenceLattice(BasicAlgebra("A", [0, 1, 2, 3], ops)).con_cardinality(progress=reporter)
+        self.assertEqual(self.lattice.con().con_cardinality(progress=reporter), 2)
+        with self.assertRaises(BudgetExceededError):
+            alg.CongruenceLattice(BasicAlgebra("A", [0, 1, 2, 3], ops)).con_cardinality(progress=reporter)
+
+    def test_wall_time_budget(self):
+        """A zero wall time stops the computation as a cancellation."""
+        reporter = ProgressReporter(budget=Budget(wall_time=0.0))
+        with self.assertRaises(CancelledError) as cm:
+            alg.FreeAlgebra(self.lattice, 3, progress=reporter)
+        self.assertIsInstance(cm.exception, BudgetExceededError)
+        self.assertEqual(cm.exception.resource, "wall time")
+        self.assertIsNone(cm.exception.partial)
+        with self.assertRaises(ValueError):
+            Budget(wall_time=-1.0)
+
+    def test_threads_run_concurrently(self):
+        """Computations release the GIL and report back from worker threads."""
+        results = []
+        events = []
+
+        def work():
+            reporter = ProgressReporter(lambda phase, message: events.append(message))
+            results.append(alg.majority_term(self.lattice, progress=reporter))
+            results.append(self.lattice.con().con_cardinality())
+
+        threads = [threading.Thread(target=work) for _ in range(4)]
+        for t in threads:
+            t.start()
+        for t in threads:
+            t.join()
+        self.assertEqual(len(results), 8)
+        self.assertEqual(results.count(2), 4)
+        self.assertTrue(len(events) > 0)
+
+
+if __name__ == '__main__':
+    unittest.main()
@@ -0,0 +1,58 @@
+"""
+Tests for quasivariety membership and quasi-equations.
+"""
+
+import unittest
+import uacalc_lib
+
+alg = uacalc_lib.alg
+eq = uacalc_lib.eq
+
+
+def group(name, size, add):
+    op = alg.Operations.make_int_operation(alg.OperationSymbol("add", 2, False), size,
+                                           [add(k % size, k // size) for k in range(size * size)])
+    return alg.BasicAlgebra(name, list(range(size)), [op])
+
+
+def cyclic(n):
+    return group("Z%d" % n, n, lambda x, y: (x + y) % n)
+
+
+class TestQuasivariety(unittest.TestCase):
+    """Test membership in SP(A)."""
+
+    def test_embedding(self):
+        """The Klein group embeds in Z2^2."""
+        klein = group("V4", 4, lambda x, y: x ^ y)
+        analysis = alg.in_quasivariety(klein, cyclic(2))
+        self.assertTrue(analysis.holds())
+        self.assertIsNone(analysis.counter_quasi_equation)
+        embedding = analysis.embedding
+        self.assertEqual(len(set(map(tuple, embedding))), 4)
+        self.assertEqual(len(embedding[0]), len(analysis.homomorphisms))
+
+    def test_counter_quasi_equation(self):
+        """Z4 is not in SP(Z2), witnessed by its diagram implying x0 = x2."""
+        analysis = alg.in_quasivariety(cyclic(4), cyclic(2))
+        self.assertFalse(analysis)
+        self.assertEqual(analysis.unseparated, (0, 2))
+        qe = analysis.counter_quasi_equation
+        self.assertEqual(len(qe), 16)
+        self.assertTrue(qe.is_satisfied_in(cyclic(2)))
+        self.assertEqual(qe.find_failure(cyclic(4)), [0, 1, 2, 3])
+
+    def test_quasi_equation(self):
+        """Cancellation holds in groups but not in semilattices."""
+        x, y, z = (uacalc_lib.terms.VariableImp(v) for v in "xyz")
+        add = alg.OperationSymbol("add", 2, False)
+        premise = eq.Equation(uacalc_lib.terms.NonVariableTerm(add, [x, z]),
+                              uacalc_lib.terms.NonVariableTerm(add, [y, z]))
+        cancel = eq.QuasiEquation([premise], eq.Equation(x, y))
+        self.assertEqual(cancel.get_variable_list(), ["x", "z", "y"])
+        self.assertTrue(cancel.is_satisfied_in(cyclic(3)))
+        self.assertFalse(cancel.is_satisfied_in(group("S", 2, min)))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,45 @@
+#!/usr/bin/env python3
+"""
+Tests for the global random seed.
+"""
+
+import unittest
+
+import uacalc_lib
+
+Operations = uacalc_lib.alg.Operations
+OperationSymbol = uacalc_lib.alg.OperationSymbol
+SimilarityType = uacalc_lib.alg.SimilarityType
+
+
+def draw():
+    """Tables of unseeded random operations."""
+    sim_type = SimilarityType([OperationSymbol("f", 2), OperationSymbol("g", 1)])
+    return [op.get_table() for op in Operations.make_random_operations(4, sim_type)]
+
+
+class TestGlobalSeed(unittest.TestCase):
+    """Test cases for set_global_seed."""
+
+    def tearDown(self):
+        uacalc_lib.set_global_seed(None)
+
+    def test_seed_reproduces_random_operations(self):
+        """The same seed gives the same unseeded random operations."""
+        uacalc_lib.set_global_seed(7)
+        first, second = draw(), draw()
+        uacalc_lib.set_global_seed(7)
+        self.assertEqual(draw(), first)
+        self.assertEqual(draw(), second)
+        self.assertNotEqual(first, second)
+
+    def test_util_alias(self):
+        """set_global_seed is also in the util module."""
+        uacalc_lib.util.set_global_seed(3)
+        first = draw()
+        uacalc_lib.set_global_seed(3)
+        self.assertEqual(draw(), first)
+
+
+if __name__ == "__main__":
+    unittest.main()
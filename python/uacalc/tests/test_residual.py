@@ -0,0 +1,86 @@
+#!/usr/bin/env python3
+"""
+Tests for subdirectly irreducible members of varieties and splitting pairs.
+"""
+
+import unittest
+
+import uacalc_lib
+
+alg = uacalc_lib.alg
+OperationSymbol = uacalc_lib.alg.OperationSymbol
+BasicAlgebra = uacalc_lib.alg.BasicAlgebra
+Operations = uacalc_lib.alg.Operations
+
+
+def lattice(name, n, leq):
+    """The lattice on range(n) with the given order."""
+    def bound(x, y, upper):
+        if upper:
+            cands = [z for z in range(n) if leq(x, z) and leq(y, z)]
+            return next(z for z in cands if all(leq(z, w) for w in cands))
+        cands = [z for z in range(n) if leq(z, x) and leq(z, y)]
+        return next(z for z in cands if all(leq(w, z) for w in cands))
+
+    ops = []
+    for sym, upper in (("join", True), ("meet", False)):
+        table = [bound(k % n, k // n, upper) for k in range(n * n)]
+        ops.append(Operations.make_int_operation(OperationSymbol(sym, 2, False), n, table))
+    return BasicAlgebra(name, list(range(n)), ops)
+
+
+class TestResidual(unittest.TestCase):
+    """Test cases for si_members_up_to, splitting_pairs and in_hs."""
+
+    def setUp(self):
+        self.m3 = lattice("M3", 5, lambda x, y: x == y or x == 0 or y == 4)
+
+    def test_si_members_up_to(self):
+        """The only SI semilattice is the two element one."""
+        meet = OperationSymbol("meet", 2, False)
+        op = Operations.make_int_operation(meet, 2, [0, 0, 0, 1])
+        s = BasicAlgebra("S", [0, 1], [op])
+        sis = alg.si_members_up_to(s, 5, 3)
+        self.assertEqual([a.cardinality() for a in sis], [2])
+        with self.assertRaises(ValueError):
+            alg.si_members_up_to(s, 5, 0)
+
+    def test_splitting_pairs(self):
+        """M3 and the two element lattice split the variety generated by M3."""
+        pairs = alg.splitting_pairs(self.m3)
+        self.assertEqual([s.cardinality() for s, _ in pairs], [2, 5])
+        self.assertEqual(pairs[0][1], [])
+        self.assertEqual([t.cardinality() for t in pairs[1][1]], [2])
+
+    def test_in_hs(self):
+        two = lattice("2", 2, lambda x, y: x <= y)
+        self.assertTrue(alg.in_hs(two, self.m3))
+        self.assertFalse(alg.in_hs(self.m3, two))
+
+    def test_subdirectly_irreducibles_in_hs(self):
+        """HS(M3) has the SIs 2 and M3, both simple."""
+        sis = list(alg.subdirectly_irreducibles_in_hs(self.m3))
+        self.assertEqual(sorted(a.cardinality() for a, _ in sis), [2, 5])
+        for _, monolith in sis:
+            self.assertEqual(monolith.number_of_blocks(), 1)
+
+    def test_subdirectly_irreducibles_in_variety(self):
+        """The 3-cycle comes from F(1), the 2-element SI from F(2)."""
+        f = Operations.make_int_operation(OperationSymbol("f", 1, False), 3, [1, 2, 0])
+        cycle = BasicAlgebra("C3", [0, 1, 2], [f])
+        sis = alg.subdirectly_irreducibles_in_variety(cycle, 3)
+        self.assertEqual(next(sis)[0].cardinality(), 3)
+        two, monolith = next(sis)
+        self.assertEqual(two.name(), "C3-SI1")
+        self.assertEqual(monolith.number_of_blocks(), 1)
+        self.assertEqual(list(sis), [])
+
+    def test_find_isomorphism(self):
+        relabelled = lattice("M3'", 5, lambda x, y: x == y or x == 4 or y == 0)
+        iso = alg.find_isomorphism(self.m3, relabelled)
+        self.assertEqual([iso[0], iso[4]], [4, 0])
+        self.assertIsNone(alg.find_isomorphism(self.m3, lattice("5", 5, lambda x, y: x <= y)))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,34 @@
+"""
+Tests for rings and modules as algebras.
+"""
+
+import unittest
+import uacalc_lib
+
+alg = uacalc_lib.alg
+
+
+class TestRing(unittest.TestCase):
+    """Test the ring and module constructors and the ideal correspondence."""
+
+    def test_ideals_of_zn(self):
+        z12 = alg.zn_ring(12)
+        self.assertEqual([len(i) for i in alg.ideals(z12)], [1, 2, 3, 4, 6, 12])
+        theta = alg.congruence_of_ideal(z12, [0, 4, 8])
+        self.assertEqual(theta.number_of_blocks(), 4)
+        self.assertEqual(alg.ideal_of_congruence(z12, theta), [0, 4, 8])
+        with self.assertRaises(ValueError):
+            alg.congruence_of_ideal(z12, [0, 5])
+
+    def test_matrix_ring_and_modules(self):
+        m2 = alg.matrix_ring(2, 2)
+        self.assertEqual(m2.cardinality(), 16)
+        self.assertEqual(len(alg.ideals(m2)), 2)
+        self.assertEqual([len(i) for i in alg.ideals(alg.regular_module(m2))], [1, 4, 4, 4, 16])
+        self.assertEqual(len(alg.ideals(alg.zn_module(2, 2))), 5)
+        with self.assertRaises(ValueError):
+            alg.ideals(alg.BasicAlgebra("E", [0, 1], []))
+
+
+if __name__ == "__main__":
+    unittest.main()
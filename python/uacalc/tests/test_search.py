@@ -0,0 +1,96 @@
+#!/usr/bin/env python3
+"""
+Tests for homomorphism and polymorphism search.
+"""
+
+import unittest
+
+import uacalc_lib
+
+alg = uacalc_lib.alg
+OperationSymbol = uacalc_lib.alg.OperationSymbol
+BasicAlgebra = uacalc_lib.alg.BasicAlgebra
+Operations = uacalc_lib.alg.Operations
+
+
+def cyclic(n):
+    """The cyclic group Z_n with its binary addition."""
+    f = OperationSymbol("f", 2, False)
+    table = [(k % n + k // n) % n for k in range(n * n)]
+    return BasicAlgebra("Z%d" % n, list(range(n)), [Operations.make_int_operation(f, n, table)])
+
+
+def backends():
+    """The search backends of this build; "sat" needs the sat feature."""
+    try:
+        alg.find_homomorphism(cyclic(1), cyclic(1), "sat")
+        return ["backtrack", "ac", "sac", "sat"]
+    except ValueError:
+        return ["backtrack", "ac", "sac"]
+
+
+class TestSearch(unittest.TestCase):
+    """Test cases for the search functions."""
+
+    def test_find_homomorphism(self):
+        """Both backends find a homomorphism Z6 -> Z3."""
+        z6, z3 = cyclic(6), cyclic(3)
+        for backend in backends():
+            h = alg.find_homomorphism(z6, z3, backend)
+            self.assertIsNotNone(h)
+            self.assertTrue(alg.is_homomorphism(h, z6, z3))
+            self.assertEqual(alg.find_homomorphism(z3, cyclic(4), backend), [0, 0, 0])
+
+    def test_find_polymorphism(self):
+        """Z3 has a Maltsev polymorphism."""
+        z3 = cyclic(3)
+        maltsev = [([0, 1, 1], 0), ([1, 1, 0], 0)]
+        for backend in backends():
+            table = alg.find_polymorphism(z3, 3, maltsev, backend)
+            self.assertTrue(alg.is_polymorphism(z3, 3, table))
+
+    def test_digraph_search(self):
+        """C5 is 3-colorable but not 2-colorable; K2 has a majority polymorphism."""
+        def cycle(n):
+            return [e for i in range(n) for e in [(i, (i + 1) % n), ((i + 1) % n, i)]]
+        majority = [([0, 0, 1], 0), ([0, 1, 0], 0), ([1, 0, 0], 0)]
+        for backend in backends():
+            h = alg.find_digraph_homomorphism(cycle(5), 5, cycle(3), 3, backend)
+            self.assertTrue(all(h[u] != h[v] for u, v in cycle(5)))
+            self.assertIsNone(alg.find_digraph_homomorphism(cycle(5), 5, cycle(2)[:2], 2, backend))
+            self.assertIsNotNone(alg.find_digraph_polymorphism(cycle(2)[:2], 2, 3, majority, backend))
+            self.assertIsNone(alg.find_digraph_polymorphism(cycle(3), 3, 3, majority, backend))
+        with self.assertRaises(ValueError):
+            alg.find_digraph_homomorphism([(0, 5)], 2, [], 1)
+
+    def test_represent_lattice(self):
+        """N5 is the congruence lattice of a four element unary algebra."""
+        Poset = uacalc_lib.lat.Poset
+        n5 = Poset([[1, 3], [2], [4], [4], []])
+        self.assertIsNone(alg.represent_lattice(n5, 3))
+        a = alg.represent_lattice(n5, 4)
+        self.assertEqual(a.cardinality(), 4)
+        self.assertEqual(alg.CongruenceLattice(a).con_cardinality(), 5)
+        with self.assertRaises(ValueError):
+            alg.represent_lattice(Poset([[], [0], [0]]), 3)
+
+    def test_represent_subalgebra_lattice(self):
+        """The four element Boolean lattice is Sub of a two element set."""
+        Poset = uacalc_lib.lat.Poset
+        square = Poset([[1, 2], [3], [3], []])
+        a = alg.represent_subalgebra_lattice(square, 4)
+        self.assertEqual(a.cardinality(), 2)
+        self.assertEqual(len(a.operations()), 0)
+        n5 = Poset([[1, 3], [2], [4], [4], []])
+        self.assertIsNone(alg.represent_subalgebra_lattice(n5, 4))
+        with self.assertRaises(ValueError):
+            alg.represent_subalgebra_lattice(square, 9)
+
+    def test_unknown_backend(self):
+        """An unknown backend name raises ValueError."""
+        with self.assertRaises(ValueError):
+            alg.find_homomorphism(cyclic(2), cyclic(2), "nonsense")
+
+
+if __name__ == '__main__':
+    unittest.main()
@@ -0,0 +1,107 @@
+"""
+Tests for the algebra workspace.
+"""
+
+import os
+import tempfile
+import unittest
+import uacalc_lib
+
+alg = uacalc_lib.alg
+Workspace = uacalc_lib.session.Workspace
+
+
+def cyclic(n):
+    op = alg.Operations.make_int_operation(alg.OperationSymbol("+", 2, False), n,
+                                           [(k % n + k // n) % n for k in range(n * n)])
+    return alg.BasicAlgebra("Z%d" % n, list(range(n)), [op])
+
+
+class TestWorkspace(unittest.TestCase):
+    """Test workspace commands and undo/redo."""
+
+    def test_undo_redo(self):
+        """Derived algebras come and go with undo and redo."""
+        ws = Workspace()
+        ws.add_algebra("A", cyclic(4))
+        ws.quotient("A", alg.Partition("|0 2|1 3|"), "Q")
+        ws.subalgebra("A", [2], "S")
+        self.assertEqual(ws.names(), ["A", "Q", "S"])
+        self.assertEqual(ws["Q"].cardinality(), 2)
+        self.assertEqual(ws.derived_from("S"), "A")
+        self.assertEqual(len(ws.history()), 3)
+
+        self.assertIsNotNone(ws.undo())
+        self.assertNotIn("S", ws)
+        self.assertTrue(ws.can_redo())
+        ws.redo()
+        self.assertIn("S", ws)
+
+        ws.rename("A", "Z4")
+        self.assertEqual(ws.derived_from("Q"), "Z4")
+        ws.undo()
+        self.assertEqual(ws.names(), ["A", "Q", "S"])
+
+    def test_operation_commands(self):
+        """Operations can be added, edited and removed."""
+        ws = Workspace()
+        ws.add_algebra("A", cyclic(2))
+        ws.add_operation("A", alg.IntOperation.unary_not("neg"))
+        ws.set_operation_value("A", "+", [1, 1], 1)
+        self.assertEqual(len(ws["A"].operations()), 2)
+        ws.remove_operation("A", "neg")
+        self.assertEqual(len(ws["A"].operations()), 1)
+        ws.undo()
+        ws.undo()
+        self.assertEqual(len(ws["A"].operations()), 2)
+
+    def test_failed_commands(self):
+        """Failed commands raise and change nothing."""
+        ws = Workspace()
+        ws.add_algebra("A", cyclic(4))
+        with self.assertRaises(ValueError):
+            ws.add_algebra("A", cyclic(2))
+        with self.assertRaises(ValueError):
+            ws.quotient("A", alg.Partition("|0 1|2 3|"), "Q")
+        with self.assertRaises(KeyError):
+            ws["Q"]
+        self.assertEqual(len(ws), 1)
+        self.assertEqual(len(ws.history()), 1)
+
+
+class TestProjectFiles(unittest.TestCase):
+    """Test saving and loading workspaces."""
+
+    def test_save_and_load(self):
+        """A loaded workspace has the algebras, lattices, terms and notes."""
+        ws = Workspace()
+        ws.add_algebra("A", cyclic(4))
+        ws.subalgebra("A", [2], "B")
+        ws.save_term("sum", "f(x,y)")
+        ws.set_notes("cyclic groups")
+        self.assertEqual(len(ws.congruences("A")), 3)
+        self.assertEqual(ws.subuniverses("B"), [[0], [0, 1]])
+
+        with tempfile.TemporaryDirectory() as d:
+            path = os.path.join(d, "cyclic.uaproj")
+            ws.save(path)
+            loaded = Workspace.load(path)
+
+        self.assertEqual(loaded.names(), ["A", "B"])
+        self.assertEqual(loaded.derived_from("B"), "A")
+        self.assertEqual(len(loaded.congruences("A")), 3)
+        self.assertEqual(loaded.terms(), ws.terms())
+        self.assertEqual(loaded.notes(), "cyclic groups")
+        self.assertEqual(loaded.history(), [])
+
+    def test_notes_are_undoable(self):
+        """Editing notes is a command."""
+        ws = Workspace()
+        ws.set_notes("first")
+        ws.set_notes("second")
+        ws.undo()
+        self.assertEqual(ws.notes(), "first")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,40 @@
+"""
+Tests for simple and strictly simple algebras.
+"""
+
+import unittest
+import uacalc_lib
+
+alg = uacalc_lib.alg
+
+
+def make_op(name, arity, table):
+    return alg.Operations.make_int_operation(alg.OperationSymbol(name, arity, False), 3, table)
+
+
+class TestSimple(unittest.TestCase):
+    """Test the simplicity checks and their witnesses."""
+
+    def test_simple(self):
+        """Z5 is strictly simple and Z4 has the congruence modulo 2."""
+        self.assertTrue(alg.is_simple(alg.zn_ring(5)))
+        self.assertTrue(alg.is_strictly_simple(alg.zn_ring(5)))
+        report = alg.check_simple(alg.zn_ring(4))
+        self.assertFalse(report)
+        self.assertEqual(report.congruence.number_of_blocks(), 2)
+        self.assertIsNone(report.subuniverse)
+
+    def test_strictly_simple_witness(self):
+        """A simple algebra with the closed subset {1, 2}."""
+        f = make_op("f", 1, [0, 2, 1])
+        m = make_op("m", 2, [1 if k == 6 else k % 3 for k in range(9)])
+        algebra = alg.BasicAlgebra("A", [0, 1, 2], [f, m])
+        self.assertTrue(alg.is_simple(algebra))
+        report = alg.check_strictly_simple(algebra)
+        self.assertFalse(report.holds())
+        self.assertEqual(report.subuniverse, [1, 2])
+        self.assertIn("subuniverse", str(report))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -180,6 +180,17 @@ def test_one_generated_subalgebras():
         assert False, f"Python test failed: {e}"
 
 
+def test_subuniverses_iter():
+    """Test streaming the subuniverses."""
+    reader = AlgebraReader.new_from_file("resources/algebras/sym3.ua")
+    sub_lat = SubalgebraLattice(reader.read_algebra_file())
+
+    streamed = sorted(tuple(s.elements()) for s in sub_lat.subuniverses_iter())
+    assert streamed == sorted(tuple(s.elements()) for s in sub_lat.universe())
+    assert len(sub_lat) == len(streamed)
+    assert len(list(sub_lat)) == len(streamed)
+
+
 def test_join_irreducibles():
     """Test join irreducibles."""
     print("\nTest 5: Join irreducibles")
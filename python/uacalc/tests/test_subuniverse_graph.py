@@ -0,0 +1,41 @@
+"""
+Tests for maximal subuniverses and the digraph of subuniverses.
+"""
+
+import unittest
+import uacalc_lib
+
+alg = uacalc_lib.alg
+
+
+class TestSubuniverseGraph(unittest.TestCase):
+    """Test the subuniverses of the group Z4 and of rings."""
+
+    def setUp(self):
+        table = [(k % 4 + k // 4) % 4 for k in range(16)]
+        add = alg.Operations.make_int_operation(alg.OperationSymbol("add", 2, False), 4, table)
+        self.z4 = alg.BasicAlgebra("Z4", list(range(4)), [add])
+
+    def test_maximal_proper_subuniverses(self):
+        """Z4 has the single maximal proper subuniverse {0, 2}."""
+        self.assertEqual(alg.maximal_proper_subuniverses(self.z4), [frozenset({0, 2})])
+        # With the constant one the ring Z4 has no proper subuniverse, and
+        # the diagonal is the only one of Z2 x Z2.
+        self.assertEqual(alg.maximal_proper_subuniverses(alg.zn_ring(4)), [])
+        z2 = alg.zn_ring(2)
+        product = alg.ProductAlgebra("Z2xZ2", [z2, z2])
+        self.assertEqual(len(alg.maximal_proper_subuniverses(product)), 1)
+
+    def test_graph(self):
+        """The vertices, edges and DOT export of the digraph."""
+        graph = alg.SubuniverseGraph(self.z4)
+        self.assertEqual(graph.vertices(), [frozenset(), frozenset({0}), frozenset({0, 2}), frozenset(range(4))])
+        self.assertEqual(graph.edges(), [(0, 1, 0), (0, 2, 2), (0, 3, 1), (1, 2, 2), (1, 3, 1), (2, 3, 1)])
+        self.assertEqual(graph.successors(1), [2, 3])
+        self.assertEqual(graph.maximal_proper(), [2])
+        self.assertEqual(len(graph), 4)
+        self.assertTrue(graph.to_dot().startswith("digraph Subuniverses {"))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,128 @@
+#!/usr/bin/env python3
+"""
+Tests for telemetry of long-running computations.
+"""
+
+import os
+import tempfile
+import unittest
+
+import uacalc_lib
+
+Recorder = uacalc_lib.telemetry.Recorder
+enable_logging = uacalc_lib.enable_logging
+OperationSymbol = uacalc_lib.alg.OperationSymbol
+BasicAlgebra = uacalc_lib.alg.BasicAlgebra
+FreeAlgebra = uacalc_lib.alg.FreeAlgebra
+CloserTiming = uacalc_lib.alg.CloserTiming
+Operations = uacalc_lib.alg.Operations
+
+
+def logging_available():
+    """Whether this build has the tracing feature behind enable_logging."""
+    try:
+        enable_logging("off")
+        return True
+    except RuntimeError:
+        return False
+
+
+def two_element_lattice():
+    """The two element lattice."""
+    join = Operations.make_int_operation(OperationSymbol("join", 2, False), 2, [0, 1, 1, 1])
+    meet = Operations.make_int_operation(OperationSymbol("meet", 2, False), 2, [0, 0, 0, 1])
+    return BasicAlgebra("L", [0, 1], [join, meet])
+
+
+class TestRecorder(unittest.TestCase):
+    """Test cases for Recorder."""
+
+    def test_records_free_algebra(self):
+        """Building a free algebra records its closure."""
+        with Recorder() as rec:
+            free = FreeAlgebra(two_element_lattice(), 3)
+        report = rec.report()
+        self.assertEqual(free.cardinality(), 18)
+        self.assertGreater(report.counter("applications"), 0)
+        self.assertGreater(report.counters["passes"], 0)
+        names = [name for name, _, _ in report.phases]
+        self.assertIn("free algebra", names)
+        self.assertIn("free algebra / closure", names)
+        self.assertGreaterEqual(report.total_seconds, 0.0)
+        self.assertIn("applications", str(report))
+
+    def test_not_recorded_outside(self):
+        """Computations outside the with block are not recorded."""
+        rec = Recorder()
+        with rec:
+            pass
+        FreeAlgebra(two_element_lattice(), 2)
+        self.assertEqual(rec.report().counters, {})
+        with self.assertRaises(RuntimeError):
+            rec.__exit__(None, None, None)
+
+
+class TestCloserTiming(unittest.TestCase):
+    """Test cases for the CloserTiming metrics."""
+
+    def test_pass_metrics(self):
+        """The pass sizes and application counts follow update_pass."""
+        timing = CloserTiming.new_with_params([2, 2], 2)
+        timing.update_pass(10)
+        timing.update_pass(20)
+        self.assertEqual(timing.get_curr_pass_size(), 20)
+        self.assertEqual(timing.get_last_pass_size(), 10)
+        self.assertEqual(timing.get_apps_needed(), 1200)
+        timing.increment_apps()
+        # One application per factor.
+        self.assertEqual(timing.get_apps_this_pass(), 2)
+        timing.increment_next_pass_size()
+        self.assertEqual(timing.get_next_pass_size(), 1)
+        self.assertEqual(timing.get_ms_per_app(), 0.0)
+
+
+@unittest.skipUnless(logging_available(), "needs the tracing feature")
+class TestLogging(unittest.TestCase):
+    """Test cases for enable_logging."""
+
+    def tearDown(self):
+        enable_logging("off")
+
+    def test_logs_phases_to_file(self):
+        """Phases are logged with their timing; counters only at debug."""
+        with tempfile.TemporaryDirectory() as d:
+            path = os.path.join(d, "uacalc.log")
+            enable_logging("info", path)
+            FreeAlgebra(two_element_lattice(), 2)
+            enable_logging("off")
+            with open(path) as f:
+                info = f.read()
+            self.assertIn("free algebra", info)
+            self.assertIn("close", info)
+            self.assertNotIn("counter", info)
+
+            enable_logging("debug", path)
+            FreeAlgebra(two_element_lattice(), 2)
+            enable_logging("off")
+            with open(path) as f:
+                self.assertIn("counter", f.read())
+
+    def test_reload_level(self):
+        """Spans are still logged after the level has been replaced."""
+        with tempfile.TemporaryDirectory() as d:
+            path = os.path.join(d, "uacalc.log")
+            enable_logging("warn", path)
+            enable_logging("info", path)
+            FreeAlgebra(two_element_lattice(), 2)
+            enable_logging("off")
+            with open(path) as f:
+                self.assertIn("free algebra", f.read())
+
+    def test_unknown_level(self):
+        """An unknown level raises ValueError."""
+        with self.assertRaises(ValueError):
+            enable_logging("loud")
+
+
+if __name__ == "__main__":
+    unittest.main()
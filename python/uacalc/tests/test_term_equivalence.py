@@ -0,0 +1,63 @@
+#!/usr/bin/env python3
+"""
+Tests for term and polynomial equivalence of algebras.
+"""
+
+import unittest
+
+import uacalc_lib
+
+alg = uacalc_lib.alg
+OperationSymbol = uacalc_lib.alg.OperationSymbol
+BasicAlgebra = uacalc_lib.alg.BasicAlgebra
+Operations = uacalc_lib.alg.Operations
+
+
+def make_algebra(name, ops):
+    """A two element algebra from (name, arity, function) triples."""
+    int_ops = []
+    for op_name, arity, f in ops:
+        table = [f(*[(c >> i) & 1 for i in range(arity)]) for c in range(2 ** arity)]
+        int_ops.append(Operations.make_int_operation(OperationSymbol(op_name, arity, False), 2, table))
+    return BasicAlgebra(name, [0, 1], int_ops)
+
+
+class TestTermEquivalence(unittest.TestCase):
+    """Test cases for are_term_equivalent and are_polynomially_equivalent."""
+
+    def setUp(self):
+        self.lattice = make_algebra("L", [
+            ("join", 2, lambda x, y: x | y),
+            ("meet", 2, lambda x, y: x & y),
+        ])
+        self.median = make_algebra("M", [
+            ("m", 3, lambda x, y, z: (x & y) | (y & z) | (x & z)),
+        ])
+
+    def test_term_equivalent(self):
+        """Reordering the operations gives a term equivalent algebra."""
+        swapped = make_algebra("L2", [
+            ("meet", 2, lambda x, y: x & y),
+            ("join", 2, lambda x, y: x | y),
+        ])
+        result = alg.are_term_equivalent(self.lattice, swapped)
+        self.assertIsNotNone(result)
+        first, second = result
+        self.assertEqual(first, ["join(x0,x1)", "meet(x0,x1)"])
+        self.assertEqual(len(second), 2)
+
+    def test_not_term_equivalent(self):
+        """Join is not a term operation of the median algebra."""
+        self.assertIsNone(alg.are_term_equivalent(self.lattice, self.median))
+
+    def test_polynomially_equivalent(self):
+        """With constants, the median algebra recovers join and meet."""
+        result = alg.are_polynomially_equivalent(self.lattice, self.median)
+        self.assertIsNotNone(result)
+        first, second = result
+        self.assertEqual(len(first), 2)
+        self.assertEqual(len(second), 1)
+
+
+if __name__ == '__main__':
+    unittest.main()
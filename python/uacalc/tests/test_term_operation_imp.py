@@ -406,5 +406,31 @@ class TestTermOperationImpComparison(unittest.TestCase):
                 self.assertEqual(val1, val2, f"Values differ at args {args}")
 
 
+    def test_lazy_term_operation(self):
+        """Test that a lazy TermOperationImp agrees with the eager one."""
+        alg = load_test_algebra("sym3")
+        term = uacalc_lib.terms.string_to_term("p(x,p(y,x))")
+        eager = self.TermOperationImp(term, ["x", "y"], alg)
+        lazy = self.TermOperationImp(term, ["x", "y"], alg, eager=False)
+        self.assertTrue(eager.is_table_based())
+        self.assertFalse(lazy.is_table_based())
+        self.assertIsNone(lazy.get_table())
+        for i in range(6):
+            for j in range(6):
+                self.assertEqual(lazy.int_value_at([i, j]), eager.int_value_at([i, j]))
+        lazy.make_table()
+        self.assertEqual(lazy.get_table(), eager.get_table())
+
+    def test_interpretation_defaults_and_products(self):
+        """Test interpretation with default variables in a product algebra."""
+        alg = load_test_algebra("sym3")
+        product = uacalc_lib.alg.ProductAlgebra("S3xS3", [alg, alg])
+        term = uacalc_lib.terms.string_to_term("p(y,x)")
+        op = term.interpretation(product)
+        self.assertEqual(op.arity(), 2)
+        self.assertEqual(op.get_set_size(), 36)
+        named = self.TermOperationImp(term, ["y", "x"], product, name="q", eager=False)
+        self.assertEqual(named.int_value_at([4, 7]), op.int_value_at([4, 7]))
+
 if __name__ == '__main__':
     unittest.main(verbosity=2)
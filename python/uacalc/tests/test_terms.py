@@ -692,6 +692,61 @@ class TestTermsWithAlgebra(unittest.TestCase):
         self.assertLess(result2, 2)
 
 
+class TestTermStatistics(unittest.TestCase):
+    """Test size, sharing and occurrence statistics of terms."""
+
+    def test_statistics(self):
+        """Test size, node_count and occurrences_of_variable."""
+        term = uacalc_lib.terms.string_to_term("f(g(x,y),g(x,y),x)")
+        self.assertEqual(term.size(), 3)
+        self.assertEqual(term.length(), 8)
+        self.assertEqual(term.node_count(), 4)
+        self.assertEqual(term.occurrences_of_variable("x"), 3)
+        self.assertEqual(term.occurrences_of_variable("z"), 0)
+
+        x = uacalc_lib.terms.VariableImp("x")
+        self.assertEqual(x.size(), 0)
+        self.assertEqual(x.node_count(), 1)
+        self.assertEqual(x.occurrences_of_variable("x"), 1)
+
+    def test_subterms_and_dot(self):
+        """Test the pre-order subterm list and the DOT export."""
+        term = uacalc_lib.terms.string_to_term("m(x,j(x,y))")
+        subterms = [str(t) for t in term.subterms()]
+        self.assertEqual(subterms, ["m(x,j(x,y))", "x", "j(x,y)", "x", "y"])
+        self.assertTrue(term.subterms()[1].isa_variable())
+
+        dot = term.to_dot()
+        self.assertTrue(dot.startswith("digraph Term {"))
+        self.assertEqual(dot.count("->"), 4)
+
+
+class TestTermEvaluationInConstructedAlgebras(unittest.TestCase):
+    """Test evaluating terms in product, power and subalgebra wrappers."""
+
+    def test_eval_in_product_power_and_subalgebra(self):
+        """Test that terms evaluate in any integer algebra wrapper."""
+        s3 = load_test_algebra("sym3")
+        term = uacalc_lib.terms.string_to_term("p(x,p(x,y))")
+        product = uacalc_lib.alg.ProductAlgebra("S3xS3", [s3, s3])
+        power = uacalc_lib.alg.PowerAlgebra(s3, 2)
+        for a in range(36):
+            for b in range(36):
+                assignment = {"x": a, "y": b}
+                self.assertEqual(term.eval(product, assignment), term.eval(power, assignment))
+        table = term.interpretation(product, ["x", "y"], True).get_table()
+        self.assertEqual(len(table), 36 ** 2)
+
+        square = uacalc_lib.terms.string_to_term("p(x,x)")
+        identity = next(e for e in range(6) if square.eval(s3, {"x": e}) == e)
+        sub = uacalc_lib.alg.Subalgebra("trivial", s3, [identity])
+        self.assertEqual(term.eval(sub, {"x": 0, "y": 0}), 0)
+        self.assertEqual(uacalc_lib.terms.VariableImp("x").eval(product, {"x": 5}), 5)
+
+        with self.assertRaises(TypeError):
+            term.eval("S3", {"x": 0, "y": 0})
+
+
 if __name__ == '__main__':
     unittest.main(verbosity=2)
 
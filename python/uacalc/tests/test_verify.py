@@ -0,0 +1,37 @@
+"""
+Tests for the cross-checks against brute force.
+"""
+
+import unittest
+import uacalc_lib
+
+alg = uacalc_lib.alg
+verify = getattr(uacalc_lib, "verify", None)
+
+
+def cyclic(n):
+    op = alg.Operations.make_int_operation(alg.OperationSymbol("+", 2, False), n,
+                                           [(k % n + k // n) % n for k in range(n * n)])
+    return alg.BasicAlgebra("Z%d" % n, list(range(n)), [op])
+
+
+@unittest.skipIf(verify is None, "needs the verify feature")
+class TestVerify(unittest.TestCase):
+    """Test the checks on algebras where the implementations agree."""
+
+    def test_agreement(self):
+        """Cyclic groups have no discrepancies."""
+        for n in range(1, 6):
+            self.assertEqual(verify.check_algebra(cyclic(n)), [])
+        self.assertEqual(verify.check_congruences(cyclic(4)), [])
+        self.assertEqual(verify.check_subuniverses(cyclic(6)), [])
+        self.assertEqual(verify.check_homomorphisms(cyclic(4), cyclic(2)), [])
+
+    def test_limits(self):
+        """Inputs too large for brute force raise ValueError."""
+        with self.assertRaises(ValueError):
+            verify.check_congruences(cyclic(verify.MAX_SIZE + 1))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -3,11 +3,12 @@ Type stubs for uacalc_lib module.
 This file provides type information for Python IDEs and type checkers.
 """
 
-from typing import Any, List, Dict, Optional, Union, Tuple, Set
+from typing import Any, Callable, List, Dict, FrozenSet, Optional, Union, Tuple, Set
 from typing_extensions import Protocol
 
 # Type aliases for common UACalc types
 Element = Union[int, str, Tuple[Any, ...]]
+IntAlgebra = Union["alg.BasicAlgebra", "alg.SmallAlgebra", "alg.ProductAlgebra", "alg.PowerAlgebra", "alg.Subalgebra", "alg.ReductAlgebra"]
 
 # ============================================================================
 # PROTOCOL DEFINITIONS
@@ -82,6 +83,15 @@ class Operation(Protocol):
     def is_total(self) -> bool: ...
     """Checks if the operation is total."""
 
+    def depends_on_argument(self, i: int) -> bool: ...
+    """Checks if changing argument i alone can change the value."""
+
+    def essential_arity(self) -> int: ...
+    """Returns the number of arguments the operation depends on."""
+
+    def essentially_unary_decomposition(self) -> Optional[Tuple[int, List[int]]]: ...
+    """Returns (i, f) with op(x) = f[x[i]], or None if it depends on two or more arguments."""
+
 class Algebra(Protocol):
     """Protocol for algebra types in universal algebra.
 
@@ -356,15 +366,19 @@ class terms:
         def depth(self) -> int: ...
         def length(self) -> int: ...
         def get_variable_list(self) -> List[str]: ...
-        def eval(self, algebra: "alg.BasicAlgebra", var_map: Dict[str, int]) -> int: ...
-        def int_eval(self, algebra: "alg.BasicAlgebra", var_map: Dict[str, int]) -> int: ...
+        def size(self) -> int: ...
+        def node_count(self) -> int: ...
+        def occurrences_of_variable(self, name: str) -> int: ...
+        def subterms(self) -> List[Union["terms.VariableImp", "terms.NonVariableTerm"]]: ...
+        def to_dot(self) -> str: ...
+        def eval(self, algebra: IntAlgebra, var_map: Dict[str, int]) -> int: ...
+        def int_eval(self, algebra: IntAlgebra, var_map: Dict[str, int]) -> int: ...
         def interpretation(
             self,
-            algebra: "alg.BasicAlgebra",
-            varlist: List[str],
-            use_all: bool,
+            algebra: IntAlgebra,
+            varlist: Optional[List[str]] = None,
+            use_all: bool = True,
         ) -> "alg.IntOperation": ...
-        def interpretation(self, algebra: "alg.BasicAlgebra") -> "alg.IntOperation": ...
         def substitute(self, var_map: Dict["terms.VariableImp", Union["terms.VariableImp", "terms.NonVariableTerm"]]) -> Union["terms.VariableImp", "terms.NonVariableTerm"]: ...
         def clone_box(self) -> Union["terms.VariableImp", "terms.NonVariableTerm"]: ...
         def to_string(self) -> str: ...
@@ -389,13 +403,18 @@ class terms:
         def length(self) -> int: ...
         def get_variable_list(self) -> List[str]: ...
         def get_children(self) -> List[Union["terms.VariableImp", "terms.NonVariableTerm"]]: ...
-        def eval(self, algebra: "alg.BasicAlgebra", var_map: Dict[str, int]) -> int: ...
-        def int_eval(self, algebra: "alg.BasicAlgebra", var_map: Dict[str, int]) -> int: ...
+        def size(self) -> int: ...
+        def node_count(self) -> int: ...
+        def occurrences_of_variable(self, name: str) -> int: ...
+        def subterms(self) -> List[Union["terms.VariableImp", "terms.NonVariableTerm"]]: ...
+        def to_dot(self) -> str: ...
+        def eval(self, algebra: IntAlgebra, var_map: Dict[str, int]) -> int: ...
+        def int_eval(self, algebra: IntAlgebra, var_map: Dict[str, int]) -> int: ...
         def interpretation(
             self,
-            algebra: "alg.BasicAlgebra",
-            varlist: List[str],
-            use_all: bool,
+            algebra: IntAlgebra,
+            varlist: Optional[List[str]] = None,
+            use_all: bool = True,
         ) -> "alg.IntOperation": ...
         def substitute(self, var_map: Dict[str, Union["terms.VariableImp", "terms.NonVariableTerm"]]) -> Union["terms.VariableImp", "terms.NonVariableTerm"]: ...
         def to_string(self) -> str: ...
@@ -455,8 +474,9 @@ class terms:
             self,
             term: Union["terms.VariableImp", "terms.NonVariableTerm"],
             variables: List[Union["terms.VariableImp", str]],
-            algebra: "alg.BasicAlgebra",
+            algebra: IntAlgebra,
             name: Optional[str] = None,
+            eager: bool = True,
         ) -> None: ...
         def get_term(self) -> str: ...
         def get_ordered_variables(self) -> List[str]: ...
@@ -464,6 +484,8 @@ class terms:
         def get_set_size(self) -> int: ...
         def int_value_at(self, args: List[int]) -> int: ...
         def get_table(self) -> Optional[List[int]]: ...
+        def make_table(self) -> None: ...
+        def is_table_based(self) -> bool: ...
         def __str__(self) -> str: ...
         def __repr__(self) -> str: ...
     
@@ -574,6 +596,7 @@ class lat:
         A BasicLattice wraps a poset (OrderedSet) and provides lattice operations
         (join, meet) along with methods for visualization via graph data structures.
         Primarily used for drawing and visualization of lattices.
+        It does not support pickling; pickle the lattice it was built from instead.
         """
         def __init__(
             self,
@@ -729,6 +752,18 @@ class lat:
         Raises:
             ValueError: If called on a BasicLattice that is not BasicLattice<Partition> or BasicLattice<i32>
         """
+        def dual(self) -> "lat.BasicLattice": ...
+        """The dual lattice, named "<name>^d".
+
+        The combinators return a BasicLattice whose elements are the indices
+        of the elements, in the order of the universe of the result.
+        """
+        def direct_product(self, other: "lat.BasicLattice") -> "lat.BasicLattice": ...
+        """The direct product, named "<name> x <other>"; the pair (a, b) is the element a * other.cardinality() + b."""
+        def ordinal_sum(self, other: "lat.BasicLattice") -> "lat.BasicLattice": ...
+        """The ordinal sum, named "<name> + <other>", with other placed above."""
+        def vertical_sum(self, other: "lat.BasicLattice") -> "lat.BasicLattice": ...
+        """The vertical sum, named "<name> ++ <other>", gluing the one of this lattice to the zero of other."""
         def __str__(self) -> str: ...
         def __repr__(self) -> str: ...
     
@@ -957,6 +992,79 @@ class lat:
         """
         def __str__(self) -> str: ...
         def __repr__(self) -> str: ...
+
+    class Poset:
+        """A finite poset on the indices range(n), with a label for each element."""
+        def __init__(self, upper_covers: List[List[int]], labels: Optional[List[str]] = None, name: Optional[str] = None) -> None: ...
+        """Create a poset from a covering relation.
+
+        Args:
+            upper_covers: The indices of the upper covers of each element; the
+                order is their reflexive transitive closure
+            labels: A label for each element (default: the indices)
+            name: The name of the poset
+
+        Raises:
+            ValueError: If an index is out of range or the covers contain a cycle
+        """
+        @staticmethod
+        def from_leq(n: int, leq: Callable[[int, int], bool], name: Optional[str] = None) -> "lat.Poset": ...
+        """Create a poset on range(n) from a <= predicate.
+
+        Raises:
+            ValueError: If leq is not a partial order
+        """
+        @staticmethod
+        def from_lattice(lat: "lat.BasicLattice") -> "lat.Poset": ...
+        def name(self) -> Optional[str]: ...
+        def labels(self) -> List[str]: ...
+        def cardinality(self) -> int: ...
+        def __len__(self) -> int: ...
+        def leq(self, a: int, b: int) -> bool: ...
+        def upper_covers(self) -> List[List[int]]: ...
+        def lower_covers(self) -> List[List[int]]: ...
+        def minimals(self) -> List[int]: ...
+        def maximals(self) -> List[int]: ...
+        def linear_extension(self) -> List[int]: ...
+        def linear_extensions(self, progress: Optional["progress.ProgressReporter"] = None) -> List[List[int]]: ...
+        def maximal_chains(self, progress: Optional["progress.ProgressReporter"] = None) -> List[List[int]]: ...
+        def maximal_antichains(self, progress: Optional["progress.ProgressReporter"] = None) -> List[List[int]]: ...
+        def height(self) -> int: ...
+        """The number of elements of a longest chain."""
+        def width(self) -> int: ...
+        """The number of elements of a largest antichain."""
+        def mobius(self, a: int, b: int) -> int: ...
+        """The Mobius function mu(a, b)."""
+        def dual(self) -> "lat.Poset": ...
+        def product(self, other: "lat.Poset") -> "lat.Poset": ...
+        """The direct product; the pair (a, b) is the element a * len(other) + b."""
+        def ordinal_sum(self, other: "lat.Poset") -> "lat.Poset": ...
+        """The ordinal sum with other placed above; its elements follow those of this poset."""
+        def vertical_sum(self, other: "lat.Poset") -> "lat.Poset": ...
+        """The vertical sum: other placed above, with the top of this poset glued to the bottom of other.
+
+        Raises:
+            ValueError: If this poset has no top or other has no bottom
+        """
+        def is_lattice(self) -> bool: ...
+        def is_meet_semidistributive(self) -> bool: ...
+        """Whether this is a lattice in which a ∧ b = a ∧ c implies a ∧ b = a ∧ (b ∨ c)."""
+        def is_join_semidistributive(self) -> bool: ...
+        """Whether this is a lattice in which a ∨ b = a ∨ c implies a ∨ b = a ∨ (b ∧ c)."""
+        def meet_semidistributivity_failure(self) -> Optional[Tuple[int, int, int]]: ...
+        """Indices (a, b, c), b < c, with a ∧ b = a ∧ c but a ∧ (b ∨ c) ≠ a ∧ b; None if none or not a lattice."""
+        def join_semidistributivity_failure(self) -> Optional[Tuple[int, int, int]]: ...
+        """Indices (a, b, c), b < c, with a ∨ b = a ∨ c but a ∨ (b ∧ c) ≠ a ∨ b; None if none or not a lattice."""
+        def to_lattice(self, name: str) -> "lat.BasicLattice": ...
+        """Convert to a BasicLattice whose elements are the indices.
+
+        Raises:
+            ValueError: If the poset is not a lattice
+        """
+        def __reduce__(self) -> Tuple[type, Tuple[List[List[int]], List[str], Optional[str]]]: ...
+        """Pickle support: the poset is rebuilt from its upper covers, labels and name."""
+        def __str__(self) -> str: ...
+        def __repr__(self) -> str: ...
     
     # Module-level functions
     @staticmethod
@@ -1080,6 +1188,65 @@ class lat:
         Currently may have limitations with BasicLattice cloning/ownership
         requirements in Python bindings.
     """
+    @staticmethod
+    def free_lattice_leq(s: str, t: str) -> bool: ...
+    """Decide whether s <= t in the free lattice, using Whitman's condition.
+
+    Args:
+        s: A lattice term using join and meet, e.g. "meet(x,join(y,z))"
+        t: A lattice term
+
+    Returns:
+        bool: Whether s <= t holds in every lattice
+
+    Raises:
+        ValueError: If a term cannot be parsed or uses other operations
+    """
+    @staticmethod
+    def lattice_canonical_form(t: str) -> str: ...
+    """Compute the canonical form of a lattice term.
+
+    Args:
+        t: A lattice term using join and meet
+
+    Returns:
+        str: The shortest term for the same element of the free lattice
+
+    Raises:
+        ValueError: If the term cannot be parsed or uses other operations
+    """
+    @staticmethod
+    def free_lattice(gens: int, max_size: int, progress: Optional["progress.ProgressReporter"] = None) -> List[str]: ...
+    """Generate elements of the free lattice on gens generators x0, x1, ...
+
+    Args:
+        gens: The number of generators
+        max_size: The most elements to return
+        progress: Progress and cancellation
+
+    Returns:
+        List[str]: Canonical terms of the elements in the order found
+    """
+    @staticmethod
+    def as_semilattice(op: Operation) -> "lat.Poset": ...
+    """Read a semilattice operation as a join and return its order (x <= y iff join(x, y) = y).
+
+    Raises:
+        ValueError: If op is not idempotent, commutative and associative
+    """
+    @staticmethod
+    def as_lattice(join: Operation, meet: Operation) -> "lat.Poset": ...
+    """Check that join and meet form a lattice and return its order.
+
+    Raises:
+        ValueError: If either is not a semilattice operation or absorption fails
+    """
+    @staticmethod
+    def find_lattice_reduct(algebra: "alg.BasicAlgebra") -> Optional[Tuple[int, int]]: ...
+    """The indices of two basic operations forming a lattice, as (join, meet), or None."""
+    @staticmethod
+    def is_lattice_ordered(algebra: "alg.BasicAlgebra") -> bool: ...
+    """Whether two basic operations of the algebra form a lattice."""
     # Internal function names (also exported for compatibility)
     @staticmethod
     def py_lattice_from_meet(name: str, meet: Operation) -> "lat.BasicLattice": ...
@@ -1212,6 +1379,43 @@ class io:
     @staticmethod
     def read_projective_plane_from_stream(data: bytes) -> "alg.BasicAlgebra": ...
 
+    SCHEMA_VERSION: int
+
+    @staticmethod
+    def algebra_to_json(algebra: "alg.BasicAlgebra") -> str: ...
+    """Get the versioned JSON representation of an algebra (kind "algebra")."""
+    @staticmethod
+    def algebra_from_json(json: str) -> "alg.BasicAlgebra": ...
+    """Read an algebra from its JSON representation.
+
+    Raises:
+        ValueError: If the document is not a valid algebra
+    """
+    @staticmethod
+    def partition_to_json(partition: "alg.Partition") -> str: ...
+    """Get the versioned JSON representation of a partition (kind "partition")."""
+    @staticmethod
+    def partition_from_json(json: str) -> "alg.Partition": ...
+    """Read a partition from its JSON representation."""
+    @staticmethod
+    def equation_to_json(equation: "eq.Equation") -> str: ...
+    """Get the versioned JSON representation of an equation (kind "equation")."""
+    @staticmethod
+    def equation_from_json(json: str) -> "eq.Equation": ...
+    """Read an equation from its JSON representation."""
+    @staticmethod
+    def malcev_report(algebra: "alg.BasicAlgebra", kinds: Optional[List[str]] = None, nu_arity: int = 3) -> str: ...
+    """Search for Mal'cev condition terms and report them as JSON (kind "malcev_report").
+
+    Args:
+        algebra: The algebra
+        kinds: The terms to look for; all kinds by default
+        nu_arity: The arity of the near unanimity term for "nu"
+    """
+    @staticmethod
+    def tct_report(algebra: "alg.BasicAlgebra") -> str: ...
+    """Find the TCT types of the join irreducible congruences and report them as JSON (kind "tct_report")."""
+
 # ============================================================================
 # EQUATION MODULE
 # ============================================================================
@@ -1227,12 +1431,40 @@ class eq:
             right: Union["terms.VariableImp", "terms.NonVariableTerm"],
             vars: Optional[List[str]] = None,
         ) -> None: ...
+        @staticmethod
+        def from_terms(
+            left: Union["terms.VariableImp", "terms.NonVariableTerm"],
+            right: Union["terms.VariableImp", "terms.NonVariableTerm"],
+        ) -> "eq.Equation": ...
         def left_side(self) -> str: ...
         def right_side(self) -> str: ...
         def get_variable_list(self) -> List[str]: ...
         def get_operation_symbols(self) -> List[str]: ...
         def find_failure(self, algebra: "alg.BasicAlgebra") -> Optional[List[int]]: ...
         def find_failure_map(self, algebra: "alg.BasicAlgebra") -> Optional[Dict[str, int]]: ...
+        def is_satisfied_in(self, algebra: "alg.BasicAlgebra") -> bool: ...
+        def find_failure_batched(self, algebra: "alg.BasicAlgebra", parallel: bool = False) -> Optional[List[int]]: ...
+        def complexity(self) -> "eq.EquationComplexity": ...
+        """Sizes, variable multisets and syntactic classes of the equation."""
+        def __str__(self) -> str: ...
+        def __repr__(self) -> str: ...
+        def to_latex(self) -> str: ...
+        """The equation as a LaTeX formula, for example `$x \\wedge y \\approx y \\wedge x$`."""
+        def __getstate__(self) -> str: ...
+        def __setstate__(self, state: str) -> None: ...
+    
+    class EquationSystem:
+        """A collection of equations checked together with shared subterm evaluation."""
+        def __init__(self, equations: List["eq.Equation"] = ...) -> None: ...
+        def add(self, equation: "eq.Equation") -> None: ...
+        def sort_by_complexity(self) -> None: ...
+        """Reorder the equations cheapest first by complexity."""
+        def equations(self) -> List["eq.Equation"]: ...
+        def get_variable_list(self) -> List[str]: ...
+        def satisfied_in(self, algebra: "alg.BasicAlgebra") -> List[Optional[List[int]]]: ...
+        def all_satisfied_in(self, algebra: "alg.BasicAlgebra") -> bool: ...
+        def failure_maps(self, algebra: "alg.BasicAlgebra") -> List[Optional[Dict[str, int]]]: ...
+        def __len__(self) -> int: ...
         def __str__(self) -> str: ...
         def __repr__(self) -> str: ...
     
@@ -1250,11 +1482,162 @@ class eq:
     def cyclic_law(op_symbol: "alg.OperationSymbol") -> "eq.Equation": ...
     @staticmethod
     def first_second_symmetric_law(op_symbol: "alg.OperationSymbol") -> "eq.Equation": ...
+    @staticmethod
+    def semigroup_theory(mul: str = "mul") -> List["eq.Equation"]: ...
+    """The axioms of semigroups. Theories take the operation names, so they apply to any algebra."""
+    @staticmethod
+    def monoid_theory(mul: str = "mul", one: str = "one") -> List["eq.Equation"]: ...
+    @staticmethod
+    def group_theory(mul: str = "mul", inv: str = "inv", one: str = "one") -> List["eq.Equation"]: ...
+    @staticmethod
+    def abelian_group_theory(add: str = "add", neg: str = "neg", zero: str = "zero") -> List["eq.Equation"]: ...
+    @staticmethod
+    def lattice_theory(join: str = "join", meet: str = "meet") -> List["eq.Equation"]: ...
+    @staticmethod
+    def distributive_lattice_theory(join: str = "join", meet: str = "meet") -> List["eq.Equation"]: ...
+    @staticmethod
+    def boolean_algebra_theory(join: str = "join", meet: str = "meet", neg: str = "neg", zero: str = "zero", one: str = "one") -> List["eq.Equation"]: ...
+    @staticmethod
+    def quasigroup_theory(mul: str = "mul", ldiv: str = "ldiv", rdiv: str = "rdiv") -> List["eq.Equation"]: ...
+    """The axioms of quasigroups with left division ldiv and right division rdiv."""
+    @staticmethod
+    def semiring_theory(add: str = "add", mul: str = "mul", zero: str = "zero", one: str = "one") -> List["eq.Equation"]: ...
+    @staticmethod
+    def ring_theory(add: str = "add", neg: str = "neg", zero: str = "zero", mul: str = "mul", one: str = "one") -> List["eq.Equation"]: ...
+
+    class EquationComplexity:
+        """Structural measures of an equation."""
+        @property
+        def size(self) -> int: ...
+        @property
+        def depth(self) -> int: ...
+        @property
+        def variable_count(self) -> int: ...
+        @property
+        def left_variables(self) -> Dict[str, int]: ...
+        @property
+        def right_variables(self) -> Dict[str, int]: ...
+        def is_linear(self) -> bool: ...
+        def is_balanced(self) -> bool: ...
+        def is_regular(self) -> bool: ...
+        def is_normal(self) -> bool: ...
+        def search_key(self) -> Tuple[int, int, int, bool, bool, bool]: ...
+        """The key ordering equations cheapest first."""
+        def __str__(self) -> str: ...
+        def __repr__(self) -> str: ...
+
+    class QuasiEquation:
+        """A quasi-equation: premises implying a conclusion."""
+        def __init__(self, premises: List["eq.Equation"], conclusion: "eq.Equation") -> None: ...
+        @property
+        def premises(self) -> List["eq.Equation"]: ...
+        @property
+        def conclusion(self) -> "eq.Equation": ...
+        def get_variable_list(self) -> List[str]: ...
+        def find_failure(self, algebra: IntAlgebra) -> Optional[List[int]]: ...
+        """An assignment satisfying every premise but not the conclusion, or None."""
+        def is_satisfied_in(self, algebra: IntAlgebra) -> bool: ...
+        def __len__(self) -> int: ...
+        def __str__(self) -> str: ...
+        def __repr__(self) -> str: ...
+
+    class TheoryReport:
+        """The result of checking the axioms of a theory in an algebra."""
+        @property
+        def theory(self) -> str: ...
+        @property
+        def operations(self) -> List[str]: ...
+        @property
+        def axioms(self) -> List[Tuple["eq.Equation", Optional[Dict[str, int]]]]: ...
+        """Each axiom with its first counterexample, or None if it holds."""
+        def holds(self) -> bool: ...
+        def failure_count(self) -> int: ...
+        def __str__(self) -> str: ...
+        def __repr__(self) -> str: ...
+
+    class Classification:
+        """The strongest theories an algebra satisfies and its nearest misses."""
+        @property
+        def satisfied(self) -> List["eq.TheoryReport"]: ...
+        @property
+        def nearest(self) -> List["eq.TheoryReport"]: ...
+        def __str__(self) -> str: ...
+        def __repr__(self) -> str: ...
+
+    @staticmethod
+    def check_theory(algebra: "alg.BasicAlgebra", theory: str, operations: Optional[List[str]] = None) -> "eq.TheoryReport": ...
+    """Check the axioms of a built-in theory ("group", "lattice", "ring", ...) in an algebra.
+
+    The operations default to the symbol names of the theory.
+    """
+    @staticmethod
+    def classify(algebra: "alg.BasicAlgebra") -> "eq.Classification": ...
+    """Find the strongest built-in theories an algebra satisfies, trying every
+    assignment of its operations, and the theories it comes closest to."""
+    @staticmethod
+    def terms_equal_in(
+        algebra: IntAlgebra,
+        left: Union["terms.VariableImp", "terms.NonVariableTerm"],
+        right: Union["terms.VariableImp", "terms.NonVariableTerm"],
+    ) -> bool: ...
+    """Whether two terms agree at every assignment in an algebra."""
+    @staticmethod
+    def terms_equal_in_variety(
+        algebra: IntAlgebra,
+        left: Union["terms.VariableImp", "terms.NonVariableTerm"],
+        right: Union["terms.VariableImp", "terms.NonVariableTerm"],
+    ) -> bool: ...
+    """Whether two terms are equal in the variety generated by an algebra,
+    computed in the free algebra on their variables."""
 
 # ============================================================================
 # GROUP MODULE
 # ============================================================================
 
+class events:
+    """Lifecycle events of the computations taking a `progress` argument."""
+
+    class ComputationEvent:
+        """An event of a computation: it started, reported progress, finished
+        or was cancelled. Events of one computation share an id; attributes
+        not belonging to the kind of event are None."""
+        @property
+        def kind(self) -> str: ...
+        @property
+        def id(self) -> int: ...
+        @property
+        def name(self) -> Optional[str]: ...
+        @property
+        def phase(self) -> Optional[str]: ...
+        @property
+        def message(self) -> Optional[str]: ...
+        @property
+        def fraction(self) -> Optional[float]: ...
+        @property
+        def elapsed(self) -> Optional[float]: ...
+        @property
+        def error(self) -> Optional[str]: ...
+        def __str__(self) -> str: ...
+        def __repr__(self) -> str: ...
+
+    @staticmethod
+    def subscribe(listener: Callable[["events.ComputationEvent"], Optional[bool]]) -> int: ...
+    """Call a function with the events of every computation taking a
+    `progress` argument, returning a handle for unsubscribe.
+
+    A listener returning True cancels the computation, which raises
+    CancelledError; exceptions raised by listeners are reported through
+    sys.unraisablehook and otherwise ignored.
+    """
+
+    @staticmethod
+    def unsubscribe(handle: int) -> bool: ...
+    """Remove a listener, returning whether it was subscribed."""
+
+    @staticmethod
+    def listener_count() -> int: ...
+    """Get the number of listeners subscribed."""
+
 class group:
     """Group module for group theory operations."""
     
@@ -1300,12 +1683,72 @@ class group:
 
         def to_basic_algebra(self, name: str) -> "alg.BasicAlgebra": ...
 
+        def orbits(self) -> List[List[int]]: ...
+        """Get the orbits on {0, ..., n-1}, each sorted, ordered by their least element."""
+
+        def is_transitive(self) -> bool: ...
+
+        def transitive_constituents(self) -> List["group.PermutationGroup"]: ...
+        """Get the restriction of the group to each orbit, with the points renumbered in increasing order."""
+
+        def minimal_block_system(self, a: int, b: int) -> "alg.Partition": ...
+        """Get the smallest block system with a and b in the same block."""
+
+        def block_systems(self) -> List["alg.Partition"]: ...
+        """Get all block systems, i.e. all congruences of the G-set, including the trivial ones."""
+
+        def to_g_set(self) -> "alg.BasicAlgebra": ...
+        """Convert to the G-set: the unary algebra whose operations are the generators."""
+
+        def is_primitive(self) -> bool: ...
+
+        def block_action(self, blocks: "alg.Partition") -> "group.PermutationGroup": ...
+        """Get the action of the group on the blocks of a block system.
+
+        Raises:
+            ValueError: If the partition is not a block system
+        """
+
+        def imprimitive_decomposition(self) -> Optional[Tuple["alg.Partition", "group.PermutationGroup", "group.PermutationGroup"]]: ...
+        """Decompose a transitive imprimitive group along its smallest nontrivial blocks.
+
+        Returns:
+            The blocks, the action on the blocks and the action of the
+            stabilizer of the block of 0 on that block, or None if the group
+            is intransitive or primitive
+        """
+
+    @staticmethod
+    def is_group(algebra: "alg.BasicAlgebra") -> bool: ...
+    """Check whether some binary operation of the algebra is a group operation."""
+    @staticmethod
+    def center(algebra: "alg.BasicAlgebra") -> List[int]: ...
+    """Get the center of a group, sorted. Raises ValueError if the algebra is not a group."""
+    @staticmethod
+    def commutator_subgroup(algebra: "alg.BasicAlgebra") -> List[int]: ...
+    """Get the commutator subgroup, generated by the commutators x^-1 y^-1 x y."""
+    @staticmethod
+    def normal_subgroups(algebra: "alg.BasicAlgebra") -> List[List[int]]: ...
+    """Get the normal subgroups, read off from the congruence lattice of the group operation, ordered by size."""
+    @staticmethod
+    def upper_central_series(algebra: "alg.BasicAlgebra") -> List[List[int]]: ...
+    """Get the upper central series, from the trivial group to the hypercenter."""
+    @staticmethod
+    def derived_series(algebra: "alg.BasicAlgebra") -> List[List[int]]: ...
+    """Get the derived series, from the group down to its perfect core."""
+    @staticmethod
+    def is_abelian(algebra: "alg.BasicAlgebra") -> bool: ...
+    @staticmethod
+    def is_nilpotent(algebra: "alg.BasicAlgebra") -> bool: ...
+    @staticmethod
+    def is_solvable(algebra: "alg.BasicAlgebra") -> bool: ...
+
 # ============================================================================
 # FPLAT MODULE
 # ============================================================================
 
 class fplat:
-    """Fplat module for partially defined lattices."""
+    """Fplat module for partially defined and finitely presented lattices."""
     
     class PartiallyDefinedLattice:
         """Python wrapper for PartiallyDefinedLattice."""
@@ -1323,103 +1766,463 @@ class fplat:
         def __str__(self) -> str: ...
         def __repr__(self) -> str: ...
 
-# ============================================================================
-# ALG MODULE
-# ============================================================================
+    class FinitelyPresentedLattice:
+        """A lattice given by generators and relations between lattice terms."""
+        def __init__(self, name: str, generators: List[str], relations: List[str]) -> None: ...
+        """Create a lattice from generators and relations.
 
-class alg:
-    """Algebra module - main algebra structures and operations."""
-    
-    class BasicAlgebra:
-        """Python wrapper for BasicAlgebra."""
-        def __init__(
-            self,
-            name: str,
-            universe: List[int],
-            operations: Optional[List[Any]] = None,
-        ) -> None: ...
-        @staticmethod
-        def new_with_constant_op(name: str, universe: List[int]) -> "alg.BasicAlgebra": ...
+        Args:
+            name: The name of the lattice
+            generators: The generator names
+            relations: Relations "s = t" or "s <= t" between terms using join
+                and meet, e.g. "join(x,y) <= z"
+
+        Raises:
+            ValueError: If a relation cannot be parsed or uses other names
+        """
         @staticmethod
-        def from_general_algebra(general_algebra: "alg.GeneralAlgebra") -> "alg.BasicAlgebra": ...
+        def from_presentation(name: str, presentation: "eq.Presentation") -> "fplat.FinitelyPresentedLattice": ...
         def name(self) -> str: ...
-        def set_name(self, name: str) -> None: ...
-        def description(self) -> Optional[str]: ...
-        def set_description(self, desc: Optional[str]) -> None: ...
-        def cardinality(self) -> int: ...
-        def input_size(self) -> int: ...
-        def is_unary(self) -> bool: ...
-        def is_idempotent(self) -> bool: ...
-        def is_total(self) -> bool: ...
-        def monitoring(self) -> bool: ...
-        def get_universe(self) -> List[int]: ...
-        def algebra_type(self) -> str: ...
-        def get_element(self, k: int) -> int: ...
-        def element_index(self, elem: int) -> int: ...
-        def get_universe_list(self) -> Optional[List[int]]: ...
-        def get_universe_order(self) -> Optional[Dict[int, int]]: ...
-        def int_universe(self) -> bool: ...
-        def reset_con_and_sub(self) -> None: ...
-        def convert_to_default_value_ops(self) -> None: ...
-        def operations(self) -> List[Any]: ...
-        def operations_count(self) -> int: ...
-        def con(self) -> "alg.CongruenceLattice": ...
-        def sub(self) -> "alg.SubalgebraLattice": ...
-        def __str__(self) -> str: ...
-        def __repr__(self) -> str: ...
-        def __eq__(self, other: object) -> bool: ...
-    
-    # TODO: Register and expose AlgebraWithGeneratingVector in Rust bindings
-    class AlgebraWithGeneratingVector:
-        """Python wrapper for AlgebraWithGeneratingVector.
+        def generators(self) -> List[str]: ...
+        def relations(self) -> List[str]: ...
+        def leq(self, s: str, t: str) -> bool: ...
+        """Decide whether s <= t in this lattice (the word problem).
 
-        Represents an algebra with an associated vector of elements that generates it.
-        Allows repeats in generating vector, supports subdirect decomposition.
+        Raises:
+            ValueError: If a term is not a lattice term in the generators
         """
-        def __init__(self, alg: "alg.BasicAlgebra", vec: List[int]) -> None: ...
-        def get_algebra(self) -> "alg.BasicAlgebra": ...
-        def get_vector(self) -> List[int]: ...
-        def is_image_of(self, other: "alg.AlgebraWithGeneratingVector") -> bool: ...
+        def equal(self, s: str, t: str) -> bool: ...
+        def universe(self, max_size: int = 1000, progress: Optional["progress.ProgressReporter"] = None) -> List[str]: ...
+        """Compute the elements of the lattice, as terms in the generators.
+
+        Raises:
+            ValueError: If the lattice has more than max_size elements
+        """
+        def upper_covers(self, max_size: int = 1000, progress: Optional["progress.ProgressReporter"] = None) -> List[List[int]]: ...
+        """For each element of universe(), the indices of its upper covers."""
         def __str__(self) -> str: ...
         def __repr__(self) -> str: ...
-        def __eq__(self, other: object) -> bool: ...
-        def __hash__(self) -> int: ...
-        def __lt__(self, other: "alg.AlgebraWithGeneratingVector") -> bool: ...
-        def __le__(self, other: "alg.AlgebraWithGeneratingVector") -> bool: ...
-        def __gt__(self, other: "alg.AlgebraWithGeneratingVector") -> bool: ...
-        def __ge__(self, other: "alg.AlgebraWithGeneratingVector") -> bool: ...
-        @staticmethod
-        def si_decompose(alg: "alg.BasicAlgebra", vec: List[int]) -> List["alg.AlgebraWithGeneratingVector"]: ...
-        @staticmethod
-        def si_decompose_with_relations(alg: "alg.BasicAlgebra", vec: List[int], relations: List["alg.Partition"]) -> List["alg.AlgebraWithGeneratingVector"]: ...
 
-    class BasicOperation:
-        """Python wrapper for BasicOperation."""
-        def __init__(
-            self,
-            symbol: "alg.OperationSymbol",
-            set_size: int,
-            table: Optional[List[int]] = None,
-        ) -> None: ...
-        @staticmethod
-        def simple_binary_op(name: str, set_size: int) -> "alg.BasicOperation": ...
-        @staticmethod
-        def simple_unary_op(name: str, set_size: int) -> "alg.BasicOperation": ...
-        @staticmethod
-        def simple_nullary_op(name: str, set_size: int) -> "alg.BasicOperation": ...
-        def arity(self) -> int: ...
-        def get_set_size(self) -> int: ...
-        def symbol(self) -> "alg.OperationSymbol": ...
-        def value_at(self, args: List[int]) -> int: ...
-        def int_value_at(self, args: List[int]) -> int: ...
-    
-        def value_at_arrays(self, args: List[List[int]]) -> List[int]: ...
-    
-        def int_value_at_horner(self, arg: int) -> int: ...
-    
-        def make_table(self) -> None: ...
-    
-        def value_at(self, args: List[int]) -> int: ...
+# ============================================================================
+# MEMORY MODULE
+# ============================================================================
+
+class memory:
+    """Memory accounting and limits for long-running computations."""
+
+    CONGRUENCE_LATTICE: str
+    FREE_ALGEBRA: str
+    PRODUCT_TABLES: str
+
+    @staticmethod
+    def set_memory_limit(limit: Optional[int]) -> None: ...
+    """Set the soft memory limit in bytes, or remove it with None.
+
+    Long-running computations check the limit wherever they check for
+    cancellation and raise MemoryError once it has been passed.
+    """
+
+    @staticmethod
+    def get_memory_limit() -> Optional[int]: ...
+    """Get the soft memory limit in bytes, or None if there is none."""
+
+    @staticmethod
+    def get_allocated_memory() -> Optional[int]: ...
+    """Get the bytes currently allocated by the library's allocator, or None if not counted.
+
+    Allocations are counted in builds with the memory-tracking feature.
+    """
+
+    @staticmethod
+    def get_used_memory() -> Optional[int]: ...
+    """Get the memory used by the process, as checked against the limit."""
+
+    @staticmethod
+    def last_memory_usage(label: str) -> Optional[Dict[str, int]]: ...
+    """Get the "retained" and "peak" bytes of the last computation of a kind.
+
+    Args:
+        label: CONGRUENCE_LATTICE, FREE_ALGEBRA or PRODUCT_TABLES
+    """
+
+    @staticmethod
+    def estimate_conlat_memory(algebra: "alg.BasicAlgebra", con_size: Optional[int] = None) -> int: ...
+    """Estimate the bytes needed to build the congruence lattice of an algebra.
+
+    Args:
+        algebra: The algebra
+        con_size: The number of congruences, if known; by default the number
+            of principal congruences, giving a lower bound
+    """
+
+    @staticmethod
+    def estimate_free_algebra_memory(algebra: "alg.BasicAlgebra", number_of_gens: int) -> int: ...
+    """Estimate an upper bound on the bytes needed for a free algebra."""
+
+    @staticmethod
+    def estimate_product_table_memory(algebras: List["alg.BasicAlgebra"]) -> int: ...
+    """Estimate the bytes needed for the operation tables of a product."""
+
+# ============================================================================
+# PROGRESS MODULE
+# ============================================================================
+
+class progress:
+    """Progress reporting and cancellation for long-running computations."""
+
+    class CancelledError(Exception):
+        """Raised when a computation is cancelled through a ProgressReporter."""
+
+    class BudgetExceededError(CancelledError):
+        """Raised when a computation exceeds the budget of its ProgressReporter.
+
+        Attributes:
+            resource: "wall time", "memory" or "elements"
+            limit: The limit (milliseconds for wall time, bytes for memory)
+            used: The amount used when the computation stopped
+            partial: What was computed so far, or None where not supported
+        """
+        resource: str
+        limit: int
+        used: int
+        partial: Any
+
+    class Budget:
+        """Limits on the resources of a computation; None means unlimited."""
+        def __init__(self, wall_time: Optional[float] = None, max_memory: Optional[int] = None, max_elements: Optional[int] = None) -> None: ...
+        @property
+        def wall_time(self) -> Optional[float]: ...
+        @property
+        def max_memory(self) -> Optional[int]: ...
+        @property
+        def max_elements(self) -> Optional[int]: ...
+        def __repr__(self) -> str: ...
+
+    class ProgressReporter:
+        """Progress reporting and cancellation for long-running computations.
+
+        Pass a reporter as the `progress` argument of a long-running function.
+        The callback is called with the current phase and a message; returning
+        True, or calling cancel() from another thread, stops the computation,
+        which then raises CancelledError. An exception raised by the callback
+        also stops it and is re-raised. With a budget, a computation passing
+        one of its limits raises BudgetExceededError.
+        """
+        def __init__(self, callback: Optional[Callable[[str, str], Optional[bool]]] = None, budget: Optional["progress.Budget"] = None) -> None: ...
+        def cancel(self) -> None: ...
+        def is_cancelled(self) -> bool: ...
+        def current_phase(self) -> str: ...
+        def __repr__(self) -> str: ...
+
+class session:
+    """A workspace of named algebras with an undo/redo command log."""
+
+    class Workspace:
+        """Named algebras, saved terms and equations and notes, with an
+        undo/redo log of the commands that built them.
+
+        Each method that changes the workspace is a command that undo reverts;
+        a command that fails raises ValueError and changes nothing. Indexing by
+        name gives a copy of an algebra. save writes the workspace, with the
+        congruences and subuniverses computed so far but without the history,
+        to a .uaproj project file that Workspace.load reads back.
+        """
+        def __init__(self) -> None: ...
+        def add_algebra(self, name: str, algebra: "alg.BasicAlgebra") -> None: ...
+        def remove(self, name: str) -> None: ...
+        def rename(self, old: str, new: str) -> None: ...
+        def add_operation(self, name: str, operation: Any) -> None: ...
+        def remove_operation(self, name: str, symbol: str) -> None: ...
+        def set_operation_value(self, name: str, symbol: str, args: List[int], value: int) -> None: ...
+        def quotient(self, name: str, congruence: "alg.Partition", new_name: str) -> None: ...
+        def subalgebra(self, name: str, generators: List[int], new_name: str) -> None: ...
+        def save_term(self, name: str, term: Any) -> None: ...
+        def save_equation(self, name: str, equation: "eq.Equation") -> None: ...
+        def set_notes(self, notes: str) -> None: ...
+        def notes(self) -> str: ...
+        def terms(self) -> List[Tuple[str, str]]: ...
+        def equations(self) -> List[Tuple[str, "eq.Equation"]]: ...
+        def congruences(self, name: str) -> List["alg.Partition"]: ...
+        def subuniverses(self, name: str) -> List[List[int]]: ...
+        def save(self, path: str) -> None: ...
+        @staticmethod
+        def load(path: str) -> "session.Workspace": ...
+        def undo(self) -> Optional[str]: ...
+        def redo(self) -> Optional[str]: ...
+        def can_undo(self) -> bool: ...
+        def can_redo(self) -> bool: ...
+        def history(self) -> List[str]: ...
+        def names(self) -> List[str]: ...
+        def derived_from(self, name: str) -> Optional[str]: ...
+        def __getitem__(self, name: str) -> "alg.BasicAlgebra": ...
+        def __contains__(self, name: str) -> bool: ...
+        def __len__(self) -> int: ...
+        def __repr__(self) -> str: ...
+
+class telemetry:
+    """Counters and phase timings of long-running computations."""
+
+    class ComputationReport:
+        """The counters and phase timings of a computation."""
+        @property
+        def counters(self) -> Dict[str, int]: ...
+        @property
+        def phases(self) -> List[Tuple[str, float, int]]: ...
+        @property
+        def total_seconds(self) -> float: ...
+        def counter(self, name: str) -> int: ...
+        def __str__(self) -> str: ...
+        def __repr__(self) -> str: ...
+
+    class Recorder:
+        """Records counters and phase timings of the computations run inside it.
+
+        Use as a context manager; closures, congruence lattices and free
+        algebras computed in the `with` block on the same thread are recorded.
+        """
+        def __init__(self) -> None: ...
+        def __enter__(self) -> "telemetry.Recorder": ...
+        def __exit__(self, *args: Any) -> bool: ...
+        def report(self) -> "telemetry.ComputationReport": ...
+        def __repr__(self) -> str: ...
+
+    @staticmethod
+    def enable_logging(level: str = "info", file: Optional[str] = None) -> None: ...
+    """Log the phases ("info"), counters ("debug") and steps ("trace") of
+    computations to standard error or appended to a file.
+
+    Calling again replaces the level and destination; "off" stops logging.
+    Raises RuntimeError if another tracing subscriber is installed, or if
+    the library was built without the tracing feature.
+    """
+
+class verify:
+    """Cross-checks of fast algorithms against brute force on small inputs.
+
+    Present in builds with the verify feature.
+    """
+
+    MAX_SIZE: int
+    MAX_MAPS: int
+
+    class Discrepancy:
+        """A difference between a fast algorithm and its brute force reference."""
+        @property
+        def check(self) -> str: ...
+        @property
+        def algorithm(self) -> str: ...
+        @property
+        def detail(self) -> str: ...
+        def __str__(self) -> str: ...
+        def __repr__(self) -> str: ...
+
+    @staticmethod
+    def check_congruences(algebra: Any, progress: Optional["progress.ProgressReporter"] = None) -> List["verify.Discrepancy"]: ...
+    """Compare the congruence lattice universe with the compatible partitions."""
+
+    @staticmethod
+    def check_subuniverses(algebra: Any, progress: Optional["progress.ProgressReporter"] = None) -> List["verify.Discrepancy"]: ...
+    """Compare the subalgebra lattice universe with the closed subsets."""
+
+    @staticmethod
+    def check_homomorphisms(a: Any, b: Any, progress: Optional["progress.ProgressReporter"] = None) -> List["verify.Discrepancy"]: ...
+    """Compare the homomorphism searches of every backend with all maps from a to b."""
+
+    @staticmethod
+    def check_algebra(algebra: Any, progress: Optional["progress.ProgressReporter"] = None) -> List["verify.Discrepancy"]: ...
+    """Run the congruence, subuniverse and endomorphism checks."""
+
+class compat:
+    """The static methods of org.uacalc classes under their Java names.
+
+    Each method delegates to the snake_case function of the same meaning,
+    which is the one to use in new code. Terms are returned as strings.
+    """
+
+    class Malcev:
+        """org.uacalc.alg.Malcev."""
+        @staticmethod
+        def malcevTerm(algebra: Any, progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
+        @staticmethod
+        def majorityTerm(algebra: Any, progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
+        @staticmethod
+        def minorityTerm(algebra: Any, progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
+        @staticmethod
+        def pixleyTerm(algebra: Any, progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
+        @staticmethod
+        def nuTerm(algebra: Any, arity: int, progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
+        @staticmethod
+        def joinTerm(algebra: Any, progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
+        @staticmethod
+        def semilatticeTerm(algebra: Any, progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
+        @staticmethod
+        def differenceTerm(algebra: Any, progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
+        @staticmethod
+        def jonssonTerms(algebra: Any, progress: Optional["progress.ProgressReporter"] = None) -> Optional[List[str]]: ...
+        @staticmethod
+        def jonssonLevel(algebra: Any, progress: Optional["progress.ProgressReporter"] = None) -> int: ...
+        @staticmethod
+        def gummTerms(algebra: Any, progress: Optional["progress.ProgressReporter"] = None) -> Optional[List[str]]: ...
+        @staticmethod
+        def hagemannMitschkeTerms(algebra: Any, progress: Optional["progress.ProgressReporter"] = None) -> Optional[List[str]]: ...
+        @staticmethod
+        def sdmeetTerms(algebra: Any, progress: Optional["progress.ProgressReporter"] = None) -> Optional[List[str]]: ...
+        @staticmethod
+        def primalityTerms(algebra: Any, progress: Optional["progress.ProgressReporter"] = None) -> Optional[List[str]]: ...
+        @staticmethod
+        def isCongruenceDistIdempotent(algebra: Any, progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
+        @staticmethod
+        def isCongruenceModularIdempotent(algebra: Any, progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
+        @staticmethod
+        def congruenceModularVariety(algebra: Any, progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
+
+    class Algebras:
+        """org.uacalc.alg.Algebras."""
+        @staticmethod
+        def findNUF(algebra: Any, arity: int, progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
+        @staticmethod
+        def isHomomorphism(map: List[int], alg0: Any, alg1: Any) -> bool: ...
+        @staticmethod
+        def matrixPower(algebra: Any, k: int) -> "alg.BasicAlgebra": ...
+        @staticmethod
+        def fullTransformationSemigroup(n: int, include_constants: bool, include_id: bool) -> "alg.BasicAlgebra": ...
+        @staticmethod
+        def ternaryDiscriminatorAlgebra(card: int) -> "alg.BasicAlgebra": ...
+        @staticmethod
+        def memberOfQuasivariety(a: Any, b: Any, progress: Optional["progress.ProgressReporter"] = None) -> Optional[List["alg.Homomorphism"]]: ...
+        @staticmethod
+        def memberOfQuasivarietyGenByProperSubs(a: Any, progress: Optional["progress.ProgressReporter"] = None) -> Optional[List["alg.Homomorphism"]]: ...
+
+    class AlgebraIO:
+        """org.uacalc.io.AlgebraIO."""
+        @staticmethod
+        def parseLine(line: str) -> int: ...
+        @staticmethod
+        def readAlgebraFile(path: str) -> "alg.BasicAlgebra": ...
+        @staticmethod
+        def readAlgebraListFile(path: str) -> List["alg.BasicAlgebra"]: ...
+        @staticmethod
+        def writeAlgebraFile(algebra: Any, path: str) -> None: ...
+        @staticmethod
+        def convertToXML(path: str) -> None: ...
+
+def set_global_seed(seed: Optional[int]) -> None:
+    """Seed the random source of all randomized routines; None clears the seed.
+
+    After set_global_seed(seed) the routines that take no seed (random
+    operations and algebras, random default value tables) give the same
+    results on every run.
+    """
+    ...
+
+# ============================================================================
+# ALG MODULE
+# ============================================================================
+
+class alg:
+    """Algebra module - main algebra structures and operations."""
+    
+    class BasicAlgebra:
+        """Python wrapper for BasicAlgebra."""
+        def __init__(
+            self,
+            name: str,
+            universe: List[int],
+            operations: Optional[List[Any]] = None,
+        ) -> None: ...
+        @staticmethod
+        def new_with_constant_op(name: str, universe: List[int]) -> "alg.BasicAlgebra": ...
+        @staticmethod
+        def from_general_algebra(general_algebra: "alg.GeneralAlgebra") -> "alg.BasicAlgebra": ...
+        def name(self) -> str: ...
+        def set_name(self, name: str) -> None: ...
+        def description(self) -> Optional[str]: ...
+        def set_description(self, desc: Optional[str]) -> None: ...
+        def cardinality(self) -> int: ...
+        def input_size(self) -> int: ...
+        def is_unary(self) -> bool: ...
+        def is_idempotent(self) -> bool: ...
+        def is_total(self) -> bool: ...
+        def monitoring(self) -> bool: ...
+        def get_universe(self) -> List[int]: ...
+        def algebra_type(self) -> str: ...
+        def get_element(self, k: int) -> int: ...
+        def element_index(self, elem: int) -> int: ...
+        def get_universe_list(self) -> Optional[List[int]]: ...
+        def get_universe_order(self) -> Optional[Dict[int, int]]: ...
+        def int_universe(self) -> bool: ...
+        def reset_con_and_sub(self) -> None: ...
+        def convert_to_default_value_ops(self) -> None: ...
+        def operations(self) -> List[Any]: ...
+        def operations_count(self) -> int: ...
+        def con(self) -> "alg.CongruenceLattice": ...
+        def sub(self) -> "alg.SubalgebraLattice": ...
+        def describe(self, style: str = "text") -> str:
+            """The name, size and description followed by the operation tables.
+
+            Args:
+                style: "text", "markdown" or "latex"
+            """
+        def to_latex(self) -> str: ...
+        def __str__(self) -> str: ...
+        def __repr__(self) -> str: ...
+        def __eq__(self, other: object) -> bool:
+            """Structural equality: same universe and operation tables; names are ignored."""
+        def __hash__(self) -> int: ...
+        def __getstate__(self) -> str: ...
+        def __setstate__(self, state: str) -> None: ...
+    
+    # TODO: Register and expose AlgebraWithGeneratingVector in Rust bindings
+    class AlgebraWithGeneratingVector:
+        """Python wrapper for AlgebraWithGeneratingVector.
+
+        Represents an algebra with an associated vector of elements that generates it.
+        Allows repeats in generating vector, supports subdirect decomposition.
+        """
+        def __init__(self, alg: "alg.BasicAlgebra", vec: List[int]) -> None: ...
+        def get_algebra(self) -> "alg.BasicAlgebra": ...
+        def get_vector(self) -> List[int]: ...
+        def is_image_of(self, other: "alg.AlgebraWithGeneratingVector") -> bool: ...
+        def __str__(self) -> str: ...
+        def __repr__(self) -> str: ...
+        def __eq__(self, other: object) -> bool: ...
+        def __hash__(self) -> int: ...
+        def __lt__(self, other: "alg.AlgebraWithGeneratingVector") -> bool: ...
+        def __le__(self, other: "alg.AlgebraWithGeneratingVector") -> bool: ...
+        def __gt__(self, other: "alg.AlgebraWithGeneratingVector") -> bool: ...
+        def __ge__(self, other: "alg.AlgebraWithGeneratingVector") -> bool: ...
+        @staticmethod
+        def si_decompose(alg: "alg.BasicAlgebra", vec: List[int]) -> List["alg.AlgebraWithGeneratingVector"]: ...
+        @staticmethod
+        def si_decompose_with_relations(alg: "alg.BasicAlgebra", vec: List[int], relations: List["alg.Partition"]) -> List["alg.AlgebraWithGeneratingVector"]: ...
+
+    class BasicOperation:
+        """Python wrapper for BasicOperation."""
+        def __init__(
+            self,
+            symbol: "alg.OperationSymbol",
+            set_size: int,
+            table: Optional[List[int]] = None,
+        ) -> None: ...
+        @staticmethod
+        def simple_binary_op(name: str, set_size: int) -> "alg.BasicOperation": ...
+        @staticmethod
+        def simple_unary_op(name: str, set_size: int) -> "alg.BasicOperation": ...
+        @staticmethod
+        def simple_nullary_op(name: str, set_size: int) -> "alg.BasicOperation": ...
+        def arity(self) -> int: ...
+        def get_set_size(self) -> int: ...
+        def symbol(self) -> "alg.OperationSymbol": ...
+        def value_at(self, args: List[int]) -> int: ...
+        def int_value_at(self, args: List[int]) -> int: ...
+    
+        def value_at_arrays(self, args: List[List[int]]) -> List[int]: ...
+    
+        def int_value_at_horner(self, arg: int) -> int: ...
+    
+        def make_table(self) -> None: ...
+    
+        def value_at(self, args: List[int]) -> int: ...
     
         def value_at_arrays(self, args: List[List[int]]) -> List[int]: ...
     
@@ -1460,6 +2263,17 @@ class alg:
         def is_maltsev(self) -> bool: ...
     
         def is_total(self) -> bool: ...
+        def depends_on_argument(self, i: int) -> bool: ...
+        def essential_arity(self) -> int: ...
+        def essentially_unary_decomposition(self) -> Optional[Tuple[int, List[int]]]: ...
+        def format_table(self, style: str = "text", labels: Optional[List[str]] = None) -> str:
+            """Format the table of the operation, a Cayley table for binary operations.
+
+            Args:
+                style: "text", "markdown" or "latex"
+                labels: Names for the elements; indices by default
+            """
+        def to_latex(self) -> str: ...
         def __str__(self) -> str: ...
         def __repr__(self) -> str: ...
     
@@ -1491,7 +2305,13 @@ class alg:
         def compare_to(self, other: "alg.OperationSymbol") -> int: ...
     
     class IntOperation:
-        """Python wrapper for IntOperation."""
+        """Python wrapper for IntOperation.
+
+        The table can be edited in place with set_value, update_from_matrix
+        and op[x, y] = z; this is the only operation class that allows it.
+        Since edits change equality, IntOperation is not hashable.
+        """
+        __hash__: None  # type: ignore[assignment]
         def __init__(
             self,
             symbol: "alg.OperationSymbol",
@@ -1503,6 +2323,27 @@ class alg:
         def symbol(self) -> "alg.OperationSymbol": ...
         def int_value_at(self, args: List[int]) -> int: ...
         def get_table(self) -> Optional[List[int]]: ...
+        def set_value(self, args: List[int], result: int) -> None:
+            """Set the value at args; raises ValueError if anything is out of range."""
+            ...
+        def update_from_matrix(self, matrix: Any) -> None:
+            """Replace the table of a binary operation; entry [x][y] is the value at (x, y)."""
+            ...
+        def __getitem__(self, key: Union[int, Tuple[int, ...]]) -> int: ...
+        def __setitem__(self, key: Union[int, Tuple[int, ...]], value: int) -> None: ...
+        def is_idempotent(self) -> bool: ...
+        def is_associative(self) -> bool: ...
+        def depends_on_argument(self, i: int) -> bool: ...
+        def essential_arity(self) -> int: ...
+        def essentially_unary_decomposition(self) -> Optional[Tuple[int, List[int]]]: ...
+        def format_table(self, style: str = "text", labels: Optional[List[str]] = None) -> str:
+            """Format the table of the operation, a Cayley table for binary operations.
+
+            Args:
+                style: "text", "markdown" or "latex"
+                labels: Names for the elements; indices by default
+            """
+        def to_latex(self) -> str: ...
         def __str__(self) -> str: ...
         def __repr__(self) -> str: ...
     
@@ -1715,6 +2556,21 @@ class alg:
     
         @staticmethod
         def find_difference(op1: "alg.Operation", op2: "alg.Operation") -> Optional[List[int]]: ...
+
+        @staticmethod
+        def recognize_structure(op: "alg.Operation") -> Dict[str, Any]: ...
+        """Classify a binary operation as a semigroup, monoid, group, quasigroup, loop, band or semilattice.
+
+        Returns:
+            A dict with "kinds", the names of the structures the operation
+            forms; "identity" and "inverses" (or None); and the failure
+            witnesses "non_associative" (x, y, z), "non_commutative" (x, y),
+            "non_idempotent" x, "non_latin" (x, y, z) with x*y = x*z or
+            y*x = z*x, and "non_invertible" x, each None if the law holds
+
+        Raises:
+            ValueError: If the operation is not binary
+        """
     
         @staticmethod
         def make_map(domain: List[int], codomain: List[int]) -> Dict[int, int]: ...
@@ -1892,6 +2748,7 @@ class alg:
         def get_operation_symbols(self) -> List["alg.OperationSymbol"]: ...
         def get_sorted_operation_symbols(self) -> List["alg.OperationSymbol"]: ...
         def input_size(self, alg_size: int) -> int: ...
+        def exact_input_size(self, alg_size: int) -> Optional[int]: ...
         def get_arities_map(self) -> Dict[int, int]: ...
         def get_max_arity(self) -> int: ...
         @staticmethod
@@ -1951,6 +2808,50 @@ class alg:
                 The kernel partition
             """
         @staticmethod
+        def find(domain: "alg.BasicAlgebra", range: "alg.BasicAlgebra", backend: str = "ac", progress: Optional["progress.ProgressReporter"] = None) -> Optional["alg.Homomorphism"]:
+            """Find a homomorphism between two algebras.
+
+            Args:
+                domain: The domain algebra
+                range: The range algebra; must have every operation symbol of the domain
+                backend: "ac" (default), "sac", "backtrack" or "sat" (with the sat feature)
+                progress: Progress and cancellation
+
+            Returns:
+                A homomorphism, or None if there is none
+            """
+        @staticmethod
+        def canonical_projection(algebra: "alg.BasicAlgebra", theta: "alg.Partition") -> "alg.Homomorphism":
+            """The natural map from an algebra onto its quotient by a congruence.
+
+            Raises:
+                ValueError: If theta is not a congruence of the algebra
+            """
+        @staticmethod
+        def inclusion(subalgebra: "alg.Subalgebra") -> "alg.Homomorphism":
+            """The inclusion of a subalgebra into its super algebra."""
+        @staticmethod
+        def first_isomorphism_theorem(h: "alg.Homomorphism") -> "alg.Homomorphism":
+            """The isomorphism A/ker(h) -> h(A) given by the first isomorphism theorem.
+
+            Raises:
+                ValueError: If h does not respect the operations
+            """
+        def is_injective(self) -> bool:
+            """Check if distinct domain elements have distinct images."""
+        def is_surjective(self) -> bool:
+            """Check if every range element is an image."""
+        def image(self) -> List[int]:
+            """Get the range elements hit by the map, in increasing order."""
+        def image_subalgebra(self) -> "alg.Subalgebra":
+            """Get the image of the domain as a subalgebra of the range."""
+        def compose(self, other: "alg.Homomorphism") -> "alg.Homomorphism":
+            """Compose with a homomorphism applied after this one: x maps to other(self(x)).
+
+            Raises:
+                ValueError: If the range of this homomorphism and the domain of the other have different sizes
+            """
+        @staticmethod
         def product_homo(homomorphisms: List["alg.Homomorphism"]) -> List["util.IntArray"]:
             """Create the product homomorphism from a list of homomorphisms.
 
@@ -2000,8 +2901,25 @@ class alg:
             """String representation of the homomorphism."""
         def __repr__(self) -> str:
             """Detailed string representation of the homomorphism."""
+        def __reduce__(self) -> Tuple[type, Tuple["alg.BasicAlgebra", "alg.BasicAlgebra", Dict[int, int]]]:
+            """Pickle support: the homomorphism is rebuilt from its domain, range and map."""
+    class Subuniverses:
+        """Lazy iterator over the subuniverses of an algebra."""
+        def __iter__(self) -> "alg.Subuniverses": ...
+        def __next__(self) -> "alg.BasicSet": ...
     class SubalgebraLattice:
         """Subalgebra lattice implementation."""
+        def subuniverses_iter(self) -> "alg.Subuniverses": ...
+        """Iterate over the subuniverses, generating them lazily.
+
+        The subuniverse generated by the constants comes first, then the join
+        irreducibles, then their joins as they are found, so a scan can stop
+        early without the whole universe being built.
+        """
+        def universe(self) -> List["alg.BasicSet"]: ...
+        """Get all the subalgebras, by size and then lexicographically."""
+        def __iter__(self) -> "alg.Subuniverses": ...
+        def __len__(self) -> int: ...
         def join_irreducibles_po(self) -> "lat.OrderedSetBasicSet": ...
         """Get the join irreducibles as an OrderedSet.
         
@@ -2185,8 +3103,21 @@ class alg:
             """Check equality with another relation."""
         def __hash__(self) -> int:
             """Get the hash value of the relation."""
+        def __lt__(self, other: "alg.BasicBinaryRelation") -> bool:
+            """Compare by universe size, then number of pairs, then the sorted pairs."""
+        def __le__(self, other: "alg.BasicBinaryRelation") -> bool: ...
+        def __gt__(self, other: "alg.BasicBinaryRelation") -> bool: ...
+        def __ge__(self, other: "alg.BasicBinaryRelation") -> bool: ...
         def __iter__(self) -> Any:
             """Get an iterator over the pairs in the relation."""
+        def __reduce__(self) -> Tuple[type, Tuple[int], List[List[int]]]:
+            """Pickle support: the universe size, with the pairs as the state."""
+        def __setstate__(self, pairs: List[List[int]]) -> None:
+            """Replace the pairs of the relation, for unpickling.
+
+            Raises:
+                ValueError: If a pair is not of two elements of the universe
+            """
     class CentralityData:
         """Python wrapper for CentralityData.
 
@@ -2269,14 +3200,29 @@ class alg:
         A partition divides a set into non-empty, pairwise disjoint subsets called blocks.
         Partitions are fundamental in congruence theory and polymorphism calculations.
         """
-        def __init__(self, array: List[int]) -> None:
-            """Create a new Partition from an array representation.
+        def __init__(self, array: Union[List[int], str]) -> None:
+            """Create a new Partition from an array or a string representation.
 
             Args:
-                array: The array representation of the partition
+                array: The array representation of the partition, or a string
+                    in bar notation such as "|0 1|2|3 4|"
 
             Raises:
-                ValueError: If the array is invalid
+                ValueError: If the array or string is invalid
+            """
+            ...
+
+        @staticmethod
+        def from_block_index_vector(indices: List[int]) -> "alg.Partition":
+            """Create a Partition from the block index of each element.
+
+            Elements with the same index are in the same block.
+
+            Args:
+                indices: The block index of each of 0, ..., n-1
+
+            Raises:
+                ValueError: If the list is empty
             """
             ...
 
@@ -2530,6 +3476,46 @@ class alg:
             """Python equality comparison."""
             ...
 
+        def covers(self, other: "alg.Partition") -> bool:
+            """Check if this partition covers another: it joins two blocks of the other."""
+            ...
+
+        def common_upper_covers(self, other: "alg.Partition") -> List["alg.Partition"]:
+            """Get the partitions covering both this partition and another.
+
+            Raises:
+                ValueError: If the universe sizes differ
+            """
+            ...
+
+        def is_complement(self, other: "alg.Partition") -> bool:
+            """Check if the meet with another partition is zero and the join is one."""
+            ...
+
+        def relational_composition(self, other: "alg.Partition") -> "alg.BasicBinaryRelation":
+            """Get the relational product of this partition followed by another.
+
+            Raises:
+                ValueError: If the universe sizes differ
+            """
+            ...
+
+        def permutes_with(self, other: "alg.Partition") -> bool:
+            """Check if this partition permutes with another: both relational products are equal."""
+            ...
+
+        def to_bar_string(self) -> str:
+            """The partition in bar notation with space separated elements, for example "|0 1|2|3 4|"."""
+            ...
+
+        def to_latex(self) -> str:
+            """The partition in LaTeX bar notation, for example `$|01|2|34|$`."""
+            ...
+
+        def __reduce__(self) -> Tuple[type, Tuple[List[int]]]:
+            """Pickle support: the partition is rebuilt from its array."""
+            ...
+
         def __hash__(self) -> int:
             """Python hash function."""
             ...
@@ -2641,8 +3627,120 @@ class alg:
             """
             ...
     class PrintType: ...
+    class Congruences:
+        """Lazy iterator over the congruences of an algebra."""
+        def __iter__(self) -> "alg.Congruences": ...
+        def __next__(self) -> "alg.Partition": ...
     class CongruenceLattice:
         """Congruence lattice implementation."""
+        def __init__(self, algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> None: ...
+        def con_cardinality(self, progress: Optional["progress.ProgressReporter"] = None) -> int: ...
+        """Get the number of congruences, building the universe if needed.
+
+        Args:
+            progress: Progress and cancellation; a cancelled build keeps its
+                partial universe and resumes later
+
+        Raises:
+            CancelledError: If the build is cancelled through progress
+        """
+        def universe(self, progress: Optional["progress.ProgressReporter"] = None) -> List["alg.Partition"]: ...
+        """Get all the congruences, building the universe if needed.
+
+        The congruences are in the order of Partition: by rank (from more
+        blocks to fewer), then by partition array, so zero comes first, one
+        last, and indices are the same in every run.
+        """
+        def join_irreducibles(self, progress: Optional["progress.ProgressReporter"] = None) -> List["alg.Partition"]: ...
+        def join_irreducible(self, partition: "alg.Partition") -> bool: ...
+        """Whether a congruence is join irreducible."""
+        def lower_star(self, partition: "alg.Partition") -> Optional["alg.Partition"]: ...
+        """The unique lower cover of a join irreducible congruence, or None."""
+        def partial_universe(self) -> Optional[List["alg.Partition"]]: ...
+        """Get the congruences found by an interrupted universe build, or None."""
+        def congruences_iter(self, progress: Optional["progress.ProgressReporter"] = None) -> "alg.Congruences": ...
+        """Iterate over the congruences, generating them lazily.
+
+        The congruences come in the order they are found: zero, the join
+        irreducibles, then their joins, so a scan can stop early without the
+        whole universe being built. This is not the order of universe();
+        iterating over the lattice itself gives that order.
+
+        Args:
+            progress: Progress and cancellation while the join irreducibles
+                are computed
+        """
+        def __iter__(self) -> "alg.Congruences": ...
+        """Iterate over the congruences in the order of universe()."""
+        def __len__(self) -> int: ...
+        def embeds_in(self, other: "alg.CongruenceLattice", progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
+        """Check whether this congruence lattice has a 0-1 lattice embedding into another."""
+        def is_dual_to(self, other: "alg.CongruenceLattice", progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
+        """Check whether this congruence lattice is isomorphic to the dual of another."""
+        def all_isomorphisms(self, other: "alg.CongruenceLattice", progress: Optional["progress.ProgressReporter"] = None) -> List[List[int]]: ...
+        """All lattice isomorphisms onto another congruence lattice.
+
+        Returns:
+            The isomorphisms; f[i] is the index in other.universe() of the
+            image of self.universe()[i]
+        """
+        def to_latex(self, progress: Optional["progress.ProgressReporter"] = None) -> str: ...
+        """The Hasse diagram of the lattice as a TikZ picture.
+
+        Congruences are labelled by their blocks in bar notation.
+
+        Args:
+            progress: Progress and cancellation while the universe is built
+        """
+        def __getstate__(self) -> str: ...
+        def __setstate__(self, state: str) -> None: ...
+        def save_checkpoint(self, path: str) -> None: ...
+        """Save the state of the universe build to a JSON file.
+
+        Args:
+            path: The file to write
+
+        Raises:
+            OSError: If the file cannot be written
+        """
+        def resume_from(self, path: str) -> None: ...
+        """Restore the state saved by save_checkpoint; the next universe build continues from it.
+
+        Args:
+            path: The checkpoint file
+
+        Raises:
+            OSError: If the file cannot be read
+            ValueError: If the checkpoint is for another algebra or holds a
+                partition that is not a congruence of this one
+        """
+        def identify_lattice(self) -> str: ...
+        """Name the lattice if it is a standard small lattice.
+
+        Returns:
+            "C<n>" for a chain, "M<n>", "N5", "2^<k>" for a Boolean lattice,
+            or "unknown"
+        """
+        def is_modular(self) -> bool: ...
+        """Test whether the congruence lattice is modular."""
+        def is_upper_semimodular(self) -> bool: ...
+        """Test whether the congruence lattice is upper semimodular."""
+        def is_lower_semimodular(self) -> bool: ...
+        """Test whether the congruence lattice is lower semimodular."""
+        def is_lower_bounded_lattice(self) -> bool: ...
+        """Test whether the congruence lattice is lower bounded."""
+        def is_relatively_complemented(self) -> bool: ...
+        """Test whether every interval of the congruence lattice is complemented."""
+        def is_geometric(self) -> bool: ...
+        """Test whether the congruence lattice is atomistic and upper semimodular."""
+        def is_meet_semidistributive(self) -> bool: ...
+        """Test whether a ∧ b = a ∧ c implies a ∧ b = a ∧ (b ∨ c) in the congruence lattice."""
+        def is_join_semidistributive(self) -> bool: ...
+        """Test whether a ∨ b = a ∨ c implies a ∨ b = a ∨ (b ∧ c) in the congruence lattice."""
+        def meet_semidistributivity_failure(self) -> Optional[Tuple["alg.Partition", "alg.Partition", "alg.Partition"]]: ...
+        """Congruences (a, b, c) with a ∧ b = a ∧ c but a ∧ (b ∨ c) ≠ a ∧ b, or None."""
+        def join_semidistributivity_failure(self) -> Optional[Tuple["alg.Partition", "alg.Partition", "alg.Partition"]]: ...
+        """Congruences (a, b, c) with a ∨ b = a ∨ c but a ∨ (b ∧ c) ≠ a ∨ b, or None."""
         def join_irreducibles_po(self) -> "lat.OrderedSetPartition": ...
         """Get the join irreducibles as an OrderedSet.
         
@@ -2803,6 +3901,10 @@ class alg:
         def __ge__(self, other: "alg.BasicSet") -> bool:
             """Greater than or equal comparison."""
     class FreeAlgebra: ...
+    class ProductElements:
+        """Lazy iterator over the elements of a product or power algebra, as tuples of factor elements."""
+        def __iter__(self) -> "alg.ProductElements": ...
+        def __next__(self) -> Tuple[int, ...]: ...
     class ProductAlgebra:
         """Python wrapper for ProductAlgebra - represents the direct product of algebras.
 
@@ -2857,6 +3959,87 @@ class alg:
                 The cardinality (size of the universe)
             """
 
+        def exact_cardinality(self) -> Optional[int]:
+            """Get the exact cardinality, even when it is too big for cardinality().
+
+            Returns:
+                The cardinality as an int of any size, or None if it is unknown
+            """
+
+        def elements(self) -> "alg.ProductElements":
+            """Iterate over the elements as tuples of factor elements, without building the whole universe.
+
+            Returns:
+                A lazy iterator of tuples, the first coordinate varying fastest
+            """
+
+        def __iter__(self) -> "alg.ProductElements": ...
+
+        def sample(self, n: int, seed: int) -> List[Tuple[int, ...]]:
+            """Draw random elements, as tuples of factor elements, uniformly and independently.
+
+            Args:
+                n: The number of elements to draw
+                seed: The seed of the random number generator
+
+            Returns:
+                The elements drawn
+            """
+
+        def cg(self, a: int, b: int) -> "alg.Partition":
+            """The congruence generated by a pair of elements, computed through the coordinates.
+
+            Args:
+                a: An element of the product
+                b: An element of the product
+
+            Returns:
+                The congruence generated by (a, b)
+
+            Raises:
+                ValueError: If the product is too big or an element is out of range
+            """
+
+        def projection_kernel(self, k: int) -> "alg.Partition":
+            """The kernel of the projection onto the k-th factor.
+
+            Args:
+                k: The index of the factor
+
+            Returns:
+                The elements related when their k-th coordinates agree
+
+            Raises:
+                ValueError: If k is out of range or the product is too big
+            """
+
+        def projection_kernels(self) -> List["alg.Partition"]:
+            """The kernels of all the projections, in the order of the factors.
+
+            Raises:
+                ValueError: If the product is too big
+            """
+
+        def is_skew_free(self, progress: Optional["progress.ProgressReporter"] = None) -> bool:
+            """Check whether every congruence of the product is a product of congruences of the factors.
+
+            Args:
+                progress: Progress and cancellation
+
+            Raises:
+                RuntimeError: If the product is too big or the check was cancelled
+            """
+
+        def skew_congruences(self, progress: Optional["progress.ProgressReporter"] = None) -> List["alg.Partition"]:
+            """The congruences of the product that are not products of congruences of the factors.
+
+            Args:
+                progress: Progress and cancellation
+
+            Raises:
+                RuntimeError: If the product is too big or the computation was cancelled
+            """
+
         def get_element(self, k: int) -> int:
             """Get the element at the given index.
 
@@ -2997,6 +4180,33 @@ class alg:
                 The cardinality of the power algebra
             """
 
+        def exact_cardinality(self) -> Optional[int]:
+            """Get the exact cardinality, even when it is too big for cardinality().
+
+            Returns:
+                The cardinality as an int of any size, or None if it is unknown
+            """
+
+        def elements(self) -> "alg.ProductElements":
+            """Iterate over the elements as tuples of root elements, without building the whole universe.
+
+            Returns:
+                A lazy iterator of tuples, the first coordinate varying fastest
+            """
+
+        def __iter__(self) -> "alg.ProductElements": ...
+
+        def sample(self, n: int, seed: int) -> List[Tuple[int, ...]]:
+            """Draw random elements, as tuples of root elements, uniformly and independently.
+
+            Args:
+                n: The number of elements to draw
+                seed: The seed of the random number generator
+
+            Returns:
+                The elements drawn
+            """
+
         def name(self) -> str:
             """Get the name of this power algebra.
 
@@ -4190,7 +5400,24 @@ class alg:
         def __repr__(self) -> str: ...
     
     class Closer: ...
-    class CloserTiming: ...
+    class CloserTiming:
+        def __init__(self, algebra: "alg.BigProductAlgebra") -> None: ...
+        @staticmethod
+        def new_with_params(arities: List[int], num_factors: int) -> "alg.CloserTiming": ...
+        def update_pass(self, size: int) -> None: ...
+        def increment_apps(self) -> None: ...
+        def increment_next_pass_size(self) -> None: ...
+        def get_pass(self) -> int: ...
+        def get_num_factors(self) -> int: ...
+        def get_arities(self) -> List[int]: ...
+        def get_curr_pass_size(self) -> int: ...
+        def get_last_pass_size(self) -> int: ...
+        def get_next_pass_size(self) -> int: ...
+        def get_apps_needed(self) -> int: ...
+        def get_apps_this_pass(self) -> int: ...
+        def get_ms_per_app(self) -> float: ...
+        @staticmethod
+        def ms_to_string(ms: int) -> str: ...
     
     # Module-level functions for algebra operations
     @staticmethod
@@ -4233,111 +5460,1049 @@ class alg:
     """
     
     @staticmethod
-    def jonsson_terms(algebra: "alg.BasicAlgebra") -> Optional[List[str]]: ...
-    """Find Jonsson terms for the algebra.
-    
-    This returns a list of Jonsson terms witnessing congruence distributivity,
-    or None if the algebra does not generate a congruence distributive variety.
-    The returned terms are guaranteed to be the least number of terms possible.
-    
+    def find_homomorphism(a: "alg.BasicAlgebra", b: "alg.BasicAlgebra", backend: str = "ac", progress: Optional["progress.ProgressReporter"] = None) -> Optional[List[int]]: ...
+    """Find a homomorphism from one algebra to another.
+
     Args:
-        algebra: The algebra to check (BasicAlgebra)
-        
+        a: The domain algebra (BasicAlgebra)
+        b: The target algebra (BasicAlgebra)
+        backend: "ac" (default), "sac", "backtrack" or "sat" (with the sat feature)
+        progress: Optional ProgressReporter for progress and cancellation
+
     Returns:
-        List of Jonsson terms as strings if they exist, None otherwise
+        The list of images of the elements of a, or None if there is no homomorphism
+
+    Raises:
+        ValueError: If an operation of a is missing from b or the backend is unknown
     """
-    
+
     @staticmethod
-    def jonsson_level(algebra: "alg.BasicAlgebra") -> int: ...
-    """Get the Jonsson level for the algebra.
-    
-    If the algebra generates a distributive variety, this returns the minimal
-    number of Jonsson terms minus 1; otherwise it returns -1.
-    For congruence distributivity testing, it's probably better to use
-    jonsson_terms to get the actual terms.
-    
-    If the algebra has only one element, it returns 1.
-    For a lattice it returns 2.
-    
+    def find_isomorphism(a: "alg.BasicAlgebra", b: "alg.BasicAlgebra", backend: str = "ac", progress: Optional["progress.ProgressReporter"] = None) -> Optional[List[int]]: ...
+    """Find an isomorphism from one algebra to another.
+
     Args:
-        algebra: The algebra to check (BasicAlgebra)
-        
+        a: The domain algebra (BasicAlgebra)
+        b: The target algebra (BasicAlgebra)
+        backend: "ac" (default), "sac", "backtrack" or "sat" (with the sat feature)
+        progress: Optional ProgressReporter for progress and cancellation
+
     Returns:
-        The Jonsson level (minimal number of Jonsson terms minus 1), or -1 if not distributive
+        The list of images of the elements of a, or None if the algebras are not isomorphic
     """
-    
+
     @staticmethod
-    def find_nuf(algebra: "alg.BasicAlgebra", arity: int) -> Optional[str]: ...
-    """Find a near unanimity term (NUF) of the given arity.
-    
-    This will find a near unanimity term of the given arity if one exists;
-    otherwise it returns None.
-    
-    A near unanimity term of arity n is a term t(x₀, x₁, ..., xₙ₋₁) such that:
-    - t(y,x,x,...,x) = x
-    - t(x,y,x,...,x) = x
-    - ...
-    - t(x,x,x,...,y) = x
-    
+    def find_polymorphism(algebra: "alg.BasicAlgebra", arity: int, identities: List[Tuple[List[int], Union[int, List[int]]]] = [], backend: str = "ac", progress: Optional["progress.ProgressReporter"] = None) -> Optional[List[int]]: ...
+    """Find a polymorphism of an algebra satisfying linear identities.
+
     Args:
-        algebra: The algebra to check (BasicAlgebra)
-        arity: The arity of the NU term (must be at least 3)
-        
+        algebra: The algebra (BasicAlgebra)
+        arity: The arity of the polymorphism
+        identities: List of (lhs, rhs) pairs; lhs is a list of variable indices
+            and rhs is a variable index or another such list, e.g.
+            [([0, 1, 1], 0), ([1, 1, 0], 0)] for a Maltsev operation
+        backend: "ac" (default), "sac", "backtrack" or "sat" (with the sat feature)
+        progress: Optional ProgressReporter for progress and cancellation
+
     Returns:
-        The NU term as a string if one exists, None otherwise
-        
+        The Horner-encoded table of a polymorphism, or None if there is none
+
     Raises:
-        ValueError: If arity is less than 3 or there's an error during computation
+        ValueError: If the problem is too large or an identity is malformed
     """
-    
+
     @staticmethod
-    def matrix_power(alg: "alg.BasicAlgebra", k: int) -> "alg.BasicAlgebra": ...
-    """The matrix power algebra as defined in Hobby-McKenzie.
-    
-    Creates a matrix power algebra A^[k] from a given algebra A and power k.
-    This is a BasicAlgebra that contains:
-    - All operations from the power algebra A^k
-    - A binary left shift operation
-    
+    def is_polymorphism(algebra: "alg.BasicAlgebra", arity: int, table: List[int]) -> bool: ...
+    """Test whether a Horner-encoded table is a polymorphism of an algebra."""
+
+    class Relation:
+        """A finitary relation on {0, ..., n-1}: a set of tuples of a fixed arity."""
+        def __init__(self, universe_size: int, arity: int, tuples: List[List[int]]) -> None: ...
+        def universe_size(self) -> int: ...
+        def arity(self) -> int: ...
+        def tuples(self) -> List[List[int]]: ...
+        def contains(self, tuple: List[int]) -> bool: ...
+        def __contains__(self, tuple: List[int]) -> bool: ...
+        def __len__(self) -> int: ...
+        def __eq__(self, other: object) -> bool: ...
+        def __hash__(self) -> int: ...
+        def __str__(self) -> str: ...
+        def __repr__(self) -> str: ...
+
+    @staticmethod
+    def preserves(op: Any, relation: "alg.Relation") -> bool: ...
+    """Check whether an operation preserves a relation."""
+
+    @staticmethod
+    def inv(universe_size: int, operations: List[Any], max_arity: int, progress: Optional["progress.ProgressReporter"] = None) -> List["alg.Relation"]: ...
+    """The relations of arity at most max_arity preserved by all the operations.
+
+    These are the subuniverses of the powers of the algebra the operations
+    form, listed by arity, then size, then tuples.
+    """
+
+    @staticmethod
+    def pol(universe_size: int, relations: List["alg.Relation"], max_arity: int, progress: Optional["progress.ProgressReporter"] = None) -> List["alg.IntOperation"]: ...
+    """The operations of arity 1 to max_arity preserving all the relations.
+
+    The i-th operation of arity m is named p{m}_{i}.
+    """
+
+    @staticmethod
+    def pp_definable(relation: "alg.Relation", base: List["alg.Relation"], progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
+    """Test whether a relation is primitive positive definable from base relations.
+
+    This holds exactly when every polymorphism of the base of arity
+    len(relation) preserves the relation.
+    """
+
+    @staticmethod
+    def pp_closure(relation: "alg.Relation", base: List["alg.Relation"], progress: Optional["progress.ProgressReporter"] = None) -> "alg.Relation": ...
+    """The least relation containing a relation that is pp-definable from base relations."""
+
+    @staticmethod
+    def pp_counterexample(relation: "alg.Relation", base: List["alg.Relation"], progress: Optional["progress.ProgressReporter"] = None) -> Optional["alg.IntOperation"]: ...
+    """A polymorphism of the base that does not preserve the relation, or None if it is pp-definable."""
+
+    class BooleanClone:
+        """A clone on {0, 1} in Post's lattice, named as in Böhler et al."""
+        def name(self) -> str: ...
+        def basis(self) -> List["alg.IntOperation"]: ...
+        def contains(self, op: "alg.IntOperation") -> bool: ...
+        def is_subclone_of(self, other: "alg.BooleanClone") -> bool: ...
+
+    @staticmethod
+    def post_lattice(max_degree: int = 3) -> List["alg.BooleanClone"]: ...
+    """The clones on {0, 1}, with the chains S0^k, ... listed for k up to max_degree."""
+
+    @staticmethod
+    def classify_boolean_clone(operations: List["alg.IntOperation"]) -> str: ...
+    """The standard name of the clone on {0, 1} generated by the operations."""
+
+    @staticmethod
+    def find_digraph_homomorphism(g_edges: List[Tuple[int, int]], g_size: int, h_edges: List[Tuple[int, int]], h_size: int, backend: str = "ac", progress: Optional["progress.ProgressReporter"] = None) -> Optional[List[int]]: ...
+    """Find a homomorphism between digraphs given by edge lists.
+
+    With H the complete graph K3 this is a 3-coloring of G.
+
     Args:
-        alg: The root algebra to raise to a power (BasicAlgebra)
-        k: The power/exponent (number of copies)
-        
+        g_edges: The edges of G as (source, target) pairs
+        g_size: The number of vertices of G
+        h_edges: The edges of H
+        h_size: The number of vertices of H
+        backend: "ac" (default), "sac", "backtrack" or "sat" (with the sat feature)
+        progress: Optional ProgressReporter for progress and cancellation
+
     Returns:
-        A BasicAlgebra representing the matrix power algebra
-        
-    Raises:
-        ValueError: If k is not positive or there's an error during creation
+        The images of the vertices of G, or None if there is no homomorphism
     """
-    
+
     @staticmethod
-    def make_random_algebra(n: int, sim_type: "alg.SimilarityType") -> "alg.BasicAlgebra": ...
-    """Make a random algebra of a given similarity type.
-    
-    Creates a random algebra with the specified size and similarity type.
-    The operations are generated randomly.
-    
+    def find_digraph_polymorphism(edges: List[Tuple[int, int]], n: int, arity: int, identities: List[Tuple[List[int], Union[int, List[int]]]] = [], backend: str = "ac", progress: Optional["progress.ProgressReporter"] = None) -> Optional[List[int]]: ...
+    """Find a polymorphism of a digraph satisfying linear identities.
+
     Args:
-        n: The size of the algebra (cardinality of the universe)
-        sim_type: The similarity type (defines the operations)
-        
+        edges: The edges as (source, target) pairs
+        n: The number of vertices
+        arity: The arity of the polymorphism
+        identities: List of (lhs, rhs) pairs as for find_polymorphism
+        backend: "ac" (default), "sac", "backtrack" or "sat" (with the sat feature)
+        progress: Optional ProgressReporter for progress and cancellation
+
     Returns:
-        BasicAlgebra: A new random algebra
-        
-    Raises:
-        ValueError: If there's an error during creation
+        The Horner-encoded table of a polymorphism, or None if there is none
     """
-    
+
     @staticmethod
-    def make_random_algebra_with_seed(n: int, sim_type: "alg.SimilarityType", seed: Optional[int] = None) -> "alg.BasicAlgebra": ...
-    """Make a random algebra of a given similarity type with a seed.
-    
-    Creates a random algebra with the specified size and similarity type.
-    The operations are generated randomly using the provided seed for reproducibility.
-    
+    def represent_lattice(lattice: "lat.Poset", max_algebra_size: int, progress: Optional["progress.ProgressReporter"] = None) -> Optional["alg.BasicAlgebra"]: ...
+    """Search for an algebra whose congruence lattice is isomorphic to a lattice.
+
+    Experimental: the search is exhaustive for each size, but grows very
+    quickly with it.
+
     Args:
-        n: The size of the algebra (cardinality of the universe)
-        sim_type: The similarity type (defines the operations)
+        lattice: The lattice to represent (Poset)
+        max_algebra_size: The largest universe to try
+        progress: Optional ProgressReporter for progress and cancellation
+
+    Returns:
+        A unary algebra of the smallest possible size, or None if there is
+        none with at most max_algebra_size elements
+
+    Raises:
+        ValueError: If the poset is not a lattice
+    """
+
+    @staticmethod
+    def represent_subalgebra_lattice(lattice: "lat.Poset", max_size: int, progress: Optional["progress.ProgressReporter"] = None) -> Optional["alg.BasicAlgebra"]: ...
+    """Search for a unary algebra whose subalgebra lattice is isomorphic to a lattice.
+
+    Only distributive lattices occur, so any other lattice gives None at once.
+
+    Args:
+        lattice: The lattice to represent (Poset)
+        max_size: The largest universe to try, at most 8
+        progress: Optional ProgressReporter for progress and cancellation
+
+    Returns:
+        A unary algebra of the smallest possible size, or None if there is
+        none with at most max_size elements
+
+    Raises:
+        ValueError: If the poset is not a lattice or max_size is too large
+    """
+
+    class SubProductAlgebra:
+        """A subalgebra of a direct product, its elements tuples of factor elements."""
+        def name(self) -> str: ...
+        def cardinality(self) -> int: ...
+        def number_of_factors(self) -> int: ...
+        def get_universe_list(self) -> List["util.IntArray"]: ...
+        def get_element(self, index: int) -> Optional["util.IntArray"]: ...
+        def element_index(self, element: "util.IntArray") -> Optional[int]: ...
+        def to_basic_algebra(self) -> "alg.BasicAlgebra": ...
+        """An isomorphic BasicAlgebra on the indices of the elements."""
+        def con(self) -> "alg.CongruenceLatticeIntArray": ...
+        def __len__(self) -> int: ...
+
+    @staticmethod
+    def create_subdirect_product(homomorphisms: List["alg.Homomorphism"]) -> "alg.SubProductAlgebra": ...
+    """Construct the image of the map into the product of the ranges induced by homomorphisms with a common domain.
+
+    Args:
+        homomorphisms: Homomorphisms with the same domain
+
+    Returns:
+        The image, whose elements are the tuples of images
+
+    Raises:
+        ValueError: If the list is empty, the domains differ, a map is not a
+            homomorphism or one is not onto its range
+    """
+
+    class FiberProduct:
+        """The pullback {(a, b) : f(a) = g(b)} of two homomorphisms, with its projections."""
+        def algebra(self) -> "alg.SubProductAlgebra": ...
+        """The fiber product as a subalgebra of A x B."""
+        def first_projection(self) -> "alg.Homomorphism": ...
+        """The projection onto A, from the fiber product on the indices of its elements."""
+        def second_projection(self) -> "alg.Homomorphism": ...
+        """The projection onto B, from the fiber product on the indices of its elements."""
+        def pairs(self) -> List[Tuple[int, int]]: ...
+        def __len__(self) -> int: ...
+
+    @staticmethod
+    def fiber_product(f: "alg.Homomorphism", g: "alg.Homomorphism") -> "alg.FiberProduct": ...
+    """Construct the fiber product {(a, b) : f(a) = g(b)} of f: A -> C and g: B -> C.
+
+    Args:
+        f: A homomorphism A -> C
+        g: A homomorphism B -> C
+
+    Returns:
+        The fiber product as a subalgebra of A x B, with its two projections
+
+    Raises:
+        ValueError: If the ranges differ in size, no pair agrees or the maps
+            are not homomorphisms
+    """
+
+    class ProductConLat:
+        """Con(A x B) assembled from Con(A) and Con(B), checking for skew
+        congruences only when asked."""
+        def product_congruences(self) -> List["alg.Partition"]: ...
+        """The product congruences alpha x beta, with alpha varying slowest."""
+        def product_congruence(self, i: int, j: int) -> "alg.Partition": ...
+        """The product of the i-th congruence of A and the j-th of B."""
+        def is_skew_free(self, progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
+        """Whether every congruence of the product is a product congruence."""
+        def universe(self, progress: Optional["progress.ProgressReporter"] = None) -> List["alg.Partition"]: ...
+        """All congruences, computing Con(A x B) in full only if there are skew congruences."""
+        def skew_congruences(self, progress: Optional["progress.ProgressReporter"] = None) -> List["alg.Partition"]: ...
+        """The congruences of the product that are not products."""
+        def cardinality(self, progress: Optional["progress.ProgressReporter"] = None) -> int: ...
+        """The number of congruences of the product."""
+
+    @staticmethod
+    def product_conlat(con_a: "alg.CongruenceLattice", con_b: "alg.CongruenceLattice", product: "alg.ProductAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> "alg.ProductConLat": ...
+    """Assemble the congruence lattice of A x B from Con(A) and Con(B).
+
+    Args:
+        con_a: The congruence lattice of the first factor
+        con_b: The congruence lattice of the second factor
+        product: The product of the two factors, in that order
+        progress: Optional ProgressReporter for progress and cancellation
+
+    Returns:
+        A ProductConLat with the product congruences
+
+    Raises:
+        ValueError: If the product does not have two factors of the right sizes
+    """
+
+    @staticmethod
+    def free_spectrum(algebra: "alg.BasicAlgebra", up_to_n: int, callback: Optional[Callable[[str], Optional[bool]]] = None, progress: Optional["progress.ProgressReporter"] = None) -> List[int]: ...
+    """Compute the free spectrum |F(0)|, ..., |F(up_to_n)| of an algebra.
+
+    Args:
+        algebra: The algebra (BasicAlgebra)
+        up_to_n: The largest number of generators
+        callback: Called with progress messages; return True to cancel
+        progress: Optional ProgressReporter for progress and cancellation
+
+    Returns:
+        The sizes; shorter than up_to_n + 1 if cancelled
+    """
+
+    @staticmethod
+    def pn_sequence(algebra: "alg.BasicAlgebra", up_to_n: int, callback: Optional[Callable[[str], Optional[bool]]] = None, progress: Optional["progress.ProgressReporter"] = None) -> List[int]: ...
+    """Compute p_0, ..., p_up_to_n: the numbers of essentially n-ary term operations.
+
+    p_0 is the number of constant unary term operations.
+
+    Args:
+        algebra: The algebra (BasicAlgebra)
+        up_to_n: The largest arity
+        callback: Called with progress messages; return True to cancel
+        progress: Optional ProgressReporter for progress and cancellation
+
+    Returns:
+        The counts; shorter than up_to_n + 1 if cancelled
+    """
+
+    @staticmethod
+    def idempotent_reduct(algebra: "alg.BasicAlgebra", max_arity: int, callback: Optional[Callable[[str], Optional[bool]]] = None, progress: Optional["progress.ProgressReporter"] = None) -> "alg.BasicAlgebra": ...
+    """Compute the idempotent reduct of an algebra up to an arity.
+
+    The operations are the idempotent term operations of arity 2..max_arity
+    depending on all of their variables, named t<k>_<i>.
+
+    Args:
+        algebra: The algebra (BasicAlgebra)
+        max_arity: The largest arity of the operations
+        callback: Called with progress messages; return True to cancel
+        progress: Optional ProgressReporter for progress and cancellation
+
+    Returns:
+        The reduct, named Id(<name>)
+
+    Raises:
+        ValueError: If a clone is too large or the callback cancelled the
+            computation
+        CancelledError: If progress cancelled the computation
+    """
+
+    @staticmethod
+    def si_members_up_to(algebra: "alg.BasicAlgebra", max_size: int, max_gens: Optional[int] = None, progress: Optional["progress.ProgressReporter"] = None) -> List["alg.BasicAlgebra"]: ...
+    """Find the subdirectly irreducible members of the variety generated by an
+    algebra with at most max_size elements, up to isomorphism.
+
+    The SIs are found among the quotients of the free algebra on max_gens
+    generators, which contains every SI generated by max_gens elements.
+
+    Args:
+        algebra: The algebra generating the variety
+        max_size: The largest size of the SIs
+        max_gens: The number of free generators; defaults to max_size
+        progress: Optional ProgressReporter for progress and cancellation
+
+    Returns:
+        The SIs, ordered by size
+
+    Raises:
+        ValueError: If max_gens is 0, the free algebra is too large or the
+            computation was cancelled
+    """
+
+    @staticmethod
+    def splitting_pairs(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> List[Tuple["alg.BasicAlgebra", List["alg.BasicAlgebra"]]]: ...
+    """Find the splitting pairs of the congruence distributive variety
+    generated by a finite algebra.
+
+    Args:
+        algebra: An algebra with Jonsson terms
+        progress: Optional ProgressReporter for progress and cancellation
+
+    Returns:
+        For each splitting algebra S, the SIs T with S not in HS(T), which
+        generate its conjugate variety
+
+    Raises:
+        ValueError: If the variety is not congruence distributive or the
+            computation was cancelled
+    """
+
+    @staticmethod
+    def in_hs(s: "alg.BasicAlgebra", t: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
+    """Decide whether s is isomorphic to a quotient of a subalgebra of t."""
+
+    class SubdirectlyIrreducibles:
+        """Lazy iterator over subdirectly irreducible algebras and their monoliths."""
+        def __iter__(self) -> "alg.SubdirectlyIrreducibles": ...
+        def __next__(self) -> Tuple["alg.BasicAlgebra", "alg.Partition"]: ...
+
+    @staticmethod
+    def subdirectly_irreducibles_in_hs(algebra: "alg.BasicAlgebra") -> "alg.SubdirectlyIrreducibles": ...
+    """Enumerate the subdirectly irreducible members of HS(algebra) up to
+    isomorphism, lazily: the SI quotients of its subalgebras.
+
+    Args:
+        algebra: The algebra
+
+    Returns:
+        An iterator of (BasicAlgebra, Partition) pairs, each SI with its monolith
+
+    Raises:
+        ValueError: If the subuniverses could not be computed
+    """
+
+    @staticmethod
+    def subdirectly_irreducibles_in_variety(algebra: "alg.BasicAlgebra", max_size: int) -> "alg.SubdirectlyIrreducibles": ...
+    """Enumerate the subdirectly irreducible members of the variety generated
+    by an algebra with at most max_size elements up to isomorphism, lazily.
+
+    The SIs are found among the quotients of the free algebras on 1, 2, ...,
+    max_size generators in turn, so SIs with few generators come first.
+    Iterating raises ValueError if a free algebra is too large.
+
+    Args:
+        algebra: The algebra generating the variety
+        max_size: The largest size of the SIs
+
+    Returns:
+        An iterator of (BasicAlgebra, Partition) pairs, each SI with its monolith
+    """
+
+    class Monounary:
+        """A monounary algebra on {0, ..., n-1} with its cycle structure.
+
+        Congruences are computed directly from the map, much faster than the
+        general congruence lattice algorithm.
+        """
+        def __init__(self, map: List[int]) -> None: ...
+        @staticmethod
+        def from_algebra(algebra: "alg.BasicAlgebra") -> "alg.Monounary": ...
+        @staticmethod
+        def is_monounary(algebra: "alg.BasicAlgebra") -> bool: ...
+        def cardinality(self) -> int: ...
+        def apply(self, x: int) -> int: ...
+        def depths(self) -> List[int]: ...
+        def height(self) -> int: ...
+        def cycles(self) -> List[List[int]]: ...
+        def cycle_lengths(self) -> List[int]: ...
+        def components(self) -> List[List[int]]: ...
+        def component_partition(self) -> "alg.Partition": ...
+        def is_congruence(self, partition: "alg.Partition") -> bool: ...
+        def principal_congruence(self, a: int, b: int) -> "alg.Partition": ...
+        def congruences(self, progress: Optional["progress.ProgressReporter"] = None) -> List["alg.Partition"]: ...
+
+    @staticmethod
+    def maximal_proper_subuniverses(algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> List[FrozenSet[int]]: ...
+    """The maximal proper subuniverses of an algebra, ordered by size."""
+
+    class SubuniverseGraph:
+        """The subuniverses of an algebra with an edge S -> T whenever T is
+        generated by S and one more element.
+
+        Reachability is inclusion; the maximal proper subuniverses are the
+        proper vertices whose edges all lead to the whole algebra.
+        """
+        def __init__(self, algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> None: ...
+        def vertices(self) -> List[FrozenSet[int]]: ...
+        """The subuniverses, ordered by size; the last is the whole algebra."""
+        def edges(self) -> List[Tuple[int, int, int]]: ...
+        """The edges (i, j, a): vertex j is generated by vertex i and the element a."""
+        def successors(self, i: int) -> List[int]: ...
+        def maximal_proper(self) -> List[int]: ...
+        def to_dot(self) -> str: ...
+        def __len__(self) -> int: ...
+        def __repr__(self) -> str: ...
+
+    class SimplicityReport:
+        """The outcome of a simplicity check with its witness on failure; truthy when it holds."""
+        @property
+        def trivial(self) -> bool: ...
+        @property
+        def congruence(self) -> Optional["alg.Partition"]: ...
+        """A congruence other than 0 and 1, or None."""
+        @property
+        def subuniverse(self) -> Optional[List[int]]: ...
+        """A proper subuniverse with at least two elements, or None."""
+        def holds(self) -> bool: ...
+        def __bool__(self) -> bool: ...
+        def __str__(self) -> str: ...
+        def __repr__(self) -> str: ...
+
+    @staticmethod
+    def is_simple(algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
+    """Whether an algebra has at least two elements and no congruences but 0 and 1."""
+
+    @staticmethod
+    def is_strictly_simple(algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
+    """Whether an algebra is simple with no proper subuniverse of two or more elements."""
+
+    @staticmethod
+    def check_simple(algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> "alg.SimplicityReport": ...
+    """Check simplicity, with a nontrivial proper congruence as witness if not. Con(A) is not built."""
+
+    @staticmethod
+    def check_strictly_simple(algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> "alg.SimplicityReport": ...
+    """Check strict simplicity, with a congruence or subuniverse as witness if not.
+    Neither Con(A) nor Sub(A) is built."""
+
+    @staticmethod
+    def minimal_generating_sets(algebra: IntAlgebra, max_size: int, progress: Optional["progress.ProgressReporter"] = None) -> List[List[int]]: ...
+    """The generating sets with at most max_size elements none of whose elements
+    is generated by the others, ordered by size. Constants are not generators."""
+
+    @staticmethod
+    def minimum_generating_set(algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> List[int]: ...
+    """The lexicographically first generating set of least size."""
+
+    @staticmethod
+    def generating_rank(algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> int: ...
+    """The least size of a generating set, not counting constants."""
+
+    @staticmethod
+    def independent_sets(algebra: IntAlgebra, max_size: int, progress: Optional["progress.ProgressReporter"] = None) -> List[List[int]]: ...
+    """The sets with at most max_size elements none of whose elements is
+    generated by the others, starting with the empty set, ordered by size."""
+
+    @staticmethod
+    def is_independent(algebra: IntAlgebra, gens: List[int]) -> bool: ...
+    """Whether no element of gens is in the subalgebra generated by the others."""
+
+    @staticmethod
+    def is_independent_generating_set(algebra: IntAlgebra, gens: List[int]) -> bool: ...
+    """Whether gens generates the algebra and is independent, i.e. is a minimal generating set."""
+
+    class FiniteBasisCriterion:
+        """One finite basis theorem and whether its checked hypothesis holds."""
+        @property
+        def name(self) -> str: ...
+        @property
+        def holds(self) -> bool: ...
+        @property
+        def unchecked(self) -> Optional[str]: ...
+        """A further hypothesis the theorem needs that is not checked, or None."""
+        @property
+        def detail(self) -> str: ...
+        @property
+        def reference(self) -> str: ...
+        def __str__(self) -> str: ...
+        def __repr__(self) -> str: ...
+
+    class FiniteBasisReport:
+        """The outcome of the finite basis probes. The verdict is "finitely based",
+        "finitely based if <hypothesis>" or "unknown"; unknown never means nonfinitely based."""
+        @property
+        def algebra(self) -> str: ...
+        @property
+        def verdict(self) -> str: ...
+        @property
+        def condition(self) -> Optional[str]: ...
+        """The unchecked hypothesis of a conditional verdict, or None."""
+        @property
+        def criteria(self) -> List["alg.FiniteBasisCriterion"]: ...
+        def is_finitely_based(self) -> bool: ...
+        def applicable(self) -> List["alg.FiniteBasisCriterion"]: ...
+        def __str__(self) -> str: ...
+        def __repr__(self) -> str: ...
+
+    @staticmethod
+    def finite_basis_probe(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> "alg.FiniteBasisReport": ...
+    """Check the hypotheses of known finite basis theorems (Lyndon, Oates-Powell,
+    Kruse-L'vov, McKenzie, Perkins, Baker, Willard), cheapest first."""
+
+    @staticmethod
+    def is_known_finitely_based(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
+    """Whether the finite basis probes prove the generated variety finitely based; False means unknown."""
+
+    class CongruenceRegularityReport:
+        """The outcome of a congruence regularity, uniformity or coherence check with its
+        counterexample on failure; truthy when the property is known to hold."""
+        @property
+        def property_name(self) -> str: ...
+        """"regular", "uniform" or "coherent"."""
+        @property
+        def verdict(self) -> Optional[bool]: ...
+        """Whether the property holds, or None when a variety check is inconclusive."""
+        @property
+        def witness_kind(self) -> Optional[str]: ...
+        """"shared block", "non-uniform" or "not a union of blocks", or None."""
+        @property
+        def congruences(self) -> List["alg.Partition"]: ...
+        """The congruences of the counterexample."""
+        @property
+        def block(self) -> Optional[List[int]]: ...
+        @property
+        def subuniverse(self) -> Optional[List[int]]: ...
+        @property
+        def free_generators(self) -> Optional[List[int]]: ...
+        """The indices of x, y, z when the counterexample lives in F_V(3), or None."""
+        def holds(self) -> bool: ...
+        def __bool__(self) -> bool: ...
+        def __str__(self) -> str: ...
+        def __repr__(self) -> str: ...
+
+    @staticmethod
+    def check_congruence_regular(algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> "alg.CongruenceRegularityReport": ...
+    """Check that no two congruences share a block, with such a pair as witness if not."""
+
+    @staticmethod
+    def check_congruence_uniform(algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> "alg.CongruenceRegularityReport": ...
+    """Check that the blocks of each congruence have the same size."""
+
+    @staticmethod
+    def check_congruence_coherent(algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> "alg.CongruenceRegularityReport": ...
+    """Check that a subuniverse containing a block of a congruence is a union of its blocks."""
+
+    @staticmethod
+    def check_variety_congruence_regular(algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> "alg.CongruenceRegularityReport": ...
+    """Csakany's condition for the generated variety, tested in F_V(3)."""
+
+    @staticmethod
+    def check_variety_congruence_uniform(algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> "alg.CongruenceRegularityReport": ...
+    """Look for a counterexample to uniformity of the generated variety; the verdict is False or None."""
+
+    @staticmethod
+    def check_variety_congruence_coherent(algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> "alg.CongruenceRegularityReport": ...
+    """Geiger's condition for the generated variety, tested in F_V(3)."""
+
+    @staticmethod
+    def is_congruence_regular(algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
+
+    @staticmethod
+    def is_congruence_uniform(algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
+
+    @staticmethod
+    def is_congruence_coherent(algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
+
+    @staticmethod
+    def variety_is_congruence_regular(algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
+
+    @staticmethod
+    def variety_is_congruence_coherent(algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
+
+    class DpcLevel:
+        """The Mal'cev chain length and term depth needed to define principal congruences in A^k."""
+        @property
+        def power(self) -> int: ...
+        @property
+        def size(self) -> int: ...
+        @property
+        def chain_length(self) -> int: ...
+        @property
+        def term_depth(self) -> int: ...
+        @property
+        def witness(self) -> Optional[List[int]]: ...
+        """Indices (u, v, x, y) where (x, y) needs the longest chain in Cg(u, v)."""
+        def __repr__(self) -> str: ...
+
+    class DpcReport:
+        """The outcome of the definable principal congruences probe."""
+        @property
+        def levels(self) -> List["alg.DpcLevel"]: ...
+        def found(self) -> bool: ...
+        @property
+        def scheme(self) -> Optional[Tuple[int, int]]: ...
+        """(chain_length, term_depth) of the defining scheme, or None."""
+        @property
+        def formula(self) -> Optional[str]: ...
+        def __str__(self) -> str: ...
+        def __repr__(self) -> str: ...
+
+    @staticmethod
+    def dpc_probe(algebra: IntAlgebra, max_power: int = 2, progress: Optional["progress.ProgressReporter"] = None) -> "alg.DpcReport": ...
+    """Bounded search for a scheme defining principal congruences in A, ..., A^max_power;
+    a scheme found suggests, but does not prove, DPC for the variety."""
+
+    @staticmethod
+    def dpc_level(algebra: IntAlgebra, k: int, progress: Optional["progress.ProgressReporter"] = None) -> "alg.DpcLevel": ...
+
+    class ArithmeticalAnalysis:
+        """Whether the variety generated by an algebra is arithmetical, with the Pixley term."""
+        def holds(self) -> bool: ...
+        @property
+        def pixley_term(self) -> Optional[str]: ...
+        def __bool__(self) -> bool: ...
+        def __repr__(self) -> str: ...
+
+    class DiscriminatorAnalysis:
+        """Whether the variety generated by an algebra is a discriminator variety."""
+        def holds(self) -> bool: ...
+        def arithmetical(self) -> bool: ...
+        @property
+        def pixley_term(self) -> Optional[str]: ...
+        @property
+        def discriminator_term(self) -> Optional[str]: ...
+        """A term that is the discriminator on every SI in HS(A), or None."""
+        @property
+        def subdirectly_irreducibles(self) -> List["alg.BasicAlgebra"]: ...
+        @property
+        def non_quasiprimal(self) -> Optional["alg.BasicAlgebra"]: ...
+        """The first SI found that is not quasiprimal, or None."""
+        def __bool__(self) -> bool: ...
+        def __repr__(self) -> str: ...
+
+    @staticmethod
+    def is_arithmetical(algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> "alg.ArithmeticalAnalysis": ...
+    """Whether V(A) is congruence distributive and permutable, via a Pixley term search."""
+
+    @staticmethod
+    def is_discriminator_variety(algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> "alg.DiscriminatorAnalysis": ...
+    """Whether V(A) is arithmetical with quasiprimal SIs sharing a discriminator term."""
+
+    @staticmethod
+    def quasiprimal_term(algebra: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
+
+    class HereditaryProperty:
+        """The built-in properties check_hereditary can evaluate."""
+        CON_CARDINALITY: "alg.HereditaryProperty"
+        CON_DISTRIBUTIVE: "alg.HereditaryProperty"
+        SIMPLE: "alg.HereditaryProperty"
+        MALCEV_TERM: "alg.HereditaryProperty"
+        MAJORITY_TERM: "alg.HereditaryProperty"
+        CONGRUENCE_DISTRIBUTIVE: "alg.HereditaryProperty"
+        CONGRUENCE_MODULAR: "alg.HereditaryProperty"
+        TAYLOR_TERM: "alg.HereditaryProperty"
+        JONSSON_LEVEL: "alg.HereditaryProperty"
+
+    class ClosureOperator:
+        """Subalgebras (S), quotients (H) and bounded powers (P)."""
+        S: "alg.ClosureOperator"
+        H: "alg.ClosureOperator"
+        P: "alg.ClosureOperator"
+
+    class HereditaryRow:
+        """The result of the property on one member of the class."""
+        @property
+        def member(self) -> str: ...
+        @property
+        def size(self) -> int: ...
+        @property
+        def value(self) -> Optional[Union[bool, int]]: ...
+        @property
+        def error(self) -> Optional[str]: ...
+        def __repr__(self) -> str: ...
+
+    class HereditaryReport:
+        """The table of results of check_hereditary."""
+        @property
+        def property(self) -> str: ...
+        @property
+        def closure(self) -> str: ...
+        @property
+        def rows(self) -> List["alg.HereditaryRow"]: ...
+        def holds(self) -> Optional[bool]: ...
+        def failures(self) -> List["alg.HereditaryRow"]: ...
+        def __len__(self) -> int: ...
+        def __str__(self) -> str: ...
+        def __repr__(self) -> str: ...
+
+    @staticmethod
+    def check_hereditary(algebra: IntAlgebra, property: Union["alg.HereditaryProperty", str, "eq.Equation"], closure: "alg.ClosureOperator", max_power: int = 2, progress: Optional["progress.ProgressReporter"] = None) -> "alg.HereditaryReport": ...
+    """Evaluate a property on every subalgebra, every quotient or the powers A, ..., A^max_power."""
+
+    class ClassMembers:
+        """A lazy iterator of subalgebras or quotients, up to isomorphism."""
+        def __iter__(self) -> "alg.ClassMembers": ...
+        def __next__(self) -> "alg.BasicAlgebra": ...
+
+    class Powers:
+        """A lazy iterator of the powers A, A^2, ..., A^k."""
+        def __iter__(self) -> "alg.Powers": ...
+        def __next__(self) -> "alg.BasicAlgebra": ...
+
+    @staticmethod
+    def all_subalgebras(algebra: IntAlgebra) -> "alg.ClassMembers": ...
+    """The subalgebras of an algebra up to isomorphism, named "<A>-S<i>"."""
+
+    @staticmethod
+    def all_quotients(algebra: IntAlgebra) -> "alg.ClassMembers": ...
+    """The quotients of an algebra up to isomorphism, named "<A>-H<i>"."""
+
+    @staticmethod
+    def powers_up_to(algebra: IntAlgebra, k: int) -> "alg.Powers": ...
+    """The powers A, ..., A^k, each built when the iterator reaches it."""
+
+    class QuasivarietyAnalysis:
+        """Whether B is in SP(A), with an embedding or a counter quasi-equation."""
+        def holds(self) -> bool: ...
+        @property
+        def homomorphisms(self) -> List["alg.Homomorphism"]: ...
+        @property
+        def embedding(self) -> Optional[List[List[int]]]: ...
+        """For each element of B its tuple in A^k, one coordinate per homomorphism."""
+        @property
+        def unseparated(self) -> Optional[Tuple[int, int]]: ...
+        @property
+        def counter_quasi_equation(self) -> Optional["eq.QuasiEquation"]: ...
+        """The diagram of B implying that the unseparated pair is equal."""
+        def __bool__(self) -> bool: ...
+        def __repr__(self) -> str: ...
+
+    @staticmethod
+    def in_quasivariety(b: IntAlgebra, a: IntAlgebra, progress: Optional["progress.ProgressReporter"] = None) -> "alg.QuasivarietyAnalysis": ...
+    """Decide whether B is in SP(A) by searching for homomorphisms separating its points."""
+
+    class DualityProbe:
+        """The outcome of a natural duality probe."""
+        def holds(self) -> bool: ...
+        @property
+        def relation_arity(self) -> int: ...
+        @property
+        def max_n(self) -> int: ...
+        @property
+        def relations(self) -> List["alg.Relation"]: ...
+        @property
+        def failure(self) -> Optional["alg.IntOperation"]: ...
+        """A polymorphism of all the relations that is not a term operation."""
+        @property
+        def candidates(self) -> List[List["alg.Relation"]]: ...
+        """The minimal alter egos passing the interpolation condition."""
+        def __bool__(self) -> bool: ...
+        def __str__(self) -> str: ...
+        def __repr__(self) -> str: ...
+
+    @staticmethod
+    def probe_duality(algebra: IntAlgebra, relation_arity: int = 2, max_n: int = 3, max_relations: int = 2, progress: Optional["progress.ProgressReporter"] = None) -> "alg.DualityProbe": ...
+    """Experimental: search for alter egos passing the interpolation condition on A^n, n <= max_n."""
+
+    class PpInterpretation:
+        """A pp-interpretation of B in A: a subuniverse of A^d mapped onto B."""
+        @property
+        def dimension(self) -> int: ...
+        @property
+        def domain(self) -> List[List[int]]: ...
+        @property
+        def coordinates(self) -> List[int]: ...
+        def coordinate(self, tuple: List[int]) -> Optional[int]: ...
+        def __str__(self) -> str: ...
+        def __repr__(self) -> str: ...
+
+    @staticmethod
+    def pp_interprets(a: IntAlgebra, b: IntAlgebra, dimension_bound: int = 2, progress: Optional["progress.ProgressReporter"] = None) -> Optional["alg.PpInterpretation"]: ...
+    """Search for a pp-interpretation of B in A of dimension at most dimension_bound."""
+
+    @staticmethod
+    def zn_ring(n: int) -> "alg.BasicAlgebra": ...
+    """The ring Z_n with the operations add, neg, zero, mul and one."""
+
+    @staticmethod
+    def matrix_ring(dim: int, p: int) -> "alg.BasicAlgebra": ...
+    """The ring of dim x dim matrices over Z_p; a matrix is encoded by its entries in base p, row by row."""
+
+    @staticmethod
+    def zn_module(n: int, dim: int) -> "alg.BasicAlgebra": ...
+    """The module Z_n^dim, with add, neg, zero and a unary scalar operation s{r} for each r in Z_n."""
+
+    @staticmethod
+    def regular_module(ring: "alg.BasicAlgebra") -> "alg.BasicAlgebra": ...
+    """The left regular module of a ring, whose submodules are its left ideals."""
+
+    @staticmethod
+    def ideals(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> List[List[int]]: ...
+    """The ideals of a ring, or submodules of a module: the blocks of 0 of its congruences, ordered by size."""
+
+    @staticmethod
+    def ideal_of_congruence(algebra: "alg.BasicAlgebra", theta: "alg.Partition") -> List[int]: ...
+    """The ideal of a congruence: its block of 0."""
+
+    @staticmethod
+    def congruence_of_ideal(algebra: "alg.BasicAlgebra", ideal: List[int]) -> "alg.Partition": ...
+    """The congruence whose blocks are the cosets of an ideal. Raises ValueError if the set is not an ideal."""
+
+    @staticmethod
+    def analyze_batch(algebras: List["alg.BasicAlgebra"], analyses: List[Union[str, "eq.Equation"]], n_threads: Optional[int] = None, progress: Optional["progress.ProgressReporter"] = None) -> List[Dict[str, Any]]: ...
+    """Run analyses over many algebras in parallel, releasing the GIL.
+
+    Each analysis is an analysis name or an Equation, whose result is
+    whether it holds. The names are "con_cardinality", "con_distributive",
+    "simple", "malcev_term", "majority_term", "congruence_distributive",
+    "congruence_modular", "taylor_term" and "jonsson_level".
+
+    Args:
+        algebras: The algebras
+        analyses: The analyses to run on each algebra
+        n_threads: The number of worker threads; defaults to one per core
+        progress: Optional ProgressReporter for progress and cancellation
+
+    Returns:
+        One dict per algebra with its "algebra" name and the result of each
+        analysis keyed by its name or equation string. A failed analysis maps
+        to None and its message is in the dict under "errors".
+
+    Raises:
+        ValueError: If an analysis name is unknown or the batch was cancelled
+    """
+
+    class TermOperations:
+        """Lazy iterator over the term operations of an algebra up to some arity."""
+        def __iter__(self) -> "alg.TermOperations": ...
+        def __next__(self) -> "terms.TermOperationImp": ...
+
+    @staticmethod
+    def term_operations_up_to_arity(algebra: "alg.BasicAlgebra", k: int, max_count: Optional[int] = None) -> "alg.TermOperations": ...
+    """Enumerate the term operations of arity at most k of an algebra, lazily.
+
+    Each operation is table based and carries a term producing it in the
+    variables x0, x1, ...
+
+    Args:
+        algebra: The algebra (BasicAlgebra)
+        k: The largest arity
+        max_count: Stop after this many operations
+
+    Returns:
+        An iterator of TermOperationImp
+
+    Raises:
+        ValueError: If the tables of arity k are too large
+    """
+
+    @staticmethod
+    def are_term_equivalent(a: "alg.BasicAlgebra", b: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> Optional[Tuple[List[str], List[str]]]: ...
+    """Test whether two algebras on the same universe have the same clone.
+
+    Args:
+        a: The first algebra (BasicAlgebra)
+        b: The second algebra (BasicAlgebra)
+        progress: Optional ProgressReporter for progress and cancellation
+
+    Returns:
+        None if they are not term equivalent; otherwise terms of b producing
+        the operations of a, and terms of a producing the operations of b,
+        in the variables x0, x1, ...
+
+    Raises:
+        ValueError: If a clone is too large
+    """
+
+    @staticmethod
+    def are_polynomially_equivalent(a: "alg.BasicAlgebra", b: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> Optional[Tuple[List[str], List[str]]]: ...
+    """Test whether two algebras on the same universe have the same polynomial clone.
+
+    Args:
+        a: The first algebra (BasicAlgebra)
+        b: The second algebra (BasicAlgebra)
+        progress: Optional ProgressReporter for progress and cancellation
+
+    Returns:
+        None if they are not polynomially equivalent; otherwise the witness
+        polynomials, with the constant i written c{i}
+
+    Raises:
+        ValueError: If a clone is too large
+    """
+
+    @staticmethod
+    def jonsson_terms(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> Optional[List[str]]: ...
+    """Find Jonsson terms for the algebra.
+    
+    This returns a list of Jonsson terms witnessing congruence distributivity,
+    or None if the algebra does not generate a congruence distributive variety.
+    The returned terms are guaranteed to be the least number of terms possible.
+    
+    Args:
+        algebra: The algebra to check (BasicAlgebra)
+        progress: Optional ProgressReporter for progress and cancellation
+        
+    Returns:
+        List of Jonsson terms as strings if they exist, None otherwise
+    """
+    
+    @staticmethod
+    def jonsson_level(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> int: ...
+    """Get the Jonsson level for the algebra.
+    
+    If the algebra generates a distributive variety, this returns the minimal
+    number of Jonsson terms minus 1; otherwise it returns -1.
+    For congruence distributivity testing, it's probably better to use
+    jonsson_terms to get the actual terms.
+    
+    If the algebra has only one element, it returns 1.
+    For a lattice it returns 2.
+    
+    Args:
+        algebra: The algebra to check (BasicAlgebra)
+        progress: Optional ProgressReporter for progress and cancellation
+        
+    Returns:
+        The Jonsson level (minimal number of Jonsson terms minus 1), or -1 if not distributive
+    """
+    
+    @staticmethod
+    def find_nuf(algebra: "alg.BasicAlgebra", arity: int) -> Optional[str]: ...
+    """Find a near unanimity term (NUF) of the given arity.
+    
+    This will find a near unanimity term of the given arity if one exists;
+    otherwise it returns None.
+    
+    A near unanimity term of arity n is a term t(x₀, x₁, ..., xₙ₋₁) such that:
+    - t(y,x,x,...,x) = x
+    - t(x,y,x,...,x) = x
+    - ...
+    - t(x,x,x,...,y) = x
+    
+    Args:
+        algebra: The algebra to check (BasicAlgebra)
+        arity: The arity of the NU term (must be at least 3)
+        
+    Returns:
+        The NU term as a string if one exists, None otherwise
+        
+    Raises:
+        ValueError: If arity is less than 3 or there's an error during computation
+    """
+    
+    @staticmethod
+    def matrix_power(alg: "alg.BasicAlgebra", k: int) -> "alg.BasicAlgebra": ...
+    """The matrix power algebra as defined in Hobby-McKenzie.
+    
+    Creates a matrix power algebra A^[k] from a given algebra A and power k.
+    This is a BasicAlgebra that contains:
+    - All operations from the power algebra A^k
+    - A binary left shift operation
+    
+    Args:
+        alg: The root algebra to raise to a power (BasicAlgebra)
+        k: The power/exponent (number of copies)
+        
+    Returns:
+        A BasicAlgebra representing the matrix power algebra
+        
+    Raises:
+        ValueError: If k is not positive or there's an error during creation
+    """
+    
+    @staticmethod
+    def make_random_algebra(n: int, sim_type: "alg.SimilarityType") -> "alg.BasicAlgebra": ...
+    """Make a random algebra of a given similarity type.
+    
+    Creates a random algebra with the specified size and similarity type.
+    The operations are generated randomly.
+    
+    Args:
+        n: The size of the algebra (cardinality of the universe)
+        sim_type: The similarity type (defines the operations)
+        
+    Returns:
+        BasicAlgebra: A new random algebra
+        
+    Raises:
+        ValueError: If there's an error during creation
+    """
+    
+    @staticmethod
+    def make_random_algebra_with_seed(n: int, sim_type: "alg.SimilarityType", seed: Optional[int] = None) -> "alg.BasicAlgebra": ...
+    """Make a random algebra of a given similarity type with a seed.
+    
+    Creates a random algebra with the specified size and similarity type.
+    The operations are generated randomly using the provided seed for reproducibility.
+    
+    Args:
+        n: The size of the algebra (cardinality of the universe)
+        sim_type: The similarity type (defines the operations)
         seed: Optional seed for the random number generator (None means use random seed)
         
     Returns:
@@ -4423,6 +6588,102 @@ class alg:
         ValueError: If n > 9 or there's an error during creation
     """
 
+    @staticmethod
+    def algebra_from_permutation_group(perms: List[List[int]]) -> "alg.BasicAlgebra": ...
+    """Create the G-set of a list of permutations.
+
+    The result is the unary algebra on {0..n-1}, named "GSet", whose
+    operations g0, g1, ... are the permutations.
+
+    Args:
+        perms: The permutations, each a list of the images of 0..n-1
+
+    Returns:
+        A BasicAlgebra whose operations are the permutations
+
+    Raises:
+        ValueError: If the list is empty or an entry is not a permutation of the same size
+    """
+
+    @staticmethod
+    def cayley_algebra(group_table: List[List[int]]) -> "alg.BasicAlgebra": ...
+    """Create the left regular representation of a group.
+
+    The result is the unary algebra on the group, named "Cayley", with an
+    operation l<g>(x) = g * x for each element g.
+
+    Args:
+        group_table: The multiplication table; group_table[g][x] is g * x
+
+    Returns:
+        A BasicAlgebra whose operations are the left translations
+
+    Raises:
+        ValueError: If the table is not the table of a group
+    """
+
+    @staticmethod
+    def digraph_algebra(edges: List[Tuple[int, int]], n: int) -> "alg.BasicAlgebra": ...
+    """Create the graph algebra of a digraph.
+
+    The universe is the vertices 0..n-1 plus a zero n, and x * y = x if
+    (x, y) is an edge and x * y = n otherwise.
+
+    Args:
+        edges: The edges as (source, target) pairs
+        n: The number of vertices
+
+    Returns:
+        A BasicAlgebra of cardinality n + 1
+
+    Raises:
+        ValueError: If an edge is not between vertices below n
+    """
+
+    @staticmethod
+    def algebra_to_digraph(algebra: "alg.BasicAlgebra") -> List[Tuple[int, int]]: ...
+    """Recover the digraph of a graph algebra or a unary algebra.
+
+    For a unary algebra the edges are x -> f(x) for each operation f.
+
+    Args:
+        algebra: A unary algebra or a graph algebra
+
+    Returns:
+        The sorted list of edges
+
+    Raises:
+        ValueError: If the algebra is neither unary nor a graph algebra
+    """
+
+    @staticmethod
+    def boolean_algebra(n_atoms: int) -> "alg.BasicAlgebra": ...
+    """Create the Boolean algebra of subsets of n_atoms atoms, as bit masks.
+
+    The operations are join, meet, neg, zero and one.
+
+    Raises:
+        ValueError: If the tables would be too large
+    """
+
+    @staticmethod
+    def heyting_algebra_from_poset(poset: "lat.Poset") -> "alg.BasicAlgebra": ...
+    """Create the Heyting algebra of a finite distributive lattice.
+
+    The operations are join, meet, imp (relative pseudocomplement), zero and one.
+
+    Raises:
+        ValueError: If the poset is not a distributive lattice
+    """
+
+    @staticmethod
+    def is_boolean_algebra(algebra: "alg.BasicAlgebra") -> Optional[List[int]]: ...
+    """The complement map if two basic operations form a Boolean lattice, else None."""
+
+    @staticmethod
+    def is_heyting_algebra(algebra: "alg.BasicAlgebra") -> Optional[List[List[int]]]: ...
+    """The relative pseudocomplement table if two basic operations form a distributive lattice, else None."""
+
     @staticmethod
     def member_of_quasivariety(a: "alg.BasicAlgebra", b: "alg.BasicAlgebra") -> Optional[List["alg.Homomorphism"]]: ...
     """Test if algebra A is in the quasivariety generated by algebra B.
@@ -4574,113 +6835,123 @@ class alg:
     """
 
     @staticmethod
-    def malcev_term(algebra: "alg.BasicAlgebra") -> Optional[str]: ...
+    def malcev_term(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
     """Find a Malcev term for the algebra.
 
     Args:
         algebra: The algebra to check (BasicAlgebra)
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         The Malcev term as a string if one exists, None otherwise
     """
 
     @staticmethod
-    def majority_term(algebra: "alg.BasicAlgebra") -> Optional[str]: ...
+    def majority_term(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
     """Find a majority term for the algebra.
 
     Args:
         algebra: The algebra to check (BasicAlgebra)
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         The majority term as a string if one exists, None otherwise
     """
 
     @staticmethod
-    def minority_term(algebra: "alg.BasicAlgebra") -> Optional[str]: ...
+    def minority_term(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
     """Find a minority term for the algebra.
 
     Args:
         algebra: The algebra to check (BasicAlgebra)
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         The minority term as a string if one exists, None otherwise
     """
 
     @staticmethod
-    def pixley_term(algebra: "alg.BasicAlgebra") -> Optional[str]: ...
+    def pixley_term(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
     """Find a Pixley term for the algebra.
 
     Args:
         algebra: The algebra to check (BasicAlgebra)
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         The Pixley term as a string if one exists, None otherwise
     """
 
     @staticmethod
-    def nu_term(algebra: "alg.BasicAlgebra", arity: int) -> Optional[str]: ...
+    def nu_term(algebra: "alg.BasicAlgebra", arity: int, progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
     """Find a near unanimity term of the given arity.
 
     Args:
         algebra: The algebra to check (BasicAlgebra)
         arity: The arity of the NU term
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         The NU term as a string if one exists, None otherwise
     """
 
     @staticmethod
-    def nu_term_idempotent(algebra: "alg.BasicAlgebra", arity: int) -> bool: ...
+    def nu_term_idempotent(algebra: "alg.BasicAlgebra", arity: int, progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
     """Test if an idempotent algebra has an NU term of the given arity.
 
     Args:
         algebra: The idempotent algebra to check
         arity: The arity of the NU term
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         True if the algebra has an NU term, False otherwise
     """
 
     @staticmethod
-    def weak_nu_term(algebra: "alg.BasicAlgebra", arity: int) -> Optional[str]: ...
+    def weak_nu_term(algebra: "alg.BasicAlgebra", arity: int, progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
     """Find a weak near unanimity term of the given arity.
 
     Args:
         algebra: The algebra to check
         arity: The arity of the weak NU term
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         The weak NU term if one exists, None otherwise
     """
 
     @staticmethod
-    def weak_majority_term(algebra: "alg.BasicAlgebra") -> Optional[str]: ...
+    def weak_majority_term(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
     """Find a weak majority term for the algebra.
 
     Args:
         algebra: The algebra to check (BasicAlgebra)
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         The weak majority term as a string if one exists, None otherwise
     """
 
     @staticmethod
-    def semilattice_term(algebra: "alg.BasicAlgebra") -> Optional[str]: ...
+    def semilattice_term(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
     """Find a semilattice term for the algebra.
 
     Args:
         algebra: The algebra to check (BasicAlgebra)
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         The semilattice term as a string if one exists, None otherwise
     """
 
     @staticmethod
-    def difference_term(algebra: "alg.BasicAlgebra") -> Optional[str]: ...
+    def difference_term(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
     """Find a difference term for the algebra.
 
     Args:
         algebra: The algebra to check
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         The difference term if one exists, None otherwise
@@ -4698,110 +6969,193 @@ class alg:
     """
 
     @staticmethod
-    def hagemann_mitschke_terms(algebra: "alg.BasicAlgebra") -> Optional[List[str]]: ...
+    def hagemann_mitschke_terms(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> Optional[List[str]]: ...
     """Find Hagemann-Mitschke terms for the algebra.
 
     Args:
         algebra: The algebra to check (BasicAlgebra)
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         List of Hagemann-Mitschke terms as strings if they exist, None otherwise
     """
 
     @staticmethod
-    def gumm_terms(algebra: "alg.BasicAlgebra") -> Optional[List[str]]: ...
+    def gumm_terms(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> Optional[List[str]]: ...
     """Find Gumm terms for the algebra.
 
     Args:
         algebra: The algebra to check
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         List of Gumm terms if they exist, None otherwise
     """
 
     @staticmethod
-    def join_term(algebra: "alg.BasicAlgebra") -> Optional[str]: ...
+    def join_term(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
     """Get a join term (Kearnes-Kiss) for the algebra.
 
     Args:
         algebra: The algebra to check (BasicAlgebra)
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         The join term as a string if one exists, None otherwise
     """
 
     @staticmethod
-    def sd_meet_terms(algebra: "alg.BasicAlgebra") -> Optional[List[str]]: ...
+    def sd_meet_terms(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> Optional[List[str]]: ...
     """Find SD-meet terms for the algebra.
 
     Args:
         algebra: The algebra to check
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         List of SD-meet terms if they exist, None otherwise
     """
 
     @staticmethod
-    def sd_terms(algebra: "alg.BasicAlgebra") -> Optional[List[str]]: ...
+    def sd_terms(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> Optional[List[str]]: ...
     """Find SD terms for the algebra.
 
     Args:
         algebra: The algebra to check (BasicAlgebra)
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         List of SD terms as strings if they exist, None otherwise
     """
 
     @staticmethod
-    def markovic_mckenzie_siggers_taylor_term(algebra: "alg.BasicAlgebra") -> Optional[str]: ...
+    def markovic_mckenzie_siggers_taylor_term(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
     """Find the Markovic-McKenzie-Siggers-Taylor term for the algebra.
 
     Args:
         algebra: The algebra to check (BasicAlgebra)
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         The MMST term as a string if one exists, None otherwise
     """
 
+    class TaylorAnalysis:
+        """Whether an algebra has a Taylor term, with the witnessing term and identities."""
+        def has_taylor_term(self) -> bool: ...
+        def term(self) -> Optional[str]: ...
+        def identities(self) -> List["eq.Equation"]: ...
+        def __bool__(self) -> bool: ...
+
+    class TypeOmission:
+        """Whether the variety generated by an algebra omits a set of TCT types."""
+        def types(self) -> List[int]: ...
+        def omitted(self) -> Optional[bool]: ...
+        """True if all the types are omitted, False if one occurs, None if undecided."""
+        def reasons(self) -> List[str]: ...
+        """The Mal'cev conditions the verdict rests on."""
+        def __str__(self) -> str: ...
+
+    class SdMeetAnalysis:
+        """Whether the variety generated by an algebra is congruence meet-semidistributive,
+        with the SD-meet terms or, for an idempotent algebra, a failure witness."""
+        def holds(self) -> bool: ...
+        def terms(self) -> Optional[List[str]]: ...
+        def failure(self) -> Optional[List[int]]: ...
+        def __bool__(self) -> bool: ...
+
+    class MalcevAnalysis:
+        """The Mal'cev condition terms found for an algebra, by kind."""
+        def algebra(self) -> str: ...
+        def size(self) -> int: ...
+        def terms(self) -> Dict[str, Optional[List[str]]]: ...
+        def properties(self) -> Dict[str, bool]: ...
+        """Properties decided alongside the terms; searching for "sd-meet" records
+        "variety_sd_meet", "con_sd_meet" and "con_sd_join", "pixley" records
+        "arithmetical" and "discriminator" records "discriminator_variety"."""
+        def omitted_types(self) -> List["alg.TypeOmission"]: ...
+        """The sets of TCT types the variety omits, as far as the terms searched for
+        decide; empty if none of "taylor", "sd-meet", "sd", "gumm" and
+        "hagemann-mitschke" was searched."""
+        def to_json(self) -> str: ...
+        """The versioned JSON representation (kind "malcev_report")."""
+        def to_latex(self) -> str: ...
+        """The terms as a LaTeX `tabular`, one row per kind."""
+
+    @staticmethod
+    def malcev_analysis(algebra: "alg.BasicAlgebra", kinds: Optional[List[str]] = None, nu_arity: int = 3, progress: Optional["progress.ProgressReporter"] = None) -> "alg.MalcevAnalysis": ...
+    """Search an algebra for Mal'cev condition terms.
+
+    Args:
+        algebra: The algebra to check (BasicAlgebra)
+        kinds: The kinds of terms to look for, for example "malcev" or
+            "jonsson"; all kinds by default
+        nu_arity: The arity of the near unanimity term for "nu"
+        progress: Optional ProgressReporter for progress and cancellation
+
+    Returns:
+        A MalcevAnalysis with the terms found for each kind
+    """
+
     @staticmethod
-    def weak_3_edge_term(algebra: "alg.BasicAlgebra") -> Optional[str]: ...
+    def has_taylor_term(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> "alg.TaylorAnalysis": ...
+    """Decide whether a finite algebra has a Taylor term.
+
+    The witness is an idempotent 4-ary term t with t(y,x,x,x) = t(x,x,y,y)
+    and t(x,x,y,x) = t(x,y,x,x), which exists exactly when the algebra has
+    a Taylor term.
+
+    Args:
+        algebra: The algebra to check (BasicAlgebra)
+        progress: Optional ProgressReporter for progress and cancellation
+
+    Returns:
+        A TaylorAnalysis with the witnessing term and the identities it satisfies
+    """
+
+    @staticmethod
+    def weak_3_edge_term(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
     """Find a weak 3-edge term for the algebra.
 
     Args:
         algebra: The algebra to check
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         The weak 3-edge term if one exists, None otherwise
     """
 
     @staticmethod
-    def is_congruence_dist_idempotent(algebra: "alg.BasicAlgebra") -> bool: ...
+    def is_congruence_dist_idempotent(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
     """Test if an idempotent algebra is congruence distributive.
 
     Args:
         algebra: The idempotent algebra to check
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         True if the algebra is congruence distributive, False otherwise
     """
 
     @staticmethod
-    def is_congruence_modular_idempotent(algebra: "alg.BasicAlgebra") -> bool: ...
+    def is_congruence_modular_idempotent(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
     """Test if an idempotent algebra is congruence modular.
 
     Args:
         algebra: The idempotent algebra to check
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         True if the algebra is congruence modular, False otherwise
     """
 
     @staticmethod
-    def congruence_modular_variety(algebra: "alg.BasicAlgebra") -> bool: ...
+    def congruence_modular_variety(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
     """Test if the variety generated by the algebra is congruence modular.
 
     Args:
         algebra: The algebra to check
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         True if the variety is congruence modular, False otherwise
@@ -4819,7 +7173,7 @@ class alg:
     """
 
     @staticmethod
-    def local_distributivity_level(a: int, b: int, c: int, algebra: "alg.BasicAlgebra") -> int: ...
+    def local_distributivity_level(a: int, b: int, c: int, algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> int: ...
     """Compute the local distributivity level for three elements.
 
     Args:
@@ -4827,13 +7181,14 @@ class alg:
         b: Second element index
         c: Third element index
         algebra: The algebra
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         The local distributivity level, or -1 if (a,c) is not in the join
     """
 
     @staticmethod
-    def day_quadruple(a: int, b: int, c: int, d: int, algebra: "alg.BasicAlgebra") -> bool: ...
+    def day_quadruple(a: int, b: int, c: int, d: int, algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
     """Check if a, b, c, d form a Day quadruple in the algebra.
 
     Note: This is a lower-level function that requires working with congruence lattices.
@@ -4842,35 +7197,68 @@ class alg:
     Args:
         a, b, c, d: Four element indices
         algebra: The algebra
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         True if a Day quadruple exists, False otherwise
     """
 
     @staticmethod
-    def find_day_quadruple_in_square(algebra: "alg.BasicAlgebra") -> Optional[List[int]]: ...
+    def find_day_quadruple_in_square(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> Optional[List[int]]: ...
     """Find a Day quadruple in the square of the algebra.
 
     Args:
         algebra: The algebra to check
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         A tuple (x0, x1, y0, y1) if a Day quadruple is found, None otherwise
     """
 
     @staticmethod
-    def sd_meet_idempotent(algebra: "alg.BasicAlgebra") -> Optional[List[int]]: ...
+    def omitted_types(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> List["alg.TypeOmission"]: ...
+    """Decide which sets of TCT types the generated variety omits: {1}, {1,2},
+    {1,5}, {1,2,5}, {1,4,5} and {1,2,4,5}.
+
+    Uses the Hobby-McKenzie characterizations: omitting 1 is having a Taylor
+    term, omitting 1, 2 is SD-meet and omitting 1, 2, 5 is SD-join; Gumm terms
+    imply omitting 1, 5 and Hagemann-Mitschke terms omitting 1, 4, 5.
+
+    Args:
+        algebra: The algebra to check (BasicAlgebra)
+        progress: Optional ProgressReporter for progress and cancellation
+
+    Returns:
+        A TypeOmission for each set of types
+    """
+
+    @staticmethod
+    def variety_is_sd_meet(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> "alg.SdMeetAnalysis": ...
+    """Test whether the variety generated by the algebra is congruence meet-semidistributive.
+
+    Args:
+        algebra: The algebra to check (BasicAlgebra)
+        progress: Optional ProgressReporter for progress and cancellation
+
+    Returns:
+        An SdMeetAnalysis with the SD-meet terms, or a failure witness for
+        an idempotent algebra
+    """
+
+    @staticmethod
+    def sd_meet_idempotent(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> Optional[List[int]]: ...
     """Find a witness for SD-meet failure in an idempotent algebra.
 
     Args:
         algebra: The idempotent algebra to check
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         A tuple [x, y] if a witness is found, None otherwise
     """
 
     @staticmethod
-    def cyclic_term_idempotent(algebra: "alg.BasicAlgebra", arity: int) -> bool: ...
+    def cyclic_term_idempotent(algebra: "alg.BasicAlgebra", arity: int, progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
     """Test if the algebra admits a cyclic term of the given arity.
 
     This implements an algorithm of Valeriote and Willard for testing if
@@ -4879,13 +7267,44 @@ class alg:
     Args:
         algebra: The algebra (must be idempotent)
         arity: The arity of the cyclic term (must be at least 2)
+        progress: Optional ProgressReporter for progress and cancellation
+
+    Returns:
+        True if a cyclic term exists, False otherwise
+    """
+
+    @staticmethod
+    def has_cyclic_term(algebra: "alg.BasicAlgebra", p: int, progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
+    """Test if the algebra has a cyclic term of arity p.
+
+    A cyclic term is an idempotent term with t(x1,...,xp) = t(x2,...,xp,x1).
+    Idempotent algebras use the algorithm of Valeriote and Willard; other
+    algebras search the term operations as find_cyclic_term does.
+
+    Args:
+        algebra: The algebra (BasicAlgebra)
+        p: The arity of the cyclic term (must be at least 2)
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         True if a cyclic term exists, False otherwise
     """
 
     @staticmethod
-    def primality_terms(algebra: "alg.BasicAlgebra") -> Optional[List[str]]: ...
+    def find_cyclic_term(algebra: "alg.BasicAlgebra", p: int, progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
+    """Find a cyclic term of arity p in the variables x0, ..., x{p-1}.
+
+    Args:
+        algebra: The algebra (BasicAlgebra)
+        p: The arity of the cyclic term (must be at least 2)
+        progress: Optional ProgressReporter for progress and cancellation
+
+    Returns:
+        The cyclic term as a string if one exists, None otherwise
+    """
+
+    @staticmethod
+    def primality_terms(algebra: "alg.BasicAlgebra", progress: Optional["progress.ProgressReporter"] = None) -> Optional[List[str]]: ...
     """Find primality terms for the algebra.
 
     This gives unary terms evaluating to the characteristic functions of the one element
@@ -4894,30 +7313,33 @@ class alg:
 
     Args:
         algebra: The algebra to check (BasicAlgebra)
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         List of primality terms as strings if they exist, None otherwise
     """
 
     @staticmethod
-    def fixed_k_edge_term(algebra: "alg.BasicAlgebra", k: int) -> Optional[str]: ...
+    def fixed_k_edge_term(algebra: "alg.BasicAlgebra", k: int, progress: Optional["progress.ProgressReporter"] = None) -> Optional[str]: ...
     """Find a k-edge term for the algebra.
 
     Args:
         algebra: The algebra to check (BasicAlgebra)
         k: The parameter k (edge term will have arity k+1)
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         The k-edge term as a string if one exists, None otherwise
     """
 
     @staticmethod
-    def fixed_k_qwnu(algebra: "alg.BasicAlgebra", arity: int) -> bool: ...
+    def fixed_k_qwnu(algebra: "alg.BasicAlgebra", arity: int, progress: Optional["progress.ProgressReporter"] = None) -> bool: ...
     """Test if an algebra has a quasi weak near unanimity (QWNU) term of the given arity.
 
     Args:
         algebra: The algebra to test (BasicAlgebra)
         arity: The arity of the QWNU term (must be at least 2)
+        progress: Optional ProgressReporter for progress and cancellation
 
     Returns:
         True if the algebra has a QWNU term of the given arity, False otherwise
@@ -4930,6 +7352,41 @@ class alg:
 class util:
     """Utility module for utility functions and classes."""
     
+    @staticmethod
+    def set_global_seed(seed: Optional[int]) -> None: ...
+    """Seed the random source of all randomized routines; None clears the seed."""
+
+    @staticmethod
+    def encode_tuple(tuple: List[int], radices: List[int]) -> int: ...
+    """Encode a tuple in mixed radix, the first coordinate varying fastest.
+
+    Args:
+        tuple: The coordinates, each below its radix
+        radices: The radix of each coordinate
+
+    Returns:
+        The code, below the product of the radices
+
+    Raises:
+        ValueError: If the lengths differ, a coordinate is out of range or
+            the code does not fit in 128 bits
+    """
+
+    @staticmethod
+    def decode_tuple(code: int, radices: List[int]) -> List[int]: ...
+    """Decode a mixed radix code into its tuple.
+
+    Args:
+        code: The code
+        radices: The radix of each coordinate
+
+    Returns:
+        The coordinates
+
+    Raises:
+        ValueError: If the code is not below the product of the radices
+    """
+
     class IntArray:
         """Python wrapper for IntArray."""
         def __init__(self, size: int) -> None: ...
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use std::fmt::{Debug, Display};
 use std::hash::Hash;
-use crate::alg::op::{Operation, OperationSymbol, SimilarityType};
+use crate::alg::op::{Operation, OperationSymbol, SimilarityType, TableStyle};
 
 /// Constants for cardinality values, matching Java implementation
 pub const CARDINALITY_UNKNOWN: i32 = -1;
@@ -50,6 +50,15 @@ pub trait Algebra: Display + Debug + Send + Sync {
     /// * Negative constant for infinite/unknown cardinalities
     fn cardinality(&self) -> i32;
     
+    /// Returns the exact cardinality of the algebra, even when it does not
+    /// fit in an `i32`.
+    /// 
+    /// The default converts [`Algebra::cardinality`]; products and powers
+    /// override it to compute the value from their factors.
+    fn exact_cardinality(&self) -> crate::alg::Cardinality {
+        crate::alg::Cardinality::from_i32(self.cardinality())
+    }
+    
     /// Calculate the input size for this algebra.
     /// 
     /// This is the sum of the cardinality raised to the power of each
@@ -192,6 +201,46 @@ pub trait Algebra: Display + Debug + Send + Sync {
     /// # Arguments
     /// * `monitor` - The progress monitor to use
     fn set_monitor(&mut self, monitor: Option<Box<dyn ProgressMonitor>>);
+
+    /// Get a human-readable summary of this algebra: its name, size and
+    /// description followed by the table of each operation.
+    /// 
+    /// # Returns
+    /// The summary as plain text
+    fn describe(&self) -> String {
+        self.describe_as(TableStyle::Text)
+    }
+
+    /// Get the summary of [`describe`](Self::describe) in the given style.
+    /// 
+    /// Elements are printed as their indices. An operation whose table
+    /// cannot be computed is reported in place of its table.
+    /// 
+    /// # Arguments
+    /// * `style` - The output format of the summary and tables
+    /// 
+    /// # Returns
+    /// The summary, ending in a newline
+    fn describe_as(&self, style: TableStyle) -> String {
+        let title = format!("Algebra {} of size {}", self.name(), self.cardinality());
+        let (title, comment) = match style {
+            TableStyle::Text => (title, ""),
+            TableStyle::Markdown => (format!("**{}**", title), ""),
+            TableStyle::Latex => (format!("% {}", title), "% "),
+        };
+        let mut out = format!("{}\n", title);
+        if let Some(desc) = self.description() {
+            out.push_str(&format!("{}{}\n", comment, desc));
+        }
+        for op in self.operations() {
+            out.push('\n');
+            match op.format_table(style) {
+                Ok(table) => out.push_str(&table),
+                Err(e) => out.push_str(&format!("{}{}: {}\n", comment, op.symbol().name(), e)),
+            }
+        }
+        out
+    }
 }
 
 /// Helper trait for algebras that need to be cloned.
@@ -405,6 +454,17 @@ mod tests {
         assert!(display_string.contains("GeneralAlgebra"));
     }
 
+    #[test]
+    fn test_describe() {
+        use crate::alg::op::operations::make_int_operation;
+        let neg = make_int_operation(OperationSymbol::new("-", 1, false), 2, vec![1, 0]).unwrap();
+        let mut alg = BasicAlgebra::new("N".to_string(), (0..2).collect::<HashSet<i32>>(), vec![neg]);
+        alg.set_description(Some("negation".to_string()));
+        assert_eq!(alg.describe(), "Algebra N of size 2\nnegation\n\nx | -\n--+--\n0 | 1\n1 | 0\n");
+        let latex = alg.describe_as(TableStyle::Latex);
+        assert!(latex.starts_with("% Algebra N of size 2\n% negation\n\n\\begin{tabular}{c|c}"));
+    }
+
     /// Test clone functionality
     #[test]
     fn test_clone_functionality() {
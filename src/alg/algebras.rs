@@ -7,9 +7,12 @@
 use crate::alg::op::Operation;
 use crate::alg::SmallAlgebra;
 use crate::alg::algebra::Algebra;
-use crate::alg::op::operations::{commutes_unary, commutes_map, make_binary_left_shift, make_int_operations, power, ternary_discriminator};
+use crate::alg::op::operations::{commutes_unary, commutes_map, make_binary_left_shift, make_int_operation, make_int_operations, power, ternary_discriminator};
+use crate::alg::op::OperationSymbol;
 use crate::alg::{PowerAlgebra, BasicAlgebra, Homomorphism};
 use crate::alg::conlat::partition::Partition;
+use crate::lat::Poset;
+use crate::terms::compiled_term::operation_table;
 use crate::util::int_array::{IntArray, IntArrayTrait};
 use std::collections::{HashSet, BTreeSet, HashMap};
 use std::sync::Arc;
@@ -581,6 +584,333 @@ pub fn full_transformation_semigroup(
     Ok(BasicAlgebra::new(name, universe, ops))
 }
 
+/// Create the G-set of a list of permutations: the unary algebra on
+/// {0..n-1} whose operations `g0, g1, ...` are the permutations.
+///
+/// # Arguments
+/// * `perms` - The permutations, each a list of the images of 0..n-1
+///
+/// # Returns
+/// * `Ok(BasicAlgebra)` - The unary algebra, named `GSet`
+/// * `Err(String)` - If the list is empty, the permutations have different
+///   sizes or one of them is not a permutation
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::{algebras, Algebra};
+///
+/// // S3 acting on 3 points, generated by a transposition and a 3-cycle
+/// let alg = algebras::algebra_from_permutation_group(&[vec![1, 0, 2], vec![1, 2, 0]]).unwrap();
+/// assert_eq!(alg.cardinality(), 3);
+/// assert_eq!(alg.operations().len(), 2);
+/// ```
+pub fn algebra_from_permutation_group(perms: &[Vec<i32>]) -> Result<BasicAlgebra<i32>, String> {
+    let n = match perms.first() {
+        Some(perm) => perm.len(),
+        None => return Err("At least one permutation is required".to_string()),
+    };
+    let mut ops = Vec::new();
+    for (k, perm) in perms.iter().enumerate() {
+        if perm.len() != n {
+            return Err(format!("Permutation {} has size {}, expected {}", k, perm.len(), n));
+        }
+        let mut seen = vec![false; n];
+        for &x in perm {
+            if x < 0 || x as usize >= n || std::mem::replace(&mut seen[x as usize], true) {
+                return Err(format!("Permutation {} is not a permutation of 0..{}", k, n));
+            }
+        }
+        let sym = OperationSymbol::new_safe(&format!("g{}", k), 1, false)?;
+        ops.push(make_int_operation(sym, n as i32, perm.clone())?);
+    }
+    Ok(BasicAlgebra::new("GSet".to_string(), (0..n as i32).collect(), ops))
+}
+
+/// Create the left regular representation of a group as a unary algebra:
+/// the universe is the group, and for each element `g` there is an
+/// operation `l<g>` with `l<g>(x) = g * x`.
+///
+/// # Arguments
+/// * `group_table` - The multiplication table; `group_table[g][x]` is `g * x`
+///
+/// # Returns
+/// * `Ok(BasicAlgebra)` - The unary algebra, named `Cayley`
+/// * `Err(String)` - If the table is not the table of a group
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::{algebras, Algebra};
+///
+/// // The cyclic group Z3
+/// let table: Vec<Vec<i32>> = (0..3).map(|g| (0..3).map(|x| (g + x) % 3).collect()).collect();
+/// let alg = algebras::cayley_algebra(&table).unwrap();
+/// assert_eq!(alg.cardinality(), 3);
+/// assert_eq!(alg.operations().len(), 3);
+/// ```
+pub fn cayley_algebra(group_table: &[Vec<i32>]) -> Result<BasicAlgebra<i32>, String> {
+    let n = group_table.len();
+    if n == 0 {
+        return Err("The group table is empty".to_string());
+    }
+    if group_table.iter().any(|row| row.len() != n || row.iter().any(|&x| x < 0 || x as usize >= n)) {
+        return Err(format!("The group table must be {} x {} with entries in 0..{}", n, n, n));
+    }
+    let mul = |a: usize, b: usize| group_table[a][b] as usize;
+    for a in 0..n {
+        for b in 0..n {
+            for c in 0..n {
+                if mul(mul(a, b), c) != mul(a, mul(b, c)) {
+                    return Err(format!("The table is not associative at ({}, {}, {})", a, b, c));
+                }
+            }
+        }
+    }
+    // An associative table whose rows and columns are permutations is a group.
+    for a in 0..n {
+        let mut row_seen = vec![false; n];
+        let mut col_seen = vec![false; n];
+        for b in 0..n {
+            if std::mem::replace(&mut row_seen[mul(a, b)], true) || std::mem::replace(&mut col_seen[mul(b, a)], true) {
+                return Err(format!("The table is not a Latin square at row and column {}", a));
+            }
+        }
+    }
+    let ops = group_table.iter()
+        .enumerate()
+        .map(|(g, row)| {
+            let sym = OperationSymbol::new_safe(&format!("l{}", g), 1, false)?;
+            make_int_operation(sym, n as i32, row.clone())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(BasicAlgebra::new("Cayley".to_string(), (0..n as i32).collect(), ops))
+}
+
+/// Create the graph algebra of a digraph (Shallon): the universe is the
+/// vertices `0..n` together with a zero `n`, and the binary operation `*`
+/// has `x * y = x` if `(x, y)` is an edge and `x * y = n` otherwise.
+///
+/// # Arguments
+/// * `edges` - The edges of the digraph
+/// * `n` - The number of vertices
+///
+/// # Returns
+/// * `Ok(BasicAlgebra)` - The graph algebra, of cardinality `n + 1`
+/// * `Err(String)` - If an edge is not between vertices below `n`
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::{algebras, Algebra};
+///
+/// let alg = algebras::digraph_algebra(&[(0, 1), (1, 1)], 2).unwrap();
+/// assert_eq!(alg.cardinality(), 3);
+/// assert_eq!(algebras::algebra_to_digraph(&alg).unwrap(), vec![(0, 1), (1, 1)]);
+/// ```
+pub fn digraph_algebra(edges: &[(usize, usize)], n: usize) -> Result<BasicAlgebra<i32>, String> {
+    let size = n + 1;
+    let mut table = vec![n as i32; size * size];
+    for &(u, v) in edges {
+        if u >= n || v >= n {
+            return Err(format!("Edge ({}, {}) is not between vertices below {}", u, v, n));
+        }
+        table[u + v * size] = u as i32;
+    }
+    let sym = OperationSymbol::new_safe("*", 2, false)?;
+    let op = make_int_operation(sym, size as i32, table)?;
+    Ok(BasicAlgebra::new(format!("Graph-{}", n), (0..size as i32).collect(), vec![op]))
+}
+
+/// Recover the digraph of an algebra.
+///
+/// For a graph algebra as built by [`digraph_algebra`] these are its edges.
+/// For a unary algebra they are the edges `x -> f(x)` of the functional
+/// digraphs of its operations, merged.
+///
+/// # Arguments
+/// * `alg` - A unary algebra or a graph algebra
+///
+/// # Returns
+/// * `Ok(Vec<(usize, usize)>)` - The edges, sorted and without repeats
+/// * `Err(String)` - If the algebra is neither unary nor a graph algebra
+pub fn algebra_to_digraph(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<Vec<(usize, usize)>, String> {
+    let ops = alg.get_operations_ref();
+    let size = alg.cardinality() as usize;
+    let mut edges = BTreeSet::new();
+    if let [op] = ops[..] {
+        if op.arity() == 2 && op.symbol().name() == "*" && size > 0 {
+            let zero = size - 1;
+            for u in 0..zero {
+                for v in 0..zero {
+                    match op.int_value_at(&[u as i32, v as i32])? as usize {
+                        x if x == u => { edges.insert((u, v)); }
+                        x if x == zero => {}
+                        _ => return Err(format!("{} is not a graph algebra", alg.name())),
+                    }
+                }
+            }
+            return Ok(edges.into_iter().collect());
+        }
+    }
+    for op in ops {
+        if op.arity() != 1 {
+            return Err(format!("{} is not a unary algebra: {} has arity {}", alg.name(), op.symbol(), op.arity()));
+        }
+        for x in 0..size {
+            edges.insert((x, op.int_value_at(&[x as i32])? as usize));
+        }
+    }
+    Ok(edges.into_iter().collect())
+}
+
+/// Create the Boolean algebra of subsets of `n_atoms` atoms.
+///
+/// The elements are the subsets as bit masks, so `x ≤ y` when the bits of
+/// `x` are among those of `y`. The operations are `join`, `meet`, the
+/// complement `neg` and the constants `zero` and `one`.
+///
+/// # Arguments
+/// * `n_atoms` - The number of atoms
+///
+/// # Returns
+/// * `Ok(BasicAlgebra)` - The algebra, named `Boolean-{n_atoms}`, with
+///   `2^n_atoms` elements
+/// * `Err(String)` - If the tables would be too large
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::{algebras, Algebra};
+///
+/// let b3 = algebras::boolean_algebra(3).unwrap();
+/// assert_eq!(b3.cardinality(), 8);
+/// assert_eq!(algebras::is_boolean_algebra(&b3).unwrap()[0b011], 0b100);
+/// ```
+pub fn boolean_algebra(n_atoms: usize) -> Result<BasicAlgebra<i32>, String> {
+    let size = 1usize.checked_shl(n_atoms as u32).filter(|&s| s <= 1 << 15)
+        .ok_or_else(|| format!("A Boolean algebra with {} atoms is too large", n_atoms))?;
+    let binary = |name: &str, f: fn(usize, usize) -> usize| {
+        let table = (0..size * size).map(|k| f(k % size, k / size) as i32).collect();
+        make_int_operation(OperationSymbol::new_safe(name, 2, false)?, size as i32, table)
+    };
+    let ops = vec![
+        binary("join", |x, y| x | y)?,
+        binary("meet", |x, y| x & y)?,
+        make_int_operation(OperationSymbol::new_safe("neg", 1, false)?, size as i32, (0..size as i32).map(|x| x ^ (size as i32 - 1)).collect())?,
+        make_int_operation(OperationSymbol::new_safe("zero", 0, false)?, size as i32, vec![0])?,
+        make_int_operation(OperationSymbol::new_safe("one", 0, false)?, size as i32, vec![size as i32 - 1])?,
+    ];
+    Ok(BasicAlgebra::new(format!("Boolean-{}", n_atoms), (0..size as i32).collect(), ops))
+}
+
+/// The relative pseudocomplement table of a finite distributive lattice:
+/// `a → b` is the largest `c` with `a ∧ c ≤ b`, the join of all such `c`.
+fn relative_pseudocomplements(join: &[Vec<usize>], meet: &[Vec<usize>], leq: impl Fn(usize, usize) -> bool) -> Vec<Vec<usize>> {
+    let n = join.len();
+    let bottom = (0..n).find(|&z| (0..n).all(|x| leq(z, x))).expect("a lattice has a least element");
+    (0..n)
+        .map(|a| (0..n).map(|b| (0..n).filter(|&c| leq(meet[a][c], b)).fold(bottom, |acc, c| join[acc][c])).collect())
+        .collect()
+}
+
+/// Create the Heyting algebra of a finite distributive lattice.
+///
+/// A finite lattice is a Heyting algebra exactly when it is distributive.
+/// The elements are the indices of the poset; the operations are `join`,
+/// `meet`, the relative pseudocomplement `imp` and the constants `zero`
+/// and `one`.
+///
+/// # Arguments
+/// * `poset` - A distributive lattice
+///
+/// # Returns
+/// * `Ok(BasicAlgebra)` - The Heyting algebra, named after the poset
+/// * `Err(String)` - If the poset is not a distributive lattice
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::{algebras, Algebra};
+/// use uacalc::lat::Poset;
+///
+/// // The 3-element chain is a Heyting algebra but not a Boolean algebra.
+/// let chain = Poset::from_leq(None, 3, |i, j| i <= j).unwrap();
+/// let h = algebras::heyting_algebra_from_poset(&chain).unwrap();
+/// assert_eq!(h.cardinality(), 3);
+/// assert!(algebras::is_heyting_algebra(&h).is_some());
+/// assert!(algebras::is_boolean_algebra(&h).is_none());
+/// ```
+pub fn heyting_algebra_from_poset(poset: &Poset) -> Result<BasicAlgebra<i32>, String> {
+    if !poset.is_distributive() {
+        return Err(format!("{} is not a distributive lattice", poset.name().unwrap_or("The poset")));
+    }
+    let name = poset.name().unwrap_or("Heyting").to_string();
+    let (join, meet) = poset.lattice_tables().expect("a distributive lattice is a lattice");
+    let imp = relative_pseudocomplements(&join, &meet, |a, b| poset.leq(a, b));
+    let n = poset.cardinality();
+    let binary = |op: &str, table: &[Vec<usize>]| {
+        let values = (0..n * n).map(|k| table[k % n][k / n] as i32).collect();
+        make_int_operation(OperationSymbol::new_safe(op, 2, false)?, n as i32, values)
+    };
+    let constant = |op: &str, value: usize| make_int_operation(OperationSymbol::new_safe(op, 0, false)?, n as i32, vec![value as i32]);
+    let ops = vec![
+        binary("join", &join)?,
+        binary("meet", &meet)?,
+        binary("imp", &imp)?,
+        constant("zero", poset.minimals()[0])?,
+        constant("one", poset.maximals()[0])?,
+    ];
+    Ok(BasicAlgebra::new(name, (0..n as i32).collect(), ops))
+}
+
+/// The join and meet tables of a lattice, indexed by element.
+type LatticeTables = (Vec<Vec<usize>>, Vec<Vec<usize>>);
+
+/// The join and meet tables of a lattice reduct of an algebra, if it has
+/// one and the lattice is distributive.
+fn distributive_lattice_reduct(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Option<LatticeTables> {
+    let (j, m) = crate::lat::find_lattice_reduct(alg)?;
+    let ops = alg.get_operations_ref();
+    if !crate::lat::as_lattice(ops[j], ops[m]).ok()?.is_distributive() {
+        return None;
+    }
+    let n = alg.cardinality() as usize;
+    let table = |op: &dyn Operation| -> Option<Vec<Vec<usize>>> {
+        let values = operation_table(op).ok()?;
+        Some((0..n).map(|x| (0..n).map(|y| values[x + n * y] as usize).collect()).collect())
+    };
+    Some((table(ops[j])?, table(ops[m])?))
+}
+
+/// Recognize a Boolean algebra: an algebra with two basic operations
+/// forming a complemented distributive lattice.
+///
+/// # Arguments
+/// * `alg` - The algebra
+///
+/// # Returns
+/// The complement of each element as witness, or `None` if the algebra
+/// has no Boolean lattice reduct
+pub fn is_boolean_algebra(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Option<Vec<usize>> {
+    let (join, meet) = distributive_lattice_reduct(alg)?;
+    let n = join.len();
+    let bottom = (0..n).find(|&z| (0..n).all(|x| join[z][x] == x))?;
+    let top = (0..n).find(|&u| (0..n).all(|x| meet[u][x] == x))?;
+    (0..n).map(|a| (0..n).find(|&b| join[a][b] == top && meet[a][b] == bottom)).collect()
+}
+
+/// Recognize a Heyting algebra: an algebra with two basic operations
+/// forming a distributive lattice, which for a finite lattice is the same
+/// as having relative pseudocomplements.
+///
+/// # Arguments
+/// * `alg` - The algebra
+///
+/// # Returns
+/// The relative pseudocomplement table as witness, `a → b` at `[a][b]`,
+/// or `None` if the algebra has no distributive lattice reduct
+pub fn is_heyting_algebra(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Option<Vec<Vec<usize>>> {
+    let (join, meet) = distributive_lattice_reduct(alg)?;
+    let leq = |a: usize, b: usize| join[a][b] == b;
+    Some(relative_pseudocomplements(&join, &meet, leq))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1009,6 +1339,84 @@ mod tests {
         assert_eq!(disc_op.int_value_at(&[2, 1, 0]).unwrap(), 2);
     }
 
+    #[test]
+    fn test_algebra_from_permutation_group() {
+        let alg = algebra_from_permutation_group(&[vec![1, 0, 2], vec![1, 2, 0]]).unwrap();
+        assert_eq!(alg.name(), "GSet");
+        let ops = alg.get_operations_ref();
+        assert_eq!(ops[1].symbol().name(), "g1");
+        assert_eq!(ops[1].int_value_at(&[2]).unwrap(), 0);
+
+        // S3 acts transitively, so the only congruences are 0 and 1
+        let mut con = crate::alg::conlat::CongruenceLattice::new(Box::new(alg) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+        assert_eq!(con.con_cardinality(), 2);
+
+        assert!(algebra_from_permutation_group(&[]).is_err());
+        assert!(algebra_from_permutation_group(&[vec![0, 0]]).is_err());
+        assert!(algebra_from_permutation_group(&[vec![0, 1], vec![0]]).is_err());
+    }
+
+    #[test]
+    fn test_cayley_algebra() {
+        // The Klein four group
+        let table: Vec<Vec<i32>> = (0..4).map(|g| (0..4).map(|x| g ^ x).collect()).collect();
+        let alg = cayley_algebra(&table).unwrap();
+        assert_eq!(alg.cardinality(), 4);
+        let ops = alg.get_operations_ref();
+        assert_eq!(ops.len(), 4);
+        assert_eq!(ops[3].symbol().name(), "l3");
+        assert_eq!(ops[3].int_value_at(&[1]).unwrap(), 2);
+
+        // Not associative
+        assert!(cayley_algebra(&[vec![1, 0], vec![0, 0]]).is_err());
+        // Associative but not a group
+        assert!(cayley_algebra(&[vec![0, 0], vec![0, 0]]).is_err());
+    }
+
+    #[test]
+    fn test_digraph_algebra() {
+        let alg = digraph_algebra(&[(0, 1), (1, 2), (2, 0)], 3).unwrap();
+        assert_eq!(alg.cardinality(), 4);
+        let op = alg.get_operations_ref()[0];
+        assert_eq!(op.int_value_at(&[1, 2]).unwrap(), 1);
+        assert_eq!(op.int_value_at(&[2, 1]).unwrap(), 3);
+        assert_eq!(op.int_value_at(&[3, 0]).unwrap(), 3);
+        assert_eq!(algebra_to_digraph(&alg).unwrap(), vec![(0, 1), (1, 2), (2, 0)]);
+        assert!(digraph_algebra(&[(0, 3)], 3).is_err());
+
+        // The functional digraph of a unary algebra
+        let sym = OperationSymbol::new_safe("f", 1, false).unwrap();
+        let f = make_int_operation(sym, 3, vec![1, 2, 2]).unwrap();
+        let unary = BasicAlgebra::new("U".to_string(), (0..3).collect(), vec![f]);
+        assert_eq!(algebra_to_digraph(&unary).unwrap(), vec![(0, 1), (1, 2), (2, 2)]);
+        assert!(algebra_to_digraph(&ternary_discriminator_algebra(2).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_boolean_and_heyting_algebras() {
+        let b2 = boolean_algebra(2).unwrap();
+        assert_eq!(is_boolean_algebra(&b2), Some(vec![3, 2, 1, 0]));
+        // In a Boolean algebra a -> b is (not a) or b.
+        let imp = is_heyting_algebra(&b2).unwrap();
+        assert!((0..4).all(|a| (0..4).all(|b| imp[a][b] == (a ^ 3) | b)));
+        assert!(boolean_algebra(40).is_err());
+
+        // The 3-element chain: 2 -> 1 is 1 and 1 -> 0 is 0.
+        let chain = Poset::from_leq(Some("3".to_string()), 3, |i, j| i <= j).unwrap();
+        let h = heyting_algebra_from_poset(&chain).unwrap();
+        let imp = h.get_operations_ref().into_iter().find(|op| op.symbol().name() == "imp").unwrap();
+        assert_eq!(imp.int_value_at(&[2, 1]).unwrap(), 1);
+        assert_eq!(imp.int_value_at(&[1, 0]).unwrap(), 0);
+        assert_eq!(imp.int_value_at(&[0, 1]).unwrap(), 2);
+        assert_eq!(is_heyting_algebra(&h).unwrap()[2][1], 1);
+        assert!(is_boolean_algebra(&h).is_none());
+
+        // M3 is a lattice but not distributive.
+        let m3 = Poset::from_upper_covers(None, (0..5).map(|i| i.to_string()).collect(), &[vec![1, 2, 3], vec![4], vec![4], vec![4], vec![]]).unwrap();
+        assert!(heyting_algebra_from_poset(&m3).is_err());
+        assert!(is_heyting_algebra(&ternary_discriminator_algebra(2).unwrap()).is_none());
+    }
+
     #[test]
     fn test_ternary_discriminator_algebra_invalid_cardinality() {
         // Test with invalid cardinality (should fail)
@@ -1217,8 +1625,27 @@ pub fn member_of_quasivariety(
 pub fn member_of_quasivariety_list(
     a: Box<dyn SmallAlgebra<UniverseItem = i32>>,
     gen_algs: Vec<Box<dyn SmallAlgebra<UniverseItem = i32>>>,
-    mut report: Option<&mut dyn crate::progress::ProgressReport>,
+    report: Option<&mut dyn crate::progress::ProgressReport>,
 ) -> Result<Option<Vec<Homomorphism>>, String> {
+    let (homos, phi) = separating_homomorphisms(a.as_ref(), &gen_algs, report)?;
+    Ok(if phi.is_zero() { Some(homos) } else { None })
+}
+
+/// Collect homomorphisms from A into the generating algebras until the
+/// meet of their kernels is zero or every homomorphism has been tried.
+///
+/// Only homomorphisms whose kernel lowers the meet are kept. A is in the
+/// quasivariety exactly when the returned meet is zero; otherwise it is the
+/// smallest congruence that homomorphisms into the generators can reach.
+///
+/// # Returns
+/// * `Ok((homos, phi))` - The homomorphisms kept and the meet of their kernels
+/// * `Err(String)` - If there's an error during computation
+pub(crate) fn separating_homomorphisms(
+    a: &dyn SmallAlgebra<UniverseItem = i32>,
+    gen_algs: &[Box<dyn SmallAlgebra<UniverseItem = i32>>],
+    mut report: Option<&mut dyn crate::progress::ProgressReport>,
+) -> Result<(Vec<Homomorphism>, Partition), String> {
     use crate::alg::conlat::CongruenceLattice;
     use crate::alg::sublat::SubalgebraLattice;
     
@@ -1229,6 +1656,11 @@ pub fn member_of_quasivariety_list(
     let a_con = CongruenceLattice::new(a.clone_box());
     let zero = a_con.zero();
     
+    // A trivial algebra is the empty product
+    if a_con.one() == zero {
+        return Ok((Vec::new(), zero));
+    }
+    
     // Create subalgebra lattice for A
     let mut a_sub = SubalgebraLattice::new_safe(a.clone_box())?;
     
@@ -1266,7 +1698,7 @@ pub fn member_of_quasivariety_list(
             if let Some(homo_map) = SubalgebraLattice::extend_to_homomorphism(
                 gens,
                 &current_arr,
-                a.as_ref(),
+                a,
                 b.as_ref(),
             ) {
                 // Convert HashMap<i32, i32> to HashMap<usize, usize> for Homomorphism
@@ -1293,7 +1725,7 @@ pub fn member_of_quasivariety_list(
                     
                     // If phi equals zero, we're done
                     if phi == zero {
-                        return Ok(Some(homos));
+                        return Ok((homos, phi));
                     }
                 }
             }
@@ -1309,8 +1741,7 @@ pub fn member_of_quasivariety_list(
         r.add_line(&format!("the intersection of the kernel is {}", phi));
     }
     
-    // If phi didn't become zero, A is not in the quasivariety
-    Ok(None)
+    Ok((homos, phi))
 }
 
 /// Test if algebra A can be embedded into a product of proper subalgebras of A.
@@ -0,0 +1,198 @@
+/*! Running one analysis over many algebras in parallel.
+
+[`analyze_batch`] evaluates a list of [`Analysis`] values on each algebra of
+a batch, one algebra per task on a rayon thread pool, so that parameter
+sweeps over many small algebras use every core. The progress token
+installed on the calling thread is installed on the workers too, so the
+whole batch can be cancelled.
+*/
+
+use std::fmt;
+use rayon::prelude::*;
+use crate::alg::{BasicAlgebra, SmallAlgebra};
+use crate::alg::conlat::CongruenceLattice;
+use crate::alg::malcev;
+use crate::eq::Equation;
+
+/// An analysis run on each algebra of a batch.
+#[derive(Debug, Clone)]
+pub enum Analysis {
+    /// The number of congruences.
+    ConCardinality,
+    /// Whether the congruence lattice is distributive.
+    ConDistributive,
+    /// Whether the algebra is simple: exactly two congruences.
+    Simple,
+    /// Whether the algebra has a Maltsev term.
+    MalcevTerm,
+    /// Whether the algebra has a majority term.
+    MajorityTerm,
+    /// Whether the variety is congruence distributive (has Jónsson terms).
+    CongruenceDistributive,
+    /// Whether the variety is congruence modular.
+    CongruenceModular,
+    /// Whether the algebra has a Taylor term.
+    TaylorTerm,
+    /// The number of Jónsson terms needed, or -1 if there are none.
+    JonssonLevel,
+    /// Whether an equation holds in the algebra.
+    Equation(Equation),
+}
+
+impl Analysis {
+    /// Parse an analysis name: `"con_cardinality"`, `"con_distributive"`,
+    /// `"simple"`, `"malcev_term"`, `"majority_term"`,
+    /// `"congruence_distributive"`, `"congruence_modular"`, `"taylor_term"`
+    /// or `"jonsson_level"`.
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "con_cardinality" => Ok(Analysis::ConCardinality),
+            "con_distributive" => Ok(Analysis::ConDistributive),
+            "simple" => Ok(Analysis::Simple),
+            "malcev_term" => Ok(Analysis::MalcevTerm),
+            "majority_term" => Ok(Analysis::MajorityTerm),
+            "congruence_distributive" => Ok(Analysis::CongruenceDistributive),
+            "congruence_modular" => Ok(Analysis::CongruenceModular),
+            "taylor_term" => Ok(Analysis::TaylorTerm),
+            "jonsson_level" => Ok(Analysis::JonssonLevel),
+            _ => Err(format!("Unknown analysis: {}", name)),
+        }
+    }
+
+    /// Run this analysis on `alg`.
+    fn run(&self, alg: &BasicAlgebra<i32>) -> Result<AnalysisValue, String> {
+        let con = || CongruenceLattice::new(Box::new(alg.clone()) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+        Ok(match self {
+            Analysis::ConCardinality => AnalysisValue::Int(con().try_con_cardinality()? as i64),
+            Analysis::ConDistributive => AnalysisValue::Bool(con().is_distributive()),
+            Analysis::Simple => AnalysisValue::Bool(con().try_con_cardinality()? == 2),
+            Analysis::MalcevTerm => AnalysisValue::Bool(malcev::malcev_term(alg)?.is_some()),
+            Analysis::MajorityTerm => AnalysisValue::Bool(malcev::majority_term(alg)?.is_some()),
+            Analysis::CongruenceDistributive => AnalysisValue::Bool(malcev::jonsson_terms(alg)?.is_some()),
+            Analysis::CongruenceModular => AnalysisValue::Bool(malcev::congruence_modular_variety(alg)?),
+            Analysis::TaylorTerm => AnalysisValue::Bool(malcev::has_taylor_term(alg)?.has_taylor_term()),
+            Analysis::JonssonLevel => AnalysisValue::Int(malcev::jonsson_level(alg)? as i64),
+            Analysis::Equation(eq) => AnalysisValue::Bool(eq.is_satisfied_in(alg)?),
+        })
+    }
+}
+
+impl fmt::Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Analysis::ConCardinality => "con_cardinality",
+            Analysis::ConDistributive => "con_distributive",
+            Analysis::Simple => "simple",
+            Analysis::MalcevTerm => "malcev_term",
+            Analysis::MajorityTerm => "majority_term",
+            Analysis::CongruenceDistributive => "congruence_distributive",
+            Analysis::CongruenceModular => "congruence_modular",
+            Analysis::TaylorTerm => "taylor_term",
+            Analysis::JonssonLevel => "jonsson_level",
+            Analysis::Equation(eq) => return write!(f, "{}", eq),
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The result of one analysis on one algebra.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnalysisValue {
+    /// The answer to a yes or no question.
+    Bool(bool),
+    /// A count or level.
+    Int(i64),
+}
+
+/// Run `analyses` on each of `algs` in parallel.
+///
+/// Each algebra is one task; its analyses run in order on the same worker.
+/// An analysis that fails, for instance an equation using an operation the
+/// algebra lacks, gives an error for that algebra and analysis only.
+///
+/// # Arguments
+/// * `algs` - The algebras
+/// * `analyses` - The analyses to run on each algebra
+/// * `n_threads` - The number of worker threads, or `None` for one per core
+///
+/// # Returns
+/// * `Ok(results)` - `results[i][j]` is analysis `j` of algebra `i`
+/// * `Err(String)` - If the thread pool could not be started or the batch
+///   was cancelled
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use uacalc::alg::BasicAlgebra;
+/// use uacalc::alg::batch::{analyze_batch, Analysis, AnalysisValue};
+/// use uacalc::alg::op::{OperationSymbol, operations};
+///
+/// let plus = OperationSymbol::new("+", 2, false);
+/// let zn = |n: i32| {
+///     let op = operations::make_int_operation(plus.clone(), n, (0..n * n).map(|k| (k % n + k / n) % n).collect()).unwrap();
+///     BasicAlgebra::new(format!("Z{}", n), (0..n).collect::<HashSet<i32>>(), vec![op])
+/// };
+/// let analyses = [Analysis::Simple, Analysis::ConCardinality];
+/// let results = analyze_batch(&[zn(2), zn(3), zn(4)], &analyses, Some(2)).unwrap();
+/// assert_eq!(results[1][0], Ok(AnalysisValue::Bool(true)));
+/// assert_eq!(results[2][1], Ok(AnalysisValue::Int(3)));
+/// ```
+pub fn analyze_batch(
+    algs: &[BasicAlgebra<i32>],
+    analyses: &[Analysis],
+    n_threads: Option<usize>,
+) -> Result<Vec<Vec<Result<AnalysisValue, String>>>, String> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(n_threads.unwrap_or(0))
+        .build()
+        .map_err(|e| e.to_string())?;
+    let token = crate::progress::current();
+    let results = pool.install(|| {
+        algs.par_iter()
+            .map(|alg| {
+                // The token is thread-local, so install it on each worker.
+                let _installed = token.as_ref().map(|t| t.install());
+                analyses.iter().map(|analysis| analysis.run(alg)).collect()
+            })
+            .collect()
+    });
+    crate::progress::check_cancelled()?;
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::op::OperationSymbol;
+    use crate::alg::op::operations::make_int_operation;
+    use crate::terms::string_to_term;
+
+    #[test]
+    fn test_analyze_batch() {
+        let meet = OperationSymbol::new("meet", 2, false);
+        let semilattice = make_int_operation(meet, 2, vec![0, 0, 0, 1]).unwrap();
+        let s = BasicAlgebra::new("S".to_string(), (0..2).collect(), vec![semilattice]);
+        let plus = OperationSymbol::new("+", 2, false);
+        let group = make_int_operation(plus, 2, vec![0, 1, 1, 0]).unwrap();
+        let z2 = BasicAlgebra::new("Z2".to_string(), (0..2).collect(), vec![group]);
+
+        let idempotent = Equation::new(
+            string_to_term("meet(x,x)").unwrap(),
+            string_to_term("x").unwrap(),
+        );
+        let analyses = vec![
+            Analysis::from_name("malcev_term").unwrap(),
+            Analysis::from_name("taylor_term").unwrap(),
+            Analysis::Equation(idempotent),
+        ];
+        assert_eq!(analyses[2].to_string(), "meet(x,x) = x");
+        let results = analyze_batch(&[s, z2], &analyses, Some(2)).unwrap();
+        assert_eq!(results[0][..2], [Ok(AnalysisValue::Bool(false)), Ok(AnalysisValue::Bool(true))]);
+        assert_eq!(results[0][2], Ok(AnalysisValue::Bool(true)));
+        assert_eq!(results[1][0], Ok(AnalysisValue::Bool(true)));
+        // Z2 has no meet operation
+        assert!(results[1][2].is_err());
+
+        assert!(Analysis::from_name("nonsense").is_err());
+    }
+}
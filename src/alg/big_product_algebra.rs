@@ -888,6 +888,10 @@ where
         total_size as i32
     }
     
+    fn exact_cardinality(&self) -> crate::alg::Cardinality {
+        crate::alg::Cardinality::of_product(&self.sizes)
+    }
+    
     fn input_size(&self) -> i32 {
         self.number_of_factors as i32
     }
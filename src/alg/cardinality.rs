@@ -0,0 +1,308 @@
+/*! Exact cardinalities of algebras too big for an `i32`.
+
+[`Algebra::cardinality`](crate::alg::Algebra::cardinality) follows the Java
+convention of an `i32` with negative constants such as
+[`CARDINALITY_UNKNOWN`] for anything it cannot represent, so a power like
+`A^20` of a three element algebra is simply unknown. [`Cardinality`] keeps
+the exact value as a big integer instead, with checked conversions back to
+the machine types.
+*/
+
+use std::fmt;
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+use crate::alg::algebra::{CARDINALITY_COUNTABLY_INFINITE, CARDINALITY_INFINITE, CARDINALITY_UNKNOWN};
+
+/// The largest number of bits a [`Cardinality`] will compute; anything
+/// bigger is [`Cardinality::Unknown`].
+pub const MAX_CARDINALITY_BITS: u64 = 1 << 24;
+
+/// The cardinality of a set, exact when it is finite.
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::Cardinality;
+///
+/// // 3^20 overflows an i32 but not a u64; 3^50 needs a big integer.
+/// let three = Cardinality::Finite(3);
+/// assert_eq!(three.pow(&Cardinality::Finite(20)), Cardinality::Finite(3486784401));
+/// assert!(three.pow(&Cardinality::Finite(20)).to_i32().is_err());
+/// let big = three.pow(&Cardinality::Finite(50));
+/// assert!(matches!(big, Cardinality::Big(_)));
+/// assert_eq!(big.to_string(), "717897987691852588770249");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Cardinality {
+    /// A finite cardinality that fits in a `u64`.
+    Finite(u64),
+    /// A finite cardinality too big for a `u64`.
+    Big(BigUint),
+    /// An infinite cardinality.
+    Infinite,
+    /// A cardinality that is not known, or too big to compute.
+    Unknown,
+}
+
+impl Cardinality {
+    /// Convert a big integer, using [`Cardinality::Finite`] when it fits.
+    pub fn from_big(n: BigUint) -> Self {
+        match n.to_u64() {
+            Some(n) => Cardinality::Finite(n),
+            None => Cardinality::Big(n),
+        }
+    }
+
+    /// Convert an `i32` cardinality, mapping the negative constants of
+    /// [`crate::alg::algebra`] to [`Cardinality::Infinite`] or
+    /// [`Cardinality::Unknown`].
+    pub fn from_i32(card: i32) -> Self {
+        match card {
+            n if n >= 0 => Cardinality::Finite(n as u64),
+            CARDINALITY_INFINITE | CARDINALITY_COUNTABLY_INFINITE => Cardinality::Infinite,
+            // CARDINALITY_FINITE and CARDINALITY_COUNTABLE give no exact value.
+            _ => Cardinality::Unknown,
+        }
+    }
+
+    /// The cardinality of a direct product of algebras of the given `i32`
+    /// cardinalities, grouping equal factors into powers.
+    ///
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::Cardinality;
+    ///
+    /// assert_eq!(Cardinality::of_product(&[2, 3, 4]), Cardinality::Finite(24));
+    /// assert_eq!(Cardinality::of_product(&[2, 0, -1]), Cardinality::Finite(0));
+    /// assert_eq!(Cardinality::of_product(&[2, -1]), Cardinality::Unknown);
+    /// assert_eq!(Cardinality::of_product(&[2; 64]).to_string(), "18446744073709551616");
+    /// ```
+    pub fn of_product(sizes: &[i32]) -> Self {
+        let mut multiplicities = std::collections::BTreeMap::new();
+        for &size in sizes {
+            *multiplicities.entry(size).or_insert(0u64) += 1;
+        }
+        multiplicities
+            .into_iter()
+            .map(|(size, count)| Cardinality::from_i32(size).pow(&Cardinality::Finite(count)))
+            .fold(Cardinality::Finite(1), |acc, c| acc.times(&c))
+    }
+
+    /// Whether the cardinality is known to be finite.
+    pub fn is_finite(&self) -> bool {
+        matches!(self, Cardinality::Finite(_) | Cardinality::Big(_))
+    }
+
+    /// Whether the cardinality is known to be zero.
+    pub fn is_zero(&self) -> bool {
+        matches!(self, Cardinality::Finite(0))
+    }
+
+    /// The exact value, or `None` if it is infinite or unknown.
+    pub fn to_biguint(&self) -> Option<BigUint> {
+        match self {
+            Cardinality::Finite(n) => Some(BigUint::from(*n)),
+            Cardinality::Big(n) => Some(n.clone()),
+            _ => None,
+        }
+    }
+
+    /// The value as a `u64`, or `None` if it is not finite or too big.
+    pub fn to_u64(&self) -> Option<u64> {
+        match self {
+            Cardinality::Finite(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// The value as an `i32`.
+    ///
+    /// # Returns
+    /// * `Ok(i32)` - The value
+    /// * `Err(String)` - If it is not finite or does not fit in an `i32`
+    pub fn to_i32(&self) -> Result<i32, String> {
+        self.to_u64()
+            .and_then(|n| i32::try_from(n).ok())
+            .ok_or_else(|| format!("The cardinality {} does not fit in an i32", self))
+    }
+
+    /// The value as a `usize`.
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - The value
+    /// * `Err(String)` - If it is not finite or does not fit in a `usize`
+    pub fn to_usize(&self) -> Result<usize, String> {
+        self.to_u64()
+            .and_then(|n| usize::try_from(n).ok())
+            .ok_or_else(|| format!("The cardinality {} does not fit in a usize", self))
+    }
+
+    /// The value in the `i32` convention of
+    /// [`Algebra::cardinality`](crate::alg::Algebra::cardinality):
+    /// [`CARDINALITY_INFINITE`] if it is infinite and [`CARDINALITY_UNKNOWN`]
+    /// if it is unknown or too big.
+    pub fn to_i32_or_unknown(&self) -> i32 {
+        match self {
+            Cardinality::Infinite => CARDINALITY_INFINITE,
+            _ => self.to_i32().unwrap_or(CARDINALITY_UNKNOWN),
+        }
+    }
+
+    /// The number of bits of a finite value; zero otherwise.
+    fn bits(&self) -> u64 {
+        match self {
+            Cardinality::Finite(n) => 64 - n.leading_zeros() as u64,
+            Cardinality::Big(n) => n.bits(),
+            _ => 0,
+        }
+    }
+
+    /// The cardinality of a disjoint union.
+    pub fn plus(&self, other: &Cardinality) -> Cardinality {
+        match (self, other) {
+            (Cardinality::Infinite, _) | (_, Cardinality::Infinite) => Cardinality::Infinite,
+            (Cardinality::Unknown, _) | (_, Cardinality::Unknown) => Cardinality::Unknown,
+            (Cardinality::Finite(a), Cardinality::Finite(b)) => match a.checked_add(*b) {
+                Some(n) => Cardinality::Finite(n),
+                None => Cardinality::Big(BigUint::from(*a) + *b),
+            },
+            _ => match (self.to_biguint(), other.to_biguint()) {
+                (Some(a), Some(b)) => Cardinality::from_big(a + b),
+                _ => Cardinality::Unknown,
+            },
+        }
+    }
+
+    /// The cardinality of a direct product; a product with an empty factor
+    /// is empty even if the other factor is infinite or unknown.
+    pub fn times(&self, other: &Cardinality) -> Cardinality {
+        if self.is_zero() || other.is_zero() {
+            return Cardinality::Finite(0);
+        }
+        match (self, other) {
+            (Cardinality::Unknown, _) | (_, Cardinality::Unknown) => Cardinality::Unknown,
+            (Cardinality::Infinite, _) | (_, Cardinality::Infinite) => Cardinality::Infinite,
+            (Cardinality::Finite(a), Cardinality::Finite(b)) => match a.checked_mul(*b) {
+                Some(n) => Cardinality::Finite(n),
+                None => Cardinality::Big(BigUint::from(*a) * *b),
+            },
+            _ => {
+                if self.bits() + other.bits() > MAX_CARDINALITY_BITS {
+                    return Cardinality::Unknown;
+                }
+                match (self.to_biguint(), other.to_biguint()) {
+                    (Some(a), Some(b)) => Cardinality::from_big(a * b),
+                    _ => Cardinality::Unknown,
+                }
+            }
+        }
+    }
+
+    /// The cardinality of the set of maps from a set of size `exponent`
+    /// into this one, such as a direct power.
+    ///
+    /// The result is [`Cardinality::Unknown`] if it would need more than
+    /// [`MAX_CARDINALITY_BITS`] bits.
+    pub fn pow(&self, exponent: &Cardinality) -> Cardinality {
+        if exponent.is_zero() {
+            return Cardinality::Finite(1);
+        }
+        match self {
+            Cardinality::Finite(0) | Cardinality::Finite(1) if exponent != &Cardinality::Unknown => {
+                return self.clone();
+            }
+            Cardinality::Unknown => return Cardinality::Unknown,
+            Cardinality::Infinite if exponent != &Cardinality::Unknown => return Cardinality::Infinite,
+            _ => {}
+        }
+        match exponent {
+            Cardinality::Unknown => Cardinality::Unknown,
+            Cardinality::Infinite => Cardinality::Infinite,
+            Cardinality::Big(_) => Cardinality::Unknown,
+            Cardinality::Finite(e) => {
+                if self.bits().saturating_sub(1).saturating_mul(*e) > MAX_CARDINALITY_BITS {
+                    return Cardinality::Unknown;
+                }
+                match self.to_biguint() {
+                    // The bit bound keeps the exponent well below u32::MAX.
+                    Some(base) => Cardinality::from_big(base.pow(*e as u32)),
+                    None => Cardinality::Unknown,
+                }
+            }
+        }
+    }
+}
+
+impl From<u64> for Cardinality {
+    fn from(n: u64) -> Self {
+        Cardinality::Finite(n)
+    }
+}
+
+impl From<usize> for Cardinality {
+    fn from(n: usize) -> Self {
+        Cardinality::Finite(n as u64)
+    }
+}
+
+impl From<BigUint> for Cardinality {
+    fn from(n: BigUint) -> Self {
+        Cardinality::from_big(n)
+    }
+}
+
+impl std::str::FromStr for Cardinality {
+    type Err = String;
+
+    /// Parse the decimal digits of a finite cardinality.
+    fn from_str(s: &str) -> Result<Self, String> {
+        s.trim()
+            .parse::<BigUint>()
+            .map(Cardinality::from_big)
+            .map_err(|_| format!("Expected a non-negative integer, got {}", s))
+    }
+}
+
+impl fmt::Display for Cardinality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Cardinality::Finite(n) => write!(f, "{}", n),
+            Cardinality::Big(n) => write!(f, "{}", n),
+            Cardinality::Infinite => write!(f, "infinite"),
+            Cardinality::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::algebra::CARDINALITY_FINITE;
+
+    #[test]
+    fn test_cardinality_arithmetic() {
+        let three = Cardinality::Finite(3);
+        assert_eq!(three.pow(&Cardinality::Finite(2)), Cardinality::Finite(9));
+        // 2^64 is just past a u64.
+        let two_64 = Cardinality::Finite(2).pow(&Cardinality::Finite(64));
+        assert_eq!(two_64, Cardinality::Big(BigUint::from(1u8) << 64));
+        assert_eq!(two_64.times(&Cardinality::Finite(0)), Cardinality::Finite(0));
+        assert_eq!(Cardinality::Finite(u64::MAX).plus(&Cardinality::Finite(1)), two_64);
+        assert!(two_64.to_i32().is_err());
+        assert_eq!(two_64.to_i32_or_unknown(), CARDINALITY_UNKNOWN);
+
+        assert_eq!(Cardinality::from_i32(CARDINALITY_INFINITE).to_i32_or_unknown(), CARDINALITY_INFINITE);
+        assert_eq!(Cardinality::from_i32(CARDINALITY_FINITE), Cardinality::Unknown);
+        assert_eq!(Cardinality::Infinite.times(&Cardinality::Finite(0)), Cardinality::Finite(0));
+        assert_eq!(Cardinality::Infinite.plus(&Cardinality::Unknown), Cardinality::Infinite);
+        assert_eq!(Cardinality::Finite(1).pow(&Cardinality::Infinite), Cardinality::Finite(1));
+
+        // 3^(3^20) would take billions of bits.
+        let huge = three.pow(&three.pow(&Cardinality::Finite(20)));
+        assert_eq!(huge, Cardinality::Unknown);
+        assert_eq!(Cardinality::of_product(&[3; 20]).to_u64(), Some(3486784401));
+
+        let parsed: Cardinality = "18446744073709551616".parse().unwrap();
+        assert_eq!(parsed, two_64);
+        assert!("-1".parse::<Cardinality>().is_err());
+    }
+}
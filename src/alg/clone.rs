@@ -0,0 +1,917 @@
+/*! The Galois connection between operations and relations.
+
+An operation `f` of arity `m` on `A` preserves a relation `R ⊆ A^k` when
+applying `f` to the columns of any `k x m` matrix whose columns lie in `R`
+gives a tuple of `R`. For a set of operations `F`, `Inv(F)` is the set of
+relations preserved by every operation of `F`; these are exactly the
+subuniverses of the powers of the algebra `(A, F)`. For a set of relations
+`Q`, `Pol(Q)` is the set of operations preserving every relation of `Q`,
+the polymorphism clone of `Q`. On a finite set, `Pol(Inv(F))` is the clone
+generated by `F` and `Inv(Pol(Q))` the relational clone generated by `Q`.
+
+Both sets are infinite, so [`inv`] and [`pol`] compute them up to an arity
+bound. They grow very fast with the bound: `Pol` of the empty set holds all
+`n^(n^m)` operations of arity `m`.
+*/
+
+use std::collections::{BTreeSet, HashSet};
+use std::fmt;
+use std::sync::Arc;
+
+use crate::alg::op::{operations, Operation, OperationSymbol};
+use crate::alg::search::{subpower, Constraint, ConstraintProblem};
+use crate::alg::{BasicAlgebra, SmallAlgebra};
+use crate::terms::compiled_term::{operation_table, table_size};
+use crate::util::horner;
+
+/// A finitary relation on `{0, ..., n-1}`: a set of tuples of a fixed arity.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Relation {
+    universe_size: i32,
+    arity: usize,
+    /// The tuples, sorted and distinct.
+    tuples: Vec<Vec<i32>>,
+}
+
+impl Relation {
+    /// Create a relation.
+    ///
+    /// # Arguments
+    /// * `universe_size` - The size `n` of the universe `{0, ..., n-1}`
+    /// * `arity` - The length of the tuples
+    /// * `tuples` - The tuples; duplicates are ignored
+    ///
+    /// # Returns
+    /// * `Ok(Relation)` - The relation
+    /// * `Err(String)` - If a tuple has the wrong length or an entry outside the universe
+    ///
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::clone::Relation;
+    ///
+    /// let order = Relation::new(2, 2, vec![vec![0, 0], vec![0, 1], vec![1, 1]]).unwrap();
+    /// assert!(order.contains(&[0, 1]));
+    /// assert!(!order.contains(&[1, 0]));
+    /// assert!(Relation::new(2, 2, vec![vec![0, 2]]).is_err());
+    /// ```
+    pub fn new(universe_size: i32, arity: usize, tuples: Vec<Vec<i32>>) -> Result<Self, String> {
+        if universe_size < 0 {
+            return Err(format!("Universe size must be non-negative, got {}", universe_size));
+        }
+        for t in &tuples {
+            if t.len() != arity {
+                return Err(format!("Tuple {:?} has length {}, expected {}", t, t.len(), arity));
+            }
+            if let Some(&x) = t.iter().find(|&&x| x < 0 || x >= universe_size) {
+                return Err(format!("Tuple {:?} has entry {} outside 0..{}", t, x, universe_size));
+            }
+        }
+        let tuples: BTreeSet<Vec<i32>> = tuples.into_iter().collect();
+        Ok(Relation { universe_size, arity, tuples: tuples.into_iter().collect() })
+    }
+
+    /// The size of the universe.
+    pub fn universe_size(&self) -> i32 {
+        self.universe_size
+    }
+
+    /// The length of the tuples.
+    pub fn arity(&self) -> usize {
+        self.arity
+    }
+
+    /// The tuples, in lexicographic order.
+    pub fn tuples(&self) -> &[Vec<i32>] {
+        &self.tuples
+    }
+
+    /// The number of tuples.
+    pub fn len(&self) -> usize {
+        self.tuples.len()
+    }
+
+    /// Whether the relation has no tuples.
+    pub fn is_empty(&self) -> bool {
+        self.tuples.is_empty()
+    }
+
+    /// Whether `tuple` belongs to the relation.
+    pub fn contains(&self, tuple: &[i32]) -> bool {
+        self.tuples.binary_search_by(|t| t.as_slice().cmp(tuple)).is_ok()
+    }
+}
+
+impl fmt::Display for Relation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tuples: Vec<String> = self.tuples.iter()
+            .map(|t| format!("({})", t.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",")))
+            .collect();
+        write!(f, "{{{}}}", tuples.join(", "))
+    }
+}
+
+/// Check whether an operation preserves a relation.
+///
+/// # Arguments
+/// * `op` - The operation
+/// * `relation` - The relation, on the same universe as `op`
+///
+/// # Returns
+/// * `Ok(true)` - If applying `op` to tuples of `relation` coordinatewise
+///   always gives a tuple of `relation`
+/// * `Ok(false)` - Otherwise
+/// * `Err(String)` - If the universes differ, the operation has no table,
+///   or the computation is cancelled
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::clone::{preserves, Relation};
+/// use uacalc::alg::op::{operations, OperationSymbol};
+///
+/// let order = Relation::new(2, 2, vec![vec![0, 0], vec![0, 1], vec![1, 1]]).unwrap();
+/// let meet = operations::make_int_operation(OperationSymbol::new("meet", 2, false), 2, vec![0, 0, 0, 1]).unwrap();
+/// let neg = operations::make_int_operation(OperationSymbol::new("neg", 1, false), 2, vec![1, 0]).unwrap();
+/// assert!(preserves(meet.as_ref(), &order).unwrap());
+/// assert!(!preserves(neg.as_ref(), &order).unwrap());
+/// ```
+pub fn preserves(op: &dyn Operation, relation: &Relation) -> Result<bool, String> {
+    if op.get_set_size() != relation.universe_size {
+        return Err(format!(
+            "The operation is on {} elements but the relation on {}",
+            op.get_set_size(),
+            relation.universe_size
+        ));
+    }
+    let table = operation_table(op)?;
+    let m = op.arity() as usize;
+    let n = relation.universe_size as usize;
+    let tuples = relation.tuples();
+    if m > 0 && tuples.is_empty() {
+        return Ok(true);
+    }
+    // The columns of the matrix, as indices into the relation.
+    let mut columns = vec![0usize; m];
+    let mut image = vec![0i32; relation.arity];
+    let mut steps = 0u32;
+    loop {
+        steps = steps.wrapping_add(1);
+        if steps.is_multiple_of(1024) {
+            crate::progress::check_cancelled()?;
+        }
+        for (i, x) in image.iter_mut().enumerate() {
+            let mut index = 0usize;
+            for &c in columns.iter().rev() {
+                index = index * n + tuples[c][i] as usize;
+            }
+            *x = table[index];
+        }
+        if !relation.contains(&image) {
+            return Ok(false);
+        }
+        // Advance the columns, the first varying fastest.
+        let mut j = 0;
+        loop {
+            if j == m {
+                return Ok(true);
+            }
+            columns[j] += 1;
+            if columns[j] < tuples.len() {
+                break;
+            }
+            columns[j] = 0;
+            j += 1;
+        }
+    }
+}
+
+/// The relations of arity at most `max_arity` preserved by all the
+/// operations: the subuniverses of the powers `A^k`, `1 <= k <= max_arity`,
+/// of the algebra the operations form.
+///
+/// Each subuniverse of `A^k` is a join of subuniverses generated by single
+/// tuples, so those are computed first and joined until no new subuniverse
+/// appears.
+///
+/// # Arguments
+/// * `universe_size` - The size `n` of the universe
+/// * `ops` - The operations, all on `n` elements
+/// * `max_arity` - The largest arity of the relations
+///
+/// # Returns
+/// * `Ok(relations)` - The relations, by arity, then size, then tuples
+/// * `Err(String)` - If an operation is on another universe, a power is too
+///   large, or the computation is cancelled
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::clone::inv;
+/// use uacalc::alg::op::{operations, OperationSymbol};
+///
+/// // The subuniverses of Z2 and of Z2^2 under +.
+/// let plus = operations::make_int_operation(OperationSymbol::new("+", 2, false), 2, vec![0, 1, 1, 0]).unwrap();
+/// let rels = inv(2, &[plus], 2).unwrap();
+/// assert_eq!(rels.iter().filter(|r| r.arity() == 1).count(), 3);
+/// assert_eq!(rels.iter().filter(|r| r.arity() == 2).count(), 6);
+/// ```
+pub fn inv(universe_size: i32, ops: &[Box<dyn Operation>], max_arity: usize) -> Result<Vec<Relation>, String> {
+    if let Some(op) = ops.iter().find(|op| op.get_set_size() != universe_size) {
+        return Err(format!("Operation {} is not on {} elements", op.symbol(), universe_size));
+    }
+    let alg = BasicAlgebra::new(
+        "A".to_string(),
+        (0..universe_size).collect(),
+        ops.iter().map(|op| op.clone_box()).collect(),
+    );
+    let constants: Vec<i32> = ops.iter()
+        .filter(|op| op.arity() == 0)
+        .map(|op| op.int_value_at(&[]))
+        .collect::<Result<_, _>>()?;
+    let mut relations = Vec::new();
+    for k in 1..=max_arity {
+        let mut found = subuniverses(&alg, universe_size, k, &constants)?;
+        found.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+        for tuples in found {
+            relations.push(Relation { universe_size, arity: k, tuples });
+        }
+    }
+    Ok(relations)
+}
+
+/// All the subuniverses of `alg^k`, each a sorted list of tuples.
+fn subuniverses(
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    n: i32,
+    k: usize,
+    constants: &[i32],
+) -> Result<Vec<Vec<Vec<i32>>>, String> {
+    let close = |generators: &[Vec<i32>]| -> Result<Vec<Vec<i32>>, String> {
+        let mut closed = if generators.is_empty() { Vec::new() } else { subpower(alg, generators)? };
+        closed.sort();
+        Ok(closed)
+    };
+    // The least subuniverse is generated by the constants.
+    let least: Vec<Vec<i32>> = if constants.is_empty() {
+        Vec::new()
+    } else {
+        close(&constants.iter().map(|&c| vec![c; k]).collect::<Vec<_>>())?
+    };
+    let mut seen: HashSet<Vec<Vec<i32>>> = HashSet::new();
+    let mut principal: Vec<Vec<Vec<i32>>> = Vec::new();
+    for p in 0..table_size(n, k)? {
+        crate::progress::check_cancelled()?;
+        let t = horner::horner_inv_same_size(p as i32, n, k);
+        let mut gens = least.clone();
+        gens.push(t);
+        let sub = close(&gens)?;
+        if seen.insert(sub.clone()) {
+            principal.push(sub);
+        }
+    }
+    let mut all: Vec<Vec<Vec<i32>>> = principal.clone();
+    if seen.insert(least.clone()) {
+        all.push(least);
+    }
+    let mut next = 0;
+    while next < all.len() {
+        crate::progress::check_cancelled()?;
+        for j in &principal {
+            let x = &all[next];
+            if j.iter().all(|t| x.binary_search(t).is_ok()) {
+                continue;
+            }
+            let mut gens = x.clone();
+            gens.extend(j.iter().cloned());
+            let join = close(&gens)?;
+            if seen.insert(join.clone()) {
+                all.push(join);
+            }
+        }
+        next += 1;
+    }
+    Ok(all)
+}
+
+/// The operations of arity `1..=max_arity` preserving all the relations.
+///
+/// The `m`-ary polymorphisms are the solutions of a constraint problem with
+/// one variable per tuple of `A^m`: for every choice of `m` tuples of a
+/// relation `R`, the rows of the matrix they form must map to a tuple of
+/// `R`.
+///
+/// # Arguments
+/// * `universe_size` - The size `n` of the universe
+/// * `relations` - The relations, all on `n` elements
+/// * `max_arity` - The largest arity of the operations
+///
+/// # Returns
+/// * `Ok(ops)` - The operations, by arity and then table; the `i`-th of
+///   arity `m` is named `p{m}_{i}`
+/// * `Err(String)` - If a relation is on another universe, a power is too
+///   large, or the computation is cancelled
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::clone::{pol, Relation};
+///
+/// // The monotone operations on the two element chain.
+/// let order = Relation::new(2, 2, vec![vec![0, 0], vec![0, 1], vec![1, 1]]).unwrap();
+/// let ops = pol(2, &[order], 2).unwrap();
+/// assert_eq!(ops.iter().filter(|op| op.arity() == 1).count(), 3);
+/// assert_eq!(ops.iter().filter(|op| op.arity() == 2).count(), 6);
+/// ```
+pub fn pol(universe_size: i32, relations: &[Relation], max_arity: usize) -> Result<Vec<Box<dyn Operation>>, String> {
+    if let Some(r) = relations.iter().find(|r| r.universe_size != universe_size) {
+        return Err(format!("Relation {} is not on {} elements", r, universe_size));
+    }
+    let n = universe_size;
+    let mut ops: Vec<Box<dyn Operation>> = Vec::new();
+    for m in 1..=max_arity {
+        let mut tables = polymorphism_problem(n, relations, m)?.all_solutions(None);
+        crate::progress::check_cancelled()?;
+        tables.sort();
+        for (i, table) in tables.into_iter().enumerate() {
+            let sym = OperationSymbol::new_safe(&format!("p{}_{}", m, i), m as i32, false)?;
+            ops.push(operations::make_int_operation(sym, n, table)?);
+        }
+    }
+    Ok(ops)
+}
+
+/// The constraint problem whose solutions are the tables of the `m`-ary
+/// polymorphisms of the relations.
+pub(crate) fn polymorphism_problem(n: i32, relations: &[Relation], m: usize) -> Result<ConstraintProblem, String> {
+    let mut problem = ConstraintProblem::new(table_size(n, m)?, n as usize);
+    for r in relations {
+        let tuples: Arc<[Vec<i32>]> = r.tuples.clone().into();
+        for c in 0..table_size(r.len() as i32, m)? {
+            crate::progress::check_cancelled()?;
+            let columns = horner::horner_inv_same_size(c as i32, r.len() as i32, m);
+            let vars = (0..r.arity)
+                .map(|i| {
+                    let row: Vec<i32> = columns.iter().map(|&j| r.tuples[j as usize][i]).collect();
+                    horner::horner_same_size(&row, n) as usize
+                })
+                .collect();
+            problem.add_constraint(Constraint::Relation { vars, tuples: tuples.clone() });
+        }
+    }
+    Ok(problem)
+}
+
+/// A tuple and the table of a polymorphism producing it.
+type Separation = (Vec<i32>, Vec<i32>);
+
+/// The tuples outside `relation` that some polymorphism of `base` of arity
+/// `m = |relation|` maps the columns of `relation` to, each with the table
+/// of such a polymorphism; only the first one if `first_only`.
+fn separating_polymorphisms(
+    relation: &Relation,
+    base: &[Relation],
+    first_only: bool,
+) -> Result<Vec<Separation>, String> {
+    let n = relation.universe_size;
+    if let Some(r) = base.iter().find(|r| r.universe_size != n) {
+        return Err(format!("Relation {} is not on {} elements", r, n));
+    }
+    let problem = polymorphism_problem(n, base, relation.len())?;
+    // The variable of the i-th row of the matrix whose columns are the
+    // tuples of the relation.
+    let rows: Vec<usize> = (0..relation.arity)
+        .map(|i| {
+            let row: Vec<i32> = relation.tuples.iter().map(|t| t[i]).collect();
+            horner::horner_same_size(&row, n) as usize
+        })
+        .collect();
+    let mut found = Vec::new();
+    for c in 0..table_size(n, relation.arity)? {
+        let tuple = horner::horner_inv_same_size(c as i32, n, relation.arity);
+        if relation.contains(&tuple) {
+            continue;
+        }
+        crate::progress::check_cancelled()?;
+        let mut image = problem.clone();
+        for (&v, &value) in rows.iter().zip(&tuple) {
+            image.fix(v, value);
+        }
+        if let Some(table) = image.all_solutions(Some(1)).pop() {
+            found.push((tuple, table));
+            if first_only {
+                break;
+            }
+        }
+    }
+    Ok(found)
+}
+
+/// The least relation containing `relation` that is primitive positive
+/// definable from `base`.
+///
+/// A relation is pp-definable from `base` exactly when it is preserved by
+/// all the polymorphisms of `base` (Geiger; Bodnarchuk, Kaluzhnin, Kotov,
+/// Romov). The least pp-definable relation containing `R` is the set of
+/// images `f(t_1, ..., t_m)` of the tuples of `R` under the `m`-ary
+/// polymorphisms `f` of `base`, `m = |R|`; each tuple is tested by a
+/// constraint problem on `A^m`, so this is practical only for small `R`.
+///
+/// # Arguments
+/// * `relation` - The relation
+/// * `base` - The relations it is defined from, all on the same universe
+///
+/// # Returns
+/// * `Ok(Relation)` - The least pp-definable relation containing `relation`
+/// * `Err(String)` - If a relation is on another universe, `A^|R|` is too
+///   large, or the computation is cancelled
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::clone::{pp_closure, Relation};
+///
+/// // Every binary relation containing (0, 1) and (1, 0) that is
+/// // pp-definable from the order is the full relation.
+/// let order = Relation::new(2, 2, vec![vec![0, 0], vec![0, 1], vec![1, 1]]).unwrap();
+/// let neq = Relation::new(2, 2, vec![vec![0, 1], vec![1, 0]]).unwrap();
+/// assert_eq!(pp_closure(&neq, &[order]).unwrap().len(), 4);
+/// ```
+pub fn pp_closure(relation: &Relation, base: &[Relation]) -> Result<Relation, String> {
+    let mut tuples = relation.tuples.clone();
+    tuples.extend(separating_polymorphisms(relation, base, false)?.into_iter().map(|(t, _)| t));
+    Relation::new(relation.universe_size, relation.arity, tuples)
+}
+
+/// Test whether `relation` is primitive positive definable from `base`,
+/// that is, defined by a formula built from the relations of `base` and
+/// equality with conjunction and existential quantification.
+///
+/// This holds exactly when every polymorphism of `base` of arity
+/// `|relation|` preserves `relation`; see [`pp_closure`].
+///
+/// # Arguments
+/// * `relation` - The relation
+/// * `base` - The relations it may be defined from, all on the same universe
+///
+/// # Returns
+/// * `Ok(bool)` - Whether `relation` is pp-definable from `base`
+/// * `Err(String)` - If a relation is on another universe, `A^|R|` is too
+///   large, or the computation is cancelled
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::clone::{pp_definable, Relation};
+///
+/// let order = Relation::new(2, 2, vec![vec![0, 0], vec![0, 1], vec![1, 1]]).unwrap();
+/// // x = y is x <= y and y <= x; x != y is not pp-definable from <=.
+/// let eq = Relation::new(2, 2, vec![vec![0, 0], vec![1, 1]]).unwrap();
+/// let neq = Relation::new(2, 2, vec![vec![0, 1], vec![1, 0]]).unwrap();
+/// assert!(pp_definable(&eq, &[order.clone()]).unwrap());
+/// assert!(!pp_definable(&neq, &[order]).unwrap());
+/// ```
+pub fn pp_definable(relation: &Relation, base: &[Relation]) -> Result<bool, String> {
+    Ok(separating_polymorphisms(relation, base, true)?.is_empty())
+}
+
+/// A polymorphism of `base` that does not preserve `relation`, witnessing
+/// that `relation` is not pp-definable from `base`.
+///
+/// # Arguments
+/// * `relation` - The relation
+/// * `base` - The relations, all on the same universe
+///
+/// # Returns
+/// * `Ok(Some(op))` - An operation named `w` of arity `|relation|`
+///   preserving `base` but not `relation`
+/// * `Ok(None)` - If `relation` is pp-definable from `base`
+/// * `Err(String)` - If a relation is on another universe, `A^|R|` is too
+///   large, or the computation is cancelled
+pub fn pp_counterexample(relation: &Relation, base: &[Relation]) -> Result<Option<Box<dyn Operation>>, String> {
+    match separating_polymorphisms(relation, base, true)?.pop() {
+        Some((_, table)) => {
+            let sym = OperationSymbol::new_safe("w", relation.len() as i32, false)?;
+            Ok(Some(operations::make_int_operation(sym, relation.universe_size, table)?))
+        }
+        None => Ok(None),
+    }
+}
+
+/// A property of Boolean operations. Every clone on `{0, 1}` is the set of
+/// operations having some of these properties.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BooleanProperty {
+    /// `f(0, ..., 0) = 0`.
+    ZeroPreserving,
+    /// `f(1, ..., 1) = 1`.
+    OnePreserving,
+    Monotone,
+    /// `f(not x) = not f(x)`.
+    SelfDual,
+    /// A sum of variables and a constant modulo 2.
+    Affine,
+    /// Any `k` zeros of `f` share a zero coordinate; all of them if `None`.
+    ZeroSeparating(Option<usize>),
+    /// Any `k` ones of `f` share a one coordinate; all of them if `None`.
+    OneSeparating(Option<usize>),
+    /// A constant or a disjunction of variables and a constant.
+    Disjunctive,
+    /// A constant or a conjunction of variables and a constant.
+    Conjunctive,
+    /// Depends on at most one variable.
+    EssentiallyUnary,
+    /// A constant or a projection.
+    Trivial,
+}
+
+impl BooleanProperty {
+    /// Whether the operation with `table`, of arity `m`, has the property;
+    /// the bit `i` of an index of the table is the `i`-th argument.
+    fn holds(self, table: &[i32], m: usize) -> Result<bool, String> {
+        let full = (1usize << m) - 1;
+        let indices = 0..table.len();
+        let depends_only_on = |i: usize| indices.clone().all(|x| table[x] == table[x & (1 << i)]);
+        let constant = indices.clone().all(|x| table[x] == table[0]);
+        Ok(match self {
+            BooleanProperty::ZeroPreserving => table[0] == 0,
+            BooleanProperty::OnePreserving => table[full] == 1,
+            BooleanProperty::Monotone => {
+                indices.clone().all(|x| (0..m).all(|i| table[x] <= table[x | (1 << i)]))
+            }
+            BooleanProperty::SelfDual => indices.clone().all(|x| table[x ^ full] == 1 - table[x]),
+            BooleanProperty::Affine => indices.clone().all(|x| {
+                let sum = (0..m).filter(|&i| x & (1 << i) != 0).fold(table[0], |s, i| s ^ table[1 << i] ^ table[0]);
+                table[x] == sum
+            }),
+            BooleanProperty::ZeroSeparating(k) => {
+                let zeros: Vec<usize> = indices.filter(|&x| table[x] == 0).collect();
+                separating(&zeros, full, k)?
+            }
+            BooleanProperty::OneSeparating(k) => {
+                let ones: Vec<usize> = indices.filter(|&x| table[x] == 1).map(|x| x ^ full).collect();
+                separating(&ones, full, k)?
+            }
+            BooleanProperty::Disjunctive => indices.clone().all(|x| {
+                table[x] == (0..m).filter(|&i| x & (1 << i) != 0).fold(table[0], |s, i| s | table[1 << i])
+            }),
+            BooleanProperty::Conjunctive => indices.clone().all(|x| {
+                table[x] == (0..m).filter(|&i| x & (1 << i) == 0).fold(table[full], |s, i| s & table[full ^ (1 << i)])
+            }),
+            BooleanProperty::EssentiallyUnary => constant || (0..m).any(depends_only_on),
+            BooleanProperty::Trivial => {
+                constant || (0..m).any(|i| indices.clone().all(|x| table[x] == ((x >> i) & 1) as i32))
+            }
+        })
+    }
+}
+
+/// Whether every `k` of the sets of zero coordinates `masks` (all of them
+/// if `k` is `None`) have a common element: the fewest masks whose union
+/// is `full` is more than `k`, found by a breadth first search on unions.
+fn separating(masks: &[usize], full: usize, k: Option<usize>) -> Result<bool, String> {
+    let mut seen = vec![false; full + 1];
+    let mut layer: Vec<usize> = Vec::new();
+    for &mask in masks {
+        if !seen[mask] {
+            seen[mask] = true;
+            layer.push(mask);
+        }
+    }
+    let mut cover = 1;
+    while !layer.is_empty() {
+        if seen[full] {
+            return Ok(k.is_some_and(|k| cover > k));
+        }
+        crate::progress::check_cancelled()?;
+        let mut next = Vec::new();
+        for &union in &layer {
+            for &mask in masks {
+                if !seen[union | mask] {
+                    seen[union | mask] = true;
+                    next.push(union | mask);
+                }
+            }
+        }
+        layer = next;
+        cover += 1;
+    }
+    Ok(true)
+}
+
+/// A clone on `{0, 1}`, as it appears in Post's lattice.
+///
+/// Clones are named as in Böhler, Creignou, Reith and Vollmer, "Playing
+/// with Boolean blocks, part I": `BF` is the clone of all operations, `R0`,
+/// `R1`, `M`, `D`, `L` are those preserving 0, preserving 1, monotone,
+/// self-dual and affine, and so on down to the projections `I2`.
+#[derive(Debug)]
+pub struct BooleanClone {
+    name: String,
+    properties: Vec<BooleanProperty>,
+    basis: Vec<Box<dyn Operation>>,
+}
+
+impl Clone for BooleanClone {
+    fn clone(&self) -> Self {
+        BooleanClone {
+            name: self.name.clone(),
+            properties: self.properties.clone(),
+            basis: self.basis.iter().map(|op| op.clone_box()).collect(),
+        }
+    }
+}
+
+impl BooleanClone {
+    /// The standard name of the clone, such as `"M2"` or `"S0^3"`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// A finite set of operations generating the clone.
+    pub fn basis(&self) -> &[Box<dyn Operation>] {
+        &self.basis
+    }
+
+    /// Check whether an operation belongs to the clone.
+    ///
+    /// # Arguments
+    /// * `op` - An operation on `{0, 1}`
+    ///
+    /// # Returns
+    /// * `Ok(bool)` - Whether `op` belongs to the clone
+    /// * `Err(String)` - If `op` is not on two elements or its table is too large
+    pub fn contains(&self, op: &dyn Operation) -> Result<bool, String> {
+        if op.get_set_size() != 2 {
+            return Err(format!("Operation {} is not on two elements", op.symbol()));
+        }
+        let table = operation_table(op)?;
+        for property in &self.properties {
+            if !property.holds(&table, op.arity() as usize)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Whether this clone is contained in `other`.
+    pub fn is_subclone_of(&self, other: &BooleanClone) -> Result<bool, String> {
+        for op in &self.basis {
+            if !other.contains(op.as_ref())? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+impl fmt::Display for BooleanClone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// A Boolean operation of arity `m` whose value at a tuple is `f` of the
+/// tuple as a bit mask, bit `i` being the `i`-th argument.
+fn boolean_op(name: &str, m: usize, f: impl Fn(u32) -> bool) -> Box<dyn Operation> {
+    let table = (0..1u32 << m).map(|x| f(x) as i32).collect();
+    operations::make_int_operation(OperationSymbol::new(name, m as i32, false), 2, table)
+        .expect("the table has 2^m entries in 0..2")
+}
+
+/// The clones of Post's lattice, the lattice of all clones on `{0, 1}`.
+///
+/// The lattice is countably infinite: the clones `S0`, `S1`, `S02`, `S12`,
+/// `S01`, `S11`, `S00` and `S10` of operations separating all their zeros
+/// or ones are the intersections of descending chains `S0^k ⊇ S0^(k+1) ⊇
+/// ...` of clones separating any `k` of them. The chains are listed for
+/// `k` in `2..=max_degree`; an operation of arity `m` is in `S0^m` only if
+/// it is in `S0`, so `max_degree` at least the largest arity of interest
+/// loses nothing.
+///
+/// # Arguments
+/// * `max_degree` - The largest degree `k` of the chains
+///
+/// # Returns
+/// The clones from the top `BF` down, each with a basis
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::clone::post_lattice;
+///
+/// let lattice = post_lattice(3);
+/// assert_eq!(lattice.len(), 38 + 8 * 2);
+/// let m2 = lattice.iter().find(|c| c.name() == "M2").unwrap();
+/// let m = lattice.iter().find(|c| c.name() == "M").unwrap();
+/// assert!(m2.is_subclone_of(m).unwrap());
+/// ```
+pub fn post_lattice(max_degree: usize) -> Vec<BooleanClone> {
+    use BooleanProperty::*;
+    let and = || boolean_op("and", 2, |x| x == 3);
+    let or = || boolean_op("or", 2, |x| x != 0);
+    let zero = || boolean_op("0", 0, |_| false);
+    let one = || boolean_op("1", 0, |_| true);
+    let xor = || boolean_op("xor", 2, |x| x == 1 || x == 2);
+    let iff = || boolean_op("iff", 2, |x| x == 0 || x == 3);
+    let maj = || boolean_op("maj", 3, |x| x.count_ones() >= 2);
+    // x or (y and z), x and (y or z) and their variants with z negated.
+    let or_and = || boolean_op("or_and", 3, |x| x & 1 != 0 || x & 6 == 6);
+    let and_or = || boolean_op("and_or", 3, |x| x & 1 != 0 && x & 6 != 0);
+    let or_and_not = || boolean_op("or_and_not", 3, |x| x & 1 != 0 || x & 6 == 2);
+    let and_or_not = || boolean_op("and_or_not", 3, |x| x & 1 != 0 && x & 6 != 4);
+    // At least k of k + 1 arguments, and at least 2 of them.
+    let threshold = |k: usize| boolean_op(&format!("h{}", k), k + 1, move |x| x.count_ones() as usize >= k);
+    let dual_threshold = |k: usize| boolean_op(&format!("dh{}", k), k + 1, |x| x.count_ones() >= 2);
+
+    let mut clones = Vec::new();
+    let mut add = |name: String, properties: Vec<BooleanProperty>, basis: Vec<Box<dyn Operation>>| {
+        clones.push(BooleanClone { name, properties, basis });
+    };
+    add("BF".into(), vec![], vec![and(), boolean_op("not", 1, |x| x == 0)]);
+    add("R0".into(), vec![ZeroPreserving], vec![and(), xor()]);
+    add("R1".into(), vec![OnePreserving], vec![or(), iff()]);
+    add("R2".into(), vec![ZeroPreserving, OnePreserving], vec![or(), boolean_op("and_iff", 3, |x| x == 1 || x == 7)]);
+    add("M".into(), vec![Monotone], vec![and(), or(), zero(), one()]);
+    add("M0".into(), vec![Monotone, ZeroPreserving], vec![and(), or(), zero()]);
+    add("M1".into(), vec![Monotone, OnePreserving], vec![and(), or(), one()]);
+    add("M2".into(), vec![Monotone, ZeroPreserving, OnePreserving], vec![and(), or()]);
+    let degrees = (2..=max_degree).map(Some).chain([None]);
+    for k in degrees {
+        let suffix = k.map_or(String::new(), |k| format!("^{}", k));
+        let with = |mut basis: Vec<Box<dyn Operation>>, threshold: &dyn Fn(usize) -> Box<dyn Operation>| {
+            basis.extend(k.map(threshold));
+            basis
+        };
+        let (s0, s1) = (ZeroSeparating(k), OneSeparating(k));
+        let imp = boolean_op("imp", 2, |x| x != 1);
+        let nimp = boolean_op("nimp", 2, |x| x == 1);
+        add(format!("S0{}", suffix), vec![s0], with(vec![imp], &dual_threshold));
+        add(format!("S02{}", suffix), vec![s0, ZeroPreserving], with(vec![or_and_not()], &dual_threshold));
+        let s01 = if k.is_some() { vec![one()] } else { vec![or_and(), one()] };
+        add(format!("S01{}", suffix), vec![s0, Monotone], with(s01, &dual_threshold));
+        add(format!("S00{}", suffix), vec![s0, ZeroPreserving, Monotone], with(vec![or_and()], &dual_threshold));
+        add(format!("S1{}", suffix), vec![s1], with(vec![nimp], &threshold));
+        add(format!("S12{}", suffix), vec![s1, OnePreserving], with(vec![and_or_not()], &threshold));
+        let s11 = if k.is_some() { vec![zero()] } else { vec![and_or(), zero()] };
+        add(format!("S11{}", suffix), vec![s1, Monotone], with(s11, &threshold));
+        add(format!("S10{}", suffix), vec![s1, OnePreserving, Monotone], with(vec![and_or()], &threshold));
+    }
+    add("D".into(), vec![SelfDual], vec![boolean_op("d", 3, |x| (x ^ 6).count_ones() >= 2)]);
+    let xor3 = || boolean_op("xor3", 3, |x| x.count_ones() % 2 == 1);
+    add("D1".into(), vec![SelfDual, ZeroPreserving], vec![maj(), xor3()]);
+    add("D2".into(), vec![SelfDual, Monotone], vec![maj()]);
+    add("L".into(), vec![Affine], vec![xor(), one()]);
+    add("L0".into(), vec![Affine, ZeroPreserving], vec![xor()]);
+    add("L1".into(), vec![Affine, OnePreserving], vec![iff()]);
+    add("L2".into(), vec![Affine, ZeroPreserving, OnePreserving], vec![xor3()]);
+    add("L3".into(), vec![Affine, SelfDual], vec![boolean_op("xnor3", 3, |x| x.count_ones() % 2 == 0)]);
+    for (family, property, op) in [("V", Disjunctive, or as fn() -> Box<dyn Operation>), ("E", Conjunctive, and)] {
+        add(family.into(), vec![property], vec![op(), zero(), one()]);
+        add(format!("{}0", family), vec![property, ZeroPreserving], vec![op(), zero()]);
+        add(format!("{}1", family), vec![property, OnePreserving], vec![op(), one()]);
+        add(format!("{}2", family), vec![property, ZeroPreserving, OnePreserving], vec![op()]);
+    }
+    add("N".into(), vec![EssentiallyUnary], vec![boolean_op("not", 1, |x| x == 0), one()]);
+    add("N2".into(), vec![EssentiallyUnary, SelfDual], vec![boolean_op("not", 1, |x| x == 0)]);
+    add("I".into(), vec![Trivial], vec![zero(), one()]);
+    add("I0".into(), vec![Trivial, ZeroPreserving], vec![zero()]);
+    add("I1".into(), vec![Trivial, OnePreserving], vec![one()]);
+    add("I2".into(), vec![Trivial, ZeroPreserving, OnePreserving], vec![]);
+    clones
+}
+
+/// The standard name of the clone on `{0, 1}` generated by some operations,
+/// its place in Post's lattice.
+///
+/// The generated clone is the least clone of [`post_lattice`] containing
+/// the operations.
+///
+/// # Arguments
+/// * `ops` - Operations on `{0, 1}`
+///
+/// # Returns
+/// * `Ok(String)` - The name of the generated clone, as in [`BooleanClone`]
+/// * `Err(String)` - If an operation is not on two elements or its table is
+///   too large
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::clone::classify_boolean_clone;
+/// use uacalc::alg::op::{operations, OperationSymbol};
+///
+/// let and = operations::make_int_operation(OperationSymbol::new("and", 2, false), 2, vec![0, 0, 0, 1]).unwrap();
+/// let xor = operations::make_int_operation(OperationSymbol::new("xor", 2, false), 2, vec![0, 1, 1, 0]).unwrap();
+/// assert_eq!(classify_boolean_clone(&[and.clone_box()]).unwrap(), "E2");
+/// assert_eq!(classify_boolean_clone(&[and, xor]).unwrap(), "R0");
+/// ```
+pub fn classify_boolean_clone(ops: &[Box<dyn Operation>]) -> Result<String, String> {
+    let max_arity = ops.iter().map(|op| op.arity() as usize).max().unwrap_or(0);
+    let mut containing = Vec::new();
+    for clone in post_lattice(max_arity.max(2)) {
+        let mut all = true;
+        for op in ops {
+            if !clone.contains(op.as_ref())? {
+                all = false;
+                break;
+            }
+        }
+        if all {
+            containing.push(clone);
+        }
+    }
+    for clone in &containing {
+        let mut least = true;
+        for other in &containing {
+            if !clone.is_subclone_of(other)? {
+                least = false;
+                break;
+            }
+        }
+        if least {
+            return Ok(clone.name.clone());
+        }
+    }
+    Err("The clones containing the operations have no least element".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pol_inv_galois_connection() {
+        // The two element lattice.
+        let meet = operations::make_int_operation(OperationSymbol::new("meet", 2, false), 2, vec![0, 0, 0, 1]).unwrap();
+        let join = operations::make_int_operation(OperationSymbol::new("join", 2, false), 2, vec![0, 1, 1, 1]).unwrap();
+        let ops = vec![meet, join];
+        let rels = inv(2, &ops, 2).unwrap();
+        assert!(rels.iter().all(|r| ops.iter().all(|op| preserves(op.as_ref(), r).unwrap())));
+        // Binary sublattices of 2^2: all subsets closed under meet and join.
+        assert_eq!(rels.iter().filter(|r| r.arity() == 2).count(), 13);
+
+        // Pol(Inv(F)) is the clone generated by F: the binary lattice
+        // terms are the two projections, meet and join.
+        let clone = pol(2, &rels, 2).unwrap();
+        let binary: Vec<Vec<i32>> = clone.iter()
+            .filter(|op| op.arity() == 2)
+            .map(|op| op.get_table().unwrap().to_vec())
+            .collect();
+        assert_eq!(binary, vec![vec![0, 0, 0, 1], vec![0, 0, 1, 1], vec![0, 1, 0, 1], vec![0, 1, 1, 1]]);
+        assert_eq!(clone.iter().filter(|op| op.arity() == 1).count(), 1);
+
+        assert!(pol(3, &rels, 1).is_err());
+        let constant = operations::make_int_operation(OperationSymbol::new("c", 0, false), 2, vec![1]).unwrap();
+        let rels = inv(2, &[constant], 1).unwrap();
+        // {1} and {0, 1}; the empty set is not closed under the constant.
+        assert_eq!(rels.len(), 2);
+        assert!(rels.iter().all(|r| r.contains(&[1])));
+    }
+
+    #[test]
+    fn test_pp_definable() {
+        let order = [Relation::new(2, 2, vec![vec![0, 0], vec![0, 1], vec![1, 1]]).unwrap()];
+        let neq = [Relation::new(2, 2, vec![vec![0, 1], vec![1, 0]]).unwrap()];
+        let w = pp_counterexample(&neq[0], &order).unwrap().unwrap();
+        assert!(preserves(w.as_ref(), &order[0]).unwrap());
+        assert!(!preserves(w.as_ref(), &neq[0]).unwrap());
+        // Every relation is pp-definable from itself.
+        assert!(pp_counterexample(&neq[0], &neq).unwrap().is_none());
+
+        // The empty relation is pp-definable exactly when no constant
+        // preserves the base: {0} and {1} give x = 0 and x = 1.
+        let empty = Relation::new(2, 1, vec![]).unwrap();
+        let constants = [Relation::new(2, 1, vec![vec![0]]).unwrap(), Relation::new(2, 1, vec![vec![1]]).unwrap()];
+        assert!(pp_definable(&empty, &constants).unwrap());
+        assert!(!pp_definable(&empty, &order).unwrap());
+        assert_eq!(pp_closure(&empty, &constants[..1]).unwrap(), constants[0]);
+
+        // x <= y <= z is pp-definable from <=.
+        let chain = Relation::new(2, 3, vec![vec![0, 0, 0], vec![0, 0, 1], vec![0, 1, 1], vec![1, 1, 1]]).unwrap();
+        assert!(pp_definable(&chain, &order).unwrap());
+        assert!(pp_closure(&order[0], &[Relation::new(3, 2, vec![]).unwrap()]).is_err());
+    }
+
+    #[test]
+    fn test_post_lattice() {
+        let lattice = post_lattice(4);
+        assert_eq!(lattice.len(), 38 + 8 * 3);
+        // Each basis generates its clone, so every clone is classified as
+        // itself.
+        for clone in &lattice {
+            assert_eq!(classify_boolean_clone(clone.basis()).unwrap(), clone.name(), "basis of {}", clone);
+        }
+        let named = |name: &str| lattice.iter().find(|c| c.name() == name).unwrap();
+        for (lower, upper) in [("I2", "D2"), ("S0", "S0^4"), ("S0^4", "S0^3"), ("D2", "M2"), ("L2", "D1"), ("V2", "S00")] {
+            assert!(named(lower).is_subclone_of(named(upper)).unwrap(), "{} <= {}", lower, upper);
+        }
+        assert!(!named("S0^3").is_subclone_of(named("S0^4")).unwrap());
+        assert!(!named("L").is_subclone_of(named("M")).unwrap());
+
+        let maj = operations::make_int_operation(OperationSymbol::new("maj", 3, false), 2, vec![0, 0, 0, 1, 0, 1, 1, 1]).unwrap();
+        assert_eq!(classify_boolean_clone(&[maj]).unwrap(), "D2");
+        // The threshold "at least 2 of 4" separates any 3 of its zeros.
+        let t = operations::make_int_operation(
+            OperationSymbol::new("t", 4, false),
+            2,
+            (0..16u32).map(|x| (x.count_ones() >= 2) as i32).collect(),
+        )
+        .unwrap();
+        assert_eq!(classify_boolean_clone(&[t]).unwrap(), "S00^3");
+        let three = operations::make_int_operation(OperationSymbol::new("c", 0, false), 3, vec![2]).unwrap();
+        assert!(classify_boolean_clone(&[three]).is_err());
+    }
+}
@@ -22,6 +22,7 @@ use crate::util::int_array::{IntArray, IntArrayTrait};
 use crate::terms::{Term, NonVariableTerm};
 use crate::eq::Equation;
 use crate::progress::ProgressReport;
+use crate::telemetry;
 
 /// A class for finding the closure of generating sets in algebras.
 /// 
@@ -60,6 +61,9 @@ where
     /// Whether the closure completed successfully
     completed: bool,
     
+    /// Operation applications of the current closure, for telemetry
+    applications: u64,
+    
     /// Term map from elements to terms that generate them
     term_map: Option<HashMap<IntArray, Box<dyn Term>>>,
     
@@ -161,13 +165,15 @@ where
             generators: Vec::new(),
             ans: Vec::new(),
             completed: false,
+            applications: 0,
             term_map: None,
             elt_to_find: None,
             elts_to_find: None,
             indeces_map_of_found_elts: None,
             all_elts_found: false,
             special_elts_found: 0,
-            report: None,
+            // Report to the thread's progress token, if one is installed.
+            report: crate::progress::current().map(|t| Arc::new(t) as Arc<dyn ProgressReport>),
             suppress_output: false,
             max_size: None,
             blocks: None,
@@ -357,6 +363,15 @@ where
         self.all_elts_found
     }
     
+    /// Stop with an error if the reporter or the thread's progress token
+    /// has been cancelled or has exceeded its budget.
+    fn check_cancelled(&self) -> Result<(), String> {
+        if let Some(ref report) = self.report {
+            report.check_cancelled()?;
+        }
+        crate::progress::check_cancelled()
+    }
+
     /// Set the progress reporter.
     /// 
     /// # Arguments
@@ -647,6 +662,11 @@ where
     /// // let closure = closer.sg_close()?;
     /// ```
     pub fn sg_close(&mut self) -> Result<Vec<IntArray>, String> {
+        self.recorded(Self::sg_close_dispatch)
+    }
+    
+    /// Choose the closure algorithm for the algebra and run it.
+    fn sg_close_dispatch(&mut self) -> Result<Vec<IntArray>, String> {
         // Check if algebra is a power algebra and use specialized path
         // Java uses sgClosePower for ALL power algebras, including free algebras
         if self.algebra.is_power() {
@@ -687,7 +707,18 @@ where
     /// // let closure = closer.sg_close_power()?;
     /// ```
     pub fn sg_close_power(&mut self) -> Result<Vec<IntArray>, String> {
-        self.sg_close_power_impl(0)
+        self.recorded(|closer| closer.sg_close_power_impl(0))
+    }
+    
+    /// Run a closure algorithm as a telemetry phase, counting the elements
+    /// of the closure and the operation applications.
+    fn recorded(&mut self, close: impl FnOnce(&mut Self) -> Result<Vec<IntArray>, String>) -> Result<Vec<IntArray>, String> {
+        let _phase = telemetry::phase("closure");
+        self.applications = 0;
+        let result = close(self);
+        telemetry::count(telemetry::APPLICATIONS, self.applications);
+        telemetry::count(telemetry::ELEMENTS, self.ans.len() as u64);
+        result
     }
     
     /// Implementation of closure computation.
@@ -806,11 +837,13 @@ where
         
         // Main closure loop
         while closed_mark < current_mark {
+            self.check_cancelled()?;
             let status_str = format!("pass: {}, size: {}", pass, self.ans.len());
             
             if let Some(ref report) = self.report {
                 report.set_pass(pass);
                 report.set_pass_size(self.ans.len());
+                report.set_size(self.ans.len());
                 if !self.suppress_output {
                     report.add_line(&status_str);
                 }
@@ -819,6 +852,9 @@ where
             }
             
             pass += 1;
+            telemetry::count(telemetry::PASSES, 1);
+            #[cfg(feature = "tracing")]
+            tracing::debug!(pass, size = self.ans.len(), "closure pass");
             
             // Check max size
             if let Some(max_size) = self.max_size {
@@ -879,6 +915,10 @@ where
                     // Get current indices (use get_current to avoid borrow issues)
                     let indices = inc.get_current();
                     combination_count += 1;
+                    self.applications += 1;
+                    if combination_count % 4096 == 0 {
+                        self.check_cancelled()?;
+                    }
                     
                     // Collect arguments - check bounds to avoid out-of-range access
                     // Note: The incrementor generates indices in range [0, original_current_mark-1],
@@ -1431,11 +1471,13 @@ where
         
         // Main closure loop
         while closed_mark < current_mark {
+            self.check_cancelled()?;
             let status_str = format!("pass: {}, size: {}", pass, self.ans.len());
             
             if let Some(ref report) = self.report {
                 report.set_pass(pass);
                 report.set_pass_size(self.ans.len());
+                report.set_size(self.ans.len());
                 if !self.suppress_output {
                     report.add_line(&status_str);
                 }
@@ -1450,6 +1492,9 @@ where
             }
             
             pass += 1;
+            telemetry::count(telemetry::PASSES, 1);
+            #[cfg(feature = "tracing")]
+            tracing::debug!(pass, size = self.ans.len(), "closure pass");
             
             // Apply operations to expand the closure
             use crate::util::SequenceGenerator;
@@ -1487,6 +1532,10 @@ where
                 loop {
                     let indices = inc.get_current();
                     combo_count += 1;
+                    self.applications += 1;
+                    if combo_count % 4096 == 0 {
+                        self.check_cancelled()?;
+                    }
                     
                     // DEBUG: Print first few combinations
                     if combo_count <= 10 {
@@ -1877,6 +1926,11 @@ where
     /// * `Ok(Vec<IntArray>)` - The closure (list of elements)
     /// * `Err(String)` - If closure computation fails
     pub fn sg_close_parallel(&mut self) -> Result<Vec<IntArray>, String> {
+        self.recorded(Self::sg_close_parallel_impl)
+    }
+    
+    /// Implementation of the parallel closure computation.
+    fn sg_close_parallel_impl(&mut self) -> Result<Vec<IntArray>, String> {
         if let Some(ref report) = self.report {
             report.add_start_line("subpower closing ...");
         }
@@ -1938,6 +1992,7 @@ where
         
         // Main closure loop
         while closed_mark < current_mark {
+            self.check_cancelled()?;
             let status_str = format!("pass: {}, size: {}", pass, self.ans.len());
             
             if let Some(ref report) = self.report {
@@ -1948,6 +2003,7 @@ where
                 }
                 report.set_pass(pass);
                 report.set_pass_size(self.ans.len());
+                report.set_size(self.ans.len());
                 if !self.suppress_output {
                     report.add_line(&status_str);
                 }
@@ -1956,6 +2012,9 @@ where
             }
             
             pass += 1;
+            telemetry::count(telemetry::PASSES, 1);
+            #[cfg(feature = "tracing")]
+            tracing::debug!(pass, size = self.ans.len(), "closure pass");
             
             // Check max size
             if let Some(max_size) = self.max_size {
@@ -1980,6 +2039,11 @@ where
                     continue;
                 }
                 
+                // SingleClose applies op to the tuples from 0..current_mark
+                // with an entry from closed_mark on
+                let arity = arity as u32;
+                self.applications += (current_mark as u64).saturating_pow(arity).saturating_sub((closed_mark as u64).saturating_pow(arity));
+                
                 // Create SingleClose instance
                 let univ_list = self.ans.clone();
                 let op_arc = Arc::from(op.clone_box());
@@ -2118,6 +2182,7 @@ where
             generators: self.generators.clone(),
             ans: self.ans.clone(),
             completed: self.completed,
+            applications: 0,
             term_map: None, // Can't clone term map easily
             elt_to_find: self.elt_to_find.clone(),
             elts_to_find: self.elts_to_find.clone(),
@@ -2229,5 +2294,34 @@ mod tests {
         // The closure should include at least the generator and the constant
         assert!(result.len() >= 2, "Closure should have at least generator and constant");
     }
+    
+    #[test]
+    fn test_element_budget_keeps_partial_answer() {
+        use crate::alg::op::{OperationSymbol, operations};
+        use crate::progress::{Budget, BudgetResource, ProgressToken};
+        
+        // The successor on Z_8: the closure of [0, 0] grows by one element per pass
+        let s_sym = OperationSymbol::new_safe("s", 1, false).unwrap();
+        let s_op = operations::make_int_operation(s_sym, 8, (1..=8).map(|i| i % 8).collect()).unwrap();
+        let alg1 = Box::new(BasicAlgebra::new(
+            "Z8".to_string(),
+            (0..8).collect(),
+            vec![s_op]
+        )) as Box<dyn crate::alg::SmallAlgebra<UniverseItem = i32>>;
+        let algebra = Arc::new(
+            BigProductAlgebra::<i32>::new_power_safe(alg1, 2).unwrap()
+        );
+        let generators = vec![IntArray::from_array(vec![0, 0]).unwrap()];
+        
+        let token = ProgressToken::new();
+        token.set_budget(Budget { max_elements: Some(3), ..Default::default() });
+        let mut closer = Closer::<i32>::new_safe(algebra, generators).unwrap();
+        closer.set_progress_report(Some(Arc::new(token.clone())));
+        let result = closer.sg_close();
+        
+        assert!(result.unwrap_err().starts_with("Budget exceeded"));
+        assert_eq!(token.budget_exceeded().unwrap().resource, BudgetResource::Elements);
+        let partial = closer.get_answer().len();
+        assert!((4..8).contains(&partial), "partial closure size {}", partial);
+    }
 }
-
@@ -405,6 +405,55 @@ impl CloserTiming {
     pub fn get_arities(&self) -> &[i32] {
         &self.arities
     }
+    
+    /// Get the size of the current pass.
+    /// 
+    /// # Returns
+    /// The number of elements at the start of the current pass
+    pub fn get_curr_pass_size(&self) -> u32 {
+        self.curr_pass_size
+    }
+    
+    /// Get the size of the previous pass.
+    /// 
+    /// # Returns
+    /// The number of elements at the start of the previous pass
+    pub fn get_last_pass_size(&self) -> u32 {
+        self.last_pass_size
+    }
+    
+    /// Get the number of elements found so far for the next pass.
+    /// 
+    /// # Returns
+    /// The next pass size counter
+    pub fn get_next_pass_size(&self) -> u32 {
+        self.next_pass_size.load(Ordering::SeqCst).max(0) as u32
+    }
+    
+    /// Get the number of coordinate applications the current pass needs.
+    /// 
+    /// # Returns
+    /// The applications needed, or `u64::MAX` if too many to count
+    pub fn get_apps_needed(&self) -> u64 {
+        self.apps_needed
+    }
+    
+    /// Get the number of coordinate applications made in the current pass.
+    /// 
+    /// # Returns
+    /// The applications made so far
+    pub fn get_apps_this_pass(&self) -> u64 {
+        self.apps_this_pass
+    }
+    
+    /// Get the measured time per application, once enough applications
+    /// have been made to measure it.
+    /// 
+    /// # Returns
+    /// Milliseconds per application, 0 until measured
+    pub fn get_ms_per_app(&self) -> f64 {
+        self.ms_per_app
+    }
 }
 
 #[cfg(test)]
@@ -444,6 +493,10 @@ mod tests {
         assert_eq!(timing.get_pass(), 2);
         assert_eq!(timing.curr_pass_size, 20);
         assert_eq!(timing.last_pass_size, 10);
+        assert_eq!(timing.get_curr_pass_size(), 20);
+        assert_eq!(timing.get_last_pass_size(), 10);
+        // Two binary operations over two factors: 2 * (20^2 - 10^2) * 2.
+        assert_eq!(timing.get_apps_needed(), 1200);
     }
     
     #[test]
@@ -0,0 +1,521 @@
+/*! Congruence regularity, uniformity and coherence.
+
+An algebra is congruence regular if a congruence is determined by any one of
+its blocks, congruence uniform if all blocks of each congruence have the
+same size, and congruence coherent if a subuniverse containing a block of a
+congruence is a union of blocks of that congruence. The checks for a single
+algebra run through Con(A).
+
+For the variety V generated by A the tests take place in the free algebra
+`F = F_V(x, y, z)`. Let `Ψ = Cg(x, y)` and let `S` be the block of `z`
+modulo `Ψ`, that is the ternary terms with `t(x, x, z) = z`.
+
+* V is congruence regular if and only if `(x, y) ∈ Cg(S × {z})`, which is
+  Csákány's condition: there are ternary terms `t_i` with `t_i(x, x, z) = z`
+  such that `t_i(x, y, z) = z` for all `i` implies `x = y`. When it fails,
+  `Cg(S × {z})` and `Ψ` are distinct congruences of F sharing the block `S`.
+* V is congruence coherent if and only if `x ∈ Sg(S ∪ {y})`, which is
+  Geiger's condition: `x = q(y, t_1(x, y, z), ..., t_n(x, y, z))` for a term
+  `q` and terms `t_i` as above. When it fails, `Sg(S ∪ {y})` contains the
+  block `S` of `Ψ` but not `x`.
+
+Congruence uniformity is not characterized by a Malcev condition, so for
+varieties it can only be refuted: a uniform variety is congruence regular
+(Taylor), and every algebra in it, A and F among them, is uniform.
+*/
+
+use std::collections::HashMap;
+use std::fmt;
+use crate::alg::{Algebra, BasicAlgebra, FreeAlgebra, SmallAlgebra};
+use crate::alg::conlat::CongruenceLattice;
+use crate::alg::conlat::partition::Partition;
+use crate::alg::op::operations::make_int_operation;
+use crate::alg::sublat::BasicSet;
+use crate::alg::subuniverse_graph::close;
+
+/// A counterexample to congruence regularity, uniformity or coherence.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CongruenceWitness {
+    /// Two different congruences with a common block.
+    SharedBlock {
+        theta: Partition,
+        phi: Partition,
+        block: Vec<usize>,
+    },
+    /// A congruence whose blocks are not all of the same size.
+    NonUniform {
+        theta: Partition,
+    },
+    /// A subuniverse containing a block of `theta` that is not a union of
+    /// blocks of `theta`.
+    NotUnionOfBlocks {
+        theta: Partition,
+        block: Vec<usize>,
+        subuniverse: BasicSet,
+    },
+}
+
+impl CongruenceWitness {
+    /// The congruences of the counterexample.
+    pub fn congruences(&self) -> Vec<&Partition> {
+        match self {
+            CongruenceWitness::SharedBlock { theta, phi, .. } => vec![theta, phi],
+            CongruenceWitness::NonUniform { theta } => vec![theta],
+            CongruenceWitness::NotUnionOfBlocks { theta, .. } => vec![theta],
+        }
+    }
+}
+
+fn block_string(block: &[usize]) -> String {
+    let elems: Vec<String> = block.iter().map(|e| e.to_string()).collect();
+    format!("{{{}}}", elems.join(","))
+}
+
+impl fmt::Display for CongruenceWitness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CongruenceWitness::SharedBlock { theta, phi, block } => {
+                write!(f, "{} and {} share the block {}", theta, phi, block_string(block))
+            }
+            CongruenceWitness::NonUniform { theta } => {
+                write!(f, "{} has blocks of different sizes", theta)
+            }
+            CongruenceWitness::NotUnionOfBlocks { theta, block, subuniverse } => {
+                write!(f, "{} contains the block {} of {} but is not a union of its blocks",
+                    subuniverse, block_string(block), theta)
+            }
+        }
+    }
+}
+
+/// The outcome of a regularity, uniformity or coherence check with its
+/// counterexample on failure.
+#[derive(Debug, Clone)]
+pub struct CongruenceRegularityReport {
+    /// The property checked: "regular", "uniform" or "coherent".
+    pub property: &'static str,
+    /// Whether the property holds; `None` when a variety check could
+    /// neither prove nor refute it.
+    pub holds: Option<bool>,
+    /// A counterexample when the property fails.
+    pub witness: Option<CongruenceWitness>,
+    /// The indices of `x`, `y` and `z` when the counterexample lives in the
+    /// free algebra `F_V(3)` rather than in the algebra itself. Its elements
+    /// are numbered as in `FreeAlgebra::new_safe(alg, 3)`.
+    pub free_generators: Option<[usize; 3]>,
+}
+
+impl CongruenceRegularityReport {
+    fn new(property: &'static str, witness: Option<CongruenceWitness>) -> Self {
+        CongruenceRegularityReport { property, holds: Some(witness.is_none()), witness, free_generators: None }
+    }
+
+    /// Whether the property is known to hold.
+    pub fn holds(&self) -> bool {
+        self.holds == Some(true)
+    }
+}
+
+impl fmt::Display for CongruenceRegularityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.holds, &self.witness) {
+            (Some(true), _) => write!(f, "holds"),
+            (_, Some(witness)) => {
+                let place = if self.free_generators.is_some() { " in F(3)" } else { "" };
+                write!(f, "not congruence {}{}: {}", self.property, place, witness)
+            }
+            _ => write!(f, "unknown: no counterexample found"),
+        }
+    }
+}
+
+fn congruences(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<Vec<Partition>, String> {
+    let mut con = CongruenceLattice::new(alg.clone_box());
+    Ok(con.try_universe()?.clone())
+}
+
+/// Whether the subuniverse `s` is a union of blocks of `theta`.
+fn is_union_of_blocks(theta: &Partition, s: &BasicSet) -> bool {
+    (0..theta.universe_size()).all(|i| s.contains(i as i32) == s.contains(theta.representative(i) as i32))
+}
+
+/// The subuniverse generated by the constants of the algebra.
+fn bottom(ops: &[&dyn crate::alg::op::Operation], n: i32) -> Result<BasicSet, String> {
+    let mut constants = Vec::new();
+    for op in ops.iter().filter(|op| op.arity() == 0) {
+        constants.push(op.int_value_at(&[])?);
+    }
+    close(ops, n, &BasicSet::new(Vec::new())?, &constants)
+}
+
+/// Check whether an algebra is congruence regular: no two different
+/// congruences have a block in common.
+///
+/// # Arguments
+/// * `alg` - The algebra
+///
+/// # Returns
+/// * `Ok(CongruenceRegularityReport)` - With a `SharedBlock` witness when
+///   the algebra is not congruence regular
+/// * `Err(String)` - If the computation was cancelled
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use uacalc::alg::BasicAlgebra;
+/// use uacalc::alg::op::{OperationSymbol, operations};
+/// use uacalc::alg::congruence_regularity::check_congruence_regular;
+///
+/// // The 3-element chain: 0 and |0 1|2| share the block {2}
+/// let meet = OperationSymbol::new("meet", 2, false);
+/// let op = operations::make_int_operation(meet, 3, (0..9).map(|k| (k % 3).min(k / 3)).collect()).unwrap();
+/// let alg = BasicAlgebra::new("C3".to_string(), (0..3).collect::<HashSet<i32>>(), vec![op]);
+///
+/// let report = check_congruence_regular(&alg).unwrap();
+/// assert!(!report.holds());
+/// assert_eq!(report.witness.unwrap().congruences().len(), 2);
+/// ```
+pub fn check_congruence_regular(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<CongruenceRegularityReport, String> {
+    let mut owner: HashMap<Vec<usize>, usize> = HashMap::new();
+    let cons = congruences(alg)?;
+    for (i, theta) in cons.iter().enumerate() {
+        for block in theta.get_blocks() {
+            if let Some(&j) = owner.get(&block) {
+                let witness = CongruenceWitness::SharedBlock { theta: cons[j].clone(), phi: theta.clone(), block };
+                return Ok(CongruenceRegularityReport::new("regular", Some(witness)));
+            }
+            owner.insert(block, i);
+        }
+    }
+    Ok(CongruenceRegularityReport::new("regular", None))
+}
+
+/// Check whether an algebra is congruence uniform: the blocks of each
+/// congruence all have the same size.
+///
+/// # Returns
+/// * `Ok(CongruenceRegularityReport)` - With a `NonUniform` witness when
+///   the algebra is not congruence uniform
+/// * `Err(String)` - If the computation was cancelled
+pub fn check_congruence_uniform(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<CongruenceRegularityReport, String> {
+    let witness = congruences(alg)?.into_iter()
+        .find(|theta| !is_uniform(theta))
+        .map(|theta| CongruenceWitness::NonUniform { theta });
+    Ok(CongruenceRegularityReport::new("uniform", witness))
+}
+
+fn is_uniform(theta: &Partition) -> bool {
+    let blocks = theta.get_blocks();
+    blocks.iter().all(|b| b.len() == blocks[0].len())
+}
+
+/// Check whether an algebra is congruence coherent: a subuniverse that
+/// contains a block of a congruence is a union of its blocks.
+///
+/// A subuniverse containing the block `B` contains `Sg(B)`, and is a union
+/// of blocks exactly when each `Sg(B ∪ {a})` inside it is, so only these
+/// subuniverses are generated; Sub(A) is never built.
+///
+/// # Returns
+/// * `Ok(CongruenceRegularityReport)` - With a `NotUnionOfBlocks` witness
+///   when the algebra is not congruence coherent
+/// * `Err(String)` - If an operation cannot be evaluated or the computation
+///   was cancelled
+pub fn check_congruence_coherent(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<CongruenceRegularityReport, String> {
+    let n = alg.cardinality();
+    let ops = alg.get_operations_ref();
+    let bottom = bottom(&ops, n)?;
+    for theta in congruences(alg)? {
+        crate::progress::check_cancelled()?;
+        if theta.is_zero() || theta.number_of_blocks() == 1 {
+            continue;
+        }
+        for block in theta.get_blocks() {
+            let gens: Vec<i32> = block.iter().map(|&e| e as i32).collect();
+            let sub = close(&ops, n, &bottom, &gens)?;
+            let mut candidates = vec![sub.clone()];
+            for a in (0..n).filter(|&a| !sub.contains(a)) {
+                candidates.push(close(&ops, n, &sub, &[a])?);
+            }
+            if let Some(subuniverse) = candidates.into_iter().find(|s| !is_union_of_blocks(&theta, s)) {
+                let witness = CongruenceWitness::NotUnionOfBlocks { theta, block, subuniverse };
+                return Ok(CongruenceRegularityReport::new("coherent", Some(witness)));
+            }
+        }
+    }
+    Ok(CongruenceRegularityReport::new("coherent", None))
+}
+
+/// The free algebra `F_V(x, y, z)` of the variety generated by `alg`, on the
+/// indices of its elements, together with `Cg(x, y)`, the block `S` of `z`
+/// and the indices of `x`, `y` and `z`.
+struct FreeWitness {
+    alg: BasicAlgebra<i32>,
+    con: CongruenceLattice<i32>,
+    psi: Partition,
+    block: Vec<usize>,
+    gens: [usize; 3],
+}
+
+impl FreeWitness {
+    fn new(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<Self, String> {
+        let free = FreeAlgebra::new_safe(alg.clone_box(), 3)?;
+        let size = free.cardinality();
+        let mut int_ops = Vec::new();
+        for op in free.get_operations_ref() {
+            let table = crate::terms::compiled_term::operation_table(op)?;
+            int_ops.push(make_int_operation(op.symbol().clone(), size, table.to_vec())?);
+        }
+        let inner = free.get_inner();
+        let mut gens = [0; 3];
+        for (g, elem) in gens.iter_mut().zip(inner.generators()) {
+            *g = inner.element_index(elem)
+                .ok_or_else(|| "Free generator not found in the free algebra".to_string())?;
+        }
+        let f = BasicAlgebra::new(free.name().to_string(), (0..size).collect(), int_ops);
+        let mut con = CongruenceLattice::new(Box::new(f.clone()) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+        let [x, y, z] = gens;
+        let psi = con.cg(x, y);
+        let block = (0..size as usize).filter(|&i| psi.is_related(i, z)).collect();
+        Ok(FreeWitness { alg: f, con, psi, block, gens })
+    }
+
+    fn report(&self, property: &'static str, witness: Option<CongruenceWitness>) -> CongruenceRegularityReport {
+        let mut report = CongruenceRegularityReport::new(property, witness);
+        if report.witness.is_some() {
+            report.free_generators = Some(self.gens);
+        }
+        report
+    }
+
+    /// Csákány's condition, or the two congruences sharing the block `S`.
+    fn regularity_failure(&self) -> Result<Option<CongruenceWitness>, String> {
+        let [x, y, z] = self.gens;
+        let indices: Vec<usize> = (0..self.alg.cardinality() as usize)
+            .map(|i| if self.psi.is_related(i, z) { z } else { i })
+            .collect();
+        let phi = self.con.cg_partition(&Partition::from_block_index_vector(&indices)?);
+        if phi.is_related(x, y) {
+            return Ok(None);
+        }
+        Ok(Some(CongruenceWitness::SharedBlock { theta: phi, phi: self.psi.clone(), block: self.block.clone() }))
+    }
+
+    /// Geiger's condition, or `Sg(S ∪ {y})`, which misses `x`.
+    fn coherence_failure(&self) -> Result<Option<CongruenceWitness>, String> {
+        let [x, y, _] = self.gens;
+        let n = self.alg.cardinality();
+        let ops = self.alg.get_operations_ref();
+        let mut gens: Vec<i32> = self.block.iter().map(|&e| e as i32).collect();
+        gens.push(y as i32);
+        let subuniverse = close(&ops, n, &bottom(&ops, n)?, &gens)?;
+        if subuniverse.contains(x as i32) {
+            return Ok(None);
+        }
+        Ok(Some(CongruenceWitness::NotUnionOfBlocks { theta: self.psi.clone(), block: self.block.clone(), subuniverse }))
+    }
+}
+
+/// Check whether the variety generated by an algebra is congruence regular.
+///
+/// A counterexample in the algebra itself is looked for first; otherwise
+/// Csákány's condition is tested in `F_V(3)`, whose size bounds the cost.
+///
+/// # Arguments
+/// * `alg` - The algebra
+///
+/// # Returns
+/// * `Ok(CongruenceRegularityReport)` - With a `SharedBlock` witness when
+///   the variety is not congruence regular; `free_generators` is set when
+///   the witness is in `F_V(3)`
+/// * `Err(String)` - If the free algebra cannot be built or the computation
+///   was cancelled
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use uacalc::alg::BasicAlgebra;
+/// use uacalc::alg::op::{OperationSymbol, operations};
+/// use uacalc::alg::congruence_regularity::{check_congruence_regular, check_variety_congruence_regular};
+///
+/// // The 2-element semilattice is congruence regular, but not its variety
+/// let meet = OperationSymbol::new("meet", 2, false);
+/// let op = operations::make_int_operation(meet, 2, vec![0, 0, 0, 1]).unwrap();
+/// let alg = BasicAlgebra::new("S2".to_string(), (0..2).collect::<HashSet<i32>>(), vec![op]);
+///
+/// assert!(check_congruence_regular(&alg).unwrap().holds());
+/// let report = check_variety_congruence_regular(&alg).unwrap();
+/// assert!(!report.holds());
+/// assert!(report.free_generators.is_some());
+/// ```
+pub fn check_variety_congruence_regular(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<CongruenceRegularityReport, String> {
+    let report = check_congruence_regular(alg)?;
+    if !report.holds() {
+        return Ok(report);
+    }
+    let free = FreeWitness::new(alg)?;
+    Ok(free.report("regular", free.regularity_failure()?))
+}
+
+/// Check whether the variety generated by an algebra is congruence
+/// coherent.
+///
+/// A counterexample in the algebra itself is looked for first; otherwise
+/// Geiger's condition is tested in `F_V(3)`.
+///
+/// # Returns
+/// * `Ok(CongruenceRegularityReport)` - With a `NotUnionOfBlocks` witness
+///   when the variety is not congruence coherent
+/// * `Err(String)` - As for `check_variety_congruence_regular`, or if an
+///   operation cannot be evaluated
+pub fn check_variety_congruence_coherent(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<CongruenceRegularityReport, String> {
+    let report = check_congruence_coherent(alg)?;
+    if !report.holds() {
+        return Ok(report);
+    }
+    let free = FreeWitness::new(alg)?;
+    Ok(free.report("coherent", free.coherence_failure()?))
+}
+
+/// Look for a counterexample to congruence uniformity of the variety
+/// generated by an algebra.
+///
+/// Uniformity has no Malcev characterization, so the check can only refute
+/// it: by a non-uniform congruence of the algebra, by a failure of
+/// congruence regularity, which uniform varieties have, or by `Cg(x, y)`
+/// in `F_V(3)` not being uniform.
+///
+/// # Returns
+/// * `Ok(CongruenceRegularityReport)` - With `holds` false and a witness
+///   when a counterexample was found, and `None` otherwise
+/// * `Err(String)` - As for `check_variety_congruence_regular`
+pub fn check_variety_congruence_uniform(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<CongruenceRegularityReport, String> {
+    let mut report = check_congruence_uniform(alg)?;
+    if !report.holds() {
+        return Ok(report);
+    }
+    let free = FreeWitness::new(alg)?;
+    let witness = match free.regularity_failure()? {
+        Some(witness) => Some(witness),
+        None if !is_uniform(&free.psi) => Some(CongruenceWitness::NonUniform { theta: free.psi.clone() }),
+        None => None,
+    };
+    if witness.is_some() {
+        return Ok(free.report("uniform", witness));
+    }
+    report.holds = if alg.cardinality() <= 1 { Some(true) } else { None };
+    Ok(report)
+}
+
+/// Test whether an algebra is congruence regular.
+pub fn is_congruence_regular(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<bool, String> {
+    Ok(check_congruence_regular(alg)?.holds())
+}
+
+/// Test whether an algebra is congruence uniform.
+pub fn is_congruence_uniform(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<bool, String> {
+    Ok(check_congruence_uniform(alg)?.holds())
+}
+
+/// Test whether an algebra is congruence coherent.
+pub fn is_congruence_coherent(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<bool, String> {
+    Ok(check_congruence_coherent(alg)?.holds())
+}
+
+/// Test whether the variety generated by an algebra is congruence regular.
+pub fn variety_is_congruence_regular(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<bool, String> {
+    Ok(check_variety_congruence_regular(alg)?.holds())
+}
+
+/// Test whether the variety generated by an algebra is congruence coherent.
+pub fn variety_is_congruence_coherent(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<bool, String> {
+    Ok(check_variety_congruence_coherent(alg)?.holds())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::op::OperationSymbol;
+
+    fn zn(n: i32) -> BasicAlgebra<i32> {
+        let add = make_int_operation(OperationSymbol::new("add", 2, false), n, (0..n * n).map(|k| (k % n + k / n) % n).collect()).unwrap();
+        BasicAlgebra::new(format!("Z{}", n), (0..n).collect(), vec![add])
+    }
+
+    fn chain(n: i32) -> BasicAlgebra<i32> {
+        let join = make_int_operation(OperationSymbol::new("join", 2, false), n, (0..n * n).map(|k| (k % n).max(k / n)).collect()).unwrap();
+        let meet = make_int_operation(OperationSymbol::new("meet", 2, false), n, (0..n * n).map(|k| (k % n).min(k / n)).collect()).unwrap();
+        BasicAlgebra::new(format!("C{}", n), (0..n).collect(), vec![join, meet])
+    }
+
+    #[test]
+    fn test_groups() {
+        let z4 = zn(4);
+        assert!(is_congruence_regular(&z4).unwrap());
+        assert!(is_congruence_uniform(&z4).unwrap());
+        assert!(is_congruence_coherent(&z4).unwrap());
+        let z2 = zn(2);
+        assert!(variety_is_congruence_regular(&z2).unwrap());
+        assert!(variety_is_congruence_coherent(&z2).unwrap());
+        let report = check_variety_congruence_uniform(&z2).unwrap();
+        assert_eq!(report.holds, None);
+        assert_eq!(report.to_string(), "unknown: no counterexample found");
+    }
+
+    #[test]
+    fn test_chain_witnesses() {
+        let c3 = chain(3);
+        let report = check_congruence_regular(&c3).unwrap();
+        match report.witness.as_ref().unwrap() {
+            CongruenceWitness::SharedBlock { theta, phi, block } => {
+                assert_ne!(theta, phi);
+                assert!(theta.get_blocks().contains(block) && phi.get_blocks().contains(block));
+            }
+            w => panic!("unexpected witness {}", w),
+        }
+        assert!(report.to_string().starts_with("not congruence regular: "));
+        assert!(!is_congruence_uniform(&c3).unwrap());
+
+        let report = check_congruence_coherent(&c3).unwrap();
+        match report.witness.unwrap() {
+            CongruenceWitness::NotUnionOfBlocks { theta, block, subuniverse } => {
+                assert!(block.iter().all(|&e| subuniverse.contains(e as i32)));
+                assert!(!is_union_of_blocks(&theta, &subuniverse));
+            }
+            w => panic!("unexpected witness {}", w),
+        }
+    }
+
+    #[test]
+    fn test_two_element_lattice() {
+        // The 2-element lattice has all three properties, but its variety
+        // has none of them.
+        let c2 = chain(2);
+        assert!(is_congruence_regular(&c2).unwrap());
+        assert!(is_congruence_uniform(&c2).unwrap());
+        assert!(is_congruence_coherent(&c2).unwrap());
+
+        let report = check_variety_congruence_regular(&c2).unwrap();
+        assert_eq!(report.holds, Some(false));
+        let [x, y, z] = report.free_generators.unwrap();
+        match report.witness.as_ref().unwrap() {
+            CongruenceWitness::SharedBlock { theta, phi, block } => {
+                assert!(phi.is_related(x, y) && !theta.is_related(x, y));
+                assert!(block.contains(&z));
+            }
+            w => panic!("unexpected witness {}", w),
+        }
+        assert!(report.to_string().starts_with("not congruence regular in F(3): "));
+
+        let report = check_variety_congruence_coherent(&c2).unwrap();
+        let [x, y, _] = report.free_generators.unwrap();
+        match report.witness.unwrap() {
+            CongruenceWitness::NotUnionOfBlocks { theta, subuniverse, .. } => {
+                assert!(theta.is_related(x, y));
+                assert!(subuniverse.contains(y as i32) && !subuniverse.contains(x as i32));
+            }
+            w => panic!("unexpected witness {}", w),
+        }
+        assert_eq!(check_variety_congruence_uniform(&c2).unwrap().holds, Some(false));
+    }
+}
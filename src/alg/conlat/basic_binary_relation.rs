@@ -378,8 +378,9 @@ impl Ord for BasicBinaryRelation {
         // First compare by universe size
         match self.univ_size.cmp(&other.univ_size) {
             std::cmp::Ordering::Equal => {
-                // Then compare by number of pairs
-                self.pairs.len().cmp(&other.pairs.len())
+                // Then compare by number of pairs, then lexicographically by
+                // the sorted pairs, so the order agrees with equality
+                self.pairs.len().cmp(&other.pairs.len()).then_with(|| self.pairs.cmp(&other.pairs))
             }
             other => other,
         }
@@ -428,6 +429,17 @@ mod tests {
         assert_eq!(relation.size(), 2);
     }
     
+    #[test]
+    fn test_order_agrees_with_equality() {
+        let mut r = BasicBinaryRelation::new(3).unwrap();
+        r.add(0, 1).unwrap();
+        let mut s = BasicBinaryRelation::new(3).unwrap();
+        s.add(1, 0).unwrap();
+        assert_ne!(r, s);
+        assert!(r < s);
+        assert_eq!(r.cmp(&r.clone()), std::cmp::Ordering::Equal);
+    }
+    
     #[test]
     fn test_add_out_of_bounds() {
         let mut relation = BasicBinaryRelation::new(3).unwrap();
@@ -2,6 +2,13 @@
  *
  * This module implements the congruence lattice of a SmallAlgebra using
  * Ralph Freese's fast algorithms from "Computing Congruences Efficiently".
+ *
+ * The lists of congruences it returns (the universe, the principal, join
+ * irreducible and meet irreducible congruences, and the upper covers of a
+ * congruence) are in the order of [`Partition`]: by rank, that is from more
+ * blocks to fewer, then by partition array. This order does not depend on
+ * how the congruences were found, so indices into the universe are the same
+ * in every run and a congruence comes after those below it.
  */
 
 use std::collections::{HashMap, HashSet};
@@ -9,6 +16,7 @@ use std::sync::Arc;
 use std::fmt::{self, Display, Debug};
 use std::hash::Hash;
 use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
 
 use crate::alg::{SmallAlgebra, Algebra};
 use crate::alg::op::{Operation, OperationSymbol, SimilarityType};
@@ -16,11 +24,120 @@ use crate::alg::conlat::{Partition, BinaryRelation, BasicBinaryRelation};
 use crate::util::simple_list::SimpleList;
 use crate::util::int_array::{IntArray, IntArrayTrait};
 use crate::lat::{Lattice, Order};
+use crate::progress::{ComputationError, ProgressReport, ProgressToken};
+use crate::telemetry;
+use crate::memory::MemoryScope;
+
+/// The label under which congruence lattice builds record their memory use.
+pub const MEMORY_SCOPE: &str = "congruence lattice";
 
 /// Maximum lattice size for drawing
 pub const MAX_DRAWABLE_SIZE: usize = 150;
 pub const MAX_DRAWABLE_INPUT_SIZE: usize = 2500;
 
+/// The saved state of a congruence lattice universe build, taken with
+/// [`CongruenceLattice::pause`] and restored with
+/// [`CongruenceLattice::resume`].
+///
+/// Congruences are stored as partition arrays, so a checkpoint can be
+/// written to disk as JSON and resumed in another process.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConLatCheckpoint {
+    /// The name of the algebra, for reference.
+    pub alg_name: String,
+    /// The size of the algebra.
+    pub alg_size: usize,
+    /// The join irreducible congruences, if computed.
+    pub join_irreducibles: Option<Vec<Vec<i32>>>,
+    /// The congruences found by an interrupted universe build.
+    pub partial_universe: Option<Vec<Vec<i32>>>,
+    /// The index of the join irreducible the interrupted build continues with.
+    pub next_k: usize,
+    /// The complete universe, if the build finished.
+    pub universe: Option<Vec<Vec<i32>>>,
+}
+
+impl ConLatCheckpoint {
+    /// Write the checkpoint to a file as JSON.
+    ///
+    /// # Arguments
+    /// * `path` - The file to write
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        let json = serde_json::to_string(self).map_err(|e| format!("Failed to serialize checkpoint: {}", e))?;
+        std::fs::write(path, json).map_err(|e| format!("Failed to write checkpoint {}: {}", path, e))
+    }
+
+    /// Read a checkpoint written by [`save`](Self::save).
+    ///
+    /// # Arguments
+    /// * `path` - The file to read
+    pub fn load(path: &str) -> Result<Self, String> {
+        let json = std::fs::read_to_string(path).map_err(|e| format!("Failed to read checkpoint {}: {}", path, e))?;
+        serde_json::from_str(&json).map_err(|e| format!("Invalid checkpoint {}: {}", path, e))
+    }
+}
+
+/// Iterator returned by [`CongruenceLattice::congruences_iter`].
+///
+/// It runs the closure of the join irreducibles under joins one join at a
+/// time. The congruences found so far are kept, since they are joined with
+/// later join irreducibles, but nothing else is.
+pub struct Congruences {
+    zero: Option<Partition>,
+    jis: Vec<Partition>,
+    /// The congruences found so far, except zero.
+    univ: Vec<Partition>,
+    seen: HashSet<Partition>,
+    /// The next element of `univ` to yield.
+    next: usize,
+    /// The closure joins `jis[k]` with `univ[i]` for `i` in `k..end`.
+    k: usize,
+    i: usize,
+    end: usize,
+}
+
+impl Congruences {
+    fn new(zero: Partition, jis: Vec<Partition>) -> Self {
+        let univ = jis.clone();
+        let seen = univ.iter().cloned().collect();
+        let end = univ.len();
+        Congruences { zero: Some(zero), jis, univ, seen, next: 0, k: 0, i: 0, end }
+    }
+
+    /// Iterate over an already computed universe.
+    fn finished(univ: Vec<Partition>) -> Self {
+        Congruences { zero: None, jis: Vec::new(), univ, seen: HashSet::new(), next: 0, k: 0, i: 0, end: 0 }
+    }
+}
+
+impl Iterator for Congruences {
+    type Item = Partition;
+
+    fn next(&mut self) -> Option<Partition> {
+        if let Some(zero) = self.zero.take() {
+            return Some(zero);
+        }
+        while self.next == self.univ.len() {
+            if self.i == self.end {
+                self.k += 1;
+                if self.k >= self.jis.len() {
+                    return None;
+                }
+                self.i = self.k;
+                self.end = self.univ.len();
+                continue;
+            }
+            let join = self.jis[self.k].join(&self.univ[self.i]).unwrap();
+            self.i += 1;
+            if self.seen.insert(join.clone()) {
+                self.univ.push(join);
+            }
+        }
+        self.next += 1;
+        Some(self.univ[self.next - 1].clone())
+    }
+}
+
 /// A congruence lattice of a SmallAlgebra.
 ///
 /// This struct represents the lattice of all congruences on a given algebra,
@@ -107,6 +224,12 @@ where
     /// Size computed during universe generation
     size_computed: usize,
     
+    /// Congruences found by an interrupted universe computation
+    partial_universe: Option<Vec<Partition>>,
+    
+    /// The join irreducible an interrupted universe computation resumes at
+    make_universe_k: usize,
+    
     /// Have principals been made?
     principals_made: bool,
     
@@ -155,6 +278,8 @@ where
             permutability_level: -1,
             permutability_level_witnesses: None,
             size_computed: 0,
+            partial_universe: None,
+            make_universe_k: 0,
             principals_made: false,
             basic_lat: None,
         }
@@ -241,6 +366,8 @@ where
             permutability_level: -1,
             permutability_level_witnesses: None,
             size_computed: 0,
+            partial_universe: None,
+            make_universe_k: 0,
             principals_made: false,
             basic_lat: None,
         }
@@ -567,12 +694,32 @@ where
     /// This method generates all congruences of the form Cg(i, j) for i < j
     /// and stores them sorted by rank.
     pub fn make_principals(&mut self) {
+        // Without a token the build cannot fail.
+        let _ = self.build_principals(None);
+    }
+    
+    /// Compute all principal congruences, stopping if the current thread's
+    /// progress token is cancelled.
+    ///
+    /// # Returns
+    /// `Err(CANCELLED)` if cancelled, in which case nothing is stored
+    pub fn try_make_principals(&mut self) -> Result<(), String> {
+        self.build_principals(Some(&crate::progress::current().unwrap_or_default()))
+    }
+    
+    fn build_principals(&mut self, token: Option<&ProgressToken>) -> Result<(), String> {
+        let _memory = MemoryScope::new(MEMORY_SCOPE);
+        let _phase = token.map(|t| t.phase("principal congruences"));
+        let _timer = telemetry::phase("principal congruences");
         let mut pc_id_map: HashMap<Partition, Partition> = HashMap::new();
         let mut principals = Vec::new();
         let mut lookup = HashMap::new();
         let mut rep_map = HashMap::new();
         
         for i in 0..(self.alg_size - 1) {
+            if let Some(t) = token {
+                t.check()?;
+            }
             for j in (i + 1)..self.alg_size {
                 let part_cong = self.make_cg(i, j);
                 
@@ -593,16 +740,17 @@ where
             }
         }
         
-        // Sort by rank (in the partition lattice)
-        Self::sort_by_rank(&mut principals);
+        principals.sort();
+        telemetry::count(telemetry::PRINCIPALS, principals.len() as u64);
         
         self.principal_congruences = Some(principals);
         self.principal_congruences_lookup = Some(lookup);
         self.principal_congruences_rep = Some(rep_map);
         self.principals_made = true;
+        Ok(())
     }
     
-    /// Get the list of principal congruences.
+    /// Get the list of principal congruences, in the order of [`Partition`].
     pub fn principals(&mut self) -> &Vec<Partition> {
         if !self.principals_made {
             self.make_principals();
@@ -610,16 +758,6 @@ where
         self.principal_congruences.as_ref().unwrap()
     }
     
-    /// Sort partitions by rank (in the partition lattice).
-    /// Rank is defined as size - number_of_blocks.
-    fn sort_by_rank(partitions: &mut Vec<Partition>) {
-        partitions.sort_by(|a, b| {
-            let rank_a = a.universe_size() - a.number_of_blocks();
-            let rank_b = b.universe_size() - b.number_of_blocks();
-            rank_a.cmp(&rank_b)
-        });
-    }
-    
     /// Generate the universe of all congruences.
     ///
     /// This method computes all congruences on the algebra by taking joins
@@ -633,22 +771,55 @@ where
     /// # Arguments
     /// * `max_size` - Maximum size before stopping (usize::MAX for no limit)
     pub fn make_universe_with_limit(&mut self, max_size: usize) {
+        // Without a token the build cannot fail.
+        let _ = self.build_universe(max_size, None);
+    }
+    
+    /// Generate the universe, stopping if the current thread's progress
+    /// token is cancelled.
+    ///
+    /// The congruences found before cancellation are kept in
+    /// [`partial_universe`](Self::partial_universe) and a later call resumes
+    /// from them.
+    ///
+    /// # Returns
+    /// `Err(CANCELLED)` if cancelled in any phase
+    pub fn try_make_universe(&mut self) -> Result<(), String> {
+        self.build_universe(usize::MAX, Some(&crate::progress::current().unwrap_or_default()))
+    }
+    
+    fn build_universe(&mut self, max_size: usize, token: Option<&ProgressToken>) -> Result<(), String> {
+        let _memory = MemoryScope::new(MEMORY_SCOPE);
         let stop_if_big = max_size < usize::MAX;
         
         // Get join irreducibles
         if self.join_irreducibles.is_none() {
-            self.make_join_irreducibles();
+            self.build_join_irreducibles(token)?;
         }
+        let _phase = token.map(|t| t.phase("universe"));
+        let _timer = telemetry::phase("universe");
         
         let jis = self.join_irreducibles.as_ref().unwrap();
-        let mut univ: Vec<Partition> = jis.clone();
-        let mut hash: HashSet<Partition> = jis.iter().cloned().collect();
+        let (mut univ, start_k) = match self.partial_universe.take() {
+            Some(univ) => (univ, self.make_universe_k),
+            None => (jis.clone(), 0),
+        };
+        let mut hash: HashSet<Partition> = univ.iter().cloned().collect();
         
         self.size_computed = univ.len();
-        let size = jis.len();
         
-        for k in 0..size {
-            let elem = jis[k].clone();
+        for (k, elem) in jis.iter().enumerate().skip(start_k) {
+            if let Some(t) = token {
+                if let Err(e) = t.check() {
+                    self.partial_universe = Some(univ);
+                    self.make_universe_k = k;
+                    return Err(e);
+                }
+                t.set_size(univ.len());
+                t.report(&format!("size: {}", univ.len()));
+            }
+            #[cfg(feature = "tracing")]
+            tracing::trace!(k, size = univ.len(), "universe step");
             let n = univ.len();
             
             // Join with all elements from k onwards (not k+1!)
@@ -660,7 +831,7 @@ where
                     self.size_computed += 1;
                     
                     if stop_if_big && self.size_computed >= max_size {
-                        return;
+                        return Ok(());
                     }
                     
                     hash.insert(join.clone());
@@ -669,17 +840,139 @@ where
             }
         }
         
-        // Add zero congruence at the beginning
-        hash.insert(self.zero_cong.clone());
-        univ.insert(0, self.zero_cong.clone());
+        // The closure order depends on the join irreducibles; sorting makes
+        // the universe canonical, with zero first.
+        univ.push(self.zero_cong.clone());
+        univ.sort();
+        telemetry::count(telemetry::CONGRUENCES, univ.len() as u64);
         
         self.universe = Some(univ);
+        Ok(())
+    }
+    
+    /// Get the congruences found by an interrupted universe computation.
+    ///
+    /// # Returns
+    /// The join irreducibles and joins found so far, or `None` if no
+    /// computation has been interrupted
+    pub fn partial_universe(&self) -> Option<&Vec<Partition>> {
+        self.partial_universe.as_ref()
+    }
+    
+    /// Capture the state of the universe build so it can be resumed later,
+    /// possibly in another process.
+    ///
+    /// The checkpoint holds the join irreducibles, the partial universe of an
+    /// interrupted build and the finished universe, whichever are known.
+    ///
+    /// # Returns
+    /// A serializable checkpoint
+    pub fn pause(&self) -> ConLatCheckpoint {
+        let arrays = |parts: &Option<Vec<Partition>>| {
+            parts.as_ref().map(|parts| parts.iter().map(|p| p.to_array()).collect())
+        };
+        ConLatCheckpoint {
+            alg_name: self.alg.name().to_string(),
+            alg_size: self.alg_size,
+            join_irreducibles: arrays(&self.join_irreducibles),
+            partial_universe: arrays(&self.partial_universe),
+            next_k: self.make_universe_k,
+            universe: arrays(&self.universe),
+        }
+    }
+    
+    /// Restore the state captured by [`pause`](Self::pause); the next
+    /// universe build continues from it.
+    ///
+    /// # Arguments
+    /// * `checkpoint` - A checkpoint taken from the congruence lattice of this
+    ///   algebra
+    ///
+    /// # Returns
+    /// `Err` if the checkpoint names another algebra, holds a partition that
+    /// is not a congruence of this one or is inconsistent
+    pub fn resume(&mut self, checkpoint: &ConLatCheckpoint) -> Result<(), String> {
+        if checkpoint.alg_name != self.alg.name() {
+            return Err(format!(
+                "Checkpoint is for the algebra {}, not {}",
+                checkpoint.alg_name,
+                self.alg.name()
+            ));
+        }
+        if checkpoint.alg_size != self.alg_size {
+            return Err(format!(
+                "Checkpoint is for an algebra of size {}, not {}",
+                checkpoint.alg_size, self.alg_size
+            ));
+        }
+        let size = self.alg_size;
+        let partitions = |arrays: &Option<Vec<Vec<i32>>>| -> Result<Option<Vec<Partition>>, String> {
+            match arrays {
+                Some(arrays) => arrays
+                    .iter()
+                    .map(|a| {
+                        if a.len() != size {
+                            return Err(format!("Checkpoint partition has size {}, not {}", a.len(), size));
+                        }
+                        let par = Partition::new(a.clone())?;
+                        if self.cg_partition(&par) != par {
+                            return Err(format!(
+                                "Checkpoint partition {} is not a congruence of {}",
+                                par,
+                                self.alg.name()
+                            ));
+                        }
+                        Ok(par)
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(Some),
+                None => Ok(None),
+            }
+        };
+        let jis = partitions(&checkpoint.join_irreducibles)?;
+        let partial = partitions(&checkpoint.partial_universe)?;
+        let universe = partitions(&checkpoint.universe)?;
+        if partial.is_some() {
+            match &jis {
+                Some(jis) if checkpoint.next_k <= jis.len() => {}
+                Some(_) => return Err("Checkpoint resumes past the last join irreducible".to_string()),
+                None => return Err("Checkpoint has a partial universe but no join irreducibles".to_string()),
+            }
+        }
+        if let Some(jis) = jis {
+            // The lower cover of a join irreducible is the join of the join
+            // irreducibles strictly below it.
+            let mut lower_covers = HashMap::new();
+            for beta in &jis {
+                let mut lower = self.zero();
+                for alpha in &jis {
+                    if alpha != beta && alpha.leq(beta) {
+                        lower = lower.join(alpha)?;
+                    }
+                }
+                lower_covers.insert(beta.clone(), lower);
+            }
+            self.lower_cover_of_jis = Some(lower_covers);
+            self.basic_lat = None;
+            self.join_irreducibles = Some(jis);
+        }
+        if let Some(mut universe) = universe {
+            // Checkpoints of older versions may hold it in another order.
+            universe.sort();
+            self.upper_covers_map = None;
+            self.meet_irreducibles = None;
+            self.universe = Some(universe);
+        }
+        self.partial_universe = partial;
+        self.make_universe_k = checkpoint.next_k;
+        Ok(())
     }
     
     /// Get the universe of all congruences.
     ///
     /// # Returns
-    /// A vector of all congruences (generates if not already computed)
+    /// A vector of all congruences in the order of [`Partition`], starting
+    /// with zero and ending with one (generates if not already computed)
     pub fn universe(&mut self) -> &Vec<Partition> {
         if self.universe.is_none() {
             self.make_universe();
@@ -696,26 +989,134 @@ where
         self.universe.as_ref().unwrap().len()
     }
     
+    /// Get the universe of all congruences, stopping if the current
+    /// thread's progress token is cancelled.
+    ///
+    /// # Returns
+    /// All congruences, or `Err(CANCELLED)` if cancelled
+    pub fn try_universe(&mut self) -> Result<&Vec<Partition>, String> {
+        if self.universe.is_none() {
+            self.try_make_universe()?;
+        }
+        Ok(self.universe.as_ref().unwrap())
+    }
+    
+    /// Get the universe of all congruences like [`try_universe`](Self::try_universe),
+    /// telling a build stopped by the current thread's progress token from
+    /// a failed one.
+    ///
+    /// # Returns
+    /// All congruences, or a [`ComputationError`] whose partial result is
+    /// the [`partial_universe`](Self::partial_universe) of the stopped build
+    ///
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::conlat::CongruenceLattice;
+    /// use uacalc::alg::{BasicAlgebra, SmallAlgebra};
+    /// use uacalc::progress::{Budget, ComputationError, ProgressToken};
+    ///
+    /// let alg = BasicAlgebra::new("A".to_string(), (0..4).collect(), Vec::new());
+    /// let mut con = CongruenceLattice::new(Box::new(alg) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+    /// let token = ProgressToken::new();
+    /// let _installed = token.install();
+    /// token.set_budget(Budget { max_elements: Some(5), ..Default::default() });
+    /// match con.compute_universe() {
+    ///     Err(e @ ComputationError::Stopped { .. }) => assert!(e.budget_exceeded().is_some()),
+    ///     other => panic!("not stopped: {:?}", other.map(|u| u.len())),
+    /// }
+    /// ```
+    pub fn compute_universe(&mut self) -> Result<&Vec<Partition>, ComputationError<Vec<Partition>>> {
+        if self.universe.is_none() {
+            if let Err(e) = self.try_make_universe() {
+                return Err(ComputationError::new(e, || self.partial_universe.clone().unwrap_or_default()));
+            }
+        }
+        Ok(self.universe.as_ref().unwrap())
+    }
+
+    /// Get the cardinality of the congruence lattice, stopping if the
+    /// current thread's progress token is cancelled.
+    pub fn try_con_cardinality(&mut self) -> Result<usize, String> {
+        Ok(self.try_universe()?.len())
+    }
+    
     /// Check if the universe has been computed.
     pub fn universe_found(&self) -> bool {
         self.universe.is_some()
     }
     
+    /// Iterate over the congruences, generating them lazily.
+    ///
+    /// The congruences come in the order the closure finds them: zero, the
+    /// join irreducibles, then their joins, so a caller can stop early
+    /// without the whole universe being built. This order is not the
+    /// canonical order of [`universe`](Self::universe), and the same
+    /// lattice iterates in the canonical order once its universe is known,
+    /// so use [`universe_iter`](Self::universe_iter) where the order
+    /// matters. The join irreducibles are computed first, stopping if the
+    /// current thread's progress token is cancelled.
+    ///
+    /// # Returns
+    /// * `Ok(Congruences)` - An iterator of all the congruences
+    /// * `Err(CANCELLED)` - If cancelled while computing the join irreducibles
+    pub fn congruences_iter(&mut self) -> Result<Congruences, String> {
+        if let Some(univ) = &self.universe {
+            return Ok(Congruences::finished(univ.clone()));
+        }
+        if self.join_irreducibles.is_none() {
+            self.try_make_join_irreducibles()?;
+        }
+        let jis = self.join_irreducibles.as_ref().unwrap().clone();
+        Ok(Congruences::new(self.zero_cong.clone(), jis))
+    }
+    
+    /// Iterate over the congruences in the canonical order of
+    /// [`universe`](Self::universe), building the universe first and
+    /// stopping if the current thread's progress token is cancelled.
+    ///
+    /// # Returns
+    /// * `Ok(Congruences)` - An iterator of all the congruences
+    /// * `Err(CANCELLED)` - If cancelled while building the universe
+    pub fn universe_iter(&mut self) -> Result<Congruences, String> {
+        Ok(Congruences::finished(self.try_universe()?.clone()))
+    }
+    
     /// Compute the join irreducible congruences.
     ///
     /// A congruence is join irreducible if it cannot be expressed as the
     /// join of two strictly smaller congruences.
     pub fn make_join_irreducibles(&mut self) {
+        // Without a token the build cannot fail.
+        let _ = self.build_join_irreducibles(None);
+    }
+    
+    /// Compute the join irreducible congruences, stopping if the current
+    /// thread's progress token is cancelled.
+    ///
+    /// # Returns
+    /// `Err(CANCELLED)` if cancelled, in which case the join irreducibles
+    /// are not stored
+    pub fn try_make_join_irreducibles(&mut self) -> Result<(), String> {
+        self.build_join_irreducibles(Some(&crate::progress::current().unwrap_or_default()))
+    }
+    
+    fn build_join_irreducibles(&mut self, token: Option<&ProgressToken>) -> Result<(), String> {
+        let _memory = MemoryScope::new(MEMORY_SCOPE);
         // Make sure principals are computed
         if !self.principals_made {
-            self.make_principals();
+            self.build_principals(token)?;
         }
+        let _phase = token.map(|t| t.phase("join irreducibles"));
+        let _timer = telemetry::phase("join irreducibles");
         
         let principals = self.principal_congruences.as_ref().unwrap();
         let mut jis = Vec::new();
         let mut lower_covers = HashMap::new();
         
         for part in principals {
+            if let Some(t) = token {
+                t.check()?;
+            }
             let mut join = self.zero();
             
             for part2 in principals {
@@ -735,14 +1136,16 @@ where
             }
         }
         
+        telemetry::count(telemetry::JOIN_IRREDUCIBLES, jis.len() as u64);
         self.join_irreducibles = Some(jis);
         self.lower_cover_of_jis = Some(lower_covers);
+        Ok(())
     }
     
     /// Get the join irreducible congruences.
     ///
     /// # Returns
-    /// A list of join irreducible congruences (sorted by rank)
+    /// A list of join irreducible congruences, in the order of [`Partition`]
     pub fn join_irreducibles(&mut self) -> &Vec<Partition> {
         if self.join_irreducibles.is_none() {
             self.make_join_irreducibles();
@@ -973,17 +1376,10 @@ where
                         hs.insert(join.clone());
                         
                         // Check if this is minimal among candidates
-                        let mut above = false;
-                        covs.retain(|cov: &Partition| {
-                            if cov.leq(&join) {
-                                above = true;
-                                false
-                            } else if join.leq(cov) {
-                                false
-                            } else {
-                                true
-                            }
-                        });
+                        let above = covs.iter().any(|cov: &Partition| cov.leq(&join));
+                        if !above {
+                            covs.retain(|cov: &Partition| !join.leq(cov));
+                        }
                         
                         if !above {
                             covs.push(join);
@@ -992,6 +1388,7 @@ where
                 }
             }
             
+            covs.sort();
             uc_map.insert(elem.clone(), covs);
         }
         
@@ -1024,7 +1421,89 @@ where
         
         true
     }
-    
+
+    /// Name the congruence lattice if it is one of the standard small
+    /// lattices: a chain `"C<n>"`, `"M<n>"`, `"N5"` or a Boolean lattice
+    /// `"2^<k>"`. See [`crate::lat::lattices::identify_lattice`].
+    ///
+    /// # Returns
+    /// The name of the lattice, or `"unknown"`
+    pub fn identify_lattice(&mut self) -> String {
+        let univ = self.universe().clone();
+        crate::lat::lattices::identify_lattice(&univ, self)
+    }
+
+    /// The lattice of congruences as a [`Poset`](crate::lat::Poset) on the
+    /// indices of [`universe`](Self::universe), labelled by the congruences.
+    ///
+    /// # Returns
+    /// * `Ok(Poset)` - The order of the congruences
+    /// * `Err(String)` - If building the universe was cancelled
+    pub fn to_poset(&mut self) -> Result<crate::lat::Poset, String> {
+        let univ = self.try_universe()?.clone();
+        let mut poset = crate::lat::Poset::from_leq(
+            Some(format!("Con({})", self.get_algebra_name())),
+            univ.len(),
+            |i, j| univ[i].leq(&univ[j]),
+        )?;
+        poset.set_labels(univ.iter().map(|par| par.to_string()).collect())?;
+        Ok(poset)
+    }
+
+    /// Check whether this congruence lattice has a 0-1 lattice embedding
+    /// into another.
+    ///
+    /// # Arguments
+    /// * `other` - The congruence lattice to embed into
+    ///
+    /// # Returns
+    /// * `Ok(bool)` - Whether there is an embedding
+    /// * `Err(String)` - If the computation was cancelled
+    pub fn embeds_in(&mut self, other: &mut CongruenceLattice<T>) -> Result<bool, String> {
+        Ok(self.to_poset()?.lattice_embedding(&other.to_poset()?)?.is_some())
+    }
+
+    /// Check whether this congruence lattice is isomorphic to the dual of
+    /// another.
+    ///
+    /// # Arguments
+    /// * `other` - The other congruence lattice
+    ///
+    /// # Returns
+    /// * `Ok(bool)` - Whether the lattices are dually isomorphic
+    /// * `Err(String)` - If the computation was cancelled
+    pub fn is_dual_to(&mut self, other: &mut CongruenceLattice<T>) -> Result<bool, String> {
+        self.to_poset()?.is_dual_to(&other.to_poset()?)
+    }
+
+    /// All lattice isomorphisms from this congruence lattice onto another.
+    ///
+    /// # Arguments
+    /// * `other` - The other congruence lattice
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Vec<usize>>)` - The isomorphisms; `f[i]` is the index in
+    ///   `other.universe()` of the image of `self.universe()[i]`
+    /// * `Err(String)` - If the computation was cancelled
+    ///
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::{BasicAlgebra, SmallAlgebra};
+    /// use uacalc::alg::conlat::CongruenceLattice;
+    /// use std::collections::HashSet;
+    ///
+    /// // The congruence lattice of a 3-element set is M3, with 3! automorphisms.
+    /// let set = || Box::new(BasicAlgebra::new("S".to_string(), (0..3).collect::<HashSet<i32>>(), Vec::new()))
+    ///     as Box<dyn SmallAlgebra<UniverseItem = i32>>;
+    /// let mut con = CongruenceLattice::new(set());
+    /// let mut other = CongruenceLattice::new(set());
+    /// assert_eq!(con.all_isomorphisms(&mut other).unwrap().len(), 6);
+    /// assert!(con.is_dual_to(&mut other).unwrap());
+    /// ```
+    pub fn all_isomorphisms(&mut self, other: &mut CongruenceLattice<T>) -> Result<Vec<Vec<usize>>, String> {
+        self.to_poset()?.lattice_isomorphisms(&other.to_poset()?)
+    }
+
     /// Test if a partition is join prime.
     ///
     /// An element β is join prime if whenever β ≤ ∨S, then β ≤ s for some s ∈ S.
@@ -1323,6 +1802,75 @@ where
         }
         result
     }
+
+    // The default property tests read `Algebra::universe`, which is empty
+    // until the universe is built, so build it first.
+
+    fn is_upper_semimodular(&self) -> bool {
+        self.congruence_order().1.is_upper_semimodular()
+    }
+
+    fn is_lower_semimodular(&self) -> bool {
+        self.congruence_order().1.is_lower_semimodular()
+    }
+
+    fn is_modular(&self) -> bool {
+        self.congruence_order().1.is_modular()
+    }
+
+    fn is_meet_semidistributive(&self) -> bool {
+        self.congruence_order().1.is_meet_semidistributive()
+    }
+
+    fn is_join_semidistributive(&self) -> bool {
+        self.congruence_order().1.is_join_semidistributive()
+    }
+
+    fn meet_semidistributivity_failure(&self) -> Option<(Partition, Partition, Partition)> {
+        let (univ, order) = self.congruence_order();
+        order.meet_semidistributivity_failure()
+            .map(|(a, b, c)| (univ[a].clone(), univ[b].clone(), univ[c].clone()))
+    }
+
+    fn join_semidistributivity_failure(&self) -> Option<(Partition, Partition, Partition)> {
+        let (univ, order) = self.congruence_order();
+        order.join_semidistributivity_failure()
+            .map(|(a, b, c)| (univ[a].clone(), univ[b].clone(), univ[c].clone()))
+    }
+
+    fn is_lower_bounded_lattice(&self) -> bool {
+        self.congruence_order().1.is_lower_bounded()
+    }
+
+    fn is_relatively_complemented(&self) -> bool {
+        self.congruence_order().1.is_relatively_complemented()
+    }
+
+    fn is_geometric(&self) -> bool {
+        self.congruence_order().1.is_geometric()
+    }
+}
+
+impl<T> CongruenceLattice<T>
+where
+    T: Clone + PartialEq + Eq + Hash + Debug + Display + Send + Sync + 'static
+{
+    /// The congruences and their order, for the lattice property tests.
+    ///
+    /// The universe is built on a copy of the lattice if it is not known,
+    /// since the tests only borrow `self`.
+    fn congruence_order(&self) -> (Vec<Partition>, crate::lat::Poset) {
+        let univ = match &self.universe {
+            Some(univ) => univ.clone(),
+            None => {
+                let mut con = self.clone();
+                con.make_universe();
+                con.universe.unwrap_or_default()
+            }
+        };
+        let order = crate::lat::Poset::from_order(&univ, self);
+        (univ, order)
+    }
 }
 
 // Implement Algebra trait
@@ -11,6 +11,8 @@ pub mod subtrace;
 pub mod congruence_lattice;
 pub mod centrality_data;
 pub mod type_finder;
+pub mod represent;
+pub mod product_conlat;
 
 pub use binary_relation::{
     BinaryRelation, MutableBinaryRelation, BinaryRelationCompare, 
@@ -20,6 +22,8 @@ pub use basic_binary_relation::BasicBinaryRelation;
 pub use partition::{Partition, PrintType};
 pub use polymorphisms::Polymorphisms;
 pub use subtrace::Subtrace;
-pub use congruence_lattice::{CongruenceLattice, MAX_DRAWABLE_SIZE, MAX_DRAWABLE_INPUT_SIZE};
+pub use congruence_lattice::{CongruenceLattice, ConLatCheckpoint, Congruences, MAX_DRAWABLE_SIZE, MAX_DRAWABLE_INPUT_SIZE};
 pub use centrality_data::CentralityData;
 pub use type_finder::TypeFinder;
+pub use represent::represent;
+pub use product_conlat::{product_conlat, ProductConLat};
@@ -87,14 +87,27 @@ impl Partition {
     
     /// Create a new partition from a string representation.
     /// 
-    /// Supports both bracket notation `[[1 2][3 4 5]]` and bar notation `|1 2|3 4 5|`.
+    /// Supports both bracket notation `[[1 2][3 4 5]]` and bar notation `|1 2|3 4 5|`,
+    /// the way the UACalc GUI displays congruences. Elements within a block may be
+    /// separated by spaces or commas.
     /// 
     /// # Arguments
     /// * `str` - String representation of the partition
     /// 
     /// # Returns
     /// * `Ok(Partition)` - Successfully created partition
-    /// * `Err(String)` - Invalid string format
+    /// * `Err(String)` - Invalid string format, or the blocks are not disjoint
+    ///   or do not cover `0, ..., n-1`
+    /// 
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::conlat::partition::Partition;
+    /// 
+    /// let partition = Partition::from_string("|0 1|2|3 4|").unwrap();
+    /// assert_eq!(partition.number_of_blocks(), 3);
+    /// assert_eq!(partition.to_bar_string(), "|0 1|2|3 4|");
+    /// assert!(Partition::from_string("|0 1|3|").is_err());
+    /// ```
     pub fn from_string(str: &str) -> Result<Self, String> {
         Self::from_string_with_length(str, -1)
     }
@@ -116,6 +129,38 @@ impl Partition {
         Self::new(array)
     }
     
+    /// Create a partition from the block index of each element.
+    /// 
+    /// Elements with the same index are in the same block; the indices
+    /// themselves are only labels and need not be consecutive.
+    /// 
+    /// # Arguments
+    /// * `indices` - The block index of each of `0, ..., n-1`
+    /// 
+    /// # Returns
+    /// * `Ok(Partition)` - Successfully created partition
+    /// * `Err(String)` - If `indices` is empty
+    /// 
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::conlat::partition::Partition;
+    /// 
+    /// let partition = Partition::from_block_index_vector(&[0, 0, 1, 2, 2]).unwrap();
+    /// assert_eq!(partition, Partition::from_string("|0 1|2|3 4|").unwrap());
+    /// ```
+    pub fn from_block_index_vector(indices: &[usize]) -> Result<Self, String> {
+        let mut roots: HashMap<usize, usize> = HashMap::new();
+        let mut array = vec![-1; indices.len()];
+        for (i, &index) in indices.iter().enumerate() {
+            let r = *roots.entry(index).or_insert(i);
+            if r != i {
+                array[i] = r as i32;
+                array[r] -= 1;
+            }
+        }
+        Self::new(array)
+    }
+    
     /// Create the zero partition (all elements in separate blocks).
     /// 
     /// # Arguments
@@ -426,6 +471,163 @@ impl Partition {
         true
     }
     
+    /// Check if this partition covers another in the partition lattice.
+    /// 
+    /// This holds exactly when `other` refines this partition and has one
+    /// more block, that is, this partition joins two blocks of `other`.
+    /// 
+    /// # Arguments
+    /// * `other` - The partition that may be covered
+    /// 
+    /// # Returns
+    /// * `true` if this partition covers `other`
+    /// * `false` otherwise, including when the universe sizes differ
+    /// 
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::conlat::partition::Partition;
+    /// 
+    /// let zero = Partition::zero(3);
+    /// let atom = Partition::from_string("|0 1|2|").unwrap();
+    /// assert!(atom.covers(&zero));
+    /// assert!(!Partition::one(3).covers(&zero));
+    /// ```
+    pub fn covers(&self, other: &Partition) -> bool {
+        other.leq(self) && other.number_of_blocks() == self.number_of_blocks() + 1
+    }
+    
+    /// Find the partitions covering both this partition and another.
+    /// 
+    /// For two different partitions there is at most one, their join; a
+    /// partition's common upper covers with itself are all its upper covers.
+    /// 
+    /// # Arguments
+    /// * `other` - The other partition
+    /// 
+    /// # Returns
+    /// * `Ok(Vec<Partition>)` - The common upper covers, in the order their
+    ///   merged blocks appear
+    /// * `Err(String)` - If the universe sizes differ
+    pub fn common_upper_covers(&self, other: &Partition) -> Result<Vec<Partition>, String> {
+        if self.universe_size() != other.universe_size() {
+            return Err(format!(
+                "Partitions have different universe sizes: {} and {}",
+                self.universe_size(),
+                other.universe_size()
+            ));
+        }
+        let reps = self.representatives();
+        let mut covers = Vec::new();
+        for (k, &r) in reps.iter().enumerate() {
+            for &s in &reps[k + 1..] {
+                let mut cover = self.clone();
+                cover.join_blocks(r, s);
+                if cover.covers(other) {
+                    covers.push(cover);
+                }
+            }
+        }
+        Ok(covers)
+    }
+    
+    /// Check if this partition and another are complements: their meet is
+    /// the zero partition and their join is the one partition.
+    /// 
+    /// # Arguments
+    /// * `other` - The other partition
+    /// 
+    /// # Returns
+    /// * `true` if the partitions are complements
+    /// * `false` otherwise, including when the universe sizes differ
+    pub fn is_complement(&self, other: &Partition) -> bool {
+        match (self.meet(other), self.join(other)) {
+            (Ok(meet), Ok(join)) => meet.is_zero() && join.number_of_blocks() == 1,
+            _ => false,
+        }
+    }
+    
+    /// The relational product of this partition followed by another.
+    /// 
+    /// The result relates `a` and `c` when there is a `b` with `a` related to
+    /// `b` by this partition and `b` related to `c` by `other`.
+    /// 
+    /// # Arguments
+    /// * `other` - The partition applied second
+    /// 
+    /// # Returns
+    /// * `Ok(BasicBinaryRelation)` - The composition
+    /// * `Err(String)` - If the universe sizes differ
+    pub fn relational_composition(&self, other: &Partition) -> Result<BasicBinaryRelation, String> {
+        let size = self.universe_size();
+        if other.universe_size() != size {
+            return Err(format!(
+                "Cannot compose relations with different universe sizes: {} and {}",
+                size,
+                other.universe_size()
+            ));
+        }
+        let mut result = BasicBinaryRelation::new(size)?;
+        for block in self.get_blocks() {
+            // The blocks of `other` met by this block.
+            let met: BTreeSet<usize> = block.iter().map(|&b| other.root(b)).collect();
+            for c in 0..size {
+                if met.contains(&other.root(c)) {
+                    for &a in &block {
+                        result.add(a, c)?;
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+    
+    /// Check if this partition permutes with another, that is, the two
+    /// relational products are equal (and so both equal the join).
+    /// 
+    /// This holds exactly when every block of this partition meets every
+    /// block of `other` lying in the same block of the join.
+    /// 
+    /// # Arguments
+    /// * `other` - The other partition
+    /// 
+    /// # Returns
+    /// * `true` if the partitions permute
+    /// * `false` otherwise, including when the universe sizes differ
+    /// 
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::conlat::partition::Partition;
+    /// 
+    /// // The kernels of the two projections of a 2 x 2 square permute.
+    /// let rows = Partition::from_string("|0 1|2 3|").unwrap();
+    /// let cols = Partition::from_string("|0 2|1 3|").unwrap();
+    /// assert!(rows.permutes_with(&cols));
+    /// let chain = Partition::from_string("|0 1|2|").unwrap();
+    /// assert!(!chain.permutes_with(&Partition::from_string("|0|1 2|").unwrap()));
+    /// ```
+    pub fn permutes_with(&self, other: &Partition) -> bool {
+        let join = match self.join(other) {
+            Ok(join) => join,
+            Err(_) => return false,
+        };
+        // For each block of the join, the number of blocks of self and of
+        // other in it; every such pair of blocks must meet.
+        let mut counts: HashMap<usize, (usize, usize)> = HashMap::new();
+        let mut meeting = BTreeSet::new();
+        for i in 0..self.universe_size() {
+            let (r, s) = (self.root(i), other.root(i));
+            let entry = counts.entry(join.root(i)).or_default();
+            if r == i {
+                entry.0 += 1;
+            }
+            if s == i {
+                entry.1 += 1;
+            }
+            meeting.insert((r, s));
+        }
+        counts.values().map(|(m, n)| m * n).sum::<usize>() == meeting.len()
+    }
+    
     /// Normalize the partition representation.
     /// 
     /// Ensures that roots are the smallest elements in their blocks
@@ -649,7 +851,17 @@ impl Partition {
             }
             
             let size = if length < 0 {
-                blocks.iter().map(|block| block.len()).sum()
+                let size = blocks.iter().map(|block| block.len()).sum();
+                let mut seen = vec![false; size];
+                for &i in blocks.iter().flatten() {
+                    if i < 0 || i as usize >= size {
+                        return Err(format!("Element {} is out of range for a partition on {} elements", i, size));
+                    }
+                    if std::mem::replace(&mut seen[i as usize], true) {
+                        return Err(format!("Element {} is in more than one block", i));
+                    }
+                }
+                size
             } else {
                 length as usize
             };
@@ -731,6 +943,15 @@ impl Partition {
         self.to_string_with_type(PrintType::Block, max_len)
     }
     
+    /// The partition in bar notation with space separated elements, for
+    /// example `|0 1|2|3 4|`, as accepted by [`from_string`](Self::from_string).
+    pub fn to_bar_string(&self) -> String {
+        let blocks: Vec<String> = self.get_blocks().iter()
+            .map(|block| block.iter().map(|e| e.to_string()).collect::<Vec<_>>().join(" "))
+            .collect();
+        format!("|{}|", blocks.join("|"))
+    }
+    
     /// Convert int array to string representation.
     fn int_array_to_string(array: &[i32]) -> String {
         if array.is_empty() {
@@ -1365,6 +1586,65 @@ mod tests {
         assert!(partition.is_related(0, 1));
         assert!(partition.is_related(2, 3));
         assert!(!partition.is_related(0, 2));
+        
+        let partition = Partition::from_string(" |0, 3|1 2 | 4| ").unwrap();
+        assert_eq!(partition.to_bar_string(), "|0 3|1 2|4|");
+        assert_eq!(Partition::from_string(&partition.to_bar_string()).unwrap(), partition);
+        assert_eq!(Partition::from_string(&partition.to_string()).unwrap(), partition);
+        assert!(Partition::from_string("|0 1|1 2|").is_err());
+        assert!(Partition::from_string("|0 x|").is_err());
+        // With a length, larger elements are ignored.
+        assert_eq!(Partition::from_string_with_length("|0 1|2 7|", 3).unwrap().to_bar_string(), "|0 1|2|");
+    }
+    
+    #[test]
+    fn test_from_block_index_vector() {
+        let partition = Partition::from_block_index_vector(&[5, 1, 5, 1, 0]).unwrap();
+        assert_eq!(partition.to_bar_string(), "|0 2|1 3|4|");
+        assert_eq!(partition.representatives(), vec![0, 1, 4]);
+        assert!(Partition::from_block_index_vector(&[]).is_err());
+    }
+    
+    #[test]
+    fn test_lattice_operations() {
+        let zero = Partition::zero(4);
+        let a = Partition::from_string("|0 1|2|3|").unwrap();
+        let b = Partition::from_string("|0|1|2 3|").unwrap();
+        assert!(a.covers(&zero));
+        assert!(!zero.covers(&a));
+        assert!(!a.covers(&a));
+        assert!(!Partition::one(4).covers(&a));
+        
+        let join = a.join(&b).unwrap();
+        assert_eq!(a.common_upper_covers(&b).unwrap(), vec![join.clone()]);
+        assert_eq!(a.common_upper_covers(&a).unwrap().len(), 3);
+        assert!(a.common_upper_covers(&join).unwrap().is_empty());
+        assert!(a.common_upper_covers(&Partition::zero(3)).is_err());
+        
+        let c = Partition::from_string("|0 2|1 3|").unwrap();
+        assert!(a.is_complement(&Partition::from_string("|0 2 3|1|").unwrap()));
+        assert!(!a.is_complement(&b));
+        assert!(a.is_complement(&c));
+        assert!(!a.is_complement(&Partition::from_string("|0 1 2|3|").unwrap()));
+        
+        // a and b permute: both products are the join.
+        let ab = a.relational_composition(&b).unwrap();
+        assert_eq!(ab, b.relational_composition(&a).unwrap());
+        assert!(a.permutes_with(&b));
+        
+        // |0 1|2 3| and |0|1 2|3| do not: 0 -> 1 -> 2 but not 0 -> 3.
+        let d = Partition::from_string("|0 1|2 3|").unwrap();
+        let e = Partition::from_string("|0|1 2|3|").unwrap();
+        let de = d.relational_composition(&e).unwrap();
+        assert!(de.is_related(0, 2));
+        assert!(!de.is_related(0, 3));
+        assert_ne!(de, e.relational_composition(&d).unwrap());
+        assert!(!d.permutes_with(&e));
+        assert!(!d.permutes_with(&Partition::zero(3)));
+        
+        // The generic BinaryRelation composition agrees.
+        let generic = BinaryRelation::compose(&d, &e).unwrap();
+        assert_eq!(generic.get_pairs(), de.get_pairs());
     }
     
     #[test]
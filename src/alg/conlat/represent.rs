@@ -0,0 +1,171 @@
+/*! Searching for a finite algebra with a given congruence lattice.
+
+This is experimental. A finite lattice `L` is the congruence lattice of an
+`n`-element algebra exactly when it is the congruence lattice of an
+`n`-element unary algebra, since `Con(A)` is also the congruence lattice of
+the unary polynomials of `A`. [`represent`] therefore looks, for `n = 1, 2,
+...`, at each 0-1 sublattice `S` of the partition lattice `Π_n` isomorphic
+to `L`, and asks whether `S` is closed: whether every partition preserved by
+all unary maps that preserve `S` is already in `S`. The search is exhaustive
+for each size, but the number of sublattices grows very quickly with `n`.
+*/
+
+use std::collections::{BTreeSet, HashSet};
+use crate::alg::BasicAlgebra;
+use crate::alg::conlat::Partition;
+use crate::alg::op::{operations, Operation, OperationSymbol};
+use crate::lat::Poset;
+use crate::util::int_array::IntArrayTrait;
+
+/// Search for an algebra with at most `max_algebra_size` elements whose
+/// congruence lattice is isomorphic to `lattice`.
+///
+/// The algebra found is unary, on the smallest possible universe, and has
+/// only as many operations as it takes to break every partition outside its
+/// congruence lattice.
+///
+/// # Arguments
+/// * `lattice` - The lattice to represent
+/// * `max_algebra_size` - The largest universe to try; past 6 or so the
+///   search is rarely practical
+///
+/// # Returns
+/// * `Ok(Some(algebra))` - An algebra whose congruence lattice is
+///   isomorphic to `lattice`
+/// * `Ok(None)` - If there is no such algebra of at most
+///   `max_algebra_size` elements
+/// * `Err(String)` - If `lattice` is not a lattice or the search was
+///   cancelled
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::Algebra;
+/// use uacalc::alg::conlat::represent;
+/// use uacalc::lat::Poset;
+///
+/// // A three element chain needs three elements, as in 0 < |0 1|2| < 1.
+/// let chain = Poset::from_leq(None, 3, |i, j| i <= j).unwrap();
+/// let alg = represent(&chain, 4).unwrap().unwrap();
+/// assert_eq!(alg.cardinality(), 3);
+/// ```
+pub fn represent(lattice: &Poset, max_algebra_size: usize) -> Result<Option<BasicAlgebra<i32>>, String> {
+    if !lattice.is_lattice() {
+        return Err(format!("{} is not a lattice", lattice.name().unwrap_or("The poset")));
+    }
+    for n in 1..=max_algebra_size {
+        crate::progress::check_cancelled()?;
+        let pars = all_partitions(n);
+        let pi_n = Poset::from_leq(None, pars.len(), |i, j| pars[i].leq(&pars[j]))?;
+        let mut seen: HashSet<Vec<usize>> = HashSet::new();
+        let mut found = None;
+        lattice.for_each_lattice_map(&pi_n, true, &mut |mut image| {
+            image.sort_unstable();
+            if !seen.insert(image.clone()) {
+                return Ok(false);
+            }
+            found = closing_maps(&pars, &image)?;
+            Ok(found.is_some())
+        })?;
+        if let Some(maps) = found {
+            let ops = maps
+                .into_iter()
+                .enumerate()
+                .map(|(k, map)| {
+                    let symbol = OperationSymbol::new(&format!("f{}", k), 1, false);
+                    operations::make_int_operation(symbol, n as i32, map)
+                })
+                .collect::<Result<Vec<Box<dyn Operation>>, String>>()?;
+            let name = format!("Rep({})", lattice.name().unwrap_or("L"));
+            return Ok(Some(BasicAlgebra::new(name, (0..n as i32).collect(), ops)));
+        }
+    }
+    Ok(None)
+}
+
+/// Every partition of `{0, ..., n-1}`, from its restricted growth string.
+fn all_partitions(n: usize) -> Vec<Partition> {
+    fn extend(indices: &mut Vec<usize>, n: usize, result: &mut Vec<Partition>) {
+        if indices.len() == n {
+            result.push(Partition::from_block_index_vector(indices).expect("n is positive"));
+            return;
+        }
+        let blocks = indices.iter().max().map_or(0, |&b| b + 1);
+        for b in 0..=blocks {
+            indices.push(b);
+            extend(indices, n, result);
+            indices.pop();
+        }
+    }
+    let mut result = Vec::new();
+    extend(&mut Vec::new(), n, &mut result);
+    result
+}
+
+/// Check whether a unary map, given by its table, preserves a partition.
+fn preserves(map: &[i32], par: &Partition) -> bool {
+    (0..map.len()).all(|i| par.is_related(map[i] as usize, map[par.representative(i)] as usize))
+}
+
+/// Unary maps whose congruences are exactly `pars[i]` for `i` in `image`,
+/// or `None` if no set of unary maps has those congruences.
+fn closing_maps(pars: &[Partition], image: &[usize]) -> Result<Option<Vec<Vec<i32>>>, String> {
+    let sublattice: Vec<Partition> = image.iter().map(|&i| pars[i].clone()).collect();
+    let polymorphisms: Vec<Vec<i32>> = Partition::unary_polymorphisms(&sublattice)?
+        .into_iter()
+        .map(|map| map.as_slice().to_vec())
+        .collect();
+    let inside: BTreeSet<usize> = image.iter().copied().collect();
+    let mut maps: Vec<Vec<i32>> = Vec::new();
+    for (i, par) in pars.iter().enumerate() {
+        if inside.contains(&i) || maps.iter().any(|map| !preserves(map, par)) {
+            continue;
+        }
+        match polymorphisms.iter().find(|map| !preserves(map, par)) {
+            Some(map) => maps.push(map.clone()),
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(maps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::{SmallAlgebra, Algebra};
+    use crate::alg::conlat::CongruenceLattice;
+
+    fn lattice(covers: &[Vec<usize>]) -> Poset {
+        let labels = (0..covers.len()).map(|i| i.to_string()).collect();
+        Poset::from_upper_covers(None, labels, covers).unwrap()
+    }
+
+    fn con_poset(alg: BasicAlgebra<i32>) -> Poset {
+        let mut con = CongruenceLattice::new(Box::new(alg) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+        con.to_poset().unwrap()
+    }
+
+    #[test]
+    fn test_represent() {
+        assert_eq!(all_partitions(4).len(), 15);
+
+        // M3 is the whole partition lattice of a three element set.
+        let m3 = lattice(&[vec![1, 2, 3], vec![4], vec![4], vec![4], vec![]]);
+        let alg = represent(&m3, 4).unwrap().unwrap();
+        assert_eq!(alg.cardinality(), 3);
+        assert!(alg.operations().is_empty());
+
+        // N5 first appears on four elements.
+        let n5 = lattice(&[vec![1, 3], vec![2], vec![4], vec![4], vec![]]);
+        assert!(represent(&n5, 3).unwrap().is_none());
+        let alg = represent(&n5, 4).unwrap().unwrap();
+        assert_eq!(alg.cardinality(), 4);
+        assert!(!con_poset(alg).lattice_isomorphisms(&n5).unwrap().is_empty());
+
+        // M4 is not a 0-1 sublattice of the partition lattice of four elements.
+        let m4 = lattice(&[vec![1, 2, 3, 4], vec![5], vec![5], vec![5], vec![5], vec![]]);
+        assert!(represent(&m4, 4).unwrap().is_none());
+
+        let v = lattice(&[vec![], vec![0], vec![0]]);
+        assert!(represent(&v, 3).is_err());
+    }
+}
@@ -0,0 +1,298 @@
+/*! Arithmetical and discriminator varieties.
+
+A variety is arithmetical if it is congruence distributive and congruence
+permutable, which by Pixley's theorem happens exactly when it has a term
+`p(x, y, z)` with `p(x, x, y) = p(y, x, x) = p(y, x, y) = y`.
+
+The ternary discriminator on a set is `t(x, y, z) = z` if `x = y` and `x`
+otherwise; a finite algebra is quasiprimal if the discriminator is one of
+its term operations. A variety is a discriminator variety if some term is
+the discriminator on every subdirectly irreducible member. Such a term is a
+Pixley term of the SIs, hence of the variety, so a discriminator variety is
+arithmetical and, by Jónsson's lemma, the SIs of V(A) for a finite algebra A
+all lie in HS(A). The test therefore searches for a Pixley term, then
+checks that each SI in HS(A) is quasiprimal, and finally looks for a single
+term that is the discriminator on all of them at once.
+*/
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use crate::alg::{BasicAlgebra, BigProductAlgebra, Closer, SmallAlgebra};
+use crate::alg::malcev;
+use crate::alg::residual::subdirectly_irreducibles_in_hs;
+use crate::terms::{Term, VariableImp};
+use crate::util::int_array::IntArray;
+
+/// The outcome of testing whether the variety generated by an algebra is
+/// arithmetical, with the Pixley term as witness.
+#[derive(Debug)]
+pub struct ArithmeticalAnalysis {
+    /// A Pixley term, if the variety is arithmetical.
+    pub pixley_term: Option<Box<dyn Term>>,
+}
+
+impl Clone for ArithmeticalAnalysis {
+    fn clone(&self) -> Self {
+        ArithmeticalAnalysis { pixley_term: self.pixley_term.as_ref().map(|t| t.clone_box()) }
+    }
+}
+
+impl ArithmeticalAnalysis {
+    /// Whether the variety is arithmetical.
+    pub fn holds(&self) -> bool {
+        self.pixley_term.is_some()
+    }
+}
+
+/// The outcome of testing whether the variety generated by an algebra is a
+/// discriminator variety.
+#[derive(Debug)]
+pub struct DiscriminatorAnalysis {
+    /// A Pixley term, if the variety is arithmetical; the SIs are only
+    /// examined when there is one.
+    pub pixley_term: Option<Box<dyn Term>>,
+    /// The subdirectly irreducible algebras in HS(A) examined.
+    pub subdirectly_irreducibles: Vec<BasicAlgebra<i32>>,
+    /// The first SI found that is not quasiprimal, if any.
+    pub non_quasiprimal: Option<BasicAlgebra<i32>>,
+    /// A term that is the discriminator on every SI, if the variety is a
+    /// discriminator variety.
+    pub discriminator_term: Option<Box<dyn Term>>,
+}
+
+impl Clone for DiscriminatorAnalysis {
+    fn clone(&self) -> Self {
+        DiscriminatorAnalysis {
+            pixley_term: self.pixley_term.as_ref().map(|t| t.clone_box()),
+            subdirectly_irreducibles: self.subdirectly_irreducibles.clone(),
+            non_quasiprimal: self.non_quasiprimal.clone(),
+            discriminator_term: self.discriminator_term.as_ref().map(|t| t.clone_box()),
+        }
+    }
+}
+
+impl DiscriminatorAnalysis {
+    /// Whether the variety is a discriminator variety.
+    pub fn holds(&self) -> bool {
+        self.discriminator_term.is_some()
+    }
+}
+
+/// Test whether the variety generated by an algebra is arithmetical, that
+/// is congruence distributive and congruence permutable.
+///
+/// # Arguments
+/// * `alg` - The algebra
+///
+/// # Returns
+/// * `Ok(ArithmeticalAnalysis)` - With a Pixley term if the variety is
+///   arithmetical
+/// * `Err(String)` - If the search fails or was cancelled
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use uacalc::alg::BasicAlgebra;
+/// use uacalc::alg::op::{OperationSymbol, operations};
+/// use uacalc::alg::discriminator::is_arithmetical;
+///
+/// // The 2-element lattice is distributive but not congruence permutable
+/// let join = operations::make_int_operation(OperationSymbol::new("join", 2, false), 2, vec![0, 1, 1, 1]).unwrap();
+/// let meet = operations::make_int_operation(OperationSymbol::new("meet", 2, false), 2, vec![0, 0, 0, 1]).unwrap();
+/// let alg = BasicAlgebra::new("2".to_string(), (0..2).collect::<HashSet<i32>>(), vec![join, meet]);
+/// assert!(!is_arithmetical(&alg).unwrap().holds());
+/// ```
+pub fn is_arithmetical(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<ArithmeticalAnalysis, String> {
+    if alg.cardinality() > 1 && alg.operations().is_empty() {
+        return Ok(ArithmeticalAnalysis { pixley_term: None });
+    }
+    Ok(ArithmeticalAnalysis { pixley_term: malcev::pixley_term(alg)? })
+}
+
+/// Find a ternary term that is the discriminator on each of the algebras,
+/// which must be of the same similarity type.
+///
+/// The term is looked for in the subalgebra of the product of one copy of
+/// `B` for every triple `(a, b, c)` of elements of `B`, over the algebras
+/// `B`, generated by the three projections.
+///
+/// # Returns
+/// * `Ok(Some(Term))` - A common discriminator term
+/// * `Ok(None)` - If there is none
+/// * `Err(String)` - If the closure fails or was cancelled
+pub fn discriminator_term(algs: &[&dyn SmallAlgebra<UniverseItem = i32>]) -> Result<Option<Box<dyn Term>>, String> {
+    let mut factors: Vec<Box<dyn SmallAlgebra<UniverseItem = i32>>> = Vec::new();
+    let mut coords: [Vec<i32>; 3] = Default::default();
+    let mut target = Vec::new();
+    for alg in algs {
+        let n = alg.cardinality();
+        for a in 0..n {
+            for b in 0..n {
+                for c in 0..n {
+                    factors.push(alg.clone_box());
+                    coords[0].push(a);
+                    coords[1].push(b);
+                    coords[2].push(c);
+                    target.push(if a == b { c } else { a });
+                }
+            }
+        }
+    }
+    if factors.is_empty() {
+        return Ok(Some(Box::new(VariableImp::x())));
+    }
+    let product = BigProductAlgebra::new_safe(factors)?;
+    let gens = coords.into_iter().map(IntArray::from_array).collect::<Result<Vec<_>, _>>()?;
+    let vars: [Box<dyn Term>; 3] = [Box::new(VariableImp::x()), Box::new(VariableImp::y()), Box::new(VariableImp::z())];
+    let term_map: HashMap<IntArray, Box<dyn Term>> = gens.iter().cloned().zip(vars).collect();
+    let target = IntArray::from_array(target)?;
+    if let Some(term) = term_map.get(&target) {
+        return Ok(Some(term.clone_box()));
+    }
+    let mut closer = Closer::new_with_term_map_safe(Arc::new(product), gens, term_map)?;
+    closer.set_element_to_find(Some(target.clone()));
+    closer.sg_close()?;
+    Ok(closer.get_term_map().and_then(|tm| tm.get(&target).map(|t| t.clone_box())))
+}
+
+/// Whether a finite algebra is quasiprimal: the ternary discriminator is one
+/// of its term operations.
+///
+/// A quasiprimal algebra is simple, since the discriminator identifies any
+/// two elements once a congruence collapses some pair; this is checked
+/// before the closure, which can be large.
+///
+/// # Returns
+/// * `Ok(Some(Term))` - A term for the discriminator
+/// * `Ok(None)` - If the algebra is not quasiprimal
+/// * `Err(String)` - As for [`discriminator_term`]
+pub fn quasiprimal_term(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<Option<Box<dyn Term>>, String> {
+    if alg.cardinality() > 1 && !crate::alg::simple::is_simple(alg)? {
+        return Ok(None);
+    }
+    discriminator_term(&[alg])
+}
+
+/// Test whether the variety generated by an algebra is a discriminator
+/// variety.
+///
+/// The variety must be arithmetical, which is checked first; then by
+/// Jónsson's lemma its SIs lie in HS(A). Each of them must be quasiprimal,
+/// and a single term must be the discriminator on all of them. When every
+/// SI is quasiprimal but no common term exists, `non_quasiprimal` and
+/// `discriminator_term` are both `None`.
+///
+/// # Arguments
+/// * `alg` - The algebra
+///
+/// # Returns
+/// * `Ok(DiscriminatorAnalysis)` - With the discriminator term, or the
+///   missing Pixley term or a non-quasiprimal SI as witness of failure
+/// * `Err(String)` - If a search fails or was cancelled
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::algebras::ternary_discriminator_algebra;
+/// use uacalc::alg::discriminator::is_discriminator_variety;
+///
+/// let alg = ternary_discriminator_algebra(3).unwrap();
+/// let analysis = is_discriminator_variety(&alg).unwrap();
+/// assert!(analysis.holds());
+/// assert_eq!(analysis.subdirectly_irreducibles.len(), 2);
+/// ```
+pub fn is_discriminator_variety(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<DiscriminatorAnalysis, String> {
+    let pixley_term = is_arithmetical(alg)?.pixley_term;
+    let mut analysis = DiscriminatorAnalysis {
+        pixley_term,
+        subdirectly_irreducibles: Vec::new(),
+        non_quasiprimal: None,
+        discriminator_term: None,
+    };
+    if analysis.pixley_term.is_none() {
+        return Ok(analysis);
+    }
+    for si in subdirectly_irreducibles_in_hs(alg)? {
+        let si = si?.algebra;
+        crate::progress::check_cancelled()?;
+        if quasiprimal_term(&si)?.is_none() {
+            analysis.non_quasiprimal = Some(si);
+            return Ok(analysis);
+        }
+        analysis.subdirectly_irreducibles.push(si);
+    }
+    let sis: Vec<&dyn SmallAlgebra<UniverseItem = i32>> = analysis.subdirectly_irreducibles.iter()
+        .map(|si| si as &dyn SmallAlgebra<UniverseItem = i32>)
+        .collect();
+    analysis.discriminator_term = discriminator_term(&sis)?;
+    Ok(analysis)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::Algebra;
+    use crate::alg::algebras::ternary_discriminator_algebra;
+    use crate::alg::op::OperationSymbol;
+    use crate::alg::op::operations::make_int_operation;
+
+    fn binary(name: &str, size: i32, f: impl Fn(i32, i32) -> i32) -> Box<dyn crate::alg::op::Operation> {
+        let table = (0..size * size).map(|k| f(k % size, k / size)).collect();
+        make_int_operation(OperationSymbol::new(name, 2, false), size, table).unwrap()
+    }
+
+    #[test]
+    fn test_boolean_algebra() {
+        // The 2-element Boolean algebra is primal
+        let ops = vec![
+            binary("join", 2, |a, b| a | b),
+            binary("meet", 2, |a, b| a & b),
+            make_int_operation(OperationSymbol::new("neg", 1, false), 2, vec![1, 0]).unwrap(),
+        ];
+        let alg = BasicAlgebra::new("B2".to_string(), (0..2).collect(), ops);
+        assert!(is_arithmetical(&alg).unwrap().holds());
+        let analysis = is_discriminator_variety(&alg).unwrap();
+        assert!(analysis.holds());
+        let term = analysis.discriminator_term.unwrap();
+        let op = term.interpretation(Arc::new(alg.clone()), &["x".into(), "y".into(), "z".into()], true).unwrap();
+        for k in 0..8 {
+            let (a, b, c) = (k & 1, (k >> 1) & 1, k >> 2);
+            assert_eq!(op.int_value_at(&[a, b, c]).unwrap(), if a == b { c } else { a });
+        }
+    }
+
+    #[test]
+    fn test_z2_not_arithmetical() {
+        // Abelian groups are congruence permutable but not distributive
+        let alg = BasicAlgebra::new("Z2".to_string(), (0..2).collect(), vec![binary("add", 2, |a, b| a ^ b)]);
+        let analysis = is_discriminator_variety(&alg).unwrap();
+        assert!(!analysis.holds());
+        assert!(analysis.pixley_term.is_none());
+        assert!(analysis.subdirectly_irreducibles.is_empty());
+    }
+
+    #[test]
+    fn test_discriminator_algebra() {
+        let alg = ternary_discriminator_algebra(3).unwrap();
+        assert!(quasiprimal_term(&alg).unwrap().is_some());
+        let analysis = is_discriminator_variety(&alg).unwrap();
+        assert!(analysis.holds());
+        assert!(analysis.subdirectly_irreducibles.iter().all(|si| si.cardinality() >= 2));
+        assert!(analysis.non_quasiprimal.is_none());
+    }
+
+    #[test]
+    fn test_heyting_chain() {
+        // The 3-element Heyting chain is subdirectly irreducible but not
+        // simple, so not quasiprimal
+        let ops = vec![
+            binary("join", 3, |a, b| a.max(b)),
+            binary("meet", 3, |a, b| a.min(b)),
+            binary("imp", 3, |a, b| if a <= b { 2 } else { b }),
+        ];
+        let alg = BasicAlgebra::new("H3".to_string(), (0..3).collect(), ops);
+        assert!(is_arithmetical(&alg).unwrap().holds());
+        let analysis = is_discriminator_variety(&alg).unwrap();
+        assert!(!analysis.holds());
+        assert_eq!(analysis.non_quasiprimal.unwrap().cardinality(), 3);
+    }
+}
@@ -0,0 +1,329 @@
+/*! A bounded probe for definable principal congruences.
+
+A variety V has definable principal congruences (DPC) if a first-order
+formula `φ(x, y, u, v)` defines `(x, y) ∈ Cg(u, v)` in every member of V.
+By Mal'cev's lemma `(x, y) ∈ Cg(u, v)` exactly when there is a chain
+`x = z_0, z_1, ..., z_n = y` with `{z_i, z_{i+1}} = {p_i(u), p_i(v)}` for
+unary polynomials `p_i`. The pairs `(p(u), p(v))` form the subuniverse of
+`B²` generated by `(u, v)` and the diagonal, and a pair first generated in
+round `d` of the closure is `(t(u, w̄), t(v, w̄))` for a term `t` of depth
+`d` and parameters `w̄`. So once the chain length is at most `n` and the
+depth at most `d`, principal congruences are defined by the existential
+positive formula
+
+```text
+φ_{n,d}(x, y, u, v) = ∃ z_1 ... z_{n-1} ⋀_i ⋁_t ∃ w̄ {z_i, z_{i+1}} = {t(u, w̄), t(v, w̄)}
+```
+
+with `t` ranging over the finitely many terms of depth at most `d`.
+
+Whether V(A) has DPC cannot be decided by looking at finitely many
+algebras, so the probe is a bounded search: it computes the least `n` and
+`d` that work in each of the powers `A, A², ..., A^k` and reports
+`φ_{n,d}` as the scheme when the last power needs no larger bounds than the
+earlier ones. Growing bounds are evidence against DPC, but not a proof.
+DPC matters for finite basis questions through McKenzie's theorem: a
+variety of finite type with DPC and a finite bound on its subdirectly
+irreducible members is finitely based.
+*/
+
+use std::collections::VecDeque;
+use std::fmt;
+use crate::alg::SmallAlgebra;
+use crate::alg::op::Operation;
+
+/// The bounds needed to define principal congruences in one power of the
+/// algebra.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DpcLevel {
+    /// The exponent `k` of the power `A^k`.
+    pub power: usize,
+    /// The number of elements of `A^k`.
+    pub size: usize,
+    /// The longest Mal'cev chain needed, over all `(x, y) ∈ Cg(u, v)`.
+    pub chain_length: usize,
+    /// The largest term depth needed to generate the pairs
+    /// `(p(u), p(v))`.
+    pub term_depth: usize,
+    /// Elements `(u, v, x, y)` of `A^k`, as indices, where `(x, y)` needs a
+    /// chain of the maximal length in `Cg(u, v)`; `None` when every
+    /// principal congruence is trivial.
+    pub witness: Option<[usize; 4]>,
+}
+
+/// A scheme `φ_{n,d}` defining principal congruences: Mal'cev chains of
+/// length at most `n` of polynomials given by terms of depth at most `d`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DpcScheme {
+    pub chain_length: usize,
+    pub term_depth: usize,
+}
+
+impl fmt::Display for DpcScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(x, y) ∈ Cg(u, v) iff x = z_0, ..., z_{} = y with {{z_i, z_i+1}} = {{t(u, w), t(v, w)}}, t of depth <= {}",
+            self.chain_length, self.term_depth)
+    }
+}
+
+/// The outcome of the DPC probe.
+#[derive(Debug, Clone)]
+pub struct DpcReport {
+    /// The bounds found in `A, A², ..., A^k`.
+    pub levels: Vec<DpcLevel>,
+    /// The scheme, when the bounds did not grow at the last power.
+    pub scheme: Option<DpcScheme>,
+}
+
+impl fmt::Display for DpcReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.scheme {
+            Some(scheme) => writeln!(f, "scheme found: {}", scheme)?,
+            None => writeln!(f, "no scheme found")?,
+        }
+        for level in &self.levels {
+            writeln!(f, "  A^{} ({} elements): chains of length {}, terms of depth {}",
+                level.power, level.size, level.chain_length, level.term_depth)?;
+        }
+        Ok(())
+    }
+}
+
+/// The power `A^k` with elements encoded in base `|A|`, coordinate 0 least
+/// significant; operations are applied coordinatewise.
+struct Power<'a> {
+    ops: Vec<&'a dyn Operation>,
+    base: usize,
+    coords: Vec<Vec<i32>>,
+}
+
+impl<'a> Power<'a> {
+    fn new(alg: &'a dyn SmallAlgebra<UniverseItem = i32>, k: usize) -> Result<Self, String> {
+        let base = alg.cardinality().max(0) as usize;
+        let size = base.checked_pow(k as u32)
+            .filter(|&s| s.checked_mul(s).is_some_and(|s2| s2 <= i32::MAX as usize))
+            .ok_or_else(|| format!("The power A^{} is too large", k))?;
+        let coords = (0..size)
+            .map(|mut e| (0..k).map(|_| { let c = e % base; e /= base; c as i32 }).collect())
+            .collect();
+        Ok(Power { ops: alg.get_operations_ref(), base, coords })
+    }
+
+    fn size(&self) -> usize {
+        self.coords.len()
+    }
+
+    fn apply(&self, op: &dyn Operation, args: &[usize], scratch: &mut [i32]) -> Result<usize, String> {
+        let mut value = 0;
+        for j in (0..self.coords.first().map_or(0, |c| c.len())).rev() {
+            for (s, &a) in scratch.iter_mut().zip(args) {
+                *s = self.coords[a][j];
+            }
+            value = value * self.base + op.int_value_at(scratch)? as usize;
+        }
+        Ok(value)
+    }
+
+    /// The pairs `(p(u), p(v))` for unary polynomials `p`, as a list of
+    /// `(a, b)`, and the number of closure rounds that added new pairs.
+    fn polynomial_pairs(&self, u: usize, v: usize) -> Result<(Vec<(usize, usize)>, usize), String> {
+        let n = self.size();
+        let mut member = vec![false; n * n];
+        let mut lst: Vec<(usize, usize)> = (0..n).map(|c| (c, c)).collect();
+        for &(a, b) in &lst {
+            member[a * n + b] = true;
+        }
+        // The diagonal is closed, so only tuples using a new pair matter.
+        let mut closed = lst.len();
+        member[u * n + v] = true;
+        lst.push((u, v));
+        let mut rounds = 0;
+        while closed < lst.len() {
+            let current = lst.len();
+            for op in self.ops.iter().filter(|op| op.arity() > 0) {
+                let arity = op.arity() as usize;
+                let mut idx = vec![0; arity];
+                let (mut left, mut right) = (vec![0; arity], vec![0; arity]);
+                let mut scratch = vec![0; arity];
+                loop {
+                    if idx.iter().any(|&i| i >= closed) {
+                        for (j, &i) in idx.iter().enumerate() {
+                            left[j] = lst[i].0;
+                            right[j] = lst[i].1;
+                        }
+                        let pair = (self.apply(*op, &left, &mut scratch)?, self.apply(*op, &right, &mut scratch)?);
+                        if !member[pair.0 * n + pair.1] {
+                            member[pair.0 * n + pair.1] = true;
+                            lst.push(pair);
+                        }
+                    }
+                    let mut pos = 0;
+                    while pos < arity && idx[pos] + 1 == current {
+                        idx[pos] = 0;
+                        pos += 1;
+                    }
+                    if pos == arity {
+                        break;
+                    }
+                    idx[pos] += 1;
+                }
+            }
+            if lst.len() > current {
+                rounds += 1;
+            }
+            closed = current;
+        }
+        Ok((lst, rounds))
+    }
+}
+
+/// The longest shortest path between connected vertices of the graph
+/// whose edges are `pairs`, with its endpoints.
+fn longest_chain(n: usize, pairs: &[(usize, usize)]) -> (usize, Option<(usize, usize)>) {
+    let mut adj = vec![Vec::new(); n];
+    for &(a, b) in pairs.iter().filter(|(a, b)| a != b) {
+        adj[a].push(b);
+        adj[b].push(a);
+    }
+    let mut best = (0, None);
+    let mut dist = vec![usize::MAX; n];
+    for x in (0..n).filter(|&x| !adj[x].is_empty()) {
+        dist.iter_mut().for_each(|d| *d = usize::MAX);
+        dist[x] = 0;
+        let mut queue = VecDeque::from([x]);
+        while let Some(a) = queue.pop_front() {
+            if dist[a] > best.0 {
+                best = (dist[a], Some((x, a)));
+            }
+            for &b in &adj[a] {
+                if dist[b] == usize::MAX {
+                    dist[b] = dist[a] + 1;
+                    queue.push_back(b);
+                }
+            }
+        }
+    }
+    best
+}
+
+/// Compute the chain length and term depth needed to define the principal
+/// congruences of `A^k`.
+///
+/// # Arguments
+/// * `alg` - The algebra
+/// * `k` - The exponent
+///
+/// # Returns
+/// * `Ok(DpcLevel)` - The bounds and a pair needing the longest chain
+/// * `Err(String)` - If the power is too large, an operation cannot be
+///   evaluated or the computation was cancelled
+pub fn dpc_level(alg: &dyn SmallAlgebra<UniverseItem = i32>, k: usize) -> Result<DpcLevel, String> {
+    let power = Power::new(alg, k)?;
+    let n = power.size();
+    let mut level = DpcLevel { power: k, size: n, chain_length: 0, term_depth: 0, witness: None };
+    for u in 0..n {
+        for v in u + 1..n {
+            crate::progress::check_cancelled()?;
+            let (pairs, rounds) = power.polynomial_pairs(u, v)?;
+            level.term_depth = level.term_depth.max(rounds);
+            let (length, ends) = longest_chain(n, &pairs);
+            if let Some((x, y)) = ends.filter(|_| length > level.chain_length) {
+                level.chain_length = length;
+                level.witness = Some([u, v, x, y]);
+            }
+        }
+    }
+    Ok(level)
+}
+
+/// Probe whether the variety generated by an algebra has definable
+/// principal congruences, looking at the powers `A, ..., A^max_power`.
+///
+/// The scheme is reported when `max_power` is at least 2 and `A^max_power`
+/// needs neither longer chains nor deeper terms than the smaller powers;
+/// it then defines principal congruences in every power examined, which
+/// suggests, but does not prove, that it does so throughout the variety.
+///
+/// # Arguments
+/// * `alg` - The algebra
+/// * `max_power` - The largest exponent, the parameter bound of the search
+///
+/// # Returns
+/// * `Ok(DpcReport)` - The bounds for each power and the scheme, if found
+/// * `Err(String)` - As for `dpc_level`
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use uacalc::alg::BasicAlgebra;
+/// use uacalc::alg::op::{OperationSymbol, operations};
+/// use uacalc::alg::dpc::dpc_probe;
+///
+/// // In Z3 and its powers Cg(u, v) is the set of pairs differing by a
+/// // multiple of u - v, and each is (p(u), p(v)) for a polynomial p.
+/// let add = OperationSymbol::new("add", 2, false);
+/// let op = operations::make_int_operation(add, 3, (0..9).map(|k| (k % 3 + k / 3) % 3).collect()).unwrap();
+/// let alg = BasicAlgebra::new("Z3".to_string(), (0..3).collect::<HashSet<i32>>(), vec![op]);
+///
+/// let report = dpc_probe(&alg, 2).unwrap();
+/// let scheme = report.scheme.unwrap();
+/// assert_eq!(scheme.chain_length, 1);
+/// ```
+pub fn dpc_probe(alg: &dyn SmallAlgebra<UniverseItem = i32>, max_power: usize) -> Result<DpcReport, String> {
+    let levels = (1..=max_power).map(|k| dpc_level(alg, k)).collect::<Result<Vec<_>, _>>()?;
+    let scheme = match levels.split_last() {
+        Some((last, earlier)) if !earlier.is_empty() => {
+            let chain_length = earlier.iter().map(|l| l.chain_length).max().unwrap_or(0);
+            let term_depth = earlier.iter().map(|l| l.term_depth).max().unwrap_or(0);
+            (last.chain_length <= chain_length && last.term_depth <= term_depth)
+                .then_some(DpcScheme { chain_length, term_depth })
+        }
+        _ => None,
+    };
+    Ok(DpcReport { levels, scheme })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::BasicAlgebra;
+    use crate::alg::op::OperationSymbol;
+    use crate::alg::op::operations::make_int_operation;
+
+    #[test]
+    fn test_longest_chain() {
+        assert_eq!(longest_chain(4, &[(0, 1), (1, 2), (2, 3), (1, 1)]).0, 3);
+        assert_eq!(longest_chain(3, &[(0, 0)]), (0, None));
+    }
+
+    #[test]
+    fn test_semilattice() {
+        // Cg(u, v) in a meet semilattice needs two steps: x ≡ x ∧ y ≡ y.
+        let meet = make_int_operation(OperationSymbol::new("meet", 2, false), 2, vec![0, 0, 0, 1]).unwrap();
+        let alg = BasicAlgebra::new("S2".to_string(), (0..2).collect(), vec![meet]);
+        let report = dpc_probe(&alg, 4).unwrap();
+        assert_eq!(report.scheme, Some(DpcScheme { chain_length: 2, term_depth: 1 }));
+        assert_eq!(report.levels.iter().map(|l| l.size).collect::<Vec<_>>(), vec![2, 4, 8, 16]);
+        assert!(report.to_string().starts_with("scheme found: "));
+    }
+
+    #[test]
+    fn test_lattice_witness() {
+        let join = make_int_operation(OperationSymbol::new("join", 2, false), 2, vec![0, 1, 1, 1]).unwrap();
+        let meet = make_int_operation(OperationSymbol::new("meet", 2, false), 2, vec![0, 0, 0, 1]).unwrap();
+        let alg = BasicAlgebra::new("2".to_string(), (0..2).collect(), vec![join, meet]);
+        // The polynomials of 2^3 need deeper terms than those of 2^2
+        let report = dpc_probe(&alg, 3).unwrap();
+        assert!(report.scheme.is_none());
+        assert_eq!(report.levels[2].term_depth, 2);
+
+        let level = &report.levels[1];
+        assert_eq!(level.chain_length, 2);
+        let [u, v, x, y] = level.witness.unwrap();
+        let power = Power::new(&alg, 2).unwrap();
+        let (pairs, _) = power.polynomial_pairs(u, v).unwrap();
+        assert!(!pairs.contains(&(x, y)) && !pairs.contains(&(y, x)));
+        assert_eq!(longest_chain(4, &pairs).0, 2);
+        assert!(dpc_probe(&alg, 1).unwrap().scheme.is_none());
+    }
+}
@@ -0,0 +1,262 @@
+/*! An experimental probe for natural dualities of small algebras.
+
+An alter ego of a finite algebra `A` is a structure on the same set whose
+relations are compatible with `A`, that is subuniverses of powers of `A`;
+operations and partial operations enter as their graphs. The dual of an
+algebra `B` in ISP(A) is the set `Hom(B, A)` with the structure inherited
+from the alter ego, and the alter ego dualizes `B` if every morphism from
+the dual of `B` to the alter ego is evaluation at an element of `B`.
+
+For the free algebra `F(n)` the dual is the `n`-th power of the alter ego
+and evaluations are the `n`-ary term operations, so the alter ego dualizes
+`F(n)` exactly when every `n`-ary polymorphism of its relations is a term
+operation of `A`: the interpolation condition on `A^n`. The probe checks it
+for `n = 1, ..., max_n` and searches among the subsets of the compatible
+relations of bounded arity for minimal alter egos passing the check.
+
+Passing the check is necessary for a duality but it is not a proof of one,
+which needs the condition on every substructure of every power. By the NU
+duality theorem the compatible relations of arity `k` do yield a duality
+when `A` has a near-unanimity term of arity `k + 1`.
+
+Each check finds the `n`-ary term operations of `A`, whose number grows
+doubly exponentially in `n`, so only small algebras and small `max_n` are
+practical.
+*/
+
+use std::collections::HashSet;
+use std::fmt;
+use crate::alg::SmallAlgebra;
+use crate::alg::clone::{self, polymorphism_problem, Relation};
+use crate::alg::free_spectrum::term_operation_tables;
+use crate::alg::op::{operations, Operation, OperationSymbol};
+
+/// The outcome of a natural duality probe.
+#[derive(Debug)]
+pub struct DualityProbe {
+    /// The largest arity of the compatible relations considered.
+    pub relation_arity: usize,
+    /// The largest power on which the interpolation condition was checked.
+    pub max_n: usize,
+    /// The compatible relations considered, without the empty relation,
+    /// the full relation and equality, which every operation preserves.
+    pub relations: Vec<Relation>,
+    /// A polymorphism of all the relations that is not a term operation, if
+    /// the check fails even for the largest alter ego.
+    pub failure: Option<Box<dyn Operation>>,
+    /// The minimal alter egos found, each a list of indices into `relations`.
+    pub candidates: Vec<Vec<usize>>,
+}
+
+impl Clone for DualityProbe {
+    fn clone(&self) -> Self {
+        DualityProbe {
+            relation_arity: self.relation_arity,
+            max_n: self.max_n,
+            relations: self.relations.clone(),
+            failure: self.failure.as_ref().map(|op| op.clone_box()),
+            candidates: self.candidates.clone(),
+        }
+    }
+}
+
+impl DualityProbe {
+    /// Whether the compatible relations of arity at most `relation_arity`
+    /// pass the interpolation condition on `A^n` for `n <= max_n`.
+    pub fn holds(&self) -> bool {
+        self.failure.is_none()
+    }
+
+    /// The relations of a candidate alter ego.
+    pub fn candidate_relations(&self, i: usize) -> Vec<&Relation> {
+        self.candidates[i].iter().map(|&r| &self.relations[r]).collect()
+    }
+}
+
+impl fmt::Display for DualityProbe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "relations of arity <= {}: {}, checked on A^n for n <= {}",
+            self.relation_arity, self.relations.len(), self.max_n)?;
+        if let Some(op) = &self.failure {
+            return writeln!(f, "fails: {}-ary polymorphism {:?} is not a term operation",
+                op.arity(), op.get_table().unwrap_or_default());
+        }
+        for candidate in &self.candidates {
+            let rels: Vec<String> = candidate.iter().map(|&r| self.relations[r].to_string()).collect();
+            writeln!(f, "candidate: {}", rels.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Probe `alg` for an alter ego yielding a natural duality.
+///
+/// The relations considered are the subuniverses of `A^k` for
+/// `k <= relation_arity`. If all of them together fail the interpolation
+/// condition on some `A^n`, `n <= max_n`, so does every subset and the probe
+/// stops with the offending polymorphism. Otherwise it tries the subsets of
+/// at most `max_relations` relations, smallest first, and keeps those that
+/// pass and contain no smaller candidate.
+///
+/// # Arguments
+/// * `alg` - The algebra
+/// * `relation_arity` - The largest arity of the relations
+/// * `max_n` - The largest power on which to check the condition
+/// * `max_relations` - The largest number of relations in a candidate
+///
+/// # Returns
+/// * `Ok(DualityProbe)` - The relations, a failure or the candidates found
+/// * `Err(String)` - If a power is too large to index or the computation
+///   was cancelled
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::BasicAlgebra;
+/// use uacalc::alg::op::{operations, OperationSymbol};
+/// use uacalc::alg::duality::probe_duality;
+///
+/// // Z2 under + is not dualized by binary relations, but is by the graph of +.
+/// let plus = operations::make_int_operation(OperationSymbol::new("+", 2, false), 2, vec![0, 1, 1, 0]).unwrap();
+/// let z2 = BasicAlgebra::new("Z2".to_string(), (0..2).collect(), vec![plus]);
+/// assert!(!probe_duality(&z2, 2, 2, 1).unwrap().holds());
+/// let probe = probe_duality(&z2, 3, 2, 1).unwrap();
+/// assert!(probe.holds());
+/// assert!(probe.candidates.iter().any(|c| probe.relations[c[0]].arity() == 3));
+/// ```
+pub fn probe_duality(
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    relation_arity: usize,
+    max_n: usize,
+    max_relations: usize,
+) -> Result<DualityProbe, String> {
+    let size = alg.cardinality();
+    let relations: Vec<Relation> = clone::inv(size, &alg.operations(), relation_arity)?
+        .into_iter()
+        .filter(|r| !is_trivial(r))
+        .collect();
+    let mut term_ops = Vec::with_capacity(max_n);
+    for n in 1..=max_n {
+        let tables = term_operation_tables(alg, n, None)?;
+        crate::progress::check_cancelled()?;
+        let tables = tables.ok_or_else(|| crate::progress::CANCELLED.to_string())?;
+        term_ops.push(tables.into_iter().collect::<HashSet<Vec<i32>>>());
+    }
+    let mut probe = DualityProbe { relation_arity, max_n, relations, failure: None, candidates: Vec::new() };
+    if let Some((arity, table)) = non_term_polymorphism(size, &probe.relations, &term_ops)? {
+        let sym = OperationSymbol::new_safe("w", arity as i32, false)?;
+        probe.failure = Some(operations::make_int_operation(sym, size, table)?);
+        return Ok(probe);
+    }
+    let count = probe.relations.len();
+    for k in 0..=max_relations.min(count) {
+        let mut subset: Vec<usize> = (0..k).collect();
+        loop {
+            crate::progress::check_cancelled()?;
+            let minimal = !probe.candidates.iter().any(|c| c.iter().all(|r| subset.contains(r)));
+            if minimal {
+                let rels: Vec<Relation> = subset.iter().map(|&r| probe.relations[r].clone()).collect();
+                if non_term_polymorphism(size, &rels, &term_ops)?.is_none() {
+                    probe.candidates.push(subset.clone());
+                }
+            }
+            if !next_subset(&mut subset, count) {
+                break;
+            }
+        }
+    }
+    Ok(probe)
+}
+
+/// The arity `n <= term_ops.len()` and table of a polymorphism of
+/// `relations` that is not a term operation, where `term_ops[n - 1]` holds
+/// the tables of the `n`-ary term operations.
+fn non_term_polymorphism(
+    size: i32,
+    relations: &[Relation],
+    term_ops: &[HashSet<Vec<i32>>],
+) -> Result<Option<(usize, Vec<i32>)>, String> {
+    for (i, ops) in term_ops.iter().enumerate() {
+        // The term operations are polymorphisms, so among any |ops| + 1
+        // polymorphisms there is one that is not a term operation.
+        let found = polymorphism_problem(size, relations, i + 1)?.all_solutions(Some(ops.len() + 1));
+        crate::progress::check_cancelled()?;
+        if let Some(table) = found.into_iter().find(|t| !ops.contains(t)) {
+            return Ok(Some((i + 1, table)));
+        }
+    }
+    Ok(None)
+}
+
+/// Whether every operation preserves `r`: the empty relation, the full
+/// relation and equality.
+fn is_trivial(r: &Relation) -> bool {
+    r.is_empty()
+        || r.len() as u64 == (r.universe_size() as u64).pow(r.arity() as u32)
+        || (r.arity() == 2 && r.len() as i32 == r.universe_size() && r.tuples().iter().all(|t| t[0] == t[1]))
+}
+
+/// Advance `subset`, a strictly increasing list of indices below `count`,
+/// to the next one in lexicographic order.
+fn next_subset(subset: &mut [usize], count: usize) -> bool {
+    let k = subset.len();
+    for i in (0..k).rev() {
+        if subset[i] < count - k + i {
+            subset[i] += 1;
+            for j in i + 1..k {
+                subset[j] = subset[j - 1] + 1;
+            }
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::BasicAlgebra;
+
+    fn binary(name: &str, size: i32, ops: &[(&str, fn(i32, i32) -> i32)]) -> BasicAlgebra<i32> {
+        let ops = ops.iter().map(|(op, f)| {
+            let table = (0..size * size).map(|k| f(k % size, k / size)).collect();
+            operations::make_int_operation(OperationSymbol::new(op, 2, false), size, table).unwrap()
+        }).collect();
+        BasicAlgebra::new(name.to_string(), (0..size).collect(), ops)
+    }
+
+    #[test]
+    fn test_lattice() {
+        // The two element lattice has a majority term, so its binary
+        // compatible relations dualize it (Priestley duality).
+        let lattice = binary("2", 2, &[("join", i32::max), ("meet", i32::min)]);
+        let probe = probe_duality(&lattice, 2, 3, 2).unwrap();
+        assert!(probe.holds());
+        assert!(!probe.candidates.is_empty());
+        let order = Relation::new(2, 2, vec![vec![0, 0], vec![0, 1], vec![1, 1]]).unwrap();
+        for i in 0..probe.candidates.len() {
+            // The ternary polymorphisms of a candidate are lattice terms,
+            // which are monotone, so the order is pp-definable from it.
+            let rels: Vec<Relation> = probe.candidate_relations(i).into_iter().cloned().collect();
+            assert!(clone::pp_definable(&order, &rels).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_primal() {
+        // The two element Boolean algebra with Sheffer stroke is primal and
+        // dualized by the bare set.
+        let nand = binary("B", 2, &[("nand", |x, y| 1 - x * y)]);
+        let probe = probe_duality(&nand, 2, 3, 1).unwrap();
+        assert!(probe.relations.is_empty());
+        assert_eq!(probe.candidates, vec![Vec::<usize>::new()]);
+    }
+
+    #[test]
+    fn test_failure() {
+        let z2 = binary("Z2", 2, &[("+", |x, y| (x + y) % 2)]);
+        let probe = probe_duality(&z2, 2, 2, 2).unwrap();
+        let w = probe.failure.as_ref().unwrap();
+        assert_eq!(w.arity(), 2);
+        assert!(probe.candidates.is_empty());
+    }
+}
@@ -0,0 +1,165 @@
+/*! Fiber products (pullbacks) of algebras.
+
+For homomorphisms `f: A -> C` and `g: B -> C` the fiber product is the
+subalgebra `{(a, b) : f(a) = g(b)}` of `A × B`. With its two projections it
+is the pullback of `f` and `g`: any pair of homomorphisms into `A` and `B`
+that agree after `f` and `g` factors through it uniquely. When `C` is trivial
+it is all of `A × B`.
+*/
+
+use std::collections::HashMap;
+use crate::alg::{Algebra, BasicAlgebra, BigProductAlgebra, Homomorphism, SmallAlgebra, SubProductAlgebra};
+use crate::util::int_array::{IntArray, IntArrayTrait};
+
+/// The fiber product of two homomorphisms with a common range, with its
+/// projections.
+pub struct FiberProduct {
+    /// The fiber product as a subalgebra of `A × B`.
+    algebra: SubProductAlgebra<i32>,
+    /// The projection onto `A`, from the fiber product on the indices of its
+    /// elements.
+    first: Homomorphism,
+    /// The projection onto `B`, likewise.
+    second: Homomorphism,
+}
+
+/// Construct the fiber product `{(a, b) : f(a) = g(b)}` of `f: A -> C` and
+/// `g: B -> C`.
+///
+/// # Arguments
+/// * `f` - A homomorphism `A -> C`
+/// * `g` - A homomorphism `B -> C`
+///
+/// # Returns
+/// * `Ok(FiberProduct)` - The fiber product and its projections
+/// * `Err(String)` - If the ranges differ in size, no pair agrees or the
+///   maps are not homomorphisms
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::{fiber_product, Homomorphism, BasicAlgebra, SmallAlgebra, Algebra};
+/// use uacalc::alg::op::{OperationSymbol, operations};
+/// use std::collections::HashSet;
+///
+/// let s = OperationSymbol::new("s", 1, false);
+/// let z = |n: i32| Box::new(BasicAlgebra::new(format!("Z{}", n), (0..n).collect::<HashSet<i32>>(),
+///     vec![operations::make_int_operation(s.clone(), n, (0..n).map(|k| (k + 1) % n).collect()).unwrap()]))
+///     as Box<dyn SmallAlgebra<UniverseItem = i32>>;
+///
+/// // Z4 and Z6 over Z2: the pairs of equal parity.
+/// let f = Homomorphism::from_images(z(4), z(2), &[0, 1, 0, 1]).unwrap();
+/// let g = Homomorphism::from_images(z(6), z(2), &[0, 1, 0, 1, 0, 1]).unwrap();
+/// let pullback = fiber_product(&f, &g).unwrap();
+/// assert_eq!(pullback.algebra().cardinality(), 12);
+/// assert!(pullback.first_projection().is_surjective());
+/// ```
+pub fn fiber_product(f: &Homomorphism, g: &Homomorphism) -> Result<FiberProduct, String> {
+    let (a, b) = (f.get_domain(), g.get_domain());
+    if f.get_range().cardinality() != g.get_range().cardinality() {
+        return Err(format!(
+            "The ranges {} and {} of the homomorphisms differ",
+            f.get_range().name(),
+            g.get_range().name()
+        ));
+    }
+    let image = |h: &Homomorphism, x: usize| {
+        h.get_map().get(&x).copied().ok_or_else(|| format!("Element {} of {} is not mapped", x, h.get_domain().name()))
+    };
+    let mut pairs = Vec::new();
+    for x in 0..a.cardinality() as usize {
+        let fx = image(f, x)?;
+        for y in 0..b.cardinality() as usize {
+            if fx == image(g, y)? {
+                pairs.push(IntArray::from_array(vec![x as i32, y as i32])?);
+            }
+        }
+    }
+    if pairs.is_empty() {
+        return Err("No pair of elements has the same image, so the fiber product is empty".to_string());
+    }
+
+    let name = format!("{} x_{} {}", a.name(), f.get_range().name(), b.name());
+    let prod = BigProductAlgebra::new_with_name_safe(format!("{} x {}", a.name(), b.name()), vec![a.clone_box(), b.clone_box()])?;
+    let algebra = SubProductAlgebra::new_safe(name.clone(), prod, pairs.clone(), false)?;
+    // The pairs are closed under the operations when f and g are homomorphisms.
+    if algebra.cardinality() as usize != pairs.len() {
+        return Err("The maps are not both homomorphisms: the pairs are not a subuniverse".to_string());
+    }
+
+    let indexed = BasicAlgebra::new(name, (0..algebra.cardinality()).collect(), algebra.operations());
+    let projection = |k: usize, range: &dyn SmallAlgebra<UniverseItem = i32>| {
+        let map: HashMap<usize, usize> = algebra
+            .get_universe_list()
+            .iter()
+            .enumerate()
+            .map(|(i, pair)| (i, pair.get(k).unwrap_or(0) as usize))
+            .collect();
+        Homomorphism::new_safe(Box::new(indexed.clone()), range.clone_box(), map)
+    };
+    let first = projection(0, a)?;
+    let second = projection(1, b)?;
+    Ok(FiberProduct { algebra, first, second })
+}
+
+impl FiberProduct {
+    /// The fiber product as a subalgebra of `A × B`.
+    pub fn algebra(&self) -> &SubProductAlgebra<i32> {
+        &self.algebra
+    }
+
+    /// The projection onto `A`, its domain the fiber product on the indices
+    /// of its elements.
+    pub fn first_projection(&self) -> &Homomorphism {
+        &self.first
+    }
+
+    /// The projection onto `B`, its domain the fiber product on the indices
+    /// of its elements.
+    pub fn second_projection(&self) -> &Homomorphism {
+        &self.second
+    }
+
+    /// The elements as pairs `(a, b)`, in the order of their indices.
+    pub fn pairs(&self) -> Vec<(i32, i32)> {
+        self.algebra
+            .get_universe_list()
+            .iter()
+            .map(|pair| (pair.get(0).unwrap_or(0), pair.get(1).unwrap_or(0)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use crate::alg::op::{operations, OperationSymbol};
+
+    fn z(n: i32) -> Box<dyn SmallAlgebra<UniverseItem = i32>> {
+        let s = OperationSymbol::new("s", 1, false);
+        let op = operations::make_int_operation(s, n, (0..n).map(|k| (k + 1) % n).collect()).unwrap();
+        Box::new(BasicAlgebra::new(format!("Z{}", n), (0..n).collect::<HashSet<i32>>(), vec![op]))
+    }
+
+    #[test]
+    fn test_fiber_product() {
+        let f = Homomorphism::from_images(z(4), z(2), &[0, 1, 0, 1]).unwrap();
+        let pullback = fiber_product(&f, &f).unwrap();
+        // The kernel of f as a subalgebra of Z4 x Z4.
+        let mut pairs = pullback.pairs();
+        pairs.sort();
+        assert_eq!(pairs, vec![(0, 0), (0, 2), (1, 1), (1, 3), (2, 0), (2, 2), (3, 1), (3, 3)]);
+        let (p, q) = (pullback.first_projection(), pullback.second_projection());
+        for (i, &(a, b)) in pullback.pairs().iter().enumerate() {
+            assert_eq!(p.get_map()[&i], a as usize);
+            assert_eq!(q.get_map()[&i], b as usize);
+        }
+
+        // Over a trivial algebra it is the whole product.
+        let to_one = |n: i32| Homomorphism::from_images(z(n), z(1), &vec![0; n as usize]).unwrap();
+        assert_eq!(fiber_product(&to_one(2), &to_one(3)).unwrap().algebra().cardinality(), 6);
+
+        let g = Homomorphism::from_images(z(3), z(3), &[0, 1, 2]).unwrap();
+        assert!(fiber_product(&f, &g).is_err());
+    }
+}
@@ -0,0 +1,315 @@
+/*! Probes for the finite axiomatizability of the variety generated by a
+finite algebra.
+
+Whether a finite algebra has a finite basis for its equations is
+undecidable in general (McKenzie 1996), so there is no complete test. What
+can be checked are the hypotheses of the known finite basis theorems. Each
+probe below checks one of them, cheapest first, and records what it found
+together with the theorem it relies on:
+
+* algebras with at most two elements (Lyndon),
+* groups, rings, lattices and commutative semigroups, when the theory uses
+  every operation of the algebra (Oates–Powell, Kruse and L'vov,
+  McKenzie, Perkins),
+* congruence distributive varieties, witnessed by Jónsson terms (Baker),
+* congruence meet-semidistributive varieties, witnessed by SD(∧) terms;
+  finitely based if also residually finite (Willard),
+* congruence modular varieties, witnessed by Gumm terms; finitely based
+  if also residually small (McKenzie).
+
+The last two theorems need a hypothesis about the subdirectly irreducible
+members of the variety that is not checked here, so they give a
+conditional verdict. A positive probe is a proof; a negative one is not:
+an algebra none of the probes applies to gets the verdict
+[`FiniteBasisVerdict::Unknown`], never "not finitely based". In
+particular inherently nonfinitely based algebras such as Murskiĭ's three
+element groupoid or the Brandt monoid B₂¹ are reported as unknown.
+*/
+
+use std::fmt;
+use crate::alg::{Algebra, BasicAlgebra};
+use crate::alg::malcev;
+use crate::eq::theories::{self, Theory};
+
+/// The conclusion drawn from the finite basis probes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FiniteBasisVerdict {
+    /// A finite basis theorem applies: the variety is finitely based.
+    FinitelyBased,
+    /// A finite basis theorem applies given the named hypothesis on the
+    /// variety, which was not checked.
+    FinitelyBasedIf(String),
+    /// None of the probed theorems applies; nothing is claimed.
+    Unknown,
+}
+
+impl fmt::Display for FiniteBasisVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FiniteBasisVerdict::FinitelyBased => write!(f, "finitely based"),
+            FiniteBasisVerdict::FinitelyBasedIf(condition) => write!(f, "finitely based if {}", condition),
+            FiniteBasisVerdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// One finite basis theorem and whether its checked hypothesis holds.
+#[derive(Debug, Clone)]
+pub struct FiniteBasisCriterion {
+    /// A short name for the hypothesis, e.g. "congruence distributive"
+    pub name: &'static str,
+    /// Whether the hypothesis holds in the variety generated by the algebra
+    pub holds: bool,
+    /// A further hypothesis the theorem needs that is not checked
+    pub unchecked: Option<&'static str>,
+    /// What was found, e.g. the witnessing terms
+    pub detail: String,
+    /// The theorem the criterion relies on
+    pub reference: &'static str,
+}
+
+impl fmt::Display for FiniteBasisCriterion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name, if self.holds { "yes" } else { "no" })?;
+        if !self.detail.is_empty() {
+            write!(f, " ({})", self.detail)?;
+        }
+        write!(f, " [{}]", self.reference)
+    }
+}
+
+/// The outcome of the finite basis probes.
+#[derive(Debug, Clone)]
+pub struct FiniteBasisReport {
+    /// The name of the algebra
+    pub algebra: String,
+    /// The verdict
+    pub verdict: FiniteBasisVerdict,
+    /// The criteria checked, in order; probing stops at the first one that
+    /// holds unconditionally
+    pub criteria: Vec<FiniteBasisCriterion>,
+}
+
+impl FiniteBasisReport {
+    /// The criteria whose hypothesis holds.
+    pub fn applicable(&self) -> Vec<&FiniteBasisCriterion> {
+        self.criteria.iter().filter(|c| c.holds).collect()
+    }
+}
+
+impl fmt::Display for FiniteBasisReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}: {}", self.algebra, self.verdict)?;
+        for criterion in &self.criteria {
+            writeln!(f, "  {}", criterion)?;
+        }
+        Ok(())
+    }
+}
+
+const LYNDON: &str = "R. C. Lyndon, Identities in two-valued calculi, Trans. Amer. Math. Soc. 71 (1951)";
+const OATES_POWELL: &str = "S. Oates and M. B. Powell, Identical relations in finite groups, J. Algebra 1 (1964)";
+const KRUSE_LVOV: &str = "R. L. Kruse, Identities satisfied by a finite ring, J. Algebra 26 (1973); I. V. L'vov, Varieties of associative rings I, Algebra i Logika 12 (1973)";
+const MCKENZIE_LATTICES: &str = "R. McKenzie, Equational bases for lattice theories, Math. Scand. 27 (1970)";
+const PERKINS: &str = "P. Perkins, Bases for equational theories of semigroups, J. Algebra 11 (1969)";
+const BAKER: &str = "K. A. Baker, Finite equational bases for finite algebras in a congruence-distributive equational class, Adv. Math. 24 (1977)";
+const WILLARD: &str = "R. Willard, A finite basis theorem for residually finite, congruence meet-semidistributive varieties, J. Symbolic Logic 65 (2000)";
+const MCKENZIE_CM: &str = "R. McKenzie, Finite equational bases for congruence modular varieties, Algebra Universalis 24 (1987)";
+
+/// Probe whether the variety generated by a finite algebra is finitely based.
+///
+/// The criteria are checked in the order listed in the module
+/// documentation and probing stops at the first one that holds without
+/// further hypotheses. The Malcev condition searches build free algebras
+/// and can be expensive for large algebras; they check the progress token
+/// installed on the current thread for cancellation.
+///
+/// # Arguments
+/// * `alg` - The algebra
+///
+/// # Returns
+/// * `Ok(FiniteBasisReport)` - The verdict and every criterion checked
+/// * `Err(String)` - If an operation cannot be evaluated or the
+///   computation was cancelled
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::ring::zn_ring;
+/// use uacalc::alg::finite_basis::{finite_basis_probe, FiniteBasisVerdict};
+///
+/// let report = finite_basis_probe(&zn_ring(6).unwrap()).unwrap();
+/// assert_eq!(report.verdict, FiniteBasisVerdict::FinitelyBased);
+/// assert_eq!(report.applicable()[0].name, "ring");
+/// ```
+pub fn finite_basis_probe(alg: &BasicAlgebra<i32>) -> Result<FiniteBasisReport, String> {
+    let mut report = FiniteBasisReport {
+        algebra: alg.name().to_string(),
+        verdict: FiniteBasisVerdict::Unknown,
+        criteria: Vec::new(),
+    };
+    let n = alg.cardinality();
+    report.criteria.push(FiniteBasisCriterion {
+        name: "at most two elements",
+        holds: n <= 2,
+        unchecked: None,
+        detail: format!("{} elements", n),
+        reference: LYNDON,
+    });
+    if n <= 2 {
+        report.verdict = FiniteBasisVerdict::FinitelyBased;
+        return Ok(report);
+    }
+
+    if let Some(criterion) = classical_criterion(alg)? {
+        report.criteria.push(criterion);
+        report.verdict = FiniteBasisVerdict::FinitelyBased;
+        return Ok(report);
+    }
+
+    crate::progress::check_cancelled()?;
+    let jonsson = malcev::jonsson_terms(alg)?;
+    let cd = jonsson.is_some();
+    report.criteria.push(FiniteBasisCriterion {
+        name: "congruence distributive",
+        holds: cd,
+        unchecked: None,
+        detail: terms_detail("Jónsson", jonsson),
+        reference: BAKER,
+    });
+    if cd {
+        report.verdict = FiniteBasisVerdict::FinitelyBased;
+        return Ok(report);
+    }
+
+    let mut conditions = Vec::new();
+    crate::progress::check_cancelled()?;
+    let sd_meet = malcev::sd_meet_terms(alg)?;
+    if sd_meet.is_some() {
+        conditions.push("residually finite");
+    }
+    report.criteria.push(FiniteBasisCriterion {
+        name: "congruence meet-semidistributive",
+        holds: sd_meet.is_some(),
+        unchecked: Some("residually finite"),
+        detail: terms_detail("SD(∧)", sd_meet),
+        reference: WILLARD,
+    });
+    crate::progress::check_cancelled()?;
+    let gumm = malcev::gumm_terms(alg)?;
+    if gumm.is_some() {
+        conditions.push("residually small");
+    }
+    report.criteria.push(FiniteBasisCriterion {
+        name: "congruence modular",
+        holds: gumm.is_some(),
+        unchecked: Some("residually small"),
+        detail: terms_detail("Gumm", gumm),
+        reference: MCKENZIE_CM,
+    });
+    if !conditions.is_empty() {
+        report.verdict = FiniteBasisVerdict::FinitelyBasedIf(conditions.join(" or "));
+    }
+    Ok(report)
+}
+
+/// Test whether the probes prove the variety generated by an algebra
+/// finitely based.
+///
+/// # Returns
+/// * `Ok(bool)` - Whether the verdict is [`FiniteBasisVerdict::FinitelyBased`];
+///   `false` means unknown, not nonfinitely based
+/// * `Err(String)` - As for `finite_basis_probe`
+pub fn is_known_finitely_based(alg: &BasicAlgebra<i32>) -> Result<bool, String> {
+    Ok(finite_basis_probe(alg)?.verdict == FiniteBasisVerdict::FinitelyBased)
+}
+
+fn terms_detail(kind: &str, terms: Option<Vec<Box<dyn crate::terms::Term>>>) -> String {
+    match terms {
+        Some(terms) => format!("{} {} terms", terms.len(), kind),
+        None => format!("no {} terms", kind),
+    }
+}
+
+/// The first classical theorem whose theory the algebra satisfies using all
+/// of its operations.
+fn classical_criterion(alg: &BasicAlgebra<i32>) -> Result<Option<FiniteBasisCriterion>, String> {
+    let op_count = alg.operations().len();
+    let classification = theories::classify(alg)?;
+    for report in classification.satisfied.iter().filter(|r| r.operations.len() == op_count) {
+        let (name, reference) = match report.theory {
+            Theory::Group | Theory::AbelianGroup => ("group", OATES_POWELL),
+            Theory::Ring => ("ring", KRUSE_LVOV),
+            Theory::Lattice | Theory::DistributiveLattice | Theory::BooleanAlgebra => ("lattice", MCKENZIE_LATTICES),
+            Theory::Semigroup => {
+                let op = alg.operations().into_iter()
+                    .find(|op| op.symbol().name() == report.operations[0])
+                    .ok_or_else(|| format!("{} has no operation {}", alg.name(), report.operations[0]))?;
+                if !op.is_commutative()? {
+                    continue;
+                }
+                ("commutative semigroup", PERKINS)
+            }
+            _ => continue,
+        };
+        return Ok(Some(FiniteBasisCriterion {
+            name,
+            holds: true,
+            unchecked: None,
+            detail: format!("{} with {}", report.theory, report.operations.join(", ")),
+            reference,
+        }));
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::op::OperationSymbol;
+    use crate::alg::op::operations::make_int_operation;
+
+    fn binary(name: &str, n: i32, f: impl Fn(i32, i32) -> i32) -> BasicAlgebra<i32> {
+        let op = make_int_operation(OperationSymbol::new("f", 2, false), n, (0..n * n).map(|k| f(k % n, k / n)).collect()).unwrap();
+        BasicAlgebra::new(name.to_string(), (0..n).collect(), vec![op])
+    }
+
+    #[test]
+    fn test_classical() {
+        let report = finite_basis_probe(&binary("Z3", 3, |x, y| (x + y) % 3)).unwrap();
+        // Addition alone is a commutative semigroup, not a group signature
+        assert_eq!(report.verdict, FiniteBasisVerdict::FinitelyBased);
+        assert_eq!(report.applicable()[0].name, "commutative semigroup");
+
+        let two = binary("2", 2, |x, y| x.min(y));
+        let report = finite_basis_probe(&two).unwrap();
+        assert_eq!(report.criteria.len(), 1);
+        assert_eq!(report.criteria[0].reference, LYNDON);
+    }
+
+    #[test]
+    fn test_malcev_criteria() {
+        // The 3 element chain with the median operation is congruence
+        // distributive, but no classical theory
+        let med = make_int_operation(
+            OperationSymbol::new("m", 3, false),
+            3,
+            (0..27).map(|k| {
+                let mut v = [k % 3, k / 3 % 3, k / 9];
+                v.sort();
+                v[1]
+            }).collect(),
+        ).unwrap();
+        let alg = BasicAlgebra::new("M3".to_string(), (0..3).collect(), vec![med]);
+        let report = finite_basis_probe(&alg).unwrap();
+        assert_eq!(report.verdict, FiniteBasisVerdict::FinitelyBased);
+        assert_eq!(report.applicable()[0].name, "congruence distributive");
+        assert!(is_known_finitely_based(&alg).unwrap());
+
+        // The left zero semigroup on 3 elements is not commutative and
+        // satisfies no Malcev condition probed
+        let report = finite_basis_probe(&binary("L3", 3, |x, _| x)).unwrap();
+        assert_eq!(report.verdict, FiniteBasisVerdict::Unknown);
+        assert!(report.applicable().is_empty());
+        assert!(report.to_string().starts_with("L3: unknown\n"));
+    }
+}
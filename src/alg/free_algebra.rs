@@ -11,18 +11,24 @@ use std::collections::{HashMap};
 use std::fmt::{self, Debug, Display};
 use std::hash::Hash;
 use std::sync::Arc;
-use crate::alg::{Algebra, SmallAlgebra, AlgebraType, BigProductAlgebra, SubProductAlgebra};
+use crate::alg::{Algebra, SmallAlgebra, AlgebraType, BasicAlgebra, BigProductAlgebra, SubProductAlgebra};
+use crate::alg::conlat::CongruenceLattice;
+use crate::alg::conlat::partition::Partition;
 use crate::alg::algebra::ProgressMonitor;
 use crate::alg::algebra_with_generating_vector::AlgebraWithGeneratingVector;
 use crate::alg::closer::Closer;
 use crate::alg::op::{Operation, OperationSymbol, SimilarityType};
 use crate::alg::op::ops::make_int_operation_str;
+use crate::alg::op::operations::make_int_operation;
 use crate::util::int_array::{IntArray, IntArrayTrait};
 use crate::util::sequence_generator::SequenceGenerator;
-use crate::terms::{Term, Variable};
+use crate::terms::{Term, Variable, CompiledTerm};
 use crate::eq::Equation;
 use crate::progress::ProgressReport;
 
+/// The label under which free algebra constructions record their memory use.
+pub const MEMORY_SCOPE: &str = "free algebra";
+
 /// A free algebra over a given base algebra.
 /// 
 /// This struct represents a subalgebra of a direct product of SmallAlgebras
@@ -253,6 +259,11 @@ impl FreeAlgebra
         if number_of_gens < 0 {
             return Err("Number of generators must be non-negative".to_string());
         }
+        let _memory = crate::memory::MemoryScope::new(MEMORY_SCOPE);
+        let _timer = crate::telemetry::phase("free algebra");
+        // Without a reporter, report to the installed progress token rather
+        // than printing.
+        let report = report.or_else(|| crate::progress::current().map(|t| Arc::new(t) as Arc<dyn ProgressReport>));
 
         let line = format!("Constructing free algebra on {} generators over {}", number_of_gens, alg.name());
         if let Some(ref report) = report {
@@ -352,6 +363,136 @@ impl FreeAlgebra
         )
     }
 
+    /// Create the free algebra on `number_of_gens` generators in the variety
+    /// generated by `alg` intersected with the models of `identities`.
+    ///
+    /// This is `new_safe` followed by `with_identities`.
+    ///
+    /// # Arguments
+    /// * `alg` - The base algebra
+    /// * `number_of_gens` - Number of generators
+    /// * `identities` - The extra identities
+    ///
+    /// # Returns
+    /// * `Ok(FreeAlgebra)` - The relatively free algebra
+    /// * `Err(String)` - If construction fails
+    pub fn new_with_identities_safe(
+        alg: Box<dyn SmallAlgebra<UniverseItem = i32>>,
+        number_of_gens: i32,
+        identities: Vec<Equation>,
+    ) -> Result<Self, String> {
+        Self::new_safe(alg, number_of_gens)?.with_identities(identities)
+    }
+
+    /// Quotient this free algebra by the congruence generated by `identities`.
+    ///
+    /// If this is F_V(n), the result is the free algebra on `n` generators of
+    /// V intersected with the models of `identities`: F_V(n) modulo the
+    /// smallest congruence whose quotient satisfies every identity. That
+    /// congruence is found by repeatedly checking the identities in the
+    /// current quotient and adding the lifted failures as generating pairs.
+    ///
+    /// The quotient is itself represented as a subproduct (of a single copy
+    /// of the quotient algebra), generated by the images of the generators.
+    /// Generators identified by the identities are merged, so the result may
+    /// have fewer generators than this algebra.
+    ///
+    /// # Arguments
+    /// * `identities` - The extra identities
+    ///
+    /// # Returns
+    /// * `Ok(FreeAlgebra)` - The relatively free algebra
+    /// * `Err(String)` - If the universe has not been computed, or an identity
+    ///   uses an operation symbol the algebra does not have
+    pub fn with_identities(self, identities: Vec<Equation>) -> Result<Self, String> {
+        let size = self.cardinality();
+        if size == 0 {
+            return Err("The universe of the free algebra has not been computed".to_string());
+        }
+        let n = size as usize;
+
+        // The free algebra on element indices, for evaluating terms and congruences.
+        let mut int_ops = Vec::new();
+        let mut tables = Vec::new();
+        for op in self.get_operations_ref() {
+            let table = crate::terms::compiled_term::operation_table(op)?;
+            int_ops.push(make_int_operation(op.symbol().clone(), size, table.to_vec())?);
+            tables.push((op.symbol().clone(), table));
+        }
+        let f = BasicAlgebra::new(self.name().to_string(), (0..size).collect(), int_ops);
+        let con = CongruenceLattice::new(Box::new(f.clone()) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+
+        let compiled = identities.iter().map(|eq| {
+            let vars = eq.get_variable_list();
+            Ok((
+                CompiledTerm::compile(eq.left_side(), &f, &vars)?,
+                CompiledTerm::compile(eq.right_side(), &f, &vars)?,
+            ))
+        }).collect::<Result<Vec<_>, String>>()?;
+
+        let mut theta = Partition::zero(n);
+        let quotient = loop {
+            let reps = theta.representatives();
+            let block: Vec<usize> = (0..n)
+                .map(|i| reps.binary_search(&theta.representative(i)).unwrap_or(0))
+                .collect();
+            let k = reps.len() as i32;
+            let mut quotient_ops = Vec::with_capacity(tables.len());
+            for (sym, table) in &tables {
+                let arity = sym.arity() as usize;
+                let mut q_table = Vec::with_capacity(crate::terms::compiled_term::table_size(k, arity)?);
+                let mut args = vec![0i32; arity];
+                loop {
+                    let mut index = 0usize;
+                    for &a in args.iter().rev() {
+                        index = index * n + reps[a as usize];
+                    }
+                    q_table.push(block[table[index] as usize] as i32);
+                    if !crate::terms::compiled_term::increment(&mut args, k) {
+                        break;
+                    }
+                }
+                quotient_ops.push(make_int_operation(sym.clone(), k, q_table)?);
+            }
+            let quotient = BasicAlgebra::new(format!("{}/theta", self.name()), (0..k).collect(), quotient_ops);
+
+            let mut new_pairs = Vec::new();
+            for (eq, (left, right)) in identities.iter().zip(&compiled) {
+                if let Some(failure) = eq.find_failure_in(&quotient)? {
+                    let lifted: Vec<i32> = failure.iter().map(|&b| reps[b as usize] as i32).collect();
+                    new_pairs.push((left.eval(&lifted) as usize, right.eval(&lifted) as usize));
+                }
+            }
+            if new_pairs.is_empty() {
+                break quotient;
+            }
+            let mut init = theta.clone();
+            for (a, b) in new_pairs {
+                let (r, s) = (init.representative(a), init.representative(b));
+                if r != s {
+                    init.join_blocks(r, s);
+                }
+            }
+            theta = con.cg_partition(&init);
+        };
+
+        let reps = theta.representatives();
+        let gens = self.inner.gens.iter()
+            .map(|g| {
+                let i = self.element_index(g).ok_or("Generator not in the universe")?;
+                let b = reps.binary_search(&theta.representative(i)).map_err(|_| "Missing block")?;
+                IntArray::from_array(vec![b as i32])
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let name = format!("{} mod {} identities", self.name(), identities.len());
+        let prod = BigProductAlgebra::new_power_safe(
+            Box::new(quotient) as Box<dyn SmallAlgebra<UniverseItem = i32>>,
+            1,
+        )?;
+        let inner = SubProductAlgebra::<i32>::new_safe(name, prod, gens, true)?;
+        Ok(FreeAlgebra { inner })
+    }
+
     /// Create a new FreeAlgebra from pre-computed data.
     /// 
     /// # Arguments
@@ -972,6 +1113,12 @@ impl FreeAlgebra
         &mut self.inner
     }
 
+    /// The exact cardinality of the power `A^(A^n)` this free algebra is a
+    /// subalgebra of, which is usually far too big for an `i32`.
+    pub fn ambient_cardinality(&self) -> crate::alg::Cardinality {
+        self.inner.super_algebra().exact_cardinality()
+    }
+
     /// Get the congruence lattice (lazy initialization).
     /// 
     /// # Returns
@@ -1252,6 +1399,47 @@ mod tests {
         assert!(auto.is_none());
     }
 
+    #[test]
+    fn test_with_identities() {
+        use crate::terms::string_to_term;
+        let eq = |l: &str, r: &str| Equation::new(string_to_term(l).unwrap(), string_to_term(r).unwrap());
+
+        // F(2) over Z4 is Z4^2; modulo 2x = 2y it is the free elementary
+        // abelian 2-group on 2 generators.
+        let add_sym = OperationSymbol::new("add", 2, false);
+        let z4 = BasicAlgebra::new(
+            "Z4".to_string(),
+            HashSet::from([0, 1, 2, 3]),
+            vec![operations::make_int_operation(add_sym, 4, (0..16).map(|k| (k % 4 + k / 4) % 4).collect()).unwrap()],
+        );
+        let f = FreeAlgebra::new_safe(Box::new(z4.clone()), 2).unwrap();
+        assert_eq!(f.cardinality(), 16);
+        let g = f.with_identities(vec![eq("add(x,x)", "add(y,y)")]).unwrap();
+        assert_eq!(g.cardinality(), 4);
+        assert_eq!(g.get_inner().gens.len(), 2);
+
+        // Identities already true in the variety change nothing.
+        let h = FreeAlgebra::new_with_identities_safe(Box::new(z4), 2, vec![eq("add(x,y)", "add(y,x)")]).unwrap();
+        assert_eq!(h.cardinality(), 16);
+
+        // Over Z3, x + x = x collapses everything.
+        let trivial = FreeAlgebra::new_with_identities_safe(create_test_algebra(), 2, vec![eq("add(x,x)", "x")]).unwrap();
+        assert_eq!(trivial.cardinality(), 1);
+    }
+
+    #[test]
+    fn test_cancelled_by_progress_token() {
+        use crate::progress::{ProgressToken, CANCELLED};
+        let token = ProgressToken::new();
+        token.cancel();
+        let result = {
+            let _installed = token.install();
+            FreeAlgebra::new_safe(create_test_algebra(), 2)
+        };
+        assert_eq!(result.err(), Some(CANCELLED.to_string()));
+        assert!(FreeAlgebra::new_safe(create_test_algebra(), 2).is_ok());
+    }
+
     #[test]
     fn test_find_equation_of_a_not_b() {
         let alg_a = create_test_algebra();
@@ -1266,4 +1454,18 @@ mod tests {
         assert!(result.is_ok());
         // The result may be None if no distinguishing equation exists
     }
+
+    #[test]
+    fn test_telemetry() {
+        use crate::telemetry;
+
+        let (free_alg, report) = telemetry::record(|| FreeAlgebra::new_safe(create_test_algebra(), 2));
+        let free_alg = free_alg.unwrap();
+        // Each closure finds the whole universe.
+        let closures = report.phase("free algebra / closure").unwrap().calls;
+        assert_eq!(report.counter(telemetry::ELEMENTS), closures * free_alg.cardinality() as u64);
+        assert!(report.counter(telemetry::PASSES) >= closures);
+        assert!(report.counter(telemetry::APPLICATIONS) > 0);
+        assert_eq!(report.phase("free algebra").unwrap().calls, 1);
+    }
 }
@@ -0,0 +1,574 @@
+/*! Free spectrum and p_n sequence of a finite algebra.
+
+The free algebra F_V(A)(k) on `k` generators is isomorphic to the algebra
+of `k`-ary term operations of `A`, which is computed here as the subalgebra
+of `A^(A^k)` generated by the `k` projections. Element `c` of a term
+operation's table is its value at the tuple with Horner index `c`, so the
+tables are in the same format as operation tables.
+
+These sizes grow doubly exponentially in `k`, so every function takes an
+optional `ProgressMonitor`, reports on it and stops when it is cancelled.
+*/
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use crate::alg::{BasicAlgebra, SmallAlgebra};
+use crate::alg::algebra::ProgressMonitor;
+use crate::alg::op::{OperationSymbol, TermOperationImp};
+use crate::alg::op::operations::{make_int_operation, table_depends_on};
+use crate::alg::search::close_tuples;
+use crate::terms::{NonVariableTerm, Term, VariableImp};
+use crate::terms::compiled_term::{operation_table, table_size};
+use crate::util::horner;
+
+/// The tables of all `arity`-ary term operations of `alg`.
+///
+/// For `arity = 0` these are the constant terms (the subuniverse generated
+/// by the nullary operations), each a table of length one.
+///
+/// # Arguments
+/// * `alg` - The algebra
+/// * `arity` - The arity of the term operations
+/// * `monitor` - Optional monitor for progress and cancellation
+///
+/// # Returns
+/// * `Ok(Some(tables))` - The term operations, projections first
+/// * `Ok(None)` - If the monitor was cancelled
+/// * `Err(String)` - If `alg^(alg^arity)` is too large to index
+pub fn term_operation_tables(
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    arity: usize,
+    monitor: Option<&dyn ProgressMonitor>,
+) -> Result<Option<Vec<Vec<i32>>>, String> {
+    // Without an explicit monitor, report to the thread's progress token.
+    let ambient = crate::progress::current();
+    let monitor = monitor.or(ambient.as_ref().map(|t| t as &dyn ProgressMonitor));
+    let n = alg.cardinality();
+    let len = table_size(n, arity)?;
+    let projections: Vec<Vec<i32>> = (0..arity)
+        .map(|i| (0..len).map(|c| horner::horner_inv_same_size(c as i32, n, arity)[i]).collect())
+        .collect();
+    close_tuples(alg, &projections, len, |pass, size| {
+        match monitor {
+            Some(m) => {
+                m.report_progress(&format!("arity {}: pass {}, size {}", arity, pass, size));
+                !m.is_cancelled()
+            }
+            None => true,
+        }
+    })
+}
+
+/// The free spectrum of `alg`: the sizes of F_V(A)(k) for `k = 0..=up_to_n`.
+///
+/// `F(0)` is the subuniverse generated by the nullary operations, which is
+/// empty if there are none.
+///
+/// # Arguments
+/// * `alg` - The algebra
+/// * `up_to_n` - The largest number of generators
+/// * `monitor` - Optional monitor for progress and cancellation
+///
+/// # Returns
+/// * `Ok(sizes)` - `sizes[k] = |F(k)|`; if the monitor is cancelled, only
+///   the sizes completed so far
+/// * `Err(String)` - If a free algebra is too large to index
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use uacalc::alg::BasicAlgebra;
+/// use uacalc::alg::op::{OperationSymbol, operations};
+/// use uacalc::alg::free_spectrum::free_spectrum;
+///
+/// // The two element meet semilattice: |F(k)| = 2^k - 1.
+/// let meet = OperationSymbol::new("meet", 2, false);
+/// let op = operations::make_int_operation(meet, 2, vec![0, 0, 0, 1]).unwrap();
+/// let alg = BasicAlgebra::new("S".to_string(), (0..2).collect::<HashSet<i32>>(), vec![op]);
+/// assert_eq!(free_spectrum(&alg, 3, None).unwrap(), vec![0, 1, 3, 7]);
+/// ```
+pub fn free_spectrum(
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    up_to_n: usize,
+    monitor: Option<&dyn ProgressMonitor>,
+) -> Result<Vec<usize>, String> {
+    let mut sizes = Vec::with_capacity(up_to_n + 1);
+    for k in 0..=up_to_n {
+        match term_operation_tables(alg, k, monitor)? {
+            Some(ops) => sizes.push(ops.len()),
+            None => break,
+        }
+        if let Some(m) = monitor {
+            m.report_progress(&format!("|F({})| = {}", k, sizes[k]));
+            m.set_progress((k + 1) as f64 / (up_to_n + 1) as f64);
+        }
+    }
+    Ok(sizes)
+}
+
+/// The p_n sequence of `alg` for `n = 0..=up_to_n`: the number of `n`-ary
+/// term operations depending on all of their variables.
+///
+/// `p_0` is the number of constant unary term operations, so that
+/// `|F(k)| = sum_j C(k, j) p_j` for `k >= 1`.
+///
+/// # Arguments
+/// * `alg` - The algebra
+/// * `up_to_n` - The largest arity
+/// * `monitor` - Optional monitor for progress and cancellation
+///
+/// # Returns
+/// * `Ok(counts)` - `counts[n] = p_n`; if the monitor is cancelled, only
+///   the counts completed so far
+/// * `Err(String)` - If a clone is too large to index
+pub fn pn_sequence(
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    up_to_n: usize,
+    monitor: Option<&dyn ProgressMonitor>,
+) -> Result<Vec<usize>, String> {
+    let n = alg.cardinality() as usize;
+    let mut counts = Vec::with_capacity(up_to_n + 1);
+    for k in 0..=up_to_n {
+        let count = if k == 0 {
+            term_operation_tables(alg, 1, monitor)?
+                .map(|ops| ops.iter().filter(|t| !table_depends_on(t, n, 0)).count())
+        } else {
+            term_operation_tables(alg, k, monitor)?
+                .map(|ops| ops.iter().filter(|t| (0..k).all(|i| table_depends_on(t, n, i))).count())
+        };
+        match count {
+            Some(c) => counts.push(c),
+            None => break,
+        }
+        if let Some(m) = monitor {
+            m.report_progress(&format!("p_{} = {}", k, counts[k]));
+            m.set_progress((k + 1) as f64 / (up_to_n + 1) as f64);
+        }
+    }
+    Ok(counts)
+}
+
+/// The idempotent reduct of `alg` up to arity `max_arity`: the algebra on
+/// the same universe whose operations are the idempotent term operations of
+/// `alg` of arity `2..=max_arity` depending on all of their variables.
+///
+/// Every idempotent term operation of arity at most `max_arity` is a term
+/// operation of the reduct, so for `max_arity` large enough this is the
+/// full idempotent reduct, on which the idempotent Taylor, Maltsev and
+/// congruence distributivity tests apply. The operation of arity `k` found
+/// `i`-th is named `t<k>_<i>`.
+///
+/// # Arguments
+/// * `alg` - The algebra
+/// * `max_arity` - The largest arity of the operations
+/// * `monitor` - Optional monitor for progress and cancellation
+///
+/// # Returns
+/// * `Ok(BasicAlgebra)` - The reduct, named `Id(<name>)`
+/// * `Err(String)` - If a clone is too large to index or the monitor was
+///   cancelled
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use uacalc::alg::{Algebra, BasicAlgebra};
+/// use uacalc::alg::op::{OperationSymbol, operations};
+/// use uacalc::alg::free_spectrum::idempotent_reduct;
+///
+/// // Z3 under x - y: the idempotent binary term operation is 2x + 2y.
+/// let minus = OperationSymbol::new("minus", 2, false);
+/// let table = (0..9).map(|k| (k % 3 + 2 * (k / 3)) % 3).collect();
+/// let op = operations::make_int_operation(minus, 3, table).unwrap();
+/// let z3 = BasicAlgebra::new("Z3".to_string(), (0..3).collect::<HashSet<i32>>(), vec![op]);
+/// let reduct = idempotent_reduct(&z3, 2, None).unwrap();
+/// assert_eq!(reduct.operations().len(), 1);
+/// ```
+pub fn idempotent_reduct(
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    max_arity: usize,
+    monitor: Option<&dyn ProgressMonitor>,
+) -> Result<BasicAlgebra<i32>, String> {
+    let n = alg.cardinality() as usize;
+    let mut ops = Vec::new();
+    for k in 2..=max_arity {
+        let tables = term_operation_tables(alg, k, monitor)?
+            .ok_or_else(|| crate::progress::CANCELLED.to_string())?;
+        // The Horner index of the constant tuple (a, ..., a)
+        let diagonal: usize = (0..k).map(|i| n.pow(i as u32)).sum();
+        let idempotent = tables.into_iter().filter(|t| {
+            (0..n).all(|a| t[a * diagonal] == a as i32) && (0..k).all(|i| table_depends_on(t, n, i))
+        });
+        for (i, table) in idempotent.enumerate() {
+            let symbol = OperationSymbol::new(&format!("t{}_{}", k, i), k as i32, false);
+            ops.push(make_int_operation(symbol, n as i32, table)?);
+        }
+        if let Some(m) = monitor {
+            m.report_progress(&format!("arity {}: {} idempotent operations", k, ops.len()));
+            m.set_progress(k as f64 / max_arity as f64);
+        }
+    }
+    Ok(BasicAlgebra::new(format!("Id({})", alg.name()), (0..n as i32).collect(), ops))
+}
+
+/// Lazily enumerate the term operations of `alg` of arity at most `k`.
+///
+/// The operations of each arity `0, 1, ..., k` are produced in the order the
+/// closure of the projections finds them, each as a table based operation
+/// together with a term producing it in the variables `x0, ..., x{arity-1}`.
+/// Only as much of each clone is computed as has been consumed.
+///
+/// # Arguments
+/// * `alg` - The algebra
+/// * `k` - The largest arity
+/// * `max_count` - Optional limit on the total number of operations produced
+///
+/// # Returns
+/// * `Ok(TermOperations)` - The iterator
+/// * `Err(String)` - If the tables of arity `k` are too large to index
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use std::sync::Arc;
+/// use uacalc::alg::{BasicAlgebra, SmallAlgebra};
+/// use uacalc::alg::op::{Operation, OperationSymbol, operations};
+/// use uacalc::alg::free_spectrum::term_operations_up_to_arity;
+///
+/// let meet = OperationSymbol::new("meet", 2, false);
+/// let op = operations::make_int_operation(meet, 2, vec![0, 0, 0, 1]).unwrap();
+/// let alg = BasicAlgebra::new("S".to_string(), (0..2).collect::<HashSet<i32>>(), vec![op]);
+/// let alg: Arc<dyn SmallAlgebra<UniverseItem = i32>> = Arc::new(alg);
+/// let binary: Vec<_> = term_operations_up_to_arity(alg, 2, None).unwrap()
+///     .filter(|op| op.arity() == 2)
+///     .collect();
+/// assert_eq!(binary.len(), 3);
+/// ```
+pub fn term_operations_up_to_arity(
+    alg: Arc<dyn SmallAlgebra<UniverseItem = i32>>,
+    k: usize,
+    max_count: Option<usize>,
+) -> Result<TermOperations, String> {
+    table_size(alg.cardinality(), k)?;
+    let closure = TermClosure::new(alg.as_ref(), 0)?;
+    Ok(TermOperations { alg, closure, max_arity: k, remaining: max_count, emitted: 0 })
+}
+
+/// Iterator returned by [`term_operations_up_to_arity`].
+pub struct TermOperations {
+    alg: Arc<dyn SmallAlgebra<UniverseItem = i32>>,
+    closure: TermClosure,
+    max_arity: usize,
+    remaining: Option<usize>,
+    /// Number of elements of the current arity already yielded.
+    emitted: usize,
+}
+
+impl Iterator for TermOperations {
+    type Item = TermOperationImp;
+
+    fn next(&mut self) -> Option<TermOperationImp> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        while self.emitted == self.closure.elems.len() && !self.closure.advance() {
+            if self.closure.arity == self.max_arity {
+                return None;
+            }
+            let arity = self.closure.arity + 1;
+            self.closure.start_arity(arity);
+            self.emitted = 0;
+        }
+        let i = self.emitted;
+        self.emitted += 1;
+        if let Some(r) = self.remaining.as_mut() {
+            *r -= 1;
+        }
+        let term = self.closure.terms[i].clone_box();
+        let name = format!("\"{}\"", term);
+        let symbol = OperationSymbol::new(&name, self.closure.arity as i32, false);
+        let interpretation = make_int_operation(symbol, self.alg.cardinality(), self.closure.elems[i].clone())
+            .expect("term operation tables have the right size");
+        Some(TermOperationImp::new_with_name(
+            name,
+            term,
+            self.closure.variables.clone(),
+            self.alg.clone(),
+            interpretation,
+        ))
+    }
+}
+
+/// The closure of the projections of one arity, each with a producing term.
+///
+/// The closure is computed semi-naively, one operation application at a
+/// time, so that callers can stop as soon as they have found what they need.
+pub(crate) struct TermClosure {
+    n: i32,
+    ops: Vec<(OperationSymbol, Arc<[i32]>)>,
+    pub(crate) arity: usize,
+    pub(crate) variables: Vec<String>,
+    pub(crate) elems: Vec<Vec<i32>>,
+    pub(crate) terms: Vec<Box<dyn Term>>,
+    seen: HashSet<Vec<i32>>,
+    /// Elements before `old` were found before the previous pass.
+    old: usize,
+    /// Number of elements at the start of the current pass.
+    current: usize,
+    op_index: usize,
+    args: Option<Vec<usize>>,
+}
+
+impl TermClosure {
+    /// Start the closure of the `arity`-ary projections of `alg`.
+    pub(crate) fn new(alg: &dyn SmallAlgebra<UniverseItem = i32>, arity: usize) -> Result<Self, String> {
+        table_size(alg.cardinality(), arity)?;
+        let ops = alg.get_operations_ref().iter()
+            .map(|op| Ok((op.symbol().clone(), operation_table(*op)?)))
+            .collect::<Result<Vec<_>, String>>()?;
+        let mut closure = TermClosure {
+            n: alg.cardinality(),
+            ops,
+            arity,
+            variables: Vec::new(),
+            elems: Vec::new(),
+            terms: Vec::new(),
+            seen: HashSet::new(),
+            old: 0,
+            current: 0,
+            op_index: 0,
+            args: None,
+        };
+        closure.start_arity(arity);
+        Ok(closure)
+    }
+
+    /// Restart with the projections of another arity; the caller checks
+    /// that its tables can be indexed.
+    pub(crate) fn start_arity(&mut self, arity: usize) {
+        let n = self.n;
+        let len = n.pow(arity as u32) as usize;
+        self.arity = arity;
+        self.variables = (0..arity).map(|i| format!("x{}", i)).collect();
+        self.elems.clear();
+        self.terms.clear();
+        self.seen.clear();
+        for i in 0..arity {
+            let table: Vec<i32> = (0..len)
+                .map(|c| horner::horner_inv_same_size(c as i32, n, arity)[i])
+                .collect();
+            self.add(table, Box::new(VariableImp::new(&self.variables[i])));
+        }
+        // Constants generate the clone of arity 0.
+        for j in 0..self.ops.len() {
+            let (sym, table) = &self.ops[j];
+            if sym.arity() == 0 {
+                let term = Box::new(NonVariableTerm::new(sym.clone(), Vec::new()));
+                let value = vec![table[0]; len];
+                self.add(value, term);
+            }
+        }
+        self.old = 0;
+        self.current = self.elems.len();
+        self.op_index = 0;
+        self.args = None;
+    }
+
+    fn add(&mut self, table: Vec<i32>, term: Box<dyn Term>) -> bool {
+        if !self.seen.insert(table.clone()) {
+            return false;
+        }
+        self.elems.push(table);
+        self.terms.push(term);
+        true
+    }
+
+    /// Apply operations until a new element is found; false if the closure
+    /// is complete.
+    pub(crate) fn advance(&mut self) -> bool {
+        let n = self.n as usize;
+        loop {
+            if self.op_index == self.ops.len() {
+                self.old = self.current;
+                self.current = self.elems.len();
+                if self.old == self.current {
+                    return false;
+                }
+                self.op_index = 0;
+                self.args = None;
+            }
+            let r = self.ops[self.op_index].0.arity() as usize;
+            if r == 0 || self.current == 0 {
+                self.op_index += 1;
+                continue;
+            }
+            match self.args.as_mut() {
+                None => self.args = Some(vec![0; r]),
+                Some(args) => {
+                    if !increment_last_first(args, self.current) {
+                        self.op_index += 1;
+                        self.args = None;
+                        continue;
+                    }
+                }
+            }
+            let args = self.args.as_ref().unwrap();
+            if args.iter().all(|&a| a < self.old) {
+                continue;
+            }
+            let table = &self.ops[self.op_index].1;
+            let len = self.elems[0].len();
+            let value: Vec<i32> = (0..len).map(|j| {
+                let mut index = 0usize;
+                for &a in args.iter().rev() {
+                    index = index * n + self.elems[a][j] as usize;
+                }
+                table[index]
+            }).collect();
+            if self.seen.contains(&value) {
+                continue;
+            }
+            let children = args.iter().map(|&a| self.terms[a].clone_box()).collect();
+            let term = Box::new(NonVariableTerm::new(self.ops[self.op_index].0.clone(), children));
+            self.add(value, term);
+            return true;
+        }
+    }
+
+    /// A term producing `table`, extending the closure only as far as needed.
+    ///
+    /// Stops with `Err(CANCELLED)` if the thread's progress token is cancelled.
+    pub(crate) fn find(&mut self, table: &[i32]) -> Result<Option<Box<dyn Term>>, String> {
+        if let Some(i) = self.elems.iter().position(|e| e.as_slice() == table) {
+            return Ok(Some(self.terms[i].clone_box()));
+        }
+        while self.advance() {
+            if self.elems.last().map(|e| e.as_slice()) == Some(table) {
+                return Ok(self.terms.last().map(|t| t.clone_box()));
+            }
+            if self.elems.len().is_multiple_of(256) {
+                crate::progress::check_cancelled()?;
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Step through argument lists over `0..bound` with the last entry varying
+/// fastest, so that terms list their earlier variables first.
+fn increment_last_first(args: &mut [usize], bound: usize) -> bool {
+    for a in args.iter_mut().rev() {
+        *a += 1;
+        if *a < bound {
+            return true;
+        }
+        *a = 0;
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use crate::alg::Algebra;
+    use crate::alg::op::{OperationSymbol, operations};
+
+    fn binary_algebra(size: i32, f: impl Fn(i32, i32) -> i32) -> BasicAlgebra<i32> {
+        let sym = OperationSymbol::new("f", 2, false);
+        let table = (0..size * size).map(|k| f(k % size, k / size)).collect();
+        let op = operations::make_int_operation(sym, size, table).unwrap();
+        BasicAlgebra::new("A".to_string(), (0..size).collect(), vec![op])
+    }
+
+    #[derive(Debug, Default)]
+    struct CancelAfter {
+        reports: AtomicUsize,
+        limit: usize,
+        cancelled: AtomicBool,
+    }
+
+    impl ProgressMonitor for CancelAfter {
+        fn report_progress(&self, _message: &str) {
+            if self.reports.fetch_add(1, Ordering::SeqCst) + 1 >= self.limit {
+                self.cancelled.store(true, Ordering::SeqCst);
+            }
+        }
+
+        fn is_cancelled(&self) -> bool {
+            self.cancelled.load(Ordering::SeqCst)
+        }
+
+        fn set_progress(&self, _progress: f64) {}
+    }
+
+    #[test]
+    fn test_free_spectrum_and_pn() {
+        // Z3 under x - y: the term operations of arity k are the linear maps
+        // a_1 x_1 + ... + a_k x_k, and p_k counts those with no a_i zero.
+        let z3 = binary_algebra(3, |x, y| (x + 2 * y) % 3);
+        let spectrum = free_spectrum(&z3, 3, None).unwrap();
+        assert_eq!(spectrum, vec![0, 3, 9, 27]);
+        let pn = pn_sequence(&z3, 3, None).unwrap();
+        for (k, size) in spectrum.iter().enumerate().skip(1) {
+            let sum: usize = (0..=k).map(|j| binomial(k, j) * pn[j]).sum();
+            assert_eq!(sum, *size);
+        }
+        assert_eq!(pn, vec![1, 2, 4, 8]);
+    }
+
+    #[test]
+    fn test_constants_and_cancellation() {
+        // The constant operation 0 is a unary term operation but not a nullary one.
+        let zero = binary_algebra(2, |_, _| 0);
+        assert_eq!(free_spectrum(&zero, 2, None).unwrap(), vec![0, 2, 3]);
+        assert_eq!(pn_sequence(&zero, 2, None).unwrap(), vec![1, 1, 0]);
+
+        let monitor = CancelAfter { limit: 3, ..Default::default() };
+        let sizes = free_spectrum(&zero, 5, Some(&monitor)).unwrap();
+        assert!(sizes.len() < 6);
+        assert!(monitor.is_cancelled());
+    }
+
+    #[test]
+    fn test_idempotent_reduct() {
+        // The idempotent term operations of Z3 under x - y are the sums
+        // a_1 x_1 + ... + a_k x_k with a_1 + ... + a_k = 1.
+        let z3 = binary_algebra(3, |x, y| (x + 2 * y) % 3);
+        let reduct = idempotent_reduct(&z3, 3, None).unwrap();
+        assert_eq!(reduct.name(), "Id(A)");
+        let ops = reduct.get_operations_ref();
+        assert_eq!(ops.iter().filter(|op| op.arity() == 2).count(), 1);
+        assert_eq!(ops.iter().filter(|op| op.arity() == 3).count(), 3);
+        let t = ops.iter().find(|op| op.symbol().name() == "t2_0").unwrap();
+        assert_eq!(t.int_value_at(&[1, 2]).unwrap(), 0);
+
+        // A constant algebra has no idempotent operations
+        let zero = binary_algebra(2, |_, _| 0);
+        assert!(idempotent_reduct(&zero, 3, None).unwrap().get_operations_ref().is_empty());
+    }
+
+    #[test]
+    fn test_term_operations_up_to_arity() {
+        use crate::alg::op::{Operation, TermOperation};
+        let z3: Arc<dyn SmallAlgebra<UniverseItem = i32>> = Arc::new(binary_algebra(3, |x, y| (x + 2 * y) % 3));
+        let ops: Vec<TermOperationImp> = term_operations_up_to_arity(z3.clone(), 2, None).unwrap().collect();
+        let counts: Vec<usize> = (0..=2).map(|a| ops.iter().filter(|op| op.arity() == a).count()).collect();
+        assert_eq!(counts, free_spectrum(z3.as_ref(), 2, None).unwrap());
+        for op in &ops {
+            let table = op.get_table().unwrap();
+            let vars = op.get_ordered_variables();
+            let compiled = crate::terms::CompiledTerm::compile(op.get_term(), z3.as_ref(), &vars).unwrap();
+            for (c, &v) in table.iter().enumerate() {
+                let args = horner::horner_inv_same_size(c as i32, 3, op.arity() as usize);
+                assert_eq!(compiled.eval(&args), v);
+            }
+        }
+
+        let first: Vec<_> = term_operations_up_to_arity(z3, 2, Some(4)).unwrap().collect();
+        assert_eq!(first.len(), 4);
+    }
+
+    fn binomial(n: usize, k: usize) -> usize {
+        (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+    }
+}
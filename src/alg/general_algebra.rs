@@ -161,8 +161,7 @@ where
     
     /// Rebuild the operations map for fast lookup.
     fn rebuild_operations_map(&mut self) {
-        // For now, we'll skip building the map since we can't clone operations easily
-        // This could be optimized later with Arc<dyn Operation> or other approaches
+        // Lookups scan the shared operations directly; see `get_operation`.
         self.operations_map = None;
     }
     
@@ -342,9 +341,12 @@ where
     }
     
     fn constant_operations(&self) -> Vec<Box<dyn Operation>> {
-        // Since we can't easily clone operations, return empty vec for now
-        // In practice, we'd need to use Arc<dyn Operation> or similar
-        Vec::new()
+        // Shared with the algebra, like the other operations.
+        self.operations
+            .iter()
+            .filter(|op| op.arity() == 0)
+            .map(|op| boxed_arc_op(Arc::clone(op)))
+            .collect()
     }
     
     fn is_idempotent(&self) -> bool {
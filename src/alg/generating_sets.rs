@@ -0,0 +1,326 @@
+/*! Minimal generating sets, independent sets and the generating rank of an
+algebra.
+
+A set is independent if no element lies in the subuniverse generated by the
+others, and a generating set is minimal, no proper subset generating, exactly
+when it is independent. Subsets are
+searched in increasing order, extending a set `G` only by elements outside
+`Sg(G)` and abandoning it as soon as one of its elements becomes
+redundant, since every superset then has the same redundancy. The
+one-generated subuniverses `Sg(a)` are computed once and rule out most
+redundant extensions before any closure is taken: `c` cannot join `G` if
+`Sg(c)` contains an element of `G`.
+*/
+
+use crate::alg::SmallAlgebra;
+use crate::alg::op::Operation;
+use crate::alg::sublat::BasicSet;
+use crate::alg::subuniverse_graph::close;
+
+struct Search<'a> {
+    ops: Vec<&'a dyn Operation>,
+    n: i32,
+    bottom: BasicSet,
+    one_generated: Vec<BasicSet>,
+    max_size: usize,
+    first_only: bool,
+    all_independent: bool,
+    found: Vec<BasicSet>,
+}
+
+impl Search<'_> {
+    fn sg(&self, gens: &[i32]) -> Result<BasicSet, String> {
+        close(&self.ops, self.n, &self.bottom, gens)
+    }
+
+    /// Whether some element of `gens` lies in the subuniverse generated by the others.
+    fn redundant(&self, gens: &[i32]) -> Result<bool, String> {
+        for i in 0..gens.len() {
+            let others: Vec<i32> = gens.iter().enumerate().filter(|&(j, _)| j != i).map(|(_, &g)| g).collect();
+            if self.sg(&others)?.contains(gens[i]) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn extend(&mut self, gens: &mut Vec<i32>, sub: &BasicSet) -> Result<(), String> {
+        if self.first_only && !self.found.is_empty() {
+            return Ok(());
+        }
+        crate::progress::check_cancelled()?;
+        let start = gens.last().map_or(0, |&g| g + 1);
+        for c in start..self.n {
+            if sub.contains(c) || gens.iter().any(|&g| self.one_generated[c as usize].contains(g)) {
+                continue;
+            }
+            gens.push(c);
+            if !self.redundant(gens)? {
+                let next = self.sg(gens)?;
+                let generates = next.size() == self.n as usize;
+                if generates || self.all_independent {
+                    self.found.push(BasicSet::new(gens.clone())?);
+                }
+                if !generates && gens.len() < self.max_size {
+                    self.extend(gens, &next)?;
+                }
+            }
+            gens.pop();
+            if self.first_only && !self.found.is_empty() {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn search<'a>(
+    alg: &'a dyn SmallAlgebra<UniverseItem = i32>,
+    max_size: usize,
+    first_only: bool,
+    all_independent: bool,
+) -> Result<Search<'a>, String> {
+    let n = alg.cardinality();
+    if n <= 0 {
+        return Err("Algebra must have positive cardinality".to_string());
+    }
+    let ops = alg.get_operations_ref();
+    let mut constants = Vec::new();
+    for op in ops.iter().filter(|op| op.arity() == 0) {
+        constants.push(op.int_value_at(&[])?);
+    }
+    let bottom = close(&ops, n, &BasicSet::new(Vec::new())?, &constants)?;
+    let mut search = Search { ops, n, bottom, one_generated: Vec::new(), max_size, first_only, all_independent, found: Vec::new() };
+    if all_independent || search.bottom.size() == n as usize {
+        search.found.push(BasicSet::new(Vec::new())?);
+    }
+    if search.bottom.size() == n as usize {
+        return Ok(search);
+    }
+    search.one_generated = (0..n).map(|a| search.sg(&[a])).collect::<Result<_, _>>()?;
+    if max_size > 0 {
+        let bottom = search.bottom.clone();
+        search.extend(&mut Vec::new(), &bottom)?;
+    }
+    Ok(search)
+}
+
+/// Compute the minimal generating sets of an algebra with at most
+/// `max_size` elements.
+///
+/// The constants of the algebra are not part of any generating set, so a
+/// generated algebra whose constants already generate it has the empty set
+/// as its only minimal generating set. The computation checks the progress
+/// token installed on the current thread for cancellation.
+///
+/// # Arguments
+/// * `alg` - The algebra
+/// * `max_size` - The largest number of generators
+///
+/// # Returns
+/// * `Ok(Vec<BasicSet>)` - The minimal generating sets, ordered by size and
+///   then lexicographically
+/// * `Err(String)` - If the algebra is empty, an operation cannot be
+///   evaluated or the computation was cancelled
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use uacalc::alg::BasicAlgebra;
+/// use uacalc::alg::op::{OperationSymbol, operations};
+/// use uacalc::alg::generating_sets::{generating_rank, minimal_generating_sets};
+///
+/// // Z6 under addition is generated by 1 or 5, or by an element of order 2
+/// // together with one of order 3
+/// let add = OperationSymbol::new("add", 2, false);
+/// let op = operations::make_int_operation(add, 6, (0..36).map(|k| (k % 6 + k / 6) % 6).collect()).unwrap();
+/// let alg = BasicAlgebra::new("Z6".to_string(), (0..6).collect::<HashSet<i32>>(), vec![op]);
+///
+/// let sets: Vec<Vec<i32>> = minimal_generating_sets(&alg, 2).unwrap().iter().map(|s| s.elements().clone()).collect();
+/// assert_eq!(sets, vec![vec![1], vec![5], vec![2, 3], vec![3, 4]]);
+/// assert_eq!(generating_rank(&alg).unwrap(), 1);
+/// ```
+pub fn minimal_generating_sets(
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    max_size: usize,
+) -> Result<Vec<BasicSet>, String> {
+    let mut sets = search(alg, max_size, false, false)?.found;
+    sets.sort_by(|s, t| s.size().cmp(&t.size()).then_with(|| s.elements().cmp(t.elements())));
+    Ok(sets)
+}
+
+/// Compute a generating set of an algebra of the least size.
+///
+/// # Returns
+/// * `Ok(BasicSet)` - The lexicographically first generating set of least size
+/// * `Err(String)` - As for `minimal_generating_sets`
+pub fn minimum_generating_set(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<BasicSet, String> {
+    let n = alg.cardinality().max(0) as usize;
+    for k in 0..=n {
+        if let Some(set) = search(alg, k, true, false)?.found.pop() {
+            return Ok(set);
+        }
+    }
+    Err("No generating set found".to_string())
+}
+
+/// Compute the generating rank of an algebra: the least size of a
+/// generating set, not counting constants.
+///
+/// # Returns
+/// * `Ok(usize)` - The generating rank
+/// * `Err(String)` - As for `minimal_generating_sets`
+pub fn generating_rank(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<usize, String> {
+    Ok(minimum_generating_set(alg)?.size())
+}
+
+/// Compute the independent subsets of an algebra with at most `max_size`
+/// elements: those none of whose elements lies in the subuniverse
+/// generated by the others.
+///
+/// # Arguments
+/// * `alg` - The algebra
+/// * `max_size` - The largest size of the sets
+///
+/// # Returns
+/// * `Ok(Vec<BasicSet>)` - The independent sets, starting with the empty
+///   set, ordered by size and then lexicographically
+/// * `Err(String)` - As for `minimal_generating_sets`
+pub fn independent_sets(
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    max_size: usize,
+) -> Result<Vec<BasicSet>, String> {
+    let mut sets = search(alg, max_size, false, true)?.found;
+    sets.sort_by(|s, t| s.size().cmp(&t.size()).then_with(|| s.elements().cmp(t.elements())));
+    Ok(sets)
+}
+
+/// Test whether no element of `gens` lies in the subuniverse generated by
+/// the others.
+///
+/// # Arguments
+/// * `alg` - The algebra
+/// * `gens` - The elements, which must be distinct
+///
+/// # Returns
+/// * `Ok(bool)` - Whether the elements are independent
+/// * `Err(String)` - If an element is repeated or out of range, or an
+///   operation cannot be evaluated
+pub fn is_independent(alg: &dyn SmallAlgebra<UniverseItem = i32>, gens: &[i32]) -> Result<bool, String> {
+    Ok(!checked_search(alg, gens)?.redundant(gens)?)
+}
+
+/// A search over `alg` for testing `gens`, after checking that the elements
+/// are distinct and in range.
+fn checked_search<'a>(alg: &'a dyn SmallAlgebra<UniverseItem = i32>, gens: &[i32]) -> Result<Search<'a>, String> {
+    let s = search(alg, 0, false, false)?;
+    let mut seen = vec![false; s.n as usize];
+    for &g in gens {
+        if g < 0 || g >= s.n {
+            return Err(format!("Element {} is out of range 0..{}", g, s.n));
+        }
+        if std::mem::replace(&mut seen[g as usize], true) {
+            return Err(format!("Element {} is repeated", g));
+        }
+    }
+    Ok(s)
+}
+
+/// Test whether `gens` is an independent generating set: it generates the
+/// algebra and none of its elements is generated by the others, which is
+/// to say it is a minimal generating set.
+///
+/// # Arguments
+/// * `alg` - The algebra
+/// * `gens` - The generators, which must be distinct
+///
+/// # Returns
+/// * `Ok(bool)` - Whether `gens` is an independent generating set
+/// * `Err(String)` - As for `is_independent`
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use uacalc::alg::BasicAlgebra;
+/// use uacalc::alg::op::{OperationSymbol, operations};
+/// use uacalc::alg::generating_sets::is_independent_generating_set;
+///
+/// let add = OperationSymbol::new("add", 2, false);
+/// let op = operations::make_int_operation(add, 6, (0..36).map(|k| (k % 6 + k / 6) % 6).collect()).unwrap();
+/// let alg = BasicAlgebra::new("Z6".to_string(), (0..6).collect::<HashSet<i32>>(), vec![op]);
+///
+/// assert!(is_independent_generating_set(&alg, &[2, 3]).unwrap());
+/// assert!(!is_independent_generating_set(&alg, &[1, 3]).unwrap());
+/// assert!(!is_independent_generating_set(&alg, &[2, 4]).unwrap());
+/// ```
+pub fn is_independent_generating_set(alg: &dyn SmallAlgebra<UniverseItem = i32>, gens: &[i32]) -> Result<bool, String> {
+    let s = checked_search(alg, gens)?;
+    Ok(s.sg(gens)?.size() == s.n as usize && !s.redundant(gens)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::{Algebra, BasicAlgebra};
+    use crate::alg::op::OperationSymbol;
+    use crate::alg::op::operations::make_int_operation;
+
+    fn brute_force(alg: &BasicAlgebra<i32>, max_size: usize) -> Vec<Vec<i32>> {
+        let n = alg.cardinality();
+        let s = search(alg, 0, false, false).unwrap();
+        let mut out: Vec<Vec<i32>> = (0..1u32 << n)
+            .map(|mask| (0..n).filter(|&i| mask & (1 << i) != 0).collect::<Vec<i32>>())
+            .filter(|g| g.len() <= max_size && s.sg(g).unwrap().size() == n as usize && !s.redundant(g).unwrap())
+            .collect();
+        out.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+        out
+    }
+
+    #[test]
+    fn test_against_brute_force() {
+        // The chain 0 < 1 < 2 < 3 as a join semilattice, and an algebra with
+        // a non-commutative binary operation.
+        let join = make_int_operation(OperationSymbol::new("join", 2, false), 4, (0..16).map(|k| (k % 4).max(k / 4)).collect()).unwrap();
+        let chain = BasicAlgebra::new("C4".to_string(), (0..4).collect(), vec![join]);
+        let m = make_int_operation(OperationSymbol::new("m", 2, false), 4, (0..16).map(|k| if k % 4 == 1 && k / 4 == 0 { 3 } else { k % 4 }).collect()).unwrap();
+        let f = make_int_operation(OperationSymbol::new("f", 1, false), 4, vec![1, 0, 2, 2]).unwrap();
+        let other = BasicAlgebra::new("B".to_string(), (0..4).collect(), vec![m, f]);
+        for alg in [&chain, &other] {
+            for max_size in 0..=4 {
+                let sets: Vec<Vec<i32>> = minimal_generating_sets(alg, max_size).unwrap().iter().map(|s| s.elements().clone()).collect();
+                assert_eq!(sets, brute_force(alg, max_size), "{} {}", alg.name(), max_size);
+            }
+        }
+        assert_eq!(generating_rank(&chain).unwrap(), 4);
+        assert_eq!(minimum_generating_set(&other).unwrap().elements(), &brute_force(&other, 4)[0]);
+        assert_eq!(generating_rank(&other).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_independent_sets() {
+        let join = make_int_operation(OperationSymbol::new("join", 2, false), 3, (0..9).map(|k| (k % 3).max(k / 3)).collect()).unwrap();
+        let chain = BasicAlgebra::new("C3".to_string(), (0..3).collect(), vec![join]);
+        // Every subset of a chain is closed under join, so all are independent
+        assert_eq!(independent_sets(&chain, 3).unwrap().len(), 8);
+        assert_eq!(independent_sets(&chain, 1).unwrap().len(), 4);
+        assert!(is_independent(&chain, &[0, 2]).unwrap());
+        assert!(!is_independent_generating_set(&chain, &[0, 2]).unwrap());
+        assert!(is_independent_generating_set(&chain, &[0, 1, 2]).unwrap());
+        assert!(is_independent(&chain, &[0, 0]).is_err());
+        assert!(is_independent(&chain, &[3]).is_err());
+
+        let f = make_int_operation(OperationSymbol::new("f", 1, false), 3, vec![1, 2, 0]).unwrap();
+        let cycle = BasicAlgebra::new("C3".to_string(), (0..3).collect(), vec![f]);
+        let sets: Vec<Vec<i32>> = independent_sets(&cycle, 3).unwrap().iter().map(|s| s.elements().clone()).collect();
+        assert_eq!(sets, vec![vec![], vec![0], vec![1], vec![2]]);
+    }
+
+    #[test]
+    fn test_constants_generate() {
+        let f = make_int_operation(OperationSymbol::new("f", 1, false), 3, vec![1, 2, 0]).unwrap();
+        let c = make_int_operation(OperationSymbol::new("c", 0, false), 3, vec![0]).unwrap();
+        let alg = BasicAlgebra::new("C3".to_string(), (0..3).collect(), vec![f, c]);
+        assert_eq!(minimal_generating_sets(&alg, 2).unwrap(), vec![BasicSet::new(Vec::new()).unwrap()]);
+        assert_eq!(generating_rank(&alg).unwrap(), 0);
+    }
+}
@@ -0,0 +1,412 @@
+/*! Checking a property across the subalgebras, quotients or powers of an
+algebra.
+
+A property is hereditary if it passes to subalgebras, preserved under
+homomorphic images if it passes to quotients, and so on. [`check_hereditary`]
+builds every member of `S(A)`, `H(A)` or `{A, A², ..., A^k}` and evaluates
+one of the built-in [`Analysis`] values on each with [`analyze_batch`],
+giving a table with one row per member. It decides the question for the
+algebra at hand; a property holding on all powers up to `k` is evidence,
+not proof, that it holds on `P_fin(A)`.
+
+The class operators are also available as lazy iterators of algebras for
+scripting experiments: [`all_subalgebras`] and [`all_quotients`] yield each
+member once up to isomorphism, and [`powers_up_to`] yields `A, ..., A^k`.
+*/
+
+use std::fmt;
+use crate::alg::{Algebra, BasicAlgebra, PowerAlgebra, SmallAlgebra};
+use crate::alg::batch::{analyze_batch, Analysis, AnalysisValue};
+use crate::alg::conlat::CongruenceLattice;
+use crate::alg::conlat::partition::Partition;
+use crate::alg::residual;
+
+/// A class operator generating the algebras a property is checked on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Closure {
+    /// The subalgebras, one for each nonempty subuniverse.
+    S,
+    /// The quotients, one for each congruence.
+    H,
+    /// The powers `A, A², ..., A^k`.
+    P(usize),
+}
+
+impl Closure {
+    /// Parse `"S"`, `"H"` or `"P(k)"` for a positive `k`.
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name.trim() {
+            "S" => Ok(Closure::S),
+            "H" => Ok(Closure::H),
+            other => other.strip_prefix("P(")
+                .and_then(|rest| rest.strip_suffix(')'))
+                .and_then(|k| k.trim().parse::<usize>().ok())
+                .filter(|&k| k > 0)
+                .map(Closure::P)
+                .ok_or_else(|| format!("Unknown closure operator: {}", name)),
+        }
+    }
+}
+
+impl fmt::Display for Closure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Closure::S => write!(f, "S"),
+            Closure::H => write!(f, "H"),
+            Closure::P(k) => write!(f, "P({})", k),
+        }
+    }
+}
+
+/// The result of the analysis on one member of the class.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HereditaryRow {
+    /// The member: its subuniverse, the congruence it is the quotient by,
+    /// or the power.
+    pub member: String,
+    /// The number of elements of the member.
+    pub size: usize,
+    /// The value of the analysis, or the error it gave on this member.
+    pub value: Result<AnalysisValue, String>,
+}
+
+/// The table of results of [`check_hereditary`].
+#[derive(Debug, Clone)]
+pub struct HereditaryReport {
+    /// The analysis evaluated.
+    pub analysis: Analysis,
+    /// The class operator.
+    pub closure: Closure,
+    /// One row per member, in the order they were generated.
+    pub rows: Vec<HereditaryRow>,
+}
+
+impl HereditaryReport {
+    /// Whether a yes or no analysis held on every member; `None` if it
+    /// failed on a member or gives counts rather than answers.
+    pub fn holds(&self) -> Option<bool> {
+        let mut holds = true;
+        for row in &self.rows {
+            match row.value {
+                Ok(AnalysisValue::Bool(b)) => holds &= b,
+                _ => return None,
+            }
+        }
+        Some(holds)
+    }
+
+    /// The rows on which a yes or no analysis is false.
+    pub fn failures(&self) -> Vec<&HereditaryRow> {
+        self.rows.iter().filter(|row| row.value == Ok(AnalysisValue::Bool(false))).collect()
+    }
+}
+
+impl fmt::Display for HereditaryReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} over {}:", self.analysis, self.closure)?;
+        for row in &self.rows {
+            let value = match &row.value {
+                Ok(AnalysisValue::Bool(b)) => b.to_string(),
+                Ok(AnalysisValue::Int(i)) => i.to_string(),
+                Err(e) => format!("error: {}", e),
+            };
+            writeln!(f, "  {} ({} elements): {}", row.member, row.size, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// The members of `closure` applied to `alg`, each with a description.
+fn members(alg: &dyn SmallAlgebra<UniverseItem = i32>, closure: Closure) -> Result<Vec<(String, BasicAlgebra<i32>)>, String> {
+    let mut members = Vec::new();
+    match closure {
+        Closure::S => {
+            for elems in residual::subuniverses(alg)? {
+                crate::progress::check_cancelled()?;
+                let elements: Vec<String> = elems.iter().map(|e| e.to_string()).collect();
+                members.push((format!("{{{}}}", elements.join(",")), residual::subalgebra(alg, &elems)?));
+            }
+        }
+        Closure::H => {
+            let mut con = CongruenceLattice::new(alg.clone_box());
+            for par in con.try_universe()?.clone() {
+                crate::progress::check_cancelled()?;
+                members.push((par.to_string(), residual::quotient(alg, &par)?));
+            }
+        }
+        Closure::P(k) => {
+            for (power, alg_k) in (1..=k).zip(powers_up_to(alg, k)) {
+                members.push((format!("A^{}", power), alg_k?));
+            }
+        }
+    }
+    Ok(members)
+}
+
+/// Evaluate an analysis on every subalgebra, every quotient or the powers
+/// up to `A^k` of an algebra.
+///
+/// The members are built first and then analysed in parallel with
+/// [`analyze_batch`], so an analysis failing on one member only marks that
+/// row. Subalgebras are not identified up to isomorphism: there is one row
+/// per subuniverse and one per congruence.
+///
+/// # Arguments
+/// * `alg` - The algebra
+/// * `analysis` - The property or invariant to evaluate
+/// * `closure` - Which members to generate
+///
+/// # Returns
+/// * `Ok(HereditaryReport)` - One row per member
+/// * `Err(String)` - If the members cannot be built, a power is too large
+///   or the computation was cancelled
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use uacalc::alg::BasicAlgebra;
+/// use uacalc::alg::batch::Analysis;
+/// use uacalc::alg::hereditary::{check_hereditary, Closure};
+/// use uacalc::alg::op::{OperationSymbol, operations};
+///
+/// // Every quotient of Z4 has a Maltsev term, but only Z2 is simple
+/// let plus = OperationSymbol::new("+", 2, false);
+/// let op = operations::make_int_operation(plus, 4, (0..16).map(|k| (k % 4 + k / 4) % 4).collect()).unwrap();
+/// let z4 = BasicAlgebra::new("Z4".to_string(), (0..4).collect::<HashSet<i32>>(), vec![op]);
+///
+/// assert_eq!(check_hereditary(&z4, &Analysis::MalcevTerm, Closure::H).unwrap().holds(), Some(true));
+/// let simple = check_hereditary(&z4, &Analysis::Simple, Closure::H).unwrap();
+/// assert_eq!(simple.rows.len(), 3);
+/// assert_eq!(simple.failures().len(), 2);
+/// ```
+pub fn check_hereditary(
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    analysis: &Analysis,
+    closure: Closure,
+) -> Result<HereditaryReport, String> {
+    let (descriptions, algs): (Vec<String>, Vec<BasicAlgebra<i32>>) = members(alg, closure)?.into_iter().unzip();
+    let results = analyze_batch(&algs, std::slice::from_ref(analysis), None)?;
+    let rows = descriptions.into_iter()
+        .zip(&algs)
+        .zip(results)
+        .map(|((member, alg), mut values)| HereditaryRow {
+            member,
+            size: alg.cardinality() as usize,
+            value: values.remove(0),
+        })
+        .collect();
+    Ok(HereditaryReport { analysis: analysis.clone(), closure, rows })
+}
+
+/// The algebras a [`ClassMembers`] iterator builds, one per item.
+enum Sources {
+    /// Subalgebras on these subuniverses.
+    Subuniverses(std::vec::IntoIter<Vec<i32>>),
+    /// Quotients by these congruences.
+    Congruences(std::vec::IntoIter<Partition>),
+}
+
+/// Iterator returned by [`all_subalgebras`] and [`all_quotients`].
+pub struct ClassMembers {
+    alg: BasicAlgebra<i32>,
+    sources: Sources,
+    /// The members yielded so far, to recognise isomorphic copies.
+    found: Vec<BasicAlgebra<i32>>,
+    failed: bool,
+}
+
+impl ClassMembers {
+    fn new(alg: &dyn SmallAlgebra<UniverseItem = i32>, sources: Sources) -> Self {
+        let elems: Vec<i32> = (0..alg.cardinality()).collect();
+        let alg = residual::subalgebra(alg, &elems).expect("an algebra is a subalgebra of itself");
+        ClassMembers { alg, sources, found: Vec::new(), failed: false }
+    }
+
+    /// The next member not isomorphic to an earlier one, named `<A>-S<i>`
+    /// or `<A>-H<i>`.
+    fn advance(&mut self) -> Result<Option<BasicAlgebra<i32>>, String> {
+        loop {
+            crate::progress::check_cancelled()?;
+            let (member, tag) = match &mut self.sources {
+                Sources::Subuniverses(subs) => match subs.next() {
+                    Some(elems) => (residual::subalgebra(&self.alg, &elems)?, "S"),
+                    None => return Ok(None),
+                },
+                Sources::Congruences(pars) => match pars.next() {
+                    Some(par) => (residual::quotient(&self.alg, &par)?, "H"),
+                    None => return Ok(None),
+                },
+            };
+            let name = format!("{}-{}{}", self.alg.name(), tag, self.found.len());
+            let ops = member.get_operations_ref().iter().map(|op| op.clone_box()).collect();
+            let member = BasicAlgebra::new(name, (0..member.cardinality()).collect(), ops);
+            if residual::add_new(&mut self.found, member.clone())? {
+                return Ok(Some(member));
+            }
+        }
+    }
+}
+
+impl Iterator for ClassMembers {
+    type Item = Result<BasicAlgebra<i32>, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let next = self.advance().transpose();
+        self.failed = matches!(next, Some(Err(_)));
+        next
+    }
+}
+
+/// Enumerate the subalgebras of an algebra up to isomorphism, lazily.
+///
+/// The subuniverses are computed at once, in increasing order; each
+/// subalgebra is built and compared with the earlier ones as the iterator
+/// reaches it.
+///
+/// # Returns
+/// * `Ok(ClassMembers)` - An iterator of the subalgebras, named `<A>-S<i>`
+/// * `Err(String)` - If the subuniverses could not be computed
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use uacalc::alg::{Algebra, BasicAlgebra};
+/// use uacalc::alg::hereditary::all_subalgebras;
+/// use uacalc::alg::op::{OperationSymbol, operations};
+///
+/// // The 3-element chain has 7 subuniverses but 3 subalgebras up to isomorphism
+/// let meet = OperationSymbol::new("meet", 2, false);
+/// let op = operations::make_int_operation(meet, 3, (0..9).map(|k| (k % 3).min(k / 3)).collect()).unwrap();
+/// let alg = BasicAlgebra::new("C3".to_string(), (0..3).collect::<HashSet<i32>>(), vec![op]);
+/// let sizes: Vec<i32> = all_subalgebras(&alg).unwrap().map(|s| s.unwrap().cardinality()).collect();
+/// assert_eq!(sizes.len(), 3);
+/// ```
+pub fn all_subalgebras(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<ClassMembers, String> {
+    let subs = residual::subuniverses(alg)?;
+    Ok(ClassMembers::new(alg, Sources::Subuniverses(subs.into_iter())))
+}
+
+/// Enumerate the quotients of an algebra up to isomorphism, lazily.
+///
+/// Con(A) is computed at once; each quotient is built and compared with
+/// the earlier ones as the iterator reaches it.
+///
+/// # Returns
+/// * `Ok(ClassMembers)` - An iterator of the quotients, named `<A>-H<i>`
+/// * `Err(String)` - If the congruences could not be computed
+pub fn all_quotients(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<ClassMembers, String> {
+    let mut con = CongruenceLattice::new(alg.clone_box());
+    let pars = con.try_universe()?.clone();
+    Ok(ClassMembers::new(alg, Sources::Congruences(pars.into_iter())))
+}
+
+/// Iterator returned by [`powers_up_to`].
+pub struct Powers {
+    alg: Box<dyn SmallAlgebra<UniverseItem = i32>>,
+    next: usize,
+    max_power: usize,
+}
+
+impl Iterator for Powers {
+    type Item = Result<BasicAlgebra<i32>, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next > self.max_power {
+            return None;
+        }
+        let k = self.next;
+        self.next += 1;
+        let power = || -> Result<BasicAlgebra<i32>, String> {
+            crate::progress::check_cancelled()?;
+            let alg_k = PowerAlgebra::new_safe(self.alg.clone_box(), k)?;
+            let size = alg_k.cardinality();
+            if size < 0 {
+                return Err(format!("The power A^{} is too large", k));
+            }
+            let elems: Vec<i32> = (0..size).collect();
+            let member = residual::subalgebra(&alg_k, &elems)?;
+            let ops = member.get_operations_ref().iter().map(|op| op.clone_box()).collect();
+            Ok(BasicAlgebra::new(format!("{}^{}", self.alg.name(), k), (0..size).collect(), ops))
+        };
+        let result = power();
+        if result.is_err() {
+            self.next = self.max_power + 1;
+        }
+        Some(result)
+    }
+}
+
+/// Enumerate the powers `A, A², ..., A^k` of an algebra, each built when
+/// the iterator reaches it.
+///
+/// # Returns
+/// * `Powers` - An iterator of the powers, named `<A>^<k>`; it yields an
+///   error and stops if a power is too large to index or the computation
+///   was cancelled
+pub fn powers_up_to(alg: &dyn SmallAlgebra<UniverseItem = i32>, k: usize) -> Powers {
+    Powers { alg: alg.clone_box(), next: 1, max_power: k }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::op::OperationSymbol;
+    use crate::alg::op::operations::make_int_operation;
+
+    fn semilattice(n: i32) -> BasicAlgebra<i32> {
+        let table = (0..n * n).map(|k| (k % n).min(k / n)).collect();
+        let meet = make_int_operation(OperationSymbol::new("meet", 2, false), n, table).unwrap();
+        BasicAlgebra::new("S".to_string(), (0..n).collect(), vec![meet])
+    }
+
+    #[test]
+    fn test_closure_names() {
+        assert_eq!(Closure::from_name("S").unwrap(), Closure::S);
+        assert_eq!(Closure::from_name("P(3)").unwrap(), Closure::P(3));
+        assert_eq!(Closure::P(3).to_string(), "P(3)");
+        assert!(Closure::from_name("P(0)").is_err());
+        assert!(Closure::from_name("Q").is_err());
+    }
+
+    #[test]
+    fn test_subalgebras_and_powers() {
+        let alg = semilattice(3);
+        let report = check_hereditary(&alg, &Analysis::ConDistributive, Closure::S).unwrap();
+        // The nonempty subsets of a chain are all subuniverses
+        assert_eq!(report.rows.len(), 7);
+        assert_eq!(report.holds(), Some(true));
+        assert!(report.rows.iter().any(|row| row.member == "{0,2}" && row.size == 2));
+
+        let powers = check_hereditary(&semilattice(2), &Analysis::ConCardinality, Closure::P(3)).unwrap();
+        let sizes: Vec<usize> = powers.rows.iter().map(|row| row.size).collect();
+        assert_eq!(sizes, vec![2, 4, 8]);
+        assert_eq!(powers.holds(), None);
+        assert_eq!(powers.rows[0].value, Ok(AnalysisValue::Int(2)));
+        assert!(powers.to_string().starts_with("con_cardinality over P(3):"));
+    }
+
+    #[test]
+    fn test_class_operators() {
+        let alg = semilattice(3);
+        let quotients: Vec<BasicAlgebra<i32>> = all_quotients(&alg).unwrap().collect::<Result<_, _>>().unwrap();
+        // Con of the 3-element chain has 4 members; |0 1|2| and |0|1 2| give
+        // isomorphic quotients
+        let mut sizes: Vec<i32> = quotients.iter().map(|q| q.cardinality()).collect();
+        sizes.sort();
+        assert_eq!(sizes, vec![1, 2, 3]);
+        assert!(quotients.iter().all(|q| q.name().starts_with("S-H")));
+        // check_hereditary keeps one row per congruence, so both
+        // 2-element quotients count as simple
+        let simple = check_hereditary(&alg, &Analysis::Simple, Closure::H).unwrap();
+        assert_eq!(simple.rows.len(), 4);
+        assert_eq!(simple.rows.iter().filter(|row| row.value == Ok(AnalysisValue::Bool(true))).count(), 2);
+
+        let powers: Vec<BasicAlgebra<i32>> = powers_up_to(&semilattice(2), 3).collect::<Result<_, _>>().unwrap();
+        assert_eq!(powers.iter().map(|p| p.cardinality()).collect::<Vec<_>>(), vec![2, 4, 8]);
+        assert_eq!(powers[2].name(), "S^3");
+        assert_eq!(powers_up_to(&alg, 0).count(), 0);
+    }
+}
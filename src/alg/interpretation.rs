@@ -0,0 +1,253 @@
+/*! Primitive positive interpretations between finite algebras.
+
+An algebra is identified here with its invariant relations: the relations
+pp-definable from those of `A` are the subuniverses of the powers of `A`.
+A pp-interpretation of `B` in `A` of dimension `d` consists of a subuniverse
+`D` of `A^d`, a map `h` from `D` onto `B` whose kernel is pp-definable, and
+such that the preimage under `h` of every invariant relation of `B` is
+pp-definable. A pp-interpretation reduces CSP(B) to CSP(A), which makes it
+the basic reduction of the algebraic approach to constraint satisfaction.
+
+The kernel of `h` is then a congruence `θ` of `D`, and the preimages are
+subuniverses exactly when every relation of `B` is a subuniverse of a power
+of `C = D/θ` carried over to `B` by `h`, that is when every operation of
+`C` carried over is a term operation of `B`. The search runs through the
+dimensions up to a bound, the subuniverses `D` of `A^d`, the congruences
+of `D` with `|B|` blocks and the bijections from `D/θ` to `B`, so it is
+practical only for small algebras.
+*/
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use crate::alg::{Algebra, BasicAlgebra, SmallAlgebra};
+use crate::alg::clone;
+use crate::alg::conlat::CongruenceLattice;
+use crate::alg::free_spectrum::term_operation_tables;
+use crate::alg::op::operations::make_int_operation;
+use crate::alg::residual::quotient;
+use crate::terms::compiled_term::table_size;
+use crate::util::horner;
+
+/// A pp-interpretation of `B` in `A`, given by its coordinatization: a
+/// subuniverse of `A^dimension` and a map from it onto `B`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PpInterpretation {
+    /// The dimension `d`.
+    pub dimension: usize,
+    /// The domain, a subuniverse of `A^d`, in lexicographic order.
+    pub domain: Vec<Vec<i32>>,
+    /// The element of `B` each tuple of the domain stands for.
+    pub coordinates: Vec<i32>,
+}
+
+impl PpInterpretation {
+    /// The element of `B` a tuple of the domain stands for, or `None` if
+    /// the tuple is not in the domain.
+    pub fn coordinate(&self, tuple: &[i32]) -> Option<i32> {
+        self.domain.binary_search_by(|t| t.as_slice().cmp(tuple)).ok().map(|i| self.coordinates[i])
+    }
+}
+
+impl fmt::Display for PpInterpretation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pairs: Vec<String> = self.domain.iter().zip(&self.coordinates)
+            .map(|(t, b)| format!("({}) -> {}", t.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","), b))
+            .collect();
+        write!(f, "dimension {}: {}", self.dimension, pairs.join(", "))
+    }
+}
+
+/// Search for a pp-interpretation of `b` in `a` of dimension at most
+/// `dimension_bound`.
+///
+/// Smaller dimensions are tried first and, within a dimension, smaller
+/// domains.
+///
+/// # Arguments
+/// * `a` - The interpreting algebra
+/// * `b` - The interpreted algebra
+/// * `dimension_bound` - The largest dimension tried
+///
+/// # Returns
+/// * `Ok(Some(interpretation))` - The coordinatization of an interpretation
+/// * `Ok(None)` - If there is none of dimension at most `dimension_bound`
+/// * `Err(String)` - If a power is too large to index or the computation
+///   was cancelled
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::BasicAlgebra;
+/// use uacalc::alg::op::{operations, OperationSymbol};
+/// use uacalc::alg::interpretation::pp_interprets;
+///
+/// // A set with no operations interprets every algebra; a 3 element one
+/// // needs two coordinates in a 2 element set.
+/// let set = BasicAlgebra::new("2".to_string(), (0..2).collect(), Vec::new());
+/// let plus = operations::make_int_operation(OperationSymbol::new("+", 2, false), 3,
+///     (0..9).map(|k| (k % 3 + k / 3) % 3).collect()).unwrap();
+/// let z3 = BasicAlgebra::new("Z3".to_string(), (0..3).collect(), vec![plus]);
+/// assert!(pp_interprets(&set, &z3, 1).unwrap().is_none());
+/// let interpretation = pp_interprets(&set, &z3, 2).unwrap().unwrap();
+/// assert_eq!(interpretation.dimension, 2);
+/// assert_eq!(interpretation.domain.len(), 3);
+/// ```
+pub fn pp_interprets(
+    a: &dyn SmallAlgebra<UniverseItem = i32>,
+    b: &dyn SmallAlgebra<UniverseItem = i32>,
+    dimension_bound: usize,
+) -> Result<Option<PpInterpretation>, String> {
+    let n = a.cardinality();
+    let m = b.cardinality();
+    if m <= 0 {
+        return Err(format!("{} is empty", b.name()));
+    }
+    // The term operations of B of each arity of an operation of A.
+    let mut term_ops: HashMap<usize, HashSet<Vec<i32>>> = HashMap::new();
+    for op in a.get_operations_ref() {
+        let k = op.arity() as usize;
+        if let std::collections::hash_map::Entry::Vacant(e) = term_ops.entry(k) {
+            let tables = term_operation_tables(b, k, None)?;
+            crate::progress::check_cancelled()?;
+            e.insert(tables.ok_or_else(|| crate::progress::CANCELLED.to_string())?.into_iter().collect());
+        }
+    }
+    for d in 1..=dimension_bound {
+        if table_size(n, d)? < m as usize {
+            continue;
+        }
+        for domain in clone::inv(n, &a.operations(), d)? {
+            if domain.arity() != d || domain.len() < m as usize {
+                continue;
+            }
+            crate::progress::check_cancelled()?;
+            let d_alg = power_subalgebra(a, domain.tuples())?;
+            let mut con = CongruenceLattice::new(Box::new(d_alg.clone()) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+            let pars = CongruenceLattice::universe(&mut con).clone();
+            for par in pars.iter().filter(|p| p.number_of_blocks() == m as usize) {
+                crate::progress::check_cancelled()?;
+                let c = quotient(&d_alg, par)?;
+                if let Some(h) = carrying_bijection(&c, &term_ops)? {
+                    let coordinates = (0..domain.len())
+                        .map(|i| par.block_index(i).map(|block| h[block]))
+                        .collect::<Result<Vec<i32>, String>>()?;
+                    return Ok(Some(PpInterpretation {
+                        dimension: d,
+                        domain: domain.tuples().to_vec(),
+                        coordinates,
+                    }));
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// The subalgebra of `alg^d` on `tuples`, numbered in their order.
+fn power_subalgebra(alg: &dyn SmallAlgebra<UniverseItem = i32>, tuples: &[Vec<i32>]) -> Result<BasicAlgebra<i32>, String> {
+    let position: HashMap<&[i32], i32> = tuples.iter().enumerate().map(|(i, t)| (t.as_slice(), i as i32)).collect();
+    let size = tuples.len() as i32;
+    let d = tuples.first().map_or(0, |t| t.len());
+    let ops = alg.get_operations_ref().iter()
+        .map(|op| {
+            let arity = op.arity() as usize;
+            let table = (0..table_size(size, arity)?)
+                .map(|c| {
+                    let args = horner::horner_inv_same_size(c as i32, size, arity);
+                    let image = (0..d)
+                        .map(|j| op.int_value_at(&args.iter().map(|&x| tuples[x as usize][j]).collect::<Vec<i32>>()))
+                        .collect::<Result<Vec<i32>, String>>()?;
+                    position.get(image.as_slice()).copied()
+                        .ok_or_else(|| format!("{:?} is not closed under {}", tuples, op.symbol()))
+                })
+                .collect::<Result<Vec<i32>, String>>()?;
+            make_int_operation(op.symbol().clone(), size, table)
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    Ok(BasicAlgebra::new(format!("{}^{}", alg.name(), d), (0..size).collect(), ops))
+}
+
+/// A bijection `h` from `c` onto `{0, ..., m-1}` carrying every operation
+/// of `c` to one of `term_ops`, keyed by arity.
+fn carrying_bijection(c: &BasicAlgebra<i32>, term_ops: &HashMap<usize, HashSet<Vec<i32>>>) -> Result<Option<Vec<i32>>, String> {
+    let m = c.cardinality();
+    let ops: Vec<(usize, &[i32])> = c.get_operations_ref().iter()
+        .map(|op| (op.arity() as usize, op.get_table().unwrap_or_default()))
+        .collect();
+    let mut h: Vec<i32> = (0..m).collect();
+    let mut inverse = vec![0; m as usize];
+    loop {
+        crate::progress::check_cancelled()?;
+        for (x, &y) in h.iter().enumerate() {
+            inverse[y as usize] = x as i32;
+        }
+        let carried = ops.iter().all(|&(k, table)| {
+            let image: Vec<i32> = (0..table.len())
+                .map(|c| {
+                    let args = horner::horner_inv_same_size(c as i32, m, k);
+                    let pre: Vec<i32> = args.iter().map(|&y| inverse[y as usize]).collect();
+                    h[table[horner::horner_same_size(&pre, m) as usize] as usize]
+                })
+                .collect();
+            term_ops[&k].contains(&image)
+        });
+        if carried {
+            return Ok(Some(h));
+        }
+        if !next_permutation(&mut h) {
+            return Ok(None);
+        }
+    }
+}
+
+/// Advance `perm` to the next permutation in lexicographic order.
+fn next_permutation(perm: &mut [i32]) -> bool {
+    let Some(i) = (1..perm.len()).rev().find(|&i| perm[i - 1] < perm[i]) else {
+        return false;
+    };
+    let j = (i..perm.len()).rev().find(|&j| perm[j] > perm[i - 1]).unwrap_or(i);
+    perm.swap(i - 1, j);
+    perm[i..].reverse();
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::op::OperationSymbol;
+
+    fn binary(name: &str, size: i32, f: fn(i32, i32) -> i32) -> BasicAlgebra<i32> {
+        let table = (0..size * size).map(|k| f(k % size, k / size)).collect();
+        let op = make_int_operation(OperationSymbol::new("f", 2, false), size, table).unwrap();
+        BasicAlgebra::new(name.to_string(), (0..size).collect(), vec![op])
+    }
+
+    #[test]
+    fn test_cyclic_groups() {
+        let z2 = binary("Z2", 2, |x, y| (x + y) % 2);
+        let z4 = binary("Z4", 4, |x, y| (x + y) % 4);
+        let interpretation = pp_interprets(&z4, &z2, 1).unwrap().unwrap();
+        assert_eq!(interpretation.dimension, 1);
+        // {0, 2} is the smallest subgroup with two elements.
+        assert_eq!(interpretation.domain, vec![vec![0], vec![2]]);
+        assert_eq!(interpretation.coordinate(&[2]), Some(1));
+    }
+
+    #[test]
+    fn test_group_does_not_interpret_lattice() {
+        let z2 = binary("Z2", 2, |x, y| (x + y) % 2);
+        let meet = binary("S", 2, i32::min);
+        assert!(pp_interprets(&z2, &meet, 2).unwrap().is_none());
+        assert!(pp_interprets(&meet, &meet, 1).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_next_permutation() {
+        let mut p = vec![0, 1, 2];
+        let mut count = 1;
+        while next_permutation(&mut p) {
+            count += 1;
+        }
+        assert_eq!(count, 6);
+        assert_eq!(p, vec![2, 1, 0]);
+    }
+}
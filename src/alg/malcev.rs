@@ -9,6 +9,7 @@
  */
 
 use crate::alg::{SmallAlgebra, BigProductAlgebra, FreeAlgebra, BasicAlgebra, Closer};
+use crate::eq::Equation;
 use crate::terms::{Term, VariableImp};
 use crate::util::int_array::IntArray;
 use crate::util::sequence_generator::SequenceGenerator;
@@ -397,6 +398,11 @@ where
 /// - ...
 /// - t(x,x,x,...,y) = x
 ///
+/// The term is found by closing the generators `(y,x,...,x)`, ...,
+/// `(x,...,x,y)` of `F(2)^arity` until `(x,...,x)` turns up. To look for an
+/// NU operation among the polymorphisms of an algebra instead, use
+/// `search::find_polymorphism` with `LinearIdentity::near_unanimity`.
+///
 /// # Arguments
 /// * `alg` - The algebra to check
 /// * `arity` - The arity of the NU term to find
@@ -2184,6 +2190,62 @@ fn sd_path(
     None
 }
 
+/// The outcome of testing whether the variety generated by an algebra is
+/// congruence meet-semidistributive, with a witness either way.
+#[derive(Debug)]
+pub struct SdMeetAnalysis {
+    /// SD-meet terms, as found by [`sd_meet_terms`], if the variety is
+    /// congruence meet-semidistributive.
+    pub terms: Option<Vec<Box<dyn Term>>>,
+    /// For an idempotent algebra that fails, the coordinates `[x, y]` found
+    /// by [`sd_meet_idempotent`] witnessing the failure; always `None` for
+    /// an algebra that is not idempotent.
+    pub failure: Option<Vec<usize>>,
+}
+
+impl Clone for SdMeetAnalysis {
+    fn clone(&self) -> Self {
+        SdMeetAnalysis {
+            terms: self.terms.as_ref().map(|ts| ts.iter().map(|t| t.clone_box()).collect()),
+            failure: self.failure.clone(),
+        }
+    }
+}
+
+impl SdMeetAnalysis {
+    /// Whether the variety is congruence meet-semidistributive.
+    pub fn holds(&self) -> bool {
+        self.terms.is_some()
+    }
+}
+
+/// Test whether the variety generated by the algebra is congruence
+/// meet-semidistributive.
+///
+/// A locally finite variety is SD(∧) exactly when it has the terms of
+/// Kearnes and Kiss (equivalently Willard's terms), which are searched for
+/// by [`sd_meet_terms`]. For an idempotent algebra a failure is first
+/// looked for with the Freese-Valeriote test, which also gives a witness.
+///
+/// # Arguments
+/// * `alg` - The algebra to check
+///
+/// # Returns
+/// * `Ok(SdMeetAnalysis)` - The terms if the variety is SD(∧), and the
+///   failure witness for an idempotent algebra if not
+/// * `Err(String)` - If there's an error during computation
+pub fn variety_is_sd_meet<T>(alg: &dyn SmallAlgebra<UniverseItem = T>) -> Result<SdMeetAnalysis, String>
+where
+    T: Clone + std::fmt::Debug + std::fmt::Display + std::hash::Hash + Eq + Send + Sync + 'static
+{
+    if alg.cardinality() > 1 && alg.is_idempotent() {
+        if let Some(failure) = sd_meet_idempotent(alg)? {
+            return Ok(SdMeetAnalysis { terms: None, failure: Some(failure) });
+        }
+    }
+    Ok(SdMeetAnalysis { terms: sd_meet_terms(alg)?, failure: None })
+}
+
 /// Find SD terms for the algebra.
 ///
 /// # Arguments
@@ -2421,6 +2483,75 @@ where
     Ok(None)
 }
 
+/// The outcome of deciding whether an algebra has a Taylor term, with the
+/// witnessing Markovic-McKenzie-Siggers term and the identities it satisfies.
+#[derive(Debug)]
+pub struct TaylorAnalysis {
+    /// A 4-ary term `t(x0,x1,x2,x3)` satisfying the identities, if any.
+    pub term: Option<Box<dyn Term>>,
+    /// The identities `t(y,x,x,x) = t(x,x,y,y)`, `t(x,x,y,x) = t(x,y,x,x)`
+    /// and `t(x,x,x,x) = x` with the term substituted for `t`; empty if
+    /// there is no term.
+    pub identities: Vec<Equation>,
+}
+
+impl Clone for TaylorAnalysis {
+    fn clone(&self) -> Self {
+        TaylorAnalysis {
+            term: self.term.as_ref().map(|t| t.clone_box()),
+            identities: self.identities.clone(),
+        }
+    }
+}
+
+impl TaylorAnalysis {
+    /// Whether the algebra has a Taylor term.
+    pub fn has_taylor_term(&self) -> bool {
+        self.term.is_some()
+    }
+}
+
+/// Decide whether a finite algebra has a Taylor term.
+///
+/// By the theorem of Markovic, Maroti and McKenzie a finite algebra has a
+/// Taylor term exactly when it has an idempotent 4-ary term `t` with
+/// `t(y,x,x,x) = t(x,x,y,y)` and `t(x,x,y,x) = t(x,y,x,x)`, which is
+/// searched for by [`markovic_mckenzie_siggers_taylor_term`].
+///
+/// # Arguments
+/// * `alg` - The algebra to check
+///
+/// # Returns
+/// * `Ok(TaylorAnalysis)` - The witness and its identities, if any
+/// * `Err(String)` - If there's an error during computation
+pub fn has_taylor_term<T>(alg: &dyn SmallAlgebra<UniverseItem = T>) -> Result<TaylorAnalysis, String>
+where
+    T: Clone + std::fmt::Debug + std::fmt::Display + std::hash::Hash + Eq + Send + Sync + 'static
+{
+    if alg.cardinality() > 1 && alg.operations().is_empty() {
+        return Ok(TaylorAnalysis { term: None, identities: Vec::new() });
+    }
+    let term = markovic_mckenzie_siggers_taylor_term(alg)?;
+    let identities = match &term {
+        Some(t) => {
+            // Substitute x and y for the variables x0, ..., x3 of t.
+            let apply = |args: &str| -> Result<Box<dyn Term>, String> {
+                let map: HashMap<String, Box<dyn Term>> = args.chars().enumerate()
+                    .map(|(i, c)| (format!("x{}", i), Box::new(VariableImp::new(&c.to_string())) as Box<dyn Term>))
+                    .collect();
+                t.substitute(&map)
+            };
+            vec![
+                Equation::new(apply("yxxx")?, apply("xxyy")?),
+                Equation::new(apply("xxyx")?, apply("xyxx")?),
+                Equation::new(apply("xxxx")?, Box::new(VariableImp::new("x"))),
+            ]
+        }
+        None => Vec::new(),
+    };
+    Ok(TaylorAnalysis { term, identities })
+}
+
 /// Find a weak 3-edge term for the algebra.
 ///
 /// A weak 3-edge term is a 4-ary term e(x0, x1, x2, x3) satisfying:
@@ -2566,6 +2697,167 @@ where
     Ok(None)
 }
 
+/// The sets of tame congruence theory types whose omission is decided by
+/// [`omitted_types`], smallest first.
+pub const TYPE_SETS: [&[i32]; 6] = [&[1], &[1, 2], &[1, 5], &[1, 2, 5], &[1, 4, 5], &[1, 2, 4, 5]];
+
+/// Whether the variety generated by an algebra omits a set of tame
+/// congruence theory types.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TypeOmission {
+    /// The types, in increasing order
+    pub types: Vec<i32>,
+    /// `Some(true)` if the variety omits all of the types, `Some(false)`
+    /// if one of them occurs, `None` if the conditions checked do not
+    /// decide it
+    pub omitted: Option<bool>,
+    /// The Mal'cev conditions the verdict rests on
+    pub reasons: Vec<String>,
+}
+
+impl std::fmt::Display for TypeOmission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let types: Vec<String> = self.types.iter().map(|t| t.to_string()).collect();
+        let verdict = match self.omitted {
+            Some(true) => "omitted",
+            Some(false) => "not omitted",
+            None => "unknown",
+        };
+        write!(f, "{{{}}}: {}", types.join(","), verdict)?;
+        if !self.reasons.is_empty() {
+            write!(f, " ({})", self.reasons.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Which of the Mal'cev conditions tied to type omission hold in a
+/// locally finite variety; `None` for a condition that was not checked.
+///
+/// By Hobby and McKenzie, "The Structure of Finite Algebras", Chapter 9,
+/// a locally finite variety
+///
+/// * omits type 1 iff it has a Taylor term,
+/// * omits types 1, 2 iff it is congruence meet-semidistributive,
+/// * omits types 1, 2, 5 iff it is congruence join-semidistributive,
+///
+/// and it omits types 1, 5 if it is congruence modular and types 1, 4, 5
+/// if it is congruence n-permutable for some n. The last two are only used
+/// in the positive direction.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OmissionConditions {
+    /// Whether there is a Taylor term
+    pub taylor: Option<bool>,
+    /// Whether the variety is congruence meet-semidistributive
+    pub sd_meet: Option<bool>,
+    /// Whether the variety is congruence join-semidistributive
+    pub sd_join: Option<bool>,
+    /// Whether the variety is congruence modular
+    pub modular: Option<bool>,
+    /// Whether the variety is congruence n-permutable for some n
+    pub permutable: Option<bool>,
+}
+
+impl OmissionConditions {
+    /// Decide, as far as the conditions allow, which of [`TYPE_SETS`] the
+    /// variety omits.
+    ///
+    /// A set is omitted when each of its types is omitted by one of the
+    /// conditions that hold, and not omitted when it contains a set whose
+    /// characterizing condition fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::malcev::OmissionConditions;
+    ///
+    /// // An abelian group: Taylor and modular, but not SD-meet
+    /// let conditions = OmissionConditions { taylor: Some(true), sd_meet: Some(false), modular: Some(true), ..Default::default() };
+    /// let omissions = conditions.omissions();
+    /// assert_eq!(omissions[0].omitted, Some(true));
+    /// assert_eq!(omissions[1].omitted, Some(false));
+    /// assert_eq!(omissions[2].omitted, Some(true));
+    /// assert_eq!(omissions[4].omitted, None);
+    /// ```
+    pub fn omissions(&self) -> Vec<TypeOmission> {
+        let holding: Vec<(&[i32], &str)> = [
+            (self.taylor, &[1][..], "Taylor term"),
+            (self.sd_meet, &[1, 2][..], "SD-meet terms"),
+            (self.sd_join, &[1, 2, 5][..], "SD-join terms"),
+            (self.modular, &[1, 5][..], "Gumm terms"),
+            (self.permutable, &[1, 4, 5][..], "Hagemann-Mitschke terms"),
+        ]
+        .into_iter()
+        .filter(|(holds, _, _)| *holds == Some(true))
+        .map(|(_, types, reason)| (types, reason))
+        .collect();
+        let failing: Vec<(&[i32], &str)> = [
+            (self.taylor, &[1][..], "no Taylor term"),
+            (self.sd_meet, &[1, 2][..], "no SD-meet terms"),
+            (self.sd_join, &[1, 2, 5][..], "no SD-join terms"),
+        ]
+        .into_iter()
+        .filter(|(holds, _, _)| *holds == Some(false))
+        .map(|(_, types, reason)| (types, reason))
+        .collect();
+
+        TYPE_SETS.iter().map(|&types| {
+            // Greedily cover the types by the conditions omitting most of
+            // the ones left, so the reasons are few
+            let mut reasons = Vec::new();
+            let mut covered: Vec<i32> = Vec::new();
+            loop {
+                let gain = |omits: &[i32]| omits.iter().filter(|t| types.contains(t) && !covered.contains(t)).count();
+                match holding.iter().filter(|(omits, _)| gain(omits) > 0).max_by_key(|(omits, _)| gain(omits)) {
+                    Some(&(omits, reason)) => {
+                        covered.extend(omits.iter().filter(|t| types.contains(t)));
+                        reasons.push(reason.to_string());
+                    }
+                    None => break,
+                }
+            }
+            let omitted = if types.iter().all(|t| covered.contains(t)) {
+                Some(true)
+            } else if let Some(&(_, reason)) = failing.iter().find(|(occurs, _)| occurs.iter().all(|t| types.contains(t))) {
+                reasons = vec![reason.to_string()];
+                Some(false)
+            } else {
+                reasons.clear();
+                None
+            };
+            TypeOmission { types: types.to_vec(), omitted, reasons }
+        })
+        .collect()
+    }
+}
+
+/// Decide which sets of tame congruence theory types the variety generated
+/// by a finite algebra omits, using the Mal'cev characterizations listed
+/// under [`OmissionConditions`].
+///
+/// Searches for a Taylor term, SD-meet, SD-join, Gumm and Hagemann-Mitschke
+/// terms; each search builds a free algebra, so this is as expensive as
+/// all of them together.
+///
+/// # Arguments
+/// * `alg` - The algebra to check
+///
+/// # Returns
+/// * `Ok(Vec<TypeOmission>)` - A verdict for each of [`TYPE_SETS`]
+/// * `Err(String)` - If there's an error during computation
+pub fn omitted_types<T>(alg: &dyn SmallAlgebra<UniverseItem = T>) -> Result<Vec<TypeOmission>, String>
+where
+    T: Clone + std::fmt::Debug + std::fmt::Display + std::hash::Hash + Eq + Send + Sync + 'static
+{
+    let conditions = OmissionConditions {
+        taylor: Some(has_taylor_term(alg)?.has_taylor_term()),
+        sd_meet: Some(variety_is_sd_meet(alg)?.holds()),
+        sd_join: Some(sd_terms(alg)?.is_some()),
+        modular: Some(gumm_terms(alg)?.is_some()),
+        permutable: Some(hagemann_mitschke_terms(alg)?.is_some()),
+    };
+    Ok(conditions.omissions())
+}
+
 /// Find a witness for SD-meet failure in an idempotent algebra.
 ///
 /// Uses Theorem 4.3 of Freese-Valeriote to test if the variety is congruence SD-meet.
@@ -3272,6 +3564,7 @@ where
     let mut incr = SequenceGenerator::sequence_incrementor(&mut v, max);
     
     loop {
+        crate::progress::check_cancelled()?;
         // Get current value from incrementor
         let current_v = incr.get_current();
         
@@ -3315,6 +3608,96 @@ where
     Ok(true)
 }
 
+/// Test if the algebra has a cyclic term of arity `p`: an idempotent term
+/// with `t(x1,...,xp) = t(x2,...,xp,x1)`.
+///
+/// For idempotent algebras this uses the algorithm of Valeriote and Willard
+/// ([`cyclic_term_idempotent`]), which closes the cyclic shifts of tuples
+/// in `A^p` without building any terms; otherwise it searches the term
+/// operations with [`find_cyclic_term`]. By a theorem of Barto and Kozik a
+/// finite idempotent algebra has a Taylor term exactly when it has a cyclic
+/// term of every prime arity `p > |A|`.
+///
+/// # Arguments
+/// * `alg` - The algebra
+/// * `p` - The arity of the cyclic term (must be at least 2)
+///
+/// # Returns
+/// * `Ok(true)` - A cyclic term exists
+/// * `Ok(false)` - No cyclic term exists
+/// * `Err(String)` - If `p < 2` or the computation was cancelled
+pub fn has_cyclic_term<T>(alg: &dyn SmallAlgebra<UniverseItem = T>, p: usize) -> Result<bool, String>
+where
+    T: Clone + std::fmt::Debug + std::fmt::Display + std::hash::Hash + Eq + Send + Sync + 'static
+{
+    if p < 2 {
+        return Err("arity must be at least 2".to_string());
+    }
+    if alg.cardinality() > 1 && alg.operations().is_empty() {
+        return Ok(false);
+    }
+    if alg.is_idempotent() {
+        cyclic_term_idempotent(alg, p)
+    } else {
+        Ok(find_cyclic_term(alg, p)?.is_some())
+    }
+}
+
+/// Find a cyclic term of arity `p`: an idempotent term with
+/// `t(x0,...,x{p-1}) = t(x1,...,x{p-1},x0)`.
+///
+/// The `p`-ary term operations are generated from the projections until
+/// one is idempotent and invariant under the cyclic shift of its
+/// arguments, so this is only practical when the free algebra on `p`
+/// generators is small; [`has_cyclic_term`] decides existence faster for
+/// idempotent algebras.
+///
+/// # Arguments
+/// * `alg` - The algebra
+/// * `p` - The arity of the cyclic term (must be at least 2)
+///
+/// # Returns
+/// * `Ok(Some(Term))` - A cyclic term in the variables `x0, ..., x{p-1}`
+/// * `Ok(None)` - No cyclic term exists
+/// * `Err(String)` - If `p < 2`, `A^(A^p)` is too large to index or the
+///   computation was cancelled
+pub fn find_cyclic_term<T>(alg: &dyn SmallAlgebra<UniverseItem = T>, p: usize) -> Result<Option<Box<dyn Term>>, String>
+where
+    T: Clone + std::fmt::Debug + std::fmt::Display + std::hash::Hash + Eq + Send + Sync + 'static
+{
+    if p < 2 {
+        return Err("arity must be at least 2".to_string());
+    }
+    if alg.cardinality() == 1 {
+        return Ok(Some(Box::new(VariableImp::x())));
+    }
+    let card = alg.cardinality();
+    let int_ops = crate::alg::op::ops::make_int_operations(alg.operations())?;
+    let i32_alg = BasicAlgebra::new(alg.name().to_string(), (0..card).collect(), int_ops);
+
+    let n = card as usize;
+    let top = n.pow(p as u32 - 1);
+    let diagonal: usize = (0..p).map(|i| n.pow(i as u32)).sum();
+    // The argument tuple with Horner index c shifted left by one place
+    let is_cyclic = |t: &[i32]| {
+        (0..n).all(|a| t[a * diagonal] == a as i32)
+            && (0..t.len()).all(|c| t[c] == t[c / n + (c % n) * top])
+    };
+    let mut closure = crate::alg::free_spectrum::TermClosure::new(&i32_alg, p)?;
+    if let Some(i) = closure.elems.iter().position(|t| is_cyclic(t)) {
+        return Ok(Some(closure.terms[i].clone_box()));
+    }
+    while closure.advance() {
+        if is_cyclic(closure.elems.last().unwrap()) {
+            return Ok(closure.terms.last().map(|t| t.clone_box()));
+        }
+        if closure.elems.len().is_multiple_of(256) {
+            crate::progress::check_cancelled()?;
+        }
+    }
+    Ok(None)
+}
+
 /// Check if a vector is "good for cyclic".
 /// 
 /// A vector is good if the first entry is the smallest and the vector is not constant.
@@ -3640,6 +4023,10 @@ where
 /// A k-edge term is a term of arity k+1 that satisfies certain edge conditions.
 /// This is used to test for certain Mal'cev conditions related to edge terms.
 ///
+/// As with `nu_term`, the term is found by closure in a power of `F(2)`;
+/// `search::find_polymorphism` with `LinearIdentity::edge` finds edge
+/// polymorphisms instead.
+///
 /// # Arguments
 /// * `alg` - The algebra to check
 /// * `k` - The parameter k (edge term will have arity k+1)
@@ -4310,6 +4697,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_variety_is_sd_meet() {
+        use crate::alg::op::{operations::make_int_operation, OperationSymbol};
+        // The idempotent quasigroup 2x + 2y on Z3 is affine, so not SD(∧)
+        let table = (0..9).map(|k| (2 * (k % 3) + 2 * (k / 3)) % 3).collect();
+        let q = make_int_operation(OperationSymbol::new("q", 2, false), 3, table).unwrap();
+        let alg = BasicAlgebra::new("Q3".to_string(), HashSet::from([0, 1, 2]), vec![q]);
+        let analysis = variety_is_sd_meet(&alg).unwrap();
+        assert!(!analysis.holds());
+        assert!(analysis.failure.is_some());
+
+        // The 2 element lattice has a majority term
+        let join = make_int_operation(OperationSymbol::new("join", 2, false), 2, vec![0, 1, 1, 1]).unwrap();
+        let meet = make_int_operation(OperationSymbol::new("meet", 2, false), 2, vec![0, 0, 0, 1]).unwrap();
+        let lat2 = BasicAlgebra::new("L2".to_string(), HashSet::from([0, 1]), vec![join, meet]);
+        let analysis = variety_is_sd_meet(&lat2).unwrap();
+        assert!(analysis.holds() && analysis.failure.is_none());
+    }
+
+    #[test]
+    fn test_omitted_types() {
+        use crate::alg::op::{operations::make_int_operation, OperationSymbol};
+        // The 2 element lattice only has type 4
+        let join = make_int_operation(OperationSymbol::new("join", 2, false), 2, vec![0, 1, 1, 1]).unwrap();
+        let meet = make_int_operation(OperationSymbol::new("meet", 2, false), 2, vec![0, 0, 0, 1]).unwrap();
+        let lat2 = BasicAlgebra::new("L2".to_string(), HashSet::from([0, 1]), vec![join, meet]);
+        let omissions = omitted_types(&lat2).unwrap();
+        assert_eq!(omissions.len(), TYPE_SETS.len());
+        let omitted: Vec<Option<bool>> = omissions.iter().map(|o| o.omitted).collect();
+        assert_eq!(omitted, vec![Some(true), Some(true), Some(true), Some(true), None, None]);
+        assert_eq!(omissions[3].to_string(), "{1,2,5}: omitted (SD-join terms)");
+
+        // A 2 element set has type 1 only
+        let f = make_int_operation(OperationSymbol::new("f", 1, false), 2, vec![0, 1]).unwrap();
+        let set = BasicAlgebra::new("S2".to_string(), HashSet::from([0, 1]), vec![f]);
+        let omissions = omitted_types(&set).unwrap();
+        assert!(omissions.iter().all(|o| o.omitted == Some(false)));
+        assert_eq!(omissions[2].reasons, vec!["no Taylor term"]);
+    }
+
+    #[test]
+    fn test_omission_conditions() {
+        // A Mal'cev algebra, e.g. a group, omits 1, 4 and 5
+        let group = OmissionConditions { taylor: Some(true), sd_meet: Some(false), modular: Some(true), permutable: Some(true), ..Default::default() };
+        let omissions = group.omissions();
+        assert_eq!(omissions[4].omitted, Some(true));
+        assert_eq!(omissions[4].reasons, vec!["Hagemann-Mitschke terms"]);
+        assert_eq!(omissions[5].omitted, Some(false));
+        assert_eq!(omissions[5].reasons, vec!["no SD-meet terms"]);
+        assert!(OmissionConditions::default().omissions().iter().all(|o| o.omitted.is_none()));
+    }
+
     #[test]
     fn test_sd_meet_terms_with_z3_java_comparison() {
         // Test sd_meet_terms with z3.ua comparing Rust vs Java
@@ -4575,5 +5014,57 @@ mod tests {
             println!("Skipping test - baker2.ua not found");
         }
     }
-}
 
+    #[test]
+    fn test_has_taylor_term() {
+        use crate::alg::op::OperationSymbol;
+        // Z3 under x - y has a Taylor term, a set with a permutation does not
+        let sym = OperationSymbol::new("d", 2, false);
+        let table = (0..9).map(|k| (k % 3 + 2 * (k / 3)) % 3).collect();
+        let op = crate::alg::op::operations::make_int_operation(sym, 3, table).unwrap();
+        let z3 = BasicAlgebra::new("Z3".to_string(), (0..3).collect(), vec![op]);
+        let analysis = has_taylor_term(&z3).unwrap();
+        assert!(analysis.has_taylor_term());
+        assert_eq!(analysis.identities.len(), 3);
+        for eq in &analysis.identities {
+            assert!(eq.is_satisfied_in(&z3).unwrap());
+        }
+
+        let sym = OperationSymbol::new("f", 1, false);
+        let op = crate::alg::op::operations::make_int_operation(sym, 2, vec![1, 0]).unwrap();
+        let set = BasicAlgebra::new("S".to_string(), (0..2).collect(), vec![op]);
+        let analysis = has_taylor_term(&set).unwrap();
+        assert!(!analysis.has_taylor_term());
+        assert!(analysis.identities.is_empty());
+    }
+
+    #[test]
+    fn test_cyclic_terms() {
+        use crate::alg::op::OperationSymbol;
+        use crate::alg::op::operations::make_int_operation;
+        // x - y on Z3 is not idempotent; 2x + 2y is, and has cyclic terms
+        // of arity 2 but not 3 (a cyclic linear term has equal coefficients)
+        let sym = OperationSymbol::new("d", 2, false);
+        let table = (0..9).map(|k| (2 * (k % 3) + 2 * (k / 3)) % 3).collect();
+        let z3 = BasicAlgebra::new("Z3".to_string(), (0..3).collect(), vec![make_int_operation(sym, 3, table).unwrap()]);
+        assert!(has_cyclic_term(&z3, 2).unwrap());
+        assert!(!has_cyclic_term(&z3, 3).unwrap());
+        let t = find_cyclic_term(&z3, 2).unwrap().unwrap();
+        assert_eq!(t.to_string(), "d(x0,x1)");
+        assert!(find_cyclic_term(&z3, 3).unwrap().is_none());
+        assert!(has_cyclic_term(&z3, 1).is_err());
+        let sym = OperationSymbol::new("minus", 2, false);
+        let table = (0..9).map(|k| (k % 3 + 2 * (k / 3)) % 3).collect();
+        let minus = BasicAlgebra::new("Z3".to_string(), (0..3).collect(), vec![make_int_operation(sym, 3, table).unwrap()]);
+        assert!(has_cyclic_term(&minus, 2).unwrap());
+        assert!(!has_cyclic_term(&minus, 3).unwrap());
+
+        // The two element semilattice has cyclic terms of every arity
+        let sym = OperationSymbol::new("meet", 2, false);
+        let s2 = BasicAlgebra::new("S".to_string(), (0..2).collect(), vec![make_int_operation(sym, 2, vec![0, 0, 0, 1]).unwrap()]);
+        for p in 2..5 {
+            assert!(has_cyclic_term(&s2, p).unwrap());
+            assert!(find_cyclic_term(&s2, p).unwrap().is_some());
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fmt::{Debug, Display};
 use std::hash::Hash;
 use std::sync::Arc;
@@ -30,6 +30,10 @@ where
         self.inner.get_operations_ref()
     }
     
+    fn operations_arc(&self) -> Vec<std::sync::Arc<dyn Operation>> {
+        self.inner.operations_arc()
+    }
+    
     fn clone_box(&self) -> Box<dyn SmallAlgebra<UniverseItem = Self::UniverseItem>> {
         Box::new(SmallAlgebraWrapper::new(self.inner.clone_box()))
     }
@@ -177,12 +181,16 @@ where
 
 pub mod algebra;
 pub mod algebras;
+pub mod batch;
 pub mod algebra_from_minimal_sets;
 pub mod algebra_with_generating_vector;
 pub mod big_product_algebra;
+pub mod cardinality;
+pub mod clone;
 pub mod closer;
 pub mod closer_timing;
 pub mod conlat;
+pub mod fiber_product;
 pub mod general_algebra;
 pub mod op;
 pub mod parallel;
@@ -190,6 +198,7 @@ pub mod polin_like_algebra;
 pub mod product_algebra;
 pub mod quotient_algebra;
 pub mod quotient_element;
+pub mod search;
 pub mod small_algebra;
 pub mod subalgebra;
 pub mod sub_product_algebra;
@@ -206,6 +215,8 @@ pub use closer_timing::CloserTiming;
 pub use closer::Closer;
 pub use algebra_from_minimal_sets::AlgebraFromMinimalSets;
 pub use big_product_algebra::BigProductAlgebra;
+pub use cardinality::Cardinality;
+pub use fiber_product::{fiber_product, FiberProduct};
 
 // Re-export algebra types
 pub use algebra::{
@@ -218,7 +229,7 @@ pub use algebra::{
 pub use general_algebra::GeneralAlgebra;
 pub use small_algebra::{SmallAlgebra, BasicAlgebra, AlgebraType};
 pub use subalgebra::Subalgebra;
-pub use product_algebra::ProductAlgebra;
+pub use product_algebra::{ProductAlgebra, ProductElements};
 pub use quotient_algebra::QuotientAlgebra;
 pub use polin_like_algebra::PolinLikeAlgebra;
 pub use quotient_element::QuotientElement;
@@ -235,6 +246,22 @@ pub use op::ParameterizedOperation;
 // Subalgebra is now implemented in subalgebra.rs
 
 pub mod free_algebra;
+pub mod free_spectrum;
+pub mod term_equivalence;
+pub mod residual;
+pub mod monounary;
+pub mod ring;
+pub mod subuniverse_graph;
+pub mod simple;
+pub mod generating_sets;
+pub mod finite_basis;
+pub mod congruence_regularity;
+pub mod dpc;
+pub mod discriminator;
+pub mod hereditary;
+pub mod quasivariety;
+pub mod duality;
+pub mod interpretation;
 
 pub use free_algebra::FreeAlgebra;
 
@@ -424,6 +451,273 @@ impl Homomorphism {
         Ok(par)
     }
     
+    /// Create a homomorphism from the list of images of the domain elements.
+    /// 
+    /// This is the form in which [`find_homomorphism`](crate::alg::search::find_homomorphism)
+    /// returns a homomorphism.
+    /// 
+    /// # Arguments
+    /// * `domain` - The domain algebra
+    /// * `range` - The range algebra
+    /// * `images` - `images[i]` is the image of element `i`
+    /// 
+    /// # Returns
+    /// * `Ok(Homomorphism)` - Successfully created homomorphism
+    /// * `Err(String)` - If there is not one image per domain element, an
+    ///   image is out of range, an operation of the domain is missing from
+    ///   the range or the map does not respect the operations
+    /// 
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::{Homomorphism, SmallAlgebra, BasicAlgebra};
+    /// use uacalc::alg::op::{OperationSymbol, operations};
+    /// use std::collections::HashSet;
+    /// 
+    /// let s = OperationSymbol::new("s", 1, false);
+    /// let z = |n: i32| Box::new(BasicAlgebra::new(format!("Z{}", n), (0..n).collect::<HashSet<i32>>(),
+    ///     vec![operations::make_int_operation(s.clone(), n, (0..n).map(|k| (k + 1) % n).collect()).unwrap()]))
+    ///     as Box<dyn SmallAlgebra<UniverseItem = i32>>;
+    /// assert!(Homomorphism::from_images(z(4), z(2), &[0, 1, 0, 1]).is_ok());
+    /// // h(s(0)) = h(1) = 0 but s(h(0)) = s(0) = 1.
+    /// assert!(Homomorphism::from_images(z(4), z(2), &[0, 0, 1, 1]).is_err());
+    /// ```
+    pub fn from_images(
+        domain: Box<dyn SmallAlgebra<UniverseItem = i32>>,
+        range: Box<dyn SmallAlgebra<UniverseItem = i32>>,
+        images: &[i32],
+    ) -> Result<Self, String> {
+        if images.len() != domain.cardinality() as usize {
+            return Err(format!(
+                "Expected {} images, got {}",
+                domain.cardinality(),
+                images.len()
+            ));
+        }
+        let mut map = HashMap::new();
+        for (i, &image) in images.iter().enumerate() {
+            if image < 0 {
+                return Err(format!("Mapped value {} is out of range [0, {})", image, range.cardinality()));
+            }
+            map.insert(i, image as usize);
+        }
+        if !crate::alg::algebras::is_homomorphism(images, domain.as_ref(), range.as_ref())? {
+            return Err(format!("The map does not respect the operations of {}", domain.name()));
+        }
+        Self::new_safe(domain, range, map)
+    }
+    
+    /// Check if distinct domain elements have distinct images.
+    pub fn is_injective(&self) -> bool {
+        let images: HashSet<usize> = self.map.values().copied().collect();
+        images.len() == self.map.len()
+    }
+    
+    /// Check if every range element is an image.
+    pub fn is_surjective(&self) -> bool {
+        self.image().len() == self.range.cardinality() as usize
+    }
+    
+    /// Get the image of the domain: the range elements hit by the map, in
+    /// increasing order.
+    pub fn image(&self) -> Vec<i32> {
+        let images: BTreeSet<usize> = self.map.values().copied().collect();
+        images.into_iter().map(|i| i as i32).collect()
+    }
+    
+    /// Get the image of the domain as a subalgebra of the range.
+    /// 
+    /// # Returns
+    /// * `Ok(Subalgebra)` - The subalgebra of the range on the image
+    /// * `Err(String)` - If the domain is empty
+    /// 
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::{Homomorphism, SmallAlgebra, BasicAlgebra, Algebra};
+    /// use uacalc::alg::op::{OperationSymbol, operations};
+    /// use std::collections::HashSet;
+    /// 
+    /// // x -> 2x embeds Z2 into Z4 under addition
+    /// let plus = OperationSymbol::new("+", 2, false);
+    /// let z = |n: i32| Box::new(BasicAlgebra::new(
+    ///     format!("Z{}", n),
+    ///     (0..n).collect::<HashSet<i32>>(),
+    ///     vec![operations::make_int_operation(plus.clone(), n, (0..n * n).map(|k| (k % n + k / n) % n).collect()).unwrap()],
+    /// )) as Box<dyn SmallAlgebra<UniverseItem = i32>>;
+    /// let homo = Homomorphism::from_images(z(2), z(4), &[0, 2]).unwrap();
+    /// assert!(homo.is_injective());
+    /// assert!(!homo.is_surjective());
+    /// let image = homo.image_subalgebra().unwrap();
+    /// assert_eq!(image.cardinality(), 2);
+    /// ```
+    pub fn image_subalgebra(&self) -> Result<Subalgebra<i32>, String> {
+        Subalgebra::new_safe(
+            format!("image of {}", self.domain.name()),
+            self.range.clone_box(),
+            self.image(),
+        )
+    }
+    
+    /// Compose this homomorphism with another applied after it.
+    /// 
+    /// The result maps `x` to `other(self(x))`, from the domain of this
+    /// homomorphism to the range of `other`.
+    /// 
+    /// # Arguments
+    /// * `other` - The homomorphism applied second; its domain must be the
+    ///   range of this one
+    /// 
+    /// # Returns
+    /// * `Ok(Homomorphism)` - The composite
+    /// * `Err(String)` - If the range of this homomorphism and the domain of
+    ///   `other` have different sizes
+    /// 
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::{Homomorphism, SmallAlgebra, BasicAlgebra};
+    /// use std::collections::HashSet;
+    /// 
+    /// let set = |n: i32| Box::new(BasicAlgebra::new(format!("S{}", n), (0..n).collect::<HashSet<i32>>(), Vec::new()))
+    ///     as Box<dyn SmallAlgebra<UniverseItem = i32>>;
+    /// let f = Homomorphism::from_images(set(3), set(2), &[0, 1, 1]).unwrap();
+    /// let g = Homomorphism::from_images(set(2), set(2), &[1, 0]).unwrap();
+    /// let gf = f.compose(&g).unwrap();
+    /// assert_eq!(gf.get_domain().name(), "S3");
+    /// assert_eq!(gf.get_map()[&0], 1);
+    /// assert_eq!(gf.get_map()[&2], 0);
+    /// assert!(g.compose(&f).is_err());
+    /// ```
+    pub fn compose(&self, other: &Homomorphism) -> Result<Homomorphism, String> {
+        if self.range.cardinality() != other.domain.cardinality() {
+            return Err(format!(
+                "Cannot compose: the range of the first homomorphism has {} elements but the domain of the second has {}",
+                self.range.cardinality(),
+                other.domain.cardinality()
+            ));
+        }
+        let mut map = HashMap::new();
+        for (&x, &y) in &self.map {
+            let z = *other.map.get(&y).ok_or_else(|| format!("Element {} is not mapped", y))?;
+            map.insert(x, z);
+        }
+        Homomorphism::new_safe(self.domain.clone_box(), other.range.clone_box(), map)
+    }
+    
+    /// The natural map from an algebra onto its quotient by a congruence.
+    /// 
+    /// The range is the quotient `A/θ` as a basic algebra whose element `j`
+    /// is the `j`-th block of `θ`, ordered by least element.
+    /// 
+    /// # Arguments
+    /// * `algebra` - The algebra `A`
+    /// * `theta` - A congruence `θ` of `A`
+    /// 
+    /// # Returns
+    /// * `Ok(Homomorphism)` - The projection `A -> A/θ`
+    /// * `Err(String)` - If `theta` is not a congruence of `algebra`
+    /// 
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::{Homomorphism, SmallAlgebra, BasicAlgebra, Partition, Algebra};
+    /// use uacalc::alg::op::{OperationSymbol, operations};
+    /// use std::collections::HashSet;
+    /// 
+    /// let s = OperationSymbol::new("s", 1, false);
+    /// let z4 = Box::new(BasicAlgebra::new("Z4".to_string(), (0..4).collect::<HashSet<i32>>(),
+    ///     vec![operations::make_int_operation(s, 4, vec![1, 2, 3, 0]).unwrap()]))
+    ///     as Box<dyn SmallAlgebra<UniverseItem = i32>>;
+    /// let theta = Partition::from_string("|0 2|1 3|").unwrap();
+    /// let pi = Homomorphism::canonical_projection(z4.clone_box(), &theta).unwrap();
+    /// assert_eq!(pi.get_range().cardinality(), 2);
+    /// assert_eq!(pi.kernel().unwrap(), theta);
+    /// 
+    /// let not_congruence = Partition::from_string("|0 1|2|3|").unwrap();
+    /// assert!(Homomorphism::canonical_projection(z4, &not_congruence).is_err());
+    /// ```
+    pub fn canonical_projection(
+        algebra: Box<dyn SmallAlgebra<UniverseItem = i32>>,
+        theta: &Partition,
+    ) -> Result<Homomorphism, String> {
+        let quotient = Self::quotient_algebra(algebra.as_ref(), theta)?;
+        let images = (0..theta.universe_size())
+            .map(|i| theta.block_index(i).map(|j| j as i32))
+            .collect::<Result<Vec<i32>, String>>()?;
+        Homomorphism::from_images(algebra, Box::new(quotient), &images)
+    }
+    
+    /// The inclusion of a subalgebra into its super algebra.
+    /// 
+    /// # Arguments
+    /// * `subalgebra` - The subalgebra
+    /// 
+    /// # Returns
+    /// * `Ok(Homomorphism)` - The embedding mapping element `k` of the
+    ///   subalgebra to its index in the super algebra
+    /// * `Err(String)` - If the homomorphism cannot be created
+    pub fn inclusion(subalgebra: &Subalgebra<i32>) -> Result<Homomorphism, String> {
+        let map = subalgebra.get_subuniverse_array().iter()
+            .enumerate()
+            .map(|(k, &e)| (k, e as usize))
+            .collect();
+        Homomorphism::new_safe(Box::new(subalgebra.clone()), subalgebra.super_algebra().clone_box(), map)
+    }
+    
+    /// The isomorphism `A/ker(h) -> h(A)` given by the first isomorphism theorem.
+    /// 
+    /// # Arguments
+    /// * `h` - A homomorphism `A -> B`
+    /// 
+    /// # Returns
+    /// * `Ok(Homomorphism)` - The isomorphism from the quotient of the domain
+    ///   by the kernel (as in [`canonical_projection`](Self::canonical_projection))
+    ///   onto the image subalgebra of the range
+    /// * `Err(String)` - If `h` does not respect the operations
+    /// 
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::{Homomorphism, SmallAlgebra, BasicAlgebra, Algebra};
+    /// use uacalc::alg::op::{OperationSymbol, operations};
+    /// use std::collections::HashSet;
+    /// 
+    /// let s = OperationSymbol::new("s", 1, false);
+    /// let z = |n: i32| Box::new(BasicAlgebra::new(format!("Z{}", n), (0..n).collect::<HashSet<i32>>(),
+    ///     vec![operations::make_int_operation(s.clone(), n, (0..n).map(|k| (k + 1) % n).collect()).unwrap()]))
+    ///     as Box<dyn SmallAlgebra<UniverseItem = i32>>;
+    /// let h = Homomorphism::from_images(z(4), z(2), &[0, 1, 0, 1]).unwrap();
+    /// let iso = Homomorphism::first_isomorphism_theorem(&h).unwrap();
+    /// assert_eq!(iso.get_domain().cardinality(), 2);
+    /// assert!(iso.is_injective() && iso.is_surjective());
+    /// 
+    /// // A map built without checking, whose kernel |0 1|2 3| is no congruence.
+    /// let map = [(0, 0), (1, 0), (2, 1), (3, 1)].into_iter().collect();
+    /// let not_homo = Homomorphism::new_safe(z(4), z(2), map).unwrap();
+    /// assert!(Homomorphism::first_isomorphism_theorem(&not_homo).is_err());
+    /// ```
+    pub fn first_isomorphism_theorem(h: &Homomorphism) -> Result<Homomorphism, String> {
+        let kernel = h.kernel()?;
+        let quotient = Self::quotient_algebra(h.domain.as_ref(), &kernel)?;
+        let image = h.image_subalgebra()?;
+        let mut images = vec![0; kernel.number_of_blocks()];
+        for (&x, &y) in &h.map {
+            let k = image.index(y as i32).ok_or_else(|| format!("Element {} is not in the image", y))?;
+            images[kernel.block_index(x)?] = k as i32;
+        }
+        Homomorphism::from_images(Box::new(quotient), Box::new(image), &images)
+    }
+    
+    /// The quotient of `algebra` by `theta` as a basic algebra on the block indices.
+    fn quotient_algebra(
+        algebra: &dyn SmallAlgebra<UniverseItem = i32>,
+        theta: &Partition,
+    ) -> Result<BasicAlgebra<i32>, String> {
+        let cg = crate::alg::conlat::CongruenceLattice::new(algebra.clone_box()).cg_partition(theta);
+        if cg != *theta {
+            return Err(format!("{} is not a congruence of {}", theta, algebra.name()));
+        }
+        let mut quotient = crate::alg::residual::quotient(algebra, theta)?;
+        quotient.set_name(format!("{}/{}", algebra.name(), theta));
+        Ok(quotient)
+    }
+    
     /// Create a product homomorphism from a list of homomorphisms.
     /// 
     /// This static method creates a list of IntArray elements representing
@@ -506,6 +800,75 @@ impl Homomorphism {
         Ok(ans)
     }
     
+    /// Construct the image of the map `A -> B_0 x ... x B_{n-1}` induced by
+    /// homomorphisms `A -> B_k` with a common domain, checking that it is a
+    /// subdirect product: that it projects onto every `B_k`.
+    /// 
+    /// The image is isomorphic to `A` modulo the meet of the kernels, so it
+    /// is a subdirect representation of `A` itself when those kernels meet
+    /// in zero.
+    /// 
+    /// # Arguments
+    /// * `homomorphisms` - Homomorphisms with the same domain
+    /// 
+    /// # Returns
+    /// * `Ok(SubProductAlgebra)` - The image, whose elements are the tuples
+    ///   `(h_0(a), ..., h_{n-1}(a))`
+    /// * `Err(String)` - If the list is empty, the domains differ, a map is
+    ///   not a homomorphism or one is not onto its range
+    /// 
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::{Homomorphism, BasicAlgebra, SmallAlgebra, Algebra};
+    /// use uacalc::alg::op::{OperationSymbol, operations};
+    /// use std::collections::{HashMap, HashSet};
+    /// 
+    /// let zn = |n: i32| {
+    ///     let plus = OperationSymbol::new("+", 2, false);
+    ///     let table = (0..n * n).map(|k| (k % n + k / n) % n).collect();
+    ///     Box::new(BasicAlgebra::new(
+    ///         format!("Z{}", n),
+    ///         (0..n).collect::<HashSet<i32>>(),
+    ///         vec![operations::make_int_operation(plus, n, table).unwrap()]
+    ///     )) as Box<dyn SmallAlgebra<UniverseItem = i32>>
+    /// };
+    /// 
+    /// // Z6 is a subdirect product of Z2 and Z3 through reduction mod 2 and 3.
+    /// let mod_k = |k: usize| (0..6).map(|a| (a, a % k)).collect::<HashMap<usize, usize>>();
+    /// let h2 = Homomorphism::new_safe(zn(6), zn(2), mod_k(2)).unwrap();
+    /// let h3 = Homomorphism::new_safe(zn(6), zn(3), mod_k(3)).unwrap();
+    /// let sub = Homomorphism::subdirect_product(&[h2, h3]).unwrap();
+    /// assert_eq!(sub.cardinality(), 6);
+    /// assert!(Homomorphism::subdirect_product(&[]).is_err());
+    /// ```
+    pub fn subdirect_product(homomorphisms: &[Self]) -> Result<SubProductAlgebra<i32>, String> {
+        let tuples = Self::product_homo(homomorphisms)?;
+        let domain_size = homomorphisms[0].domain.cardinality();
+        if let Some(k) = homomorphisms.iter().position(|h| h.domain.cardinality() != domain_size) {
+            return Err(format!("Homomorphism {} does not have the same domain as homomorphism 0", k));
+        }
+        if let Some(k) = homomorphisms.iter().position(|h| !h.is_surjective()) {
+            return Err(format!(
+                "Homomorphism {} is not onto {}, so the product is not subdirect",
+                k,
+                homomorphisms[k].range.name()
+            ));
+        }
+        
+        let mut seen = HashSet::new();
+        let image: Vec<crate::util::int_array::IntArray> =
+            tuples.into_iter().filter(|t| seen.insert(t.clone())).collect();
+        let ranges = homomorphisms.iter().map(|h| h.range.clone_box()).collect();
+        let name = format!("{}_subdirect", homomorphisms[0].domain.name());
+        let prod = BigProductAlgebra::new_with_name_safe(name.clone(), ranges)?;
+        let sub = SubProductAlgebra::new_safe(name, prod, image.clone(), false)?;
+        // The image of a homomorphism is closed under the operations.
+        if sub.cardinality() as usize != image.len() {
+            return Err("The maps are not all homomorphisms: their image is not a subuniverse".to_string());
+        }
+        Ok(sub)
+    }
+    
     /// Get the domain algebra.
     /// 
     /// # Returns
@@ -875,6 +1238,10 @@ impl Algebra for MatrixPowerAlgebra {
         self.power_algebra.cardinality()
     }
     
+    fn exact_cardinality(&self) -> Cardinality {
+        self.power_algebra.exact_cardinality()
+    }
+    
     fn input_size(&self) -> i32 {
         self.power_algebra.input_size()
     }
@@ -1263,6 +1630,22 @@ impl std::fmt::Display for ParameterizedAlgebra {
 /// assert_eq!(power.cardinality(), 8); // 2^3 = 8
 /// assert_eq!(power.get_power(), 3);
 /// ```
+///
+/// Powers too big for an `i32` still have an exact cardinality:
+/// ```
+/// use uacalc::alg::{PowerAlgebra, SmallAlgebra, BasicAlgebra, Algebra, Cardinality};
+/// use std::collections::HashSet;
+///
+/// let alg = Box::new(BasicAlgebra::new(
+///     "A".to_string(),
+///     HashSet::from([0, 1, 2]),
+///     Vec::new()
+/// )) as Box<dyn SmallAlgebra<UniverseItem = i32>>;
+///
+/// let power = PowerAlgebra::new_safe(alg, 20).unwrap();
+/// assert_eq!(power.cardinality(), -1);
+/// assert_eq!(power.exact_cardinality(), Cardinality::Finite(3486784401));
+/// ```
 pub struct PowerAlgebra {
     /// The underlying product algebra
     product: ProductAlgebra,
@@ -1475,6 +1858,50 @@ impl PowerAlgebra {
         self.root_size
     }
     
+    /// Encode a tuple of root elements as an element of the power.
+    /// 
+    /// # Arguments
+    /// * `tuple` - `power` elements of the root algebra
+    /// 
+    /// # Returns
+    /// * `Ok(i32)` - The element of the power
+    /// * `Err(String)` - If the length is wrong or a coordinate is out of range
+    pub fn encode(&self, tuple: &[i32]) -> Result<i32, String> {
+        self.product.encode(tuple)
+    }
+    
+    /// Decode an element of the power into its tuple of root elements.
+    /// 
+    /// # Arguments
+    /// * `element` - The element of the power
+    /// 
+    /// # Returns
+    /// * `Ok(Vec<i32>)` - `power` elements of the root algebra
+    /// * `Err(String)` - If `element` is out of range
+    pub fn decode(&self, element: i32) -> Result<Vec<i32>, String> {
+        self.product.decode(element)
+    }
+    
+    /// Iterate over the elements as tuples of root elements, without
+    /// materializing the universe; see [`ProductAlgebra::elements`].
+    pub fn elements(&self) -> product_algebra::ProductElements {
+        self.product.elements()
+    }
+    
+    /// Draw random elements, as tuples of root elements, uniformly and
+    /// independently; see [`ProductAlgebra::sample`].
+    /// 
+    /// # Arguments
+    /// * `n` - The number of elements to draw
+    /// * `seed` - The seed of the random number generator
+    /// 
+    /// # Returns
+    /// * `Ok(Vec<Vec<i32>>)` - The elements drawn
+    /// * `Err(String)` - If the root algebra is empty
+    pub fn sample(&self, n: usize, seed: u64) -> Result<Vec<Vec<i32>>, String> {
+        self.product.sample(n, seed)
+    }
+    
     /// Add operations to the power algebra.
     /// 
     /// # Arguments
@@ -1568,6 +1995,10 @@ impl Algebra for PowerAlgebra {
         self.product.cardinality()
     }
     
+    fn exact_cardinality(&self) -> Cardinality {
+        self.root.exact_cardinality().pow(&Cardinality::from(self.power))
+    }
+    
     fn input_size(&self) -> i32 {
         self.product.input_size()
     }
@@ -1658,6 +2089,10 @@ impl SmallAlgebra for PowerAlgebra {
         self.product.get_operations_ref()
     }
     
+    fn operations_arc(&self) -> Vec<std::sync::Arc<dyn Operation>> {
+        self.product.operations_arc()
+    }
+    
     fn clone_box(&self) -> Box<dyn SmallAlgebra<UniverseItem = Self::UniverseItem>> {
         Box::new(self.clone())
     }
@@ -2085,9 +2520,12 @@ impl Algebra for ReductAlgebra {
     }
     
     fn constant_operations(&self) -> Vec<Box<dyn Operation>> {
-        // Since we can't easily clone operations, return empty vec for now
-        // In practice, we'd need to use Arc<dyn Operation> or similar
-        Vec::new()
+        // Shared with the algebra, like the other operations.
+        self.operations
+            .iter()
+            .filter(|op| op.arity() == 0)
+            .map(|op| crate::alg::op::operation::boxed_arc_op(Arc::clone(op)))
+            .collect()
     }
     
     fn is_idempotent(&self) -> bool {
@@ -2143,6 +2581,10 @@ impl SmallAlgebra for ReductAlgebra {
         self.operations.iter().map(|op| op.as_ref()).collect()
     }
     
+    fn operations_arc(&self) -> Vec<std::sync::Arc<dyn Operation>> {
+        self.operations.clone()
+    }
+    
     fn clone_box(&self) -> Box<dyn SmallAlgebra<UniverseItem = Self::UniverseItem>> {
         // We can't clone trait objects, so we'll create a new one
         // This is a limitation of the current design
@@ -3084,6 +3526,10 @@ impl SmallAlgebra for QuotientAlgebraWrapper {
         self.inner.get_operations_ref()
     }
     
+    fn operations_arc(&self) -> Vec<std::sync::Arc<dyn Operation>> {
+        self.inner.operations_arc()
+    }
+    
     fn clone_box(&self) -> Box<dyn SmallAlgebra<UniverseItem = i32>> {
         Box::new(QuotientAlgebraWrapper { inner: self.inner.clone() })
     }
@@ -3279,6 +3725,7 @@ pub use malcev::{
     semilattice_term, difference_term, jonsson_terms,
     hagemann_mitschke_terms, gumm_terms, join_term,
     sd_meet_terms, sd_terms, markovic_mckenzie_siggers_taylor_term,
+    variety_is_sd_meet, SdMeetAnalysis, omitted_types, OmissionConditions, TypeOmission,
     weak_3_edge_term, is_congruence_dist_idempotent,
     is_congruence_modular_idempotent, congruence_modular_variety,
     jonsson_level, local_distributivity_level, day_quadruple,
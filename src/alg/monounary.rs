@@ -0,0 +1,344 @@
+/*! Monounary algebras: a set with a single unary operation `f`.
+
+The graph of `f` splits into connected components, each with exactly one
+cycle and trees hanging into it. The invariants of this picture, namely
+cycle lengths, the depth of each element above its cycle and the
+components, are computed directly from the map.
+
+Congruences are also computed directly. For a unary operation the
+equivalence relation generated by a set of pairs closed under `f` is a
+congruence, so the principal congruence `Cg(a, b)` is the equivalence
+generated by the pairs `(f^k(a), f^k(b))`, `k ≥ 0`. Every congruence is a
+join of principal ones, so `Con(A)` is the join closure of the principal
+congruences, with no closure under polynomials needed.
+*/
+
+use std::collections::{HashSet, VecDeque};
+
+use crate::alg::conlat::partition::Partition;
+use crate::alg::SmallAlgebra;
+use crate::terms::compiled_term::operation_table;
+
+/// A monounary algebra on `{0, ..., n-1}` with its cycle structure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Monounary {
+    map: Vec<usize>,
+    /// The depth of each element: the least `k` with `f^k(x)` on a cycle.
+    depths: Vec<usize>,
+    /// The component of each element, numbered by least element.
+    components: Vec<usize>,
+}
+
+impl Monounary {
+    /// Create a monounary algebra from its operation.
+    ///
+    /// # Arguments
+    /// * `map` - The values `f(0), ..., f(n-1)`
+    ///
+    /// # Returns
+    /// * `Ok(Monounary)` - The algebra
+    /// * `Err(String)` - If `map` is empty or has a value outside `0..n`
+    ///
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::monounary::Monounary;
+    ///
+    /// // A 3-cycle 0 -> 1 -> 2 -> 0 with the tail 4 -> 3 -> 0.
+    /// let a = Monounary::new(vec![1, 2, 0, 0, 3]).unwrap();
+    /// assert_eq!(a.cycle_lengths(), vec![3]);
+    /// assert_eq!(a.height(), 2);
+    /// assert!(Monounary::new(vec![1, 5]).is_err());
+    /// ```
+    pub fn new(map: Vec<i32>) -> Result<Self, String> {
+        let n = map.len();
+        if n == 0 {
+            return Err("A monounary algebra must have at least one element".to_string());
+        }
+        if let Some(&x) = map.iter().find(|&&x| x < 0 || x as usize >= n) {
+            return Err(format!("Value {} is outside 0..{}", x, n));
+        }
+        let map: Vec<usize> = map.into_iter().map(|x| x as usize).collect();
+
+        // Peel off elements with no preimage; what remains are the cycles.
+        let mut in_degree = vec![0usize; n];
+        for &y in &map {
+            in_degree[y] += 1;
+        }
+        let mut on_cycle = vec![true; n];
+        let mut leaves: Vec<usize> = (0..n).filter(|&x| in_degree[x] == 0).collect();
+        while let Some(x) = leaves.pop() {
+            on_cycle[x] = false;
+            in_degree[map[x]] -= 1;
+            if in_degree[map[x]] == 0 {
+                leaves.push(map[x]);
+            }
+        }
+
+        // Depths by a breadth first search up the preimages from the cycles.
+        let mut preimages = vec![Vec::new(); n];
+        for (x, &y) in map.iter().enumerate() {
+            if !on_cycle[x] {
+                preimages[y].push(x);
+            }
+        }
+        let mut depths = vec![0; n];
+        let mut queue: VecDeque<usize> = (0..n).filter(|&x| on_cycle[x]).collect();
+        while let Some(y) = queue.pop_front() {
+            for &x in &preimages[y] {
+                depths[x] = depths[y] + 1;
+                queue.push_back(x);
+            }
+        }
+
+        let mut components = vec![usize::MAX; n];
+        for start in 0..n {
+            if components[start] != usize::MAX {
+                continue;
+            }
+            // The component of start is everything above the cycle it reaches.
+            let mut x = start;
+            while !on_cycle[x] {
+                x = map[x];
+            }
+            let mut stack = Vec::new();
+            let mut y = x;
+            loop {
+                stack.push(y);
+                y = map[y];
+                if y == x {
+                    break;
+                }
+            }
+            let mut members = Vec::new();
+            while let Some(y) = stack.pop() {
+                members.push(y);
+                stack.extend(preimages[y].iter().copied());
+            }
+            let least = *members.iter().min().expect("a component is not empty");
+            for y in members {
+                components[y] = least;
+            }
+        }
+        Ok(Monounary { map, depths, components })
+    }
+
+    /// Recognize a monounary algebra: one whose only operation is unary.
+    ///
+    /// # Arguments
+    /// * `alg` - The algebra
+    ///
+    /// # Returns
+    /// * `Ok(Monounary)` - The algebra with its operation
+    /// * `Err(String)` - If the algebra is not monounary
+    pub fn from_algebra(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<Self, String> {
+        let ops = alg.get_operations_ref();
+        match ops.as_slice() {
+            [op] if op.arity() == 1 => Self::new(operation_table(*op)?.to_vec()),
+            _ => Err(format!("Algebra {} is not monounary", alg.name())),
+        }
+    }
+
+    /// Whether the algebra has a single operation, of arity 1.
+    pub fn is_monounary(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> bool {
+        let ops = alg.get_operations_ref();
+        ops.len() == 1 && ops[0].arity() == 1
+    }
+
+    /// The number of elements.
+    pub fn cardinality(&self) -> usize {
+        self.map.len()
+    }
+
+    /// The value `f(x)`.
+    pub fn apply(&self, x: usize) -> usize {
+        self.map[x]
+    }
+
+    /// The depth of each element: the least `k` such that `f^k(x)` lies on
+    /// a cycle.
+    pub fn depths(&self) -> &[usize] {
+        &self.depths
+    }
+
+    /// The largest depth, the height of the tallest tree.
+    pub fn height(&self) -> usize {
+        self.depths.iter().copied().max().unwrap_or(0)
+    }
+
+    /// The cycles, each starting at its least element and following `f`,
+    /// ordered by least element.
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        let mut cycles = Vec::new();
+        let mut seen = vec![false; self.map.len()];
+        for x in 0..self.map.len() {
+            if self.depths[x] > 0 || seen[x] {
+                continue;
+            }
+            let mut cycle = vec![x];
+            seen[x] = true;
+            let mut y = self.map[x];
+            while y != x {
+                seen[y] = true;
+                cycle.push(y);
+                y = self.map[y];
+            }
+            cycles.push(cycle);
+        }
+        cycles
+    }
+
+    /// The lengths of the cycles, ordered by least element.
+    pub fn cycle_lengths(&self) -> Vec<usize> {
+        self.cycles().iter().map(|c| c.len()).collect()
+    }
+
+    /// The connected components, each sorted, ordered by least element.
+    /// Each component contains exactly one cycle.
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let mut blocks: Vec<Vec<usize>> = Vec::new();
+        let mut index = vec![usize::MAX; self.map.len()];
+        for (x, &c) in self.components.iter().enumerate() {
+            if index[c] == usize::MAX {
+                index[c] = blocks.len();
+                blocks.push(Vec::new());
+            }
+            blocks[index[c]].push(x);
+        }
+        blocks
+    }
+
+    /// The partition into connected components; it is a congruence.
+    pub fn component_partition(&self) -> Partition {
+        Partition::from_block_index_vector(&self.components).expect("the algebra is not empty")
+    }
+
+    /// Whether a partition of the universe is a congruence.
+    pub fn is_congruence(&self, par: &Partition) -> bool {
+        par.universe_size() == self.map.len()
+            && (0..self.map.len()).all(|x| par.is_related(self.map[x], self.map[par.representative(x)]))
+    }
+
+    /// The principal congruence `Cg(a, b)`, the equivalence relation
+    /// generated by the pairs `(f^k(a), f^k(b))`.
+    ///
+    /// # Arguments
+    /// * `a` - An element
+    /// * `b` - An element
+    ///
+    /// # Returns
+    /// * `Ok(Partition)` - The least congruence relating `a` and `b`
+    /// * `Err(String)` - If `a` or `b` is outside the universe
+    ///
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::monounary::Monounary;
+    ///
+    /// // Collapsing the two leaves of 2 -> 0 <- 1 and 0 -> 0 identifies them only.
+    /// let a = Monounary::new(vec![0, 0, 0]).unwrap();
+    /// assert_eq!(a.principal_congruence(1, 2).unwrap().number_of_blocks(), 2);
+    /// ```
+    pub fn principal_congruence(&self, a: usize, b: usize) -> Result<Partition, String> {
+        let n = self.map.len();
+        if a >= n || b >= n {
+            return Err(format!("Elements {} and {} must be less than {}", a, b, n));
+        }
+        let mut parent: Vec<usize> = (0..n).collect();
+        fn root(parent: &mut [usize], mut x: usize) -> usize {
+            while parent[x] != x {
+                parent[x] = parent[parent[x]];
+                x = parent[x];
+            }
+            x
+        }
+        let mut seen = HashSet::new();
+        let (mut x, mut y) = (a, b);
+        while x != y && seen.insert((x, y)) {
+            let (rx, ry) = (root(&mut parent, x), root(&mut parent, y));
+            parent[rx.max(ry)] = rx.min(ry);
+            x = self.map[x];
+            y = self.map[y];
+        }
+        let blocks: Vec<usize> = (0..n).map(|x| root(&mut parent, x)).collect();
+        Partition::from_block_index_vector(&blocks)
+    }
+
+    /// All the congruences, as the join closure of the principal ones.
+    ///
+    /// The computation checks the progress token installed on the current
+    /// thread for cancellation.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Partition>)` - The congruences, in the order of [`Partition`]:
+    ///   by number of blocks, from the zero congruence up
+    /// * `Err(String)` - If the computation is cancelled
+    ///
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::monounary::Monounary;
+    ///
+    /// // A 2-cycle: only the zero and the one congruence.
+    /// let a = Monounary::new(vec![1, 0]).unwrap();
+    /// assert_eq!(a.congruences().unwrap().len(), 2);
+    /// ```
+    pub fn congruences(&self) -> Result<Vec<Partition>, String> {
+        let n = self.map.len();
+        let mut principals = Vec::new();
+        let mut distinct = HashSet::new();
+        for a in 0..n {
+            for b in a + 1..n {
+                let cg = self.principal_congruence(a, b)?;
+                if distinct.insert(cg.clone()) {
+                    principals.push(cg);
+                }
+            }
+        }
+        let zero = Partition::zero(n);
+        let mut all: HashSet<Partition> = HashSet::from([zero.clone()]);
+        let mut queue = VecDeque::from([zero]);
+        while let Some(theta) = queue.pop_front() {
+            crate::progress::check_cancelled()?;
+            for p in &principals {
+                let join = theta.join(p)?;
+                if all.insert(join.clone()) {
+                    queue.push_back(join);
+                }
+            }
+        }
+        let mut all: Vec<Partition> = all.into_iter().collect();
+        all.sort();
+        Ok(all)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use crate::alg::conlat::CongruenceLattice;
+    use crate::alg::op::{operations, OperationSymbol};
+    use crate::alg::BasicAlgebra;
+
+    #[test]
+    fn test_monounary_matches_general_conlat() {
+        for map in [vec![1, 2, 0, 0, 3], vec![0, 0, 1, 1, 4, 4], vec![1, 0, 3, 4, 2, 5, 5], vec![0; 5]] {
+            let n = map.len() as i32;
+            let op = operations::make_int_operation(OperationSymbol::new("f", 1, false), n, map.clone()).unwrap();
+            let alg = BasicAlgebra::new("A".to_string(), (0..n).collect::<HashSet<i32>>(), vec![op]);
+            let mono = Monounary::from_algebra(&alg).unwrap();
+            let fast = mono.congruences().unwrap();
+            assert!(fast.iter().all(|p| mono.is_congruence(p)));
+            assert!(mono.is_congruence(&mono.component_partition()));
+
+            let mut con = CongruenceLattice::new(Box::new(alg) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+            let mut general = con.try_universe().unwrap().clone();
+            general.sort();
+            assert_eq!(fast, general, "congruences of {:?}", map);
+        }
+
+        let a = Monounary::new(vec![1, 0, 3, 4, 2, 5, 5]).unwrap();
+        assert_eq!(a.cycles(), vec![vec![0, 1], vec![2, 3, 4], vec![5]]);
+        assert_eq!(a.components(), vec![vec![0, 1], vec![2, 3, 4], vec![5, 6]]);
+        assert_eq!(a.depths(), &[0, 0, 0, 0, 0, 0, 1]);
+        assert!(a.principal_congruence(0, 7).is_err());
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::cmp::Ordering;
+use std::sync::OnceLock;
 use crate::alg::op::{Operation, OperationSymbol};
 
 /// IntOperation is a table-based implementation of the Operation trait.
@@ -8,11 +9,17 @@ use crate::alg::op::{Operation, OperationSymbol};
 /// This struct stores the operation results in a precomputed table for fast access.
 /// It's particularly useful for small operations where table lookup is more efficient
 /// than computation.
+///
+/// The table can be edited in place with `set_value` and `update_from_matrix`;
+/// the memoized results of `is_idempotent` and `is_associative` are cleared
+/// by every edit.
 #[derive(Debug, Clone)]
 pub struct IntOperation {
     symbol: OperationSymbol,
     set_size: i32,
     table: Vec<i32>,
+    idempotent: OnceLock<bool>,
+    associative: OnceLock<bool>,
 }
 
 impl IntOperation {
@@ -70,6 +77,8 @@ impl IntOperation {
             symbol,
             set_size,
             table,
+            idempotent: OnceLock::new(),
+            associative: OnceLock::new(),
         })
     }
     
@@ -140,6 +149,105 @@ impl IntOperation {
         Self::new(symbol, constant_value + 1, table)
     }
 
+    /// Set the value of the operation at `args` to `result`.
+    /// 
+    /// # Arguments
+    /// * `args` - The arguments, one for each place
+    /// * `result` - The new value
+    /// 
+    /// # Returns
+    /// * `Ok(())` if successful
+    /// * `Err(String)` if the number of arguments is wrong or an argument or
+    ///   the result is out of range; the table is then unchanged
+    /// 
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::op::{IntOperation, Operation};
+    /// 
+    /// let mut op = IntOperation::binary_and("and").unwrap();
+    /// assert!(op.is_idempotent().unwrap());
+    /// op.set_value(&[1, 1], 0).unwrap();
+    /// assert_eq!(op.int_value_at(&[1, 1]).unwrap(), 0);
+    /// assert!(!op.is_idempotent().unwrap());
+    /// assert!(op.set_value(&[1, 1], 2).is_err());
+    /// ```
+    pub fn set_value(&mut self, args: &[i32], result: i32) -> Result<(), String> {
+        if args.len() != self.arity() as usize {
+            return Err(format!(
+                "Expected {} arguments, got {}",
+                self.arity(),
+                args.len()
+            ));
+        }
+        for &arg in args {
+            if arg < 0 || arg >= self.set_size {
+                return Err(format!("Argument {} is out of bounds [0, {})", arg, self.set_size));
+            }
+        }
+        if result < 0 || result >= self.set_size {
+            return Err(format!("Value {} is out of range [0, {})", result, self.set_size));
+        }
+        let index = self.horner_encode(args) as usize;
+        self.table[index] = result;
+        self.invalidate();
+        Ok(())
+    }
+
+    /// Replace the table of a binary operation by a matrix whose entry
+    /// `[x][y]` is the value at `(x, y)`.
+    /// 
+    /// # Arguments
+    /// * `matrix` - A square matrix of side the set size
+    /// 
+    /// # Returns
+    /// * `Ok(())` if successful
+    /// * `Err(String)` if the operation is not binary, the matrix has the
+    ///   wrong shape or an entry is out of range; the table is then unchanged
+    /// 
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::op::{IntOperation, Operation};
+    /// 
+    /// let mut op = IntOperation::binary_xor("f").unwrap();
+    /// // The left projection.
+    /// op.update_from_matrix(&[vec![0, 0], vec![1, 1]]).unwrap();
+    /// assert_eq!(op.int_value_at(&[1, 0]).unwrap(), 1);
+    /// assert!(op.is_associative().unwrap());
+    /// ```
+    pub fn update_from_matrix(&mut self, matrix: &[Vec<i32>]) -> Result<(), String> {
+        if self.arity() != 2 {
+            return Err(format!("Only binary operations have a matrix, {} has arity {}", self.symbol, self.arity()));
+        }
+        let n = self.set_size as usize;
+        if matrix.len() != n {
+            return Err(format!("Matrix has {} rows but expected {}", matrix.len(), n));
+        }
+        let mut table = vec![0; n * n];
+        for (x, row) in matrix.iter().enumerate() {
+            if row.len() != n {
+                return Err(format!("Row {} has length {} but expected {}", x, row.len(), n));
+            }
+            for (y, &value) in row.iter().enumerate() {
+                if value < 0 || value >= self.set_size {
+                    return Err(format!(
+                        "Value {} at position [{}, {}] is out of range [0, {})",
+                        value, x, y, self.set_size
+                    ));
+                }
+                table[x + n * y] = value;
+            }
+        }
+        self.table = table;
+        self.invalidate();
+        Ok(())
+    }
+
+    /// Clear the memoized properties after an edit of the table.
+    fn invalidate(&mut self) {
+        self.idempotent.take();
+        self.associative.take();
+    }
+
     /// Compute Horner encoding for arguments.
     /// This matches horner_same_size: iterates from last to first argument.
     fn horner_encode(&self, args: &[i32]) -> i32 {
@@ -245,23 +353,32 @@ impl Operation for IntOperation {
     }
 
     fn is_idempotent(&self) -> Result<bool, String> {
+        if let Some(&idempotent) = self.idempotent.get() {
+            return Ok(idempotent);
+        }
         // Check if f(x,x,...,x) = x for all x in the domain
+        let mut idempotent = true;
         for x in 0..self.set_size {
             let args = vec![x; self.arity() as usize];
             if self.int_value_at(&args)? != x {
-                return Ok(false);
+                idempotent = false;
+                break;
             }
         }
-        Ok(true)
+        Ok(*self.idempotent.get_or_init(|| idempotent))
     }
 
     fn is_associative(&self) -> Result<bool, String> {
         if self.arity() != 2 {
             return Ok(false); // Only binary operations can be associative
         }
+        if let Some(&associative) = self.associative.get() {
+            return Ok(associative);
+        }
         
         // Check if f(f(x,y),z) = f(x,f(y,z)) for all x,y,z
-        for x in 0..self.set_size {
+        let mut associative = true;
+        'outer: for x in 0..self.set_size {
             for y in 0..self.set_size {
                 for z in 0..self.set_size {
                     let xy = self.int_value_at(&[x, y])?;
@@ -270,12 +387,13 @@ impl Operation for IntOperation {
                     let right = self.int_value_at(&[x, yz])?;
                     
                     if left != right {
-                        return Ok(false);
+                        associative = false;
+                        break 'outer;
                     }
                 }
             }
         }
-        Ok(true)
+        Ok(*self.associative.get_or_init(|| associative))
     }
 
     fn is_commutative(&self) -> Result<bool, String> {
@@ -3,7 +3,6 @@ use std::collections::HashMap;
 use std::cmp::Ordering;
 use std::hash::{Hash, Hasher};
 use once_cell::sync::Lazy;
-use num_traits::cast::ToPrimitive;
 
 /// An operation symbol with a name and arity.
 /// 
@@ -258,6 +257,7 @@ pub mod abstract_int_operation;
 pub mod int_operation; 
 pub mod operation_with_default_value;
 pub mod operations;
+pub mod table_format;
 
 // Re-exports
 pub use abstract_operation::AbstractOperation; // This will be the trait
@@ -266,6 +266,7 @@ pub use abstract_int_operation::AbstractIntOperation; // Task 13
 pub use int_operation::IntOperation;
 pub use operation_with_default_value::OperationWithDefaultValue;
 pub use operations as ops; // Re-export operations module
+pub use table_format::TableStyle;
 
 // Tests module
 #[cfg(test)]
@@ -529,18 +530,37 @@ impl SimilarityType {
             return alg_size;
         }
         
-        let mut input_size = num_bigint::BigInt::from(0);
-        let algebra_size = num_bigint::BigInt::from(alg_size as i64);
-        let max_int = num_bigint::BigInt::from(i32::MAX as i64);
-        
-        for sym in &self.operation_symbols {
-            input_size += algebra_size.pow(sym.arity() as u32);
-            if input_size >= max_int {
-                return -1;
-            }
+        match self.exact_input_size(&crate::alg::Cardinality::from_i32(alg_size)).to_i32() {
+            Ok(input_size) if input_size < i32::MAX => input_size,
+            _ => -1,
         }
-        
-        input_size.to_i32().unwrap_or(-1)
+    }
+    
+    /// Calculate the exact input size for this similarity type: the sum of
+    /// `alg_size` raised to the arity of each operation.
+    /// 
+    /// # Arguments
+    /// * `alg_size` - The algebra size
+    /// 
+    /// # Returns
+    /// The input size, or `alg_size` itself if there are no operations
+    /// 
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::Cardinality;
+    /// use uacalc::alg::op::{OperationSymbol, SimilarityType};
+    /// 
+    /// let st = SimilarityType::new(vec![OperationSymbol::new("f", 20, false)]);
+    /// assert_eq!(st.input_size(3), -1);
+    /// assert_eq!(st.exact_input_size(&Cardinality::Finite(3)), Cardinality::Finite(3486784401));
+    /// ```
+    pub fn exact_input_size(&self, alg_size: &crate::alg::Cardinality) -> crate::alg::Cardinality {
+        if self.operation_symbols.is_empty() {
+            return alg_size.clone();
+        }
+        self.operation_symbols.iter().fold(crate::alg::Cardinality::Finite(0), |acc, sym| {
+            acc.plus(&alg_size.pow(&crate::alg::Cardinality::Finite(sym.arity().max(0) as u64)))
+        })
     }
     
     /// Get a map from arity to the number of operations of that arity.
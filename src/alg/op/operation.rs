@@ -1,6 +1,7 @@
 use std::fmt::{Debug, Display};
 use std::sync::Arc;
 use crate::alg::op::OperationSymbol;
+use crate::alg::op::table_format::TableStyle;
 
 /// This trait specifies an operation, that is, a map from 
 /// the direct product of some number (called the arity) of a set
@@ -168,6 +169,19 @@ pub trait Operation: Display + Debug + Send + Sync {
     /// * `Ok(bool)` - `true` if the operation is total
     /// * `Err(String)` - Error message if the check fails
     fn is_total(&self) -> Result<bool, String>;
+
+    /// Format the table of this operation as aligned text, Markdown or
+    /// LaTeX, a Cayley table for binary operations.
+    /// 
+    /// # Arguments
+    /// * `style` - The output format
+    /// 
+    /// # Returns
+    /// * `Ok(String)` - The table, with elements printed as their indices
+    /// * `Err(String)` - Error message if the operation cannot be evaluated
+    fn format_table(&self, style: TableStyle) -> Result<String, String> {
+        crate::alg::op::table_format::format_table(self, style, None)
+    }
 }
 
 /// Helper trait for operations that need cloning.
@@ -3,6 +3,7 @@ use std::hash::{Hash, Hasher};
 use std::cmp::Ordering;
 use crate::alg::op::{Operation, OperationSymbol, BasicOperation};
 use crate::util::horner;
+use crate::util::random::RandomSource;
 
 /// A convenience class for the UI that wraps operations with default value handling.
 /// 
@@ -250,24 +251,16 @@ impl OperationWithDefaultValue {
     }
     
     /// Update the random value table with new random values.
+    ///
+    /// The values are drawn from a source seeded by
+    /// [`crate::util::random::next_seed`], so they are reproducible after
+    /// [`crate::util::random::set_global_seed`].
     pub fn update_random_value_table(&mut self) {
-        use std::collections::hash_map::RandomState;
-        use std::hash::{BuildHasher, Hasher};
-        
         let table_size = Self::calculate_table_size(self.symbol.arity(), self.alg_size);
-        let mut random_table = Vec::with_capacity(table_size);
-        
-        // Use hash-based pseudo-random generation
-        let random_state = RandomState::new();
-        let mut hasher = random_state.build_hasher();
-        
-        for i in 0..table_size {
-            hasher.write_usize(i);
-            let hash = hasher.finish();
-            let value = (hash % self.alg_size as u64) as i32;
-            random_table.push(value);
-        }
-        
+        let mut source = RandomSource::from_global();
+        let random_table = (0..table_size)
+            .map(|_| source.below(self.alg_size as u64) as i32)
+            .collect();
         self.random_value_table = Some(random_table);
     }
     
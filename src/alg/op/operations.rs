@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use crate::alg::op::{Operation, OperationSymbol, IntOperation, SimilarityType};
 use crate::util::horner;
+use crate::util::random::RandomSource;
 use crate::util::array_string as ArrayString;
 
 /// Operations is a factory module with static methods to make and test Operations.
@@ -162,6 +163,231 @@ pub fn equal_values(op0: &dyn Operation, op1: &dyn Operation) -> Result<bool, St
     Ok(find_difference(op0, op1)?.is_none())
 }
 
+/// Test whether an operation depends on its `i`-th argument, i.e., whether
+/// changing only that argument can change the value.
+///
+/// # Arguments
+/// * `op` - The operation
+/// * `i` - The argument index
+///
+/// # Returns
+/// * `Ok(true)` - If the operation depends on argument `i`
+/// * `Ok(false)` - If argument `i` is fictitious
+/// * `Err(String)` - If `i` is not less than the arity
+pub fn depends_on_argument(op: &dyn Operation, i: usize) -> Result<bool, String> {
+    if i >= op.arity() as usize {
+        return Err(format!("Argument index {} out of range for arity {}", i, op.arity()));
+    }
+    let table = crate::terms::compiled_term::operation_table(op)?;
+    Ok(table_depends_on(&table, op.get_set_size() as usize, i))
+}
+
+/// The indices of the arguments an operation depends on, in increasing order.
+pub fn essential_arguments(op: &dyn Operation) -> Result<Vec<usize>, String> {
+    let table = crate::terms::compiled_term::operation_table(op)?;
+    let n = op.get_set_size() as usize;
+    Ok((0..op.arity() as usize).filter(|&i| table_depends_on(&table, n, i)).collect())
+}
+
+/// The number of arguments an operation depends on.
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::op::{OperationSymbol, operations};
+///
+/// // f(x,y,z) = x + z mod 2 ignores y.
+/// let sym = OperationSymbol::new("f", 3, false);
+/// let table = (0..8).map(|k| (k % 2 + k / 4) % 2).collect();
+/// let op = operations::make_int_operation(sym, 2, table).unwrap();
+/// assert_eq!(operations::essential_arity(op.as_ref()).unwrap(), 2);
+/// assert!(!operations::depends_on_argument(op.as_ref(), 1).unwrap());
+/// ```
+pub fn essential_arity(op: &dyn Operation) -> Result<usize, String> {
+    Ok(essential_arguments(op)?.len())
+}
+
+/// Write an operation depending on at most one argument as a unary map.
+///
+/// # Returns
+/// * `Ok(Some((i, f)))` - `op(x_0, ..., x_{k-1}) = f[x_i]` for all arguments;
+///   for a constant operation `i` is 0
+/// * `Ok(None)` - If the operation depends on two or more arguments, or is nullary
+/// * `Err(String)` - If the operation's values cannot be computed
+pub fn essentially_unary_decomposition(op: &dyn Operation) -> Result<Option<(usize, Vec<i32>)>, String> {
+    if op.arity() == 0 {
+        return Ok(None);
+    }
+    let essential = essential_arguments(op)?;
+    if essential.len() > 1 {
+        return Ok(None);
+    }
+    let i = essential.first().copied().unwrap_or(0);
+    let table = crate::terms::compiled_term::operation_table(op)?;
+    let n = op.get_set_size() as usize;
+    let stride = n.pow(i as u32);
+    Ok(Some((i, (0..n).map(|x| table[x * stride]).collect())))
+}
+
+/// The result of [`recognize_structure`]: the laws a binary operation
+/// satisfies, its identity and inverses, and a witness for each law that
+/// fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BinaryStructure {
+    /// `(x, y, z)` with `(x*y)*z != x*(y*z)`
+    pub non_associative: Option<(i32, i32, i32)>,
+    /// `(x, y)` with `x*y != y*x`
+    pub non_commutative: Option<(i32, i32)>,
+    /// `x` with `x*x != x`
+    pub non_idempotent: Option<i32>,
+    /// `(x, y, z)` with `y != z` and `x*y = x*z` or `y*x = z*x`, so the
+    /// table is not a Latin square
+    pub non_latin: Option<(i32, i32, i32)>,
+    /// The two-sided identity, if there is one
+    pub identity: Option<i32>,
+    /// The two-sided inverse of each element, if there is an identity and
+    /// every element has one
+    pub inverses: Option<Vec<i32>>,
+    /// An element without a two-sided inverse, if there is an identity
+    pub non_invertible: Option<i32>,
+}
+
+impl BinaryStructure {
+    /// Associative.
+    pub fn is_semigroup(&self) -> bool {
+        self.non_associative.is_none()
+    }
+
+    /// Associative with an identity.
+    pub fn is_monoid(&self) -> bool {
+        self.is_semigroup() && self.identity.is_some()
+    }
+
+    /// A monoid in which every element has an inverse.
+    pub fn is_group(&self) -> bool {
+        self.is_monoid() && self.inverses.is_some()
+    }
+
+    /// The table is a Latin square, so `x*y = z` is uniquely solvable for
+    /// either `x` or `y`.
+    pub fn is_quasigroup(&self) -> bool {
+        self.non_latin.is_none()
+    }
+
+    /// A quasigroup with an identity.
+    pub fn is_loop(&self) -> bool {
+        self.is_quasigroup() && self.identity.is_some()
+    }
+
+    /// Associative and idempotent.
+    pub fn is_band(&self) -> bool {
+        self.is_semigroup() && self.non_idempotent.is_none()
+    }
+
+    /// A commutative band.
+    pub fn is_semilattice(&self) -> bool {
+        self.is_band() && self.non_commutative.is_none()
+    }
+
+    /// The names of all the structures the operation forms, from
+    /// `"semigroup"`, `"monoid"`, `"group"`, `"quasigroup"`, `"loop"`,
+    /// `"band"`, `"semilattice"` and `"commutative"`.
+    pub fn kinds(&self) -> Vec<&'static str> {
+        [
+            ("semigroup", self.is_semigroup()),
+            ("monoid", self.is_monoid()),
+            ("group", self.is_group()),
+            ("quasigroup", self.is_quasigroup()),
+            ("loop", self.is_loop()),
+            ("band", self.is_band()),
+            ("semilattice", self.is_semilattice()),
+            ("commutative", self.non_commutative.is_none()),
+        ]
+        .into_iter()
+        .filter(|(_, holds)| *holds)
+        .map(|(name, _)| name)
+        .collect()
+    }
+}
+
+/// Classify a binary operation, e.g. an imported multiplication table, as
+/// a semigroup, monoid, group, quasigroup, loop, band or semilattice.
+///
+/// # Arguments
+/// * `op` - A binary operation
+///
+/// # Returns
+/// * `Ok(BinaryStructure)` - The laws that hold, with witnesses for those
+///   that fail
+/// * `Err(String)` - If the operation is not binary or its values cannot
+///   be computed
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::op::{operations, OperationSymbol};
+///
+/// // Z3 under addition
+/// let table: Vec<i32> = (0..9).map(|i| (i % 3 + i / 3) % 3).collect();
+/// let op = operations::make_int_operation(OperationSymbol::new("+", 2, false), 3, table).unwrap();
+/// let structure = operations::recognize_structure(op.as_ref()).unwrap();
+/// assert!(structure.is_group());
+/// assert_eq!(structure.identity, Some(0));
+/// assert_eq!(structure.inverses, Some(vec![0, 2, 1]));
+/// ```
+pub fn recognize_structure(op: &dyn Operation) -> Result<BinaryStructure, String> {
+    if op.arity() != 2 {
+        return Err(format!("The operation {} is not binary", op.symbol()));
+    }
+    let table = crate::terms::compiled_term::operation_table(op)?;
+    let n = op.get_set_size() as usize;
+    let mul = |x: usize, y: usize| table[x + y * n] as usize;
+    let elems = || 0..n;
+    let pairs = || elems().flat_map(move |x| elems().map(move |y| (x, y)));
+
+    let non_associative = pairs()
+        .flat_map(|(x, y)| elems().map(move |z| (x, y, z)))
+        .find(|&(x, y, z)| mul(mul(x, y), z) != mul(x, mul(y, z)));
+    let non_commutative = pairs().find(|&(x, y)| mul(x, y) != mul(y, x));
+    let non_idempotent = elems().find(|&x| mul(x, x) != x);
+    let non_latin = elems().find_map(|x| {
+        let mut left = vec![None; n];
+        let mut right = vec![None; n];
+        elems().find_map(|y| {
+            let l = left[mul(x, y)].replace(y).map(|z| (x, z, y));
+            l.or_else(|| right[mul(y, x)].replace(y).map(|z| (x, z, y)))
+        })
+    });
+    let identity = elems().find(|&e| elems().all(|x| mul(e, x) == x && mul(x, e) == x));
+    let (inverses, non_invertible) = match identity {
+        Some(e) => {
+            let inverse = |x: usize| elems().find(|&y| mul(x, y) == e && mul(y, x) == e);
+            match elems().find(|&x| inverse(x).is_none()) {
+                Some(x) => (None, Some(x as i32)),
+                None => (Some(elems().map(|x| inverse(x).unwrap() as i32).collect()), None),
+            }
+        }
+        None => (None, None),
+    };
+    let to_i32 = |x: usize| x as i32;
+    Ok(BinaryStructure {
+        non_associative: non_associative.map(|(x, y, z)| (to_i32(x), to_i32(y), to_i32(z))),
+        non_commutative: non_commutative.map(|(x, y)| (to_i32(x), to_i32(y))),
+        non_idempotent: non_idempotent.map(to_i32),
+        non_latin: non_latin.map(|(x, y, z)| (to_i32(x), to_i32(y), to_i32(z))),
+        identity: identity.map(to_i32),
+        inverses,
+        non_invertible,
+    })
+}
+
+/// Whether the Horner-encoded table `t` of an operation on an `n` element
+/// set depends on argument `i`.
+pub(crate) fn table_depends_on(t: &[i32], n: usize, i: usize) -> bool {
+    let stride = n.pow(i as u32);
+    (0..t.len())
+        .filter(|c| (c / stride).is_multiple_of(n))
+        .any(|c| (1..n).any(|d| t[c + d * stride] != t[c]))
+}
+
 // =============================================================================
 // Additional Constructors (matching Java public API)
 // =============================================================================
@@ -419,13 +645,12 @@ pub fn make_full_cycle(alg_size: i32) -> Result<Box<dyn Operation>, String> {
 /// # Returns
 /// A new random operation
 ///
-/// Note: This function is not deterministic as it uses a random seed.
-/// For reproducible results, use make_random_operations_with_seed.
+/// The seed is drawn from [`crate::util::random::next_seed`], so the
+/// operation is reproducible only after
+/// [`crate::util::random::set_global_seed`]. For a fixed seed use
+/// make_random_operation_with_seed.
 pub fn make_random_operation(n: i32, op_sym: OperationSymbol) -> Result<Box<dyn Operation>, String> {
-    // Simple deterministic "random" based on operation name hash for now
-    // In Java this uses Random without a seed, which is non-deterministic
-    let seed = op_sym.name().bytes().fold(0u64, |acc, b| acc.wrapping_mul(31).wrapping_add(b as u64));
-    make_random_operation_with_seed(n, op_sym, seed)
+    make_random_operation_with_seed(n, op_sym, crate::util::random::next_seed())
 }
 
 /// Make a random operation with a specific seed.
@@ -437,13 +662,8 @@ pub fn make_random_operation_with_seed(
     let arity = op_sym.arity();
     let table_size = (n as usize).pow(arity as u32);
     
-    // Simple LCG (Linear Congruential Generator) for deterministic randomness
-    let mut rng_state = seed;
-    let mut values = Vec::with_capacity(table_size);
-    for _ in 0..table_size {
-        rng_state = rng_state.wrapping_mul(1103515245).wrapping_add(12345);
-        values.push(((rng_state / 65536) % (n as u64)) as i32);
-    }
+    let mut source = RandomSource::new(seed);
+    let values = (0..table_size).map(|_| source.below(n as u64) as i32).collect();
     
     make_int_operation(op_sym, n, values)
 }
@@ -464,12 +684,15 @@ pub fn make_random_operations(
 }
 
 /// Make a list of random operations with an optional seed.
+///
+/// Without a seed the seed is drawn from
+/// [`crate::util::random::next_seed`].
 pub fn make_random_operations_with_seed(
     n: i32,
     sim_type: &SimilarityType,
     seed: Option<u64>,
 ) -> Result<Vec<Box<dyn Operation>>, String> {
-    let base_seed = seed.unwrap_or(12345);
+    let base_seed = seed.unwrap_or_else(crate::util::random::next_seed);
     
     let op_syms = sim_type.get_operation_symbols();
     let mut ops = Vec::with_capacity(op_syms.len());
@@ -675,6 +898,58 @@ mod tests {
         assert!(diff.is_some());
     }
 
+    #[test]
+    fn test_essential_arity() {
+        // f(x,y,z) = 2z + 1 mod 3 depends only on z.
+        let sym = OperationSymbol::new("f", 3, false);
+        let table = (0..27).map(|k| (2 * (k / 9) + 1) % 3).collect();
+        let op = IntOperation::new(sym, 3, table).unwrap();
+        assert_eq!(essential_arity(&op).unwrap(), 1);
+        assert!(depends_on_argument(&op, 2).unwrap());
+        assert!(!depends_on_argument(&op, 0).unwrap());
+        assert!(depends_on_argument(&op, 3).is_err());
+        assert_eq!(essentially_unary_decomposition(&op).unwrap(), Some((2, vec![1, 0, 2])));
+
+        let xor = IntOperation::binary_xor("xor").unwrap();
+        assert_eq!(essential_arity(&xor).unwrap(), 2);
+        assert_eq!(essentially_unary_decomposition(&xor).unwrap(), None);
+
+        let constant = IntOperation::new(OperationSymbol::new("c", 2, false), 2, vec![1; 4]).unwrap();
+        assert_eq!(essential_arity(&constant).unwrap(), 0);
+        assert_eq!(essentially_unary_decomposition(&constant).unwrap(), Some((0, vec![1, 1])));
+    }
+
+    #[test]
+    fn test_recognize_structure() {
+        let binary = |name: &str, n: i32, f: &dyn Fn(i32, i32) -> i32| {
+            let table = (0..n * n).map(|k| f(k % n, k / n)).collect();
+            IntOperation::new(OperationSymbol::new(name, 2, false), n, table).unwrap()
+        };
+
+        let plus = recognize_structure(&binary("+", 3, &|x, y| (x + y) % 3)).unwrap();
+        assert_eq!(plus.kinds(), vec!["semigroup", "monoid", "group", "quasigroup", "loop", "commutative"]);
+        assert_eq!(plus.inverses, Some(vec![0, 2, 1]));
+        assert_eq!(plus.non_idempotent, Some(1));
+
+        let and = recognize_structure(&IntOperation::binary_and("and").unwrap()).unwrap();
+        assert!(and.is_semilattice() && and.is_monoid() && !and.is_group());
+        assert_eq!(and.identity, Some(1));
+        assert_eq!(and.non_invertible, Some(0));
+        assert_eq!(and.non_latin, Some((0, 0, 1)));
+
+        let left = recognize_structure(&binary("p", 3, &|x, _| x)).unwrap();
+        assert_eq!(left.kinds(), vec!["semigroup", "band"]);
+        assert_eq!(left.non_commutative, Some((0, 1)));
+        assert_eq!(left.identity, None);
+
+        // Subtraction is a quasigroup with only a right identity
+        let minus = recognize_structure(&binary("-", 3, &|x, y| (x + 3 - y) % 3)).unwrap();
+        assert_eq!(minus.kinds(), vec!["quasigroup"]);
+        assert_eq!(minus.non_associative, Some((0, 0, 1)));
+
+        assert!(recognize_structure(ternary_discriminator(2).unwrap().as_ref()).is_err());
+    }
+
     #[test]
     fn test_equal_values() {
         let op1 = IntOperation::binary_xor("xor1").unwrap();
@@ -327,4 +327,24 @@ mod tests {
         // (though they might return errors since compute_value fails)
         let _ = op.is_associative(); // May succeed or fail depending on implementation
     }
+
+    #[test]
+    fn test_int_operation_editing() {
+        let mut op = IntOperation::binary_and("and").unwrap();
+        assert!(op.is_associative().unwrap());
+        // x * y = x and not y is no longer associative.
+        op.update_from_matrix(&[vec![0, 0], vec![1, 0]]).unwrap();
+        assert_eq!(op.int_value_at(&[1, 0]).unwrap(), 1);
+        assert!(!op.is_associative().unwrap());
+        op.set_value(&[1, 0], 0).unwrap();
+        assert!(op.is_associative().unwrap());
+
+        // Rejected edits leave the table alone.
+        assert!(op.set_value(&[0], 1).is_err());
+        assert!(op.set_value(&[0, 2], 1).is_err());
+        assert!(op.update_from_matrix(&[vec![0, 0], vec![0, 3]]).is_err());
+        assert!(op.update_from_matrix(&[vec![0, 0]]).is_err());
+        assert_eq!(op.get_table().unwrap(), &[0, 0, 0, 0]);
+        assert!(IntOperation::unary_not("not").unwrap().update_from_matrix(&[vec![1], vec![0]]).is_err());
+    }
 }
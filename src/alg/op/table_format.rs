@@ -0,0 +1,259 @@
+/*! Formatting operation tables as text, Markdown and LaTeX.
+
+Binary operations are shown as Cayley tables, with the first argument
+labelling the rows and the second the columns. Operations of any other
+arity are shown as one row per argument tuple, in lexicographic order,
+followed by the value. Elements are printed as their indices unless labels
+are given.
+*/
+
+use std::fmt;
+use crate::alg::op::Operation;
+use crate::util::horner;
+
+/// The output format of an operation table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TableStyle {
+    /// Plain text with aligned columns.
+    #[default]
+    Text,
+    /// A GitHub-flavoured Markdown table.
+    Markdown,
+    /// A LaTeX `tabular` environment.
+    Latex,
+}
+
+impl TableStyle {
+    /// Parse a style name: `"text"`, `"markdown"` or `"latex"`.
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "text" => Ok(TableStyle::Text),
+            "markdown" => Ok(TableStyle::Markdown),
+            "latex" => Ok(TableStyle::Latex),
+            _ => Err(format!("Unknown table style: {}", name)),
+        }
+    }
+}
+
+impl fmt::Display for TableStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            TableStyle::Text => "text",
+            TableStyle::Markdown => "markdown",
+            TableStyle::Latex => "latex",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Format the table of an operation.
+///
+/// # Arguments
+/// * `op` - The operation
+/// * `style` - The output format
+/// * `labels` - Names for the elements `0, ..., n-1`, or `None` to print
+///   the indices
+///
+/// # Returns
+/// * `Ok(String)` - The table, ending in a newline
+/// * `Err(String)` - If there are not `n` labels or the operation cannot be
+///   evaluated
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::op::{Operation, OperationSymbol, operations};
+/// use uacalc::alg::op::table_format::{format_table, TableStyle};
+///
+/// let op = operations::make_int_operation(OperationSymbol::new("+", 2, false), 2, vec![0, 1, 1, 0]).unwrap();
+/// assert_eq!(op.format_table(TableStyle::Text).unwrap(), "+ | 0 1\n--+----\n0 | 0 1\n1 | 1 0\n");
+/// let labels = ["a".to_string(), "b".to_string()];
+/// let md = format_table(op.as_ref(), TableStyle::Markdown, Some(&labels)).unwrap();
+/// assert!(md.contains("| b | b | a |"));
+/// ```
+pub fn format_table<O: Operation + ?Sized>(
+    op: &O,
+    style: TableStyle,
+    labels: Option<&[String]>,
+) -> Result<String, String> {
+    let n = op.get_set_size();
+    let labels: Vec<String> = match labels {
+        Some(labels) if labels.len() == n as usize => labels.to_vec(),
+        Some(labels) => {
+            return Err(format!("Expected {} element labels, got {}", n, labels.len()));
+        }
+        None => (0..n).map(|i| i.to_string()).collect(),
+    };
+    let label = |v: i32| -> Result<String, String> {
+        labels.get(v as usize).cloned().ok_or_else(|| format!("Value {} is out of range", v))
+    };
+    let name = op.symbol().name().to_string();
+    let arity = op.arity().max(0) as usize;
+
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let split;
+    if arity == 2 {
+        split = 1;
+        rows.push(std::iter::once(name).chain(labels.iter().cloned()).collect());
+        for a in 0..n {
+            let mut row = vec![label(a)?];
+            for b in 0..n {
+                row.push(label(op.int_value_at(&[a, b])?)?);
+            }
+            rows.push(row);
+        }
+    } else {
+        split = arity;
+        let mut header: Vec<String> = if arity == 1 {
+            vec!["x".to_string()]
+        } else {
+            (0..arity).map(|i| format!("x{}", i)).collect()
+        };
+        header.push(name);
+        rows.push(header);
+        let count = (n as usize).checked_pow(arity as u32).ok_or("Operation table is too large")?;
+        for k in 0..count {
+            // Reverse Horner order, so the last argument varies fastest.
+            let mut args = horner::horner_inv_same_size(k as i32, n, arity);
+            args.reverse();
+            let mut row = args.iter().map(|&a| label(a)).collect::<Result<Vec<_>, _>>()?;
+            row.push(label(op.int_value_at(&args)?)?);
+            rows.push(row);
+        }
+    }
+
+    Ok(match style {
+        TableStyle::Text => render_text(&rows, split),
+        TableStyle::Markdown => render_markdown(&rows),
+        TableStyle::Latex => render_latex(&rows, split),
+    })
+}
+
+fn render_text(rows: &[Vec<String>], split: usize) -> String {
+    let columns = rows[0].len();
+    let widths: Vec<usize> = (0..columns)
+        .map(|c| rows.iter().map(|row| row[c].chars().count()).max().unwrap_or(0))
+        .collect();
+    let line = |row: &[String]| {
+        let cell = |c: usize| format!("{:>width$}", row[c], width = widths[c]);
+        let left: Vec<String> = (0..split).map(cell).collect();
+        let right: Vec<String> = (split..columns).map(cell).collect();
+        if split == 0 {
+            right.join(" ")
+        } else {
+            format!("{} | {}", left.join(" "), right.join(" "))
+        }
+    };
+    let mut out = String::new();
+    out.push_str(&line(&rows[0]));
+    out.push('\n');
+    let left_width: usize = widths[..split].iter().sum::<usize>() + split.saturating_sub(1);
+    let right_width: usize = widths[split..].iter().sum::<usize>() + (columns - split - 1);
+    if split == 0 {
+        out.push_str(&"-".repeat(right_width));
+    } else {
+        out.push_str(&format!("{}+{}", "-".repeat(left_width + 1), "-".repeat(right_width + 1)));
+    }
+    out.push('\n');
+    for row in &rows[1..] {
+        out.push_str(&line(row));
+        out.push('\n');
+    }
+    out
+}
+
+fn render_markdown(rows: &[Vec<String>]) -> String {
+    let escape = |s: &String| s.replace('|', "\\|");
+    let line = |row: &[String]| format!("| {} |\n", row.iter().map(escape).collect::<Vec<_>>().join(" | "));
+    let mut out = line(&rows[0]);
+    out.push_str(&format!("|{}\n", "---|".repeat(rows[0].len())));
+    for row in &rows[1..] {
+        out.push_str(&line(row));
+    }
+    out
+}
+
+/// Escape the characters LaTeX treats specially.
+pub(crate) fn latex_escape(s: &str) -> String {
+    let mut out = String::new();
+    for ch in s.chars() {
+        match ch {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                out.push('\\');
+                out.push(ch);
+            }
+            '\\' => out.push_str("\\textbackslash{}"),
+            '^' => out.push_str("\\^{}"),
+            '~' => out.push_str("\\~{}"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+fn render_latex(rows: &[Vec<String>], split: usize) -> String {
+    let columns = rows[0].len();
+    let spec = if split == 0 {
+        "c".repeat(columns)
+    } else {
+        format!("{}|{}", "c".repeat(split), "c".repeat(columns - split))
+    };
+    let line = |row: &[String]| {
+        format!("{} \\\\\n", row.iter().map(|s| latex_escape(s)).collect::<Vec<_>>().join(" & "))
+    };
+    let mut out = format!("\\begin{{tabular}}{{{}}}\n", spec);
+    out.push_str(&line(&rows[0]));
+    out.push_str("\\hline\n");
+    for row in &rows[1..] {
+        out.push_str(&line(row));
+    }
+    out.push_str("\\end{tabular}\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::op::{operations, OperationSymbol};
+
+    #[test]
+    fn test_binary_styles() {
+        let op = operations::make_int_operation(OperationSymbol::new("meet", 2, false), 2, vec![0, 0, 0, 1]).unwrap();
+        assert_eq!(
+            op.format_table(TableStyle::Text).unwrap(),
+            "meet | 0 1\n-----+----\n   0 | 0 0\n   1 | 0 1\n"
+        );
+        assert_eq!(
+            op.format_table(TableStyle::Markdown).unwrap(),
+            "| meet | 0 | 1 |\n|---|---|---|\n| 0 | 0 | 0 |\n| 1 | 0 | 1 |\n"
+        );
+        assert_eq!(
+            op.format_table(TableStyle::Latex).unwrap(),
+            "\\begin{tabular}{c|cc}\nmeet & 0 & 1 \\\\\n\\hline\n0 & 0 & 0 \\\\\n1 & 0 & 1 \\\\\n\\end{tabular}\n"
+        );
+    }
+
+    #[test]
+    fn test_other_arities() {
+        let neg = operations::make_int_operation(OperationSymbol::new("-", 1, false), 3, vec![0, 2, 1]).unwrap();
+        assert_eq!(neg.format_table(TableStyle::Text).unwrap(), "x | -\n--+--\n0 | 0\n1 | 2\n2 | 1\n");
+
+        let zero = operations::make_int_operation(OperationSymbol::new("0", 0, false), 3, vec![0]).unwrap();
+        assert_eq!(zero.format_table(TableStyle::Text).unwrap(), "0\n-\n0\n");
+
+        // m(x0,x1,x2) = x0 on {0, 1}; the last argument varies fastest
+        let m = operations::make_int_operation(OperationSymbol::new("m", 3, false), 2, vec![0, 1, 0, 1, 0, 1, 0, 1]).unwrap();
+        let text = m.format_table(TableStyle::Text).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "x0 x1 x2 | m");
+        assert_eq!(lines[4], " 0  1  0 | 0");
+        assert_eq!(lines[7], " 1  0  1 | 1");
+
+        let labels = ["a_1".to_string(), "b".to_string()];
+        let latex = format_table(m.as_ref(), TableStyle::Latex, Some(&labels)).unwrap();
+        assert!(latex.starts_with("\\begin{tabular}{ccc|c}"));
+        assert!(latex.contains("a\\_1 & a\\_1 & b & a\\_1 \\\\"));
+        assert!(format_table(m.as_ref(), TableStyle::Text, Some(&labels[..1])).is_err());
+        assert_eq!(TableStyle::from_name("latex").unwrap(), TableStyle::Latex);
+        assert!(TableStyle::from_name("html").is_err());
+    }
+}
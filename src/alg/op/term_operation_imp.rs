@@ -2,7 +2,8 @@ use std::fmt::{Display, Debug};
 use std::sync::Arc;
 use crate::alg::op::{Operation, OperationSymbol, TermOperation, AbstractOperation};
 use crate::alg::SmallAlgebra;
-use crate::terms::Term;
+use crate::terms::{CompiledTerm, Term};
+use crate::util::horner;
 
 /// Implementation of a term operation.
 /// 
@@ -121,6 +122,71 @@ impl TermOperationImp {
         }
     }
     
+    /// Create the term operation of `term` on `alg` over `variables`.
+    /// 
+    /// With `eager` the full value table is computed up front. Otherwise the
+    /// term is compiled once and evaluated at each call until `make_table`
+    /// materializes the table, which suits large algebras or high arities
+    /// where only a few values are needed.
+    /// 
+    /// # Arguments
+    /// * `term` - The term to interpret
+    /// * `variables` - The ordered list of variable names; must contain every variable of `term`
+    /// * `alg` - The algebra for interpretation
+    /// * `eager` - Whether to compute the value table immediately
+    /// 
+    /// # Returns
+    /// * `Ok(TermOperationImp)` - The term operation
+    /// * `Err(String)` - If a variable or operation of the term cannot be resolved
+    /// 
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use uacalc::alg::op::{Operation, TermOperationImp};
+    /// use uacalc::io::AlgebraReader;
+    /// use uacalc::terms::string_to_term;
+    /// 
+    /// let alg = AlgebraReader::new_from_file(std::path::Path::new("resources/algebras/sym3.ua"))
+    ///     .unwrap().read_algebra_file().unwrap();
+    /// let term = string_to_term("p(x,p(y,x))").unwrap();
+    /// let vars = vec!["x".to_string(), "y".to_string()];
+    /// let mut op = TermOperationImp::from_term(term, vars, Arc::new(alg), false).unwrap();
+    /// assert!(!op.is_table_based());
+    /// let v = op.int_value_at(&[1, 2]).unwrap();
+    /// op.make_table().unwrap();
+    /// assert_eq!(op.int_value_at(&[1, 2]).unwrap(), v);
+    /// assert_eq!(op.get_table().unwrap().len(), 36);
+    /// ```
+    pub fn from_term(
+        term: Box<dyn Term>,
+        variables: Vec<String>,
+        alg: Arc<dyn SmallAlgebra<UniverseItem = i32>>,
+        eager: bool,
+    ) -> Result<Self, String> {
+        let interpretation: Box<dyn Operation> = if eager {
+            term.interpretation(alg.clone(), &variables, true)?
+        } else {
+            Box::new(LazyTermOperation {
+                symbol: OperationSymbol::new(&term.to_string(), variables.len() as i32, false),
+                compiled: CompiledTerm::compile(term.as_ref(), alg.as_ref(), &variables)?,
+                table: None,
+            })
+        };
+        Self::new_safe(term, variables, alg, interpretation)
+    }
+    
+    /// Rename this term operation, keeping its arity.
+    /// 
+    /// # Arguments
+    /// * `name` - The new operation name
+    /// 
+    /// # Returns
+    /// The renamed term operation
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.symbol = OperationSymbol::new(name, self.symbol.arity(), false);
+        self
+    }
+    
     /// Create a new TermOperationImp with proper error handling.
     /// 
     /// # Arguments
@@ -205,10 +271,7 @@ impl AbstractOperation for TermOperationImp {
     }
     
     fn create_value_table(&mut self) -> Result<(), String> {
-        // Delegate to interpretation
-        // Note: This requires mutable access which we don't have
-        // For now, return an error
-        Err("Cannot create value table on immutable interpretation".to_string())
+        self.interpretation.make_table()
     }
     
     fn compute_value(&self, args: &[i32]) -> Result<i32, String> {
@@ -247,18 +310,15 @@ impl Operation for TermOperationImp {
     }
     
     fn make_table(&mut self) -> Result<(), String> {
-        // We can't make a table on an immutable interpretation
-        Err("Cannot make table on immutable interpretation".to_string())
+        self.interpretation.make_table()
     }
     
     fn get_table(&self) -> Option<&[i32]> {
         self.interpretation.get_table()
     }
     
-    fn get_table_force(&mut self, _make_table: bool) -> Result<&[i32], String> {
-        // We can't force table creation on an immutable interpretation
-        self.interpretation.get_table()
-            .ok_or_else(|| "No table available".to_string())
+    fn get_table_force(&mut self, make_table: bool) -> Result<&[i32], String> {
+        self.interpretation.get_table_force(make_table)
     }
     
     fn is_table_based(&self) -> bool {
@@ -320,3 +380,129 @@ impl Debug for TermOperationImp {
     }
 }
 
+/// The interpretation of a compiled term, evaluated on demand.
+///
+/// Used by `TermOperationImp::from_term` when the table is not built eagerly.
+#[derive(Debug, Clone)]
+struct LazyTermOperation {
+    symbol: OperationSymbol,
+    compiled: CompiledTerm,
+    table: Option<Vec<i32>>,
+}
+
+impl Display for LazyTermOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol)
+    }
+}
+
+impl AbstractOperation for LazyTermOperation {
+    fn get_symbol(&self) -> &OperationSymbol {
+        &self.symbol
+    }
+    
+    fn get_algebra_size(&self) -> i32 {
+        self.compiled.alg_size()
+    }
+    
+    fn has_value_table(&self) -> bool {
+        self.table.is_some()
+    }
+    
+    fn get_value_table(&self) -> Option<&[i32]> {
+        self.table.as_deref()
+    }
+    
+    fn create_value_table(&mut self) -> Result<(), String> {
+        if self.table.is_none() {
+            self.table = Some(self.compiled.table()?);
+        }
+        Ok(())
+    }
+    
+    fn compute_value(&self, args: &[i32]) -> Result<i32, String> {
+        self.compiled.eval_safe(args)
+    }
+}
+
+impl Operation for LazyTermOperation {
+    fn arity(&self) -> i32 {
+        self.default_arity()
+    }
+    
+    fn get_set_size(&self) -> i32 {
+        self.default_get_set_size()
+    }
+    
+    fn symbol(&self) -> &OperationSymbol {
+        self.default_symbol()
+    }
+    
+    fn value_at(&self, args: &[i32]) -> Result<i32, String> {
+        self.int_value_at(args)
+    }
+    
+    fn value_at_arrays(&self, args: &[&[i32]]) -> Result<Vec<i32>, String> {
+        self.default_value_at_arrays(args)
+    }
+    
+    fn int_value_at(&self, args: &[i32]) -> Result<i32, String> {
+        // The table is indexed with the first argument least significant,
+        // as produced by `CompiledTerm::table`.
+        match &self.table {
+            Some(table) if args.len() == self.compiled.arity()
+                && args.iter().all(|&a| 0 <= a && a < self.compiled.alg_size()) => {
+                Ok(table[horner::horner_same_size(args, self.compiled.alg_size()) as usize])
+            }
+            _ => self.compiled.eval_safe(args),
+        }
+    }
+    
+    fn int_value_at_horner(&self, arg: i32) -> Result<i32, String> {
+        self.default_int_value_at_horner(arg)
+    }
+    
+    fn make_table(&mut self) -> Result<(), String> {
+        self.default_make_table()
+    }
+    
+    fn get_table(&self) -> Option<&[i32]> {
+        self.default_get_table()
+    }
+    
+    fn get_table_force(&mut self, make_table: bool) -> Result<&[i32], String> {
+        self.default_get_table_force(make_table)
+    }
+    
+    fn is_table_based(&self) -> bool {
+        self.default_is_table_based()
+    }
+    
+    fn is_idempotent(&self) -> Result<bool, String> {
+        self.default_is_idempotent()
+    }
+    
+    fn is_associative(&self) -> Result<bool, String> {
+        self.default_is_associative()
+    }
+    
+    fn is_commutative(&self) -> Result<bool, String> {
+        self.default_is_commutative()
+    }
+    
+    fn is_totally_symmetric(&self) -> Result<bool, String> {
+        self.default_is_totally_symmetric()
+    }
+    
+    fn is_maltsev(&self) -> Result<bool, String> {
+        self.default_is_maltsev()
+    }
+    
+    fn is_total(&self) -> Result<bool, String> {
+        self.default_is_total()
+    }
+    
+    fn clone_box(&self) -> Box<dyn Operation> {
+        Box::new(self.clone())
+    }
+}
@@ -55,6 +55,39 @@ mod tests {
         assert_eq!(format!("{}", var), "x");
     }
     
+    #[test]
+    fn test_from_term_lazy_matches_eager() {
+        use std::sync::Arc;
+        use crate::io::AlgebraReader;
+        use crate::terms::string_to_term;
+        
+        let alg = AlgebraReader::new_from_file(std::path::Path::new("resources/algebras/sym3.ua"))
+            .unwrap().read_algebra_file().unwrap();
+        let alg: Arc<dyn SmallAlgebra<UniverseItem = i32>> = Arc::new(alg);
+        let term = string_to_term("p(x,p(y,p(x,z)))").unwrap();
+        let vars = vec!["x".to_string(), "y".to_string(), "z".to_string()];
+        
+        let eager = TermOperationImp::from_term(term.clone_box(), vars.clone(), alg.clone(), true).unwrap();
+        let mut lazy = TermOperationImp::from_term(term.clone_box(), vars.clone(), alg.clone(), false).unwrap();
+        assert!(eager.is_table_based());
+        assert!(!lazy.is_table_based());
+        assert_eq!(lazy.symbol(), eager.symbol());
+        assert_eq!(lazy.get_ordered_variables(), vars);
+        
+        let table = eager.get_table().unwrap().to_vec();
+        for (k, &v) in table.iter().enumerate() {
+            let args = crate::util::horner::horner_inv_same_size(k as i32, 6, 3);
+            assert_eq!(lazy.int_value_at(&args).unwrap(), v);
+        }
+        assert_eq!(lazy.get_table_force(true).unwrap(), &table[..]);
+        assert!(lazy.is_table_based());
+        assert_eq!(lazy.int_value_at(&[5, 4, 3]).unwrap(), table[5 + 6 * 4 + 36 * 3]);
+        assert!(lazy.int_value_at(&[6, 0, 0]).is_err());
+        
+        let missing = TermOperationImp::from_term(term, vec!["x".to_string()], alg, false);
+        assert!(missing.is_err());
+    }
+    
     // TODO: Add more comprehensive tests once Term interpretation is implemented
     // These tests should include:
     // - Creating TermOperationImp from various terms
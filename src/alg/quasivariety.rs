@@ -0,0 +1,174 @@
+/*! Membership in the quasivariety generated by a finite algebra.
+
+The quasivariety generated by A is SP(A), the subalgebras of powers of A.
+A finite algebra B lies in it exactly when the homomorphisms from B into A
+separate the points of B, that is when the meet of their kernels is zero;
+the homomorphisms then give an embedding of B into a power of A.
+
+When they do not, some pair `x != y` is identified by every homomorphism
+into A. Writing the diagram of B as equations `f(x_b1, ..., x_bn) = x_c`,
+one variable per element, any assignment in A satisfying the diagram is a
+homomorphism, so the quasi-equation "diagram implies `x_x = x_y`" holds in
+A and therefore in SP(A), but fails in B at the assignment `x_b = b`.
+*/
+
+use crate::alg::{Homomorphism, SmallAlgebra};
+use crate::alg::algebras::separating_homomorphisms;
+use crate::eq::{Equation, QuasiEquation};
+use crate::terms::{NonVariableTerm, Term, VariableImp};
+
+/// The outcome of testing whether B is in SP(A).
+#[derive(Debug, Clone)]
+pub struct QuasivarietyAnalysis {
+    /// Homomorphisms from B into A whose kernels have zero meet, or as small
+    /// a meet as can be reached if B is not in SP(A).
+    pub homomorphisms: Vec<Homomorphism>,
+    /// For each element of B its image in A^k, one coordinate per
+    /// homomorphism, if B is in SP(A).
+    pub embedding: Option<Vec<Vec<i32>>>,
+    /// A pair of distinct elements of B no homomorphism into A separates.
+    pub unseparated: Option<(usize, usize)>,
+    /// A quasi-equation true in A and false in B, if B is not in SP(A).
+    pub counter_quasi_equation: Option<QuasiEquation>,
+}
+
+impl QuasivarietyAnalysis {
+    /// Whether B is in SP(A).
+    pub fn holds(&self) -> bool {
+        self.embedding.is_some()
+    }
+}
+
+/// Decide whether `b` is in the quasivariety SP(`a`) generated by `a`.
+///
+/// # Arguments
+/// * `b` - The algebra to test for membership
+/// * `a` - The generating algebra
+///
+/// # Returns
+/// * `Ok(QuasivarietyAnalysis)` - With the embedding into a power of `a` on
+///   success, or an unseparated pair and a counter quasi-equation
+/// * `Err(String)` - If `a` lacks an operation of `b` or the computation
+///   was cancelled
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::BasicAlgebra;
+/// use uacalc::alg::op::OperationSymbol;
+/// use uacalc::alg::op::operations::make_int_operation;
+/// use uacalc::alg::quasivariety::in_quasivariety;
+///
+/// let cyclic = |n: i32| {
+///     let add = make_int_operation(OperationSymbol::new("add", 2, false), n,
+///         (0..n * n).map(|k| (k % n + k / n) % n).collect()).unwrap();
+///     BasicAlgebra::new(format!("Z{}", n), (0..n).collect(), vec![add])
+/// };
+/// // Z4 has an element of order 4, so it does not embed in a power of Z2
+/// let analysis = in_quasivariety(&cyclic(4), &cyclic(2)).unwrap();
+/// assert!(!analysis.holds());
+/// assert_eq!(analysis.unseparated, Some((0, 2)));
+/// let qe = analysis.counter_quasi_equation.unwrap();
+/// assert!(qe.is_satisfied_in(&cyclic(2)).unwrap());
+/// assert!(!qe.is_satisfied_in(&cyclic(4)).unwrap());
+/// ```
+pub fn in_quasivariety(
+    b: &dyn SmallAlgebra<UniverseItem = i32>,
+    a: &dyn SmallAlgebra<UniverseItem = i32>,
+) -> Result<QuasivarietyAnalysis, String> {
+    for op in b.get_operations_ref() {
+        if a.get_operation_ref(op.symbol()).is_none() {
+            return Err(format!("{} has no operation {}", a.name(), op.symbol()));
+        }
+    }
+    let (homomorphisms, phi) = separating_homomorphisms(b, &[a.clone_box()], None)?;
+    let n = b.cardinality().max(0) as usize;
+    if phi.is_zero() {
+        let embedding = (0..n)
+            .map(|x| homomorphisms.iter().map(|h| h.map[&x] as i32).collect())
+            .collect();
+        return Ok(QuasivarietyAnalysis {
+            homomorphisms,
+            embedding: Some(embedding),
+            unseparated: None,
+            counter_quasi_equation: None,
+        });
+    }
+    let unseparated = (0..n)
+        .flat_map(|x| (x + 1..n).map(move |y| (x, y)))
+        .find(|&(x, y)| phi.is_related(x, y))
+        .ok_or_else(|| "The meet of the kernels is not zero but relates no pair".to_string())?;
+    let counter = diagram_implies(b, unseparated)?;
+    Ok(QuasivarietyAnalysis {
+        homomorphisms,
+        embedding: None,
+        unseparated: Some(unseparated),
+        counter_quasi_equation: Some(counter),
+    })
+}
+
+/// The quasi-equation whose premises are the diagram of `b`, over the
+/// variables `x0, x1, ...`, and whose conclusion is `x_x = x_y`.
+fn diagram_implies(b: &dyn SmallAlgebra<UniverseItem = i32>, (x, y): (usize, usize)) -> Result<QuasiEquation, String> {
+    let var = |i: i32| -> Box<dyn Term> { Box::new(VariableImp::new(&format!("x{}", i))) };
+    let size = b.cardinality();
+    let mut premises = Vec::new();
+    for op in b.get_operations_ref() {
+        let mut args = vec![0; op.arity().max(0) as usize];
+        loop {
+            crate::progress::check_cancelled()?;
+            let value = op.int_value_at(&args)?;
+            let left = NonVariableTerm::new(op.symbol().clone(), args.iter().map(|&i| var(i)).collect());
+            premises.push(Equation::new(Box::new(left), var(value)));
+            if !crate::terms::compiled_term::increment(&mut args, size) {
+                break;
+            }
+        }
+    }
+    Ok(QuasiEquation::new(premises, Equation::new(var(x as i32), var(y as i32))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::BasicAlgebra;
+    use crate::alg::op::OperationSymbol;
+    use crate::alg::op::operations::make_int_operation;
+
+    fn cyclic(n: i32) -> BasicAlgebra<i32> {
+        let add = make_int_operation(OperationSymbol::new("add", 2, false), n,
+            (0..n * n).map(|k| (k % n + k / n) % n).collect()).unwrap();
+        BasicAlgebra::new(format!("Z{}", n), (0..n).collect(), vec![add])
+    }
+
+    #[test]
+    fn test_klein_group_embeds() {
+        let add = make_int_operation(OperationSymbol::new("add", 2, false), 4,
+            (0..16).map(|k| (k % 4) ^ (k / 4)).collect()).unwrap();
+        let klein = BasicAlgebra::new("V4".to_string(), (0..4).collect(), vec![add]);
+        let analysis = in_quasivariety(&klein, &cyclic(2)).unwrap();
+        assert!(analysis.holds());
+        let embedding = analysis.embedding.unwrap();
+        for x in 0..4 {
+            for y in x + 1..4 {
+                assert_ne!(embedding[x], embedding[y]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_counter_quasi_equation() {
+        let analysis = in_quasivariety(&cyclic(4), &cyclic(2)).unwrap();
+        assert!(analysis.embedding.is_none());
+        let qe = analysis.counter_quasi_equation.unwrap();
+        assert_eq!(qe.premises().len(), 16);
+        assert_eq!(qe.conclusion().to_string(), "x0 = x2");
+        assert!(qe.is_satisfied_in(&cyclic(2)).unwrap());
+        assert_eq!(qe.find_failure_in(&cyclic(4)).unwrap(), Some(vec![0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn test_trivial_algebra() {
+        assert!(in_quasivariety(&cyclic(1), &cyclic(3)).unwrap().holds());
+        assert!(in_quasivariety(&cyclic(3), &cyclic(3)).unwrap().holds());
+    }
+}
@@ -367,16 +367,10 @@ where
         let mut base = GeneralAlgebra::new(name);
         base.set_universe(HashSet::new());
         
-        // Create quotient operations
-        // Use get_operations_ref() instead of operations() to avoid deep cloning/recursion
-        // Following IMPLEMENT_CLONE_FIX.md: use reference-based access instead of cloning
-        let super_ops_ref = super_algebra.get_operations_ref();
+        // Create quotient operations, sharing the super algebra's operations
+        // rather than copying their tables
         let mut operations = Vec::new();
-        for super_op in super_ops_ref.iter() {
-            // Convert reference to Arc-backed operation - this should be safe since operations are Arc-backed
-            // We need to clone the Arc, not the operation itself
-            // Try to get the Arc directly if possible, otherwise wrap in Arc from clone_box
-            let op_arc: Arc<dyn Operation> = Arc::from(super_op.clone_box());
+        for op_arc in super_algebra.operations_arc() {
             operations.push(QuotientOperation::new(
                 op_arc,
                 representatives.clone(),
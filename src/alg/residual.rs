@@ -0,0 +1,518 @@
+/*! Subdirectly irreducible members of a variety and splitting pairs.
+
+A finite algebra is subdirectly irreducible (SI) if it has more than one
+element and its least nonzero congruence, the monolith, is below every
+other nonzero congruence. The SI quotients of an algebra are its quotients
+by meet irreducible congruences. Every member of V(A) generated by `k`
+elements is a quotient of the free algebra F(k), computed here as the
+algebra of `k`-ary term operations of A, so the `k`-generated SIs of V(A)
+are the SI quotients of F(k).
+
+When V(A) is congruence distributive, Jónsson's lemma puts every SI of
+V(A) in HS(A), and the subvarieties of V(A) correspond to the down-sets of
+these SIs ordered by `S ≤ T` iff `S ∈ HS(T)`. Every SI S is then a
+splitting algebra: the largest subvariety of V(A) not containing S, its
+conjugate, is generated by the SIs T with `S ∉ HS(T)`.
+*/
+
+use std::collections::{HashMap, VecDeque};
+use crate::alg::{Algebra, BasicAlgebra, SmallAlgebra};
+use crate::alg::conlat::CongruenceLattice;
+use crate::alg::conlat::partition::Partition;
+use crate::alg::free_spectrum::term_operation_tables;
+use crate::alg::op::operations::make_int_operation;
+use crate::alg::search::{find_isomorphism, SearchBackend};
+use crate::alg::sublat::SubalgebraLattice;
+use crate::terms::compiled_term::{operation_table, table_size};
+use crate::util::horner;
+
+/// A subdirectly irreducible algebra with its monolith.
+#[derive(Debug, Clone)]
+pub struct SubdirectlyIrreducible {
+    /// The SI algebra, on `0..n`.
+    pub algebra: BasicAlgebra<i32>,
+    /// The least nonzero congruence of the algebra.
+    pub monolith: Partition,
+}
+
+/// A splitting algebra of a congruence distributive variety and the SIs
+/// generating its conjugate variety.
+#[derive(Debug, Clone)]
+pub struct SplittingPair {
+    /// The splitting algebra S.
+    pub algebra: BasicAlgebra<i32>,
+    /// The SIs T of the variety with `S ∉ HS(T)`; they generate the largest
+    /// subvariety not containing S, which is trivial if there are none.
+    pub conjugate: Vec<BasicAlgebra<i32>>,
+}
+
+/// Compute the subdirectly irreducible members of V(A) with at most
+/// `max_size` elements that are generated by at most `max_gens` elements,
+/// up to isomorphism.
+///
+/// They are found as the SI quotients of the free algebra F(`max_gens`),
+/// whose congruence lattice is computed in full, so this is only practical
+/// when that free algebra is small. An SI with `m` elements is generated by
+/// at most `m` elements, so `max_gens = max_size` finds every SI with at
+/// most `max_size` elements. The computation checks the progress token
+/// installed on the current thread for cancellation.
+///
+/// # Arguments
+/// * `alg` - The algebra A
+/// * `max_size` - The largest number of elements of the SIs
+/// * `max_gens` - The largest number of generators of the SIs, at least 1
+///
+/// # Returns
+/// * `Ok(Vec<BasicAlgebra>)` - The SIs in order of size, named `<A>-SI<i>`
+/// * `Err(String)` - If `max_gens` is 0, the free algebra is too large to
+///   index or the computation was cancelled
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use uacalc::alg::{Algebra, BasicAlgebra};
+/// use uacalc::alg::op::{OperationSymbol, operations};
+/// use uacalc::alg::residual::si_members_up_to;
+///
+/// // The only SI semilattice is the two element one
+/// let meet = OperationSymbol::new("meet", 2, false);
+/// let op = operations::make_int_operation(meet, 2, vec![0, 0, 0, 1]).unwrap();
+/// let s = BasicAlgebra::new("S".to_string(), (0..2).collect::<HashSet<i32>>(), vec![op]);
+/// let sis = si_members_up_to(&s, 5, 3).unwrap();
+/// assert_eq!(sis.len(), 1);
+/// assert_eq!(sis[0].cardinality(), 2);
+/// ```
+pub fn si_members_up_to(
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    max_size: usize,
+    max_gens: usize,
+) -> Result<Vec<BasicAlgebra<i32>>, String> {
+    if max_gens == 0 {
+        return Err("An SI has at least one generator".to_string());
+    }
+    let free = free_algebra(alg, max_gens)?;
+    let mut sis = Vec::new();
+    for (si, _) in si_quotients(&free, max_size)? {
+        add_new(&mut sis, si)?;
+    }
+    Ok(named(sis, alg.name()))
+}
+
+/// Compute the splitting pairs of the congruence distributive variety
+/// V(A): for each SI S of V(A), up to isomorphism, the SIs generating the
+/// largest subvariety not containing S.
+///
+/// # Arguments
+/// * `alg` - The algebra A
+///
+/// # Returns
+/// * `Ok(Vec<SplittingPair>)` - One pair for each SI, in order of size; the
+///   SIs are named `<A>-SI<i>`
+/// * `Err(String)` - If V(A) is not congruence distributive or the
+///   computation was cancelled
+pub fn splitting_pairs(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<Vec<SplittingPair>, String> {
+    if crate::alg::malcev::jonsson_terms(alg)?.is_none() {
+        return Err(format!("{} does not generate a congruence distributive variety", alg.name()));
+    }
+    let mut sis = Vec::new();
+    for elems in subuniverses(alg)? {
+        let sub = subalgebra(alg, &elems)?;
+        for (si, _) in si_quotients(&sub, usize::MAX)? {
+            add_new(&mut sis, si)?;
+        }
+    }
+    let sis = named(sis, alg.name());
+    let mut pairs = Vec::with_capacity(sis.len());
+    for s in &sis {
+        let mut conjugate = Vec::new();
+        for t in &sis {
+            if !in_hs(s, t)? {
+                conjugate.push(t.clone());
+            }
+        }
+        pairs.push(SplittingPair { algebra: s.clone(), conjugate });
+    }
+    Ok(pairs)
+}
+
+/// Decide whether `s` is a homomorphic image of a subalgebra of `t`.
+///
+/// # Arguments
+/// * `s` - The algebra sought
+/// * `t` - The algebra searched
+///
+/// # Returns
+/// * `Ok(bool)` - Whether `s ∈ HS(t)`
+/// * `Err(String)` - If the computation was cancelled
+pub fn in_hs(
+    s: &dyn SmallAlgebra<UniverseItem = i32>,
+    t: &dyn SmallAlgebra<UniverseItem = i32>,
+) -> Result<bool, String> {
+    let size = s.cardinality() as usize;
+    for elems in subuniverses(t)? {
+        if elems.len() < size {
+            continue;
+        }
+        let sub = subalgebra(t, &elems)?;
+        let mut con = CongruenceLattice::new(Box::new(sub.clone()) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+        let pars: Vec<Partition> = CongruenceLattice::universe(&mut con).clone();
+        for par in pars.iter().filter(|p| p.number_of_blocks() == size) {
+            crate::progress::check_cancelled()?;
+            if find_isomorphism(&quotient(&sub, par)?, s, SearchBackend::ArcConsistency)?.is_some() {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+
+/// Enumerate the subdirectly irreducible members of HS(A), up to
+/// isomorphism: the SI quotients of the subalgebras of A.
+///
+/// The subuniverses of A are computed at once; the quotients of each
+/// subalgebra are computed when the iterator reaches it. The computation
+/// checks the progress token installed on the current thread for
+/// cancellation.
+///
+/// # Arguments
+/// * `alg` - The algebra A
+///
+/// # Returns
+/// * `Ok(SubdirectlyIrreducibles)` - An iterator of the SIs with their
+///   monoliths, named `<A>-SI<i>` in the order they are found
+/// * `Err(String)` - If the subuniverses could not be computed
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use uacalc::alg::{Algebra, BasicAlgebra};
+/// use uacalc::alg::op::{OperationSymbol, operations};
+/// use uacalc::alg::residual::subdirectly_irreducibles_in_hs;
+///
+/// // The 3-cycle has no proper subalgebras and is simple
+/// let f = operations::make_int_operation(OperationSymbol::new("f", 1, false), 3, vec![1, 2, 0]).unwrap();
+/// let cycle = BasicAlgebra::new("C3".to_string(), (0..3).collect::<HashSet<i32>>(), vec![f]);
+/// let sis: Vec<_> = subdirectly_irreducibles_in_hs(&cycle).unwrap().collect::<Result<_, _>>().unwrap();
+/// assert_eq!(sis.len(), 1);
+/// assert_eq!(sis[0].algebra.cardinality(), 3);
+/// assert_eq!(sis[0].monolith.number_of_blocks(), 1);
+/// ```
+pub fn subdirectly_irreducibles_in_hs(
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+) -> Result<SubdirectlyIrreducibles, String> {
+    let subs = subuniverses(alg)?;
+    Ok(SubdirectlyIrreducibles::new(alg, Sources::Subalgebras(subs.into_iter()), usize::MAX))
+}
+
+/// Enumerate the subdirectly irreducible members of V(A) with at most
+/// `max_size` elements, up to isomorphism.
+///
+/// The SIs are found among the quotients of the free algebras F(1), F(2),
+/// ..., F(`max_size`) in turn, each computed when the iterator reaches it,
+/// so SIs with few generators come first and a caller can stop before the
+/// larger free algebras are built. An SI with `m` elements is generated by
+/// at most `m` elements, so every SI with at most `max_size` elements is
+/// found. The computation checks the progress token installed on the
+/// current thread for cancellation.
+///
+/// # Arguments
+/// * `alg` - The algebra A
+/// * `max_size` - The largest number of elements of the SIs
+///
+/// # Returns
+/// * `SubdirectlyIrreducibles` - An iterator of the SIs with their
+///   monoliths, named `<A>-SI<i>` in the order they are found; it yields an
+///   error if a free algebra is too large to index or the computation was
+///   cancelled
+pub fn subdirectly_irreducibles_in_variety(
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    max_size: usize,
+) -> SubdirectlyIrreducibles {
+    SubdirectlyIrreducibles::new(alg, Sources::FreeAlgebras(1..=max_size), max_size)
+}
+
+/// The algebras whose SI quotients a [`SubdirectlyIrreducibles`] collects.
+enum Sources {
+    /// Subalgebras on these subuniverses.
+    Subalgebras(std::vec::IntoIter<Vec<i32>>),
+    /// Free algebras on these numbers of generators.
+    FreeAlgebras(std::ops::RangeInclusive<usize>),
+}
+
+/// Iterator returned by [`subdirectly_irreducibles_in_hs`] and
+/// [`subdirectly_irreducibles_in_variety`].
+pub struct SubdirectlyIrreducibles {
+    alg: BasicAlgebra<i32>,
+    sources: Sources,
+    max_size: usize,
+    /// The SIs found so far, to recognise isomorphic copies.
+    found: Vec<BasicAlgebra<i32>>,
+    pending: VecDeque<SubdirectlyIrreducible>,
+    failed: bool,
+}
+
+impl SubdirectlyIrreducibles {
+    fn new(alg: &dyn SmallAlgebra<UniverseItem = i32>, sources: Sources, max_size: usize) -> Self {
+        let elems: Vec<i32> = (0..alg.cardinality()).collect();
+        let alg = subalgebra(alg, &elems).expect("an algebra is a subalgebra of itself");
+        SubdirectlyIrreducibles { alg, sources, max_size, found: Vec::new(), pending: VecDeque::new(), failed: false }
+    }
+
+    /// Queue the new SI quotients of the next source algebra, returning
+    /// whether there was one.
+    fn advance(&mut self) -> Result<bool, String> {
+        let source = match &mut self.sources {
+            Sources::Subalgebras(subs) => match subs.next() {
+                Some(elems) => subalgebra(&self.alg, &elems)?,
+                None => return Ok(false),
+            },
+            Sources::FreeAlgebras(gens) => match gens.next() {
+                Some(k) => free_algebra(&self.alg, k)?,
+                None => return Ok(false),
+            },
+        };
+        for (si, monolith) in si_quotients(&source, self.max_size)? {
+            let name = format!("{}-SI{}", self.alg.name(), self.found.len());
+            let ops = si.get_operations_ref().iter().map(|op| op.clone_box()).collect();
+            let algebra = BasicAlgebra::new(name, (0..si.cardinality()).collect(), ops);
+            if add_new(&mut self.found, algebra.clone())? {
+                self.pending.push_back(SubdirectlyIrreducible { algebra, monolith });
+            }
+        }
+        Ok(true)
+    }
+}
+
+impl Iterator for SubdirectlyIrreducibles {
+    type Item = Result<SubdirectlyIrreducible, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pending.is_empty() && !self.failed {
+            match self.advance() {
+                Ok(true) => {}
+                Ok(false) => return None,
+                Err(e) => {
+                    self.failed = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+        self.pending.pop_front().map(Ok)
+    }
+}
+
+/// The free algebra F(k) of V(A) as the algebra of `k`-ary term operations.
+fn free_algebra(alg: &dyn SmallAlgebra<UniverseItem = i32>, k: usize) -> Result<BasicAlgebra<i32>, String> {
+    let tables = term_operation_tables(alg, k, None)?
+        .ok_or_else(|| crate::progress::CANCELLED.to_string())?;
+    let index: HashMap<&[i32], i32> = tables.iter().enumerate().map(|(i, t)| (t.as_slice(), i as i32)).collect();
+    let n = alg.cardinality() as usize;
+    let m = tables.len() as i32;
+    let mut ops = Vec::new();
+    for op in alg.get_operations_ref() {
+        crate::progress::check_cancelled()?;
+        let arity = op.arity() as usize;
+        let op_table = operation_table(op)?;
+        let mut table = Vec::with_capacity(table_size(m, arity)?);
+        for c in 0..table_size(m, arity)? {
+            let args = horner::horner_inv_same_size(c as i32, m, arity);
+            let value: Vec<i32> = (0..tables[0].len())
+                .map(|j| {
+                    let point: Vec<i32> = args.iter().map(|&a| tables[a as usize][j]).collect();
+                    op_table[horner::horner_same_size(&point, n as i32) as usize]
+                })
+                .collect();
+            let v = index.get(value.as_slice()).ok_or("The term operations are not closed")?;
+            table.push(*v);
+        }
+        ops.push(make_int_operation(op.symbol().clone(), m, table)?);
+    }
+    Ok(BasicAlgebra::new(format!("F({})", k), (0..m).collect(), ops))
+}
+
+/// The subuniverses of an algebra, except the empty one, in a fixed order.
+pub(crate) fn subuniverses(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<Vec<Vec<i32>>, String> {
+    let mut sub = SubalgebraLattice::new_safe(alg.clone_box())?;
+    let mut subs: Vec<Vec<i32>> = sub.universe_mut().iter()
+        .map(|s| s.elements().clone())
+        .filter(|s| !s.is_empty())
+        .collect();
+    subs.sort();
+    Ok(subs)
+}
+
+/// The subalgebra on `elems`, a sorted subuniverse, renumbered from 0.
+pub(crate) fn subalgebra(alg: &dyn SmallAlgebra<UniverseItem = i32>, elems: &[i32]) -> Result<BasicAlgebra<i32>, String> {
+    let position: HashMap<i32, i32> = elems.iter().enumerate().map(|(i, &e)| (e, i as i32)).collect();
+    let m = elems.len() as i32;
+    let ops = alg.get_operations_ref().iter()
+        .map(|op| {
+            let arity = op.arity() as usize;
+            let table = (0..table_size(m, arity)?)
+                .map(|c| {
+                    let args: Vec<i32> = horner::horner_inv_same_size(c as i32, m, arity)
+                        .iter().map(|&a| elems[a as usize]).collect();
+                    Ok(position[&op.int_value_at(&args)?])
+                })
+                .collect::<Result<Vec<i32>, String>>()?;
+            make_int_operation(op.symbol().clone(), m, table)
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    Ok(BasicAlgebra::new(alg.name().to_string(), (0..m).collect(), ops))
+}
+
+/// The quotient by `par`, its blocks numbered in order of their least elements.
+pub(crate) fn quotient(alg: &dyn SmallAlgebra<UniverseItem = i32>, par: &Partition) -> Result<BasicAlgebra<i32>, String> {
+    let reps = par.representatives();
+    let m = reps.len() as i32;
+    let ops = alg.get_operations_ref().iter()
+        .map(|op| {
+            let arity = op.arity() as usize;
+            let table = (0..table_size(m, arity)?)
+                .map(|c| {
+                    let args: Vec<i32> = horner::horner_inv_same_size(c as i32, m, arity)
+                        .iter().map(|&a| reps[a as usize] as i32).collect();
+                    Ok(par.block_index(op.int_value_at(&args)? as usize)? as i32)
+                })
+                .collect::<Result<Vec<i32>, String>>()?;
+            make_int_operation(op.symbol().clone(), m, table)
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    Ok(BasicAlgebra::new(alg.name().to_string(), (0..m).collect(), ops))
+}
+
+/// The quotients of `alg` by its meet irreducible congruences with at most
+/// `max_size` blocks, each with its monolith.
+fn si_quotients(alg: &BasicAlgebra<i32>, max_size: usize) -> Result<Vec<(BasicAlgebra<i32>, Partition)>, String> {
+    let mut con = CongruenceLattice::new(Box::new(alg.clone()) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+    let irreducibles = con.meet_irreducibles().clone();
+    let mut quotients = Vec::new();
+    for par in irreducibles.iter().filter(|p| p.number_of_blocks() <= max_size) {
+        crate::progress::check_cancelled()?;
+        // The monolith of the quotient is the unique upper cover of `par`
+        // divided by `par`.
+        let cover = &con.upper_covers_map()[par][0];
+        let reps = par.representatives();
+        let mut monolith = Partition::zero(reps.len());
+        for i in 0..reps.len() {
+            for j in i + 1..reps.len() {
+                let (r, s) = (monolith.representative(i), monolith.representative(j));
+                if r != s && cover.is_related(reps[i], reps[j]) {
+                    monolith.join_blocks(r, s);
+                }
+            }
+        }
+        monolith.normalize();
+        quotients.push((quotient(alg, par)?, monolith));
+    }
+    Ok(quotients)
+}
+
+/// Add `alg` to `found` unless it is isomorphic to a member, returning
+/// whether it was added.
+pub(crate) fn add_new(found: &mut Vec<BasicAlgebra<i32>>, alg: BasicAlgebra<i32>) -> Result<bool, String> {
+    for other in found.iter() {
+        if find_isomorphism(other, &alg, SearchBackend::ArcConsistency)?.is_some() {
+            return Ok(false);
+        }
+    }
+    found.push(alg);
+    Ok(true)
+}
+
+/// Sort by size and name `<name>-SI<i>`.
+fn named(mut sis: Vec<BasicAlgebra<i32>>, name: &str) -> Vec<BasicAlgebra<i32>> {
+    sis.sort_by_key(|si| si.cardinality());
+    sis.into_iter()
+        .enumerate()
+        .map(|(i, si)| {
+            let ops = si.get_operations_ref().iter().map(|op| op.clone_box()).collect();
+            BasicAlgebra::new(format!("{}-SI{}", name, i), (0..si.cardinality()).collect(), ops)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::op::OperationSymbol;
+
+    /// The lattice on `0..n` with the given order.
+    fn lattice(name: &str, n: i32, leq: impl Fn(i32, i32) -> bool) -> BasicAlgebra<i32> {
+        let bound = |x: i32, y: i32, upper: bool| {
+            let cands: Vec<i32> = (0..n).filter(|&z| if upper { leq(x, z) && leq(y, z) } else { leq(z, x) && leq(z, y) }).collect();
+            *cands.iter().find(|&&z| cands.iter().all(|&w| if upper { leq(z, w) } else { leq(w, z) })).unwrap()
+        };
+        let table = |upper: bool| (0..n * n).map(|k| bound(k % n, k / n, upper)).collect();
+        let join = make_int_operation(OperationSymbol::new("join", 2, false), n, table(true)).unwrap();
+        let meet = make_int_operation(OperationSymbol::new("meet", 2, false), n, table(false)).unwrap();
+        BasicAlgebra::new(name.to_string(), (0..n).collect(), vec![join, meet])
+    }
+
+    #[test]
+    fn test_si_members_up_to() {
+        let two = lattice("2", 2, |x, y| x <= y);
+        let sis = si_members_up_to(&two, 4, 3).unwrap();
+        assert_eq!(sis.len(), 1);
+        assert_eq!(sis[0].name(), "2-SI0");
+        assert!(si_members_up_to(&two, 4, 0).is_err());
+
+        // A 3-cycle: the one generated SI is the 3-cycle, and with two
+        // generators two fixed points also occur.
+        let f = make_int_operation(OperationSymbol::new("f", 1, false), 3, vec![1, 2, 0]).unwrap();
+        let cycle = BasicAlgebra::new("C3".to_string(), (0..3).collect(), vec![f]);
+        let sizes = |gens| si_members_up_to(&cycle, 3, gens).unwrap().iter().map(|a| a.cardinality()).collect::<Vec<_>>();
+        assert_eq!(sizes(1), vec![3]);
+        assert_eq!(sizes(2), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_subdirectly_irreducibles() {
+        // HS(M3) has the SIs 2 and M3; M3 is simple.
+        let m3 = lattice("M3", 5, |x, y| x == y || x == 0 || y == 4);
+        let sis: Vec<_> = subdirectly_irreducibles_in_hs(&m3).unwrap().collect::<Result<_, _>>().unwrap();
+        let mut sizes: Vec<i32> = sis.iter().map(|si| si.algebra.cardinality()).collect();
+        sizes.sort();
+        assert_eq!(sizes, vec![2, 5]);
+        for si in &sis {
+            assert_eq!(si.monolith.number_of_blocks(), 1);
+        }
+
+        // In the variety of the 3-cycle the 2-element SI comes from F(2).
+        let f = make_int_operation(OperationSymbol::new("f", 1, false), 3, vec![1, 2, 0]).unwrap();
+        let cycle = BasicAlgebra::new("C3".to_string(), (0..3).collect(), vec![f]);
+        let mut sis = subdirectly_irreducibles_in_variety(&cycle, 3);
+        assert_eq!(sis.next().unwrap().unwrap().algebra.cardinality(), 3);
+        let two = sis.next().unwrap().unwrap();
+        assert_eq!(two.algebra.name(), "C3-SI1");
+        assert_eq!(two.monolith, Partition::one(2));
+        assert!(sis.next().is_none());
+
+        // The SI chain 0 < 1 < 2 of a unary algebra: f(2) = 1, f(1) = f(0) = 0;
+        // its monolith collapses 0 and 1.
+        let f = make_int_operation(OperationSymbol::new("f", 1, false), 3, vec![0, 0, 1]).unwrap();
+        let chain = BasicAlgebra::new("C".to_string(), (0..3).collect(), vec![f]);
+        let sis: Vec<_> = subdirectly_irreducibles_in_hs(&chain).unwrap().collect::<Result<_, _>>().unwrap();
+        let three = sis.iter().find(|si| si.algebra.cardinality() == 3).unwrap();
+        assert_eq!(three.monolith.get_blocks(), vec![vec![0, 1], vec![2]]);
+    }
+
+    #[test]
+    fn test_splitting_pairs() {
+        // M3: its SIs are the two element lattice and M3 itself
+        let m3 = lattice("M3", 5, |x, y| x == y || x == 0 || y == 4);
+        let pairs = splitting_pairs(&m3).unwrap();
+        assert_eq!(pairs.len(), 2);
+        assert_eq!(pairs[0].algebra.cardinality(), 2);
+        assert!(pairs[0].conjugate.is_empty());
+        assert_eq!(pairs[1].algebra.cardinality(), 5);
+        assert_eq!(pairs[1].conjugate.len(), 1);
+        assert!(in_hs(&pairs[0].algebra, &m3).unwrap());
+        assert!(!in_hs(&m3, &pairs[0].algebra).unwrap());
+
+        // Z3 is not congruence distributive
+        let plus = make_int_operation(OperationSymbol::new("+", 2, false), 3, (0..9).map(|k| (k % 3 + k / 3) % 3).collect()).unwrap();
+        let z3 = BasicAlgebra::new("Z3".to_string(), (0..3).collect(), vec![plus]);
+        assert!(splitting_pairs(&z3).is_err());
+    }
+}
@@ -0,0 +1,365 @@
+//! Finite rings and modules as algebras.
+//!
+//! A ring is an algebra with the operations `add`, `neg`, `zero`, `mul` and
+//! `one`. A module over a ring R is its abelian group together with a unary
+//! operation `s<r>`, `x ↦ r·x`, for each scalar `r`, which turns the
+//! two-sorted action into a one-sorted algebra. The congruences of a ring
+//! are the cosets of its two-sided ideals, and those of a module the cosets
+//! of its submodules, so both are read off the congruence lattice as the
+//! block of 0.
+
+use crate::alg::conlat::{CongruenceLattice, Partition};
+use crate::alg::op::operations::make_int_operation;
+use crate::alg::op::{Operation, OperationSymbol};
+use crate::alg::{Algebra, BasicAlgebra, SmallAlgebra};
+use crate::group::GroupTable;
+
+/// The largest number of elements of a constructed ring or module.
+const MAX_ELEMENTS: usize = 1 << 12;
+
+fn binary(name: &str, size: usize, f: impl Fn(usize, usize) -> usize) -> Result<Box<dyn Operation>, String> {
+    let table = (0..size * size).map(|k| f(k % size, k / size) as i32).collect();
+    make_int_operation(OperationSymbol::new_safe(name, 2, false)?, size as i32, table)
+}
+
+fn unary(name: &str, size: usize, f: impl Fn(usize) -> usize) -> Result<Box<dyn Operation>, String> {
+    make_int_operation(OperationSymbol::new_safe(name, 1, false)?, size as i32, (0..size).map(|x| f(x) as i32).collect())
+}
+
+fn constant(name: &str, size: usize, c: usize) -> Result<Box<dyn Operation>, String> {
+    make_int_operation(OperationSymbol::new_safe(name, 0, false)?, size as i32, vec![c as i32])
+}
+
+/// `base^exp` elements, if there are at most `MAX_ELEMENTS`.
+fn checked_size(base: usize, exp: usize, what: &str) -> Result<usize, String> {
+    u32::try_from(exp)
+        .ok()
+        .and_then(|e| base.checked_pow(e))
+        .filter(|&s| s <= MAX_ELEMENTS)
+        .ok_or_else(|| format!("{} would have more than {} elements", what, MAX_ELEMENTS))
+}
+
+/// The elements of `Z_m^len` as digit vectors, the first digit least
+/// significant, so the encoding of element `x` is `x` itself.
+fn digit_vectors(m: usize, len: usize, size: usize) -> Vec<Vec<usize>> {
+    (0..size)
+        .map(|x| (0..len).scan(x, |rest, _| {
+            let d = *rest % m;
+            *rest /= m;
+            Some(d)
+        }).collect())
+        .collect()
+}
+
+fn encode(m: usize, digits: &[usize]) -> usize {
+    digits.iter().rev().fold(0, |acc, &d| acc * m + d)
+}
+
+/// The additive group of an algebra: its first binary operation that is an
+/// abelian group operation.
+fn additive_group(alg: &BasicAlgebra<i32>) -> Result<GroupTable, String> {
+    alg.operations()
+        .iter()
+        .filter(|op| op.arity() == 2)
+        .filter_map(|op| GroupTable::from_operation(op.as_ref()).ok())
+        .find(|g| g.center().len() == g.n)
+        .ok_or_else(|| format!("{} has no abelian group operation", alg.name()))
+}
+
+/// The additive group and the multiplication table of a ring: the first
+/// binary operation other than the addition that is associative and
+/// distributes over it on both sides.
+fn ring_tables(alg: &BasicAlgebra<i32>) -> Result<(GroupTable, Vec<usize>), String> {
+    let add = additive_group(alg)?;
+    let n = add.n;
+    let is_multiplication = |t: &[usize]| {
+        let f = |x: usize, y: usize| t[x + n * y];
+        (0..n).all(|x| (0..n).all(|y| (0..n).all(|z| {
+            f(f(x, y), z) == f(x, f(y, z))
+                && f(x, add.mul(y, z)) == add.mul(f(x, y), f(x, z))
+                && f(add.mul(x, y), z) == add.mul(f(x, z), f(y, z))
+        })))
+    };
+    alg.operations()
+        .iter()
+        .filter(|op| op.arity() == 2 && op.symbol().name() != add.name)
+        .filter_map(|op| crate::terms::compiled_term::operation_table(op.as_ref()).ok())
+        .map(|t| t.iter().map(|&v| v as usize).collect::<Vec<usize>>())
+        .find(|t| is_multiplication(t))
+        .map(|mul| (add, mul))
+        .ok_or_else(|| format!("{} has no ring multiplication", alg.name()))
+}
+
+/// Create the ring `Z_n` of integers modulo `n`.
+///
+/// # Arguments
+/// * `n` - The modulus
+///
+/// # Returns
+/// * `Ok(BasicAlgebra)` - The ring, named `Z{n}`, with the operations
+///   `add`, `neg`, `zero`, `mul` and `one`
+/// * `Err(String)` - If `n` is 0 or too large
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::ring;
+///
+/// // The ideals of Z12 are generated by the divisors of 12
+/// let z12 = ring::zn_ring(12).unwrap();
+/// assert_eq!(ring::ideals(&z12).unwrap().len(), 6);
+/// ```
+pub fn zn_ring(n: usize) -> Result<BasicAlgebra<i32>, String> {
+    if n == 0 {
+        return Err("The modulus must be positive".to_string());
+    }
+    checked_size(n, 1, &format!("Z{}", n))?;
+    let ops = vec![
+        binary("add", n, |x, y| (x + y) % n)?,
+        unary("neg", n, |x| (n - x) % n)?,
+        constant("zero", n, 0)?,
+        binary("mul", n, |x, y| (x * y) % n)?,
+        constant("one", n, 1 % n)?,
+    ];
+    Ok(BasicAlgebra::new(format!("Z{}", n), (0..n as i32).collect(), ops))
+}
+
+/// Create the ring of `dim × dim` matrices over `Z_p`.
+///
+/// A matrix is encoded by its entries in base `p`, row by row, the entry
+/// `(0, 0)` the least significant digit.
+///
+/// # Arguments
+/// * `dim` - The number of rows and columns
+/// * `p` - The modulus of the entries
+///
+/// # Returns
+/// * `Ok(BasicAlgebra)` - The ring, named `M{dim}(Z{p})`, with the
+///   operations `add`, `neg`, `zero`, `mul` and `one`
+/// * `Err(String)` - If `dim` or `p` is 0 or the ring is too large
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::{ring, Algebra};
+///
+/// // A full matrix ring over a field is simple
+/// let m2 = ring::matrix_ring(2, 2).unwrap();
+/// assert_eq!(m2.cardinality(), 16);
+/// assert_eq!(ring::ideals(&m2).unwrap().len(), 2);
+/// ```
+pub fn matrix_ring(dim: usize, p: usize) -> Result<BasicAlgebra<i32>, String> {
+    if dim == 0 || p == 0 {
+        return Err("The dimension and the modulus must be positive".to_string());
+    }
+    let name = format!("M{}(Z{})", dim, p);
+    let size = checked_size(p, dim * dim, &name)?;
+    let mats = digit_vectors(p, dim * dim, size);
+    let product = |x: usize, y: usize| {
+        let (a, b) = (&mats[x], &mats[y]);
+        let c: Vec<usize> = (0..dim * dim)
+            .map(|ij| (0..dim).map(|k| a[ij / dim * dim + k] * b[k * dim + ij % dim]).sum::<usize>() % p)
+            .collect();
+        encode(p, &c)
+    };
+    let identity: Vec<usize> = (0..dim * dim).map(|ij| usize::from(ij / dim == ij % dim) % p).collect();
+    let ops = vec![
+        binary("add", size, |x, y| encode(p, &mats[x].iter().zip(&mats[y]).map(|(a, b)| (a + b) % p).collect::<Vec<_>>()))?,
+        unary("neg", size, |x| encode(p, &mats[x].iter().map(|a| (p - a) % p).collect::<Vec<_>>()))?,
+        constant("zero", size, 0)?,
+        binary("mul", size, product)?,
+        constant("one", size, encode(p, &identity))?,
+    ];
+    Ok(BasicAlgebra::new(name, (0..size as i32).collect(), ops))
+}
+
+/// Create the free module `Z_n^dim` over `Z_n`.
+///
+/// The vectors are encoded in base `n`, the first coordinate the least
+/// significant digit. Besides `add`, `neg` and `zero` there is a unary
+/// operation `s<r>` multiplying by the scalar `r` for each `r` in `Z_n`.
+///
+/// # Arguments
+/// * `n` - The modulus of the scalars
+/// * `dim` - The number of coordinates
+///
+/// # Returns
+/// * `Ok(BasicAlgebra)` - The module, named `Z{n}^{dim}`
+/// * `Err(String)` - If `n` is 0 or the module is too large
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::ring;
+///
+/// // The subspaces of the plane over Z2: 0, three lines and the plane
+/// let plane = ring::zn_module(2, 2).unwrap();
+/// assert_eq!(ring::ideals(&plane).unwrap().len(), 5);
+/// ```
+pub fn zn_module(n: usize, dim: usize) -> Result<BasicAlgebra<i32>, String> {
+    if n == 0 {
+        return Err("The modulus must be positive".to_string());
+    }
+    let name = format!("Z{}^{}", n, dim);
+    let size = checked_size(n, dim, &name)?;
+    let vecs = digit_vectors(n, dim, size);
+    let mut ops = vec![
+        binary("add", size, |x, y| encode(n, &vecs[x].iter().zip(&vecs[y]).map(|(a, b)| (a + b) % n).collect::<Vec<_>>()))?,
+        unary("neg", size, |x| encode(n, &vecs[x].iter().map(|a| (n - a) % n).collect::<Vec<_>>()))?,
+        constant("zero", size, 0)?,
+    ];
+    for r in 0..n {
+        ops.push(unary(&format!("s{}", r), size, |x| encode(n, &vecs[x].iter().map(|a| a * r % n).collect::<Vec<_>>()))?);
+    }
+    Ok(BasicAlgebra::new(name, (0..size as i32).collect(), ops))
+}
+
+/// Create the left regular module of a ring: its additive group with a
+/// unary operation `s<r>`, `x ↦ r·x`, for each element `r` of the ring.
+/// Its submodules are the left ideals of the ring.
+///
+/// # Arguments
+/// * `ring` - An algebra with a ring reduct
+///
+/// # Returns
+/// * `Ok(BasicAlgebra)` - The module, named `{ring}-module`
+/// * `Err(String)` - If the algebra has no abelian group operation or no
+///   multiplication distributing over it
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::ring;
+///
+/// // M2(Z2) is simple but has three proper nonzero left ideals
+/// let module = ring::regular_module(&ring::matrix_ring(2, 2).unwrap()).unwrap();
+/// assert_eq!(ring::ideals(&module).unwrap().len(), 5);
+/// ```
+pub fn regular_module(ring: &BasicAlgebra<i32>) -> Result<BasicAlgebra<i32>, String> {
+    let (add, mul) = ring_tables(ring)?;
+    let n = add.n;
+    let mut ops = vec![
+        binary("add", n, |x, y| add.mul(x, y))?,
+        unary("neg", n, |x| add.inverse[x])?,
+        constant("zero", n, add.identity)?,
+    ];
+    for r in 0..n {
+        ops.push(unary(&format!("s{}", r), n, |x| mul[r + n * x])?);
+    }
+    Ok(BasicAlgebra::new(format!("{}-module", ring.name()), (0..n as i32).collect(), ops))
+}
+
+/// The ideals of a ring, or the submodules of a module: the blocks of 0 of
+/// its congruences.
+///
+/// # Arguments
+/// * `alg` - A ring, module or other algebra with an abelian group
+///   operation; its first such binary operation gives the 0
+///
+/// # Returns
+/// * `Ok(Vec<Vec<usize>>)` - The ideals, each sorted, ordered by size and
+///   then by their elements
+/// * `Err(String)` - If the algebra has no abelian group operation
+pub fn ideals(alg: &BasicAlgebra<i32>) -> Result<Vec<Vec<usize>>, String> {
+    let zero = additive_group(alg)?.identity;
+    let mut con = CongruenceLattice::new(Box::new(alg.clone()) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+    let mut ideals: Vec<Vec<usize>> = con
+        .try_universe()?
+        .iter()
+        .map(|theta| (0..theta.universe_size()).filter(|&x| theta.is_related(zero, x)).collect())
+        .collect();
+    ideals.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+    Ok(ideals)
+}
+
+/// The ideal of a congruence of a ring, or the submodule of a congruence of
+/// a module: the block of 0.
+///
+/// # Arguments
+/// * `alg` - A ring or module
+/// * `theta` - A congruence of `alg`
+///
+/// # Returns
+/// * `Ok(Vec<usize>)` - The block of 0, sorted
+/// * `Err(String)` - If the algebra has no abelian group operation or the
+///   partition is on a different set
+pub fn ideal_of_congruence(alg: &BasicAlgebra<i32>, theta: &Partition) -> Result<Vec<usize>, String> {
+    let add = additive_group(alg)?;
+    if theta.universe_size() != add.n {
+        return Err(format!("The partition is on {} elements, not {}", theta.universe_size(), add.n));
+    }
+    Ok((0..add.n).filter(|&x| theta.is_related(add.identity, x)).collect())
+}
+
+/// The congruence of an ideal of a ring, or of a submodule of a module:
+/// `x θ y` when `x - y` is in the ideal.
+///
+/// # Arguments
+/// * `alg` - A ring or module
+/// * `ideal` - The elements of the ideal
+///
+/// # Returns
+/// * `Ok(Partition)` - The congruence whose blocks are the cosets
+/// * `Err(String)` - If the algebra has no abelian group operation or the
+///   set is not an ideal
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::ring;
+///
+/// let z12 = ring::zn_ring(12).unwrap();
+/// let theta = ring::congruence_of_ideal(&z12, &[0, 4, 8]).unwrap();
+/// assert_eq!(theta.number_of_blocks(), 4);
+/// assert!(ring::congruence_of_ideal(&z12, &[0, 4]).is_err());
+/// ```
+pub fn congruence_of_ideal(alg: &BasicAlgebra<i32>, ideal: &[usize]) -> Result<Partition, String> {
+    let add = additive_group(alg)?;
+    if let Some(&x) = ideal.iter().find(|&&x| x >= add.n) {
+        return Err(format!("Element {} is not below {}", x, add.n));
+    }
+    let mut generators = Partition::zero(add.n);
+    for &x in ideal {
+        let (r, s) = (generators.representative(add.identity), generators.representative(x));
+        if r != s {
+            generators.join_blocks(r, s);
+        }
+    }
+    let con = CongruenceLattice::new(Box::new(alg.clone()) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+    let theta = con.cg_partition(&generators);
+    let mut members = ideal.to_vec();
+    members.sort_unstable();
+    members.dedup();
+    let block = ideal_of_congruence(alg, &theta)?;
+    if block != members {
+        return Err(format!("{:?} is not an ideal of {}: the ideal it generates is {:?}", members, alg.name(), block));
+    }
+    Ok(theta)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rings_and_modules() {
+        let z12 = zn_ring(12).unwrap();
+        let sizes = |alg: &BasicAlgebra<i32>| ideals(alg).unwrap().iter().map(|i| i.len()).collect::<Vec<_>>();
+        assert_eq!(sizes(&z12), vec![1, 2, 3, 4, 6, 12]);
+        // Z12 acting on itself has the same submodules as ideals
+        assert_eq!(ideals(&regular_module(&z12).unwrap()).unwrap(), ideals(&z12).unwrap());
+
+        // The ideal <3> and back
+        let theta = congruence_of_ideal(&z12, &[0, 3, 6, 9]).unwrap();
+        assert!(theta.is_related(1, 10));
+        assert_eq!(ideal_of_congruence(&z12, &theta).unwrap(), vec![0, 3, 6, 9]);
+        assert!(congruence_of_ideal(&z12, &[3, 6, 9]).is_err());
+
+        // M2(Z2): the identity is 1 + 8 (entries (0, 0) and (1, 1))
+        let m2 = matrix_ring(2, 2).unwrap();
+        let one = m2.operations().iter().find(|op| op.symbol().name() == "one").unwrap().int_value_at(&[]).unwrap();
+        assert_eq!(one, 9);
+        assert_eq!(sizes(&m2), vec![1, 16]);
+        assert_eq!(sizes(&regular_module(&m2).unwrap()), vec![1, 4, 4, 4, 16]);
+
+        assert_eq!(sizes(&zn_module(3, 2).unwrap()), vec![1, 3, 3, 3, 3, 9]);
+        assert_eq!(sizes(&zn_module(4, 1).unwrap()), vec![1, 2, 4]);
+        assert!(matrix_ring(3, 3).is_err());
+        assert!(ideals(&crate::alg::algebras::boolean_algebra(2).unwrap()).is_err());
+    }
+}
@@ -0,0 +1,975 @@
+/*! Homomorphism and polymorphism search.
+
+Searches are phrased as finite constraint problems: one variable per point
+of the domain (an element of `A` for a homomorphism `A -> B`, a tuple of
+`A^k` for a `k`-ary polymorphism), ranging over the target universe, and
+one functional constraint per operation application. Further constraints
+(membership of a tuple of variables in a subpower, or custom constraints via
+`ConstraintHook`) can be added before solving. A `SearchBackend` selects how
+the problem is solved.
+
+NU and edge laws can be imposed on a polymorphism with `LinearIdentity`.
+The term searches `malcev::nu_term` and `malcev::fixed_k_edge_term` do not
+use this module: they need the term itself, which only the closure in a
+power of the free algebra records, while a solution here is just a table.
+*/
+
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+use crate::alg::SmallAlgebra;
+use crate::terms::compiled_term::{operation_table, table_size};
+use crate::util::horner;
+
+pub mod propagation;
+#[cfg(feature = "sat")]
+pub mod sat;
+
+pub use propagation::Propagator;
+
+/// The algorithm used to solve a search problem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchBackend {
+    /// Depth-first search with checking of completed constraints.
+    Backtrack,
+    /// Search maintaining generalized arc consistency (AC-3) at every node.
+    #[default]
+    ArcConsistency,
+    /// As `ArcConsistency`, after first enforcing singleton arc consistency.
+    SingletonArcConsistency,
+    /// Encode the problem as CNF and run the embedded CDCL solver.
+    #[cfg(feature = "sat")]
+    Sat,
+}
+
+impl SearchBackend {
+    /// Parse a backend name: `"backtrack"`, `"ac"`, `"sac"` or `"sat"`.
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "backtrack" => Ok(SearchBackend::Backtrack),
+            "ac" => Ok(SearchBackend::ArcConsistency),
+            "sac" => Ok(SearchBackend::SingletonArcConsistency),
+            #[cfg(feature = "sat")]
+            "sat" => Ok(SearchBackend::Sat),
+            _ => Err(format!("Unknown search backend: {}", name)),
+        }
+    }
+}
+
+/// A user supplied constraint.
+///
+/// Only `vars` and `holds` are required. The default `revise` finds supports
+/// by enumerating the current domains of `vars`, which is fine for
+/// constraints of small arity; implement it directly for anything larger.
+pub trait ConstraintHook: fmt::Debug + Send + Sync {
+    /// The variables this constraint mentions.
+    fn vars(&self) -> Vec<usize>;
+
+    /// Whether the constraint holds; `values` has one entry per problem variable.
+    fn holds(&self, values: &[i32]) -> bool;
+
+    /// Remove the values of `vars` without support in `domains`.
+    ///
+    /// # Returns
+    /// Whether any domain changed
+    fn revise(&self, domains: &mut [Vec<bool>]) -> bool {
+        propagation::revise_by_enumeration(&self.vars(), domains, |values| self.holds(values))
+    }
+}
+
+/// A constraint between variables of a `ConstraintProblem`.
+#[derive(Debug, Clone)]
+pub enum Constraint {
+    /// The two variables take the same value.
+    Equal(usize, usize),
+    /// `result = table[horner(args)]`, with the first argument varying fastest.
+    Table { args: Vec<usize>, result: usize, table: Arc<[i32]> },
+    /// The values of `vars` form one of `tuples`, e.g. an element of a subpower.
+    Relation { vars: Vec<usize>, tuples: Arc<[Vec<i32>]> },
+    /// A user supplied constraint.
+    Custom(Arc<dyn ConstraintHook>),
+}
+
+impl Constraint {
+    /// The constraint that `vars` take values in the subpower of `alg`
+    /// generated by `generators`.
+    ///
+    /// # Arguments
+    /// * `alg` - The algebra
+    /// * `vars` - The constrained variables
+    /// * `generators` - Tuples of length `vars.len()` generating the subpower
+    ///
+    /// # Returns
+    /// * `Ok(Constraint::Relation)` - The membership constraint
+    /// * `Err(String)` - If a generator has the wrong length or an operation has no table
+    pub fn subpower(
+        alg: &dyn SmallAlgebra<UniverseItem = i32>,
+        vars: Vec<usize>,
+        generators: &[Vec<i32>],
+    ) -> Result<Self, String> {
+        if let Some(g) = generators.iter().find(|g| g.len() != vars.len()) {
+            return Err(format!("Generator has length {}, expected {}", g.len(), vars.len()));
+        }
+        let tuples = subpower(alg, generators)?;
+        Ok(Constraint::Relation { vars, tuples: tuples.into() })
+    }
+
+    /// The variables this constraint mentions.
+    pub fn vars(&self) -> Vec<usize> {
+        match self {
+            Constraint::Equal(u, v) => vec![*u, *v],
+            Constraint::Table { args, result, .. } => {
+                let mut vs = args.clone();
+                vs.push(*result);
+                vs
+            }
+            Constraint::Relation { vars, .. } => vars.clone(),
+            Constraint::Custom(hook) => hook.vars(),
+        }
+    }
+
+    /// Whether the constraint holds under a complete assignment of its variables.
+    pub fn holds(&self, values: &[i32], domain_size: usize) -> bool {
+        match self {
+            Constraint::Equal(u, v) => values[*u] == values[*v],
+            Constraint::Table { args, result, table } => {
+                let mut index = 0usize;
+                for &a in args.iter().rev() {
+                    index = index * domain_size + values[a] as usize;
+                }
+                table[index] == values[*result]
+            }
+            Constraint::Relation { vars, tuples } => {
+                tuples.iter().any(|t| t.iter().zip(vars).all(|(&x, &v)| values[v] == x))
+            }
+            Constraint::Custom(hook) => hook.holds(values),
+        }
+    }
+}
+
+/// The subuniverse of `alg^k` generated by tuples of length `k`.
+///
+/// The tuples are returned in the order they are found, generators first.
+pub fn subpower(
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    generators: &[Vec<i32>],
+) -> Result<Vec<Vec<i32>>, String> {
+    let k = generators.first().map_or(0, |g| g.len());
+    Ok(close_tuples(alg, generators, k, |_, _| true)?.unwrap_or_default())
+}
+
+/// Close `generators`, tuples of length `len`, under the operations of `alg`
+/// acting coordinatewise.
+///
+/// `keep_going(pass, size)` is called before each pass of the closure and
+/// every 1024 new elements; returning `false` abandons the computation.
+///
+/// # Returns
+/// * `Ok(Some(elems))` - The closure, generators first
+/// * `Ok(None)` - If `keep_going` stopped the computation
+/// * `Err(String)` - If an operation table cannot be built
+pub(crate) fn close_tuples(
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    generators: &[Vec<i32>],
+    len: usize,
+    mut keep_going: impl FnMut(usize, usize) -> bool,
+) -> Result<Option<Vec<Vec<i32>>>, String> {
+    let n = alg.cardinality();
+    let tables = alg.get_operations_ref().iter()
+        .map(|op| Ok((op.arity() as usize, operation_table(*op)?)))
+        .collect::<Result<Vec<_>, String>>()?;
+    let mut elems: Vec<Vec<i32>> = Vec::new();
+    let mut seen: HashSet<Vec<i32>> = HashSet::new();
+    for g in generators {
+        if seen.insert(g.clone()) {
+            elems.push(g.clone());
+        }
+    }
+    // Constants generate the subpower of the empty set.
+    for (arity, table) in &tables {
+        if *arity == 0 && seen.insert(vec![table[0]; len]) {
+            elems.push(vec![table[0]; len]);
+        }
+    }
+    // Semi-naive closure: each pass applies operations to argument lists
+    // containing at least one element found in the previous pass.
+    let mut old = 0;
+    let mut pass = 0;
+    while old < elems.len() {
+        if !keep_going(pass, elems.len()) {
+            return Ok(None);
+        }
+        let current = elems.len();
+        for (arity, table) in &tables {
+            if *arity == 0 {
+                continue;
+            }
+            let mut args = vec![0usize; *arity];
+            loop {
+                if args.iter().any(|&a| a >= old) {
+                    let tuple: Vec<i32> = (0..len).map(|j| {
+                        let mut index = 0usize;
+                        for &a in args.iter().rev() {
+                            index = index * n as usize + elems[a][j] as usize;
+                        }
+                        table[index]
+                    }).collect();
+                    if seen.insert(tuple.clone()) {
+                        elems.push(tuple);
+                        if elems.len().is_multiple_of(1024) && !keep_going(pass, elems.len()) {
+                            return Ok(None);
+                        }
+                    }
+                }
+                if !increment_bounded(&mut args, current) {
+                    break;
+                }
+            }
+        }
+        old = current;
+        pass += 1;
+    }
+    Ok(Some(elems))
+}
+
+fn increment_bounded(args: &mut [usize], bound: usize) -> bool {
+    for a in args.iter_mut() {
+        *a += 1;
+        if *a < bound {
+            return true;
+        }
+        *a = 0;
+    }
+    false
+}
+
+/// A finite constraint satisfaction problem with a common domain `{0, ..., n-1}`.
+#[derive(Debug, Clone)]
+pub struct ConstraintProblem {
+    domain_size: usize,
+    domains: Vec<Vec<bool>>,
+    constraints: Vec<Constraint>,
+}
+
+impl ConstraintProblem {
+    /// Create a problem with `num_vars` unrestricted variables and no constraints.
+    pub fn new(num_vars: usize, domain_size: usize) -> Self {
+        ConstraintProblem {
+            domain_size,
+            domains: vec![vec![true; domain_size]; num_vars],
+            constraints: Vec::new(),
+        }
+    }
+
+    /// The number of variables.
+    pub fn num_vars(&self) -> usize {
+        self.domains.len()
+    }
+
+    /// The size of the common domain.
+    pub fn domain_size(&self) -> usize {
+        self.domain_size
+    }
+
+    /// The allowed values of variable `v`.
+    pub fn domain(&self, v: usize) -> &[bool] {
+        &self.domains[v]
+    }
+
+    /// The constraints of the problem.
+    pub fn constraints(&self) -> &[Constraint] {
+        &self.constraints
+    }
+
+    /// Restrict variable `v` to the single value `value`.
+    pub fn fix(&mut self, v: usize, value: i32) {
+        for (d, allowed) in self.domains[v].iter_mut().enumerate() {
+            *allowed = *allowed && d as i32 == value;
+        }
+    }
+
+    /// Add a constraint.
+    pub fn add_constraint(&mut self, c: Constraint) {
+        self.constraints.push(c);
+    }
+
+    /// Add a custom constraint.
+    pub fn add_hook(&mut self, hook: impl ConstraintHook + 'static) {
+        self.constraints.push(Constraint::Custom(Arc::new(hook)));
+    }
+
+    /// Whether a complete assignment satisfies every domain and constraint.
+    pub fn is_solution(&self, values: &[i32]) -> bool {
+        values.len() == self.num_vars()
+            && values.iter().enumerate().all(|(v, &d)| d >= 0 && (d as usize) < self.domain_size && self.domains[v][d as usize])
+            && self.constraints.iter().all(|c| c.holds(values, self.domain_size))
+    }
+
+    /// Solve the problem with the given backend.
+    ///
+    /// The search gives up, returning `None`, when the thread's progress
+    /// token (see [`crate::progress::ProgressToken::install`]) is cancelled.
+    ///
+    /// # Returns
+    /// * `Some(values)` - A value for every variable satisfying all constraints
+    /// * `None` - If the problem has no solution
+    pub fn solve(&self, backend: SearchBackend) -> Option<Vec<i32>> {
+        let _timer = crate::telemetry::phase("search");
+        #[cfg(feature = "tracing")]
+        tracing::debug!(?backend, variables = self.num_vars(), domain = self.domain_size,
+            constraints = self.constraints.len(), "solving");
+        let solution = match backend {
+            SearchBackend::Backtrack => self.solve_backtrack(),
+            SearchBackend::ArcConsistency => Propagator::new(self).solve(false),
+            SearchBackend::SingletonArcConsistency => Propagator::new(self).solve(true),
+            #[cfg(feature = "sat")]
+            SearchBackend::Sat => {
+                let mut solver = sat::encode(self);
+                solver.set_cancel_token(crate::progress::current());
+                match solver.solve() {
+                    sat::SatResult::Sat(model) => Some(sat::decode(self, &model)),
+                    sat::SatResult::Unsat => None,
+                    // Only a cancelled token stops an unlimited search.
+                    sat::SatResult::Unknown => None,
+                }
+            }
+        };
+        #[cfg(feature = "tracing")]
+        tracing::debug!(found = solution.is_some(), "solved");
+        solution
+    }
+
+    /// All the solutions of the problem, found maintaining arc consistency.
+    ///
+    /// The search gives up, returning the solutions found so far, when the
+    /// thread's progress token is cancelled; check
+    /// [`crate::progress::check_cancelled`] to tell.
+    ///
+    /// # Arguments
+    /// * `limit` - Stop after this many solutions, if given
+    ///
+    /// # Returns
+    /// The solutions, each a value for every variable
+    pub fn all_solutions(&self, limit: Option<usize>) -> Vec<Vec<i32>> {
+        let mut solutions = Vec::new();
+        if limit == Some(0) {
+            return solutions;
+        }
+        let _timer = crate::telemetry::phase("search");
+        Propagator::new(self).for_each_solution(false, |values| {
+            solutions.push(values.to_vec());
+            limit.is_none_or(|limit| solutions.len() < limit)
+        });
+        #[cfg(feature = "tracing")]
+        tracing::debug!(solutions = solutions.len(), variables = self.num_vars(), "enumerated");
+        solutions
+    }
+
+    fn solve_backtrack(&self) -> Option<Vec<i32>> {
+        let nv = self.num_vars();
+        // Each constraint is checked when its last variable (in search order) is assigned.
+        let mut check_at: Vec<Vec<usize>> = vec![Vec::new(); nv];
+        for (ci, c) in self.constraints.iter().enumerate() {
+            if let Some(&last) = c.vars().iter().max() {
+                check_at[last].push(ci);
+            }
+        }
+        let mut values = vec![-1; nv];
+        let mut v = 0;
+        let token = crate::progress::current();
+        let mut steps = 0u32;
+        while v < nv {
+            steps = steps.wrapping_add(1);
+            if steps.is_multiple_of(1024) && token.as_ref().is_some_and(|t| t.check().is_err()) {
+                return None;
+            }
+            let next = (values[v] + 1..self.domain_size as i32).find(|&d| {
+                if !self.domains[v][d as usize] {
+                    return false;
+                }
+                values[v] = d;
+                check_at[v].iter().all(|&ci| self.constraints[ci].holds(&values, self.domain_size))
+            });
+            match next {
+                Some(d) => {
+                    values[v] = d;
+                    v += 1;
+                }
+                None => {
+                    values[v] = -1;
+                    if v == 0 {
+                        return None;
+                    }
+                    v -= 1;
+                }
+            }
+        }
+        Some(values)
+    }
+}
+
+/// The right side of a linear identity `f(lhs) = rhs`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdentityRhs {
+    /// A variable, given by its index.
+    Var(usize),
+    /// The operation applied to a pattern of variables.
+    Pattern(Vec<usize>),
+}
+
+/// A linear identity for a single operation symbol `f`.
+///
+/// Patterns list variable indices, so the majority law `f(y,x,x) = x`
+/// is `LinearIdentity::new(vec![1, 0, 0], IdentityRhs::Var(0))` and the
+/// Maltsev law `f(x,y,y) = x` is `LinearIdentity::new(vec![0, 1, 1], IdentityRhs::Var(0))`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinearIdentity {
+    pub lhs: Vec<usize>,
+    pub rhs: IdentityRhs,
+}
+
+impl LinearIdentity {
+    /// Create a new linear identity.
+    pub fn new(lhs: Vec<usize>, rhs: IdentityRhs) -> Self {
+        LinearIdentity { lhs, rhs }
+    }
+
+    /// The number of variables the identity mentions.
+    pub fn num_vars(&self) -> usize {
+        let rhs_max = match &self.rhs {
+            IdentityRhs::Var(v) => Some(*v),
+            IdentityRhs::Pattern(p) => p.iter().copied().max(),
+        };
+        self.lhs.iter().copied().max().max(rhs_max).map_or(0, |m| m + 1)
+    }
+
+    /// The idempotent law `f(x,...,x) = x` for the given arity.
+    pub fn idempotent(arity: usize) -> Self {
+        LinearIdentity::new(vec![0; arity], IdentityRhs::Var(0))
+    }
+
+    /// The near-unanimity laws `f(y,x,...,x) = ... = f(x,...,x,y) = x`.
+    pub fn near_unanimity(arity: usize) -> Vec<Self> {
+        (0..arity).map(|i| {
+            let lhs = (0..arity).map(|j| if i == j { 1 } else { 0 }).collect();
+            LinearIdentity::new(lhs, IdentityRhs::Var(0))
+        }).collect()
+    }
+
+    /// The laws of a `k`-edge operation, of arity `k + 1`:
+    /// `f(y,y,x,x,...,x) = f(y,x,y,x,...,x) = x` and, for `3 <= i <= k`,
+    /// `f(x,...,x,y,x,...,x) = x` with `y` in position `i`.
+    pub fn edge(k: usize) -> Vec<Self> {
+        let arity = k + 1;
+        let mut laws = Vec::with_capacity(k);
+        for ys in [[0, 1], [0, 2]] {
+            let lhs = (0..arity).map(|j| if ys.contains(&j) { 1 } else { 0 }).collect();
+            laws.push(LinearIdentity::new(lhs, IdentityRhs::Var(0)));
+        }
+        for i in 3..arity {
+            let lhs = (0..arity).map(|j| if i == j { 1 } else { 0 }).collect();
+            laws.push(LinearIdentity::new(lhs, IdentityRhs::Var(0)));
+        }
+        laws
+    }
+
+    /// The Maltsev laws `f(x,y,y) = x = f(y,y,x)`.
+    pub fn maltsev() -> Vec<Self> {
+        vec![
+            LinearIdentity::new(vec![0, 1, 1], IdentityRhs::Var(0)),
+            LinearIdentity::new(vec![1, 1, 0], IdentityRhs::Var(0)),
+        ]
+    }
+}
+
+/// Build the constraint problem whose solutions are the homomorphisms `a -> b`.
+///
+/// Variable `i` is the image of element `i` of `a`.
+///
+/// # Returns
+/// * `Ok(problem)` - The constraint problem
+/// * `Err(String)` - If an operation of `a` is missing from `b`
+pub fn homomorphism_problem(
+    a: &dyn SmallAlgebra<UniverseItem = i32>,
+    b: &dyn SmallAlgebra<UniverseItem = i32>,
+) -> Result<ConstraintProblem, String> {
+    let n = a.cardinality() as usize;
+    let mut problem = ConstraintProblem::new(n, b.cardinality() as usize);
+    for op_a in a.get_operations_ref() {
+        let sym = op_a.symbol();
+        let op_b = b.get_operation_ref(sym)
+            .ok_or_else(|| format!("Operation {} not found in target algebra", sym.name()))?;
+        let table_a = operation_table(op_a)?;
+        let table_b = operation_table(op_b)?;
+        let arity = sym.arity() as usize;
+        for (k, &value) in table_a.iter().enumerate() {
+            let args = horner::horner_inv_same_size(k as i32, n as i32, arity);
+            problem.add_constraint(Constraint::Table {
+                args: args.iter().map(|&x| x as usize).collect(),
+                result: value as usize,
+                table: table_b.clone(),
+            });
+        }
+    }
+    Ok(problem)
+}
+
+/// Find a homomorphism from `a` to `b`.
+///
+/// # Arguments
+/// * `a` - The domain algebra
+/// * `b` - The target algebra; must have every operation symbol of `a`
+/// * `backend` - The search algorithm to use
+///
+/// # Returns
+/// * `Ok(Some(map))` - A homomorphism, `map[i]` being the image of element `i`
+/// * `Ok(None)` - If there is no homomorphism
+/// * `Err(String)` - If an operation of `a` is missing from `b`
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use uacalc::alg::BasicAlgebra;
+/// use uacalc::alg::op::{OperationSymbol, operations};
+/// use uacalc::alg::search::{find_homomorphism, SearchBackend};
+///
+/// let sym = OperationSymbol::new("s", 1, false);
+/// let z4 = BasicAlgebra::new("Z4".to_string(), (0..4).collect::<HashSet<i32>>(),
+///     vec![operations::make_int_operation(sym.clone(), 4, vec![1, 2, 3, 0]).unwrap()]);
+/// let z2 = BasicAlgebra::new("Z2".to_string(), (0..2).collect::<HashSet<i32>>(),
+///     vec![operations::make_int_operation(sym, 2, vec![1, 0]).unwrap()]);
+/// let h = find_homomorphism(&z4, &z2, SearchBackend::Backtrack).unwrap().unwrap();
+/// assert_eq!(h, vec![0, 1, 0, 1]);
+/// assert!(find_homomorphism(&z2, &z4, SearchBackend::Backtrack).unwrap().is_none());
+/// ```
+pub fn find_homomorphism(
+    a: &dyn SmallAlgebra<UniverseItem = i32>,
+    b: &dyn SmallAlgebra<UniverseItem = i32>,
+    backend: SearchBackend,
+) -> Result<Option<Vec<i32>>, String> {
+    let result = homomorphism_problem(a, b)?.solve(backend);
+    crate::progress::check_cancelled()?;
+    Ok(result)
+}
+
+/// Find an isomorphism from `a` to `b`.
+///
+/// This is a homomorphism search with the images of any two elements
+/// required to differ.
+///
+/// # Arguments
+/// * `a` - The domain algebra
+/// * `b` - The target algebra
+/// * `backend` - The search algorithm to use
+///
+/// # Returns
+/// * `Ok(Some(map))` - An isomorphism, `map[i]` being the image of element `i`
+/// * `Ok(None)` - If the algebras are not isomorphic
+/// * `Err(String)` - If an operation has no table
+pub fn find_isomorphism(
+    a: &dyn SmallAlgebra<UniverseItem = i32>,
+    b: &dyn SmallAlgebra<UniverseItem = i32>,
+    backend: SearchBackend,
+) -> Result<Option<Vec<i32>>, String> {
+    let n = a.cardinality();
+    let ops_b = b.get_operations_ref();
+    if n != b.cardinality()
+        || a.get_operations_ref().len() != ops_b.len()
+        || ops_b.iter().any(|op| a.get_operation_ref(op.symbol()).is_none())
+    {
+        return Ok(None);
+    }
+    let mut problem = homomorphism_problem(a, b)?;
+    let distinct: Arc<[Vec<i32>]> = (0..n)
+        .flat_map(|x| (0..n).filter(move |&y| y != x).map(move |y| vec![x, y]))
+        .collect::<Vec<_>>()
+        .into();
+    for i in 0..n as usize {
+        for j in i + 1..n as usize {
+            problem.add_constraint(Constraint::Relation { vars: vec![i, j], tuples: distinct.clone() });
+        }
+    }
+    let result = problem.solve(backend);
+    crate::progress::check_cancelled()?;
+    Ok(result)
+}
+
+/// Build the constraint problem whose solutions are the `arity`-ary
+/// polymorphisms of `alg` (operations commuting with every operation of
+/// `alg`) that satisfy the given linear identities.
+///
+/// Variable `k` is the value of the polymorphism at the tuple with Horner index `k`.
+pub fn polymorphism_problem(
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    arity: usize,
+    identities: &[LinearIdentity],
+) -> Result<ConstraintProblem, String> {
+    let n = alg.cardinality();
+    let num_points = table_size(n, arity)?;
+    let mut problem = ConstraintProblem::new(num_points, n as usize);
+
+    for op in alg.get_operations_ref() {
+        let table = operation_table(op)?;
+        let r = op.arity() as usize;
+        // One constraint per r x arity matrix of elements (rows are points of A^arity).
+        let rows = table_size(n, arity * r)?;
+        for m in 0..rows {
+            let entries = horner::horner_inv_same_size(m as i32, n, arity * r);
+            let row_points: Vec<usize> = (0..r)
+                .map(|i| horner::horner_same_size(&entries[i * arity..(i + 1) * arity], n) as usize)
+                .collect();
+            let column_values: Vec<i32> = (0..arity).map(|j| {
+                let args: Vec<i32> = (0..r).map(|i| entries[i * arity + j]).collect();
+                table[horner::horner_same_size(&args, n) as usize]
+            }).collect();
+            let result = horner::horner_same_size(&column_values, n) as usize;
+            problem.add_constraint(Constraint::Table { args: row_points, result, table: table.clone() });
+        }
+    }
+
+    add_identities(&mut problem, n, arity, identities)?;
+    Ok(problem)
+}
+
+/// Add the constraints that the polymorphism whose values are the variables
+/// of `problem` satisfies the linear identities.
+fn add_identities(
+    problem: &mut ConstraintProblem,
+    n: i32,
+    arity: usize,
+    identities: &[LinearIdentity],
+) -> Result<(), String> {
+    for identity in identities {
+        if identity.lhs.len() != arity {
+            return Err(format!("Identity pattern has length {}, expected {}", identity.lhs.len(), arity));
+        }
+        let m = identity.num_vars();
+        for k in 0..table_size(n, m)? {
+            let vals = horner::horner_inv_same_size(k as i32, n, m);
+            let point = |pattern: &[usize]| {
+                let args: Vec<i32> = pattern.iter().map(|&p| vals[p]).collect();
+                horner::horner_same_size(&args, n) as usize
+            };
+            let lhs = point(&identity.lhs);
+            match &identity.rhs {
+                // Conflicting fixes leave an empty domain, which both backends treat as infeasible.
+                IdentityRhs::Var(v) => problem.fix(lhs, vals[*v]),
+                IdentityRhs::Pattern(p) => {
+                    if p.len() != arity {
+                        return Err(format!("Identity pattern has length {}, expected {}", p.len(), arity));
+                    }
+                    let rhs = point(p);
+                    if lhs != rhs {
+                        problem.add_constraint(Constraint::Equal(lhs, rhs));
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Find an `arity`-ary polymorphism of `alg` satisfying the given linear identities.
+///
+/// A polymorphism of `alg` is an operation on its universe that commutes
+/// with all of its operations, i.e., a homomorphism `alg^arity -> alg`.
+///
+/// # Arguments
+/// * `alg` - The algebra
+/// * `arity` - The arity of the polymorphism
+/// * `identities` - Linear identities the polymorphism must satisfy
+/// * `backend` - The search algorithm to use
+///
+/// # Returns
+/// * `Ok(Some(table))` - The Horner-encoded table of a polymorphism
+/// * `Ok(None)` - If no such polymorphism exists
+/// * `Err(String)` - If the problem is too large or an identity is malformed
+pub fn find_polymorphism(
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    arity: usize,
+    identities: &[LinearIdentity],
+    backend: SearchBackend,
+) -> Result<Option<Vec<i32>>, String> {
+    let result = polymorphism_problem(alg, arity, identities)?.solve(backend);
+    crate::progress::check_cancelled()?;
+    Ok(result)
+}
+
+/// Check that `table` is the table of a polymorphism of `alg`.
+pub fn is_polymorphism(
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    arity: usize,
+    table: &[i32],
+) -> Result<bool, String> {
+    Ok(polymorphism_problem(alg, arity, &[])?.is_solution(table))
+}
+
+/// Check that the edges of a digraph are pairs of vertices below `n`, and
+/// return them as relation tuples.
+fn edge_tuples(edges: &[(usize, usize)], n: usize) -> Result<Arc<[Vec<i32>]>, String> {
+    if let Some(&(u, v)) = edges.iter().find(|&&(u, v)| u >= n || v >= n) {
+        return Err(format!("Edge ({}, {}) is not between vertices below {}", u, v, n));
+    }
+    Ok(edges.iter().map(|&(u, v)| vec![u as i32, v as i32]).collect::<Vec<_>>().into())
+}
+
+/// Build the constraint problem whose solutions are the digraph
+/// homomorphisms `G -> H`, i.e. the `H`-colorings of `G`.
+///
+/// Variable `i` is the image of vertex `i` of `G`; each edge of `G` must map
+/// to an edge of `H`.
+///
+/// # Returns
+/// * `Ok(problem)` - The constraint problem
+/// * `Err(String)` - If an edge mentions a vertex out of range
+pub fn digraph_homomorphism_problem(
+    g_edges: &[(usize, usize)],
+    g_size: usize,
+    h_edges: &[(usize, usize)],
+    h_size: usize,
+) -> Result<ConstraintProblem, String> {
+    edge_tuples(g_edges, g_size)?;
+    let tuples = edge_tuples(h_edges, h_size)?;
+    let mut problem = ConstraintProblem::new(g_size, h_size);
+    for &(u, v) in g_edges {
+        problem.add_constraint(Constraint::Relation { vars: vec![u, v], tuples: tuples.clone() });
+    }
+    Ok(problem)
+}
+
+/// Find a homomorphism between digraphs given by edge lists, e.g. a
+/// 3-coloring of `G` when `H` is the complete graph `K3`.
+///
+/// # Arguments
+/// * `g_edges`, `g_size` - The edges and number of vertices of `G`
+/// * `h_edges`, `h_size` - The edges and number of vertices of `H`
+/// * `backend` - The search algorithm to use
+///
+/// # Returns
+/// * `Ok(Some(map))` - A homomorphism, `map[i]` being the image of vertex `i`
+/// * `Ok(None)` - If there is no homomorphism
+/// * `Err(String)` - If an edge mentions a vertex out of range
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::search::{find_digraph_homomorphism, SearchBackend};
+///
+/// let cycle = |n: usize| (0..n).flat_map(|i| [(i, (i + 1) % n), ((i + 1) % n, i)]).collect::<Vec<_>>();
+/// // C5 is 3-colorable but not 2-colorable
+/// assert!(find_digraph_homomorphism(&cycle(5), 5, &cycle(3), 3, SearchBackend::ArcConsistency).unwrap().is_some());
+/// assert!(find_digraph_homomorphism(&cycle(5), 5, &[(0, 1), (1, 0)], 2, SearchBackend::ArcConsistency).unwrap().is_none());
+/// ```
+pub fn find_digraph_homomorphism(
+    g_edges: &[(usize, usize)],
+    g_size: usize,
+    h_edges: &[(usize, usize)],
+    h_size: usize,
+    backend: SearchBackend,
+) -> Result<Option<Vec<i32>>, String> {
+    let result = digraph_homomorphism_problem(g_edges, g_size, h_edges, h_size)?.solve(backend);
+    crate::progress::check_cancelled()?;
+    Ok(result)
+}
+
+/// Build the constraint problem whose solutions are the `arity`-ary
+/// polymorphisms of a digraph `H` satisfying the given linear identities:
+/// operations on the vertices mapping every `arity`-tuple of edges,
+/// applied coordinatewise, to an edge.
+///
+/// Variable `k` is the value of the polymorphism at the tuple with Horner index `k`.
+pub fn digraph_polymorphism_problem(
+    edges: &[(usize, usize)],
+    n: usize,
+    arity: usize,
+    identities: &[LinearIdentity],
+) -> Result<ConstraintProblem, String> {
+    let tuples = edge_tuples(edges, n)?;
+    let num_points = table_size(n as i32, arity)?;
+    let mut problem = ConstraintProblem::new(num_points, n);
+    for m in 0..table_size(edges.len() as i32, arity)? {
+        crate::progress::check_cancelled()?;
+        let choice = horner::horner_inv_same_size(m as i32, edges.len() as i32, arity);
+        let sources: Vec<i32> = choice.iter().map(|&e| edges[e as usize].0 as i32).collect();
+        let targets: Vec<i32> = choice.iter().map(|&e| edges[e as usize].1 as i32).collect();
+        let vars = vec![
+            horner::horner_same_size(&sources, n as i32) as usize,
+            horner::horner_same_size(&targets, n as i32) as usize,
+        ];
+        problem.add_constraint(Constraint::Relation { vars, tuples: tuples.clone() });
+    }
+    add_identities(&mut problem, n as i32, arity, identities)?;
+    Ok(problem)
+}
+
+/// Find an `arity`-ary polymorphism of a digraph satisfying the given
+/// linear identities; by the algebraic dichotomy theorem, for example, the
+/// `H`-coloring problem is tractable when `H` has a Siggers polymorphism.
+///
+/// # Arguments
+/// * `edges`, `n` - The edges and number of vertices of the digraph
+/// * `arity` - The arity of the polymorphism
+/// * `identities` - Linear identities the polymorphism must satisfy
+/// * `backend` - The search algorithm to use
+///
+/// # Returns
+/// * `Ok(Some(table))` - The Horner-encoded table of a polymorphism
+/// * `Ok(None)` - If no such polymorphism exists
+/// * `Err(String)` - If the problem is too large, an edge is out of range or
+///   an identity is malformed
+pub fn find_digraph_polymorphism(
+    edges: &[(usize, usize)],
+    n: usize,
+    arity: usize,
+    identities: &[LinearIdentity],
+    backend: SearchBackend,
+) -> Result<Option<Vec<i32>>, String> {
+    let result = digraph_polymorphism_problem(edges, n, arity, identities)?.solve(backend);
+    crate::progress::check_cancelled()?;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::{algebras, BasicAlgebra};
+    use crate::alg::op::{OperationSymbol, operations};
+
+    fn backends() -> Vec<SearchBackend> {
+        vec![
+            SearchBackend::Backtrack,
+            SearchBackend::ArcConsistency,
+            SearchBackend::SingletonArcConsistency,
+            #[cfg(feature = "sat")]
+            SearchBackend::Sat,
+        ]
+    }
+
+    fn binary_algebra(name: &str, size: i32, f: impl Fn(i32, i32) -> i32) -> BasicAlgebra<i32> {
+        let sym = OperationSymbol::new("f", 2, false);
+        let table = (0..size * size).map(|k| f(k % size, k / size)).collect();
+        let op = operations::make_int_operation(sym, size, table).unwrap();
+        BasicAlgebra::new(name.to_string(), (0..size).collect(), vec![op])
+    }
+
+    #[test]
+    fn test_find_homomorphism() {
+        let z6 = binary_algebra("Z6", 6, |x, y| (x + y) % 6);
+        let z3 = binary_algebra("Z3", 3, |x, y| (x + y) % 3);
+        let z4 = binary_algebra("Z4", 4, |x, y| (x + y) % 4);
+        for backend in backends() {
+            let h = find_homomorphism(&z6, &z3, backend).unwrap().unwrap();
+            assert!(algebras::is_homomorphism(&h, &z6, &z3).unwrap());
+            // The only homomorphism Z3 -> Z4 is the zero map.
+            assert_eq!(find_homomorphism(&z3, &z4, backend).unwrap(), Some(vec![0, 0, 0]));
+        }
+    }
+
+    #[test]
+    fn test_find_isomorphism() {
+        let z4 = binary_algebra("Z4", 4, |x, y| (x + y) % 4);
+        // Z4 with 0 and 1 swapped
+        let swap = |x: i32| [1, 0, 2, 3][x as usize];
+        let twisted = binary_algebra("Z4'", 4, |x, y| swap((swap(x) + swap(y)) % 4));
+        let klein = binary_algebra("V", 4, |x, y| x ^ y);
+        for backend in backends() {
+            let h = find_isomorphism(&z4, &twisted, backend).unwrap().unwrap();
+            assert!(algebras::is_homomorphism(&h, &z4, &twisted).unwrap());
+            assert!(find_isomorphism(&z4, &klein, backend).unwrap().is_none());
+        }
+    }
+
+    #[test]
+    fn test_find_homomorphism_with_fixed_points() {
+        let z6 = binary_algebra("Z6", 6, |x, y| (x + y) % 6);
+        let z3 = binary_algebra("Z3", 3, |x, y| (x + y) % 3);
+        let mut problem = homomorphism_problem(&z3, &z6).unwrap();
+        problem.fix(1, 1);
+        for backend in backends() {
+            assert!(problem.solve(backend).is_none());
+        }
+        let mut problem = homomorphism_problem(&z3, &z6).unwrap();
+        problem.fix(1, 2);
+        for backend in backends() {
+            assert_eq!(problem.solve(backend), Some(vec![0, 2, 4]));
+        }
+    }
+
+    #[test]
+    fn test_find_polymorphism_identities() {
+        let z3 = binary_algebra("Z3", 3, |x, y| (x + y) % 3);
+        let semilattice = binary_algebra("S2", 2, |x, y| x.min(y));
+        let neg_sym = OperationSymbol::new("neg", 1, false);
+        let neg = BasicAlgebra::new("N2".to_string(), (0..2).collect(),
+            vec![operations::make_int_operation(neg_sym, 2, vec![1, 0]).unwrap()]);
+        for backend in backends() {
+            let m = find_polymorphism(&z3, 3, &LinearIdentity::maltsev(), backend).unwrap().unwrap();
+            assert!(is_polymorphism(&z3, 3, &m).unwrap());
+            assert_eq!(m[horner::horner_same_size(&[2, 1, 1], 3) as usize], 2);
+
+            // Majority commutes with negation; the semilattice's ternary
+            // polymorphisms are meets of coordinates, so it has neither.
+            let nu = find_polymorphism(&neg, 3, &LinearIdentity::near_unanimity(3), backend).unwrap().unwrap();
+            assert!(is_polymorphism(&neg, 3, &nu).unwrap());
+            assert!(find_polymorphism(&semilattice, 3, &LinearIdentity::near_unanimity(3), backend).unwrap().is_none());
+            assert!(find_polymorphism(&semilattice, 3, &LinearIdentity::maltsev(), backend).unwrap().is_none());
+        }
+    }
+
+    #[test]
+    fn test_edge_polymorphism() {
+        // Z3 has a 2-edge operation (edge terms generalize Maltsev terms);
+        // the semilattice has none of any arity.
+        let z3 = binary_algebra("Z3", 3, |x, y| (x + y) % 3);
+        let semilattice = binary_algebra("S2", 2, |x, y| x.min(y));
+        assert_eq!(LinearIdentity::edge(3).len(), 3);
+        for backend in backends() {
+            let e = find_polymorphism(&z3, 3, &LinearIdentity::edge(2), backend).unwrap().unwrap();
+            assert!(is_polymorphism(&z3, 3, &e).unwrap());
+            assert!(find_polymorphism(&semilattice, 4, &LinearIdentity::edge(3), backend).unwrap().is_none());
+        }
+    }
+
+    #[test]
+    fn test_digraph_homomorphism_and_polymorphism() {
+        let sym = |edges: &[(usize, usize)]| edges.iter().flat_map(|&(u, v)| [(u, v), (v, u)]).collect::<Vec<_>>();
+        let k2 = sym(&[(0, 1)]);
+        let k3 = sym(&[(0, 1), (1, 2), (0, 2)]);
+        let c4 = sym(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+        for backend in backends() {
+            let h = find_digraph_homomorphism(&c4, 4, &k2, 2, backend).unwrap().unwrap();
+            assert!(c4.iter().all(|&(u, v)| h[u] != h[v]));
+            assert!(find_digraph_homomorphism(&k3, 3, &k2, 2, backend).unwrap().is_none());
+            // K2 has a majority polymorphism and K3 does not
+            assert!(find_digraph_polymorphism(&k2, 2, 3, &LinearIdentity::near_unanimity(3), backend).unwrap().is_some());
+            assert!(find_digraph_polymorphism(&k3, 3, 3, &LinearIdentity::near_unanimity(3), backend).unwrap().is_none());
+        }
+        assert!(find_digraph_homomorphism(&[(0, 2)], 2, &k2, 2, SearchBackend::Backtrack).is_err());
+    }
+
+    #[test]
+    fn test_subpower_constraint() {
+        let z4 = binary_algebra("Z4", 4, |x, y| (x + y) % 4);
+        // The subgroup of Z4^2 generated by (1, 2) is {(0,0), (1,2), (2,0), (3,2)}.
+        let sub = subpower(&z4, &[vec![1, 2]]).unwrap();
+        assert_eq!(sub.len(), 4);
+        assert!(sub.contains(&vec![3, 2]));
+
+        // (h(1), h(3)) = (h(1), 3 h(1)) lies in the subgroup only for the zero map.
+        let mut problem = homomorphism_problem(&z4, &z4).unwrap();
+        problem.add_constraint(Constraint::subpower(&z4, vec![1, 3], &[vec![1, 2]]).unwrap());
+        let mut odd = problem.clone();
+        odd.add_constraint(Constraint::Relation { vars: vec![1], tuples: vec![vec![1], vec![3]].into() });
+        for backend in backends() {
+            assert_eq!(problem.solve(backend), Some(vec![0, 0, 0, 0]));
+            assert!(odd.solve(backend).is_none());
+        }
+        assert!(Constraint::subpower(&z4, vec![0, 1], &[vec![1]]).is_err());
+    }
+
+    #[test]
+    fn test_search_backend_from_name() {
+        assert_eq!(SearchBackend::from_name("backtrack"), Ok(SearchBackend::Backtrack));
+        assert_eq!(SearchBackend::from_name("sac"), Ok(SearchBackend::SingletonArcConsistency));
+        assert!(SearchBackend::from_name("nonsense").is_err());
+    }
+}
@@ -0,0 +1,480 @@
+/*! Constraint propagation for `ConstraintProblem`s.
+
+`Propagator` enforces generalized arc consistency with AC-3: every value
+left in a variable's domain has a support, an assignment of the other
+variables of each constraint, drawn from their domains, under which the
+constraint holds. The search built on it (maintaining arc consistency)
+branches on a variable with the smallest domain and propagates after every
+choice, so most dead ends are found without being enumerated. Singleton
+arc consistency additionally removes each value whose assignment alone
+propagates to a wipeout.
+*/
+
+use std::collections::VecDeque;
+use crate::alg::search::{Constraint, ConstraintProblem};
+
+/// An AC-3 propagator and search over a fixed constraint problem.
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::search::{Constraint, ConstraintProblem, Propagator};
+///
+/// let mut problem = ConstraintProblem::new(3, 4);
+/// problem.add_constraint(Constraint::Equal(0, 1));
+/// problem.add_constraint(Constraint::Equal(1, 2));
+/// problem.fix(2, 3);
+///
+/// let propagator = Propagator::new(&problem);
+/// let mut domains = propagator.initial_domains();
+/// assert!(propagator.propagate(&mut domains));
+/// assert_eq!(domains[0], vec![false, false, false, true]);
+/// assert_eq!(propagator.solve(false), Some(vec![3, 3, 3]));
+/// ```
+#[derive(Debug)]
+pub struct Propagator<'a> {
+    problem: &'a ConstraintProblem,
+    /// For each variable, the constraints mentioning it.
+    watchers: Vec<Vec<usize>>,
+}
+
+impl<'a> Propagator<'a> {
+    /// Create a propagator for `problem`.
+    pub fn new(problem: &'a ConstraintProblem) -> Self {
+        let mut watchers = vec![Vec::new(); problem.num_vars()];
+        for (ci, c) in problem.constraints().iter().enumerate() {
+            let mut vars = c.vars();
+            vars.sort_unstable();
+            vars.dedup();
+            for v in vars {
+                watchers[v].push(ci);
+            }
+        }
+        Propagator { problem, watchers }
+    }
+
+    /// The domains of the problem before any propagation.
+    pub fn initial_domains(&self) -> Vec<Vec<bool>> {
+        (0..self.problem.num_vars()).map(|v| self.problem.domain(v).to_vec()).collect()
+    }
+
+    /// Make `domains` arc consistent.
+    ///
+    /// # Returns
+    /// `false` if some domain became empty, in which case the problem has
+    /// no solution within the given domains
+    pub fn propagate(&self, domains: &mut [Vec<bool>]) -> bool {
+        if domains.iter().any(|d| !d.contains(&true)) {
+            return false;
+        }
+        self.propagate_from(domains, 0..self.problem.constraints().len())
+    }
+
+    /// Make `domains` arc consistent after the domain of `v` was narrowed.
+    ///
+    /// `domains` must have been arc consistent before the change.
+    pub fn propagate_var(&self, domains: &mut [Vec<bool>], v: usize) -> bool {
+        if !domains[v].contains(&true) {
+            return false;
+        }
+        self.propagate_from(domains, self.watchers[v].iter().copied())
+    }
+
+    fn propagate_from(&self, domains: &mut [Vec<bool>], start: impl IntoIterator<Item = usize>) -> bool {
+        let constraints = self.problem.constraints();
+        let mut queued = vec![false; constraints.len()];
+        let mut queue = VecDeque::new();
+        for ci in start {
+            if !queued[ci] {
+                queued[ci] = true;
+                queue.push_back(ci);
+            }
+        }
+        let mut before: Vec<Vec<bool>> = Vec::new();
+        while let Some(ci) = queue.pop_front() {
+            queued[ci] = false;
+            let c = &constraints[ci];
+            let mut vars = c.vars();
+            vars.sort_unstable();
+            vars.dedup();
+            before.clear();
+            before.extend(vars.iter().map(|&v| domains[v].clone()));
+            if !revise(c, domains, self.problem.domain_size()) {
+                continue;
+            }
+            for (v, old) in vars.iter().zip(&before) {
+                if domains[*v] == *old {
+                    continue;
+                }
+                if !domains[*v].contains(&true) {
+                    return false;
+                }
+                for &cj in &self.watchers[*v] {
+                    if cj != ci && !queued[cj] {
+                        queued[cj] = true;
+                        queue.push_back(cj);
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// Make `domains` singleton arc consistent.
+    ///
+    /// A value `d` of `v` is kept only if fixing `v = d` and propagating
+    /// leaves every domain nonempty. This is repeated until nothing changes.
+    ///
+    /// # Returns
+    /// `false` if some domain became empty
+    pub fn singleton_propagate(&self, domains: &mut [Vec<bool>]) -> bool {
+        if !self.propagate(domains) {
+            return false;
+        }
+        let n = self.problem.domain_size();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for v in 0..domains.len() {
+                if domains[v].iter().filter(|&&b| b).count() < 2 {
+                    continue;
+                }
+                for d in 0..n {
+                    if !domains[v][d] {
+                        continue;
+                    }
+                    let mut trial = domains.to_vec();
+                    assign(&mut trial[v], d);
+                    if !self.propagate_var(&mut trial, v) {
+                        domains[v][d] = false;
+                        if !self.propagate_var(domains, v) {
+                            return false;
+                        }
+                        changed = true;
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// Search for a solution, maintaining arc consistency.
+    ///
+    /// # Arguments
+    /// * `singleton` - Enforce singleton arc consistency before searching
+    ///
+    /// # Returns
+    /// * `Some(values)` - A solution of the problem
+    /// * `None` - If the problem has no solution
+    pub fn solve(&self, singleton: bool) -> Option<Vec<i32>> {
+        let mut found = None;
+        self.for_each_solution(singleton, |values| {
+            found = Some(values.to_vec());
+            false
+        });
+        found
+    }
+
+    /// Call `f` on the solutions of the problem, maintaining arc
+    /// consistency, until it returns false.
+    ///
+    /// Solutions come in lexicographic order of the branching choices. The
+    /// search stops early when the thread's progress token is cancelled.
+    ///
+    /// # Arguments
+    /// * `singleton` - Enforce singleton arc consistency before searching
+    /// * `f` - Called with each solution; returns whether to go on
+    pub fn for_each_solution(&self, singleton: bool, mut f: impl FnMut(&[i32]) -> bool) {
+        let mut domains = self.initial_domains();
+        let consistent = if singleton {
+            self.singleton_propagate(&mut domains)
+        } else {
+            self.propagate(&mut domains)
+        };
+        if !consistent {
+            return;
+        }
+        // Each frame records the domains before a branching variable was
+        // assigned, that variable, and the next value to try.
+        let mut stack: Vec<(Vec<Vec<bool>>, usize, usize)> = Vec::new();
+        let mut next_branch = choose_var(&domains).map(|v| (v, 0));
+        let token = crate::progress::current();
+        loop {
+            if token.as_ref().is_some_and(|t| t.check().is_err()) {
+                return;
+            }
+            let next = match next_branch {
+                None => {
+                    if !f(&solution(&domains)) {
+                        return;
+                    }
+                    None
+                }
+                Some((v, from)) => (from..self.problem.domain_size()).find(|&d| domains[v][d]).map(|d| (v, d)),
+            };
+            match next {
+                Some((v, d)) => {
+                    let mut trial = domains.clone();
+                    assign(&mut trial[v], d);
+                    if self.propagate_var(&mut trial, v) {
+                        stack.push((std::mem::replace(&mut domains, trial), v, d + 1));
+                        next_branch = choose_var(&domains).map(|w| (w, 0));
+                    } else {
+                        next_branch = Some((v, d + 1));
+                    }
+                }
+                None => {
+                    let Some((saved, w, next)) = stack.pop() else { return };
+                    domains = saved;
+                    next_branch = Some((w, next));
+                }
+            }
+        }
+    }
+}
+
+/// Remove the values of the constraint's variables without support.
+///
+/// # Returns
+/// Whether any domain changed
+pub fn revise(c: &Constraint, domains: &mut [Vec<bool>], domain_size: usize) -> bool {
+    match c {
+        Constraint::Equal(u, v) => {
+            let both: Vec<bool> = domains[*u].iter().zip(&domains[*v]).map(|(a, b)| *a && *b).collect();
+            let changed = domains[*u] != both || domains[*v] != both;
+            domains[*u] = both.clone();
+            domains[*v] = both;
+            changed
+        }
+        Constraint::Table { args, result, table } => {
+            // Enumerate the distinct arguments only: the result is determined.
+            let mut vars = args.clone();
+            vars.sort_unstable();
+            vars.dedup();
+            let result_is_arg = vars.contains(result);
+            let mut support = Supports::new(&vars, domains);
+            let mut result_support = vec![false; domain_size];
+            let mut values = vec![0i32; domains.len()];
+            support.for_each_tuple(domains, &mut values, |values| {
+                let mut index = 0usize;
+                for &a in args.iter().rev() {
+                    index = index * domain_size + values[a] as usize;
+                }
+                let r = table[index];
+                let ok = if result_is_arg { values[*result] == r } else { domains[*result][r as usize] };
+                if ok {
+                    result_support[r as usize] = true;
+                }
+                ok
+            });
+            let mut changed = support.apply(domains);
+            if !result_is_arg {
+                for (allowed, supported) in domains[*result].iter_mut().zip(&result_support) {
+                    if *allowed && !supported {
+                        *allowed = false;
+                        changed = true;
+                    }
+                }
+            }
+            changed
+        }
+        Constraint::Relation { vars, tuples } => {
+            let mut distinct = vars.clone();
+            distinct.sort_unstable();
+            distinct.dedup();
+            let mut support = Supports::new(&distinct, domains);
+            let mut values = vec![-1i32; domains.len()];
+            for t in tuples.iter() {
+                let mut ok = true;
+                for (&v, &x) in vars.iter().zip(t) {
+                    if !domains[v][x as usize] || (values[v] >= 0 && values[v] != x) {
+                        ok = false;
+                        break;
+                    }
+                    values[v] = x;
+                }
+                if ok {
+                    support.mark(&values);
+                }
+                for &v in vars {
+                    values[v] = -1;
+                }
+            }
+            support.apply(domains)
+        }
+        Constraint::Custom(hook) => hook.revise(domains),
+    }
+}
+
+/// Revise a constraint on `vars` by enumerating all tuples of their domains.
+///
+/// `holds` is called with a vector indexed by problem variable in which
+/// the entries of `vars` are set.
+///
+/// # Returns
+/// Whether any domain changed
+pub fn revise_by_enumeration(vars: &[usize], domains: &mut [Vec<bool>], holds: impl Fn(&[i32]) -> bool) -> bool {
+    let mut vars = vars.to_vec();
+    vars.sort_unstable();
+    vars.dedup();
+    let mut support = Supports::new(&vars, domains);
+    let mut values = vec![0i32; domains.len()];
+    support.for_each_tuple(domains, &mut values, holds);
+    support.apply(domains)
+}
+
+/// Supported values found so far for a set of distinct variables.
+struct Supports {
+    vars: Vec<usize>,
+    supported: Vec<Vec<bool>>,
+}
+
+impl Supports {
+    fn new(vars: &[usize], domains: &[Vec<bool>]) -> Self {
+        let n = domains.first().map_or(0, |d| d.len());
+        Supports { vars: vars.to_vec(), supported: vec![vec![false; n]; vars.len()] }
+    }
+
+    fn mark(&mut self, values: &[i32]) {
+        for (i, &v) in self.vars.iter().enumerate() {
+            self.supported[i][values[v] as usize] = true;
+        }
+    }
+
+    /// Call `holds` on every tuple of the current domains of `vars`, marking
+    /// the values of each tuple it accepts.
+    fn for_each_tuple(&mut self, domains: &[Vec<bool>], values: &mut [i32], mut holds: impl FnMut(&[i32]) -> bool) {
+        let choices: Vec<Vec<i32>> = self.vars.iter()
+            .map(|&v| (0..domains[v].len()).filter(|&d| domains[v][d]).map(|d| d as i32).collect())
+            .collect();
+        if choices.iter().any(|c| c.is_empty()) {
+            return;
+        }
+        let mut pos = vec![0usize; self.vars.len()];
+        loop {
+            for (i, &v) in self.vars.iter().enumerate() {
+                values[v] = choices[i][pos[i]];
+            }
+            if holds(values) {
+                self.mark(values);
+            }
+            let mut i = 0;
+            loop {
+                if i == pos.len() {
+                    return;
+                }
+                pos[i] += 1;
+                if pos[i] < choices[i].len() {
+                    break;
+                }
+                pos[i] = 0;
+                i += 1;
+            }
+        }
+    }
+
+    fn apply(&self, domains: &mut [Vec<bool>]) -> bool {
+        let mut changed = false;
+        for (i, &v) in self.vars.iter().enumerate() {
+            for (allowed, supported) in domains[v].iter_mut().zip(&self.supported[i]) {
+                if *allowed && !supported {
+                    *allowed = false;
+                    changed = true;
+                }
+            }
+        }
+        changed
+    }
+}
+
+fn assign(domain: &mut [bool], d: usize) {
+    for (e, allowed) in domain.iter_mut().enumerate() {
+        *allowed = e == d;
+    }
+}
+
+/// The unassigned variable with the fewest remaining values.
+fn choose_var(domains: &[Vec<bool>]) -> Option<usize> {
+    domains.iter()
+        .enumerate()
+        .map(|(v, d)| (v, d.iter().filter(|&&b| b).count()))
+        .filter(|&(_, size)| size > 1)
+        .min_by_key(|&(_, size)| size)
+        .map(|(v, _)| v)
+}
+
+fn solution(domains: &[Vec<bool>]) -> Vec<i32> {
+    domains.iter().map(|d| d.iter().position(|&b| b).unwrap_or(0) as i32).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::search::ConstraintHook;
+
+    /// `x_0 + ... + x_{k-1} = target (mod n)`, revised by enumeration.
+    #[derive(Debug)]
+    struct SumMod {
+        vars: Vec<usize>,
+        n: i32,
+        target: i32,
+    }
+
+    impl ConstraintHook for SumMod {
+        fn vars(&self) -> Vec<usize> {
+            self.vars.clone()
+        }
+
+        fn holds(&self, values: &[i32]) -> bool {
+            self.vars.iter().map(|&v| values[v]).sum::<i32>() % self.n == self.target
+        }
+    }
+
+    #[test]
+    fn test_propagate_table_and_equal() {
+        // x2 = x0 + x1 mod 3, x0 = x1, x2 = 2 forces x0 = x1 = 1, but each
+        // constraint alone supports every value: only SAC sees it.
+        let table: Vec<i32> = (0..9).map(|k| (k % 3 + k / 3) % 3).collect();
+        let mut problem = ConstraintProblem::new(3, 3);
+        problem.add_constraint(Constraint::Table { args: vec![0, 1], result: 2, table: table.into() });
+        problem.add_constraint(Constraint::Equal(0, 1));
+        problem.fix(2, 2);
+        let propagator = Propagator::new(&problem);
+        let mut domains = propagator.initial_domains();
+        assert!(propagator.propagate(&mut domains));
+        assert_eq!(domains[0], vec![true, true, true]);
+        assert!(propagator.singleton_propagate(&mut domains));
+        assert_eq!(domains[0], vec![false, true, false]);
+        assert_eq!(domains[1], vec![false, true, false]);
+        assert_eq!(propagator.solve(false), Some(vec![1, 1, 2]));
+    }
+
+    #[test]
+    fn test_custom_constraint_and_singleton() {
+        // Pairwise distinct variables on a domain that is too small: plain AC
+        // keeps every value, singleton AC detects the contradiction.
+        let mut problem = ConstraintProblem::new(3, 2);
+        for (u, v) in [(0, 1), (1, 2), (0, 2)] {
+            problem.add_hook(SumMod { vars: vec![u, v], n: 2, target: 1 });
+        }
+        let propagator = Propagator::new(&problem);
+        let mut domains = propagator.initial_domains();
+        assert!(propagator.propagate(&mut domains));
+        assert!(!propagator.singleton_propagate(&mut domains));
+        assert_eq!(propagator.solve(false), None);
+
+        let mut problem = ConstraintProblem::new(4, 3);
+        problem.add_hook(SumMod { vars: vec![0, 1, 2, 3], n: 3, target: 2 });
+        problem.fix(0, 1);
+        problem.fix(1, 1);
+        let solution = Propagator::new(&problem).solve(true).unwrap();
+        assert!(problem.is_solution(&solution));
+    }
+
+    #[test]
+    fn test_relation_with_repeated_variable() {
+        let tuples: Vec<Vec<i32>> = vec![vec![0, 1], vec![2, 2]];
+        let mut problem = ConstraintProblem::new(1, 3);
+        problem.add_constraint(Constraint::Relation { vars: vec![0, 0], tuples: tuples.into() });
+        assert_eq!(Propagator::new(&problem).solve(false), Some(vec![2]));
+    }
+}
@@ -0,0 +1,567 @@
+/*! An embedded CDCL SAT solver and the CNF encoding of constraint problems.
+
+The solver is a compact conflict-driven clause-learning solver with two
+watched literals, first-UIP learning, activity-based branching, phase saving
+and Luby restarts. It is sized for the instances produced by homomorphism and
+polymorphism searches on small algebras, not as a general purpose solver.
+*/
+
+use crate::alg::search::{Constraint, ConstraintProblem};
+
+/// A literal: variable `v` is encoded as `2v` (positive) or `2v + 1` (negative).
+pub type Lit = u32;
+
+/// The positive literal of variable `v`.
+pub fn pos(v: usize) -> Lit {
+    (v as Lit) << 1
+}
+
+/// The negative literal of variable `v`.
+pub fn neg(v: usize) -> Lit {
+    ((v as Lit) << 1) | 1
+}
+
+fn var(l: Lit) -> usize {
+    (l >> 1) as usize
+}
+
+/// The outcome of a bounded SAT search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SatResult {
+    /// A satisfying assignment, indexed by variable.
+    Sat(Vec<bool>),
+    /// The clauses are unsatisfiable.
+    Unsat,
+    /// The conflict limit was reached, or the search was cancelled, before a decision.
+    Unknown,
+}
+
+/// A CDCL SAT solver.
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::search::sat::{SatSolver, SatResult, pos, neg};
+///
+/// let mut solver = SatSolver::new(2);
+/// solver.add_clause(&[pos(0), pos(1)]);
+/// solver.add_clause(&[neg(0)]);
+/// assert_eq!(solver.solve(), SatResult::Sat(vec![false, true]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SatSolver {
+    num_vars: usize,
+    clauses: Vec<Vec<Lit>>,
+    watches: Vec<Vec<usize>>,
+    values: Vec<Option<bool>>,
+    level: Vec<usize>,
+    reason: Vec<Option<usize>>,
+    trail: Vec<Lit>,
+    trail_lim: Vec<usize>,
+    qhead: usize,
+    activity: Vec<f64>,
+    var_inc: f64,
+    polarity: Vec<bool>,
+    unsat: bool,
+    conflicts: u64,
+    cancel: Option<crate::progress::ProgressToken>,
+}
+
+impl SatSolver {
+    /// Create a solver over `num_vars` variables and no clauses.
+    pub fn new(num_vars: usize) -> Self {
+        SatSolver {
+            num_vars,
+            clauses: Vec::new(),
+            watches: vec![Vec::new(); 2 * num_vars],
+            values: vec![None; num_vars],
+            level: vec![0; num_vars],
+            reason: vec![None; num_vars],
+            trail: Vec::new(),
+            trail_lim: Vec::new(),
+            qhead: 0,
+            activity: vec![0.0; num_vars],
+            var_inc: 1.0,
+            polarity: vec![false; num_vars],
+            unsat: false,
+            conflicts: 0,
+            cancel: None,
+        }
+    }
+
+    /// Give up with `SatResult::Unknown` once `token` is cancelled; it is
+    /// checked at every conflict.
+    pub fn set_cancel_token(&mut self, token: Option<crate::progress::ProgressToken>) {
+        self.cancel = token;
+    }
+
+    /// Add a fresh variable and return its index.
+    pub fn new_var(&mut self) -> usize {
+        let v = self.num_vars;
+        self.num_vars += 1;
+        self.watches.push(Vec::new());
+        self.watches.push(Vec::new());
+        self.values.push(None);
+        self.level.push(0);
+        self.reason.push(None);
+        self.activity.push(0.0);
+        self.polarity.push(false);
+        v
+    }
+
+    /// The number of variables.
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// The number of clauses, including learnt clauses.
+    pub fn num_clauses(&self) -> usize {
+        self.clauses.len()
+    }
+
+    /// The number of conflicts encountered so far.
+    pub fn conflicts(&self) -> u64 {
+        self.conflicts
+    }
+
+    /// Add a clause, given as a disjunction of literals.
+    ///
+    /// Clauses must be added before `solve` is called.
+    pub fn add_clause(&mut self, lits: &[Lit]) {
+        if self.unsat {
+            return;
+        }
+        let mut clause: Vec<Lit> = lits.to_vec();
+        clause.sort_unstable();
+        clause.dedup();
+        if clause.windows(2).any(|w| var(w[0]) == var(w[1])) {
+            return; // tautology
+        }
+        clause.retain(|&l| self.lit_value(l) != Some(false));
+        if clause.iter().any(|&l| self.lit_value(l) == Some(true)) {
+            return;
+        }
+        match clause.len() {
+            0 => self.unsat = true,
+            1 => {
+                self.enqueue(clause[0], None);
+                if self.propagate().is_some() {
+                    self.unsat = true;
+                }
+            }
+            _ => {
+                self.attach(clause);
+            }
+        }
+    }
+
+    /// Solve without a conflict limit.
+    ///
+    /// # Returns
+    /// * `SatResult::Sat(model)` - A satisfying assignment
+    /// * `SatResult::Unsat` - If the clauses are unsatisfiable
+    /// * `SatResult::Unknown` - If the cancel token was cancelled first
+    pub fn solve(&mut self) -> SatResult {
+        self.solve_limited(None)
+    }
+
+    /// Solve, giving up after `max_conflicts` conflicts if a limit is given.
+    pub fn solve_limited(&mut self, max_conflicts: Option<u64>) -> SatResult {
+        if self.unsat {
+            return SatResult::Unsat;
+        }
+        let mut restart = 0u32;
+        let mut restart_limit = self.conflicts + 100 * luby(restart);
+        loop {
+            if let Some(confl) = self.propagate() {
+                self.conflicts += 1;
+                if self.trail_lim.is_empty() {
+                    self.unsat = true;
+                    return SatResult::Unsat;
+                }
+                let (learnt, bt_level) = self.analyze(confl);
+                self.backtrack(bt_level);
+                if learnt.len() == 1 {
+                    self.enqueue(learnt[0], None);
+                } else {
+                    let first = learnt[0];
+                    let ci = self.attach(learnt);
+                    self.enqueue(first, Some(ci));
+                }
+                self.var_inc /= 0.95;
+                if max_conflicts.is_some_and(|m| self.conflicts >= m)
+                    || self.cancel.as_ref().is_some_and(|t| t.check().is_err())
+                {
+                    self.backtrack(0);
+                    return SatResult::Unknown;
+                }
+                if self.conflicts >= restart_limit {
+                    self.backtrack(0);
+                    restart += 1;
+                    restart_limit = self.conflicts + 100 * luby(restart);
+                }
+            } else {
+                match self.pick_branch_var() {
+                    Some(v) => {
+                        self.trail_lim.push(self.trail.len());
+                        let lit = if self.polarity[v] { pos(v) } else { neg(v) };
+                        self.enqueue(lit, None);
+                    }
+                    None => {
+                        let model = self.values.iter().map(|v| v.unwrap_or(false)).collect();
+                        self.backtrack(0);
+                        return SatResult::Sat(model);
+                    }
+                }
+            }
+        }
+    }
+
+    fn lit_value(&self, l: Lit) -> Option<bool> {
+        self.values[var(l)].map(|b| b ^ (l & 1 == 1))
+    }
+
+    fn attach(&mut self, clause: Vec<Lit>) -> usize {
+        let ci = self.clauses.len();
+        self.watches[clause[0] as usize].push(ci);
+        self.watches[clause[1] as usize].push(ci);
+        self.clauses.push(clause);
+        ci
+    }
+
+    fn enqueue(&mut self, l: Lit, reason: Option<usize>) {
+        let v = var(l);
+        self.values[v] = Some(l & 1 == 0);
+        self.level[v] = self.trail_lim.len();
+        self.reason[v] = reason;
+        self.trail.push(l);
+    }
+
+    fn propagate(&mut self) -> Option<usize> {
+        while self.qhead < self.trail.len() {
+            let false_lit = self.trail[self.qhead] ^ 1;
+            self.qhead += 1;
+            let mut ws = std::mem::take(&mut self.watches[false_lit as usize]);
+            let mut i = 0;
+            let mut j = 0;
+            let mut conflict = None;
+            while i < ws.len() {
+                let ci = ws[i];
+                i += 1;
+                if self.clauses[ci][0] == false_lit {
+                    self.clauses[ci].swap(0, 1);
+                }
+                let first = self.clauses[ci][0];
+                if self.lit_value(first) == Some(true) {
+                    ws[j] = ci;
+                    j += 1;
+                    continue;
+                }
+                let len = self.clauses[ci].len();
+                let new_watch = (2..len).find(|&k| self.lit_value(self.clauses[ci][k]) != Some(false));
+                if let Some(k) = new_watch {
+                    self.clauses[ci].swap(1, k);
+                    let w = self.clauses[ci][1];
+                    self.watches[w as usize].push(ci);
+                    continue;
+                }
+                ws[j] = ci;
+                j += 1;
+                if self.lit_value(first) == Some(false) {
+                    conflict = Some(ci);
+                    while i < ws.len() {
+                        ws[j] = ws[i];
+                        j += 1;
+                        i += 1;
+                    }
+                } else {
+                    self.enqueue(first, Some(ci));
+                }
+            }
+            ws.truncate(j);
+            self.watches[false_lit as usize] = ws;
+            if conflict.is_some() {
+                self.qhead = self.trail.len();
+                return conflict;
+            }
+        }
+        None
+    }
+
+    fn analyze(&mut self, mut confl: usize) -> (Vec<Lit>, usize) {
+        let current = self.trail_lim.len();
+        let mut seen = vec![false; self.num_vars];
+        let mut learnt = vec![0];
+        let mut counter = 0;
+        let mut p: Option<Lit> = None;
+        let mut idx = self.trail.len();
+        loop {
+            let start = if p.is_some() { 1 } else { 0 };
+            for k in start..self.clauses[confl].len() {
+                let q = self.clauses[confl][k];
+                let v = var(q);
+                if !seen[v] && self.level[v] > 0 {
+                    seen[v] = true;
+                    self.bump(v);
+                    if self.level[v] >= current {
+                        counter += 1;
+                    } else {
+                        learnt.push(q);
+                    }
+                }
+            }
+            loop {
+                idx -= 1;
+                if seen[var(self.trail[idx])] {
+                    break;
+                }
+            }
+            let lit = self.trail[idx];
+            seen[var(lit)] = false;
+            counter -= 1;
+            p = Some(lit);
+            if counter == 0 {
+                break;
+            }
+            confl = self.reason[var(lit)].expect("implied literal without a reason");
+        }
+        learnt[0] = p.unwrap() ^ 1;
+
+        let mut bt_level = 0;
+        if learnt.len() > 1 {
+            let mut max_i = 1;
+            for k in 2..learnt.len() {
+                if self.level[var(learnt[k])] > self.level[var(learnt[max_i])] {
+                    max_i = k;
+                }
+            }
+            learnt.swap(1, max_i);
+            bt_level = self.level[var(learnt[1])];
+        }
+        (learnt, bt_level)
+    }
+
+    fn bump(&mut self, v: usize) {
+        self.activity[v] += self.var_inc;
+        if self.activity[v] > 1e100 {
+            for a in self.activity.iter_mut() {
+                *a *= 1e-100;
+            }
+            self.var_inc *= 1e-100;
+        }
+    }
+
+    fn backtrack(&mut self, level: usize) {
+        if self.trail_lim.len() <= level {
+            return;
+        }
+        let lim = self.trail_lim[level];
+        for &l in &self.trail[lim..] {
+            let v = var(l);
+            self.polarity[v] = l & 1 == 0;
+            self.values[v] = None;
+            self.reason[v] = None;
+        }
+        self.trail.truncate(lim);
+        self.trail_lim.truncate(level);
+        self.qhead = self.trail.len();
+    }
+
+    fn pick_branch_var(&self) -> Option<usize> {
+        let mut best: Option<usize> = None;
+        for v in 0..self.num_vars {
+            if self.values[v].is_none() && best.is_none_or(|b| self.activity[v] > self.activity[b]) {
+                best = Some(v);
+            }
+        }
+        best
+    }
+}
+
+/// The Luby restart sequence 1, 1, 2, 1, 1, 2, 4, ...
+fn luby(i: u32) -> u64 {
+    let mut i = i as u64 + 1;
+    loop {
+        let mut k = 1;
+        while (1u64 << k) - 1 < i {
+            k += 1;
+        }
+        if (1u64 << k) - 1 == i {
+            return 1 << (k - 1);
+        }
+        i -= (1u64 << (k - 1)) - 1;
+    }
+}
+
+/// Encode a constraint problem as CNF using one boolean per (variable, value) pair.
+pub fn encode(problem: &ConstraintProblem) -> SatSolver {
+    let n = problem.domain_size();
+    let bv = |v: usize, d: usize| v * n + d;
+    let mut solver = SatSolver::new(problem.num_vars() * n);
+    for v in 0..problem.num_vars() {
+        let allowed = problem.domain(v);
+        let alo: Vec<Lit> = (0..n).filter(|&d| allowed[d]).map(|d| pos(bv(v, d))).collect();
+        solver.add_clause(&alo);
+        for d in 0..n {
+            if !allowed[d] {
+                solver.add_clause(&[neg(bv(v, d))]);
+            }
+            for e in (d + 1)..n {
+                if allowed[d] && allowed[e] {
+                    solver.add_clause(&[neg(bv(v, d)), neg(bv(v, e))]);
+                }
+            }
+        }
+    }
+    for c in problem.constraints() {
+        match c {
+            Constraint::Equal(u, v) => {
+                for d in 0..n {
+                    solver.add_clause(&[neg(bv(*u, d)), pos(bv(*v, d))]);
+                    solver.add_clause(&[pos(bv(*u, d)), neg(bv(*v, d))]);
+                }
+            }
+            Constraint::Table { args, result, table } => {
+                let mut tuple = vec![0usize; args.len()];
+                for &value in table.iter() {
+                    let mut clause: Vec<Lit> = args.iter().zip(&tuple).map(|(&a, &t)| neg(bv(a, t))).collect();
+                    clause.push(pos(bv(*result, value as usize)));
+                    solver.add_clause(&clause);
+                    for t in tuple.iter_mut() {
+                        *t += 1;
+                        if *t < n {
+                            break;
+                        }
+                        *t = 0;
+                    }
+                }
+            }
+            Constraint::Relation { vars, tuples } => {
+                // One selector per tuple: some tuple is chosen, and a chosen
+                // tuple fixes the values of `vars`.
+                let mut alo = Vec::with_capacity(tuples.len());
+                for t in tuples.iter() {
+                    let s = solver.new_var();
+                    alo.push(pos(s));
+                    for (&v, &x) in vars.iter().zip(t) {
+                        solver.add_clause(&[neg(s), pos(bv(v, x as usize))]);
+                    }
+                }
+                solver.add_clause(&alo);
+            }
+            Constraint::Custom(hook) => {
+                // Forbid every failing tuple of the variables' domains.
+                let mut vars = hook.vars();
+                vars.sort_unstable();
+                vars.dedup();
+                let mut values = vec![0i32; problem.num_vars()];
+                let choices: Vec<Vec<usize>> = vars.iter()
+                    .map(|&v| (0..n).filter(|&d| problem.domain(v)[d]).collect())
+                    .collect();
+                if choices.iter().any(|c| c.is_empty()) {
+                    continue;
+                }
+                let mut idx = vec![0usize; vars.len()];
+                loop {
+                    for (i, &v) in vars.iter().enumerate() {
+                        values[v] = choices[i][idx[i]] as i32;
+                    }
+                    if !hook.holds(&values) {
+                        let clause: Vec<Lit> = vars.iter().map(|&v| neg(bv(v, values[v] as usize))).collect();
+                        solver.add_clause(&clause);
+                    }
+                    let mut i = 0;
+                    while i < idx.len() {
+                        idx[i] += 1;
+                        if idx[i] < choices[i].len() {
+                            break;
+                        }
+                        idx[i] = 0;
+                        i += 1;
+                    }
+                    if i == idx.len() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    solver
+}
+
+/// Decode a model of `encode(problem)` into a value per problem variable.
+pub fn decode(problem: &ConstraintProblem, model: &[bool]) -> Vec<i32> {
+    let n = problem.domain_size();
+    (0..problem.num_vars())
+        .map(|v| (0..n).find(|&d| model[v * n + d]).unwrap_or(0) as i32)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_luby() {
+        let seq: Vec<u64> = (0..8).map(luby).collect();
+        assert_eq!(seq, vec![1, 1, 2, 1, 1, 2, 4, 1]);
+    }
+
+    #[test]
+    fn test_pigeonhole_unsat() {
+        // 4 pigeons in 3 holes: p(i,h) = 3i + h
+        let mut solver = SatSolver::new(12);
+        for i in 0..4 {
+            solver.add_clause(&[pos(3 * i), pos(3 * i + 1), pos(3 * i + 2)]);
+        }
+        for h in 0..3 {
+            for i in 0..4 {
+                for j in (i + 1)..4 {
+                    solver.add_clause(&[neg(3 * i + h), neg(3 * j + h)]);
+                }
+            }
+        }
+        assert_eq!(solver.solve(), SatResult::Unsat);
+    }
+
+    #[test]
+    fn test_cancelled_is_unknown() {
+        // Two pigeons, one hole: the first decision already conflicts.
+        let token = crate::progress::ProgressToken::new();
+        token.cancel();
+        let mut solver = SatSolver::new(2);
+        solver.add_clause(&[neg(0), neg(1)]);
+        solver.add_clause(&[pos(0), pos(1)]);
+        solver.add_clause(&[pos(0), neg(1)]);
+        solver.add_clause(&[neg(0), pos(1)]);
+        solver.set_cancel_token(Some(token));
+        assert_eq!(solver.solve(), SatResult::Unknown);
+    }
+
+    #[test]
+    fn test_random_3sat_models_are_valid() {
+        // A fixed pseudo-random satisfiable-ish instance; any model returned must satisfy it.
+        let mut seed: u64 = 12345;
+        let mut next = || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (seed >> 33) as usize
+        };
+        let nv = 30;
+        let mut clauses = Vec::new();
+        for _ in 0..110 {
+            let c: Vec<Lit> = (0..3).map(|_| {
+                let v = next() % nv;
+                if next() % 2 == 0 { pos(v) } else { neg(v) }
+            }).collect();
+            clauses.push(c);
+        }
+        let mut solver = SatSolver::new(nv);
+        for c in &clauses {
+            solver.add_clause(c);
+        }
+        if let SatResult::Sat(model) = solver.solve() {
+            for c in &clauses {
+                assert!(c.iter().any(|&l| model[var(l)] == (l & 1 == 0)));
+            }
+        }
+    }
+}
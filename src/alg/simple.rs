@@ -0,0 +1,209 @@
+/*! Simple and strictly simple algebras.
+
+An algebra is simple if it has at least two elements and its only
+congruences are the two trivial ones. Every nonzero congruence contains a
+principal congruence `Cg(a, b)`, so A is simple exactly when `Cg(a, b)` is
+the full congruence for every pair `a ≠ b`; the checks below compute these
+one at a time and never build Con(A).
+
+A simple algebra is strictly simple if it has no subuniverse other than A
+with two or more elements. Such a subuniverse contains `Sg(a, b)` for two
+of its elements, so only the subuniverses generated by two elements are
+computed, never Sub(A).
+*/
+
+use std::fmt;
+use crate::alg::SmallAlgebra;
+use crate::alg::conlat::CongruenceLattice;
+use crate::alg::conlat::partition::Partition;
+use crate::alg::sublat::BasicSet;
+use crate::alg::subuniverse_graph::close;
+
+/// The outcome of a simplicity check with its witness on failure.
+#[derive(Debug, Clone)]
+pub struct SimplicityReport {
+    /// Whether the algebra has a single element, so is not simple.
+    pub trivial: bool,
+    /// A congruence other than 0 and 1, if there is one.
+    pub congruence: Option<Partition>,
+    /// A proper subuniverse with at least two elements; only looked for by
+    /// `check_strictly_simple`, and only when the algebra is simple.
+    pub subuniverse: Option<BasicSet>,
+}
+
+impl SimplicityReport {
+    /// Whether the checked property holds: no witness was found.
+    pub fn holds(&self) -> bool {
+        !self.trivial && self.congruence.is_none() && self.subuniverse.is_none()
+    }
+}
+
+impl fmt::Display for SimplicityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.trivial {
+            write!(f, "not simple: the algebra has one element")
+        } else if let Some(theta) = &self.congruence {
+            write!(f, "not simple: {} is a nontrivial proper congruence", theta)
+        } else if let Some(s) = &self.subuniverse {
+            write!(f, "not strictly simple: {} is a nontrivial proper subuniverse", s)
+        } else {
+            write!(f, "holds")
+        }
+    }
+}
+
+/// Check whether an algebra is simple, finding a witness if not.
+///
+/// The principal congruences are computed pair by pair and the first one
+/// that is not the full congruence is returned.
+///
+/// # Arguments
+/// * `alg` - The algebra
+///
+/// # Returns
+/// * `Ok(SimplicityReport)` - With `congruence` set when the algebra has a
+///   nontrivial proper congruence and `trivial` set when it has one element
+/// * `Err(String)` - If the computation was cancelled
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use uacalc::alg::BasicAlgebra;
+/// use uacalc::alg::op::{OperationSymbol, operations};
+/// use uacalc::alg::simple::check_simple;
+///
+/// // Z4 under addition has the congruence modulo 2
+/// let add = OperationSymbol::new("add", 2, false);
+/// let op = operations::make_int_operation(add, 4, (0..16).map(|k| (k % 4 + k / 4) % 4).collect()).unwrap();
+/// let alg = BasicAlgebra::new("Z4".to_string(), (0..4).collect::<HashSet<i32>>(), vec![op]);
+///
+/// let report = check_simple(&alg).unwrap();
+/// assert!(!report.holds());
+/// assert_eq!(report.congruence.unwrap().number_of_blocks(), 2);
+/// ```
+pub fn check_simple(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<SimplicityReport, String> {
+    let n = alg.cardinality() as usize;
+    let mut report = SimplicityReport { trivial: n < 2, congruence: None, subuniverse: None };
+    if report.trivial {
+        return Ok(report);
+    }
+    let mut con = CongruenceLattice::new(alg.clone_box());
+    for a in 0..n {
+        crate::progress::check_cancelled()?;
+        for b in a + 1..n {
+            let theta = con.cg(a, b);
+            if theta.number_of_blocks() > 1 {
+                report.congruence = Some(theta);
+                return Ok(report);
+            }
+        }
+    }
+    Ok(report)
+}
+
+/// Check whether an algebra is strictly simple, finding a witness if not.
+///
+/// The algebra is first checked to be simple; then the subuniverses
+/// `Sg(a, b)` are generated pair by pair and the first proper one is
+/// returned.
+///
+/// # Arguments
+/// * `alg` - The algebra
+///
+/// # Returns
+/// * `Ok(SimplicityReport)` - As for `check_simple`, with `subuniverse` set
+///   when the algebra is simple but has a nontrivial proper subuniverse
+/// * `Err(String)` - If an operation cannot be evaluated or the computation
+///   was cancelled
+pub fn check_strictly_simple(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<SimplicityReport, String> {
+    let mut report = check_simple(alg)?;
+    if !report.holds() {
+        return Ok(report);
+    }
+    let n = alg.cardinality();
+    let ops = alg.get_operations_ref();
+    let mut constants = Vec::new();
+    for op in ops.iter().filter(|op| op.arity() == 0) {
+        constants.push(op.int_value_at(&[])?);
+    }
+    let bottom = close(&ops, n, &BasicSet::new(Vec::new())?, &constants)?;
+    for a in 0..n {
+        crate::progress::check_cancelled()?;
+        for b in a + 1..n {
+            let s = close(&ops, n, &bottom, &[a, b])?;
+            if s.size() < n as usize {
+                report.subuniverse = Some(s);
+                return Ok(report);
+            }
+        }
+    }
+    Ok(report)
+}
+
+/// Test whether an algebra is simple.
+///
+/// # Returns
+/// * `Ok(bool)` - Whether it has at least two elements and no congruence
+///   other than 0 and 1
+/// * `Err(String)` - If the computation was cancelled
+pub fn is_simple(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<bool, String> {
+    Ok(check_simple(alg)?.holds())
+}
+
+/// Test whether an algebra is strictly simple.
+///
+/// # Returns
+/// * `Ok(bool)` - Whether it is simple and its only subuniverse with two or
+///   more elements is the whole algebra
+/// * `Err(String)` - If an operation cannot be evaluated or the computation
+///   was cancelled
+pub fn is_strictly_simple(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<bool, String> {
+    Ok(check_strictly_simple(alg)?.holds())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::BasicAlgebra;
+    use crate::alg::op::OperationSymbol;
+    use crate::alg::op::operations::make_int_operation;
+
+    fn zn(n: i32) -> BasicAlgebra<i32> {
+        let add = make_int_operation(OperationSymbol::new("add", 2, false), n, (0..n * n).map(|k| (k % n + k / n) % n).collect()).unwrap();
+        BasicAlgebra::new(format!("Z{}", n), (0..n).collect(), vec![add])
+    }
+
+    #[test]
+    fn test_simple() {
+        assert!(is_simple(&zn(5)).unwrap());
+        assert!(is_strictly_simple(&zn(5)).unwrap());
+        assert!(!is_simple(&zn(6)).unwrap());
+        let one = check_simple(&zn(1)).unwrap();
+        assert!(one.trivial && !one.holds());
+        assert_eq!(one.to_string(), "not simple: the algebra has one element");
+    }
+
+    #[test]
+    fn test_strictly_simple_witness() {
+        let meet = make_int_operation(OperationSymbol::new("meet", 2, false), 2, vec![0, 0, 0, 1]).unwrap();
+        let two = BasicAlgebra::new("2".to_string(), (0..2).collect(), vec![meet]);
+        assert!(is_strictly_simple(&two).unwrap());
+
+        // f swaps 1 and 2, and m is the first projection except m(0, 2) = 1.
+        // Every principal congruence is full, but {1, 2} is closed.
+        let f = make_int_operation(OperationSymbol::new("f", 1, false), 3, vec![0, 2, 1]).unwrap();
+        let m = make_int_operation(OperationSymbol::new("m", 2, false), 3, (0..9).map(|k| if k == 6 { 1 } else { k % 3 }).collect()).unwrap();
+        let alg = BasicAlgebra::new("A".to_string(), (0..3).collect(), vec![f.clone_box(), m]);
+        assert!(is_simple(&alg).unwrap());
+        let report = check_strictly_simple(&alg).unwrap();
+        assert!(!report.holds());
+        assert_eq!(report.subuniverse.as_ref().unwrap().elements(), &vec![1, 2]);
+        assert_eq!(report.to_string(), "not strictly simple: {1,2} is a nontrivial proper subuniverse");
+
+        // Without m, {0}{1,2} is a congruence.
+        let swap = BasicAlgebra::new("S".to_string(), (0..3).collect(), vec![f]);
+        let report = check_strictly_simple(&swap).unwrap();
+        assert_eq!(report.congruence.unwrap().number_of_blocks(), 2);
+        assert!(report.subuniverse.is_none());
+    }
+}
@@ -38,6 +38,15 @@ pub trait SmallAlgebra: Algebra {
     /// This is a workaround for the limitation of not being able to clone trait objects.
     fn get_operations_ref(&self) -> Vec<&dyn Operation>;
     
+    /// Get the operations as shared handles, for algebras built on this one.
+    /// 
+    /// Algebras that store their operations behind `Arc` hand out the same
+    /// operations, so views such as quotients share the tables instead of
+    /// copying them; the default copies each operation once.
+    fn operations_arc(&self) -> Vec<std::sync::Arc<dyn Operation>> {
+        self.get_operations_ref().into_iter().map(|op| std::sync::Arc::from(op.clone_box())).collect()
+    }
+    
     /// Clone this algebra into a new boxed trait object.
     /// 
     /// This allows cloning of trait objects by delegating to the concrete type's
@@ -419,64 +428,63 @@ where
         self.base.get_operations_ref()
     }
     
+    fn operations_arc(&self) -> Vec<std::sync::Arc<dyn Operation>> {
+        self.base.operations_ref_arc().to_vec()
+    }
+    
     fn clone_box(&self) -> Box<dyn SmallAlgebra<UniverseItem = Self::UniverseItem>> {
-        // Create a new BasicAlgebra with the same properties
-        // Key insight: Preserve operations by forcing them to have tables first,
-        // then reconstruct from those tables.
+        // Create a new BasicAlgebra with the same properties.
+        // Operations that have tables are immutable, so the copy shares them;
+        // the others get tables of their own, computed from int_value_at.
         let universe: HashSet<T> = self.base.universe().collect();
         let mut new_base = GeneralAlgebra::new_with_universe(
             self.base.name().to_string(),
             universe.clone(),
         );
         
-        // Clone all operations from the current algebra
-        // For each operation, if it doesn't have a table, force it to create one
-        let ops = self.base.get_operations_ref();
-        let mut operations_to_add = Vec::new();
+        let mut operations_to_add: Vec<std::sync::Arc<dyn Operation>> = Vec::new();
         
-        for op_ref in ops {
-            // Try to get the table - if missing, compute it
-            let table = if let Some(t) = op_ref.get_table() {
-                t.to_vec()
+        for op in self.base.operations_ref_arc() {
+            if op.get_table().is_some() {
+                operations_to_add.push(std::sync::Arc::clone(op));
+                continue;
+            }
+            let op_ref = op.as_ref();
+            // This works for operations that implement int_value_at correctly
+            let arity = op_ref.arity();
+            let set_size = op_ref.get_set_size();
+            
+            if arity < 0 || set_size <= 0 {
+                // Skip operations with invalid arity or set_size
+                continue;
+            }
+            
+            // Compute the table by calling int_value_at for all argument combinations
+            let table_size = if arity == 0 {
+                1
             } else {
-                // For operations without tables, we need to compute the table
-                // This works for operations that implement int_value_at correctly
-                let arity = op_ref.arity();
-                let set_size = op_ref.get_set_size();
-                
-                if arity < 0 || set_size <= 0 {
-                    // Skip operations with invalid arity or set_size
-                    continue;
-                }
-                
-                // Compute the table by calling int_value_at for all argument combinations
-                let table_size = if arity == 0 {
-                    1
-                } else {
-                    (set_size as usize).pow(arity as u32)
-                };
-                
-                let mut table = Vec::with_capacity(table_size);
-                
-                use crate::util::horner;
-                for k in 0..table_size {
-                    let args = horner::horner_inv_same_size(k as i32, set_size, arity as usize);
-                    match op_ref.int_value_at(&args) {
-                        Ok(val) => table.push(val),
-                        Err(_) => {
-                            // If we can't compute a value, skip this operation
-                            eprintln!("Warning: Could not compute value for operation {}", op_ref.symbol());
-                            table.clear();
-                            break;
-                        }
+                (set_size as usize).pow(arity as u32)
+            };
+            
+            let mut table = Vec::with_capacity(table_size);
+            
+            use crate::util::horner;
+            for k in 0..table_size {
+                let args = horner::horner_inv_same_size(k as i32, set_size, arity as usize);
+                match op_ref.int_value_at(&args) {
+                    Ok(val) => table.push(val),
+                    Err(_) => {
+                        // If we can't compute a value, skip this operation
+                        eprintln!("Warning: Could not compute value for operation {}", op_ref.symbol());
+                        table.clear();
+                        break;
                     }
                 }
-                
-                if table.is_empty() {
-                    continue; // Skip operations we couldn't compute
-                }
-                table
-            };
+            }
+            
+            if table.is_empty() {
+                continue; // Skip operations we couldn't compute
+            }
             
             // Create a new operation with the table
             if let Ok(new_op) = crate::alg::op::operations::make_int_operation(
@@ -484,12 +492,12 @@ where
                 op_ref.get_set_size(),
                 table,
             ) {
-                operations_to_add.push(new_op);
+                operations_to_add.push(std::sync::Arc::from(new_op));
             }
         }
         
         if !operations_to_add.is_empty() {
-            new_base.set_operations(operations_to_add);
+            *new_base.operations_mut_arc() = operations_to_add;
         }
         
         Box::new(BasicAlgebra {
@@ -575,3 +583,43 @@ where
         write!(f, "BasicAlgebra({})", self.base)
     }
 }
+
+impl BasicAlgebra<i32> {
+    /// The operations as `(symbol, table)` pairs in symbol order.
+    ///
+    /// An operation that cannot be evaluated gets an empty table.
+    fn operation_tables(&self) -> Vec<(OperationSymbol, Vec<i32>)> {
+        let mut tables: Vec<(OperationSymbol, Vec<i32>)> = self
+            .operations_ref_arc()
+            .iter()
+            .map(|op| {
+                let table = crate::terms::compiled_term::operation_table(op.as_ref())
+                    .map(|t| t.to_vec())
+                    .unwrap_or_default();
+                (op.symbol().clone(), table)
+            })
+            .collect();
+        tables.sort();
+        tables
+    }
+}
+
+/// Structural equality: two algebras are equal when they have the same
+/// universe and the same operations, compared by symbol and table. Names
+/// and descriptions are ignored.
+impl PartialEq for BasicAlgebra<i32> {
+    fn eq(&self, other: &Self) -> bool {
+        self.base.universe == other.base.universe && self.operation_tables() == other.operation_tables()
+    }
+}
+
+impl Eq for BasicAlgebra<i32> {}
+
+impl Hash for BasicAlgebra<i32> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let mut universe: Vec<i32> = self.base.universe.iter().copied().collect();
+        universe.sort_unstable();
+        universe.hash(state);
+        self.operation_tables().hash(state);
+    }
+}
@@ -915,7 +915,12 @@ where
     }
     
     fn constant_operations(&self) -> Vec<Box<dyn Operation>> {
-        Vec::new() // Simplified
+        // Shared with the algebra, like the other operations.
+        self.operations
+            .iter()
+            .filter(|op| op.arity() == 0)
+            .map(|op| boxed_arc_op(Arc::clone(op)))
+            .collect()
     }
     
     fn is_idempotent(&self) -> bool {
@@ -982,6 +987,10 @@ where
         self.operations.iter().map(|arc| arc.as_ref() as &dyn Operation).collect()
     }
     
+    fn operations_arc(&self) -> Vec<std::sync::Arc<dyn Operation>> {
+        self.operations.clone()
+    }
+    
     fn clone_box(&self) -> Box<dyn SmallAlgebra<UniverseItem = Self::UniverseItem>> {
         Box::new(self.clone())
     }
@@ -575,6 +575,10 @@ where
         self.base.get_operations_ref()
     }
     
+    fn operations_arc(&self) -> Vec<std::sync::Arc<dyn Operation>> {
+        self.base.operations_ref_arc().to_vec()
+    }
+    
     fn clone_box(&self) -> Box<dyn SmallAlgebra<UniverseItem = Self::UniverseItem>> {
         Box::new(self.clone())
     }
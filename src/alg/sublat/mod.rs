@@ -1,3 +1,7 @@
+pub mod represent;
+
+pub use represent::represent;
+
 use std::cmp::Ordering;
 use std::collections::HashSet;
 use std::fmt::{self, Debug, Display};
@@ -473,6 +477,10 @@ pub const MAX_DRAWABLE_SIZE: usize = 100;
 /// ordered by inclusion. It provides methods for computing subalgebras,
 /// join and meet operations, and lattice-theoretic properties.
 /// 
+/// Lists of subalgebras, such as [`universe_list`](Self::universe_list) and
+/// the join irreducibles, are in the order of [`BasicSet`]: by size, then
+/// lexicographically, which does not depend on how they were found.
+/// 
 /// # Examples
 /// ```
 /// use uacalc::alg::sublat::SubalgebraLattice;
@@ -516,6 +524,8 @@ where
     one_generated_subalg_generator: Option<HashMap<BasicSet, i32>>,
     /// The universe of all subalgebras
     universe: Option<HashSet<BasicSet>>,
+    /// The universe in the order of [`BasicSet`]
+    universe_list: Option<Vec<BasicSet>>,
     /// Map from elements to their upper covers
     upper_covers_map: Option<HashMap<BasicSet, Vec<BasicSet>>>,
     /// Map from join irreducibles to their lower cover
@@ -556,6 +566,7 @@ where
             one_generated_subalg_lookup: None,
             one_generated_subalg_generator: None,
             universe: None,
+            universe_list: None,
             upper_covers_map: None,
             lower_cover_of_jis: None,
             one_generated_subalgebras: None,
@@ -648,6 +659,7 @@ where
             one_generated_subalg_lookup: None,
             one_generated_subalg_generator: None,
             universe: None,
+            universe_list: None,
             upper_covers_map: None,
             lower_cover_of_jis: None,
             one_generated_subalgebras: None,
@@ -894,6 +906,31 @@ where
         self.universe.is_some()
     }
     
+    /// Iterate over the subuniverses, generating them lazily.
+    /// 
+    /// The subuniverse generated by the constants comes first, then the
+    /// join irreducibles, then their joins as the closure finds them, so a
+    /// caller can stop early without the whole universe being built. If the
+    /// universe is already known it is iterated instead.
+    /// 
+    /// # Returns
+    /// An iterator of all the subuniverses
+    pub fn subuniverses_iter(&mut self) -> Subuniverses<T> {
+        let lat = SubalgebraLattice::new(self.alg.clone_box());
+        if self.universe.is_some() {
+            let univ = self.universe_list().to_vec();
+            return Subuniverses { lat, zero: None, jis: Vec::new(), univ, seen: HashSet::new(), next: 0, k: 0, i: 0, end: 0 };
+        }
+        let jis = self.join_irreducibles_mut().clone();
+        let univ = jis.clone();
+        let mut seen: HashSet<BasicSet> = univ.iter().cloned().collect();
+        let zero = self.zero_subalg.clone();
+        // The zero is a join irreducible when it is one-generated.
+        let zero = if seen.insert(zero.clone()) { Some(zero) } else { None };
+        let end = univ.len();
+        Subuniverses { lat, zero, jis, univ, seen, next: 0, k: 0, i: 0, end }
+    }
+    
     /// Generate the subalgebra generated by a set of generators.
     /// 
     /// # Arguments
@@ -1313,6 +1350,31 @@ where
         self.universe.as_ref().unwrap()
     }
     
+    /// Get the universe of all subalgebras as a list in the order of
+    /// [`BasicSet`], starting with the smallest subalgebra.
+    /// 
+    /// # Examples
+    /// ```
+    /// use uacalc::alg::sublat::SubalgebraLattice;
+    /// use uacalc::alg::{SmallAlgebra, BasicAlgebra};
+    /// use std::collections::HashSet;
+    /// 
+    /// let alg = Box::new(BasicAlgebra::new(
+    ///     "A".to_string(),
+    ///     HashSet::from([0, 1, 2]),
+    ///     Vec::new()
+    /// )) as Box<dyn SmallAlgebra<UniverseItem = i32>>;
+    /// let mut sub_lat = SubalgebraLattice::new_safe(alg).unwrap();
+    /// let sizes: Vec<usize> = sub_lat.universe_list().iter().map(|s| s.size()).collect();
+    /// assert_eq!(sizes, vec![0, 1, 1, 1, 2, 2, 2, 3]);
+    /// ```
+    pub fn universe_list(&mut self) -> &[BasicSet] {
+        if self.universe.is_none() {
+            self.make_universe_default();
+        }
+        self.universe_list.as_deref().unwrap_or_default()
+    }
+    
     /// Make the universe with default parameters.
     fn make_universe_default(&mut self) {
         self.make_universe(-1);
@@ -1334,6 +1396,9 @@ where
         
         if let Some(mut univ) = universe {
             univ.insert(self.zero_subalg.clone());
+            let mut list: Vec<BasicSet> = univ.iter().cloned().collect();
+            list.sort();
+            self.universe_list = Some(list);
             self.universe = Some(univ);
         }
     }
@@ -1737,7 +1802,7 @@ where
     type UniverseItem = BasicSet;
     
     fn universe(&self) -> Box<dyn Iterator<Item = Self::UniverseItem>> {
-        if let Some(ref univ) = self.universe {
+        if let Some(ref univ) = self.universe_list {
             Box::new(univ.clone().into_iter())
         } else {
             Box::new(std::iter::empty())
@@ -1867,6 +1932,60 @@ where
     }
 }
 
+/// Iterator returned by [`SubalgebraLattice::subuniverses_iter`].
+/// 
+/// It runs the closure of the join irreducibles under joins one join at a
+/// time, keeping the subuniverses found so far.
+pub struct Subuniverses<T>
+where
+    T: Clone + PartialEq + Eq + Hash + Debug + Display + Send + Sync + 'static
+{
+    /// A lattice of the same algebra, used to compute joins.
+    lat: SubalgebraLattice<T>,
+    zero: Option<BasicSet>,
+    jis: Vec<BasicSet>,
+    /// The subuniverses found so far, except the zero.
+    univ: Vec<BasicSet>,
+    seen: HashSet<BasicSet>,
+    /// The next element of `univ` to yield.
+    next: usize,
+    /// The closure joins `jis[k]` with `univ[i]` for `i` in `0..end`.
+    k: usize,
+    i: usize,
+    end: usize,
+}
+
+impl<T> Iterator for Subuniverses<T>
+where
+    T: Clone + PartialEq + Eq + Hash + Debug + Display + Send + Sync + 'static
+{
+    type Item = BasicSet;
+
+    fn next(&mut self) -> Option<BasicSet> {
+        if let Some(zero) = self.zero.take() {
+            return Some(zero);
+        }
+        while self.next == self.univ.len() {
+            if self.i == self.end {
+                self.k += 1;
+                if self.k >= self.jis.len() {
+                    return None;
+                }
+                self.i = 0;
+                self.end = self.univ.len();
+                continue;
+            }
+            let join = self.lat.join_sets(&self.jis[self.k], &self.univ[self.i]);
+            self.i += 1;
+            if self.seen.insert(join.clone()) {
+                self.univ.push(join);
+            }
+        }
+        self.next += 1;
+        Some(self.univ[self.next - 1].clone())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
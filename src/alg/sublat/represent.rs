@@ -0,0 +1,154 @@
+/*! Searching for a unary algebra with a given subalgebra lattice.
+
+The subuniverses of a unary algebra with no constants are closed under
+unions as well as intersections, so they form a 0-1 sublattice of the
+lattice of all subsets, and only distributive lattices occur. [`represent`]
+looks, for `n = 1, 2, ...`, at each 0-1 sublattice `S` of the subsets of
+`{0, ..., n-1}` isomorphic to the given lattice and asks whether `S` is
+exactly the set of subsets closed under every unary map that preserves the
+members of `S`. It is meant for building small examples.
+*/
+
+use std::collections::{BTreeSet, HashSet};
+use crate::alg::BasicAlgebra;
+use crate::alg::op::{operations, Operation, OperationSymbol};
+use crate::lat::Poset;
+
+/// The largest universe [`represent`] will try; the search works in the
+/// lattice of all `2^n` subsets.
+pub const MAX_REPRESENT_SIZE: usize = 8;
+
+/// Search for a unary algebra with at most `max_size` elements whose
+/// subalgebra lattice is isomorphic to `lattice`.
+///
+/// Only distributive lattices with at least two elements are subalgebra
+/// lattices of unary algebras, so for any other lattice the answer is
+/// `Ok(None)` without a search. The algebra found is on the smallest
+/// possible universe, and each of its operations moves a single element.
+///
+/// # Arguments
+/// * `lattice` - The lattice to represent
+/// * `max_size` - The largest universe to try, at most
+///   [`MAX_REPRESENT_SIZE`]
+///
+/// # Returns
+/// * `Ok(Some(algebra))` - A unary algebra whose subalgebra lattice is
+///   isomorphic to `lattice`
+/// * `Ok(None)` - If there is no such algebra of at most `max_size` elements
+/// * `Err(String)` - If `lattice` is not a lattice, `max_size` is too large
+///   or the search was cancelled
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::Algebra;
+/// use uacalc::alg::sublat::represent;
+/// use uacalc::lat::Poset;
+///
+/// // The three element chain: the empty set, {0} and {0, 1}.
+/// let chain = Poset::from_leq(None, 3, |i, j| i <= j).unwrap();
+/// let alg = represent(&chain, 4).unwrap().unwrap();
+/// assert_eq!(alg.cardinality(), 2);
+/// assert_eq!(alg.operations().len(), 1);
+/// ```
+pub fn represent(lattice: &Poset, max_size: usize) -> Result<Option<BasicAlgebra<i32>>, String> {
+    if !lattice.is_lattice() {
+        return Err(format!("{} is not a lattice", lattice.name().unwrap_or("The poset")));
+    }
+    if max_size > MAX_REPRESENT_SIZE {
+        return Err(format!("The search is limited to {} elements", MAX_REPRESENT_SIZE));
+    }
+    if !lattice.is_distributive() {
+        return Ok(None);
+    }
+    let token = crate::progress::current();
+    for n in 1..=max_size {
+        crate::progress::check_cancelled()?;
+        if let Some(token) = &token {
+            token.report(&format!("trying {} elements", n));
+        }
+        let subsets = Poset::from_leq(None, 1 << n, |x, y| x & !y == 0)?;
+        let mut seen: HashSet<Vec<usize>> = HashSet::new();
+        let mut found = None;
+        lattice.for_each_lattice_map(&subsets, true, &mut |mut image| {
+            image.sort_unstable();
+            if !seen.insert(image.clone()) {
+                return Ok(false);
+            }
+            found = closing_moves(n, &image);
+            Ok(found.is_some())
+        })?;
+        if let Some(moves) = found {
+            let ops = moves
+                .into_iter()
+                .enumerate()
+                .map(|(k, (i, j))| {
+                    let mut table: Vec<i32> = (0..n as i32).collect();
+                    table[i] = j as i32;
+                    let symbol = OperationSymbol::new(&format!("f{}", k), 1, false);
+                    operations::make_int_operation(symbol, n as i32, table)
+                })
+                .collect::<Result<Vec<Box<dyn Operation>>, String>>()?;
+            let name = format!("Rep({})", lattice.name().unwrap_or("L"));
+            return Ok(Some(BasicAlgebra::new(name, (0..n as i32).collect(), ops)));
+        }
+    }
+    Ok(None)
+}
+
+/// Moves `(i, j)`, each the unary map sending `i` to `j` and fixing every
+/// other element, whose closed subsets are exactly the bit masks in
+/// `image`, or `None` if no set of unary maps has those closed subsets.
+fn closing_moves(n: usize, image: &[usize]) -> Option<Vec<(usize, usize)>> {
+    // allowed[i]: the intersection of the members of the image containing
+    // i, which is where a map preserving them all may send i.
+    let allowed: Vec<usize> = (0..n)
+        .map(|i| image.iter().filter(|&&x| x & (1 << i) != 0).fold((1 << n) - 1, |acc, &x| acc & x))
+        .collect();
+    let inside: BTreeSet<usize> = image.iter().copied().collect();
+    let breaks = |(i, j): (usize, usize), y: usize| y & (1 << i) != 0 && y & (1 << j) == 0;
+    let mut moves: Vec<(usize, usize)> = Vec::new();
+    for y in 0..1usize << n {
+        if inside.contains(&y) || moves.iter().any(|&m| breaks(m, y)) {
+            continue;
+        }
+        let i = (0..n).find(|&i| y & (1 << i) != 0 && allowed[i] & !y != 0)?;
+        let j = (allowed[i] & !y).trailing_zeros() as usize;
+        moves.push((i, j));
+    }
+    Some(moves)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::{Algebra, SmallAlgebra};
+    use crate::alg::sublat::SubalgebraLattice;
+
+    fn lattice(covers: &[Vec<usize>]) -> Poset {
+        let labels = (0..covers.len()).map(|i| i.to_string()).collect();
+        Poset::from_upper_covers(None, labels, covers).unwrap()
+    }
+
+    #[test]
+    fn test_represent() {
+        // 2 x 3 needs three elements: the down-sets of a two element chain
+        // beside a point.
+        let two = Poset::from_leq(None, 2, |i, j| i <= j).unwrap();
+        let three = Poset::from_leq(None, 3, |i, j| i <= j).unwrap();
+        let target = two.product(&three);
+        assert!(represent(&target, 2).unwrap().is_none());
+        let alg = represent(&target, 3).unwrap().unwrap();
+        assert_eq!(alg.cardinality(), 3);
+        let mut sub = SubalgebraLattice::new(Box::new(alg) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+        assert_eq!(sub.universe_mut().len(), 6);
+
+        // N5 is not distributive and a single point is not a subalgebra
+        // lattice of a non-empty unary algebra.
+        let n5 = lattice(&[vec![1, 3], vec![2], vec![4], vec![4], vec![]]);
+        assert!(represent(&n5, 4).unwrap().is_none());
+        assert!(represent(&lattice(&[vec![]]), 3).unwrap().is_none());
+
+        assert!(represent(&lattice(&[vec![], vec![0], vec![0]]), 3).is_err());
+        assert!(represent(&three, MAX_REPRESENT_SIZE + 1).is_err());
+    }
+}
@@ -0,0 +1,270 @@
+/*! The digraph of subuniverses under one element extensions.
+
+The vertices are the subuniverses of a finite algebra A, including the
+empty set when A has no constants. There is an edge `S -> T` when T is
+generated by S together with a single element `a ∉ S`, that is, when T is
+obtained from S by adding one element and closing under the operations.
+Every subuniverse containing S is reachable from S, adding the missing
+elements one at a time, so reachability is inclusion; the edges record
+which of these inclusions take a single generator.
+
+The maximal proper subuniverses are the proper vertices all of whose
+edges lead to A itself.
+*/
+
+use std::collections::{HashMap, VecDeque};
+use crate::alg::SmallAlgebra;
+use crate::alg::op::Operation;
+use crate::alg::sublat::BasicSet;
+use crate::terms::compiled_term::increment;
+
+/// The subuniverses of an algebra with their one element extensions.
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use uacalc::alg::BasicAlgebra;
+/// use uacalc::alg::op::{OperationSymbol, operations};
+/// use uacalc::alg::subuniverse_graph::SubuniverseGraph;
+///
+/// // The 3 element chain as a meet semilattice: every subset is a subuniverse
+/// let meet = OperationSymbol::new("meet", 2, false);
+/// let op = operations::make_int_operation(meet, 3, (0..9).map(|k| (k % 3).min(k / 3)).collect()).unwrap();
+/// let alg = BasicAlgebra::new("C3".to_string(), (0..3).collect::<HashSet<i32>>(), vec![op]);
+///
+/// let graph = SubuniverseGraph::new(&alg).unwrap();
+/// assert_eq!(graph.vertices().len(), 8);
+/// assert_eq!(graph.maximal_proper().len(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SubuniverseGraph {
+    vertices: Vec<BasicSet>,
+    edges: Vec<(usize, usize, i32)>,
+    top: usize,
+}
+
+impl SubuniverseGraph {
+    /// Compute the subuniverses of an algebra and their one element extensions.
+    ///
+    /// The subuniverses are found by a breadth first search from the least
+    /// one, so their number bounds the work; it can be exponential in the
+    /// size of the algebra. The computation checks the progress token
+    /// installed on the current thread for cancellation.
+    ///
+    /// # Arguments
+    /// * `alg` - The algebra
+    ///
+    /// # Returns
+    /// * `Ok(SubuniverseGraph)` - The digraph, with vertices ordered by size
+    ///   and then lexicographically
+    /// * `Err(String)` - If the algebra is empty, an operation cannot be
+    ///   evaluated or the computation was cancelled
+    pub fn new(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<Self, String> {
+        let n = alg.cardinality();
+        if n <= 0 {
+            return Err("Algebra must have positive cardinality".to_string());
+        }
+        let ops = alg.get_operations_ref();
+        let mut constants = Vec::new();
+        for op in ops.iter().filter(|op| op.arity() == 0) {
+            constants.push(op.int_value_at(&[])?);
+        }
+        let bottom = close(&ops, n, &BasicSet::new(Vec::new())?, &constants)?;
+
+        let mut found: Vec<BasicSet> = vec![bottom.clone()];
+        let mut index: HashMap<BasicSet, usize> = HashMap::from([(bottom, 0)]);
+        let mut edges: HashMap<(usize, usize), i32> = HashMap::new();
+        let mut queue = VecDeque::from([0]);
+        while let Some(i) = queue.pop_front() {
+            crate::progress::check_cancelled()?;
+            let s = found[i].clone();
+            for a in (0..n).filter(|&a| !s.contains(a)) {
+                let t = close(&ops, n, &s, &[a])?;
+                let j = match index.get(&t) {
+                    Some(&j) => j,
+                    None => {
+                        found.push(t.clone());
+                        index.insert(t, found.len() - 1);
+                        queue.push_back(found.len() - 1);
+                        found.len() - 1
+                    }
+                };
+                edges.entry((i, j)).or_insert(a);
+            }
+        }
+
+        let mut order: Vec<usize> = (0..found.len()).collect();
+        order.sort_by(|&i, &j| {
+            found[i].size().cmp(&found[j].size()).then_with(|| found[i].elements().cmp(found[j].elements()))
+        });
+        let mut position = vec![0; found.len()];
+        for (k, &i) in order.iter().enumerate() {
+            position[i] = k;
+        }
+        let mut edges: Vec<(usize, usize, i32)> = edges.into_iter()
+            .map(|((i, j), a)| (position[i], position[j], a))
+            .collect();
+        edges.sort();
+        let vertices: Vec<BasicSet> = order.into_iter().map(|i| found[i].clone()).collect();
+        let top = vertices.len() - 1;
+        Ok(SubuniverseGraph { vertices, edges, top })
+    }
+
+    /// The subuniverses, ordered by size and then lexicographically; the
+    /// first is the least subuniverse and the last the whole algebra.
+    pub fn vertices(&self) -> &[BasicSet] {
+        &self.vertices
+    }
+
+    /// The edges `(i, j, a)`: vertex `j` is generated by vertex `i` and the
+    /// element `a`, the least element doing so.
+    pub fn edges(&self) -> &[(usize, usize, i32)] {
+        &self.edges
+    }
+
+    /// The vertices reached from vertex `i` by a single edge.
+    pub fn successors(&self, i: usize) -> Vec<usize> {
+        self.edges.iter().filter(|e| e.0 == i).map(|e| e.1).collect()
+    }
+
+    /// The indices of the maximal proper subuniverses.
+    pub fn maximal_proper(&self) -> Vec<usize> {
+        (0..self.top).filter(|&i| self.edges.iter().all(|e| e.0 != i || e.1 == self.top)).collect()
+    }
+
+    /// Export the digraph in Graphviz DOT format.
+    ///
+    /// Vertices are labelled with their elements and the maximal proper
+    /// subuniverses are drawn bold; edges are labelled with the added
+    /// element.
+    ///
+    /// # Returns
+    /// A string containing the DOT representation
+    pub fn to_dot(&self) -> String {
+        let maximal = self.maximal_proper();
+        let mut dot = String::from("digraph Subuniverses {\n");
+        dot.push_str("  node [shape=box];\n");
+        for (i, s) in self.vertices.iter().enumerate() {
+            let elems: Vec<String> = s.elements().iter().map(|e| e.to_string()).collect();
+            let style = if maximal.contains(&i) { ", style=bold" } else { "" };
+            dot.push_str(&format!("  {} [label=\"{{{}}}\"{}];\n", i, elems.join(","), style));
+        }
+        for (i, j, a) in &self.edges {
+            dot.push_str(&format!("  {} -> {} [label=\"{}\"];\n", i, j, a));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// The subuniverse generated by the subuniverse `s` and the elements `extra`.
+pub(crate) fn close(ops: &[&dyn Operation], n: i32, s: &BasicSet, extra: &[i32]) -> Result<BasicSet, String> {
+    let mut member = vec![false; n as usize];
+    let mut lst: Vec<i32> = s.elements().clone();
+    for &e in &lst {
+        member[e as usize] = true;
+    }
+    let mut closed = lst.len();
+    for &e in extra {
+        if !member[e as usize] {
+            member[e as usize] = true;
+            lst.push(e);
+        }
+    }
+    while closed < lst.len() {
+        let current = lst.len();
+        for op in ops.iter().filter(|op| op.arity() > 0) {
+            let mut idx = vec![0; op.arity() as usize];
+            let mut args = vec![0; idx.len()];
+            loop {
+                // Tuples inside the closed part were handled in earlier rounds
+                if idx.iter().any(|&k| k as usize >= closed) {
+                    for (arg, &k) in args.iter_mut().zip(&idx) {
+                        *arg = lst[k as usize];
+                    }
+                    let v = op.int_value_at(&args)?;
+                    if !member[v as usize] {
+                        member[v as usize] = true;
+                        lst.push(v);
+                    }
+                }
+                if !increment(&mut idx, current as i32) {
+                    break;
+                }
+            }
+        }
+        closed = current;
+    }
+    BasicSet::new(lst)
+}
+
+/// Compute the maximal proper subuniverses of an algebra.
+///
+/// # Arguments
+/// * `alg` - The algebra
+///
+/// # Returns
+/// * `Ok(Vec<BasicSet>)` - The maximal proper subuniverses, ordered by size
+///   and then lexicographically; empty for a one element algebra with a
+///   constant
+/// * `Err(String)` - As for `SubuniverseGraph::new`
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use uacalc::alg::BasicAlgebra;
+/// use uacalc::alg::op::{OperationSymbol, operations};
+/// use uacalc::alg::subuniverse_graph::maximal_proper_subuniverses;
+///
+/// // Z4 under addition: the only proper nonempty subuniverse is {0, 2}
+/// let add = OperationSymbol::new("add", 2, false);
+/// let op = operations::make_int_operation(add, 4, (0..16).map(|k| (k % 4 + k / 4) % 4).collect()).unwrap();
+/// let alg = BasicAlgebra::new("Z4".to_string(), (0..4).collect::<HashSet<i32>>(), vec![op]);
+///
+/// let maximal = maximal_proper_subuniverses(&alg).unwrap();
+/// assert_eq!(maximal.len(), 1);
+/// assert_eq!(maximal[0].elements(), &vec![0, 2]);
+/// ```
+pub fn maximal_proper_subuniverses(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<Vec<BasicSet>, String> {
+    let graph = SubuniverseGraph::new(alg)?;
+    Ok(graph.maximal_proper().into_iter().map(|i| graph.vertices[i].clone()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::BasicAlgebra;
+    use crate::alg::op::OperationSymbol;
+    use crate::alg::op::operations::make_int_operation;
+
+    #[test]
+    fn test_non_commutative_closure() {
+        // f(1,0) = 2 and f(x,y) = x otherwise: {0,1} generates everything,
+        // which is only seen when f is applied to (1,0).
+        let table = (0..9).map(|k| if k == 1 { 2 } else { k % 3 }).collect();
+        let f = make_int_operation(OperationSymbol::new("f", 2, false), 3, table).unwrap();
+        let alg = BasicAlgebra::new("A".to_string(), (0..3).collect(), vec![f]);
+        let graph = SubuniverseGraph::new(&alg).unwrap();
+        let sets: Vec<Vec<i32>> = graph.vertices().iter().map(|s| s.elements().clone()).collect();
+        assert!(!sets.contains(&vec![0, 1]));
+        assert_eq!(sets.len(), 7);
+        let maximal: Vec<Vec<i32>> = maximal_proper_subuniverses(&alg).unwrap().iter().map(|s| s.elements().clone()).collect();
+        assert_eq!(maximal, vec![vec![0, 2], vec![1, 2]]);
+    }
+
+    #[test]
+    fn test_edges_and_dot() {
+        let f = make_int_operation(OperationSymbol::new("f", 1, false), 3, vec![1, 2, 0]).unwrap();
+        let c = make_int_operation(OperationSymbol::new("c", 0, false), 3, vec![0]).unwrap();
+        let cycle = BasicAlgebra::new("C3".to_string(), (0..3).collect(), vec![f.clone_box()]);
+        let graph = SubuniverseGraph::new(&cycle).unwrap();
+        assert_eq!(graph.vertices().len(), 2);
+        assert_eq!(graph.edges(), &[(0, 1, 0)]);
+        assert_eq!(graph.successors(0), vec![1]);
+        assert_eq!(graph.maximal_proper(), vec![0]);
+        assert!(graph.to_dot().contains("0 -> 1 [label=\"0\"]"));
+
+        let pointed = BasicAlgebra::new("C3".to_string(), (0..3).collect(), vec![f, c]);
+        assert!(maximal_proper_subuniverses(&pointed).unwrap().is_empty());
+    }
+}
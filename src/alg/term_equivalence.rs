@@ -0,0 +1,212 @@
+/*! Term and polynomial equivalence of algebras on the same universe.
+
+Two algebras on `{0, ..., n-1}` are term equivalent if they have the same
+clone of term operations, which holds exactly when every fundamental
+operation of each is a term operation of the other. They are polynomially
+equivalent if the same holds after adding all constants to both.
+
+The checks return witness terms, found by closing the projections of the
+required arity only as far as necessary.
+*/
+
+use crate::alg::{BasicAlgebra, SmallAlgebra};
+use crate::alg::free_spectrum::TermClosure;
+use crate::alg::op::{Operation, OperationSymbol};
+use crate::alg::op::operations::make_int_operation;
+use crate::terms::Term;
+use crate::terms::compiled_term::operation_table;
+
+/// Witness terms for the equivalence of two algebras.
+///
+/// Terms of arity `k` use the variables `x0, ..., x{k-1}`; for polynomial
+/// equivalence the constant `i` is written as the nullary symbol `c{i}`.
+#[derive(Debug)]
+pub struct CloneEquivalence {
+    /// For each operation of the first algebra, a term of the second producing it.
+    pub first_in_second: Vec<Box<dyn Term>>,
+    /// For each operation of the second algebra, a term of the first producing it.
+    pub second_in_first: Vec<Box<dyn Term>>,
+}
+
+/// Express every operation of `a` as a term operation of `b`.
+///
+/// # Arguments
+/// * `a` - The algebra whose operations are sought
+/// * `b` - The algebra whose terms are searched
+///
+/// # Returns
+/// * `Ok(Some(terms))` - A term of `b` for each operation of `a`, in order
+/// * `Ok(None)` - If some operation of `a` is not a term operation of `b`,
+///   or the algebras have different cardinalities
+/// * `Err(String)` - If a clone is too large to index
+pub fn term_witnesses(
+    a: &dyn SmallAlgebra<UniverseItem = i32>,
+    b: &dyn SmallAlgebra<UniverseItem = i32>,
+) -> Result<Option<Vec<Box<dyn Term>>>, String> {
+    if a.cardinality() != b.cardinality() {
+        return Ok(None);
+    }
+    let mut closures: Vec<Option<TermClosure>> = Vec::new();
+    let mut witnesses = Vec::new();
+    for op in a.get_operations_ref() {
+        let arity = op.arity() as usize;
+        let table = operation_table(op)?;
+        if closures.len() <= arity {
+            closures.resize_with(arity + 1, || None);
+        }
+        if closures[arity].is_none() {
+            closures[arity] = Some(TermClosure::new(b, arity)?);
+        }
+        match closures[arity].as_mut().unwrap().find(&table)? {
+            Some(term) => witnesses.push(term),
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(witnesses))
+}
+
+/// Test whether two algebras on the same universe have the same clone.
+///
+/// # Arguments
+/// * `a` - The first algebra
+/// * `b` - The second algebra
+///
+/// # Returns
+/// * `Ok(Some(witnesses))` - If they are term equivalent
+/// * `Ok(None)` - If they are not
+/// * `Err(String)` - If a clone is too large to index
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use uacalc::alg::BasicAlgebra;
+/// use uacalc::alg::op::{OperationSymbol, operations};
+/// use uacalc::alg::term_equivalence::are_term_equivalent;
+///
+/// // Z3 under x - y and under the Maltsev operation x - y + z.
+/// let minus = operations::make_int_operation(
+///     OperationSymbol::new("minus", 2, false), 3,
+///     (0..9).map(|k| (k % 3 + 2 * (k / 3)) % 3).collect()).unwrap();
+/// let p = operations::make_int_operation(
+///     OperationSymbol::new("p", 3, false), 3,
+///     (0..27).map(|k| (k % 3 + 2 * (k / 3 % 3) + k / 9) % 3).collect()).unwrap();
+/// let a = BasicAlgebra::new("A".to_string(), (0..3).collect::<HashSet<i32>>(), vec![minus]);
+/// let b = BasicAlgebra::new("B".to_string(), (0..3).collect::<HashSet<i32>>(), vec![p]);
+/// // x - y is not idempotent, so it is not a term operation of b.
+/// assert!(are_term_equivalent(&a, &b).unwrap().is_none());
+/// ```
+pub fn are_term_equivalent(
+    a: &dyn SmallAlgebra<UniverseItem = i32>,
+    b: &dyn SmallAlgebra<UniverseItem = i32>,
+) -> Result<Option<CloneEquivalence>, String> {
+    let first_in_second = match term_witnesses(a, b)? {
+        Some(terms) => terms,
+        None => return Ok(None),
+    };
+    let second_in_first = match term_witnesses(b, a)? {
+        Some(terms) => terms,
+        None => return Ok(None),
+    };
+    Ok(Some(CloneEquivalence { first_in_second, second_in_first }))
+}
+
+/// Test whether two algebras on the same universe have the same polynomial clone.
+///
+/// # Arguments
+/// * `a` - The first algebra
+/// * `b` - The second algebra
+///
+/// # Returns
+/// * `Ok(Some(witnesses))` - If they are polynomially equivalent; the
+///   witnesses express the operations of each as polynomials of the other
+/// * `Ok(None)` - If they are not
+/// * `Err(String)` - If a clone is too large to index
+pub fn are_polynomially_equivalent(
+    a: &dyn SmallAlgebra<UniverseItem = i32>,
+    b: &dyn SmallAlgebra<UniverseItem = i32>,
+) -> Result<Option<CloneEquivalence>, String> {
+    if a.cardinality() != b.cardinality() {
+        return Ok(None);
+    }
+    let first_in_second = match term_witnesses(a, &with_constants(b)?)? {
+        Some(terms) => terms,
+        None => return Ok(None),
+    };
+    let second_in_first = match term_witnesses(b, &with_constants(a)?)? {
+        Some(terms) => terms,
+        None => return Ok(None),
+    };
+    Ok(Some(CloneEquivalence { first_in_second, second_in_first }))
+}
+
+/// The algebra with a nullary operation `c{i}` added for each element `i`.
+fn with_constants(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<BasicAlgebra<i32>, String> {
+    let n = alg.cardinality();
+    let mut ops: Vec<Box<dyn Operation>> = Vec::new();
+    for op in alg.get_operations_ref() {
+        ops.push(make_int_operation(op.symbol().clone(), n, operation_table(op)?.to_vec())?);
+    }
+    for i in 0..n {
+        ops.push(make_int_operation(OperationSymbol::new(&format!("c{}", i), 0, false), n, vec![i])?);
+    }
+    Ok(BasicAlgebra::new(format!("{}+", alg.name()), (0..n).collect(), ops))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::terms::CompiledTerm;
+    use crate::util::horner;
+
+    fn algebra(size: i32, ops: Vec<(&str, usize, Vec<i32>)>) -> BasicAlgebra<i32> {
+        let ops = ops.into_iter()
+            .map(|(name, arity, table)| {
+                make_int_operation(OperationSymbol::new(name, arity as i32, false), size, table).unwrap()
+            })
+            .collect();
+        BasicAlgebra::new("A".to_string(), (0..size).collect(), ops)
+    }
+
+    fn table(size: i32, arity: usize, f: impl Fn(&[i32]) -> i32) -> Vec<i32> {
+        (0..size.pow(arity as u32)).map(|c| f(&horner::horner_inv_same_size(c, size, arity))).collect()
+    }
+
+    fn check_witnesses(a: &BasicAlgebra<i32>, b: &BasicAlgebra<i32>, terms: &[Box<dyn Term>]) {
+        for (op, term) in a.get_operations_ref().iter().zip(terms) {
+            let arity = op.arity() as usize;
+            let vars: Vec<String> = (0..arity).map(|i| format!("x{}", i)).collect();
+            let compiled = CompiledTerm::compile(term.as_ref(), b, &vars).unwrap();
+            assert_eq!(table(2, arity, |args| compiled.eval(args)), operation_table(*op).unwrap().to_vec());
+        }
+    }
+
+    #[test]
+    fn test_term_equivalence() {
+        // The two element lattice with join and meet, and with the median.
+        let lattice = algebra(2, vec![
+            ("join", 2, table(2, 2, |x| x[0] | x[1])),
+            ("meet", 2, table(2, 2, |x| x[0] & x[1])),
+        ]);
+        let median = algebra(2, vec![
+            ("m", 3, table(2, 3, |x| (x[0] & x[1]) | (x[1] & x[2]) | (x[0] & x[2]))),
+        ]);
+        // The median is a lattice term, but join is not a median term.
+        let witnesses = term_witnesses(&median, &lattice).unwrap().unwrap();
+        check_witnesses(&median, &lattice, &witnesses);
+        assert!(term_witnesses(&lattice, &median).unwrap().is_none());
+        assert!(are_term_equivalent(&lattice, &median).unwrap().is_none());
+
+        // With the constants, join(x, y) = m(x, y, 1) and meet(x, y) = m(x, y, 0).
+        let eq = are_polynomially_equivalent(&lattice, &median).unwrap().unwrap();
+        assert_eq!(eq.first_in_second.len(), 2);
+        assert_eq!(eq.second_in_first.len(), 1);
+        check_witnesses(&lattice, &with_constants(&median).unwrap(), &eq.first_in_second);
+
+        let swapped = algebra(2, vec![
+            ("meet", 2, table(2, 2, |x| x[0] & x[1])),
+            ("join", 2, table(2, 2, |x| x[0] | x[1])),
+        ]);
+        let eq = are_term_equivalent(&lattice, &swapped).unwrap().unwrap();
+        check_witnesses(&lattice, &swapped, &eq.first_in_second);
+    }
+}
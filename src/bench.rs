@@ -0,0 +1,198 @@
+/*!
+ * The canonical algebras and workloads used to benchmark UACalc.
+ *
+ * [`bench_suite`] returns a fixed list of small algebras covering the
+ * common shapes of input: lattices, groups, Polin's algebra and seeded
+ * random groupoids. [`BenchTask`] names the workloads run on each of them:
+ * the congruence lattice, a closure in a power, and equation checking.
+ * The criterion benchmarks and `uacalc-cli bench` both run this suite, so
+ * their timings are comparable across versions.
+ */
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::alg::conlat::CongruenceLattice;
+use crate::alg::op::{operations, OperationSymbol, SimilarityType};
+use crate::alg::{algebras, Algebra, BasicAlgebra, BigProductAlgebra, Closer, SmallAlgebra};
+use crate::eq::Equation;
+use crate::io::AlgebraReader;
+use crate::terms::string_to_term;
+use crate::util::int_array::IntArray;
+
+/// An algebra of the benchmark suite.
+#[derive(Debug, Clone)]
+pub struct BenchAlgebra {
+    /// The family of the algebra: "lattice", "group", "polin" or "groupoid".
+    pub family: &'static str,
+    /// The algebra; its name is unique within the suite.
+    pub algebra: BasicAlgebra<i32>,
+}
+
+/// The sizes of the random groupoids, with their seeds.
+const GROUPOIDS: &[(i32, i64)] = &[(3, 1), (4, 2), (4, 3)];
+
+fn read_ua(data: &[u8]) -> BasicAlgebra<i32> {
+    AlgebraReader::new_from_stream(data.to_vec())
+        .and_then(|reader| reader.read_algebra_file())
+        .expect("the bundled benchmark algebras are valid")
+}
+
+/// A binary operation given by a formula.
+type Formula = fn(i32, i32) -> i32;
+
+fn table_algebra(name: &str, n: i32, ops: &[(&str, Formula)]) -> BasicAlgebra<i32> {
+    let ops = ops
+        .iter()
+        .map(|&(op_name, f)| {
+            let table = (0..n * n).map(|k| f(k / n, k % n)).collect();
+            operations::make_int_operation(OperationSymbol::new(op_name, 2, false), n, table)
+                .expect("the table has n^2 entries in 0..n")
+        })
+        .collect();
+    BasicAlgebra::new(name.to_string(), (0..n).collect::<HashSet<i32>>(), ops)
+}
+
+/// The algebras of the benchmark suite.
+///
+/// The suite is deterministic: the random groupoids are generated from
+/// fixed seeds, so every call returns the same algebras.
+///
+/// # Returns
+/// The lattices `lat2`, `n5`, `m3` and `2^3`, the groups `Sym3` and `Z7`,
+/// Polin's algebra and the random groupoids `RG3-1`, `RG4-2` and `RG4-3`,
+/// named by size and seed
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::Algebra;
+///
+/// let suite = uacalc::bench_suite();
+/// assert!(suite.iter().any(|b| b.algebra.name() == "polin"));
+/// assert!(suite.iter().all(|b| b.algebra.cardinality() <= 8));
+/// ```
+pub fn bench_suite() -> Vec<BenchAlgebra> {
+    let mut suite = vec![
+        BenchAlgebra { family: "lattice", algebra: read_ua(include_bytes!("../resources/algebras/lat2.ua")) },
+        BenchAlgebra { family: "lattice", algebra: read_ua(include_bytes!("../resources/algebras/n5.ua")) },
+        BenchAlgebra { family: "lattice", algebra: read_ua(include_bytes!("../resources/algebras/m3.ua")) },
+    ];
+    suite.push(BenchAlgebra {
+        family: "lattice",
+        algebra: table_algebra("2^3", 8, &[("join", |x, y| x | y), ("meet", |x, y| x & y)]),
+    });
+    suite.push(BenchAlgebra { family: "group", algebra: read_ua(include_bytes!("../resources/algebras/sym3.ua")) });
+    suite.push(BenchAlgebra { family: "group", algebra: table_algebra("Z7", 7, &[("add", |x, y| (x + y) % 7)]) });
+    suite.push(BenchAlgebra { family: "polin", algebra: read_ua(include_bytes!("../resources/algebras/polin.ua")) });
+    let groupoid = SimilarityType::new(vec![OperationSymbol::new("f", 2, false)]);
+    for &(n, seed) in GROUPOIDS {
+        let mut algebra = algebras::make_random_algebra_with_seed(n, &groupoid, Some(seed))
+            .expect("the groupoid size is positive");
+        algebra.set_name(format!("RG{}-{}", n, seed));
+        suite.push(BenchAlgebra { family: "groupoid", algebra });
+    }
+    suite
+}
+
+/// A workload run on each algebra of the benchmark suite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BenchTask {
+    /// Compute the congruence lattice.
+    Conlat,
+    /// Close two elements of `A^n` under the operations, `n = |A|`.
+    Closure,
+    /// Check associativity and commutativity of each binary operation.
+    Equations,
+}
+
+impl BenchTask {
+    /// All the tasks, in the order they are reported.
+    pub const ALL: [BenchTask; 3] = [BenchTask::Conlat, BenchTask::Closure, BenchTask::Equations];
+
+    /// The name of the task, as accepted by [`BenchTask::from_name`].
+    pub fn name(&self) -> &'static str {
+        match self {
+            BenchTask::Conlat => "conlat",
+            BenchTask::Closure => "closure",
+            BenchTask::Equations => "equations",
+        }
+    }
+
+    /// Parse a task name: `"conlat"`, `"closure"` or `"equations"`.
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        Self::ALL
+            .into_iter()
+            .find(|t| t.name() == name)
+            .ok_or_else(|| format!("Unknown benchmark task: {}", name))
+    }
+
+    /// Run the task on an algebra.
+    ///
+    /// # Arguments
+    /// * `alg` - The algebra
+    ///
+    /// # Returns
+    /// The size of the result: the number of congruences, the size of the
+    /// closure, or the number of equations that hold
+    pub fn run(&self, alg: &BasicAlgebra<i32>) -> Result<usize, String> {
+        match self {
+            BenchTask::Conlat => {
+                let mut con = CongruenceLattice::new(Box::new(alg.clone()) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+                con.try_universe().map(|u| u.len())
+            }
+            BenchTask::Closure => {
+                let n = alg.cardinality();
+                let power = BigProductAlgebra::new_power_safe(Box::new(alg.clone()), n as usize)?;
+                let gens = vec![
+                    IntArray::from_array((0..n).collect())?,
+                    IntArray::from_array((0..n).map(|i| (i + 1) % n).collect())?,
+                ];
+                let mut closer = Closer::new_safe(Arc::new(power), gens)?;
+                closer.sg_close().map(|elems| elems.len())
+            }
+            BenchTask::Equations => {
+                let mut holding = 0;
+                for op in alg.operations() {
+                    let sym = op.symbol();
+                    if sym.arity() != 2 {
+                        continue;
+                    }
+                    let f = sym.name();
+                    for (lhs, rhs) in [
+                        (format!("{f}({f}(x,y),z)"), format!("{f}(x,{f}(y,z))")),
+                        (format!("{f}(x,y)"), format!("{f}(y,x)")),
+                    ] {
+                        let eq = Equation::new(string_to_term(&lhs)?, string_to_term(&rhs)?);
+                        if eq.is_satisfied_in(alg)? {
+                            holding += 1;
+                        }
+                    }
+                }
+                Ok(holding)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bench_suite() {
+        let suite = bench_suite();
+        let names: HashSet<String> = suite.iter().map(|b| b.algebra.name().to_string()).collect();
+        assert_eq!(names.len(), suite.len());
+        // The random groupoids are the same on every call.
+        let table = |suite: &[BenchAlgebra]| suite[9].algebra.operations()[0].get_table().map(|t| t.to_vec());
+        assert_eq!(table(&bench_suite()), table(&suite));
+
+        let n5 = &suite.iter().find(|b| b.algebra.name() == "n5").unwrap().algebra;
+        assert_eq!(BenchTask::Conlat.run(n5), Ok(5));
+        // A two generated lattice has at most four elements.
+        assert_eq!(BenchTask::Closure.run(n5), Ok(4));
+        assert_eq!(BenchTask::Equations.run(n5), Ok(4));
+        assert_eq!(BenchTask::from_name("closure"), Ok(BenchTask::Closure));
+        assert!(BenchTask::from_name("nope").is_err());
+    }
+}
@@ -0,0 +1,274 @@
+/*! Java-style names for porting UACalc programs.
+
+Each type here stands for a class of `org.uacalc` with static methods and
+keeps the Java method names, so a call such as `Malcev.malcevTerm(alg)`
+becomes `Malcev::malcevTerm(&alg)`. Every method delegates to the function
+named in its documentation, which is the one to use in new code.
+
+The Java overloads taking a `ProgressReport` are not repeated: progress and
+cancellation go through the token installed by [`crate::progress`].
+Algebras have integer universes, as in Java.
+
+# Examples
+```
+#![allow(non_snake_case)]
+use uacalc::alg::BasicAlgebra;
+use uacalc::alg::op::{operations, OperationSymbol};
+use uacalc::compat::{Algebras, Malcev};
+
+let meet = operations::make_int_operation(OperationSymbol::new("meet", 2, false), 2, vec![0, 0, 0, 1]).unwrap();
+let join = operations::make_int_operation(OperationSymbol::new("join", 2, false), 2, vec![0, 1, 1, 1]).unwrap();
+let lattice = BasicAlgebra::new("2".to_string(), (0..2).collect(), vec![meet, join]);
+assert!(Malcev::majorityTerm(&lattice).unwrap().is_some());
+assert_eq!(Algebras::jonssonLevel(&lattice).unwrap(), 2);
+```
+*/
+
+#![allow(non_snake_case)]
+
+use std::collections::HashMap;
+use std::path::Path;
+use crate::alg::{algebras, malcev, BasicAlgebra, Homomorphism, SmallAlgebra};
+use crate::alg::conlat::partition::Partition;
+use crate::alg::op::{Operation, SimilarityType};
+use crate::io::{algebra_io, BadAlgebraFileException};
+use crate::terms::Term;
+
+type IntAlgebra = dyn SmallAlgebra<UniverseItem = i32>;
+
+/// The static methods of `org.uacalc.alg.Malcev`.
+pub struct Malcev;
+
+impl Malcev {
+    /// See [`malcev::malcev_term`].
+    pub fn malcevTerm(alg: &IntAlgebra) -> Result<Option<Box<dyn Term>>, String> {
+        malcev::malcev_term(alg)
+    }
+
+    /// See [`malcev::majority_term`].
+    pub fn majorityTerm(alg: &IntAlgebra) -> Result<Option<Box<dyn Term>>, String> {
+        malcev::majority_term(alg)
+    }
+
+    /// See [`malcev::minority_term`].
+    pub fn minorityTerm(alg: &IntAlgebra) -> Result<Option<Box<dyn Term>>, String> {
+        malcev::minority_term(alg)
+    }
+
+    /// See [`malcev::pixley_term`].
+    pub fn pixleyTerm(alg: &IntAlgebra) -> Result<Option<Box<dyn Term>>, String> {
+        malcev::pixley_term(alg)
+    }
+
+    /// See [`malcev::nu_term`].
+    pub fn nuTerm(alg: &IntAlgebra, arity: usize) -> Result<Option<Box<dyn Term>>, String> {
+        malcev::nu_term(alg, arity)
+    }
+
+    /// See [`malcev::weak_nu_term`].
+    pub fn weakNUTerm(alg: &IntAlgebra, arity: usize) -> Result<Option<Box<dyn Term>>, String> {
+        malcev::weak_nu_term(alg, arity)
+    }
+
+    /// See [`malcev::join_term`].
+    pub fn joinTerm(alg: &IntAlgebra) -> Result<Option<Box<dyn Term>>, String> {
+        malcev::join_term(alg)
+    }
+
+    /// See [`malcev::semilattice_term`].
+    pub fn semilatticeTerm(alg: &IntAlgebra) -> Result<Option<Box<dyn Term>>, String> {
+        malcev::semilattice_term(alg)
+    }
+
+    /// See [`malcev::difference_term`].
+    pub fn differenceTerm(alg: &IntAlgebra) -> Result<Option<Box<dyn Term>>, String> {
+        malcev::difference_term(alg)
+    }
+
+    /// See [`malcev::jonsson_terms`].
+    pub fn jonssonTerms(alg: &IntAlgebra) -> Result<Option<Vec<Box<dyn Term>>>, String> {
+        malcev::jonsson_terms(alg)
+    }
+
+    /// See [`malcev::jonsson_level`].
+    pub fn jonssonLevel(alg: &IntAlgebra) -> Result<i32, String> {
+        malcev::jonsson_level(alg)
+    }
+
+    /// See [`malcev::gumm_terms`].
+    pub fn gummTerms(alg: &IntAlgebra) -> Result<Option<Vec<Box<dyn Term>>>, String> {
+        malcev::gumm_terms(alg)
+    }
+
+    /// See [`malcev::hagemann_mitschke_terms`].
+    pub fn hagemannMitschkeTerms(alg: &IntAlgebra) -> Result<Option<Vec<Box<dyn Term>>>, String> {
+        malcev::hagemann_mitschke_terms(alg)
+    }
+
+    /// See [`malcev::sd_meet_terms`].
+    pub fn sdmeetTerms(alg: &IntAlgebra) -> Result<Option<Vec<Box<dyn Term>>>, String> {
+        malcev::sd_meet_terms(alg)
+    }
+
+    /// See [`malcev::primality_terms`].
+    pub fn primalityTerms(alg: &IntAlgebra) -> Result<Option<Vec<Box<dyn Term>>>, String> {
+        malcev::primality_terms(alg)
+    }
+
+    /// See [`malcev::is_congruence_dist_idempotent`].
+    pub fn isCongruenceDistIdempotent(alg: &IntAlgebra) -> Result<bool, String> {
+        malcev::is_congruence_dist_idempotent(alg)
+    }
+
+    /// See [`malcev::is_congruence_modular_idempotent`].
+    pub fn isCongruenceModularIdempotent(alg: &IntAlgebra) -> Result<bool, String> {
+        malcev::is_congruence_modular_idempotent(alg)
+    }
+
+    /// See [`malcev::congruence_modular_variety`].
+    pub fn congruenceModularVariety(alg: &IntAlgebra) -> Result<bool, String> {
+        malcev::congruence_modular_variety(alg)
+    }
+}
+
+/// The static methods of `org.uacalc.alg.Algebras`.
+pub struct Algebras;
+
+impl Algebras {
+    /// See [`algebras::find_nuf`].
+    pub fn findNUF(alg: &IntAlgebra, arity: usize) -> Result<Option<Box<dyn Term>>, String> {
+        algebras::find_nuf(alg, arity)
+    }
+
+    /// See [`algebras::jonsson_terms`].
+    pub fn jonssonTerms(alg: &IntAlgebra) -> Result<Option<Vec<Box<dyn Term>>>, String> {
+        algebras::jonsson_terms(alg)
+    }
+
+    /// See [`algebras::jonsson_level`].
+    pub fn jonssonLevel(alg: &IntAlgebra) -> Result<i32, String> {
+        algebras::jonsson_level(alg)
+    }
+
+    /// See [`algebras::is_endomorphism`].
+    pub fn isEndomorphism(endo: &dyn Operation, alg: &IntAlgebra) -> Result<bool, String> {
+        algebras::is_endomorphism(endo, alg)
+    }
+
+    /// See [`algebras::is_homomorphism`].
+    pub fn isHomomorphism(map: &[i32], alg0: &IntAlgebra, alg1: &IntAlgebra) -> Result<bool, String> {
+        algebras::is_homomorphism(map, alg0, alg1)
+    }
+
+    /// See [`algebras::matrix_power`].
+    pub fn matrixPower(alg: Box<IntAlgebra>, k: i32) -> Result<BasicAlgebra<i32>, String> {
+        algebras::matrix_power(alg, k)
+    }
+
+    /// See [`algebras::full_transformation_semigroup`].
+    pub fn fullTransformationSemigroup(n: i32, include_constants: bool, include_id: bool) -> Result<BasicAlgebra<i32>, String> {
+        algebras::full_transformation_semigroup(n, include_constants, include_id)
+    }
+
+    /// See [`algebras::make_random_algebra`].
+    pub fn makeRandomAlgebra(n: i32, sim_type: &SimilarityType) -> Result<BasicAlgebra<i32>, String> {
+        algebras::make_random_algebra(n, sim_type)
+    }
+
+    /// See [`algebras::ternary_discriminator_algebra`].
+    pub fn ternaryDiscriminatorAlgebra(card: i32) -> Result<BasicAlgebra<i32>, String> {
+        algebras::ternary_discriminator_algebra(card)
+    }
+
+    /// See [`algebras::member_of_quasivariety`].
+    pub fn memberOfQuasivariety(a: Box<IntAlgebra>, b: Box<IntAlgebra>) -> Result<Option<Vec<Homomorphism>>, String> {
+        algebras::member_of_quasivariety(a, b, None)
+    }
+
+    /// See [`algebras::member_of_quasivariety_list`].
+    pub fn memberOfQuasivarietyList(a: Box<IntAlgebra>, gen_algs: Vec<Box<IntAlgebra>>) -> Result<Option<Vec<Homomorphism>>, String> {
+        algebras::member_of_quasivariety_list(a, gen_algs, None)
+    }
+
+    /// See [`algebras::member_of_quasivariety_gen_by_proper_subs`].
+    pub fn memberOfQuasivarietyGenByProperSubs(a: Box<IntAlgebra>) -> Result<Option<Vec<Homomorphism>>, String> {
+        algebras::member_of_quasivariety_gen_by_proper_subs(a, None)
+    }
+
+    /// See [`algebras::quasi_critical_congruences`].
+    pub fn quasiCriticalCongruences(a: Box<IntAlgebra>) -> Result<Vec<Partition>, String> {
+        algebras::quasi_critical_congruences(a, None)
+    }
+
+    /// See [`algebras::quasi_critical`].
+    pub fn quasiCritical(a: Box<IntAlgebra>) -> Result<Option<HashMap<Partition, Vec<i32>>>, String> {
+        algebras::quasi_critical(a, None)
+    }
+}
+
+/// The static methods of `org.uacalc.io.AlgebraIO`, taking paths as
+/// strings.
+pub struct AlgebraIO;
+
+impl AlgebraIO {
+    /// See [`algebra_io::parse_line`].
+    pub fn parseLine(line: &str) -> Result<i32, String> {
+        algebra_io::parse_line(line)
+    }
+
+    /// See [`algebra_io::read_algebra_file`].
+    pub fn readAlgebraFile(path: &str) -> Result<Box<IntAlgebra>, BadAlgebraFileException> {
+        algebra_io::read_algebra_file(Path::new(path))
+    }
+
+    /// See [`algebra_io::read_algebra_list_file`].
+    pub fn readAlgebraListFile(path: &str) -> Result<Vec<Box<IntAlgebra>>, BadAlgebraFileException> {
+        algebra_io::read_algebra_list_file(Path::new(path))
+    }
+
+    /// See [`algebra_io::read_projective_plane`].
+    pub fn readProjectivePlane(path: &str) -> Result<Box<IntAlgebra>, BadAlgebraFileException> {
+        algebra_io::read_projective_plane(Path::new(path))
+    }
+
+    /// See [`algebra_io::write_algebra_file`].
+    pub fn writeAlgebraFile(alg: Box<IntAlgebra>, path: &str) -> Result<(), String> {
+        algebra_io::write_algebra_file(alg, Path::new(path))
+    }
+
+    /// See [`algebra_io::convert_to_xml`].
+    pub fn convertToXML(path: &str) -> Result<(), BadAlgebraFileException> {
+        algebra_io::convert_to_xml(Path::new(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::Algebra;
+    use crate::alg::op::{operations, OperationSymbol};
+
+    #[test]
+    fn test_delegation() {
+        let plus = operations::make_int_operation(OperationSymbol::new("+", 2, false), 2, vec![0, 1, 1, 0]).unwrap();
+        let z2 = BasicAlgebra::new("Z2".to_string(), (0..2).collect(), vec![plus]);
+        assert!(Malcev::malcevTerm(&z2).unwrap().is_some());
+        assert!(Malcev::majorityTerm(&z2).unwrap().is_none());
+        assert!(Algebras::isHomomorphism(&[0, 1], &z2, &z2).unwrap());
+        let square = Algebras::matrixPower(Box::new(z2.clone()), 2).unwrap();
+        assert_eq!(square.cardinality(), 4);
+        assert!(Algebras::memberOfQuasivariety(Box::new(square), Box::new(z2)).unwrap().is_some());
+        assert_eq!(AlgebraIO::parseLine("3").unwrap(), 3);
+    }
+
+    #[test]
+    fn test_file_round_trip() {
+        let path = std::env::temp_dir().join(format!("compat_{}.xml", std::process::id()));
+        let path = path.to_str().unwrap();
+        let alg = Algebras::ternaryDiscriminatorAlgebra(3).unwrap();
+        AlgebraIO::writeAlgebraFile(Box::new(alg), path).unwrap();
+        let read = AlgebraIO::readAlgebraFile(path).unwrap();
+        assert_eq!(read.cardinality(), 3);
+        std::fs::remove_file(path).unwrap();
+    }
+}
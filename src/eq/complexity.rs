@@ -0,0 +1,224 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use crate::eq::Equation;
+use crate::terms::Term;
+
+/// Structural measures of an equation.
+///
+/// Besides the sizes of the two sides this records, for each side, how
+/// often every variable occurs, and from that the syntactic classes an
+/// equation can belong to:
+///
+/// * *linear* - no variable occurs twice on the same side,
+/// * *balanced* - every variable occurs equally often on both sides,
+/// * *regular* - both sides have the same set of variables,
+/// * *normal* - the equation is trivial or neither side is a variable.
+///
+/// `search_key` orders equations cheapest first, which is the order
+/// searches over candidate equations should try them in.
+///
+/// # Examples
+/// ```
+/// use uacalc::eq::Equation;
+/// use uacalc::terms::string_to_term;
+///
+/// let assoc = Equation::new(
+///     string_to_term("f(f(x,y),z)").unwrap(),
+///     string_to_term("f(x,f(y,z))").unwrap(),
+/// );
+/// let c = assoc.complexity();
+/// assert_eq!(c.size(), 4);
+/// assert!(c.is_linear() && c.is_balanced() && c.is_regular() && c.is_normal());
+///
+/// let idem = Equation::new(string_to_term("f(x,x)").unwrap(), string_to_term("x").unwrap());
+/// let c = idem.complexity();
+/// assert!(!c.is_linear() && !c.is_balanced() && c.is_regular() && !c.is_normal());
+/// assert!(idem.complexity().search_key() < assoc.complexity().search_key());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EquationComplexity {
+    left_size: usize,
+    right_size: usize,
+    depth: usize,
+    left_variables: BTreeMap<String, usize>,
+    right_variables: BTreeMap<String, usize>,
+    trivial: bool,
+    left_is_variable: bool,
+    right_is_variable: bool,
+}
+
+impl EquationComplexity {
+    /// Compute the complexity of an equation.
+    pub fn new(eq: &Equation) -> Self {
+        let (left, right) = (eq.left_side(), eq.right_side());
+        EquationComplexity {
+            left_size: left.size(),
+            right_size: right.size(),
+            depth: left.depth().max(right.depth()) as usize,
+            left_variables: variable_multiset(left),
+            right_variables: variable_multiset(right),
+            trivial: left.to_string() == right.to_string(),
+            left_is_variable: left.isa_variable(),
+            right_is_variable: right.isa_variable(),
+        }
+    }
+
+    /// The number of operation symbol occurrences on both sides.
+    pub fn size(&self) -> usize {
+        self.left_size + self.right_size
+    }
+
+    /// The number of operation symbol occurrences on the left side.
+    pub fn left_size(&self) -> usize {
+        self.left_size
+    }
+
+    /// The number of operation symbol occurrences on the right side.
+    pub fn right_size(&self) -> usize {
+        self.right_size
+    }
+
+    /// The larger of the depths of the two sides.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// The number of distinct variables of the equation.
+    pub fn variable_count(&self) -> usize {
+        self.left_variables.keys()
+            .chain(self.right_variables.keys().filter(|v| !self.left_variables.contains_key(*v)))
+            .count()
+    }
+
+    /// How often each variable occurs on the left side.
+    pub fn left_variables(&self) -> &BTreeMap<String, usize> {
+        &self.left_variables
+    }
+
+    /// How often each variable occurs on the right side.
+    pub fn right_variables(&self) -> &BTreeMap<String, usize> {
+        &self.right_variables
+    }
+
+    /// Whether no variable occurs more than once on either side.
+    pub fn is_linear(&self) -> bool {
+        self.left_variables.values().chain(self.right_variables.values()).all(|&n| n == 1)
+    }
+
+    /// Whether every variable occurs the same number of times on both sides.
+    pub fn is_balanced(&self) -> bool {
+        self.left_variables == self.right_variables
+    }
+
+    /// Whether both sides contain the same variables.
+    pub fn is_regular(&self) -> bool {
+        self.left_variables.keys().eq(self.right_variables.keys())
+    }
+
+    /// Whether the equation is trivial or neither side is a variable.
+    pub fn is_normal(&self) -> bool {
+        self.trivial || !(self.left_is_variable || self.right_is_variable)
+    }
+
+    /// A key ordering equations from cheapest to most expensive to check.
+    ///
+    /// Equations with fewer variables come first, since the number of
+    /// assignments grows exponentially with them; ties are broken by size,
+    /// then depth, and finally by preferring the syntactically stronger
+    /// classes (balanced, regular, linear), which are usually decided early.
+    pub fn search_key(&self) -> (usize, usize, usize, bool, bool, bool) {
+        (
+            self.variable_count(),
+            self.size(),
+            self.depth,
+            !self.is_balanced(),
+            !self.is_regular(),
+            !self.is_linear(),
+        )
+    }
+}
+
+fn variable_multiset(term: &dyn Term) -> BTreeMap<String, usize> {
+    term.get_variable_list().into_iter()
+        .map(|v| {
+            let n = term.occurrences_of_variable(&v);
+            (v, n)
+        })
+        .collect()
+}
+
+impl fmt::Display for EquationComplexity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "EquationComplexity(size={}, depth={}, variables={}, linear={}, balanced={}, regular={}, normal={})",
+            self.size(),
+            self.depth,
+            self.variable_count(),
+            self.is_linear(),
+            self.is_balanced(),
+            self.is_regular(),
+            self.is_normal(),
+        )
+    }
+}
+
+/// Sort equations cheapest first by `EquationComplexity::search_key`.
+///
+/// The sort is stable, so equations of equal complexity keep their order.
+pub fn sort_by_complexity(equations: &mut [Equation]) {
+    equations.sort_by_cached_key(|eq| eq.complexity().search_key());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::terms::string_to_term;
+
+    fn eq(left: &str, right: &str) -> Equation {
+        Equation::new(string_to_term(left).unwrap(), string_to_term(right).unwrap())
+    }
+
+    #[test]
+    fn test_classes() {
+        let cases = [
+            // (left, right, linear, balanced, regular, normal)
+            ("f(x,y)", "f(y,x)", true, true, true, true),
+            ("f(x,f(x,y))", "f(x,y)", false, false, true, true),
+            ("f(x,y)", "x", true, false, false, false),
+            ("x", "x", true, true, true, true),
+            ("f(x,y)", "f(x,z)", true, false, false, true),
+            ("g(x,x,y)", "g(y,x,x)", false, true, true, true),
+        ];
+        for (l, r, linear, balanced, regular, normal) in cases {
+            let c = eq(l, r).complexity();
+            assert_eq!(c.is_linear(), linear, "{} = {}", l, r);
+            assert_eq!(c.is_balanced(), balanced, "{} = {}", l, r);
+            assert_eq!(c.is_regular(), regular, "{} = {}", l, r);
+            assert_eq!(c.is_normal(), normal, "{} = {}", l, r);
+        }
+        let c = eq("g(x,x,y)", "f(y,z)").complexity();
+        assert_eq!(c.left_variables().get("x"), Some(&2));
+        assert_eq!(c.right_variables().get("x"), None);
+        assert_eq!(c.variable_count(), 3);
+        assert_eq!((c.left_size(), c.right_size(), c.depth()), (1, 1, 1));
+    }
+
+    #[test]
+    fn test_sort_by_complexity() {
+        let mut eqs = vec![
+            eq("f(f(x,y),z)", "f(x,f(y,z))"),
+            eq("f(x,y)", "f(y,x)"),
+            eq("f(x,x)", "x"),
+            eq("f(x,f(x,y))", "f(x,y)"),
+        ];
+        sort_by_complexity(&mut eqs);
+        let order: Vec<String> = eqs.iter().map(|e| e.to_string()).collect();
+        assert_eq!(order, vec![
+            "f(x,x) = x",
+            "f(x,y) = f(y,x)",
+            "f(x,f(x,y)) = f(x,y)",
+            "f(f(x,y),z) = f(x,f(y,z))",
+        ]);
+    }
+}
@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+use std::fmt;
+use crate::alg::SmallAlgebra;
+use crate::eq::Equation;
+use crate::terms::{Term, TermDag};
+use crate::terms::term_dag::DEFAULT_BLOCK_SIZE;
+
+/// A collection of equations checked together.
+///
+/// All sides of all equations are compiled into a single `TermDag` over the
+/// union of their variables, so a subterm occurring in several equations
+/// (for example `join(x,y)` in several lattice axioms) is evaluated once per
+/// assignment. Checking stops as soon as every equation has failed.
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use uacalc::alg::BasicAlgebra;
+/// use uacalc::alg::op::{OperationSymbol, operations};
+/// use uacalc::eq::{equations, EquationSystem};
+///
+/// let sym = OperationSymbol::new("f", 2, false);
+/// let op = operations::make_int_operation(sym.clone(), 2, vec![0, 0, 0, 1]).unwrap();
+/// let alg = BasicAlgebra::new("A".to_string(), (0..2).collect::<HashSet<i32>>(), vec![op]);
+///
+/// let system = EquationSystem::new(vec![
+///     equations::associative_law(&sym).unwrap(),
+///     equations::first_second_symmetric_law(&sym).unwrap(),
+/// ]);
+/// assert!(system.all_satisfied_in(&alg).unwrap());
+/// ```
+#[derive(Debug, Clone)]
+pub struct EquationSystem {
+    equations: Vec<Equation>,
+}
+
+impl EquationSystem {
+    /// Create a new equation system from a list of equations.
+    pub fn new(equations: Vec<Equation>) -> Self {
+        EquationSystem { equations }
+    }
+
+    /// Add an equation to the system.
+    pub fn add(&mut self, equation: Equation) {
+        self.equations.push(equation);
+    }
+
+    /// Reorder the equations cheapest first by `EquationComplexity::search_key`.
+    ///
+    /// Verdicts returned afterwards follow the new order.
+    pub fn sort_by_complexity(&mut self) {
+        crate::eq::sort_by_complexity(&mut self.equations);
+    }
+
+    /// Get the equations of this system.
+    pub fn equations(&self) -> &[Equation] {
+        &self.equations
+    }
+
+    /// The number of equations in the system.
+    pub fn len(&self) -> usize {
+        self.equations.len()
+    }
+
+    /// Whether the system has no equations.
+    pub fn is_empty(&self) -> bool {
+        self.equations.is_empty()
+    }
+
+    /// The union of the variable lists of the equations, in order of first occurrence.
+    pub fn get_variable_list(&self) -> Vec<String> {
+        let mut vars: Vec<String> = Vec::new();
+        for eq in &self.equations {
+            for v in eq.get_variable_list() {
+                if !vars.contains(&v) {
+                    vars.push(v);
+                }
+            }
+        }
+        vars
+    }
+
+    /// Check every equation in the given algebra with shared evaluation.
+    ///
+    /// # Arguments
+    /// * `alg` - The algebra to check
+    ///
+    /// # Returns
+    /// * `Ok(verdicts)` - One entry per equation: `None` if it holds, otherwise
+    ///   `Some(args)`, a failing assignment given in the equation's own
+    ///   variable order (as in `Equation::find_failure`)
+    /// * `Err(String)` - If an operation is missing from the algebra
+    pub fn satisfied_in(&self, alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<Vec<Option<Vec<i32>>>, String> {
+        self.check(alg, false)
+    }
+
+    /// Test whether every equation holds in the given algebra.
+    ///
+    /// Stops at the first failure found.
+    pub fn all_satisfied_in(&self, alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<bool, String> {
+        Ok(self.check(alg, true)?.iter().all(|v| v.is_none()))
+    }
+
+    /// Check every equation and report failures as variable maps.
+    ///
+    /// # Returns
+    /// One entry per equation: `None` if it holds, otherwise a map from
+    /// variable names to the values of a failing assignment
+    pub fn failure_maps(&self, alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<Vec<Option<HashMap<String, i32>>>, String> {
+        let verdicts = self.satisfied_in(alg)?;
+        Ok(verdicts.into_iter().zip(&self.equations).map(|(v, eq)| {
+            v.map(|args| eq.get_variable_list().into_iter().zip(args).collect())
+        }).collect())
+    }
+
+    fn check(&self, alg: &dyn SmallAlgebra<UniverseItem = i32>, stop_at_first: bool) -> Result<Vec<Option<Vec<i32>>>, String> {
+        // Assignments range over the union of the variables of both sides of
+        // every equation, so an equation whose sides have different variables
+        // is checked at every value of the variables on either side.
+        let var_list = self.get_variable_list();
+        let mut sides: Vec<&dyn Term> = Vec::with_capacity(2 * self.equations.len());
+        for eq in &self.equations {
+            sides.push(eq.left_side());
+            sides.push(eq.right_side());
+        }
+        let dag = TermDag::compile(&sides, alg, &var_list)?;
+        let total = dag.assignment_count()?;
+
+        let mut failures: Vec<Option<usize>> = vec![None; self.equations.len()];
+        let mut open = self.equations.len();
+        let mut buf = Vec::new();
+        let mut start = 0;
+        while start < total && open > 0 {
+            let len = DEFAULT_BLOCK_SIZE.min(total - start);
+            dag.eval_block(start, len, &mut buf);
+            for (i, failure) in failures.iter_mut().enumerate() {
+                if failure.is_some() {
+                    continue;
+                }
+                let left = dag.column(&buf, dag.roots()[2 * i], len);
+                let right = dag.column(&buf, dag.roots()[2 * i + 1], len);
+                if let Some(j) = left.iter().zip(right).position(|(x, y)| x != y) {
+                    *failure = Some(start + j);
+                    open -= 1;
+                }
+            }
+            if stop_at_first && open < self.equations.len() {
+                break;
+            }
+            start += len;
+        }
+
+        // Restrict each failing assignment to the equation's own variables.
+        Ok(failures.into_iter().zip(&self.equations).map(|(failure, eq)| {
+            failure.map(|k| {
+                let args = dag.assignment(k);
+                eq.get_variable_list().iter()
+                    .map(|v| args[var_list.iter().position(|u| u == v).unwrap()])
+                    .collect()
+            })
+        }).collect())
+    }
+}
+
+impl fmt::Display for EquationSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let eqs: Vec<String> = self.equations.iter().map(|e| e.to_string()).collect();
+        write!(f, "EquationSystem([{}])", eqs.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::BasicAlgebra;
+    use crate::alg::op::{OperationSymbol, operations};
+    use crate::terms::string_to_term;
+
+    fn eq(left: &str, right: &str) -> Equation {
+        Equation::new(string_to_term(left).unwrap(), string_to_term(right).unwrap())
+    }
+
+    fn make_semilattice_and_projection() -> BasicAlgebra<i32> {
+        let meet = OperationSymbol::new("meet", 2, false);
+        let meet_op = operations::make_int_operation(meet, 3, (0..9).map(|k| (k % 3).min(k / 3)).collect()).unwrap();
+        let proj = OperationSymbol::new("p", 2, false);
+        let proj_op = operations::make_int_operation(proj, 3, (0..9).map(|k| k % 3).collect()).unwrap();
+        BasicAlgebra::new("A".to_string(), (0..3).collect(), vec![meet_op, proj_op])
+    }
+
+    #[test]
+    fn test_satisfied_in_matches_single_equations() {
+        let alg = make_semilattice_and_projection();
+        let system = EquationSystem::new(vec![
+            eq("meet(x,y)", "meet(y,x)"),
+            eq("meet(x,meet(y,z))", "meet(meet(x,y),z)"),
+            eq("p(x,y)", "p(y,x)"),
+            eq("p(z,meet(x,y))", "z"),
+            eq("meet(x,x)", "x"),
+        ]);
+        assert_eq!(system.get_variable_list(), vec!["x", "y", "z"]);
+
+        let verdicts = system.satisfied_in(&alg).unwrap();
+        assert_eq!(verdicts.len(), 5);
+        for (verdict, e) in verdicts.iter().zip(system.equations()) {
+            assert_eq!(verdict.is_none(), e.is_satisfied_in(&alg).unwrap(), "{}", e);
+        }
+
+        let failure = verdicts[2].as_ref().unwrap();
+        let map: HashMap<String, i32> = [("x".to_string(), failure[0]), ("y".to_string(), failure[1])].into();
+        assert_ne!(
+            e_eval(&alg, "p(x,y)", &map),
+            e_eval(&alg, "p(y,x)", &map),
+        );
+        assert!(!system.all_satisfied_in(&alg).unwrap());
+    }
+
+    fn e_eval(alg: &BasicAlgebra<i32>, term: &str, map: &HashMap<String, i32>) -> i32 {
+        string_to_term(term).unwrap().eval(alg, map).unwrap()
+    }
+
+    #[test]
+    fn test_sides_with_different_variables() {
+        let alg = make_semilattice_and_projection();
+        let system = EquationSystem::new(vec![
+            eq("p(x,y)", "x"),
+            eq("meet(x,y)", "z"),
+            eq("p(w,meet(x,w))", "w"),
+            eq("x", "y"),
+        ]);
+        assert_eq!(system.get_variable_list(), vec!["x", "y", "z", "w"]);
+        let verdicts = system.satisfied_in(&alg).unwrap();
+        let maps = system.failure_maps(&alg).unwrap();
+        for ((verdict, map), e) in verdicts.iter().zip(&maps).zip(system.equations()) {
+            assert_eq!(verdict.is_none(), e.is_satisfied_in(&alg).unwrap(), "{}", e);
+            if let Some(map) = map {
+                assert_eq!(map.len(), e.get_variable_list().len(), "{}", e);
+                assert_ne!(e.left_side().eval(&alg, map).unwrap(), e.right_side().eval(&alg, map).unwrap(), "{}", e);
+            }
+        }
+        assert!(verdicts[0].is_none());
+        assert_eq!(verdicts[1].as_ref().unwrap().len(), 3);
+        assert!(verdicts[2].is_none());
+        assert!(verdicts[3].is_some());
+    }
+
+    #[test]
+    fn test_failure_maps_and_empty_system() {
+        let alg = make_semilattice_and_projection();
+        let system = EquationSystem::new(vec![eq("meet(x,y)", "x"), eq("meet(x,y)", "meet(y,x)")]);
+        let maps = system.failure_maps(&alg).unwrap();
+        let map = maps[0].as_ref().unwrap();
+        assert_ne!(e_eval(&alg, "meet(x,y)", map), map["x"]);
+        assert!(maps[1].is_none());
+
+        let empty = EquationSystem::new(Vec::new());
+        assert!(empty.is_empty());
+        assert!(empty.all_satisfied_in(&alg).unwrap());
+    }
+}
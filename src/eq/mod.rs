@@ -1,9 +1,9 @@
 use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
 use std::fmt;
-use crate::terms::Term;
+use crate::terms::{Term, TermArena, CompiledTerm, TermDag, compiled_term, term_dag};
 use crate::alg::SmallAlgebra;
-use crate::alg::op::{OperationSymbol, operations};
+use crate::alg::op::OperationSymbol;
 
 /// A class to represent equations, that is, pairs of terms.
 /// 
@@ -60,6 +60,35 @@ impl Equation {
         }
     }
     
+    /// Create an equation from two nodes of a `TermArena`.
+    /// 
+    /// Both sides are rebuilt from the arena, so the equation does not
+    /// borrow it; import terms from other arenas with `TermArena::import_from`.
+    /// 
+    /// # Arguments
+    /// * `arena` - The arena holding both sides
+    /// * `left` - The node index of the left side
+    /// * `right` - The node index of the right side
+    /// 
+    /// # Returns
+    /// * `Ok(Equation)` - The equation `left = right`
+    /// * `Err(String)` - If either index is not a node of `arena`
+    /// 
+    /// # Examples
+    /// ```
+    /// use uacalc::eq::Equation;
+    /// use uacalc::terms::{string_to_term, TermArena};
+    /// 
+    /// let mut arena = TermArena::new();
+    /// let l = arena.add(string_to_term("f(x,y)").unwrap().as_ref());
+    /// let r = arena.add(string_to_term("f(y,x)").unwrap().as_ref());
+    /// let eq = Equation::from_term_ids(&arena, l, r).unwrap();
+    /// assert_eq!(eq.to_string(), "f(x,y) = f(y,x)");
+    /// ```
+    pub fn from_term_ids(arena: &TermArena, left: usize, right: usize) -> Result<Self, String> {
+        Ok(Equation::new(arena.term(left)?, arena.term(right)?))
+    }
+    
     /// Get the left side term.
     /// 
     /// # Returns
@@ -116,6 +145,14 @@ impl Equation {
         set
     }
     
+    /// Compute the structural complexity of this equation.
+    /// 
+    /// # Returns
+    /// The sizes, variable multisets and syntactic classes of the equation
+    pub fn complexity(&self) -> EquationComplexity {
+        EquationComplexity::new(self)
+    }
+    
     /// Find where this equation fails in the given algebra.
     /// 
     /// # Arguments
@@ -126,10 +163,96 @@ impl Equation {
     /// * `Ok(None)` - If the equation holds in the algebra
     /// * `Err(String)` - If an error occurs during checking
     pub fn find_failure(&self, alg: Arc<dyn SmallAlgebra<UniverseItem = i32>>) -> Result<Option<Vec<i32>>, String> {
+        self.find_failure_in(alg.as_ref())
+    }
+    
+    /// Find where this equation fails in the given algebra, without requiring an `Arc`.
+    /// 
+    /// Both sides are compiled to `CompiledTerm`s and compared at every
+    /// assignment in Horner order, so the first failure reported is the same
+    /// as the one found by comparing the interpretations.
+    /// 
+    /// # Arguments
+    /// * `alg` - The algebra to check
+    /// 
+    /// # Returns
+    /// * `Ok(Some(args))` - The first variable assignment where the equation fails
+    /// * `Ok(None)` - If the equation holds in the algebra
+    /// * `Err(String)` - If an operation of the equation is missing from the algebra
+    pub fn find_failure_in(&self, alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<Option<Vec<i32>>, String> {
         let var_list = self.get_variable_list();
-        let left_op = self.left_side.interpretation(alg.clone(), &var_list, true)?;
-        let right_op = self.right_side.interpretation(alg, &var_list, true)?;
-        operations::find_difference(left_op.as_ref(), right_op.as_ref())
+        let left = CompiledTerm::compile(self.left_side.as_ref(), alg, &var_list)?;
+        let right = CompiledTerm::compile(self.right_side.as_ref(), alg, &var_list)?;
+        let size = alg.cardinality();
+        compiled_term::table_size(size, var_list.len())?;
+        
+        let mut args = vec![0; var_list.len()];
+        let mut left_stack = Vec::with_capacity(left.max_stack());
+        let mut right_stack = Vec::with_capacity(right.max_stack());
+        loop {
+            if left.eval_with_stack(&args, &mut left_stack) != right.eval_with_stack(&args, &mut right_stack) {
+                return Ok(Some(args));
+            }
+            if !compiled_term::increment(&mut args, size) {
+                return Ok(None);
+            }
+        }
+    }
+    
+    /// Find where this equation fails using the batched evaluator.
+    /// 
+    /// Both sides are merged into one `TermDag`, so subterms shared between
+    /// them are evaluated once, and assignments are processed in blocks of
+    /// `term_dag::DEFAULT_BLOCK_SIZE`. With `parallel` set the blocks are
+    /// distributed over the rayon thread pool. Either way the search stops
+    /// at the first failing block and the reported failure is the first one
+    /// in Horner order, the same as `find_failure_in`.
+    /// 
+    /// # Arguments
+    /// * `alg` - The algebra to check
+    /// * `parallel` - Whether to evaluate blocks in parallel
+    /// 
+    /// # Returns
+    /// * `Ok(Some(args))` - The first variable assignment where the equation fails
+    /// * `Ok(None)` - If the equation holds in the algebra
+    /// * `Err(String)` - If an operation of the equation is missing from the algebra
+    pub fn find_failure_batched(
+        &self,
+        alg: &dyn SmallAlgebra<UniverseItem = i32>,
+        parallel: bool,
+    ) -> Result<Option<Vec<i32>>, String> {
+        let var_list = self.get_variable_list();
+        let dag = TermDag::compile(&[self.left_side(), self.right_side()], alg, &var_list)?;
+        dag.find_difference(0, 1, term_dag::DEFAULT_BLOCK_SIZE, parallel)
+    }
+    
+    /// Test whether this equation holds in the given algebra.
+    /// 
+    /// # Arguments
+    /// * `alg` - The algebra to check
+    /// 
+    /// # Returns
+    /// * `Ok(true)` - If the equation holds for every assignment
+    /// * `Ok(false)` - If some assignment is a counterexample
+    /// * `Err(String)` - If an operation of the equation is missing from the algebra
+    /// 
+    /// # Examples
+    /// ```
+    /// use std::collections::HashSet;
+    /// use uacalc::alg::BasicAlgebra;
+    /// use uacalc::alg::op::{OperationSymbol, operations};
+    /// use uacalc::eq::Equation;
+    /// use uacalc::terms::string_to_term;
+    /// 
+    /// let sym = OperationSymbol::new("f", 2, false);
+    /// let op = operations::make_int_operation(sym, 2, vec![0, 1, 1, 1]).unwrap();
+    /// let alg = BasicAlgebra::new("A".to_string(), (0..2).collect::<HashSet<i32>>(), vec![op]);
+    /// 
+    /// let eq = Equation::new(string_to_term("f(x,y)").unwrap(), string_to_term("f(y,x)").unwrap());
+    /// assert!(eq.is_satisfied_in(&alg).unwrap());
+    /// ```
+    pub fn is_satisfied_in(&self, alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<bool, String> {
+        Ok(self.find_failure_in(alg)?.is_none())
     }
     
     /// Find where this equation fails in the given algebra as a variable map.
@@ -173,13 +296,189 @@ impl fmt::Display for Presentation {
     }
 }
 
+/// Check whether two terms define the same operation on an algebra.
+/// 
+/// The terms are compared at every assignment of the variables occurring
+/// in either term, so this is the same as checking the equation
+/// `left = right` without building one.
+/// 
+/// # Arguments
+/// * `alg` - The algebra
+/// * `left`, `right` - The terms to compare
+/// 
+/// # Returns
+/// * `Ok(true)` - If the terms agree at every assignment
+/// * `Ok(false)` - If they differ somewhere
+/// * `Err(String)` - If an operation of a term is missing from the algebra
+/// 
+/// # Examples
+/// ```
+/// use uacalc::eq::terms_equal_in;
+/// use uacalc::io::AlgebraReader;
+/// use uacalc::terms::string_to_term;
+/// 
+/// let alg = AlgebraReader::new_from_file(std::path::Path::new("resources/algebras/sym3.ua"))
+///     .unwrap().read_algebra_file().unwrap();
+/// let assoc_l = string_to_term("p(p(x,y),z)").unwrap();
+/// let assoc_r = string_to_term("p(x,p(y,z))").unwrap();
+/// assert!(terms_equal_in(&alg, assoc_l.as_ref(), assoc_r.as_ref()).unwrap());
+/// let comm = string_to_term("p(y,x)").unwrap();
+/// let xy = string_to_term("p(x,y)").unwrap();
+/// assert!(!terms_equal_in(&alg, xy.as_ref(), comm.as_ref()).unwrap());
+/// ```
+pub fn terms_equal_in(
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    left: &dyn Term,
+    right: &dyn Term,
+) -> Result<bool, String> {
+    let eq = Equation::new(left.clone_box(), right.clone_box());
+    Ok(eq.find_failure_in(alg)?.is_none())
+}
+
+/// Check whether two terms are equal in the variety generated by an algebra.
+/// 
+/// Both terms are evaluated in the free algebra of the variety on one
+/// generator per variable occurring in either term, the variables being
+/// sent to the free generators; the terms are equal in the variety exactly
+/// when they evaluate to the same element. This gives the same answer as
+/// `terms_equal_in` but builds the free algebra, which can be far larger
+/// than the table of assignments; it is useful when the free algebra is
+/// needed anyway.
+/// 
+/// # Arguments
+/// * `alg` - The algebra generating the variety
+/// * `left`, `right` - The terms to compare
+/// 
+/// # Returns
+/// * `Ok(true)` - If `left = right` holds in the variety
+/// * `Ok(false)` - If it does not
+/// * `Err(String)` - If the free algebra cannot be built or a term cannot be evaluated in it
+pub fn terms_equal_in_variety(
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    left: &dyn Term,
+    right: &dyn Term,
+) -> Result<bool, String> {
+    let mut vars = left.get_variable_list();
+    for v in right.get_variable_list() {
+        if !vars.contains(&v) {
+            vars.push(v);
+        }
+    }
+    let free = crate::alg::FreeAlgebra::new_safe(alg.clone_box(), vars.len().max(1) as i32)?;
+    let inner = free.get_inner();
+    let mut map = HashMap::new();
+    for (v, gen) in vars.iter().zip(inner.generators()) {
+        let index = inner.element_index(gen)
+            .ok_or_else(|| "Free generator not found in the free algebra".to_string())?;
+        map.insert(v.clone(), index as i32);
+    }
+    Ok(left.eval_on_free_algebra(&free, &map)? == right.eval_on_free_algebra(&free, &map)?)
+}
+
 // Equations module for generating common algebraic equations
 pub mod equations;
 
+pub mod equation_system;
+pub use equation_system::EquationSystem;
+
+pub mod complexity;
+pub use complexity::{EquationComplexity, sort_by_complexity};
+
+// Axiom sets of common varieties
+pub mod theories;
+
+pub mod quasi_equation;
+pub use quasi_equation::QuasiEquation;
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::terms::VariableImp;
+    use crate::alg::op::operations;
+    use crate::terms::{VariableImp, string_to_term};
+    use crate::alg::BasicAlgebra;
+    
+    fn make_z3() -> BasicAlgebra<i32> {
+        let sym = OperationSymbol::new("add", 2, false);
+        let table = (0..9).map(|k| (k % 3 + k / 3) % 3).collect();
+        let op = operations::make_int_operation(sym, 3, table).unwrap();
+        BasicAlgebra::new("Z3".to_string(), (0..3).collect(), vec![op])
+    }
+    
+    #[test]
+    fn test_terms_equal_in_algebra_and_variety() {
+        let alg = make_z3();
+        let pairs = [
+            ("add(x,y)", "add(y,x)", true),
+            ("add(add(x,y),z)", "add(x,add(y,z))", true),
+            ("add(x,add(x,add(x,y)))", "y", true),
+            ("add(x,x)", "x", false),
+            ("add(x,y)", "add(x,z)", false),
+        ];
+        for (l, r, expected) in pairs {
+            let l = string_to_term(l).unwrap();
+            let r = string_to_term(r).unwrap();
+            assert_eq!(terms_equal_in(&alg, l.as_ref(), r.as_ref()).unwrap(), expected, "{} = {}", l, r);
+            assert_eq!(terms_equal_in_variety(&alg, l.as_ref(), r.as_ref()).unwrap(), expected, "{} = {}", l, r);
+        }
+        let missing = string_to_term("mul(x,y)").unwrap();
+        assert!(terms_equal_in(&alg, missing.as_ref(), missing.as_ref()).is_err());
+    }
+    
+    #[test]
+    fn test_is_satisfied_in() {
+        let alg = make_z3();
+        let comm = Equation::new(
+            string_to_term("add(x,y)").unwrap(),
+            string_to_term("add(y,x)").unwrap(),
+        );
+        assert!(comm.is_satisfied_in(&alg).unwrap());
+        
+        let idem = Equation::new(string_to_term("add(x,x)").unwrap(), string_to_term("x").unwrap());
+        assert!(!idem.is_satisfied_in(&alg).unwrap());
+        assert_eq!(idem.find_failure_in(&alg).unwrap(), Some(vec![1]));
+    }
+    
+    #[test]
+    fn test_find_failure_matches_interpretation() {
+        let alg = make_z3();
+        let eq = Equation::new(
+            string_to_term("add(x,add(y,y))").unwrap(),
+            string_to_term("add(y,x)").unwrap(),
+        );
+        let arc: Arc<dyn SmallAlgebra<UniverseItem = i32>> = Arc::new(alg.clone());
+        let var_list = eq.get_variable_list();
+        let left = eq.left_side().interpretation(arc.clone(), &var_list, true).unwrap();
+        let right = eq.right_side().interpretation(arc.clone(), &var_list, true).unwrap();
+        let expected = operations::find_difference(left.as_ref(), right.as_ref()).unwrap();
+        assert_eq!(eq.find_failure(arc).unwrap(), expected);
+    }
+    
+    #[test]
+    fn test_find_failure_batched() {
+        let alg = make_z3();
+        let eq = Equation::new(
+            string_to_term("add(add(x,y),add(x,z))").unwrap(),
+            string_to_term("add(add(x,x),add(z,y))").unwrap(),
+        );
+        assert_eq!(eq.find_failure_batched(&alg, false).unwrap(), None);
+        assert_eq!(eq.find_failure_batched(&alg, true).unwrap(), None);
+        
+        let eq = Equation::new(
+            string_to_term("add(x,add(y,z))").unwrap(),
+            string_to_term("add(x,add(y,y))").unwrap(),
+        );
+        let expected = eq.find_failure_in(&alg).unwrap();
+        assert!(expected.is_some());
+        assert_eq!(eq.find_failure_batched(&alg, false).unwrap(), expected);
+        assert_eq!(eq.find_failure_batched(&alg, true).unwrap(), expected);
+    }
+    
+    #[test]
+    fn test_is_satisfied_in_missing_operation() {
+        let alg = make_z3();
+        let eq = Equation::new(string_to_term("mul(x,y)").unwrap(), string_to_term("x").unwrap());
+        assert!(eq.is_satisfied_in(&alg).is_err());
+    }
     
     #[test]
     fn test_presentation_creation() {
@@ -0,0 +1,198 @@
+use std::fmt;
+use crate::alg::SmallAlgebra;
+use crate::eq::Equation;
+use crate::terms::CompiledTerm;
+
+/// A quasi-equation: a finite conjunction of equations, the premises,
+/// implying a further equation, the conclusion.
+///
+/// It holds in an algebra if the conclusion holds at every assignment at
+/// which all the premises hold. Quasi-equations axiomatize quasivarieties
+/// as equations axiomatize varieties.
+///
+/// # Examples
+/// ```
+/// use uacalc::eq::{Equation, QuasiEquation};
+/// use uacalc::terms::string_to_term;
+///
+/// // Cancellation: x + z = y + z implies x = y
+/// let cancel = QuasiEquation::new(
+///     vec![Equation::new(string_to_term("add(x,z)").unwrap(), string_to_term("add(y,z)").unwrap())],
+///     Equation::new(string_to_term("x").unwrap(), string_to_term("y").unwrap()),
+/// );
+/// assert_eq!(cancel.to_string(), "add(x,z) = add(y,z) => x = y");
+/// assert_eq!(cancel.get_variable_list(), vec!["x", "z", "y"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct QuasiEquation {
+    premises: Vec<Equation>,
+    conclusion: Equation,
+}
+
+impl QuasiEquation {
+    /// Create a quasi-equation from its premises and conclusion.
+    pub fn new(premises: Vec<Equation>, conclusion: Equation) -> Self {
+        QuasiEquation { premises, conclusion }
+    }
+
+    /// The premises.
+    pub fn premises(&self) -> &[Equation] {
+        &self.premises
+    }
+
+    /// The conclusion.
+    pub fn conclusion(&self) -> &Equation {
+        &self.conclusion
+    }
+
+    /// The variables in order of first occurrence, in the premises and then
+    /// in the conclusion.
+    pub fn get_variable_list(&self) -> Vec<String> {
+        let mut vars: Vec<String> = Vec::new();
+        for eq in self.premises.iter().chain(std::iter::once(&self.conclusion)) {
+            for v in eq.get_variable_list() {
+                if !vars.contains(&v) {
+                    vars.push(v);
+                }
+            }
+        }
+        vars
+    }
+
+    /// Find an assignment at which every premise holds but the conclusion
+    /// fails.
+    ///
+    /// The variables are assigned one at a time in the order of
+    /// `get_variable_list`, and a premise is checked as soon as its
+    /// variables are assigned, so assignments violating a premise are cut
+    /// off early.
+    ///
+    /// # Arguments
+    /// * `alg` - The algebra to check
+    ///
+    /// # Returns
+    /// * `Ok(Some(args))` - The values of the variables at a failure
+    /// * `Ok(None)` - If the quasi-equation holds in the algebra
+    /// * `Err(String)` - If an operation is missing from the algebra or the
+    ///   computation was cancelled
+    pub fn find_failure_in(&self, alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<Option<Vec<i32>>, String> {
+        let vars = self.get_variable_list();
+        let n = vars.len();
+        let size = alg.cardinality();
+        // The premises to check once variable `i` is assigned: those whose
+        // last variable is `i`. Variable-free premises are checked first.
+        let mut checks: Vec<Vec<(CompiledTerm, CompiledTerm)>> = (0..=n).map(|_| Vec::new()).collect();
+        let compile = |eq: &Equation| -> Result<(CompiledTerm, CompiledTerm), String> {
+            Ok((CompiledTerm::compile(eq.left_side(), alg, &vars)?, CompiledTerm::compile(eq.right_side(), alg, &vars)?))
+        };
+        for eq in &self.premises {
+            let last = eq.get_variable_list().iter()
+                .filter_map(|v| vars.iter().position(|w| w == v))
+                .max()
+                .map_or(0, |i| i + 1);
+            checks[last].push(compile(eq)?);
+        }
+        let conclusion = compile(&self.conclusion)?;
+        let holds = |pairs: &[(CompiledTerm, CompiledTerm)], args: &[i32]| {
+            pairs.iter().all(|(l, r)| l.eval(args) == r.eval(args))
+        };
+
+        let mut args = vec![0; n];
+        if !holds(&checks[0], &args) {
+            return Ok(None);
+        }
+        if n == 0 {
+            return Ok((!holds(std::slice::from_ref(&conclusion), &args)).then_some(args));
+        }
+        if size <= 0 {
+            return Ok(None);
+        }
+        // Depth-first search: `depth` variables are assigned and the
+        // premises up to `depth` hold.
+        let mut depth = 1;
+        loop {
+            crate::progress::check_cancelled()?;
+            let i = depth - 1;
+            if holds(&checks[depth], &args) {
+                if depth == n {
+                    if !holds(std::slice::from_ref(&conclusion), &args) {
+                        return Ok(Some(args));
+                    }
+                } else {
+                    depth += 1;
+                    args[depth - 1] = 0;
+                    continue;
+                }
+            }
+            // Move to the next value, backtracking past exhausted variables.
+            let mut i = i;
+            loop {
+                if args[i] + 1 < size {
+                    args[i] += 1;
+                    depth = i + 1;
+                    break;
+                }
+                args[i] = 0;
+                if i == 0 {
+                    return Ok(None);
+                }
+                i -= 1;
+            }
+        }
+    }
+
+    /// Whether the quasi-equation holds in an algebra.
+    ///
+    /// # Returns
+    /// * `Ok(bool)` - Whether it holds
+    /// * `Err(String)` - As for `find_failure_in`
+    pub fn is_satisfied_in(&self, alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<bool, String> {
+        Ok(self.find_failure_in(alg)?.is_none())
+    }
+}
+
+impl fmt::Display for QuasiEquation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let premises: Vec<String> = self.premises.iter().map(|eq| eq.to_string()).collect();
+        if premises.is_empty() {
+            write!(f, "{}", self.conclusion)
+        } else {
+            write!(f, "{} => {}", premises.join(" & "), self.conclusion)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::BasicAlgebra;
+    use crate::alg::op::OperationSymbol;
+    use crate::alg::op::operations::make_int_operation;
+    use crate::terms::string_to_term;
+
+    fn eq(left: &str, right: &str) -> Equation {
+        Equation::new(string_to_term(left).unwrap(), string_to_term(right).unwrap())
+    }
+
+    #[test]
+    fn test_cancellation() {
+        let cancel = QuasiEquation::new(vec![eq("f(x,z)", "f(y,z)")], eq("x", "y"));
+        let z3 = make_int_operation(OperationSymbol::new("f", 2, false), 3, (0..9).map(|k| (k % 3 + k / 3) % 3).collect()).unwrap();
+        let z3 = BasicAlgebra::new("Z3".to_string(), (0..3).collect(), vec![z3]);
+        assert!(cancel.is_satisfied_in(&z3).unwrap());
+
+        let meet = make_int_operation(OperationSymbol::new("f", 2, false), 2, vec![0, 0, 0, 1]).unwrap();
+        let s = BasicAlgebra::new("S".to_string(), (0..2).collect(), vec![meet]);
+        // Variables x, z, y: 0 ∧ 0 = 1 ∧ 0 but 0 != 1
+        assert_eq!(cancel.find_failure_in(&s).unwrap(), Some(vec![0, 0, 1]));
+    }
+
+    #[test]
+    fn test_without_premises() {
+        let comm = QuasiEquation::new(Vec::new(), eq("f(x,y)", "f(y,x)"));
+        assert_eq!(comm.to_string(), "f(x,y) = f(y,x)");
+        let proj = make_int_operation(OperationSymbol::new("f", 2, false), 2, vec![0, 1, 0, 1]).unwrap();
+        let alg = BasicAlgebra::new("P".to_string(), (0..2).collect(), vec![proj]);
+        assert!(!comm.is_satisfied_in(&alg).unwrap());
+    }
+}
@@ -0,0 +1,718 @@
+//! Axiom sets of common equational theories.
+//!
+//! Each function returns the defining equations of a variety, with the
+//! operation symbols given by name so the axioms can be checked against
+//! any algebra whatever its operations are called:
+//!
+//! ```
+//! use uacalc::alg::ring;
+//! use uacalc::eq::theories;
+//!
+//! let z6 = ring::zn_ring(6).unwrap();
+//! let axioms = theories::ring("add", "neg", "zero", "mul", "one").unwrap();
+//! assert!(axioms.iter().all(|eq| eq.is_satisfied_in(&z6).unwrap()));
+//! ```
+
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::alg::op::OperationSymbol;
+use crate::alg::{Algebra, BasicAlgebra};
+use crate::eq::Equation;
+use crate::terms::{NonVariableTerm, Term, VariableImp};
+
+type BoxedTerm = Box<dyn Term>;
+
+fn x() -> BoxedTerm {
+    Box::new(VariableImp::x())
+}
+
+fn y() -> BoxedTerm {
+    Box::new(VariableImp::y())
+}
+
+fn z() -> BoxedTerm {
+    Box::new(VariableImp::z())
+}
+
+/// The operation symbols of a theory, checked to have distinct names.
+fn symbols<const N: usize>(names: [(&str, i32); N]) -> Result<[OperationSymbol; N], String> {
+    let mut seen = HashSet::new();
+    for (name, _) in names {
+        if name.is_empty() {
+            return Err("Operation names must not be empty".to_string());
+        }
+        if !seen.insert(name) {
+            return Err(format!("The operation name {} is used twice", name));
+        }
+    }
+    Ok(names.map(|(name, arity)| OperationSymbol::new(name, arity, false)))
+}
+
+fn app(f: &OperationSymbol, args: Vec<BoxedTerm>) -> BoxedTerm {
+    Box::new(NonVariableTerm::new(f.clone(), args))
+}
+
+fn bin(f: &OperationSymbol, a: BoxedTerm, b: BoxedTerm) -> BoxedTerm {
+    app(f, vec![a, b])
+}
+
+fn con(c: &OperationSymbol) -> BoxedTerm {
+    app(c, vec![])
+}
+
+fn eq(left: BoxedTerm, right: BoxedTerm) -> Equation {
+    Equation::new(left, right)
+}
+
+fn associative(f: &OperationSymbol) -> Equation {
+    eq(bin(f, x(), bin(f, y(), z())), bin(f, bin(f, x(), y()), z()))
+}
+
+fn commutative(f: &OperationSymbol) -> Equation {
+    eq(bin(f, x(), y()), bin(f, y(), x()))
+}
+
+/// `e·x = x` and `x·e = x`.
+fn identity_laws(f: &OperationSymbol, e: &OperationSymbol) -> [Equation; 2] {
+    [eq(bin(f, con(e), x()), x()), eq(bin(f, x(), con(e)), x())]
+}
+
+/// `x⁻¹·x = e` and `x·x⁻¹ = e`.
+fn inverse_laws(f: &OperationSymbol, inv: &OperationSymbol, e: &OperationSymbol) -> [Equation; 2] {
+    [
+        eq(bin(f, app(inv, vec![x()]), x()), con(e)),
+        eq(bin(f, x(), app(inv, vec![x()])), con(e)),
+    ]
+}
+
+/// `x·(y+z) = x·y + x·z` and `(x+y)·z = x·z + y·z`.
+fn distributive_laws(mul: &OperationSymbol, add: &OperationSymbol) -> [Equation; 2] {
+    [
+        eq(bin(mul, x(), bin(add, y(), z())), bin(add, bin(mul, x(), y()), bin(mul, x(), z()))),
+        eq(bin(mul, bin(add, x(), y()), z()), bin(add, bin(mul, x(), z()), bin(mul, y(), z()))),
+    ]
+}
+
+/// The theory of semigroups: `mul` is associative.
+///
+/// # Arguments
+/// * `mul` - The name of the binary operation
+///
+/// # Returns
+/// * `Ok(Vec<Equation>)` - The axioms
+/// * `Err(String)` - If the name is empty
+pub fn semigroup(mul: &str) -> Result<Vec<Equation>, String> {
+    let [mul] = symbols([(mul, 2)])?;
+    Ok(vec![associative(&mul)])
+}
+
+/// The theory of monoids: an associative `mul` with identity `one`.
+///
+/// # Arguments
+/// * `mul` - The name of the binary operation
+/// * `one` - The name of the identity constant
+///
+/// # Returns
+/// * `Ok(Vec<Equation>)` - The axioms
+/// * `Err(String)` - If a name is empty or the names are not distinct
+pub fn monoid(mul: &str, one: &str) -> Result<Vec<Equation>, String> {
+    let [mul, one] = symbols([(mul, 2), (one, 0)])?;
+    let mut axioms = vec![associative(&mul)];
+    axioms.extend(identity_laws(&mul, &one));
+    Ok(axioms)
+}
+
+/// The theory of groups: a monoid in which `inv` gives two-sided inverses.
+///
+/// # Arguments
+/// * `mul` - The name of the product
+/// * `inv` - The name of the inverse
+/// * `one` - The name of the identity
+///
+/// # Returns
+/// * `Ok(Vec<Equation>)` - The axioms
+/// * `Err(String)` - If a name is empty or the names are not distinct
+pub fn group(mul: &str, inv: &str, one: &str) -> Result<Vec<Equation>, String> {
+    let [mul, inv, one] = symbols([(mul, 2), (inv, 1), (one, 0)])?;
+    let mut axioms = vec![associative(&mul)];
+    axioms.extend(identity_laws(&mul, &one));
+    axioms.extend(inverse_laws(&mul, &inv, &one));
+    Ok(axioms)
+}
+
+/// The theory of abelian groups: a commutative group, usually written
+/// additively.
+///
+/// # Arguments
+/// * `add` - The name of the sum
+/// * `neg` - The name of the negation
+/// * `zero` - The name of the zero
+///
+/// # Returns
+/// * `Ok(Vec<Equation>)` - The axioms
+/// * `Err(String)` - If a name is empty or the names are not distinct
+pub fn abelian_group(add: &str, neg: &str, zero: &str) -> Result<Vec<Equation>, String> {
+    let mut axioms = group(add, neg, zero)?;
+    axioms.push(commutative(&OperationSymbol::new(add, 2, false)));
+    Ok(axioms)
+}
+
+/// The theory of lattices: `join` and `meet` are associative and
+/// commutative, and satisfy the absorption laws.
+///
+/// # Arguments
+/// * `join` - The name of the join
+/// * `meet` - The name of the meet
+///
+/// # Returns
+/// * `Ok(Vec<Equation>)` - The axioms
+/// * `Err(String)` - If a name is empty or the names are the same
+pub fn lattice(join: &str, meet: &str) -> Result<Vec<Equation>, String> {
+    let [join, meet] = symbols([(join, 2), (meet, 2)])?;
+    Ok(vec![
+        associative(&join),
+        associative(&meet),
+        commutative(&join),
+        commutative(&meet),
+        eq(bin(&join, x(), bin(&meet, x(), y())), x()),
+        eq(bin(&meet, x(), bin(&join, x(), y())), x()),
+    ])
+}
+
+/// The theory of distributive lattices: the lattice axioms and the
+/// distributivity of `meet` over `join`.
+///
+/// # Arguments
+/// * `join` - The name of the join
+/// * `meet` - The name of the meet
+///
+/// # Returns
+/// * `Ok(Vec<Equation>)` - The axioms
+/// * `Err(String)` - If a name is empty or the names are the same
+pub fn distributive_lattice(join: &str, meet: &str) -> Result<Vec<Equation>, String> {
+    let mut axioms = lattice(join, meet)?;
+    let [join, meet] = symbols([(join, 2), (meet, 2)])?;
+    let [left, _] = distributive_laws(&meet, &join);
+    axioms.push(left);
+    Ok(axioms)
+}
+
+/// The theory of Boolean algebras: a distributive lattice with bounds
+/// `zero` and `one` in which `neg` gives complements.
+///
+/// # Arguments
+/// * `join` - The name of the join
+/// * `meet` - The name of the meet
+/// * `neg` - The name of the complement
+/// * `zero` - The name of the least element
+/// * `one` - The name of the greatest element
+///
+/// # Returns
+/// * `Ok(Vec<Equation>)` - The axioms
+/// * `Err(String)` - If a name is empty or the names are not distinct
+pub fn boolean_algebra(join: &str, meet: &str, neg: &str, zero: &str, one: &str) -> Result<Vec<Equation>, String> {
+    let [join_s, meet_s, neg, zero, one] = symbols([(join, 2), (meet, 2), (neg, 1), (zero, 0), (one, 0)])?;
+    let mut axioms = distributive_lattice(join, meet)?;
+    let not_x = || app(&neg, vec![x()]);
+    axioms.extend([
+        eq(bin(&join_s, x(), con(&zero)), x()),
+        eq(bin(&meet_s, x(), con(&one)), x()),
+        eq(bin(&join_s, x(), not_x()), con(&one)),
+        eq(bin(&meet_s, x(), not_x()), con(&zero)),
+    ]);
+    Ok(axioms)
+}
+
+/// The theory of quasigroups with left division `ldiv` and right division
+/// `rdiv`: `x\(x·y) = y`, `x·(x\y) = y`, `(x·y)/y = x` and `(x/y)·y = x`.
+///
+/// # Arguments
+/// * `mul` - The name of the product
+/// * `ldiv` - The name of the left division `x\y`
+/// * `rdiv` - The name of the right division `x/y`
+///
+/// # Returns
+/// * `Ok(Vec<Equation>)` - The axioms
+/// * `Err(String)` - If a name is empty or the names are not distinct
+pub fn quasigroup(mul: &str, ldiv: &str, rdiv: &str) -> Result<Vec<Equation>, String> {
+    let [mul, ldiv, rdiv] = symbols([(mul, 2), (ldiv, 2), (rdiv, 2)])?;
+    Ok(vec![
+        eq(bin(&ldiv, x(), bin(&mul, x(), y())), y()),
+        eq(bin(&mul, x(), bin(&ldiv, x(), y())), y()),
+        eq(bin(&rdiv, bin(&mul, x(), y()), y()), x()),
+        eq(bin(&mul, bin(&rdiv, x(), y()), y()), x()),
+    ])
+}
+
+/// The theory of semirings: `add` is a commutative monoid with identity
+/// `zero`, `mul` a monoid with identity `one` that distributes over `add`
+/// on both sides, and `zero` is absorbing for `mul`.
+///
+/// # Arguments
+/// * `add` - The name of the sum
+/// * `mul` - The name of the product
+/// * `zero` - The name of the additive identity
+/// * `one` - The name of the multiplicative identity
+///
+/// # Returns
+/// * `Ok(Vec<Equation>)` - The axioms
+/// * `Err(String)` - If a name is empty or the names are not distinct
+pub fn semiring(add: &str, mul: &str, zero: &str, one: &str) -> Result<Vec<Equation>, String> {
+    let [add, mul, zero, one] = symbols([(add, 2), (mul, 2), (zero, 0), (one, 0)])?;
+    let mut axioms = vec![associative(&add), commutative(&add), eq(bin(&add, x(), con(&zero)), x()), associative(&mul)];
+    axioms.extend(identity_laws(&mul, &one));
+    axioms.extend(distributive_laws(&mul, &add));
+    axioms.push(eq(bin(&mul, con(&zero), x()), con(&zero)));
+    axioms.push(eq(bin(&mul, x(), con(&zero)), con(&zero)));
+    Ok(axioms)
+}
+
+/// The theory of rings with identity: an abelian group under `add`, and a
+/// monoid under `mul` that distributes over `add` on both sides.
+///
+/// # Arguments
+/// * `add` - The name of the sum
+/// * `neg` - The name of the negation
+/// * `zero` - The name of the zero
+/// * `mul` - The name of the product
+/// * `one` - The name of the identity
+///
+/// # Returns
+/// * `Ok(Vec<Equation>)` - The axioms
+/// * `Err(String)` - If a name is empty or the names are not distinct
+pub fn ring(add: &str, neg: &str, zero: &str, mul: &str, one: &str) -> Result<Vec<Equation>, String> {
+    let [add_s, _, _, mul_s, _] = symbols([(add, 2), (neg, 1), (zero, 0), (mul, 2), (one, 0)])?;
+    let mut axioms = abelian_group(add, neg, zero)?;
+    axioms.extend(monoid(mul, one)?);
+    axioms.extend(distributive_laws(&mul_s, &add_s));
+    Ok(axioms)
+}
+
+/// A built-in theory, for checking and classifying algebras.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Theory {
+    Semigroup,
+    Monoid,
+    Group,
+    AbelianGroup,
+    Lattice,
+    DistributiveLattice,
+    BooleanAlgebra,
+    Quasigroup,
+    Semiring,
+    Ring,
+}
+
+impl Theory {
+    /// All the theories, weaker ones before the stronger ones they are
+    /// implied by.
+    pub const ALL: [Theory; 10] = [
+        Theory::Semigroup,
+        Theory::Monoid,
+        Theory::Group,
+        Theory::AbelianGroup,
+        Theory::Lattice,
+        Theory::DistributiveLattice,
+        Theory::BooleanAlgebra,
+        Theory::Quasigroup,
+        Theory::Semiring,
+        Theory::Ring,
+    ];
+
+    /// The name of the theory, as accepted by [`Theory::from_name`].
+    pub fn name(&self) -> &'static str {
+        match self {
+            Theory::Semigroup => "semigroup",
+            Theory::Monoid => "monoid",
+            Theory::Group => "group",
+            Theory::AbelianGroup => "abelian_group",
+            Theory::Lattice => "lattice",
+            Theory::DistributiveLattice => "distributive_lattice",
+            Theory::BooleanAlgebra => "boolean_algebra",
+            Theory::Quasigroup => "quasigroup",
+            Theory::Semiring => "semiring",
+            Theory::Ring => "ring",
+        }
+    }
+
+    /// Parse a theory name such as `"group"` or `"distributive_lattice"`.
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        Self::ALL
+            .into_iter()
+            .find(|t| t.name() == name)
+            .ok_or_else(|| format!("Unknown theory: {}", name))
+    }
+
+    /// The operation symbols of the theory with their arities, by their
+    /// default names, in the order the axiom functions take them.
+    pub fn signature(&self) -> &'static [(&'static str, i32)] {
+        match self {
+            Theory::Semigroup => &[("mul", 2)],
+            Theory::Monoid => &[("mul", 2), ("one", 0)],
+            Theory::Group => &[("mul", 2), ("inv", 1), ("one", 0)],
+            Theory::AbelianGroup => &[("add", 2), ("neg", 1), ("zero", 0)],
+            Theory::Lattice | Theory::DistributiveLattice => &[("join", 2), ("meet", 2)],
+            Theory::BooleanAlgebra => &[("join", 2), ("meet", 2), ("neg", 1), ("zero", 0), ("one", 0)],
+            Theory::Quasigroup => &[("mul", 2), ("ldiv", 2), ("rdiv", 2)],
+            Theory::Semiring => &[("add", 2), ("mul", 2), ("zero", 0), ("one", 0)],
+            Theory::Ring => &[("add", 2), ("neg", 1), ("zero", 0), ("mul", 2), ("one", 0)],
+        }
+    }
+
+    /// The axioms of the theory.
+    ///
+    /// # Arguments
+    /// * `names` - The operation names, in the order of [`Theory::signature`]
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Equation>)` - The axioms
+    /// * `Err(String)` - If the number of names is wrong, a name is empty
+    ///   or the names are not distinct
+    pub fn axioms(&self, names: &[&str]) -> Result<Vec<Equation>, String> {
+        if names.len() != self.signature().len() {
+            return Err(format!("The theory {} has {} operations, not {}", self.name(), self.signature().len(), names.len()));
+        }
+        match (self, names) {
+            (Theory::Semigroup, &[mul]) => semigroup(mul),
+            (Theory::Monoid, &[mul, one]) => monoid(mul, one),
+            (Theory::Group, &[mul, inv, one]) => group(mul, inv, one),
+            (Theory::AbelianGroup, &[add, neg, zero]) => abelian_group(add, neg, zero),
+            (Theory::Lattice, &[join, meet]) => lattice(join, meet),
+            (Theory::DistributiveLattice, &[join, meet]) => distributive_lattice(join, meet),
+            (Theory::BooleanAlgebra, &[join, meet, neg, zero, one]) => boolean_algebra(join, meet, neg, zero, one),
+            (Theory::Quasigroup, &[mul, ldiv, rdiv]) => quasigroup(mul, ldiv, rdiv),
+            (Theory::Semiring, &[add, mul, zero, one]) => semiring(add, mul, zero, one),
+            (Theory::Ring, &[add, neg, zero, mul, one]) => ring(add, neg, zero, mul, one),
+            _ => unreachable!("the number of names matches the signature"),
+        }
+    }
+
+    /// The theories this one directly implies, each with the index in this
+    /// signature of every symbol of the weaker one.
+    fn implies(&self) -> &'static [(Theory, &'static [usize])] {
+        match self {
+            Theory::Semigroup | Theory::Quasigroup => &[],
+            Theory::Monoid => &[(Theory::Semigroup, &[0])],
+            Theory::Group => &[(Theory::Monoid, &[0, 2])],
+            Theory::AbelianGroup => &[(Theory::Group, &[0, 1, 2])],
+            Theory::Lattice => &[(Theory::Semigroup, &[0]), (Theory::Semigroup, &[1])],
+            Theory::DistributiveLattice => &[(Theory::Lattice, &[0, 1])],
+            Theory::BooleanAlgebra => &[
+                (Theory::DistributiveLattice, &[0, 1]),
+                (Theory::Semiring, &[0, 1, 3, 4]),
+                (Theory::Semiring, &[1, 0, 4, 3]),
+            ],
+            Theory::Semiring => &[(Theory::Monoid, &[0, 2]), (Theory::Monoid, &[1, 3])],
+            Theory::Ring => &[(Theory::AbelianGroup, &[0, 1, 2]), (Theory::Semiring, &[0, 3, 2, 4])],
+        }
+    }
+
+    /// Whether this theory under `names` implies `weaker` under
+    /// `weaker_names`, following the implications transitively.
+    fn entails(&self, names: &[String], weaker: Theory, weaker_names: &[String]) -> bool {
+        self.implies().iter().any(|&(t, map)| {
+            let mapped: Vec<String> = map.iter().map(|&i| names[i].clone()).collect();
+            (t == weaker && mapped == weaker_names) || t.entails(&mapped, weaker, weaker_names)
+        })
+    }
+}
+
+impl fmt::Display for Theory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// An axiom checked in an algebra.
+#[derive(Debug, Clone)]
+pub struct AxiomCheck {
+    /// The axiom
+    pub equation: Equation,
+    /// The first assignment of its variables, in the order of
+    /// `equation.get_variable_list()`, where it fails; `None` if it holds
+    pub failure: Option<Vec<i32>>,
+}
+
+impl fmt::Display for AxiomCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.failure {
+            None => write!(f, "{} holds", self.equation),
+            Some(args) => {
+                let at: Vec<String> = self.equation.get_variable_list().iter().zip(args)
+                    .map(|(v, a)| format!("{}={}", v, a))
+                    .collect();
+                write!(f, "{} fails at {}", self.equation, at.join(", "))
+            }
+        }
+    }
+}
+
+/// The result of checking the axioms of a theory in an algebra.
+#[derive(Debug, Clone)]
+pub struct TheoryReport {
+    /// The theory
+    pub theory: Theory,
+    /// The operations of the algebra taking the place of the symbols of the
+    /// theory, in the order of [`Theory::signature`]
+    pub operations: Vec<String>,
+    /// Each axiom with its counterexample, if any
+    pub axioms: Vec<AxiomCheck>,
+}
+
+impl TheoryReport {
+    /// Whether every axiom holds.
+    pub fn holds(&self) -> bool {
+        self.axioms.iter().all(|a| a.failure.is_none())
+    }
+
+    /// The number of axioms that fail.
+    pub fn failure_count(&self) -> usize {
+        self.axioms.iter().filter(|a| a.failure.is_some()).count()
+    }
+}
+
+impl fmt::Display for TheoryReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({}): ", self.theory, self.operations.join(", "))?;
+        if self.holds() {
+            return write!(f, "all {} axioms hold", self.axioms.len());
+        }
+        write!(f, "{} of {} axioms fail", self.failure_count(), self.axioms.len())?;
+        for axiom in self.axioms.iter().filter(|a| a.failure.is_some()) {
+            write!(f, "\n  {}", axiom)?;
+        }
+        Ok(())
+    }
+}
+
+/// Check the axioms of a theory in an algebra.
+///
+/// # Arguments
+/// * `alg` - The algebra
+/// * `theory` - The theory
+/// * `operations` - The operations of `alg` taking the place of the
+///   symbols of the theory, in the order of [`Theory::signature`]
+///
+/// # Returns
+/// * `Ok(TheoryReport)` - Which axioms hold, with a counterexample to each
+///   one that fails
+/// * `Err(String)` - If an operation is missing from the algebra or has the
+///   wrong arity
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::ring;
+/// use uacalc::eq::theories::{check_theory, Theory};
+///
+/// let z4 = ring::zn_ring(4).unwrap();
+/// // 0 has no multiplicative inverse
+/// let report = check_theory(&z4, Theory::Group, &["mul", "neg", "one"]).unwrap();
+/// assert!(!report.holds());
+/// assert_eq!(report.failure_count(), 2);
+/// ```
+pub fn check_theory(alg: &BasicAlgebra<i32>, theory: Theory, operations: &[&str]) -> Result<TheoryReport, String> {
+    let axioms = theory.axioms(operations)?;
+    for (&name, &(_, arity)) in operations.iter().zip(theory.signature()) {
+        let op = alg.operations().into_iter()
+            .find(|op| op.symbol().name() == name)
+            .ok_or_else(|| format!("{} has no operation {}", alg.name(), name))?;
+        if op.arity() != arity {
+            return Err(format!("Operation {} has arity {}, not {}", name, op.arity(), arity));
+        }
+    }
+    let axioms = axioms
+        .into_iter()
+        .map(|equation| {
+            let failure = equation.find_failure_in(alg)?;
+            Ok(AxiomCheck { equation, failure })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    Ok(TheoryReport { theory, operations: operations.iter().map(|s| s.to_string()).collect(), axioms })
+}
+
+/// The theories an algebra satisfies, and those it comes closest to.
+#[derive(Debug, Clone)]
+pub struct Classification {
+    /// The strongest theories satisfied: every assignment of operations
+    /// under which a theory holds and which no other satisfied theory
+    /// implies
+    pub satisfied: Vec<TheoryReport>,
+    /// For each theory whose signature fits but which holds under no
+    /// assignment, the assignment with the fewest failing axioms, ordered
+    /// by the number of failures
+    pub nearest: Vec<TheoryReport>,
+}
+
+impl fmt::Display for Classification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let satisfied: Vec<String> = self.satisfied.iter()
+            .map(|r| format!("{}({})", r.theory, r.operations.join(", ")))
+            .collect();
+        write!(f, "satisfies: {}", if satisfied.is_empty() { "none".to_string() } else { satisfied.join(", ") })?;
+        for report in &self.nearest {
+            write!(f, "\nnearest {}", report)?;
+        }
+        Ok(())
+    }
+}
+
+/// The ways to choose distinct operations of an algebra, of the right
+/// arities, for the symbols of a signature.
+fn assignments(alg: &BasicAlgebra<i32>, signature: &[(&str, i32)]) -> Vec<Vec<String>> {
+    fn extend(ops: &[(String, i32)], signature: &[(&str, i32)], current: &mut Vec<String>, out: &mut Vec<Vec<String>>) {
+        let Some(&(_, arity)) = signature.get(current.len()) else {
+            out.push(current.clone());
+            return;
+        };
+        for (name, _) in ops.iter().filter(|(_, a)| *a == arity) {
+            if !current.contains(name) {
+                current.push(name.clone());
+                extend(ops, signature, current, out);
+                current.pop();
+            }
+        }
+    }
+    let ops: Vec<(String, i32)> = alg.operations().iter().map(|op| (op.symbol().name().to_string(), op.arity())).collect();
+    let mut out = Vec::new();
+    extend(&ops, signature, &mut Vec::new(), &mut out);
+    out
+}
+
+/// Classify an algebra by the built-in theories: check every theory under
+/// every assignment of the operations of the algebra to its symbols, and
+/// report the strongest theories satisfied and the nearest misses.
+///
+/// Assignments using the same operations as an earlier satisfying one,
+/// such as a lattice read upside down, are not reported again.
+///
+/// # Arguments
+/// * `alg` - The algebra
+///
+/// # Returns
+/// * `Ok(Classification)` - The satisfied and the nearest theories
+/// * `Err(String)` - If an axiom cannot be evaluated, e.g. the algebra is
+///   too large
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::ring;
+/// use uacalc::eq::theories::{classify, Theory};
+///
+/// let z4 = ring::zn_ring(4).unwrap();
+/// let classification = classify(&z4).unwrap();
+/// assert_eq!(classification.satisfied.len(), 1);
+/// assert_eq!(classification.satisfied[0].theory, Theory::Ring);
+/// ```
+pub fn classify(alg: &BasicAlgebra<i32>) -> Result<Classification, String> {
+    let mut satisfied: Vec<TheoryReport> = Vec::new();
+    let mut nearest = Vec::new();
+    for theory in Theory::ALL {
+        let mut best: Option<TheoryReport> = None;
+        let mut holds = false;
+        for names in assignments(alg, theory.signature()) {
+            let mut used = names.clone();
+            used.sort();
+            let seen = satisfied.iter().any(|r| {
+                let mut ops = r.operations.clone();
+                ops.sort();
+                r.theory == theory && ops == used
+            });
+            if seen {
+                continue;
+            }
+            let refs: Vec<&str> = names.iter().map(|s| s.as_str()).collect();
+            let report = check_theory(alg, theory, &refs)?;
+            if report.holds() {
+                holds = true;
+                satisfied.push(report);
+            } else if best.as_ref().is_none_or(|b| report.failure_count() < b.failure_count()) {
+                best = Some(report);
+            }
+        }
+        if !holds {
+            nearest.extend(best);
+        }
+    }
+    let strongest = satisfied
+        .iter()
+        .filter(|w| !satisfied.iter().any(|t| t.theory.entails(&t.operations, w.theory, &w.operations)))
+        .cloned()
+        .collect();
+    nearest.sort_by_key(|r| r.failure_count());
+    Ok(Classification { satisfied: strongest, nearest })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::op::operations::make_int_operation;
+    use crate::alg::{algebras, ring as rings};
+
+    fn holds(axioms: &[Equation], alg: &BasicAlgebra<i32>) -> bool {
+        axioms.iter().all(|eq| eq.is_satisfied_in(alg).unwrap())
+    }
+
+    #[test]
+    fn test_theories() {
+        let z6 = rings::zn_ring(6).unwrap();
+        assert!(holds(&ring("add", "neg", "zero", "mul", "one").unwrap(), &z6));
+        assert!(holds(&semiring("add", "mul", "zero", "one").unwrap(), &z6));
+        assert!(holds(&abelian_group("add", "neg", "zero").unwrap(), &z6));
+        assert!(holds(&monoid("mul", "one").unwrap(), &z6));
+        assert!(!holds(&group("mul", "neg", "one").unwrap(), &z6));
+        // Matrix multiplication is not commutative
+        let m2 = rings::matrix_ring(2, 2).unwrap();
+        assert!(holds(&ring("add", "neg", "zero", "mul", "one").unwrap(), &m2));
+        assert!(!holds(&abelian_group("mul", "neg", "one").unwrap(), &m2));
+
+        let b2 = algebras::boolean_algebra(2).unwrap();
+        assert!(holds(&boolean_algebra("join", "meet", "neg", "zero", "one").unwrap(), &b2));
+        assert_eq!(boolean_algebra("join", "meet", "neg", "zero", "one").unwrap().len(), 11);
+        let n5 = crate::bench::bench_suite().into_iter().find(|b| b.algebra.name() == "n5").unwrap().algebra;
+        assert!(holds(&lattice("join", "meet").unwrap(), &n5));
+        assert!(!holds(&distributive_lattice("join", "meet").unwrap(), &n5));
+
+        // Z3 under subtraction-based divisions
+        let table = |f: fn(i32, i32) -> i32| (0..9).map(|k| f(k % 3, k / 3)).collect();
+        let ops = vec![
+            make_int_operation(OperationSymbol::new("mul", 2, false), 3, table(|x, y| (x + y) % 3)).unwrap(),
+            make_int_operation(OperationSymbol::new("ldiv", 2, false), 3, table(|x, y| (y + 3 - x) % 3)).unwrap(),
+            make_int_operation(OperationSymbol::new("rdiv", 2, false), 3, table(|x, y| (x + 3 - y) % 3)).unwrap(),
+        ];
+        let q = BasicAlgebra::new("Q".to_string(), (0..3).collect(), ops);
+        assert!(holds(&quasigroup("mul", "ldiv", "rdiv").unwrap(), &q));
+        assert!(!holds(&quasigroup("mul", "rdiv", "ldiv").unwrap(), &q));
+        assert!(holds(&semigroup("mul").unwrap(), &q));
+
+        assert!(lattice("join", "join").is_err());
+        assert!(group("mul", "", "one").is_err());
+    }
+
+    #[test]
+    fn test_check_theory_and_classify() {
+        let z4 = rings::zn_ring(4).unwrap();
+        let report = check_theory(&z4, Theory::Monoid, &["add", "one"]).unwrap();
+        assert_eq!(report.failure_count(), 2);
+        // 1 + 0 = 1, not 0
+        assert_eq!(report.axioms[1].failure, Some(vec![0]));
+        assert!(report.to_string().starts_with("monoid(add, one): 2 of 3 axioms fail"));
+        assert!(check_theory(&z4, Theory::Monoid, &["add", "neg"]).is_err());
+        assert!(check_theory(&z4, Theory::Monoid, &["add", "two"]).is_err());
+        assert!(check_theory(&z4, Theory::Monoid, &["add"]).is_err());
+
+        let classification = classify(&z4).unwrap();
+        let satisfied: Vec<Theory> = classification.satisfied.iter().map(|r| r.theory).collect();
+        assert_eq!(satisfied, vec![Theory::Ring]);
+        assert!(classification.nearest.iter().any(|r| r.theory == Theory::Lattice));
+        assert!(classification.nearest.windows(2).all(|w| w[0].failure_count() <= w[1].failure_count()));
+
+        let b2 = algebras::boolean_algebra(2).unwrap();
+        let classification = classify(&b2).unwrap();
+        assert_eq!(classification.satisfied.len(), 1);
+        assert_eq!(classification.satisfied[0].theory, Theory::BooleanAlgebra);
+        assert_eq!(classification.satisfied[0].operations, ["join", "meet", "neg", "zero", "one"]);
+        assert_eq!(Theory::from_name("abelian_group"), Ok(Theory::AbelianGroup));
+        assert!(Theory::from_name("loop").is_err());
+    }
+}
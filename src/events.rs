@@ -0,0 +1,270 @@
+/*!
+ * Lifecycle events of long computations.
+ *
+ * An [`EventBus`] passes the events of the computations run through it
+ * ([`EventBus::run`]) to any number of listeners: when a computation
+ * starts, each progress message it reports to its [`ProgressToken`], and
+ * when it finishes or is cancelled. Listeners are independent of each
+ * other and of the token's own callback, so a log, a GUI and a notebook
+ * widget can all watch the same computation. A listener returning
+ * `ControlFlow::Break` cancels the computation, as a token callback does.
+ *
+ * The Python bindings run every computation that takes a `progress`
+ * argument through the [`global`] bus.
+ */
+
+use std::fmt;
+use std::ops::ControlFlow;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use crate::progress::{self, ProgressEvent, ProgressToken};
+
+/// An event of a computation run through an [`EventBus`]. The `id` tells
+/// apart the computations of a bus.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComputationEvent {
+    /// The computation started.
+    Started { id: u64, name: String },
+    /// The computation reported progress to its token.
+    Progress { id: u64, progress: ProgressEvent },
+    /// The computation returned, with its error if it failed.
+    Finished { id: u64, elapsed: Duration, error: Option<String> },
+    /// The computation stopped because its token was cancelled.
+    Cancelled { id: u64, elapsed: Duration, reason: String },
+}
+
+impl ComputationEvent {
+    /// The id of the computation.
+    pub fn id(&self) -> u64 {
+        match self {
+            ComputationEvent::Started { id, .. }
+            | ComputationEvent::Progress { id, .. }
+            | ComputationEvent::Finished { id, .. }
+            | ComputationEvent::Cancelled { id, .. } => *id,
+        }
+    }
+
+    /// "started", "progress", "finished" or "cancelled".
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ComputationEvent::Started { .. } => "started",
+            ComputationEvent::Progress { .. } => "progress",
+            ComputationEvent::Finished { .. } => "finished",
+            ComputationEvent::Cancelled { .. } => "cancelled",
+        }
+    }
+}
+
+impl fmt::Display for ComputationEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComputationEvent::Started { id, name } => write!(f, "#{} started {}", id, name),
+            ComputationEvent::Progress { id, progress } => write!(f, "#{} {}: {}", id, progress.phase, progress.message),
+            ComputationEvent::Finished { id, elapsed, error: None } => {
+                write!(f, "#{} finished in {:.3}s", id, elapsed.as_secs_f64())
+            }
+            ComputationEvent::Finished { id, elapsed, error: Some(e) } => {
+                write!(f, "#{} failed after {:.3}s: {}", id, elapsed.as_secs_f64(), e)
+            }
+            ComputationEvent::Cancelled { id, elapsed, reason } => {
+                write!(f, "#{} cancelled after {:.3}s: {}", id, elapsed.as_secs_f64(), reason)
+            }
+        }
+    }
+}
+
+/// A listener on an [`EventBus`]; returning `ControlFlow::Break` cancels
+/// the computation the event is about.
+pub type Listener = Arc<dyn Fn(&ComputationEvent) -> ControlFlow<()> + Send + Sync>;
+
+/// The handle of a listener, for [`EventBus::unsubscribe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ListenerId(pub u64);
+
+/// Passes the events of computations to listeners.
+///
+/// # Examples
+/// ```
+/// use std::ops::ControlFlow;
+/// use std::sync::{Arc, Mutex};
+/// use uacalc::events::EventBus;
+/// use uacalc::progress;
+///
+/// let bus = Arc::new(EventBus::new());
+/// let log = Arc::new(Mutex::new(Vec::new()));
+/// let sink = log.clone();
+/// bus.subscribe(move |event| {
+///     sink.lock().unwrap().push(event.kind());
+///     ControlFlow::Continue(())
+/// });
+/// let answer = bus.run("answer", || {
+///     progress::current().unwrap().report("thinking");
+///     Ok::<_, String>(42)
+/// });
+/// assert_eq!(answer, Ok(42));
+/// assert_eq!(*log.lock().unwrap(), vec!["started", "progress", "finished"]);
+/// ```
+#[derive(Default)]
+pub struct EventBus {
+    listeners: Mutex<Vec<(ListenerId, Listener)>>,
+    next_listener: AtomicU64,
+    next_computation: AtomicU64,
+}
+
+impl EventBus {
+    /// A bus without listeners.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a listener, which receives the events published from now on.
+    pub fn subscribe(&self, listener: impl Fn(&ComputationEvent) -> ControlFlow<()> + Send + Sync + 'static) -> ListenerId {
+        let id = ListenerId(self.next_listener.fetch_add(1, Ordering::SeqCst));
+        self.listeners.lock().unwrap().push((id, Arc::new(listener)));
+        id
+    }
+
+    /// Remove a listener, returning whether it was subscribed.
+    pub fn unsubscribe(&self, id: ListenerId) -> bool {
+        let mut listeners = self.listeners.lock().unwrap();
+        let before = listeners.len();
+        listeners.retain(|(l, _)| *l != id);
+        listeners.len() < before
+    }
+
+    /// The number of listeners.
+    pub fn listener_count(&self) -> usize {
+        self.listeners.lock().unwrap().len()
+    }
+
+    /// Pass `event` to every listener, in the order they subscribed.
+    ///
+    /// # Returns
+    /// `ControlFlow::Break` if some listener asked to cancel
+    pub fn publish(&self, event: &ComputationEvent) -> ControlFlow<()> {
+        // Listeners may subscribe or unsubscribe, so call them unlocked.
+        let listeners: Vec<Listener> = self.listeners.lock().unwrap().iter().map(|(_, l)| l.clone()).collect();
+        let mut flow = ControlFlow::Continue(());
+        for listener in listeners {
+            if listener(event).is_break() {
+                flow = ControlFlow::Break(());
+            }
+        }
+        flow
+    }
+
+    /// Run the computation `f`, publishing its events under a new id.
+    ///
+    /// `f` runs with the current thread's [`ProgressToken`], or a new one
+    /// installed for the call if there is none; what is reported to the
+    /// token is published as [`ComputationEvent::Progress`], and a listener
+    /// cancelling cancels the token. A computation that fails after its
+    /// token was cancelled is reported as cancelled, with its error as the
+    /// reason.
+    pub fn run<R, E: fmt::Display>(self: &Arc<Self>, name: &str, f: impl FnOnce() -> Result<R, E>) -> Result<R, E> {
+        let id = self.next_computation.fetch_add(1, Ordering::SeqCst);
+        let (token, _installed) = match progress::current() {
+            Some(token) => (token, None),
+            None => {
+                let token = ProgressToken::new();
+                let installed = token.install();
+                (token, Some(installed))
+            }
+        };
+        let outer = token.attach_events(Some((self.clone(), id)));
+        if self.publish(&ComputationEvent::Started { id, name: name.to_string() }).is_break() {
+            token.cancel();
+        }
+        let start = Instant::now();
+        let result = f();
+        token.attach_events(outer);
+        let elapsed = start.elapsed();
+        let event = match &result {
+            Err(e) if token.is_cancelled() => ComputationEvent::Cancelled { id, elapsed, reason: e.to_string() },
+            Err(e) => ComputationEvent::Finished { id, elapsed, error: Some(e.to_string()) },
+            Ok(_) => ComputationEvent::Finished { id, elapsed, error: None },
+        };
+        // The computation is over, so there is nothing left to cancel.
+        let _ = self.publish(&event);
+        result
+    }
+}
+
+impl fmt::Debug for EventBus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventBus").field("listeners", &self.listener_count()).finish()
+    }
+}
+
+/// The process-wide bus.
+pub fn global() -> Arc<EventBus> {
+    static GLOBAL: OnceLock<Arc<EventBus>> = OnceLock::new();
+    GLOBAL.get_or_init(|| Arc::new(EventBus::new())).clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_listeners_and_cancellation() {
+        let bus = Arc::new(EventBus::new());
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let logger = bus.subscribe(move |e| {
+            sink.lock().unwrap().push(e.clone());
+            ControlFlow::Continue(())
+        });
+        let stopper = bus.subscribe(|e| match e {
+            ComputationEvent::Progress { progress, .. } if progress.message == "stop" => ControlFlow::Break(()),
+            _ => ControlFlow::Continue(()),
+        });
+        assert_eq!(bus.listener_count(), 2);
+
+        let result: Result<(), String> = bus.run("loop", || {
+            let token = progress::current().unwrap();
+            let _phase = token.phase("pass");
+            token.report("stop");
+            token.check()
+        });
+        assert_eq!(result, Err(progress::CANCELLED.to_string()));
+        assert!(progress::current().is_none());
+        {
+            let events = events.lock().unwrap();
+            assert_eq!(events[0], ComputationEvent::Started { id: 0, name: "loop".to_string() });
+            assert!(matches!(&events[2], ComputationEvent::Progress { progress, .. } if progress.phase == "pass"));
+            assert_eq!(events.last().unwrap().kind(), "cancelled");
+        }
+
+        assert!(bus.unsubscribe(stopper));
+        assert!(!bus.unsubscribe(stopper));
+        let result: Result<i32, String> = bus.run("fail", || Err("bad".to_string()));
+        assert!(result.is_err());
+        let last = events.lock().unwrap().last().cloned().unwrap();
+        assert_eq!(last.id(), 1);
+        assert!(matches!(last, ComputationEvent::Finished { error: Some(ref e), .. } if e == "bad"));
+        bus.unsubscribe(logger);
+        assert_eq!(bus.listener_count(), 0);
+    }
+
+    #[test]
+    fn test_run_keeps_the_current_token() {
+        let bus = Arc::new(EventBus::new());
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        let token = ProgressToken::with_callback(move |e| {
+            sink.lock().unwrap().push(e.message.clone());
+            ControlFlow::Continue(())
+        });
+        bus.subscribe(|_| ControlFlow::Break(()));
+        let _installed = token.install();
+        let result: Result<(), String> = bus.run("nested", || {
+            progress::current().unwrap().report("hello");
+            progress::check_cancelled()
+        });
+        assert!(result.is_err());
+        assert!(token.is_cancelled());
+        assert_eq!(*seen.lock().unwrap(), vec!["hello"]);
+    }
+}
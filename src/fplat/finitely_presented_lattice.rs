@@ -0,0 +1,497 @@
+//! Finitely presented lattices.
+//!
+//! A finitely presented lattice is given by generators and relations
+//! between lattice terms. The relations are turned into a finite partial
+//! lattice P: one element for each generator and each subterm of a
+//! relation, with the defined joins and meets of the subterms. The order of
+//! P is closed up until every defined join and meet is a true join or meet
+//! in P, and the word problem is then decided by Dean's theorem (Freese,
+//! Ježek and Nation, "Free Lattices", Theorem 2.3): `s ≤ t` iff
+//!
+//! * `s = s_1 ∨ ... ∨ s_n` and every `s_i ≤ t`, or
+//! * `t = t_1 ∧ ... ∧ t_n` and `s ≤` every `t_j`, or
+//! * `s = s_1 ∧ ... ∧ s_n` and some `s_i ≤ t`, or
+//! * `t = t_1 ∨ ... ∨ t_n` and `s ≤` some `t_j`, or
+//! * some element of P lies between `s` and `t`.
+
+use std::collections::HashMap;
+
+use crate::eq::{Equation, Presentation};
+use crate::lat::free_lattice::LatticeTerm;
+use crate::terms::{string_to_term, Term};
+
+/// A lattice given by generators and relations.
+///
+/// # Examples
+/// ```
+/// use uacalc::fplat::FinitelyPresentedLattice;
+/// use uacalc::terms::string_to_term;
+///
+/// let lat = FinitelyPresentedLattice::from_relations("L", &["x", "y", "z"], &["x <= y"]).unwrap();
+/// let s = string_to_term("join(x,y)").unwrap();
+/// let t = string_to_term("meet(y,join(x,z))").unwrap();
+/// assert!(lat.leq(t.as_ref(), s.as_ref()).unwrap());
+/// assert!(!lat.leq(s.as_ref(), t.as_ref()).unwrap());
+/// ```
+#[derive(Debug, Clone)]
+pub struct FinitelyPresentedLattice {
+    name: String,
+    generators: Vec<String>,
+    relations: Vec<Equation>,
+    /// The element of P for each generator.
+    generator_index: HashMap<String, usize>,
+    /// Defined joins `(k, S)`: element k of P is the join of the elements S.
+    joins: Vec<(usize, Vec<usize>)>,
+    /// Defined meets `(k, S)`: element k of P is the meet of the elements S.
+    meets: Vec<(usize, Vec<usize>)>,
+    /// The order of P.
+    leq: Vec<Vec<bool>>,
+}
+
+impl FinitelyPresentedLattice {
+    /// Create the lattice of a presentation whose relations are equations
+    /// between lattice terms in the presentation's variables.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the lattice
+    /// * `presentation` - The generators and relations
+    ///
+    /// # Returns
+    /// * `Ok(FinitelyPresentedLattice)` - The lattice
+    /// * `Err(String)` - If a relation is not an equation between lattice
+    ///   terms in the generators, or the computation was cancelled
+    pub fn new(name: &str, presentation: &Presentation) -> Result<Self, String> {
+        let generators = presentation.get_variables().clone();
+        let mut lat = FinitelyPresentedLattice {
+            name: name.to_string(),
+            generators: generators.clone(),
+            relations: presentation.get_relations().clone(),
+            generator_index: HashMap::new(),
+            joins: Vec::new(),
+            meets: Vec::new(),
+            leq: Vec::new(),
+        };
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for gen in &generators {
+            if !index.contains_key(gen) {
+                let k = lat.new_element();
+                index.insert(gen.clone(), k);
+            }
+        }
+        lat.generator_index = index.clone();
+        let mut related = Vec::new();
+        for eq in presentation.get_relations() {
+            let a = lat.add_term(&LatticeTerm::from_term(eq.left_side())?, &mut index)?;
+            let b = lat.add_term(&LatticeTerm::from_term(eq.right_side())?, &mut index)?;
+            related.push((a, b));
+        }
+        for (a, b) in related {
+            lat.leq[a][b] = true;
+            lat.leq[b][a] = true;
+        }
+        for (k, args) in &lat.joins {
+            for &a in args {
+                lat.leq[a][*k] = true;
+            }
+        }
+        for (k, args) in &lat.meets {
+            for &a in args {
+                lat.leq[*k][a] = true;
+            }
+        }
+        lat.close_order()?;
+        Ok(lat)
+    }
+
+    /// Create a lattice from generator names and relations written as
+    /// strings `s = t` or `s <= t`, with `join` and `meet` for the lattice
+    /// operations. `s <= t` is the relation `s = meet(s,t)`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the lattice
+    /// * `generators` - The generator names
+    /// * `relations` - The relations
+    ///
+    /// # Returns
+    /// * `Ok(FinitelyPresentedLattice)` - The lattice
+    /// * `Err(String)` - If a relation cannot be parsed or uses other names
+    pub fn from_relations(name: &str, generators: &[&str], relations: &[&str]) -> Result<Self, String> {
+        let mut eqs = Vec::new();
+        for rel in relations {
+            let eq = if let Some((s, t)) = rel.split_once("<=") {
+                let s = string_to_term(s)?;
+                let meet = format!("meet({},{})", s, t.trim());
+                Equation::new(s, string_to_term(&meet)?)
+            } else if let Some((s, t)) = rel.split_once('=') {
+                Equation::new(string_to_term(s)?, string_to_term(t)?)
+            } else {
+                return Err(format!("A relation must have the form s = t or s <= t: {}", rel));
+            };
+            eqs.push(eq);
+        }
+        let generators = generators.iter().map(|g| g.to_string()).collect();
+        Self::new(name, &Presentation::new(generators, eqs))
+    }
+
+    /// Get the name of the lattice.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the generator names.
+    pub fn generators(&self) -> &[String] {
+        &self.generators
+    }
+
+    /// Get the relations.
+    pub fn relations(&self) -> &[Equation] {
+        &self.relations
+    }
+
+    /// Decide whether `s ≤ t` in this lattice (the word problem).
+    ///
+    /// # Arguments
+    /// * `s` - A lattice term in the generators
+    /// * `t` - A lattice term in the generators
+    ///
+    /// # Returns
+    /// * `Ok(bool)` - Whether `s ≤ t`
+    /// * `Err(String)` - If a term is not a lattice term in the generators
+    pub fn leq(&self, s: &dyn Term, t: &dyn Term) -> Result<bool, String> {
+        let s = self.check_term(s)?;
+        let t = self.check_term(t)?;
+        Ok(self.term_leq(&s, &t))
+    }
+
+    /// Decide whether `s = t` in this lattice.
+    ///
+    /// # Arguments
+    /// * `s` - A lattice term in the generators
+    /// * `t` - A lattice term in the generators
+    ///
+    /// # Returns
+    /// * `Ok(bool)` - Whether `s = t`
+    /// * `Err(String)` - If a term is not a lattice term in the generators
+    pub fn equal(&self, s: &dyn Term, t: &dyn Term) -> Result<bool, String> {
+        let s = self.check_term(s)?;
+        let t = self.check_term(t)?;
+        Ok(self.term_leq(&s, &t) && self.term_leq(&t, &s))
+    }
+
+    /// Compute the elements of the lattice if it has at most `max_size`
+    /// elements.
+    ///
+    /// Each element is given by a term in the generators: the first one
+    /// found, as joins and meets of pairs of earlier elements, in its
+    /// canonical form as a free lattice term. The computation checks the
+    /// progress token installed on the current thread for cancellation.
+    ///
+    /// # Arguments
+    /// * `max_size` - The most elements to compute
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Box<dyn Term>>)` - The elements
+    /// * `Err(String)` - If the lattice has more than `max_size` elements
+    ///   or the computation was cancelled
+    pub fn universe(&self, max_size: usize) -> Result<Vec<Box<dyn Term>>, String> {
+        Ok(self.elements(max_size)?.iter().map(|e| e.canonical_form().to_term()).collect())
+    }
+
+    /// Compute the upper covers of the elements of the lattice if it has
+    /// at most `max_size` elements.
+    ///
+    /// # Arguments
+    /// * `max_size` - The most elements to compute
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Vec<usize>>)` - For each element of [`Self::universe`],
+    ///   the indices of its upper covers
+    /// * `Err(String)` - If the lattice has more than `max_size` elements
+    ///   or the computation was cancelled
+    pub fn upper_covers(&self, max_size: usize) -> Result<Vec<Vec<usize>>, String> {
+        let elems = self.elements(max_size)?;
+        let n = elems.len();
+        let mut less = vec![vec![false; n]; n];
+        for i in 0..n {
+            crate::progress::check_cancelled()?;
+            for j in 0..n {
+                less[i][j] = i != j && self.term_leq(&elems[i], &elems[j]);
+            }
+        }
+        Ok((0..n)
+            .map(|i| (0..n).filter(|&j| less[i][j] && !(0..n).any(|k| less[i][k] && less[k][j])).collect())
+            .collect())
+    }
+
+    /// The elements of the lattice as terms, one for each element.
+    fn elements(&self, max_size: usize) -> Result<Vec<LatticeTerm>, String> {
+        let mut elems: Vec<LatticeTerm> = Vec::new();
+        let add = |elems: &mut Vec<LatticeTerm>, t: LatticeTerm| -> Result<(), String> {
+            if elems.iter().all(|e| !(self.term_leq(e, &t) && self.term_leq(&t, e))) {
+                if elems.len() == max_size {
+                    return Err(format!("The lattice {} has more than {} elements", self.name, max_size));
+                }
+                elems.push(t);
+            }
+            Ok(())
+        };
+        for gen in &self.generators {
+            add(&mut elems, LatticeTerm::Var(gen.clone()))?;
+        }
+        let mut done = 0;
+        while done < elems.len() {
+            let level_end = elems.len();
+            for i in 0..level_end {
+                crate::progress::check_cancelled()?;
+                for j in done.max(i + 1)..level_end {
+                    for is_join in [true, false] {
+                        let t = LatticeTerm::op(is_join, vec![elems[i].clone(), elems[j].clone()]);
+                        add(&mut elems, t)?;
+                    }
+                }
+            }
+            done = level_end;
+        }
+        Ok(elems)
+    }
+
+    fn check_term(&self, t: &dyn Term) -> Result<LatticeTerm, String> {
+        let term = LatticeTerm::from_term(t)?;
+        for var in t.get_variable_list() {
+            if !self.generator_index.contains_key(&var) {
+                return Err(format!("{} is not a generator of {}", var, self.name));
+            }
+        }
+        Ok(term)
+    }
+
+    fn new_element(&mut self) -> usize {
+        let k = self.leq.len();
+        for row in self.leq.iter_mut() {
+            row.push(false);
+        }
+        let mut row = vec![false; k + 1];
+        row[k] = true;
+        self.leq.push(row);
+        k
+    }
+
+    /// Add the element of P for a term and its subterms.
+    fn add_term(&mut self, t: &LatticeTerm, index: &mut HashMap<String, usize>) -> Result<usize, String> {
+        let key = t.to_string();
+        if let Some(&k) = index.get(&key) {
+            return Ok(k);
+        }
+        let (is_join, children) = match t {
+            LatticeTerm::Var(name) => return Err(format!("{} is not a generator of {}", name, self.name)),
+            LatticeTerm::Join(children) => (true, children),
+            LatticeTerm::Meet(children) => (false, children),
+        };
+        let args = children.iter()
+            .map(|c| self.add_term(c, index))
+            .collect::<Result<Vec<_>, _>>()?;
+        let k = self.new_element();
+        index.insert(key, k);
+        if is_join { self.joins.push((k, args)) } else { self.meets.push((k, args)) }
+        Ok(k)
+    }
+
+    /// Close the order of P until every defined join and meet is a join
+    /// and meet in P.
+    fn close_order(&mut self) -> Result<(), String> {
+        let n = self.leq.len();
+        loop {
+            crate::progress::check_cancelled()?;
+            for k in 0..n {
+                for i in 0..n {
+                    if self.leq[i][k] {
+                        for j in 0..n {
+                            if self.leq[k][j] {
+                                self.leq[i][j] = true;
+                            }
+                        }
+                    }
+                }
+            }
+            let ideals: Vec<Vec<bool>> = (0..n).map(|q| self.ideal_of(self.leq.iter().map(|row| row[q]).collect())).collect();
+            let filters: Vec<Vec<bool>> = (0..n).map(|p| self.filter_of(self.leq[p].clone())).collect();
+            let mut changed = false;
+            for (row, filter) in self.leq.iter_mut().zip(&filters) {
+                for (below, ideal) in row.iter_mut().zip(&ideals) {
+                    if !*below && filter.iter().zip(ideal).any(|(&f, &i)| f && i) {
+                        *below = true;
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                return Ok(());
+            }
+        }
+    }
+
+    /// The smallest ideal of P containing `set` and closed under the
+    /// defined joins.
+    fn ideal_of(&self, set: Vec<bool>) -> Vec<bool> {
+        self.close(set, true)
+    }
+
+    /// The smallest filter of P containing `set` and closed under the
+    /// defined meets.
+    fn filter_of(&self, set: Vec<bool>) -> Vec<bool> {
+        self.close(set, false)
+    }
+
+    fn close(&self, mut set: Vec<bool>, down: bool) -> Vec<bool> {
+        let n = set.len();
+        let ops = if down { &self.joins } else { &self.meets };
+        loop {
+            let members: Vec<usize> = (0..n).filter(|&i| set[i]).collect();
+            for i in members {
+                for (j, in_set) in set.iter_mut().enumerate() {
+                    if (down && self.leq[j][i]) || (!down && self.leq[i][j]) {
+                        *in_set = true;
+                    }
+                }
+            }
+            let mut changed = false;
+            for (k, args) in ops {
+                if !set[*k] && args.iter().all(|&a| set[a]) {
+                    set[*k] = true;
+                    changed = true;
+                }
+            }
+            if !changed {
+                return set;
+            }
+        }
+    }
+
+    /// The elements of P below `t`.
+    fn term_ideal(&self, t: &LatticeTerm) -> Vec<bool> {
+        match t {
+            LatticeTerm::Var(x) => {
+                let k = self.generator_index[x];
+                self.ideal_of(self.leq.iter().map(|row| row[k]).collect())
+            }
+            LatticeTerm::Join(children) => {
+                let mut set = vec![false; self.leq.len()];
+                for c in children {
+                    for (s, c) in set.iter_mut().zip(self.term_ideal(c)) {
+                        *s |= c;
+                    }
+                }
+                self.ideal_of(set)
+            }
+            LatticeTerm::Meet(children) => {
+                let mut set = vec![true; self.leq.len()];
+                for c in children {
+                    for (s, c) in set.iter_mut().zip(self.term_ideal(c)) {
+                        *s &= c;
+                    }
+                }
+                set
+            }
+        }
+    }
+
+    /// The elements of P above `t`.
+    fn term_filter(&self, t: &LatticeTerm) -> Vec<bool> {
+        match t {
+            LatticeTerm::Var(x) => self.filter_of(self.leq[self.generator_index[x]].clone()),
+            LatticeTerm::Meet(children) => {
+                let mut set = vec![false; self.leq.len()];
+                for c in children {
+                    for (s, c) in set.iter_mut().zip(self.term_filter(c)) {
+                        *s |= c;
+                    }
+                }
+                self.filter_of(set)
+            }
+            LatticeTerm::Join(children) => {
+                let mut set = vec![true; self.leq.len()];
+                for c in children {
+                    for (s, c) in set.iter_mut().zip(self.term_filter(c)) {
+                        *s &= c;
+                    }
+                }
+                set
+            }
+        }
+    }
+
+    fn term_leq(&self, s: &LatticeTerm, t: &LatticeTerm) -> bool {
+        if let LatticeTerm::Join(ss) = s {
+            return ss.iter().all(|si| self.term_leq(si, t));
+        }
+        if let LatticeTerm::Meet(ts) = t {
+            return ts.iter().all(|tj| self.term_leq(s, tj));
+        }
+        if let LatticeTerm::Meet(ss) = s {
+            if ss.iter().any(|si| self.term_leq(si, t)) {
+                return true;
+            }
+        }
+        if let LatticeTerm::Join(ts) = t {
+            if ts.iter().any(|tj| self.term_leq(s, tj)) {
+                return true;
+            }
+        }
+        let filter = self.term_filter(s);
+        self.term_ideal(t).iter().zip(filter).any(|(&i, f)| i && f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leq(lat: &FinitelyPresentedLattice, s: &str, t: &str) -> bool {
+        lat.leq(string_to_term(s).unwrap().as_ref(), string_to_term(t).unwrap().as_ref()).unwrap()
+    }
+
+    #[test]
+    fn test_free_lattice_without_relations() {
+        let lat = FinitelyPresentedLattice::from_relations("FL2", &["x", "y"], &[]).unwrap();
+        assert!(leq(&lat, "meet(x,y)", "join(x,y)"));
+        assert!(!leq(&lat, "x", "y"));
+        assert_eq!(lat.universe(10).unwrap().len(), 4);
+        let lat = FinitelyPresentedLattice::from_relations("FL3", &["x", "y", "z"], &[]).unwrap();
+        assert!(!leq(&lat, "meet(x,join(y,z))", "join(meet(x,y),meet(x,z))"));
+        assert!(lat.universe(100).is_err());
+    }
+
+    #[test]
+    fn test_relations() {
+        // A chain of three generators collapses to a 3 element chain
+        let lat = FinitelyPresentedLattice::from_relations("C3", &["x", "y", "z"], &["x <= y", "y <= z"]).unwrap();
+        assert!(leq(&lat, "z", "join(x,y,z)") && leq(&lat, "join(x,z)", "z"));
+        assert_eq!(lat.universe(10).unwrap().len(), 3);
+        assert_eq!(lat.upper_covers(10).unwrap(), vec![vec![1], vec![2], vec![]]);
+
+        // Requiring the meet of x and y to be above z and the join below z
+        // identifies everything with z
+        let lat = FinitelyPresentedLattice::from_relations("T", &["x", "y", "z"], &["join(x,y) <= z", "z <= meet(x,y)"]).unwrap();
+        assert!(leq(&lat, "x", "y") && leq(&lat, "y", "x"));
+        assert_eq!(lat.universe(10).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_distributive_relations() {
+        // The relations make {x, y, z} generate a lattice in which x∧(y∨z)
+        // is the join of x∧y and x∧z.
+        let lat = FinitelyPresentedLattice::from_relations(
+            "D", &["x", "y", "z"], &["meet(x,join(y,z)) = join(meet(x,y),meet(x,z))"],
+        ).unwrap();
+        assert!(leq(&lat, "meet(x,join(y,z))", "join(meet(x,y),meet(x,z))"));
+        assert!(!leq(&lat, "meet(y,join(x,z))", "join(meet(x,y),meet(y,z))"));
+    }
+
+    #[test]
+    fn test_unknown_generator() {
+        assert!(FinitelyPresentedLattice::from_relations("L", &["x"], &["x <= y"]).is_err());
+        let lat = FinitelyPresentedLattice::from_relations("L", &["x"], &[]).unwrap();
+        let t = string_to_term("join(x,y)").unwrap();
+        assert!(lat.leq(t.as_ref(), t.as_ref()).is_err());
+    }
+}
@@ -1,3 +1,7 @@
+pub mod finitely_presented_lattice;
+
+pub use finitely_presented_lattice::FinitelyPresentedLattice;
+
 use crate::lat::Order;
 use crate::terms::VariableImp;
 use std::sync::Arc;
@@ -0,0 +1,313 @@
+//! Group theory for algebras that are groups.
+//!
+//! These functions take any algebra with a binary group operation, such as
+//! `resources/algebras/sym3.ua`, and work with its multiplication table
+//! directly rather than through the general congruence machinery. Subgroups are given as
+//! sorted lists of elements.
+
+use std::collections::HashSet;
+
+use crate::alg::conlat::CongruenceLattice;
+use crate::alg::op::{operations, Operation, OperationSymbol};
+use crate::alg::{Algebra, BasicAlgebra, SmallAlgebra};
+use crate::terms::compiled_term::operation_table;
+
+/// The multiplication table of a finite group, with its identity and
+/// inverses.
+pub(crate) struct GroupTable {
+    pub(crate) name: String,
+    pub(crate) n: usize,
+    pub(crate) table: Vec<usize>,
+    pub(crate) identity: usize,
+    pub(crate) inverse: Vec<usize>,
+}
+
+impl GroupTable {
+    /// Read a binary operation as a group operation.
+    pub(crate) fn from_operation(op: &dyn Operation) -> Result<Self, String> {
+        let name = op.symbol().name().to_string();
+        if op.arity() != 2 {
+            return Err(format!("Operation {} is not binary", name));
+        }
+        let n = op.get_set_size() as usize;
+        let table: Vec<usize> = operation_table(op)?.iter().map(|&v| v as usize).collect();
+        let f = |x: usize, y: usize| table[x + n * y];
+        let identity = (0..n)
+            .find(|&e| (0..n).all(|x| f(e, x) == x && f(x, e) == x))
+            .ok_or_else(|| format!("Operation {} has no identity", name))?;
+        let mut inverse = Vec::with_capacity(n);
+        for x in 0..n {
+            let inv = (0..n)
+                .find(|&y| f(x, y) == identity && f(y, x) == identity)
+                .ok_or_else(|| format!("Element {} has no inverse under {}", x, name))?;
+            inverse.push(inv);
+        }
+        for x in 0..n {
+            for y in 0..n {
+                if let Some(z) = (0..n).find(|&z| f(f(x, y), z) != f(x, f(y, z))) {
+                    return Err(format!("Operation {} is not associative at ({}, {}, {})", name, x, y, z));
+                }
+            }
+        }
+        Ok(GroupTable { name, n, table, identity, inverse })
+    }
+
+    /// The group operation of an algebra: its first binary operation that
+    /// is a group operation.
+    fn from_algebra(alg: &BasicAlgebra<i32>) -> Result<Self, String> {
+        alg.operations()
+            .iter()
+            .filter(|op| op.arity() == 2)
+            .find_map(|op| Self::from_operation(op.as_ref()).ok())
+            .ok_or_else(|| format!("{} has no group operation", alg.name()))
+    }
+
+    pub(crate) fn mul(&self, x: usize, y: usize) -> usize {
+        self.table[x + self.n * y]
+    }
+
+    /// The commutator `[x, y] = x⁻¹y⁻¹xy`.
+    fn commutator(&self, x: usize, y: usize) -> usize {
+        let xy = self.mul(x, y);
+        self.mul(self.mul(self.inverse[x], self.inverse[y]), xy)
+    }
+
+    /// The subgroup generated by a set of elements, sorted. In a finite
+    /// group, closing under the product is enough.
+    fn generate(&self, gens: impl IntoIterator<Item = usize>) -> Vec<usize> {
+        let mut seen = vec![false; self.n];
+        seen[self.identity] = true;
+        let mut elems = vec![self.identity];
+        let gens: Vec<usize> = gens.into_iter().filter(|&g| !std::mem::replace(&mut seen[g], true)).collect();
+        elems.extend(&gens);
+        let mut k = 0;
+        while k < elems.len() {
+            let x = elems[k];
+            for &g in &gens {
+                let xg = self.mul(x, g);
+                if !seen[xg] {
+                    seen[xg] = true;
+                    elems.push(xg);
+                }
+            }
+            k += 1;
+        }
+        elems.sort_unstable();
+        elems
+    }
+
+    /// The subgroup `[H, K]` generated by the commutators of `H` and `K`.
+    fn commutator_of(&self, h: &[usize], k: &[usize]) -> Vec<usize> {
+        let gens: HashSet<usize> = h.iter().flat_map(|&x| k.iter().map(move |&y| (x, y)))
+            .map(|(x, y)| self.commutator(x, y))
+            .collect();
+        self.generate(gens)
+    }
+
+    pub(crate) fn center(&self) -> Vec<usize> {
+        (0..self.n).filter(|&z| (0..self.n).all(|x| self.mul(z, x) == self.mul(x, z))).collect()
+    }
+
+    /// The upper central series `1 = Z₀ ≤ Z₁ ≤ …`, where `Zᵢ₊₁` is the set
+    /// of elements whose commutators with all of G lie in `Zᵢ`, up to the
+    /// hypercenter.
+    fn upper_central_series(&self) -> Vec<Vec<usize>> {
+        let mut series = vec![vec![self.identity]];
+        loop {
+            let last = series.last().expect("the series is not empty");
+            let mut inside = vec![false; self.n];
+            for &z in last {
+                inside[z] = true;
+            }
+            let next: Vec<usize> = (0..self.n)
+                .filter(|&g| (0..self.n).all(|x| inside[self.commutator(g, x)]))
+                .collect();
+            if next.len() == last.len() {
+                return series;
+            }
+            series.push(next);
+        }
+    }
+
+    /// The derived series `G ≥ G' ≥ G'' ≥ …`, down to the perfect core.
+    fn derived_series(&self) -> Vec<Vec<usize>> {
+        let mut series = vec![(0..self.n).collect::<Vec<usize>>()];
+        loop {
+            let last = series.last().expect("the series is not empty");
+            let next = self.commutator_of(last, last);
+            if next.len() == last.len() {
+                return series;
+            }
+            series.push(next);
+        }
+    }
+
+    /// The algebra with just the group operation.
+    fn reduct(&self) -> Result<BasicAlgebra<i32>, String> {
+        let n = self.n as i32;
+        let table = self.table.iter().map(|&v| v as i32).collect();
+        let op = operations::make_int_operation(OperationSymbol::new(&self.name, 2, false), n, table)?;
+        Ok(BasicAlgebra::new(format!("group({})", self.name), (0..n).collect(), vec![op]))
+    }
+}
+
+/// Check whether an algebra has a binary operation that makes it a group.
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use uacalc::group;
+/// use uacalc::io::AlgebraReader;
+///
+/// let read = |path: &str| AlgebraReader::new_from_file(Path::new(path)).unwrap().read_algebra_file().unwrap();
+/// assert!(group::is_group(&read("resources/algebras/cyclic3.ua")));
+/// // Multiplication on Z3 has no inverse of 0
+/// assert!(!group::is_group(&read("resources/algebras/z3.ua")));
+/// ```
+pub fn is_group(alg: &BasicAlgebra<i32>) -> bool {
+    GroupTable::from_algebra(alg).is_ok()
+}
+
+/// The center of a group: the elements commuting with every element.
+///
+/// # Arguments
+/// * `alg` - An algebra with a group operation; its first binary operation
+///   satisfying the group axioms is used
+///
+/// # Returns
+/// * `Ok(Vec<usize>)` - The elements of the center, sorted
+/// * `Err(String)` - If the algebra has no group operation
+pub fn center(alg: &BasicAlgebra<i32>) -> Result<Vec<usize>, String> {
+    Ok(GroupTable::from_algebra(alg)?.center())
+}
+
+/// The commutator subgroup G', generated by the commutators `x⁻¹y⁻¹xy`.
+///
+/// # Arguments
+/// * `alg` - An algebra with a group operation
+///
+/// # Returns
+/// * `Ok(Vec<usize>)` - The elements of G', sorted
+/// * `Err(String)` - If the algebra has no group operation
+pub fn commutator_subgroup(alg: &BasicAlgebra<i32>) -> Result<Vec<usize>, String> {
+    let g = GroupTable::from_algebra(alg)?;
+    let all: Vec<usize> = (0..g.n).collect();
+    Ok(g.commutator_of(&all, &all))
+}
+
+/// The normal subgroups of a group, read off from the congruence lattice of
+/// the group operation: each congruence is determined by the block of the
+/// identity, which is a normal subgroup, and every normal subgroup arises
+/// this way.
+///
+/// Only the group operation is used, so other operations of the algebra do
+/// not restrict the subgroups.
+///
+/// # Arguments
+/// * `alg` - An algebra with a group operation
+///
+/// # Returns
+/// * `Ok(Vec<Vec<usize>>)` - The normal subgroups, each sorted, ordered by
+///   size and then by their elements
+/// * `Err(String)` - If the algebra has no group operation
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use uacalc::group::normal_subgroups;
+/// use uacalc::io::AlgebraReader;
+///
+/// let s3 = AlgebraReader::new_from_file(Path::new("resources/algebras/sym3.ua")).unwrap().read_algebra_file().unwrap();
+/// // 1, A3 and S3
+/// let sizes: Vec<usize> = normal_subgroups(&s3).unwrap().iter().map(|h| h.len()).collect();
+/// assert_eq!(sizes, vec![1, 3, 6]);
+/// ```
+pub fn normal_subgroups(alg: &BasicAlgebra<i32>) -> Result<Vec<Vec<usize>>, String> {
+    let g = GroupTable::from_algebra(alg)?;
+    let mut con = CongruenceLattice::new(Box::new(g.reduct()?) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+    let mut subgroups: Vec<Vec<usize>> = con
+        .try_universe()?
+        .iter()
+        .map(|theta| (0..g.n).filter(|&x| theta.is_related(g.identity, x)).collect())
+        .collect();
+    subgroups.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+    Ok(subgroups)
+}
+
+/// The upper central series `1 = Z₀ < Z₁ < …` of a group, ending at the
+/// hypercenter; `Z₁` is the center.
+///
+/// # Arguments
+/// * `alg` - An algebra with a group operation
+///
+/// # Returns
+/// * `Ok(Vec<Vec<usize>>)` - The terms of the series, each sorted
+/// * `Err(String)` - If the algebra has no group operation
+pub fn upper_central_series(alg: &BasicAlgebra<i32>) -> Result<Vec<Vec<usize>>, String> {
+    Ok(GroupTable::from_algebra(alg)?.upper_central_series())
+}
+
+/// The derived series `G > G' > G'' > …` of a group, ending at its perfect
+/// core.
+///
+/// # Arguments
+/// * `alg` - An algebra with a group operation
+///
+/// # Returns
+/// * `Ok(Vec<Vec<usize>>)` - The terms of the series, each sorted
+/// * `Err(String)` - If the algebra has no group operation
+pub fn derived_series(alg: &BasicAlgebra<i32>) -> Result<Vec<Vec<usize>>, String> {
+    Ok(GroupTable::from_algebra(alg)?.derived_series())
+}
+
+/// Check whether a group is abelian, from its multiplication table.
+///
+/// # Arguments
+/// * `alg` - An algebra with a group operation
+///
+/// # Returns
+/// * `Ok(bool)` - Whether the group operation is commutative
+/// * `Err(String)` - If the algebra has no group operation
+pub fn is_abelian(alg: &BasicAlgebra<i32>) -> Result<bool, String> {
+    let g = GroupTable::from_algebra(alg)?;
+    Ok(g.center().len() == g.n)
+}
+
+/// Check whether a group is nilpotent: its upper central series reaches
+/// the whole group.
+///
+/// # Arguments
+/// * `alg` - An algebra with a group operation
+///
+/// # Returns
+/// * `Ok(bool)` - Whether the group is nilpotent
+/// * `Err(String)` - If the algebra has no group operation
+pub fn is_nilpotent(alg: &BasicAlgebra<i32>) -> Result<bool, String> {
+    let g = GroupTable::from_algebra(alg)?;
+    Ok(g.upper_central_series().last().is_some_and(|z| z.len() == g.n))
+}
+
+/// Check whether a group is solvable: its derived series reaches the
+/// trivial group.
+///
+/// # Arguments
+/// * `alg` - An algebra with a group operation
+///
+/// # Returns
+/// * `Ok(bool)` - Whether the group is solvable
+/// * `Err(String)` - If the algebra has no group operation
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use uacalc::group::{is_nilpotent, is_solvable};
+/// use uacalc::io::AlgebraReader;
+///
+/// let s3 = AlgebraReader::new_from_file(Path::new("resources/algebras/sym3.ua")).unwrap().read_algebra_file().unwrap();
+/// assert!(is_solvable(&s3).unwrap());
+/// assert!(!is_nilpotent(&s3).unwrap());
+/// ```
+pub fn is_solvable(alg: &BasicAlgebra<i32>) -> Result<bool, String> {
+    let g = GroupTable::from_algebra(alg)?;
+    Ok(g.derived_series().last().is_some_and(|d| d.len() == 1))
+}
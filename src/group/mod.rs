@@ -4,10 +4,20 @@ use std::hash::{Hash, Hasher};
 use crate::alg::general_algebra::GeneralAlgebra;
 use crate::alg::op::{Operation, OperationSymbol};
 use crate::alg::{BasicAlgebra, Algebra};
-use crate::alg::algebras::is_homomorphism;
+use crate::alg::algebras::{algebra_from_permutation_group, is_homomorphism};
+use crate::alg::conlat::{CongruenceLattice, Partition};
+use crate::alg::SmallAlgebra;
 use crate::util::int_array::{IntArray, IntArrayTrait};
 use crate::util::PermutationGenerator;
 
+mod group_algebra;
+
+pub use group_algebra::{
+    center, commutator_subgroup, derived_series, is_abelian, is_group, is_nilpotent, is_solvable,
+    normal_subgroups, upper_central_series,
+};
+pub(crate) use group_algebra::GroupTable;
+
 /// A permutation group on the set {0, ..., n-1}.
 /// 
 /// This struct represents a group of permutations with operations for
@@ -503,6 +513,275 @@ impl PermutationGroup {
     }
 }
 
+/// A decomposition of a transitive imprimitive permutation group G: a
+/// nontrivial block system, the action K of G on the blocks, and the action
+/// H of the stabilizer of the block containing 0 on that block. G embeds in
+/// the wreath product H wr K.
+#[derive(Debug, Clone)]
+pub struct ImprimitiveDecomposition {
+    /// The block system; its blocks are the smallest nontrivial blocks
+    pub blocks: Partition,
+    /// The action on the blocks, numbered as by `Partition::block_index`
+    pub block_action: PermutationGroup,
+    /// The action of the stabilizer of the block of 0 on that block, whose
+    /// elements are numbered in increasing order
+    pub block_stabilizer_action: PermutationGroup,
+}
+
+/// Orbits, block systems and primitivity. These are the congruence
+/// structure of the G-set, the unary algebra whose operations are the
+/// generators.
+impl PermutationGroup {
+    /// The generators as maps on `0..n`.
+    fn generator_maps(&self) -> Vec<Vec<usize>> {
+        self.generators.iter()
+            .map(|g| g.as_slice().iter().map(|&x| x as usize).collect())
+            .collect()
+    }
+
+    /// The orbits on {0, ..., n-1}, each sorted, ordered by their least
+    /// element.
+    ///
+    /// # Examples
+    /// ```
+    /// use uacalc::group::PermutationGroup;
+    /// use uacalc::util::int_array::{IntArray, IntArrayTrait};
+    ///
+    /// let group = PermutationGroup::new("G".to_string(), vec![IntArray::from_array(vec![1, 0, 3, 2, 4]).unwrap()]);
+    /// assert_eq!(group.orbits(), vec![vec![0, 1], vec![2, 3], vec![4]]);
+    /// ```
+    pub fn orbits(&self) -> Vec<Vec<usize>> {
+        let gens = self.generator_maps();
+        let n = self.underlying_set_size;
+        let mut seen = vec![false; n];
+        let mut orbits = Vec::new();
+        for start in 0..n {
+            if seen[start] {
+                continue;
+            }
+            seen[start] = true;
+            let mut orbit = vec![start];
+            let mut k = 0;
+            while k < orbit.len() {
+                let x = orbit[k];
+                for g in &gens {
+                    if !seen[g[x]] {
+                        seen[g[x]] = true;
+                        orbit.push(g[x]);
+                    }
+                }
+                k += 1;
+            }
+            orbit.sort_unstable();
+            orbits.push(orbit);
+        }
+        orbits
+    }
+
+    /// Check whether the group has a single orbit.
+    pub fn is_transitive(&self) -> bool {
+        self.orbits().len() <= 1
+    }
+
+    /// The transitive constituents: the restriction of the group to each
+    /// orbit, with the points of the orbit numbered in increasing order.
+    pub fn transitive_constituents(&self) -> Vec<PermutationGroup> {
+        let gens = self.generator_maps();
+        self.orbits()
+            .into_iter()
+            .map(|orbit| {
+                let restricted = gens.iter()
+                    .map(|g| Self::restrict(g, &orbit))
+                    .collect();
+                let name = format!("{}|{:?}", self.name, orbit);
+                PermutationGroup::new(name, restricted)
+            })
+            .collect()
+    }
+
+    /// The restriction of `g` to a sorted invariant set, renumbered.
+    fn restrict(g: &[usize], set: &[usize]) -> IntArray {
+        let image = set.iter()
+            .map(|&x| set.binary_search(&g[x]).expect("the set is invariant") as i32)
+            .collect();
+        IntArray::from_array(image).expect("a restriction is a valid array")
+    }
+
+    /// The smallest block system in which `a` and `b` are in the same block,
+    /// i.e. the principal congruence of the G-set generated by `(a, b)`.
+    ///
+    /// # Panics
+    /// Panics if `a` or `b` is not below the size of the underlying set
+    pub fn minimal_block_system(&self, a: usize, b: usize) -> Partition {
+        let gens = self.generator_maps();
+        let mut part = Partition::zero(self.underlying_set_size);
+        let mut pending = vec![(a, b)];
+        while let Some((x, y)) = pending.pop() {
+            let (rx, ry) = (part.representative(x), part.representative(y));
+            if rx == ry {
+                continue;
+            }
+            part.join_blocks(rx, ry);
+            pending.extend(gens.iter().map(|g| (g[x], g[y])));
+        }
+        part
+    }
+
+    /// All block systems, that is all congruences of the G-set, from the
+    /// congruence lattice of `to_g_set`.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Partition>)` - The block systems, including the trivial ones
+    /// * `Err(String)` - If a generator is not a permutation
+    pub fn block_systems(&self) -> Result<Vec<Partition>, String> {
+        if self.underlying_set_size == 0 {
+            return Ok(Vec::new());
+        }
+        let alg: Box<dyn SmallAlgebra<UniverseItem = i32>> = Box::new(self.to_g_set()?);
+        let mut con = CongruenceLattice::new(alg);
+        Ok(CongruenceLattice::universe(&mut con).clone())
+    }
+
+    /// The G-set of this group: the unary algebra on {0, ..., n-1} whose
+    /// operations are the generators.
+    ///
+    /// # Returns
+    /// * `Ok(BasicAlgebra<i32>)` - The G-set
+    /// * `Err(String)` - If there are no generators or one is not a permutation
+    pub fn to_g_set(&self) -> Result<BasicAlgebra<i32>, String> {
+        let perms: Vec<Vec<i32>> = self.generators.iter().map(|g| g.as_slice().to_vec()).collect();
+        let mut alg = algebra_from_permutation_group(&perms)?;
+        alg.set_name(self.name.clone());
+        Ok(alg)
+    }
+
+    /// Check whether the group is primitive: transitive with no block
+    /// systems other than the trivial ones.
+    ///
+    /// # Examples
+    /// ```
+    /// use uacalc::group::PermutationGroup;
+    /// use uacalc::util::int_array::{IntArray, IntArrayTrait};
+    ///
+    /// // The cyclic group of order 4 preserves the blocks {0, 2} and {1, 3}
+    /// let c4 = PermutationGroup::new("C4".to_string(), vec![IntArray::from_array(vec![1, 2, 3, 0]).unwrap()]);
+    /// assert!(!c4.is_primitive());
+    /// let c5 = PermutationGroup::new("C5".to_string(), vec![IntArray::from_array(vec![1, 2, 3, 4, 0]).unwrap()]);
+    /// assert!(c5.is_primitive());
+    /// ```
+    pub fn is_primitive(&self) -> bool {
+        self.is_transitive()
+            && (1..self.underlying_set_size).all(|a| self.minimal_block_system(0, a).number_of_blocks() == 1)
+    }
+
+    /// The action of the group on the blocks of a block system, with the
+    /// blocks numbered as by `Partition::block_index`.
+    ///
+    /// # Returns
+    /// * `Ok(PermutationGroup)` - The action on the blocks
+    /// * `Err(String)` - If the partition is not a block system of the group
+    pub fn block_action(&self, blocks: &Partition) -> Result<PermutationGroup, String> {
+        if blocks.universe_size() != self.underlying_set_size {
+            return Err(format!("The partition is on {} elements, not {}", blocks.universe_size(), self.underlying_set_size));
+        }
+        let block_of = |x: usize| blocks.block_index(x).expect("every element has a block");
+        let mut images = Vec::new();
+        for (k, g) in self.generator_maps().iter().enumerate() {
+            let mut image = vec![-1; blocks.number_of_blocks()];
+            for (x, &gx) in g.iter().enumerate() {
+                let (b, gb) = (block_of(x), block_of(gx) as i32);
+                if image[b] != -1 && image[b] != gb {
+                    return Err(format!("The partition is not a block system: generator {} splits the block of {}", k, x));
+                }
+                image[b] = gb;
+            }
+            images.push(IntArray::from_array(image)?);
+        }
+        Ok(PermutationGroup::new(format!("{}/blocks", self.name), images))
+    }
+
+    /// Decompose a transitive imprimitive group along a block system with
+    /// the smallest nontrivial blocks, so that the group embeds in the wreath
+    /// product of the action of a block stabilizer on its block by the action
+    /// on the blocks.
+    ///
+    /// The block stabilizer is generated by Schreier generators, so no group
+    /// elements are enumerated.
+    ///
+    /// # Returns
+    /// * `Some(ImprimitiveDecomposition)` - The decomposition
+    /// * `None` - If the group is intransitive or primitive
+    ///
+    /// # Examples
+    /// ```
+    /// use uacalc::group::PermutationGroup;
+    /// use uacalc::util::int_array::{IntArray, IntArrayTrait};
+    ///
+    /// let c4 = PermutationGroup::new("C4".to_string(), vec![IntArray::from_array(vec![1, 2, 3, 0]).unwrap()]);
+    /// let decomposition = c4.imprimitive_decomposition().unwrap();
+    /// assert_eq!(decomposition.blocks.get_blocks(), vec![vec![0, 2], vec![1, 3]]);
+    /// assert_eq!(decomposition.block_action.underlying_set_size, 2);
+    /// assert_eq!(decomposition.block_stabilizer_action.underlying_set_size, 2);
+    /// ```
+    pub fn imprimitive_decomposition(&self) -> Option<ImprimitiveDecomposition> {
+        if !self.is_transitive() {
+            return None;
+        }
+        let n = self.underlying_set_size;
+        let blocks = (1..n)
+            .map(|a| self.minimal_block_system(0, a))
+            .filter(|p| p.number_of_blocks() > 1)
+            .max_by_key(|p| p.number_of_blocks())?;
+        let block_action = self.block_action(&blocks).ok()?;
+        let block_of = |x: usize| blocks.block_index(x).expect("every element has a block");
+
+        // Schreier generators for the stabilizer of the block of 0, from a
+        // transversal t[b] mapping the block of 0 onto block b.
+        let gens = self.generator_maps();
+        let compose = |f: &[usize], g: &[usize]| g.iter().map(|&x| f[x]).collect::<Vec<usize>>();
+        let inverse = |f: &[usize]| {
+            let mut inv = vec![0; f.len()];
+            for (x, &fx) in f.iter().enumerate() {
+                inv[fx] = x;
+            }
+            inv
+        };
+        let mut transversal: Vec<Option<Vec<usize>>> = vec![None; blocks.number_of_blocks()];
+        transversal[block_of(0)] = Some((0..n).collect());
+        let mut queue = vec![block_of(0)];
+        let mut k = 0;
+        while k < queue.len() {
+            let b = queue[k];
+            let t = transversal[b].clone().expect("queued blocks have a transversal");
+            for g in &gens {
+                let gb = block_of(g[t[0]]);
+                if transversal[gb].is_none() {
+                    transversal[gb] = Some(compose(g, &t));
+                    queue.push(gb);
+                }
+            }
+            k += 1;
+        }
+        let block0: Vec<usize> = (0..n).filter(|&x| blocks.is_related(0, x)).collect();
+        let mut stabilizer_gens: Vec<IntArray> = Vec::new();
+        for t in transversal.iter().flatten() {
+            for g in &gens {
+                let gt = compose(g, t);
+                let back = inverse(transversal[block_of(gt[0])].as_ref().expect("the action is transitive"));
+                let s = Self::restrict(&compose(&back, &gt), &block0);
+                if !stabilizer_gens.contains(&s) {
+                    stabilizer_gens.push(s);
+                }
+            }
+        }
+        Some(ImprimitiveDecomposition {
+            block_stabilizer_action: PermutationGroup::new(format!("{}_B", self.name), stabilizer_gens),
+            block_action,
+            blocks,
+        })
+    }
+}
+
 impl PartialEq for PermutationGroup {
     fn eq(&self, other: &Self) -> bool {
         self.name == other.name &&
@@ -0,0 +1,685 @@
+//! Versioned JSON representations of algebras, operations, partitions,
+//! lattices, terms and analysis reports.
+//!
+//! Every document is a JSON object carrying `"schema_version"` and `"kind"`
+//! alongside its own fields, for example
+//!
+//! ```json
+//! {
+//!   "schema_version": 1,
+//!   "kind": "algebra",
+//!   "name": "Z2",
+//!   "size": 2,
+//!   "operations": [{"name": "+", "arity": 2, "table": [0, 1, 1, 0]}]
+//! }
+//! ```
+//!
+//! Readers accept documents without the two envelope fields, reject a
+//! `kind` other than the expected one and reject schema versions newer than
+//! [`SCHEMA_VERSION`]. Operation tables list values in Horner order, the
+//! first argument varying fastest, as in `.ua` files. Partitions are lists
+//! of blocks, terms are strings in the syntax of
+//! [`string_to_term`](crate::terms::string_to_term), and lattices and
+//! ordered sets list their elements as strings with the upper covers of
+//! each element given by index. A congruence lattice is stored as its
+//! algebra together with a [`ConLatCheckpoint`] of what has been computed.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::alg::conlat::{CongruenceLattice, ConLatCheckpoint, Partition, TypeFinder};
+use crate::alg::op::{operations, Operation, OperationSymbol};
+use crate::alg::{discriminator, malcev, Algebra, BasicAlgebra, SmallAlgebra};
+use crate::eq::Equation;
+use crate::lat::ordered_set::OrderedSet;
+use crate::lat::BasicLattice;
+use crate::terms::{string_to_term, Term};
+
+/// The version of the JSON representations written by this module.
+///
+/// It is increased whenever a representation changes incompatibly.
+pub const SCHEMA_VERSION: u64 = 1;
+
+/// Types with a JSON representation.
+pub trait ToJson {
+    /// The value of the `"kind"` field.
+    const KIND: &'static str;
+
+    /// Get the fields of the representation, without the envelope.
+    fn to_json_value(&self) -> Value;
+
+    /// Get the representation as a JSON string, with the envelope.
+    fn to_json_str(&self) -> String {
+        let mut doc = Map::new();
+        doc.insert("schema_version".to_string(), Value::from(SCHEMA_VERSION));
+        doc.insert("kind".to_string(), Value::from(Self::KIND));
+        if let Value::Object(fields) = self.to_json_value() {
+            doc.extend(fields);
+        }
+        Value::Object(doc).to_string()
+    }
+}
+
+/// Types that can be read back from their JSON representation.
+pub trait FromJson: ToJson + Sized {
+    /// Read the representation from the fields of a document.
+    fn from_json_value(value: &Value) -> Result<Self, String>;
+
+    /// Read a JSON document, checking its envelope.
+    fn from_json_str(json: &str) -> Result<Self, String> {
+        let value: Value = serde_json::from_str(json).map_err(|e| format!("Invalid JSON: {}", e))?;
+        check_envelope(&value, Self::KIND)?;
+        Self::from_json_value(&value)
+    }
+}
+
+/// Check the `"schema_version"` and `"kind"` fields of a document, if present.
+pub fn check_envelope(value: &Value, kind: &str) -> Result<(), String> {
+    if !value.is_object() {
+        return Err(format!("Expected a JSON object for {}", kind));
+    }
+    if let Some(version) = value.get("schema_version") {
+        let version = version.as_u64().ok_or("\"schema_version\" must be a non-negative integer")?;
+        if version > SCHEMA_VERSION {
+            return Err(format!("Unsupported schema version {} (supported up to {})", version, SCHEMA_VERSION));
+        }
+    }
+    match value.get("kind").and_then(Value::as_str) {
+        Some(found) if found != kind => Err(format!("Expected a {} document, found {}", kind, found)),
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn field<'a>(value: &'a Value, key: &str, kind: &str) -> Result<&'a Value, String> {
+    value.get(key).ok_or_else(|| format!("{} is missing \"{}\"", kind, key))
+}
+
+fn usize_field(value: &Value, key: &str, kind: &str) -> Result<usize, String> {
+    field(value, key, kind)?
+        .as_u64()
+        .map(|n| n as usize)
+        .ok_or_else(|| format!("\"{}\" of {} must be a non-negative integer", key, kind))
+}
+
+pub(crate) fn str_field<'a>(value: &'a Value, key: &str, kind: &str) -> Result<&'a str, String> {
+    field(value, key, kind)?
+        .as_str()
+        .ok_or_else(|| format!("\"{}\" of {} must be a string", key, kind))
+}
+
+fn from_serde<T: for<'de> Deserialize<'de>>(value: &Value, kind: &str) -> Result<T, String> {
+    // The envelope fields are not part of the serde structs.
+    let mut value = value.clone();
+    if let Value::Object(fields) = &mut value {
+        fields.remove("schema_version");
+        fields.remove("kind");
+    }
+    serde_json::from_value(value).map_err(|e| format!("Invalid {}: {}", kind, e))
+}
+
+/// Get the representation of an operation, evaluating it if it has no table.
+fn operation_value(op: &dyn Operation) -> Result<Value, String> {
+    let arity = op.arity();
+    let table = match op.get_table() {
+        Some(table) => table.to_vec(),
+        None => {
+            let n = op.get_set_size();
+            let count = (n as usize).checked_pow(arity as u32).ok_or("Operation table is too large")?;
+            (0..count)
+                .map(|k| op.int_value_at(&crate::util::horner::horner_inv_same_size(k as i32, n, arity as usize)))
+                .collect::<Result<Vec<i32>, String>>()?
+        }
+    };
+    Ok(serde_json::json!({
+        "name": op.symbol().name(),
+        "arity": arity,
+        "set_size": op.get_set_size(),
+        "table": table,
+    }))
+}
+
+fn operation_from_value(value: &Value, set_size: Option<i32>) -> Result<Box<dyn Operation>, String> {
+    let name = str_field(value, "name", "operation")?;
+    let arity = usize_field(value, "arity", "operation")? as i32;
+    let set_size = match (value.get("set_size"), set_size) {
+        (Some(size), _) => size.as_i64().ok_or("\"set_size\" of operation must be an integer")? as i32,
+        (None, Some(size)) => size,
+        (None, None) => return Err("operation is missing \"set_size\"".to_string()),
+    };
+    let table: Vec<i32> = from_serde(field(value, "table", "operation")?, "operation table")?;
+    operations::make_int_operation(OperationSymbol::new_safe(name, arity, false)?, set_size, table)
+}
+
+impl ToJson for Box<dyn Operation> {
+    const KIND: &'static str = "operation";
+
+    fn to_json_value(&self) -> Value {
+        operation_value(self.as_ref()).unwrap_or(Value::Null)
+    }
+}
+
+impl FromJson for Box<dyn Operation> {
+    fn from_json_value(value: &Value) -> Result<Self, String> {
+        operation_from_value(value, None)
+    }
+}
+
+/// Get the representation of any algebra on `{0, ..., n-1}`.
+///
+/// # Returns
+/// The fields of an `"algebra"` document, or `Err` if an operation cannot
+/// be evaluated
+pub fn algebra_to_json_value(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<Value, String> {
+    let ops = alg
+        .get_operations_ref()
+        .into_iter()
+        .map(|op| {
+            operation_value(op).map(|mut v| {
+                // The size is that of the algebra.
+                v.as_object_mut().unwrap().remove("set_size");
+                v
+            })
+        })
+        .collect::<Result<Vec<Value>, String>>()?;
+    let mut value = serde_json::json!({
+        "name": alg.name(),
+        "size": alg.cardinality(),
+        "operations": ops,
+    });
+    if let Some(desc) = alg.description() {
+        value["description"] = Value::from(desc);
+    }
+    Ok(value)
+}
+
+impl ToJson for BasicAlgebra<i32> {
+    const KIND: &'static str = "algebra";
+
+    fn to_json_value(&self) -> Value {
+        algebra_to_json_value(self).unwrap_or(Value::Null)
+    }
+}
+
+impl FromJson for BasicAlgebra<i32> {
+    fn from_json_value(value: &Value) -> Result<Self, String> {
+        let size = usize_field(value, "size", "algebra")? as i32;
+        if size == 0 {
+            return Err("\"size\" of algebra must be positive".to_string());
+        }
+        let name = value.get("name").and_then(Value::as_str).unwrap_or("A").to_string();
+        let ops = match value.get("operations") {
+            Some(ops) => ops
+                .as_array()
+                .ok_or("\"operations\" of algebra must be a list")?
+                .iter()
+                .map(|op| operation_from_value(op, Some(size)))
+                .collect::<Result<Vec<_>, String>>()?,
+            None => Vec::new(),
+        };
+        let mut alg = BasicAlgebra::new(name, (0..size).collect::<HashSet<i32>>(), ops);
+        if let Some(desc) = value.get("description").and_then(Value::as_str) {
+            alg.set_description(Some(desc.to_string()));
+        }
+        Ok(alg)
+    }
+}
+
+impl ToJson for Partition {
+    const KIND: &'static str = "partition";
+
+    fn to_json_value(&self) -> Value {
+        serde_json::json!({
+            "size": self.universe_size(),
+            "blocks": self.get_blocks(),
+        })
+    }
+}
+
+impl FromJson for Partition {
+    fn from_json_value(value: &Value) -> Result<Self, String> {
+        let size = usize_field(value, "size", "partition")?;
+        let blocks: Vec<Vec<usize>> = from_serde(field(value, "blocks", "partition")?, "partition blocks")?;
+        let mut array = vec![0_i32; size];
+        let mut seen = vec![false; size];
+        for block in &blocks {
+            let root = *block.iter().min().ok_or("partition has an empty block")?;
+            for &x in block {
+                if x >= size || seen[x] {
+                    return Err(format!("element {} is out of range or in two blocks", x));
+                }
+                seen[x] = true;
+                array[x] = root as i32;
+            }
+            array[root] = -(block.len() as i32);
+        }
+        if seen.contains(&false) {
+            return Err("partition blocks do not cover the universe".to_string());
+        }
+        Partition::new(array)
+    }
+}
+
+impl ToJson for Box<dyn Term> {
+    const KIND: &'static str = "term";
+
+    fn to_json_value(&self) -> Value {
+        serde_json::json!({ "term": self.to_string() })
+    }
+}
+
+impl FromJson for Box<dyn Term> {
+    fn from_json_value(value: &Value) -> Result<Self, String> {
+        string_to_term(str_field(value, "term", "term")?)
+    }
+}
+
+impl ToJson for Equation {
+    const KIND: &'static str = "equation";
+
+    fn to_json_value(&self) -> Value {
+        serde_json::json!({
+            "left": self.left_side().to_string(),
+            "right": self.right_side().to_string(),
+        })
+    }
+}
+
+impl FromJson for Equation {
+    fn from_json_value(value: &Value) -> Result<Self, String> {
+        Ok(Equation::new(
+            string_to_term(str_field(value, "left", "equation")?)?,
+            string_to_term(str_field(value, "right", "equation")?)?,
+        ))
+    }
+}
+
+impl ToJson for CongruenceLattice<i32> {
+    const KIND: &'static str = "congruence_lattice";
+
+    fn to_json_value(&self) -> Value {
+        serde_json::json!({
+            "algebra": algebra_to_json_value(self.alg.as_ref()).unwrap_or(Value::Null),
+            "checkpoint": serde_json::to_value(self.pause()).unwrap_or(Value::Null),
+        })
+    }
+}
+
+impl FromJson for CongruenceLattice<i32> {
+    fn from_json_value(value: &Value) -> Result<Self, String> {
+        let alg = BasicAlgebra::<i32>::from_json_value(field(value, "algebra", "congruence_lattice")?)?;
+        let mut con = CongruenceLattice::new(Box::new(alg) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+        if let Some(checkpoint) = value.get("checkpoint") {
+            let checkpoint: ConLatCheckpoint = from_serde(checkpoint, "congruence lattice checkpoint")?;
+            con.resume(&checkpoint)?;
+        }
+        Ok(con)
+    }
+}
+
+fn ordered_set_value<T>(name: Option<&str>, poset: &OrderedSet<T>) -> Value
+where
+    T: Clone + PartialEq + Eq + Hash + Debug + Display + Send + Sync + 'static,
+{
+    let univ = poset.univ();
+    let elements: Vec<String> = univ.iter().map(|e| e.get_underlying_object().to_string()).collect();
+    let covers: Vec<Vec<usize>> = univ
+        .iter()
+        .map(|e| poset.get_upper_covers(e).iter().map(|c| c.index()).collect())
+        .collect();
+    serde_json::json!({
+        "name": name,
+        "elements": elements,
+        "upper_covers": covers,
+    })
+}
+
+fn ordered_set_from_value(value: &Value, kind: &str) -> Result<OrderedSet<String>, String> {
+    let elements: Vec<String> = from_serde(field(value, "elements", kind)?, kind)?;
+    let covers: Vec<Vec<usize>> = from_serde(field(value, "upper_covers", kind)?, kind)?;
+    let covers = covers
+        .iter()
+        .map(|cs| {
+            cs.iter()
+                .map(|&c| elements.get(c).cloned().ok_or_else(|| format!("upper cover {} is out of range", c)))
+                .collect::<Result<Vec<String>, String>>()
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    let name = value.get("name").and_then(Value::as_str).map(|s| s.to_string());
+    OrderedSet::new(name, elements, covers)
+}
+
+impl<T> ToJson for OrderedSet<T>
+where
+    T: Clone + PartialEq + Eq + Hash + Debug + Display + Send + Sync + 'static,
+{
+    const KIND: &'static str = "ordered_set";
+
+    fn to_json_value(&self) -> Value {
+        ordered_set_value(self.name(), self)
+    }
+}
+
+impl FromJson for OrderedSet<String> {
+    fn from_json_value(value: &Value) -> Result<Self, String> {
+        ordered_set_from_value(value, "ordered set")
+    }
+}
+
+impl<T> ToJson for BasicLattice<T>
+where
+    T: Clone + PartialEq + Eq + Hash + Debug + Display + Send + Sync + 'static,
+{
+    const KIND: &'static str = "lattice";
+
+    fn to_json_value(&self) -> Value {
+        ordered_set_value(Some(self.name()), self.get_poset())
+    }
+}
+
+impl FromJson for BasicLattice<String> {
+    fn from_json_value(value: &Value) -> Result<Self, String> {
+        let poset = ordered_set_from_value(value, "lattice")?;
+        let name = value.get("name").and_then(Value::as_str).unwrap_or("L").to_string();
+        BasicLattice::new_from_poset(name, poset, None)
+    }
+}
+
+/// The Mal'cev condition terms of an algebra.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MalcevReport {
+    /// The name of the algebra.
+    pub algebra: String,
+    /// The size of the algebra.
+    pub size: usize,
+    /// For each kind of term searched for, the terms found or `None` if
+    /// there are none.
+    pub terms: BTreeMap<String, Option<Vec<String>>>,
+    /// Properties decided alongside the terms: searching for `"sd-meet"`
+    /// records whether the variety (`"variety_sd_meet"`) and Con(A)
+    /// (`"con_sd_meet"`, `"con_sd_join"`) are semidistributive, `"pixley"`
+    /// whether the variety is `"arithmetical"` and `"discriminator"`
+    /// whether it is a `"discriminator_variety"`.
+    #[serde(default)]
+    pub properties: BTreeMap<String, bool>,
+    /// The sets of tame congruence theory types the variety omits, as far
+    /// as the terms searched for decide; empty if none of `"taylor"`,
+    /// `"sd-meet"`, `"sd"`, `"gumm"` and `"hagemann-mitschke"` was searched.
+    #[serde(default)]
+    pub omitted_types: Vec<malcev::TypeOmission>,
+}
+
+impl MalcevReport {
+    /// The kinds of terms [`analyze`](Self::analyze) can search for.
+    pub const KINDS: &'static [&'static str] = &[
+        "malcev", "majority", "minority", "pixley", "semilattice", "difference", "weak-majority",
+        "taylor", "nu", "jonsson", "gumm", "hagemann-mitschke", "sd-meet", "sd", "discriminator",
+    ];
+
+    /// Search an algebra for Mal'cev condition terms.
+    ///
+    /// # Arguments
+    /// * `alg` - The algebra
+    /// * `kinds` - The terms to look for, from [`KINDS`](Self::KINDS)
+    /// * `nu_arity` - The arity of the near unanimity term for `"nu"`
+    ///
+    /// # Returns
+    /// The report, or `Err` for an unknown kind or a failed search
+    pub fn analyze(alg: &dyn SmallAlgebra<UniverseItem = i32>, kinds: &[&str], nu_arity: usize) -> Result<Self, String> {
+        let strings = |terms: Option<Vec<Box<dyn Term>>>| terms.map(|ts| ts.iter().map(|t| t.to_string()).collect());
+        let single = |term: Option<Box<dyn Term>>| strings(term.map(|t| vec![t]));
+        let mut terms = BTreeMap::new();
+        let mut properties = BTreeMap::new();
+        for &kind in kinds {
+            let found = match kind {
+                "malcev" => single(malcev::malcev_term(alg)?),
+                "majority" => single(malcev::majority_term(alg)?),
+                "minority" => single(malcev::minority_term(alg)?),
+                "pixley" => {
+                    let analysis = discriminator::is_arithmetical(alg)?;
+                    properties.insert("arithmetical".to_string(), analysis.holds());
+                    single(analysis.pixley_term)
+                }
+                "semilattice" => single(malcev::semilattice_term(alg)?),
+                "difference" => single(malcev::difference_term(alg)?),
+                "weak-majority" => single(malcev::weak_majority_term(alg)?),
+                "taylor" => single(malcev::markovic_mckenzie_siggers_taylor_term(alg)?),
+                "nu" => single(malcev::nu_term(alg, nu_arity)?),
+                "jonsson" => strings(malcev::jonsson_terms(alg)?),
+                "gumm" => strings(malcev::gumm_terms(alg)?),
+                "hagemann-mitschke" => strings(malcev::hagemann_mitschke_terms(alg)?),
+                "sd-meet" => {
+                    let analysis = malcev::variety_is_sd_meet(alg)?;
+                    properties.insert("variety_sd_meet".to_string(), analysis.holds());
+                    let mut con = CongruenceLattice::new(alg.clone_box());
+                    con.try_universe()?;
+                    properties.insert("con_sd_meet".to_string(), crate::lat::Lattice::is_meet_semidistributive(&con));
+                    properties.insert("con_sd_join".to_string(), crate::lat::Lattice::is_join_semidistributive(&con));
+                    strings(analysis.terms)
+                }
+                "sd" => strings(malcev::sd_terms(alg)?),
+                "discriminator" => {
+                    let analysis = discriminator::is_discriminator_variety(alg)?;
+                    properties.insert("discriminator_variety".to_string(), analysis.holds());
+                    single(analysis.discriminator_term)
+                }
+                _ => return Err(format!("Unknown term kind: {}", kind)),
+            };
+            terms.insert(kind.to_string(), found);
+        }
+        let found = |kind: &str| terms.get(kind).map(|t: &Option<Vec<String>>| t.is_some());
+        let conditions = malcev::OmissionConditions {
+            taylor: found("taylor"),
+            sd_meet: found("sd-meet"),
+            sd_join: found("sd"),
+            modular: found("gumm"),
+            permutable: found("hagemann-mitschke"),
+        };
+        let omitted_types = if conditions == malcev::OmissionConditions::default() {
+            Vec::new()
+        } else {
+            conditions.omissions()
+        };
+        Ok(MalcevReport {
+            algebra: alg.name().to_string(),
+            size: alg.cardinality() as usize,
+            terms,
+            properties,
+            omitted_types,
+        })
+    }
+}
+
+impl ToJson for MalcevReport {
+    const KIND: &'static str = "malcev_report";
+
+    fn to_json_value(&self) -> Value {
+        serde_json::to_value(self).unwrap_or(Value::Null)
+    }
+}
+
+impl FromJson for MalcevReport {
+    fn from_json_value(value: &Value) -> Result<Self, String> {
+        from_serde(value, "Mal'cev report")
+    }
+}
+
+/// The tame congruence theory type of a join irreducible congruence.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CongruenceType {
+    /// The blocks of the join irreducible congruence.
+    pub congruence: Vec<Vec<usize>>,
+    /// The blocks of its unique lower cover.
+    pub lower_cover: Vec<Vec<usize>>,
+    /// The type, from 1 to 5.
+    #[serde(rename = "type")]
+    pub tct_type: i32,
+}
+
+/// The tame congruence theory types of an algebra.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TctReport {
+    /// The name of the algebra.
+    pub algebra: String,
+    /// The size of the algebra.
+    pub size: usize,
+    /// The types occurring in the congruence lattice, in increasing order.
+    pub type_set: Vec<i32>,
+    /// The type of each join irreducible congruence.
+    pub join_irreducibles: Vec<CongruenceType>,
+}
+
+impl TctReport {
+    /// Find the type of every join irreducible congruence of an algebra.
+    ///
+    /// # Returns
+    /// The report, or `Err` if a type cannot be found
+    pub fn analyze(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Result<Self, String> {
+        let mut con = CongruenceLattice::new(alg.clone_box());
+        let mut finder = TypeFinder::new(alg.clone_box())?;
+        let jis = con.join_irreducibles().clone();
+        let mut join_irreducibles = Vec::with_capacity(jis.len());
+        let mut type_set = Vec::new();
+        for ji in &jis {
+            let lower = con.lower_star(ji).ok_or_else(|| format!("{} is not join irreducible", ji))?;
+            let tct_type = finder.find_type(ji)?;
+            if !type_set.contains(&tct_type) {
+                type_set.push(tct_type);
+            }
+            join_irreducibles.push(CongruenceType {
+                congruence: ji.get_blocks(),
+                lower_cover: lower.get_blocks(),
+                tct_type,
+            });
+        }
+        type_set.sort();
+        Ok(TctReport { algebra: alg.name().to_string(), size: alg.cardinality() as usize, type_set, join_irreducibles })
+    }
+}
+
+impl ToJson for TctReport {
+    const KIND: &'static str = "tct_report";
+
+    fn to_json_value(&self) -> Value {
+        serde_json::to_value(self).unwrap_or(Value::Null)
+    }
+}
+
+impl FromJson for TctReport {
+    fn from_json_value(value: &Value) -> Result<Self, String> {
+        from_serde(value, "TCT report")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lat2() -> BasicAlgebra<i32> {
+        BasicAlgebra::<i32>::from_json_str(
+            r#"{"name": "L2", "size": 2, "operations": [
+                {"name": "join", "arity": 2, "table": [0, 1, 1, 1]},
+                {"name": "meet", "arity": 2, "table": [0, 0, 0, 1]}]}"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_algebra_round_trip() {
+        let alg = lat2();
+        let json = alg.to_json_str();
+        assert!(json.contains("\"schema_version\":1"));
+        assert!(json.contains("\"kind\":\"algebra\""));
+        let back = BasicAlgebra::<i32>::from_json_str(&json).unwrap();
+        assert_eq!(back.name(), "L2");
+        assert_eq!(back.to_json_value(), alg.to_json_value());
+
+        assert!(BasicAlgebra::<i32>::from_json_str(r#"{"kind": "partition", "size": 2}"#).is_err());
+        assert!(BasicAlgebra::<i32>::from_json_str(r#"{"schema_version": 99, "size": 2}"#).is_err());
+        assert!(BasicAlgebra::<i32>::from_json_str(r#"{"size": 2, "operations": [{"name": "f", "arity": 1, "table": [0]}]}"#).is_err());
+    }
+
+    #[test]
+    fn test_partition_term_and_equation() {
+        let par = Partition::new(vec![-2, 0, -1]).unwrap();
+        let json = par.to_json_str();
+        assert!(json.contains("\"blocks\":[[0,1],[2]]"));
+        assert_eq!(Partition::from_json_str(&json).unwrap(), par);
+        assert!(Partition::from_json_str(r#"{"size": 3, "blocks": [[0, 1]]}"#).is_err());
+        assert!(Partition::from_json_str(r#"{"size": 2, "blocks": [[0, 1], [1]]}"#).is_err());
+
+        let term = string_to_term("f(x,g(y))").unwrap();
+        let back = <Box<dyn Term>>::from_json_str(&term.to_json_str()).unwrap();
+        assert_eq!(back.to_string(), "f(x,g(y))");
+
+        let eq = Equation::new(string_to_term("f(x,y)").unwrap(), string_to_term("f(y,x)").unwrap());
+        let back = Equation::from_json_str(&eq.to_json_str()).unwrap();
+        assert_eq!(back.to_string(), eq.to_string());
+    }
+
+    #[test]
+    fn test_congruence_lattice_round_trip() {
+        let mut con = CongruenceLattice::new(Box::new(lat2()) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+        assert_eq!(con.con_cardinality(), 2);
+        let json = con.to_json_str();
+        assert!(json.contains("\"kind\":\"congruence_lattice\""));
+        let mut back = CongruenceLattice::<i32>::from_json_str(&json).unwrap();
+        assert_eq!(back.alg.name(), "L2");
+        assert_eq!(back.pause(), con.pause());
+        assert_eq!(back.con_cardinality(), 2);
+
+        let fresh = r#"{"algebra": {"name": "L2", "size": 2}}"#;
+        assert_eq!(CongruenceLattice::<i32>::from_json_str(fresh).unwrap().con_cardinality(), 2);
+        assert!(CongruenceLattice::<i32>::from_json_str(r#"{"checkpoint": null}"#).is_err());
+    }
+
+    #[test]
+    fn test_lattice_round_trip() {
+        let json = r#"{"kind": "lattice", "name": "2x2",
+            "elements": ["0", "a", "b", "1"], "upper_covers": [[1, 2], [3], [3], []]}"#;
+        let lat = BasicLattice::<String>::from_json_str(json).unwrap();
+        let back = BasicLattice::<String>::from_json_str(&lat.to_json_str()).unwrap();
+        assert_eq!(back.to_json_value(), lat.to_json_value());
+        assert_eq!(lat.to_json_value()["elements"].as_array().unwrap().len(), 4);
+
+        let poset = OrderedSet::<String>::from_json_str(r#"{"elements": ["a", "b"], "upper_covers": [[], []]}"#).unwrap();
+        assert_eq!(poset.univ().len(), 2);
+        assert!(OrderedSet::<String>::from_json_str(r#"{"elements": ["a"], "upper_covers": [[3]]}"#).is_err());
+    }
+
+    #[test]
+    fn test_reports() {
+        let alg = lat2();
+        let report = MalcevReport::analyze(&alg, &["majority", "malcev"], 3).unwrap();
+        assert!(report.terms["majority"].is_some());
+        assert!(report.terms["malcev"].is_none());
+        assert_eq!(MalcevReport::from_json_str(&report.to_json_str()).unwrap(), report);
+        assert!(MalcevReport::analyze(&alg, &["unknown"], 3).is_err());
+        assert!(report.properties.is_empty());
+        let sd = MalcevReport::analyze(&alg, &["sd-meet"], 3).unwrap();
+        assert!(sd.terms["sd-meet"].is_some());
+        assert!(sd.properties["variety_sd_meet"] && sd.properties["con_sd_meet"] && sd.properties["con_sd_join"]);
+        assert_eq!(MalcevReport::from_json_str(&sd.to_json_str()).unwrap(), sd);
+        let disc = MalcevReport::analyze(&alg, &["pixley", "discriminator"], 3).unwrap();
+        assert!(!disc.properties["arithmetical"] && !disc.properties["discriminator_variety"]);
+        assert!(disc.terms["discriminator"].is_none());
+        let old = r#"{"kind": "malcev_report", "algebra": "L", "size": 2, "terms": {}}"#;
+        assert!(MalcevReport::from_json_str(old).unwrap().properties.is_empty());
+
+        let omitting = MalcevReport::analyze(&alg, &["taylor", "sd"], 3).unwrap();
+        let omitted: Vec<Option<bool>> = omitting.omitted_types.iter().map(|o| o.omitted).collect();
+        assert_eq!(omitted, vec![Some(true), Some(true), Some(true), Some(true), None, None]);
+        assert_eq!(MalcevReport::from_json_str(&omitting.to_json_str()).unwrap(), omitting);
+        assert!(report.omitted_types.is_empty());
+
+        let tct = TctReport::analyze(&alg).unwrap();
+        assert_eq!(tct.type_set, vec![4]);
+        assert_eq!(tct.join_irreducibles.len(), 1);
+        let json = tct.to_json_str();
+        assert!(json.contains("\"type\":4"));
+        assert_eq!(TctReport::from_json_str(&json).unwrap(), tct);
+    }
+}
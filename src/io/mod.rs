@@ -4,6 +4,9 @@ pub use algebra_reader::AlgebraReader;
 pub mod algebra_io;
 pub use algebra_io::*;
 
+pub mod json;
+pub mod report;
+
 #[cfg(test)]
 mod mace4_reader_tests;
 
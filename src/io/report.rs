@@ -0,0 +1,313 @@
+//! LaTeX fragments for lattices, partitions, terms, equations and Mal'cev
+//! analysis results, ready to paste into a paper.
+//!
+//! Partitions are written in bar notation, `$|01|2|34|$`, with the elements
+//! of a block separated by commas once the universe has more than ten
+//! elements. Terms use `\vee` and `\wedge` for `join` and `meet`, write other
+//! binary operations with symbolic names infix and give variables with a
+//! numeric suffix, like `x0` or `x_0`, a subscript, so `join(x0,meet(x1,x2))` becomes
+//! `$x_{0} \vee (x_{1} \wedge x_{2})$`. Lattices are drawn as TikZ Hasse
+//! diagrams with each element on the level of the longest chain below it.
+
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+
+use crate::alg::conlat::{CongruenceLattice, Partition};
+use crate::alg::op::table_format::latex_escape;
+use crate::eq::Equation;
+use crate::io::json::MalcevReport;
+use crate::lat::BasicLattice;
+use crate::terms::{string_to_term, Term};
+
+/// Types with a LaTeX rendering.
+pub trait ToLatex {
+    /// Get the LaTeX fragment, in math mode where appropriate.
+    fn to_latex(&self) -> String;
+}
+
+fn partition_math(par: &Partition) -> String {
+    let sep = if par.universe_size() > 10 { "," } else { "" };
+    let blocks: Vec<String> = par
+        .get_blocks()
+        .iter()
+        .map(|block| block.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(sep))
+        .collect();
+    format!("|{}|", blocks.join("|"))
+}
+
+impl ToLatex for Partition {
+    fn to_latex(&self) -> String {
+        format!("${}$", partition_math(self))
+    }
+}
+
+fn variable_math(name: &str) -> String {
+    let digits = name.len() - name.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    if digits > 0 && digits < name.len() {
+        let (stem, index) = name.split_at(name.len() - digits);
+        format!("{}_{{{}}}", latex_escape(stem.trim_end_matches('_')), index)
+    } else {
+        latex_escape(name)
+    }
+}
+
+/// The infix form of a binary operation symbol, if it has one.
+fn infix_symbol(name: &str) -> Option<String> {
+    match name {
+        "join" => Some("\\vee".to_string()),
+        "meet" => Some("\\wedge".to_string()),
+        _ if !name.chars().any(|c| c.is_alphanumeric()) => Some(latex_escape(name)),
+        _ => None,
+    }
+}
+
+fn term_math(term: &dyn Term) -> String {
+    let sym = match term.leading_operation_symbol() {
+        Some(sym) if !term.isa_variable() => sym,
+        _ => return variable_math(&term.to_string()),
+    };
+    let children = term.get_children().unwrap_or_default();
+    if children.len() == 2 {
+        if let Some(op) = infix_symbol(sym.name()) {
+            let operand = |t: &dyn Term| {
+                let is_infix = !t.isa_variable()
+                    && t.get_children().is_some_and(|c| c.len() == 2)
+                    && t.leading_operation_symbol().and_then(|s| infix_symbol(s.name())).is_some();
+                if is_infix {
+                    format!("({})", term_math(t))
+                } else {
+                    term_math(t)
+                }
+            };
+            return format!("{} {} {}", operand(children[0].as_ref()), op, operand(children[1].as_ref()));
+        }
+    }
+    let name = if sym.name().chars().count() == 1 {
+        latex_escape(sym.name())
+    } else {
+        format!("\\mathrm{{{}}}", latex_escape(sym.name()))
+    };
+    if children.is_empty() {
+        return name;
+    }
+    let args: Vec<String> = children.iter().map(|c| term_math(c.as_ref())).collect();
+    format!("{}({})", name, args.join(", "))
+}
+
+impl ToLatex for dyn Term {
+    fn to_latex(&self) -> String {
+        format!("${}$", term_math(self))
+    }
+}
+
+impl ToLatex for Equation {
+    fn to_latex(&self) -> String {
+        format!("${} \\approx {}$", term_math(self.left_side()), term_math(self.right_side()))
+    }
+}
+
+impl ToLatex for MalcevReport {
+    fn to_latex(&self) -> String {
+        let term = |s: &String| match string_to_term(s) {
+            Ok(t) => t.to_latex(),
+            Err(_) => format!("\\texttt{{{}}}", latex_escape(s)),
+        };
+        let mut out = format!(
+            "% Mal'cev terms of {} (size {})\n\\begin{{tabular}}{{ll}}\nkind & terms \\\\\n\\hline\n",
+            latex_escape(&self.algebra),
+            self.size
+        );
+        for (kind, terms) in &self.terms {
+            let found = match terms {
+                Some(terms) => terms.iter().map(term).collect::<Vec<_>>().join(", "),
+                None => "none".to_string(),
+            };
+            out.push_str(&format!("{} & {} \\\\\n", latex_escape(kind), found));
+        }
+        for (property, holds) in &self.properties {
+            out.push_str(&format!("{} & {} \\\\\n", latex_escape(property), if *holds { "yes" } else { "no" }));
+        }
+        for omission in &self.omitted_types {
+            let types: Vec<String> = omission.types.iter().map(|t| t.to_string()).collect();
+            let verdict = match omission.omitted {
+                Some(true) => "yes",
+                Some(false) => "no",
+                None => "unknown",
+            };
+            out.push_str(&format!("omits $\\{{{}\\}}$ & {} \\\\\n", types.join(","), verdict));
+        }
+        out.push_str("\\end{tabular}\n");
+        out
+    }
+}
+
+/// Draw a Hasse diagram in TikZ.
+///
+/// # Arguments
+/// * `labels` - The node labels, already in LaTeX
+/// * `upper_covers` - The upper covers of each element, by index
+///
+/// # Returns
+/// A `tikzpicture` with one node per element, on the level of the longest
+/// chain below it, and an edge for each covering pair
+pub fn hasse_tikz(labels: &[String], upper_covers: &[Vec<usize>]) -> String {
+    let n = labels.len();
+    let mut lower_covers = vec![Vec::new(); n];
+    for (i, covers) in upper_covers.iter().enumerate() {
+        for &j in covers {
+            lower_covers[j].push(i);
+        }
+    }
+    // The level of an element is one more than the highest level below it.
+    let mut level: Vec<Option<usize>> = vec![None; n];
+    fn find_level(i: usize, lower: &[Vec<usize>], level: &mut [Option<usize>]) -> usize {
+        if let Some(l) = level[i] {
+            return l;
+        }
+        let l = lower[i].iter().map(|&j| find_level(j, lower, level) + 1).max().unwrap_or(0);
+        level[i] = Some(l);
+        l
+    }
+    let levels: Vec<usize> = (0..n).map(|i| find_level(i, &lower_covers, &mut level)).collect();
+    let mut rows: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (i, &l) in levels.iter().enumerate() {
+        rows.entry(l).or_default().push(i);
+    }
+
+    let mut out = String::from("\\begin{tikzpicture}[every node/.style={font=\\scriptsize}]\n");
+    for (i, label) in labels.iter().enumerate() {
+        let row = &rows[&levels[i]];
+        let pos = row.iter().position(|&j| j == i).unwrap_or(0) as f64;
+        let x = 1.5 * (pos - (row.len() - 1) as f64 / 2.0);
+        out.push_str(&format!("\\node (n{}) at ({},{}) {{{}}};\n", i, x, 1.5 * levels[i] as f64, label));
+    }
+    for (i, covers) in upper_covers.iter().enumerate() {
+        for &j in covers {
+            out.push_str(&format!("\\draw (n{}) -- (n{});\n", i, j));
+        }
+    }
+    out.push_str("\\end{tikzpicture}\n");
+    out
+}
+
+impl<T> ToLatex for BasicLattice<T>
+where
+    T: Clone + PartialEq + Eq + Hash + Debug + Display + Send + Sync + 'static,
+{
+    fn to_latex(&self) -> String {
+        let poset = self.get_poset();
+        let univ = poset.univ();
+        let labels: Vec<String> = univ.iter().map(|e| latex_escape(&e.get_underlying_object().to_string())).collect();
+        let covers: Vec<Vec<usize>> = univ
+            .iter()
+            .map(|e| poset.get_upper_covers(e).iter().map(|c| c.index()).collect())
+            .collect();
+        hasse_tikz(&labels, &covers)
+    }
+}
+
+/// Draw a congruence lattice as a TikZ Hasse diagram with the congruences
+/// in bar notation, computing the lattice first if needed.
+///
+/// # Arguments
+/// * `con` - The congruence lattice
+///
+/// # Returns
+/// * `Ok(String)` - The `tikzpicture`
+/// * `Err(String)` - If computing the lattice was cancelled
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::{BasicAlgebra, SmallAlgebra};
+/// use uacalc::alg::conlat::CongruenceLattice;
+/// use uacalc::io::report::congruence_lattice_latex;
+///
+/// let set = BasicAlgebra::new("S".to_string(), (0..2).collect(), Vec::new());
+/// let mut con = CongruenceLattice::new(Box::new(set) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+/// let tikz = congruence_lattice_latex(&mut con).unwrap();
+/// assert!(tikz.contains("\\node (n0) at (0,0) {$|0|1|$};"));
+/// assert!(tikz.contains("\\draw (n0) -- (n1);"));
+/// ```
+pub fn congruence_lattice_latex(con: &mut CongruenceLattice<i32>) -> Result<String, String> {
+    let univ = con.try_universe()?.clone();
+    let index: HashMap<&Partition, usize> = univ.iter().enumerate().map(|(i, p)| (p, i)).collect();
+    let covers_map = con.upper_covers_map().clone();
+    let covers: Vec<Vec<usize>> = univ
+        .iter()
+        .map(|p| {
+            let mut covers: Vec<usize> = covers_map.get(p).map_or(Vec::new(), |cs| cs.iter().map(|c| index[c]).collect());
+            covers.sort_unstable();
+            covers
+        })
+        .collect();
+    let labels: Vec<String> = univ.iter().map(|p| p.to_latex()).collect();
+    Ok(hasse_tikz(&labels, &covers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::{BasicAlgebra, SmallAlgebra};
+    use crate::alg::op::OperationSymbol;
+    use crate::io::json::FromJson;
+    use crate::terms::{NonVariableTerm, VariableImp};
+
+    #[test]
+    fn test_partition_and_terms() {
+        let par = Partition::new(vec![-2, 0, -1, -2, 3]).unwrap();
+        assert_eq!(par.to_latex(), "$|01|2|34|$");
+        let big = Partition::zero(11);
+        assert!(big.to_latex().starts_with("$|0|1|"));
+        let array: Vec<i32> = std::iter::once(-11).chain(vec![0; 10]).collect();
+        assert_eq!(Partition::new(array).unwrap().to_latex(), "$|0,1,2,3,4,5,6,7,8,9,10|$");
+
+        let term = string_to_term("join(x0,meet(x1,x2))").unwrap();
+        assert_eq!(term.to_latex(), "$x_{0} \\vee (x_{1} \\wedge x_{2})$");
+        let times = NonVariableTerm::new(
+            OperationSymbol::new("*", 2, false),
+            vec![Box::new(VariableImp::new("y")), Box::new(VariableImp::new("z_1"))],
+        );
+        let term = NonVariableTerm::new(
+            OperationSymbol::new("maj", 3, false),
+            vec![Box::new(VariableImp::new("x")), Box::new(VariableImp::new("y")), Box::new(times)],
+        );
+        assert_eq!((&term as &dyn Term).to_latex(), "$\\mathrm{maj}(x, y, y * z_{1})$");
+
+        let eq = Equation::new(string_to_term("f(x,y)").unwrap(), string_to_term("f(y,x)").unwrap());
+        assert_eq!(eq.to_latex(), "$f(x, y) \\approx f(y, x)$");
+    }
+
+    #[test]
+    fn test_lattices_and_reports() {
+        let lat2 = BasicAlgebra::<i32>::from_json_str(
+            r#"{"name": "L_2", "size": 2, "operations": [
+                {"name": "join", "arity": 2, "table": [0, 1, 1, 1]},
+                {"name": "meet", "arity": 2, "table": [0, 0, 0, 1]}]}"#,
+        )
+        .unwrap();
+        let report = MalcevReport::analyze(&lat2, &["majority", "malcev"], 3).unwrap();
+        let latex = report.to_latex();
+        assert!(latex.starts_with("% Mal'cev terms of L\\_2 (size 2)\n\\begin{tabular}{ll}"));
+        assert!(latex.contains("malcev & none \\\\"));
+        assert!(latex.contains("majority & $"));
+        let sd = MalcevReport::analyze(&lat2, &["sd-meet"], 3).unwrap().to_latex();
+        assert!(sd.contains("con\\_sd\\_meet & yes \\\\"));
+        assert!(sd.contains("omits $\\{1,2\\}$ & yes \\\\"));
+        assert!(sd.contains("omits $\\{1,5\\}$ & unknown \\\\"));
+
+        let mut con = CongruenceLattice::new(Box::new(lat2) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+        let tikz = congruence_lattice_latex(&mut con).unwrap();
+        assert!(tikz.contains("{$|0|1|$}"));
+        assert!(tikz.contains("{$|01|$}"));
+        assert_eq!(tikz.matches("\\draw").count(), 1);
+
+        // The four element Boolean lattice: 0 < a, b < 1
+        let labels: Vec<String> = ["0", "a", "b", "1"].iter().map(|s| s.to_string()).collect();
+        let tikz = hasse_tikz(&labels, &[vec![1, 2], vec![3], vec![3], vec![]]);
+        assert!(tikz.contains("\\node (n1) at (-0.75,1.5) {a};"));
+        assert!(tikz.contains("\\node (n2) at (0.75,1.5) {b};"));
+        assert!(tikz.contains("\\node (n3) at (0,3) {1};"));
+        assert_eq!(tikz.matches("\\draw").count(), 4);
+    }
+}
@@ -15,6 +15,7 @@ use crate::alg::op::{Operation, OperationSymbol, SimilarityType};
 use crate::lat::{Lattice, Order};
 use crate::lat::ordered_set::{OrderedSet, POElem, Edge};
 use crate::lat::graph_data::LatticeGraphData;
+use crate::lat::poset::Poset;
 
 /// A basic lattice structure for visualization and computation.
 ///
@@ -495,6 +496,69 @@ where
     pub fn name(&self) -> &str {
         &self.base.name
     }
+
+    /// The dual lattice, named `<name>^d`, with the order reversed.
+    ///
+    /// # Returns
+    /// * `Ok(BasicLattice<String>)` - The dual, labelled by the elements
+    /// * `Err(String)` - If the lattice cannot be built
+    pub fn dual(&self) -> Result<BasicLattice<String>, String> {
+        let dual = Poset::from_lattice(self).dual();
+        dual.to_lattice(&format!("{}^d", self.name()))
+    }
+
+    /// The direct product with another lattice, named `<name> x <other>`
+    /// and ordered coordinatewise. Its elements are labelled `(a,b)`.
+    ///
+    /// # Arguments
+    /// * `other` - The second factor
+    ///
+    /// # Returns
+    /// * `Ok(BasicLattice<String>)` - The product
+    /// * `Err(String)` - If the lattice cannot be built
+    pub fn direct_product<U>(&self, other: &BasicLattice<U>) -> Result<BasicLattice<String>, String>
+    where
+        U: Clone + PartialEq + Eq + Hash + Debug + Display + Send + Sync + 'static,
+    {
+        let product = Poset::from_lattice(self).product(&Poset::from_lattice(other));
+        product.to_lattice(&format!("{} x {}", self.name(), other.name()))
+    }
+
+    /// The ordinal sum with another lattice, named `<name> + <other>`:
+    /// `other` placed above this lattice. Labels that occur in both are
+    /// tagged `(0,a)` and `(1,b)` to keep them distinct.
+    ///
+    /// # Arguments
+    /// * `other` - The upper summand
+    ///
+    /// # Returns
+    /// * `Ok(BasicLattice<String>)` - The ordinal sum
+    /// * `Err(String)` - If the lattice cannot be built
+    pub fn ordinal_sum<U>(&self, other: &BasicLattice<U>) -> Result<BasicLattice<String>, String>
+    where
+        U: Clone + PartialEq + Eq + Hash + Debug + Display + Send + Sync + 'static,
+    {
+        let sum = Poset::from_lattice(self).ordinal_sum(&Poset::from_lattice(other));
+        sum.to_lattice(&format!("{} + {}", self.name(), other.name()))
+    }
+
+    /// The vertical (glued) sum with another lattice, named
+    /// `<name> ++ <other>`: `other` placed above this lattice with the one
+    /// of this lattice identified with the zero of `other`.
+    ///
+    /// # Arguments
+    /// * `other` - The upper summand
+    ///
+    /// # Returns
+    /// * `Ok(BasicLattice<String>)` - The vertical sum
+    /// * `Err(String)` - If the lattice cannot be built
+    pub fn vertical_sum<U>(&self, other: &BasicLattice<U>) -> Result<BasicLattice<String>, String>
+    where
+        U: Clone + PartialEq + Eq + Hash + Debug + Display + Send + Sync + 'static,
+    {
+        let sum = Poset::from_lattice(self).vertical_sum(&Poset::from_lattice(other))?;
+        sum.to_lattice(&format!("{} ++ {}", self.name(), other.name()))
+    }
 }
 
 // Implement Algebra trait for BasicLattice
@@ -0,0 +1,292 @@
+//! Free lattices.
+//!
+//! Lattice terms are terms built from variables and the operation symbols
+//! `join` and `meet` (of any arity ≥ 1), e.g. `join(x,meet(y,z))`. Two
+//! terms are equal in the free lattice exactly when each is below the other
+//! under Whitman's condition, which [`free_lattice_leq`] decides. Every
+//! element of the free lattice has a unique shortest term up to the order of
+//! the joinands and meetands, its canonical form, computed by
+//! [`lattice_canonical_form`].
+//!
+//! See Freese, Ježek and Nation, "Free Lattices", Chapter I.
+
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+
+use crate::alg::op::OperationSymbol;
+use crate::terms::{NonVariableTerm, Term, VariableImp};
+
+/// A lattice term with flattened joins and meets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum LatticeTerm {
+    Var(String),
+    Join(Vec<LatticeTerm>),
+    Meet(Vec<LatticeTerm>),
+}
+
+impl LatticeTerm {
+    pub(crate) fn from_term(term: &dyn Term) -> Result<Self, String> {
+        if term.isa_variable() {
+            return Ok(LatticeTerm::Var(term.to_string()));
+        }
+        let sym = term.leading_operation_symbol()
+            .ok_or_else(|| format!("Not a lattice term: {}", term))?;
+        let children = term.get_children().unwrap_or_default();
+        if children.is_empty() {
+            return Err(format!("Not a lattice term: {}", term));
+        }
+        let children = children.iter()
+            .map(|child| Self::from_term(child.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+        match sym.name() {
+            "join" => Ok(LatticeTerm::Join(children)),
+            "meet" => Ok(LatticeTerm::Meet(children)),
+            name => Err(format!("Not a lattice operation: {} in {}", name, term)),
+        }
+    }
+
+    pub(crate) fn to_term(&self) -> Box<dyn Term> {
+        let (name, children) = match self {
+            LatticeTerm::Var(name) => return Box::new(VariableImp::new(name)),
+            LatticeTerm::Join(children) => ("join", children),
+            LatticeTerm::Meet(children) => ("meet", children),
+        };
+        let sym = OperationSymbol::new(name, children.len() as i32, false);
+        Box::new(NonVariableTerm::new(sym, children.iter().map(|c| c.to_term()).collect()))
+    }
+
+    /// The join (`is_join`) or meet of `args`; a single argument is itself.
+    pub(crate) fn op(is_join: bool, mut args: Vec<LatticeTerm>) -> Self {
+        if args.len() == 1 {
+            return args.pop().unwrap();
+        }
+        if is_join { LatticeTerm::Join(args) } else { LatticeTerm::Meet(args) }
+    }
+
+    /// The joinands (`is_join`) or meetands of this term.
+    fn parts(&self, is_join: bool) -> &[LatticeTerm] {
+        match self {
+            LatticeTerm::Join(children) if is_join => children,
+            LatticeTerm::Meet(children) if !is_join => children,
+            _ => std::slice::from_ref(self),
+        }
+    }
+
+    fn leq(&self, other: &LatticeTerm) -> bool {
+        use LatticeTerm::*;
+        match (self, other) {
+            (Join(ss), _) => ss.iter().all(|s| s.leq(other)),
+            (_, Meet(ts)) => ts.iter().all(|t| self.leq(t)),
+            (Var(x), Var(y)) => x == y,
+            (Var(_), Join(ts)) => ts.iter().any(|t| self.leq(t)),
+            (Meet(ss), Var(_)) => ss.iter().any(|s| s.leq(other)),
+            // Whitman's condition
+            (Meet(ss), Join(ts)) => ss.iter().any(|s| s.leq(other)) || ts.iter().any(|t| self.leq(t)),
+        }
+    }
+
+    /// Compute the canonical form, assuming the children are canonical.
+    fn canonical(self) -> Self {
+        let is_join = match &self {
+            LatticeTerm::Var(_) => return self,
+            LatticeTerm::Join(_) => true,
+            LatticeTerm::Meet(_) => false,
+        };
+        let mut args: Vec<LatticeTerm> = self.parts(is_join).to_vec();
+        loop {
+            // Flatten and keep only the maximal joinands (minimal meetands),
+            // one of each.
+            args = args.iter().flat_map(|a| a.parts(is_join).to_vec()).collect();
+            let absorbed = |a: &LatticeTerm, b: &LatticeTerm| if is_join { a.leq(b) } else { b.leq(a) };
+            let keep: Vec<bool> = (0..args.len())
+                .map(|i| !(0..args.len()).any(|j| {
+                    j != i && absorbed(&args[i], &args[j]) && (!absorbed(&args[j], &args[i]) || j < i)
+                }))
+                .collect();
+            args = args.into_iter().zip(keep).filter(|(_, k)| *k).map(|(a, _)| a).collect();
+
+            // A joinand ∧ t_j with some t_j ≤ the whole join is replaced by
+            // that t_j, and dually.
+            let whole = Self::op(is_join, args.clone());
+            let mut changed = false;
+            for arg in args.iter_mut() {
+                let replacement = arg.parts(!is_join).iter()
+                    .find(|t| arg.parts(!is_join).len() > 1 && absorbed(t, &whole))
+                    .cloned();
+                if let Some(t) = replacement {
+                    *arg = t;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        args.sort_by(compare);
+        Self::op(is_join, args)
+    }
+
+    pub(crate) fn canonical_form(&self) -> Self {
+        match self {
+            LatticeTerm::Var(_) => self.clone(),
+            LatticeTerm::Join(children) => {
+                LatticeTerm::Join(children.iter().map(|c| c.canonical_form()).collect()).canonical()
+            }
+            LatticeTerm::Meet(children) => {
+                LatticeTerm::Meet(children.iter().map(|c| c.canonical_form()).collect()).canonical()
+            }
+        }
+    }
+
+    fn length(&self) -> usize {
+        match self {
+            LatticeTerm::Var(_) => 1,
+            LatticeTerm::Join(children) | LatticeTerm::Meet(children) => {
+                1 + children.iter().map(|c| c.length()).sum::<usize>()
+            }
+        }
+    }
+}
+
+impl Display for LatticeTerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (name, children) = match self {
+            LatticeTerm::Var(name) => return write!(f, "{}", name),
+            LatticeTerm::Join(children) => ("join", children),
+            LatticeTerm::Meet(children) => ("meet", children),
+        };
+        write!(f, "{}(", name)?;
+        for (i, child) in children.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", child)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Order canonical terms by length and then by their string form.
+fn compare(a: &LatticeTerm, b: &LatticeTerm) -> Ordering {
+    a.length().cmp(&b.length()).then_with(|| a.to_string().cmp(&b.to_string()))
+}
+
+/// Decide whether `s ≤ t` in the free lattice, using Whitman's condition:
+/// `∧ s_i ≤ ∨ t_j` iff some `s_i ≤ ∨ t_j` or `∧ s_i ≤` some `t_j`.
+///
+/// # Arguments
+/// * `s` - A lattice term
+/// * `t` - A lattice term
+///
+/// # Returns
+/// * `Ok(bool)` - Whether `s ≤ t` holds in every lattice
+/// * `Err(String)` - If a term uses an operation other than `join` and `meet`
+///
+/// # Examples
+/// ```
+/// use uacalc::lat::free_lattice::free_lattice_leq;
+/// use uacalc::terms::string_to_term;
+///
+/// let s = string_to_term("meet(x,join(y,z))").unwrap();
+/// let t = string_to_term("join(meet(x,y),meet(x,z))").unwrap();
+/// // The distributive law fails in the free lattice
+/// assert!(free_lattice_leq(t.as_ref(), s.as_ref()).unwrap());
+/// assert!(!free_lattice_leq(s.as_ref(), t.as_ref()).unwrap());
+/// ```
+pub fn free_lattice_leq(s: &dyn Term, t: &dyn Term) -> Result<bool, String> {
+    Ok(LatticeTerm::from_term(s)?.leq(&LatticeTerm::from_term(t)?))
+}
+
+/// Decide whether two lattice terms are equal in the free lattice.
+///
+/// # Arguments
+/// * `s` - A lattice term
+/// * `t` - A lattice term
+///
+/// # Returns
+/// * `Ok(bool)` - Whether `s = t` holds in every lattice
+/// * `Err(String)` - If a term uses an operation other than `join` and `meet`
+pub fn free_lattice_eq(s: &dyn Term, t: &dyn Term) -> Result<bool, String> {
+    let s = LatticeTerm::from_term(s)?;
+    let t = LatticeTerm::from_term(t)?;
+    Ok(s.leq(&t) && t.leq(&s))
+}
+
+/// Compute the canonical form of a lattice term: the shortest term for the
+/// same element of the free lattice. Joins and meets are flattened and
+/// their arguments are sorted by length and then by their string form, so
+/// two terms are equal in the free lattice exactly when their canonical
+/// forms are identical.
+///
+/// # Arguments
+/// * `t` - A lattice term
+///
+/// # Returns
+/// * `Ok(Box<dyn Term>)` - The canonical form
+/// * `Err(String)` - If the term uses an operation other than `join` and `meet`
+///
+/// # Examples
+/// ```
+/// use uacalc::lat::free_lattice::lattice_canonical_form;
+/// use uacalc::terms::string_to_term;
+///
+/// let t = string_to_term("join(x,meet(x,y),join(y,x))").unwrap();
+/// assert_eq!(lattice_canonical_form(t.as_ref()).unwrap().to_string(), "join(x,y)");
+/// ```
+pub fn lattice_canonical_form(t: &dyn Term) -> Result<Box<dyn Term>, String> {
+    Ok(LatticeTerm::from_term(t)?.canonical_form().to_term())
+}
+
+/// Generate elements of the free lattice on `gens` generators, named
+/// `x0, x1, ...`, as canonical terms.
+///
+/// Elements are produced level by level: the generators, then the joins and
+/// meets of pairs of elements found so far, until no new element appears or
+/// `max_size` elements have been found. The free lattice on 3 or more
+/// generators is infinite, so there `max_size` is always reached. The
+/// computation checks the progress token installed on the current thread
+/// for cancellation.
+///
+/// # Arguments
+/// * `gens` - The number of generators
+/// * `max_size` - The most elements to return
+///
+/// # Returns
+/// * `Ok(Vec<Box<dyn Term>>)` - The elements in the order they were found
+/// * `Err(String)` - If the computation was cancelled
+///
+/// # Examples
+/// ```
+/// use uacalc::lat::free_lattice::free_lattice;
+///
+/// // The free lattice on 2 generators has 4 elements
+/// assert_eq!(free_lattice(2, 100).unwrap().len(), 4);
+/// assert_eq!(free_lattice(3, 50).unwrap().len(), 50);
+/// ```
+pub fn free_lattice(gens: usize, max_size: usize) -> Result<Vec<Box<dyn Term>>, String> {
+    let mut elems: Vec<LatticeTerm> = (0..gens)
+        .map(|i| LatticeTerm::Var(format!("x{}", i)))
+        .take(max_size)
+        .collect();
+    let mut seen: std::collections::HashSet<String> = elems.iter().map(|e| e.to_string()).collect();
+    let mut done = 0;
+    'levels: while done < elems.len() && elems.len() < max_size {
+        let level_end = elems.len();
+        for i in 0..level_end {
+            crate::progress::check_cancelled()?;
+            for j in done.max(i + 1)..level_end {
+                for is_join in [true, false] {
+                    let term = LatticeTerm::op(is_join, vec![elems[i].clone(), elems[j].clone()]).canonical();
+                    if seen.insert(term.to_string()) {
+                        elems.push(term);
+                        if elems.len() >= max_size {
+                            break 'levels;
+                        }
+                    }
+                }
+            }
+        }
+        done = level_end;
+    }
+    Ok(elems.iter().map(|e| e.to_term()).collect())
+}
@@ -130,4 +130,80 @@ pub trait Lattice<E>: Algebra<UniverseItem = E> + Order<E> {
     /// # Returns
     /// The meet of all elements in the list
     fn meet_list(&self, args: &[E]) -> E;
-}
\ No newline at end of file
+
+    /// Test if the lattice is upper semimodular: whenever `a` and `b` cover
+    /// `c`, `a ∨ b` covers `a` and `b`.
+    ///
+    /// This and the following tests work on the elements returned by
+    /// `Algebra::universe`; a lattice computing its universe lazily, such
+    /// as a `CongruenceLattice`, overrides them to compute it first.
+    fn is_upper_semimodular(&self) -> bool {
+        crate::lat::Poset::from_order(&self.universe().collect::<Vec<_>>(), self).is_upper_semimodular()
+    }
+
+    /// Test if the lattice is lower semimodular: whenever `c` covers `a` and
+    /// `b`, `a` and `b` cover `a ∧ b`.
+    fn is_lower_semimodular(&self) -> bool {
+        crate::lat::Poset::from_order(&self.universe().collect::<Vec<_>>(), self).is_lower_semimodular()
+    }
+
+    /// Test if the lattice is modular, that is, both upper and lower
+    /// semimodular.
+    fn is_modular(&self) -> bool {
+        crate::lat::Poset::from_order(&self.universe().collect::<Vec<_>>(), self).is_modular()
+    }
+
+    /// Test if the lattice is meet semidistributive: `a ∧ b = a ∧ c`
+    /// implies `a ∧ b = a ∧ (b ∨ c)`.
+    fn is_meet_semidistributive(&self) -> bool {
+        crate::lat::Poset::from_order(&self.universe().collect::<Vec<_>>(), self).is_meet_semidistributive()
+    }
+
+    /// Test if the lattice is join semidistributive: `a ∨ b = a ∨ c`
+    /// implies `a ∨ b = a ∨ (b ∧ c)`.
+    fn is_join_semidistributive(&self) -> bool {
+        crate::lat::Poset::from_order(&self.universe().collect::<Vec<_>>(), self).is_join_semidistributive()
+    }
+
+    /// Find elements `(a, b, c)` with `a ∧ b = a ∧ c` but
+    /// `a ∧ (b ∨ c) ≠ a ∧ b`, witnessing that the lattice is not meet
+    /// semidistributive.
+    fn meet_semidistributivity_failure(&self) -> Option<(E, E, E)>
+    where
+        E: Clone,
+    {
+        let univ: Vec<E> = self.universe().collect();
+        crate::lat::Poset::from_order(&univ, self)
+            .meet_semidistributivity_failure()
+            .map(|(a, b, c)| (univ[a].clone(), univ[b].clone(), univ[c].clone()))
+    }
+
+    /// Find elements `(a, b, c)` with `a ∨ b = a ∨ c` but
+    /// `a ∨ (b ∧ c) ≠ a ∨ b`, witnessing that the lattice is not join
+    /// semidistributive.
+    fn join_semidistributivity_failure(&self) -> Option<(E, E, E)>
+    where
+        E: Clone,
+    {
+        let univ: Vec<E> = self.universe().collect();
+        crate::lat::Poset::from_order(&univ, self)
+            .join_semidistributivity_failure()
+            .map(|(a, b, c)| (univ[a].clone(), univ[b].clone(), univ[c].clone()))
+    }
+
+    /// Test if the lattice is lower bounded: the D relation on its join
+    /// irreducibles has no cycle.
+    fn is_lower_bounded_lattice(&self) -> bool {
+        crate::lat::Poset::from_order(&self.universe().collect::<Vec<_>>(), self).is_lower_bounded()
+    }
+
+    /// Test if every interval of the lattice is complemented.
+    fn is_relatively_complemented(&self) -> bool {
+        crate::lat::Poset::from_order(&self.universe().collect::<Vec<_>>(), self).is_relatively_complemented()
+    }
+
+    /// Test if the lattice is geometric: atomistic and upper semimodular.
+    fn is_geometric(&self) -> bool {
+        crate::lat::Poset::from_order(&self.universe().collect::<Vec<_>>(), self).is_geometric()
+    }
+}
@@ -67,10 +67,14 @@ pub use small_lattice::{SmallLattice, DiamondLattice, BooleanLattice};
 pub mod ordered_set;
 pub mod graph_data;
 pub mod basic_lattice;
+pub mod free_lattice;
+pub mod poset;
 
 pub use ordered_set::{OrderedSet, POElem, Edge};
 pub use graph_data::{LatticeGraphData, GraphNode, GraphEdge};
 pub use basic_lattice::BasicLattice;
+pub use poset::{as_lattice, as_semilattice, find_lattice_reduct, is_lattice_ordered, Poset};
+pub use free_lattice::{free_lattice, free_lattice_eq, free_lattice_leq, lattice_canonical_form};
 
 /// Utility functions for creating and manipulating lattices.
 /// 
@@ -479,8 +483,83 @@ pub mod lattices {
         }))
     }
     
+    /// Name a small lattice by matching it against a library of standard
+    /// lattices.
+    ///
+    /// The names are `"C<n>"` for the n element chain, `"M<n>"` for the
+    /// lattice of height 2 with n ≥ 3 atoms (so `"M3"` is the diamond),
+    /// `"N5"` for the pentagon and `"2^<k>"` for the Boolean lattice with k
+    /// atoms (k ≥ 2). Anything else, including an order that is not a
+    /// bounded poset, is `"unknown"`.
+    ///
+    /// # Arguments
+    /// * `elems` - The elements of the lattice
+    /// * `order` - The order of the lattice
+    ///
+    /// # Returns
+    /// The name of the lattice, or `"unknown"`
+    ///
+    /// # Examples
+    /// ```
+    /// use uacalc::lat::{lattices::identify_lattice, DivisibilityOrder};
+    ///
+    /// assert_eq!(identify_lattice(&[1, 2, 4, 8], &DivisibilityOrder), "C4");
+    /// assert_eq!(identify_lattice(&[1, 2, 3, 5, 6, 10, 15, 30], &DivisibilityOrder), "2^3");
+    /// ```
+    pub fn identify_lattice<E, O>(elems: &[E], order: &O) -> String
+    where
+        O: crate::lat::Order<E> + ?Sized,
+    {
+        let n = elems.len();
+        let leq: Vec<Vec<bool>> = elems.iter()
+            .map(|a| elems.iter().map(|b| order.leq(a, b)).collect())
+            .collect();
+        let bottom = (0..n).find(|&i| (0..n).all(|j| leq[i][j]));
+        let top = (0..n).find(|&i| (0..n).all(|j| leq[j][i]));
+        let (bottom, top) = match (bottom, top) {
+            (Some(bottom), Some(top)) => (bottom, top),
+            _ => return "unknown".to_string(),
+        };
+
+        if (0..n).all(|i| (0..n).all(|j| leq[i][j] || leq[j][i])) {
+            return format!("C{}", n);
+        }
+
+        let middle: Vec<usize> = (0..n).filter(|&i| i != bottom && i != top).collect();
+        let comparable_pairs = middle.iter().enumerate()
+            .flat_map(|(k, &i)| middle[k + 1..].iter().map(move |&j| (i, j)))
+            .filter(|&(i, j)| leq[i][j] || leq[j][i])
+            .count();
+        if comparable_pairs == 0 {
+            return if middle.len() == 2 { "2^2".to_string() } else { format!("M{}", middle.len()) };
+        }
+        if n == 5 && comparable_pairs == 1 {
+            return "N5".to_string();
+        }
+
+        // Boolean: the sets of atoms below the elements are exactly the
+        // subsets of the atoms, ordered by inclusion.
+        let atoms: Vec<usize> = middle.iter().copied()
+            .filter(|&i| middle.iter().all(|&j| j == i || !leq[j][i]))
+            .collect();
+        if atoms.len() < usize::BITS as usize && n == 1 << atoms.len() {
+            let below: Vec<usize> = (0..n)
+                .map(|i| atoms.iter().enumerate()
+                    .filter(|&(_, &a)| leq[a][i])
+                    .fold(0, |set, (k, _)| set | (1 << k)))
+                .collect();
+            let boolean = (0..n).all(|i| (0..n).all(|j| {
+                leq[i][j] == (below[i] & below[j] == below[i]) && (i == j || below[i] != below[j])
+            }));
+            if boolean {
+                return format!("2^{}", atoms.len());
+            }
+        }
+        "unknown".to_string()
+    }
+
     /// Create the dual of a basic lattice.
-    /// 
+    ///
     /// The dual lattice reverses the order (leq becomes reversed) and swaps
     /// join and meet operations.
     /// 
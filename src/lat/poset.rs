@@ -0,0 +1,1062 @@
+/*! Finite posets on the indices `0..n`.
+ *
+ * A `Poset` stores its order as a matrix, so order queries are constant
+ * time, and each element carries a label for display and for conversion
+ * to and from `OrderedSet` and `BasicLattice`.
+ */
+
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
+use std::sync::Arc;
+
+use crate::alg::op::Operation;
+use crate::alg::SmallAlgebra;
+use crate::lat::{BasicLattice, OrderedSet};
+use crate::terms::compiled_term::operation_table;
+
+/// A join or meet table indexed by element.
+type Table = Vec<Vec<usize>>;
+
+/// A finite partially ordered set with elements `0..n`.
+///
+/// # Examples
+/// ```
+/// use uacalc::lat::Poset;
+///
+/// // The divisors of 12
+/// let divisors = [1, 2, 3, 4, 6, 12];
+/// let poset = Poset::from_leq(None, divisors.len(), |i, j| divisors[j] % divisors[i] == 0).unwrap();
+/// assert_eq!(poset.height(), 4);
+/// assert_eq!(poset.width(), 2);
+/// assert_eq!(poset.mobius(0, 5), 0);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Poset {
+    name: Option<String>,
+    labels: Vec<String>,
+    leq: Vec<Vec<bool>>,
+}
+
+impl Poset {
+    /// Create a poset on `0..n` from a ≤ predicate.
+    ///
+    /// # Arguments
+    /// * `name` - Optional name for the poset
+    /// * `n` - The number of elements
+    /// * `leq` - The order; it must be reflexive, antisymmetric and transitive
+    ///
+    /// # Returns
+    /// * `Ok(Poset)` - The poset, with the indices as labels
+    /// * `Err(String)` - If `leq` is not a partial order
+    pub fn from_leq(name: Option<String>, n: usize, leq: impl Fn(usize, usize) -> bool) -> Result<Self, String> {
+        let matrix: Vec<Vec<bool>> = (0..n).map(|i| (0..n).map(|j| leq(i, j)).collect()).collect();
+        for i in 0..n {
+            if !matrix[i][i] {
+                return Err(format!("The order is not reflexive at {}", i));
+            }
+            for j in 0..n {
+                if i != j && matrix[i][j] && matrix[j][i] {
+                    return Err(format!("The order is not antisymmetric: {} and {}", i, j));
+                }
+                for k in 0..n {
+                    if matrix[i][j] && matrix[j][k] && !matrix[i][k] {
+                        return Err(format!("The order is not transitive: {} ≤ {} ≤ {}", i, j, k));
+                    }
+                }
+            }
+        }
+        Ok(Poset { name, labels: (0..n).map(|i| i.to_string()).collect(), leq: matrix })
+    }
+
+    /// Create a poset from a covering relation.
+    ///
+    /// The order is the reflexive transitive closure of the covers, so
+    /// `upper_covers` may also list non-covering pairs.
+    ///
+    /// # Arguments
+    /// * `name` - Optional name for the poset
+    /// * `labels` - A label for each element
+    /// * `upper_covers` - The indices of the upper covers of each element
+    ///
+    /// # Returns
+    /// * `Ok(Poset)` - The poset
+    /// * `Err(String)` - If the sizes differ, an index is out of range or
+    ///   the covers contain a cycle
+    pub fn from_upper_covers(
+        name: Option<String>,
+        labels: Vec<String>,
+        upper_covers: &[Vec<usize>],
+    ) -> Result<Self, String> {
+        let n = labels.len();
+        if upper_covers.len() != n {
+            return Err(format!("{} labels but {} lists of upper covers", n, upper_covers.len()));
+        }
+        let mut leq = vec![vec![false; n]; n];
+        for (i, covers) in upper_covers.iter().enumerate() {
+            leq[i][i] = true;
+            for &j in covers {
+                if j >= n {
+                    return Err(format!("Upper cover {} of {} is out of range", j, i));
+                }
+                leq[i][j] = true;
+            }
+        }
+        for k in 0..n {
+            let above_k = leq[k].clone();
+            for row in leq.iter_mut().filter(|row| row[k]) {
+                for (entry, &above) in row.iter_mut().zip(&above_k) {
+                    *entry |= above;
+                }
+            }
+        }
+        if let Some((i, j)) = (0..n).flat_map(|i| (0..n).map(move |j| (i, j))).find(|&(i, j)| i != j && leq[i][j] && leq[j][i]) {
+            return Err(format!("The covers contain a cycle through {} and {}", i, j));
+        }
+        Ok(Poset { name, labels, leq })
+    }
+
+    /// Create the poset of some elements under an order, without checking
+    /// that it is a partial order.
+    pub(crate) fn from_order<E, O>(elems: &[E], order: &O) -> Self
+    where
+        O: crate::lat::Order<E> + ?Sized,
+    {
+        Poset {
+            name: None,
+            labels: (0..elems.len()).map(|i| i.to_string()).collect(),
+            leq: elems.iter().map(|a| elems.iter().map(|b| order.leq(a, b)).collect()).collect(),
+        }
+    }
+
+    /// Create a poset from an `OrderedSet`, labelling the elements by their
+    /// underlying objects.
+    pub fn from_ordered_set<T>(poset: &OrderedSet<T>) -> Self
+    where
+        T: Clone + PartialEq + Eq + Hash + Debug + Display + Send + Sync + 'static,
+    {
+        let univ = poset.univ();
+        Poset {
+            name: poset.name().map(|s| s.to_string()),
+            labels: univ.iter().map(|e| e.get_underlying_object().to_string()).collect(),
+            leq: univ.iter().map(|a| univ.iter().map(|b| poset.leq(a, b)).collect()).collect(),
+        }
+    }
+
+    /// Create the poset of a `BasicLattice`.
+    pub fn from_lattice<T>(lat: &BasicLattice<T>) -> Self
+    where
+        T: Clone + PartialEq + Eq + Hash + Debug + Display + Send + Sync + 'static,
+    {
+        let mut poset = Self::from_ordered_set(lat.get_poset());
+        poset.name = Some(lat.name().to_string());
+        poset
+    }
+
+    /// Get the name of the poset.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Get the labels of the elements.
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    /// Set the labels of the elements.
+    ///
+    /// # Returns
+    /// * `Ok(())` - The labels were set
+    /// * `Err(String)` - If the number of labels is wrong
+    pub fn set_labels(&mut self, labels: Vec<String>) -> Result<(), String> {
+        if labels.len() != self.cardinality() {
+            return Err(format!("{} labels for {} elements", labels.len(), self.cardinality()));
+        }
+        self.labels = labels;
+        Ok(())
+    }
+
+    /// The number of elements.
+    pub fn cardinality(&self) -> usize {
+        self.leq.len()
+    }
+
+    /// Check if `a ≤ b`.
+    pub fn leq(&self, a: usize, b: usize) -> bool {
+        self.leq[a][b]
+    }
+
+    fn less(&self, a: usize, b: usize) -> bool {
+        a != b && self.leq[a][b]
+    }
+
+    /// The indices of the upper covers of each element.
+    pub fn upper_covers(&self) -> Vec<Vec<usize>> {
+        let n = self.cardinality();
+        (0..n)
+            .map(|a| (0..n).filter(|&b| self.less(a, b) && !(0..n).any(|c| self.less(a, c) && self.less(c, b))).collect())
+            .collect()
+    }
+
+    /// The indices of the lower covers of each element.
+    pub fn lower_covers(&self) -> Vec<Vec<usize>> {
+        self.dual().upper_covers()
+    }
+
+    /// The minimal elements.
+    pub fn minimals(&self) -> Vec<usize> {
+        let n = self.cardinality();
+        (0..n).filter(|&a| !(0..n).any(|b| self.less(b, a))).collect()
+    }
+
+    /// The maximal elements.
+    pub fn maximals(&self) -> Vec<usize> {
+        let n = self.cardinality();
+        (0..n).filter(|&a| !(0..n).any(|b| self.less(a, b))).collect()
+    }
+
+    /// A linear extension: the elements listed so that every element comes
+    /// after all the elements below it.
+    pub fn linear_extension(&self) -> Vec<usize> {
+        let n = self.cardinality();
+        let mut order: Vec<usize> = (0..n).collect();
+        // The number of elements strictly below is a valid sort key.
+        order.sort_by_key(|&a| (0..n).filter(|&b| self.less(b, a)).count());
+        order
+    }
+
+    /// All linear extensions, in lexicographic order.
+    ///
+    /// There can be up to n! of them. The computation checks the progress
+    /// token installed on the current thread for cancellation.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Vec<usize>>)` - The linear extensions
+    /// * `Err(String)` - If the computation was cancelled
+    pub fn linear_extensions(&self) -> Result<Vec<Vec<usize>>, String> {
+        let mut result = Vec::new();
+        let mut prefix = Vec::new();
+        let mut used = vec![false; self.cardinality()];
+        self.extend_linear(&mut prefix, &mut used, &mut result)?;
+        Ok(result)
+    }
+
+    fn extend_linear(&self, prefix: &mut Vec<usize>, used: &mut Vec<bool>, result: &mut Vec<Vec<usize>>) -> Result<(), String> {
+        let n = self.cardinality();
+        if prefix.len() == n {
+            crate::progress::check_cancelled()?;
+            result.push(prefix.clone());
+            return Ok(());
+        }
+        for a in 0..n {
+            if !used[a] && (0..n).all(|b| used[b] || !self.less(b, a)) {
+                used[a] = true;
+                prefix.push(a);
+                self.extend_linear(prefix, used, result)?;
+                prefix.pop();
+                used[a] = false;
+            }
+        }
+        Ok(())
+    }
+
+    /// All maximal chains, each listed from the bottom up.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Vec<usize>>)` - The maximal chains
+    /// * `Err(String)` - If the computation was cancelled
+    pub fn maximal_chains(&self) -> Result<Vec<Vec<usize>>, String> {
+        let covers = self.upper_covers();
+        let mut result = Vec::new();
+        let mut stack: Vec<Vec<usize>> = self.minimals().into_iter().map(|a| vec![a]).collect();
+        while let Some(chain) = stack.pop() {
+            crate::progress::check_cancelled()?;
+            let last = *chain.last().unwrap();
+            if covers[last].is_empty() {
+                result.push(chain);
+                continue;
+            }
+            for &b in covers[last].iter().rev() {
+                let mut longer = chain.clone();
+                longer.push(b);
+                stack.push(longer);
+            }
+        }
+        result.sort();
+        Ok(result)
+    }
+
+    /// All maximal antichains, each sorted.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Vec<usize>>)` - The maximal antichains
+    /// * `Err(String)` - If the computation was cancelled
+    pub fn maximal_antichains(&self) -> Result<Vec<Vec<usize>>, String> {
+        // Maximal antichains are the maximal cliques of the
+        // incomparability graph (Bron–Kerbosch).
+        let mut result = Vec::new();
+        let all: Vec<usize> = (0..self.cardinality()).collect();
+        self.bron_kerbosch(&mut Vec::new(), all, Vec::new(), &mut result)?;
+        result.sort();
+        Ok(result)
+    }
+
+    fn bron_kerbosch(
+        &self,
+        clique: &mut Vec<usize>,
+        candidates: Vec<usize>,
+        excluded: Vec<usize>,
+        result: &mut Vec<Vec<usize>>,
+    ) -> Result<(), String> {
+        crate::progress::check_cancelled()?;
+        if candidates.is_empty() {
+            if excluded.is_empty() {
+                let mut antichain = clique.clone();
+                antichain.sort();
+                result.push(antichain);
+            }
+            return Ok(());
+        }
+        let incomparable = |a: usize, b: usize| !self.leq[a][b] && !self.leq[b][a];
+        let mut candidates = candidates;
+        let mut excluded = excluded;
+        while let Some(a) = candidates.pop() {
+            clique.push(a);
+            self.bron_kerbosch(
+                clique,
+                candidates.iter().copied().filter(|&b| incomparable(a, b)).collect(),
+                excluded.iter().copied().filter(|&b| incomparable(a, b)).collect(),
+                result,
+            )?;
+            clique.pop();
+            excluded.push(a);
+        }
+        Ok(())
+    }
+
+    /// The number of elements of a longest chain (0 for the empty poset).
+    pub fn height(&self) -> usize {
+        let n = self.cardinality();
+        let mut longest = vec![1; n];
+        for &a in &self.linear_extension() {
+            longest[a] = 1 + (0..n).filter(|&b| self.less(b, a)).map(|b| longest[b]).max().unwrap_or(0);
+        }
+        longest.into_iter().max().unwrap_or(0)
+    }
+
+    /// The number of elements of a largest antichain.
+    ///
+    /// By Dilworth's theorem this is the number of elements minus the size
+    /// of a maximum matching between the elements and the elements above
+    /// them.
+    pub fn width(&self) -> usize {
+        let n = self.cardinality();
+        let mut matched_to: Vec<Option<usize>> = vec![None; n];
+        let mut matching = 0;
+        for a in 0..n {
+            let mut visited = vec![false; n];
+            if self.augment(a, &mut visited, &mut matched_to) {
+                matching += 1;
+            }
+        }
+        n - matching
+    }
+
+    fn augment(&self, a: usize, visited: &mut [bool], matched_to: &mut [Option<usize>]) -> bool {
+        for b in 0..self.cardinality() {
+            if self.less(a, b) && !visited[b] {
+                visited[b] = true;
+                if matched_to[b].is_none_or(|c| self.augment(c, visited, matched_to)) {
+                    matched_to[b] = Some(a);
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// The Möbius function `μ(a, b)`: 1 if `a = b`, `-Σ μ(a, c)` over
+    /// `a ≤ c < b` if `a < b`, and 0 otherwise.
+    pub fn mobius(&self, a: usize, b: usize) -> i64 {
+        if !self.leq[a][b] {
+            return 0;
+        }
+        let mut mu: HashMap<usize, i64> = HashMap::new();
+        for &c in &self.linear_extension() {
+            if self.leq[a][c] && self.leq[c][b] {
+                let value = if c == a {
+                    1
+                } else {
+                    -mu.iter().filter(|&(&d, _)| self.less(d, c)).map(|(_, &m)| m).sum::<i64>()
+                };
+                mu.insert(c, value);
+            }
+        }
+        mu[&b]
+    }
+
+    /// The dual poset, with the order reversed.
+    pub fn dual(&self) -> Poset {
+        let n = self.cardinality();
+        Poset {
+            name: self.name.as_ref().map(|name| format!("{}^d", name)),
+            labels: self.labels.clone(),
+            leq: (0..n).map(|i| (0..n).map(|j| self.leq[j][i]).collect()).collect(),
+        }
+    }
+
+    /// The direct product, ordered coordinatewise. The pair `(a, b)` is the
+    /// element `a * other.cardinality() + b`.
+    pub fn product(&self, other: &Poset) -> Poset {
+        let m = other.cardinality();
+        let n = self.cardinality() * m;
+        let name = match (&self.name, &other.name) {
+            (Some(a), Some(b)) => Some(format!("{} x {}", a, b)),
+            _ => None,
+        };
+        Poset {
+            name,
+            labels: (0..n).map(|i| format!("({},{})", self.labels[i / m], other.labels[i % m])).collect(),
+            leq: (0..n)
+                .map(|i| (0..n).map(|j| self.leq[i / m][j / m] && other.leq[i % m][j % m]).collect())
+                .collect(),
+        }
+    }
+
+    /// The ordinal sum: `other` placed above `self`, so every element of
+    /// `self` is below every element of `other`. The elements of `other`
+    /// follow those of `self`.
+    pub fn ordinal_sum(&self, other: &Poset) -> Poset {
+        let m = self.cardinality();
+        let n = m + other.cardinality();
+        Poset {
+            name: Self::sum_name(self, other, "+"),
+            labels: Self::sum_labels(self, other, None),
+            leq: (0..n)
+                .map(|i| (0..n).map(|j| match (i < m, j < m) {
+                    (true, true) => self.leq[i][j],
+                    (false, false) => other.leq[i - m][j - m],
+                    (lower, _) => lower,
+                }).collect())
+                .collect(),
+        }
+    }
+
+    /// The vertical (glued) sum: `other` placed above `self` with the top
+    /// of `self` identified with the bottom of `other`. The glued element
+    /// keeps its index and label in `self`, and the other elements of
+    /// `other` follow those of `self`.
+    ///
+    /// # Returns
+    /// * `Ok(Poset)` - The vertical sum
+    /// * `Err(String)` - If `self` has no greatest element or `other` has no
+    ///   least element
+    pub fn vertical_sum(&self, other: &Poset) -> Result<Poset, String> {
+        match self.maximals()[..] {
+            [_] => {}
+            _ => return Err("The lower poset has no greatest element".to_string()),
+        }
+        let bottom = match other.minimals()[..] {
+            [bottom] => bottom,
+            _ => return Err("The upper poset has no least element".to_string()),
+        };
+        // The index in `other` of each element above the top of `self`,
+        // with the glued element first.
+        let upper: Vec<usize> = std::iter::once(bottom)
+            .chain((0..other.cardinality()).filter(|&j| j != bottom))
+            .collect();
+        let m = self.cardinality();
+        let pos = |i: usize| if i < m { None } else { Some(upper[i - m + 1]) };
+        let n = m + upper.len() - 1;
+        let leq = (0..n)
+            .map(|i| (0..n).map(|j| match (pos(i), pos(j)) {
+                (None, None) => self.leq[i][j],
+                (Some(a), Some(b)) => other.leq[a][b],
+                (None, Some(_)) => true,
+                (Some(_), None) => false,
+            }).collect())
+            .collect();
+        Ok(Poset {
+            name: Self::sum_name(self, other, "++"),
+            labels: Self::sum_labels(self, other, Some(bottom)),
+            leq,
+        })
+    }
+
+    fn sum_name(lower: &Poset, upper: &Poset, op: &str) -> Option<String> {
+        match (&lower.name, &upper.name) {
+            (Some(a), Some(b)) => Some(format!("{} {} {}", a, op, b)),
+            _ => None,
+        }
+    }
+
+    /// The labels of `lower` followed by those of `upper` except `skip`.
+    /// If the two sets of labels overlap they are tagged `(0,a)` and
+    /// `(1,b)` to keep them distinct.
+    fn sum_labels(lower: &Poset, upper: &Poset, skip: Option<usize>) -> Vec<String> {
+        let upper_labels: Vec<&String> = upper.labels.iter()
+            .enumerate()
+            .filter(|&(j, _)| Some(j) != skip)
+            .map(|(_, l)| l)
+            .collect();
+        if upper_labels.iter().all(|l| !lower.labels.contains(l)) {
+            return lower.labels.iter().chain(upper_labels).cloned().collect();
+        }
+        lower.labels.iter()
+            .map(|l| format!("(0,{})", l))
+            .chain(upper_labels.iter().map(|l| format!("(1,{})", l)))
+            .collect()
+    }
+
+    /// Check whether every pair of elements has a join and a meet.
+    pub fn is_lattice(&self) -> bool {
+        self.lattice_tables().is_some()
+    }
+
+    /// The join and meet tables, or `None` if this is not a lattice.
+    pub(crate) fn lattice_tables(&self) -> Option<(Table, Table)> {
+        let n = self.cardinality();
+        if n == 0 {
+            return None;
+        }
+        // In a linear extension the join of a and b is the first upper
+        // bound, and it must be below all the later ones; dually for meets.
+        let ext = self.linear_extension();
+        let bound = |order: &mut dyn Iterator<Item = &usize>, a: usize, b: usize, up: bool| {
+            let is_bound = |c: usize| if up { self.leq[a][c] && self.leq[b][c] } else { self.leq[c][a] && self.leq[c][b] };
+            let mut bounds = order.copied().filter(|&c| is_bound(c));
+            let first = bounds.next()?;
+            bounds
+                .all(|c| if up { self.leq[first][c] } else { self.leq[c][first] })
+                .then_some(first)
+        };
+        let mut join = vec![vec![0; n]; n];
+        let mut meet = vec![vec![0; n]; n];
+        for a in 0..n {
+            for b in a..n {
+                join[a][b] = bound(&mut ext.iter(), a, b, true)?;
+                meet[a][b] = bound(&mut ext.iter().rev(), a, b, false)?;
+                join[b][a] = join[a][b];
+                meet[b][a] = meet[a][b];
+            }
+        }
+        Some((join, meet))
+    }
+
+    /// Check whether this is an upper semimodular lattice: whenever `a` and
+    /// `b` cover `c`, `a ∨ b` covers `a` and `b`.
+    pub fn is_upper_semimodular(&self) -> bool {
+        let (join, _) = match self.lattice_tables() {
+            Some(tables) => tables,
+            None => return false,
+        };
+        let covers = self.upper_covers();
+        covers.iter().all(|cs| {
+            cs.iter().enumerate().all(|(i, &a)| {
+                cs[i + 1..].iter().all(|&b| {
+                    let j = join[a][b];
+                    covers[a].contains(&j) && covers[b].contains(&j)
+                })
+            })
+        })
+    }
+
+    /// Check whether this is a lower semimodular lattice: whenever `c`
+    /// covers `a` and `b`, `a` and `b` cover `a ∧ b`.
+    pub fn is_lower_semimodular(&self) -> bool {
+        self.dual().is_upper_semimodular()
+    }
+
+    /// Check whether this is a modular lattice. A finite lattice is modular
+    /// if and only if it is upper and lower semimodular.
+    pub fn is_modular(&self) -> bool {
+        self.is_upper_semimodular() && self.is_lower_semimodular()
+    }
+
+    /// Check whether this is a distributive lattice: every join
+    /// irreducible element is join prime.
+    pub fn is_distributive(&self) -> bool {
+        let (join, _) = match self.lattice_tables() {
+            Some(tables) => tables,
+            None => return false,
+        };
+        let lower = self.lower_covers();
+        let n = self.cardinality();
+        (0..n).filter(|&p| lower[p].len() == 1).all(|p| {
+            (0..n).all(|a| (0..n).all(|b| !self.leq[p][join[a][b]] || self.leq[p][a] || self.leq[p][b]))
+        })
+    }
+
+    /// Find a failure of meet semidistributivity: elements `(a, b, c)`,
+    /// with `b < c` as indices, such that `a ∧ b = a ∧ c` but
+    /// `a ∧ (b ∨ c) ≠ a ∧ b`. `None` if there is none or this is not a
+    /// lattice.
+    pub fn meet_semidistributivity_failure(&self) -> Option<(usize, usize, usize)> {
+        let (join, meet) = self.lattice_tables()?;
+        let n = self.cardinality();
+        (0..n).find_map(|a| {
+            (0..n).find_map(|b| {
+                (b + 1..n)
+                    .find(|&c| meet[a][b] == meet[a][c] && meet[a][join[b][c]] != meet[a][b])
+                    .map(|c| (a, b, c))
+            })
+        })
+    }
+
+    /// Find a failure of join semidistributivity: elements `(a, b, c)`,
+    /// with `b < c` as indices, such that `a ∨ b = a ∨ c` but
+    /// `a ∨ (b ∧ c) ≠ a ∨ b`. `None` if there is none or this is not a
+    /// lattice.
+    pub fn join_semidistributivity_failure(&self) -> Option<(usize, usize, usize)> {
+        self.dual().meet_semidistributivity_failure()
+    }
+
+    /// Check whether this is a meet semidistributive lattice:
+    /// `a ∧ b = a ∧ c` implies `a ∧ b = a ∧ (b ∨ c)`.
+    pub fn is_meet_semidistributive(&self) -> bool {
+        self.is_lattice() && self.meet_semidistributivity_failure().is_none()
+    }
+
+    /// Check whether this is a join semidistributive lattice:
+    /// `a ∨ b = a ∨ c` implies `a ∨ b = a ∨ (b ∧ c)`.
+    pub fn is_join_semidistributive(&self) -> bool {
+        self.is_lattice() && self.join_semidistributivity_failure().is_none()
+    }
+
+    /// Check whether this is a relatively complemented lattice: for all
+    /// `a ≤ x ≤ b` there is `y` with `x ∧ y = a` and `x ∨ y = b`.
+    pub fn is_relatively_complemented(&self) -> bool {
+        let (join, meet) = match self.lattice_tables() {
+            Some(tables) => tables,
+            None => return false,
+        };
+        let n = self.cardinality();
+        (0..n).all(|a| {
+            (0..n).filter(|&b| self.leq[a][b]).all(|b| {
+                let interval: Vec<usize> = (0..n).filter(|&x| self.leq[a][x] && self.leq[x][b]).collect();
+                interval.iter().all(|&x| interval.iter().any(|&y| meet[x][y] == a && join[x][y] == b))
+            })
+        })
+    }
+
+    /// Check whether this is a geometric lattice: atomistic (every join
+    /// irreducible element is an atom) and upper semimodular.
+    pub fn is_geometric(&self) -> bool {
+        let lower = self.lower_covers();
+        let atomistic = lower.iter().all(|ls| ls.len() != 1 || lower[ls[0]].is_empty());
+        atomistic && self.is_upper_semimodular()
+    }
+
+    /// Check whether this is a lower bounded lattice, that is, whether the
+    /// D relation on its join irreducible elements has no cycle. For join
+    /// irreducibles `p ≠ q`, `p D q` when `p ≤ q ∨ x` but `p ≰ q_* ∨ x`
+    /// for some `x`, where `q_*` is the lower cover of `q` (Freese, Ježek
+    /// and Nation, "Free Lattices", Section 2.6).
+    pub fn is_lower_bounded(&self) -> bool {
+        let (join, _) = match self.lattice_tables() {
+            Some(tables) => tables,
+            None => return false,
+        };
+        let n = self.cardinality();
+        let lower = self.lower_covers();
+        let jis: Vec<usize> = (0..n).filter(|&p| lower[p].len() == 1).collect();
+        let d: Vec<Vec<usize>> = jis.iter()
+            .map(|&p| {
+                (0..jis.len())
+                    .filter(|&k| {
+                        let q = jis[k];
+                        q != p && (0..n).any(|x| self.leq[p][join[q][x]] && !self.leq[p][join[lower[q][0]][x]])
+                    })
+                    .collect()
+            })
+            .collect();
+        !has_cycle(&d)
+    }
+
+    /// All lattice isomorphisms from this lattice onto `other`.
+    ///
+    /// An isomorphism is determined by where it sends the join irreducible
+    /// elements, so the search backtracks over those, mapping each to a join
+    /// irreducible of `other` with as many elements below it and keeping the
+    /// order among the ones already placed.
+    ///
+    /// # Arguments
+    /// * `other` - The target lattice
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Vec<usize>>)` - The isomorphisms, `f[x]` being the image of `x`
+    /// * `Err(String)` - If either poset is not a lattice or the search was
+    ///   cancelled
+    ///
+    /// # Examples
+    /// ```
+    /// use uacalc::lat::Poset;
+    ///
+    /// // 2 x 2 has two automorphisms, swapping the atoms or not.
+    /// let two = Poset::from_leq(None, 2, |i, j| i <= j).unwrap();
+    /// let square = two.product(&two);
+    /// assert_eq!(square.lattice_isomorphisms(&square).unwrap().len(), 2);
+    /// ```
+    pub fn lattice_isomorphisms(&self, other: &Poset) -> Result<Vec<Vec<usize>>, String> {
+        self.lattice_maps(other, false, None)
+    }
+
+    /// Find a 0-1 lattice embedding of this lattice into `other`: a one-to-one
+    /// map preserving joins, meets, the least and the greatest element.
+    ///
+    /// # Arguments
+    /// * `other` - The target lattice
+    ///
+    /// # Returns
+    /// * `Ok(Some(f))` - An embedding, `f[x]` being the image of `x`
+    /// * `Ok(None)` - If there is none
+    /// * `Err(String)` - If either poset is not a lattice or the search was
+    ///   cancelled
+    ///
+    /// # Examples
+    /// ```
+    /// use uacalc::lat::Poset;
+    ///
+    /// let chain = |n: usize| Poset::from_leq(None, n, |i, j| i <= j).unwrap();
+    /// let square = chain(2).product(&chain(2));
+    /// // 0 < 1 < 3 is a 0-1 sublattice of 2 x 2, but the square is not a chain.
+    /// assert_eq!(chain(3).lattice_embedding(&square).unwrap(), Some(vec![0, 1, 3]));
+    /// assert_eq!(square.lattice_embedding(&chain(4)).unwrap(), None);
+    /// ```
+    pub fn lattice_embedding(&self, other: &Poset) -> Result<Option<Vec<usize>>, String> {
+        Ok(self.lattice_maps(other, true, Some(1))?.pop())
+    }
+
+    /// Check whether this lattice is isomorphic to the dual of `other`.
+    ///
+    /// # Returns
+    /// * `Ok(bool)` - Whether the lattices are dually isomorphic
+    /// * `Err(String)` - If either poset is not a lattice or the search was
+    ///   cancelled
+    pub fn is_dual_to(&self, other: &Poset) -> Result<bool, String> {
+        Ok(!self.lattice_maps(&other.dual(), false, Some(1))?.is_empty())
+    }
+
+    /// Search for lattice isomorphisms, or 0-1 embeddings if `embedding`,
+    /// into `other`, stopping after `limit` of them.
+    fn lattice_maps(&self, other: &Poset, embedding: bool, limit: Option<usize>) -> Result<Vec<Vec<usize>>, String> {
+        let mut result = Vec::new();
+        self.for_each_lattice_map(other, embedding, &mut |f| {
+            result.push(f);
+            Ok(limit.is_some_and(|limit| result.len() >= limit))
+        })?;
+        Ok(result)
+    }
+
+    /// Pass each lattice isomorphism, or 0-1 embedding if `embedding`, into
+    /// `other` to `visit`, stopping once it returns `Ok(true)`.
+    pub(crate) fn for_each_lattice_map(
+        &self,
+        other: &Poset,
+        embedding: bool,
+        visit: &mut dyn FnMut(Vec<usize>) -> Result<bool, String>,
+    ) -> Result<(), String> {
+        let not_lattice = |p: &Poset| format!("{} is not a lattice", p.name.as_deref().unwrap_or("The poset"));
+        let (join_a, meet_a) = self.lattice_tables().ok_or_else(|| not_lattice(self))?;
+        let (join_b, meet_b) = other.lattice_tables().ok_or_else(|| not_lattice(other))?;
+        let (n, m) = (self.cardinality(), other.cardinality());
+        if n > m || (!embedding && n != m) {
+            return Ok(());
+        }
+        let (ext_a, ext_b) = (self.linear_extension(), other.linear_extension());
+        let (one_a, zero_b, one_b) = (ext_a[n - 1], ext_b[0], ext_b[m - 1]);
+        let below = |p: &Poset, x: usize| (0..p.cardinality()).filter(|&y| p.leq[y][x]).count();
+
+        // Join irreducibles of this lattice, lower ones first, and the
+        // possible images of each.
+        let lower_a = self.lower_covers();
+        let mut jis: Vec<usize> = (0..n).filter(|&x| lower_a[x].len() == 1).collect();
+        jis.sort_by_key(|&x| below(self, x));
+        let candidates: Vec<Vec<usize>> = if embedding {
+            jis.iter().map(|_| (0..m).filter(|&y| y != zero_b).collect()).collect()
+        } else {
+            let lower_b = other.lower_covers();
+            jis.iter()
+                .map(|&j| (0..m).filter(|&y| lower_b[y].len() == 1 && below(other, y) == below(self, j)).collect())
+                .collect()
+        };
+
+        let search = LatticeMapSearch {
+            a: self,
+            b: other,
+            jis: &jis,
+            candidates: &candidates,
+            tables: [&join_a, &meet_a, &join_b, &meet_b],
+            zero_b,
+            one_a,
+            one_b,
+        };
+        search.extend(&mut Vec::new(), visit)?;
+        Ok(())
+    }
+
+    /// Convert to an `OrderedSet` of the labels.
+    ///
+    /// # Returns
+    /// * `Ok(OrderedSet<String>)` - The ordered set
+    /// * `Err(String)` - If the labels are not distinct
+    pub fn to_ordered_set(&self) -> Result<OrderedSet<String>, String> {
+        let mut seen = std::collections::HashSet::new();
+        if let Some(label) = self.labels.iter().find(|l| !seen.insert(*l)) {
+            return Err(format!("The label {} is used more than once", label));
+        }
+        let covers = self.upper_covers()
+            .into_iter()
+            .map(|cs| cs.into_iter().map(|c| self.labels[c].clone()).collect())
+            .collect();
+        OrderedSet::new(self.name.clone(), self.labels.clone(), covers)
+    }
+
+    /// Convert to a `BasicLattice` of the labels.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the lattice
+    ///
+    /// # Returns
+    /// * `Ok(BasicLattice<String>)` - The lattice
+    /// * `Err(String)` - If the poset is not a lattice or the labels are
+    ///   not distinct
+    pub fn to_lattice(&self, name: &str) -> Result<BasicLattice<String>, String> {
+        if !self.is_lattice() {
+            return Err(format!("{} is not a lattice", self.name.as_deref().unwrap_or("The poset")));
+        }
+        BasicLattice::new_from_poset(name.to_string(), self.to_ordered_set()?, None)
+    }
+}
+
+/// The table of a binary operation, checked to be a semilattice operation:
+/// idempotent, commutative and associative. The value at `(x, y)` is at
+/// index `x + n * y`.
+fn semilattice_table(op: &dyn Operation) -> Result<Arc<[i32]>, String> {
+    let name = op.symbol().name().to_string();
+    if op.arity() != 2 {
+        return Err(format!("Operation {} is not binary", name));
+    }
+    let n = op.get_set_size() as usize;
+    let table = operation_table(op)?;
+    let f = |x: usize, y: usize| table[x + n * y] as usize;
+    if let Some(x) = (0..n).find(|&x| f(x, x) != x) {
+        return Err(format!("Operation {} is not idempotent: {}({}, {}) = {}", name, name, x, x, f(x, x)));
+    }
+    for x in 0..n {
+        for y in 0..n {
+            if f(x, y) != f(y, x) {
+                return Err(format!("Operation {} is not commutative at ({}, {})", name, x, y));
+            }
+        }
+    }
+    for x in 0..n {
+        for y in 0..n {
+            if let Some(z) = (0..n).find(|&z| f(f(x, y), z) != f(x, f(y, z))) {
+                return Err(format!("Operation {} is not associative at ({}, {}, {})", name, x, y, z));
+            }
+        }
+    }
+    Ok(table)
+}
+
+/// Read a semilattice operation as a join and return its order, in which
+/// `x ≤ y` when `x ∨ y = y`.
+///
+/// For a meet semilattice take the [`Poset::dual`] of the result.
+///
+/// # Arguments
+/// * `op` - A binary operation
+///
+/// # Returns
+/// * `Ok(Poset)` - The order on the indices of the universe, named after `op`
+/// * `Err(String)` - If `op` is not idempotent, commutative and associative,
+///   naming a failing instance
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::op::{operations, OperationSymbol};
+/// use uacalc::lat::as_semilattice;
+///
+/// // max on a 3-element chain
+/// let max = operations::make_int_operation(OperationSymbol::new("max", 2, false), 3, vec![0, 1, 2, 1, 1, 2, 2, 2, 2]).unwrap();
+/// let chain = as_semilattice(max.as_ref()).unwrap();
+/// assert!(chain.leq(0, 2) && !chain.leq(2, 1));
+/// ```
+pub fn as_semilattice(op: &dyn Operation) -> Result<Poset, String> {
+    let table = semilattice_table(op)?;
+    let n = op.get_set_size() as usize;
+    Poset::from_leq(Some(op.symbol().name().to_string()), n, |x, y| table[x + n * y] as usize == y)
+}
+
+/// Check that two operations form a lattice and return its order.
+///
+/// # Arguments
+/// * `join` - The join operation
+/// * `meet` - The meet operation, on the same set
+///
+/// # Returns
+/// * `Ok(Poset)` - The lattice order, in which `x ≤ y` when `x ∨ y = y`
+/// * `Err(String)` - If either is not a semilattice operation, the sets
+///   differ, or an absorption law fails
+///
+/// # Examples
+/// ```
+/// use uacalc::alg::op::{operations, OperationSymbol};
+/// use uacalc::lat::as_lattice;
+///
+/// let join = operations::make_int_operation(OperationSymbol::new("join", 2, false), 2, vec![0, 1, 1, 1]).unwrap();
+/// let meet = operations::make_int_operation(OperationSymbol::new("meet", 2, false), 2, vec![0, 0, 0, 1]).unwrap();
+/// assert!(as_lattice(join.as_ref(), meet.as_ref()).unwrap().is_lattice());
+/// assert!(as_lattice(join.as_ref(), join.as_ref()).is_err());
+/// ```
+pub fn as_lattice(join: &dyn Operation, meet: &dyn Operation) -> Result<Poset, String> {
+    if join.get_set_size() != meet.get_set_size() {
+        return Err(format!("Operations {} and {} are on sets of different sizes", join.symbol(), meet.symbol()));
+    }
+    let (j, m) = (semilattice_table(join)?, semilattice_table(meet)?);
+    let n = join.get_set_size() as usize;
+    for x in 0..n {
+        for y in 0..n {
+            if j[x + n * m[x + n * y] as usize] as usize != x || m[x + n * j[x + n * y] as usize] as usize != x {
+                return Err(format!("The absorption laws fail at ({}, {})", x, y));
+            }
+        }
+    }
+    Poset::from_leq(Some(join.symbol().name().to_string()), n, |x, y| j[x + n * y] as usize == y)
+}
+
+/// Find two basic operations of an algebra that form a lattice.
+///
+/// # Arguments
+/// * `alg` - The algebra
+///
+/// # Returns
+/// The indices `(j, m)` of the first such pair of operations, read as join
+/// and meet, or `None` if no two operations form a lattice
+pub fn find_lattice_reduct(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> Option<(usize, usize)> {
+    let ops = alg.get_operations_ref();
+    let semilattices: Vec<usize> = (0..ops.len()).filter(|&i| semilattice_table(ops[i]).is_ok()).collect();
+    for &j in &semilattices {
+        for &m in &semilattices {
+            if j != m && as_lattice(ops[j], ops[m]).is_ok() {
+                return Some((j, m));
+            }
+        }
+    }
+    None
+}
+
+/// Check whether an algebra has a lattice reduct: two basic operations
+/// forming a lattice, as in lattice ordered groups.
+pub fn is_lattice_ordered(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> bool {
+    find_lattice_reduct(alg).is_some()
+}
+
+/// The state of a search for maps between two lattices, which backtracks
+/// over the images of the join irreducibles of `a`.
+struct LatticeMapSearch<'a> {
+    a: &'a Poset,
+    b: &'a Poset,
+    jis: &'a [usize],
+    candidates: &'a [Vec<usize>],
+    /// The join and meet tables of `a`, then of `b`.
+    tables: [&'a Table; 4],
+    zero_b: usize,
+    one_a: usize,
+    one_b: usize,
+}
+
+impl LatticeMapSearch<'_> {
+    /// Try every image for the next join irreducible after `images`; return
+    /// `Ok(true)` once `visit` asks to stop.
+    fn extend(
+        &self,
+        images: &mut Vec<usize>,
+        visit: &mut dyn FnMut(Vec<usize>) -> Result<bool, String>,
+    ) -> Result<bool, String> {
+        let k = images.len();
+        if k == self.jis.len() {
+            crate::progress::check_cancelled()?;
+            return match self.complete(images) {
+                Some(f) => visit(f),
+                None => Ok(false),
+            };
+        }
+        let j = self.jis[k];
+        for &y in &self.candidates[k] {
+            let consistent = self.jis[..k].iter().zip(images.iter()).all(|(&i, &x)| {
+                x != y && self.a.leq[i][j] == self.b.leq[x][y] && self.a.leq[j][i] == self.b.leq[y][x]
+            });
+            if consistent {
+                images.push(y);
+                let done = self.extend(images, visit)?;
+                images.pop();
+                if done {
+                    return Ok(true);
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    /// Extend the images of the join irreducibles by joins and check that
+    /// the result is a 0-1 lattice embedding.
+    fn complete(&self, images: &[usize]) -> Option<Vec<usize>> {
+        let [join_a, meet_a, join_b, meet_b] = self.tables;
+        let n = self.a.cardinality();
+        let f: Vec<usize> = (0..n)
+            .map(|x| {
+                self.jis.iter()
+                    .zip(images)
+                    .filter(|&(&j, _)| self.a.leq[j][x])
+                    .fold(self.zero_b, |acc, (_, &y)| join_b[acc][y])
+            })
+            .collect();
+        let mut seen = vec![false; self.b.cardinality()];
+        if f[self.one_a] != self.one_b || f.iter().any(|&y| std::mem::replace(&mut seen[y], true)) {
+            return None;
+        }
+        let preserved = (0..n).all(|x| {
+            (0..n).all(|y| f[join_a[x][y]] == join_b[f[x]][f[y]] && f[meet_a[x][y]] == meet_b[f[x]][f[y]])
+        });
+        preserved.then_some(f)
+    }
+}
+
+/// Check whether a directed graph, given by its adjacency lists, has a
+/// cycle.
+fn has_cycle(graph: &[Vec<usize>]) -> bool {
+    // 0: unvisited, 1: on the current path, 2: done
+    fn visit(v: usize, graph: &[Vec<usize>], state: &mut [u8]) -> bool {
+        state[v] = 1;
+        for &w in &graph[v] {
+            if state[w] == 1 || (state[w] == 0 && visit(w, graph, state)) {
+                return true;
+            }
+        }
+        state[v] = 2;
+        false
+    }
+    let mut state = vec![0; graph.len()];
+    (0..graph.len()).any(|v| state[v] == 0 && visit(v, graph, &mut state))
+}
+
+impl Display for Poset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "Poset({}, {} elements)", name, self.cardinality()),
+            None => write!(f, "Poset({} elements)", self.cardinality()),
+        }
+    }
+}
+
+impl Debug for Poset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Poset")
+            .field("name", &self.name)
+            .field("labels", &self.labels)
+            .field("upper_covers", &self.upper_covers())
+            .finish()
+    }
+}
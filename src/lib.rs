@@ -1,13 +1,25 @@
 pub mod alg;
+pub mod bench;
 #[cfg(feature = "test-infrastructure")]
 pub mod common;
+pub mod compat;
 pub mod element;
 pub mod eq;
+pub mod events;
 pub mod example;
 pub mod fplat;
 pub mod group;
 pub mod io;
 pub mod lat;
+pub mod memory;
 pub mod progress;
+pub mod session;
+pub mod telemetry;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod terms;
 pub mod util;
+#[cfg(feature = "verify")]
+pub mod verify;
+
+pub use bench::bench_suite;
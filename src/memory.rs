@@ -0,0 +1,316 @@
+/*!
+ * Memory accounting and limits for long-running computations.
+ *
+ * With the `memory-tracking` feature the crate installs [`TrackingAllocator`]
+ * as the global allocator. It counts the bytes allocated by the process and,
+ * inside a [`MemoryScope`], by the current thread, so that individual
+ * computations (congruence lattices, free algebras, product operation
+ * tables) can be measured. A soft limit set with [`set_memory_limit`] is
+ * checked cooperatively wherever computations check for cancellation; it
+ * never makes an allocation fail.
+ *
+ * Without the feature nothing is counted: [`allocated_memory`] returns
+ * `None` and the limit falls back to the resident set size where it can be
+ * read (Linux).
+ */
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use once_cell::sync::Lazy;
+
+/// A global allocator counting allocated bytes.
+///
+/// Installed by the `memory-tracking` feature; it delegates to the system
+/// allocator.
+pub struct TrackingAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+/// No limit is encoded as zero.
+static LIMIT: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// Net bytes allocated by this thread.
+    static THREAD_NET: Cell<isize> = const { Cell::new(0) };
+    /// The largest value of `THREAD_NET` since the innermost scope started.
+    static THREAD_PEAK: Cell<isize> = const { Cell::new(0) };
+}
+
+fn record(delta: isize) {
+    // try_with: the thread locals may already be gone during thread exit.
+    let _ = THREAD_NET.try_with(|net| {
+        let now = net.get() + delta;
+        net.set(now);
+        let _ = THREAD_PEAK.try_with(|peak| {
+            if now > peak.get() {
+                peak.set(now);
+            }
+        });
+    });
+}
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+            record(layout.size() as isize);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+        record(-(layout.size() as isize));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            let delta = new_size as isize - layout.size() as isize;
+            if delta >= 0 {
+                ALLOCATED.fetch_add(delta as usize, Ordering::Relaxed);
+            } else {
+                ALLOCATED.fetch_sub((-delta) as usize, Ordering::Relaxed);
+            }
+            record(delta);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "memory-tracking")]
+#[global_allocator]
+static GLOBAL: TrackingAllocator = TrackingAllocator;
+
+/// Check whether allocations are being counted.
+pub fn tracking_enabled() -> bool {
+    cfg!(feature = "memory-tracking")
+}
+
+/// Get the number of bytes currently allocated by the process.
+///
+/// # Returns
+/// The byte count, or `None` without the `memory-tracking` feature
+pub fn allocated_memory() -> Option<usize> {
+    if tracking_enabled() {
+        Some(ALLOCATED.load(Ordering::Relaxed))
+    } else {
+        None
+    }
+}
+
+/// Get the memory used by the process: the allocated bytes when tracking,
+/// otherwise the resident set size where it can be read.
+pub fn used_memory() -> Option<usize> {
+    allocated_memory().or_else(resident_memory)
+}
+
+/// The resident set size of this process in bytes, where it can be read.
+pub(crate) fn resident_memory() -> Option<usize> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+    let statm = std::fs::read_to_string("/proc/self/statm").ok()?;
+    let pages: usize = statm.split_whitespace().nth(1)?.parse().ok()?;
+    // statm counts pages; assume the common 4 KiB page size
+    Some(pages * 4096)
+}
+
+/// Set the soft memory limit in bytes, or remove it with `None`.
+///
+/// Computations checking for cancellation stop with a "Memory limit
+/// exceeded" error once [`used_memory`] passes the limit.
+pub fn set_memory_limit(limit: Option<usize>) {
+    LIMIT.store(limit.unwrap_or(0), Ordering::SeqCst);
+}
+
+/// Get the soft memory limit in bytes, if one is set.
+pub fn memory_limit() -> Option<usize> {
+    match LIMIT.load(Ordering::SeqCst) {
+        0 => None,
+        limit => Some(limit),
+    }
+}
+
+/// The start of the error message of a computation stopped by the soft
+/// memory limit.
+pub const MEMORY_LIMIT_EXCEEDED: &str = "Memory limit exceeded";
+
+/// Return an error if the soft memory limit has been passed.
+pub fn check_memory_limit() -> Result<(), String> {
+    match memory_limit() {
+        Some(limit) => check_limit(limit, used_memory()),
+        None => Ok(()),
+    }
+}
+
+fn check_limit(limit: usize, used: Option<usize>) -> Result<(), String> {
+    match used {
+        Some(used) if used > limit => Err(format!("{}: {} bytes used, limit {} bytes", MEMORY_LIMIT_EXCEEDED, used, limit)),
+        _ => Ok(()),
+    }
+}
+
+/// The memory used by a finished [`MemoryScope`], in bytes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// Bytes still allocated when the scope ended.
+    pub retained: usize,
+    /// The most bytes allocated at any point during the scope.
+    pub peak: usize,
+}
+
+static USAGE: Lazy<Mutex<HashMap<String, MemoryUsage>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Accounts for the memory the current thread allocates while it is alive.
+///
+/// When the scope is dropped its usage is recorded under its label and can
+/// be read back with [`last_usage`]. Allocations made by other threads (for
+/// example rayon workers) are not included.
+///
+/// # Examples
+/// ```
+/// use uacalc::memory::{MemoryScope, last_usage};
+///
+/// {
+///     let _scope = MemoryScope::new("example");
+///     let v: Vec<u64> = vec![0; 1000];
+///     drop(v);
+/// }
+/// assert!(last_usage("example").is_some());
+/// ```
+pub struct MemoryScope {
+    label: String,
+    start: isize,
+    saved_peak: isize,
+}
+
+impl MemoryScope {
+    /// Start accounting under `label`.
+    pub fn new(label: &str) -> Self {
+        let start = THREAD_NET.with(|net| net.get());
+        let saved_peak = THREAD_PEAK.with(|peak| peak.replace(start));
+        MemoryScope { label: label.to_string(), start, saved_peak }
+    }
+
+    /// Get the usage so far.
+    pub fn usage(&self) -> MemoryUsage {
+        let net = THREAD_NET.with(|net| net.get());
+        let peak = THREAD_PEAK.with(|peak| peak.get());
+        MemoryUsage {
+            retained: (net - self.start).max(0) as usize,
+            peak: (peak - self.start).max(0) as usize,
+        }
+    }
+}
+
+impl Drop for MemoryScope {
+    fn drop(&mut self) {
+        let usage = self.usage();
+        // Restore the enclosing scope's peak, which includes this one.
+        THREAD_PEAK.with(|peak| peak.set(peak.get().max(self.saved_peak)));
+        let label = std::mem::take(&mut self.label);
+        USAGE.lock().unwrap().insert(label, usage);
+    }
+}
+
+/// Get the usage of the last finished scope with the given label.
+pub fn last_usage(label: &str) -> Option<MemoryUsage> {
+    USAGE.lock().unwrap().get(label).copied()
+}
+
+/// Approximate heap size of a partition of an `n` element set.
+fn partition_bytes(n: usize) -> usize {
+    4 * n + 64
+}
+
+/// Estimate the memory needed to build the congruence lattice of an
+/// algebra with `alg_size` elements.
+///
+/// Counts the principal congruences with their lookup tables and one
+/// partition per congruence of a lattice with `con_size` elements; pass the
+/// number of principal congruences, `alg_size * (alg_size - 1) / 2`, as a
+/// lower bound when the size is unknown.
+///
+/// # Returns
+/// The estimate in bytes, saturating at `usize::MAX`
+pub fn estimate_conlat_memory(alg_size: usize, con_size: usize) -> usize {
+    let pairs = alg_size.saturating_mul(alg_size.saturating_sub(1)) / 2;
+    let part = partition_bytes(alg_size);
+    // lookup: pair key and canonical partition; principals list and reps
+    let principals = pairs.saturating_mul(part.saturating_mul(3).saturating_add(96));
+    principals.saturating_add(con_size.saturating_mul(part.saturating_add(32)))
+}
+
+/// Estimate the memory needed for the free algebra on `gens` generators
+/// over an algebra with `alg_size` elements.
+///
+/// Uses the bound `|F(gens)| <= alg_size^(alg_size^gens)` on the number of
+/// elements, each an array of length `alg_size^gens`, together with the
+/// term stored for each element.
+///
+/// # Returns
+/// The estimate in bytes, saturating at `usize::MAX`
+pub fn estimate_free_algebra_memory(alg_size: usize, gens: usize) -> usize {
+    let len = saturating_pow(alg_size, gens);
+    let count = if len >= usize::BITS as usize {
+        usize::MAX
+    } else {
+        saturating_pow(alg_size, len)
+    };
+    count.saturating_mul(len.saturating_mul(4).saturating_add(128))
+}
+
+/// Estimate the memory needed for the operation tables of a product of
+/// algebras with the given sizes and operation arities.
+///
+/// # Returns
+/// The estimate in bytes, saturating at `usize::MAX`
+pub fn estimate_product_table_memory(sizes: &[usize], arities: &[usize]) -> usize {
+    let card = sizes.iter().fold(1usize, |acc, &s| acc.saturating_mul(s));
+    arities.iter().fold(0usize, |acc, &k| acc.saturating_add(saturating_pow(card, k).saturating_mul(4)))
+}
+
+fn saturating_pow(base: usize, exp: usize) -> usize {
+    (0..exp).fold(1usize, |acc, _| acc.saturating_mul(base))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_scope_and_limit() {
+        {
+            let _scope = MemoryScope::new("test_scope");
+            let v: Vec<u64> = vec![7; 10_000];
+            assert_eq!(v.len(), 10_000);
+        }
+        let usage = last_usage("test_scope").unwrap();
+        if tracking_enabled() {
+            assert!(usage.peak >= 80_000);
+            assert!(usage.retained < usage.peak);
+        } else {
+            assert_eq!(usage, MemoryUsage::default());
+        }
+
+        // The limit is global, so only the check itself is tested here.
+        assert!(check_limit(100, Some(100)).is_ok());
+        assert!(check_limit(100, None).is_ok());
+        assert!(check_limit(100, Some(101)).unwrap_err().starts_with(MEMORY_LIMIT_EXCEEDED));
+    }
+
+    #[test]
+    fn test_estimates() {
+        assert_eq!(estimate_conlat_memory(1, 1), 68 + 32);
+        assert!(estimate_conlat_memory(10, 45) < estimate_conlat_memory(20, 45));
+        assert_eq!(estimate_free_algebra_memory(2, 1), 4 * (8 + 128));
+        assert_eq!(estimate_free_algebra_memory(100, 10), usize::MAX);
+        assert_eq!(estimate_product_table_memory(&[2, 3], &[2, 0]), 36 * 4 + 4);
+    }
+}
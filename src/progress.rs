@@ -8,8 +8,13 @@
  * making it suitable for both CLI and library usage.
  */
 
-use std::sync::Arc;
-use std::time::Instant;
+use std::cell::RefCell;
+use std::fmt;
+use std::ops::ControlFlow;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+use crate::events::{ComputationEvent, EventBus};
 
 /// Progress reporting trait for long-running operations.
 /// 
@@ -64,6 +69,26 @@ pub trait ProgressReport: Send + Sync {
     /// # Arguments
     /// * `time_str` - A formatted string representing time for next pass
     fn set_time_next(&self, time_str: &str);
+
+    /// Check whether the computation reporting here has been cancelled.
+    ///
+    /// Long-running algorithms poll this and stop with an error when it
+    /// returns true. Reporters without cancellation never cancel.
+    fn is_cancelled(&self) -> bool {
+        false
+    }
+
+    /// Return an error describing why the computation must stop, if it must.
+    ///
+    /// Defaults to `Err(CANCELLED)` when [`is_cancelled`](Self::is_cancelled)
+    /// returns true; reporters with a budget say which limit was passed.
+    fn check_cancelled(&self) -> Result<(), String> {
+        if self.is_cancelled() {
+            Err(CANCELLED.to_string())
+        } else {
+            Ok(())
+        }
+    }
 }
 
 /// A no-op implementation that suppresses all progress output.
@@ -235,6 +260,514 @@ impl ProgressReport for ConsoleProgressReport {
     }
 }
 
+/// The error message of a computation stopped by a [`ProgressToken`].
+///
+/// Whether a computation was stopped is known from its token
+/// ([`ProgressToken::stop_reason`], [`ComputationError::new`]), not from this
+/// message: an inner computation's error may be wrapped in another message.
+pub const CANCELLED: &str = "Computation cancelled";
+
+/// The start of the error message of a computation stopped by its budget.
+pub const BUDGET_EXCEEDED: &str = "Budget exceeded";
+
+/// Limits on the resources a computation may use.
+///
+/// A budget is attached to a [`ProgressToken`] with
+/// [`ProgressToken::set_budget`]; computations checking the token stop once
+/// a limit is passed, and [`ProgressToken::budget_exceeded`] tells which.
+/// What was computed so far stays available where the computation keeps
+/// it, for example `Closer::get_answer` and
+/// `CongruenceLattice::partial_universe`, and is carried by the
+/// [`ComputationError`] of `CongruenceLattice::compute_universe`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Budget {
+    /// The longest the computation may run.
+    pub wall_time: Option<Duration>,
+    /// The most memory, in bytes, the process may use, as measured by
+    /// [`crate::memory::used_memory`].
+    pub max_memory: Option<usize>,
+    /// The most elements (closure size, congruences, ...) the computation
+    /// may produce.
+    pub max_elements: Option<usize>,
+}
+
+/// The resource whose limit a computation passed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BudgetResource {
+    WallTime,
+    Memory,
+    Elements,
+}
+
+impl fmt::Display for BudgetResource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BudgetResource::WallTime => write!(f, "wall time"),
+            BudgetResource::Memory => write!(f, "memory"),
+            BudgetResource::Elements => write!(f, "elements"),
+        }
+    }
+}
+
+/// A passed [`Budget`] limit: the resource, its limit and the amount used
+/// (milliseconds for wall time, bytes for memory).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BudgetExceeded {
+    pub resource: BudgetResource,
+    pub limit: u64,
+    pub used: u64,
+}
+
+impl fmt::Display for BudgetExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} used {} of {}", BUDGET_EXCEEDED, self.resource, self.used, self.limit)
+    }
+}
+
+impl std::error::Error for BudgetExceeded {}
+
+/// Why a [`ProgressToken`] stopped a computation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StopReason {
+    /// The token was cancelled, by [`ProgressToken::cancel`] or its callback.
+    Cancelled,
+    /// A limit of the token's [`Budget`] was passed.
+    BudgetExceeded(BudgetExceeded),
+    /// The soft memory limit (see [`crate::memory::set_memory_limit`]) was
+    /// passed; the message says by how much.
+    MemoryLimit(String),
+}
+
+impl fmt::Display for StopReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StopReason::Cancelled => write!(f, "{}", CANCELLED),
+            StopReason::BudgetExceeded(exceeded) => write!(f, "{}", exceeded),
+            StopReason::MemoryLimit(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+/// The error of a computation checking a [`ProgressToken`]: either the
+/// token stopped it, and `partial` holds what it had computed, or it failed.
+///
+/// Computations return `Result<_, String>`; [`ComputationError::new`] tells
+/// the two cases apart by the state of the token, not by the message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComputationError<P> {
+    /// Stopped by the token.
+    Stopped { reason: StopReason, partial: P },
+    /// Failed, with the error message.
+    Failed(String),
+}
+
+impl<P> ComputationError<P> {
+    /// Type the error `err` of a computation which ran on the current
+    /// thread; `partial` is called only if the thread's token stopped it.
+    /// Without an installed token every error is a failure.
+    ///
+    /// # Examples
+    /// ```
+    /// use uacalc::progress::{Budget, ComputationError, ProgressReport, ProgressToken, StopReason};
+    ///
+    /// let token = ProgressToken::new();
+    /// let _installed = token.install();
+    /// let failed = ComputationError::new("Operation not found".to_string(), || 0);
+    /// assert_eq!(failed, ComputationError::Failed("Operation not found".to_string()));
+    ///
+    /// token.set_budget(Budget { max_elements: Some(2), ..Default::default() });
+    /// token.set_size(3);
+    /// let err = format!("Closing failed: {}", token.check().unwrap_err());
+    /// let stopped = ComputationError::new(err, || 3);
+    /// let exceeded = stopped.budget_exceeded().unwrap();
+    /// assert_eq!((exceeded.limit, exceeded.used), (2, 3));
+    /// assert!(matches!(stopped, ComputationError::Stopped { reason: StopReason::BudgetExceeded(_), partial: 3 }));
+    /// ```
+    pub fn new(err: String, partial: impl FnOnce() -> P) -> Self {
+        match current().and_then(|token| token.stop_reason()) {
+            Some(reason) => ComputationError::Stopped { reason, partial: partial() },
+            None => ComputationError::Failed(err),
+        }
+    }
+
+    /// Whether the token stopped the computation.
+    pub fn is_stopped(&self) -> bool {
+        matches!(self, ComputationError::Stopped { .. })
+    }
+
+    /// The budget limit that stopped the computation, if one did.
+    pub fn budget_exceeded(&self) -> Option<BudgetExceeded> {
+        match self {
+            ComputationError::Stopped { reason: StopReason::BudgetExceeded(exceeded), .. } => Some(*exceeded),
+            _ => None,
+        }
+    }
+
+    /// Convert the partial result.
+    pub fn map_partial<Q>(self, f: impl FnOnce(P) -> Q) -> ComputationError<Q> {
+        match self {
+            ComputationError::Stopped { reason, partial } => ComputationError::Stopped { reason, partial: f(partial) },
+            ComputationError::Failed(message) => ComputationError::Failed(message),
+        }
+    }
+}
+
+impl<P> fmt::Display for ComputationError<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComputationError::Stopped { reason, .. } => write!(f, "{}", reason),
+            ComputationError::Failed(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl<P: fmt::Debug> std::error::Error for ComputationError<P> {}
+
+impl<P> From<ComputationError<P>> for String {
+    fn from(err: ComputationError<P>) -> String {
+        err.to_string()
+    }
+}
+
+impl Budget {
+    /// Find the first limit passed after running for `elapsed` and
+    /// producing `elements` elements.
+    fn exceeded(&self, elapsed: Duration, elements: usize) -> Option<BudgetExceeded> {
+        if let Some(limit) = self.wall_time {
+            if elapsed > limit {
+                return Some(BudgetExceeded {
+                    resource: BudgetResource::WallTime,
+                    limit: limit.as_millis() as u64,
+                    used: elapsed.as_millis() as u64,
+                });
+            }
+        }
+        if let Some(limit) = self.max_elements {
+            if elements > limit {
+                return Some(BudgetExceeded { resource: BudgetResource::Elements, limit: limit as u64, used: elements as u64 });
+            }
+        }
+        if let Some(limit) = self.max_memory {
+            if let Some(used) = crate::memory::used_memory() {
+                if used > limit {
+                    return Some(BudgetExceeded { resource: BudgetResource::Memory, limit: limit as u64, used: used as u64 });
+                }
+            }
+        }
+        None
+    }
+}
+
+/// A progress event passed to a [`ProgressToken`] callback.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgressEvent {
+    /// The phase labels in effect, outermost first, joined by " / ".
+    pub phase: String,
+    /// The message.
+    pub message: String,
+    /// The fraction of the work done, if known.
+    pub fraction: Option<f64>,
+}
+
+/// A callback receiving the events of a [`ProgressToken`]; returning
+/// `ControlFlow::Break` cancels the token.
+pub type ProgressCallback = Arc<dyn Fn(&ProgressEvent) -> ControlFlow<()> + Send + Sync>;
+
+/// A handle for observing and cancelling long-running computations.
+///
+/// A token combines an optional callback, a cancellation flag and a stack
+/// of phase labels. Inside [`EventBus::run`] its events also go to the
+/// listeners of the bus. Clones share all state, so one clone can be cancelled
+/// from another thread while the computation holds the other. It can be
+/// passed wherever a `ProgressReport` or `ProgressMonitor` is accepted, and
+/// it can be installed for the current thread with [`ProgressToken::install`]
+/// so that computations which take no reporter (closures, free algebras,
+/// Mal'cev term searches, homomorphism searches) report to it and stop with
+/// the error [`CANCELLED`] once it is cancelled.
+///
+/// # Examples
+/// ```
+/// use std::ops::ControlFlow;
+/// use std::sync::{Arc, Mutex};
+/// use uacalc::progress::ProgressToken;
+///
+/// let messages = Arc::new(Mutex::new(Vec::new()));
+/// let sink = messages.clone();
+/// let token = ProgressToken::with_callback(move |event| {
+///     sink.lock().unwrap().push(format!("{}: {}", event.phase, event.message));
+///     ControlFlow::Continue(())
+/// });
+/// {
+///     let _phase = token.phase("closing");
+///     token.report("pass 1");
+/// }
+/// token.cancel();
+/// assert!(token.check().is_err());
+/// assert_eq!(messages.lock().unwrap()[1], "closing: pass 1");
+/// ```
+#[derive(Clone, Default)]
+pub struct ProgressToken {
+    inner: Arc<TokenState>,
+}
+
+#[derive(Default)]
+struct TokenState {
+    cancelled: AtomicBool,
+    callback: Option<ProgressCallback>,
+    phases: Mutex<Vec<String>>,
+    pass: AtomicUsize,
+    pass_size: AtomicUsize,
+    size: AtomicUsize,
+    description: Mutex<String>,
+    budget: Mutex<Option<(Budget, Instant)>>,
+    /// The budget limit or memory limit that stopped the computation.
+    stopped: Mutex<Option<StopReason>>,
+    events: Mutex<Option<(Arc<EventBus>, u64)>>,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<ProgressToken>> = const { RefCell::new(None) };
+}
+
+impl ProgressToken {
+    /// Create a token without a callback.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a token passing every event to `callback`, which cancels the
+    /// token by returning `ControlFlow::Break`.
+    pub fn with_callback(callback: impl Fn(&ProgressEvent) -> ControlFlow<()> + Send + Sync + 'static) -> Self {
+        ProgressToken {
+            inner: Arc::new(TokenState { callback: Some(Arc::new(callback)), ..Default::default() }),
+        }
+    }
+
+    /// Request cancellation; computations stop at their next check.
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Check whether cancellation has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Return an error if cancellation has been requested, the budget has
+    /// been exceeded or the global memory limit (see
+    /// [`crate::memory::set_memory_limit`]) has been passed; the two limits
+    /// also cancel the token.
+    ///
+    /// # Returns
+    /// `Err` with the message of the [`StopReason`], see [`stop_reason`](Self::stop_reason)
+    pub fn check(&self) -> Result<(), String> {
+        if !self.is_cancelled() {
+            let exceeded = self.inner.budget.lock().unwrap().as_ref()
+                .and_then(|(budget, start)| budget.exceeded(start.elapsed(), self.inner.size.load(Ordering::SeqCst)));
+            let reason = match exceeded {
+                Some(e) => StopReason::BudgetExceeded(e),
+                None => match crate::memory::check_memory_limit() {
+                    Ok(()) => return Ok(()),
+                    Err(message) => StopReason::MemoryLimit(message),
+                },
+            };
+            *self.inner.stopped.lock().unwrap() = Some(reason);
+            self.cancel();
+        }
+        Err(self.stop_reason().unwrap_or(StopReason::Cancelled).to_string())
+    }
+
+    /// Limit the resources of computations checking this token; the wall
+    /// time and the elements are counted from this call.
+    ///
+    /// Setting a budget starts a new run: if a limit stopped the previous
+    /// computation, the token is no longer cancelled, so one token can be
+    /// used for several computations with a budget each.
+    pub fn set_budget(&self, budget: Budget) {
+        if self.inner.stopped.lock().unwrap().take().is_some() {
+            self.inner.cancelled.store(false, Ordering::SeqCst);
+        }
+        self.inner.size.store(0, Ordering::SeqCst);
+        *self.inner.budget.lock().unwrap() = Some((budget, Instant::now()));
+    }
+
+    /// Why the token stopped the computation, if it has.
+    pub fn stop_reason(&self) -> Option<StopReason> {
+        if !self.is_cancelled() {
+            return None;
+        }
+        Some(self.inner.stopped.lock().unwrap().clone().unwrap_or(StopReason::Cancelled))
+    }
+
+    /// The limit that stopped the computation, if the budget did.
+    pub fn budget_exceeded(&self) -> Option<BudgetExceeded> {
+        match *self.inner.stopped.lock().unwrap() {
+            Some(StopReason::BudgetExceeded(exceeded)) => Some(exceeded),
+            _ => None,
+        }
+    }
+
+    /// Enter a phase, which lasts until the returned guard is dropped.
+    pub fn phase(&self, label: &str) -> PhaseGuard {
+        self.inner.phases.lock().unwrap().push(label.to_string());
+        self.emit("started", None);
+        PhaseGuard { token: self.clone() }
+    }
+
+    /// The current phase labels, outermost first, joined by " / ".
+    pub fn current_phase(&self) -> String {
+        self.inner.phases.lock().unwrap().join(" / ")
+    }
+
+    /// Send a message to the callback.
+    pub fn report(&self, message: &str) {
+        self.emit(message, None);
+    }
+
+    fn emit(&self, message: &str, fraction: Option<f64>) {
+        let events = self.inner.events.lock().unwrap().clone();
+        if self.inner.callback.is_none() && events.is_none() {
+            return;
+        }
+        let event = ProgressEvent { phase: self.current_phase(), message: message.to_string(), fraction };
+        if let Some(ref callback) = self.inner.callback {
+            if callback(&event).is_break() {
+                self.cancel();
+            }
+        }
+        if let Some((bus, id)) = events {
+            if bus.publish(&ComputationEvent::Progress { id, progress: event }).is_break() {
+                self.cancel();
+            }
+        }
+    }
+
+    /// Publish the events of this token to `events`, a bus and the id of
+    /// the computation, returning the previous target.
+    pub(crate) fn attach_events(&self, events: Option<(Arc<EventBus>, u64)>) -> Option<(Arc<EventBus>, u64)> {
+        std::mem::replace(&mut *self.inner.events.lock().unwrap(), events)
+    }
+
+    /// Make this the current thread's token until the guard is dropped.
+    ///
+    /// Computations that take no reporter of their own pick up the current
+    /// token through [`current`] and [`check_cancelled`].
+    pub fn install(&self) -> InstallGuard {
+        let previous = CURRENT.with(|c| c.replace(Some(self.clone())));
+        InstallGuard { previous }
+    }
+}
+
+impl fmt::Debug for ProgressToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProgressToken")
+            .field("cancelled", &self.is_cancelled())
+            .field("phase", &self.current_phase())
+            .finish()
+    }
+}
+
+/// Guard returned by [`ProgressToken::phase`]; leaves the phase on drop.
+pub struct PhaseGuard {
+    token: ProgressToken,
+}
+
+impl Drop for PhaseGuard {
+    fn drop(&mut self) {
+        self.token.emit("finished", None);
+        self.token.inner.phases.lock().unwrap().pop();
+    }
+}
+
+/// Guard returned by [`ProgressToken::install`]; restores the previous
+/// token on drop.
+pub struct InstallGuard {
+    previous: Option<ProgressToken>,
+}
+
+impl Drop for InstallGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT.with(|c| *c.borrow_mut() = previous);
+    }
+}
+
+/// The token installed for the current thread, if any.
+pub fn current() -> Option<ProgressToken> {
+    CURRENT.with(|c| c.borrow().clone())
+}
+
+/// Return an error if the current thread's token has been cancelled or the
+/// global memory limit has been passed.
+pub fn check_cancelled() -> Result<(), String> {
+    CURRENT.with(|c| match c.borrow().as_ref() {
+        Some(token) => token.check(),
+        None => crate::memory::check_memory_limit(),
+    })
+}
+
+impl ProgressReport for ProgressToken {
+    fn set_pass(&self, pass: usize) {
+        self.inner.pass.store(pass, Ordering::SeqCst);
+    }
+    fn set_pass_size(&self, pass_size: usize) {
+        self.inner.pass_size.store(pass_size, Ordering::SeqCst);
+    }
+    fn set_size(&self, size: usize) {
+        self.inner.size.store(size, Ordering::SeqCst);
+    }
+    fn set_description(&self, description: &str) {
+        *self.inner.description.lock().unwrap() = description.to_string();
+        self.emit(description, None);
+    }
+    fn add_line(&self, line: &str) {
+        self.emit(line, None);
+    }
+    fn add_start_line(&self, line: &str) {
+        self.emit(line, None);
+    }
+    fn add_end_line(&self, line: &str) {
+        self.emit(line, None);
+    }
+    fn reset(&self) {
+        self.inner.pass.store(0, Ordering::SeqCst);
+        self.inner.pass_size.store(0, Ordering::SeqCst);
+        self.inner.size.store(0, Ordering::SeqCst);
+    }
+    fn get_pass(&self) -> usize {
+        self.inner.pass.load(Ordering::SeqCst)
+    }
+    fn get_pass_size(&self) -> usize {
+        self.inner.pass_size.load(Ordering::SeqCst)
+    }
+    fn get_size(&self) -> usize {
+        self.inner.size.load(Ordering::SeqCst)
+    }
+    fn get_description(&self) -> String {
+        self.inner.description.lock().unwrap().clone()
+    }
+    fn set_time_left(&self, _time_str: &str) {}
+    fn set_time_next(&self, _time_str: &str) {}
+    fn is_cancelled(&self) -> bool {
+        self.check().is_err()
+    }
+    fn check_cancelled(&self) -> Result<(), String> {
+        self.check()
+    }
+}
+
+impl crate::alg::algebra::ProgressMonitor for ProgressToken {
+    fn report_progress(&self, message: &str) {
+        self.emit(message, None);
+    }
+    fn is_cancelled(&self) -> bool {
+        self.check().is_err()
+    }
+    fn set_progress(&self, progress: f64) {
+        self.emit("", Some(progress));
+    }
+}
+
 /// A type alias for a shared progress reporter.
 pub type SharedProgressReport = Arc<dyn ProgressReport>;
 
@@ -315,4 +848,77 @@ mod tests {
         assert_eq!(no_op.get_description(), "");
         assert_eq!(console.get_description(), "test");
     }
+
+    #[test]
+    fn test_progress_token() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let token = ProgressToken::with_callback(move |e| {
+            sink.lock().unwrap().push(e.clone());
+            if e.message == "stop" { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+        });
+        assert!(check_cancelled().is_ok());
+        {
+            let _installed = token.install();
+            let _outer = token.phase("outer");
+            let _inner = token.phase("inner");
+            current().unwrap().add_line("working");
+            assert!(check_cancelled().is_ok());
+            token.clone().report("stop");
+            assert_eq!(check_cancelled(), Err(CANCELLED.to_string()));
+        }
+        assert!(current().is_none());
+        assert!(ProgressReport::is_cancelled(&token));
+        assert!(!ProgressReport::is_cancelled(&NoOpProgressReport));
+        let events = events.lock().unwrap();
+        assert_eq!(events[2].phase, "outer / inner");
+        assert_eq!(events[2].message, "working");
+        assert_eq!(events.last().unwrap().phase, "outer");
+        assert_eq!(token.current_phase(), "");
+    }
+
+    #[test]
+    fn test_budget() {
+        let token = ProgressToken::new();
+        assert!(token.check().is_ok());
+        token.set_budget(Budget { max_elements: Some(10), ..Default::default() });
+        token.set_size(10);
+        assert!(token.check().is_ok());
+        token.set_size(11);
+        let exceeded = BudgetExceeded { resource: BudgetResource::Elements, limit: 10, used: 11 };
+        assert_eq!(token.check(), Err(exceeded.to_string()));
+        assert_eq!(token.budget_exceeded(), Some(exceeded));
+        assert!(token.is_cancelled());
+
+        let token = ProgressToken::new();
+        token.set_budget(Budget { wall_time: Some(Duration::ZERO), ..Default::default() });
+        std::thread::sleep(Duration::from_millis(2));
+        assert!(ProgressReport::is_cancelled(&token));
+        assert_eq!(token.budget_exceeded().unwrap().resource, BudgetResource::WallTime);
+    }
+
+    #[test]
+    fn test_budget_applies_to_each_run() {
+        let token = ProgressToken::new();
+        let budget = Budget { max_elements: Some(10), ..Default::default() };
+        token.set_budget(budget);
+        token.set_size(11);
+        let err = token.check().unwrap_err();
+        let exceeded = token.budget_exceeded().unwrap();
+        assert_eq!(err, exceeded.to_string());
+        assert_eq!(token.stop_reason(), Some(StopReason::BudgetExceeded(exceeded)));
+
+        // The next run starts afresh
+        token.set_budget(budget);
+        assert!(!token.is_cancelled());
+        assert_eq!(token.budget_exceeded(), None);
+        token.set_size(5);
+        assert!(token.check().is_ok());
+
+        // A cancellation by the user is kept
+        token.cancel();
+        token.set_budget(budget);
+        assert_eq!(token.check(), Err(CANCELLED.to_string()));
+        assert_eq!(token.stop_reason(), Some(StopReason::Cancelled));
+    }
 }
@@ -0,0 +1,667 @@
+/*! A workspace of named algebras with an undo/redo command log.
+
+An [`AlgebraWorkspace`] is the model behind an interactive frontend: it
+holds the algebras the user has loaded or derived, in the order they were
+added, together with saved terms and equations and free-form notes, and
+changes them only through [`Command`]s, which can be undone and redone.
+
+Every command keeps the workspace as it was before it ran, and an undone
+command keeps it as it was after. The operations of an algebra are shared
+between its copies, so this costs little more than the universes. A
+command that fails leaves the workspace unchanged and is not logged.
+
+The congruences and subuniverses of an algebra are computed on request and
+kept with it until a command changes its operations.
+
+# Project files
+
+[`save`](AlgebraWorkspace::save) writes a workspace to a project file,
+conventionally with the extension `.uaproj`: a JSON document of kind
+`"project"` in the format of [`crate::io::json`],
+
+```json
+{
+  "schema_version": 1,
+  "kind": "project",
+  "algebras": [{"name": "A", "size": 2, "operations": [...],
+                "derived_from": null, "congruences": [...], "subuniverses": null}],
+  "terms": [{"name": "m", "term": "f(x,y)"}],
+  "equations": [{"name": "comm", "left": "f(x,y)", "right": "f(y,x)"}],
+  "notes": "..."
+}
+```
+
+where `"congruences"` lists partitions and `"subuniverses"` lists sorted
+lists of elements, each `null` if not computed. The command log is not
+saved: a loaded workspace starts with an empty history.
+
+# Examples
+```
+use uacalc::alg::{Algebra, BasicAlgebra};
+use uacalc::alg::op::{operations, OperationSymbol};
+use uacalc::session::{AlgebraWorkspace, Command};
+
+let plus = operations::make_int_operation(OperationSymbol::new("+", 2, false), 4,
+    (0..16).map(|k| (k % 4 + k / 4) % 4).collect()).unwrap();
+let z4 = BasicAlgebra::new("Z4".to_string(), (0..4).collect(), vec![plus]);
+
+let mut ws = AlgebraWorkspace::new();
+ws.execute(Command::AddAlgebra { name: "A".to_string(), algebra: Box::new(z4) }).unwrap();
+ws.execute(Command::Subalgebra { algebra: "A".to_string(), generators: vec![2], name: "B".to_string() }).unwrap();
+assert_eq!(ws.get("B").unwrap().cardinality(), 2);
+assert_eq!(ws.derived_from("B"), Some("A"));
+
+ws.undo();
+assert!(ws.get("B").is_none());
+ws.redo();
+assert_eq!(ws.names(), vec!["A", "B"]);
+assert_eq!(ws.congruences("A").unwrap().len(), 3);
+```
+*/
+
+use std::fmt;
+use std::sync::Arc;
+use serde_json::Value;
+use crate::alg::{Algebra, BasicAlgebra, SmallAlgebra};
+use crate::alg::conlat::CongruenceLattice;
+use crate::alg::conlat::partition::Partition;
+use crate::alg::op::{IntOperation, Operation};
+use crate::alg::residual::{quotient, subalgebra, subuniverses};
+use crate::alg::sublat::SubalgebraLattice;
+use crate::eq::Equation;
+use crate::io::json::{self, algebra_to_json_value, FromJson, ToJson};
+use crate::terms::{string_to_term, Term};
+use crate::util::horner;
+
+/// A change to an [`AlgebraWorkspace`]. Algebras, terms and equations are
+/// referred to by their names in the workspace.
+#[derive(Debug, Clone)]
+pub enum Command {
+    /// Add `algebra` under `name`, which it takes as its own name.
+    AddAlgebra { name: String, algebra: Box<BasicAlgebra<i32>> },
+    /// Remove an algebra.
+    RemoveAlgebra { name: String },
+    /// Rename an algebra.
+    Rename { from: String, to: String },
+    /// Add an operation to an algebra.
+    AddOperation { algebra: String, operation: Arc<dyn Operation> },
+    /// Remove the operation named `symbol` from an algebra.
+    RemoveOperation { algebra: String, symbol: String },
+    /// Set the value of the operation named `symbol` at `args`.
+    SetOperationValue { algebra: String, symbol: String, args: Vec<i32>, value: i32 },
+    /// Add the quotient of an algebra by a congruence under `name`.
+    Quotient { algebra: String, congruence: Partition, name: String },
+    /// Add the subalgebra generated by `generators` under `name`.
+    Subalgebra { algebra: String, generators: Vec<i32>, name: String },
+    /// Save a term under `name`, replacing any term of that name.
+    SaveTerm { name: String, term: Arc<dyn Term> },
+    /// Save an equation under `name`, replacing any equation of that name.
+    SaveEquation { name: String, equation: Equation },
+    /// Replace the notes.
+    SetNotes { notes: String },
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::AddAlgebra { name, .. } => write!(f, "add {}", name),
+            Command::RemoveAlgebra { name } => write!(f, "remove {}", name),
+            Command::Rename { from, to } => write!(f, "rename {} to {}", from, to),
+            Command::AddOperation { algebra, operation } => write!(f, "add operation {} to {}", operation.symbol(), algebra),
+            Command::RemoveOperation { algebra, symbol } => write!(f, "remove operation {} from {}", symbol, algebra),
+            Command::SetOperationValue { algebra, symbol, args, value } => {
+                let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+                write!(f, "set {}({}) = {} in {}", symbol, args.join(","), value, algebra)
+            }
+            Command::Quotient { algebra, congruence, name } => write!(f, "{} = {}/{}", name, algebra, congruence),
+            Command::Subalgebra { algebra, generators, name } => write!(f, "{} = Sg_{}({:?})", name, algebra, generators),
+            Command::SaveTerm { name, term } => write!(f, "save term {} = {}", name, term),
+            Command::SaveEquation { name, equation } => write!(f, "save equation {}: {}", name, equation),
+            Command::SetNotes { .. } => write!(f, "edit notes"),
+        }
+    }
+}
+
+/// An algebra in a workspace.
+#[derive(Debug, Clone)]
+pub struct WorkspaceEntry {
+    /// The algebra, whose name is its name in the workspace.
+    pub algebra: BasicAlgebra<i32>,
+    /// The name of the algebra it was derived from, if any.
+    pub derived_from: Option<String>,
+    /// The congruences, if computed.
+    pub congruences: Option<Vec<Partition>>,
+    /// The nonempty subuniverses, if computed.
+    pub subuniverses: Option<Vec<Vec<i32>>>,
+}
+
+impl WorkspaceEntry {
+    fn new(algebra: BasicAlgebra<i32>, derived_from: Option<String>) -> Self {
+        WorkspaceEntry { algebra, derived_from, congruences: None, subuniverses: None }
+    }
+}
+
+/// What the commands change.
+#[derive(Debug, Clone, Default)]
+struct State {
+    entries: Vec<WorkspaceEntry>,
+    terms: Vec<(String, Arc<dyn Term>)>,
+    equations: Vec<(String, Equation)>,
+    notes: String,
+}
+
+/// Named algebras, terms and equations with an undo/redo log of the
+/// commands that built them.
+#[derive(Debug, Clone, Default)]
+pub struct AlgebraWorkspace {
+    state: State,
+    done: Vec<(Command, State)>,
+    undone: Vec<(Command, State)>,
+}
+
+impl AlgebraWorkspace {
+    /// An empty workspace.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The names of the algebras, in the order they were added.
+    pub fn names(&self) -> Vec<&str> {
+        self.state.entries.iter().map(|e| e.algebra.name()).collect()
+    }
+
+    /// The entries, in the order they were added.
+    pub fn entries(&self) -> &[WorkspaceEntry] {
+        &self.state.entries
+    }
+
+    /// The algebra named `name`.
+    pub fn get(&self, name: &str) -> Option<&BasicAlgebra<i32>> {
+        self.position(name).map(|i| &self.state.entries[i].algebra)
+    }
+
+    /// The name of the algebra `name` was derived from, if any.
+    pub fn derived_from(&self, name: &str) -> Option<&str> {
+        self.position(name).and_then(|i| self.state.entries[i].derived_from.as_deref())
+    }
+
+    /// The saved terms with their names, in the order they were saved.
+    pub fn terms(&self) -> impl Iterator<Item = (&str, &dyn Term)> {
+        self.state.terms.iter().map(|(name, term)| (name.as_str(), term.as_ref()))
+    }
+
+    /// The saved equations with their names, in the order they were saved.
+    pub fn equations(&self) -> impl Iterator<Item = (&str, &Equation)> {
+        self.state.equations.iter().map(|(name, eq)| (name.as_str(), eq))
+    }
+
+    /// The notes.
+    pub fn notes(&self) -> &str {
+        &self.state.notes
+    }
+
+    /// The congruences of the algebra `name`, computing them if needed.
+    pub fn congruences(&mut self, name: &str) -> Result<&[Partition], String> {
+        let i = self.find(name)?;
+        let entry = &mut self.state.entries[i];
+        if entry.congruences.is_none() {
+            let mut con = CongruenceLattice::new(entry.algebra.clone_box());
+            let universe = CongruenceLattice::universe(&mut con).clone();
+            entry.congruences = Some(universe);
+        }
+        Ok(entry.congruences.as_deref().unwrap_or_default())
+    }
+
+    /// The nonempty subuniverses of the algebra `name`, sorted, computing
+    /// them if needed.
+    pub fn subuniverses(&mut self, name: &str) -> Result<&[Vec<i32>], String> {
+        let i = self.find(name)?;
+        let entry = &mut self.state.entries[i];
+        if entry.subuniverses.is_none() {
+            entry.subuniverses = Some(subuniverses(&entry.algebra)?);
+        }
+        Ok(entry.subuniverses.as_deref().unwrap_or_default())
+    }
+
+    /// The commands in effect, oldest first.
+    pub fn history(&self) -> impl Iterator<Item = &Command> {
+        self.done.iter().map(|(cmd, _)| cmd)
+    }
+
+    /// The command `undo` would undo.
+    pub fn undo_command(&self) -> Option<&Command> {
+        self.done.last().map(|(cmd, _)| cmd)
+    }
+
+    /// The command `redo` would redo.
+    pub fn redo_command(&self) -> Option<&Command> {
+        self.undone.last().map(|(cmd, _)| cmd)
+    }
+
+    /// Run `cmd` and log it, discarding the commands undone before.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the command ran
+    /// * `Err(String)` - If it refers to a missing algebra or operation,
+    ///   would reuse a name or has invalid arguments; the workspace is then
+    ///   unchanged
+    pub fn execute(&mut self, cmd: Command) -> Result<(), String> {
+        let before = self.state.clone();
+        if let Err(e) = self.apply(&cmd) {
+            self.state = before;
+            return Err(e);
+        }
+        self.done.push((cmd, before));
+        self.undone.clear();
+        Ok(())
+    }
+
+    /// Undo the last command in effect, returning it.
+    pub fn undo(&mut self) -> Option<&Command> {
+        let (cmd, before) = self.done.pop()?;
+        let after = std::mem::replace(&mut self.state, before);
+        self.undone.push((cmd, after));
+        self.undone.last().map(|(cmd, _)| cmd)
+    }
+
+    /// Redo the last command undone, returning it.
+    pub fn redo(&mut self) -> Option<&Command> {
+        let (cmd, after) = self.undone.pop()?;
+        let before = std::mem::replace(&mut self.state, after);
+        self.done.push((cmd, before));
+        self.done.last().map(|(cmd, _)| cmd)
+    }
+
+    /// Write the workspace to a project file.
+    ///
+    /// # Arguments
+    /// * `path` - The file to write, conventionally ending in `.uaproj`
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        std::fs::write(path, self.to_json_str()).map_err(|e| format!("Failed to write project {}: {}", path, e))
+    }
+
+    /// Read a workspace written by [`save`](Self::save), with an empty
+    /// history.
+    ///
+    /// # Arguments
+    /// * `path` - The file to read
+    pub fn load(path: &str) -> Result<Self, String> {
+        let json = std::fs::read_to_string(path).map_err(|e| format!("Failed to read project {}: {}", path, e))?;
+        Self::from_json_str(&json).map_err(|e| format!("Invalid project {}: {}", path, e))
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.state.entries.iter().position(|e| e.algebra.name() == name)
+    }
+
+    fn find(&self, name: &str) -> Result<usize, String> {
+        self.position(name).ok_or_else(|| format!("No algebra named {}", name))
+    }
+
+    fn check_new_name(&self, name: &str) -> Result<(), String> {
+        if name.is_empty() {
+            return Err("The name is empty".to_string());
+        }
+        if self.position(name).is_some() {
+            return Err(format!("There is already an algebra named {}", name));
+        }
+        Ok(())
+    }
+
+    /// Replace the operations of the algebra at `i`, forgetting what was
+    /// computed from the old ones.
+    fn set_operations(&mut self, i: usize, ops: Vec<Box<dyn Operation>>) {
+        let entry = &mut self.state.entries[i];
+        entry.algebra = with_operations(&entry.algebra, ops);
+        entry.congruences = None;
+        entry.subuniverses = None;
+    }
+
+    fn apply(&mut self, cmd: &Command) -> Result<(), String> {
+        match cmd {
+            Command::AddAlgebra { name, algebra } => {
+                self.check_new_name(name)?;
+                let mut algebra = (**algebra).clone();
+                algebra.set_name(name.clone());
+                self.state.entries.push(WorkspaceEntry::new(algebra, None));
+            }
+            Command::RemoveAlgebra { name } => {
+                let i = self.find(name)?;
+                self.state.entries.remove(i);
+            }
+            Command::Rename { from, to } => {
+                let i = self.find(from)?;
+                self.check_new_name(to)?;
+                self.state.entries[i].algebra.set_name(to.clone());
+                for entry in &mut self.state.entries {
+                    if entry.derived_from.as_ref() == Some(from) {
+                        entry.derived_from = Some(to.clone());
+                    }
+                }
+            }
+            Command::AddOperation { algebra, operation } => {
+                let i = self.find(algebra)?;
+                let alg = &self.state.entries[i].algebra;
+                if operation.get_set_size() != alg.cardinality() {
+                    return Err(format!("{} is defined on {} elements but {} has {}",
+                        operation.symbol(), operation.get_set_size(), algebra, alg.cardinality()));
+                }
+                if operation_index(alg, operation.symbol().name()).is_ok() {
+                    return Err(format!("{} already has an operation named {}", algebra, operation.symbol().name()));
+                }
+                let mut ops = alg.operations();
+                ops.push(operation.clone_box());
+                self.set_operations(i, ops);
+            }
+            Command::RemoveOperation { algebra, symbol } => {
+                let i = self.find(algebra)?;
+                let alg = &self.state.entries[i].algebra;
+                let k = operation_index(alg, symbol)?;
+                let mut ops = alg.operations();
+                ops.remove(k);
+                self.set_operations(i, ops);
+            }
+            Command::SetOperationValue { algebra, symbol, args, value } => {
+                let i = self.find(algebra)?;
+                let alg = &self.state.entries[i].algebra;
+                let k = operation_index(alg, symbol)?;
+                let mut ops = alg.operations();
+                let mut edited = table_operation(ops[k].as_ref())?;
+                edited.set_value(args, *value)?;
+                ops[k] = Box::new(edited);
+                self.set_operations(i, ops);
+            }
+            Command::Quotient { algebra, congruence, name } => {
+                let i = self.find(algebra)?;
+                self.check_new_name(name)?;
+                let alg = &self.state.entries[i].algebra;
+                if congruence.universe_size() != alg.cardinality() as usize {
+                    return Err(format!("{} is a partition of {} elements but {} has {}",
+                        congruence, congruence.universe_size(), algebra, alg.cardinality()));
+                }
+                check_congruence(alg, congruence)?;
+                let mut q = quotient(alg, congruence)?;
+                q.set_name(name.clone());
+                self.state.entries.push(WorkspaceEntry::new(q, Some(algebra.clone())));
+            }
+            Command::Subalgebra { algebra, generators, name } => {
+                let i = self.find(algebra)?;
+                self.check_new_name(name)?;
+                let alg = &self.state.entries[i].algebra;
+                if let Some(g) = generators.iter().find(|&&g| g < 0 || g >= alg.cardinality()) {
+                    return Err(format!("Generator {} is out of range [0, {})", g, alg.cardinality()));
+                }
+                let sub = SubalgebraLattice::new_safe(alg.clone_box())?;
+                let elems = sub.sg(generators).elements().clone();
+                if elems.is_empty() {
+                    return Err(format!("The subuniverse of {} generated by {:?} is empty", algebra, generators));
+                }
+                let mut s = subalgebra(alg, &elems)?;
+                s.set_name(name.clone());
+                self.state.entries.push(WorkspaceEntry::new(s, Some(algebra.clone())));
+            }
+            Command::SaveTerm { name, term } => {
+                save_named(&mut self.state.terms, name, term.clone())?;
+            }
+            Command::SaveEquation { name, equation } => {
+                save_named(&mut self.state.equations, name, equation.clone())?;
+            }
+            Command::SetNotes { notes } => {
+                self.state.notes = notes.clone();
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ToJson for AlgebraWorkspace {
+    const KIND: &'static str = "project";
+
+    fn to_json_value(&self) -> Value {
+        let algebras: Vec<Value> = self.state.entries.iter()
+            .map(|entry| {
+                let mut value = algebra_to_json_value(&entry.algebra).unwrap_or(Value::Null);
+                value["derived_from"] = serde_json::json!(entry.derived_from);
+                value["congruences"] = match &entry.congruences {
+                    Some(pars) => Value::from(pars.iter().map(|p| p.to_json_value()).collect::<Vec<_>>()),
+                    None => Value::Null,
+                };
+                value["subuniverses"] = serde_json::json!(entry.subuniverses);
+                value
+            })
+            .collect();
+        let terms: Vec<Value> = self.state.terms.iter()
+            .map(|(name, term)| serde_json::json!({ "name": name, "term": term.to_string() }))
+            .collect();
+        let equations: Vec<Value> = self.state.equations.iter()
+            .map(|(name, eq)| {
+                let mut value = eq.to_json_value();
+                value["name"] = Value::from(name.as_str());
+                value
+            })
+            .collect();
+        serde_json::json!({
+            "algebras": algebras,
+            "terms": terms,
+            "equations": equations,
+            "notes": self.state.notes,
+        })
+    }
+}
+
+impl FromJson for AlgebraWorkspace {
+    fn from_json_value(value: &Value) -> Result<Self, String> {
+        let mut state = State::default();
+        for alg in list_field(value, "algebras")? {
+            let mut entry = WorkspaceEntry::new(BasicAlgebra::<i32>::from_json_value(alg)?, None);
+            entry.derived_from = alg.get("derived_from").and_then(Value::as_str).map(String::from);
+            if let Some(pars) = alg.get("congruences").and_then(Value::as_array) {
+                entry.congruences = Some(pars.iter().map(Partition::from_json_value).collect::<Result<_, String>>()?);
+            }
+            if let Some(subs) = alg.get("subuniverses").filter(|v| !v.is_null()) {
+                entry.subuniverses = Some(serde_json::from_value(subs.clone()).map_err(|e| format!("Invalid subuniverses: {}", e))?);
+            }
+            if state.entries.iter().any(|e| e.algebra.name() == entry.algebra.name()) {
+                return Err(format!("Two algebras are named {}", entry.algebra.name()));
+            }
+            state.entries.push(entry);
+        }
+        for term in list_field(value, "terms")? {
+            let name = json::str_field(term, "name", "term")?;
+            let parsed = string_to_term(json::str_field(term, "term", "term")?)?;
+            save_named(&mut state.terms, name, Arc::from(parsed))?;
+        }
+        for eq in list_field(value, "equations")? {
+            let name = json::str_field(eq, "name", "equation")?;
+            save_named(&mut state.equations, name, Equation::from_json_value(eq)?)?;
+        }
+        state.notes = value.get("notes").and_then(Value::as_str).unwrap_or_default().to_string();
+        Ok(AlgebraWorkspace { state, ..Default::default() })
+    }
+}
+
+/// The list under `key` of a project, empty if absent.
+fn list_field<'a>(value: &'a Value, key: &str) -> Result<&'a [Value], String> {
+    match value.get(key) {
+        Some(list) => list.as_array().map(Vec::as_slice).ok_or_else(|| format!("\"{}\" of project must be a list", key)),
+        None => Ok(&[]),
+    }
+}
+
+/// Save `item` under `name` in `items`, replacing an item of that name.
+fn save_named<T>(items: &mut Vec<(String, T)>, name: &str, item: T) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("The name is empty".to_string());
+    }
+    match items.iter_mut().find(|(n, _)| n == name) {
+        Some(slot) => slot.1 = item,
+        None => items.push((name.to_string(), item)),
+    }
+    Ok(())
+}
+
+/// `alg` with its operations replaced by `ops`.
+fn with_operations(alg: &BasicAlgebra<i32>, ops: Vec<Box<dyn Operation>>) -> BasicAlgebra<i32> {
+    let mut result = BasicAlgebra::new(alg.name().to_string(), (0..alg.cardinality()).collect(), ops);
+    result.set_description(alg.description().map(|d| d.to_string()));
+    result
+}
+
+/// The index of the operation of `alg` named `symbol`.
+fn operation_index(alg: &BasicAlgebra<i32>, symbol: &str) -> Result<usize, String> {
+    alg.get_operations_ref().iter()
+        .position(|op| op.symbol().name() == symbol)
+        .ok_or_else(|| format!("{} has no operation named {}", alg.name(), symbol))
+}
+
+/// A table based copy of `op`, which can be edited.
+fn table_operation(op: &dyn Operation) -> Result<IntOperation, String> {
+    let size = op.get_set_size();
+    let arity = op.arity() as usize;
+    let table = match op.get_table() {
+        Some(table) => table.to_vec(),
+        None => (0..crate::terms::compiled_term::table_size(size, arity)?)
+            .map(|c| op.int_value_at(&horner::horner_inv_same_size(c as i32, size, arity)))
+            .collect::<Result<Vec<i32>, String>>()?,
+    };
+    IntOperation::new(op.symbol().clone(), size, table)
+}
+
+/// Check that the operations of `alg` respect `par`, so that the quotient
+/// computed from representatives is well defined.
+fn check_congruence(alg: &BasicAlgebra<i32>, par: &Partition) -> Result<(), String> {
+    let n = alg.cardinality();
+    for op in alg.get_operations_ref() {
+        let arity = op.arity() as usize;
+        for c in 0..crate::terms::compiled_term::table_size(n, arity)? {
+            let args = horner::horner_inv_same_size(c as i32, n, arity);
+            let reps: Vec<i32> = args.iter().map(|&a| par.representative(a as usize) as i32).collect();
+            if !par.is_related(op.int_value_at(&args)? as usize, op.int_value_at(&reps)? as usize) {
+                return Err(format!("{} is not a congruence: {} does not respect it", par, op.symbol()));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::op::{operations, OperationSymbol};
+
+    fn cyclic(n: i32) -> BasicAlgebra<i32> {
+        let plus = operations::make_int_operation(OperationSymbol::new("+", 2, false), n,
+            (0..n * n).map(|k| (k % n + k / n) % n).collect()).unwrap();
+        BasicAlgebra::new(format!("Z{}", n), (0..n).collect(), vec![plus])
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut ws = AlgebraWorkspace::new();
+        ws.execute(Command::AddAlgebra { name: "A".to_string(), algebra: Box::new(cyclic(4)) }).unwrap();
+        let congruence = Partition::new(vec![-2, -2, 0, 1]).unwrap();
+        ws.execute(Command::Quotient { algebra: "A".to_string(), congruence, name: "Q".to_string() }).unwrap();
+        ws.execute(Command::Rename { from: "A".to_string(), to: "Z4".to_string() }).unwrap();
+        assert_eq!(ws.names(), vec!["Z4", "Q"]);
+        assert_eq!(ws.derived_from("Q"), Some("Z4"));
+        assert_eq!(ws.get("Q").unwrap().cardinality(), 2);
+        assert_eq!(ws.history().count(), 3);
+
+        assert_eq!(ws.undo().unwrap().to_string(), "rename A to Z4");
+        assert_eq!(ws.names(), vec!["A", "Q"]);
+        assert_eq!(ws.derived_from("Q"), Some("A"));
+        ws.undo();
+        assert_eq!(ws.names(), vec!["A"]);
+        assert!(ws.redo().is_some());
+        assert_eq!(ws.names(), vec!["A", "Q"]);
+
+        // A new command discards what was undone.
+        ws.execute(Command::RemoveAlgebra { name: "Q".to_string() }).unwrap();
+        assert!(ws.redo_command().is_none());
+        assert!(ws.redo().is_none());
+        assert_eq!(ws.names(), vec!["A"]);
+    }
+
+    #[test]
+    fn test_operation_commands() {
+        let mut ws = AlgebraWorkspace::new();
+        ws.execute(Command::AddAlgebra { name: "A".to_string(), algebra: Box::new(cyclic(2)) }).unwrap();
+        let neg = operations::make_int_operation(OperationSymbol::new("-", 1, false), 2, vec![0, 1]).unwrap();
+        ws.execute(Command::AddOperation { algebra: "A".to_string(), operation: Arc::from(neg) }).unwrap();
+        ws.execute(Command::SetOperationValue {
+            algebra: "A".to_string(), symbol: "+".to_string(), args: vec![1, 1], value: 1,
+        }).unwrap();
+        let a = ws.get("A").unwrap();
+        assert_eq!(a.operations().len(), 2);
+        assert_eq!(a.get_operations_ref()[0].int_value_at(&[1, 1]).unwrap(), 1);
+        ws.execute(Command::RemoveOperation { algebra: "A".to_string(), symbol: "-".to_string() }).unwrap();
+        assert_eq!(ws.get("A").unwrap().operations().len(), 1);
+        ws.undo();
+        ws.undo();
+        assert_eq!(ws.get("A").unwrap().get_operations_ref()[0].int_value_at(&[1, 1]).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_failures_leave_workspace_unchanged() {
+        let mut ws = AlgebraWorkspace::new();
+        ws.execute(Command::AddAlgebra { name: "A".to_string(), algebra: Box::new(cyclic(4)) }).unwrap();
+        assert!(ws.execute(Command::AddAlgebra { name: "A".to_string(), algebra: Box::new(cyclic(2)) }).is_err());
+        assert!(ws.execute(Command::Rename { from: "B".to_string(), to: "C".to_string() }).is_err());
+        // {0, 1} {2, 3} is not a congruence of Z4.
+        let par = Partition::new(vec![-2, 0, -2, 2]).unwrap();
+        assert!(ws.execute(Command::Quotient { algebra: "A".to_string(), congruence: par, name: "Q".to_string() }).is_err());
+        assert!(ws.execute(Command::SetOperationValue {
+            algebra: "A".to_string(), symbol: "+".to_string(), args: vec![0, 0], value: 4,
+        }).is_err());
+        assert_eq!(ws.names(), vec!["A"]);
+        assert_eq!(ws.history().count(), 1);
+    }
+
+    #[test]
+    fn test_lattices_are_kept_until_operations_change() {
+        let mut ws = AlgebraWorkspace::new();
+        ws.execute(Command::AddAlgebra { name: "A".to_string(), algebra: Box::new(cyclic(4)) }).unwrap();
+        assert_eq!(ws.congruences("A").unwrap().len(), 3);
+        assert_eq!(ws.subuniverses("A").unwrap().len(), 3);
+        ws.execute(Command::Rename { from: "A".to_string(), to: "B".to_string() }).unwrap();
+        assert!(ws.entries()[0].congruences.is_some());
+        ws.execute(Command::SetOperationValue {
+            algebra: "B".to_string(), symbol: "+".to_string(), args: vec![1, 1], value: 0,
+        }).unwrap();
+        assert!(ws.entries()[0].congruences.is_none());
+        assert!(ws.entries()[0].subuniverses.is_none());
+        ws.undo();
+        assert!(ws.entries()[0].congruences.is_some());
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let mut ws = AlgebraWorkspace::new();
+        ws.execute(Command::AddAlgebra { name: "A".to_string(), algebra: Box::new(cyclic(4)) }).unwrap();
+        ws.execute(Command::Subalgebra { algebra: "A".to_string(), generators: vec![2], name: "B".to_string() }).unwrap();
+        let term = string_to_term("f(x,y)").unwrap();
+        ws.execute(Command::SaveTerm { name: "sum".to_string(), term: Arc::from(term) }).unwrap();
+        let eq = Equation::new(string_to_term("f(x,y)").unwrap(), string_to_term("f(y,x)").unwrap());
+        ws.execute(Command::SaveEquation { name: "comm".to_string(), equation: eq }).unwrap();
+        ws.execute(Command::SetNotes { notes: "cyclic groups".to_string() }).unwrap();
+        ws.congruences("A").unwrap();
+
+        let path = std::env::temp_dir().join(format!("workspace_{}.uaproj", std::process::id()));
+        let path = path.to_str().unwrap();
+        ws.save(path).unwrap();
+        let mut loaded = AlgebraWorkspace::load(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        assert_eq!(loaded.names(), vec!["A", "B"]);
+        assert_eq!(loaded.derived_from("B"), Some("A"));
+        assert_eq!(loaded.get("B").unwrap().cardinality(), 2);
+        assert_eq!(loaded.entries()[0].congruences.as_ref().unwrap().len(), 3);
+        assert!(loaded.entries()[0].subuniverses.is_none());
+        let terms: Vec<String> = loaded.terms().map(|(name, t)| format!("{} = {}", name, t)).collect();
+        assert_eq!(terms, vec![format!("sum = {}", string_to_term("f(x,y)").unwrap())]);
+        assert_eq!(loaded.equations().next().unwrap().0, "comm");
+        assert_eq!(loaded.notes(), "cyclic groups");
+        assert_eq!(loaded.history().count(), 0);
+        assert_eq!(loaded.congruences("A").unwrap(), ws.congruences("A").unwrap());
+    }
+}
@@ -0,0 +1,315 @@
+/*!
+ * Structured telemetry for long computations.
+ *
+ * Closures, congruence lattices and free algebras count what they do
+ * (elements, operation applications, passes) and time their phases into
+ * the [`Recorder`] installed for the current thread, if any. [`record`]
+ * runs a computation with a fresh recorder and returns its
+ * [`ComputationReport`] alongside the result. With no recorder installed
+ * the calls do nothing, so computations pay for telemetry only when it is
+ * asked for.
+ *
+ * Counts are made once per pass or per computation, never per operation
+ * application, and only on the thread the recorder is installed on.
+ *
+ * With the `tracing` feature every phase is also an info-level
+ * [`tracing`] span named `phase` with the label in its `name` field, and
+ * every count a debug-level event, whether or not a recorder is installed,
+ * so a subscriber can log the course and timing of a computation.
+ */
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Counter: elements of the closures computed.
+pub const ELEMENTS: &str = "elements";
+/// Counter: operation applications, one per tuple of arguments.
+pub const APPLICATIONS: &str = "applications";
+/// Counter: passes of closure computations.
+pub const PASSES: &str = "passes";
+/// Counter: principal congruences computed.
+pub const PRINCIPALS: &str = "principal congruences";
+/// Counter: join irreducible congruences found.
+pub const JOIN_IRREDUCIBLES: &str = "join irreducibles";
+/// Counter: congruences in the universes computed.
+pub const CONGRUENCES: &str = "congruences";
+
+/// The time spent in one phase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhaseReport {
+    /// The phase labels, outermost first, joined by " / ".
+    pub name: String,
+    /// The number of times the phase was entered.
+    pub calls: u64,
+    /// The total time spent in the phase.
+    pub time: Duration,
+}
+
+/// The counters and phase timings of a computation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComputationReport {
+    /// The counters by name.
+    pub counters: BTreeMap<String, u64>,
+    /// The phases, in the order they were first entered.
+    pub phases: Vec<PhaseReport>,
+    /// The time since recording started.
+    pub total: Duration,
+}
+
+impl ComputationReport {
+    /// The value of a counter, 0 if it was never counted.
+    pub fn counter(&self, name: &str) -> u64 {
+        self.counters.get(name).copied().unwrap_or(0)
+    }
+
+    /// The timing of a phase, by its full name.
+    pub fn phase(&self, name: &str) -> Option<&PhaseReport> {
+        self.phases.iter().find(|p| p.name == name)
+    }
+}
+
+impl fmt::Display for ComputationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "total: {:.3}s", self.total.as_secs_f64())?;
+        for (name, value) in &self.counters {
+            writeln!(f, "{}: {}", name, value)?;
+        }
+        for phase in &self.phases {
+            writeln!(f, "{}: {:.3}s in {} calls", phase.name, phase.time.as_secs_f64(), phase.calls)?;
+        }
+        Ok(())
+    }
+}
+
+/// Collects counters and phase timings for the computations run while it
+/// is installed.
+///
+/// # Examples
+/// ```
+/// use uacalc::telemetry::{self, Recorder};
+///
+/// let recorder = Recorder::new();
+/// {
+///     let _installed = recorder.install();
+///     let _phase = telemetry::phase("search");
+///     telemetry::count(telemetry::PASSES, 2);
+/// }
+/// telemetry::count(telemetry::PASSES, 5); // not installed: ignored
+/// let report = recorder.report();
+/// assert_eq!(report.counter(telemetry::PASSES), 2);
+/// assert_eq!(report.phase("search").unwrap().calls, 1);
+/// ```
+#[derive(Clone)]
+pub struct Recorder {
+    inner: Arc<Mutex<RecorderState>>,
+}
+
+struct RecorderState {
+    start: Instant,
+    counters: BTreeMap<String, u64>,
+    phases: Vec<PhaseReport>,
+    open: Vec<String>,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Recorder {
+    /// Create a recorder; its total time is measured from now.
+    pub fn new() -> Self {
+        Recorder {
+            inner: Arc::new(Mutex::new(RecorderState {
+                start: Instant::now(),
+                counters: BTreeMap::new(),
+                phases: Vec::new(),
+                open: Vec::new(),
+            })),
+        }
+    }
+
+    /// Add `n` to a counter.
+    pub fn count(&self, name: &str, n: u64) {
+        *self.inner.lock().unwrap().counters.entry(name.to_string()).or_insert(0) += n;
+    }
+
+    /// Enter a phase, which is timed until the returned guard is dropped.
+    pub fn phase(&self, label: &str) -> PhaseTimer {
+        let mut state = self.inner.lock().unwrap();
+        state.open.push(label.to_string());
+        let name = state.open.join(" / ");
+        PhaseTimer { recorder: Some(self.clone()), name, start: Instant::now(), #[cfg(feature = "tracing")] _span: span(label) }
+    }
+
+    /// The counters and phase timings so far.
+    pub fn report(&self) -> ComputationReport {
+        let state = self.inner.lock().unwrap();
+        ComputationReport {
+            counters: state.counters.clone(),
+            phases: state.phases.clone(),
+            total: state.start.elapsed(),
+        }
+    }
+
+    /// Make this the current thread's recorder until the guard is dropped.
+    pub fn install(&self) -> InstallGuard {
+        let previous = CURRENT.with(|c| c.replace(Some(self.clone())));
+        InstallGuard { previous }
+    }
+}
+
+impl fmt::Debug for Recorder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Recorder").field("report", &self.report()).finish()
+    }
+}
+
+/// Guard returned by [`Recorder::phase`] and [`phase`]; adds the time
+/// since the phase was entered on drop.
+pub struct PhaseTimer {
+    recorder: Option<Recorder>,
+    name: String,
+    start: Instant,
+    #[cfg(feature = "tracing")]
+    _span: tracing::span::EnteredSpan,
+}
+
+#[cfg(feature = "tracing")]
+fn span(label: &str) -> tracing::span::EnteredSpan {
+    tracing::info_span!("phase", name = label).entered()
+}
+
+impl Drop for PhaseTimer {
+    fn drop(&mut self) {
+        let Some(recorder) = self.recorder.take() else { return };
+        let time = self.start.elapsed();
+        let mut state = recorder.inner.lock().unwrap();
+        state.open.pop();
+        match state.phases.iter_mut().find(|p| p.name == self.name) {
+            Some(phase) => {
+                phase.calls += 1;
+                phase.time += time;
+            }
+            None => {
+                let name = std::mem::take(&mut self.name);
+                state.phases.push(PhaseReport { name, calls: 1, time });
+            }
+        }
+    }
+}
+
+/// Guard returned by [`Recorder::install`]; restores the previous recorder
+/// on drop.
+pub struct InstallGuard {
+    previous: Option<Recorder>,
+}
+
+impl Drop for InstallGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT.with(|c| *c.borrow_mut() = previous);
+    }
+}
+
+/// The recorder installed for the current thread, if any.
+pub fn current() -> Option<Recorder> {
+    CURRENT.with(|c| c.borrow().clone())
+}
+
+/// Add `n` to a counter of the current thread's recorder, if any.
+pub fn count(name: &str, n: u64) {
+    #[cfg(feature = "tracing")]
+    tracing::debug!(counter = name, n);
+    CURRENT.with(|c| {
+        if let Some(recorder) = c.borrow().as_ref() {
+            recorder.count(name, n);
+        }
+    });
+}
+
+/// Enter a phase of the current thread's recorder, if any; the guard does
+/// nothing without one.
+pub fn phase(label: &str) -> PhaseTimer {
+    match current() {
+        Some(recorder) => recorder.phase(label),
+        None => PhaseTimer {
+            recorder: None,
+            name: String::new(),
+            start: Instant::now(),
+            #[cfg(feature = "tracing")]
+            _span: span(label),
+        },
+    }
+}
+
+/// Run `f` with a fresh recorder installed and return its report with the
+/// result.
+///
+/// # Arguments
+/// * `f` - The computation
+///
+/// # Returns
+/// The result of `f` and what it recorded
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use uacalc::alg::{BasicAlgebra, SmallAlgebra};
+/// use uacalc::alg::conlat::CongruenceLattice;
+/// use uacalc::alg::op::{OperationSymbol, operations};
+/// use uacalc::telemetry;
+///
+/// let plus = OperationSymbol::new("+", 2, false);
+/// let op = operations::make_int_operation(plus, 4, (0..16).map(|k| (k % 4 + k / 4) % 4).collect()).unwrap();
+/// let z4 = BasicAlgebra::new("Z4".to_string(), (0..4).collect::<HashSet<i32>>(), vec![op]);
+/// let mut con = CongruenceLattice::new(Box::new(z4) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+/// let (size, report) = telemetry::record(|| con.try_universe().map(|u| u.len()));
+/// assert_eq!(size, Ok(3));
+/// assert_eq!(report.counter(telemetry::CONGRUENCES), 3);
+/// ```
+pub fn record<T>(f: impl FnOnce() -> T) -> (T, ComputationReport) {
+    let recorder = Recorder::new();
+    let result = {
+        let _installed = recorder.install();
+        f()
+    };
+    (result, recorder.report())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record() {
+        let ((), report) = record(|| {
+            let _outer = phase("outer");
+            for _ in 0..3 {
+                let _inner = phase("inner");
+                count(PASSES, 1);
+            }
+            count(ELEMENTS, 10);
+            // A nested recording does not leak into the outer one.
+            let ((), inner) = record(|| count(ELEMENTS, 5));
+            assert_eq!(inner.counter(ELEMENTS), 5);
+        });
+        assert!(current().is_none());
+        assert_eq!(report.counter(PASSES), 3);
+        assert_eq!(report.counter(ELEMENTS), 10);
+        assert_eq!(report.counter(APPLICATIONS), 0);
+        let names: Vec<&str> = report.phases.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["outer / inner", "outer"]);
+        assert_eq!(report.phase("outer / inner").unwrap().calls, 3);
+        assert!(report.phase("outer").unwrap().time <= report.total);
+        assert!(report.to_string().contains("passes: 3"));
+    }
+}
@@ -0,0 +1,287 @@
+/*! Compiled terms for fast integer evaluation.
+
+A `CompiledTerm` is a term flattened into a post-order instruction array
+with every operation symbol resolved to its value table up front. Evaluation
+is then a small stack machine: no symbol lookups, no `HashMap` variable
+maps and no recursion per node.
+*/
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use crate::alg::op::{Operation, OperationSymbol};
+use crate::alg::SmallAlgebra;
+use crate::terms::Term;
+use crate::util::horner;
+
+/// A single instruction of a compiled term.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    /// Push the value of the variable at this index of the variable list.
+    Var(usize),
+    /// Pop `arity` values and push the value of operation `op` on them.
+    Apply { op: usize, arity: usize },
+}
+
+/// A term compiled against a fixed algebra and variable list.
+///
+/// Operation tables are shared through `Arc` so clones are cheap and
+/// several compiled terms can be built from one table set.
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use uacalc::alg::BasicAlgebra;
+/// use uacalc::alg::op::{OperationSymbol, operations};
+/// use uacalc::terms::{CompiledTerm, string_to_term};
+///
+/// let sym = OperationSymbol::new("f", 2, false);
+/// let op = operations::make_int_operation(sym, 2, vec![0, 1, 1, 1]).unwrap();
+/// let alg = BasicAlgebra::new("A".to_string(), (0..2).collect::<HashSet<i32>>(), vec![op]);
+///
+/// let t = string_to_term("f(x,f(y,x))").unwrap();
+/// let ct = CompiledTerm::compile_simple(t.as_ref(), &alg).unwrap();
+/// assert_eq!(ct.eval(&[0, 1]), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CompiledTerm {
+    instructions: Vec<Instruction>,
+    tables: Vec<Arc<[i32]>>,
+    var_list: Vec<String>,
+    alg_size: i32,
+    max_stack: usize,
+}
+
+impl CompiledTerm {
+    /// Compile `term` against `alg` using `var_list` as the argument order.
+    ///
+    /// # Arguments
+    /// * `term` - The term to compile
+    /// * `alg` - The algebra supplying the operations
+    /// * `var_list` - The variable order; must contain every variable of `term`
+    ///
+    /// # Returns
+    /// * `Ok(CompiledTerm)` - The compiled term
+    /// * `Err(String)` - If a variable or operation cannot be resolved
+    pub fn compile(
+        term: &dyn Term,
+        alg: &dyn SmallAlgebra<UniverseItem = i32>,
+        var_list: &[String],
+    ) -> Result<Self, String> {
+        let mut tables = Vec::new();
+        let mut handles = HashMap::new();
+        let instructions = compile_instructions(term, alg, var_list, &mut tables, &mut handles)?;
+        Ok(Self::from_parts(instructions, tables, var_list.to_vec(), alg.cardinality()))
+    }
+
+    /// Compile `term` against `alg` using the term's own variable list.
+    pub fn compile_simple(
+        term: &dyn Term,
+        alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    ) -> Result<Self, String> {
+        let var_list = term.get_variable_list();
+        Self::compile(term, alg, &var_list)
+    }
+
+    fn from_parts(
+        instructions: Vec<Instruction>,
+        tables: Vec<Arc<[i32]>>,
+        var_list: Vec<String>,
+        alg_size: i32,
+    ) -> Self {
+        let mut depth = 0usize;
+        let mut max_stack = 0usize;
+        for instr in &instructions {
+            match *instr {
+                Instruction::Var(_) => depth += 1,
+                Instruction::Apply { arity, .. } => depth = depth + 1 - arity,
+            }
+            max_stack = max_stack.max(depth);
+        }
+        CompiledTerm { instructions, tables, var_list, alg_size, max_stack }
+    }
+
+    /// Evaluate the term at `args`, indexed by the compiled variable list.
+    ///
+    /// The arguments are not range checked; use `eval_safe` for untrusted input.
+    ///
+    /// # Panics
+    /// Panics if `args` is shorter than the variable list or an argument
+    /// is outside the universe.
+    pub fn eval(&self, args: &[i32]) -> i32 {
+        let mut stack = Vec::with_capacity(self.max_stack);
+        self.eval_with_stack(args, &mut stack)
+    }
+
+    /// Evaluate the term at `args`, reusing `stack` as scratch space.
+    ///
+    /// This is the allocation free inner loop used when a term is evaluated
+    /// at many assignments.
+    pub fn eval_with_stack(&self, args: &[i32], stack: &mut Vec<i32>) -> i32 {
+        stack.clear();
+        let size = self.alg_size as usize;
+        for instr in &self.instructions {
+            match *instr {
+                Instruction::Var(v) => stack.push(args[v]),
+                Instruction::Apply { op, arity } => {
+                    let base = stack.len() - arity;
+                    let mut index = 0usize;
+                    for &a in stack[base..].iter().rev() {
+                        index = index * size + a as usize;
+                    }
+                    stack.truncate(base);
+                    stack.push(self.tables[op][index]);
+                }
+            }
+        }
+        stack[0]
+    }
+
+    /// Evaluate the term at `args` after checking the arguments.
+    ///
+    /// # Returns
+    /// * `Ok(i32)` - The value of the term
+    /// * `Err(String)` - If the argument count or an argument is invalid
+    pub fn eval_safe(&self, args: &[i32]) -> Result<i32, String> {
+        if args.len() != self.var_list.len() {
+            return Err(format!("Expected {} arguments, got {}", self.var_list.len(), args.len()));
+        }
+        if let Some(&a) = args.iter().find(|&&a| a < 0 || a >= self.alg_size) {
+            return Err(format!("Argument {} is out of bounds [0, {})", a, self.alg_size));
+        }
+        Ok(self.eval(args))
+    }
+
+    /// The full Horner-encoded value table of the term as an operation.
+    pub fn table(&self) -> Result<Vec<i32>, String> {
+        let total = table_size(self.alg_size, self.arity())?;
+        let mut ans = Vec::with_capacity(total);
+        let mut args = vec![0; self.arity()];
+        let mut stack = Vec::with_capacity(self.max_stack);
+        for _ in 0..total {
+            ans.push(self.eval_with_stack(&args, &mut stack));
+            increment(&mut args, self.alg_size);
+        }
+        Ok(ans)
+    }
+
+    /// The instructions in post order.
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    /// The variable order used for arguments.
+    pub fn var_list(&self) -> &[String] {
+        &self.var_list
+    }
+
+    /// The number of arguments, i.e. the length of the variable list.
+    pub fn arity(&self) -> usize {
+        self.var_list.len()
+    }
+
+    /// The size of the algebra the term was compiled against.
+    pub fn alg_size(&self) -> i32 {
+        self.alg_size
+    }
+
+    /// The maximum stack depth needed during evaluation.
+    pub fn max_stack(&self) -> usize {
+        self.max_stack
+    }
+}
+
+/// Flatten `term` into post-order instructions, registering operation tables
+/// in `tables` (deduplicated through `handles`).
+pub(crate) fn compile_instructions(
+    term: &dyn Term,
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    var_list: &[String],
+    tables: &mut Vec<Arc<[i32]>>,
+    handles: &mut HashMap<OperationSymbol, usize>,
+) -> Result<Vec<Instruction>, String> {
+    let mut instructions = Vec::new();
+    push_instructions(term, alg, var_list, tables, handles, &mut instructions)?;
+    Ok(instructions)
+}
+
+fn push_instructions(
+    term: &dyn Term,
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    var_list: &[String],
+    tables: &mut Vec<Arc<[i32]>>,
+    handles: &mut HashMap<OperationSymbol, usize>,
+    out: &mut Vec<Instruction>,
+) -> Result<(), String> {
+    if term.isa_variable() {
+        let name = term.to_string();
+        let idx = var_list.iter().position(|v| *v == name)
+            .ok_or_else(|| format!("Variable {} not in the variable list", name))?;
+        out.push(Instruction::Var(idx));
+        return Ok(());
+    }
+    let sym = term.leading_operation_symbol()
+        .ok_or_else(|| "Non-variable term without an operation symbol".to_string())?;
+    let op = operation_handle(sym, alg, tables, handles)?;
+    let children = term.get_children().unwrap_or_default();
+    for child in &children {
+        push_instructions(child.as_ref(), alg, var_list, tables, handles, out)?;
+    }
+    out.push(Instruction::Apply { op, arity: children.len() });
+    Ok(())
+}
+
+/// Resolve `sym` in `alg` to an index into `tables`, adding its table if new.
+pub(crate) fn operation_handle(
+    sym: &OperationSymbol,
+    alg: &dyn SmallAlgebra<UniverseItem = i32>,
+    tables: &mut Vec<Arc<[i32]>>,
+    handles: &mut HashMap<OperationSymbol, usize>,
+) -> Result<usize, String> {
+    if let Some(&h) = handles.get(sym) {
+        return Ok(h);
+    }
+    let op = alg.get_operation_ref(sym)
+        .ok_or_else(|| format!("Operation {} not found in algebra", sym.name()))?;
+    let table = operation_table(op)?;
+    tables.push(table);
+    handles.insert(sym.clone(), tables.len() - 1);
+    Ok(tables.len() - 1)
+}
+
+/// The Horner-encoded table of `op`, materialized if the operation is not table based.
+pub(crate) fn operation_table(op: &dyn Operation) -> Result<Arc<[i32]>, String> {
+    if let Some(table) = op.get_table() {
+        return Ok(Arc::from(table));
+    }
+    let size = op.get_set_size();
+    let arity = op.arity() as usize;
+    let total = table_size(size, arity)?;
+    let mut table = Vec::with_capacity(total);
+    for k in 0..total {
+        let args = horner::horner_inv_same_size(k as i32, size, arity);
+        table.push(op.int_value_at(&args)?);
+    }
+    Ok(Arc::from(table))
+}
+
+/// The number of assignments `size^arity`, or an error if it does not fit in an `i32`.
+pub(crate) fn table_size(size: i32, arity: usize) -> Result<usize, String> {
+    (size as u64).checked_pow(arity as u32)
+        .filter(|&t| t <= i32::MAX as u64)
+        .map(|t| t as usize)
+        .ok_or_else(|| format!("Table of size {}^{} is too large", size, arity))
+}
+
+/// Advance `args` to the next assignment in Horner order (first coordinate fastest).
+///
+/// Returns `false` once all assignments have been visited and `args` wraps to zeros.
+pub(crate) fn increment(args: &mut [i32], size: i32) -> bool {
+    for a in args.iter_mut() {
+        *a += 1;
+        if *a < size {
+            return true;
+        }
+        *a = 0;
+    }
+    false
+}
@@ -4,6 +4,13 @@ use std::sync::Arc;
 use crate::alg::op::{OperationSymbol, Operation, TermOperation, TermOperationImp, operations};
 use crate::alg::SmallAlgebra;
 
+pub mod compiled_term;
+pub use compiled_term::{CompiledTerm, Instruction};
+pub mod term_dag;
+pub use term_dag::{TermDag, DagNode};
+pub mod term_arena;
+pub use term_arena::{TermArena, ArenaNode, Subterms};
+
 /// The Term trait represents algebraic terms in universal algebra.
 /// 
 /// A term is a tree structure with variables as leaves and operations as internal nodes.
@@ -209,6 +216,55 @@ pub trait Term: Display + Debug + Send + Sync {
     /// # Arguments
     /// * `sb` - The string buffer to write to
     fn write_string_buffer(&self, sb: &mut String);
+    
+    /// Returns the number of operation symbol occurrences in the term.
+    /// 
+    /// A variable has size 0; constants count as one occurrence each.
+    /// 
+    /// # Returns
+    /// The number of non-variable nodes of the term tree
+    fn size(&self) -> usize {
+        match self.get_children() {
+            Some(children) => 1 + children.iter().map(|c| c.size()).sum::<usize>(),
+            None => 0,
+        }
+    }
+    
+    /// Returns the number of distinct subterms of the term.
+    /// 
+    /// This is the node count of the term with identical subterms shared,
+    /// as built by `TermArena`; it is at most `length()`.
+    /// 
+    /// # Returns
+    /// The number of nodes of the shared-subterm DAG
+    fn node_count(&self) -> usize {
+        TermArena::from_terms(&[self.clone_box().as_ref()]).node_count()
+    }
+    
+    /// Returns the number of times the variable `name` occurs in the term.
+    /// 
+    /// # Arguments
+    /// * `name` - The variable name
+    /// 
+    /// # Returns
+    /// The number of occurrences, 0 if the variable does not occur
+    fn occurrences_of_variable(&self, name: &str) -> usize {
+        match self.get_children() {
+            Some(children) => children.iter().map(|c| c.occurrences_of_variable(name)).sum(),
+            None => usize::from(self.to_string() == name),
+        }
+    }
+    
+    /// Returns an iterator over all subterms, the term itself first.
+    /// 
+    /// Subterms are visited in pre-order, left to right; repeated
+    /// subterms are visited once per occurrence.
+    /// 
+    /// # Returns
+    /// A `Subterms` iterator yielding boxed terms
+    fn subterms(&self) -> Subterms {
+        Subterms::new(self.clone_box())
+    }
 }
 
 /// The Variable trait extends Term for variable terms.
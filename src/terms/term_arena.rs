@@ -0,0 +1,281 @@
+/*! Terms stored as shared-subterm DAGs, independent of any algebra.
+
+A `TermArena` interns terms node by node so that identical subterms,
+within one term or across several, are stored once. Unlike `TermDag` it
+needs no algebra: it is meant for inspecting the structure of terms,
+counting distinct subterms and exporting them for visualization. Terms
+move between arenas with `import_from`, which re-interns every node so
+node indices are never mixed across arenas.
+*/
+
+use std::collections::HashMap;
+use crate::alg::op::OperationSymbol;
+use crate::terms::{NonVariableTerm, Term, VariableImp};
+
+/// A node of a `TermArena`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ArenaNode {
+    /// A variable with the given name.
+    Var(String),
+    /// An operation symbol applied to child nodes.
+    Apply { symbol: OperationSymbol, children: Vec<usize> },
+}
+
+/// A collection of terms with identical subterms merged.
+///
+/// Nodes are stored in topological order: children always precede parents.
+#[derive(Debug, Clone, Default)]
+pub struct TermArena {
+    nodes: Vec<ArenaNode>,
+    index: HashMap<ArenaNode, usize>,
+    roots: Vec<usize>,
+}
+
+impl TermArena {
+    /// Create an empty arena.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an arena holding `terms`; root `i` is `terms[i]`.
+    ///
+    /// # Examples
+    /// ```
+    /// use uacalc::terms::{string_to_term, TermArena};
+    ///
+    /// let t = string_to_term("f(g(x),g(x))").unwrap();
+    /// let arena = TermArena::from_terms(&[t.as_ref()]);
+    /// // x, g(x) and the root
+    /// assert_eq!(arena.node_count(), 3);
+    /// ```
+    pub fn from_terms(terms: &[&dyn Term]) -> Self {
+        let mut arena = Self::new();
+        for term in terms {
+            arena.add(*term);
+        }
+        arena
+    }
+
+    /// Add `term` as a new root, reusing any nodes already in the arena.
+    ///
+    /// # Returns
+    /// The node index of the term's root
+    pub fn add(&mut self, term: &dyn Term) -> usize {
+        let root = self.intern(term);
+        self.roots.push(root);
+        root
+    }
+
+    fn intern(&mut self, term: &dyn Term) -> usize {
+        let node = match (term.leading_operation_symbol(), term.get_children()) {
+            (Some(symbol), Some(children)) => ArenaNode::Apply {
+                symbol: symbol.clone(),
+                children: children.iter().map(|c| self.intern(c.as_ref())).collect(),
+            },
+            _ => ArenaNode::Var(term.to_string()),
+        };
+        self.insert(node)
+    }
+
+    fn insert(&mut self, node: ArenaNode) -> usize {
+        if let Some(&i) = self.index.get(&node) {
+            return i;
+        }
+        self.nodes.push(node.clone());
+        self.index.insert(node, self.nodes.len() - 1);
+        self.nodes.len() - 1
+    }
+
+    fn check_node(&self, node: usize) -> Result<(), String> {
+        if node >= self.nodes.len() {
+            return Err(format!("Node index {} out of range (arena has {} nodes)", node, self.nodes.len()));
+        }
+        Ok(())
+    }
+
+    /// Copy the term rooted at node `node` of `other` into this arena as a new root.
+    ///
+    /// The copy is deep: every subterm is re-interned here, so subterms
+    /// already present in this arena are shared rather than duplicated.
+    ///
+    /// # Arguments
+    /// * `other` - The arena holding the term
+    /// * `node` - The node index of the term in `other`
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - The node index of the copy in this arena
+    /// * `Err(String)` - If `node` is not a node of `other`
+    ///
+    /// # Examples
+    /// ```
+    /// use uacalc::terms::{string_to_term, TermArena};
+    ///
+    /// let t = string_to_term("f(x,g(y))").unwrap();
+    /// let source = TermArena::from_terms(&[t.as_ref()]);
+    /// let mut target = TermArena::from_terms(&[string_to_term("g(y)").unwrap().as_ref()]);
+    /// let copy = target.import_from(&source, source.roots()[0]).unwrap();
+    /// assert_eq!(target.term(copy).unwrap().to_string(), "f(x,g(y))");
+    /// // y and g(y) were already present
+    /// assert_eq!(target.node_count(), 4);
+    /// ```
+    pub fn import_from(&mut self, other: &TermArena, node: usize) -> Result<usize, String> {
+        other.check_node(node)?;
+        let mut copied = HashMap::new();
+        let root = self.import_node(other, node, &mut copied);
+        self.roots.push(root);
+        Ok(root)
+    }
+
+    fn import_node(&mut self, other: &TermArena, node: usize, copied: &mut HashMap<usize, usize>) -> usize {
+        if let Some(&i) = copied.get(&node) {
+            return i;
+        }
+        let new = match &other.nodes[node] {
+            ArenaNode::Var(name) => ArenaNode::Var(name.clone()),
+            ArenaNode::Apply { symbol, children } => ArenaNode::Apply {
+                symbol: symbol.clone(),
+                children: children.iter().map(|&c| self.import_node(other, c, copied)).collect(),
+            },
+        };
+        let i = self.insert(new);
+        copied.insert(node, i);
+        i
+    }
+
+    /// Substitute nodes for variables in the term at `node`, adding the result as a new root.
+    ///
+    /// Variables not in `map` are left unchanged. Replacements are nodes of
+    /// this arena; use `import_from` first to substitute terms from another arena.
+    ///
+    /// # Arguments
+    /// * `node` - The node index of the term
+    /// * `map` - A map from variable names to node indices of replacement terms
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - The node index of the substituted term
+    /// * `Err(String)` - If `node` or a replacement is not a node of this arena
+    pub fn substitute(&mut self, node: usize, map: &HashMap<String, usize>) -> Result<usize, String> {
+        self.check_node(node)?;
+        for &r in map.values() {
+            self.check_node(r)?;
+        }
+        let mut done = HashMap::new();
+        let root = self.substitute_node(node, map, &mut done);
+        self.roots.push(root);
+        Ok(root)
+    }
+
+    fn substitute_node(&mut self, node: usize, map: &HashMap<String, usize>, done: &mut HashMap<usize, usize>) -> usize {
+        if let Some(&i) = done.get(&node) {
+            return i;
+        }
+        let i = match self.nodes[node].clone() {
+            ArenaNode::Var(name) => map.get(&name).copied().unwrap_or(node),
+            ArenaNode::Apply { symbol, children } => {
+                let children = children.iter().map(|&c| self.substitute_node(c, map, done)).collect();
+                self.insert(ArenaNode::Apply { symbol, children })
+            }
+        };
+        done.insert(node, i);
+        i
+    }
+
+    /// Rebuild the term at node `node` as a tree.
+    ///
+    /// # Returns
+    /// * `Ok(Box<dyn Term>)` - The term
+    /// * `Err(String)` - If `node` is not a node of this arena
+    pub fn term(&self, node: usize) -> Result<Box<dyn Term>, String> {
+        self.check_node(node)?;
+        Ok(self.build_term(node))
+    }
+
+    fn build_term(&self, node: usize) -> Box<dyn Term> {
+        match &self.nodes[node] {
+            ArenaNode::Var(name) => Box::new(VariableImp::new(name)),
+            ArenaNode::Apply { symbol, children } => Box::new(NonVariableTerm::new(
+                symbol.clone(),
+                children.iter().map(|&c| self.build_term(c)).collect(),
+            )),
+        }
+    }
+
+    /// The nodes in topological order.
+    pub fn nodes(&self) -> &[ArenaNode] {
+        &self.nodes
+    }
+
+    /// The node index of each added term, in the order they were added.
+    pub fn roots(&self) -> &[usize] {
+        &self.roots
+    }
+
+    /// The number of distinct subterms over all roots.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Export the arena in Graphviz DOT format.
+    ///
+    /// Variables are drawn as boxes and operations as circles; roots are
+    /// drawn bold. Edges run from an operation to its arguments and are
+    /// labelled with the argument position when the operation has more
+    /// than one argument.
+    ///
+    /// # Returns
+    /// A string containing the DOT representation
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph Term {\n");
+        dot.push_str("  node [shape=circle];\n");
+        for (i, node) in self.nodes.iter().enumerate() {
+            let (label, shape) = match node {
+                ArenaNode::Var(name) => (name.as_str(), ", shape=box"),
+                ArenaNode::Apply { symbol, .. } => (symbol.name(), ""),
+            };
+            let style = if self.roots.contains(&i) { ", style=bold" } else { "" };
+            dot.push_str(&format!(
+                "  {} [label=\"{}\"{}{}];\n",
+                i,
+                label.replace('"', "\\\""),
+                shape,
+                style
+            ));
+        }
+        for (i, node) in self.nodes.iter().enumerate() {
+            if let ArenaNode::Apply { children, .. } = node {
+                for (pos, c) in children.iter().enumerate() {
+                    if children.len() > 1 {
+                        dot.push_str(&format!("  {} -> {} [label=\"{}\"];\n", i, c, pos));
+                    } else {
+                        dot.push_str(&format!("  {} -> {};\n", i, c));
+                    }
+                }
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// A pre-order iterator over the subterms of a term, see `Term::subterms`.
+pub struct Subterms {
+    stack: Vec<Box<dyn Term>>,
+}
+
+impl Subterms {
+    pub(crate) fn new(term: Box<dyn Term>) -> Self {
+        Subterms { stack: vec![term] }
+    }
+}
+
+impl Iterator for Subterms {
+    type Item = Box<dyn Term>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let term = self.stack.pop()?;
+        if let Some(children) = term.get_children() {
+            self.stack.extend(children.into_iter().rev());
+        }
+        Some(term)
+    }
+}
@@ -0,0 +1,229 @@
+/*! Shared term DAGs evaluated over blocks of assignments.
+
+A `TermDag` holds one or more terms over a common variable list with
+identical subterms merged (hash consing). Evaluating the DAG over a block
+of consecutive assignments computes every node's values as a column, once,
+so subterms shared between the roots are never recomputed. This is the
+engine behind batched equation checking.
+*/
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use rayon::prelude::*;
+use crate::alg::op::OperationSymbol;
+use crate::alg::SmallAlgebra;
+use crate::terms::Term;
+use crate::terms::compiled_term::{operation_handle, table_size};
+use crate::util::horner;
+
+/// The default number of assignments evaluated together in one block.
+pub const DEFAULT_BLOCK_SIZE: usize = 1024;
+
+/// A node of a `TermDag`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DagNode {
+    /// The variable at this index of the variable list.
+    Var(usize),
+    /// An operation (index into the DAG's tables) applied to child nodes.
+    Apply { op: usize, children: Vec<usize> },
+}
+
+/// A set of terms compiled against one algebra with shared subterms merged.
+///
+/// Nodes are stored in topological order: children always precede parents.
+#[derive(Debug, Clone)]
+pub struct TermDag {
+    nodes: Vec<DagNode>,
+    roots: Vec<usize>,
+    tables: Vec<Arc<[i32]>>,
+    var_list: Vec<String>,
+    alg_size: i32,
+}
+
+impl TermDag {
+    /// Compile `terms` against `alg` over the variable list `var_list`.
+    ///
+    /// # Arguments
+    /// * `terms` - The terms to compile; root `i` of the DAG is `terms[i]`
+    /// * `alg` - The algebra supplying the operations
+    /// * `var_list` - The variable order; must contain every variable of every term
+    ///
+    /// # Returns
+    /// * `Ok(TermDag)` - The compiled DAG
+    /// * `Err(String)` - If a variable or operation cannot be resolved
+    pub fn compile(
+        terms: &[&dyn Term],
+        alg: &dyn SmallAlgebra<UniverseItem = i32>,
+        var_list: &[String],
+    ) -> Result<Self, String> {
+        let mut builder = DagBuilder {
+            alg,
+            var_list,
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            tables: Vec::new(),
+            handles: HashMap::new(),
+        };
+        let mut roots = Vec::with_capacity(terms.len());
+        for term in terms {
+            roots.push(builder.add(*term)?);
+        }
+        Ok(TermDag {
+            nodes: builder.nodes,
+            roots,
+            tables: builder.tables,
+            var_list: var_list.to_vec(),
+            alg_size: alg.cardinality(),
+        })
+    }
+
+    /// The nodes in topological order.
+    pub fn nodes(&self) -> &[DagNode] {
+        &self.nodes
+    }
+
+    /// The node index of each compiled term, in the order given to `compile`.
+    pub fn roots(&self) -> &[usize] {
+        &self.roots
+    }
+
+    /// The variable order used for assignments.
+    pub fn var_list(&self) -> &[String] {
+        &self.var_list
+    }
+
+    /// The size of the algebra the DAG was compiled against.
+    pub fn alg_size(&self) -> i32 {
+        self.alg_size
+    }
+
+    /// The number of assignments, `alg_size^n` for the `n` variables of the
+    /// variable list; these include the variables of every root, not just one.
+    pub fn assignment_count(&self) -> Result<usize, String> {
+        table_size(self.alg_size, self.var_list.len())
+    }
+
+    /// The assignment with Horner index `k`.
+    pub fn assignment(&self, k: usize) -> Vec<i32> {
+        horner::horner_inv_same_size(k as i32, self.alg_size, self.var_list.len())
+    }
+
+    /// Evaluate every node at the `len` assignments starting at Horner index `start`.
+    ///
+    /// On return `buf` holds `len` values per node; use `column` to read them.
+    pub fn eval_block(&self, start: usize, len: usize, buf: &mut Vec<i32>) {
+        buf.clear();
+        buf.resize(self.nodes.len() * len, 0);
+        let size = self.alg_size as usize;
+        for (i, node) in self.nodes.iter().enumerate() {
+            let (done, rest) = buf.split_at_mut(i * len);
+            let out = &mut rest[..len];
+            match node {
+                DagNode::Var(v) => {
+                    let stride = size.pow(*v as u32);
+                    for (j, o) in out.iter_mut().enumerate() {
+                        *o = (((start + j) / stride) % size) as i32;
+                    }
+                }
+                DagNode::Apply { op, children } => {
+                    let table = &self.tables[*op];
+                    if children.is_empty() {
+                        out.fill(table[0]);
+                        continue;
+                    }
+                    out.fill(0);
+                    for &c in children.iter().rev() {
+                        let col = &done[c * len..(c + 1) * len];
+                        for (o, &a) in out.iter_mut().zip(col) {
+                            *o = *o * size as i32 + a;
+                        }
+                    }
+                    for o in out.iter_mut() {
+                        *o = table[*o as usize];
+                    }
+                }
+            }
+        }
+    }
+
+    /// The values of node `node` in a buffer filled by `eval_block` with block length `len`.
+    pub fn column<'a>(&self, buf: &'a [i32], node: usize, len: usize) -> &'a [i32] {
+        &buf[node * len..(node + 1) * len]
+    }
+
+    /// Find the first assignment, in Horner order, at which roots `a` and `b` differ.
+    ///
+    /// # Arguments
+    /// * `a`, `b` - Indices into `roots()`
+    /// * `block_size` - The number of assignments evaluated per block
+    /// * `parallel` - Evaluate blocks on the rayon thread pool
+    ///
+    /// # Returns
+    /// * `Ok(Some(args))` - The first assignment where the roots differ
+    /// * `Ok(None)` - If the roots agree everywhere
+    /// * `Err(String)` - If the number of assignments is too large
+    pub fn find_difference(
+        &self,
+        a: usize,
+        b: usize,
+        block_size: usize,
+        parallel: bool,
+    ) -> Result<Option<Vec<i32>>, String> {
+        let total = self.assignment_count()?;
+        let block_size = block_size.max(1);
+        let (ra, rb) = (self.roots[a], self.roots[b]);
+        let check = |buf: &mut Vec<i32>, start: usize| -> Option<usize> {
+            let len = block_size.min(total - start);
+            self.eval_block(start, len, buf);
+            let left = self.column(buf, ra, len);
+            let right = self.column(buf, rb, len);
+            left.iter().zip(right).position(|(x, y)| x != y).map(|j| start + j)
+        };
+        let starts = (0..total).step_by(block_size);
+        let found = if parallel {
+            starts.collect::<Vec<_>>()
+                .into_par_iter()
+                .map_init(Vec::new, check)
+                .find_map_first(|k| k)
+        } else {
+            let mut buf = Vec::new();
+            starts.into_iter().find_map(|start| check(&mut buf, start))
+        };
+        Ok(found.map(|k| self.assignment(k)))
+    }
+}
+
+struct DagBuilder<'a> {
+    alg: &'a dyn SmallAlgebra<UniverseItem = i32>,
+    var_list: &'a [String],
+    nodes: Vec<DagNode>,
+    index: HashMap<DagNode, usize>,
+    tables: Vec<Arc<[i32]>>,
+    handles: HashMap<OperationSymbol, usize>,
+}
+
+impl DagBuilder<'_> {
+    fn add(&mut self, term: &dyn Term) -> Result<usize, String> {
+        let node = if term.isa_variable() {
+            let name = term.to_string();
+            let v = self.var_list.iter().position(|x| *x == name)
+                .ok_or_else(|| format!("Variable {} not in the variable list", name))?;
+            DagNode::Var(v)
+        } else {
+            let sym = term.leading_operation_symbol()
+                .ok_or_else(|| "Non-variable term without an operation symbol".to_string())?;
+            let op = operation_handle(sym, self.alg, &mut self.tables, &mut self.handles)?;
+            let mut children = Vec::new();
+            for child in term.get_children().unwrap_or_default() {
+                children.push(self.add(child.as_ref())?);
+            }
+            DagNode::Apply { op, children }
+        };
+        if let Some(&i) = self.index.get(&node) {
+            return Ok(i);
+        }
+        self.nodes.push(node.clone());
+        self.index.insert(node, self.nodes.len() - 1);
+        Ok(self.nodes.len() - 1)
+    }
+}
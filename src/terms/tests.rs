@@ -1071,3 +1071,163 @@ fn test_term_interpretation_vs_java() {
     );
 }
 
+
+#[test]
+fn test_compiled_term_matches_eval() {
+    let alg = create_test_algebra();
+    let term = string_to_term("add(add(x,y),add(y,z))").unwrap();
+    let vars = vec!["x".to_string(), "y".to_string(), "z".to_string()];
+    let compiled = CompiledTerm::compile(term.as_ref(), &alg, &vars).unwrap();
+    
+    assert_eq!(compiled.arity(), 3);
+    assert_eq!(compiled.instructions().len(), 7);
+    assert_eq!(compiled.max_stack(), 3);
+    
+    for x in 0..3 {
+        for y in 0..3 {
+            for z in 0..3 {
+                let mut map = HashMap::new();
+                map.insert("x".to_string(), x);
+                map.insert("y".to_string(), y);
+                map.insert("z".to_string(), z);
+                assert_eq!(compiled.eval(&[x, y, z]), term.eval(&alg, &map).unwrap());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_compiled_term_table_matches_interpretation() {
+    let reader = AlgebraReader::new_from_path("resources/algebras/baker2.ua")
+        .expect("Failed to create algebra reader");
+    let alg = reader.read_algebra_file().expect("Failed to read algebra file");
+    let term = string_to_term("bak(x,bak(y,x,y),y)").unwrap();
+    let vars = vec!["x".to_string(), "y".to_string()];
+    
+    let compiled = CompiledTerm::compile(term.as_ref(), &alg, &vars).unwrap();
+    let alg_arc: Arc<dyn SmallAlgebra<UniverseItem = i32>> = Arc::new(alg);
+    let op = term.interpretation(alg_arc, &vars, true).unwrap();
+    
+    let table = compiled.table().unwrap();
+    for (k, &value) in table.iter().enumerate() {
+        let args = crate::util::horner::horner_inv_same_size(k as i32, 2, 2);
+        assert_eq!(value, op.int_value_at(&args).unwrap());
+    }
+}
+
+#[test]
+fn test_compiled_term_errors() {
+    let alg = create_test_algebra();
+    let term = string_to_term("mul(x,y)").unwrap();
+    assert!(CompiledTerm::compile_simple(term.as_ref(), &alg).is_err());
+    
+    let term = string_to_term("add(x,y)").unwrap();
+    assert!(CompiledTerm::compile(term.as_ref(), &alg, &["x".to_string()]).is_err());
+    
+    let compiled = CompiledTerm::compile_simple(term.as_ref(), &alg).unwrap();
+    assert!(compiled.eval_safe(&[0]).is_err());
+    assert!(compiled.eval_safe(&[0, 3]).is_err());
+    assert_eq!(compiled.eval_safe(&[1, 1]), Ok(2));
+}
+
+#[test]
+fn test_term_dag_shares_subterms() {
+    let alg = create_test_algebra();
+    let s = string_to_term("add(add(x,y),z)").unwrap();
+    let t = string_to_term("add(z,add(x,y))").unwrap();
+    let vars = vec!["x".to_string(), "y".to_string(), "z".to_string()];
+    let dag = TermDag::compile(&[s.as_ref(), t.as_ref()], &alg, &vars).unwrap();
+    
+    // x, y, z, add(x,y) and the two roots
+    assert_eq!(dag.nodes().len(), 6);
+    assert_eq!(dag.roots().len(), 2);
+    assert_eq!(dag.assignment_count().unwrap(), 27);
+}
+
+#[test]
+fn test_term_dag_blocks_match_compiled_term() {
+    let alg = create_test_algebra();
+    let term = string_to_term("add(add(x,add(y,y)),add(y,z))").unwrap();
+    let vars = vec!["x".to_string(), "y".to_string(), "z".to_string()];
+    let dag = TermDag::compile(&[term.as_ref()], &alg, &vars).unwrap();
+    let compiled = CompiledTerm::compile(term.as_ref(), &alg, &vars).unwrap();
+    let table = compiled.table().unwrap();
+    
+    // Uneven block lengths exercise the block offsets.
+    let mut buf = Vec::new();
+    let mut start = 0;
+    while start < 27 {
+        let len = 5.min(27 - start);
+        dag.eval_block(start, len, &mut buf);
+        assert_eq!(dag.column(&buf, dag.roots()[0], len), &table[start..start + len]);
+        start += len;
+    }
+}
+
+#[test]
+fn test_term_statistics() {
+    let t = string_to_term("f(g(x,y),g(x,y),x)").unwrap();
+    assert_eq!(t.size(), 3);
+    assert_eq!(t.length(), 8);
+    // x, y, g(x,y) and the root
+    assert_eq!(t.node_count(), 4);
+    assert_eq!(t.occurrences_of_variable("x"), 3);
+    assert_eq!(t.occurrences_of_variable("y"), 2);
+    assert_eq!(t.occurrences_of_variable("z"), 0);
+    
+    let subs: Vec<String> = t.subterms().map(|s| s.to_string()).collect();
+    assert_eq!(subs, vec!["f(g(x,y),g(x,y),x)", "g(x,y)", "x", "y", "g(x,y)", "x", "y", "x"]);
+    assert_eq!(subs.len() as i32, t.length());
+    
+    let x = VariableImp::x();
+    assert_eq!(x.size(), 0);
+    assert_eq!(x.node_count(), 1);
+    assert_eq!(x.occurrences_of_variable("x"), 1);
+    assert_eq!(string_to_term("c()").unwrap().size(), 1);
+}
+
+#[test]
+fn test_term_arena_shares_across_roots() {
+    let s = string_to_term("m(x,j(x,y))").unwrap();
+    let t = string_to_term("j(x,y)").unwrap();
+    let arena = TermArena::from_terms(&[s.as_ref(), t.as_ref()]);
+    
+    // x, y, j(x,y) and m(...); the second root is an existing node
+    assert_eq!(arena.node_count(), 4);
+    assert_eq!(arena.roots(), &[3, 2]);
+    assert_eq!(arena.nodes()[0], ArenaNode::Var("x".to_string()));
+    
+    let dot = arena.to_dot();
+    assert!(dot.starts_with("digraph Term {"));
+    assert!(dot.contains("  0 [label=\"x\", shape=box];"));
+    assert!(dot.contains("  2 [label=\"j\", style=bold];"));
+    assert!(dot.contains("  3 -> 2 [label=\"1\"];"));
+    assert_eq!(dot.matches("->").count(), 4);
+}
+
+#[test]
+fn test_term_arena_import_and_substitute() {
+    let s = string_to_term("m(x,j(x,y))").unwrap();
+    let source = TermArena::from_terms(&[s.as_ref()]);
+    let mut target = TermArena::from_terms(&[string_to_term("j(y,x)").unwrap().as_ref()]);
+    
+    let copy = target.import_from(&source, source.roots()[0]).unwrap();
+    assert_eq!(target.term(copy).unwrap().to_string(), "m(x,j(x,y))");
+    // y, x, j(y,x), j(x,y), m(...)
+    assert_eq!(target.node_count(), 5);
+    assert!(target.import_from(&source, 9).is_err());
+    
+    // x -> j(y,x), shared with the subterm introduced by the substitution
+    let mut map = HashMap::new();
+    map.insert("x".to_string(), target.roots()[0]);
+    let sub = target.substitute(copy, &map).unwrap();
+    assert_eq!(target.term(sub).unwrap().to_string(), "m(j(y,x),j(j(y,x),y))");
+    assert_eq!(target.node_count(), 7);
+    map.insert("y".to_string(), 42);
+    assert!(target.substitute(copy, &map).is_err());
+    
+    let eq = crate::eq::Equation::from_term_ids(&target, copy, sub).unwrap();
+    assert_eq!(eq.left_side().to_string(), "m(x,j(x,y))");
+    assert_eq!(eq.right_side().to_string(), "m(j(y,x),j(j(y,x),y))");
+    assert!(crate::eq::Equation::from_term_ids(&target, copy, 99).is_err());
+}
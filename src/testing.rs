@@ -0,0 +1,214 @@
+/*!
+ * Property-based test generators.
+ *
+ * With the `testing` feature this module provides [`proptest`] strategies
+ * for operations, algebras, partitions and terms, and [`Arbitrary`]
+ * implementations for [`Partition`] and [`BasicAlgebra<i32>`], so that
+ * algorithms can be checked against random inputs in this crate and in
+ * downstream crates:
+ *
+ * ```
+ * use proptest::prelude::*;
+ * use uacalc::alg::{algebras, Algebra, BasicAlgebra};
+ * use uacalc::testing::partition;
+ *
+ * let pairs = (1..=6usize).prop_flat_map(|n| (partition(n), partition(n)));
+ * proptest!(|((p, q) in pairs)| {
+ *     prop_assert_eq!(p.join(&q).unwrap(), q.join(&p).unwrap());
+ * });
+ * proptest!(|(alg in any::<BasicAlgebra<i32>>())| {
+ *     let identity: Vec<i32> = (0..alg.cardinality()).collect();
+ *     prop_assert!(algebras::is_homomorphism(&identity, &alg, &alg).unwrap());
+ * });
+ * ```
+ *
+ * Sizes are kept small by default, since the tables of an operation grow
+ * as the size to the power of the arity.
+ */
+
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::select;
+use crate::alg::BasicAlgebra;
+use crate::alg::conlat::partition::Partition;
+use crate::alg::op::{IntOperation, Operation, OperationSymbol};
+use crate::terms::{NonVariableTerm, Term, VariableImp};
+
+/// Operations with the given symbol on `{0, ..., set_size - 1}` with
+/// uniformly random tables.
+///
+/// # Panics
+/// If `set_size` is not positive.
+pub fn int_operation(symbol: OperationSymbol, set_size: i32) -> impl Strategy<Value = IntOperation> {
+    assert!(set_size > 0, "set_size must be positive");
+    let len = (set_size as usize).pow(symbol.arity() as u32);
+    vec(0..set_size, len).prop_map(move |table| {
+        IntOperation::new(symbol.clone(), set_size, table).expect("a table of the right length and range")
+    })
+}
+
+/// Similarity types of at most `max_operations` operations of arity at
+/// most `max_arity`, named `f0`, `f1`, ...
+pub fn operation_symbols(max_operations: usize, max_arity: i32) -> impl Strategy<Value = Vec<OperationSymbol>> {
+    vec(0..=max_arity, 0..=max_operations).prop_map(|arities| {
+        arities.iter().enumerate()
+            .map(|(i, &arity)| OperationSymbol::new(&format!("f{}", i), arity, false))
+            .collect()
+    })
+}
+
+/// Algebras with the operations `symbols` on `{0, ..., n - 1}` for `n`
+/// in `size`, so that any two are similar.
+///
+/// # Panics
+/// If `size` contains a non-positive number.
+pub fn algebra_of_type(symbols: Vec<OperationSymbol>, size: RangeInclusive<i32>) -> impl Strategy<Value = BasicAlgebra<i32>> {
+    assert!(*size.start() > 0, "algebras must be nonempty");
+    size.prop_flat_map(move |n| {
+        let ops: Vec<_> = symbols.iter().map(|s| int_operation(s.clone(), n)).collect();
+        ops.prop_map(move |ops| {
+            let ops = ops.into_iter().map(|op| Box::new(op) as Box<dyn Operation>).collect();
+            BasicAlgebra::new(format!("A{}", n), (0..n).collect::<HashSet<i32>>(), ops)
+        })
+    })
+}
+
+/// The parameters of [`algebra`] and of `any_with::<BasicAlgebra<i32>>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlgebraParams {
+    /// The sizes of the algebras.
+    pub size: RangeInclusive<i32>,
+    /// The most operations.
+    pub max_operations: usize,
+    /// The largest arity of an operation.
+    pub max_arity: i32,
+}
+
+impl Default for AlgebraParams {
+    /// Algebras of 1 to 4 elements with at most 2 operations of arity at most 2.
+    fn default() -> Self {
+        AlgebraParams { size: 1..=4, max_operations: 2, max_arity: 2 }
+    }
+}
+
+/// Algebras of random similarity type and size.
+pub fn algebra(params: AlgebraParams) -> impl Strategy<Value = BasicAlgebra<i32>> {
+    let size = params.size;
+    operation_symbols(params.max_operations, params.max_arity)
+        .prop_flat_map(move |symbols| algebra_of_type(symbols, size.clone()))
+}
+
+impl Arbitrary for BasicAlgebra<i32> {
+    type Parameters = AlgebraParams;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(params: AlgebraParams) -> Self::Strategy {
+        algebra(params).boxed()
+    }
+}
+
+/// Partitions of `{0, ..., size - 1}`, each element put in a random one of
+/// `size` blocks.
+///
+/// # Panics
+/// If `size` is 0.
+pub fn partition(size: usize) -> impl Strategy<Value = Partition> {
+    assert!(size > 0, "partitions must be of a nonempty set");
+    vec(0..size, size).prop_map(|blocks| {
+        Partition::from_block_index_vector(&blocks).expect("a nonempty block index vector")
+    })
+}
+
+/// The parameters of `any_with::<Partition>`: the sizes of the set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartitionParams {
+    pub size: RangeInclusive<usize>,
+}
+
+impl Default for PartitionParams {
+    /// Partitions of 1 to 6 elements.
+    fn default() -> Self {
+        PartitionParams { size: 1..=6 }
+    }
+}
+
+impl Arbitrary for Partition {
+    type Parameters = PartitionParams;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(params: PartitionParams) -> Self::Strategy {
+        params.size.prop_flat_map(partition).boxed()
+    }
+}
+
+/// Terms in `variables` built with `symbols`, nested at most `depth` deep.
+///
+/// # Panics
+/// If `variables` is empty.
+pub fn term(variables: Vec<String>, symbols: Vec<OperationSymbol>, depth: u32) -> BoxedStrategy<Arc<dyn Term>> {
+    assert!(!variables.is_empty(), "terms need a variable");
+    let leaf = select(variables).prop_map(|v| Arc::new(VariableImp::new(&v)) as Arc<dyn Term>);
+    if symbols.is_empty() {
+        return leaf.boxed();
+    }
+    let max_arity = symbols.iter().map(|s| s.arity().max(0) as u32).max().unwrap_or(0);
+    leaf.prop_recursive(depth, 64, max_arity.max(1), move |inner| {
+        select(symbols.clone()).prop_flat_map(move |symbol| {
+            vec(inner.clone(), symbol.arity().max(0) as usize).prop_map(move |children| {
+                let children = children.iter().map(|c| c.clone_box()).collect();
+                Arc::new(NonVariableTerm::new(symbol.clone(), children)) as Arc<dyn Term>
+            })
+        })
+    })
+    .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::{algebras, Algebra, SmallAlgebra};
+    use crate::alg::conlat::CongruenceLattice;
+    use crate::alg::residual::quotient;
+    use crate::terms::string_to_term;
+
+    fn partitions(size: usize) -> impl Strategy<Value = (Partition, Partition, Partition)> {
+        (partition(size), partition(size), partition(size))
+    }
+
+    proptest! {
+        #[test]
+        fn join_and_meet_are_associative((p, q, r) in (1..=6usize).prop_flat_map(partitions)) {
+            let join = |a: &Partition, b: &Partition| a.join(b).unwrap();
+            let meet = |a: &Partition, b: &Partition| a.meet(b).unwrap();
+            prop_assert_eq!(join(&join(&p, &q), &r), join(&p, &join(&q, &r)));
+            prop_assert_eq!(meet(&meet(&p, &q), &r), meet(&p, &meet(&q, &r)));
+            prop_assert_eq!(join(&p, &meet(&p, &q)), p.clone());
+        }
+
+        #[test]
+        fn quotient_maps_are_homomorphisms(alg in any::<BasicAlgebra<i32>>(), a in 0..4usize, b in 0..4usize) {
+            let n = alg.cardinality() as usize;
+            let mut con = CongruenceLattice::new(Box::new(alg.clone()) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+            let theta = con.cg(a % n, b % n);
+            let q = quotient(&alg, &theta).unwrap();
+            let map: Vec<i32> = (0..n).map(|x| theta.block_index(x).unwrap() as i32).collect();
+            prop_assert!(algebras::is_homomorphism(&map, &alg, &q).unwrap());
+        }
+
+        #[test]
+        fn terms_print_and_parse(t in term(vec!["x".to_string(), "y".to_string()],
+                vec![OperationSymbol::new("f", 2, false), OperationSymbol::new("g", 1, false)], 4)) {
+            let parsed = string_to_term(&t.to_string()).unwrap();
+            prop_assert_eq!(parsed.to_string(), t.to_string());
+        }
+
+        #[test]
+        fn algebras_have_the_requested_type(alg in algebra_of_type(vec![OperationSymbol::new("f", 3, false)], 2..=3)) {
+            prop_assert!((2..=3).contains(&alg.cardinality()));
+            prop_assert_eq!(alg.operations()[0].arity(), 3);
+        }
+    }
+}
@@ -0,0 +1,204 @@
+/*! Mixed radix encoding of tuples.
+
+A tuple `(a_0, ..., a_{n-1})` with `0 <= a_i < r_i` is encoded as
+`a_0 + r_0 * (a_1 + r_1 * (a_2 + ...))`, the first coordinate varying
+fastest. This is the Horner encoding of [`crate::util::horner`], used for
+the elements of direct products, but with every step checked for overflow
+and with codes up to `u128`.
+*/
+
+/// A codec between tuples and their mixed radix codes.
+///
+/// # Examples
+/// ```
+/// use uacalc::util::MixedRadix;
+///
+/// let codec = MixedRadix::new(vec![2, 3]);
+/// assert_eq!(codec.cardinality(), Some(6));
+/// assert_eq!(codec.encode(&[1, 2]).unwrap(), 5);
+/// assert_eq!(codec.decode(5).unwrap(), vec![1, 2]);
+/// assert!(codec.encode(&[2, 0]).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MixedRadix {
+    radices: Vec<usize>,
+    /// The number of tuples, or `None` if it does not fit in a `u128`.
+    cardinality: Option<u128>,
+}
+
+impl MixedRadix {
+    /// Create a codec for tuples whose `i`-th coordinate is below
+    /// `radices[i]`.
+    pub fn new(radices: Vec<usize>) -> Self {
+        // A zero radix leaves no tuples, however large the other radices.
+        let cardinality = if radices.contains(&0) {
+            Some(0)
+        } else {
+            radices.iter().try_fold(1u128, |acc, &r| acc.checked_mul(r as u128))
+        };
+        MixedRadix { radices, cardinality }
+    }
+
+    /// Create a codec for tuples of length `length` over `{0, ..., size-1}`,
+    /// the elements of a direct power.
+    pub fn same_size(size: usize, length: usize) -> Self {
+        Self::new(vec![size; length])
+    }
+
+    /// The radix of each coordinate.
+    pub fn radices(&self) -> &[usize] {
+        &self.radices
+    }
+
+    /// The length of the tuples.
+    pub fn len(&self) -> usize {
+        self.radices.len()
+    }
+
+    /// Whether the tuples are empty, so that there is exactly one.
+    pub fn is_empty(&self) -> bool {
+        self.radices.is_empty()
+    }
+
+    /// The number of tuples, or `None` if it does not fit in a `u128`.
+    pub fn cardinality(&self) -> Option<u128> {
+        self.cardinality
+    }
+
+    /// Encode a tuple.
+    ///
+    /// # Arguments
+    /// * `tuple` - The coordinates
+    ///
+    /// # Returns
+    /// * `Ok(u128)` - The code
+    /// * `Err(String)` - If the length is wrong, a coordinate is out of
+    ///   range or the code does not fit in a `u128`
+    pub fn encode(&self, tuple: &[usize]) -> Result<u128, String> {
+        if tuple.len() != self.radices.len() {
+            return Err(format!("Expected a tuple of length {}, got {}", self.radices.len(), tuple.len()));
+        }
+        let mut code: u128 = 0;
+        for (i, (&a, &r)) in tuple.iter().zip(&self.radices).enumerate().rev() {
+            code = Self::push(code, i, a, r)?;
+        }
+        Ok(code)
+    }
+
+    /// One Horner step: the code of the tuple with `a` put before the
+    /// coordinates encoded in `code`.
+    fn push(code: u128, i: usize, a: usize, r: usize) -> Result<u128, String> {
+        if a >= r {
+            return Err(format!("Coordinate {} is {}, but must be below {}", i, a, r));
+        }
+        code.checked_mul(r as u128)
+            .and_then(|c| c.checked_add(a as u128))
+            .ok_or_else(|| "The code does not fit in 128 bits".to_string())
+    }
+
+    /// Decode a code into its tuple.
+    ///
+    /// # Arguments
+    /// * `code` - The code
+    ///
+    /// # Returns
+    /// * `Ok(Vec<usize>)` - The coordinates
+    /// * `Err(String)` - If `code` is not below the number of tuples, which
+    ///   is always the case when a radix is 0
+    pub fn decode(&self, code: u128) -> Result<Vec<usize>, String> {
+        if self.cardinality.is_some_and(|card| code >= card) {
+            return Err(format!("Code {} is out of range for radices {:?}", code, self.radices));
+        }
+        let mut rest = code;
+        Ok(self
+            .radices
+            .iter()
+            .map(|&r| {
+                let a = rest % r as u128;
+                rest /= r as u128;
+                a as usize
+            })
+            .collect())
+    }
+
+    /// Encode a tuple of `i32` coordinates as an `i32`, the representation
+    /// used for the elements of product algebras. Like [`encode`](Self::encode)
+    /// it does not allocate, so it can be used to index operation tables.
+    ///
+    /// # Returns
+    /// * `Ok(i32)` - The code
+    /// * `Err(String)` - If a coordinate is negative or out of range, the
+    ///   length is wrong or the code does not fit in an `i32`
+    pub fn encode_i32(&self, tuple: &[i32]) -> Result<i32, String> {
+        if tuple.len() != self.radices.len() {
+            return Err(format!("Expected a tuple of length {}, got {}", self.radices.len(), tuple.len()));
+        }
+        let mut code: u128 = 0;
+        for (i, (&a, &r)) in tuple.iter().zip(&self.radices).enumerate().rev() {
+            let a = usize::try_from(a).map_err(|_| format!("Coordinate {} is negative", a))?;
+            code = Self::push(code, i, a, r)?;
+        }
+        i32::try_from(code).map_err(|_| format!("The code {} does not fit in an i32", code))
+    }
+
+    /// Decode an `i32` code into a tuple of `i32` coordinates.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<i32>)` - The coordinates
+    /// * `Err(String)` - If `code` is negative or out of range
+    pub fn decode_i32(&self, code: i32) -> Result<Vec<i32>, String> {
+        let code = u128::try_from(code).map_err(|_| format!("Code {} is negative", code))?;
+        // Each coordinate is below its radix and so below the code's range.
+        Ok(self.decode(code)?.into_iter().map(|a| a as i32).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::horner;
+
+    #[test]
+    fn test_mixed_radix() {
+        // Agrees with the Horner encoding.
+        let codec = MixedRadix::new(vec![4, 5, 6]);
+        for k in 0..120 {
+            let tuple = codec.decode_i32(k).unwrap();
+            assert_eq!(tuple, horner::horner_inv(k, &[4, 5, 6]));
+            assert_eq!(codec.encode_i32(&tuple).unwrap(), k);
+        }
+        assert!(codec.decode(120).is_err());
+        assert!(codec.encode_i32(&[0, -1, 0]).is_err());
+        assert!(codec.encode(&[0, 0]).is_err());
+
+        // 2^100 needs more than 64 bits; 2^200 overflows.
+        let big = MixedRadix::same_size(2, 100);
+        assert_eq!(big.cardinality(), Some(1 << 100));
+        let ones = vec![1; 100];
+        assert_eq!(big.encode(&ones).unwrap(), (1 << 100) - 1);
+        assert_eq!(big.decode((1 << 100) - 1).unwrap(), ones);
+        assert!(big.encode_i32(&[1; 100]).is_err());
+        let huge = MixedRadix::same_size(2, 200);
+        assert_eq!(huge.cardinality(), None);
+        assert!(huge.encode(&[1; 200]).is_err());
+        assert_eq!(huge.encode(&[0; 200]).unwrap(), 0);
+
+        let empty = MixedRadix::new(Vec::new());
+        assert_eq!(empty.cardinality(), Some(1));
+        assert_eq!(empty.encode(&[]).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_zero_radix() {
+        // No tuples, even when the other radices overflow the cardinality.
+        let mut radices = vec![2; 200];
+        radices.push(0);
+        for codec in [MixedRadix::new(vec![3, 0, 2]), MixedRadix::new(radices)] {
+            assert_eq!(codec.cardinality(), Some(0));
+            assert!(codec.decode(0).is_err());
+            assert!(codec.decode(5).is_err());
+            assert!(codec.decode_i32(0).is_err());
+            assert!(codec.encode(&vec![0; codec.len()]).is_err());
+        }
+    }
+}
@@ -1,15 +1,18 @@
 pub mod virtuallist;
 pub mod horner;
+pub mod mixed_radix;
 pub mod simple_list;
 pub mod array_string;
 pub mod permutation_generator;
 pub mod array_incrementor;
 pub mod int_array;
 pub mod sequence_generator;
+pub mod random;
 
 pub use permutation_generator::PermutationGenerator;
 pub use array_incrementor::{ArrayIncrementor, ArrayIncrementorImpl, SimpleArrayIncrementor};
 pub use int_array::{IntArrayTrait, IntArray};
+pub use mixed_radix::MixedRadix;
 pub use sequence_generator::{
     SequenceGenerator, NondecreasingSequenceIncrementor, IncreasingSequenceIncrementor,
     SequenceIncrementor, LeftSequenceIncrementor, PartitionArrayIncrementor
@@ -0,0 +1,316 @@
+/*!
+ * Cross-checks of fast algorithms against naive reference implementations.
+ *
+ * With the `verify` feature the functions here compute a result twice, once
+ * with the algorithm the crate normally uses and once by brute force
+ * straight from the definitions, and report every difference as a
+ * [`Discrepancy`]:
+ *
+ * - [`check_congruences`]: the congruence lattice universe against the
+ *   partitions compatible with every operation;
+ * - [`check_subuniverses`]: the subalgebra lattice universe against the
+ *   subsets closed under every operation;
+ * - [`check_homomorphisms`]: the homomorphism searches of every
+ *   [`SearchBackend`] against all maps between the universes.
+ *
+ * The brute force is exponential, so inputs are limited to
+ * [`MAX_SIZE`] elements and [`MAX_MAPS`] maps. An empty list means the
+ * implementations agree.
+ */
+
+use std::collections::HashSet;
+use std::fmt;
+use crate::alg::SmallAlgebra;
+use crate::alg::conlat::CongruenceLattice;
+use crate::alg::conlat::partition::Partition;
+use crate::alg::search::{self, SearchBackend};
+use crate::alg::sublat::SubalgebraLattice;
+use crate::util::horner;
+
+/// The most elements of an algebra checked by brute force.
+pub const MAX_SIZE: usize = 8;
+
+/// The most maps enumerated by [`check_homomorphisms`].
+pub const MAX_MAPS: u64 = 1 << 20;
+
+/// A difference between a fast algorithm and its reference.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Discrepancy {
+    /// The check that failed: "congruences", "subuniverses" or
+    /// "homomorphisms".
+    pub check: &'static str,
+    /// The algorithm that disagreed with the reference.
+    pub algorithm: String,
+    /// What differs, with a witness.
+    pub detail: String,
+}
+
+impl fmt::Display for Discrepancy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}): {}", self.check, self.algorithm, self.detail)
+    }
+}
+
+type IntAlgebra = dyn SmallAlgebra<UniverseItem = i32>;
+
+/// Compare the congruences found by [`CongruenceLattice`] with the
+/// partitions of the universe compatible with every operation.
+///
+/// # Returns
+/// * `Ok(discrepancies)` - The congruences missed, found twice or wrongly
+///   found, empty if the two agree
+/// * `Err(String)` - If the algebra has more than [`MAX_SIZE`] elements or
+///   an operation cannot be evaluated
+pub fn check_congruences(alg: &IntAlgebra) -> Result<Vec<Discrepancy>, String> {
+    let n = checked_size(alg)?;
+    let mut reference = Vec::new();
+    for blocks in set_partitions(n) {
+        if compatible(alg, &blocks)? {
+            reference.push(Partition::from_block_index_vector(&blocks)?);
+        }
+    }
+    let mut con = CongruenceLattice::new(alg.clone_box());
+    let found = CongruenceLattice::universe(&mut con).clone();
+    Ok(compare("congruences", "CongruenceLattice::universe", &reference, &found))
+}
+
+/// Compare the nonempty subuniverses found by [`SubalgebraLattice`] with the
+/// nonempty subsets of the universe closed under every operation.
+///
+/// # Returns
+/// * `Ok(discrepancies)` - The subuniverses missed, found twice or wrongly
+///   found, as sorted lists of elements
+/// * `Err(String)` - If the algebra has more than [`MAX_SIZE`] elements or
+///   an operation cannot be evaluated
+pub fn check_subuniverses(alg: &IntAlgebra) -> Result<Vec<Discrepancy>, String> {
+    let n = checked_size(alg)?;
+    let mut reference = Vec::new();
+    for mask in 1u32..(1 << n) {
+        let subset: Vec<i32> = (0..n as i32).filter(|&x| mask & (1 << x) != 0).collect();
+        if closed(alg, &subset)? {
+            reference.push(subset);
+        }
+    }
+    let mut sub = SubalgebraLattice::new_safe(alg.clone_box())?;
+    let found: Vec<Vec<i32>> = sub.universe_mut().iter()
+        .map(|s| s.elements().clone())
+        .filter(|s| !s.is_empty())
+        .collect();
+    Ok(compare("subuniverses", "SubalgebraLattice::universe", &reference, &found))
+}
+
+/// Compare the homomorphism searches with all maps from `a` to `b`.
+///
+/// Every backend must find a homomorphism exactly when there is one, and
+/// the one it finds must be a homomorphism; enumerating all solutions must
+/// give every homomorphism once.
+///
+/// # Returns
+/// * `Ok(discrepancies)` - Where a search disagrees with the enumeration
+/// * `Err(String)` - If there are more than [`MAX_MAPS`] maps or an
+///   operation of `a` is missing from `b`
+pub fn check_homomorphisms(a: &IntAlgebra, b: &IntAlgebra) -> Result<Vec<Discrepancy>, String> {
+    let (n, m) = (a.cardinality() as u32, b.cardinality() as u32);
+    if (m as u64).checked_pow(n).is_none_or(|maps| maps > MAX_MAPS) {
+        return Err(format!("{}^{} maps are too many to enumerate (at most {})", m, n, MAX_MAPS));
+    }
+    let problem = search::homomorphism_problem(a, b)?;
+    let mut reference = Vec::new();
+    for k in 0..(m as u64).pow(n) {
+        let map = horner::horner_inv_same_size(k as i32, m as i32, n as usize);
+        if preserves(a, b, &map)? {
+            reference.push(map);
+        }
+    }
+    let reference_set: HashSet<&Vec<i32>> = reference.iter().collect();
+
+    let mut discrepancies = Vec::new();
+    for backend in backends() {
+        let algorithm = format!("find_homomorphism({:?})", backend);
+        match search::find_homomorphism(a, b, backend)? {
+            Some(map) if !reference_set.contains(&map) => discrepancies.push(Discrepancy {
+                check: "homomorphisms",
+                algorithm,
+                detail: format!("found {:?}, which is not a homomorphism", map),
+            }),
+            None if !reference.is_empty() => discrepancies.push(Discrepancy {
+                check: "homomorphisms",
+                algorithm,
+                detail: format!("found none, but {:?} is a homomorphism", reference[0]),
+            }),
+            _ => {}
+        }
+    }
+    let found = problem.all_solutions(None);
+    discrepancies.extend(compare("homomorphisms", "ConstraintProblem::all_solutions", &reference, &found));
+    Ok(discrepancies)
+}
+
+/// Run [`check_congruences`], [`check_subuniverses`] and
+/// [`check_homomorphisms`] from the algebra to itself.
+pub fn check_algebra(alg: &IntAlgebra) -> Result<Vec<Discrepancy>, String> {
+    let mut discrepancies = check_congruences(alg)?;
+    discrepancies.extend(check_subuniverses(alg)?);
+    discrepancies.extend(check_homomorphisms(alg, alg)?);
+    Ok(discrepancies)
+}
+
+fn checked_size(alg: &IntAlgebra) -> Result<usize, String> {
+    let n = alg.cardinality();
+    if n < 0 || n as usize > MAX_SIZE {
+        return Err(format!("{} has {} elements; brute force is limited to {}", alg.name(), n, MAX_SIZE));
+    }
+    Ok(n as usize)
+}
+
+/// The backends available in this build.
+fn backends() -> Vec<SearchBackend> {
+    #[allow(unused_mut)]
+    let mut backends = vec![SearchBackend::Backtrack, SearchBackend::ArcConsistency, SearchBackend::SingletonArcConsistency];
+    #[cfg(feature = "sat")]
+    backends.push(SearchBackend::Sat);
+    backends
+}
+
+/// The discrepancies between the reference results and the results found.
+fn compare<T: Eq + std::hash::Hash + fmt::Debug>(check: &'static str, algorithm: &str, reference: &[T], found: &[T]) -> Vec<Discrepancy> {
+    let reference_set: HashSet<&T> = reference.iter().collect();
+    let mut seen = HashSet::new();
+    let mut discrepancies = Vec::new();
+    let mut report = |detail: String| discrepancies.push(Discrepancy { check, algorithm: algorithm.to_string(), detail });
+    for x in found {
+        if !reference_set.contains(x) {
+            report(format!("found {:?}, which the reference rejects", x));
+        } else if !seen.insert(x) {
+            report(format!("found {:?} twice", x));
+        }
+    }
+    for x in reference {
+        if !seen.contains(x) {
+            report(format!("missed {:?}", x));
+        }
+    }
+    discrepancies
+}
+
+/// The partitions of `{0, ..., n - 1}` as block index vectors in which each
+/// block is numbered when its first element appears.
+fn set_partitions(n: usize) -> Vec<Vec<usize>> {
+    let mut all = Vec::new();
+    let mut blocks = vec![0; n];
+    fn extend(i: usize, used: usize, blocks: &mut Vec<usize>, all: &mut Vec<Vec<usize>>) {
+        if i == blocks.len() {
+            all.push(blocks.clone());
+            return;
+        }
+        for b in 0..=used {
+            blocks[i] = b;
+            extend(i + 1, used.max(b + 1), blocks, all);
+        }
+    }
+    if n > 0 {
+        extend(1, 1, &mut blocks, &mut all);
+    }
+    all
+}
+
+/// Whether changing one argument of an operation within its block keeps
+/// the value within its block, which for every operation makes the
+/// partition a congruence.
+fn compatible(alg: &IntAlgebra, blocks: &[usize]) -> Result<bool, String> {
+    let n = blocks.len() as i32;
+    for op in alg.get_operations_ref() {
+        let arity = op.arity() as usize;
+        for k in 0..n.pow(arity as u32) {
+            let args = horner::horner_inv_same_size(k, n, arity);
+            let value = op.int_value_at(&args)?;
+            for i in 0..arity {
+                let mut changed = args.clone();
+                for y in (0..n).filter(|&y| blocks[y as usize] == blocks[args[i] as usize]) {
+                    changed[i] = y;
+                    if blocks[op.int_value_at(&changed)? as usize] != blocks[value as usize] {
+                        return Ok(false);
+                    }
+                }
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// Whether `subset` is closed under every operation.
+fn closed(alg: &IntAlgebra, subset: &[i32]) -> Result<bool, String> {
+    let members: HashSet<i32> = subset.iter().copied().collect();
+    let k = subset.len() as i32;
+    for op in alg.get_operations_ref() {
+        let arity = op.arity() as usize;
+        for c in 0..k.pow(arity as u32) {
+            let args: Vec<i32> = horner::horner_inv_same_size(c, k, arity).iter().map(|&i| subset[i as usize]).collect();
+            if !members.contains(&op.int_value_at(&args)?) {
+                return Ok(false);
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// Whether `map` commutes with every operation of `a`.
+fn preserves(a: &IntAlgebra, b: &IntAlgebra, map: &[i32]) -> Result<bool, String> {
+    let n = a.cardinality();
+    for op in a.get_operations_ref() {
+        let op_b = b.get_operation_ref(op.symbol())
+            .ok_or_else(|| format!("Operation {} not found in target algebra", op.symbol().name()))?;
+        let arity = op.arity() as usize;
+        for k in 0..n.pow(arity as u32) {
+            let args = horner::horner_inv_same_size(k, n, arity);
+            let image: Vec<i32> = args.iter().map(|&x| map[x as usize]).collect();
+            if map[op.int_value_at(&args)? as usize] != op_b.int_value_at(&image)? {
+                return Ok(false);
+            }
+        }
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alg::BasicAlgebra;
+    use crate::alg::op::{operations, OperationSymbol};
+
+    fn cyclic(n: i32) -> BasicAlgebra<i32> {
+        let plus = operations::make_int_operation(OperationSymbol::new("+", 2, false), n,
+            (0..n * n).map(|k| (k % n + k / n) % n).collect()).unwrap();
+        BasicAlgebra::new(format!("Z{}", n), (0..n).collect(), vec![plus])
+    }
+
+    #[test]
+    fn test_set_partitions() {
+        let bell: Vec<usize> = (1..=6).map(|n| set_partitions(n).len()).collect();
+        assert_eq!(bell, vec![1, 2, 5, 15, 52, 203]);
+    }
+
+    #[test]
+    fn test_agreement() {
+        for n in 1..=6 {
+            let z = cyclic(n);
+            assert_eq!(check_algebra(&z).unwrap(), vec![]);
+        }
+        assert_eq!(check_homomorphisms(&cyclic(6), &cyclic(3)).unwrap(), vec![]);
+        assert_eq!(check_homomorphisms(&cyclic(2), &cyclic(3)).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_compare() {
+        let found = compare("congruences", "test", &[1, 2, 3], &[1, 1, 4]);
+        let details: Vec<String> = found.iter().map(|d| d.detail.clone()).collect();
+        assert_eq!(details, vec!["found 1 twice", "found 4, which the reference rejects", "missed 2", "missed 3"]);
+        assert_eq!(found[0].to_string(), "congruences (test): found 1 twice");
+    }
+
+    #[test]
+    fn test_limits() {
+        assert!(check_congruences(&cyclic(9)).is_err());
+        assert!(check_homomorphisms(&cyclic(8), &cyclic(8)).is_err());
+    }
+}
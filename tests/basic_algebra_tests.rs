@@ -197,6 +197,29 @@ mod basic_algebra_tests {
         assert_eq!(alg.algebra_type(), alg_clone.algebra_type());
     }
 
+    #[test]
+    fn test_structural_equality() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+        use uacalc::alg::op::{operations, OperationSymbol};
+
+        let plus = || operations::make_int_operation(OperationSymbol::new("+", 2, false), 2, vec![0, 1, 1, 0]).unwrap();
+        let neg = || operations::make_int_operation(OperationSymbol::new("-", 1, false), 2, vec![0, 1]).unwrap();
+        let a = BasicAlgebra::new("A".to_string(), (0..2).collect(), vec![plus(), neg()]);
+        // The same operations in another order, under another name
+        let b = BasicAlgebra::new("B".to_string(), (0..2).collect(), vec![neg(), plus()]);
+        let c = BasicAlgebra::new("A".to_string(), (0..2).collect(), vec![plus()]);
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+
+        let hash = |alg: &BasicAlgebra<i32>| {
+            let mut hasher = DefaultHasher::new();
+            alg.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&a), hash(&b));
+    }
+
     #[test]
     fn test_display() {
         // Create algebra
@@ -207,5 +230,63 @@ mod basic_algebra_tests {
         let display_string = format!("{}", alg);
         assert!(display_string.contains("BasicAlgebra"));
     }
-}
 
+    #[test]
+    fn test_operations_shared_across_threads() {
+        use uacalc::alg::op::{operations, OperationSymbol};
+
+        let plus = OperationSymbol::new("+", 2, false);
+        let zero = OperationSymbol::new("0", 0, false);
+        let ops = vec![
+            operations::make_int_operation(plus, 5, (0..25).map(|k| (k % 5 + k / 5) % 5).collect()).unwrap(),
+            operations::make_int_operation(zero, 5, vec![0]).unwrap(),
+        ];
+        let alg = BasicAlgebra::new("Z5".to_string(), (0..5).collect(), ops);
+
+        // Handing out operations shares their tables instead of copying them.
+        let (first, second) = (alg.operations(), alg.operations());
+        assert_eq!(first[0].get_table().unwrap().as_ptr(), second[0].get_table().unwrap().as_ptr());
+
+        let constants = alg.constant_operations();
+        assert_eq!(constants.len(), 1);
+        assert_eq!(constants[0].int_value_at(&[]).unwrap(), 0);
+
+        // The algebra is evaluated from several threads at once, without locks.
+        let sums: Vec<i32> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4)
+                .map(|t| {
+                    let alg = &alg;
+                    scope.spawn(move || {
+                        let op = &alg.operations()[0];
+                        (0..5).map(|x| op.int_value_at(&[x, t]).unwrap()).sum::<i32>()
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        assert_eq!(sums, vec![10; 4]);
+    }
+
+    #[test]
+    fn test_derived_algebras_share_tables() {
+        use std::sync::Arc;
+        use uacalc::alg::{Partition, QuotientAlgebra};
+        use uacalc::alg::op::{operations, OperationSymbol};
+
+        let plus = OperationSymbol::new("+", 2, false);
+        let op = operations::make_int_operation(plus, 4, (0..16).map(|k| (k % 4 + k / 4) % 4).collect()).unwrap();
+        let alg = BasicAlgebra::new("Z4".to_string(), (0..4).collect(), vec![op]);
+        let table = |alg: &dyn SmallAlgebra<UniverseItem = i32>| alg.get_operations_ref()[0].get_table().unwrap().as_ptr();
+
+        let copy = alg.clone_box();
+        assert_eq!(table(&alg), table(copy.as_ref()));
+
+        // The quotient evaluates through the super algebra's operation itself.
+        let shared = Arc::clone(&alg.operations_arc()[0]);
+        let before = Arc::strong_count(&shared);
+        let congruence = Partition::from_block_index_vector(&[0, 1, 0, 1]).unwrap();
+        let quotient = QuotientAlgebra::new(copy, congruence);
+        assert!(Arc::strong_count(&shared) > before);
+        assert_eq!(quotient.cardinality(), 2);
+    }
+}
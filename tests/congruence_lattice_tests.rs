@@ -184,6 +184,75 @@ fn test_universe_generation() {
     assert_eq!(con_lat.con_cardinality(), 5);
 }
 
+#[test]
+fn test_congruences_iter() {
+    let alg = Box::new(BasicAlgebra::new(
+        "TestAlg".to_string(),
+        HashSet::from([0, 1, 2, 3]),
+        Vec::new()
+    )) as Box<dyn SmallAlgebra<UniverseItem = i32>>;
+    
+    let mut con_lat = CongruenceLattice::new(alg);
+    
+    // Stopping early does not build the universe
+    let first: Vec<_> = con_lat.congruences_iter().unwrap().take(3).collect();
+    assert_eq!(first[0], con_lat.zero());
+    assert!(!con_lat.universe_found());
+    
+    // The stream has the elements of the universe: all 15 partitions
+    let mut streamed: Vec<_> = con_lat.congruences_iter().unwrap().collect();
+    assert_eq!(streamed.len(), 15);
+    streamed.sort();
+    assert_eq!(&streamed, con_lat.universe());
+    
+    // Once the universe is known it is streamed in its order
+    let again: Vec<_> = con_lat.congruences_iter().unwrap().collect();
+    assert_eq!(&again, con_lat.universe());
+    
+    // universe_iter always has the order of the universe
+    let mut fresh = CongruenceLattice::new(Box::new(BasicAlgebra::new(
+        "TestAlg".to_string(),
+        HashSet::from([0, 1, 2, 3]),
+        Vec::new()
+    )) as Box<dyn SmallAlgebra<UniverseItem = i32>>);
+    let canonical: Vec<_> = fresh.universe_iter().unwrap().collect();
+    assert_eq!(&canonical, con_lat.universe());
+}
+
+#[test]
+fn test_universe_order_is_canonical() {
+    let size = 4;
+    let mut table = Vec::new();
+    for a in 0..size {
+        for b in 0..size {
+            table.push((a + b) % 2 + 2 * ((a / 2 + b / 2) % 2));
+        }
+    }
+    let op = operations::make_int_operation(OperationSymbol::new("f", 2, false), size, table).unwrap();
+    let make = || {
+        let alg = Box::new(BasicAlgebra::new(
+            "TestAlg".to_string(),
+            (0..size).collect::<HashSet<_>>(),
+            vec![op.clone_box()]
+        )) as Box<dyn SmallAlgebra<UniverseItem = i32>>;
+        CongruenceLattice::new(alg)
+    };
+    
+    let mut con_lat = make();
+    let univ = con_lat.universe().clone();
+    assert_eq!(univ[0], con_lat.zero());
+    assert_eq!(univ[univ.len() - 1], con_lat.one());
+    assert!(univ.windows(2).all(|w| w[0] < w[1]));
+    assert!(con_lat.principals().windows(2).all(|w| w[0] < w[1]));
+    assert!(con_lat.join_irreducibles().windows(2).all(|w| w[0] < w[1]));
+    assert!(con_lat.meet_irreducibles().windows(2).all(|w| w[0] < w[1]));
+    
+    // Another lattice of the algebra, built in steps, has the same order
+    let mut other = make();
+    other.join_irreducibles();
+    assert_eq!(other.universe(), &univ);
+}
+
 #[test]
 fn test_meet_irreducibles() {
     let alg = Box::new(BasicAlgebra::new(
@@ -406,3 +475,179 @@ fn test_centralizes_stubbed() {
     // Should return true (stubbed implementation)
     assert!(centralizes);
 }
+
+#[test]
+fn test_cancelled_universe_resumes() {
+    use std::ops::ControlFlow;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use uacalc::progress::{ProgressToken, CANCELLED};
+
+    let alg = Box::new(BasicAlgebra::new(
+        "TestAlg".to_string(),
+        HashSet::from([0, 1, 2, 3]),
+        Vec::new()
+    )) as Box<dyn SmallAlgebra<UniverseItem = i32>>;
+    let mut con_lat = CongruenceLattice::new(alg);
+
+    // Cancel at the second step of the universe phase
+    let steps = AtomicUsize::new(0);
+    let token = ProgressToken::with_callback(move |e| {
+        if e.phase == "universe" && e.message.starts_with("size") && steps.fetch_add(1, Ordering::SeqCst) == 1 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+    {
+        let _installed = token.install();
+        assert_eq!(con_lat.try_con_cardinality(), Err(CANCELLED.to_string()));
+    }
+    assert!(!con_lat.universe_found());
+    assert!(con_lat.partial_universe().unwrap().len() >= 6);
+
+    // The partition lattice of a 4 element set has 15 elements
+    assert_eq!(con_lat.try_con_cardinality(), Ok(15));
+    assert!(con_lat.partial_universe().is_none());
+}
+
+#[test]
+fn test_checkpoint_resume_from_file() {
+    use std::ops::ControlFlow;
+    use uacalc::alg::conlat::ConLatCheckpoint;
+    use uacalc::progress::{ProgressToken, CANCELLED};
+
+    let make_alg = |size: i32| {
+        Box::new(BasicAlgebra::new(
+            "TestAlg".to_string(),
+            (0..size).collect::<HashSet<i32>>(),
+            Vec::new()
+        )) as Box<dyn SmallAlgebra<UniverseItem = i32>>
+    };
+    let mut con_lat = CongruenceLattice::new(make_alg(4));
+    let token = ProgressToken::with_callback(|e| {
+        if e.phase == "universe" && e.message.starts_with("size") && e.message != "size: 6" {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+    {
+        let _installed = token.install();
+        assert_eq!(con_lat.try_con_cardinality(), Err(CANCELLED.to_string()));
+    }
+    let checkpoint = con_lat.pause();
+    let found = checkpoint.partial_universe.as_ref().unwrap().len();
+    assert!(found > 6);
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("con.json");
+    let path = path.to_str().unwrap();
+    checkpoint.save(path).unwrap();
+    let loaded = ConLatCheckpoint::load(path).unwrap();
+    assert_eq!(loaded, checkpoint);
+
+    let mut resumed = CongruenceLattice::new(make_alg(4));
+    resumed.resume(&loaded).unwrap();
+    assert_eq!(resumed.partial_universe().unwrap().len(), found);
+    assert_eq!(resumed.try_con_cardinality(), Ok(15));
+    assert!(resumed.pause().universe.is_some());
+
+    // The lower covers of the join irreducibles are restored with them
+    let mut fresh = CongruenceLattice::new(make_alg(4));
+    let mut restored = CongruenceLattice::new(make_alg(4));
+    restored.resume(&loaded).unwrap();
+    for beta in fresh.join_irreducibles().clone() {
+        assert!(restored.join_irreducible(&beta));
+        assert_eq!(restored.lower_star(&beta), fresh.lower_star(&beta));
+    }
+    assert!(!restored.join_irreducible(&restored.one()));
+    restored.make_universe();
+    let lat = uacalc::lat::BasicLattice::new_from_lattice("Con".to_string(), &restored).unwrap();
+    assert_eq!(lat.get_universe_list().len(), 15);
+
+    let mut other = CongruenceLattice::new(make_alg(3));
+    assert!(other.resume(&loaded).is_err());
+
+    // A checkpoint of another algebra is rejected, even of the same size
+    let mut renamed = loaded.clone();
+    renamed.alg_name = "Other".to_string();
+    assert!(CongruenceLattice::new(make_alg(4)).resume(&renamed).is_err());
+
+    // So is one holding a partition that is not a congruence: with the
+    // successor operation of Z4 only 0, 02|13 and 1 are congruences
+    let succ = operations::make_int_operation(OperationSymbol::new("s", 1, false), 4, vec![1, 2, 3, 0]).unwrap();
+    let z4 = Box::new(BasicAlgebra::new(
+        "TestAlg".to_string(),
+        (0..4).collect::<HashSet<i32>>(),
+        vec![succ]
+    )) as Box<dyn SmallAlgebra<UniverseItem = i32>>;
+    let mut cyclic = CongruenceLattice::new(z4);
+    let err = cyclic.resume(&loaded).unwrap_err();
+    assert!(err.contains("not a congruence"), "{}", err);
+    assert!(cyclic.partial_universe().is_none());
+    assert!(ConLatCheckpoint::load(dir.path().join("missing.json").to_str().unwrap()).is_err());
+}
+
+#[test]
+fn test_identify_lattice() {
+    let set = |n: i32| Box::new(BasicAlgebra::new(
+        format!("Set{}", n),
+        (0..n).collect::<HashSet<i32>>(),
+        Vec::new()
+    )) as Box<dyn SmallAlgebra<UniverseItem = i32>>;
+
+    assert_eq!(CongruenceLattice::new(set(2)).identify_lattice(), "C2");
+    // The partition lattice of a 3 element set is the diamond M3
+    assert_eq!(CongruenceLattice::new(set(3)).identify_lattice(), "M3");
+    assert_eq!(CongruenceLattice::new(set(4)).identify_lattice(), "unknown");
+}
+
+#[test]
+fn test_lattice_properties() {
+    use uacalc::lat::Lattice;
+    let set = |n: i32| Box::new(BasicAlgebra::new(
+        format!("Set{}", n),
+        (0..n).collect::<HashSet<i32>>(),
+        Vec::new()
+    )) as Box<dyn SmallAlgebra<UniverseItem = i32>>;
+
+    // Partition lattices are geometric but not modular from 4 elements on
+    let mut con4 = CongruenceLattice::new(set(4));
+    con4.universe();
+    assert!(con4.is_geometric() && con4.is_relatively_complemented());
+    assert!(con4.is_upper_semimodular() && !con4.is_lower_semimodular());
+    assert!(!con4.is_modular() && !con4.is_lower_bounded_lattice());
+
+    let mut con3 = CongruenceLattice::new(set(3));
+    con3.universe();
+    assert!(con3.is_modular() && con3.is_geometric());
+
+    // M3 is neither meet nor join semidistributive
+    assert!(!con3.is_meet_semidistributive() && !con3.is_join_semidistributive());
+    let (a, b, c) = con3.meet_semidistributivity_failure().unwrap();
+    assert_eq!(con3.meet(&a, &b), con3.meet(&a, &c));
+    assert_ne!(con3.meet(&a, &con3.join(&b, &c)), con3.meet(&a, &b));
+}
+
+#[test]
+fn test_lattice_properties_of_a_fresh_lattice() {
+    use uacalc::lat::Lattice;
+    let set = |n: i32| Box::new(BasicAlgebra::new(
+        format!("Set{}", n),
+        (0..n).collect::<HashSet<i32>>(),
+        Vec::new()
+    )) as Box<dyn SmallAlgebra<UniverseItem = i32>>;
+
+    // The tests build the universe themselves
+    let con4 = CongruenceLattice::new(set(4));
+    assert!(!con4.universe_found());
+    assert!(!con4.is_modular() && !con4.is_lower_semimodular());
+    assert!(con4.is_upper_semimodular() && con4.is_relatively_complemented());
+
+    let con3 = CongruenceLattice::new(set(3));
+    assert!(con3.is_modular() && !con3.is_join_semidistributive());
+    assert!(con3.meet_semidistributivity_failure().is_some());
+
+    let con2 = CongruenceLattice::new(set(2));
+    assert!(con2.is_meet_semidistributive() && con2.is_lower_bounded_lattice());
+}
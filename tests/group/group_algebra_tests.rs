@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use uacalc::alg::op::{operations, OperationSymbol};
+use uacalc::alg::BasicAlgebra;
+use uacalc::group;
+use uacalc::io::AlgebraReader;
+
+/// The group generated by permutations, as an algebra with its
+/// multiplication; the identity is element 0.
+fn permutation_group(name: &str, gens: &[Vec<usize>]) -> BasicAlgebra<i32> {
+    let compose = |p: &[usize], q: &[usize]| q.iter().map(|&x| p[x]).collect::<Vec<usize>>();
+    let mut elems: Vec<Vec<usize>> = vec![(0..gens[0].len()).collect()];
+    let mut index: HashMap<Vec<usize>, usize> = HashMap::from([(elems[0].clone(), 0)]);
+    let mut k = 0;
+    while k < elems.len() {
+        for g in gens {
+            let h = compose(&elems[k], g);
+            if !index.contains_key(&h) {
+                index.insert(h.clone(), elems.len());
+                elems.push(h);
+            }
+        }
+        k += 1;
+    }
+    let n = elems.len();
+    let table = (0..n * n).map(|i| index[&compose(&elems[i % n], &elems[i / n])] as i32).collect();
+    let op = operations::make_int_operation(OperationSymbol::new("mul", 2, false), n as i32, table).unwrap();
+    BasicAlgebra::new(name.to_string(), (0..n as i32).collect(), vec![op])
+}
+
+fn sizes(subgroups: &[Vec<usize>]) -> Vec<usize> {
+    subgroups.iter().map(|h| h.len()).collect()
+}
+
+#[test]
+fn test_sym3() {
+    let s3 = AlgebraReader::new_from_file(Path::new("resources/algebras/sym3.ua"))
+        .unwrap()
+        .read_algebra_file()
+        .unwrap();
+    assert!(group::is_group(&s3));
+    assert_eq!(group::center(&s3).unwrap(), vec![0]);
+    assert_eq!(group::commutator_subgroup(&s3).unwrap(), vec![0, 1, 2]);
+    assert_eq!(group::normal_subgroups(&s3).unwrap(), vec![vec![0], vec![0, 1, 2], (0..6).collect()]);
+    assert!(!group::is_abelian(&s3).unwrap());
+    assert!(!group::is_nilpotent(&s3).unwrap());
+    assert!(group::is_solvable(&s3).unwrap());
+    assert_eq!(group::upper_central_series(&s3).unwrap(), vec![vec![0]]);
+}
+
+#[test]
+fn test_dihedral_and_symmetric_groups() {
+    let d4 = permutation_group("D4", &[vec![1, 2, 3, 0], vec![0, 3, 2, 1]]);
+    assert_eq!(group::center(&d4).unwrap().len(), 2);
+    assert_eq!(group::commutator_subgroup(&d4).unwrap(), group::center(&d4).unwrap());
+    assert_eq!(sizes(&group::normal_subgroups(&d4).unwrap()), vec![1, 2, 4, 4, 4, 8]);
+    assert!(group::is_nilpotent(&d4).unwrap());
+    assert_eq!(sizes(&group::upper_central_series(&d4).unwrap()), vec![1, 2, 8]);
+
+    let s4 = permutation_group("S4", &[vec![1, 0, 2, 3], vec![1, 2, 3, 0]]);
+    assert_eq!(sizes(&group::normal_subgroups(&s4).unwrap()), vec![1, 4, 12, 24]);
+    assert_eq!(sizes(&group::derived_series(&s4).unwrap()), vec![24, 12, 4, 1]);
+    assert!(group::is_solvable(&s4).unwrap());
+    assert!(!group::is_nilpotent(&s4).unwrap());
+
+    // A5 is simple and perfect
+    let a5 = permutation_group("A5", &[vec![1, 2, 0, 3, 4], vec![1, 2, 3, 4, 0]]);
+    assert_eq!(sizes(&group::normal_subgroups(&a5).unwrap()), vec![1, 60]);
+    assert_eq!(group::commutator_subgroup(&a5).unwrap().len(), 60);
+    assert!(!group::is_solvable(&a5).unwrap());
+}
+
+#[test]
+fn test_group_operation_is_found() {
+    // Z4 with a unary operation that is not a group endomorphism: the
+    // normal subgroups come from the group operation alone.
+    let table = |f: fn(i32, i32) -> i32| (0..16).map(|i| f(i % 4, i / 4)).collect();
+    let ops = vec![
+        operations::make_int_operation(OperationSymbol::new("f", 1, false), 4, vec![0, 0, 0, 1]).unwrap(),
+        operations::make_int_operation(OperationSymbol::new("max", 2, false), 4, table(|x, y| x.max(y))).unwrap(),
+        operations::make_int_operation(OperationSymbol::new("plus", 2, false), 4, table(|x, y| (x + y) % 4)).unwrap(),
+    ];
+    let alg = BasicAlgebra::new("Z4f".to_string(), (0..4).collect(), ops);
+    assert!(group::is_abelian(&alg).unwrap());
+    assert_eq!(sizes(&group::normal_subgroups(&alg).unwrap()), vec![1, 2, 4]);
+
+    let z3 = AlgebraReader::new_from_file(Path::new("resources/algebras/z3.ua"))
+        .unwrap()
+        .read_algebra_file()
+        .unwrap();
+    assert!(!group::is_group(&z3));
+    assert!(group::center(&z3).unwrap_err().contains("no group operation"));
+}
@@ -1 +1,2 @@
 mod permutation_group_tests;
+mod group_algebra_tests;
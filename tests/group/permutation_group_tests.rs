@@ -351,3 +351,56 @@ fn test_to_basic_algebra_and_back() {
     assert!(universe.contains(&0));
     assert!(universe.contains(&1));
 }
+
+fn group(name: &str, gens: &[Vec<i32>]) -> PermutationGroup {
+    PermutationGroup::new(name.to_string(), gens.iter().map(|g| IntArray::from_array(g.clone()).unwrap()).collect())
+}
+
+#[test]
+fn test_orbits_and_constituents() {
+    let g = group("G", &[vec![1, 0, 3, 2, 4], vec![0, 1, 3, 2, 4]]);
+    assert_eq!(g.orbits(), vec![vec![0, 1], vec![2, 3], vec![4]]);
+    assert!(!g.is_transitive());
+    assert!(!g.is_primitive());
+    assert!(g.imprimitive_decomposition().is_none());
+    let constituents = g.transitive_constituents();
+    assert_eq!(constituents.len(), 3);
+    assert_eq!(constituents[1].generators[0].as_slice(), &[1, 0]);
+    assert_eq!(constituents[2].underlying_set_size, 1);
+}
+
+#[test]
+fn test_block_systems() {
+    let s3 = group("S3", &[vec![1, 0, 2], vec![1, 2, 0]]);
+    assert!(s3.is_primitive());
+    assert_eq!(s3.block_systems().unwrap().len(), 2);
+
+    // The dihedral group of the square keeps the diagonals together
+    let d4 = group("D4", &[vec![1, 2, 3, 0], vec![0, 3, 2, 1]]);
+    assert!(!d4.is_primitive());
+    assert_eq!(d4.block_systems().unwrap().len(), 3);
+    assert_eq!(d4.minimal_block_system(0, 2).get_blocks(), vec![vec![0, 2], vec![1, 3]]);
+    assert_eq!(d4.minimal_block_system(0, 1).number_of_blocks(), 1);
+
+    // The Klein four group acting regularly has a block system for each
+    // subgroup of order 2
+    let v4 = group("V4", &[vec![1, 0, 3, 2], vec![2, 3, 0, 1]]);
+    assert_eq!(v4.block_systems().unwrap().len(), 5);
+}
+
+#[test]
+fn test_imprimitive_decomposition() {
+    let d4 = group("D4", &[vec![1, 2, 3, 0], vec![0, 3, 2, 1]]);
+    let decomposition = d4.imprimitive_decomposition().unwrap();
+    assert_eq!(decomposition.blocks.get_blocks(), vec![vec![0, 2], vec![1, 3]]);
+    assert!(decomposition.block_action.is_transitive());
+    assert_eq!(decomposition.block_action.underlying_set_size, 2);
+    // The stabilizer of {0, 2} swaps 0 and 2
+    let stabilizer = &decomposition.block_stabilizer_action;
+    assert!(stabilizer.generators.iter().any(|g| g.as_slice() == [1, 0]));
+
+    let blocks = decomposition.blocks.clone();
+    assert_eq!(d4.block_action(&blocks).unwrap().generators[1].as_slice(), &[0, 1]);
+    let s3 = group("S3", &[vec![1, 0, 2, 3], vec![1, 2, 0, 3]]);
+    assert!(s3.block_action(&d4.minimal_block_system(0, 2)).is_err());
+}
@@ -0,0 +1,55 @@
+use uacalc::lat::{free_lattice, free_lattice_eq, free_lattice_leq, lattice_canonical_form};
+use uacalc::terms::string_to_term;
+
+fn leq(s: &str, t: &str) -> bool {
+    free_lattice_leq(string_to_term(s).unwrap().as_ref(), string_to_term(t).unwrap().as_ref()).unwrap()
+}
+
+fn canonical(t: &str) -> String {
+    lattice_canonical_form(string_to_term(t).unwrap().as_ref()).unwrap().to_string()
+}
+
+#[test]
+fn test_free_lattice_leq() {
+    assert!(leq("x", "x"));
+    assert!(!leq("x", "y"));
+    assert!(leq("meet(x,y)", "join(x,z)"));
+    assert!(leq("join(meet(x,y),meet(x,z))", "meet(x,join(y,z))"));
+    assert!(!leq("meet(x,join(y,z))", "join(meet(x,y),meet(x,z))"));
+    // The modular law fails in the free lattice
+    assert!(!leq("meet(join(x,y),join(x,z))", "join(x,meet(join(x,y),z))"));
+}
+
+#[test]
+fn test_free_lattice_eq() {
+    let s = string_to_term("join(x,meet(x,y))").unwrap();
+    let t = string_to_term("x").unwrap();
+    assert!(free_lattice_eq(s.as_ref(), t.as_ref()).unwrap());
+    let f = string_to_term("f(x,y)").unwrap();
+    assert!(free_lattice_eq(f.as_ref(), t.as_ref()).is_err());
+}
+
+#[test]
+fn test_lattice_canonical_form() {
+    assert_eq!(canonical("meet(x,join(x,y))"), "x");
+    assert_eq!(canonical("join(y,join(x,y))"), "join(x,y)");
+    assert_eq!(canonical("join(z,meet(x,join(y,z)))"), "join(z,meet(x,join(y,z)))");
+    // The meetand join(x,y) is below the whole join
+    assert_eq!(canonical("join(x,y,meet(join(x,y),z))"), "join(x,y)");
+    assert_eq!(canonical("join(x,meet(join(x,y),z))"), canonical("join(meet(z,join(y,x)),x)"));
+}
+
+#[test]
+fn test_free_lattice_generation() {
+    let fl2 = free_lattice(2, 100).unwrap();
+    let names: Vec<String> = fl2.iter().map(|t| t.to_string()).collect();
+    assert_eq!(names, vec!["x0", "x1", "join(x0,x1)", "meet(x0,x1)"]);
+
+    let fl3 = free_lattice(3, 200).unwrap();
+    assert_eq!(fl3.len(), 200);
+    for (i, s) in fl3.iter().enumerate() {
+        for t in &fl3[i + 1..] {
+            assert!(!free_lattice_eq(s.as_ref(), t.as_ref()).unwrap(), "{} = {}", s, t);
+        }
+    }
+}
@@ -1,2 +1,4 @@
 pub mod order_tests;
 pub mod lattice_tests;
+pub mod free_lattice_tests;
+pub mod poset_tests;
@@ -0,0 +1,212 @@
+use std::collections::HashSet;
+
+use uacalc::alg::op::{operations, Operation, OperationSymbol};
+use uacalc::alg::{BasicAlgebra, SmallAlgebra};
+use uacalc::lat::{as_lattice, as_semilattice, is_lattice_ordered, find_lattice_reduct, Poset};
+
+/// The pentagon N5: 0 < a < b < 1 and 0 < c < 1.
+fn pentagon() -> Poset {
+    let labels = ["0", "a", "b", "c", "1"].iter().map(|s| s.to_string()).collect();
+    Poset::from_upper_covers(Some("N5".to_string()), labels, &[vec![1, 3], vec![2], vec![4], vec![4], vec![]]).unwrap()
+}
+
+/// The 2-element chain 0 < 1.
+fn two() -> Poset {
+    Poset::from_leq(Some("2".to_string()), 2, |i, j| i <= j).unwrap()
+}
+
+#[test]
+fn test_construction() {
+    let n5 = pentagon();
+    assert_eq!(n5.cardinality(), 5);
+    assert!(n5.leq(0, 2) && n5.leq(1, 4) && !n5.leq(3, 2));
+    assert_eq!(n5.upper_covers(), vec![vec![1, 3], vec![2], vec![4], vec![4], vec![]]);
+    assert_eq!(n5.lower_covers()[4], vec![2, 3]);
+    assert_eq!(n5.minimals(), vec![0]);
+    assert_eq!(n5.maximals(), vec![4]);
+
+    assert!(Poset::from_leq(None, 2, |_, _| true).is_err());
+    assert!(Poset::from_upper_covers(None, vec!["a".into(), "b".into()], &[vec![1], vec![0]]).is_err());
+}
+
+#[test]
+fn test_chains_and_antichains() {
+    let n5 = pentagon();
+    assert_eq!(n5.height(), 4);
+    assert_eq!(n5.width(), 2);
+    assert_eq!(n5.maximal_chains().unwrap(), vec![vec![0, 1, 2, 4], vec![0, 3, 4]]);
+    assert_eq!(n5.maximal_antichains().unwrap(), vec![vec![0], vec![1, 3], vec![2, 3], vec![4]]);
+
+    let ext = n5.linear_extension();
+    for (i, &a) in ext.iter().enumerate() {
+        assert!(ext[i + 1..].iter().all(|&b| !n5.leq(b, a) || a == b));
+    }
+    // c can go in any of 3 places between 0 and 1
+    assert_eq!(n5.linear_extensions().unwrap().len(), 3);
+}
+
+#[test]
+fn test_mobius() {
+    let n5 = pentagon();
+    assert_eq!(n5.mobius(0, 0), 1);
+    assert_eq!(n5.mobius(0, 1), -1);
+    assert_eq!(n5.mobius(0, 2), 0);
+    assert_eq!(n5.mobius(0, 4), 1);
+    assert_eq!(n5.mobius(3, 1), 0);
+
+    // The Boolean lattice 2^3 has μ(0, 1) = (-1)^3
+    let cube = two().product(&two()).product(&two());
+    assert_eq!(cube.cardinality(), 8);
+    assert_eq!(cube.mobius(0, 7), -1);
+}
+
+#[test]
+fn test_dual_and_product() {
+    let n5 = pentagon();
+    let dual = n5.dual();
+    assert!(dual.leq(4, 0) && !dual.leq(0, 4));
+    assert_eq!(dual.height(), 4);
+
+    let square = two().product(&two());
+    assert_eq!(square.labels(), &["(0,0)", "(0,1)", "(1,0)", "(1,1)"]);
+    assert_eq!(square.width(), 2);
+    assert_eq!(square.height(), 3);
+
+    let ordinal = two().ordinal_sum(&square);
+    assert_eq!(ordinal.cardinality(), 6);
+    assert!(ordinal.leq(1, 2) && !ordinal.leq(2, 1));
+    let glued = n5.vertical_sum(&square).unwrap();
+    assert_eq!(glued.cardinality(), 8);
+    assert_eq!(glued.height(), 6);
+    assert!(square.vertical_sum(&Poset::from_leq(None, 2, |i, j| i == j).unwrap()).is_err());
+}
+
+#[test]
+fn test_lattice_conversion() {
+    let n5 = pentagon();
+    assert!(n5.is_lattice());
+    let lat = n5.to_lattice("N5").unwrap();
+    assert_eq!(lat.cardinality(), 5);
+    let back = Poset::from_lattice(&lat);
+    assert_eq!(back.cardinality(), 5);
+    assert_eq!(back.height(), 4);
+    assert_eq!(back.width(), 2);
+
+    let antichain = Poset::from_leq(None, 2, |i, j| i == j).unwrap();
+    assert!(!antichain.is_lattice());
+    assert!(antichain.to_lattice("A").is_err());
+}
+
+#[test]
+fn test_lattice_properties() {
+    let n5 = pentagon();
+    assert!(!n5.is_modular() && !n5.is_distributive());
+    assert!(!n5.is_upper_semimodular() && !n5.is_lower_semimodular());
+    assert!(n5.is_lower_bounded());
+    assert!(!n5.is_relatively_complemented());
+
+    let labels = ["0", "a", "b", "c", "1"].iter().map(|s| s.to_string()).collect();
+    let m3 = Poset::from_upper_covers(Some("M3".to_string()), labels, &[vec![1, 2, 3], vec![4], vec![4], vec![4], vec![]]).unwrap();
+    assert!(m3.is_modular() && !m3.is_distributive());
+    assert!(m3.is_relatively_complemented() && m3.is_geometric());
+    assert!(!m3.is_lower_bounded());
+
+    let cube = two().product(&two()).product(&two());
+    assert!(cube.is_distributive() && cube.is_geometric() && cube.is_lower_bounded());
+
+    assert!(cube.dual().is_upper_semimodular() && cube.dual().is_lower_semimodular());
+    let three = Poset::from_leq(None, 3, |i, j| i <= j).unwrap();
+    assert!(three.is_distributive() && !three.is_relatively_complemented() && !three.is_geometric());
+}
+
+#[test]
+fn test_semidistributivity() {
+    let n5 = pentagon();
+    assert!(n5.is_meet_semidistributive() && n5.is_join_semidistributive());
+
+    let labels = ["0", "a", "b", "c", "1"].iter().map(|s| s.to_string()).collect();
+    let m3 = Poset::from_upper_covers(Some("M3".to_string()), labels, &[vec![1, 2, 3], vec![4], vec![4], vec![4], vec![]]).unwrap();
+    assert!(!m3.is_meet_semidistributive() && !m3.is_join_semidistributive());
+    // a ∧ b = a ∧ c = 0 but a ∧ (b ∨ c) = a, and dually
+    assert_eq!(m3.meet_semidistributivity_failure(), Some((1, 2, 3)));
+    assert_eq!(m3.join_semidistributivity_failure(), Some((1, 2, 3)));
+
+    let antichain = Poset::from_leq(None, 2, |i, j| i == j).unwrap();
+    assert!(!antichain.is_meet_semidistributive());
+    assert_eq!(antichain.meet_semidistributivity_failure(), None);
+}
+
+#[test]
+fn test_lattice_maps() {
+    let n5 = pentagon();
+    let square = two().product(&two());
+    let chain = |n: usize| Poset::from_leq(None, n, |i, j| i <= j).unwrap();
+    // M3: 0 below three atoms below 1
+    let m3 = Poset::from_leq(Some("M3".to_string()), 5, |i, j| i == j || i == 0 || j == 4).unwrap();
+
+    assert_eq!(n5.lattice_isomorphisms(&n5).unwrap(), vec![vec![0, 1, 2, 3, 4]]);
+    assert_eq!(m3.lattice_isomorphisms(&m3).unwrap().len(), 6);
+    assert!(n5.lattice_isomorphisms(&m3).unwrap().is_empty());
+
+    // N5 and M3 are self-dual; a square with a new top is not.
+    assert!(n5.is_dual_to(&n5).unwrap());
+    assert!(m3.is_dual_to(&m3).unwrap());
+    let square_top = square.ordinal_sum(&chain(1));
+    assert!(!square_top.is_dual_to(&square_top).unwrap());
+    assert!(square_top.is_dual_to(&chain(1).ordinal_sum(&square)).unwrap());
+
+    // N5 embeds in 2 x N5 but not in a distributive lattice.
+    assert!(n5.lattice_embedding(&two().product(&n5)).unwrap().is_some());
+    assert!(n5.lattice_embedding(&square.product(&two())).unwrap().is_none());
+    assert!(chain(3).lattice_embedding(&n5).unwrap().is_some());
+    // Embeddings must send 1 to 1.
+    assert!(chain(1).lattice_embedding(&two()).unwrap().is_none());
+
+    let not_lattice = Poset::from_leq(None, 2, |i, j| i == j).unwrap();
+    assert!(not_lattice.lattice_isomorphisms(&n5).is_err());
+}
+
+fn binary(name: &str, n: i32, f: impl Fn(i32, i32) -> i32) -> Box<dyn Operation> {
+    let table = (0..n * n).map(|k| f(k % n, k / n)).collect();
+    operations::make_int_operation(OperationSymbol::new(name, 2, false), n, table).unwrap()
+}
+
+#[test]
+fn test_orders_from_operations() {
+    // The divisors of 12 under lcm and gcd.
+    let divisors = [1, 2, 3, 4, 6, 12];
+    let index = |d: i32| divisors.iter().position(|&e| e == d).unwrap() as i32;
+    let gcd = |mut a: i32, mut b: i32| {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    };
+    let lcm = binary("lcm", 6, |x, y| {
+        let (a, b) = (divisors[x as usize], divisors[y as usize]);
+        index(a * b / gcd(a, b))
+    });
+    let meet = binary("gcd", 6, |x, y| index(gcd(divisors[x as usize], divisors[y as usize])));
+    let order = as_lattice(lcm.as_ref(), meet.as_ref()).unwrap();
+    assert_eq!(order, Poset::from_leq(Some("lcm".to_string()), 6, |i, j| divisors[j] % divisors[i] == 0).unwrap());
+    assert!(order.is_distributive());
+    assert!(as_semilattice(meet.as_ref()).unwrap().dual().leq(0, 5));
+
+    let minus = binary("minus", 3, |x, y| (x - y).rem_euclid(3));
+    assert!(as_semilattice(minus.as_ref()).unwrap_err().contains("not idempotent"));
+    let first = binary("first", 3, |x, _| x);
+    assert!(as_semilattice(first.as_ref()).unwrap_err().contains("not commutative"));
+    // Two chains on {0, 1, 2} with different orders do not absorb.
+    let max = binary("max", 3, |x, y| x.max(y));
+    let other = binary("max2", 3, |x, y| if x == 0 || y == 0 { 0 } else { x.max(y) });
+    assert!(as_lattice(max.as_ref(), other.as_ref()).unwrap_err().contains("absorption"));
+
+    let min = binary("min", 3, |x, y| x.min(y));
+    let alg = BasicAlgebra::new("A".to_string(), (0..3).collect::<HashSet<i32>>(), vec![minus, min, max]);
+    let (j, m) = find_lattice_reduct(&alg).unwrap();
+    let ops = alg.get_operations_ref();
+    assert_eq!((ops[j].symbol().name(), ops[m].symbol().name()), ("max", "min"));
+    assert!(is_lattice_ordered(&alg));
+    let alg = BasicAlgebra::new("B".to_string(), (0..3).collect::<HashSet<i32>>(), vec![first, other]);
+    assert!(!is_lattice_ordered(&alg));
+}
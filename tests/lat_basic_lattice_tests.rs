@@ -241,3 +241,38 @@ fn test_ordered_set_from_filters() {
     assert!(poset.leq(&univ_list[1], &univ_list[2]));
 }
 
+
+#[test]
+fn test_lattice_combinators() {
+    let chain = |name: &str, n: i32| {
+        let ucs = (0..n).map(|i| if i + 1 < n { vec![i + 1] } else { vec![] }).collect();
+        let poset = OrderedSet::new(Some(name.to_string()), (0..n).collect(), ucs).unwrap();
+        BasicLattice::new_from_poset(name.to_string(), poset, None).unwrap()
+    };
+    let two = chain("2", 2);
+    let three = chain("3", 3);
+
+    let dual = three.dual().unwrap();
+    assert_eq!(dual.name(), "3^d");
+    assert_eq!(dual.zero().get_underlying_object(), "2");
+
+    let square = two.direct_product(&two).unwrap();
+    assert_eq!(square.name(), "2 x 2");
+    assert_eq!(square.cardinality(), 4);
+    assert_eq!(lattices::identify_lattice(square.get_universe_list(), &square), "2^2");
+
+    // Both sums of chains are chains
+    let ordinal = two.ordinal_sum(&three).unwrap();
+    assert_eq!(ordinal.cardinality(), 5);
+    assert_eq!(ordinal.one().get_underlying_object(), "(1,2)");
+    assert_eq!(lattices::identify_lattice(ordinal.get_universe_list(), &ordinal), "C5");
+    let vertical = two.vertical_sum(&three).unwrap();
+    assert_eq!(vertical.name(), "2 ++ 3");
+    assert_eq!(lattices::identify_lattice(vertical.get_universe_list(), &vertical), "C4");
+
+    // Gluing two squares gives 2^2 ++ 2^2, which is neither Boolean nor a chain
+    let glued = square.vertical_sum(&square).unwrap();
+    assert_eq!(glued.cardinality(), 7);
+    assert_eq!(glued.atoms().len(), 2);
+    assert_eq!(lattices::identify_lattice(glued.get_universe_list(), &glued), "unknown");
+}
@@ -6,6 +6,7 @@ mod subalgebra_lattice_tests {
     use uacalc::alg::SmallAlgebra;
     use uacalc::io::AlgebraReader;
     use uacalc::lat::{Order, Lattice};
+    use std::collections::HashSet;
     use std::path::Path;
 
     fn load_algebra(path: &str) -> Box<dyn SmallAlgebra<UniverseItem = i32>> {
@@ -170,6 +171,19 @@ mod subalgebra_lattice_tests {
         assert_eq!(generated.size(), 3);
     }
     
+    #[test]
+    fn test_subuniverses_iter() {
+        for path in ["resources/algebras/cyclic3.ua", "resources/algebras/m3.ua", "resources/algebras/sym3.ua"] {
+            let mut sub_lat = SubalgebraLattice::new_safe(load_algebra(path)).unwrap();
+            let streamed: Vec<BasicSet> = sub_lat.subuniverses_iter().collect();
+            let distinct: HashSet<BasicSet> = streamed.iter().cloned().collect();
+            assert_eq!(distinct.len(), streamed.len());
+            assert_eq!(&distinct, sub_lat.universe_mut());
+            // Once the universe is known it is iterated instead
+            assert_eq!(sub_lat.subuniverses_iter().count(), streamed.len());
+        }
+    }
+
     fn get_classpath_separator() -> &'static str {
         if cfg!(target_os = "windows") {
             ";"
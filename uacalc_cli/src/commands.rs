@@ -0,0 +1,221 @@
+//! The subcommands. Each runs with the progress token installed on the
+//! current thread and returns its result as an [`Output`].
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use serde_json::{Map, Value};
+use uacalc::alg::conlat::CongruenceLattice;
+use uacalc::alg::search::{find_homomorphism, SearchBackend};
+use uacalc::alg::sublat::SubalgebraLattice;
+use uacalc::alg::{Algebra, BasicAlgebra, FreeAlgebra, SmallAlgebra};
+use uacalc::bench::{bench_suite, BenchTask};
+use uacalc::eq::Equation;
+use uacalc::progress::ComputationError;
+use uacalc::io::json::MalcevReport;
+use uacalc::terms::string_to_term;
+
+use crate::output::{Output, Table};
+
+/// The Mal'cev condition terms `malcev` searches for by default.
+pub const DEFAULT_TERMS: &[&str] = &["malcev", "majority", "minority", "pixley", "semilattice", "taylor"];
+
+fn boxed(alg: &BasicAlgebra<i32>) -> Box<dyn SmallAlgebra<UniverseItem = i32>> {
+    Box::new(alg.clone())
+}
+
+fn describe(output: &mut Output, alg: &BasicAlgebra<i32>) {
+    output.set("algebra", alg.name());
+    output.set("size", alg.cardinality());
+}
+
+/// Compute the congruence lattice.
+///
+/// An interrupted build gives an incomplete output listing the congruences
+/// found so far.
+pub fn conlat(alg: &BasicAlgebra<i32>) -> Result<Output, String> {
+    let mut output = Output::new();
+    describe(&mut output, alg);
+    let mut con = CongruenceLattice::new(boxed(alg));
+    let universe = match con.compute_universe() {
+        Ok(universe) => universe.clone(),
+        Err(ComputationError::Stopped { reason, partial }) => {
+            output.complete = false;
+            output.set("stopped", reason.to_string());
+            partial
+        }
+        Err(ComputationError::Failed(e)) => return Err(e),
+    };
+    let mut table = Table::new(&["index", "congruence"]);
+    for (i, part) in universe.iter().enumerate() {
+        table.push(vec![i.to_string(), part.to_string()]);
+    }
+    if output.complete {
+        output.set("cardinality", universe.len());
+        let jis: Vec<String> = con.join_irreducibles().iter().map(|p| p.to_string()).collect();
+        output.set("join_irreducibles", jis);
+        output.set("distributive", con.is_distributive());
+    }
+    output.set("congruences", universe.iter().map(|p| p.to_string()).collect::<Vec<_>>());
+    output.table = Some(table);
+    Ok(output)
+}
+
+/// Compute the subuniverse generated by `gens`.
+pub fn sub(alg: &BasicAlgebra<i32>, gens: &[i32]) -> Result<Output, String> {
+    if let Some(&g) = gens.iter().find(|&&g| g < 0 || g >= alg.cardinality()) {
+        return Err(format!("Generator {} is not an element of {}", g, alg.name()));
+    }
+    let sub_lat = SubalgebraLattice::new_safe(boxed(alg))?;
+    let elements = sub_lat.sg(gens).elements().clone();
+    let mut output = Output::new();
+    describe(&mut output, alg);
+    output.set("generators", gens.to_vec());
+    output.set("subuniverse_size", elements.len());
+    let mut table = Table::new(&["element"]);
+    for e in &elements {
+        table.push(vec![e.to_string()]);
+    }
+    output.set("subuniverse", elements);
+    output.table = Some(table);
+    Ok(output)
+}
+
+/// Compute the free algebra on `gens` generators in the variety generated
+/// by the algebra.
+pub fn free(alg: &BasicAlgebra<i32>, gens: usize) -> Result<Output, String> {
+    let free = FreeAlgebra::new_safe(boxed(alg), gens as i32)?;
+    let mut output = Output::new();
+    describe(&mut output, alg);
+    output.set("generators", gens);
+    output.set("cardinality", free.cardinality());
+    Ok(output)
+}
+
+/// Check whether an equation `lhs = rhs` holds in the algebra.
+pub fn check_equation(alg: &BasicAlgebra<i32>, equation: &str) -> Result<Output, String> {
+    let (lhs, rhs) = equation
+        .split_once('=')
+        .ok_or_else(|| format!("Equation must have the form lhs = rhs: {}", equation))?;
+    let eq = Equation::new(string_to_term(lhs)?, string_to_term(rhs)?);
+    let failure = eq.find_failure_map(Arc::new(alg.clone()))?;
+    let mut output = Output::new();
+    describe(&mut output, alg);
+    output.set("equation", eq.to_string());
+    output.set("holds", failure.is_none());
+    let mut table = Table::new(&["variable", "value"]);
+    match failure {
+        Some(failure) => {
+            let mut vars: Vec<_> = failure.into_iter().collect();
+            vars.sort();
+            let mut map = Map::new();
+            for (var, value) in vars {
+                table.push(vec![var.clone(), value.to_string()]);
+                map.insert(var, value.into());
+            }
+            output.set("failure", Value::Object(map));
+        }
+        None => output.set("failure", Value::Null),
+    }
+    output.table = Some(table);
+    Ok(output)
+}
+
+/// Search for Mal'cev condition terms.
+///
+/// # Arguments
+/// * `kinds` - The terms to look for, from [`MalcevReport::KINDS`]
+/// * `arity` - The arity of the near unanimity term
+pub fn malcev(alg: &BasicAlgebra<i32>, kinds: &[&str], arity: usize) -> Result<Output, String> {
+    let report = MalcevReport::analyze(alg, kinds, arity)?;
+    let mut output = Output::new();
+    describe(&mut output, alg);
+    let mut table = Table::new(&["kind", "found", "terms"]);
+    for kind in kinds {
+        let terms = &report.terms[*kind];
+        table.push(vec![
+            kind.to_string(),
+            terms.is_some().to_string(),
+            terms.as_ref().map(|t| t.join("; ")).unwrap_or_default(),
+        ]);
+    }
+    output.set("terms", serde_json::to_value(&report.terms).map_err(|e| e.to_string())?);
+    output.table = Some(table);
+    Ok(output)
+}
+
+/// Search for a homomorphism from `a` to `b`.
+pub fn homomorphism(a: &BasicAlgebra<i32>, b: &BasicAlgebra<i32>, backend: SearchBackend) -> Result<Output, String> {
+    let map = find_homomorphism(a, b, backend)?;
+    let mut output = Output::new();
+    output.set("from", a.name());
+    output.set("to", b.name());
+    output.set("exists", map.is_some());
+    let mut table = Table::new(&["element", "image"]);
+    if let Some(map) = &map {
+        for (i, image) in map.iter().enumerate() {
+            table.push(vec![i.to_string(), image.to_string()]);
+        }
+    }
+    output.set("homomorphism", map.map_or(Value::Null, Value::from));
+    output.table = Some(table);
+    Ok(output)
+}
+
+/// Time benchmark tasks on the algebras of [`bench_suite`].
+///
+/// An interrupted run gives an incomplete output with the timings made so
+/// far.
+///
+/// # Arguments
+/// * `tasks` - The tasks to run
+/// * `names` - The names of the suite algebras to run on, or `None` for all
+/// * `repeat` - The runs of each task; the fastest is reported
+pub fn bench(tasks: &[BenchTask], names: Option<&[&str]>, repeat: usize) -> Result<Output, String> {
+    let suite = bench_suite();
+    if let Some(name) = names.and_then(|names| names.iter().find(|n| !suite.iter().any(|b| b.algebra.name() == **n))) {
+        return Err(format!("No algebra named {} in the benchmark suite", name));
+    }
+    let mut output = Output::new();
+    let mut table = Table::new(&["algebra", "family", "size", "task", "result", "seconds"]);
+    let mut results = Vec::new();
+    'suite: for bench in suite.iter().filter(|b| names.is_none_or(|names| names.contains(&b.algebra.name()))) {
+        for task in tasks {
+            let mut best = f64::INFINITY;
+            let mut result = 0;
+            for _ in 0..repeat {
+                let start = Instant::now();
+                result = match task.run(&bench.algebra).map_err(|e| ComputationError::new(e, || ())) {
+                    Ok(result) => result,
+                    Err(ComputationError::Stopped { reason, .. }) => {
+                        output.complete = false;
+                        output.set("stopped", reason.to_string());
+                        break 'suite;
+                    }
+                    Err(ComputationError::Failed(e)) => return Err(e),
+                };
+                best = best.min(start.elapsed().as_secs_f64());
+            }
+            table.push(vec![
+                bench.algebra.name().to_string(),
+                bench.family.to_string(),
+                bench.algebra.cardinality().to_string(),
+                task.name().to_string(),
+                result.to_string(),
+                format!("{:.6}", best),
+            ]);
+            results.push(serde_json::json!({
+                "algebra": bench.algebra.name(),
+                "family": bench.family,
+                "size": bench.algebra.cardinality(),
+                "task": task.name(),
+                "result": result,
+                "seconds": best,
+            }));
+        }
+    }
+    output.set("repeat", repeat);
+    output.set("results", results);
+    output.table = Some(table);
+    Ok(output)
+}
@@ -0,0 +1,31 @@
+//! Reading algebras from `.ua` (XML) and JSON files.
+//!
+//! JSON files use the `"algebra"` representation of [`uacalc::io::json`].
+
+use std::path::Path;
+
+use uacalc::alg::BasicAlgebra;
+use uacalc::io::json::FromJson;
+use uacalc::io::AlgebraReader;
+
+/// Read an algebra, choosing the format by the file extension: `.json` is
+/// read as JSON and anything else as a `.ua` (XML) file.
+///
+/// # Arguments
+/// * `path` - The algebra file
+///
+/// # Returns
+/// The algebra, or an error naming the file
+pub fn read_algebra(path: &str) -> Result<BasicAlgebra<i32>, String> {
+    let is_json = Path::new(path)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+    let result = if is_json {
+        std::fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| BasicAlgebra::<i32>::from_json_str(&text))
+    } else {
+        AlgebraReader::new_from_path(path).and_then(|reader| reader.read_algebra_file())
+    };
+    result.map_err(|e| format!("Failed to read algebra {}: {}", path, e))
+}
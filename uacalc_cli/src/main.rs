@@ -0,0 +1,287 @@
+//! `uacalc-cli`: run UACalc computations from the command line.
+//!
+//! Algebras are read from `.ua` (XML) or JSON files and results are written
+//! as JSON or CSV to standard output or a file. The exit status is 0 on
+//! success, 1 on an error, 2 on a usage error and 3 if the computation was
+//! stopped by `--timeout`; a stopped computation still writes whatever
+//! partial result it has.
+
+mod commands;
+mod input;
+mod output;
+
+use std::io::Write;
+use std::ops::ControlFlow;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use uacalc::alg::search::SearchBackend;
+use uacalc::bench::BenchTask;
+use uacalc::io::json::MalcevReport;
+use uacalc::progress::{Budget, BudgetExceeded, BudgetResource, ComputationError, ProgressToken, StopReason};
+
+use crate::commands::DEFAULT_TERMS;
+use crate::input::read_algebra;
+use crate::output::{Format, Output};
+
+const EXIT_ERROR: i32 = 1;
+const EXIT_STOPPED: i32 = 3;
+
+/// How long after `--timeout` a computation that does not check for
+/// cancellation is abandoned.
+const TIMEOUT_GRACE: Duration = Duration::from_secs(2);
+
+fn algebra_arg(name: &'static str, help: &'static str) -> Arg {
+    Arg::new(name).required(true).value_name("FILE").help(help)
+}
+
+fn cli() -> Command {
+    Command::new("uacalc-cli")
+        .version(env!("CARGO_PKG_VERSION"))
+        .about("Universal algebra computations on algebras read from .ua or JSON files")
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .global(true)
+                .value_parser(["json", "csv"])
+                .default_value("json")
+                .help("Output format"),
+        )
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .short('o')
+                .global(true)
+                .value_name("FILE")
+                .help("Write the result to FILE instead of standard output"),
+        )
+        .arg(
+            Arg::new("progress")
+                .long("progress")
+                .global(true)
+                .action(ArgAction::SetTrue)
+                .help("Report progress on standard error"),
+        )
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .global(true)
+                .value_name("SECONDS")
+                .value_parser(value_parser!(f64))
+                .help("Stop the computation after SECONDS"),
+        )
+        .subcommand(
+            Command::new("conlat")
+                .about("Compute the congruence lattice")
+                .arg(algebra_arg("algebra", "The algebra")),
+        )
+        .subcommand(
+            Command::new("sub")
+                .about("Compute the subuniverse generated by some elements")
+                .arg(algebra_arg("algebra", "The algebra"))
+                .arg(
+                    Arg::new("gens")
+                        .long("gens")
+                        .required(true)
+                        .value_delimiter(',')
+                        .value_parser(value_parser!(i32))
+                        .help("Comma separated generators"),
+                ),
+        )
+        .subcommand(
+            Command::new("free")
+                .about("Compute the free algebra in the variety generated by the algebra")
+                .arg(algebra_arg("algebra", "The algebra"))
+                .arg(
+                    Arg::new("gens")
+                        .long("gens")
+                        .required(true)
+                        .value_parser(value_parser!(usize))
+                        .help("Number of generators"),
+                ),
+        )
+        .subcommand(
+            Command::new("check-equation")
+                .about("Check whether an equation holds in the algebra")
+                .arg(algebra_arg("algebra", "The algebra"))
+                .arg(Arg::new("equation").required(true).help("The equation, e.g. \"f(x,y) = f(y,x)\"")),
+        )
+        .subcommand(
+            Command::new("malcev")
+                .about("Search for Mal'cev condition terms")
+                .arg(algebra_arg("algebra", "The algebra"))
+                .arg(
+                    Arg::new("term")
+                        .long("term")
+                        .action(ArgAction::Append)
+                        .value_parser(MalcevReport::KINDS.to_vec())
+                        .help("A term to search for; may be repeated (default: malcev, majority, minority, pixley, semilattice, taylor)"),
+                )
+                .arg(
+                    Arg::new("arity")
+                        .long("arity")
+                        .default_value("3")
+                        .value_parser(value_parser!(usize))
+                        .help("Arity of the near unanimity term"),
+                ),
+        )
+        .subcommand(
+            Command::new("homomorphism")
+                .about("Search for a homomorphism between two algebras")
+                .arg(algebra_arg("from", "The domain algebra"))
+                .arg(algebra_arg("to", "The target algebra"))
+                .arg(
+                    Arg::new("backend")
+                        .long("backend")
+                        .default_value("ac")
+                        .help("Search backend: backtrack, ac, sac or sat (with the sat feature)"),
+                ),
+        )
+        .subcommand(
+            Command::new("bench")
+                .about("Time the benchmark tasks on the canonical algebra suite")
+                .arg(
+                    Arg::new("task")
+                        .long("task")
+                        .action(ArgAction::Append)
+                        .value_parser(BenchTask::ALL.map(|t| t.name()).to_vec())
+                        .help("A task to run; may be repeated (default: all)"),
+                )
+                .arg(
+                    Arg::new("algebra")
+                        .long("algebra")
+                        .action(ArgAction::Append)
+                        .help("The name of a suite algebra to run on; may be repeated (default: all)"),
+                )
+                .arg(
+                    Arg::new("repeat")
+                        .long("repeat")
+                        .default_value("3")
+                        .value_parser(value_parser!(u64).range(1..))
+                        .help("Runs of each task; the fastest is reported"),
+                ),
+        )
+}
+
+/// Run a subcommand on the current thread.
+fn run(name: &str, args: &ArgMatches) -> Result<Output, String> {
+    let algebra = |key: &str| read_algebra(args.get_one::<String>(key).unwrap());
+    match name {
+        "conlat" => commands::conlat(&algebra("algebra")?),
+        "sub" => {
+            let gens: Vec<i32> = args.get_many::<i32>("gens").unwrap().copied().collect();
+            commands::sub(&algebra("algebra")?, &gens)
+        }
+        "free" => commands::free(&algebra("algebra")?, *args.get_one::<usize>("gens").unwrap()),
+        "check-equation" => commands::check_equation(&algebra("algebra")?, args.get_one::<String>("equation").unwrap()),
+        "malcev" => {
+            let kinds: Vec<&str> = match args.get_many::<String>("term") {
+                Some(kinds) => kinds.map(|k| k.as_str()).collect(),
+                None => DEFAULT_TERMS.to_vec(),
+            };
+            commands::malcev(&algebra("algebra")?, &kinds, *args.get_one::<usize>("arity").unwrap())
+        }
+        "homomorphism" => {
+            let backend = SearchBackend::from_name(args.get_one::<String>("backend").unwrap())?;
+            commands::homomorphism(&algebra("from")?, &algebra("to")?, backend)
+        }
+        "bench" => {
+            let tasks: Vec<BenchTask> = match args.get_many::<String>("task") {
+                Some(names) => names.map(|n| BenchTask::from_name(n)).collect::<Result<_, _>>()?,
+                None => BenchTask::ALL.to_vec(),
+            };
+            let names: Option<Vec<&str>> = args.get_many::<String>("algebra").map(|n| n.map(|n| n.as_str()).collect());
+            commands::bench(&tasks, names.as_deref(), *args.get_one::<u64>("repeat").unwrap() as usize)
+        }
+        _ => unreachable!("clap only accepts the subcommands above"),
+    }
+}
+
+fn write_output(output: &Output, format: Format, path: Option<&String>) -> Result<(), String> {
+    match path {
+        Some(path) => {
+            let mut file = std::fs::File::create(path).map_err(|e| format!("Failed to create {}: {}", path, e))?;
+            output.write(format, &mut file)
+        }
+        None => output.write(format, &mut std::io::stdout().lock()),
+    }
+    .map_err(|e| format!("Failed to write output: {}", e))
+}
+
+fn main() {
+    let matches = cli().get_matches();
+    let format = Format::from_name(matches.get_one::<String>("format").unwrap()).unwrap();
+    let output_path = matches.get_one::<String>("output").cloned();
+    let timeout = matches.get_one::<f64>("timeout").copied();
+    if timeout.is_some_and(|t| !(t.is_finite() && t >= 0.0)) {
+        eprintln!("error: --timeout must be a non-negative number of seconds");
+        std::process::exit(2);
+    }
+    let timeout = timeout.map(Duration::from_secs_f64);
+
+    let token = if matches.get_flag("progress") {
+        ProgressToken::with_callback(|event| {
+            let _ = if event.phase.is_empty() {
+                writeln!(std::io::stderr(), "{}", event.message)
+            } else {
+                writeln!(std::io::stderr(), "[{}] {}", event.phase, event.message)
+            };
+            ControlFlow::Continue(())
+        })
+    } else {
+        ProgressToken::new()
+    };
+    if let Some(wall_time) = timeout {
+        token.set_budget(Budget { wall_time: Some(wall_time), ..Budget::default() });
+    }
+
+    // The computation runs on a worker thread so that one which never checks
+    // the token can still be abandoned after the timeout.
+    let running = token.clone();
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        let _installed = token.install();
+        let (name, args) = matches.subcommand().unwrap();
+        let _ = sender.send(run(name, args));
+    });
+    let result = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout + TIMEOUT_GRACE).map_err(|_| {
+            let exceeded = BudgetExceeded {
+                resource: BudgetResource::WallTime,
+                limit: timeout.as_millis() as u64,
+                used: (timeout + TIMEOUT_GRACE).as_millis() as u64,
+            };
+            ComputationError::Stopped { reason: StopReason::BudgetExceeded(exceeded), partial: () }
+        }),
+        None => receiver.recv().map_err(|_| ComputationError::Failed("The computation panicked".to_string())),
+    };
+    // The worker's error is typed by the token it ran with.
+    let result = result.and_then(|r| r.map_err(|e| match running.stop_reason() {
+        Some(reason) => ComputationError::Stopped { reason, partial: () },
+        None => ComputationError::Failed(e),
+    }));
+
+    match result {
+        Ok(output) => {
+            if let Err(e) = write_output(&output, format, output_path.as_ref()) {
+                eprintln!("error: {}", e);
+                std::process::exit(EXIT_ERROR);
+            }
+            if !output.complete {
+                eprintln!("warning: the computation was stopped; the result is partial");
+                std::process::exit(EXIT_STOPPED);
+            }
+        }
+        Err(ComputationError::Stopped { reason, .. }) => {
+            eprintln!("error: {}", reason);
+            std::process::exit(EXIT_STOPPED);
+        }
+        Err(ComputationError::Failed(e)) => {
+            eprintln!("error: {}", e);
+            std::process::exit(EXIT_ERROR);
+        }
+    }
+}
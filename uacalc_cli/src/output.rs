@@ -0,0 +1,144 @@
+//! Writing command results as JSON or CSV.
+
+use std::io::Write;
+
+use serde_json::{Map, Value};
+
+/// The output formats selected with `--format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+impl Format {
+    /// Parse a format name: `"json"` or `"csv"`.
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Unknown output format: {}", name)),
+        }
+    }
+}
+
+/// Rows of a result, written when the output format is CSV.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: &[&str]) -> Self {
+        Table { headers: headers.iter().map(|h| h.to_string()).collect(), rows: Vec::new() }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+}
+
+/// The result of a command.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Output {
+    /// The full result, written as JSON.
+    pub value: Map<String, Value>,
+    /// The main listing of the result, written as CSV. Without one the
+    /// scalar fields of `value` are written as `key,value` rows.
+    pub table: Option<Table>,
+    /// False if the computation was stopped early and `value` holds a
+    /// partial result.
+    pub complete: bool,
+}
+
+impl Output {
+    pub fn new() -> Self {
+        Output { value: Map::new(), table: None, complete: true }
+    }
+
+    /// Add a field to the JSON result.
+    pub fn set(&mut self, key: &str, value: impl Into<Value>) {
+        self.value.insert(key.to_string(), value.into());
+    }
+
+    /// Write the result in the given format.
+    pub fn write(&self, format: Format, out: &mut dyn Write) -> std::io::Result<()> {
+        match format {
+            Format::Json => {
+                let mut value = self.value.clone();
+                value.insert("complete".to_string(), Value::Bool(self.complete));
+                serde_json::to_writer_pretty(&mut *out, &Value::Object(value))?;
+                writeln!(out)
+            }
+            Format::Csv => {
+                let table = match &self.table {
+                    Some(table) => table.clone(),
+                    None => self.scalar_table(),
+                };
+                write_csv_row(out, &table.headers)?;
+                for row in &table.rows {
+                    write_csv_row(out, row)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn scalar_table(&self) -> Table {
+        let mut table = Table::new(&["key", "value"]);
+        for (key, value) in &self.value {
+            let text = match value {
+                Value::String(s) => s.clone(),
+                Value::Array(_) | Value::Object(_) => continue,
+                other => other.to_string(),
+            };
+            table.push(vec![key.clone(), text]);
+        }
+        table.push(vec!["complete".to_string(), self.complete.to_string()]);
+        table
+    }
+}
+
+impl Default for Output {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn write_csv_row(out: &mut dyn Write, fields: &[String]) -> std::io::Result<()> {
+    let fields: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+    writeln!(out, "{}", fields.join(","))
+}
+
+/// Quote a field if it contains a separator, quote or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_csv() {
+        let mut output = Output::new();
+        output.set("algebra", "A");
+        output.set("size", 3);
+        output.set("elements", vec![0, 1, 2]);
+        let mut buf = Vec::new();
+        output.write(Format::Csv, &mut buf).unwrap();
+        assert_eq!(String::from_utf8(buf).unwrap(), "key,value\nalgebra,A\nsize,3\ncomplete,true\n");
+
+        let mut table = Table::new(&["index", "congruence"]);
+        table.push(vec!["0".to_string(), "|0,1|2|".to_string()]);
+        output.table = Some(table);
+        let mut buf = Vec::new();
+        output.write(Format::Csv, &mut buf).unwrap();
+        assert_eq!(String::from_utf8(buf).unwrap(), "index,congruence\n0,\"|0,1|2|\"\n");
+    }
+}
@@ -0,0 +1,100 @@
+//! Tests running the `uacalc-cli` binary.
+
+use std::process::{Command, Output};
+
+use serde_json::Value;
+
+const N5: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../resources/algebras/n5.ua");
+const LAT2: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../resources/algebras/lat2.ua");
+const M3: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../resources/algebras/m3.ua");
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_uacalc-cli")).args(args).output().unwrap()
+}
+
+fn run_json(args: &[&str]) -> Value {
+    let output = run(args);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn test_conlat() {
+    let json = run_json(&["conlat", N5]);
+    assert_eq!(json["cardinality"], 5);
+    assert_eq!(json["join_irreducibles"].as_array().unwrap().len(), 3);
+    assert_eq!(json["complete"], true);
+
+    let output = run(&["conlat", M3, "--format", "csv"]);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "index,congruence\n0,|0|1|2|3|4|\n1,\"|0,1,2,3,4|\"\n");
+}
+
+#[test]
+fn test_sub_and_free() {
+    let json = run_json(&["sub", N5, "--gens", "1,2"]);
+    assert_eq!(json["subuniverse"], serde_json::json!([0, 1, 2, 4]));
+
+    let json = run_json(&["free", LAT2, "--gens", "3"]);
+    assert_eq!(json["cardinality"], 18);
+
+    let output = run(&["sub", N5, "--gens", "9"]);
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_check_equation() {
+    let json = run_json(&["check-equation", LAT2, "join(x,meet(x,y)) = x"]);
+    assert_eq!(json["holds"], true);
+    let json = run_json(&["check-equation", LAT2, "join(x,y) = meet(x,y)"]);
+    assert_eq!(json["holds"], false);
+    assert!(json["failure"].is_object());
+}
+
+#[test]
+fn test_malcev_and_homomorphism() {
+    let json = run_json(&["malcev", LAT2, "--term", "majority", "--term", "malcev"]);
+    assert!(json["terms"]["majority"].is_array());
+    assert!(json["terms"]["malcev"].is_null());
+
+    let json = run_json(&["homomorphism", LAT2, N5, "--backend", "backtrack"]);
+    assert_eq!(json["exists"], true);
+    assert_eq!(json["homomorphism"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_json_input_and_output_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let alg = dir.path().join("z2.json");
+    std::fs::write(&alg, r#"{"name": "Z2", "size": 2, "operations": [{"name": "+", "arity": 2, "table": [0, 1, 1, 0]}]}"#).unwrap();
+    let out = dir.path().join("con.json");
+    let output = run(&["conlat", alg.to_str().unwrap(), "-o", out.to_str().unwrap()]);
+    assert!(output.status.success());
+    let json: Value = serde_json::from_str(&std::fs::read_to_string(&out).unwrap()).unwrap();
+    assert_eq!(json["algebra"], "Z2");
+    assert_eq!(json["cardinality"], 2);
+}
+
+#[test]
+fn test_timeout_gives_partial_result() {
+    let output = run(&["conlat", N5, "--timeout", "0"]);
+    assert_eq!(output.status.code(), Some(3));
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["complete"], false);
+    assert!(json["stopped"].as_str().unwrap().starts_with("Budget exceeded"));
+
+    let output = run(&["conlat", N5, "--timeout", "-1"]);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_bench() {
+    let json = run_json(&["bench", "--task", "conlat", "--algebra", "n5", "--algebra", "polin", "--repeat", "1"]);
+    let results = json["results"].as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0]["algebra"], "n5");
+    assert_eq!(results[0]["result"], 5);
+    assert!(results[1]["seconds"].as_f64().unwrap() >= 0.0);
+
+    let output = run(&["bench", "--algebra", "nope"]);
+    assert_eq!(output.status.code(), Some(1));
+}
@@ -34,6 +34,14 @@ pub fn register_algebras_functions(_py: Python, m: &Bound<'_, PyModule>) -> PyRe
     m.add_function(wrap_pyfunction!(make_random_algebra_with_arities, m)?)?;
     m.add_function(wrap_pyfunction!(make_random_algebra_with_arities_and_seed, m)?)?;
     m.add_function(wrap_pyfunction!(full_transformation_semigroup, m)?)?;
+    m.add_function(wrap_pyfunction!(algebra_from_permutation_group, m)?)?;
+    m.add_function(wrap_pyfunction!(cayley_algebra, m)?)?;
+    m.add_function(wrap_pyfunction!(digraph_algebra, m)?)?;
+    m.add_function(wrap_pyfunction!(algebra_to_digraph, m)?)?;
+    m.add_function(wrap_pyfunction!(boolean_algebra, m)?)?;
+    m.add_function(wrap_pyfunction!(heyting_algebra_from_poset, m)?)?;
+    m.add_function(wrap_pyfunction!(is_boolean_algebra, m)?)?;
+    m.add_function(wrap_pyfunction!(is_heyting_algebra, m)?)?;
     m.add_function(wrap_pyfunction!(quasi_critical_congruences, m)?)?;
     m.add_function(wrap_pyfunction!(quasi_critical, m)?)?;
     m.add_function(wrap_pyfunction!(unary_clone, m)?)?;
@@ -432,6 +440,141 @@ fn full_transformation_semigroup(n: i32, include_constants: bool, include_id: bo
     }
 }
 
+/// Create the G-set of a list of permutations: the unary algebra on
+/// {0..n-1} whose operations g0, g1, ... are the permutations.
+///
+/// # Arguments
+/// * `perms` - The permutations, each a list of the images of 0..n-1
+///
+/// # Returns
+/// A BasicAlgebra named "GSet"
+///
+/// # Raises
+/// `ValueError` if the list is empty or an entry is not a permutation of
+/// the same size as the others
+#[pyfunction]
+fn algebra_from_permutation_group(perms: Vec<Vec<i32>>) -> PyResult<PyBasicAlgebra> {
+    match algebras::algebra_from_permutation_group(&perms) {
+        Ok(result) => Ok(PyBasicAlgebra { inner: result }),
+        Err(e) => Err(PyValueError::new_err(e)),
+    }
+}
+
+/// Create the left regular representation of a group: the unary algebra on
+/// the group with an operation l<g>(x) = g * x for each element g.
+///
+/// # Arguments
+/// * `group_table` - The multiplication table; group_table[g][x] is g * x
+///
+/// # Returns
+/// A BasicAlgebra named "Cayley"
+///
+/// # Raises
+/// `ValueError` if the table is not the table of a group
+#[pyfunction]
+fn cayley_algebra(group_table: Vec<Vec<i32>>) -> PyResult<PyBasicAlgebra> {
+    match algebras::cayley_algebra(&group_table) {
+        Ok(result) => Ok(PyBasicAlgebra { inner: result }),
+        Err(e) => Err(PyValueError::new_err(e)),
+    }
+}
+
+/// Create the graph algebra of a digraph: the vertices 0..n-1 plus a zero n,
+/// with x * y = x if (x, y) is an edge and x * y = n otherwise.
+///
+/// # Arguments
+/// * `edges` - The edges, a list of (source, target) pairs
+/// * `n` - The number of vertices
+///
+/// # Returns
+/// A BasicAlgebra of cardinality n + 1
+///
+/// # Raises
+/// `ValueError` if an edge is not between vertices below n
+#[pyfunction]
+fn digraph_algebra(edges: Vec<(usize, usize)>, n: usize) -> PyResult<PyBasicAlgebra> {
+    match algebras::digraph_algebra(&edges, n) {
+        Ok(result) => Ok(PyBasicAlgebra { inner: result }),
+        Err(e) => Err(PyValueError::new_err(e)),
+    }
+}
+
+/// Recover the digraph of a graph algebra, or the functional digraph
+/// x -> f(x) of the operations of a unary algebra.
+///
+/// # Arguments
+/// * `algebra` - A unary algebra or a graph algebra (BasicAlgebra)
+///
+/// # Returns
+/// The sorted list of edges as (source, target) pairs
+///
+/// # Raises
+/// `ValueError` if the algebra is neither unary nor a graph algebra
+#[pyfunction]
+fn algebra_to_digraph(algebra: &PyBasicAlgebra) -> PyResult<Vec<(usize, usize)>> {
+    algebras::algebra_to_digraph(&algebra.inner).map_err(PyValueError::new_err)
+}
+
+/// Create the Boolean algebra of subsets of n_atoms atoms, with elements the
+/// subsets as bit masks and operations join, meet, neg, zero and one.
+///
+/// # Arguments
+/// * `n_atoms` - The number of atoms
+///
+/// # Returns
+/// A BasicAlgebra named Boolean-{n_atoms} with 2^n_atoms elements
+///
+/// # Raises
+/// `ValueError` if the tables would be too large
+#[pyfunction]
+fn boolean_algebra(n_atoms: usize) -> PyResult<PyBasicAlgebra> {
+    algebras::boolean_algebra(n_atoms).map(|inner| PyBasicAlgebra { inner }).map_err(PyValueError::new_err)
+}
+
+/// Create the Heyting algebra of a finite distributive lattice, with
+/// operations join, meet, imp (relative pseudocomplement), zero and one.
+///
+/// # Arguments
+/// * `poset` - A distributive lattice (Poset)
+///
+/// # Returns
+/// A BasicAlgebra on the indices of the poset
+///
+/// # Raises
+/// `ValueError` if the poset is not a distributive lattice
+#[pyfunction]
+fn heyting_algebra_from_poset(poset: &crate::lat::PyPoset) -> PyResult<PyBasicAlgebra> {
+    algebras::heyting_algebra_from_poset(&poset.inner).map(|inner| PyBasicAlgebra { inner }).map_err(PyValueError::new_err)
+}
+
+/// Recognize a Boolean algebra: two basic operations forming a complemented
+/// distributive lattice.
+///
+/// # Arguments
+/// * `algebra` - The algebra (BasicAlgebra)
+///
+/// # Returns
+/// The complement of each element, or `None` if the algebra has no Boolean
+/// lattice reduct
+#[pyfunction]
+fn is_boolean_algebra(algebra: &PyBasicAlgebra) -> Option<Vec<usize>> {
+    algebras::is_boolean_algebra(&algebra.inner)
+}
+
+/// Recognize a Heyting algebra: two basic operations forming a distributive
+/// lattice.
+///
+/// # Arguments
+/// * `algebra` - The algebra (BasicAlgebra)
+///
+/// # Returns
+/// The relative pseudocomplement table, a -> b at [a][b], or `None` if the
+/// algebra has no distributive lattice reduct
+#[pyfunction]
+fn is_heyting_algebra(algebra: &PyBasicAlgebra) -> Option<Vec<Vec<usize>>> {
+    algebras::is_heyting_algebra(&algebra.inner)
+}
+
 /// Find all quasi-critical congruences of an algebra.
 ///
 /// A congruence theta is quasi-critical if A/theta is quasi-critical,
@@ -4,11 +4,12 @@ use pyo3::types::PyList;
 use std::collections::HashMap;
 use uacalc::alg::*;
 use uacalc::alg::op::{Operation, IntOperation, BasicOperation};
+use uacalc::io::json::{FromJson, ToJson};
 use crate::alg::PySubalgebraLattice;
 use crate::alg::PyCongruenceLattice;
 
 /// Python wrapper for BasicAlgebra (for integer universes)
-#[pyclass]
+#[pyclass(module = "uacalc_lib.alg")]
 pub struct PyBasicAlgebra {
     pub(crate) inner: uacalc::alg::BasicAlgebra<i32>,
 }
@@ -32,7 +33,7 @@ impl PyBasicAlgebra {
 }
 
 /// Helper function to extract operations from a Python list
-fn extract_operations(ops_list: &Bound<'_, PyList>) -> PyResult<Vec<Box<dyn Operation>>> {
+pub(crate) fn extract_operations(ops_list: &Bound<'_, PyList>) -> PyResult<Vec<Box<dyn Operation>>> {
     let mut rust_ops = Vec::new();
     
     for item in ops_list.iter() {
@@ -313,21 +314,71 @@ impl PyBasicAlgebra {
         self.inner.convert_to_default_value_ops();
     }
 
-    /// Python string representation
+    /// Python string representation: the name, size and description of the
+    /// algebra followed by the table of each operation.
     fn __str__(&self) -> String {
-        self.inner.to_string()
+        self.inner.describe()
     }
 
-    /// Python repr representation
+    /// Get a human-readable summary of the algebra.
+    ///
+    /// Args:
+    ///     style (str, optional): "text" (default), "markdown" or "latex"
+    ///
+    /// Returns:
+    ///     str: The name, size and description followed by the operation tables
+    ///
+    /// Raises:
+    ///     ValueError: If the style is unknown
+    #[pyo3(signature = (style="text"))]
+    fn describe(&self, style: &str) -> PyResult<String> {
+        let style = uacalc::alg::op::TableStyle::from_name(style).map_err(PyValueError::new_err)?;
+        Ok(self.inner.describe_as(style))
+    }
+
+    /// Get the summary of the algebra with its operations as LaTeX tables.
+    fn to_latex(&self) -> String {
+        self.inner.describe_as(uacalc::alg::op::TableStyle::Latex)
+    }
+
+    /// Python repr representation, listing the name, size and operation symbols
     fn __repr__(&self) -> String {
-        format!("BasicAlgebra({})", self.inner.to_string())
+        let symbols: Vec<String> = self.inner.operations_ref_arc()
+            .iter()
+            .map(|op| format!("'{}'", op.symbol().name()))
+            .collect();
+        format!("BasicAlgebra(name='{}', size={}, operations=[{}])",
+                self.inner.name(), self.inner.cardinality(), symbols.join(", "))
     }
 
-    /// Python equality comparison
+    /// Pickle support: the state is the JSON representation of the algebra.
+    fn __getstate__(&self) -> String {
+        self.inner.to_json_str()
+    }
+
+    fn __setstate__(&mut self, state: &str) -> PyResult<()> {
+        self.inner = uacalc::alg::BasicAlgebra::<i32>::from_json_str(state).map_err(PyValueError::new_err)?;
+        Ok(())
+    }
+
+    fn __getnewargs__(&self) -> (String, Vec<i32>) {
+        (String::new(), vec![0])
+    }
+
+    /// Python equality comparison: the same universe and the same operations,
+    /// compared by symbol and table. Names are ignored.
     fn __eq__(&self, other: &PyBasicAlgebra) -> bool {
-        // Compare basic properties since we can't easily compare the full structure
-        self.inner.name() == other.inner.name() &&
-        self.inner.cardinality() == other.inner.cardinality()
+        self.inner == other.inner
+    }
+
+    /// Python hash function, consistent with equality
+    fn __hash__(&self) -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        self.inner.hash(&mut hasher);
+        hasher.finish()
     }
 
     /// Get the operations of this algebra.
@@ -404,10 +455,10 @@ impl PyBasicAlgebra {
     ///
     /// Returns:
     ///     CongruenceLattice: The congruence lattice
-    fn con(&mut self) -> PyCongruenceLattice {
+    fn con(&self, py: Python<'_>) -> PyCongruenceLattice {
         // Construct a new congruence lattice for this algebra.
         // We create a fresh lattice instance rather than exposing an internal reference.
-        PyCongruenceLattice::from_algebra(self)
+        PyCongruenceLattice::from_algebra(py, self)
     }
 
     /// Get the subalgebra lattice (lazy initialization).
@@ -0,0 +1,80 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::types::PyDict;
+use uacalc::alg::Algebra;
+use uacalc::alg::batch::{self, Analysis, AnalysisValue};
+use crate::alg::PyBasicAlgebra;
+use crate::eq::PyEquation;
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register batch analysis functions.
+pub fn register_batch_functions(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(analyze_batch, m)?)?;
+    Ok(())
+}
+
+/// Run analyses over many algebras in parallel, releasing the GIL.
+///
+/// Each analysis is an analysis name or an Equation, whose result is
+/// whether it holds. The names are "con_cardinality", "con_distributive",
+/// "simple", "malcev_term", "majority_term", "congruence_distributive",
+/// "congruence_modular", "taylor_term" and "jonsson_level".
+///
+/// Args:
+///     algebras (List[BasicAlgebra]): The algebras
+///     analyses (List[Union[str, Equation]]): The analyses to run on each algebra
+///     n_threads (int, optional): The number of worker threads; defaults to
+///         one per core
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     List[Dict[str, Any]]: One dict per algebra with its "algebra" name
+///         and the result of each analysis keyed by its name or equation
+///         string. A failed analysis maps to None and its message is in the
+///         dict under "errors".
+///
+/// Raises:
+///     ValueError: If an analysis name is unknown or the batch was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebras, analyses, n_threads=None, progress=None))]
+fn analyze_batch(
+    py: Python<'_>,
+    algebras: Vec<PyRef<'_, PyBasicAlgebra>>,
+    analyses: Vec<Bound<'_, PyAny>>,
+    n_threads: Option<usize>,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Vec<PyObject>> {
+    let analyses = analyses
+        .iter()
+        .map(|a| match a.extract::<String>() {
+            Ok(name) => Analysis::from_name(&name).map_err(PyValueError::new_err),
+            Err(_) => Ok(Analysis::Equation(a.extract::<PyRef<PyEquation>>()?.inner.clone())),
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    let algs: Vec<_> = algebras.iter().map(|a| a.inner.clone()).collect();
+    let results = with_progress(py, progress, || {
+        batch::analyze_batch(&algs, &analyses, n_threads).map_err(PyValueError::new_err)
+    })?;
+    let mut dicts = Vec::with_capacity(results.len());
+    for (alg, values) in algs.iter().zip(results) {
+        let dict = PyDict::new_bound(py);
+        let errors = PyDict::new_bound(py);
+        dict.set_item("algebra", alg.name())?;
+        for (analysis, value) in analyses.iter().zip(values) {
+            let key = analysis.to_string();
+            match value {
+                Ok(AnalysisValue::Bool(b)) => dict.set_item(key, b)?,
+                Ok(AnalysisValue::Int(i)) => dict.set_item(key, i)?,
+                Err(e) => {
+                    dict.set_item(&key, py.None())?;
+                    errors.set_item(key, e)?;
+                }
+            }
+        }
+        if !errors.is_empty() {
+            dict.set_item("errors", errors)?;
+        }
+        dicts.push(dict.into());
+    }
+    Ok(dicts)
+}
@@ -3,6 +3,7 @@ use pyo3::exceptions::PyValueError;
 use std::sync::Arc;
 use uacalc::alg::{BigProductAlgebra, SmallAlgebra, Algebra};
 use crate::alg::small_algebra::PySmallAlgebra;
+use crate::alg::cardinality::cardinality_to_py;
 
 /// Python wrapper for BigProductAlgebra
 #[pyclass(name = "BigProductAlgebra")]
@@ -81,6 +82,14 @@ impl PyBigProductAlgebra {
     fn cardinality(&self) -> i32 {
         self.inner.cardinality()
     }
+
+    /// Get the exact cardinality, even when it is too big for cardinality().
+    ///
+    /// # Returns
+    /// The cardinality as an int of any size, or None if it is unknown
+    fn exact_cardinality(&self, py: Python<'_>) -> PyResult<PyObject> {
+        cardinality_to_py(py, &self.inner.exact_cardinality())
+    }
     
     fn __str__(&self) -> String {
         format!("BigProductAlgebra(name: {}, factors: {})",
@@ -0,0 +1,29 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::types::PyLong;
+use uacalc::alg::Cardinality;
+
+/// Convert a cardinality to a Python int of arbitrary size, `math.inf` if
+/// it is infinite or `None` if it is unknown.
+pub(crate) fn cardinality_to_py(py: Python<'_>, card: &Cardinality) -> PyResult<PyObject> {
+    match card {
+        Cardinality::Finite(n) => Ok(n.into_py(py)),
+        Cardinality::Big(n) => {
+            let int = py.import_bound("builtins")?.getattr("int")?;
+            Ok(int.call1((n.to_string(),))?.unbind())
+        }
+        Cardinality::Infinite => Ok(f64::INFINITY.into_py(py)),
+        Cardinality::Unknown => Ok(py.None()),
+    }
+}
+
+/// Convert a non-negative Python int of any size to a cardinality.
+pub(crate) fn cardinality_from_py(value: &Bound<'_, PyAny>) -> PyResult<Cardinality> {
+    if !value.is_instance_of::<PyLong>() {
+        return Err(PyTypeError::new_err("Expected an int"));
+    }
+    if let Ok(n) = value.extract::<u64>() {
+        return Ok(Cardinality::Finite(n));
+    }
+    value.str()?.to_str()?.parse::<Cardinality>().map_err(PyValueError::new_err)
+}
@@ -0,0 +1,359 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::types::PyList;
+use uacalc::alg::clone::{self, BooleanClone, Relation};
+use uacalc::alg::op::{IntOperation, Operation};
+use crate::alg::basic_algebra::extract_operations;
+use crate::alg::op::int_operation::PyIntOperation;
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register the Relation class and the Pol-Inv functions.
+pub fn register_clone(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyRelation>()?;
+    m.add("Relation", m.getattr("PyRelation")?)?;
+    m.add_function(wrap_pyfunction!(preserves, m)?)?;
+    m.add_function(wrap_pyfunction!(inv, m)?)?;
+    m.add_function(wrap_pyfunction!(pol, m)?)?;
+    m.add_function(wrap_pyfunction!(pp_definable, m)?)?;
+    m.add_function(wrap_pyfunction!(pp_closure, m)?)?;
+    m.add_function(wrap_pyfunction!(pp_counterexample, m)?)?;
+    m.add_class::<PyBooleanClone>()?;
+    m.add("BooleanClone", m.getattr("PyBooleanClone")?)?;
+    m.add_function(wrap_pyfunction!(post_lattice, m)?)?;
+    m.add_function(wrap_pyfunction!(classify_boolean_clone, m)?)?;
+    Ok(())
+}
+
+/// A finitary relation on {0, ..., n-1}: a set of tuples of a fixed arity.
+#[pyclass(module = "uacalc_lib.alg")]
+#[derive(Clone)]
+pub struct PyRelation {
+    pub(crate) inner: Relation,
+}
+
+#[pymethods]
+impl PyRelation {
+    /// Create a relation.
+    ///
+    /// Args:
+    ///     universe_size (int): The size n of the universe {0, ..., n-1}
+    ///     arity (int): The length of the tuples
+    ///     tuples (List[List[int]]): The tuples; duplicates are ignored
+    ///
+    /// Raises:
+    ///     ValueError: If a tuple has the wrong length or an entry outside the universe
+    #[new]
+    fn new(universe_size: i32, arity: usize, tuples: Vec<Vec<i32>>) -> PyResult<Self> {
+        Relation::new(universe_size, arity, tuples)
+            .map(|inner| PyRelation { inner })
+            .map_err(PyValueError::new_err)
+    }
+
+    /// The size of the universe.
+    fn universe_size(&self) -> i32 {
+        self.inner.universe_size()
+    }
+
+    /// The length of the tuples.
+    fn arity(&self) -> usize {
+        self.inner.arity()
+    }
+
+    /// The tuples, in lexicographic order.
+    fn tuples(&self) -> Vec<Vec<i32>> {
+        self.inner.tuples().to_vec()
+    }
+
+    /// Whether a tuple belongs to the relation.
+    fn contains(&self, tuple: Vec<i32>) -> bool {
+        self.inner.contains(&tuple)
+    }
+
+    fn __contains__(&self, tuple: Vec<i32>) -> bool {
+        self.inner.contains(&tuple)
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn __eq__(&self, other: &PyRelation) -> bool {
+        self.inner == other.inner
+    }
+
+    fn __hash__(&self) -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        self.inner.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Relation({}, {}, {:?})", self.inner.universe_size(), self.inner.arity(), self.inner.tuples())
+    }
+}
+
+/// Check whether an operation preserves a relation.
+///
+/// Args:
+///     op (Operation): The operation (IntOperation, BasicOperation or TermOperationImp)
+///     relation (Relation): The relation, on the same universe
+///
+/// Returns:
+///     bool: Whether applying op to tuples of the relation coordinatewise
+///         always gives a tuple of the relation
+///
+/// Raises:
+///     ValueError: If the universes differ
+#[pyfunction]
+fn preserves(py: Python<'_>, op: Bound<'_, PyAny>, relation: &PyRelation) -> PyResult<bool> {
+    let ops = extract_operations(&PyList::new_bound(py, [op]))?;
+    clone::preserves(ops[0].as_ref(), &relation.inner).map_err(PyValueError::new_err)
+}
+
+/// The relations of arity at most max_arity preserved by all the operations:
+/// the subuniverses of the powers of the algebra they form.
+///
+/// Args:
+///     universe_size (int): The size n of the universe
+///     operations (List[Operation]): The operations, all on n elements
+///     max_arity (int): The largest arity of the relations
+///     progress (ProgressReporter, optional): For progress and cancellation
+///
+/// Returns:
+///     List[Relation]: The relations, by arity, then size, then tuples
+///
+/// Raises:
+///     ValueError: If an operation is on another universe or a power is too large
+#[pyfunction]
+#[pyo3(signature = (universe_size, operations, max_arity, progress=None))]
+fn inv(
+    py: Python<'_>,
+    universe_size: i32,
+    operations: &Bound<'_, PyList>,
+    max_arity: usize,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Vec<PyRelation>> {
+    let ops = extract_operations(operations)?;
+    with_progress(py, progress, || {
+        clone::inv(universe_size, &ops, max_arity).map_err(PyValueError::new_err)
+    })
+    .map(|rels| rels.into_iter().map(|inner| PyRelation { inner }).collect())
+}
+
+/// The operations of arity 1 to max_arity preserving all the relations.
+///
+/// Args:
+///     universe_size (int): The size n of the universe
+///     relations (List[Relation]): The relations, all on n elements
+///     max_arity (int): The largest arity of the operations
+///     progress (ProgressReporter, optional): For progress and cancellation
+///
+/// Returns:
+///     List[IntOperation]: The operations, by arity and then table; the i-th
+///         of arity m is named p{m}_{i}
+///
+/// Raises:
+///     ValueError: If a relation is on another universe or a power is too large
+#[pyfunction]
+#[pyo3(signature = (universe_size, relations, max_arity, progress=None))]
+fn pol(
+    py: Python<'_>,
+    universe_size: i32,
+    relations: Vec<PyRelation>,
+    max_arity: usize,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Vec<PyIntOperation>> {
+    let rels: Vec<Relation> = relations.into_iter().map(|r| r.inner).collect();
+    let ops = with_progress(py, progress, || {
+        clone::pol(universe_size, &rels, max_arity).map_err(PyValueError::new_err)
+    })?;
+    ops.into_iter().map(|op| to_py_operation(op.as_ref())).collect()
+}
+
+pub(crate) fn to_py_operation(op: &dyn Operation) -> PyResult<PyIntOperation> {
+    let table = op.get_table().map(|t| t.to_vec()).unwrap_or_default();
+    IntOperation::new(op.symbol().clone(), op.get_set_size(), table)
+        .map(|inner| PyIntOperation { inner })
+        .map_err(PyValueError::new_err)
+}
+
+/// Test whether a relation is primitive positive definable from base
+/// relations, by checking that every polymorphism of the base of arity
+/// len(relation) preserves it.
+///
+/// Args:
+///     relation (Relation): The relation
+///     base (List[Relation]): The relations it may be defined from
+///     progress (ProgressReporter, optional): For progress and cancellation
+///
+/// Returns:
+///     bool: Whether the relation is pp-definable from the base
+///
+/// Raises:
+///     ValueError: If a relation is on another universe or A^len(relation) is too large
+#[pyfunction]
+#[pyo3(signature = (relation, base, progress=None))]
+fn pp_definable(
+    py: Python<'_>,
+    relation: &PyRelation,
+    base: Vec<PyRelation>,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<bool> {
+    let base: Vec<Relation> = base.into_iter().map(|r| r.inner).collect();
+    with_progress(py, progress, || {
+        clone::pp_definable(&relation.inner, &base).map_err(PyValueError::new_err)
+    })
+}
+
+/// The least relation containing a relation that is primitive positive
+/// definable from base relations.
+///
+/// Args:
+///     relation (Relation): The relation
+///     base (List[Relation]): The relations it is defined from
+///     progress (ProgressReporter, optional): For progress and cancellation
+///
+/// Returns:
+///     Relation: The least pp-definable relation containing the relation
+///
+/// Raises:
+///     ValueError: If a relation is on another universe or A^len(relation) is too large
+#[pyfunction]
+#[pyo3(signature = (relation, base, progress=None))]
+fn pp_closure(
+    py: Python<'_>,
+    relation: &PyRelation,
+    base: Vec<PyRelation>,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<PyRelation> {
+    let base: Vec<Relation> = base.into_iter().map(|r| r.inner).collect();
+    with_progress(py, progress, || {
+        clone::pp_closure(&relation.inner, &base).map_err(PyValueError::new_err)
+    })
+    .map(|inner| PyRelation { inner })
+}
+
+/// A polymorphism of the base relations that does not preserve a relation,
+/// witnessing that the relation is not pp-definable from them.
+///
+/// Args:
+///     relation (Relation): The relation
+///     base (List[Relation]): The base relations
+///     progress (ProgressReporter, optional): For progress and cancellation
+///
+/// Returns:
+///     Optional[IntOperation]: An operation named w of arity len(relation),
+///         or None if the relation is pp-definable from the base
+///
+/// Raises:
+///     ValueError: If a relation is on another universe or A^len(relation) is too large
+#[pyfunction]
+#[pyo3(signature = (relation, base, progress=None))]
+fn pp_counterexample(
+    py: Python<'_>,
+    relation: &PyRelation,
+    base: Vec<PyRelation>,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Option<PyIntOperation>> {
+    let base: Vec<Relation> = base.into_iter().map(|r| r.inner).collect();
+    let op = with_progress(py, progress, || {
+        clone::pp_counterexample(&relation.inner, &base).map_err(PyValueError::new_err)
+    })?;
+    op.map(|op| to_py_operation(op.as_ref())).transpose()
+}
+
+/// A clone on {0, 1}, as it appears in Post's lattice, named as in Böhler
+/// et al. (BF, R0, M2, S0^3, L, V2, I2, ...).
+#[pyclass(module = "uacalc_lib.alg")]
+#[derive(Clone)]
+pub struct PyBooleanClone {
+    inner: BooleanClone,
+}
+
+#[pymethods]
+impl PyBooleanClone {
+    /// The standard name of the clone.
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    /// A finite set of operations generating the clone.
+    ///
+    /// Returns:
+    ///     List[IntOperation]: The basis
+    fn basis(&self) -> PyResult<Vec<PyIntOperation>> {
+        self.inner.basis().iter().map(|op| to_py_operation(op.as_ref())).collect()
+    }
+
+    /// Check whether an operation belongs to the clone.
+    ///
+    /// Args:
+    ///     op (IntOperation): An operation on {0, 1}
+    ///
+    /// Returns:
+    ///     bool: Whether the operation belongs to the clone
+    ///
+    /// Raises:
+    ///     ValueError: If the operation is not on two elements
+    fn contains(&self, op: &PyIntOperation) -> PyResult<bool> {
+        self.inner.contains(&op.inner).map_err(PyValueError::new_err)
+    }
+
+    /// Whether this clone is contained in another.
+    ///
+    /// Args:
+    ///     other (BooleanClone): The other clone
+    ///
+    /// Returns:
+    ///     bool: Whether this clone is a subclone of other
+    fn is_subclone_of(&self, other: &PyBooleanClone) -> PyResult<bool> {
+        self.inner.is_subclone_of(&other.inner).map_err(PyValueError::new_err)
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("BooleanClone({})", self.inner.name())
+    }
+}
+
+/// The clones of Post's lattice, the lattice of all clones on {0, 1}.
+///
+/// The infinite chains S0^k, S1^k, ... are listed for k from 2 to
+/// max_degree, followed by their intersections S0, S1, ...
+///
+/// Args:
+///     max_degree (int, optional): The largest degree k of the chains (default 3)
+///
+/// Returns:
+///     List[BooleanClone]: The clones from the top BF down
+#[pyfunction]
+#[pyo3(signature = (max_degree=3))]
+fn post_lattice(max_degree: usize) -> Vec<PyBooleanClone> {
+    clone::post_lattice(max_degree).into_iter().map(|inner| PyBooleanClone { inner }).collect()
+}
+
+/// The standard name of the clone on {0, 1} generated by some operations.
+///
+/// Args:
+///     operations (List[Operation]): Operations on {0, 1}
+///
+/// Returns:
+///     str: The name of the generated clone in Post's lattice
+///
+/// Raises:
+///     ValueError: If an operation is not on two elements
+#[pyfunction]
+fn classify_boolean_clone(operations: &Bound<'_, PyList>) -> PyResult<String> {
+    let ops = extract_operations(operations)?;
+    clone::classify_boolean_clone(&ops).map_err(PyValueError::new_err)
+}
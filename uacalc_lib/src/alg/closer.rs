@@ -1,9 +1,12 @@
 use pyo3::prelude::*;
 use pyo3::exceptions::PyValueError;
 use uacalc::alg::Closer;
+use uacalc::progress::ComputationError;
 use uacalc::util::int_array::IntArray;
 use crate::alg::big_product_algebra::PyBigProductAlgebra;
 use crate::util::PyIntArray;
+use crate::progress::{with_progress_partial, PyProgressReporter};
+use std::sync::Arc;
 
 /// Python wrapper for Closer
 #[pyclass]
@@ -57,15 +60,20 @@ impl PyCloser {
 
     /// Compute the closure of the generators.
     ///
+    /// # Arguments
+    /// * `progress` - Optional ProgressReporter for progress and cancellation
+    ///
     /// # Returns
     /// List of IntArray elements in the closure
-    fn sg_close(&mut self) -> PyResult<Vec<PyIntArray>> {
-        match self.inner.sg_close() {
+    #[pyo3(signature = (progress=None))]
+    fn sg_close(&mut self, py: Python<'_>, progress: Option<&PyProgressReporter>) -> PyResult<Vec<PyIntArray>> {
+        self.set_reporter(progress);
+        with_progress_partial(py, progress, PyValueError::new_err, || match self.inner.sg_close() {
             Ok(result) => Ok(result.iter()
                 .map(|ia| PyIntArray { inner: ia.clone() })
                 .collect()),
-            Err(e) => Err(PyValueError::new_err(e)),
-        }
+            Err(e) => Err(ComputationError::new(e, || self.get_answer())),
+        })
     }
 
     /// Compute the closure using the specialized power algebra algorithm.
@@ -73,15 +81,20 @@ impl PyCloser {
     /// This method matches Java's `sgClosePower()` public method and uses
     /// the optimized power algebra closure algorithm.
     ///
+    /// # Arguments
+    /// * `progress` - Optional ProgressReporter for progress and cancellation
+    ///
     /// # Returns
     /// List of IntArray elements in the closure
-    fn sg_close_power(&mut self) -> PyResult<Vec<PyIntArray>> {
-        match self.inner.sg_close_power() {
+    #[pyo3(signature = (progress=None))]
+    fn sg_close_power(&mut self, py: Python<'_>, progress: Option<&PyProgressReporter>) -> PyResult<Vec<PyIntArray>> {
+        self.set_reporter(progress);
+        with_progress_partial(py, progress, PyValueError::new_err, || match self.inner.sg_close_power() {
             Ok(result) => Ok(result.iter()
                 .map(|ia| PyIntArray { inner: ia.clone() })
                 .collect()),
-            Err(e) => Err(PyValueError::new_err(e)),
-        }
+            Err(e) => Err(ComputationError::new(e, || self.get_answer())),
+        })
     }
 
     /// Set whether to suppress output.
@@ -173,8 +186,11 @@ pub fn register_closer(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     Ok(())
 }
 
-
-
-
-
-
+impl PyCloser {
+    /// Send the closer's progress lines to the reporter, if one is given.
+    fn set_reporter(&mut self, progress: Option<&PyProgressReporter>) {
+        if let Some(p) = progress {
+            self.inner.set_progress_report(Some(Arc::new(p.token.clone())));
+        }
+    }
+}
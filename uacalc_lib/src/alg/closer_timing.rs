@@ -80,6 +80,54 @@ impl PyCloserTiming {
         self.inner.get_arities().to_vec()
     }
 
+    /// Get the size of the current pass.
+    ///
+    /// # Returns
+    /// The number of elements at the start of the current pass
+    fn get_curr_pass_size(&self) -> u32 {
+        self.inner.get_curr_pass_size()
+    }
+
+    /// Get the size of the previous pass.
+    ///
+    /// # Returns
+    /// The number of elements at the start of the previous pass
+    fn get_last_pass_size(&self) -> u32 {
+        self.inner.get_last_pass_size()
+    }
+
+    /// Get the number of elements found so far for the next pass.
+    ///
+    /// # Returns
+    /// The next pass size counter
+    fn get_next_pass_size(&self) -> u32 {
+        self.inner.get_next_pass_size()
+    }
+
+    /// Get the number of coordinate applications the current pass needs.
+    ///
+    /// # Returns
+    /// The applications needed
+    fn get_apps_needed(&self) -> u64 {
+        self.inner.get_apps_needed()
+    }
+
+    /// Get the number of coordinate applications made in the current pass.
+    ///
+    /// # Returns
+    /// The applications made so far
+    fn get_apps_this_pass(&self) -> u64 {
+        self.inner.get_apps_this_pass()
+    }
+
+    /// Get the measured time per application.
+    ///
+    /// # Returns
+    /// Milliseconds per application, 0 until measured
+    fn get_ms_per_app(&self) -> f64 {
+        self.inner.get_ms_per_app()
+    }
+
     /// Convert milliseconds to a formatted time string.
     ///
     /// # Arguments
@@ -0,0 +1,272 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use uacalc::alg::congruence_regularity::{self, CongruenceRegularityReport, CongruenceWitness};
+use crate::alg::conlat::partition::PyPartition;
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register the congruence regularity, uniformity and coherence checks.
+pub fn register_congruence_regularity_functions(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(check_congruence_regular, m)?)?;
+    m.add_function(wrap_pyfunction!(check_congruence_uniform, m)?)?;
+    m.add_function(wrap_pyfunction!(check_congruence_coherent, m)?)?;
+    m.add_function(wrap_pyfunction!(check_variety_congruence_regular, m)?)?;
+    m.add_function(wrap_pyfunction!(check_variety_congruence_uniform, m)?)?;
+    m.add_function(wrap_pyfunction!(check_variety_congruence_coherent, m)?)?;
+    m.add_function(wrap_pyfunction!(is_congruence_regular, m)?)?;
+    m.add_function(wrap_pyfunction!(is_congruence_uniform, m)?)?;
+    m.add_function(wrap_pyfunction!(is_congruence_coherent, m)?)?;
+    m.add_function(wrap_pyfunction!(variety_is_congruence_regular, m)?)?;
+    m.add_function(wrap_pyfunction!(variety_is_congruence_coherent, m)?)?;
+    m.add_class::<PyCongruenceRegularityReport>()?;
+    m.add("CongruenceRegularityReport", m.getattr("PyCongruenceRegularityReport")?)?;
+    Ok(())
+}
+
+/// The outcome of a regularity, uniformity or coherence check with its
+/// counterexample on failure.
+///
+/// Truthy exactly when the property is known to hold.
+#[pyclass(module = "uacalc_lib.alg")]
+#[derive(Clone)]
+pub struct PyCongruenceRegularityReport {
+    inner: CongruenceRegularityReport,
+}
+
+#[pymethods]
+impl PyCongruenceRegularityReport {
+    /// The property checked: "regular", "uniform" or "coherent".
+    #[getter]
+    fn property_name(&self) -> &'static str {
+        self.inner.property
+    }
+
+    /// Whether the property holds, or None when a variety check could
+    /// neither prove nor refute it.
+    #[getter]
+    fn verdict(&self) -> Option<bool> {
+        self.inner.holds
+    }
+
+    /// The kind of counterexample: "shared block", "non-uniform" or
+    /// "not a union of blocks", or None.
+    #[getter]
+    fn witness_kind(&self) -> Option<&'static str> {
+        self.inner.witness.as_ref().map(|w| match w {
+            CongruenceWitness::SharedBlock { .. } => "shared block",
+            CongruenceWitness::NonUniform { .. } => "non-uniform",
+            CongruenceWitness::NotUnionOfBlocks { .. } => "not a union of blocks",
+        })
+    }
+
+    /// The congruences of the counterexample: two sharing a block, or one.
+    #[getter]
+    fn congruences(&self) -> Vec<PyPartition> {
+        self.inner.witness.as_ref()
+            .map(|w| w.congruences().into_iter().cloned().map(PyPartition::from_inner).collect())
+            .unwrap_or_default()
+    }
+
+    /// The shared block, or the block contained in the subuniverse, or None.
+    #[getter]
+    fn block(&self) -> Option<Vec<usize>> {
+        match self.inner.witness.as_ref()? {
+            CongruenceWitness::SharedBlock { block, .. } | CongruenceWitness::NotUnionOfBlocks { block, .. } => Some(block.clone()),
+            CongruenceWitness::NonUniform { .. } => None,
+        }
+    }
+
+    /// The subuniverse that is not a union of blocks, or None.
+    #[getter]
+    fn subuniverse(&self) -> Option<Vec<i32>> {
+        match self.inner.witness.as_ref()? {
+            CongruenceWitness::NotUnionOfBlocks { subuniverse, .. } => Some(subuniverse.elements().clone()),
+            _ => None,
+        }
+    }
+
+    /// The indices of x, y and z when the counterexample lives in the free
+    /// algebra F_V(3), or None when it lives in the algebra itself.
+    #[getter]
+    fn free_generators(&self) -> Option<[usize; 3]> {
+        self.inner.free_generators
+    }
+
+    /// Whether the property is known to hold.
+    fn holds(&self) -> bool {
+        self.inner.holds()
+    }
+
+    fn __bool__(&self) -> bool {
+        self.inner.holds()
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("CongruenceRegularityReport({}: {})", self.inner.property, self.inner)
+    }
+}
+
+fn run(
+    py: Python<'_>,
+    algebra: &Bound<'_, PyAny>,
+    progress: Option<&PyProgressReporter>,
+    check: fn(&dyn uacalc::alg::SmallAlgebra<UniverseItem = i32>) -> Result<CongruenceRegularityReport, String>,
+) -> PyResult<PyCongruenceRegularityReport> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    let inner = with_progress(py, progress, || check(alg.as_ref()).map_err(PyValueError::new_err))?;
+    Ok(PyCongruenceRegularityReport { inner })
+}
+
+/// Check whether an algebra is congruence regular: no two different
+/// congruences share a block. The witness is such a pair and their block.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     CongruenceRegularityReport: The verdict and its witness
+///
+/// Raises:
+///     ValueError: If the computation was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn check_congruence_regular(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<PyCongruenceRegularityReport> {
+    run(py, algebra, progress, congruence_regularity::check_congruence_regular)
+}
+
+/// Check whether an algebra is congruence uniform: the blocks of each
+/// congruence have the same size. The witness is a congruence that is not.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     CongruenceRegularityReport: The verdict and its witness
+///
+/// Raises:
+///     ValueError: If the computation was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn check_congruence_uniform(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<PyCongruenceRegularityReport> {
+    run(py, algebra, progress, congruence_regularity::check_congruence_uniform)
+}
+
+/// Check whether an algebra is congruence coherent: a subuniverse containing
+/// a block of a congruence is a union of its blocks. The witness is a
+/// congruence, a block and a subuniverse containing it that is not.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     CongruenceRegularityReport: The verdict and its witness
+///
+/// Raises:
+///     ValueError: If an operation cannot be evaluated or the computation
+///         was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn check_congruence_coherent(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<PyCongruenceRegularityReport> {
+    run(py, algebra, progress, congruence_regularity::check_congruence_coherent)
+}
+
+/// Check whether the variety generated by an algebra is congruence regular,
+/// by Csákány's condition in the free algebra F_V(3) when the algebra itself
+/// has no counterexample.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     CongruenceRegularityReport: The verdict and its witness
+///
+/// Raises:
+///     ValueError: If the free algebra cannot be built or the computation
+///         was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn check_variety_congruence_regular(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<PyCongruenceRegularityReport> {
+    run(py, algebra, progress, congruence_regularity::check_variety_congruence_regular)
+}
+
+/// Look for a counterexample to congruence uniformity of the variety
+/// generated by an algebra. Uniformity has no Malcev characterization, so
+/// the verdict is False with a witness, or None.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     CongruenceRegularityReport: The verdict and its witness
+///
+/// Raises:
+///     ValueError: If the free algebra cannot be built or the computation
+///         was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn check_variety_congruence_uniform(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<PyCongruenceRegularityReport> {
+    run(py, algebra, progress, congruence_regularity::check_variety_congruence_uniform)
+}
+
+/// Check whether the variety generated by an algebra is congruence coherent,
+/// by Geiger's condition in the free algebra F_V(3) when the algebra itself
+/// has no counterexample.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     CongruenceRegularityReport: The verdict and its witness
+///
+/// Raises:
+///     ValueError: If the free algebra cannot be built, an operation cannot
+///         be evaluated or the computation was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn check_variety_congruence_coherent(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<PyCongruenceRegularityReport> {
+    run(py, algebra, progress, congruence_regularity::check_variety_congruence_coherent)
+}
+
+/// Test whether an algebra is congruence regular.
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn is_congruence_regular(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+    Ok(check_congruence_regular(py, algebra, progress)?.inner.holds())
+}
+
+/// Test whether an algebra is congruence uniform.
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn is_congruence_uniform(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+    Ok(check_congruence_uniform(py, algebra, progress)?.inner.holds())
+}
+
+/// Test whether an algebra is congruence coherent.
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn is_congruence_coherent(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+    Ok(check_congruence_coherent(py, algebra, progress)?.inner.holds())
+}
+
+/// Test whether the variety generated by an algebra is congruence regular.
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn variety_is_congruence_regular(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+    Ok(check_variety_congruence_regular(py, algebra, progress)?.inner.holds())
+}
+
+/// Test whether the variety generated by an algebra is congruence coherent.
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn variety_is_congruence_coherent(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+    Ok(check_variety_congruence_coherent(py, algebra, progress)?.inner.holds())
+}
@@ -4,7 +4,7 @@ use uacalc::alg::conlat::{BasicBinaryRelation, BinaryRelation, MutableBinaryRela
 use uacalc::util::IntArrayTrait;
 
 /// Python wrapper for BasicBinaryRelation
-#[pyclass]
+#[pyclass(module = "uacalc_lib.alg")]
 pub struct PyBasicBinaryRelation {
     pub(crate) inner: BasicBinaryRelation,
 }
@@ -224,6 +224,30 @@ impl PyBasicBinaryRelation {
         }
     }
 
+    /// Pickle support: the universe size, with the pairs as the state.
+    fn __reduce__(slf: &Bound<'_, Self>) -> (PyObject, (usize,), Vec<Vec<i32>>) {
+        let relation = slf.borrow();
+        (slf.get_type().into_any().unbind(), (relation.universe_size(),), relation.get_pairs())
+    }
+
+    /// Replace the pairs of the relation, for unpickling.
+    ///
+    /// Args:
+    ///     pairs (List[List[int]]): The pairs as [i, j] lists
+    ///
+    /// Raises:
+    ///     ValueError: If a pair is not of two elements of the universe
+    fn __setstate__(&mut self, pairs: Vec<Vec<usize>>) -> PyResult<()> {
+        self.inner.clear();
+        for pair in pairs {
+            match pair.as_slice() {
+                &[i, j] => self.add(i, j)?,
+                _ => return Err(PyValueError::new_err(format!("Not a pair: {:?}", pair))),
+            }
+        }
+        Ok(())
+    }
+
     /// Python string representation
     fn __str__(&self) -> String {
         self.inner.to_string()
@@ -249,6 +273,26 @@ impl PyBasicBinaryRelation {
         hasher.finish()
     }
 
+    /// Python comparison (less than): by universe size, then by the sorted pairs.
+    fn __lt__(&self, other: &PyBasicBinaryRelation) -> bool {
+        self.inner < other.inner
+    }
+
+    /// Python comparison (less than or equal).
+    fn __le__(&self, other: &PyBasicBinaryRelation) -> bool {
+        self.inner <= other.inner
+    }
+
+    /// Python comparison (greater than).
+    fn __gt__(&self, other: &PyBasicBinaryRelation) -> bool {
+        self.inner > other.inner
+    }
+
+    /// Python comparison (greater than or equal).
+    fn __ge__(&self, other: &PyBasicBinaryRelation) -> bool {
+        self.inner >= other.inner
+    }
+
     /// Python iterator support
     fn __iter__(&self) -> PyResult<PyObject> {
         let pairs = self.get_pairs();
@@ -1,48 +1,82 @@
 use pyo3::prelude::*;
-use pyo3::exceptions::PyRuntimeError;
+use pyo3::exceptions::{PyIOError, PyRuntimeError, PyValueError};
 
 use crate::alg::basic_algebra::PyBasicAlgebra;
 use crate::alg::conlat::partition::PyPartition;
 use crate::alg::conlat::basic_binary_relation::PyBasicBinaryRelation;
 use crate::util::PyIntArray;
+use crate::progress::{with_progress, with_progress_partial, PyProgressReporter};
+use uacalc::io::json::{FromJson, ToJson};
 
 /// Python wrapper for CongruenceLattice
-#[pyclass]
+#[pyclass(module = "uacalc_lib.alg")]
 pub struct PyCongruenceLattice {
     pub(crate) inner: uacalc::alg::conlat::CongruenceLattice<i32>,
 }
 
 // Rust-visible constructor for internal use (separate from the #[new] Python ctor)
 impl PyCongruenceLattice {
-    pub fn from_algebra(algebra: &PyBasicAlgebra) -> Self {
+    /// The orders of two congruence lattices, borrowing one at a time so
+    /// that `other` may be the same object.
+    fn posets(
+        slf: &Bound<'_, Self>,
+        other: &Bound<'_, Self>,
+        progress: Option<&PyProgressReporter>,
+    ) -> PyResult<(uacalc::lat::Poset, uacalc::lat::Poset)> {
+        let poset = |lat: &Bound<'_, Self>| {
+            let mut lat = lat.borrow_mut();
+            let inner = &mut lat.inner;
+            with_progress(slf.py(), progress, || inner.to_poset().map_err(PyRuntimeError::new_err))
+        };
+        Ok((poset(slf)?, poset(other)?))
+    }
+
+
+    pub fn from_algebra(py: Python<'_>, algebra: &PyBasicAlgebra) -> Self {
         use uacalc::alg::SmallAlgebraWrapper;
-        let mut con_lat = uacalc::alg::conlat::CongruenceLattice::new(
-            Box::new(SmallAlgebraWrapper::new(Box::new(algebra.inner.clone())))
-        );
-        // Ensure principals are computed when the lattice is created
-        // This ensures the lattice is "built" and ready to use
-        con_lat.make_principals();
+        let alg = algebra.inner.clone();
+        // Building the principals is the expensive part; let other Python threads run.
+        let con_lat = py.allow_threads(move || {
+            let mut con_lat = uacalc::alg::conlat::CongruenceLattice::new(
+                Box::new(SmallAlgebraWrapper::new(Box::new(alg)))
+            );
+            // Ensure principals are computed when the lattice is created
+            // This ensures the lattice is "built" and ready to use
+            con_lat.make_principals();
+            con_lat
+        });
         PyCongruenceLattice {
             inner: con_lat,
         }
     }
 }
 
+/// Wrap the partial universe of a stopped build for Python.
+fn to_py_partitions(univ: Vec<uacalc::alg::conlat::Partition>) -> Vec<PyPartition> {
+    univ.into_iter().map(|inner| PyPartition { inner }).collect()
+}
+
 #[pymethods]
 impl PyCongruenceLattice {
     /// Create a new congruence lattice for an algebra.
+    ///
+    /// Args:
+    ///     algebra (BasicAlgebra): The algebra
+    ///     progress (ProgressReporter, optional): Progress and cancellation
+    ///         while the principal congruences are computed
     #[new]
-    fn new(algebra: &PyBasicAlgebra) -> Self {
+    #[pyo3(signature = (algebra, progress=None))]
+    fn new(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<Self> {
         use uacalc::alg::SmallAlgebraWrapper;
-        let mut con_lat = uacalc::alg::conlat::CongruenceLattice::new(
-            Box::new(SmallAlgebraWrapper::new(Box::new(algebra.inner.clone())))
-        );
-        // Ensure principals are computed when the lattice is created
-        // This ensures the lattice is "built" and ready to use
-        con_lat.make_principals();
-        PyCongruenceLattice {
-            inner: con_lat,
-        }
+        let alg = algebra.inner.clone();
+        let inner = with_progress(py, progress, move || {
+            let mut con_lat = uacalc::alg::conlat::CongruenceLattice::new(
+                Box::new(SmallAlgebraWrapper::new(Box::new(alg)))
+            );
+            con_lat.try_make_principals().map_err(PyRuntimeError::new_err)?;
+            Ok(con_lat)
+        })?;
+        Ok(PyCongruenceLattice { inner })
     }
 
     fn alg_size(&self) -> usize { self.inner.alg_size() }
@@ -51,9 +85,135 @@ impl PyCongruenceLattice {
 
     fn one(&self) -> PyPartition { PyPartition { inner: self.inner.one() } }
 
-    fn con_cardinality(&mut self) -> usize { self.inner.con_cardinality() }
+    /// Get the number of congruences, building the universe if needed.
+    ///
+    /// Args:
+    ///     progress (ProgressReporter, optional): Progress and cancellation;
+    ///         a cancelled build keeps its partial universe and resumes later
+    #[pyo3(signature = (progress=None))]
+    fn con_cardinality(&mut self, py: Python<'_>, progress: Option<&PyProgressReporter>) -> PyResult<usize> {
+        with_progress_partial(py, progress, PyRuntimeError::new_err, || {
+            self.inner.compute_universe().map(|univ| univ.len()).map_err(|e| e.map_partial(to_py_partitions))
+        })
+    }
+
+    fn is_distributive(&mut self, py: Python<'_>) -> bool { py.allow_threads(|| self.inner.is_distributive()) }
+
+    /// Name the lattice if it is a standard small lattice.
+    ///
+    /// Returns:
+    ///     str: "C<n>" for a chain, "M<n>", "N5", "2^<k>" for a Boolean
+    ///         lattice, or "unknown"
+    fn identify_lattice(&mut self, py: Python<'_>) -> String { py.allow_threads(|| self.inner.identify_lattice()) }
+
+    /// Test whether the congruence lattice is modular.
+    ///
+    /// Builds the universe if needed.
+    fn is_modular(&mut self, py: Python<'_>) -> bool {
+        py.allow_threads(|| {
+            self.inner.universe();
+            uacalc::lat::Lattice::is_modular(&self.inner)
+        })
+    }
+
+    /// Test whether the congruence lattice is upper semimodular: a covers a ∧ b implies a ∨ b covers b.
+    ///
+    /// Builds the universe if needed.
+    fn is_upper_semimodular(&mut self, py: Python<'_>) -> bool {
+        py.allow_threads(|| {
+            self.inner.universe();
+            uacalc::lat::Lattice::is_upper_semimodular(&self.inner)
+        })
+    }
+
+    /// Test whether the congruence lattice is lower semimodular (the dual of upper semimodular).
+    ///
+    /// Builds the universe if needed.
+    fn is_lower_semimodular(&mut self, py: Python<'_>) -> bool {
+        py.allow_threads(|| {
+            self.inner.universe();
+            uacalc::lat::Lattice::is_lower_semimodular(&self.inner)
+        })
+    }
+
+    /// Test whether the congruence lattice is lower bounded, i.e. the join dependency relation
+    /// on the join irreducibles has no cycle.
+    ///
+    /// Builds the universe if needed.
+    fn is_lower_bounded_lattice(&mut self, py: Python<'_>) -> bool {
+        py.allow_threads(|| {
+            self.inner.universe();
+            uacalc::lat::Lattice::is_lower_bounded_lattice(&self.inner)
+        })
+    }
+
+    /// Test whether the congruence lattice is relatively complemented: every interval is complemented.
+    ///
+    /// Builds the universe if needed.
+    fn is_relatively_complemented(&mut self, py: Python<'_>) -> bool {
+        py.allow_threads(|| {
+            self.inner.universe();
+            uacalc::lat::Lattice::is_relatively_complemented(&self.inner)
+        })
+    }
+
+    /// Test whether the congruence lattice is geometric: atomistic and upper semimodular.
+    ///
+    /// Builds the universe if needed.
+    fn is_geometric(&mut self, py: Python<'_>) -> bool {
+        py.allow_threads(|| {
+            self.inner.universe();
+            uacalc::lat::Lattice::is_geometric(&self.inner)
+        })
+    }
+
+    /// Test whether the congruence lattice is meet semidistributive:
+    /// a ∧ b = a ∧ c implies a ∧ b = a ∧ (b ∨ c).
+    ///
+    /// Builds the universe if needed.
+    fn is_meet_semidistributive(&mut self, py: Python<'_>) -> bool {
+        py.allow_threads(|| {
+            self.inner.universe();
+            uacalc::lat::Lattice::is_meet_semidistributive(&self.inner)
+        })
+    }
+
+    /// Test whether the congruence lattice is join semidistributive:
+    /// a ∨ b = a ∨ c implies a ∨ b = a ∨ (b ∧ c).
+    ///
+    /// Builds the universe if needed.
+    fn is_join_semidistributive(&mut self, py: Python<'_>) -> bool {
+        py.allow_threads(|| {
+            self.inner.universe();
+            uacalc::lat::Lattice::is_join_semidistributive(&self.inner)
+        })
+    }
+
+    /// Find congruences (a, b, c) with a ∧ b = a ∧ c but a ∧ (b ∨ c) ≠ a ∧ b.
+    ///
+    /// Returns:
+    ///     Optional[Tuple[Partition, Partition, Partition]]: The witness, or
+    ///         None if the lattice is meet semidistributive
+    fn meet_semidistributivity_failure(&mut self, py: Python<'_>) -> Option<(PyPartition, PyPartition, PyPartition)> {
+        py.allow_threads(|| {
+            self.inner.universe();
+            uacalc::lat::Lattice::meet_semidistributivity_failure(&self.inner)
+        })
+        .map(|(a, b, c)| (PyPartition { inner: a }, PyPartition { inner: b }, PyPartition { inner: c }))
+    }
 
-    fn is_distributive(&mut self) -> bool { self.inner.is_distributive() }
+    /// Find congruences (a, b, c) with a ∨ b = a ∨ c but a ∨ (b ∧ c) ≠ a ∨ b.
+    ///
+    /// Returns:
+    ///     Optional[Tuple[Partition, Partition, Partition]]: The witness, or
+    ///         None if the lattice is join semidistributive
+    fn join_semidistributivity_failure(&mut self, py: Python<'_>) -> Option<(PyPartition, PyPartition, PyPartition)> {
+        py.allow_threads(|| {
+            self.inner.universe();
+            uacalc::lat::Lattice::join_semidistributivity_failure(&self.inner)
+        })
+        .map(|(a, b, c)| (PyPartition { inner: a }, PyPartition { inner: b }, PyPartition { inner: c }))
+    }
 
     fn get_description(&self) -> String { self.inner.get_description() }
 
@@ -61,6 +221,86 @@ impl PyCongruenceLattice {
 
     fn __repr__(&self) -> String { format!("CongruenceLattice({})", self.inner.to_string()) }
 
+    /// Check whether this congruence lattice has a 0-1 lattice embedding
+    /// into another.
+    ///
+    /// Args:
+    ///     other (CongruenceLattice): The congruence lattice to embed into
+    ///     progress (ProgressReporter, optional): Progress and cancellation
+    ///
+    /// Returns:
+    ///     bool: Whether there is an embedding
+    #[pyo3(signature = (other, progress=None))]
+    fn embeds_in(slf: &Bound<'_, Self>, other: &Bound<'_, Self>, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+        let (a, b) = Self::posets(slf, other, progress)?;
+        with_progress(slf.py(), progress, || {
+            a.lattice_embedding(&b).map(|f| f.is_some()).map_err(PyRuntimeError::new_err)
+        })
+    }
+
+    /// Check whether this congruence lattice is isomorphic to the dual of
+    /// another.
+    ///
+    /// Args:
+    ///     other (CongruenceLattice): The other congruence lattice
+    ///     progress (ProgressReporter, optional): Progress and cancellation
+    ///
+    /// Returns:
+    ///     bool: Whether the lattices are dually isomorphic
+    #[pyo3(signature = (other, progress=None))]
+    fn is_dual_to(slf: &Bound<'_, Self>, other: &Bound<'_, Self>, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+        let (a, b) = Self::posets(slf, other, progress)?;
+        with_progress(slf.py(), progress, || a.is_dual_to(&b).map_err(PyRuntimeError::new_err))
+    }
+
+    /// All lattice isomorphisms from this congruence lattice onto another.
+    ///
+    /// Args:
+    ///     other (CongruenceLattice): The other congruence lattice
+    ///     progress (ProgressReporter, optional): Progress and cancellation
+    ///
+    /// Returns:
+    ///     List[List[int]]: The isomorphisms; f[i] is the index in
+    ///         other.universe() of the image of self.universe()[i]
+    #[pyo3(signature = (other, progress=None))]
+    fn all_isomorphisms(slf: &Bound<'_, Self>, other: &Bound<'_, Self>, progress: Option<&PyProgressReporter>) -> PyResult<Vec<Vec<usize>>> {
+        let (a, b) = Self::posets(slf, other, progress)?;
+        with_progress(slf.py(), progress, || a.lattice_isomorphisms(&b).map_err(PyRuntimeError::new_err))
+    }
+
+    /// The Hasse diagram of the lattice as a TikZ picture.
+    ///
+    /// Congruences are labelled by their blocks in bar notation.
+    ///
+    /// Args:
+    ///     progress (ProgressReporter, optional): Progress and cancellation
+    ///         while the universe is built
+    ///
+    /// Returns:
+    ///     str: A LaTeX `tikzpicture` environment
+    #[pyo3(name = "to_latex", signature = (progress=None))]
+    fn render_latex(&mut self, py: Python<'_>, progress: Option<&PyProgressReporter>) -> PyResult<String> {
+        with_progress(py, progress, || {
+            uacalc::io::report::congruence_lattice_latex(&mut self.inner).map_err(PyRuntimeError::new_err)
+        })
+    }
+
+    /// Pickle support: the state is the JSON representation of the algebra
+    /// together with a checkpoint of the congruences computed so far.
+    fn __getstate__(&self) -> String {
+        self.inner.to_json_str()
+    }
+
+    fn __setstate__(&mut self, state: &str) -> PyResult<()> {
+        self.inner = uacalc::alg::conlat::CongruenceLattice::<i32>::from_json_str(state).map_err(PyValueError::new_err)?;
+        Ok(())
+    }
+
+    fn __getnewargs__(&self) -> (PyBasicAlgebra,) {
+        let trivial = uacalc::alg::BasicAlgebra::new(String::new(), [0].into_iter().collect(), Vec::new());
+        (PyBasicAlgebra::from_inner(trivial),)
+    }
+
     fn tg(&mut self, a: usize, b: usize) -> PyResult<PyBasicBinaryRelation> {
         match self.inner.tg(a, b) {
             Ok(relation) => Ok(PyBasicBinaryRelation { inner: relation }),
@@ -84,15 +324,29 @@ impl PyCongruenceLattice {
         self.inner.find_meet_irred(&a.inner, &b.inner).map(|p| PyPartition { inner: p })
     }
 
-    fn join_irreducibles(&mut self) -> Vec<PyPartition> {
+    /// Whether a congruence is join irreducible.
+    fn join_irreducible(&mut self, partition: &PyPartition) -> bool {
+        self.inner.join_irreducible(&partition.inner)
+    }
+
+    /// The unique lower cover of a join irreducible congruence, or None.
+    fn lower_star(&mut self, partition: &PyPartition) -> Option<PyPartition> {
+        self.inner.lower_star(&partition.inner).map(|p| PyPartition { inner: p })
+    }
+
+    #[pyo3(signature = (progress=None))]
+    fn join_irreducibles(&mut self, py: Python<'_>, progress: Option<&PyProgressReporter>) -> PyResult<Vec<PyPartition>> {
         use uacalc::alg::conlat::CongruenceLattice;
-        let jis: &Vec<uacalc::alg::conlat::partition::Partition> = CongruenceLattice::join_irreducibles(&mut self.inner);
-        jis.iter().map(|p| PyPartition { inner: p.clone() }).collect()
+        with_progress(py, progress, || {
+            self.inner.try_make_join_irreducibles().map_err(PyRuntimeError::new_err)?;
+            let jis: &Vec<uacalc::alg::conlat::partition::Partition> = CongruenceLattice::join_irreducibles(&mut self.inner);
+            Ok(jis.iter().map(|p| PyPartition { inner: p.clone() }).collect())
+        })
     }
 
-    fn principals(&mut self) -> Vec<PyPartition> {
+    fn principals(&mut self, py: Python<'_>) -> Vec<PyPartition> {
         use uacalc::alg::conlat::CongruenceLattice;
-        let principals: &Vec<uacalc::alg::conlat::partition::Partition> = CongruenceLattice::principals(&mut self.inner);
+        let principals: &Vec<uacalc::alg::conlat::partition::Partition> = py.allow_threads(|| CongruenceLattice::principals(&mut self.inner));
         principals.iter().map(|p| PyPartition { inner: p.clone() }).collect()
     }
 
@@ -102,9 +356,9 @@ impl PyCongruenceLattice {
         atoms.iter().map(|p| PyPartition { inner: p.clone() }).collect()
     }
 
-    fn meet_irreducibles(&mut self) -> Vec<PyPartition> {
+    fn meet_irreducibles(&mut self, py: Python<'_>) -> Vec<PyPartition> {
         use uacalc::alg::conlat::CongruenceLattice;
-        let mis: &Vec<uacalc::alg::conlat::partition::Partition> = CongruenceLattice::meet_irreducibles(&mut self.inner);
+        let mis: &Vec<uacalc::alg::conlat::partition::Partition> = py.allow_threads(|| CongruenceLattice::meet_irreducibles(&mut self.inner));
         mis.iter().map(|p| PyPartition { inner: p.clone() }).collect()
     }
 
@@ -128,13 +382,81 @@ impl PyCongruenceLattice {
         }
     }
 
-    fn universe(&mut self) -> Vec<PyPartition> {
-        use uacalc::alg::conlat::CongruenceLattice;
-        let univ: &Vec<uacalc::alg::conlat::partition::Partition> = CongruenceLattice::universe(&mut self.inner);
-        univ.iter().map(|p| PyPartition { inner: p.clone() }).collect()
+    #[pyo3(signature = (progress=None))]
+    fn universe(&mut self, py: Python<'_>, progress: Option<&PyProgressReporter>) -> PyResult<Vec<PyPartition>> {
+        with_progress_partial(py, progress, PyRuntimeError::new_err, || {
+            let univ = self.inner.compute_universe().map_err(|e| e.map_partial(to_py_partitions))?;
+            Ok(univ.iter().map(|p| PyPartition { inner: p.clone() }).collect())
+        })
+    }
+
+    /// Get the congruences found by an interrupted universe build.
+    ///
+    /// Returns:
+    ///     Optional[List[Partition]]: The congruences found so far, or None
+    fn partial_universe(&self) -> Option<Vec<PyPartition>> {
+        self.inner.partial_universe().map(|univ| univ.iter().map(|p| PyPartition { inner: p.clone() }).collect())
+    }
+
+    /// Iterate over the congruences, generating them lazily.
+    ///
+    /// The congruences come in the order they are found: zero, the join
+    /// irreducibles, then their joins, so a scan can stop early without the
+    /// whole universe being built. This is not the order of `universe()`;
+    /// iterating over the lattice itself gives that order.
+    ///
+    /// Args:
+    ///     progress (ProgressReporter, optional): Progress and cancellation
+    ///         while the join irreducibles are computed
+    ///
+    /// Returns:
+    ///     Congruences: An iterator of Partition
+    #[pyo3(signature = (progress=None))]
+    fn congruences_iter(&mut self, py: Python<'_>, progress: Option<&PyProgressReporter>) -> PyResult<PyCongruences> {
+        let inner = with_progress(py, progress, || self.inner.congruences_iter().map_err(PyRuntimeError::new_err))?;
+        Ok(PyCongruences { inner })
+    }
+
+    /// Iterate over the congruences in the order of `universe()`.
+    fn __iter__(&mut self, py: Python<'_>) -> PyResult<PyCongruences> {
+        let inner = with_progress(py, None, || self.inner.universe_iter().map_err(PyRuntimeError::new_err))?;
+        Ok(PyCongruences { inner })
+    }
+
+    fn __len__(&mut self, py: Python<'_>) -> PyResult<usize> {
+        self.con_cardinality(py, None)
+    }
+
+    /// Save the state of the universe build to a JSON file.
+    ///
+    /// An interrupted build can be resumed later, in another process, with
+    /// `resume_from` on the congruence lattice of the same algebra.
+    ///
+    /// Args:
+    ///     path (str): The file to write
+    ///
+    /// Raises:
+    ///     OSError: If the file cannot be written
+    fn save_checkpoint(&self, path: &str) -> PyResult<()> {
+        self.inner.pause().save(path).map_err(PyIOError::new_err)
+    }
+
+    /// Restore the state saved by `save_checkpoint`; the next universe
+    /// build continues from it.
+    ///
+    /// Args:
+    ///     path (str): The checkpoint file
+    ///
+    /// Raises:
+    ///     OSError: If the file cannot be read
+    ///     ValueError: If the checkpoint is for another algebra or holds a
+    ///         partition that is not a congruence of this one
+    fn resume_from(&mut self, path: &str) -> PyResult<()> {
+        let checkpoint = uacalc::alg::conlat::ConLatCheckpoint::load(path).map_err(PyIOError::new_err)?;
+        self.inner.resume(&checkpoint).map_err(PyValueError::new_err)
     }
 
-    fn permutability_level(&mut self) -> i32 { self.inner.permutability_level() }
+    fn permutability_level(&mut self, py: Python<'_>) -> i32 { py.allow_threads(|| self.inner.permutability_level()) }
 
     fn cg(&mut self, a: usize, b: usize) -> PyPartition { PyPartition { inner: self.inner.cg(a, b) } }
 
@@ -202,8 +524,8 @@ impl PyCongruenceLattice {
     ///
     /// Returns:
     ///     int: The cardinality
-    fn cardinality(&mut self) -> usize {
-        self.con_cardinality()
+    fn cardinality(&mut self, py: Python<'_>) -> PyResult<usize> {
+        self.con_cardinality(py, None)
     }
 
     /// Get the BasicLattice view of this congruence lattice.
@@ -253,6 +575,24 @@ impl PyCongruenceLattice {
     }
 }
 
+/// Lazy iterator over the congruences of an algebra.
+#[pyclass]
+pub struct PyCongruences {
+    inner: uacalc::alg::conlat::Congruences,
+}
+
+#[pymethods]
+impl PyCongruences {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> Option<PyPartition> {
+        let inner = &mut self.inner;
+        py.allow_threads(|| inner.next()).map(|inner| PyPartition { inner })
+    }
+}
+
 /// Python wrapper for CongruenceLattice<IntArray>
 #[pyclass]
 pub struct PyCongruenceLatticeIntArray {
@@ -267,11 +607,137 @@ impl PyCongruenceLatticeIntArray {
 
     fn one(&self) -> PyPartition { PyPartition { inner: self.inner.one() } }
 
-    fn con_cardinality(&mut self) -> usize { self.inner.con_cardinality() }
+    /// Get the number of congruences, building the universe if needed.
+    ///
+    /// Args:
+    ///     progress (ProgressReporter, optional): Progress and cancellation;
+    ///         a cancelled build keeps its partial universe and resumes later
+    #[pyo3(signature = (progress=None))]
+    fn con_cardinality(&mut self, py: Python<'_>, progress: Option<&PyProgressReporter>) -> PyResult<usize> {
+        with_progress_partial(py, progress, PyRuntimeError::new_err, || {
+            self.inner.compute_universe().map(|univ| univ.len()).map_err(|e| e.map_partial(to_py_partitions))
+        })
+    }
+
+    fn cardinality(&mut self, py: Python<'_>) -> PyResult<usize> { self.con_cardinality(py, None) }
+
+    fn is_distributive(&mut self, py: Python<'_>) -> bool { py.allow_threads(|| self.inner.is_distributive()) }
+
+    /// Name the lattice if it is a standard small lattice.
+    ///
+    /// Returns:
+    ///     str: "C<n>" for a chain, "M<n>", "N5", "2^<k>" for a Boolean
+    ///         lattice, or "unknown"
+    fn identify_lattice(&mut self, py: Python<'_>) -> String { py.allow_threads(|| self.inner.identify_lattice()) }
+
+    /// Test whether the congruence lattice is modular.
+    ///
+    /// Builds the universe if needed.
+    fn is_modular(&mut self, py: Python<'_>) -> bool {
+        py.allow_threads(|| {
+            self.inner.universe();
+            uacalc::lat::Lattice::is_modular(&self.inner)
+        })
+    }
+
+    /// Test whether the congruence lattice is upper semimodular: a covers a ∧ b implies a ∨ b covers b.
+    ///
+    /// Builds the universe if needed.
+    fn is_upper_semimodular(&mut self, py: Python<'_>) -> bool {
+        py.allow_threads(|| {
+            self.inner.universe();
+            uacalc::lat::Lattice::is_upper_semimodular(&self.inner)
+        })
+    }
+
+    /// Test whether the congruence lattice is lower semimodular (the dual of upper semimodular).
+    ///
+    /// Builds the universe if needed.
+    fn is_lower_semimodular(&mut self, py: Python<'_>) -> bool {
+        py.allow_threads(|| {
+            self.inner.universe();
+            uacalc::lat::Lattice::is_lower_semimodular(&self.inner)
+        })
+    }
+
+    /// Test whether the congruence lattice is lower bounded, i.e. the join dependency relation
+    /// on the join irreducibles has no cycle.
+    ///
+    /// Builds the universe if needed.
+    fn is_lower_bounded_lattice(&mut self, py: Python<'_>) -> bool {
+        py.allow_threads(|| {
+            self.inner.universe();
+            uacalc::lat::Lattice::is_lower_bounded_lattice(&self.inner)
+        })
+    }
+
+    /// Test whether the congruence lattice is relatively complemented: every interval is complemented.
+    ///
+    /// Builds the universe if needed.
+    fn is_relatively_complemented(&mut self, py: Python<'_>) -> bool {
+        py.allow_threads(|| {
+            self.inner.universe();
+            uacalc::lat::Lattice::is_relatively_complemented(&self.inner)
+        })
+    }
+
+    /// Test whether the congruence lattice is geometric: atomistic and upper semimodular.
+    ///
+    /// Builds the universe if needed.
+    fn is_geometric(&mut self, py: Python<'_>) -> bool {
+        py.allow_threads(|| {
+            self.inner.universe();
+            uacalc::lat::Lattice::is_geometric(&self.inner)
+        })
+    }
+
+    /// Test whether the congruence lattice is meet semidistributive:
+    /// a ∧ b = a ∧ c implies a ∧ b = a ∧ (b ∨ c).
+    ///
+    /// Builds the universe if needed.
+    fn is_meet_semidistributive(&mut self, py: Python<'_>) -> bool {
+        py.allow_threads(|| {
+            self.inner.universe();
+            uacalc::lat::Lattice::is_meet_semidistributive(&self.inner)
+        })
+    }
+
+    /// Test whether the congruence lattice is join semidistributive:
+    /// a ∨ b = a ∨ c implies a ∨ b = a ∨ (b ∧ c).
+    ///
+    /// Builds the universe if needed.
+    fn is_join_semidistributive(&mut self, py: Python<'_>) -> bool {
+        py.allow_threads(|| {
+            self.inner.universe();
+            uacalc::lat::Lattice::is_join_semidistributive(&self.inner)
+        })
+    }
 
-    fn cardinality(&mut self) -> usize { self.con_cardinality() }
+    /// Find congruences (a, b, c) with a ∧ b = a ∧ c but a ∧ (b ∨ c) ≠ a ∧ b.
+    ///
+    /// Returns:
+    ///     Optional[Tuple[Partition, Partition, Partition]]: The witness, or
+    ///         None if the lattice is meet semidistributive
+    fn meet_semidistributivity_failure(&mut self, py: Python<'_>) -> Option<(PyPartition, PyPartition, PyPartition)> {
+        py.allow_threads(|| {
+            self.inner.universe();
+            uacalc::lat::Lattice::meet_semidistributivity_failure(&self.inner)
+        })
+        .map(|(a, b, c)| (PyPartition { inner: a }, PyPartition { inner: b }, PyPartition { inner: c }))
+    }
 
-    fn is_distributive(&mut self) -> bool { self.inner.is_distributive() }
+    /// Find congruences (a, b, c) with a ∨ b = a ∨ c but a ∨ (b ∧ c) ≠ a ∨ b.
+    ///
+    /// Returns:
+    ///     Optional[Tuple[Partition, Partition, Partition]]: The witness, or
+    ///         None if the lattice is join semidistributive
+    fn join_semidistributivity_failure(&mut self, py: Python<'_>) -> Option<(PyPartition, PyPartition, PyPartition)> {
+        py.allow_threads(|| {
+            self.inner.universe();
+            uacalc::lat::Lattice::join_semidistributivity_failure(&self.inner)
+        })
+        .map(|(a, b, c)| (PyPartition { inner: a }, PyPartition { inner: b }, PyPartition { inner: c }))
+    }
 
     fn get_description(&self) -> String { self.inner.get_description() }
 
@@ -302,15 +768,19 @@ impl PyCongruenceLatticeIntArray {
         self.inner.find_meet_irred(&a.inner, &b.inner).map(|p| PyPartition { inner: p })
     }
 
-    fn join_irreducibles(&mut self) -> Vec<PyPartition> {
+    #[pyo3(signature = (progress=None))]
+    fn join_irreducibles(&mut self, py: Python<'_>, progress: Option<&PyProgressReporter>) -> PyResult<Vec<PyPartition>> {
         use uacalc::alg::conlat::CongruenceLattice;
-        let jis: &Vec<uacalc::alg::conlat::partition::Partition> = CongruenceLattice::join_irreducibles(&mut self.inner);
-        jis.iter().map(|p| PyPartition { inner: p.clone() }).collect()
+        with_progress(py, progress, || {
+            self.inner.try_make_join_irreducibles().map_err(PyRuntimeError::new_err)?;
+            let jis: &Vec<uacalc::alg::conlat::partition::Partition> = CongruenceLattice::join_irreducibles(&mut self.inner);
+            Ok(jis.iter().map(|p| PyPartition { inner: p.clone() }).collect())
+        })
     }
 
-    fn principals(&mut self) -> Vec<PyPartition> {
+    fn principals(&mut self, py: Python<'_>) -> Vec<PyPartition> {
         use uacalc::alg::conlat::CongruenceLattice;
-        let principals: &Vec<uacalc::alg::conlat::partition::Partition> = CongruenceLattice::principals(&mut self.inner);
+        let principals: &Vec<uacalc::alg::conlat::partition::Partition> = py.allow_threads(|| CongruenceLattice::principals(&mut self.inner));
         principals.iter().map(|p| PyPartition { inner: p.clone() }).collect()
     }
 
@@ -320,9 +790,9 @@ impl PyCongruenceLatticeIntArray {
         atoms.iter().map(|p| PyPartition { inner: p.clone() }).collect()
     }
 
-    fn meet_irreducibles(&mut self) -> Vec<PyPartition> {
+    fn meet_irreducibles(&mut self, py: Python<'_>) -> Vec<PyPartition> {
         use uacalc::alg::conlat::CongruenceLattice;
-        let mis: &Vec<uacalc::alg::conlat::partition::Partition> = CongruenceLattice::meet_irreducibles(&mut self.inner);
+        let mis: &Vec<uacalc::alg::conlat::partition::Partition> = py.allow_threads(|| CongruenceLattice::meet_irreducibles(&mut self.inner));
         mis.iter().map(|p| PyPartition { inner: p.clone() }).collect()
     }
 
@@ -346,13 +816,23 @@ impl PyCongruenceLatticeIntArray {
         }
     }
 
-    fn universe(&mut self) -> Vec<PyPartition> {
-        use uacalc::alg::conlat::CongruenceLattice;
-        let univ: &Vec<uacalc::alg::conlat::partition::Partition> = CongruenceLattice::universe(&mut self.inner);
-        univ.iter().map(|p| PyPartition { inner: p.clone() }).collect()
+    #[pyo3(signature = (progress=None))]
+    fn universe(&mut self, py: Python<'_>, progress: Option<&PyProgressReporter>) -> PyResult<Vec<PyPartition>> {
+        with_progress_partial(py, progress, PyRuntimeError::new_err, || {
+            let univ = self.inner.compute_universe().map_err(|e| e.map_partial(to_py_partitions))?;
+            Ok(univ.iter().map(|p| PyPartition { inner: p.clone() }).collect())
+        })
+    }
+
+    /// Get the congruences found by an interrupted universe build.
+    ///
+    /// Returns:
+    ///     Optional[List[Partition]]: The congruences found so far, or None
+    fn partial_universe(&self) -> Option<Vec<PyPartition>> {
+        self.inner.partial_universe().map(|univ| univ.iter().map(|p| PyPartition { inner: p.clone() }).collect())
     }
 
-    fn permutability_level(&mut self) -> i32 { self.inner.permutability_level() }
+    fn permutability_level(&mut self, py: Python<'_>) -> i32 { py.allow_threads(|| self.inner.permutability_level()) }
 
     fn cg(&mut self, a: usize, b: usize) -> PyPartition { PyPartition { inner: self.inner.cg(a, b) } }
 
@@ -5,4 +5,4 @@ pub mod polymorphisms;
 pub mod subtrace;
 pub mod type_finder;
 pub mod print_type;
-pub mod congruence_lattice;
\ No newline at end of file
+pub mod congruence_lattice;pub mod product_conlat;
@@ -1,33 +1,36 @@
 use pyo3::prelude::*;
 use pyo3::exceptions::PyValueError;
 use uacalc::util::IntArrayTrait;
+use uacalc::io::report::ToLatex;
 use uacalc::alg::conlat::{BinaryRelation, MutableBinaryRelation};
 use crate::alg::conlat::basic_binary_relation::PyBasicBinaryRelation;
 
 /// Python wrapper for Partition
-#[pyclass]
+#[pyclass(module = "uacalc_lib.alg")]
 pub struct PyPartition {
     pub(crate) inner: uacalc::alg::conlat::partition::Partition,
 }
 
 #[pymethods]
 impl PyPartition {
-    /// Create a new Partition from an array representation.
+    /// Create a new Partition from an array or a string representation.
     /// 
     /// Args:
-    ///     array (List[int]): The array representation of the partition
+    ///     array (Union[List[int], str]): The array representation of the
+    ///         partition, or a string in bar notation such as "|0 1|2|3 4|"
     /// 
     /// Returns:
     ///     Partition: A new Partition instance
     /// 
     /// Raises:
-    ///     ValueError: If the array is invalid
+    ///     ValueError: If the array or string is invalid
     #[new]
-    fn new(array: Vec<i32>) -> PyResult<Self> {
-        match uacalc::alg::conlat::partition::Partition::new(array) {
-            Ok(inner) => Ok(PyPartition { inner }),
-            Err(e) => Err(PyValueError::new_err(e)),
-        }
+    fn new(array: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let inner = match array.extract::<String>() {
+            Ok(str) => uacalc::alg::conlat::partition::Partition::from_string(&str),
+            Err(_) => uacalc::alg::conlat::partition::Partition::new(array.extract::<Vec<i32>>()?),
+        };
+        inner.map(|inner| PyPartition { inner }).map_err(PyValueError::new_err)
     }
     
     /// Create a new Partition from a string representation.
@@ -67,6 +70,25 @@ impl PyPartition {
         }
     }
     
+    /// Create a Partition from the block index of each element.
+    /// 
+    /// Elements with the same index are in the same block.
+    /// 
+    /// Args:
+    ///     indices (List[int]): The block index of each of 0, ..., n-1
+    /// 
+    /// Returns:
+    ///     Partition: A new Partition instance
+    /// 
+    /// Raises:
+    ///     ValueError: If the list is empty
+    #[staticmethod]
+    fn from_block_index_vector(indices: Vec<usize>) -> PyResult<Self> {
+        uacalc::alg::conlat::partition::Partition::from_block_index_vector(&indices)
+            .map(|inner| PyPartition { inner })
+            .map_err(PyValueError::new_err)
+    }
+    
     /// Create the zero partition (all elements in separate blocks).
     /// 
     /// Args:
@@ -289,6 +311,73 @@ impl PyPartition {
         }
     }
     
+    /// Check if this partition covers another: it joins two blocks of the other.
+    /// 
+    /// Args:
+    ///     other (Partition): The partition that may be covered
+    /// 
+    /// Returns:
+    ///     bool: True if this partition covers the other
+    fn covers(&self, other: &PyPartition) -> bool {
+        self.inner.covers(&other.inner)
+    }
+    
+    /// Get the partitions covering both this partition and another.
+    /// 
+    /// Args:
+    ///     other (Partition): The other partition
+    /// 
+    /// Returns:
+    ///     List[Partition]: The common upper covers
+    /// 
+    /// Raises:
+    ///     ValueError: If the universe sizes differ
+    fn common_upper_covers(&self, other: &PyPartition) -> PyResult<Vec<PyPartition>> {
+        self.inner.common_upper_covers(&other.inner)
+            .map(|covers| covers.into_iter().map(|inner| PyPartition { inner }).collect())
+            .map_err(PyValueError::new_err)
+    }
+    
+    /// Check if this partition and another are complements: their meet is
+    /// zero and their join is one.
+    /// 
+    /// Args:
+    ///     other (Partition): The other partition
+    /// 
+    /// Returns:
+    ///     bool: True if the partitions are complements
+    fn is_complement(&self, other: &PyPartition) -> bool {
+        self.inner.is_complement(&other.inner)
+    }
+    
+    /// Get the relational product of this partition followed by another.
+    /// 
+    /// Args:
+    ///     other (Partition): The partition applied second
+    /// 
+    /// Returns:
+    ///     BasicBinaryRelation: The composition
+    /// 
+    /// Raises:
+    ///     ValueError: If the universe sizes differ
+    fn relational_composition(&self, other: &PyPartition) -> PyResult<PyBasicBinaryRelation> {
+        self.inner.relational_composition(&other.inner)
+            .map(|inner| PyBasicBinaryRelation { inner })
+            .map_err(PyValueError::new_err)
+    }
+    
+    /// Check if this partition permutes with another: both relational
+    /// products are equal.
+    /// 
+    /// Args:
+    ///     other (Partition): The other partition
+    /// 
+    /// Returns:
+    ///     bool: True if the partitions permute
+    fn permutes_with(&self, other: &PyPartition) -> bool {
+        self.inner.permutes_with(&other.inner)
+    }
+    
     /// Python string representation.
     fn __str__(&self) -> String {
         self.inner.to_string()
@@ -298,7 +387,23 @@ impl PyPartition {
     fn __repr__(&self) -> String {
         format!("Partition({})", self.inner.to_string())
     }
+
+    /// The partition in bar notation with space separated elements, for
+    /// example "|0 1|2|3 4|", as accepted by the constructor.
+    fn to_bar_string(&self) -> String {
+        self.inner.to_bar_string()
+    }
+
+    /// The partition in LaTeX bar notation, for example `$|01|2|34|$`.
+    fn to_latex(&self) -> String {
+        self.inner.to_latex()
+    }
     
+    /// Pickle support: the partition is rebuilt from its array.
+    fn __reduce__(slf: &Bound<'_, Self>) -> (PyObject, (Vec<i32>,)) {
+        (slf.get_type().into_any().unbind(), (slf.borrow().inner.to_array(),))
+    }
+
     /// Python equality comparison.
     fn __eq__(&self, other: &PyPartition) -> bool {
         self.inner == other.inner
@@ -0,0 +1,148 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+
+use crate::alg::conlat::congruence_lattice::PyCongruenceLattice;
+use crate::alg::conlat::partition::PyPartition;
+use crate::alg::product_algebra::PyProductAlgebra;
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register the ProductConLat class and the product_conlat function.
+pub fn register_product_conlat(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyProductConLat>()?;
+    m.add("ProductConLat", m.getattr("PyProductConLat")?)?;
+    m.add_function(wrap_pyfunction!(product_conlat, m)?)?;
+    Ok(())
+}
+
+/// Python wrapper for ProductConLat: Con(A x B) assembled from Con(A) and
+/// Con(B), checking for skew congruences only when asked.
+#[pyclass]
+pub struct PyProductConLat {
+    inner: uacalc::alg::conlat::ProductConLat,
+}
+
+#[pymethods]
+impl PyProductConLat {
+    /// The product congruences alpha x beta, with alpha varying slowest.
+    ///
+    /// Returns:
+    ///     List[Partition]: The product congruences
+    fn product_congruences(&self) -> Vec<PyPartition> {
+        self.inner.product_congruences().iter().cloned().map(PyPartition::from_inner).collect()
+    }
+
+    /// The product of the i-th congruence of the first factor and the j-th
+    /// of the second.
+    ///
+    /// Args:
+    ///     i (int): The index in the universe of the first factor's lattice
+    ///     j (int): The index in the universe of the second factor's lattice
+    ///
+    /// Raises:
+    ///     ValueError: If either index is out of range
+    fn product_congruence(&self, i: usize, j: usize) -> PyResult<PyPartition> {
+        self.inner
+            .product_congruence(i, j)
+            .cloned()
+            .map(PyPartition::from_inner)
+            .ok_or_else(|| PyValueError::new_err(format!("No product congruence ({}, {})", i, j)))
+    }
+
+    /// Check whether every congruence of the product is a product congruence.
+    ///
+    /// Args:
+    ///     progress (ProgressReporter, optional): Progress and cancellation
+    ///
+    /// Returns:
+    ///     bool: Whether there are no skew congruences
+    #[pyo3(signature = (progress=None))]
+    fn is_skew_free(&mut self, py: Python<'_>, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+        let inner = &mut self.inner;
+        with_progress(py, progress, || inner.is_skew_free().map_err(PyRuntimeError::new_err))
+    }
+
+    /// All congruences of the product, computing Con(A x B) in full only if
+    /// there are skew congruences.
+    ///
+    /// Args:
+    ///     progress (ProgressReporter, optional): Progress and cancellation
+    ///
+    /// Returns:
+    ///     List[Partition]: The congruences
+    #[pyo3(signature = (progress=None))]
+    fn universe(&mut self, py: Python<'_>, progress: Option<&PyProgressReporter>) -> PyResult<Vec<PyPartition>> {
+        let inner = &mut self.inner;
+        let univ = with_progress(py, progress, || inner.universe().map_err(PyRuntimeError::new_err))?;
+        Ok(univ.into_iter().map(PyPartition::from_inner).collect())
+    }
+
+    /// The congruences of the product that are not products.
+    ///
+    /// Args:
+    ///     progress (ProgressReporter, optional): Progress and cancellation
+    ///
+    /// Returns:
+    ///     List[Partition]: The skew congruences
+    #[pyo3(signature = (progress=None))]
+    fn skew_congruences(&mut self, py: Python<'_>, progress: Option<&PyProgressReporter>) -> PyResult<Vec<PyPartition>> {
+        let inner = &mut self.inner;
+        let skew = with_progress(py, progress, || inner.skew_congruences().map_err(PyRuntimeError::new_err))?;
+        Ok(skew.into_iter().map(PyPartition::from_inner).collect())
+    }
+
+    /// The number of congruences of the product.
+    ///
+    /// Args:
+    ///     progress (ProgressReporter, optional): Progress and cancellation
+    ///
+    /// Returns:
+    ///     int: The number of congruences
+    #[pyo3(signature = (progress=None))]
+    fn cardinality(&mut self, py: Python<'_>, progress: Option<&PyProgressReporter>) -> PyResult<usize> {
+        let inner = &mut self.inner;
+        with_progress(py, progress, || inner.cardinality().map_err(PyRuntimeError::new_err))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ProductConLat({} product congruences)", self.inner.product_congruences().len())
+    }
+}
+
+/// Assemble the congruence lattice of a product of two algebras from the
+/// congruence lattices of the factors.
+///
+/// Args:
+///     con_a (CongruenceLattice): The congruence lattice of the first factor
+///     con_b (CongruenceLattice): The congruence lattice of the second factor
+///     product (ProductAlgebra): The product of the two factors, in that order
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     ProductConLat: The product congruences, with skew congruences still
+///     to be checked
+///
+/// Raises:
+///     ValueError: If the product does not have two factors of the right sizes
+#[pyfunction]
+#[pyo3(signature = (con_a, con_b, product, progress=None))]
+fn product_conlat(
+    py: Python<'_>,
+    con_a: &Bound<'_, PyCongruenceLattice>,
+    con_b: &Bound<'_, PyCongruenceLattice>,
+    product: &PyProductAlgebra,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<PyProductConLat> {
+    // Compute each universe on its own borrow, so that con_a and con_b may
+    // be the same object.
+    for con in [con_a, con_b] {
+        let mut con = con.borrow_mut();
+        let inner = &mut con.inner;
+        with_progress(py, progress, || inner.try_universe().map(|_| ()).map_err(PyRuntimeError::new_err))?;
+    }
+    let mut a = con_a.borrow().inner.clone();
+    let mut b = con_b.borrow().inner.clone();
+    let inner = with_progress(py, progress, || {
+        uacalc::alg::conlat::product_conlat(&mut a, &mut b, &product.inner).map_err(PyValueError::new_err)
+    })?;
+    Ok(PyProductConLat { inner })
+}
@@ -0,0 +1,161 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use uacalc::alg::discriminator::{self, ArithmeticalAnalysis, DiscriminatorAnalysis};
+use crate::alg::PyBasicAlgebra;
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register the arithmetical and discriminator variety tests.
+pub fn register_discriminator_functions(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(is_arithmetical, m)?)?;
+    m.add_function(wrap_pyfunction!(is_discriminator_variety, m)?)?;
+    m.add_function(wrap_pyfunction!(quasiprimal_term, m)?)?;
+    m.add_class::<PyArithmeticalAnalysis>()?;
+    m.add("ArithmeticalAnalysis", m.getattr("PyArithmeticalAnalysis")?)?;
+    m.add_class::<PyDiscriminatorAnalysis>()?;
+    m.add("DiscriminatorAnalysis", m.getattr("PyDiscriminatorAnalysis")?)?;
+    Ok(())
+}
+
+/// Whether the variety generated by an algebra is arithmetical, with the
+/// Pixley term as witness.
+#[pyclass(module = "uacalc_lib.alg")]
+#[derive(Clone)]
+pub struct PyArithmeticalAnalysis {
+    inner: ArithmeticalAnalysis,
+}
+
+#[pymethods]
+impl PyArithmeticalAnalysis {
+    /// Whether the variety is arithmetical.
+    fn holds(&self) -> bool {
+        self.inner.holds()
+    }
+
+    /// The Pixley term as a string, or None.
+    #[getter]
+    fn pixley_term(&self) -> Option<String> {
+        self.inner.pixley_term.as_ref().map(|t| t.to_string())
+    }
+
+    fn __bool__(&self) -> bool {
+        self.inner.holds()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ArithmeticalAnalysis(pixley_term={})", self.pixley_term().unwrap_or_else(|| "None".to_string()))
+    }
+}
+
+/// Whether the variety generated by an algebra is a discriminator variety.
+#[pyclass(module = "uacalc_lib.alg")]
+#[derive(Clone)]
+pub struct PyDiscriminatorAnalysis {
+    inner: DiscriminatorAnalysis,
+}
+
+#[pymethods]
+impl PyDiscriminatorAnalysis {
+    /// Whether the variety is a discriminator variety.
+    fn holds(&self) -> bool {
+        self.inner.holds()
+    }
+
+    /// Whether the variety is arithmetical.
+    fn arithmetical(&self) -> bool {
+        self.inner.pixley_term.is_some()
+    }
+
+    /// The Pixley term as a string, or None.
+    #[getter]
+    fn pixley_term(&self) -> Option<String> {
+        self.inner.pixley_term.as_ref().map(|t| t.to_string())
+    }
+
+    /// A term that is the discriminator on every SI, as a string, or None.
+    #[getter]
+    fn discriminator_term(&self) -> Option<String> {
+        self.inner.discriminator_term.as_ref().map(|t| t.to_string())
+    }
+
+    /// The quasiprimal SIs in HS(A) examined.
+    #[getter]
+    fn subdirectly_irreducibles(&self) -> Vec<PyBasicAlgebra> {
+        self.inner.subdirectly_irreducibles.iter().cloned().map(PyBasicAlgebra::from_inner).collect()
+    }
+
+    /// The first SI found that is not quasiprimal, or None.
+    #[getter]
+    fn non_quasiprimal(&self) -> Option<PyBasicAlgebra> {
+        self.inner.non_quasiprimal.clone().map(PyBasicAlgebra::from_inner)
+    }
+
+    fn __bool__(&self) -> bool {
+        self.inner.holds()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("DiscriminatorAnalysis(arithmetical={}, discriminator_term={})",
+            self.arithmetical(), self.discriminator_term().unwrap_or_else(|| "None".to_string()))
+    }
+}
+
+/// Test whether the variety generated by an algebra is arithmetical
+/// (congruence distributive and congruence permutable) by searching for a
+/// Pixley term.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     ArithmeticalAnalysis: With the Pixley term if there is one
+///
+/// Raises:
+///     ValueError: If the search fails or was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn is_arithmetical(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<PyArithmeticalAnalysis> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    let inner = with_progress(py, progress, || discriminator::is_arithmetical(alg.as_ref()).map_err(PyValueError::new_err))?;
+    Ok(PyArithmeticalAnalysis { inner })
+}
+
+/// Test whether the variety generated by an algebra is a discriminator
+/// variety.
+///
+/// The variety must be arithmetical, every SI in HS(A) quasiprimal, and a
+/// single term the discriminator on all of them.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     DiscriminatorAnalysis: With the discriminator term, or the missing
+///         Pixley term or a non-quasiprimal SI as witness of failure
+///
+/// Raises:
+///     ValueError: If a search fails or was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn is_discriminator_variety(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<PyDiscriminatorAnalysis> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    let inner = with_progress(py, progress, || discriminator::is_discriminator_variety(alg.as_ref()).map_err(PyValueError::new_err))?;
+    Ok(PyDiscriminatorAnalysis { inner })
+}
+
+/// A term for the ternary discriminator if the algebra is quasiprimal.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     Optional[str]: The term, or None if the algebra is not quasiprimal
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn quasiprimal_term(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    let term = with_progress(py, progress, || discriminator::quasiprimal_term(alg.as_ref()).map_err(PyValueError::new_err))?;
+    Ok(term.map(|t| t.to_string()))
+}
@@ -0,0 +1,150 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use uacalc::alg::dpc::{self, DpcLevel, DpcReport};
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register the definable principal congruences probe.
+pub fn register_dpc_functions(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(dpc_probe, m)?)?;
+    m.add_function(wrap_pyfunction!(dpc_level, m)?)?;
+    m.add_class::<PyDpcLevel>()?;
+    m.add("DpcLevel", m.getattr("PyDpcLevel")?)?;
+    m.add_class::<PyDpcReport>()?;
+    m.add("DpcReport", m.getattr("PyDpcReport")?)?;
+    Ok(())
+}
+
+/// The bounds needed to define principal congruences in one power A^k.
+#[pyclass(module = "uacalc_lib.alg")]
+#[derive(Clone)]
+pub struct PyDpcLevel {
+    inner: DpcLevel,
+}
+
+#[pymethods]
+impl PyDpcLevel {
+    /// The exponent k.
+    #[getter]
+    fn power(&self) -> usize {
+        self.inner.power
+    }
+
+    /// The number of elements of A^k.
+    #[getter]
+    fn size(&self) -> usize {
+        self.inner.size
+    }
+
+    /// The longest Mal'cev chain needed.
+    #[getter]
+    fn chain_length(&self) -> usize {
+        self.inner.chain_length
+    }
+
+    /// The largest term depth needed for the polynomials of the chains.
+    #[getter]
+    fn term_depth(&self) -> usize {
+        self.inner.term_depth
+    }
+
+    /// Indices (u, v, x, y) where (x, y) needs the longest chain in
+    /// Cg(u, v), or None.
+    #[getter]
+    fn witness(&self) -> Option<[usize; 4]> {
+        self.inner.witness
+    }
+
+    fn __repr__(&self) -> String {
+        format!("DpcLevel(power={}, chain_length={}, term_depth={})",
+            self.inner.power, self.inner.chain_length, self.inner.term_depth)
+    }
+}
+
+/// The outcome of the definable principal congruences probe.
+#[pyclass(module = "uacalc_lib.alg")]
+#[derive(Clone)]
+pub struct PyDpcReport {
+    inner: DpcReport,
+}
+
+#[pymethods]
+impl PyDpcReport {
+    /// The bounds found in A, A^2, ..., A^k.
+    #[getter]
+    fn levels(&self) -> Vec<PyDpcLevel> {
+        self.inner.levels.iter().cloned().map(|inner| PyDpcLevel { inner }).collect()
+    }
+
+    /// Whether a scheme was found.
+    fn found(&self) -> bool {
+        self.inner.scheme.is_some()
+    }
+
+    /// The chain length and term depth of the scheme, or None.
+    #[getter]
+    fn scheme(&self) -> Option<(usize, usize)> {
+        self.inner.scheme.map(|s| (s.chain_length, s.term_depth))
+    }
+
+    /// The scheme written out, or None.
+    #[getter]
+    fn formula(&self) -> Option<String> {
+        self.inner.scheme.map(|s| s.to_string())
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        match self.inner.scheme {
+            Some(s) => format!("DpcReport(chain_length={}, term_depth={})", s.chain_length, s.term_depth),
+            None => "DpcReport(no scheme)".to_string(),
+        }
+    }
+}
+
+/// Probe whether the variety generated by an algebra has definable
+/// principal congruences, looking at the powers A, ..., A^max_power.
+///
+/// A scheme is reported when the last power needs neither longer Mal'cev
+/// chains nor deeper terms than the smaller ones. It defines principal
+/// congruences in every power examined, which suggests, but does not
+/// prove, DPC for the variety.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     max_power (int, optional): The largest exponent (default 2)
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     DpcReport: The bounds for each power and the scheme, if found
+///
+/// Raises:
+///     ValueError: If a power is too large, an operation cannot be
+///         evaluated or the computation was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, max_power=2, progress=None))]
+fn dpc_probe(py: Python<'_>, algebra: &Bound<'_, PyAny>, max_power: usize, progress: Option<&PyProgressReporter>) -> PyResult<PyDpcReport> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    let inner = with_progress(py, progress, || dpc::dpc_probe(alg.as_ref(), max_power).map_err(PyValueError::new_err))?;
+    Ok(PyDpcReport { inner })
+}
+
+/// Compute the chain length and term depth needed to define the principal
+/// congruences of A^k.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     k (int): The exponent
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Raises:
+///     ValueError: As for dpc_probe
+#[pyfunction]
+#[pyo3(signature = (algebra, k, progress=None))]
+fn dpc_level(py: Python<'_>, algebra: &Bound<'_, PyAny>, k: usize, progress: Option<&PyProgressReporter>) -> PyResult<PyDpcLevel> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    let inner = with_progress(py, progress, || dpc::dpc_level(alg.as_ref(), k).map_err(PyValueError::new_err))?;
+    Ok(PyDpcLevel { inner })
+}
@@ -0,0 +1,112 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use uacalc::alg::duality::{self, DualityProbe};
+use crate::alg::clone::{to_py_operation, PyRelation};
+use crate::alg::op::int_operation::PyIntOperation;
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register the natural duality probe.
+pub fn register_duality_functions(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(probe_duality, m)?)?;
+    m.add_class::<PyDualityProbe>()?;
+    m.add("DualityProbe", m.getattr("PyDualityProbe")?)?;
+    Ok(())
+}
+
+/// The outcome of a natural duality probe.
+#[pyclass(module = "uacalc_lib.alg")]
+#[derive(Clone)]
+pub struct PyDualityProbe {
+    inner: DualityProbe,
+}
+
+#[pymethods]
+impl PyDualityProbe {
+    /// Whether all the compatible relations pass the interpolation
+    /// condition on A^n for n <= max_n.
+    fn holds(&self) -> bool {
+        self.inner.holds()
+    }
+
+    /// The largest arity of the relations considered.
+    #[getter]
+    fn relation_arity(&self) -> usize {
+        self.inner.relation_arity
+    }
+
+    /// The largest power checked.
+    #[getter]
+    fn max_n(&self) -> usize {
+        self.inner.max_n
+    }
+
+    /// The nontrivial compatible relations considered.
+    #[getter]
+    fn relations(&self) -> Vec<PyRelation> {
+        self.inner.relations.iter().cloned().map(|inner| PyRelation { inner }).collect()
+    }
+
+    /// A polymorphism of all the relations that is not a term operation, or None.
+    #[getter]
+    fn failure(&self) -> PyResult<Option<PyIntOperation>> {
+        self.inner.failure.as_ref().map(|op| to_py_operation(op.as_ref())).transpose()
+    }
+
+    /// The minimal alter egos found, each a list of relations.
+    #[getter]
+    fn candidates(&self) -> Vec<Vec<PyRelation>> {
+        (0..self.inner.candidates.len())
+            .map(|i| self.inner.candidate_relations(i).into_iter().cloned().map(|inner| PyRelation { inner }).collect())
+            .collect()
+    }
+
+    fn __bool__(&self) -> bool {
+        self.inner.holds()
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("DualityProbe(relations={}, holds={}, candidates={})",
+            self.inner.relations.len(), self.inner.holds(), self.inner.candidates.len())
+    }
+}
+
+/// Probe an algebra for an alter ego yielding a natural duality.
+///
+/// Experimental. The alter egos are sets of subuniverses of A^k for
+/// k <= relation_arity; each is checked against the interpolation condition
+/// on A^n for n <= max_n, that is that its n-ary polymorphisms are term
+/// operations. Passing is necessary for a duality, not a proof of one.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     relation_arity (int, optional): The largest arity of the relations (default 2)
+///     max_n (int, optional): The largest power checked (default 3)
+///     max_relations (int, optional): The largest candidate size (default 2)
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     DualityProbe: The relations, a failing polymorphism or the minimal
+///         candidates found
+///
+/// Raises:
+///     ValueError: If a power is too large or the computation was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, relation_arity=2, max_n=3, max_relations=2, progress=None))]
+fn probe_duality(
+    py: Python<'_>,
+    algebra: &Bound<'_, PyAny>,
+    relation_arity: usize,
+    max_n: usize,
+    max_relations: usize,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<PyDualityProbe> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    let inner = with_progress(py, progress, || {
+        duality::probe_duality(alg.as_ref(), relation_arity, max_n, max_relations).map_err(PyValueError::new_err)
+    })?;
+    Ok(PyDualityProbe { inner })
+}
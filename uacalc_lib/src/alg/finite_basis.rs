@@ -0,0 +1,166 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use uacalc::alg::finite_basis::{self, FiniteBasisCriterion, FiniteBasisReport, FiniteBasisVerdict};
+use crate::alg::PyBasicAlgebra;
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register the finite basis probes.
+pub fn register_finite_basis_functions(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(finite_basis_probe, m)?)?;
+    m.add_function(wrap_pyfunction!(is_known_finitely_based, m)?)?;
+    m.add_class::<PyFiniteBasisCriterion>()?;
+    m.add("FiniteBasisCriterion", m.getattr("PyFiniteBasisCriterion")?)?;
+    m.add_class::<PyFiniteBasisReport>()?;
+    m.add("FiniteBasisReport", m.getattr("PyFiniteBasisReport")?)?;
+    Ok(())
+}
+
+/// One finite basis theorem and whether its checked hypothesis holds.
+#[pyclass(module = "uacalc_lib.alg")]
+#[derive(Clone)]
+pub struct PyFiniteBasisCriterion {
+    inner: FiniteBasisCriterion,
+}
+
+#[pymethods]
+impl PyFiniteBasisCriterion {
+    /// A short name for the hypothesis, e.g. "congruence distributive".
+    #[getter]
+    fn name(&self) -> &'static str {
+        self.inner.name
+    }
+
+    /// Whether the hypothesis holds in the variety generated by the algebra.
+    #[getter]
+    fn holds(&self) -> bool {
+        self.inner.holds
+    }
+
+    /// A further hypothesis the theorem needs that is not checked, or None.
+    #[getter]
+    fn unchecked(&self) -> Option<&'static str> {
+        self.inner.unchecked
+    }
+
+    /// What was found, e.g. the witnessing terms.
+    #[getter]
+    fn detail(&self) -> String {
+        self.inner.detail.clone()
+    }
+
+    /// The theorem the criterion relies on.
+    #[getter]
+    fn reference(&self) -> &'static str {
+        self.inner.reference
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("FiniteBasisCriterion({}, holds={})", self.inner.name, self.inner.holds)
+    }
+}
+
+/// The outcome of the finite basis probes.
+///
+/// The verdict is "finitely based", "finitely based if <hypothesis>" or
+/// "unknown"; unknown never means nonfinitely based.
+#[pyclass(module = "uacalc_lib.alg")]
+#[derive(Clone)]
+pub struct PyFiniteBasisReport {
+    inner: FiniteBasisReport,
+}
+
+#[pymethods]
+impl PyFiniteBasisReport {
+    /// The name of the algebra.
+    #[getter]
+    fn algebra(&self) -> String {
+        self.inner.algebra.clone()
+    }
+
+    /// The verdict as a string.
+    #[getter]
+    fn verdict(&self) -> String {
+        self.inner.verdict.to_string()
+    }
+
+    /// The unchecked hypothesis of a conditional verdict, or None.
+    #[getter]
+    fn condition(&self) -> Option<String> {
+        match &self.inner.verdict {
+            FiniteBasisVerdict::FinitelyBasedIf(condition) => Some(condition.clone()),
+            _ => None,
+        }
+    }
+
+    /// Whether the probes prove the variety finitely based.
+    fn is_finitely_based(&self) -> bool {
+        self.inner.verdict == FiniteBasisVerdict::FinitelyBased
+    }
+
+    /// The criteria checked, in order.
+    #[getter]
+    fn criteria(&self) -> Vec<PyFiniteBasisCriterion> {
+        self.inner.criteria.iter().cloned().map(|inner| PyFiniteBasisCriterion { inner }).collect()
+    }
+
+    /// The criteria whose hypothesis holds.
+    fn applicable(&self) -> Vec<PyFiniteBasisCriterion> {
+        self.inner.applicable().into_iter().cloned().map(|inner| PyFiniteBasisCriterion { inner }).collect()
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("FiniteBasisReport({}: {})", self.inner.algebra, self.inner.verdict)
+    }
+}
+
+/// Probe whether the variety generated by a finite algebra is finitely based.
+///
+/// Checks the hypotheses of known finite basis theorems, cheapest first:
+/// two element algebras, groups, rings, lattices and commutative
+/// semigroups, congruence distributivity, and conditionally congruence
+/// meet-semidistributivity and congruence modularity.
+///
+/// Args:
+///     algebra (BasicAlgebra): The algebra
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     FiniteBasisReport: The verdict and every criterion checked, with references
+///
+/// Raises:
+///     ValueError: If an operation cannot be evaluated or the computation
+///         was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn finite_basis_probe(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<PyFiniteBasisReport> {
+    let inner = with_progress(py, progress, || {
+        finite_basis::finite_basis_probe(&algebra.inner).map_err(PyValueError::new_err)
+    })?;
+    Ok(PyFiniteBasisReport { inner })
+}
+
+/// Whether the finite basis probes prove the variety generated by an
+/// algebra finitely based; False means unknown.
+///
+/// Args:
+///     algebra (BasicAlgebra): The algebra
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Raises:
+///     ValueError: If an operation cannot be evaluated or the computation
+///         was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn is_known_finitely_based(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+    with_progress(py, progress, || {
+        finite_basis::is_known_finitely_based(&algebra.inner).map_err(PyValueError::new_err)
+    })
+}
@@ -5,7 +5,9 @@ use crate::alg::{PyBasicAlgebra, PyBasicOperation};
 use crate::alg::conlat::congruence_lattice::PyCongruenceLatticeIntArray;
 use crate::eq::PyEquation;
 use crate::util::PyIntArray;
+use crate::progress::{with_progress, PyProgressReporter};
 use std::collections::HashMap;
+use crate::alg::cardinality::cardinality_to_py;
 
 /// Python wrapper for FreeAlgebra
 #[pyclass]
@@ -20,17 +22,20 @@ impl PyFreeAlgebra {
     /// Args:
     ///     base (BasicAlgebra): The base algebra
     ///     number_of_gens (int): Number of generators
+    ///     progress (ProgressReporter, optional): Progress and cancellation
     ///
     /// Raises:
     ///     ValueError: If construction fails
+    ///     CancelledError: If cancelled through `progress`
     #[new]
-    fn new(base: &PyBasicAlgebra, number_of_gens: i32) -> PyResult<Self> {
+    #[pyo3(signature = (base, number_of_gens, progress=None))]
+    fn new(py: Python<'_>, base: &PyBasicAlgebra, number_of_gens: i32, progress: Option<&PyProgressReporter>) -> PyResult<Self> {
         let rust_base = Box::new(base.inner.clone()) as Box<dyn uacalc::alg::SmallAlgebra<UniverseItem = i32>>;
 
-        match uacalc::alg::FreeAlgebra::new_safe(rust_base, number_of_gens) {
+        with_progress(py, progress, || match uacalc::alg::FreeAlgebra::new_safe(rust_base, number_of_gens) {
             Ok(inner) => Ok(PyFreeAlgebra { inner }),
             Err(e) => Err(PyValueError::new_err(e)),
-        }
+        })
     }
 
     /// Create a new FreeAlgebra with a custom name.
@@ -60,23 +65,28 @@ impl PyFreeAlgebra {
     ///     make_universe (bool): Whether to compute the universe
     ///     thin_gens (bool): Whether to thin generators
     ///     decompose (bool): Whether to decompose
+    ///     progress (ProgressReporter, optional): Progress and cancellation
     ///
     /// Raises:
     ///     ValueError: If construction fails
+    ///     CancelledError: If cancelled through `progress`
     #[staticmethod]
+    #[pyo3(signature = (base, number_of_gens, make_universe, thin_gens, decompose, progress=None))]
     fn new_with_progress(
+        py: Python<'_>,
         base: &PyBasicAlgebra,
         number_of_gens: i32,
         make_universe: bool,
         thin_gens: bool,
-        decompose: bool
+        decompose: bool,
+        progress: Option<&PyProgressReporter>,
     ) -> PyResult<Self> {
         let rust_base = Box::new(base.inner.clone()) as Box<dyn uacalc::alg::SmallAlgebra<UniverseItem = i32>>;
 
-        match uacalc::alg::FreeAlgebra::new_with_progress_safe(rust_base, number_of_gens, None) {
+        with_progress(py, progress, || match uacalc::alg::FreeAlgebra::new_with_progress_safe(rust_base, number_of_gens, None) {
             Ok(inner) => Ok(PyFreeAlgebra { inner }),
             Err(e) => Err(PyValueError::new_err(e)),
-        }
+        })
     }
 
     /// Create a new FreeAlgebra with relations (finitely presented algebra).
@@ -85,22 +95,78 @@ impl PyFreeAlgebra {
     ///     base (BasicAlgebra): The base algebra
     ///     number_of_gens (int): Number of generators
     ///     relations (List[Equation]): The relations
+    ///     progress (ProgressReporter, optional): Progress and cancellation
     ///
     /// Raises:
     ///     ValueError: If construction fails
+    ///     CancelledError: If cancelled through `progress`
     #[staticmethod]
+    #[pyo3(signature = (base, number_of_gens, relations, progress=None))]
     fn new_with_relations(
+        py: Python<'_>,
         base: &PyBasicAlgebra,
         number_of_gens: i32,
-        relations: Vec<PyEquation>
+        relations: Vec<PyEquation>,
+        progress: Option<&PyProgressReporter>,
     ) -> PyResult<Self> {
         let rust_base = Box::new(base.inner.clone()) as Box<dyn uacalc::alg::SmallAlgebra<UniverseItem = i32>>;
         let rust_relations: Vec<uacalc::eq::Equation> = relations.into_iter().map(|eq| eq.inner).collect();
 
-        match uacalc::alg::FreeAlgebra::new_with_relations_safe(rust_base, number_of_gens, rust_relations, None) {
+        with_progress(py, progress, || match uacalc::alg::FreeAlgebra::new_with_relations_safe(rust_base, number_of_gens, rust_relations, None) {
             Ok(inner) => Ok(PyFreeAlgebra { inner }),
             Err(e) => Err(PyValueError::new_err(e)),
-        }
+        })
+    }
+
+    /// Create the free algebra in the variety of the base algebra
+    /// intersected with the models of extra identities.
+    ///
+    /// Args:
+    ///     base (BasicAlgebra): The base algebra
+    ///     number_of_gens (int): Number of generators
+    ///     identities (List[Equation]): The extra identities
+    ///     progress (ProgressReporter, optional): Progress and cancellation
+    ///
+    /// Raises:
+    ///     ValueError: If construction fails
+    ///     CancelledError: If cancelled through `progress`
+    #[staticmethod]
+    #[pyo3(signature = (base, number_of_gens, identities, progress=None))]
+    fn new_with_identities(
+        py: Python<'_>,
+        base: &PyBasicAlgebra,
+        number_of_gens: i32,
+        identities: Vec<PyEquation>,
+        progress: Option<&PyProgressReporter>,
+    ) -> PyResult<Self> {
+        let rust_base = Box::new(base.inner.clone()) as Box<dyn uacalc::alg::SmallAlgebra<UniverseItem = i32>>;
+        let rust_identities: Vec<uacalc::eq::Equation> = identities.into_iter().map(|eq| eq.inner).collect();
+
+        with_progress(py, progress, || match uacalc::alg::FreeAlgebra::new_with_identities_safe(rust_base, number_of_gens, rust_identities) {
+            Ok(inner) => Ok(PyFreeAlgebra { inner }),
+            Err(e) => Err(PyValueError::new_err(e)),
+        })
+    }
+
+    /// Quotient this free algebra by the congruence generated by identities.
+    ///
+    /// Args:
+    ///     identities (List[Equation]): The extra identities
+    ///     progress (ProgressReporter, optional): Progress and cancellation
+    ///
+    /// Returns:
+    ///     FreeAlgebra: The relatively free algebra
+    ///
+    /// Raises:
+    ///     ValueError: If an identity cannot be evaluated in the algebra
+    ///     CancelledError: If cancelled through `progress`
+    #[pyo3(signature = (identities, progress=None))]
+    fn with_identities(&self, py: Python<'_>, identities: Vec<PyEquation>, progress: Option<&PyProgressReporter>) -> PyResult<Self> {
+        let rust_identities: Vec<uacalc::eq::Equation> = identities.into_iter().map(|eq| eq.inner).collect();
+        with_progress(py, progress, || match self.inner.clone().with_identities(rust_identities) {
+            Ok(inner) => Ok(PyFreeAlgebra { inner }),
+            Err(e) => Err(PyValueError::new_err(e)),
+        })
     }
 
     /// Get the idempotent terms.
@@ -173,6 +239,25 @@ impl PyFreeAlgebra {
         self.inner.cardinality()
     }
 
+    /// Get the exact cardinality, even when it is too big for cardinality().
+    ///
+    /// Returns:
+    ///     Optional[int]: The cardinality as an int of any size, or None if it
+    ///     is unknown
+    fn exact_cardinality(&self, py: Python<'_>) -> PyResult<PyObject> {
+        cardinality_to_py(py, &self.inner.exact_cardinality())
+    }
+
+    /// Get the exact cardinality of the power A^(A^n) this free algebra is
+    /// a subalgebra of.
+    ///
+    /// Returns:
+    ///     Optional[int]: The cardinality as an int of any size, or None if it
+    ///     is too big to compute
+    fn ambient_cardinality(&self, py: Python<'_>) -> PyResult<PyObject> {
+        cardinality_to_py(py, &self.inner.ambient_cardinality())
+    }
+
     /// Get the name of the algebra.
     ///
     /// Returns:
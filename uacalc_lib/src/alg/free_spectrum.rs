@@ -0,0 +1,210 @@
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use uacalc::alg::SmallAlgebra;
+use uacalc::alg::algebra::ProgressMonitor;
+use uacalc::alg::free_spectrum as spectrum;
+use crate::alg::PyBasicAlgebra;
+use crate::alg::op::term_operation_imp::PyTermOperationImp;
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register free spectrum functions.
+pub fn register_free_spectrum_functions(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(free_spectrum, m)?)?;
+    m.add_function(wrap_pyfunction!(pn_sequence, m)?)?;
+    m.add_function(wrap_pyfunction!(idempotent_reduct, m)?)?;
+    m.add_function(wrap_pyfunction!(term_operations_up_to_arity, m)?)?;
+    m.add_class::<PyTermOperations>()?;
+    m.add("TermOperations", m.getattr("PyTermOperations")?)?;
+    Ok(())
+}
+
+/// A progress monitor calling a Python function with each progress message.
+///
+/// The computation is cancelled when the callback returns a true value or
+/// raises; a raised exception is re-raised once the computation stops.
+#[derive(Debug)]
+struct CallbackMonitor {
+    callback: PyObject,
+    cancelled: AtomicBool,
+    error: Mutex<Option<PyErr>>,
+}
+
+impl CallbackMonitor {
+    fn new(callback: PyObject) -> Self {
+        CallbackMonitor { callback, cancelled: AtomicBool::new(false), error: Mutex::new(None) }
+    }
+
+    fn finish<T>(self, result: Result<T, String>) -> PyResult<T> {
+        if let Some(e) = self.error.into_inner().unwrap() {
+            return Err(e);
+        }
+        result.map_err(PyValueError::new_err)
+    }
+}
+
+impl ProgressMonitor for CallbackMonitor {
+    fn report_progress(&self, message: &str) {
+        Python::with_gil(|py| {
+            let stop = match self.callback.call1(py, (message,)) {
+                Ok(r) => r.bind(py).is_truthy().unwrap_or(false),
+                Err(e) => {
+                    *self.error.lock().unwrap() = Some(e);
+                    true
+                }
+            };
+            if stop {
+                self.cancelled.store(true, Ordering::SeqCst);
+            }
+        });
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    fn set_progress(&self, _progress: f64) {}
+}
+
+fn run<T>(
+    callback: Option<PyObject>,
+    f: impl FnOnce(Option<&dyn ProgressMonitor>) -> Result<T, String>,
+) -> PyResult<T> {
+    match callback {
+        Some(cb) => {
+            let monitor = CallbackMonitor::new(cb);
+            let result = f(Some(&monitor));
+            monitor.finish(result)
+        }
+        None => f(None).map_err(PyValueError::new_err),
+    }
+}
+
+/// Compute the free spectrum |F(0)|, ..., |F(up_to_n)| of an algebra.
+///
+/// Args:
+///     algebra (BasicAlgebra): The algebra
+///     up_to_n (int): The largest number of generators
+///     callback (Callable[[str], Optional[bool]], optional): Called with
+///         progress messages; return True to cancel
+///     progress (ProgressReporter, optional): Progress and cancellation,
+///         used when no callback is given
+///
+/// Returns:
+///     List[int]: The sizes; shorter than up_to_n + 1 if cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, up_to_n, callback=None, progress=None))]
+fn free_spectrum(
+    py: Python<'_>,
+    algebra: &PyBasicAlgebra,
+    up_to_n: usize,
+    callback: Option<PyObject>,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Vec<usize>> {
+    with_progress(py, progress, || {
+        run(callback, |monitor| spectrum::free_spectrum(&algebra.inner, up_to_n, monitor))
+    })
+}
+
+/// Compute p_0, ..., p_up_to_n: the numbers of essentially n-ary term operations.
+///
+/// Args:
+///     algebra (BasicAlgebra): The algebra
+///     up_to_n (int): The largest arity
+///     callback (Callable[[str], Optional[bool]], optional): Called with
+///         progress messages; return True to cancel
+///     progress (ProgressReporter, optional): Progress and cancellation,
+///         used when no callback is given
+///
+/// Returns:
+///     List[int]: The counts; shorter than up_to_n + 1 if cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, up_to_n, callback=None, progress=None))]
+fn pn_sequence(
+    py: Python<'_>,
+    algebra: &PyBasicAlgebra,
+    up_to_n: usize,
+    callback: Option<PyObject>,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Vec<usize>> {
+    with_progress(py, progress, || {
+        run(callback, |monitor| spectrum::pn_sequence(&algebra.inner, up_to_n, monitor))
+    })
+}
+
+/// Compute the idempotent reduct of an algebra up to an arity: the algebra
+/// of its idempotent term operations of arity 2..max_arity that depend on
+/// all of their variables, named t<k>_<i>.
+///
+/// Args:
+///     algebra (BasicAlgebra): The algebra
+///     max_arity (int): The largest arity of the operations
+///     callback (Callable[[str], Optional[bool]], optional): Called with
+///         progress messages; return True to cancel
+///     progress (ProgressReporter, optional): Progress and cancellation,
+///         used when no callback is given
+///
+/// Returns:
+///     BasicAlgebra: The reduct, named Id(<name>)
+///
+/// Raises:
+///     ValueError: If a clone is too large or the callback cancelled the
+///         computation
+///     CancelledError: If progress cancelled the computation
+#[pyfunction]
+#[pyo3(signature = (algebra, max_arity, callback=None, progress=None))]
+fn idempotent_reduct(
+    py: Python<'_>,
+    algebra: &PyBasicAlgebra,
+    max_arity: usize,
+    callback: Option<PyObject>,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<PyBasicAlgebra> {
+    let inner = with_progress(py, progress, || {
+        run(callback, |monitor| spectrum::idempotent_reduct(&algebra.inner, max_arity, monitor))
+    })?;
+    Ok(PyBasicAlgebra { inner })
+}
+
+/// Lazy iterator over the term operations of an algebra up to some arity.
+#[pyclass]
+pub struct PyTermOperations {
+    inner: spectrum::TermOperations,
+}
+
+#[pymethods]
+impl PyTermOperations {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(&mut self) -> Option<PyTermOperationImp> {
+        self.inner.next().map(|inner| PyTermOperationImp { inner })
+    }
+}
+
+/// Enumerate the term operations of arity at most k of an algebra, lazily.
+///
+/// Each operation is table based and carries a term producing it in the
+/// variables x0, x1, ...; the operations of each arity come in the order the
+/// closure of the projections finds them.
+///
+/// Args:
+///     algebra (BasicAlgebra): The algebra
+///     k (int): The largest arity
+///     max_count (int, optional): Stop after this many operations
+///
+/// Returns:
+///     TermOperations: An iterator of TermOperationImp
+///
+/// Raises:
+///     ValueError: If the tables of arity k are too large
+#[pyfunction]
+#[pyo3(signature = (algebra, k, max_count=None))]
+fn term_operations_up_to_arity(algebra: &PyBasicAlgebra, k: usize, max_count: Option<usize>) -> PyResult<PyTermOperations> {
+    let alg: Arc<dyn SmallAlgebra<UniverseItem = i32>> = Arc::new(algebra.inner.clone());
+    spectrum::term_operations_up_to_arity(alg, k, max_count)
+        .map(|inner| PyTermOperations { inner })
+        .map_err(PyValueError::new_err)
+}
@@ -0,0 +1,150 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use uacalc::alg::generating_sets;
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register the generating set functions.
+pub fn register_generating_set_functions(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(minimal_generating_sets, m)?)?;
+    m.add_function(wrap_pyfunction!(minimum_generating_set, m)?)?;
+    m.add_function(wrap_pyfunction!(generating_rank, m)?)?;
+    m.add_function(wrap_pyfunction!(independent_sets, m)?)?;
+    m.add_function(wrap_pyfunction!(is_independent, m)?)?;
+    m.add_function(wrap_pyfunction!(is_independent_generating_set, m)?)?;
+    Ok(())
+}
+
+/// Find the minimal generating sets of an algebra with at most max_size
+/// elements: the generating sets none of whose elements is generated by the
+/// others. Constants are not counted as generators.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     max_size (int): The largest number of generators
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     List[List[int]]: The minimal generating sets, ordered by size
+///
+/// Raises:
+///     ValueError: If an operation cannot be evaluated or the computation
+///         was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, max_size, progress=None))]
+fn minimal_generating_sets(
+    py: Python<'_>,
+    algebra: &Bound<'_, PyAny>,
+    max_size: usize,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Vec<Vec<i32>>> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    let sets = with_progress(py, progress, || {
+        generating_sets::minimal_generating_sets(alg.as_ref(), max_size).map_err(PyValueError::new_err)
+    })?;
+    Ok(sets.into_iter().map(|s| s.elements().clone()).collect())
+}
+
+/// Find a generating set of an algebra of the least size, the
+/// lexicographically first one.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     List[int]: The generating set
+///
+/// Raises:
+///     ValueError: If an operation cannot be evaluated or the computation
+///         was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn minimum_generating_set(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<Vec<i32>> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    let set = with_progress(py, progress, || {
+        generating_sets::minimum_generating_set(alg.as_ref()).map_err(PyValueError::new_err)
+    })?;
+    Ok(set.elements().clone())
+}
+
+/// The least size of a generating set of an algebra, not counting constants.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     int: The generating rank
+///
+/// Raises:
+///     ValueError: If an operation cannot be evaluated or the computation
+///         was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn generating_rank(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<usize> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    with_progress(py, progress, || {
+        generating_sets::generating_rank(alg.as_ref()).map_err(PyValueError::new_err)
+    })
+}
+
+/// Find the independent subsets of an algebra with at most max_size
+/// elements: those none of whose elements is generated by the others.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     max_size (int): The largest size of the sets
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     List[List[int]]: The independent sets, starting with the empty set,
+///         ordered by size
+///
+/// Raises:
+///     ValueError: If an operation cannot be evaluated or the computation
+///         was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, max_size, progress=None))]
+fn independent_sets(
+    py: Python<'_>,
+    algebra: &Bound<'_, PyAny>,
+    max_size: usize,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Vec<Vec<i32>>> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    let sets = with_progress(py, progress, || {
+        generating_sets::independent_sets(alg.as_ref(), max_size).map_err(PyValueError::new_err)
+    })?;
+    Ok(sets.into_iter().map(|s| s.elements().clone()).collect())
+}
+
+/// Test whether no element of gens is in the subalgebra generated by the others.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     gens (List[int]): Distinct elements of the algebra
+///
+/// Raises:
+///     ValueError: If an element is repeated or out of range
+#[pyfunction]
+fn is_independent(py: Python<'_>, algebra: &Bound<'_, PyAny>, gens: Vec<i32>) -> PyResult<bool> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    py.allow_threads(|| generating_sets::is_independent(alg.as_ref(), &gens))
+        .map_err(PyValueError::new_err)
+}
+
+/// Test whether gens generates the algebra and is independent, that is,
+/// whether it is a minimal generating set.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     gens (List[int]): Distinct elements of the algebra
+///
+/// Raises:
+///     ValueError: If an element is repeated or out of range
+#[pyfunction]
+fn is_independent_generating_set(py: Python<'_>, algebra: &Bound<'_, PyAny>, gens: Vec<i32>) -> PyResult<bool> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    py.allow_threads(|| generating_sets::is_independent_generating_set(alg.as_ref(), &gens))
+        .map_err(PyValueError::new_err)
+}
@@ -0,0 +1,297 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use uacalc::alg::batch::{Analysis, AnalysisValue};
+use uacalc::alg::hereditary::{self, ClassMembers, Closure, HereditaryReport, HereditaryRow, Powers};
+use crate::alg::PyBasicAlgebra;
+use crate::eq::PyEquation;
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register the hereditary properties runner.
+pub fn register_hereditary_functions(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(check_hereditary, m)?)?;
+    m.add_function(wrap_pyfunction!(all_subalgebras, m)?)?;
+    m.add_function(wrap_pyfunction!(all_quotients, m)?)?;
+    m.add_function(wrap_pyfunction!(powers_up_to, m)?)?;
+    m.add_class::<PyClassMembers>()?;
+    m.add("ClassMembers", m.getattr("PyClassMembers")?)?;
+    m.add_class::<PyPowers>()?;
+    m.add("Powers", m.getattr("PyPowers")?)?;
+    m.add_class::<PyHereditaryProperty>()?;
+    m.add("HereditaryProperty", m.getattr("PyHereditaryProperty")?)?;
+    m.add_class::<PyClosureOperator>()?;
+    m.add("ClosureOperator", m.getattr("PyClosureOperator")?)?;
+    m.add_class::<PyHereditaryRow>()?;
+    m.add("HereditaryRow", m.getattr("PyHereditaryRow")?)?;
+    m.add_class::<PyHereditaryReport>()?;
+    m.add("HereditaryReport", m.getattr("PyHereditaryReport")?)?;
+    Ok(())
+}
+
+/// The built-in properties check_hereditary can evaluate.
+#[pyclass(module = "uacalc_lib.alg")]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+pub enum PyHereditaryProperty {
+    CON_CARDINALITY,
+    CON_DISTRIBUTIVE,
+    SIMPLE,
+    MALCEV_TERM,
+    MAJORITY_TERM,
+    CONGRUENCE_DISTRIBUTIVE,
+    CONGRUENCE_MODULAR,
+    TAYLOR_TERM,
+    JONSSON_LEVEL,
+}
+
+impl PyHereditaryProperty {
+    fn analysis(self) -> Analysis {
+        match self {
+            PyHereditaryProperty::CON_CARDINALITY => Analysis::ConCardinality,
+            PyHereditaryProperty::CON_DISTRIBUTIVE => Analysis::ConDistributive,
+            PyHereditaryProperty::SIMPLE => Analysis::Simple,
+            PyHereditaryProperty::MALCEV_TERM => Analysis::MalcevTerm,
+            PyHereditaryProperty::MAJORITY_TERM => Analysis::MajorityTerm,
+            PyHereditaryProperty::CONGRUENCE_DISTRIBUTIVE => Analysis::CongruenceDistributive,
+            PyHereditaryProperty::CONGRUENCE_MODULAR => Analysis::CongruenceModular,
+            PyHereditaryProperty::TAYLOR_TERM => Analysis::TaylorTerm,
+            PyHereditaryProperty::JONSSON_LEVEL => Analysis::JonssonLevel,
+        }
+    }
+}
+
+/// The class operators: subalgebras, quotients and bounded powers.
+#[pyclass(module = "uacalc_lib.alg")]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum PyClosureOperator {
+    S,
+    H,
+    P,
+}
+
+/// The result of the property on one member of the class.
+#[pyclass(module = "uacalc_lib.alg")]
+#[derive(Clone)]
+pub struct PyHereditaryRow {
+    inner: HereditaryRow,
+}
+
+#[pymethods]
+impl PyHereditaryRow {
+    /// The subuniverse, the congruence or the power, as a string.
+    #[getter]
+    fn member(&self) -> String {
+        self.inner.member.clone()
+    }
+
+    /// The number of elements of the member.
+    #[getter]
+    fn size(&self) -> usize {
+        self.inner.size
+    }
+
+    /// The value of the property: a bool or an int, or None if it failed.
+    #[getter]
+    fn value(&self, py: Python<'_>) -> PyObject {
+        match self.inner.value {
+            Ok(AnalysisValue::Bool(b)) => b.into_py(py),
+            Ok(AnalysisValue::Int(i)) => i.into_py(py),
+            Err(_) => py.None(),
+        }
+    }
+
+    /// The error the property gave on this member, or None.
+    #[getter]
+    fn error(&self) -> Option<String> {
+        self.inner.value.as_ref().err().cloned()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("HereditaryRow(member='{}', size={}, value={:?})", self.inner.member, self.inner.size, self.inner.value)
+    }
+}
+
+/// The table of results of check_hereditary.
+#[pyclass(module = "uacalc_lib.alg")]
+#[derive(Clone)]
+pub struct PyHereditaryReport {
+    inner: HereditaryReport,
+}
+
+#[pymethods]
+impl PyHereditaryReport {
+    /// The property, by name or as an equation string.
+    #[getter]
+    fn property(&self) -> String {
+        self.inner.analysis.to_string()
+    }
+
+    /// The class operator, "S", "H" or "P(k)".
+    #[getter]
+    fn closure(&self) -> String {
+        self.inner.closure.to_string()
+    }
+
+    /// One row per member.
+    #[getter]
+    fn rows(&self) -> Vec<PyHereditaryRow> {
+        self.inner.rows.iter().cloned().map(|inner| PyHereditaryRow { inner }).collect()
+    }
+
+    /// Whether a yes or no property held on every member; None if it
+    /// failed on a member or gives counts.
+    fn holds(&self) -> Option<bool> {
+        self.inner.holds()
+    }
+
+    /// The rows on which a yes or no property is false.
+    fn failures(&self) -> Vec<PyHereditaryRow> {
+        self.inner.failures().into_iter().cloned().map(|inner| PyHereditaryRow { inner }).collect()
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.rows.len()
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("HereditaryReport(property='{}', closure='{}', rows={})",
+            self.inner.analysis, self.inner.closure, self.inner.rows.len())
+    }
+}
+
+/// Evaluate a property on every subalgebra, every quotient or the powers
+/// A, ..., A^k of an algebra.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     property (HereditaryProperty | str | Equation): A built-in property,
+///         its name as for analyze_batch, or an equation
+///     closure (ClosureOperator): S, H or P
+///     max_power (int, optional): The largest exponent for P (default 2)
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     HereditaryReport: One row per subuniverse, congruence or power
+///
+/// Raises:
+///     ValueError: If the property name is unknown, max_power is 0, a power
+///         is too large or the computation was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, property, closure, max_power=2, progress=None))]
+fn check_hereditary(
+    py: Python<'_>,
+    algebra: &Bound<'_, PyAny>,
+    property: &Bound<'_, PyAny>,
+    closure: PyClosureOperator,
+    max_power: usize,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<PyHereditaryReport> {
+    let analysis = if let Ok(p) = property.extract::<PyHereditaryProperty>() {
+        p.analysis()
+    } else if let Ok(name) = property.extract::<String>() {
+        Analysis::from_name(&name).map_err(PyValueError::new_err)?
+    } else {
+        Analysis::Equation(property.extract::<PyRef<PyEquation>>()?.inner.clone())
+    };
+    let closure = match closure {
+        PyClosureOperator::S => Closure::S,
+        PyClosureOperator::H => Closure::H,
+        PyClosureOperator::P if max_power == 0 => return Err(PyValueError::new_err("max_power must be positive")),
+        PyClosureOperator::P => Closure::P(max_power),
+    };
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    let inner = with_progress(py, progress, || {
+        hereditary::check_hereditary(alg.as_ref(), &analysis, closure).map_err(PyValueError::new_err)
+    })?;
+    Ok(PyHereditaryReport { inner })
+}
+
+/// A lazy iterator of the subalgebras or quotients of an algebra, up to
+/// isomorphism.
+#[pyclass(module = "uacalc_lib.alg")]
+pub struct PyClassMembers {
+    inner: ClassMembers,
+}
+
+#[pymethods]
+impl PyClassMembers {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(&mut self) -> PyResult<Option<PyBasicAlgebra>> {
+        self.inner.next().transpose().map(|a| a.map(PyBasicAlgebra::from_inner)).map_err(PyValueError::new_err)
+    }
+}
+
+/// A lazy iterator of the powers A, A^2, ..., A^k of an algebra.
+#[pyclass(module = "uacalc_lib.alg")]
+pub struct PyPowers {
+    inner: Powers,
+}
+
+#[pymethods]
+impl PyPowers {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(&mut self) -> PyResult<Option<PyBasicAlgebra>> {
+        self.inner.next().transpose().map(|a| a.map(PyBasicAlgebra::from_inner)).map_err(PyValueError::new_err)
+    }
+}
+
+/// Enumerate the subalgebras of an algebra up to isomorphism, lazily.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///
+/// Returns:
+///     ClassMembers: An iterator of BasicAlgebra, named "<A>-S<i>"
+///
+/// Raises:
+///     ValueError: If the subuniverses could not be computed
+#[pyfunction]
+fn all_subalgebras(algebra: &Bound<'_, PyAny>) -> PyResult<PyClassMembers> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    hereditary::all_subalgebras(alg.as_ref())
+        .map(|inner| PyClassMembers { inner })
+        .map_err(PyValueError::new_err)
+}
+
+/// Enumerate the quotients of an algebra up to isomorphism, lazily.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///
+/// Returns:
+///     ClassMembers: An iterator of BasicAlgebra, named "<A>-H<i>"
+///
+/// Raises:
+///     ValueError: If the congruences could not be computed
+#[pyfunction]
+fn all_quotients(algebra: &Bound<'_, PyAny>) -> PyResult<PyClassMembers> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    hereditary::all_quotients(alg.as_ref())
+        .map(|inner| PyClassMembers { inner })
+        .map_err(PyValueError::new_err)
+}
+
+/// Enumerate the powers A, A^2, ..., A^k of an algebra, lazily.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     k (int): The largest exponent
+///
+/// Returns:
+///     Powers: An iterator of BasicAlgebra, named "<A>^<k>"; iteration
+///         raises ValueError if a power is too large
+#[pyfunction]
+fn powers_up_to(algebra: &Bound<'_, PyAny>, k: usize) -> PyResult<PyPowers> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    Ok(PyPowers { inner: hereditary::powers_up_to(alg.as_ref(), k) })
+}
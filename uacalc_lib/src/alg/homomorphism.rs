@@ -6,10 +6,13 @@ use pyo3::types::PyList;
 use pyo3::Bound;
 use crate::alg::PyBasicAlgebra;
 use crate::alg::conlat::partition::PyPartition;
+use crate::alg::subalgebra::PySubalgebra;
+use crate::alg::sub_product_algebra::PySubProductAlgebra;
 use crate::util::PyIntArray;
+use crate::progress::{with_progress, PyProgressReporter};
 
 /// Python wrapper for Homomorphism
-#[pyclass]
+#[pyclass(module = "uacalc_lib.alg")]
 #[derive(Clone)]
 pub struct PyHomomorphism {
     inner: uacalc::alg::Homomorphism,
@@ -59,6 +62,148 @@ impl PyHomomorphism {
         }
     }
 
+    /// Find a homomorphism between two algebras.
+    ///
+    /// Args:
+    ///     domain (BasicAlgebra): The domain algebra
+    ///     range (BasicAlgebra): The range algebra; must have every operation
+    ///         symbol of the domain
+    ///     backend (str): "ac" (default), "sac", "backtrack" or "sat" (with the sat feature)
+    ///     progress (ProgressReporter, optional): Progress and cancellation
+    ///
+    /// Returns:
+    ///     Optional[Homomorphism]: A homomorphism, or None if there is none
+    ///
+    /// Raises:
+    ///     ValueError: If an operation of the domain is missing from the range
+    #[staticmethod]
+    #[pyo3(signature = (domain, range, backend="ac", progress=None))]
+    fn find(
+        py: Python<'_>,
+        domain: &PyBasicAlgebra,
+        range: &PyBasicAlgebra,
+        backend: &str,
+        progress: Option<&PyProgressReporter>,
+    ) -> PyResult<Option<Self>> {
+        let backend = crate::alg::search::parse_backend(backend)?;
+        let images = with_progress(py, progress, || {
+            uacalc::alg::search::find_homomorphism(&domain.inner, &range.inner, backend).map_err(PyValueError::new_err)
+        })?;
+        images
+            .map(|images| {
+                uacalc::alg::Homomorphism::from_images(
+                    Box::new(domain.inner.clone()),
+                    Box::new(range.inner.clone()),
+                    &images,
+                )
+                .map(|inner| PyHomomorphism { inner })
+                .map_err(PyValueError::new_err)
+            })
+            .transpose()
+    }
+
+    /// The natural map from an algebra onto its quotient by a congruence.
+    ///
+    /// Args:
+    ///     algebra (BasicAlgebra): The algebra A
+    ///     theta (Partition): A congruence of A
+    ///
+    /// Returns:
+    ///     Homomorphism: The projection A -> A/theta, whose range has the
+    ///         blocks of theta as elements, ordered by least element
+    ///
+    /// Raises:
+    ///     ValueError: If theta is not a congruence of the algebra
+    #[staticmethod]
+    fn canonical_projection(algebra: &PyBasicAlgebra, theta: &PyPartition) -> PyResult<Self> {
+        uacalc::alg::Homomorphism::canonical_projection(Box::new(algebra.inner.clone()), &theta.inner)
+            .map(|inner| PyHomomorphism { inner })
+            .map_err(PyValueError::new_err)
+    }
+
+    /// The inclusion of a subalgebra into its super algebra.
+    ///
+    /// Args:
+    ///     subalgebra (Subalgebra): The subalgebra
+    ///
+    /// Returns:
+    ///     Homomorphism: The embedding
+    #[staticmethod]
+    fn inclusion(subalgebra: &PySubalgebra) -> PyResult<Self> {
+        uacalc::alg::Homomorphism::inclusion(&subalgebra.inner)
+            .map(|inner| PyHomomorphism { inner })
+            .map_err(PyValueError::new_err)
+    }
+
+    /// The isomorphism A/ker(h) -> h(A) given by the first isomorphism theorem.
+    ///
+    /// Args:
+    ///     h (Homomorphism): A homomorphism A -> B
+    ///
+    /// Returns:
+    ///     Homomorphism: The isomorphism from the quotient of the domain by
+    ///         the kernel onto the image subalgebra of the range
+    ///
+    /// Raises:
+    ///     ValueError: If h does not respect the operations
+    #[staticmethod]
+    fn first_isomorphism_theorem(h: &PyHomomorphism) -> PyResult<Self> {
+        uacalc::alg::Homomorphism::first_isomorphism_theorem(&h.inner)
+            .map(|inner| PyHomomorphism { inner })
+            .map_err(PyValueError::new_err)
+    }
+
+    /// Check if distinct domain elements have distinct images.
+    fn is_injective(&self) -> bool {
+        self.inner.is_injective()
+    }
+
+    /// Check if every range element is an image.
+    fn is_surjective(&self) -> bool {
+        self.inner.is_surjective()
+    }
+
+    /// Get the range elements hit by the map, in increasing order.
+    ///
+    /// Returns:
+    ///     list[int]: The image
+    fn image(&self) -> Vec<i32> {
+        self.inner.image()
+    }
+
+    /// Get the image of the domain as a subalgebra of the range.
+    ///
+    /// Returns:
+    ///     Subalgebra: The subalgebra of the range on the image
+    ///
+    /// Raises:
+    ///     ValueError: If the domain is empty
+    fn image_subalgebra(&self) -> PyResult<PySubalgebra> {
+        self.inner.image_subalgebra()
+            .map(|inner| PySubalgebra { inner })
+            .map_err(PyValueError::new_err)
+    }
+
+    /// Compose this homomorphism with another applied after it.
+    ///
+    /// The result maps x to other(self(x)).
+    ///
+    /// Args:
+    ///     other (Homomorphism): The homomorphism applied second; its domain
+    ///         must be the range of this one
+    ///
+    /// Returns:
+    ///     Homomorphism: The composite
+    ///
+    /// Raises:
+    ///     ValueError: If the range of this homomorphism and the domain of
+    ///         the other have different sizes
+    fn compose(&self, other: &PyHomomorphism) -> PyResult<PyHomomorphism> {
+        self.inner.compose(&other.inner)
+            .map(|inner| PyHomomorphism { inner })
+            .map_err(PyValueError::new_err)
+    }
+
     /// Create a product homomorphism from a list of homomorphisms.
     ///
     /// This static method creates a list of IntArray elements representing
@@ -167,6 +312,14 @@ impl PyHomomorphism {
         self.inner.set_map(map);
     }
 
+    /// Pickle support: the homomorphism is rebuilt from its domain, range
+    /// and map.
+    #[allow(clippy::type_complexity)]
+    fn __reduce__(slf: &Bound<'_, Self>) -> (PyObject, (PyBasicAlgebra, PyBasicAlgebra, std::collections::HashMap<usize, usize>)) {
+        let hom = slf.borrow();
+        (slf.get_type().into_any().unbind(), (hom.get_domain(), hom.get_range(), hom.get_map()))
+    }
+
     /// Python string representation
     fn __str__(&self) -> String {
         self.inner.to_string()
@@ -194,4 +347,92 @@ impl PyHomomorphism {
     pub fn from_inner(inner: uacalc::alg::Homomorphism) -> Self {
         PyHomomorphism { inner }
     }
-}
\ No newline at end of file
+}
+/// Construct the image of the map into the product of the ranges induced by
+/// homomorphisms with a common domain, checking that it is a subdirect
+/// product.
+///
+/// Args:
+///     homomorphisms (list[Homomorphism]): Homomorphisms with the same domain
+///
+/// Returns:
+///     SubProductAlgebra: The image, whose elements are the tuples of images
+///
+/// Raises:
+///     ValueError: If the list is empty, the domains differ, a map is not a
+///         homomorphism or one is not onto its range
+#[pyfunction]
+pub(crate) fn create_subdirect_product(py: Python<'_>, homomorphisms: Vec<PyHomomorphism>) -> PyResult<PySubProductAlgebra> {
+    let homos: Vec<uacalc::alg::Homomorphism> = homomorphisms.into_iter().map(|h| h.inner).collect();
+    py.allow_threads(|| uacalc::alg::Homomorphism::subdirect_product(&homos))
+        .map(|inner| PySubProductAlgebra { inner })
+        .map_err(PyValueError::new_err)
+}
+
+/// Python wrapper for FiberProduct: the pullback {(a, b) : f(a) = g(b)} of
+/// two homomorphisms with its projections.
+#[pyclass]
+pub struct PyFiberProduct {
+    inner: uacalc::alg::FiberProduct,
+}
+
+#[pymethods]
+impl PyFiberProduct {
+    /// The fiber product as a subalgebra of A x B.
+    ///
+    /// Returns:
+    ///     SubProductAlgebra: The fiber product
+    fn algebra(&self) -> PySubProductAlgebra {
+        PySubProductAlgebra { inner: self.inner.algebra().clone() }
+    }
+
+    /// The projection onto A, its domain the fiber product on the indices of
+    /// its elements.
+    ///
+    /// Returns:
+    ///     Homomorphism: The first projection
+    fn first_projection(&self) -> PyHomomorphism {
+        PyHomomorphism { inner: self.inner.first_projection().clone() }
+    }
+
+    /// The projection onto B, its domain the fiber product on the indices of
+    /// its elements.
+    ///
+    /// Returns:
+    ///     Homomorphism: The second projection
+    fn second_projection(&self) -> PyHomomorphism {
+        PyHomomorphism { inner: self.inner.second_projection().clone() }
+    }
+
+    /// The elements as pairs (a, b), in the order of their indices.
+    ///
+    /// Returns:
+    ///     List[Tuple[int, int]]: The pairs
+    fn pairs(&self) -> Vec<(i32, i32)> {
+        self.inner.pairs()
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.pairs().len()
+    }
+}
+
+/// Construct the fiber product {(a, b) : f(a) = g(b)} of f: A -> C and
+/// g: B -> C as a subalgebra of A x B, with its two projections.
+///
+/// Args:
+///     f (Homomorphism): A homomorphism A -> C
+///     g (Homomorphism): A homomorphism B -> C
+///
+/// Returns:
+///     FiberProduct: The fiber product and its projections
+///
+/// Raises:
+///     ValueError: If the ranges differ in size, no pair agrees or the maps
+///         are not homomorphisms
+#[pyfunction]
+pub(crate) fn fiber_product(py: Python<'_>, f: &PyHomomorphism, g: &PyHomomorphism) -> PyResult<PyFiberProduct> {
+    py.allow_threads(|| uacalc::alg::fiber_product(&f.inner, &g.inner))
+        .map(|inner| PyFiberProduct { inner })
+        .map_err(PyValueError::new_err)
+}
@@ -0,0 +1,91 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use uacalc::alg::interpretation::{self, PpInterpretation};
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register the pp-interpretation search.
+pub fn register_interpretation_functions(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(pp_interprets, m)?)?;
+    m.add_class::<PyPpInterpretation>()?;
+    m.add("PpInterpretation", m.getattr("PyPpInterpretation")?)?;
+    Ok(())
+}
+
+/// A pp-interpretation of B in A, given by a subuniverse of A^d and a map
+/// from it onto B.
+#[pyclass(module = "uacalc_lib.alg")]
+#[derive(Clone)]
+pub struct PyPpInterpretation {
+    inner: PpInterpretation,
+}
+
+#[pymethods]
+impl PyPpInterpretation {
+    /// The dimension d.
+    #[getter]
+    fn dimension(&self) -> usize {
+        self.inner.dimension
+    }
+
+    /// The domain, a subuniverse of A^d, in lexicographic order.
+    #[getter]
+    fn domain(&self) -> Vec<Vec<i32>> {
+        self.inner.domain.clone()
+    }
+
+    /// The element of B each tuple of the domain stands for.
+    #[getter]
+    fn coordinates(&self) -> Vec<i32> {
+        self.inner.coordinates.clone()
+    }
+
+    /// The element of B a tuple stands for, or None if it is not in the domain.
+    fn coordinate(&self, tuple: Vec<i32>) -> Option<i32> {
+        self.inner.coordinate(&tuple)
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("PpInterpretation(dimension={}, domain_size={})", self.inner.dimension, self.inner.domain.len())
+    }
+}
+
+/// Search for a primitive positive interpretation of B in A.
+///
+/// Algebras stand for their invariant relations. The search tries each
+/// dimension d up to the bound, each subuniverse D of A^d and each
+/// congruence of D with |B| blocks, looking for a bijection to B that
+/// carries the operations of the quotient to term operations of B.
+///
+/// Args:
+///     a: The interpreting algebra (BasicAlgebra, Subalgebra, ...)
+///     b: The interpreted algebra
+///     dimension_bound (int, optional): The largest dimension tried (default 2)
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     Optional[PpInterpretation]: The coordinatization, or None if there is
+///         no interpretation of dimension at most the bound
+///
+/// Raises:
+///     ValueError: If B is empty, a power is too large or the computation
+///         was cancelled
+#[pyfunction]
+#[pyo3(signature = (a, b, dimension_bound=2, progress=None))]
+fn pp_interprets(
+    py: Python<'_>,
+    a: &Bound<'_, PyAny>,
+    b: &Bound<'_, PyAny>,
+    dimension_bound: usize,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Option<PyPpInterpretation>> {
+    let a = crate::alg::small_algebra_from_py(a)?;
+    let b = crate::alg::small_algebra_from_py(b)?;
+    let found = with_progress(py, progress, || {
+        interpretation::pp_interprets(a.as_ref(), b.as_ref(), dimension_bound).map_err(PyValueError::new_err)
+    })?;
+    Ok(found.map(|inner| PyPpInterpretation { inner }))
+}
@@ -6,7 +6,11 @@
 use pyo3::prelude::*;
 use pyo3::exceptions::PyValueError;
 use crate::alg::PyBasicAlgebra;
+use crate::eq::PyEquation;
+use crate::progress::{with_progress, PyProgressReporter};
 use uacalc::alg::malcev;
+use uacalc::io::json::{MalcevReport, ToJson};
+use uacalc::io::report::ToLatex;
 
 /// Python module for Malcev functions.
 ///
@@ -33,6 +37,12 @@ pub fn register_malcev_functions(_py: Python, m: &Bound<'_, PyModule>) -> PyResu
     m.add_function(wrap_pyfunction!(sd_meet_terms, m)?)?;
     m.add_function(wrap_pyfunction!(sd_terms, m)?)?;
     m.add_function(wrap_pyfunction!(markovic_mckenzie_siggers_taylor_term, m)?)?;
+    m.add_function(wrap_pyfunction!(has_taylor_term, m)?)?;
+    m.add_class::<PyTaylorAnalysis>()?;
+    m.add("TaylorAnalysis", m.getattr("PyTaylorAnalysis")?)?;
+    m.add_function(wrap_pyfunction!(malcev_analysis, m)?)?;
+    m.add_class::<PyMalcevAnalysis>()?;
+    m.add("MalcevAnalysis", m.getattr("PyMalcevAnalysis")?)?;
     m.add_function(wrap_pyfunction!(weak_3_edge_term, m)?)?;
     m.add_function(wrap_pyfunction!(is_congruence_dist_idempotent, m)?)?;
     m.add_function(wrap_pyfunction!(is_congruence_modular_idempotent, m)?)?;
@@ -42,7 +52,15 @@ pub fn register_malcev_functions(_py: Python, m: &Bound<'_, PyModule>) -> PyResu
     m.add_function(wrap_pyfunction!(day_quadruple, m)?)?;
     m.add_function(wrap_pyfunction!(find_day_quadruple_in_square, m)?)?;
     m.add_function(wrap_pyfunction!(sd_meet_idempotent, m)?)?;
+    m.add_function(wrap_pyfunction!(variety_is_sd_meet, m)?)?;
+    m.add_function(wrap_pyfunction!(omitted_types, m)?)?;
+    m.add_class::<PyTypeOmission>()?;
+    m.add("TypeOmission", m.getattr("PyTypeOmission")?)?;
+    m.add_class::<PySdMeetAnalysis>()?;
+    m.add("SdMeetAnalysis", m.getattr("PySdMeetAnalysis")?)?;
     m.add_function(wrap_pyfunction!(cyclic_term_idempotent, m)?)?;
+    m.add_function(wrap_pyfunction!(has_cyclic_term, m)?)?;
+    m.add_function(wrap_pyfunction!(find_cyclic_term, m)?)?;
     m.add_function(wrap_pyfunction!(primality_terms, m)?)?;
     m.add_function(wrap_pyfunction!(fixed_k_edge_term, m)?)?;
     m.add_function(wrap_pyfunction!(fixed_k_qwnu, m)?)?;
@@ -54,64 +72,80 @@ pub fn register_malcev_functions(_py: Python, m: &Bound<'_, PyModule>) -> PyResu
 ///
 /// # Arguments
 /// * `algebra` - The algebra to check (BasicAlgebra)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// The Malcev term as a string if one exists, None otherwise
 #[pyfunction]
-fn malcev_term(algebra: &PyBasicAlgebra) -> PyResult<Option<String>> {
-    match malcev::malcev_term(&algebra.inner) {
-        Ok(Some(term)) => Ok(Some(format!("{}", term))),
-        Ok(None) => Ok(None),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, progress=None))]
+fn malcev_term(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+    with_progress(py, progress, || {
+        match malcev::malcev_term(&algebra.inner) {
+            Ok(Some(term)) => Ok(Some(format!("{}", term))),
+            Ok(None) => Ok(None),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Find a majority term for the algebra.
 ///
 /// # Arguments
 /// * `algebra` - The algebra to check (BasicAlgebra)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// The majority term as a string if one exists, None otherwise
 #[pyfunction]
-fn majority_term(algebra: &PyBasicAlgebra) -> PyResult<Option<String>> {
-    match malcev::majority_term(&algebra.inner) {
-        Ok(Some(term)) => Ok(Some(format!("{}", term))),
-        Ok(None) => Ok(None),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, progress=None))]
+fn majority_term(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+    with_progress(py, progress, || {
+        match malcev::majority_term(&algebra.inner) {
+            Ok(Some(term)) => Ok(Some(format!("{}", term))),
+            Ok(None) => Ok(None),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Find a minority term for the algebra.
 ///
 /// # Arguments
 /// * `algebra` - The algebra to check (BasicAlgebra)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// The minority term as a string if one exists, None otherwise
 #[pyfunction]
-fn minority_term(algebra: &PyBasicAlgebra) -> PyResult<Option<String>> {
-    match malcev::minority_term(&algebra.inner) {
-        Ok(Some(term)) => Ok(Some(format!("{}", term))),
-        Ok(None) => Ok(None),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, progress=None))]
+fn minority_term(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+    with_progress(py, progress, || {
+        match malcev::minority_term(&algebra.inner) {
+            Ok(Some(term)) => Ok(Some(format!("{}", term))),
+            Ok(None) => Ok(None),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Find a Pixley term for the algebra.
 ///
 /// # Arguments
 /// * `algebra` - The algebra to check (BasicAlgebra)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// The Pixley term as a string if one exists, None otherwise
 #[pyfunction]
-fn pixley_term(algebra: &PyBasicAlgebra) -> PyResult<Option<String>> {
-    match malcev::pixley_term(&algebra.inner) {
-        Ok(Some(term)) => Ok(Some(format!("{}", term))),
-        Ok(None) => Ok(None),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, progress=None))]
+fn pixley_term(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+    with_progress(py, progress, || {
+        match malcev::pixley_term(&algebra.inner) {
+            Ok(Some(term)) => Ok(Some(format!("{}", term))),
+            Ok(None) => Ok(None),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Find a near unanimity term of the given arity.
@@ -119,16 +153,20 @@ fn pixley_term(algebra: &PyBasicAlgebra) -> PyResult<Option<String>> {
 /// # Arguments
 /// * `algebra` - The algebra to check (BasicAlgebra)
 /// * `arity` - The arity of the NU term
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// The NU term as a string if one exists, None otherwise
 #[pyfunction]
-fn nu_term(algebra: &PyBasicAlgebra, arity: usize) -> PyResult<Option<String>> {
-    match malcev::nu_term(&algebra.inner, arity) {
-        Ok(Some(term)) => Ok(Some(format!("{}", term))),
-        Ok(None) => Ok(None),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, arity, progress=None))]
+fn nu_term(py: Python<'_>, algebra: &PyBasicAlgebra, arity: usize, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+    with_progress(py, progress, || {
+        match malcev::nu_term(&algebra.inner, arity) {
+            Ok(Some(term)) => Ok(Some(format!("{}", term))),
+            Ok(None) => Ok(None),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Test if an idempotent algebra has an NU term of the given arity.
@@ -136,15 +174,19 @@ fn nu_term(algebra: &PyBasicAlgebra, arity: usize) -> PyResult<Option<String>> {
 /// # Arguments
 /// * `algebra` - The idempotent algebra to check
 /// * `arity` - The arity of the NU term
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// True if the algebra has an NU term, False otherwise
 #[pyfunction]
-fn nu_term_idempotent(algebra: &PyBasicAlgebra, arity: usize) -> PyResult<bool> {
-    match malcev::nu_term_idempotent(&algebra.inner, arity) {
-        Ok(result) => Ok(result),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, arity, progress=None))]
+fn nu_term_idempotent(py: Python<'_>, algebra: &PyBasicAlgebra, arity: usize, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+    with_progress(py, progress, || {
+        match malcev::nu_term_idempotent(&algebra.inner, arity) {
+            Ok(result) => Ok(result),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Find a weak near unanimity term of the given arity.
@@ -152,267 +194,494 @@ fn nu_term_idempotent(algebra: &PyBasicAlgebra, arity: usize) -> PyResult<bool>
 /// # Arguments
 /// * `algebra` - The algebra to check
 /// * `arity` - The arity of the weak NU term
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// The weak NU term if one exists, None otherwise
 #[pyfunction]
-fn weak_nu_term(algebra: &PyBasicAlgebra, arity: usize) -> PyResult<Option<String>> {
-    match malcev::weak_nu_term(&algebra.inner, arity) {
-        Ok(Some(term)) => Ok(Some(format!("{}", term))),
-        Ok(None) => Ok(None),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, arity, progress=None))]
+fn weak_nu_term(py: Python<'_>, algebra: &PyBasicAlgebra, arity: usize, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+    with_progress(py, progress, || {
+        match malcev::weak_nu_term(&algebra.inner, arity) {
+            Ok(Some(term)) => Ok(Some(format!("{}", term))),
+            Ok(None) => Ok(None),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Find a weak majority term for the algebra.
 ///
 /// # Arguments
 /// * `algebra` - The algebra to check (BasicAlgebra)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// The weak majority term as a string if one exists, None otherwise
 #[pyfunction]
-fn weak_majority_term(algebra: &PyBasicAlgebra) -> PyResult<Option<String>> {
-    match malcev::weak_majority_term(&algebra.inner) {
-        Ok(Some(term)) => Ok(Some(format!("{}", term))),
-        Ok(None) => Ok(None),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, progress=None))]
+fn weak_majority_term(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+    with_progress(py, progress, || {
+        match malcev::weak_majority_term(&algebra.inner) {
+            Ok(Some(term)) => Ok(Some(format!("{}", term))),
+            Ok(None) => Ok(None),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Find a semilattice term for the algebra.
 ///
 /// # Arguments
 /// * `algebra` - The algebra to check (BasicAlgebra)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// The semilattice term as a string if one exists, None otherwise
 #[pyfunction]
-fn semilattice_term(algebra: &PyBasicAlgebra) -> PyResult<Option<String>> {
-    match malcev::semilattice_term(&algebra.inner) {
-        Ok(Some(term)) => Ok(Some(format!("{}", term))),
-        Ok(None) => Ok(None),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, progress=None))]
+fn semilattice_term(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+    with_progress(py, progress, || {
+        match malcev::semilattice_term(&algebra.inner) {
+            Ok(Some(term)) => Ok(Some(format!("{}", term))),
+            Ok(None) => Ok(None),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Find a difference term for the algebra.
 ///
 /// # Arguments
 /// * `algebra` - The algebra to check
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// The difference term if one exists, None otherwise
 #[pyfunction]
-fn difference_term(algebra: &PyBasicAlgebra) -> PyResult<Option<String>> {
-    match malcev::difference_term(&algebra.inner) {
-        Ok(Some(term)) => Ok(Some(format!("{}", term))),
-        Ok(None) => Ok(None),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, progress=None))]
+fn difference_term(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+    with_progress(py, progress, || {
+        match malcev::difference_term(&algebra.inner) {
+            Ok(Some(term)) => Ok(Some(format!("{}", term))),
+            Ok(None) => Ok(None),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Find Jonsson terms for the algebra.
 ///
 /// # Arguments
 /// * `algebra` - The algebra to check (BasicAlgebra)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// List of Jonsson terms as strings if they exist, None otherwise
 #[pyfunction]
-fn jonsson_terms(algebra: &PyBasicAlgebra) -> PyResult<Option<Vec<String>>> {
-    match malcev::jonsson_terms(&algebra.inner) {
-        Ok(Some(terms)) => {
-            let term_strings: Vec<String> = terms.iter().map(|t| format!("{}", t)).collect();
-            Ok(Some(term_strings))
-        },
-        Ok(None) => Ok(None),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, progress=None))]
+fn jonsson_terms(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<Option<Vec<String>>> {
+    with_progress(py, progress, || {
+        match malcev::jonsson_terms(&algebra.inner) {
+            Ok(Some(terms)) => {
+                let term_strings: Vec<String> = terms.iter().map(|t| format!("{}", t)).collect();
+                Ok(Some(term_strings))
+            },
+            Ok(None) => Ok(None),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Find Hagemann-Mitschke terms for the algebra.
 ///
 /// # Arguments
 /// * `algebra` - The algebra to check (BasicAlgebra)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// List of Hagemann-Mitschke terms as strings if they exist, None otherwise
 #[pyfunction]
-fn hagemann_mitschke_terms(algebra: &PyBasicAlgebra) -> PyResult<Option<Vec<String>>> {
-    match malcev::hagemann_mitschke_terms(&algebra.inner) {
-        Ok(Some(terms)) => {
-            let term_strings: Vec<String> = terms.iter().map(|t| format!("{}", t)).collect();
-            Ok(Some(term_strings))
-        },
-        Ok(None) => Ok(None),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, progress=None))]
+fn hagemann_mitschke_terms(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<Option<Vec<String>>> {
+    with_progress(py, progress, || {
+        match malcev::hagemann_mitschke_terms(&algebra.inner) {
+            Ok(Some(terms)) => {
+                let term_strings: Vec<String> = terms.iter().map(|t| format!("{}", t)).collect();
+                Ok(Some(term_strings))
+            },
+            Ok(None) => Ok(None),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Find Gumm terms for the algebra.
 ///
 /// # Arguments
 /// * `algebra` - The algebra to check
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// List of Gumm terms if they exist, None otherwise
 #[pyfunction]
-fn gumm_terms(algebra: &PyBasicAlgebra) -> PyResult<Option<Vec<String>>> {
-    match malcev::gumm_terms(&algebra.inner) {
-        Ok(Some(terms)) => {
-            let term_strings: Vec<String> = terms.iter().map(|t| format!("{}", t)).collect();
-            Ok(Some(term_strings))
-        },
-        Ok(None) => Ok(None),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, progress=None))]
+fn gumm_terms(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<Option<Vec<String>>> {
+    with_progress(py, progress, || {
+        match malcev::gumm_terms(&algebra.inner) {
+            Ok(Some(terms)) => {
+                let term_strings: Vec<String> = terms.iter().map(|t| format!("{}", t)).collect();
+                Ok(Some(term_strings))
+            },
+            Ok(None) => Ok(None),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Get a join term (Kearnes-Kiss) for the algebra.
 ///
 /// # Arguments
 /// * `algebra` - The algebra to check (BasicAlgebra)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// The join term as a string if one exists, None otherwise
 #[pyfunction]
-fn join_term(algebra: &PyBasicAlgebra) -> PyResult<Option<String>> {
-    match malcev::join_term(&algebra.inner) {
-        Ok(Some(term)) => Ok(Some(format!("{}", term))),
-        Ok(None) => Ok(None),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, progress=None))]
+fn join_term(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+    with_progress(py, progress, || {
+        match malcev::join_term(&algebra.inner) {
+            Ok(Some(term)) => Ok(Some(format!("{}", term))),
+            Ok(None) => Ok(None),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Find SD-meet terms for the algebra.
 ///
 /// # Arguments
 /// * `algebra` - The algebra to check
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// List of SD-meet terms if they exist, None otherwise
 #[pyfunction]
-fn sd_meet_terms(algebra: &PyBasicAlgebra) -> PyResult<Option<Vec<String>>> {
-    match malcev::sd_meet_terms(&algebra.inner) {
-        Ok(Some(terms)) => {
-            let term_strings: Vec<String> = terms.iter().map(|t| format!("{}", t)).collect();
-            Ok(Some(term_strings))
-        },
-        Ok(None) => Ok(None),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, progress=None))]
+fn sd_meet_terms(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<Option<Vec<String>>> {
+    with_progress(py, progress, || {
+        match malcev::sd_meet_terms(&algebra.inner) {
+            Ok(Some(terms)) => {
+                let term_strings: Vec<String> = terms.iter().map(|t| format!("{}", t)).collect();
+                Ok(Some(term_strings))
+            },
+            Ok(None) => Ok(None),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Find SD terms for the algebra.
 ///
 /// # Arguments
 /// * `algebra` - The algebra to check (BasicAlgebra)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// List of SD terms as strings if they exist, None otherwise
 #[pyfunction]
-fn sd_terms(algebra: &PyBasicAlgebra) -> PyResult<Option<Vec<String>>> {
-    match malcev::sd_terms(&algebra.inner) {
-        Ok(Some(terms)) => {
-            let term_strings: Vec<String> = terms.iter().map(|t| format!("{}", t)).collect();
-            Ok(Some(term_strings))
-        },
-        Ok(None) => Ok(None),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, progress=None))]
+fn sd_terms(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<Option<Vec<String>>> {
+    with_progress(py, progress, || {
+        match malcev::sd_terms(&algebra.inner) {
+            Ok(Some(terms)) => {
+                let term_strings: Vec<String> = terms.iter().map(|t| format!("{}", t)).collect();
+                Ok(Some(term_strings))
+            },
+            Ok(None) => Ok(None),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Find the Markovic-McKenzie-Siggers-Taylor term for the algebra.
 ///
 /// # Arguments
 /// * `algebra` - The algebra to check (BasicAlgebra)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// The MMST term as a string if one exists, None otherwise
 #[pyfunction]
-fn markovic_mckenzie_siggers_taylor_term(algebra: &PyBasicAlgebra) -> PyResult<Option<String>> {
-    match malcev::markovic_mckenzie_siggers_taylor_term(&algebra.inner) {
-        Ok(Some(term)) => Ok(Some(format!("{}", term))),
-        Ok(None) => Ok(None),
-        Err(e) => Err(PyValueError::new_err(e)),
+#[pyo3(signature = (algebra, progress=None))]
+fn markovic_mckenzie_siggers_taylor_term(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+    with_progress(py, progress, || {
+        match malcev::markovic_mckenzie_siggers_taylor_term(&algebra.inner) {
+            Ok(Some(term)) => Ok(Some(format!("{}", term))),
+            Ok(None) => Ok(None),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
+}
+
+/// Whether an algebra has a Taylor term, with the witnessing term and the
+/// identities it satisfies.
+#[pyclass]
+pub struct PyTaylorAnalysis {
+    inner: malcev::TaylorAnalysis,
+}
+
+#[pymethods]
+impl PyTaylorAnalysis {
+    /// Whether the algebra has a Taylor term.
+    fn has_taylor_term(&self) -> bool {
+        self.inner.has_taylor_term()
+    }
+
+    /// The 4-ary Markovic-McKenzie-Siggers term t(x0,x1,x2,x3) as a string,
+    /// or None if there is no Taylor term.
+    fn term(&self) -> Option<String> {
+        self.inner.term.as_ref().map(|t| t.to_string())
+    }
+
+    /// The identities t(y,x,x,x) = t(x,x,y,y), t(x,x,y,x) = t(x,y,x,x) and
+    /// t(x,x,x,x) = x satisfied by the term; empty if there is none.
+    fn identities(&self) -> Vec<PyEquation> {
+        self.inner.identities.iter().map(|eq| PyEquation { inner: eq.clone() }).collect()
+    }
+
+    fn __bool__(&self) -> bool {
+        self.inner.has_taylor_term()
+    }
+
+    fn __repr__(&self) -> String {
+        match &self.inner.term {
+            Some(t) => format!("TaylorAnalysis(term={})", t),
+            None => "TaylorAnalysis(term=None)".to_string(),
+        }
+    }
+}
+
+/// Decide whether a finite algebra has a Taylor term.
+///
+/// The witness is an idempotent 4-ary term t with t(y,x,x,x) = t(x,x,y,y)
+/// and t(x,x,y,x) = t(x,y,x,x), which exists exactly when the algebra has a
+/// Taylor term.
+///
+/// # Arguments
+/// * `algebra` - The algebra to check (BasicAlgebra)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
+///
+/// # Returns
+/// A TaylorAnalysis with the witnessing term and identities
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn has_taylor_term(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<PyTaylorAnalysis> {
+    with_progress(py, progress, || {
+        malcev::has_taylor_term(&algebra.inner)
+            .map(|inner| PyTaylorAnalysis { inner })
+            .map_err(PyValueError::new_err)
+    })
+}
+
+/// The Mal'cev condition terms found for an algebra, by kind.
+#[pyclass]
+pub struct PyMalcevAnalysis {
+    inner: MalcevReport,
+}
+
+#[pymethods]
+impl PyMalcevAnalysis {
+    /// The name of the algebra.
+    fn algebra(&self) -> String {
+        self.inner.algebra.clone()
+    }
+
+    /// The size of the algebra.
+    fn size(&self) -> usize {
+        self.inner.size
+    }
+
+    /// For each kind searched for, the terms found as strings, or None if
+    /// there are none.
+    fn terms(&self) -> std::collections::BTreeMap<String, Option<Vec<String>>> {
+        self.inner.terms.clone()
+    }
+
+    /// Properties decided alongside the terms; searching for "sd-meet"
+    /// records "variety_sd_meet", "con_sd_meet" and "con_sd_join",
+    /// "pixley" records "arithmetical" and "discriminator" records
+    /// "discriminator_variety".
+    fn properties(&self) -> std::collections::BTreeMap<String, bool> {
+        self.inner.properties.clone()
+    }
+
+    /// The sets of TCT types the variety omits, as far as the terms
+    /// searched for decide; empty if none of "taylor", "sd-meet", "sd",
+    /// "gumm" and "hagemann-mitschke" was searched.
+    fn omitted_types(&self) -> Vec<PyTypeOmission> {
+        self.inner.omitted_types.iter().cloned().map(|inner| PyTypeOmission { inner }).collect()
+    }
+
+    /// The versioned JSON representation (kind "malcev_report").
+    fn to_json(&self) -> String {
+        self.inner.to_json_str()
+    }
+
+    /// The terms as a LaTeX `tabular`, one row per kind.
+    fn to_latex(&self) -> String {
+        self.inner.to_latex()
+    }
+
+    fn __repr__(&self) -> String {
+        let found: Vec<&str> = self.inner.terms.iter()
+            .filter(|(_, terms)| terms.is_some())
+            .map(|(kind, _)| kind.as_str())
+            .collect();
+        let holding: Vec<&str> = self.inner.properties.iter()
+            .filter(|(_, &holds)| holds)
+            .map(|(property, _)| property.as_str())
+            .collect();
+        let omits: Vec<String> = self.inner.omitted_types.iter()
+            .filter(|o| o.omitted == Some(true))
+            .map(|o| format!("{{{}}}", o.types.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(",")))
+            .collect();
+        let mut repr = format!("MalcevAnalysis(algebra='{}', found={:?}", self.inner.algebra, found);
+        if !holding.is_empty() {
+            repr.push_str(&format!(", properties={:?}", holding));
+        }
+        if !omits.is_empty() {
+            repr.push_str(&format!(", omits=[{}]", omits.join(", ")));
+        }
+        repr.push(')');
+        repr
     }
 }
 
+/// Search an algebra for Mal'cev condition terms.
+///
+/// # Arguments
+/// * `algebra` - The algebra to check (BasicAlgebra)
+/// * `kinds` - The kinds of terms to look for, for example "malcev" or
+///   "jonsson"; all kinds by default
+/// * `nu_arity` - The arity of the near unanimity term for "nu"
+/// * `progress` - Optional ProgressReporter for progress and cancellation
+///
+/// # Returns
+/// A MalcevAnalysis with the terms found for each kind
+#[pyfunction]
+#[pyo3(signature = (algebra, kinds=None, nu_arity=3, progress=None))]
+fn malcev_analysis(
+    py: Python<'_>,
+    algebra: &PyBasicAlgebra,
+    kinds: Option<Vec<String>>,
+    nu_arity: usize,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<PyMalcevAnalysis> {
+    let kinds: Vec<String> = kinds.unwrap_or_else(|| MalcevReport::KINDS.iter().map(|k| k.to_string()).collect());
+    let kinds: Vec<&str> = kinds.iter().map(|k| k.as_str()).collect();
+    with_progress(py, progress, || {
+        MalcevReport::analyze(&algebra.inner, &kinds, nu_arity)
+            .map(|inner| PyMalcevAnalysis { inner })
+            .map_err(PyValueError::new_err)
+    })
+}
+
 /// Find a weak 3-edge term for the algebra.
 ///
 /// # Arguments
 /// * `algebra` - The algebra to check
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// The weak 3-edge term if one exists, None otherwise
 #[pyfunction]
-fn weak_3_edge_term(algebra: &PyBasicAlgebra) -> PyResult<Option<String>> {
-    match malcev::weak_3_edge_term(&algebra.inner) {
-        Ok(Some(term)) => Ok(Some(format!("{}", term))),
-        Ok(None) => Ok(None),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, progress=None))]
+fn weak_3_edge_term(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+    with_progress(py, progress, || {
+        match malcev::weak_3_edge_term(&algebra.inner) {
+            Ok(Some(term)) => Ok(Some(format!("{}", term))),
+            Ok(None) => Ok(None),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Test if an idempotent algebra is congruence distributive.
 ///
 /// # Arguments
 /// * `algebra` - The idempotent algebra to check
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// True if the algebra is congruence distributive, False otherwise
 #[pyfunction]
-fn is_congruence_dist_idempotent(algebra: &PyBasicAlgebra) -> PyResult<bool> {
-    match malcev::is_congruence_dist_idempotent(&algebra.inner) {
-        Ok(result) => Ok(result),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, progress=None))]
+fn is_congruence_dist_idempotent(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+    with_progress(py, progress, || {
+        match malcev::is_congruence_dist_idempotent(&algebra.inner) {
+            Ok(result) => Ok(result),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Test if an idempotent algebra is congruence modular.
 ///
 /// # Arguments
 /// * `algebra` - The idempotent algebra to check
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// True if the algebra is congruence modular, False otherwise
 #[pyfunction]
-fn is_congruence_modular_idempotent(algebra: &PyBasicAlgebra) -> PyResult<bool> {
-    match malcev::is_congruence_modular_idempotent(&algebra.inner) {
-        Ok(result) => Ok(result),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, progress=None))]
+fn is_congruence_modular_idempotent(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+    with_progress(py, progress, || {
+        match malcev::is_congruence_modular_idempotent(&algebra.inner) {
+            Ok(result) => Ok(result),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Test if the variety generated by the algebra is congruence modular.
 ///
 /// # Arguments
 /// * `algebra` - The algebra to check
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// True if the variety is congruence modular, False otherwise
 #[pyfunction]
-fn congruence_modular_variety(algebra: &PyBasicAlgebra) -> PyResult<bool> {
-    match malcev::congruence_modular_variety(&algebra.inner) {
-        Ok(result) => Ok(result),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, progress=None))]
+fn congruence_modular_variety(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+    with_progress(py, progress, || {
+        match malcev::congruence_modular_variety(&algebra.inner) {
+            Ok(result) => Ok(result),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Compute the Jonsson level of an algebra.
 ///
 /// # Arguments
 /// * `algebra` - The algebra (BasicAlgebra)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// The Jonsson level
 #[pyfunction]
-fn jonsson_level(algebra: &PyBasicAlgebra) -> PyResult<i32> {
-    match malcev::jonsson_level(&algebra.inner) {
-        Ok(level) => Ok(level),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, progress=None))]
+fn jonsson_level(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<i32> {
+    with_progress(py, progress, || {
+        match malcev::jonsson_level(&algebra.inner) {
+            Ok(level) => Ok(level),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Compute the local distributivity level for three elements.
@@ -422,49 +691,179 @@ fn jonsson_level(algebra: &PyBasicAlgebra) -> PyResult<i32> {
 /// * `b` - Second element index
 /// * `c` - Third element index
 /// * `algebra` - The algebra
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// The local distributivity level, or -1 if (a,c) is not in the join
 #[pyfunction]
-fn local_distributivity_level(a: usize, b: usize, c: usize, algebra: &PyBasicAlgebra) -> PyResult<i32> {
-    match malcev::local_distributivity_level(a, b, c, &algebra.inner) {
-        Ok(level) => Ok(level),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (a, b, c, algebra, progress=None))]
+fn local_distributivity_level(py: Python<'_>, a: usize, b: usize, c: usize, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<i32> {
+    with_progress(py, progress, || {
+        match malcev::local_distributivity_level(a, b, c, &algebra.inner) {
+            Ok(level) => Ok(level),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Find a Day quadruple in the square of the algebra.
 ///
 /// # Arguments
 /// * `algebra` - The algebra to check
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// A tuple (x0, x1, y0, y1) if a Day quadruple is found, None otherwise
 #[pyfunction]
-fn find_day_quadruple_in_square(algebra: &PyBasicAlgebra) -> PyResult<Option<Vec<usize>>> {
-    match malcev::find_day_quadruple_in_square(&algebra.inner) {
-        Ok(Some(coords)) => Ok(Some(coords)),
-        Ok(None) => Ok(None),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, progress=None))]
+fn find_day_quadruple_in_square(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<Option<Vec<usize>>> {
+    with_progress(py, progress, || {
+        match malcev::find_day_quadruple_in_square(&algebra.inner) {
+            Ok(Some(coords)) => Ok(Some(coords)),
+            Ok(None) => Ok(None),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Find a witness for SD-meet failure in an idempotent algebra.
 ///
 /// # Arguments
 /// * `algebra` - The idempotent algebra to check
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// A tuple [x, y] if a witness is found, None otherwise
 #[pyfunction]
-fn sd_meet_idempotent(algebra: &PyBasicAlgebra) -> PyResult<Option<Vec<usize>>> {
-    match malcev::sd_meet_idempotent(&algebra.inner) {
-        Ok(Some(coords)) => Ok(Some(coords)),
-        Ok(None) => Ok(None),
-        Err(e) => Err(PyValueError::new_err(e)),
+#[pyo3(signature = (algebra, progress=None))]
+fn sd_meet_idempotent(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<Option<Vec<usize>>> {
+    with_progress(py, progress, || {
+        match malcev::sd_meet_idempotent(&algebra.inner) {
+            Ok(Some(coords)) => Ok(Some(coords)),
+            Ok(None) => Ok(None),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
+}
+
+/// Whether the variety generated by an algebra is congruence
+/// meet-semidistributive, with the SD-meet terms or a failure witness.
+#[pyclass]
+pub struct PySdMeetAnalysis {
+    inner: malcev::SdMeetAnalysis,
+}
+
+#[pymethods]
+impl PySdMeetAnalysis {
+    /// Whether the variety is congruence meet-semidistributive.
+    fn holds(&self) -> bool {
+        self.inner.holds()
+    }
+
+    /// The SD-meet terms as strings, or None if the variety is not SD-meet.
+    fn terms(&self) -> Option<Vec<String>> {
+        self.inner.terms.as_ref().map(|ts| ts.iter().map(|t| t.to_string()).collect())
+    }
+
+    /// For an idempotent algebra that fails, the coordinates [x, y]
+    /// witnessing the failure; None otherwise.
+    fn failure(&self) -> Option<Vec<usize>> {
+        self.inner.failure.clone()
+    }
+
+    fn __bool__(&self) -> bool {
+        self.inner.holds()
+    }
+
+    fn __repr__(&self) -> String {
+        match (&self.inner.terms, &self.inner.failure) {
+            (Some(ts), _) => format!("SdMeetAnalysis(terms={})", ts.len()),
+            (None, Some(f)) => format!("SdMeetAnalysis(failure={:?})", f),
+            (None, None) => "SdMeetAnalysis(terms=None)".to_string(),
+        }
+    }
+}
+
+/// Test whether the variety generated by the algebra is congruence
+/// meet-semidistributive.
+///
+/// The variety is SD-meet exactly when it has SD-meet terms; for an
+/// idempotent algebra a failure is looked for first, giving a witness.
+///
+/// # Arguments
+/// * `algebra` - The algebra to check (BasicAlgebra)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
+///
+/// # Returns
+/// An SdMeetAnalysis with the terms or the failure witness
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn variety_is_sd_meet(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<PySdMeetAnalysis> {
+    with_progress(py, progress, || {
+        malcev::variety_is_sd_meet(&algebra.inner)
+            .map(|inner| PySdMeetAnalysis { inner })
+            .map_err(PyValueError::new_err)
+    })
+}
+
+/// Whether the variety generated by an algebra omits a set of tame
+/// congruence theory types.
+#[pyclass]
+#[derive(Clone)]
+pub struct PyTypeOmission {
+    inner: malcev::TypeOmission,
+}
+
+#[pymethods]
+impl PyTypeOmission {
+    /// The types, in increasing order.
+    fn types(&self) -> Vec<i32> {
+        self.inner.types.clone()
+    }
+
+    /// True if the variety omits all of the types, False if one of them
+    /// occurs, None if the conditions checked do not decide it.
+    fn omitted(&self) -> Option<bool> {
+        self.inner.omitted
+    }
+
+    /// The Mal'cev conditions the verdict rests on.
+    fn reasons(&self) -> Vec<String> {
+        self.inner.reasons.clone()
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TypeOmission({})", self.inner)
     }
 }
 
+/// Decide which sets of TCT types the variety generated by the algebra
+/// omits: {1}, {1,2}, {1,5}, {1,2,5}, {1,4,5} and {1,2,4,5}.
+///
+/// Uses the Hobby-McKenzie characterizations: omitting 1 is having a Taylor
+/// term, omitting 1, 2 is SD-meet and omitting 1, 2, 5 is SD-join; Gumm
+/// terms imply omitting 1, 5 and Hagemann-Mitschke terms omitting 1, 4, 5.
+///
+/// # Arguments
+/// * `algebra` - The algebra to check (BasicAlgebra)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
+///
+/// # Returns
+/// A TypeOmission for each set of types
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn omitted_types(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<Vec<PyTypeOmission>> {
+    with_progress(py, progress, || {
+        malcev::omitted_types(&algebra.inner)
+            .map(|omissions| omissions.into_iter().map(|inner| PyTypeOmission { inner }).collect())
+            .map_err(PyValueError::new_err)
+    })
+}
+
 /// Check if a, b, c, d form a Day quadruple in the algebra.
 ///
 /// Note: This is a lower-level function that requires working with congruence lattices.
@@ -473,12 +872,16 @@ fn sd_meet_idempotent(algebra: &PyBasicAlgebra) -> PyResult<Option<Vec<usize>>>
 /// # Arguments
 /// * `_a`, `_b`, `_c`, `_d` - Four element indices
 /// * `_algebra` - The algebra
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// True if a Day quadruple exists, False otherwise
 #[pyfunction]
-fn day_quadruple(_a: usize, _b: usize, _c: usize, _d: usize, _algebra: PyObject) -> PyResult<bool> {
-    Err(PyValueError::new_err("Day quadruple test requires congruence lattice; use find_day_quadruple_in_square instead"))
+#[pyo3(signature = (_a, _b, _c, _d, _algebra, progress=None))]
+fn day_quadruple(py: Python<'_>, _a: usize, _b: usize, _c: usize, _d: usize, _algebra: PyObject, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+    with_progress(py, progress, || {
+        Err(PyValueError::new_err("Day quadruple test requires congruence lattice; use find_day_quadruple_in_square instead"))
+    })
 }
 
 /// Test if the algebra admits a cyclic term of the given arity.
@@ -489,15 +892,61 @@ fn day_quadruple(_a: usize, _b: usize, _c: usize, _d: usize, _algebra: PyObject)
 /// # Arguments
 /// * `algebra` - The algebra (must be idempotent)
 /// * `arity` - The arity of the cyclic term (must be at least 2)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// True if a cyclic term exists, False otherwise
 #[pyfunction]
-fn cyclic_term_idempotent(algebra: &PyBasicAlgebra, arity: usize) -> PyResult<bool> {
-    match malcev::cyclic_term_idempotent(&algebra.inner, arity) {
-        Ok(result) => Ok(result),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, arity, progress=None))]
+fn cyclic_term_idempotent(py: Python<'_>, algebra: &PyBasicAlgebra, arity: usize, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+    with_progress(py, progress, || {
+        match malcev::cyclic_term_idempotent(&algebra.inner, arity) {
+            Ok(result) => Ok(result),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
+}
+
+/// Test if the algebra has a cyclic term of arity p: an idempotent term
+/// with t(x1,...,xp) = t(x2,...,xp,x1).
+///
+/// Idempotent algebras use the algorithm of Valeriote and Willard; other
+/// algebras search the term operations as `find_cyclic_term` does.
+///
+/// # Arguments
+/// * `algebra` - The algebra (BasicAlgebra)
+/// * `p` - The arity of the cyclic term (must be at least 2)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
+///
+/// # Returns
+/// True if a cyclic term exists, False otherwise
+#[pyfunction]
+#[pyo3(signature = (algebra, p, progress=None))]
+fn has_cyclic_term(py: Python<'_>, algebra: &PyBasicAlgebra, p: usize, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+    with_progress(py, progress, || {
+        malcev::has_cyclic_term(&algebra.inner, p).map_err(PyValueError::new_err)
+    })
+}
+
+/// Find a cyclic term of arity p: an idempotent term with
+/// t(x0,...,x{p-1}) = t(x1,...,x{p-1},x0).
+///
+/// # Arguments
+/// * `algebra` - The algebra (BasicAlgebra)
+/// * `p` - The arity of the cyclic term (must be at least 2)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
+///
+/// # Returns
+/// The cyclic term as a string if one exists, None otherwise
+#[pyfunction]
+#[pyo3(signature = (algebra, p, progress=None))]
+fn find_cyclic_term(py: Python<'_>, algebra: &PyBasicAlgebra, p: usize, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+    with_progress(py, progress, || {
+        match malcev::find_cyclic_term(&algebra.inner, p) {
+            Ok(term) => Ok(term.map(|t| t.to_string())),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Find primality terms for the algebra.
@@ -508,19 +957,23 @@ fn cyclic_term_idempotent(algebra: &PyBasicAlgebra, arity: usize) -> PyResult<bo
 ///
 /// # Arguments
 /// * `algebra` - The algebra to check (BasicAlgebra)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// List of primality terms as strings if they exist, None otherwise
 #[pyfunction]
-fn primality_terms(algebra: &PyBasicAlgebra) -> PyResult<Option<Vec<String>>> {
-    match malcev::primality_terms(&algebra.inner) {
-        Ok(Some(terms)) => {
-            let term_strings: Vec<String> = terms.iter().map(|t| format!("{}", t)).collect();
-            Ok(Some(term_strings))
-        },
-        Ok(None) => Ok(None),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, progress=None))]
+fn primality_terms(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<Option<Vec<String>>> {
+    with_progress(py, progress, || {
+        match malcev::primality_terms(&algebra.inner) {
+            Ok(Some(terms)) => {
+                let term_strings: Vec<String> = terms.iter().map(|t| format!("{}", t)).collect();
+                Ok(Some(term_strings))
+            },
+            Ok(None) => Ok(None),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Find a k-edge term for the algebra.
@@ -528,16 +981,20 @@ fn primality_terms(algebra: &PyBasicAlgebra) -> PyResult<Option<Vec<String>>> {
 /// # Arguments
 /// * `algebra` - The algebra to check (BasicAlgebra)
 /// * `k` - The parameter k (edge term will have arity k+1)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// The k-edge term as a string if one exists, None otherwise
 #[pyfunction]
-fn fixed_k_edge_term(algebra: &PyBasicAlgebra, k: usize) -> PyResult<Option<String>> {
-    match malcev::fixed_k_edge_term(&algebra.inner, k) {
-        Ok(Some(term)) => Ok(Some(format!("{}", term))),
-        Ok(None) => Ok(None),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, k, progress=None))]
+fn fixed_k_edge_term(py: Python<'_>, algebra: &PyBasicAlgebra, k: usize, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+    with_progress(py, progress, || {
+        match malcev::fixed_k_edge_term(&algebra.inner, k) {
+            Ok(Some(term)) => Ok(Some(format!("{}", term))),
+            Ok(None) => Ok(None),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 /// Test if an algebra has a quasi weak near unanimity (QWNU) term of the given arity.
@@ -545,15 +1002,19 @@ fn fixed_k_edge_term(algebra: &PyBasicAlgebra, k: usize) -> PyResult<Option<Stri
 /// # Arguments
 /// * `algebra` - The algebra to test (BasicAlgebra)
 /// * `arity` - The arity of the QWNU term (must be at least 2)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
 ///
 /// # Returns
 /// True if the algebra has a QWNU term of the given arity, False otherwise
 #[pyfunction]
-fn fixed_k_qwnu(algebra: &PyBasicAlgebra, arity: usize) -> PyResult<bool> {
-    match malcev::fixed_k_qwnu(&algebra.inner, arity) {
-        Ok(result) => Ok(result),
-        Err(e) => Err(PyValueError::new_err(e)),
-    }
+#[pyo3(signature = (algebra, arity, progress=None))]
+fn fixed_k_qwnu(py: Python<'_>, algebra: &PyBasicAlgebra, arity: usize, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+    with_progress(py, progress, || {
+        match malcev::fixed_k_qwnu(&algebra.inner, arity) {
+            Ok(result) => Ok(result),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    })
 }
 
 
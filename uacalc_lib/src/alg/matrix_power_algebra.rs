@@ -4,6 +4,7 @@ use std::collections::HashMap;
 use uacalc::alg::*;
 use crate::alg::PyBasicAlgebra;
 use crate::alg::power_algebra::PyPowerAlgebra;
+use crate::alg::cardinality::cardinality_to_py;
 
 /// Python wrapper for MatrixPowerAlgebra
 #[pyclass]
@@ -177,6 +178,15 @@ impl PyMatrixPowerAlgebra {
         self.inner.cardinality()
     }
 
+    /// Get the exact cardinality, even when it is too big for cardinality().
+    ///
+    /// Returns:
+    ///     Optional[int]: The cardinality as an int of any size, or None if it
+    ///     is unknown
+    fn exact_cardinality(&self, py: Python<'_>) -> PyResult<PyObject> {
+        cardinality_to_py(py, &self.inner.exact_cardinality())
+    }
+
     /// Get the name of this algebra.
     ///
     /// Returns:
@@ -3,13 +3,17 @@ pub mod basic_operation;
 pub mod algebra_from_minimal_sets;
 pub mod algebra_with_generating_vector;
 pub mod big_product_algebra;
+pub mod cardinality;
+pub mod clone;
 pub mod closer;
 pub mod closer_timing;
 pub mod free_algebra;
+pub mod free_spectrum;
 pub mod general_algebra;
 pub mod homomorphism;
 pub mod malcev;
 pub mod algebras;
+pub mod batch;
 pub mod maltsev_product_decomposition;
 pub mod maltsev_decomposition_iterator;
 pub mod parameterized_algebra;
@@ -18,7 +22,24 @@ pub mod matrix_power_algebra;
 pub mod power_algebra;
 pub mod product_algebra;
 pub mod reduct_algebra;
+pub mod residual;
+pub mod monounary;
+pub mod ring;
+pub mod search;
 pub mod subalgebra;
+pub mod subuniverse_graph;
+pub mod simple;
+pub mod generating_sets;
+pub mod finite_basis;
+pub mod congruence_regularity;
+pub mod dpc;
+pub mod discriminator;
+pub mod hereditary;
+pub mod quasivariety;
+pub mod duality;
+pub mod interpretation;
+pub mod sub_product_algebra;
+pub mod term_equivalence;
 pub mod unary_terms_monoid;
 pub mod conlat;
 pub mod op;
@@ -33,13 +54,13 @@ pub use conlat::basic_binary_relation::PyBasicBinaryRelation;
 pub use conlat::centrality_data::PyCentralityData;
 pub use conlat::partition::PyPartition;
 pub use conlat::print_type::PyPrintType;
-pub use conlat::congruence_lattice::{PyCongruenceLattice, PyCongruenceLatticeIntArray};
+pub use conlat::congruence_lattice::{PyCongruenceLattice, PyCongruenceLatticeIntArray, PyCongruences};
 pub use op::similarity_type::PySimilarityType;
 pub use op::parameterized_operation::PyParameterizedOperation;
 pub use op::operations::PyOperations;
 pub use op::operation_with_default_value::PyOperationWithDefaultValue;
 pub use sublat::basic_set::PyBasicSet;
-pub use sublat::subalgebra_lattice::PySubalgebraLattice;
+pub use sublat::subalgebra_lattice::{PySubalgebraLattice, PySubuniverses};
 pub use crate::alg::op::operation_symbol::PyOperationSymbol;
 pub use algebra_with_generating_vector::PyAlgebraWithGeneratingVector;
 
@@ -48,10 +69,11 @@ use pyo3::prelude::*;
 use crate::alg::homomorphism::PyHomomorphism;
 use crate::alg::algebra_from_minimal_sets::PyAlgebraFromMinimalSets;
 use crate::alg::free_algebra::PyFreeAlgebra;
-use crate::alg::product_algebra::PyProductAlgebra;
+use crate::alg::product_algebra::{PyProductAlgebra, PyProductElements};
 use crate::alg::power_algebra::PyPowerAlgebra;
 use crate::alg::matrix_power_algebra::PyMatrixPowerAlgebra;
 use crate::alg::subalgebra::PySubalgebra;
+use crate::alg::sub_product_algebra::PySubProductAlgebra;
 use crate::alg::reduct_algebra::PyReductAlgebra;
 use crate::alg::unary_terms_monoid::PyUnaryTermsMonoid;
 use crate::alg::polin_like_algebra::PyPolinLikeAlgebra;
@@ -80,20 +102,24 @@ pub fn register_alg_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()>
     m.add_class::<PyHomomorphism>()?;
     m.add_class::<PyAlgebraFromMinimalSets>()?;
     m.add_class::<PySubalgebraLattice>()?;
+    m.add_class::<PySubuniverses>()?;
     m.add_class::<PyBasicBinaryRelation>()?;
     m.add_class::<PyCentralityData>()?;
     m.add_class::<PySimilarityType>()?;
     m.add_class::<PyPartition>()?;
     m.add_class::<PyPrintType>()?;
     m.add_class::<PyCongruenceLattice>()?;
+    m.add_class::<PyCongruences>()?;
     m.add_class::<PyCongruenceLatticeIntArray>()?;
     m.add_class::<PyParameterizedOperation>()?;
     m.add_class::<PyBasicSet>()?;
     m.add_class::<PyFreeAlgebra>()?;
     m.add_class::<PyProductAlgebra>()?;
+    m.add_class::<PyProductElements>()?;
     m.add_class::<PyPowerAlgebra>()?;
     m.add_class::<PyMatrixPowerAlgebra>()?;
     m.add_class::<PySubalgebra>()?;
+    m.add_class::<PySubProductAlgebra>()?;
     m.add_class::<PyReductAlgebra>()?;
     m.add_class::<PyUnaryTermsMonoid>()?;
     m.add_class::<PyPolinLikeAlgebra>()?;
@@ -111,6 +137,11 @@ pub fn register_alg_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()>
     closer::register_closer(_py, m)?;
     closer_timing::register_closer_timing(_py, m)?;
 
+    m.add_class::<homomorphism::PyFiberProduct>()?;
+    m.add("FiberProduct", m.getattr("PyFiberProduct")?)?;
+    m.add_function(wrap_pyfunction!(homomorphism::create_subdirect_product, m)?)?;
+    m.add_function(wrap_pyfunction!(homomorphism::fiber_product, m)?)?;
+
     // Export only clean names (without Py prefix)
     m.add("Closer", m.getattr("PyCloser")?)?;
     m.add("CloserTiming", m.getattr("PyCloserTiming")?)?;
@@ -125,19 +156,23 @@ pub fn register_alg_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()>
     m.add("Homomorphism", m.getattr("PyHomomorphism")?)?;
     m.add("AlgebraFromMinimalSets", m.getattr("PyAlgebraFromMinimalSets")?)?;
     m.add("SubalgebraLattice", m.getattr("PySubalgebraLattice")?)?;
+    m.add("Subuniverses", m.getattr("PySubuniverses")?)?;
     m.add("BasicBinaryRelation", m.getattr("PyBasicBinaryRelation")?)?;
     m.add("SimilarityType", m.getattr("PySimilarityType")?)?;
     m.add("CentralityData", m.getattr("PyCentralityData")?)?;
     m.add("Partition", m.getattr("PyPartition")?)?;
     m.add("PrintType", m.getattr("PyPrintType")?)?;
     m.add("CongruenceLattice", m.getattr("PyCongruenceLattice")?)?;
+    m.add("Congruences", m.getattr("PyCongruences")?)?;
     m.add("ParameterizedOperation", m.getattr("PyParameterizedOperation")?)?;
     m.add("BasicSet", m.getattr("PyBasicSet")?)?;
     m.add("FreeAlgebra", m.getattr("PyFreeAlgebra")?)?;
     m.add("ProductAlgebra", m.getattr("PyProductAlgebra")?)?;
+    m.add("ProductElements", m.getattr("PyProductElements")?)?;
     m.add("PowerAlgebra", m.getattr("PyPowerAlgebra")?)?;
     m.add("MatrixPowerAlgebra", m.getattr("PyMatrixPowerAlgebra")?)?;
     m.add("Subalgebra", m.getattr("PySubalgebra")?)?;
+    m.add("SubProductAlgebra", m.getattr("PySubProductAlgebra")?)?;
     m.add("ReductAlgebra", m.getattr("PyReductAlgebra")?)?;
     m.add("UnaryTermsMonoid", m.getattr("PyUnaryTermsMonoid")?)?;
     m.add("PolinLikeAlgebra", m.getattr("PyPolinLikeAlgebra")?)?;
@@ -166,5 +201,94 @@ pub fn register_alg_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()>
     // Register algebras module-level functions
     algebras::register_algebras_functions(_py, m)?;
 
+    // Register homomorphism and polymorphism search functions
+    search::register_search_functions(_py, m)?;
+
+    // Register relations and the Pol-Inv functions
+    clone::register_clone(_py, m)?;
+
+    // Register the product congruence lattice shortcut
+    conlat::product_conlat::register_product_conlat(_py, m)?;
+
+    // Register free spectrum functions
+    free_spectrum::register_free_spectrum_functions(_py, m)?;
+
+    // Register term and polynomial equivalence functions
+    term_equivalence::register_term_equivalence_functions(_py, m)?;
+
+    // Register subdirectly irreducible and splitting algebra functions
+    residual::register_residual_functions(_py, m)?;
+
+    // Register monounary algebras
+    monounary::register_monounary(_py, m)?;
+
+    // Register ring and module functions
+    ring::register_ring_functions(_py, m)?;
+
+    // Register batch analysis functions
+    batch::register_batch_functions(_py, m)?;
+
+    // Register the subuniverse digraph
+    subuniverse_graph::register_subuniverse_graph(_py, m)?;
+
+    // Register simple and strictly simple checks
+    simple::register_simple_functions(_py, m)?;
+
+    // Register minimal generating set functions
+    generating_sets::register_generating_set_functions(_py, m)?;
+
+    // Register finite basis probes
+    finite_basis::register_finite_basis_functions(_py, m)?;
+
+    // Register congruence regularity, uniformity and coherence checks
+    congruence_regularity::register_congruence_regularity_functions(_py, m)?;
+
+    // Register the definable principal congruences probe
+    dpc::register_dpc_functions(_py, m)?;
+
+    // Register the arithmetical and discriminator variety tests
+    discriminator::register_discriminator_functions(_py, m)?;
+
+    // Register the hereditary properties runner
+    hereditary::register_hereditary_functions(_py, m)?;
+
+    // Register the quasivariety membership test
+    quasivariety::register_quasivariety_functions(_py, m)?;
+
+    // Register the natural duality probe
+    duality::register_duality_functions(_py, m)?;
+
+    // Register the pp-interpretation search
+    interpretation::register_interpretation_functions(_py, m)?;
+
     Ok(())
-}
\ No newline at end of file
+}
+/// Extract any Python algebra wrapper over integer elements as a boxed `SmallAlgebra`.
+///
+/// Accepts BasicAlgebra, SmallAlgebra, ProductAlgebra, PowerAlgebra,
+/// Subalgebra and ReductAlgebra; the algebra keeps all of its operations.
+pub(crate) fn small_algebra_from_py(
+    obj: &Bound<'_, PyAny>,
+) -> PyResult<Box<dyn uacalc::alg::SmallAlgebra<UniverseItem = i32>>> {
+    if let Ok(a) = obj.extract::<PyRef<PyBasicAlgebra>>() {
+        return Ok(Box::new(a.inner.clone()));
+    }
+    if let Ok(a) = obj.extract::<PyRef<small_algebra::PySmallAlgebra>>() {
+        return Ok(a.clone_box());
+    }
+    if let Ok(a) = obj.extract::<PyRef<PyProductAlgebra>>() {
+        return Ok(Box::new(a.inner.clone()));
+    }
+    if let Ok(a) = obj.extract::<PyRef<PyPowerAlgebra>>() {
+        return Ok(Box::new(a.inner.clone()));
+    }
+    if let Ok(a) = obj.extract::<PyRef<PySubalgebra>>() {
+        return Ok(Box::new(a.inner.clone()));
+    }
+    if let Ok(a) = obj.extract::<PyRef<PyReductAlgebra>>() {
+        return Ok(Box::new(a.inner.clone()));
+    }
+    Err(pyo3::exceptions::PyTypeError::new_err(
+        "Expected BasicAlgebra, SmallAlgebra, ProductAlgebra, PowerAlgebra, Subalgebra or ReductAlgebra",
+    ))
+}
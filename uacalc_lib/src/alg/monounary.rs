@@ -0,0 +1,142 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use uacalc::alg::monounary::Monounary;
+use crate::alg::PyBasicAlgebra;
+use crate::alg::conlat::partition::PyPartition;
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register the Monounary class.
+pub fn register_monounary(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyMonounary>()?;
+    m.add("Monounary", m.getattr("PyMonounary")?)?;
+    Ok(())
+}
+
+/// A monounary algebra on {0, ..., n-1}: a single unary operation f.
+///
+/// Cycles, depths, components and congruences are computed directly from
+/// the map, much faster than the general congruence lattice algorithm.
+#[pyclass(module = "uacalc_lib.alg")]
+#[derive(Clone)]
+pub struct PyMonounary {
+    inner: Monounary,
+}
+
+#[pymethods]
+impl PyMonounary {
+    /// Create a monounary algebra from its operation.
+    ///
+    /// Args:
+    ///     map (List[int]): The values f(0), ..., f(n-1)
+    ///
+    /// Raises:
+    ///     ValueError: If map is empty or has a value outside 0..n
+    #[new]
+    fn new(map: Vec<i32>) -> PyResult<Self> {
+        Monounary::new(map).map(|inner| PyMonounary { inner }).map_err(PyValueError::new_err)
+    }
+
+    /// Recognize a monounary algebra: one whose only operation is unary.
+    ///
+    /// Args:
+    ///     algebra (BasicAlgebra): The algebra
+    ///
+    /// Returns:
+    ///     Monounary: The algebra with its operation
+    ///
+    /// Raises:
+    ///     ValueError: If the algebra is not monounary
+    #[staticmethod]
+    fn from_algebra(algebra: &PyBasicAlgebra) -> PyResult<Self> {
+        Monounary::from_algebra(&algebra.inner).map(|inner| PyMonounary { inner }).map_err(PyValueError::new_err)
+    }
+
+    /// Whether the algebra has a single operation, of arity 1.
+    #[staticmethod]
+    fn is_monounary(algebra: &PyBasicAlgebra) -> bool {
+        Monounary::is_monounary(&algebra.inner)
+    }
+
+    /// The number of elements.
+    fn cardinality(&self) -> usize {
+        self.inner.cardinality()
+    }
+
+    /// The value f(x).
+    fn apply(&self, x: usize) -> PyResult<usize> {
+        if x >= self.inner.cardinality() {
+            return Err(PyValueError::new_err(format!("Element {} is out of range", x)));
+        }
+        Ok(self.inner.apply(x))
+    }
+
+    /// The depth of each element: the least k such that f^k(x) is on a cycle.
+    fn depths(&self) -> Vec<usize> {
+        self.inner.depths().to_vec()
+    }
+
+    /// The largest depth, the height of the tallest tree.
+    fn height(&self) -> usize {
+        self.inner.height()
+    }
+
+    /// The cycles, each starting at its least element, ordered by least element.
+    fn cycles(&self) -> Vec<Vec<usize>> {
+        self.inner.cycles()
+    }
+
+    /// The lengths of the cycles, ordered by least element.
+    fn cycle_lengths(&self) -> Vec<usize> {
+        self.inner.cycle_lengths()
+    }
+
+    /// The connected components, ordered by least element.
+    fn components(&self) -> Vec<Vec<usize>> {
+        self.inner.components()
+    }
+
+    /// The partition into connected components.
+    fn component_partition(&self) -> PyPartition {
+        PyPartition::from_inner(self.inner.component_partition())
+    }
+
+    /// Whether a partition of the universe is a congruence.
+    fn is_congruence(&self, partition: &PyPartition) -> bool {
+        self.inner.is_congruence(&partition.inner)
+    }
+
+    /// The principal congruence Cg(a, b).
+    ///
+    /// Args:
+    ///     a (int): An element
+    ///     b (int): An element
+    ///
+    /// Returns:
+    ///     Partition: The least congruence relating a and b
+    ///
+    /// Raises:
+    ///     ValueError: If a or b is out of range
+    fn principal_congruence(&self, a: usize, b: usize) -> PyResult<PyPartition> {
+        self.inner.principal_congruence(a, b).map(PyPartition::from_inner).map_err(PyValueError::new_err)
+    }
+
+    /// All the congruences, from the zero congruence up.
+    ///
+    /// Args:
+    ///     progress (ProgressReporter, optional): Progress and cancellation
+    ///
+    /// Returns:
+    ///     List[Partition]: The congruences, ordered by number of blocks
+    ///
+    /// Raises:
+    ///     ValueError: If the computation was cancelled
+    #[pyo3(signature = (progress=None))]
+    fn congruences(&self, py: Python<'_>, progress: Option<&PyProgressReporter>) -> PyResult<Vec<PyPartition>> {
+        let cons = with_progress(py, progress, || self.inner.congruences().map_err(PyValueError::new_err))?;
+        Ok(cons.into_iter().map(PyPartition::from_inner).collect())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Monounary(cardinality={}, cycles={:?})", self.inner.cardinality(), self.inner.cycle_lengths())
+    }
+}
@@ -5,7 +5,11 @@ use uacalc::alg::op::{IntOperation, Operation};
 use crate::alg::op::operation_symbol::PyOperationSymbol;
 
 /// Python wrapper for IntOperation
-#[pyclass]
+///
+/// The table can be edited in place with `set_value`, `update_from_matrix`
+/// and `op[x, y] = z`; this is the only operation class that allows it.
+/// Since edits change equality, IntOperation is not hashable.
+#[pyclass(module = "uacalc_lib.alg")]
 pub struct PyIntOperation {
     pub(crate) inner: IntOperation,
 }
@@ -392,6 +396,42 @@ impl PyIntOperation {
         self.inner.is_table_based()
     }
 
+    /// Set the value of the operation at the given arguments.
+    ///
+    /// Args:
+    ///     args (List[int]): The arguments, one for each place
+    ///     result (int): The new value
+    ///
+    /// Raises:
+    ///     ValueError: If the number of arguments is wrong or a value is out of range
+    fn set_value(&mut self, args: Vec<i32>, result: i32) -> PyResult<()> {
+        self.inner.set_value(&args, result).map_err(PyValueError::new_err)
+    }
+
+    /// Replace the table of a binary operation by a matrix.
+    ///
+    /// Args:
+    ///     matrix (List[List[int]] or 2D numpy.ndarray): Entry [x][y] is the value at (x, y)
+    ///
+    /// Raises:
+    ///     ValueError: If the operation is not binary, the matrix is not square
+    ///         of side the set size or an entry is out of range
+    fn update_from_matrix(&mut self, matrix: Vec<Vec<i32>>) -> PyResult<()> {
+        self.inner.update_from_matrix(&matrix).map_err(PyValueError::new_err)
+    }
+
+    /// The value at `op[x, y]`, or `op[x]` for a unary operation.
+    fn __getitem__(&self, key: &Bound<'_, PyAny>) -> PyResult<i32> {
+        let args = args_from_key(key)?;
+        self.inner.int_value_at(&args).map_err(PyValueError::new_err)
+    }
+
+    /// Edit the table with `op[x, y] = z`, or `op[x] = z` for a unary operation.
+    fn __setitem__(&mut self, key: &Bound<'_, PyAny>, value: i32) -> PyResult<()> {
+        let args = args_from_key(key)?;
+        self.inner.set_value(&args, value).map_err(PyValueError::new_err)
+    }
+
     fn is_idempotent(&self) -> PyResult<bool> {
         match self.inner.is_idempotent() {
             Ok(result) => Ok(result),
@@ -434,6 +474,41 @@ impl PyIntOperation {
         }
     }
 
+    fn depends_on_argument(&self, i: usize) -> PyResult<bool> {
+        uacalc::alg::op::ops::depends_on_argument(&self.inner, i).map_err(PyValueError::new_err)
+    }
+
+    fn essential_arity(&self) -> PyResult<usize> {
+        uacalc::alg::op::ops::essential_arity(&self.inner).map_err(PyValueError::new_err)
+    }
+
+    fn essentially_unary_decomposition(&self) -> PyResult<Option<(usize, Vec<i32>)>> {
+        uacalc::alg::op::ops::essentially_unary_decomposition(&self.inner).map_err(PyValueError::new_err)
+    }
+
+    /// Format the table of this operation, a Cayley table for binary operations.
+    ///
+    /// Args:
+    ///     style (str, optional): "text" (default), "markdown" or "latex"
+    ///     labels (List[str], optional): Names for the elements; indices by default
+    ///
+    /// Returns:
+    ///     str: The formatted table
+    ///
+    /// Raises:
+    ///     ValueError: If the style is unknown or there is not one label per element
+    #[pyo3(signature = (style="text", labels=None))]
+    fn format_table(&self, style: &str, labels: Option<Vec<String>>) -> PyResult<String> {
+        let style = uacalc::alg::op::TableStyle::from_name(style).map_err(PyValueError::new_err)?;
+        uacalc::alg::op::table_format::format_table(&self.inner, style, labels.as_deref())
+            .map_err(PyValueError::new_err)
+    }
+
+    /// Get the table of this operation as a LaTeX tabular.
+    fn to_latex(&self) -> PyResult<String> {
+        self.inner.format_table(uacalc::alg::op::TableStyle::Latex).map_err(PyValueError::new_err)
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
@@ -442,18 +517,19 @@ impl PyIntOperation {
         format!("IntOperation({})", self.inner.to_string())
     }
 
+    /// Pickle support: the constructor arguments.
+    fn __getnewargs__(&self) -> (PyOperationSymbol, i32, Vec<i32>) {
+        let table = self.inner.get_table().map(|t| t.to_vec()).unwrap_or_default();
+        (PyOperationSymbol::from_inner(self.inner.symbol().clone()), self.inner.get_set_size(), table)
+    }
+
     fn __eq__(&self, other: &PyIntOperation) -> bool {
         self.inner == other.inner
     }
 
-    fn __hash__(&self) -> u64 {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-
-        let mut hasher = DefaultHasher::new();
-        self.inner.hash(&mut hasher);
-        hasher.finish()
-    }
+    /// The table is mutable, so the operation is unhashable.
+    #[classattr]
+    const __hash__: Option<PyObject> = None;
 
     fn __lt__(&self, other: &PyIntOperation) -> bool {
         self.inner < other.inner
@@ -470,4 +546,12 @@ impl PyIntOperation {
     fn __ge__(&self, other: &PyIntOperation) -> bool {
         self.inner >= other.inner
     }
-}
\ No newline at end of file
+}
+
+/// The arguments of an index: an int for a unary operation, otherwise a tuple.
+fn args_from_key(key: &Bound<'_, PyAny>) -> PyResult<Vec<i32>> {
+    match key.extract::<i32>() {
+        Ok(x) => Ok(vec![x]),
+        Err(_) => key.extract(),
+    }
+}
@@ -6,7 +6,7 @@ use uacalc::alg::op::{Operation, BasicOperation};
 use super::operation_symbol::PyOperationSymbol;
 
 /// Python wrapper for BasicOperation
-#[pyclass]
+#[pyclass(module = "uacalc_lib.alg")]
 pub struct PyBasicOperation {
     pub(crate) inner: BasicOperation,
 }
@@ -304,6 +304,60 @@ impl PyBasicOperation {
         }
     }
 
+    /// Check whether this operation depends on its `i`-th argument.
+    ///
+    /// Args:
+    ///     i (int): The argument index
+    ///
+    /// Returns:
+    ///     bool: True if changing argument `i` alone can change the value
+    ///
+    /// Raises:
+    ///     ValueError: If `i` is not less than the arity
+    fn depends_on_argument(&self, i: usize) -> PyResult<bool> {
+        uacalc::alg::op::ops::depends_on_argument(&self.inner, i).map_err(PyValueError::new_err)
+    }
+
+    /// Get the number of arguments this operation depends on.
+    ///
+    /// Returns:
+    ///     int: The essential arity
+    fn essential_arity(&self) -> PyResult<usize> {
+        uacalc::alg::op::ops::essential_arity(&self.inner).map_err(PyValueError::new_err)
+    }
+
+    /// Write this operation as a unary map of a single argument, if possible.
+    ///
+    /// Returns:
+    ///     Optional[Tuple[int, List[int]]]: `(i, f)` with `op(x) = f[x[i]]`,
+    ///     or None if the operation depends on two or more arguments
+    fn essentially_unary_decomposition(&self) -> PyResult<Option<(usize, Vec<i32>)>> {
+        uacalc::alg::op::ops::essentially_unary_decomposition(&self.inner).map_err(PyValueError::new_err)
+    }
+
+    /// Format the table of this operation, a Cayley table for binary operations.
+    ///
+    /// Args:
+    ///     style (str, optional): "text" (default), "markdown" or "latex"
+    ///     labels (List[str], optional): Names for the elements; indices by default
+    ///
+    /// Returns:
+    ///     str: The formatted table
+    ///
+    /// Raises:
+    ///     ValueError: If the style is unknown or there is not one label per element
+    #[pyo3(signature = (style="text", labels=None))]
+    fn format_table(&self, style: &str, labels: Option<Vec<String>>) -> PyResult<String> {
+        let style = uacalc::alg::op::TableStyle::from_name(style).map_err(PyValueError::new_err)?;
+        uacalc::alg::op::table_format::format_table(&self.inner, style, labels.as_deref())
+            .map_err(PyValueError::new_err)
+    }
+
+    /// Get the table of this operation as a LaTeX tabular.
+    fn to_latex(&self) -> PyResult<String> {
+        self.inner.format_table(uacalc::alg::op::TableStyle::Latex).map_err(PyValueError::new_err)
+    }
+
     /// Python string representation.
     fn __str__(&self) -> String {
         self.inner.to_string()
@@ -314,6 +368,12 @@ impl PyBasicOperation {
         format!("BasicOperation({})", self.inner.to_string())
     }
 
+    /// Pickle support: the constructor arguments.
+    fn __getnewargs__(&self) -> (PyOperationSymbol, i32, Option<Vec<i32>>) {
+        let table = self.inner.get_table().map(|t| t.to_vec());
+        (PyOperationSymbol::from_inner(self.inner.symbol().clone()), self.inner.get_set_size(), table)
+    }
+
     /// Python equality comparison.
     fn __eq__(&self, other: &PyBasicOperation) -> bool {
         self.inner == other.inner
@@ -5,7 +5,7 @@ use uacalc::alg::op::Operation;
 use uacalc::lat::Lattice;
 
 /// Python wrapper for OperationSymbol
-#[pyclass]
+#[pyclass(module = "uacalc_lib.alg")]
 pub struct PyOperationSymbol {
     inner: uacalc::alg::op::OperationSymbol,
 }
@@ -150,6 +150,11 @@ impl PyOperationSymbol {
                 self.inner.name(), self.inner.arity(), self.inner.is_associative())
     }
 
+    /// Pickle support: the constructor arguments.
+    fn __getnewargs__(&self) -> (String, i32, bool) {
+        (self.inner.name().to_string(), self.inner.arity(), self.inner.is_associative())
+    }
+
     /// Python equality comparison.
     fn __eq__(&self, other: &PyOperationSymbol) -> bool {
         self.inner == other.inner
@@ -100,6 +100,43 @@ impl PyOperations {
         Err(PyValueError::new_err("Unsupported operation type"))
     }
 
+    /// Classify a binary operation as a semigroup, monoid, group,
+    /// quasigroup, loop, band or semilattice.
+    ///
+    /// Args:
+    ///     op (Operation): A binary operation
+    ///
+    /// Returns:
+    ///     dict: "kinds", the names of the structures the operation forms;
+    ///         "identity" and "inverses" (or None); and the failure witnesses
+    ///         "non_associative" (x, y, z), "non_commutative" (x, y),
+    ///         "non_idempotent" x, "non_latin" (x, y, z) with x*y = x*z or
+    ///         y*x = z*x, and "non_invertible" x (each None if the law holds)
+    ///
+    /// Raises:
+    ///     ValueError: If the operation is not binary
+    #[staticmethod]
+    fn recognize_structure(py: Python<'_>, op: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let structure = if let Ok(o) = op.extract::<PyRef<PyBasicOperation>>() {
+            uacalc::alg::op::ops::recognize_structure(&o.inner)
+        } else if let Ok(o) = op.extract::<PyRef<PyIntOperation>>() {
+            uacalc::alg::op::ops::recognize_structure(&o.inner)
+        } else {
+            return Err(PyValueError::new_err("Unsupported operation type"));
+        }
+        .map_err(PyValueError::new_err)?;
+        let dict = pyo3::types::PyDict::new_bound(py);
+        dict.set_item("kinds", structure.kinds())?;
+        dict.set_item("identity", structure.identity)?;
+        dict.set_item("inverses", structure.inverses)?;
+        dict.set_item("non_associative", structure.non_associative)?;
+        dict.set_item("non_commutative", structure.non_commutative)?;
+        dict.set_item("non_idempotent", structure.non_idempotent)?;
+        dict.set_item("non_latin", structure.non_latin)?;
+        dict.set_item("non_invertible", structure.non_invertible)?;
+        Ok(dict.into())
+    }
+
     /// Find the first difference between two operations.
     #[staticmethod]
     fn find_difference(op1: &Bound<'_, PyAny>, op2: &Bound<'_, PyAny>) -> PyResult<Option<Vec<i32>>> {
@@ -6,6 +6,7 @@ use uacalc::alg::op::SimilarityType;
 use super::operation_symbol as op_mod_symbol;
 use crate::alg as root_alg;
 use crate::alg::op::operation_symbol::PyOperationSymbol;
+use crate::alg::cardinality::{cardinality_from_py, cardinality_to_py};
 
 /// Python wrapper for SimilarityType
 #[pyclass]
@@ -86,6 +87,23 @@ impl PySimilarityType {
         self.inner.input_size(alg_size)
     }
 
+    /// Calculate the exact input size for this similarity type.
+    ///
+    /// Args:
+    ///     alg_size (int): The algebra size, an int of any size
+    ///
+    /// Returns:
+    ///     Optional[int]: The input size as an int of any size, or None if it
+    ///     is too big to compute
+    ///
+    /// Raises:
+    ///     TypeError: If alg_size is not an int
+    ///     ValueError: If alg_size is negative
+    fn exact_input_size(&self, py: Python<'_>, alg_size: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let alg_size = cardinality_from_py(alg_size)?;
+        cardinality_to_py(py, &self.inner.exact_input_size(&alg_size))
+    }
+
     /// Get a map from arity to the number of operations of that arity.
     ///
     /// This method caches the result for performance.
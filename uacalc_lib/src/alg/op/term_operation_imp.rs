@@ -5,7 +5,7 @@ use std::sync::Arc;
 use uacalc::alg::op::{TermOperationImp, TermOperation, Operation};
 use uacalc::alg::SmallAlgebra;
 use uacalc::terms::{Term, Variable};
-use crate::alg::PyBasicAlgebra;
+use crate::alg::small_algebra_from_py;
 use crate::terms::{PyVariableImp, PyNonVariableTerm};
 
 /// Python wrapper for TermOperationImp
@@ -24,7 +24,9 @@ impl PyTermOperationImp {
     /// * `name` - Optional name for the operation (if None, uses term's string representation)
     /// * `term` - The term to interpret (VariableImp or NonVariableTerm)
     /// * `variables` - List of variables (VariableImp instances or variable names as strings)
-    /// * `algebra` - The algebra for interpretation
+    /// * `algebra` - The algebra for interpretation (BasicAlgebra, ProductAlgebra, PowerAlgebra, ...)
+    /// * `eager` - Compute the value table now (default True); otherwise values are
+    ///   computed on demand until `make_table` is called
     ///
     /// # Returns
     /// A new TermOperationImp instance
@@ -32,12 +34,13 @@ impl PyTermOperationImp {
     /// # Raises
     /// ValueError: If parameters are invalid
     #[new]
-    #[pyo3(signature = (term, variables, algebra, name=None))]
+    #[pyo3(signature = (term, variables, algebra, name=None, eager=true))]
     fn new(
         term: &Bound<'_, PyAny>,
         variables: &Bound<'_, PyList>,
-        algebra: &PyBasicAlgebra,
+        algebra: &Bound<'_, PyAny>,
         name: Option<String>,
+        eager: bool,
     ) -> PyResult<Self> {
         // Convert term to Box<dyn Term>
         let term_box: Box<dyn Term> = if let Ok(var) = term.extract::<PyRef<PyVariableImp>>() {
@@ -65,36 +68,17 @@ impl PyTermOperationImp {
         }
 
         // Get algebra as Arc
-        let alg_arc: Arc<dyn SmallAlgebra<UniverseItem = i32>> = 
-            Arc::new(algebra.inner.clone());
-
-        // Get interpretation
-        let interpretation = term_box.interpretation(alg_arc.clone(), &var_list, true)
-            .map_err(|e| PyValueError::new_err(e))?;
-
-        // Create TermOperationImp
-        let term_clone = term_box.clone_box();
-        let term_op = if let Some(op_name) = name {
-            TermOperationImp::new_with_name_safe(
-                op_name,
-                term_clone,
-                var_list,
-                alg_arc,
-                interpretation,
-            )
-        } else {
-            TermOperationImp::new_safe(
-                term_clone,
-                var_list,
-                alg_arc,
-                interpretation,
-            )
-        };
-
-        match term_op {
-            Ok(inner) => Ok(PyTermOperationImp { inner }),
-            Err(e) => Err(PyValueError::new_err(e)),
-        }
+        let alg_arc: Arc<dyn SmallAlgebra<UniverseItem = i32>> =
+            Arc::from(small_algebra_from_py(algebra)?);
+
+        let term_op = TermOperationImp::from_term(term_box, var_list, alg_arc, eager)
+            .map_err(PyValueError::new_err)?;
+        Ok(PyTermOperationImp {
+            inner: match name {
+                Some(op_name) => term_op.with_name(&op_name),
+                None => term_op,
+            },
+        })
     }
 
     /// Get the term that this operation interprets.
@@ -154,6 +138,19 @@ impl PyTermOperationImp {
         self.inner.get_table().map(|slice| slice.to_vec())
     }
 
+    /// Compute and store the operation table if it is not there yet.
+    ///
+    /// # Raises
+    /// ValueError: If the table is too large
+    fn make_table(&mut self) -> PyResult<()> {
+        self.inner.make_table().map_err(PyValueError::new_err)
+    }
+
+    /// Check whether the operation table has been computed.
+    fn is_table_based(&self) -> bool {
+        self.inner.is_table_based()
+    }
+
     /// Python string representation.
     fn __str__(&self) -> String {
         format!("{}", self.inner)
@@ -1,16 +1,19 @@
 use pyo3::prelude::*;
 use pyo3::exceptions::PyValueError;
+use pyo3::types::PyTuple;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 use uacalc::alg::{Algebra, SmallAlgebra};
 use uacalc::alg::op::{IntOperation, BasicOperation};
 use crate::alg::{PyBasicAlgebra, PySubalgebraLattice};
 use crate::alg::conlat::congruence_lattice::PyCongruenceLattice;
+use crate::alg::cardinality::cardinality_to_py;
+use crate::alg::product_algebra::PyProductElements;
 
 /// Python wrapper for PowerAlgebra
 #[pyclass]
 pub struct PyPowerAlgebra {
-    inner: uacalc::alg::PowerAlgebra,
+    pub(crate) inner: uacalc::alg::PowerAlgebra,
 }
 
 #[pymethods]
@@ -116,6 +119,47 @@ impl PyPowerAlgebra {
         self.inner.cardinality()
     }
 
+    /// Get the exact cardinality, even when it is too big for cardinality().
+    ///
+    /// Returns:
+    ///     Optional[int]: The cardinality as an int of any size, or None if it
+    ///     is unknown
+    fn exact_cardinality(&self, py: Python<'_>) -> PyResult<PyObject> {
+        cardinality_to_py(py, &self.inner.exact_cardinality())
+    }
+
+    /// Iterate over the elements as tuples of root elements, without
+    /// building the whole universe.
+    ///
+    /// Returns:
+    ///     ProductElements: A lazy iterator of tuples, the first coordinate
+    ///     varying fastest
+    fn elements(&self) -> PyProductElements {
+        PyProductElements { inner: self.inner.elements() }
+    }
+
+    /// Iterate over the elements as tuples of root elements.
+    fn __iter__(&self) -> PyProductElements {
+        self.elements()
+    }
+
+    /// Draw random elements, as tuples of root elements, uniformly and
+    /// independently.
+    ///
+    /// Args:
+    ///     n (int): The number of elements to draw
+    ///     seed (int): The seed of the random number generator
+    ///
+    /// Returns:
+    ///     list[tuple[int, ...]]: The elements drawn
+    ///
+    /// Raises:
+    ///     ValueError: If the root algebra is empty
+    fn sample<'py>(&self, py: Python<'py>, n: usize, seed: u64) -> PyResult<Vec<Bound<'py, PyTuple>>> {
+        let tuples = self.inner.sample(n, seed).map_err(PyValueError::new_err)?;
+        Ok(tuples.into_iter().map(|t| PyTuple::new_bound(py, t)).collect())
+    }
+
     /// Get the name of this power algebra.
     ///
     /// Returns:
@@ -1,13 +1,17 @@
 use pyo3::prelude::*;
-use pyo3::exceptions::PyValueError;
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::types::PyTuple;
 use uacalc::alg::*;
 use crate::alg::{PyBasicAlgebra, PySubalgebraLattice};
 use crate::alg::conlat::congruence_lattice::PyCongruenceLattice;
+use crate::alg::conlat::partition::PyPartition;
+use crate::progress::{with_progress, PyProgressReporter};
+use crate::alg::cardinality::cardinality_to_py;
 
 /// Python wrapper for ProductAlgebra
-#[pyclass]
+#[pyclass(module = "uacalc_lib.alg")]
 pub struct PyProductAlgebra {
-    inner: uacalc::alg::ProductAlgebra,
+    pub(crate) inner: uacalc::alg::ProductAlgebra,
 }
 
 #[pymethods]
@@ -21,7 +25,7 @@ impl PyProductAlgebra {
     /// Raises:
     ///     ValueError: If algebras are incompatible or empty
     #[new]
-    fn new(name: String, algebras: Vec<PyRef<PyBasicAlgebra>>) -> PyResult<Self> {
+    fn new(py: Python<'_>, name: String, algebras: Vec<PyRef<PyBasicAlgebra>>) -> PyResult<Self> {
         if algebras.is_empty() {
             return Err(PyValueError::new_err("Cannot create product of empty algebra list"));
         }
@@ -31,7 +35,7 @@ impl PyProductAlgebra {
             .map(|alg| Box::new(alg.inner.clone()) as Box<dyn uacalc::alg::SmallAlgebra<UniverseItem = i32>>)
             .collect();
 
-        match uacalc::alg::ProductAlgebra::new_safe(name, rust_algebras) {
+        match py.allow_threads(|| uacalc::alg::ProductAlgebra::new_safe(name, rust_algebras)) {
             Ok(inner) => Ok(PyProductAlgebra { inner }),
             Err(e) => Err(PyValueError::new_err(e)),
         }
@@ -79,6 +83,125 @@ impl PyProductAlgebra {
         self.inner.cardinality()
     }
 
+    /// Get the exact cardinality, even when it is too big for cardinality().
+    ///
+    /// Returns:
+    ///     Optional[int]: The cardinality as an int of any size, or None if it
+    ///     is unknown
+    fn exact_cardinality(&self, py: Python<'_>) -> PyResult<PyObject> {
+        cardinality_to_py(py, &self.inner.exact_cardinality())
+    }
+
+    /// Iterate over the elements as tuples of factor elements, without
+    /// building the whole universe.
+    ///
+    /// Returns:
+    ///     ProductElements: A lazy iterator of tuples, the first coordinate
+    ///     varying fastest
+    fn elements(&self) -> PyProductElements {
+        PyProductElements { inner: self.inner.elements() }
+    }
+
+    /// Iterate over the elements as tuples of factor elements.
+    fn __iter__(&self) -> PyProductElements {
+        self.elements()
+    }
+
+    /// Draw random elements, as tuples of factor elements, uniformly and
+    /// independently.
+    ///
+    /// Args:
+    ///     n (int): The number of elements to draw
+    ///     seed (int): The seed of the random number generator
+    ///
+    /// Returns:
+    ///     list[tuple[int, ...]]: The elements drawn
+    ///
+    /// Raises:
+    ///     ValueError: If a factor is empty
+    fn sample<'py>(&self, py: Python<'py>, n: usize, seed: u64) -> PyResult<Vec<Bound<'py, PyTuple>>> {
+        let tuples = self.inner.sample(n, seed).map_err(PyValueError::new_err)?;
+        Ok(tuples.into_iter().map(|t| PyTuple::new_bound(py, t)).collect())
+    }
+
+    /// The congruence generated by a pair of elements, computed through the
+    /// coordinates instead of on the flat product.
+    ///
+    /// Args:
+    ///     a (int): An element of the product
+    ///     b (int): An element of the product
+    ///
+    /// Returns:
+    ///     Partition: The congruence generated by (a, b)
+    ///
+    /// Raises:
+    ///     ValueError: If the product is too big or an element is out of range
+    fn cg(&self, a: usize, b: usize) -> PyResult<PyPartition> {
+        self.inner.cg(a, b).map(PyPartition::from_inner).map_err(PyValueError::new_err)
+    }
+
+    /// The kernel of the projection onto the k-th factor.
+    ///
+    /// Args:
+    ///     k (int): The index of the factor
+    ///
+    /// Returns:
+    ///     Partition: The elements related when their k-th coordinates agree
+    ///
+    /// Raises:
+    ///     ValueError: If k is out of range or the product is too big
+    fn projection_kernel(&self, k: usize) -> PyResult<PyPartition> {
+        self.inner.projection_kernel(k).map(PyPartition::from_inner).map_err(PyValueError::new_err)
+    }
+
+    /// The kernels of all the projections, in the order of the factors.
+    ///
+    /// Returns:
+    ///     List[Partition]: The projection kernels
+    ///
+    /// Raises:
+    ///     ValueError: If the product is too big
+    fn projection_kernels(&self) -> PyResult<Vec<PyPartition>> {
+        let kernels = self.inner.projection_kernels().map_err(PyValueError::new_err)?;
+        Ok(kernels.into_iter().map(PyPartition::from_inner).collect())
+    }
+
+    /// Check whether every congruence of the product is a product of
+    /// congruences of the factors.
+    ///
+    /// Args:
+    ///     progress (ProgressReporter, optional): Progress and cancellation
+    ///
+    /// Returns:
+    ///     bool: Whether there are no skew congruences
+    ///
+    /// Raises:
+    ///     RuntimeError: If the product is too big or the check was cancelled
+    #[pyo3(signature = (progress=None))]
+    fn is_skew_free(&self, py: Python<'_>, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+        let inner = &self.inner;
+        with_progress(py, progress, || inner.is_skew_free().map_err(PyRuntimeError::new_err))
+    }
+
+    /// The congruences of the product that are not products of congruences
+    /// of the factors.
+    ///
+    /// Args:
+    ///     progress (ProgressReporter, optional): Progress and cancellation
+    ///
+    /// Returns:
+    ///     List[Partition]: The skew congruences
+    ///
+    /// Raises:
+    ///     RuntimeError: If the product is too big or the computation was
+    ///         cancelled
+    #[pyo3(signature = (progress=None))]
+    fn skew_congruences(&self, py: Python<'_>, progress: Option<&PyProgressReporter>) -> PyResult<Vec<PyPartition>> {
+        let inner = &self.inner;
+        let skew = with_progress(py, progress, || inner.skew_congruences().map_err(PyRuntimeError::new_err))?;
+        Ok(skew.into_iter().map(PyPartition::from_inner).collect())
+    }
+
     /// Get the element at the given index.
     ///
     /// Args:
@@ -133,6 +256,15 @@ impl PyProductAlgebra {
         self.inner.make_operation_tables();
     }
 
+    /// Pickle support: the product is rebuilt from its name and factors.
+    fn __reduce__(slf: &Bound<'_, Self>) -> (PyObject, (String, Vec<PyBasicAlgebra>)) {
+        let product = &slf.borrow().inner;
+        let factors = product.factors().iter()
+            .map(|f| PyBasicAlgebra { inner: BasicAlgebra::new(f.name().to_string(), f.universe().collect(), f.operations()) })
+            .collect();
+        (slf.get_type().into_any().unbind(), (product.name().to_string(), factors))
+    }
+
     /// Python string representation
     fn __str__(&self) -> String {
         self.inner.to_string()
@@ -160,4 +292,21 @@ impl PyProductAlgebra {
         let sub_lat = self.inner.sub();
         PySubalgebraLattice::from_inner(sub_lat.clone())
     }
-}
\ No newline at end of file
+}
+/// Lazy iterator over the elements of a product or power algebra, as tuples
+/// of factor elements.
+#[pyclass]
+pub struct PyProductElements {
+    pub(crate) inner: uacalc::alg::ProductElements,
+}
+
+#[pymethods]
+impl PyProductElements {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__<'py>(&mut self, py: Python<'py>) -> Option<Bound<'py, PyTuple>> {
+        self.inner.next().map(|t| PyTuple::new_bound(py, t))
+    }
+}
@@ -0,0 +1,93 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use uacalc::alg::quasivariety::{self, QuasivarietyAnalysis};
+use crate::alg::homomorphism::PyHomomorphism;
+use crate::eq::PyQuasiEquation;
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register the quasivariety membership test.
+pub fn register_quasivariety_functions(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(in_quasivariety, m)?)?;
+    m.add_class::<PyQuasivarietyAnalysis>()?;
+    m.add("QuasivarietyAnalysis", m.getattr("PyQuasivarietyAnalysis")?)?;
+    Ok(())
+}
+
+/// Whether B is in the quasivariety SP(A), with an embedding into a power
+/// of A or a counter quasi-equation as witness.
+#[pyclass(module = "uacalc_lib.alg")]
+#[derive(Clone)]
+pub struct PyQuasivarietyAnalysis {
+    inner: QuasivarietyAnalysis,
+}
+
+#[pymethods]
+impl PyQuasivarietyAnalysis {
+    /// Whether B is in SP(A).
+    fn holds(&self) -> bool {
+        self.inner.holds()
+    }
+
+    /// The homomorphisms from B into A found by the search.
+    #[getter]
+    fn homomorphisms(&self) -> Vec<PyHomomorphism> {
+        self.inner.homomorphisms.iter().cloned().map(PyHomomorphism::from_inner).collect()
+    }
+
+    /// For each element of B its tuple in A^k, or None.
+    #[getter]
+    fn embedding(&self) -> Option<Vec<Vec<i32>>> {
+        self.inner.embedding.clone()
+    }
+
+    /// A pair of elements of B no homomorphism into A separates, or None.
+    #[getter]
+    fn unseparated(&self) -> Option<(usize, usize)> {
+        self.inner.unseparated
+    }
+
+    /// A quasi-equation true in A and false in B, or None.
+    #[getter]
+    fn counter_quasi_equation(&self) -> Option<PyQuasiEquation> {
+        self.inner.counter_quasi_equation.clone().map(|inner| PyQuasiEquation { inner })
+    }
+
+    fn __bool__(&self) -> bool {
+        self.inner.holds()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("QuasivarietyAnalysis(holds={}, homomorphisms={}, unseparated={:?})",
+            self.inner.holds(), self.inner.homomorphisms.len(), self.inner.unseparated)
+    }
+}
+
+/// Decide whether B is in the quasivariety SP(A) generated by A.
+///
+/// Args:
+///     b: The algebra to test (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     a: The generating algebra
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     QuasivarietyAnalysis: With the embedding of B into a power of A, or
+///         an unseparated pair and a counter quasi-equation
+///
+/// Raises:
+///     ValueError: If A lacks an operation of B or the computation was
+///         cancelled
+#[pyfunction]
+#[pyo3(signature = (b, a, progress=None))]
+fn in_quasivariety(
+    py: Python<'_>,
+    b: &Bound<'_, PyAny>,
+    a: &Bound<'_, PyAny>,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<PyQuasivarietyAnalysis> {
+    let b = crate::alg::small_algebra_from_py(b)?;
+    let a = crate::alg::small_algebra_from_py(a)?;
+    let inner = with_progress(py, progress, || {
+        quasivariety::in_quasivariety(b.as_ref(), a.as_ref()).map_err(PyValueError::new_err)
+    })?;
+    Ok(PyQuasivarietyAnalysis { inner })
+}
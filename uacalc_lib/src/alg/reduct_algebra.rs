@@ -11,7 +11,7 @@ use crate::alg::conlat::congruence_lattice::PyCongruenceLattice;
 
 #[pyclass]
 pub struct PyReductAlgebra {
-    inner: uacalc::alg::ReductAlgebra,
+    pub(crate) inner: uacalc::alg::ReductAlgebra,
 }
 
 impl PyReductAlgebra {
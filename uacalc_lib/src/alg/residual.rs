@@ -0,0 +1,167 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use uacalc::alg::residual;
+use crate::alg::PyBasicAlgebra;
+use crate::alg::conlat::partition::PyPartition;
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register subdirectly irreducible and splitting algebra functions.
+pub fn register_residual_functions(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(si_members_up_to, m)?)?;
+    m.add_function(wrap_pyfunction!(splitting_pairs, m)?)?;
+    m.add_function(wrap_pyfunction!(in_hs, m)?)?;
+    m.add_function(wrap_pyfunction!(subdirectly_irreducibles_in_hs, m)?)?;
+    m.add_function(wrap_pyfunction!(subdirectly_irreducibles_in_variety, m)?)?;
+    m.add_class::<PySubdirectlyIrreducibles>()?;
+    m.add("SubdirectlyIrreducibles", m.getattr("PySubdirectlyIrreducibles")?)?;
+    Ok(())
+}
+
+/// Find the subdirectly irreducible members of the variety generated by an
+/// algebra with at most max_size elements, up to isomorphism.
+///
+/// The SIs are found among the quotients of the free algebra on max_gens
+/// generators, which contains every SI generated by max_gens elements.
+///
+/// Args:
+///     algebra (BasicAlgebra): The algebra generating the variety
+///     max_size (int): The largest size of the SIs
+///     max_gens (int, optional): The number of free generators; defaults to max_size
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     List[BasicAlgebra]: The SIs, ordered by size
+///
+/// Raises:
+///     ValueError: If max_gens is 0, the free algebra is too large or the
+///         computation was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, max_size, max_gens=None, progress=None))]
+fn si_members_up_to(
+    py: Python<'_>,
+    algebra: &PyBasicAlgebra,
+    max_size: usize,
+    max_gens: Option<usize>,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Vec<PyBasicAlgebra>> {
+    let max_gens = max_gens.unwrap_or(max_size);
+    let sis = with_progress(py, progress, || {
+        residual::si_members_up_to(&algebra.inner, max_size, max_gens).map_err(PyValueError::new_err)
+    })?;
+    Ok(sis.into_iter().map(|inner| PyBasicAlgebra { inner }).collect())
+}
+
+/// Find the splitting pairs of the congruence distributive variety generated
+/// by a finite algebra.
+///
+/// Args:
+///     algebra (BasicAlgebra): An algebra with Jonsson terms
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     List[Tuple[BasicAlgebra, List[BasicAlgebra]]]: For each splitting
+///         algebra S, the SIs T with S not in HS(T), which generate its
+///         conjugate variety
+///
+/// Raises:
+///     ValueError: If the variety is not congruence distributive or the
+///         computation was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn splitting_pairs(
+    py: Python<'_>,
+    algebra: &PyBasicAlgebra,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Vec<(PyBasicAlgebra, Vec<PyBasicAlgebra>)>> {
+    let pairs = with_progress(py, progress, || {
+        residual::splitting_pairs(&algebra.inner).map_err(PyValueError::new_err)
+    })?;
+    Ok(pairs
+        .into_iter()
+        .map(|pair| {
+            let conjugate = pair.conjugate.into_iter().map(|inner| PyBasicAlgebra { inner }).collect();
+            (PyBasicAlgebra { inner: pair.algebra }, conjugate)
+        })
+        .collect())
+}
+
+/// Decide whether s is isomorphic to a quotient of a subalgebra of t.
+///
+/// Args:
+///     s (BasicAlgebra): The algebra to look for
+///     t (BasicAlgebra): The algebra to search
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     bool: Whether s is in HS(t)
+#[pyfunction]
+#[pyo3(signature = (s, t, progress=None))]
+fn in_hs(
+    py: Python<'_>,
+    s: &PyBasicAlgebra,
+    t: &PyBasicAlgebra,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<bool> {
+    with_progress(py, progress, || {
+        residual::in_hs(&s.inner, &t.inner).map_err(PyValueError::new_err)
+    })
+}
+
+/// Lazy iterator over subdirectly irreducible algebras and their monoliths.
+#[pyclass]
+pub struct PySubdirectlyIrreducibles {
+    inner: residual::SubdirectlyIrreducibles,
+}
+
+#[pymethods]
+impl PySubdirectlyIrreducibles {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(&mut self) -> PyResult<Option<(PyBasicAlgebra, PyPartition)>> {
+        match self.inner.next() {
+            Some(Ok(si)) => Ok(Some((PyBasicAlgebra { inner: si.algebra }, PyPartition { inner: si.monolith }))),
+            Some(Err(e)) => Err(PyValueError::new_err(e)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Enumerate the subdirectly irreducible members of HS(algebra) up to
+/// isomorphism, lazily: the SI quotients of its subalgebras.
+///
+/// Args:
+///     algebra (BasicAlgebra): The algebra
+///
+/// Returns:
+///     SubdirectlyIrreducibles: An iterator of (BasicAlgebra, Partition)
+///         pairs, each SI with its monolith
+///
+/// Raises:
+///     ValueError: If the subuniverses could not be computed
+#[pyfunction]
+fn subdirectly_irreducibles_in_hs(algebra: &PyBasicAlgebra) -> PyResult<PySubdirectlyIrreducibles> {
+    residual::subdirectly_irreducibles_in_hs(&algebra.inner)
+        .map(|inner| PySubdirectlyIrreducibles { inner })
+        .map_err(PyValueError::new_err)
+}
+
+/// Enumerate the subdirectly irreducible members of the variety generated by
+/// an algebra with at most max_size elements up to isomorphism, lazily.
+///
+/// The SIs are found among the quotients of the free algebras on 1, 2, ...,
+/// max_size generators in turn, so SIs with few generators come first.
+/// Iterating raises ValueError if a free algebra is too large.
+///
+/// Args:
+///     algebra (BasicAlgebra): The algebra generating the variety
+///     max_size (int): The largest size of the SIs
+///
+/// Returns:
+///     SubdirectlyIrreducibles: An iterator of (BasicAlgebra, Partition)
+///         pairs, each SI with its monolith
+#[pyfunction]
+fn subdirectly_irreducibles_in_variety(algebra: &PyBasicAlgebra, max_size: usize) -> PySubdirectlyIrreducibles {
+    PySubdirectlyIrreducibles { inner: residual::subdirectly_irreducibles_in_variety(&algebra.inner, max_size) }
+}
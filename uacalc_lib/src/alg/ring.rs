@@ -0,0 +1,137 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use uacalc::alg::ring;
+use crate::alg::PyBasicAlgebra;
+use crate::alg::conlat::partition::PyPartition;
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register ring and module functions.
+pub fn register_ring_functions(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(zn_ring, m)?)?;
+    m.add_function(wrap_pyfunction!(matrix_ring, m)?)?;
+    m.add_function(wrap_pyfunction!(zn_module, m)?)?;
+    m.add_function(wrap_pyfunction!(regular_module, m)?)?;
+    m.add_function(wrap_pyfunction!(ideals, m)?)?;
+    m.add_function(wrap_pyfunction!(ideal_of_congruence, m)?)?;
+    m.add_function(wrap_pyfunction!(congruence_of_ideal, m)?)?;
+    Ok(())
+}
+
+/// Create the ring Z_n of integers modulo n, with the operations add, neg,
+/// zero, mul and one.
+///
+/// Args:
+///     n (int): The modulus
+///
+/// Returns:
+///     BasicAlgebra: The ring, named Z{n}
+///
+/// Raises:
+///     ValueError: If n is 0 or too large
+#[pyfunction]
+fn zn_ring(n: usize) -> PyResult<PyBasicAlgebra> {
+    ring::zn_ring(n).map(|inner| PyBasicAlgebra { inner }).map_err(PyValueError::new_err)
+}
+
+/// Create the ring of dim x dim matrices over Z_p.
+///
+/// A matrix is encoded by its entries in base p, row by row, the entry
+/// (0, 0) the least significant digit.
+///
+/// Args:
+///     dim (int): The number of rows and columns
+///     p (int): The modulus of the entries
+///
+/// Returns:
+///     BasicAlgebra: The ring, named M{dim}(Z{p})
+///
+/// Raises:
+///     ValueError: If dim or p is 0 or the ring is too large
+#[pyfunction]
+fn matrix_ring(dim: usize, p: usize) -> PyResult<PyBasicAlgebra> {
+    ring::matrix_ring(dim, p).map(|inner| PyBasicAlgebra { inner }).map_err(PyValueError::new_err)
+}
+
+/// Create the module Z_n^dim over Z_n, with add, neg, zero and a unary
+/// operation s{r} for each scalar r.
+///
+/// Args:
+///     n (int): The modulus of the scalars
+///     dim (int): The number of coordinates
+///
+/// Returns:
+///     BasicAlgebra: The module, named Z{n}^{dim}
+///
+/// Raises:
+///     ValueError: If n is 0 or the module is too large
+#[pyfunction]
+fn zn_module(n: usize, dim: usize) -> PyResult<PyBasicAlgebra> {
+    ring::zn_module(n, dim).map(|inner| PyBasicAlgebra { inner }).map_err(PyValueError::new_err)
+}
+
+/// Create the left regular module of a ring, whose submodules are its left
+/// ideals.
+///
+/// Args:
+///     ring (BasicAlgebra): An algebra with a ring reduct
+///
+/// Returns:
+///     BasicAlgebra: The module, with a unary operation s{r} for each r
+///
+/// Raises:
+///     ValueError: If the algebra has no ring reduct
+#[pyfunction]
+fn regular_module(ring: &PyBasicAlgebra) -> PyResult<PyBasicAlgebra> {
+    ring::regular_module(&ring.inner).map(|inner| PyBasicAlgebra { inner }).map_err(PyValueError::new_err)
+}
+
+/// Get the ideals of a ring, or the submodules of a module, as the blocks
+/// of 0 of its congruences.
+///
+/// Args:
+///     algebra (BasicAlgebra): A ring or module
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     List[List[int]]: The ideals, each sorted, ordered by size
+///
+/// Raises:
+///     ValueError: If the algebra has no abelian group operation or the
+///         computation was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn ideals(py: Python<'_>, algebra: &PyBasicAlgebra, progress: Option<&PyProgressReporter>) -> PyResult<Vec<Vec<usize>>> {
+    with_progress(py, progress, || ring::ideals(&algebra.inner).map_err(PyValueError::new_err))
+}
+
+/// Get the ideal of a congruence: the block of 0.
+///
+/// Args:
+///     algebra (BasicAlgebra): A ring or module
+///     theta (Partition): A congruence of the algebra
+///
+/// Returns:
+///     List[int]: The block of 0, sorted
+///
+/// Raises:
+///     ValueError: If the algebra has no abelian group operation
+#[pyfunction]
+fn ideal_of_congruence(algebra: &PyBasicAlgebra, theta: &PyPartition) -> PyResult<Vec<usize>> {
+    ring::ideal_of_congruence(&algebra.inner, &theta.inner).map_err(PyValueError::new_err)
+}
+
+/// Get the congruence of an ideal, whose blocks are its cosets.
+///
+/// Args:
+///     algebra (BasicAlgebra): A ring or module
+///     ideal (List[int]): The elements of the ideal
+///
+/// Returns:
+///     Partition: The congruence
+///
+/// Raises:
+///     ValueError: If the set is not an ideal
+#[pyfunction]
+fn congruence_of_ideal(algebra: &PyBasicAlgebra, ideal: Vec<usize>) -> PyResult<PyPartition> {
+    ring::congruence_of_ideal(&algebra.inner, &ideal).map(PyPartition::from_inner).map_err(PyValueError::new_err)
+}
@@ -0,0 +1,236 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use uacalc::alg::search::{self, IdentityRhs, LinearIdentity, SearchBackend};
+use crate::alg::PyBasicAlgebra;
+use crate::lat::PyPoset;
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register homomorphism and polymorphism search functions.
+pub fn register_search_functions(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(find_homomorphism, m)?)?;
+    m.add_function(wrap_pyfunction!(find_isomorphism, m)?)?;
+    m.add_function(wrap_pyfunction!(find_polymorphism, m)?)?;
+    m.add_function(wrap_pyfunction!(is_polymorphism, m)?)?;
+    m.add_function(wrap_pyfunction!(find_digraph_homomorphism, m)?)?;
+    m.add_function(wrap_pyfunction!(find_digraph_polymorphism, m)?)?;
+    m.add_function(wrap_pyfunction!(represent_lattice, m)?)?;
+    m.add_function(wrap_pyfunction!(represent_subalgebra_lattice, m)?)?;
+    Ok(())
+}
+
+pub(crate) fn parse_backend(name: &str) -> PyResult<SearchBackend> {
+    SearchBackend::from_name(name).map_err(PyValueError::new_err)
+}
+
+fn parse_identities(identities: Vec<(Vec<usize>, Bound<'_, PyAny>)>) -> PyResult<Vec<LinearIdentity>> {
+    let mut ids = Vec::with_capacity(identities.len());
+    for (lhs, rhs) in identities {
+        let rhs = if let Ok(v) = rhs.extract::<usize>() {
+            IdentityRhs::Var(v)
+        } else {
+            IdentityRhs::Pattern(rhs.extract::<Vec<usize>>()?)
+        };
+        ids.push(LinearIdentity::new(lhs, rhs));
+    }
+    Ok(ids)
+}
+
+/// Find a homomorphism between two algebras.
+///
+/// # Arguments
+/// * `a` - The domain algebra (BasicAlgebra)
+/// * `b` - The target algebra (BasicAlgebra)
+/// * `backend` - "ac" (default), "sac", "backtrack" or "sat" (with the sat feature)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
+///
+/// # Returns
+/// The list of images of the elements of `a`, or None if there is no homomorphism
+#[pyfunction]
+#[pyo3(signature = (a, b, backend="ac", progress=None))]
+fn find_homomorphism(
+    py: Python<'_>,
+    a: &PyBasicAlgebra,
+    b: &PyBasicAlgebra,
+    backend: &str,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Option<Vec<i32>>> {
+    let backend = parse_backend(backend)?;
+    with_progress(py, progress, || {
+        search::find_homomorphism(&a.inner, &b.inner, backend).map_err(PyValueError::new_err)
+    })
+}
+
+/// Find an isomorphism between two algebras.
+///
+/// # Arguments
+/// * `a` - The domain algebra (BasicAlgebra)
+/// * `b` - The target algebra (BasicAlgebra)
+/// * `backend` - "ac" (default), "sac", "backtrack" or "sat" (with the sat feature)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
+///
+/// # Returns
+/// The list of images of the elements of `a`, or None if the algebras are not isomorphic
+#[pyfunction]
+#[pyo3(signature = (a, b, backend="ac", progress=None))]
+fn find_isomorphism(
+    py: Python<'_>,
+    a: &PyBasicAlgebra,
+    b: &PyBasicAlgebra,
+    backend: &str,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Option<Vec<i32>>> {
+    let backend = parse_backend(backend)?;
+    with_progress(py, progress, || {
+        search::find_isomorphism(&a.inner, &b.inner, backend).map_err(PyValueError::new_err)
+    })
+}
+
+/// Find a polymorphism of an algebra satisfying linear identities.
+///
+/// # Arguments
+/// * `algebra` - The algebra (BasicAlgebra)
+/// * `arity` - The arity of the polymorphism
+/// * `identities` - List of `(lhs, rhs)` pairs: `lhs` is a list of variable
+///   indices and `rhs` is either a variable index or another such list,
+///   e.g. `[([0, 1, 1], 0), ([1, 1, 0], 0)]` for a Maltsev operation
+/// * `backend` - "ac" (default), "sac", "backtrack" or "sat" (with the sat feature)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
+///
+/// # Returns
+/// The Horner-encoded table of a polymorphism, or None if there is none
+#[pyfunction]
+#[pyo3(signature = (algebra, arity, identities=Vec::new(), backend="ac", progress=None))]
+fn find_polymorphism(
+    py: Python<'_>,
+    algebra: &PyBasicAlgebra,
+    arity: usize,
+    identities: Vec<(Vec<usize>, Bound<'_, PyAny>)>,
+    backend: &str,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Option<Vec<i32>>> {
+    let ids = parse_identities(identities)?;
+    let backend = parse_backend(backend)?;
+    with_progress(py, progress, || {
+        search::find_polymorphism(&algebra.inner, arity, &ids, backend).map_err(PyValueError::new_err)
+    })
+}
+
+/// Test whether a Horner-encoded table is a polymorphism of an algebra.
+#[pyfunction]
+fn is_polymorphism(algebra: &PyBasicAlgebra, arity: usize, table: Vec<i32>) -> PyResult<bool> {
+    search::is_polymorphism(&algebra.inner, arity, &table)
+        .map_err(PyValueError::new_err)
+}
+
+/// Find a homomorphism between digraphs given by edge lists, e.g. a
+/// 3-coloring of G when H is the complete graph K3.
+///
+/// # Arguments
+/// * `g_edges` - The edges of G, a list of (source, target) pairs
+/// * `g_size` - The number of vertices of G
+/// * `h_edges` - The edges of H
+/// * `h_size` - The number of vertices of H
+/// * `backend` - "ac" (default), "sac", "backtrack" or "sat" (with the sat feature)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
+///
+/// # Returns
+/// The list of images of the vertices of G, or None if there is no homomorphism
+#[pyfunction]
+#[pyo3(signature = (g_edges, g_size, h_edges, h_size, backend="ac", progress=None))]
+fn find_digraph_homomorphism(
+    py: Python<'_>,
+    g_edges: Vec<(usize, usize)>,
+    g_size: usize,
+    h_edges: Vec<(usize, usize)>,
+    h_size: usize,
+    backend: &str,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Option<Vec<i32>>> {
+    let backend = parse_backend(backend)?;
+    with_progress(py, progress, || {
+        search::find_digraph_homomorphism(&g_edges, g_size, &h_edges, h_size, backend)
+            .map_err(PyValueError::new_err)
+    })
+}
+
+/// Find a polymorphism of a digraph satisfying linear identities.
+///
+/// # Arguments
+/// * `edges` - The edges, a list of (source, target) pairs
+/// * `n` - The number of vertices
+/// * `arity` - The arity of the polymorphism
+/// * `identities` - List of `(lhs, rhs)` pairs as for `find_polymorphism`
+/// * `backend` - "ac" (default), "sac", "backtrack" or "sat" (with the sat feature)
+/// * `progress` - Optional ProgressReporter for progress and cancellation
+///
+/// # Returns
+/// The Horner-encoded table of a polymorphism, or None if there is none
+#[pyfunction]
+#[pyo3(signature = (edges, n, arity, identities=Vec::new(), backend="ac", progress=None))]
+fn find_digraph_polymorphism(
+    py: Python<'_>,
+    edges: Vec<(usize, usize)>,
+    n: usize,
+    arity: usize,
+    identities: Vec<(Vec<usize>, Bound<'_, PyAny>)>,
+    backend: &str,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Option<Vec<i32>>> {
+    let ids = parse_identities(identities)?;
+    let backend = parse_backend(backend)?;
+    with_progress(py, progress, || {
+        search::find_digraph_polymorphism(&edges, n, arity, &ids, backend).map_err(PyValueError::new_err)
+    })
+}
+
+/// Search for an algebra whose congruence lattice is isomorphic to a given
+/// lattice. Experimental; the search is exhaustive for each size but grows
+/// very quickly with it.
+///
+/// # Arguments
+/// * `lattice` - The lattice to represent (Poset)
+/// * `max_algebra_size` - The largest universe to try
+/// * `progress` - Optional ProgressReporter for progress and cancellation
+///
+/// # Returns
+/// A unary BasicAlgebra of the smallest possible size, or None if there is
+/// none with at most `max_algebra_size` elements
+#[pyfunction]
+#[pyo3(signature = (lattice, max_algebra_size, progress=None))]
+fn represent_lattice(
+    py: Python<'_>,
+    lattice: &PyPoset,
+    max_algebra_size: usize,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Option<PyBasicAlgebra>> {
+    let found = with_progress(py, progress, || {
+        uacalc::alg::conlat::represent(&lattice.inner, max_algebra_size).map_err(PyValueError::new_err)
+    })?;
+    Ok(found.map(PyBasicAlgebra::from_inner))
+}
+
+/// Search for a unary algebra whose subalgebra lattice is isomorphic to a
+/// given lattice. Only distributive lattices occur, so any other lattice
+/// gives None at once.
+///
+/// # Arguments
+/// * `lattice` - The lattice to represent (Poset)
+/// * `max_size` - The largest universe to try, at most 8
+/// * `progress` - Optional ProgressReporter for progress and cancellation
+///
+/// # Returns
+/// A unary BasicAlgebra of the smallest possible size, or None if there is
+/// none with at most `max_size` elements
+#[pyfunction]
+#[pyo3(signature = (lattice, max_size, progress=None))]
+fn represent_subalgebra_lattice(
+    py: Python<'_>,
+    lattice: &PyPoset,
+    max_size: usize,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Option<PyBasicAlgebra>> {
+    let found = with_progress(py, progress, || {
+        uacalc::alg::sublat::represent(&lattice.inner, max_size).map_err(PyValueError::new_err)
+    })?;
+    Ok(found.map(PyBasicAlgebra::from_inner))
+}
@@ -0,0 +1,126 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use uacalc::alg::simple::{self, SimplicityReport};
+use crate::alg::conlat::partition::PyPartition;
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register the simplicity checks.
+pub fn register_simple_functions(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(is_simple, m)?)?;
+    m.add_function(wrap_pyfunction!(is_strictly_simple, m)?)?;
+    m.add_function(wrap_pyfunction!(check_simple, m)?)?;
+    m.add_function(wrap_pyfunction!(check_strictly_simple, m)?)?;
+    m.add_class::<PySimplicityReport>()?;
+    m.add("SimplicityReport", m.getattr("PySimplicityReport")?)?;
+    Ok(())
+}
+
+/// The outcome of a simplicity check with its witness on failure.
+///
+/// Truthy exactly when the checked property holds.
+#[pyclass(module = "uacalc_lib.alg")]
+#[derive(Clone)]
+pub struct PySimplicityReport {
+    inner: SimplicityReport,
+}
+
+#[pymethods]
+impl PySimplicityReport {
+    /// Whether the algebra has a single element, so is not simple.
+    #[getter]
+    fn trivial(&self) -> bool {
+        self.inner.trivial
+    }
+
+    /// A congruence other than 0 and 1, or None.
+    #[getter]
+    fn congruence(&self) -> Option<PyPartition> {
+        self.inner.congruence.clone().map(PyPartition::from_inner)
+    }
+
+    /// A proper subuniverse with at least two elements, or None.
+    #[getter]
+    fn subuniverse(&self) -> Option<Vec<i32>> {
+        self.inner.subuniverse.as_ref().map(|s| s.elements().clone())
+    }
+
+    /// Whether the checked property holds.
+    fn holds(&self) -> bool {
+        self.inner.holds()
+    }
+
+    fn __bool__(&self) -> bool {
+        self.inner.holds()
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SimplicityReport({})", self.inner)
+    }
+}
+
+fn run(
+    py: Python<'_>,
+    algebra: &Bound<'_, PyAny>,
+    progress: Option<&PyProgressReporter>,
+    check: fn(&dyn uacalc::alg::SmallAlgebra<UniverseItem = i32>) -> Result<SimplicityReport, String>,
+) -> PyResult<PySimplicityReport> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    let inner = with_progress(py, progress, || check(alg.as_ref()).map_err(PyValueError::new_err))?;
+    Ok(PySimplicityReport { inner })
+}
+
+/// Check whether an algebra is simple, with a nontrivial proper congruence
+/// as witness if not. Con(A) is not built.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     SimplicityReport: The verdict and its witness
+///
+/// Raises:
+///     ValueError: If the computation was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn check_simple(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<PySimplicityReport> {
+    run(py, algebra, progress, simple::check_simple)
+}
+
+/// Check whether an algebra is strictly simple: simple with no proper
+/// subuniverse of two or more elements. The witness is a congruence or such
+/// a subuniverse; neither Con(A) nor Sub(A) is built.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     SimplicityReport: The verdict and its witness
+///
+/// Raises:
+///     ValueError: If an operation cannot be evaluated or the computation
+///         was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn check_strictly_simple(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<PySimplicityReport> {
+    run(py, algebra, progress, simple::check_strictly_simple)
+}
+
+/// Test whether an algebra is simple.
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn is_simple(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+    Ok(check_simple(py, algebra, progress)?.inner.holds())
+}
+
+/// Test whether an algebra is strictly simple.
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn is_strictly_simple(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+    Ok(check_strictly_simple(py, algebra, progress)?.inner.holds())
+}
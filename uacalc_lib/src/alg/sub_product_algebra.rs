@@ -0,0 +1,101 @@
+//! Python wrapper for SubProductAlgebra
+
+use pyo3::prelude::*;
+use uacalc::alg::Algebra;
+use crate::alg::PyBasicAlgebra;
+use crate::alg::conlat::congruence_lattice::PyCongruenceLatticeIntArray;
+use crate::util::PyIntArray;
+
+/// Python wrapper for SubProductAlgebra: a subalgebra of a direct product,
+/// its elements tuples of factor elements.
+#[pyclass]
+pub struct PySubProductAlgebra {
+    pub(crate) inner: uacalc::alg::SubProductAlgebra<i32>,
+}
+
+#[pymethods]
+impl PySubProductAlgebra {
+    /// Get the name of the algebra.
+    ///
+    /// Returns:
+    ///     str: The name
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    /// Get the cardinality of the algebra.
+    ///
+    /// Returns:
+    ///     int: The cardinality
+    fn cardinality(&self) -> i32 {
+        self.inner.cardinality()
+    }
+
+    /// Get the number of factors of the product it is a subalgebra of.
+    ///
+    /// Returns:
+    ///     int: The number of factors
+    fn number_of_factors(&self) -> usize {
+        self.inner.super_algebra().get_number_of_factors()
+    }
+
+    /// Get the universe as a list.
+    ///
+    /// Returns:
+    ///     List[IntArray]: The universe elements, tuples of factor elements
+    fn get_universe_list(&self) -> Vec<PyIntArray> {
+        self.inner.get_universe_list().iter().map(|ia| PyIntArray { inner: ia.clone() }).collect()
+    }
+
+    /// Get an element by its index.
+    ///
+    /// Args:
+    ///     index (int): The index of the element
+    ///
+    /// Returns:
+    ///     Optional[IntArray]: The element, or None if the index is out of range
+    fn get_element(&self, index: usize) -> Option<PyIntArray> {
+        self.inner.get_element(index).map(|ia| PyIntArray { inner: ia.clone() })
+    }
+
+    /// Get the index of an element.
+    ///
+    /// Args:
+    ///     element (IntArray): The element to find
+    ///
+    /// Returns:
+    ///     Optional[int]: The index, or None if it is not an element
+    fn element_index(&self, element: &PyIntArray) -> Option<usize> {
+        self.inner.element_index(&element.inner)
+    }
+
+    /// Convert to a BasicAlgebra on the indices of the elements.
+    ///
+    /// Returns:
+    ///     BasicAlgebra: An isomorphic algebra on {0, ..., n-1}
+    fn to_basic_algebra(&self) -> PyBasicAlgebra {
+        PyBasicAlgebra {
+            inner: uacalc::alg::BasicAlgebra::new(
+                self.inner.name().to_string(),
+                (0..self.inner.cardinality()).collect(),
+                self.inner.operations(),
+            ),
+        }
+    }
+
+    /// Get the congruence lattice (lazy initialization).
+    ///
+    /// Returns:
+    ///     CongruenceLatticeIntArray: The congruence lattice
+    fn con(&mut self) -> PyCongruenceLatticeIntArray {
+        PyCongruenceLatticeIntArray { inner: self.inner.con().clone() }
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.cardinality() as usize
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SubProductAlgebra(name='{}', cardinality={})", self.inner.name(), self.inner.cardinality())
+    }
+}
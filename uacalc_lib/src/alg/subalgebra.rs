@@ -10,7 +10,7 @@ use crate::alg::conlat::congruence_lattice::PyCongruenceLattice;
 /// Python wrapper for Subalgebra
 #[pyclass]
 pub struct PySubalgebra {
-    inner: uacalc::alg::Subalgebra<i32>,
+    pub(crate) inner: uacalc::alg::Subalgebra<i32>,
 }
 
 #[pymethods]
@@ -202,11 +202,11 @@ impl PySubalgebraLattice {
     /// Get the universe of all subalgebras.
     ///
     /// Returns:
-    ///     List[BasicSet]: List of all subalgebras
+    ///     List[BasicSet]: List of all subalgebras, by size and then
+    ///         lexicographically
     fn universe(&self) -> Vec<PyBasicSet> {
         let mut inner = self.inner.borrow_mut();
-        let univ = inner.universe_mut();
-        univ.iter().map(|bs| PyBasicSet::from_inner(bs.clone())).collect()
+        inner.universe_list().iter().map(|bs| PyBasicSet::from_inner(bs.clone())).collect()
     }
 
     /// Get the cardinality (number of subalgebras).
@@ -218,6 +218,26 @@ impl PySubalgebraLattice {
         inner.universe_mut().len() as i32
     }
 
+    /// Iterate over the subuniverses, generating them lazily.
+    ///
+    /// The subuniverse generated by the constants comes first, then the join
+    /// irreducibles, then their joins as they are found, so a scan can stop
+    /// early without the whole universe being built.
+    ///
+    /// Returns:
+    ///     Subuniverses: An iterator of BasicSet
+    fn subuniverses_iter(&self) -> PySubuniverses {
+        PySubuniverses { inner: self.inner.borrow_mut().subuniverses_iter() }
+    }
+
+    fn __iter__(&self) -> PySubuniverses {
+        self.subuniverses_iter()
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.borrow_mut().universe_mut().len()
+    }
+
     /// Filter subalgebras that are greater than or equal to the given element.
     ///
     /// Args:
@@ -342,4 +362,20 @@ impl PySubalgebraLattice {
     pub(crate) fn from_inner(inner: uacalc::alg::sublat::SubalgebraLattice<i32>) -> Self {
         PySubalgebraLattice { inner: std::cell::RefCell::new(inner) }
     }
-}
\ No newline at end of file
+}
+/// Lazy iterator over the subuniverses of an algebra.
+#[pyclass]
+pub struct PySubuniverses {
+    inner: uacalc::alg::sublat::Subuniverses<i32>,
+}
+
+#[pymethods]
+impl PySubuniverses {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(&mut self) -> Option<PyBasicSet> {
+        self.inner.next().map(PyBasicSet::from_inner)
+    }
+}
@@ -0,0 +1,110 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::types::PyFrozenSet;
+use uacalc::alg::sublat::BasicSet;
+use uacalc::alg::subuniverse_graph::{self, SubuniverseGraph};
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register the subuniverse digraph and maximal subuniverse functions.
+pub fn register_subuniverse_graph(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(maximal_proper_subuniverses, m)?)?;
+    m.add_class::<PySubuniverseGraph>()?;
+    m.add("SubuniverseGraph", m.getattr("PySubuniverseGraph")?)?;
+    Ok(())
+}
+
+fn to_frozensets<'py>(py: Python<'py>, sets: &[BasicSet]) -> PyResult<Vec<Bound<'py, PyFrozenSet>>> {
+    sets.iter().map(|s| PyFrozenSet::new_bound(py, s.elements())).collect()
+}
+
+/// Compute the maximal proper subuniverses of an algebra.
+///
+/// Args:
+///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     List[FrozenSet[int]]: The maximal proper subuniverses, ordered by size
+///
+/// Raises:
+///     ValueError: If an operation cannot be evaluated or the computation
+///         was cancelled
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn maximal_proper_subuniverses<'py>(
+    py: Python<'py>,
+    algebra: &Bound<'py, PyAny>,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Vec<Bound<'py, PyFrozenSet>>> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    let sets = with_progress(py, progress, || {
+        subuniverse_graph::maximal_proper_subuniverses(alg.as_ref()).map_err(PyValueError::new_err)
+    })?;
+    to_frozensets(py, &sets)
+}
+
+/// The subuniverses of an algebra with an edge S -> T whenever T is
+/// generated by S and one more element.
+///
+/// Every subuniverse containing S is reachable from S, so reachability is
+/// inclusion; the maximal proper subuniverses are the proper vertices whose
+/// edges all lead to the whole algebra.
+#[pyclass(module = "uacalc_lib.alg")]
+pub struct PySubuniverseGraph {
+    inner: SubuniverseGraph,
+}
+
+#[pymethods]
+impl PySubuniverseGraph {
+    /// Compute the subuniverses of an algebra and their one element extensions.
+    ///
+    /// Args:
+    ///     algebra: The algebra (BasicAlgebra, ProductAlgebra, Subalgebra, ...)
+    ///     progress (ProgressReporter, optional): Progress and cancellation
+    ///
+    /// Raises:
+    ///     ValueError: If an operation cannot be evaluated or the computation
+    ///         was cancelled
+    #[new]
+    #[pyo3(signature = (algebra, progress=None))]
+    fn new(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<Self> {
+        let alg = crate::alg::small_algebra_from_py(algebra)?;
+        let inner = with_progress(py, progress, || {
+            SubuniverseGraph::new(alg.as_ref()).map_err(PyValueError::new_err)
+        })?;
+        Ok(PySubuniverseGraph { inner })
+    }
+
+    /// The subuniverses, ordered by size; the last is the whole algebra.
+    fn vertices<'py>(&self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyFrozenSet>>> {
+        to_frozensets(py, self.inner.vertices())
+    }
+
+    /// The edges (i, j, a): vertex j is generated by vertex i and the element a.
+    fn edges(&self) -> Vec<(usize, usize, i32)> {
+        self.inner.edges().to_vec()
+    }
+
+    /// The vertices reached from vertex i by a single edge.
+    fn successors(&self, i: usize) -> Vec<usize> {
+        self.inner.successors(i)
+    }
+
+    /// The indices of the maximal proper subuniverses.
+    fn maximal_proper(&self) -> Vec<usize> {
+        self.inner.maximal_proper()
+    }
+
+    /// The digraph in Graphviz DOT format.
+    fn to_dot(&self) -> String {
+        self.inner.to_dot()
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.vertices().len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SubuniverseGraph(vertices={}, edges={})", self.inner.vertices().len(), self.inner.edges().len())
+    }
+}
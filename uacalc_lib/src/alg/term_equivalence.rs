@@ -0,0 +1,77 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use uacalc::alg::term_equivalence::{self, CloneEquivalence};
+use crate::alg::PyBasicAlgebra;
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register term and polynomial equivalence functions.
+pub fn register_term_equivalence_functions(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(are_term_equivalent, m)?)?;
+    m.add_function(wrap_pyfunction!(are_polynomially_equivalent, m)?)?;
+    Ok(())
+}
+
+fn witness_strings(eq: Option<CloneEquivalence>) -> Option<(Vec<String>, Vec<String>)> {
+    eq.map(|eq| (
+        eq.first_in_second.iter().map(|t| t.to_string()).collect(),
+        eq.second_in_first.iter().map(|t| t.to_string()).collect(),
+    ))
+}
+
+/// Test whether two algebras on the same universe have the same clone.
+///
+/// Args:
+///     a (BasicAlgebra): The first algebra
+///     b (BasicAlgebra): The second algebra
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     Optional[Tuple[List[str], List[str]]]: None if they are not term
+///     equivalent; otherwise terms of b producing the operations of a, and
+///     terms of a producing the operations of b, in the variables x0, x1, ...
+///
+/// Raises:
+///     ValueError: If a clone is too large
+#[pyfunction]
+#[pyo3(signature = (a, b, progress=None))]
+fn are_term_equivalent(
+    py: Python<'_>,
+    a: &PyBasicAlgebra,
+    b: &PyBasicAlgebra,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Option<(Vec<String>, Vec<String>)>> {
+    with_progress(py, progress, || {
+        term_equivalence::are_term_equivalent(&a.inner, &b.inner)
+            .map(witness_strings)
+            .map_err(PyValueError::new_err)
+    })
+}
+
+/// Test whether two algebras on the same universe have the same polynomial clone.
+///
+/// Args:
+///     a (BasicAlgebra): The first algebra
+///     b (BasicAlgebra): The second algebra
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     Optional[Tuple[List[str], List[str]]]: None if they are not
+///     polynomially equivalent; otherwise the witness polynomials, with the
+///     constant i written c{i}
+///
+/// Raises:
+///     ValueError: If a clone is too large
+#[pyfunction]
+#[pyo3(signature = (a, b, progress=None))]
+fn are_polynomially_equivalent(
+    py: Python<'_>,
+    a: &PyBasicAlgebra,
+    b: &PyBasicAlgebra,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Option<(Vec<String>, Vec<String>)>> {
+    with_progress(py, progress, || {
+        term_equivalence::are_polynomially_equivalent(&a.inner, &b.inner)
+            .map(witness_strings)
+            .map_err(PyValueError::new_err)
+    })
+}
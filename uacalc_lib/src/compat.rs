@@ -0,0 +1,322 @@
+//! Python wrapper for the Java-style compatibility layer
+
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use uacalc::alg::SmallAlgebra;
+use uacalc::compat::{AlgebraIO, Algebras, Malcev};
+use uacalc::terms::Term;
+use crate::alg::homomorphism::PyHomomorphism;
+use crate::alg::{small_algebra_from_py, PyBasicAlgebra};
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register the compat module.
+pub fn register_compat_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyMalcev>()?;
+    m.add_class::<PyAlgebras>()?;
+    m.add_class::<PyAlgebraIO>()?;
+    Ok(())
+}
+
+type IntAlgebra = dyn SmallAlgebra<UniverseItem = i32>;
+type TermResult = Result<Option<Box<dyn Term>>, String>;
+type TermsResult = Result<Option<Vec<Box<dyn Term>>>, String>;
+
+/// Run a term search on `algebra` and render the term.
+fn find_term(
+    py: Python<'_>,
+    algebra: &Bound<'_, PyAny>,
+    progress: Option<&PyProgressReporter>,
+    f: fn(&IntAlgebra) -> TermResult,
+) -> PyResult<Option<String>> {
+    let alg = small_algebra_from_py(algebra)?;
+    let term = with_progress(py, progress, || f(alg.as_ref()).map_err(PyValueError::new_err))?;
+    Ok(term.map(|t| t.to_string()))
+}
+
+/// Run a search for a sequence of terms on `algebra` and render them.
+fn find_terms(
+    py: Python<'_>,
+    algebra: &Bound<'_, PyAny>,
+    progress: Option<&PyProgressReporter>,
+    f: fn(&IntAlgebra) -> TermsResult,
+) -> PyResult<Option<Vec<String>>> {
+    let alg = small_algebra_from_py(algebra)?;
+    let terms = with_progress(py, progress, || f(alg.as_ref()).map_err(PyValueError::new_err))?;
+    Ok(terms.map(|ts| ts.iter().map(|t| t.to_string()).collect()))
+}
+
+/// Run a test on `algebra`.
+fn test(
+    py: Python<'_>,
+    algebra: &Bound<'_, PyAny>,
+    progress: Option<&PyProgressReporter>,
+    f: fn(&IntAlgebra) -> Result<bool, String>,
+) -> PyResult<bool> {
+    let alg = small_algebra_from_py(algebra)?;
+    with_progress(py, progress, || f(alg.as_ref()).map_err(PyValueError::new_err))
+}
+
+/// Copy an algebra read from a file into a BasicAlgebra.
+fn to_basic_algebra(alg: &dyn SmallAlgebra<UniverseItem = i32>) -> PyBasicAlgebra {
+    let ops = alg.get_operations_ref().iter().map(|op| op.clone_box()).collect();
+    PyBasicAlgebra::from_inner(uacalc::alg::BasicAlgebra::new(alg.name().to_string(), (0..alg.cardinality()).collect(), ops))
+}
+
+fn to_py_homomorphisms(homos: Option<Vec<uacalc::alg::Homomorphism>>) -> Option<Vec<PyHomomorphism>> {
+    homos.map(|hs| hs.into_iter().map(PyHomomorphism::from_inner).collect())
+}
+
+/// The static methods of org.uacalc.alg.Malcev, under their Java names.
+///
+/// Terms are returned as strings.
+#[pyclass(name = "Malcev", module = "uacalc_lib.compat")]
+pub struct PyMalcev;
+
+#[pymethods]
+impl PyMalcev {
+    /// Same as alg.malcev_term.
+    #[staticmethod]
+    #[pyo3(name = "malcevTerm", signature = (algebra, progress=None))]
+    fn malcev_term(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+        find_term(py, algebra, progress, Malcev::malcevTerm)
+    }
+
+    /// Same as alg.majority_term.
+    #[staticmethod]
+    #[pyo3(name = "majorityTerm", signature = (algebra, progress=None))]
+    fn majority_term(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+        find_term(py, algebra, progress, Malcev::majorityTerm)
+    }
+
+    /// Same as alg.minority_term.
+    #[staticmethod]
+    #[pyo3(name = "minorityTerm", signature = (algebra, progress=None))]
+    fn minority_term(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+        find_term(py, algebra, progress, Malcev::minorityTerm)
+    }
+
+    /// Same as alg.pixley_term.
+    #[staticmethod]
+    #[pyo3(name = "pixleyTerm", signature = (algebra, progress=None))]
+    fn pixley_term(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+        find_term(py, algebra, progress, Malcev::pixleyTerm)
+    }
+
+    /// Same as alg.nu_term.
+    #[staticmethod]
+    #[pyo3(name = "nuTerm", signature = (algebra, arity, progress=None))]
+    fn nu_term(py: Python<'_>, algebra: &Bound<'_, PyAny>, arity: usize, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+        let alg = small_algebra_from_py(algebra)?;
+        let term = with_progress(py, progress, || Malcev::nuTerm(alg.as_ref(), arity).map_err(PyValueError::new_err))?;
+        Ok(term.map(|t| t.to_string()))
+    }
+
+    /// Same as alg.join_term.
+    #[staticmethod]
+    #[pyo3(name = "joinTerm", signature = (algebra, progress=None))]
+    fn join_term(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+        find_term(py, algebra, progress, Malcev::joinTerm)
+    }
+
+    /// Same as alg.semilattice_term.
+    #[staticmethod]
+    #[pyo3(name = "semilatticeTerm", signature = (algebra, progress=None))]
+    fn semilattice_term(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+        find_term(py, algebra, progress, Malcev::semilatticeTerm)
+    }
+
+    /// Same as alg.difference_term.
+    #[staticmethod]
+    #[pyo3(name = "differenceTerm", signature = (algebra, progress=None))]
+    fn difference_term(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+        find_term(py, algebra, progress, Malcev::differenceTerm)
+    }
+
+    /// Same as alg.jonsson_terms.
+    #[staticmethod]
+    #[pyo3(name = "jonssonTerms", signature = (algebra, progress=None))]
+    fn jonsson_terms(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<Option<Vec<String>>> {
+        find_terms(py, algebra, progress, Malcev::jonssonTerms)
+    }
+
+    /// Same as alg.jonsson_level.
+    #[staticmethod]
+    #[pyo3(name = "jonssonLevel", signature = (algebra, progress=None))]
+    fn jonsson_level(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<i32> {
+        let alg = small_algebra_from_py(algebra)?;
+        with_progress(py, progress, || Malcev::jonssonLevel(alg.as_ref()).map_err(PyValueError::new_err))
+    }
+
+    /// Same as alg.gumm_terms.
+    #[staticmethod]
+    #[pyo3(name = "gummTerms", signature = (algebra, progress=None))]
+    fn gumm_terms(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<Option<Vec<String>>> {
+        find_terms(py, algebra, progress, Malcev::gummTerms)
+    }
+
+    /// Same as alg.hagemann_mitschke_terms.
+    #[staticmethod]
+    #[pyo3(name = "hagemannMitschkeTerms", signature = (algebra, progress=None))]
+    fn hagemann_mitschke_terms(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<Option<Vec<String>>> {
+        find_terms(py, algebra, progress, Malcev::hagemannMitschkeTerms)
+    }
+
+    /// Same as alg.sd_meet_terms.
+    #[staticmethod]
+    #[pyo3(name = "sdmeetTerms", signature = (algebra, progress=None))]
+    fn sdmeet_terms(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<Option<Vec<String>>> {
+        find_terms(py, algebra, progress, Malcev::sdmeetTerms)
+    }
+
+    /// Same as alg.primality_terms.
+    #[staticmethod]
+    #[pyo3(name = "primalityTerms", signature = (algebra, progress=None))]
+    fn primality_terms(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<Option<Vec<String>>> {
+        find_terms(py, algebra, progress, Malcev::primalityTerms)
+    }
+
+    /// Same as alg.is_congruence_dist_idempotent.
+    #[staticmethod]
+    #[pyo3(name = "isCongruenceDistIdempotent", signature = (algebra, progress=None))]
+    fn is_congruence_dist_idempotent(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+        test(py, algebra, progress, Malcev::isCongruenceDistIdempotent)
+    }
+
+    /// Same as alg.is_congruence_modular_idempotent.
+    #[staticmethod]
+    #[pyo3(name = "isCongruenceModularIdempotent", signature = (algebra, progress=None))]
+    fn is_congruence_modular_idempotent(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+        test(py, algebra, progress, Malcev::isCongruenceModularIdempotent)
+    }
+
+    /// Same as alg.congruence_modular_variety.
+    #[staticmethod]
+    #[pyo3(name = "congruenceModularVariety", signature = (algebra, progress=None))]
+    fn congruence_modular_variety(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<bool> {
+        test(py, algebra, progress, Malcev::congruenceModularVariety)
+    }
+}
+
+/// The static methods of org.uacalc.alg.Algebras, under their Java names.
+#[pyclass(name = "Algebras", module = "uacalc_lib.compat")]
+pub struct PyAlgebras;
+
+#[pymethods]
+impl PyAlgebras {
+    /// Same as alg.find_nuf.
+    #[staticmethod]
+    #[pyo3(name = "findNUF", signature = (algebra, arity, progress=None))]
+    fn find_nuf(py: Python<'_>, algebra: &Bound<'_, PyAny>, arity: usize, progress: Option<&PyProgressReporter>) -> PyResult<Option<String>> {
+        let alg = small_algebra_from_py(algebra)?;
+        let term = with_progress(py, progress, || Algebras::findNUF(alg.as_ref(), arity).map_err(PyValueError::new_err))?;
+        Ok(term.map(|t| t.to_string()))
+    }
+
+    /// Same as alg.is_homomorphism.
+    #[staticmethod]
+    #[pyo3(name = "isHomomorphism")]
+    fn is_homomorphism(map: Vec<i32>, alg0: &Bound<'_, PyAny>, alg1: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let alg0 = small_algebra_from_py(alg0)?;
+        let alg1 = small_algebra_from_py(alg1)?;
+        Algebras::isHomomorphism(&map, alg0.as_ref(), alg1.as_ref()).map_err(PyValueError::new_err)
+    }
+
+    /// Same as alg.matrix_power.
+    #[staticmethod]
+    #[pyo3(name = "matrixPower")]
+    fn matrix_power(algebra: &Bound<'_, PyAny>, k: i32) -> PyResult<PyBasicAlgebra> {
+        let alg = small_algebra_from_py(algebra)?;
+        Algebras::matrixPower(alg, k).map(PyBasicAlgebra::from_inner).map_err(PyValueError::new_err)
+    }
+
+    /// Same as alg.full_transformation_semigroup.
+    #[staticmethod]
+    #[pyo3(name = "fullTransformationSemigroup")]
+    fn full_transformation_semigroup(n: i32, include_constants: bool, include_id: bool) -> PyResult<PyBasicAlgebra> {
+        Algebras::fullTransformationSemigroup(n, include_constants, include_id)
+            .map(PyBasicAlgebra::from_inner)
+            .map_err(PyValueError::new_err)
+    }
+
+    /// Same as alg.ternary_discriminator_algebra.
+    #[staticmethod]
+    #[pyo3(name = "ternaryDiscriminatorAlgebra")]
+    fn ternary_discriminator_algebra(card: i32) -> PyResult<PyBasicAlgebra> {
+        Algebras::ternaryDiscriminatorAlgebra(card).map(PyBasicAlgebra::from_inner).map_err(PyValueError::new_err)
+    }
+
+    /// Same as alg.member_of_quasivariety.
+    #[staticmethod]
+    #[pyo3(name = "memberOfQuasivariety", signature = (a, b, progress=None))]
+    fn member_of_quasivariety(
+        py: Python<'_>,
+        a: &Bound<'_, PyAny>,
+        b: &Bound<'_, PyAny>,
+        progress: Option<&PyProgressReporter>,
+    ) -> PyResult<Option<Vec<PyHomomorphism>>> {
+        let a = small_algebra_from_py(a)?;
+        let b = small_algebra_from_py(b)?;
+        let homos = with_progress(py, progress, || Algebras::memberOfQuasivariety(a, b).map_err(PyValueError::new_err))?;
+        Ok(to_py_homomorphisms(homos))
+    }
+
+    /// Same as alg.member_of_quasivariety_gen_by_proper_subs.
+    #[staticmethod]
+    #[pyo3(name = "memberOfQuasivarietyGenByProperSubs", signature = (a, progress=None))]
+    fn member_of_quasivariety_gen_by_proper_subs(
+        py: Python<'_>,
+        a: &Bound<'_, PyAny>,
+        progress: Option<&PyProgressReporter>,
+    ) -> PyResult<Option<Vec<PyHomomorphism>>> {
+        let a = small_algebra_from_py(a)?;
+        let homos = with_progress(py, progress, || Algebras::memberOfQuasivarietyGenByProperSubs(a).map_err(PyValueError::new_err))?;
+        Ok(to_py_homomorphisms(homos))
+    }
+}
+
+/// The static methods of org.uacalc.io.AlgebraIO, under their Java names.
+#[pyclass(name = "AlgebraIO", module = "uacalc_lib.compat")]
+pub struct PyAlgebraIO;
+
+#[pymethods]
+impl PyAlgebraIO {
+    /// Same as io.parse_line.
+    #[staticmethod]
+    #[pyo3(name = "parseLine")]
+    fn parse_line(line: &str) -> PyResult<i32> {
+        AlgebraIO::parseLine(line).map_err(PyValueError::new_err)
+    }
+
+    /// Same as io.read_algebra_file.
+    #[staticmethod]
+    #[pyo3(name = "readAlgebraFile")]
+    fn read_algebra_file(path: &str) -> PyResult<PyBasicAlgebra> {
+        AlgebraIO::readAlgebraFile(path)
+            .map(|alg| to_basic_algebra(alg.as_ref()))
+            .map_err(|e| PyValueError::new_err(e.message().to_string()))
+    }
+
+    /// Same as io.read_algebra_list_file.
+    #[staticmethod]
+    #[pyo3(name = "readAlgebraListFile")]
+    fn read_algebra_list_file(path: &str) -> PyResult<Vec<PyBasicAlgebra>> {
+        AlgebraIO::readAlgebraListFile(path)
+            .map(|algs| algs.iter().map(|alg| to_basic_algebra(alg.as_ref())).collect())
+            .map_err(|e| PyValueError::new_err(e.message().to_string()))
+    }
+
+    /// Same as io.write_algebra_file.
+    #[staticmethod]
+    #[pyo3(name = "writeAlgebraFile")]
+    fn write_algebra_file(algebra: &Bound<'_, PyAny>, path: &str) -> PyResult<()> {
+        let alg = small_algebra_from_py(algebra)?;
+        AlgebraIO::writeAlgebraFile(alg, path).map_err(PyValueError::new_err)
+    }
+
+    /// Same as io.convert_to_xml.
+    #[staticmethod]
+    #[pyo3(name = "convertToXML")]
+    fn convert_to_xml(path: &str) -> PyResult<()> {
+        AlgebraIO::convertToXML(path).map_err(|e| PyValueError::new_err(e.message().to_string()))
+    }
+}
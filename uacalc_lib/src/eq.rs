@@ -1,13 +1,15 @@
 use pyo3::prelude::*;
 use pyo3::exceptions::PyValueError;
-use uacalc::eq::{Equation, equations, Presentation};
+use uacalc::eq::{Equation, EquationComplexity, EquationSystem, QuasiEquation, equations, theories, Presentation};
 use uacalc::terms::Term;  // Import Term trait for clone_box method
+use uacalc::io::json::{FromJson, ToJson};
+use uacalc::io::report::ToLatex;
 use std::collections::HashMap;
 use std::sync::Arc;
 use crate::alg::op::operation_symbol::PyOperationSymbol;
 
 /// Python wrapper for Equation
-#[pyclass]
+#[pyclass(name = "Equation", module = "uacalc_lib.eq")]
 #[derive(Clone)]
 pub struct PyEquation {
     pub inner: Equation,
@@ -43,6 +45,26 @@ impl PyEquation {
         Ok(PyEquation { inner })
     }
     
+    /// Create an equation from two term objects.
+    /// 
+    /// Both terms are copied directly, without going through strings.
+    /// 
+    /// # Arguments
+    /// * `left` - The left side term (VariableImp or NonVariableTerm)
+    /// * `right` - The right side term (VariableImp or NonVariableTerm)
+    /// 
+    /// # Returns
+    /// The equation `left = right`
+    /// 
+    /// # Raises
+    /// * `ValueError` - If either side is not a term
+    #[staticmethod]
+    fn from_terms(left: &Bound<'_, PyAny>, right: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyEquation {
+            inner: Equation::new(convert_to_term(left)?, convert_to_term(right)?),
+        })
+    }
+    
     /// Get the left side term as a string representation.
     /// 
     /// # Returns
@@ -88,10 +110,12 @@ impl PyEquation {
     /// # Returns
     /// * List of variable values where the equation fails
     /// * None if the equation holds in the algebra
-    fn find_failure(&self, algebra: &crate::alg::PyBasicAlgebra) -> PyResult<Option<Vec<i32>>> {
-        let alg_arc: Arc<dyn uacalc::alg::SmallAlgebra<UniverseItem = i32>> = Arc::new(algebra.inner.clone());
-        self.inner.find_failure(alg_arc)
-            .map_err(|e| PyValueError::new_err(e))
+    fn find_failure(&self, py: Python<'_>, algebra: &crate::alg::PyBasicAlgebra) -> PyResult<Option<Vec<i32>>> {
+        py.allow_threads(|| {
+            let alg_arc: Arc<dyn uacalc::alg::SmallAlgebra<UniverseItem = i32>> = Arc::new(algebra.inner.clone());
+            self.inner.find_failure(alg_arc)
+                .map_err(PyValueError::new_err)
+        })
     }
     
     /// Find where this equation fails in the given algebra as a variable map.
@@ -102,10 +126,51 @@ impl PyEquation {
     /// # Returns
     /// * Dictionary from variable names to values where the equation fails
     /// * None if the equation holds in the algebra
-    fn find_failure_map(&self, algebra: &crate::alg::PyBasicAlgebra) -> PyResult<Option<HashMap<String, i32>>> {
-        let alg_arc: Arc<dyn uacalc::alg::SmallAlgebra<UniverseItem = i32>> = Arc::new(algebra.inner.clone());
-        self.inner.find_failure_map(alg_arc)
-            .map_err(|e| PyValueError::new_err(e))
+    fn find_failure_map(&self, py: Python<'_>, algebra: &crate::alg::PyBasicAlgebra) -> PyResult<Option<HashMap<String, i32>>> {
+        py.allow_threads(|| {
+            let alg_arc: Arc<dyn uacalc::alg::SmallAlgebra<UniverseItem = i32>> = Arc::new(algebra.inner.clone());
+            self.inner.find_failure_map(alg_arc)
+                .map_err(PyValueError::new_err)
+        })
+    }
+    
+    /// Find where this equation fails using the batched block evaluator.
+    /// 
+    /// # Arguments
+    /// * `algebra` - The algebra to check (BasicAlgebra from Python)
+    /// * `parallel` - Evaluate blocks of assignments in parallel (default: False)
+    /// 
+    /// # Returns
+    /// * The first list of variable values where the equation fails
+    /// * None if the equation holds in the algebra
+    #[pyo3(signature = (algebra, parallel=false))]
+    fn find_failure_batched(&self, py: Python<'_>, algebra: &crate::alg::PyBasicAlgebra, parallel: bool) -> PyResult<Option<Vec<i32>>> {
+        py.allow_threads(|| {
+            self.inner.find_failure_batched(&algebra.inner, parallel)
+                .map_err(PyValueError::new_err)
+        })
+    }
+    
+    /// Test whether this equation holds in the given algebra.
+    /// 
+    /// Both sides are compiled once and evaluated at every assignment.
+    /// 
+    /// # Arguments
+    /// * `algebra` - The algebra to check (BasicAlgebra from Python)
+    /// 
+    /// # Returns
+    /// * True if the equation holds in the algebra
+    fn is_satisfied_in(&self, py: Python<'_>, algebra: &crate::alg::PyBasicAlgebra) -> PyResult<bool> {
+        py.allow_threads(|| {
+            self.inner.is_satisfied_in(&algebra.inner)
+                .map_err(PyValueError::new_err)
+        })
+    }
+    
+    /// The structural complexity of the equation: sizes, variable
+    /// multisets and whether it is linear, balanced, regular or normal.
+    fn complexity(&self) -> PyEquationComplexity {
+        PyEquationComplexity { inner: self.inner.complexity() }
     }
     
     /// Python string representation
@@ -117,10 +182,30 @@ impl PyEquation {
     fn __repr__(&self) -> String {
         format!("Equation({})", self.inner)
     }
+
+    /// The equation as a LaTeX formula, for example `$x \wedge y \approx y \wedge x$`.
+    fn to_latex(&self) -> String {
+        self.inner.to_latex()
+    }
+
+    /// Pickle support: the state is the JSON representation of the equation.
+    fn __getstate__(&self) -> String {
+        self.inner.to_json_str()
+    }
+
+    fn __setstate__(&mut self, state: &str) -> PyResult<()> {
+        self.inner = Equation::from_json_str(state).map_err(PyValueError::new_err)?;
+        Ok(())
+    }
+
+    fn __getnewargs__(&self) -> (crate::terms::PyVariableImp, crate::terms::PyVariableImp) {
+        let x = || crate::terms::PyVariableImp { inner: uacalc::terms::VariableImp::new("x") };
+        (x(), x())
+    }
 }
 
 /// Helper function to convert Python term objects to Rust Box<dyn Term>
-fn convert_to_term(obj: &Bound<'_, PyAny>) -> PyResult<Box<dyn uacalc::terms::Term>> {
+pub(crate) fn convert_to_term(obj: &Bound<'_, PyAny>) -> PyResult<Box<dyn uacalc::terms::Term>> {
     // Try to extract as VariableImp
     if let Ok(var) = obj.extract::<pyo3::PyRef<crate::terms::PyVariableImp>>() {
         return Ok(Box::new(var.inner.clone()));
@@ -151,7 +236,7 @@ fn convert_to_term(obj: &Bound<'_, PyAny>) -> PyResult<Box<dyn uacalc::terms::Te
 #[pyfunction]
 fn associative_law(op_symbol: &PyOperationSymbol) -> PyResult<PyEquation> {
     let equation = equations::associative_law(&op_symbol.get_inner())
-        .map_err(|e| PyValueError::new_err(e))?;
+        .map_err(PyValueError::new_err)?;
     Ok(PyEquation { inner: equation })
 }
 
@@ -170,7 +255,7 @@ fn associative_law(op_symbol: &PyOperationSymbol) -> PyResult<PyEquation> {
 #[pyfunction]
 fn cyclic_law(op_symbol: &PyOperationSymbol) -> PyResult<PyEquation> {
     let equation = equations::cyclic_law(&op_symbol.get_inner())
-        .map_err(|e| PyValueError::new_err(e))?;
+        .map_err(PyValueError::new_err)?;
     Ok(PyEquation { inner: equation })
 }
 
@@ -189,36 +274,557 @@ fn cyclic_law(op_symbol: &PyOperationSymbol) -> PyResult<PyEquation> {
 #[pyfunction]
 fn first_second_symmetric_law(op_symbol: &PyOperationSymbol) -> PyResult<PyEquation> {
     let equation = equations::first_second_symmetric_law(&op_symbol.get_inner())
-        .map_err(|e| PyValueError::new_err(e))?;
+        .map_err(PyValueError::new_err)?;
     Ok(PyEquation { inner: equation })
 }
 
+fn to_py_equations(axioms: Result<Vec<Equation>, String>) -> PyResult<Vec<PyEquation>> {
+    let axioms = axioms.map_err(PyValueError::new_err)?;
+    Ok(axioms.into_iter().map(|inner| PyEquation { inner }).collect())
+}
+
+/// The axioms of semigroups: `mul` is associative.
+///
+/// # Raises
+/// * `ValueError` - If the name is empty
+#[pyfunction]
+#[pyo3(signature = (mul="mul"))]
+fn semigroup_theory(mul: &str) -> PyResult<Vec<PyEquation>> {
+    to_py_equations(theories::semigroup(mul))
+}
+
+/// The axioms of monoids: an associative `mul` with identity `one`.
+///
+/// # Raises
+/// * `ValueError` - If a name is empty or the names are not distinct
+#[pyfunction]
+#[pyo3(signature = (mul="mul", one="one"))]
+fn monoid_theory(mul: &str, one: &str) -> PyResult<Vec<PyEquation>> {
+    to_py_equations(theories::monoid(mul, one))
+}
+
+/// The axioms of groups with product `mul`, inverse `inv` and identity `one`.
+///
+/// # Raises
+/// * `ValueError` - If a name is empty or the names are not distinct
+#[pyfunction]
+#[pyo3(signature = (mul="mul", inv="inv", one="one"))]
+fn group_theory(mul: &str, inv: &str, one: &str) -> PyResult<Vec<PyEquation>> {
+    to_py_equations(theories::group(mul, inv, one))
+}
+
+/// The axioms of abelian groups with sum `add`, negation `neg` and zero `zero`.
+///
+/// # Raises
+/// * `ValueError` - If a name is empty or the names are not distinct
+#[pyfunction]
+#[pyo3(signature = (add="add", neg="neg", zero="zero"))]
+fn abelian_group_theory(add: &str, neg: &str, zero: &str) -> PyResult<Vec<PyEquation>> {
+    to_py_equations(theories::abelian_group(add, neg, zero))
+}
+
+/// The axioms of lattices: associativity, commutativity and absorption.
+///
+/// # Raises
+/// * `ValueError` - If a name is empty or the names are the same
+#[pyfunction]
+#[pyo3(signature = (join="join", meet="meet"))]
+fn lattice_theory(join: &str, meet: &str) -> PyResult<Vec<PyEquation>> {
+    to_py_equations(theories::lattice(join, meet))
+}
+
+/// The axioms of distributive lattices.
+///
+/// # Raises
+/// * `ValueError` - If a name is empty or the names are the same
+#[pyfunction]
+#[pyo3(signature = (join="join", meet="meet"))]
+fn distributive_lattice_theory(join: &str, meet: &str) -> PyResult<Vec<PyEquation>> {
+    to_py_equations(theories::distributive_lattice(join, meet))
+}
+
+/// The axioms of Boolean algebras: a bounded distributive lattice with
+/// complement `neg`.
+///
+/// # Raises
+/// * `ValueError` - If a name is empty or the names are not distinct
+#[pyfunction]
+#[pyo3(signature = (join="join", meet="meet", neg="neg", zero="zero", one="one"))]
+fn boolean_algebra_theory(join: &str, meet: &str, neg: &str, zero: &str, one: &str) -> PyResult<Vec<PyEquation>> {
+    to_py_equations(theories::boolean_algebra(join, meet, neg, zero, one))
+}
+
+/// The axioms of quasigroups with left division `ldiv` and right division `rdiv`.
+///
+/// # Raises
+/// * `ValueError` - If a name is empty or the names are not distinct
+#[pyfunction]
+#[pyo3(signature = (mul="mul", ldiv="ldiv", rdiv="rdiv"))]
+fn quasigroup_theory(mul: &str, ldiv: &str, rdiv: &str) -> PyResult<Vec<PyEquation>> {
+    to_py_equations(theories::quasigroup(mul, ldiv, rdiv))
+}
+
+/// The axioms of semirings with identities `zero` and `one`.
+///
+/// # Raises
+/// * `ValueError` - If a name is empty or the names are not distinct
+#[pyfunction]
+#[pyo3(signature = (add="add", mul="mul", zero="zero", one="one"))]
+fn semiring_theory(add: &str, mul: &str, zero: &str, one: &str) -> PyResult<Vec<PyEquation>> {
+    to_py_equations(theories::semiring(add, mul, zero, one))
+}
+
+/// The axioms of rings with identity.
+///
+/// # Raises
+/// * `ValueError` - If a name is empty or the names are not distinct
+#[pyfunction]
+#[pyo3(signature = (add="add", neg="neg", zero="zero", mul="mul", one="one"))]
+fn ring_theory(add: &str, neg: &str, zero: &str, mul: &str, one: &str) -> PyResult<Vec<PyEquation>> {
+    to_py_equations(theories::ring(add, neg, zero, mul, one))
+}
+
+/// Structural measures of an equation.
+#[pyclass(name = "EquationComplexity", module = "uacalc_lib.eq")]
+#[derive(Clone)]
+pub struct PyEquationComplexity {
+    inner: EquationComplexity,
+}
+
+#[pymethods]
+impl PyEquationComplexity {
+    /// The number of operation symbol occurrences on both sides.
+    #[getter]
+    fn size(&self) -> usize {
+        self.inner.size()
+    }
+
+    /// The larger of the depths of the two sides.
+    #[getter]
+    fn depth(&self) -> usize {
+        self.inner.depth()
+    }
+
+    /// The number of distinct variables.
+    #[getter]
+    fn variable_count(&self) -> usize {
+        self.inner.variable_count()
+    }
+
+    /// How often each variable occurs on the left side.
+    #[getter]
+    fn left_variables(&self) -> HashMap<String, usize> {
+        self.inner.left_variables().clone().into_iter().collect()
+    }
+
+    /// How often each variable occurs on the right side.
+    #[getter]
+    fn right_variables(&self) -> HashMap<String, usize> {
+        self.inner.right_variables().clone().into_iter().collect()
+    }
+
+    /// Whether no variable occurs more than once on either side.
+    fn is_linear(&self) -> bool {
+        self.inner.is_linear()
+    }
+
+    /// Whether every variable occurs equally often on both sides.
+    fn is_balanced(&self) -> bool {
+        self.inner.is_balanced()
+    }
+
+    /// Whether both sides contain the same variables.
+    fn is_regular(&self) -> bool {
+        self.inner.is_regular()
+    }
+
+    /// Whether the equation is trivial or neither side is a variable.
+    fn is_normal(&self) -> bool {
+        self.inner.is_normal()
+    }
+
+    /// The key ordering equations cheapest first.
+    fn search_key(&self) -> (usize, usize, usize, bool, bool, bool) {
+        self.inner.search_key()
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        self.inner.to_string()
+    }
+}
+
+/// A quasi-equation: premises implying a conclusion.
+#[pyclass(name = "QuasiEquation", module = "uacalc_lib.eq")]
+#[derive(Clone)]
+pub struct PyQuasiEquation {
+    pub inner: QuasiEquation,
+}
+
+#[pymethods]
+impl PyQuasiEquation {
+    /// Create a quasi-equation.
+    ///
+    /// # Arguments
+    /// * `premises` - List of Equation objects
+    /// * `conclusion` - The Equation they imply
+    #[new]
+    fn new(premises: Vec<PyRef<PyEquation>>, conclusion: PyRef<PyEquation>) -> Self {
+        let premises = premises.iter().map(|eq| eq.inner.clone()).collect();
+        PyQuasiEquation { inner: QuasiEquation::new(premises, conclusion.inner.clone()) }
+    }
+
+    /// The premises.
+    #[getter]
+    fn premises(&self) -> Vec<PyEquation> {
+        self.inner.premises().iter().map(|eq| PyEquation { inner: eq.clone() }).collect()
+    }
+
+    /// The conclusion.
+    #[getter]
+    fn conclusion(&self) -> PyEquation {
+        PyEquation { inner: self.inner.conclusion().clone() }
+    }
+
+    /// The variables in order of first occurrence.
+    fn get_variable_list(&self) -> Vec<String> {
+        self.inner.get_variable_list()
+    }
+
+    /// Find an assignment at which every premise holds but the conclusion fails.
+    ///
+    /// # Arguments
+    /// * `algebra` - The algebra to check (BasicAlgebra, Subalgebra, ...)
+    ///
+    /// # Returns
+    /// * List of variable values, in the order of `get_variable_list`
+    /// * None if the quasi-equation holds in the algebra
+    fn find_failure(&self, py: Python<'_>, algebra: &Bound<'_, PyAny>) -> PyResult<Option<Vec<i32>>> {
+        let alg = crate::alg::small_algebra_from_py(algebra)?;
+        py.allow_threads(|| self.inner.find_failure_in(alg.as_ref()).map_err(PyValueError::new_err))
+    }
+
+    /// Test whether the quasi-equation holds in the given algebra.
+    fn is_satisfied_in(&self, py: Python<'_>, algebra: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let alg = crate::alg::small_algebra_from_py(algebra)?;
+        py.allow_threads(|| self.inner.is_satisfied_in(alg.as_ref()).map_err(PyValueError::new_err))
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.premises().len()
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("QuasiEquation(premises={}, conclusion={})", self.inner.premises().len(), self.inner.conclusion())
+    }
+}
+
+/// The result of checking the axioms of a theory in an algebra.
+#[pyclass(name = "TheoryReport", module = "uacalc_lib.eq")]
+#[derive(Clone)]
+pub struct PyTheoryReport {
+    inner: theories::TheoryReport,
+}
+
+#[pymethods]
+impl PyTheoryReport {
+    /// The name of the theory, e.g. "group".
+    #[getter]
+    fn theory(&self) -> &'static str {
+        self.inner.theory.name()
+    }
+
+    /// The operations of the algebra in place of the symbols of the theory.
+    #[getter]
+    fn operations(&self) -> Vec<String> {
+        self.inner.operations.clone()
+    }
+
+    /// The axioms, each with its first counterexample or None if it holds.
+    ///
+    /// # Returns
+    /// * List of (Equation, Optional[Dict[str, int]]) pairs
+    #[getter]
+    fn axioms(&self) -> Vec<(PyEquation, Option<HashMap<String, i32>>)> {
+        self.inner.axioms.iter().map(|a| {
+            let failure = a.failure.as_ref().map(|args| a.equation.get_variable_list().into_iter().zip(args.iter().copied()).collect());
+            (PyEquation { inner: a.equation.clone() }, failure)
+        }).collect()
+    }
+
+    /// Whether every axiom holds.
+    fn holds(&self) -> bool {
+        self.inner.holds()
+    }
+
+    /// The number of axioms that fail.
+    fn failure_count(&self) -> usize {
+        self.inner.failure_count()
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TheoryReport({}({}), failures={})", self.inner.theory, self.inner.operations.join(", "), self.inner.failure_count())
+    }
+}
+
+/// The strongest theories an algebra satisfies and its nearest misses.
+#[pyclass(name = "Classification", module = "uacalc_lib.eq")]
+pub struct PyClassification {
+    inner: theories::Classification,
+}
+
+#[pymethods]
+impl PyClassification {
+    /// The strongest theories satisfied, one report per assignment of operations.
+    #[getter]
+    fn satisfied(&self) -> Vec<PyTheoryReport> {
+        self.inner.satisfied.iter().cloned().map(|inner| PyTheoryReport { inner }).collect()
+    }
+
+    /// For each theory that fits but never holds, the assignment with the
+    /// fewest failing axioms, ordered by the number of failures.
+    #[getter]
+    fn nearest(&self) -> Vec<PyTheoryReport> {
+        self.inner.nearest.iter().cloned().map(|inner| PyTheoryReport { inner }).collect()
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        let names: Vec<&str> = self.inner.satisfied.iter().map(|r| r.theory.name()).collect();
+        format!("Classification(satisfied={:?})", names)
+    }
+}
+
+/// Check the axioms of a built-in theory in an algebra.
+///
+/// # Arguments
+/// * `algebra` - The algebra
+/// * `theory` - The theory name: "semigroup", "monoid", "group",
+///   "abelian_group", "lattice", "distributive_lattice", "boolean_algebra",
+///   "quasigroup", "semiring" or "ring"
+/// * `operations` - The operations of the algebra in place of the symbols
+///   of the theory; defaults to the symbol names, e.g. mul, inv and one
+///
+/// # Returns
+/// * `TheoryReport` - Which axioms hold, with counterexamples
+///
+/// # Raises
+/// * `ValueError` - If the theory is unknown or an operation is missing or
+///   has the wrong arity
+#[pyfunction]
+#[pyo3(signature = (algebra, theory, operations=None))]
+fn check_theory(py: Python<'_>, algebra: &crate::alg::PyBasicAlgebra, theory: &str, operations: Option<Vec<String>>) -> PyResult<PyTheoryReport> {
+    let theory = theories::Theory::from_name(theory).map_err(PyValueError::new_err)?;
+    let names: Vec<String> = operations.unwrap_or_else(|| theory.signature().iter().map(|(n, _)| n.to_string()).collect());
+    let refs: Vec<&str> = names.iter().map(|s| s.as_str()).collect();
+    py.allow_threads(|| theories::check_theory(&algebra.inner, theory, &refs))
+        .map(|inner| PyTheoryReport { inner })
+        .map_err(PyValueError::new_err)
+}
+
+/// Classify an algebra by the built-in theories, trying every assignment
+/// of its operations to their symbols.
+///
+/// # Arguments
+/// * `algebra` - The algebra
+///
+/// # Returns
+/// * `Classification` - The strongest theories satisfied and the nearest misses
+///
+/// # Raises
+/// * `ValueError` - If an axiom cannot be evaluated, e.g. the algebra is too large
+#[pyfunction]
+fn classify(py: Python<'_>, algebra: &crate::alg::PyBasicAlgebra) -> PyResult<PyClassification> {
+    py.allow_threads(|| theories::classify(&algebra.inner))
+        .map(|inner| PyClassification { inner })
+        .map_err(PyValueError::new_err)
+}
+
+/// Check whether two terms define the same operation on an algebra.
+///
+/// # Arguments
+/// * `algebra` - The algebra (BasicAlgebra, ProductAlgebra, PowerAlgebra, ...)
+/// * `left`, `right` - The terms (VariableImp or NonVariableTerm)
+///
+/// # Returns
+/// * `bool` - True if the terms agree at every assignment of their variables
+///
+/// # Raises
+/// * `ValueError` - If an operation of a term is missing from the algebra
+#[pyfunction]
+fn terms_equal_in(py: Python<'_>, algebra: &Bound<'_, PyAny>, left: &Bound<'_, PyAny>, right: &Bound<'_, PyAny>) -> PyResult<bool> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    let (left, right) = (convert_to_term(left)?, convert_to_term(right)?);
+    py.allow_threads(|| uacalc::eq::terms_equal_in(alg.as_ref(), left.as_ref(), right.as_ref()))
+        .map_err(PyValueError::new_err)
+}
+
+/// Check whether two terms are equal in the variety generated by an algebra,
+/// by evaluating them in the free algebra on their variables.
+///
+/// # Arguments
+/// * `algebra` - The algebra generating the variety
+/// * `left`, `right` - The terms (VariableImp or NonVariableTerm)
+///
+/// # Returns
+/// * `bool` - True if `left = right` holds in the variety
+///
+/// # Raises
+/// * `ValueError` - If the free algebra cannot be built or a term cannot be evaluated in it
+#[pyfunction]
+fn terms_equal_in_variety(py: Python<'_>, algebra: &Bound<'_, PyAny>, left: &Bound<'_, PyAny>, right: &Bound<'_, PyAny>) -> PyResult<bool> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    let (left, right) = (convert_to_term(left)?, convert_to_term(right)?);
+    py.allow_threads(|| uacalc::eq::terms_equal_in_variety(alg.as_ref(), left.as_ref(), right.as_ref()))
+        .map_err(PyValueError::new_err)
+}
+
 pub fn register_eq_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Register classes internally but only export clean names
     m.add_class::<PyEquation>()?;
     m.add_class::<PyPresentation>()?;
+    m.add_class::<PyEquationSystem>()?;
+    m.add_class::<PyTheoryReport>()?;
+    m.add_class::<PyEquationComplexity>()?;
+    m.add_class::<PyQuasiEquation>()?;
+    m.add_class::<PyClassification>()?;
     
     // Register equation generation functions
     m.add_function(wrap_pyfunction!(associative_law, m)?)?;
     m.add_function(wrap_pyfunction!(cyclic_law, m)?)?;
     m.add_function(wrap_pyfunction!(first_second_symmetric_law, m)?)?;
+    m.add_function(wrap_pyfunction!(semigroup_theory, m)?)?;
+    m.add_function(wrap_pyfunction!(monoid_theory, m)?)?;
+    m.add_function(wrap_pyfunction!(group_theory, m)?)?;
+    m.add_function(wrap_pyfunction!(abelian_group_theory, m)?)?;
+    m.add_function(wrap_pyfunction!(lattice_theory, m)?)?;
+    m.add_function(wrap_pyfunction!(distributive_lattice_theory, m)?)?;
+    m.add_function(wrap_pyfunction!(boolean_algebra_theory, m)?)?;
+    m.add_function(wrap_pyfunction!(quasigroup_theory, m)?)?;
+    m.add_function(wrap_pyfunction!(semiring_theory, m)?)?;
+    m.add_function(wrap_pyfunction!(ring_theory, m)?)?;
+    m.add_function(wrap_pyfunction!(check_theory, m)?)?;
+    m.add_function(wrap_pyfunction!(classify, m)?)?;
+    m.add_function(wrap_pyfunction!(terms_equal_in, m)?)?;
+    m.add_function(wrap_pyfunction!(terms_equal_in_variety, m)?)?;
     
     // Export only clean names (without Py prefix)
-    m.add("Equation", m.getattr("PyEquation")?)?;
     m.add("Presentation", m.getattr("PyPresentation")?)?;
+    m.add("EquationSystem", m.getattr("PyEquationSystem")?)?;
     
     // Remove the Py* names from the module to avoid confusion
     let module_dict = m.dict();
-    module_dict.del_item("PyEquation")?;
     module_dict.del_item("PyPresentation")?;
+    module_dict.del_item("PyEquationSystem")?;
     
     Ok(())
 }
 
+/// Python wrapper for EquationSystem
+#[pyclass]
+pub struct PyEquationSystem {
+    inner: EquationSystem,
+}
+
+#[pymethods]
+impl PyEquationSystem {
+    /// Create a new equation system from a list of equations.
+    /// 
+    /// # Arguments
+    /// * `equations` - List of Equation objects (default: empty)
+    #[new]
+    #[pyo3(signature = (equations=Vec::new()))]
+    fn new(equations: Vec<PyEquation>) -> Self {
+        let eqs = equations.into_iter().map(|e| e.inner).collect();
+        PyEquationSystem { inner: EquationSystem::new(eqs) }
+    }
+    
+    /// Add an equation to the system.
+    fn add(&mut self, equation: PyEquation) {
+        self.inner.add(equation.inner);
+    }
+    
+    /// Reorder the equations cheapest first by complexity.
+    fn sort_by_complexity(&mut self) {
+        self.inner.sort_by_complexity();
+    }
+    
+    /// Get the equations of this system.
+    fn equations(&self) -> Vec<PyEquation> {
+        self.inner.equations().iter().map(|e| PyEquation { inner: e.clone() }).collect()
+    }
+    
+    /// Get the union of the variable lists of the equations.
+    fn get_variable_list(&self) -> Vec<String> {
+        self.inner.get_variable_list()
+    }
+    
+    /// Check every equation in the algebra with shared subterm evaluation.
+    /// 
+    /// # Arguments
+    /// * `algebra` - The algebra to check (BasicAlgebra from Python)
+    /// 
+    /// # Returns
+    /// One entry per equation: None if it holds, otherwise a failing
+    /// assignment in the equation's variable order
+    fn satisfied_in(&self, py: Python<'_>, algebra: &crate::alg::PyBasicAlgebra) -> PyResult<Vec<Option<Vec<i32>>>> {
+        py.allow_threads(|| {
+            self.inner.satisfied_in(&algebra.inner)
+                .map_err(PyValueError::new_err)
+        })
+    }
+    
+    /// Test whether every equation holds in the algebra.
+    fn all_satisfied_in(&self, py: Python<'_>, algebra: &crate::alg::PyBasicAlgebra) -> PyResult<bool> {
+        py.allow_threads(|| {
+            self.inner.all_satisfied_in(&algebra.inner)
+                .map_err(PyValueError::new_err)
+        })
+    }
+    
+    /// Check every equation and report failures as variable maps.
+    /// 
+    /// # Returns
+    /// One entry per equation: None if it holds, otherwise a dictionary
+    /// from variable names to values where it fails
+    fn failure_maps(&self, py: Python<'_>, algebra: &crate::alg::PyBasicAlgebra) -> PyResult<Vec<Option<HashMap<String, i32>>>> {
+        py.allow_threads(|| {
+            self.inner.failure_maps(&algebra.inner)
+                .map_err(PyValueError::new_err)
+        })
+    }
+    
+    fn __len__(&self) -> usize {
+        self.inner.len()
+    }
+    
+    fn __str__(&self) -> String {
+        format!("{}", self.inner)
+    }
+    
+    fn __repr__(&self) -> String {
+        format!("{}", self.inner)
+    }
+}
+
 /// Python wrapper for Presentation
 #[pyclass]
 pub struct PyPresentation {
-    inner: Presentation,
+    pub inner: Presentation,
 }
 
 #[pymethods]
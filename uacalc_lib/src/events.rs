@@ -0,0 +1,147 @@
+//! Python wrapper for the computation event bus
+
+use std::ops::ControlFlow;
+use pyo3::prelude::*;
+use uacalc::events::{self, ComputationEvent, ListenerId};
+
+/// Register the events module.
+pub fn register_events_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyComputationEvent>()?;
+    m.add_function(wrap_pyfunction!(subscribe, m)?)?;
+    m.add_function(wrap_pyfunction!(unsubscribe, m)?)?;
+    m.add_function(wrap_pyfunction!(listener_count, m)?)?;
+    Ok(())
+}
+
+/// An event of a computation: it started, reported progress, finished or
+/// was cancelled.
+///
+/// Events of the same computation share an `id`. The attributes not
+/// belonging to the kind of event are None.
+#[pyclass(name = "ComputationEvent", module = "uacalc_lib.events")]
+#[derive(Clone)]
+pub struct PyComputationEvent {
+    inner: ComputationEvent,
+}
+
+#[pymethods]
+impl PyComputationEvent {
+    /// "started", "progress", "finished" or "cancelled".
+    #[getter]
+    fn kind(&self) -> &'static str {
+        self.inner.kind()
+    }
+
+    /// The id of the computation.
+    #[getter]
+    fn id(&self) -> u64 {
+        self.inner.id()
+    }
+
+    /// The name of the computation, for "started" events.
+    #[getter]
+    fn name(&self) -> Option<String> {
+        match &self.inner {
+            ComputationEvent::Started { name, .. } => Some(name.clone()),
+            _ => None,
+        }
+    }
+
+    /// The phase labels, outermost first, joined by " / ", for "progress" events.
+    #[getter]
+    fn phase(&self) -> Option<String> {
+        match &self.inner {
+            ComputationEvent::Progress { progress, .. } => Some(progress.phase.clone()),
+            _ => None,
+        }
+    }
+
+    /// The message, for "progress" events.
+    #[getter]
+    fn message(&self) -> Option<String> {
+        match &self.inner {
+            ComputationEvent::Progress { progress, .. } => Some(progress.message.clone()),
+            _ => None,
+        }
+    }
+
+    /// The fraction of the work done, for "progress" events that know it.
+    #[getter]
+    fn fraction(&self) -> Option<f64> {
+        match &self.inner {
+            ComputationEvent::Progress { progress, .. } => progress.fraction,
+            _ => None,
+        }
+    }
+
+    /// The seconds the computation ran, for "finished" and "cancelled" events.
+    #[getter]
+    fn elapsed(&self) -> Option<f64> {
+        match &self.inner {
+            ComputationEvent::Finished { elapsed, .. } | ComputationEvent::Cancelled { elapsed, .. } => {
+                Some(elapsed.as_secs_f64())
+            }
+            _ => None,
+        }
+    }
+
+    /// The error of a failed computation, or why a cancelled one stopped.
+    #[getter]
+    fn error(&self) -> Option<String> {
+        match &self.inner {
+            ComputationEvent::Finished { error, .. } => error.clone(),
+            ComputationEvent::Cancelled { reason, .. } => Some(reason.clone()),
+            _ => None,
+        }
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ComputationEvent(kind='{}', id={})", self.inner.kind(), self.inner.id())
+    }
+}
+
+/// Call a function with the events of every computation taking a
+/// `progress` argument.
+///
+/// Any number of listeners may be subscribed, alongside the callback of
+/// a ProgressReporter. A listener returning True cancels the computation,
+/// which then raises `CancelledError`; an exception raised by a listener is
+/// reported through `sys.unraisablehook` and otherwise ignored. Listeners
+/// are called from the thread running the computation.
+///
+/// Args:
+///     listener (Callable[[ComputationEvent], Optional[bool]]): Called with each event
+///
+/// Returns:
+///     int: A handle for `unsubscribe`
+#[pyfunction]
+fn subscribe(listener: PyObject) -> u64 {
+    events::global()
+        .subscribe(move |event| {
+            Python::with_gil(|py| match listener.call1(py, (PyComputationEvent { inner: event.clone() },)) {
+                Ok(r) if r.bind(py).is_truthy().unwrap_or(false) => ControlFlow::Break(()),
+                Ok(_) => ControlFlow::Continue(()),
+                Err(e) => {
+                    e.write_unraisable_bound(py, Some(listener.bind(py)));
+                    ControlFlow::Continue(())
+                }
+            })
+        })
+        .0
+}
+
+/// Remove a listener, returning whether it was subscribed.
+#[pyfunction]
+fn unsubscribe(handle: u64) -> bool {
+    events::global().unsubscribe(ListenerId(handle))
+}
+
+/// The number of listeners subscribed.
+#[pyfunction]
+fn listener_count() -> usize {
+    events::global().listener_count()
+}
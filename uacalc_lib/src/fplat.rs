@@ -1,5 +1,7 @@
 use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
 use pyo3::types::PyList;
+use uacalc::fplat::FinitelyPresentedLattice;
 use uacalc::terms::{VariableImp, Variable};
 use uacalc::lat::Order;
 use std::sync::Arc;
@@ -104,6 +106,8 @@ impl PyPartiallyDefinedLattice {
 
 // Import PyVariableImp from terms module
 use crate::terms::PyVariableImp;
+use crate::eq::PyPresentation;
+use crate::progress::{with_progress, PyProgressReporter};
 
 /// Simple order implementation that orders variables by name
 /// This implements the Order trait and is thread-safe
@@ -116,16 +120,145 @@ impl Order<VariableImp> for VariableNameOrder {
     }
 }
 
+fn parse_term(s: &str) -> PyResult<Box<dyn uacalc::terms::Term>> {
+    uacalc::terms::string_to_term(s).map_err(PyValueError::new_err)
+}
+
+/// Python wrapper for FinitelyPresentedLattice
+#[pyclass]
+pub struct PyFinitelyPresentedLattice {
+    inner: FinitelyPresentedLattice,
+}
+
+#[pymethods]
+impl PyFinitelyPresentedLattice {
+    /// Create a lattice from generators and relations.
+    ///
+    /// Args:
+    ///     name (str): The name of the lattice
+    ///     generators (List[str]): The generator names
+    ///     relations (List[str]): Relations "s = t" or "s <= t" between
+    ///         terms using join and meet, e.g. "join(x,y) <= z"
+    ///
+    /// Raises:
+    ///     ValueError: If a relation cannot be parsed or uses other names
+    #[new]
+    fn new(name: &str, generators: Vec<String>, relations: Vec<String>) -> PyResult<Self> {
+        let generators: Vec<&str> = generators.iter().map(|g| g.as_str()).collect();
+        let relations: Vec<&str> = relations.iter().map(|r| r.as_str()).collect();
+        FinitelyPresentedLattice::from_relations(name, &generators, &relations)
+            .map(|inner| PyFinitelyPresentedLattice { inner })
+            .map_err(PyValueError::new_err)
+    }
+
+    /// Create the lattice of a presentation.
+    ///
+    /// Args:
+    ///     name (str): The name of the lattice
+    ///     presentation (Presentation): Generators and equations between
+    ///         lattice terms
+    ///
+    /// Raises:
+    ///     ValueError: If a relation is not an equation between lattice terms
+    ///         in the generators
+    #[staticmethod]
+    fn from_presentation(name: &str, presentation: &PyPresentation) -> PyResult<Self> {
+        FinitelyPresentedLattice::new(name, &presentation.inner)
+            .map(|inner| PyFinitelyPresentedLattice { inner })
+            .map_err(PyValueError::new_err)
+    }
+
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    fn generators(&self) -> Vec<String> {
+        self.inner.generators().to_vec()
+    }
+
+    fn relations(&self) -> Vec<String> {
+        self.inner.relations().iter().map(|eq| eq.to_string()).collect()
+    }
+
+    /// Decide whether s <= t in this lattice.
+    ///
+    /// Args:
+    ///     s (str): A lattice term in the generators
+    ///     t (str): A lattice term in the generators
+    ///
+    /// Raises:
+    ///     ValueError: If a term is not a lattice term in the generators
+    fn leq(&self, s: &str, t: &str) -> PyResult<bool> {
+        self.inner.leq(parse_term(s)?.as_ref(), parse_term(t)?.as_ref()).map_err(PyValueError::new_err)
+    }
+
+    /// Decide whether s = t in this lattice.
+    ///
+    /// Args:
+    ///     s (str): A lattice term in the generators
+    ///     t (str): A lattice term in the generators
+    ///
+    /// Raises:
+    ///     ValueError: If a term is not a lattice term in the generators
+    fn equal(&self, s: &str, t: &str) -> PyResult<bool> {
+        self.inner.equal(parse_term(s)?.as_ref(), parse_term(t)?.as_ref()).map_err(PyValueError::new_err)
+    }
+
+    /// Compute the elements of the lattice, as terms in the generators.
+    ///
+    /// Args:
+    ///     max_size (int): The most elements to compute
+    ///     progress (ProgressReporter, optional): Progress and cancellation
+    ///
+    /// Raises:
+    ///     ValueError: If the lattice has more than max_size elements
+    #[pyo3(signature = (max_size=1000, progress=None))]
+    fn universe(&self, py: Python<'_>, max_size: usize, progress: Option<&PyProgressReporter>) -> PyResult<Vec<String>> {
+        with_progress(py, progress, || {
+            self.inner.universe(max_size)
+                .map(|elems| elems.iter().map(|t| t.to_string()).collect())
+                .map_err(PyValueError::new_err)
+        })
+    }
+
+    /// Compute the upper covers of the elements of universe().
+    ///
+    /// Args:
+    ///     max_size (int): The most elements to compute
+    ///     progress (ProgressReporter, optional): Progress and cancellation
+    ///
+    /// Returns:
+    ///     List[List[int]]: For each element, the indices of its upper covers
+    ///
+    /// Raises:
+    ///     ValueError: If the lattice has more than max_size elements
+    #[pyo3(signature = (max_size=1000, progress=None))]
+    fn upper_covers(&self, py: Python<'_>, max_size: usize, progress: Option<&PyProgressReporter>) -> PyResult<Vec<Vec<usize>>> {
+        with_progress(py, progress, || self.inner.upper_covers(max_size).map_err(PyValueError::new_err))
+    }
+
+    fn __str__(&self) -> String {
+        format!("FinitelyPresentedLattice({})", self.inner.name())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("FinitelyPresentedLattice('{}', generators={:?})", self.inner.name(), self.inner.generators())
+    }
+}
+
 pub fn register_fplat_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Register the PartiallyDefinedLattice class
     m.add_class::<PyPartiallyDefinedLattice>()?;
+    m.add_class::<PyFinitelyPresentedLattice>()?;
     
     // Export clean name (without Py prefix)
     m.add("PartiallyDefinedLattice", m.getattr("PyPartiallyDefinedLattice")?)?;
+    m.add("FinitelyPresentedLattice", m.getattr("PyFinitelyPresentedLattice")?)?;
     
     // Remove the Py* name from the module to avoid confusion
     let module_dict = m.dict();
     module_dict.del_item("PyPartiallyDefinedLattice")?;
+    module_dict.del_item("PyFinitelyPresentedLattice")?;
     
     Ok(())
 }
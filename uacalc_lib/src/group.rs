@@ -1,7 +1,8 @@
 use pyo3::prelude::*;
-use uacalc::group::PermutationGroup;
+use uacalc::group::{self, PermutationGroup};
 use uacalc::util::int_array::{IntArray, IntArrayTrait};
 use crate::alg::basic_algebra::PyBasicAlgebra;
+use crate::alg::conlat::partition::PyPartition;
 
 #[pyclass]
 pub struct PyPermutationGroup {
@@ -14,10 +15,10 @@ impl PyPermutationGroup {
     fn new(name: String, generators: Vec<Vec<i32>>) -> PyResult<Self> {
         let int_arrays: Result<Vec<IntArray>, String> = generators
             .into_iter()
-            .map(|gen| IntArray::from_array(gen))
+            .map(IntArray::from_array)
             .collect();
         
-        let int_arrays = int_arrays.map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+        let int_arrays = int_arrays.map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
         
         let perm_group = PermutationGroup::new(name, int_arrays);
         
@@ -28,17 +29,17 @@ impl PyPermutationGroup {
     fn new_with_universe(name: String, generators: Vec<Vec<i32>>, universe: Vec<Vec<i32>>) -> PyResult<Self> {
         let int_arrays: Result<Vec<IntArray>, String> = generators
             .into_iter()
-            .map(|gen| IntArray::from_array(gen))
+            .map(IntArray::from_array)
             .collect();
         
-        let int_arrays = int_arrays.map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+        let int_arrays = int_arrays.map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
         
         let universe_arrays: Result<Vec<IntArray>, String> = universe
             .into_iter()
-            .map(|univ| IntArray::from_array(univ))
+            .map(IntArray::from_array)
             .collect();
         
-        let universe_arrays = universe_arrays.map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+        let universe_arrays = universe_arrays.map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
         
         let perm_group = PermutationGroup::new_with_universe(name, int_arrays, universe_arrays);
         
@@ -49,13 +50,13 @@ impl PyPermutationGroup {
     fn new_safe(name: String, generators: Vec<Vec<i32>>) -> PyResult<Self> {
         let int_arrays: Result<Vec<IntArray>, String> = generators
             .into_iter()
-            .map(|gen| IntArray::from_array(gen))
+            .map(IntArray::from_array)
             .collect();
         
-        let int_arrays = int_arrays.map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+        let int_arrays = int_arrays.map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
         
         let perm_group = PermutationGroup::new_safe(name, int_arrays)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
         
         Ok(PyPermutationGroup { inner: perm_group })
     }
@@ -64,40 +65,40 @@ impl PyPermutationGroup {
     fn new_with_universe_safe(name: String, generators: Vec<Vec<i32>>, universe: Vec<Vec<i32>>) -> PyResult<Self> {
         let int_arrays: Result<Vec<IntArray>, String> = generators
             .into_iter()
-            .map(|gen| IntArray::from_array(gen))
+            .map(IntArray::from_array)
             .collect();
         
-        let int_arrays = int_arrays.map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+        let int_arrays = int_arrays.map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
         
         let universe_arrays: Result<Vec<IntArray>, String> = universe
             .into_iter()
-            .map(|univ| IntArray::from_array(univ))
+            .map(IntArray::from_array)
             .collect();
         
-        let universe_arrays = universe_arrays.map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+        let universe_arrays = universe_arrays.map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
         
         let perm_group = PermutationGroup::new_with_universe_safe(name, int_arrays, universe_arrays)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
         
         Ok(PyPermutationGroup { inner: perm_group })
     }
 
     #[staticmethod]
     fn prod(p1: Vec<i32>, p2: Vec<i32>) -> PyResult<Vec<i32>> {
-        let arr1 = IntArray::from_array(p1).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
-        let arr2 = IntArray::from_array(p2).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+        let arr1 = IntArray::from_array(p1).map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+        let arr2 = IntArray::from_array(p2).map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
         
         let result = PermutationGroup::prod(arr1, arr2)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
         Ok(result.as_slice().to_vec())
     }
 
     #[staticmethod]
     fn inv(p: Vec<i32>) -> PyResult<Vec<i32>> {
-        let arr = IntArray::from_array(p).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+        let arr = IntArray::from_array(p).map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
         
         let result = PermutationGroup::inv(arr)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
         Ok(result.as_slice().to_vec())
     }
 
@@ -123,7 +124,7 @@ impl PyPermutationGroup {
     #[staticmethod]
     fn automorphism_group(alg: &PyBasicAlgebra) -> PyResult<Self> {
         let aut_group = PermutationGroup::automorphism_group(&alg.inner)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
         Ok(PyPermutationGroup { inner: aut_group })
     }
 
@@ -173,9 +174,206 @@ impl PyPermutationGroup {
     ///     ValueError: If there's an error during conversion
     fn to_basic_algebra(&self, name: String) -> PyResult<PyBasicAlgebra> {
         let alg = self.inner.to_basic_algebra(name)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
         Ok(PyBasicAlgebra::from_inner(alg))
     }
+
+    /// Get the orbits on {0, ..., n-1}, each sorted, ordered by their least element.
+    fn orbits(&self) -> Vec<Vec<usize>> {
+        self.inner.orbits()
+    }
+
+    fn is_transitive(&self) -> bool {
+        self.inner.is_transitive()
+    }
+
+    /// Get the restriction of the group to each orbit, with the points of
+    /// the orbit numbered in increasing order.
+    fn transitive_constituents(&self) -> Vec<PyPermutationGroup> {
+        self.inner.transitive_constituents()
+            .into_iter()
+            .map(|inner| PyPermutationGroup { inner })
+            .collect()
+    }
+
+    /// Get the smallest block system with a and b in the same block.
+    ///
+    /// Raises:
+    ///     ValueError: If a or b is out of range
+    fn minimal_block_system(&self, a: usize, b: usize) -> PyResult<PyPartition> {
+        let n = self.inner.underlying_set_size;
+        if a >= n || b >= n {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Elements must be below {}", n)));
+        }
+        Ok(PyPartition { inner: self.inner.minimal_block_system(a, b) })
+    }
+
+    /// Get all block systems, i.e. all congruences of the G-set.
+    ///
+    /// Returns:
+    ///     List[Partition]: The block systems, including the trivial ones
+    ///
+    /// Raises:
+    ///     ValueError: If a generator is not a permutation
+    fn block_systems(&self) -> PyResult<Vec<PyPartition>> {
+        let systems = self.inner.block_systems()
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+        Ok(systems.into_iter().map(|inner| PyPartition { inner }).collect())
+    }
+
+    /// Convert to the G-set: the unary algebra whose operations are the generators.
+    ///
+    /// Raises:
+    ///     ValueError: If there are no generators or one is not a permutation
+    fn to_g_set(&self) -> PyResult<PyBasicAlgebra> {
+        let alg = self.inner.to_g_set()
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+        Ok(PyBasicAlgebra::from_inner(alg))
+    }
+
+    fn is_primitive(&self) -> bool {
+        self.inner.is_primitive()
+    }
+
+    /// Get the action of the group on the blocks of a block system.
+    ///
+    /// Args:
+    ///     blocks (Partition): The block system
+    ///
+    /// Raises:
+    ///     ValueError: If the partition is not a block system
+    fn block_action(&self, blocks: &PyPartition) -> PyResult<PyPermutationGroup> {
+        let inner = self.inner.block_action(&blocks.inner)
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+        Ok(PyPermutationGroup { inner })
+    }
+
+    /// Decompose a transitive imprimitive group along a block system with
+    /// the smallest nontrivial blocks; the group embeds in the wreath
+    /// product of the block stabilizer action by the block action.
+    ///
+    /// Returns:
+    ///     Optional[Tuple[Partition, PermutationGroup, PermutationGroup]]:
+    ///         The blocks, the action on the blocks and the action of the
+    ///         stabilizer of the block of 0 on that block, or None if the
+    ///         group is intransitive or primitive
+    fn imprimitive_decomposition(&self) -> Option<(PyPartition, PyPermutationGroup, PyPermutationGroup)> {
+        self.inner.imprimitive_decomposition().map(|d| (
+            PyPartition { inner: d.blocks },
+            PyPermutationGroup { inner: d.block_action },
+            PyPermutationGroup { inner: d.block_stabilizer_action },
+        ))
+    }
+}
+
+fn group_err(e: String) -> PyErr {
+    PyErr::new::<pyo3::exceptions::PyValueError, _>(e)
+}
+
+/// Check whether an algebra has a binary operation making it a group.
+///
+/// Args:
+///     algebra (BasicAlgebra): The algebra
+///
+/// Returns:
+///     bool: True if some binary operation is a group operation
+#[pyfunction]
+fn is_group(algebra: &PyBasicAlgebra) -> bool {
+    group::is_group(&algebra.inner)
+}
+
+/// Get the center of a group: the elements commuting with every element.
+///
+/// The first binary operation of the algebra satisfying the group axioms
+/// is used.
+///
+/// Args:
+///     algebra (BasicAlgebra): An algebra with a group operation
+///
+/// Returns:
+///     List[int]: The elements of the center, sorted
+///
+/// Raises:
+///     ValueError: If the algebra has no group operation
+#[pyfunction]
+fn center(algebra: &PyBasicAlgebra) -> PyResult<Vec<usize>> {
+    group::center(&algebra.inner).map_err(group_err)
+}
+
+/// Get the commutator subgroup, generated by the commutators x^-1 y^-1 x y.
+///
+/// Args:
+///     algebra (BasicAlgebra): An algebra with a group operation
+///
+/// Returns:
+///     List[int]: The elements of the commutator subgroup, sorted
+///
+/// Raises:
+///     ValueError: If the algebra has no group operation
+#[pyfunction]
+fn commutator_subgroup(algebra: &PyBasicAlgebra) -> PyResult<Vec<usize>> {
+    group::commutator_subgroup(&algebra.inner).map_err(group_err)
+}
+
+/// Get the normal subgroups, read off from the congruence lattice of the
+/// group operation as the blocks of the identity.
+///
+/// Args:
+///     algebra (BasicAlgebra): An algebra with a group operation
+///
+/// Returns:
+///     List[List[int]]: The normal subgroups, each sorted, ordered by size
+///
+/// Raises:
+///     ValueError: If the algebra has no group operation
+#[pyfunction]
+fn normal_subgroups(algebra: &PyBasicAlgebra) -> PyResult<Vec<Vec<usize>>> {
+    group::normal_subgroups(&algebra.inner).map_err(group_err)
+}
+
+/// Get the upper central series, from the trivial group to the hypercenter.
+///
+/// Raises:
+///     ValueError: If the algebra has no group operation
+#[pyfunction]
+fn upper_central_series(algebra: &PyBasicAlgebra) -> PyResult<Vec<Vec<usize>>> {
+    group::upper_central_series(&algebra.inner).map_err(group_err)
+}
+
+/// Get the derived series, from the group down to its perfect core.
+///
+/// Raises:
+///     ValueError: If the algebra has no group operation
+#[pyfunction]
+fn derived_series(algebra: &PyBasicAlgebra) -> PyResult<Vec<Vec<usize>>> {
+    group::derived_series(&algebra.inner).map_err(group_err)
+}
+
+/// Check whether a group is abelian.
+///
+/// Raises:
+///     ValueError: If the algebra has no group operation
+#[pyfunction]
+fn is_abelian(algebra: &PyBasicAlgebra) -> PyResult<bool> {
+    group::is_abelian(&algebra.inner).map_err(group_err)
+}
+
+/// Check whether a group is nilpotent, by its upper central series.
+///
+/// Raises:
+///     ValueError: If the algebra has no group operation
+#[pyfunction]
+fn is_nilpotent(algebra: &PyBasicAlgebra) -> PyResult<bool> {
+    group::is_nilpotent(&algebra.inner).map_err(group_err)
+}
+
+/// Check whether a group is solvable, by its derived series.
+///
+/// Raises:
+///     ValueError: If the algebra has no group operation
+#[pyfunction]
+fn is_solvable(algebra: &PyBasicAlgebra) -> PyResult<bool> {
+    group::is_solvable(&algebra.inner).map_err(group_err)
 }
 
 pub fn register_group_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
@@ -184,6 +382,15 @@ pub fn register_group_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<(
     
     // Export only clean names (without Py prefix)
     m.add("PermutationGroup", m.getattr("PyPermutationGroup")?)?;
+    m.add_function(wrap_pyfunction!(is_group, m)?)?;
+    m.add_function(wrap_pyfunction!(center, m)?)?;
+    m.add_function(wrap_pyfunction!(commutator_subgroup, m)?)?;
+    m.add_function(wrap_pyfunction!(normal_subgroups, m)?)?;
+    m.add_function(wrap_pyfunction!(upper_central_series, m)?)?;
+    m.add_function(wrap_pyfunction!(derived_series, m)?)?;
+    m.add_function(wrap_pyfunction!(is_abelian, m)?)?;
+    m.add_function(wrap_pyfunction!(is_nilpotent, m)?)?;
+    m.add_function(wrap_pyfunction!(is_solvable, m)?)?;
     
     // Remove the Py* names from the module to avoid confusion
     let module_dict = m.dict();
@@ -4,7 +4,10 @@ use std::fs::File;
 use std::path::Path;
 use std::cell::RefCell;
 use uacalc::alg::SmallAlgebra;
+use uacalc::io::json::{FromJson, MalcevReport, TctReport, ToJson};
 use crate::alg::PyBasicAlgebra;
+use crate::alg::conlat::partition::PyPartition;
+use crate::eq::PyEquation;
 
 /// Python iterator for parsing algebras from a Mace4 file lazily
 #[pyclass(unsendable)]
@@ -742,6 +745,96 @@ fn read_projective_plane_from_stream(data: Vec<u8>) -> PyResult<PyBasicAlgebra>
     }
 }
 
+/// Get the versioned JSON representation of an algebra.
+///
+/// Args:
+///     algebra (BasicAlgebra): The algebra
+///
+/// Returns:
+///     str: A JSON document of kind "algebra"
+#[pyfunction]
+fn algebra_to_json(algebra: &PyBasicAlgebra) -> String {
+    algebra.inner.to_json_str()
+}
+
+/// Read an algebra from its JSON representation.
+///
+/// Args:
+///     json (str): A JSON document of kind "algebra"
+///
+/// Raises:
+///     ValueError: If the document is not a valid algebra
+#[pyfunction]
+fn algebra_from_json(json: &str) -> PyResult<PyBasicAlgebra> {
+    uacalc::alg::BasicAlgebra::<i32>::from_json_str(json)
+        .map(PyBasicAlgebra::from_inner)
+        .map_err(PyValueError::new_err)
+}
+
+/// Get the versioned JSON representation of a partition.
+#[pyfunction]
+fn partition_to_json(partition: &PyPartition) -> String {
+    partition.inner.to_json_str()
+}
+
+/// Read a partition from its JSON representation.
+///
+/// Raises:
+///     ValueError: If the document is not a valid partition
+#[pyfunction]
+fn partition_from_json(json: &str) -> PyResult<PyPartition> {
+    uacalc::alg::conlat::Partition::from_json_str(json)
+        .map(|inner| PyPartition { inner })
+        .map_err(PyValueError::new_err)
+}
+
+/// Get the versioned JSON representation of an equation.
+#[pyfunction]
+fn equation_to_json(equation: &PyEquation) -> String {
+    equation.inner.to_json_str()
+}
+
+/// Read an equation from its JSON representation.
+///
+/// Raises:
+///     ValueError: If the document is not a valid equation
+#[pyfunction]
+fn equation_from_json(json: &str) -> PyResult<PyEquation> {
+    uacalc::eq::Equation::from_json_str(json)
+        .map(|inner| PyEquation { inner })
+        .map_err(PyValueError::new_err)
+}
+
+/// Search an algebra for Mal'cev condition terms and report them as JSON.
+///
+/// Args:
+///     algebra (BasicAlgebra): The algebra
+///     kinds (Optional[List[str]]): The terms to look for; all kinds by default
+///     nu_arity (int): The arity of the near unanimity term for "nu"
+///
+/// Returns:
+///     str: A JSON document of kind "malcev_report"
+#[pyfunction]
+#[pyo3(signature = (algebra, kinds=None, nu_arity=3))]
+fn malcev_report(py: Python<'_>, algebra: &PyBasicAlgebra, kinds: Option<Vec<String>>, nu_arity: usize) -> PyResult<String> {
+    let kinds: Vec<String> = kinds.unwrap_or_else(|| MalcevReport::KINDS.iter().map(|k| k.to_string()).collect());
+    let kinds: Vec<&str> = kinds.iter().map(|k| k.as_str()).collect();
+    py.allow_threads(|| MalcevReport::analyze(&algebra.inner, &kinds, nu_arity))
+        .map(|report| report.to_json_str())
+        .map_err(PyValueError::new_err)
+}
+
+/// Find the tame congruence theory types of an algebra and report them as JSON.
+///
+/// Returns:
+///     str: A JSON document of kind "tct_report"
+#[pyfunction]
+fn tct_report(py: Python<'_>, algebra: &PyBasicAlgebra) -> PyResult<String> {
+    py.allow_threads(|| TctReport::analyze(&algebra.inner))
+        .map(|report| report.to_json_str())
+        .map_err(PyValueError::new_err)
+}
+
 /// Register the io module
 pub fn register_io_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyMace4Reader>()?;
@@ -770,6 +863,17 @@ pub fn register_io_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()>
     m.add_function(wrap_pyfunction!(write_algebra_file_with_style, m)?)?;
     m.add_function(wrap_pyfunction!(read_projective_plane, m)?)?;
     m.add_function(wrap_pyfunction!(read_projective_plane_from_stream, m)?)?;
+
+    // Versioned JSON representations
+    m.add("SCHEMA_VERSION", uacalc::io::json::SCHEMA_VERSION)?;
+    m.add_function(wrap_pyfunction!(algebra_to_json, m)?)?;
+    m.add_function(wrap_pyfunction!(algebra_from_json, m)?)?;
+    m.add_function(wrap_pyfunction!(partition_to_json, m)?)?;
+    m.add_function(wrap_pyfunction!(partition_from_json, m)?)?;
+    m.add_function(wrap_pyfunction!(equation_to_json, m)?)?;
+    m.add_function(wrap_pyfunction!(equation_from_json, m)?)?;
+    m.add_function(wrap_pyfunction!(malcev_report, m)?)?;
+    m.add_function(wrap_pyfunction!(tct_report, m)?)?;
     
     let module_dict = m.dict();
     module_dict.del_item("PyMace4Reader")?;
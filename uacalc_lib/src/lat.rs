@@ -8,6 +8,7 @@ use uacalc::lat::lattices;
 use crate::alg::op::int_operation::PyIntOperation;
 use crate::alg::op::operation::PyBasicOperation;
 use std::ops::Deref;
+use crate::progress::{with_progress, PyProgressReporter};
 
 /// Internal enum to hold either type of BasicLattice
 /// Made public(crate) so it can be used in other modules
@@ -542,6 +543,133 @@ fn py_dual(lat: &Bound<'_, PyAny>, _py: Python) -> PyResult<PyObject> {
     }
 }
 
+fn parse_lattice_term(s: &str) -> PyResult<Box<dyn uacalc::terms::Term>> {
+    uacalc::terms::string_to_term(s).map_err(PyValueError::new_err)
+}
+
+/// Decide whether s <= t in the free lattice, using Whitman's condition.
+///
+/// Args:
+///     s (str): A lattice term using join and meet, e.g. "meet(x,join(y,z))"
+///     t (str): A lattice term
+///
+/// Returns:
+///     bool: Whether s <= t holds in every lattice
+///
+/// Raises:
+///     ValueError: If a term cannot be parsed or uses other operations
+#[pyfunction]
+fn py_free_lattice_leq(s: &str, t: &str) -> PyResult<bool> {
+    free_lattice_leq(parse_lattice_term(s)?.as_ref(), parse_lattice_term(t)?.as_ref()).map_err(PyValueError::new_err)
+}
+
+/// Compute the canonical form of a lattice term.
+///
+/// Args:
+///     t (str): A lattice term using join and meet
+///
+/// Returns:
+///     str: The shortest term for the same element of the free lattice
+///
+/// Raises:
+///     ValueError: If the term cannot be parsed or uses other operations
+#[pyfunction]
+fn py_lattice_canonical_form(t: &str) -> PyResult<String> {
+    lattice_canonical_form(parse_lattice_term(t)?.as_ref())
+        .map(|c| c.to_string())
+        .map_err(PyValueError::new_err)
+}
+
+/// Generate elements of the free lattice on gens generators x0, x1, ...
+///
+/// Args:
+///     gens (int): The number of generators
+///     max_size (int): The most elements to return
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     List[str]: Canonical terms of the elements in the order found
+#[pyfunction]
+#[pyo3(signature = (gens, max_size, progress=None))]
+fn py_free_lattice(
+    py: Python<'_>,
+    gens: usize,
+    max_size: usize,
+    progress: Option<&crate::progress::PyProgressReporter>,
+) -> PyResult<Vec<String>> {
+    crate::progress::with_progress(py, progress, || {
+        free_lattice(gens, max_size)
+            .map(|elems| elems.iter().map(|t| t.to_string()).collect())
+            .map_err(PyValueError::new_err)
+    })
+}
+
+/// The single operation given from Python, as an operation.
+fn extract_operation(py: Python<'_>, op: Bound<'_, PyAny>) -> PyResult<Box<dyn Operation>> {
+    let ops = crate::alg::basic_algebra::extract_operations(&pyo3::types::PyList::new_bound(py, [op]))?;
+    Ok(ops.into_iter().next().expect("one operation was given"))
+}
+
+/// Read a semilattice operation as a join and return its order, in which
+/// x <= y when join(x, y) = y. For a meet semilattice take the dual.
+///
+/// Args:
+///     op (Operation): A binary operation
+///
+/// Returns:
+///     Poset: The order on the indices of the universe
+///
+/// Raises:
+///     ValueError: If op is not idempotent, commutative and associative
+#[pyfunction]
+fn py_as_semilattice(py: Python<'_>, op: Bound<'_, PyAny>) -> PyResult<PyPoset> {
+    let op = extract_operation(py, op)?;
+    as_semilattice(op.as_ref()).map(|inner| PyPoset { inner }).map_err(PyValueError::new_err)
+}
+
+/// Check that two operations form a lattice and return its order.
+///
+/// Args:
+///     join (Operation): The join operation
+///     meet (Operation): The meet operation
+///
+/// Returns:
+///     Poset: The lattice order, in which x <= y when join(x, y) = y
+///
+/// Raises:
+///     ValueError: If either is not a semilattice operation or an
+///         absorption law fails
+#[pyfunction]
+fn py_as_lattice(py: Python<'_>, join: Bound<'_, PyAny>, meet: Bound<'_, PyAny>) -> PyResult<PyPoset> {
+    let (join, meet) = (extract_operation(py, join)?, extract_operation(py, meet)?);
+    as_lattice(join.as_ref(), meet.as_ref()).map(|inner| PyPoset { inner }).map_err(PyValueError::new_err)
+}
+
+/// Find two basic operations of an algebra that form a lattice.
+///
+/// Args:
+///     algebra (BasicAlgebra): The algebra
+///
+/// Returns:
+///     Optional[Tuple[int, int]]: The indices of the join and meet
+///         operations, or None
+#[pyfunction]
+fn py_find_lattice_reduct(algebra: &crate::alg::PyBasicAlgebra) -> Option<(usize, usize)> {
+    find_lattice_reduct(&algebra.inner)
+}
+
+/// Check whether an algebra has two basic operations forming a lattice.
+///
+/// Args:
+///     algebra (BasicAlgebra): The algebra
+///
+/// Returns:
+///     bool: Whether the algebra has a lattice reduct
+#[pyfunction]
+fn py_is_lattice_ordered(algebra: &crate::alg::PyBasicAlgebra) -> bool {
+    is_lattice_ordered(&algebra.inner)
+}
+
 /// Python wrapper for LatticeGraphData
 #[pyclass]
 pub struct PyLatticeGraphData {
@@ -640,11 +768,38 @@ impl PyLatticeGraphData {
 
 /// Python wrapper for BasicLattice
 /// This is a type-erased wrapper that can hold BasicLattice<Partition> or BasicLattice<BasicSet>
+///
+/// It does not support pickling; pickle the lattice it was built from instead.
 #[pyclass]
 pub struct PyBasicLattice {
     pub(crate) inner: BasicLatticeInner,
 }
 
+impl PyBasicLattice {
+    /// The lattice of a poset, with the indices as elements.
+    fn from_poset(poset: &Poset, name: &str) -> PyResult<Self> {
+        if !poset.is_lattice() {
+            return Err(PyValueError::new_err(format!("{} is not a lattice", poset)));
+        }
+        let univ: Vec<i32> = (0..poset.cardinality() as i32).collect();
+        let covers = poset.upper_covers()
+            .into_iter()
+            .map(|cs| cs.into_iter().map(|c| c as i32).collect())
+            .collect();
+        let ordered = OrderedSet::new(Some(name.to_string()), univ, covers).map_err(PyValueError::new_err)?;
+        let lat = BasicLattice::new_from_poset(name.to_string(), ordered, None).map_err(PyValueError::new_err)?;
+        Ok(PyBasicLattice { inner: BasicLatticeInner::Int32(std::sync::Arc::new(std::sync::Mutex::new(lat))) })
+    }
+
+    fn to_poset(&self) -> Poset {
+        match &self.inner {
+            BasicLatticeInner::Partition(inner) => Poset::from_lattice(&*inner.lock().unwrap()),
+            BasicLatticeInner::BasicSet(inner) => Poset::from_lattice(&*inner.lock().unwrap()),
+            BasicLatticeInner::Int32(inner) => Poset::from_lattice(&*inner.lock().unwrap()),
+        }
+    }
+}
+
 #[pymethods]
 impl PyBasicLattice {
     /// Create a BasicLattice from a CongruenceLattice
@@ -950,6 +1105,55 @@ impl PyBasicLattice {
         }
     }
     
+    /// The dual lattice, named "<name>^d".
+    ///
+    /// The combinators return a BasicLattice whose elements are the indices
+    /// of the elements, in the order of the universe of the result.
+    ///
+    /// Returns:
+    ///     BasicLattice: The dual lattice
+    fn dual(&self) -> PyResult<PyBasicLattice> {
+        Self::from_poset(&self.to_poset().dual(), &format!("{}^d", self.name()))
+    }
+
+    /// The direct product, named "<name> x <other>". The pair (a, b) is the
+    /// element a * other.cardinality() + b.
+    ///
+    /// Args:
+    ///     other (BasicLattice): The second factor
+    ///
+    /// Returns:
+    ///     BasicLattice: The product lattice
+    fn direct_product(&self, other: &PyBasicLattice) -> PyResult<PyBasicLattice> {
+        let product = self.to_poset().product(&other.to_poset());
+        Self::from_poset(&product, &format!("{} x {}", self.name(), other.name()))
+    }
+
+    /// The ordinal sum, named "<name> + <other>", with other placed above.
+    ///
+    /// Args:
+    ///     other (BasicLattice): The upper summand
+    ///
+    /// Returns:
+    ///     BasicLattice: The ordinal sum
+    fn ordinal_sum(&self, other: &PyBasicLattice) -> PyResult<PyBasicLattice> {
+        let sum = self.to_poset().ordinal_sum(&other.to_poset());
+        Self::from_poset(&sum, &format!("{} + {}", self.name(), other.name()))
+    }
+
+    /// The vertical (glued) sum, named "<name> ++ <other>": other placed
+    /// above with the one of this lattice identified with the zero of other.
+    ///
+    /// Args:
+    ///     other (BasicLattice): The upper summand
+    ///
+    /// Returns:
+    ///     BasicLattice: The vertical sum
+    fn vertical_sum(&self, other: &PyBasicLattice) -> PyResult<PyBasicLattice> {
+        let sum = self.to_poset().vertical_sum(&other.to_poset()).map_err(PyValueError::new_err)?;
+        Self::from_poset(&sum, &format!("{} ++ {}", self.name(), other.name()))
+    }
+
     /// Get zero (bottom) element.
     ///
     /// Returns:
@@ -1373,6 +1577,239 @@ impl PyOrderedSetBasicSet {
     }
 }
 
+/// Python wrapper for Poset
+#[pyclass(name = "Poset", module = "uacalc_lib.lat")]
+pub struct PyPoset {
+    pub(crate) inner: uacalc::lat::Poset,
+}
+
+impl PyPoset {
+    fn check_index(&self, a: usize) -> PyResult<()> {
+        if a >= self.inner.cardinality() {
+            return Err(PyValueError::new_err(format!("Element {} is out of range", a)));
+        }
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl PyPoset {
+    /// Create a poset from a covering relation.
+    ///
+    /// Args:
+    ///     upper_covers (List[List[int]]): The indices of the upper covers of
+    ///         each element; the order is their reflexive transitive closure
+    ///     labels (List[str], optional): A label for each element
+    ///     name (str, optional): The name of the poset
+    ///
+    /// Raises:
+    ///     ValueError: If an index is out of range or the covers contain a cycle
+    #[new]
+    #[pyo3(signature = (upper_covers, labels=None, name=None))]
+    fn new(upper_covers: Vec<Vec<usize>>, labels: Option<Vec<String>>, name: Option<String>) -> PyResult<Self> {
+        let labels = labels.unwrap_or_else(|| (0..upper_covers.len()).map(|i| i.to_string()).collect());
+        Poset::from_upper_covers(name, labels, &upper_covers)
+            .map(|inner| PyPoset { inner })
+            .map_err(PyValueError::new_err)
+    }
+
+    /// Create a poset on range(n) from a <= predicate.
+    ///
+    /// Args:
+    ///     n (int): The number of elements
+    ///     leq (Callable[[int, int], bool]): The order
+    ///     name (str, optional): The name of the poset
+    ///
+    /// Raises:
+    ///     ValueError: If leq is not a partial order
+    #[staticmethod]
+    #[pyo3(signature = (n, leq, name=None))]
+    fn from_leq(n: usize, leq: &Bound<'_, PyAny>, name: Option<String>) -> PyResult<Self> {
+        let mut matrix = vec![vec![false; n]; n];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                *entry = leq.call1((i, j))?.is_truthy()?;
+            }
+        }
+        Poset::from_leq(name, n, |i, j| matrix[i][j])
+            .map(|inner| PyPoset { inner })
+            .map_err(PyValueError::new_err)
+    }
+
+    /// Pickle support: the poset is rebuilt from its covers, labels and name.
+    #[allow(clippy::type_complexity)]
+    fn __reduce__(slf: &Bound<'_, Self>) -> (PyObject, (Vec<Vec<usize>>, Vec<String>, Option<String>)) {
+        let poset = &slf.borrow().inner;
+        let args = (poset.upper_covers(), poset.labels().to_vec(), poset.name().map(str::to_string));
+        (slf.get_type().into_any().unbind(), args)
+    }
+
+    /// Create the poset of a BasicLattice.
+    #[staticmethod]
+    fn from_lattice(lat: &PyBasicLattice) -> Self {
+        let inner = match &lat.inner {
+            BasicLatticeInner::Partition(inner) => Poset::from_lattice(&inner.lock().unwrap()),
+            BasicLatticeInner::BasicSet(inner) => Poset::from_lattice(&inner.lock().unwrap()),
+            BasicLatticeInner::Int32(inner) => Poset::from_lattice(&inner.lock().unwrap()),
+        };
+        PyPoset { inner }
+    }
+
+    fn name(&self) -> Option<String> {
+        self.inner.name().map(|s| s.to_string())
+    }
+
+    fn labels(&self) -> Vec<String> {
+        self.inner.labels().to_vec()
+    }
+
+    fn cardinality(&self) -> usize {
+        self.inner.cardinality()
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.cardinality()
+    }
+
+    /// Check if a <= b.
+    fn leq(&self, a: usize, b: usize) -> PyResult<bool> {
+        self.check_index(a)?;
+        self.check_index(b)?;
+        Ok(self.inner.leq(a, b))
+    }
+
+    fn upper_covers(&self) -> Vec<Vec<usize>> {
+        self.inner.upper_covers()
+    }
+
+    fn lower_covers(&self) -> Vec<Vec<usize>> {
+        self.inner.lower_covers()
+    }
+
+    fn minimals(&self) -> Vec<usize> {
+        self.inner.minimals()
+    }
+
+    fn maximals(&self) -> Vec<usize> {
+        self.inner.maximals()
+    }
+
+    fn linear_extension(&self) -> Vec<usize> {
+        self.inner.linear_extension()
+    }
+
+    /// All linear extensions, in lexicographic order.
+    ///
+    /// Args:
+    ///     progress (ProgressReporter, optional): Progress and cancellation
+    #[pyo3(signature = (progress=None))]
+    fn linear_extensions(&self, py: Python<'_>, progress: Option<&PyProgressReporter>) -> PyResult<Vec<Vec<usize>>> {
+        with_progress(py, progress, || self.inner.linear_extensions().map_err(PyValueError::new_err))
+    }
+
+    /// All maximal chains, each listed from the bottom up.
+    #[pyo3(signature = (progress=None))]
+    fn maximal_chains(&self, py: Python<'_>, progress: Option<&PyProgressReporter>) -> PyResult<Vec<Vec<usize>>> {
+        with_progress(py, progress, || self.inner.maximal_chains().map_err(PyValueError::new_err))
+    }
+
+    /// All maximal antichains, each sorted.
+    #[pyo3(signature = (progress=None))]
+    fn maximal_antichains(&self, py: Python<'_>, progress: Option<&PyProgressReporter>) -> PyResult<Vec<Vec<usize>>> {
+        with_progress(py, progress, || self.inner.maximal_antichains().map_err(PyValueError::new_err))
+    }
+
+    /// The number of elements of a longest chain.
+    fn height(&self) -> usize {
+        self.inner.height()
+    }
+
+    /// The number of elements of a largest antichain.
+    fn width(&self) -> usize {
+        self.inner.width()
+    }
+
+    /// The Mobius function mu(a, b).
+    fn mobius(&self, a: usize, b: usize) -> PyResult<i64> {
+        self.check_index(a)?;
+        self.check_index(b)?;
+        Ok(self.inner.mobius(a, b))
+    }
+
+    fn dual(&self) -> PyPoset {
+        PyPoset { inner: self.inner.dual() }
+    }
+
+    /// The direct product; the pair (a, b) is the element a * len(other) + b.
+    fn product(&self, other: &PyPoset) -> PyPoset {
+        PyPoset { inner: self.inner.product(&other.inner) }
+    }
+
+    /// The ordinal sum with other placed above; the elements of other
+    /// follow those of this poset.
+    fn ordinal_sum(&self, other: &PyPoset) -> PyPoset {
+        PyPoset { inner: self.inner.ordinal_sum(&other.inner) }
+    }
+
+    /// The vertical sum: other placed above with the top of this poset
+    /// identified with the bottom of other.
+    ///
+    /// Raises:
+    ///     ValueError: If this poset has no top or other has no bottom
+    fn vertical_sum(&self, other: &PyPoset) -> PyResult<PyPoset> {
+        self.inner.vertical_sum(&other.inner)
+            .map(|inner| PyPoset { inner })
+            .map_err(PyValueError::new_err)
+    }
+
+    fn is_lattice(&self) -> bool {
+        self.inner.is_lattice()
+    }
+
+    /// Whether this is a meet semidistributive lattice:
+    /// a ∧ b = a ∧ c implies a ∧ b = a ∧ (b ∨ c).
+    fn is_meet_semidistributive(&self) -> bool {
+        self.inner.is_meet_semidistributive()
+    }
+
+    /// Whether this is a join semidistributive lattice:
+    /// a ∨ b = a ∨ c implies a ∨ b = a ∨ (b ∧ c).
+    fn is_join_semidistributive(&self) -> bool {
+        self.inner.is_join_semidistributive()
+    }
+
+    /// Indices (a, b, c), b < c, with a ∧ b = a ∧ c but a ∧ (b ∨ c) ≠ a ∧ b;
+    /// None if there are none or this is not a lattice.
+    fn meet_semidistributivity_failure(&self) -> Option<(usize, usize, usize)> {
+        self.inner.meet_semidistributivity_failure()
+    }
+
+    /// Indices (a, b, c), b < c, with a ∨ b = a ∨ c but a ∨ (b ∧ c) ≠ a ∨ b;
+    /// None if there are none or this is not a lattice.
+    fn join_semidistributivity_failure(&self) -> Option<(usize, usize, usize)> {
+        self.inner.join_semidistributivity_failure()
+    }
+
+    /// Convert to a BasicLattice whose elements are the indices.
+    ///
+    /// Args:
+    ///     name (str): The name of the lattice
+    ///
+    /// Raises:
+    ///     ValueError: If the poset is not a lattice
+    fn to_lattice(&self, name: &str) -> PyResult<PyBasicLattice> {
+        PyBasicLattice::from_poset(&self.inner, name)
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        self.inner.to_string()
+    }
+}
+
 pub fn register_lat_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Register classes internally but only export clean names
     m.add_class::<PyDivisibilityOrder>()?;
@@ -1385,6 +1822,7 @@ pub fn register_lat_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()>
     m.add_class::<PyOrderedSet>()?;
     m.add_class::<PyOrderedSetPartition>()?;
     m.add_class::<PyOrderedSetBasicSet>()?;
+    m.add_class::<PyPoset>()?;
     
     // Export only clean names (without Py prefix)
     m.add("DivisibilityOrder", m.getattr("PyDivisibilityOrder")?)?;
@@ -1406,6 +1844,13 @@ pub fn register_lat_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()>
     m.add_function(wrap_pyfunction!(py_lattice_from_join_with_universe, m)?)?;
     m.add_function(wrap_pyfunction!(py_con_to_small_lattice, m)?)?;
     m.add_function(wrap_pyfunction!(py_dual, m)?)?;
+    m.add_function(wrap_pyfunction!(py_free_lattice_leq, m)?)?;
+    m.add_function(wrap_pyfunction!(py_lattice_canonical_form, m)?)?;
+    m.add_function(wrap_pyfunction!(py_free_lattice, m)?)?;
+    m.add_function(wrap_pyfunction!(py_as_semilattice, m)?)?;
+    m.add_function(wrap_pyfunction!(py_as_lattice, m)?)?;
+    m.add_function(wrap_pyfunction!(py_find_lattice_reduct, m)?)?;
+    m.add_function(wrap_pyfunction!(py_is_lattice_ordered, m)?)?;
     
     // Add clean function names
     m.add("lattice_from_meet", m.getattr("py_lattice_from_meet")?)?;
@@ -1414,6 +1859,13 @@ pub fn register_lat_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()>
     m.add("lattice_from_join_with_universe", m.getattr("py_lattice_from_join_with_universe")?)?;
     m.add("con_to_small_lattice", m.getattr("py_con_to_small_lattice")?)?;
     m.add("dual", m.getattr("py_dual")?)?;
+    m.add("free_lattice_leq", m.getattr("py_free_lattice_leq")?)?;
+    m.add("lattice_canonical_form", m.getattr("py_lattice_canonical_form")?)?;
+    m.add("free_lattice", m.getattr("py_free_lattice")?)?;
+    m.add("as_semilattice", m.getattr("py_as_semilattice")?)?;
+    m.add("as_lattice", m.getattr("py_as_lattice")?)?;
+    m.add("find_lattice_reduct", m.getattr("py_find_lattice_reduct")?)?;
+    m.add("is_lattice_ordered", m.getattr("py_is_lattice_ordered")?)?;
     
     // Export clean names for new classes
     m.add("BasicLattice", m.getattr("PyBasicLattice")?)?;
@@ -1442,6 +1894,13 @@ pub fn register_lat_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()>
     module_dict.del_item("py_lattice_from_join_with_universe")?;
     module_dict.del_item("py_con_to_small_lattice")?;
     module_dict.del_item("py_dual")?;
+    module_dict.del_item("py_free_lattice_leq")?;
+    module_dict.del_item("py_lattice_canonical_form")?;
+    module_dict.del_item("py_free_lattice")?;
+    module_dict.del_item("py_as_semilattice")?;
+    module_dict.del_item("py_as_lattice")?;
+    module_dict.del_item("py_find_lattice_reduct")?;
+    module_dict.del_item("py_is_lattice_ordered")?;
     
     // Note: Lattice and SmallLattice are traits (interfaces) and cannot be instantiated directly.
     // Python bindings are provided for concrete implementations like DiamondLattice and BooleanLattice.
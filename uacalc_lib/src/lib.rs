@@ -1,16 +1,24 @@
 use pyo3::prelude::*;
 
 pub mod alg;
+pub mod compat;
 pub mod element;
 pub mod eq;
+pub mod events;
 pub mod example;
 pub mod fplat;
 pub mod group;
 pub mod io;
 pub mod lat;
+pub mod memory;
+pub mod progress;
+pub mod session;
+pub mod telemetry;
 pub mod terms;
 pub mod types;
 pub mod util;
+#[cfg(feature = "verify")]
+pub mod verify;
 
 /// A Python module implemented in Rust.
 #[pymodule]
@@ -20,6 +28,11 @@ fn uacalc_lib(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     alg::register_alg_module(_py, &alg_module)?;
     m.add_submodule(&alg_module)?;
 
+    // Java-style compatibility module
+    let compat_module = PyModule::new_bound(_py, "compat")?;
+    compat::register_compat_module(_py, &compat_module)?;
+    m.add_submodule(&compat_module)?;
+
     // Element module
     let element_module = PyModule::new_bound(_py, "element")?;
     element::register_element_module(_py, &element_module)?;
@@ -30,6 +43,11 @@ fn uacalc_lib(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     eq::register_eq_module(_py, &eq_module)?;
     m.add_submodule(&eq_module)?;
 
+    // Events module
+    let events_module = PyModule::new_bound(_py, "events")?;
+    events::register_events_module(_py, &events_module)?;
+    m.add_submodule(&events_module)?;
+
     // Example module
     let example_module = PyModule::new_bound(_py, "example")?;
     example::register_example_module(_py, &example_module)?;
@@ -55,6 +73,27 @@ fn uacalc_lib(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     lat::register_lat_module(_py, &lat_module)?;
     m.add_submodule(&lat_module)?;
 
+    // Memory module
+    let memory_module = PyModule::new_bound(_py, "memory")?;
+    memory::register_memory_module(_py, &memory_module)?;
+    m.add_submodule(&memory_module)?;
+
+    // Progress module
+    let progress_module = PyModule::new_bound(_py, "progress")?;
+    progress::register_progress_module(_py, &progress_module)?;
+    m.add_submodule(&progress_module)?;
+
+    // Session module
+    let session_module = PyModule::new_bound(_py, "session")?;
+    session::register_session_module(_py, &session_module)?;
+    m.add_submodule(&session_module)?;
+
+    // Telemetry module
+    let telemetry_module = PyModule::new_bound(_py, "telemetry")?;
+    telemetry::register_telemetry_module(_py, &telemetry_module)?;
+    m.add_submodule(&telemetry_module)?;
+    m.add("enable_logging", telemetry_module.getattr("enable_logging")?)?;
+
     // Terms module
     let terms_module = PyModule::new_bound(_py, "terms")?;
     terms::register_terms_module(_py, &terms_module)?;
@@ -64,11 +103,31 @@ fn uacalc_lib(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     let util_module = PyModule::new_bound(_py, "util")?;
     util::register_util_module(_py, &util_module)?;
     m.add_submodule(&util_module)?;
+    m.add("set_global_seed", util_module.getattr("set_global_seed")?)?;
 
     // Types module
     let types_module = PyModule::new_bound(_py, "types")?;
     types::register_types_module(_py, &types_module)?;
     m.add_submodule(&types_module)?;
 
+    // Verify module
+    #[cfg(feature = "verify")]
+    {
+        let verify_module = PyModule::new_bound(_py, "verify")?;
+        verify::register_verify_module(_py, &verify_module)?;
+        m.add_submodule(&verify_module)?;
+    }
+
+    // Make the submodules importable by their dotted names, so that pickle
+    // can find the classes they define.
+    let sys_modules = _py.import_bound("sys")?.getattr("modules")?;
+    let mut names = vec!["alg", "compat", "element", "eq", "events", "example", "fplat", "group", "io", "lat", "memory", "progress", "session", "telemetry", "terms", "util", "types"];
+    if cfg!(feature = "verify") {
+        names.push("verify");
+    }
+    for name in names {
+        sys_modules.set_item(format!("uacalc_lib.{}", name), m.getattr(name)?)?;
+    }
+
     Ok(())
 }
@@ -0,0 +1,121 @@
+//! Python bindings for memory accounting and limits
+
+use std::collections::HashMap;
+use pyo3::prelude::*;
+use uacalc::alg::{Algebra, SmallAlgebra};
+use uacalc::memory;
+use crate::alg::PyBasicAlgebra;
+
+/// Register the memory module.
+pub fn register_memory_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(set_memory_limit, m)?)?;
+    m.add_function(wrap_pyfunction!(get_memory_limit, m)?)?;
+    m.add_function(wrap_pyfunction!(get_allocated_memory, m)?)?;
+    m.add_function(wrap_pyfunction!(get_used_memory, m)?)?;
+    m.add_function(wrap_pyfunction!(last_memory_usage, m)?)?;
+    m.add_function(wrap_pyfunction!(estimate_conlat_memory, m)?)?;
+    m.add_function(wrap_pyfunction!(estimate_free_algebra_memory, m)?)?;
+    m.add_function(wrap_pyfunction!(estimate_product_table_memory, m)?)?;
+    m.add("CONGRUENCE_LATTICE", uacalc::alg::conlat::congruence_lattice::MEMORY_SCOPE)?;
+    m.add("FREE_ALGEBRA", uacalc::alg::free_algebra::MEMORY_SCOPE)?;
+    m.add("PRODUCT_TABLES", uacalc::alg::product_algebra::MEMORY_SCOPE)?;
+    Ok(())
+}
+
+/// Set the soft memory limit in bytes, or remove it with None.
+///
+/// Long-running computations check the limit wherever they check for
+/// cancellation and raise MemoryError once it has been passed.
+///
+/// Args:
+///     limit (Optional[int]): The limit in bytes
+#[pyfunction]
+#[pyo3(signature = (limit))]
+fn set_memory_limit(limit: Option<usize>) {
+    memory::set_memory_limit(limit);
+}
+
+/// Get the soft memory limit in bytes, or None if there is none.
+#[pyfunction]
+fn get_memory_limit() -> Option<usize> {
+    memory::memory_limit()
+}
+
+/// Get the number of bytes currently allocated by the library's allocator.
+///
+/// Returns:
+///     Optional[int]: The byte count, or None if allocations are not counted
+#[pyfunction]
+fn get_allocated_memory() -> Option<usize> {
+    memory::allocated_memory()
+}
+
+/// Get the memory used by the process, as checked against the limit.
+///
+/// Returns:
+///     Optional[int]: The byte count, or None if it cannot be measured
+#[pyfunction]
+fn get_used_memory() -> Option<usize> {
+    memory::used_memory()
+}
+
+/// Get the memory used by the last computation of a kind.
+///
+/// Args:
+///     label (str): CONGRUENCE_LATTICE, FREE_ALGEBRA or PRODUCT_TABLES
+///
+/// Returns:
+///     Optional[Dict[str, int]]: "retained" and "peak" bytes, or None if no
+///         such computation has finished
+#[pyfunction]
+fn last_memory_usage(label: &str) -> Option<HashMap<String, usize>> {
+    memory::last_usage(label).map(|usage| {
+        HashMap::from([("retained".to_string(), usage.retained), ("peak".to_string(), usage.peak)])
+    })
+}
+
+/// Estimate the memory needed to build the congruence lattice of an algebra.
+///
+/// Args:
+///     algebra (BasicAlgebra): The algebra
+///     con_size (int, optional): The number of congruences, if known; by
+///         default the number of principal congruences, giving a lower bound
+///
+/// Returns:
+///     int: The estimate in bytes
+#[pyfunction]
+#[pyo3(signature = (algebra, con_size=None))]
+fn estimate_conlat_memory(algebra: &PyBasicAlgebra, con_size: Option<usize>) -> usize {
+    let n = algebra.inner.cardinality().max(0) as usize;
+    let con_size = con_size.unwrap_or(n * n.saturating_sub(1) / 2);
+    memory::estimate_conlat_memory(n, con_size)
+}
+
+/// Estimate the memory needed for a free algebra over an algebra.
+///
+/// Args:
+///     algebra (BasicAlgebra): The base algebra
+///     number_of_gens (int): The number of generators
+///
+/// Returns:
+///     int: An upper bound in bytes, saturating at the largest machine integer
+#[pyfunction]
+fn estimate_free_algebra_memory(algebra: &PyBasicAlgebra, number_of_gens: usize) -> usize {
+    memory::estimate_free_algebra_memory(algebra.inner.cardinality().max(0) as usize, number_of_gens)
+}
+
+/// Estimate the memory needed for the operation tables of a product.
+///
+/// Args:
+///     algebras (List[BasicAlgebra]): The factors, all of the same similarity type
+///
+/// Returns:
+///     int: The estimate in bytes
+#[pyfunction]
+fn estimate_product_table_memory(algebras: Vec<PyRef<PyBasicAlgebra>>) -> usize {
+    let sizes: Vec<usize> = algebras.iter().map(|a| a.inner.cardinality().max(0) as usize).collect();
+    let arities: Vec<usize> = algebras.first()
+        .map(|a| a.inner.get_operations_ref().iter().map(|op| op.arity().max(0) as usize).collect())
+        .unwrap_or_default();
+    memory::estimate_product_table_memory(&sizes, &arities)
+}
@@ -0,0 +1,260 @@
+//! Python wrapper for ProgressToken
+
+use std::ops::ControlFlow;
+use std::sync::{Arc, Mutex};
+use pyo3::prelude::*;
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyMemoryError};
+use std::time::Duration;
+use uacalc::progress::{Budget, ComputationError, ProgressToken, StopReason};
+
+create_exception!(uacalc_lib, CancelledError, PyException, "Raised when a computation is cancelled through a ProgressReporter.");
+create_exception!(uacalc_lib, BudgetExceededError, CancelledError, "Raised when a computation exceeds the budget of its ProgressReporter.");
+
+/// Register the progress module.
+pub fn register_progress_module(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyProgressReporter>()?;
+    m.add("ProgressReporter", m.getattr("PyProgressReporter")?)?;
+    m.add_class::<PyBudget>()?;
+    m.add("Budget", m.getattr("PyBudget")?)?;
+    m.add("CancelledError", py.get_type_bound::<CancelledError>())?;
+    m.add("BudgetExceededError", py.get_type_bound::<BudgetExceededError>())?;
+    Ok(())
+}
+
+/// Limits on the resources of a computation.
+///
+/// Give a budget to a ProgressReporter; a computation run with that
+/// reporter which passes a limit raises `BudgetExceededError`, whose
+/// `partial` attribute holds what was computed so far where the function
+/// supports it (closures and congruence lattice universes).
+#[pyclass]
+#[derive(Clone)]
+pub struct PyBudget {
+    pub(crate) inner: Budget,
+}
+
+#[pymethods]
+impl PyBudget {
+    /// Create a budget; limits left as None are not checked.
+    ///
+    /// Args:
+    ///     wall_time (float, optional): The most seconds a computation may run
+    ///     max_memory (int, optional): The most bytes the process may use
+    ///         (checked on Linux only)
+    ///     max_elements (int, optional): The most elements a computation may produce
+    #[new]
+    #[pyo3(signature = (wall_time=None, max_memory=None, max_elements=None))]
+    fn new(wall_time: Option<f64>, max_memory: Option<usize>, max_elements: Option<usize>) -> PyResult<Self> {
+        let wall_time = match wall_time {
+            Some(t) if !(t >= 0.0 && t.is_finite()) => {
+                return Err(pyo3::exceptions::PyValueError::new_err("wall_time must be a non-negative number of seconds"));
+            }
+            t => t.map(Duration::from_secs_f64),
+        };
+        Ok(PyBudget { inner: Budget { wall_time, max_memory, max_elements } })
+    }
+
+    #[getter]
+    fn wall_time(&self) -> Option<f64> {
+        self.inner.wall_time.map(|t| t.as_secs_f64())
+    }
+
+    #[getter]
+    fn max_memory(&self) -> Option<usize> {
+        self.inner.max_memory
+    }
+
+    #[getter]
+    fn max_elements(&self) -> Option<usize> {
+        self.inner.max_elements
+    }
+
+    fn __repr__(&self) -> String {
+        let show = |v: Option<String>| v.unwrap_or_else(|| "None".to_string());
+        format!(
+            "Budget(wall_time={}, max_memory={}, max_elements={})",
+            show(self.wall_time().map(|t| t.to_string())),
+            show(self.inner.max_memory.map(|m| m.to_string())),
+            show(self.inner.max_elements.map(|m| m.to_string())),
+        )
+    }
+}
+
+/// Progress reporting and cancellation for long-running computations.
+///
+/// Pass a reporter as the `progress` argument of a long-running function.
+/// The callback is called with the current phase and a message; returning
+/// True, or calling `cancel()` from another thread, stops the computation,
+/// which then raises `CancelledError`. An exception raised by the callback
+/// also stops it and is re-raised. With a budget, a computation passing one
+/// of its limits raises `BudgetExceededError`.
+#[pyclass]
+pub struct PyProgressReporter {
+    pub(crate) token: ProgressToken,
+    error: Arc<Mutex<Option<PyErr>>>,
+    budget: Option<Budget>,
+}
+
+#[pymethods]
+impl PyProgressReporter {
+    /// Create a progress reporter.
+    ///
+    /// Args:
+    ///     callback (Callable[[str, str], Optional[bool]], optional): Called
+    ///         with the phase and message of each progress event
+    ///     budget (Budget, optional): Resource limits for each computation
+    ///         run with this reporter
+    #[new]
+    #[pyo3(signature = (callback=None, budget=None))]
+    fn new(callback: Option<PyObject>, budget: Option<PyBudget>) -> Self {
+        let error: Arc<Mutex<Option<PyErr>>> = Arc::new(Mutex::new(None));
+        let token = match callback {
+            None => ProgressToken::new(),
+            Some(cb) => {
+                let error = error.clone();
+                ProgressToken::with_callback(move |event| {
+                    Python::with_gil(|py| match cb.call1(py, (event.phase.as_str(), event.message.as_str())) {
+                        Ok(r) if r.bind(py).is_truthy().unwrap_or(false) => ControlFlow::Break(()),
+                        Ok(_) => ControlFlow::Continue(()),
+                        Err(e) => {
+                            *error.lock().unwrap() = Some(e);
+                            ControlFlow::Break(())
+                        }
+                    })
+                })
+            }
+        };
+        PyProgressReporter { token, error, budget: budget.map(|b| b.inner) }
+    }
+
+    /// Request cancellation; the computation stops at its next check.
+    fn cancel(&self) {
+        self.token.cancel();
+    }
+
+    /// Check whether cancellation has been requested.
+    fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// Get the current phase labels, outermost first, joined by " / ".
+    fn current_phase(&self) -> String {
+        self.token.current_phase()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ProgressReporter(cancelled={})", self.token.is_cancelled())
+    }
+}
+
+/// Run `f` with the GIL released and the reporter's token installed.
+///
+/// Other Python threads keep running while `f` computes; the reporter's
+/// callback reacquires the GIL for each call. The reporter's budget, if any,
+/// starts counting now, afresh for each computation. The computation runs
+/// through the global event bus, named after the function calling
+/// `with_progress`, so listeners subscribed with `events.subscribe` see it
+/// and may cancel it. A computation stopped by the reporter raises the
+/// callback's exception, if it raised one, `BudgetExceededError` if the
+/// budget stopped it and `CancelledError` otherwise. With or without a
+/// reporter, a computation stopped by the soft memory limit raises
+/// `MemoryError`.
+pub fn with_progress<R: Send, F: FnOnce() -> PyResult<R> + Send>(
+    py: Python<'_>,
+    progress: Option<&PyProgressReporter>,
+    f: F,
+) -> PyResult<R> {
+    let (token, result) = run(py, progress, f);
+    if let Some(e) = progress.and_then(|p| p.error.lock().unwrap().take()) {
+        return Err(e);
+    }
+    match result {
+        Err(e) => match token.stop_reason() {
+            Some(reason) => Err(stopped_error(py, reason, py.None())?),
+            None => Err(e),
+        },
+        ok => ok,
+    }
+}
+
+/// Like [`with_progress`], for a computation returning the typed error of
+/// the core library: a stopped computation raises as there, with its
+/// partial result in the `partial` attribute of a `BudgetExceededError`,
+/// and a failed one raises `failed` of the message.
+pub fn with_progress_partial<R, P, F>(
+    py: Python<'_>,
+    progress: Option<&PyProgressReporter>,
+    failed: fn(String) -> PyErr,
+    f: F,
+) -> PyResult<R>
+where
+    R: Send,
+    P: IntoPy<PyObject> + Send,
+    F: FnOnce() -> Result<R, ComputationError<P>> + Send,
+{
+    let (_, result) = run(py, progress, f);
+    if let Some(e) = progress.and_then(|p| p.error.lock().unwrap().take()) {
+        return Err(e);
+    }
+    match result {
+        Ok(r) => Ok(r),
+        Err(ComputationError::Stopped { reason, partial }) => Err(stopped_error(py, reason, partial.into_py(py))?),
+        Err(ComputationError::Failed(message)) => Err(failed(message)),
+    }
+}
+
+/// Run `f` for [`with_progress`], returning the token it ran with.
+fn run<R: Send, E: std::fmt::Display + Send, F: FnOnce() -> Result<R, E> + Send>(
+    py: Python<'_>,
+    progress: Option<&PyProgressReporter>,
+    f: F,
+) -> (ProgressToken, Result<R, E>) {
+    let token = match progress {
+        Some(p) => {
+            p.token.set_budget(p.budget.unwrap_or_default());
+            p.token.clone()
+        }
+        None => ProgressToken::new(),
+    };
+    let installed = token.clone();
+    let result = py.allow_threads(move || {
+        // The token is thread-local, so install it on the thread doing the work.
+        let _installed = installed.install();
+        uacalc::events::global().run(&computation_name::<F>(), f)
+    });
+    (token, result)
+}
+
+/// The exception for a computation stopped for `reason`.
+fn stopped_error(py: Python<'_>, reason: StopReason, partial: PyObject) -> PyResult<PyErr> {
+    Ok(match reason {
+        StopReason::Cancelled => CancelledError::new_err(reason.to_string()),
+        StopReason::BudgetExceeded(exceeded) => {
+            let err = BudgetExceededError::new_err(exceeded.to_string());
+            let value = err.value_bound(py);
+            value.setattr("resource", exceeded.resource.to_string())?;
+            value.setattr("limit", exceeded.limit)?;
+            value.setattr("used", exceeded.used)?;
+            value.setattr("partial", partial)?;
+            err
+        }
+        StopReason::MemoryLimit(message) => PyMemoryError::new_err(message),
+    })
+}
+
+/// The name of the function defining the closure `F`: "pp_interprets" or,
+/// for a method of a wrapper class, "CongruenceLattice.universe".
+fn computation_name<F>() -> String {
+    let path: Vec<&str> = std::any::type_name::<F>()
+        .split("::")
+        .filter(|s| !s.starts_with("{{"))
+        .collect();
+    match path.as_slice() {
+        [.., class, method] if class.starts_with("Py") && class[2..].starts_with(char::is_uppercase) => {
+            format!("{}.{}", &class[2..], method)
+        }
+        [.., function] => function.to_string(),
+        [] => String::new(),
+    }
+}
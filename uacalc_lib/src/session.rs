@@ -0,0 +1,209 @@
+//! Python wrapper for the algebra workspace
+
+use std::sync::Arc;
+use pyo3::prelude::*;
+use pyo3::exceptions::{PyKeyError, PyValueError};
+use pyo3::types::PyList;
+use uacalc::session::{AlgebraWorkspace, Command};
+use uacalc::terms::string_to_term;
+use crate::alg::basic_algebra::extract_operations;
+use crate::alg::{PyBasicAlgebra, PyPartition};
+use crate::eq::{convert_to_term, PyEquation};
+
+/// Register the session module.
+pub fn register_session_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyWorkspace>()?;
+    Ok(())
+}
+
+/// Named algebras, saved terms and equations and notes, with an undo/redo
+/// log of the commands that built them.
+///
+/// Each method that changes the workspace is a command that `undo` reverts;
+/// a command that fails raises ValueError and changes nothing. Indexing by
+/// name gives a copy of an algebra:
+///
+///     ws = Workspace()
+///     ws.add_algebra("A", alg)
+///     ws.quotient("A", theta, "Q")
+///     ws.undo()  # 'Q = A/...'
+///
+/// `save` writes the workspace, with the congruences and subuniverses
+/// computed so far but without the history, to a `.uaproj` project file
+/// that `Workspace.load` reads back.
+#[pyclass(name = "Workspace", module = "uacalc_lib.session")]
+#[derive(Default)]
+pub struct PyWorkspace {
+    inner: AlgebraWorkspace,
+}
+
+impl PyWorkspace {
+    fn execute(&mut self, cmd: Command) -> PyResult<()> {
+        self.inner.execute(cmd).map_err(PyValueError::new_err)
+    }
+}
+
+#[pymethods]
+impl PyWorkspace {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a copy of an algebra under a new name, which it takes as its own.
+    fn add_algebra(&mut self, name: String, algebra: &PyBasicAlgebra) -> PyResult<()> {
+        self.execute(Command::AddAlgebra { name, algebra: Box::new(algebra.inner.clone()) })
+    }
+
+    /// Remove an algebra.
+    fn remove(&mut self, name: String) -> PyResult<()> {
+        self.execute(Command::RemoveAlgebra { name })
+    }
+
+    /// Rename an algebra.
+    fn rename(&mut self, old: String, new: String) -> PyResult<()> {
+        self.execute(Command::Rename { from: old, to: new })
+    }
+
+    /// Add an operation (IntOperation, BasicOperation, ...) to an algebra.
+    fn add_operation(&mut self, py: Python<'_>, name: String, operation: PyObject) -> PyResult<()> {
+        let op = extract_operations(&PyList::new_bound(py, [operation]))?
+            .pop()
+            .ok_or_else(|| PyValueError::new_err("Not an operation"))?;
+        self.execute(Command::AddOperation { algebra: name, operation: Arc::from(op) })
+    }
+
+    /// Remove the operation with the given symbol name from an algebra.
+    fn remove_operation(&mut self, name: String, symbol: String) -> PyResult<()> {
+        self.execute(Command::RemoveOperation { algebra: name, symbol })
+    }
+
+    /// Set the value of an operation of an algebra at the given arguments.
+    fn set_operation_value(&mut self, name: String, symbol: String, args: Vec<i32>, value: i32) -> PyResult<()> {
+        self.execute(Command::SetOperationValue { algebra: name, symbol, args, value })
+    }
+
+    /// Add the quotient of an algebra by a congruence under a new name.
+    fn quotient(&mut self, name: String, congruence: &PyPartition, new_name: String) -> PyResult<()> {
+        self.execute(Command::Quotient { algebra: name, congruence: congruence.inner.clone(), name: new_name })
+    }
+
+    /// Add the subalgebra generated by the given elements under a new name.
+    fn subalgebra(&mut self, name: String, generators: Vec<i32>, new_name: String) -> PyResult<()> {
+        self.execute(Command::Subalgebra { algebra: name, generators, name: new_name })
+    }
+
+    /// Save a term, given as a string or a term object, under a name.
+    fn save_term(&mut self, name: String, term: &Bound<'_, PyAny>) -> PyResult<()> {
+        let term = match term.extract::<String>() {
+            Ok(s) => string_to_term(&s).map_err(PyValueError::new_err)?,
+            Err(_) => convert_to_term(term)?,
+        };
+        self.execute(Command::SaveTerm { name, term: Arc::from(term) })
+    }
+
+    /// Save an equation under a name.
+    fn save_equation(&mut self, name: String, equation: &PyEquation) -> PyResult<()> {
+        self.execute(Command::SaveEquation { name, equation: equation.inner.clone() })
+    }
+
+    /// Replace the notes.
+    fn set_notes(&mut self, notes: String) -> PyResult<()> {
+        self.execute(Command::SetNotes { notes })
+    }
+
+    /// The notes.
+    fn notes(&self) -> String {
+        self.inner.notes().to_string()
+    }
+
+    /// The saved terms as strings, by name.
+    fn terms(&self) -> Vec<(String, String)> {
+        self.inner.terms().map(|(name, term)| (name.to_string(), term.to_string())).collect()
+    }
+
+    /// The saved equations, by name.
+    fn equations(&self) -> Vec<(String, PyEquation)> {
+        self.inner.equations().map(|(name, eq)| (name.to_string(), PyEquation { inner: eq.clone() })).collect()
+    }
+
+    /// The congruences of an algebra, computed once and kept until its
+    /// operations change.
+    fn congruences(&mut self, name: &str) -> PyResult<Vec<PyPartition>> {
+        self.inner.get(name).ok_or_else(|| PyKeyError::new_err(name.to_string()))?;
+        let cons = self.inner.congruences(name).map_err(PyValueError::new_err)?;
+        Ok(cons.iter().cloned().map(PyPartition::from_inner).collect())
+    }
+
+    /// The nonempty subuniverses of an algebra as sorted lists, computed once
+    /// and kept until its operations change.
+    fn subuniverses(&mut self, name: &str) -> PyResult<Vec<Vec<i32>>> {
+        self.inner.get(name).ok_or_else(|| PyKeyError::new_err(name.to_string()))?;
+        self.inner.subuniverses(name).map(<[_]>::to_vec).map_err(PyValueError::new_err)
+    }
+
+    /// Write the workspace to a project file, conventionally ending in `.uaproj`.
+    fn save(&self, path: &str) -> PyResult<()> {
+        self.inner.save(path).map_err(PyValueError::new_err)
+    }
+
+    /// Read a workspace written by `save`, with an empty history.
+    #[staticmethod]
+    fn load(path: &str) -> PyResult<Self> {
+        AlgebraWorkspace::load(path).map(|inner| PyWorkspace { inner }).map_err(PyValueError::new_err)
+    }
+
+    /// Undo the last command, returning its description, or None if there is none.
+    fn undo(&mut self) -> Option<String> {
+        self.inner.undo().map(|cmd| cmd.to_string())
+    }
+
+    /// Redo the last command undone, returning its description, or None if there is none.
+    fn redo(&mut self) -> Option<String> {
+        self.inner.redo().map(|cmd| cmd.to_string())
+    }
+
+    /// Whether there is a command to undo.
+    fn can_undo(&self) -> bool {
+        self.inner.undo_command().is_some()
+    }
+
+    /// Whether there is a command to redo.
+    fn can_redo(&self) -> bool {
+        self.inner.redo_command().is_some()
+    }
+
+    /// The descriptions of the commands in effect, oldest first.
+    fn history(&self) -> Vec<String> {
+        self.inner.history().map(|cmd| cmd.to_string()).collect()
+    }
+
+    /// The names of the algebras, in the order they were added.
+    fn names(&self) -> Vec<String> {
+        self.inner.names().into_iter().map(String::from).collect()
+    }
+
+    /// The name of the algebra the given one was derived from, if any.
+    fn derived_from(&self, name: &str) -> PyResult<Option<String>> {
+        self.inner.get(name).ok_or_else(|| PyKeyError::new_err(name.to_string()))?;
+        Ok(self.inner.derived_from(name).map(String::from))
+    }
+
+    fn __getitem__(&self, name: &str) -> PyResult<PyBasicAlgebra> {
+        self.inner.get(name)
+            .map(|alg| PyBasicAlgebra::from_inner(alg.clone()))
+            .ok_or_else(|| PyKeyError::new_err(name.to_string()))
+    }
+
+    fn __contains__(&self, name: &str) -> bool {
+        self.inner.get(name).is_some()
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.entries().len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Workspace({})", self.inner.names().join(", "))
+    }
+}
@@ -0,0 +1,220 @@
+//! Python wrapper for computation telemetry
+
+use pyo3::prelude::*;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::types::PyDict;
+use uacalc::telemetry::{ComputationReport, InstallGuard, Recorder};
+
+/// Register the telemetry module.
+pub fn register_telemetry_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyRecorder>()?;
+    m.add("Recorder", m.getattr("PyRecorder")?)?;
+    m.add_class::<PyComputationReport>()?;
+    m.add("ComputationReport", m.getattr("PyComputationReport")?)?;
+    m.add_function(wrap_pyfunction!(enable_logging, m)?)?;
+    Ok(())
+}
+
+/// The subscriber behind `enable_logging`, built with the tracing feature.
+#[cfg(feature = "tracing")]
+mod logging {
+    use std::fs::File;
+    use std::io::{self, Write};
+    use std::sync::{Mutex, OnceLock};
+    use pyo3::prelude::*;
+    use pyo3::exceptions::{PyRuntimeError, PyValueError};
+    use tracing_subscriber::filter::LevelFilter;
+    use tracing_subscriber::fmt::{self, format::FmtSpan};
+    use tracing_subscriber::prelude::*;
+    use tracing_subscriber::{reload, Registry};
+
+    /// The handle replacing the log level, once logging has been enabled.
+    static LOGGING: OnceLock<reload::Handle<LevelFilter, Registry>> = OnceLock::new();
+
+    /// The file logged to, or `None` for the standard error of the process.
+    static DESTINATION: Mutex<Option<File>> = Mutex::new(None);
+
+    /// Writes each formatted line to the current `DESTINATION`.
+    struct LogWriter;
+
+    impl Write for LogWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            match DESTINATION.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+                Some(file) => file.write(buf),
+                None => io::stderr().write(buf),
+            }
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            match DESTINATION.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+                Some(file) => file.flush(),
+                None => io::stderr().flush(),
+            }
+        }
+    }
+
+    /// Install the subscriber, or replace its level and destination.
+    pub fn enable(level: &str, file: Option<&str>) -> PyResult<()> {
+        let level: LevelFilter = level.parse().map_err(|_| PyValueError::new_err(format!("Unknown log level: {}", level)))?;
+        let file = match file {
+            Some(path) => Some(std::fs::OpenOptions::new().create(true).append(true).open(path)?),
+            None => None,
+        };
+        *DESTINATION.lock().unwrap_or_else(|e| e.into_inner()) = file;
+        match LOGGING.get() {
+            Some(handle) => handle.reload(level).map_err(|e| PyRuntimeError::new_err(e.to_string())),
+            None => {
+                let (filter, handle) = reload::Layer::new(level);
+                let layer = fmt::layer()
+                    .with_writer(|| LogWriter)
+                    .with_span_events(FmtSpan::CLOSE)
+                    .with_filter(filter);
+                tracing::subscriber::set_global_default(Registry::default().with(layer))
+                    .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+                let _ = LOGGING.set(handle);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Log the phases and counters of computations.
+///
+/// Closures, congruence lattices, free algebras and searches log each phase
+/// they enter and, when it closes, the time it took ("info"); the counters
+/// and closure passes ("debug"); and the steps of congruence lattice
+/// universes ("trace"). Calling again replaces the level and destination.
+/// Needs a build with the tracing feature, e.g.
+/// `maturin develop --features tracing`.
+///
+/// Args:
+///     level (str): "off", "error", "warn", "info", "debug" or "trace"
+///         (default "info")
+///     file (str, optional): A file to append to, instead of the standard
+///         error of the process
+///
+/// Raises:
+///     ValueError: If the level is unknown
+///     OSError: If the file cannot be opened
+///     RuntimeError: If another tracing subscriber is installed in the
+///         process, or the library was built without the tracing feature
+#[pyfunction]
+#[pyo3(signature = (level="info", file=None))]
+pub fn enable_logging(level: &str, file: Option<&str>) -> PyResult<()> {
+    #[cfg(feature = "tracing")]
+    return logging::enable(level, file);
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = (level, file);
+        Err(PyRuntimeError::new_err("uacalc_lib was built without the tracing feature"))
+    }
+}
+
+/// Records counters and phase timings of the computations run inside it.
+///
+/// Use as a context manager; closures, congruence lattices and free
+/// algebras computed in the `with` block on the same thread are recorded:
+///
+///     with Recorder() as rec:
+///         alg.con().cardinality()
+///     print(rec.report())
+///
+/// A recorder is tied to the thread that created it.
+#[pyclass(unsendable)]
+pub struct PyRecorder {
+    inner: Recorder,
+    installed: Vec<InstallGuard>,
+}
+
+#[pymethods]
+impl PyRecorder {
+    /// Create a recorder; its total time is measured from now.
+    #[new]
+    fn new() -> Self {
+        PyRecorder { inner: Recorder::new(), installed: Vec::new() }
+    }
+
+    fn __enter__(mut slf: PyRefMut<'_, Self>) -> PyRefMut<'_, Self> {
+        let guard = slf.inner.install();
+        slf.installed.push(guard);
+        slf
+    }
+
+    #[pyo3(signature = (*_args))]
+    fn __exit__(&mut self, _args: &Bound<'_, pyo3::types::PyTuple>) -> PyResult<bool> {
+        match self.installed.pop() {
+            Some(guard) => {
+                drop(guard);
+                Ok(false)
+            }
+            None => Err(PyRuntimeError::new_err("Recorder is not installed")),
+        }
+    }
+
+    /// The counters and phase timings recorded so far.
+    ///
+    /// Returns:
+    ///     ComputationReport: The report
+    fn report(&self) -> PyComputationReport {
+        PyComputationReport { inner: self.inner.report() }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Recorder(installed={})", !self.installed.is_empty())
+    }
+}
+
+/// The counters and phase timings of a computation.
+#[pyclass]
+#[derive(Clone)]
+pub struct PyComputationReport {
+    inner: ComputationReport,
+}
+
+#[pymethods]
+impl PyComputationReport {
+    /// The counters by name, e.g. "elements", "applications", "passes".
+    #[getter]
+    fn counters<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new_bound(py);
+        for (name, value) in &self.inner.counters {
+            dict.set_item(name, value)?;
+        }
+        Ok(dict)
+    }
+
+    /// The phases as (name, seconds, calls), in the order first entered.
+    #[getter]
+    fn phases(&self) -> Vec<(String, f64, u64)> {
+        self.inner.phases.iter().map(|p| (p.name.clone(), p.time.as_secs_f64(), p.calls)).collect()
+    }
+
+    /// The seconds since recording started.
+    #[getter]
+    fn total_seconds(&self) -> f64 {
+        self.inner.total.as_secs_f64()
+    }
+
+    /// The value of a counter, 0 if it was never counted.
+    ///
+    /// Args:
+    ///     name (str): The counter name
+    ///
+    /// Returns:
+    ///     int: The counter value
+    fn counter(&self, name: &str) -> u64 {
+        self.inner.counter(name)
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ComputationReport(counters={:?}, total_seconds={:.3})",
+            self.inner.counters,
+            self.inner.total.as_secs_f64()
+        )
+    }
+}
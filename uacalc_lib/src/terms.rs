@@ -1,14 +1,14 @@
 use pyo3::prelude::*;
 use pyo3::exceptions::PyValueError;
 use pyo3::types::PyList;
-use uacalc::terms::{VariableImp, NonVariableTerm, Term, Variable};
+use uacalc::terms::{VariableImp, NonVariableTerm, Term, TermArena, Variable};
 use std::collections::HashMap;
 use crate::alg::op::operation_symbol::PyOperationSymbol;
-use crate::alg::PyBasicAlgebra;
+use crate::alg::small_algebra_from_py;
 use crate::alg::op::term_operation_imp::PyTermOperationImp;
 
 /// Python wrapper for VariableImp
-#[pyclass]
+#[pyclass(name = "VariableImp", module = "uacalc_lib.terms")]
 #[derive(Clone)]
 pub struct PyVariableImp {
     pub(crate) inner: VariableImp,
@@ -59,6 +59,11 @@ impl PyVariableImp {
         self.inner.get_name().to_string()
     }
     
+    /// Pickle support: the constructor arguments.
+    fn __getnewargs__(&self) -> (String,) {
+        (self.inner.get_name().to_string(),)
+    }
+    
     /// Check if this term is a variable (always true for VariableImp)
     fn isa_variable(&self) -> bool {
         self.inner.isa_variable()
@@ -79,17 +84,49 @@ impl PyVariableImp {
         self.inner.get_variable_list()
     }
     
+    /// Get the number of operation symbol occurrences in this term (always 0 for variables)
+    fn size(&self) -> usize {
+        self.inner.size()
+    }
+    
+    /// Get the number of distinct subterms (nodes with shared subterms merged) (always 1 for variables)
+    fn node_count(&self) -> usize {
+        self.inner.node_count()
+    }
+    
+    /// Count the occurrences of a variable in this term
+    /// 
+    /// # Arguments
+    /// * `name` - The variable name
+    /// 
+    /// # Returns
+    /// The number of occurrences
+    fn occurrences_of_variable(&self, name: &str) -> usize {
+        self.inner.occurrences_of_variable(name)
+    }
+    
+    /// Get all subterms in pre-order, this term first, once per occurrence
+    fn subterms(&self, py: Python<'_>) -> PyResult<Vec<PyObject>> {
+        self.inner.subterms().map(|t| term_to_py(py, t.as_ref())).collect()
+    }
+    
+    /// Export the shared-subterm DAG of this term in Graphviz DOT format
+    fn to_dot(&self) -> String {
+        TermArena::from_terms(&[&self.inner]).to_dot()
+    }
+    
     /// Evaluate this variable using the given variable assignment
     /// 
     /// # Arguments
-    /// * `algebra` - The algebra in which to evaluate (a BasicAlgebra from Python)
+    /// * `algebra` - The algebra in which to evaluate (BasicAlgebra, ProductAlgebra, PowerAlgebra, Subalgebra, ...)
     /// * `var_map` - A dictionary mapping variable names to integer values
     /// 
     /// # Returns
     /// The value assigned to this variable
-    fn eval(&self, algebra: &PyBasicAlgebra, var_map: HashMap<String, i32>) -> PyResult<i32> {
-        self.inner.eval(&algebra.inner, &var_map)
-            .map_err(|e| PyValueError::new_err(e))
+    fn eval(&self, algebra: &Bound<'_, PyAny>, var_map: HashMap<String, i32>) -> PyResult<i32> {
+        let alg = small_algebra_from_py(algebra)?;
+        self.inner.eval(alg.as_ref(), &var_map)
+            .map_err(PyValueError::new_err)
     }
     
     /// Evaluate this variable as an integer
@@ -100,9 +137,10 @@ impl PyVariableImp {
     /// 
     /// # Returns
     /// The integer value assigned to this variable
-    fn int_eval(&self, algebra: &PyBasicAlgebra, var_map: HashMap<String, i32>) -> PyResult<i32> {
-        self.inner.int_eval(&algebra.inner, &var_map)
-            .map_err(|e| PyValueError::new_err(e))
+    fn int_eval(&self, algebra: &Bound<'_, PyAny>, var_map: HashMap<String, i32>) -> PyResult<i32> {
+        let alg = small_algebra_from_py(algebra)?;
+        self.inner.int_eval(alg.as_ref(), &var_map)
+            .map_err(PyValueError::new_err)
     }
     
     /// Returns the interpretation of this term as an operation.
@@ -110,26 +148,28 @@ impl PyVariableImp {
     /// The interpretation is the operation on the algebra that corresponds to this term.
     /// 
     /// # Arguments
-    /// * `algebra` - The algebra for interpretation
-    /// * `varlist` - The ordered list of variable names
-    /// * `use_all` - If true, use all variables in varlist regardless of occurrence
+    /// * `algebra` - The algebra for interpretation (BasicAlgebra, ProductAlgebra, PowerAlgebra, ...)
+    /// * `varlist` - The ordered list of variable names (default: the term's own variables)
+    /// * `use_all` - If true, use all variables in varlist regardless of occurrence (default: true)
     /// 
     /// # Returns
     /// An IntOperation that interprets this term
+    #[pyo3(signature = (algebra, varlist=None, use_all=true))]
     fn interpretation(
         &self,
-        algebra: &PyBasicAlgebra,
-        varlist: Vec<String>,
+        algebra: &Bound<'_, PyAny>,
+        varlist: Option<Vec<String>>,
         use_all: bool,
     ) -> PyResult<crate::alg::op::int_operation::PyIntOperation> {
+        let varlist = varlist.unwrap_or_else(|| self.inner.get_variable_list());
         use std::sync::Arc;
         use uacalc::alg::op::IntOperation;
         
         let alg_arc: Arc<dyn uacalc::alg::SmallAlgebra<UniverseItem = i32>> = 
-            Arc::new(algebra.inner.clone());
+            Arc::from(small_algebra_from_py(algebra)?);
         
         let op = self.inner.interpretation(alg_arc, &varlist, use_all)
-            .map_err(|e| PyValueError::new_err(e))?;
+            .map_err(PyValueError::new_err)?;
         
         // Extract table and symbol from the operation to create PyIntOperation
         let table = op.get_table()
@@ -139,7 +179,7 @@ impl PyVariableImp {
         let set_size = op.get_set_size();
         
         let int_op = IntOperation::new(symbol, set_size, table)
-            .map_err(|e| PyValueError::new_err(e))?;
+            .map_err(PyValueError::new_err)?;
         
         Ok(crate::alg::op::int_operation::PyIntOperation { inner: int_op })
     }
@@ -244,17 +284,49 @@ impl PyNonVariableTerm {
         self.inner.get_variable_list()
     }
     
+    /// Get the number of operation symbol occurrences in this term
+    fn size(&self) -> usize {
+        self.inner.size()
+    }
+    
+    /// Get the number of distinct subterms (nodes with shared subterms merged)
+    fn node_count(&self) -> usize {
+        self.inner.node_count()
+    }
+    
+    /// Count the occurrences of a variable in this term
+    /// 
+    /// # Arguments
+    /// * `name` - The variable name
+    /// 
+    /// # Returns
+    /// The number of occurrences
+    fn occurrences_of_variable(&self, name: &str) -> usize {
+        self.inner.occurrences_of_variable(name)
+    }
+    
+    /// Get all subterms in pre-order, this term first, once per occurrence
+    fn subterms(&self, py: Python<'_>) -> PyResult<Vec<PyObject>> {
+        self.inner.subterms().map(|t| term_to_py(py, t.as_ref())).collect()
+    }
+    
+    /// Export the shared-subterm DAG of this term in Graphviz DOT format
+    fn to_dot(&self) -> String {
+        TermArena::from_terms(&[&self.inner]).to_dot()
+    }
+    
     /// Evaluate this term using the given algebra and variable assignment
     /// 
     /// # Arguments
-    /// * `algebra` - The algebra in which to evaluate (a BasicAlgebra from Python)
+    /// * `algebra` - The algebra in which to evaluate (BasicAlgebra, ProductAlgebra, PowerAlgebra, Subalgebra, ...)
     /// * `var_map` - A dictionary mapping variable names to integer values
     /// 
     /// # Returns
     /// The result of evaluating the term
-    fn eval(&self, algebra: &PyBasicAlgebra, var_map: HashMap<String, i32>) -> PyResult<i32> {
-        self.inner.eval(&algebra.inner, &var_map)
-            .map_err(|e| PyValueError::new_err(e))
+    fn eval(&self, algebra: &Bound<'_, PyAny>, var_map: HashMap<String, i32>) -> PyResult<i32> {
+        let alg = small_algebra_from_py(algebra)?;
+        self.inner.eval(alg.as_ref(), &var_map)
+            .map_err(PyValueError::new_err)
     }
     
     /// Evaluate this term as an integer
@@ -265,9 +337,10 @@ impl PyNonVariableTerm {
     /// 
     /// # Returns
     /// The integer result of evaluating the term
-    fn int_eval(&self, algebra: &PyBasicAlgebra, var_map: HashMap<String, i32>) -> PyResult<i32> {
-        self.inner.int_eval(&algebra.inner, &var_map)
-            .map_err(|e| PyValueError::new_err(e))
+    fn int_eval(&self, algebra: &Bound<'_, PyAny>, var_map: HashMap<String, i32>) -> PyResult<i32> {
+        let alg = small_algebra_from_py(algebra)?;
+        self.inner.int_eval(alg.as_ref(), &var_map)
+            .map_err(PyValueError::new_err)
     }
     
     /// Returns the interpretation of this term as an operation.
@@ -275,26 +348,28 @@ impl PyNonVariableTerm {
     /// The interpretation is the operation on the algebra that corresponds to this term.
     /// 
     /// # Arguments
-    /// * `algebra` - The algebra for interpretation
-    /// * `varlist` - The ordered list of variable names
-    /// * `use_all` - If true, use all variables in varlist regardless of occurrence
+    /// * `algebra` - The algebra for interpretation (BasicAlgebra, ProductAlgebra, PowerAlgebra, ...)
+    /// * `varlist` - The ordered list of variable names (default: the term's own variables)
+    /// * `use_all` - If true, use all variables in varlist regardless of occurrence (default: true)
     /// 
     /// # Returns
     /// An IntOperation that interprets this term
+    #[pyo3(signature = (algebra, varlist=None, use_all=true))]
     fn interpretation(
         &self,
-        algebra: &PyBasicAlgebra,
-        varlist: Vec<String>,
+        algebra: &Bound<'_, PyAny>,
+        varlist: Option<Vec<String>>,
         use_all: bool,
     ) -> PyResult<crate::alg::op::int_operation::PyIntOperation> {
+        let varlist = varlist.unwrap_or_else(|| self.inner.get_variable_list());
         use std::sync::Arc;
         use uacalc::alg::op::IntOperation;
         
         let alg_arc: Arc<dyn uacalc::alg::SmallAlgebra<UniverseItem = i32>> = 
-            Arc::new(algebra.inner.clone());
+            Arc::from(small_algebra_from_py(algebra)?);
         
         let op = self.inner.interpretation(alg_arc, &varlist, use_all)
-            .map_err(|e| PyValueError::new_err(e))?;
+            .map_err(PyValueError::new_err)?;
         
         // Extract table and symbol from the operation to create PyIntOperation
         let table = op.get_table()
@@ -304,7 +379,7 @@ impl PyNonVariableTerm {
         let set_size = op.get_set_size();
         
         let int_op = IntOperation::new(symbol, set_size, table)
-            .map_err(|e| PyValueError::new_err(e))?;
+            .map_err(PyValueError::new_err)?;
         
         Ok(crate::alg::op::int_operation::PyIntOperation { inner: int_op })
     }
@@ -342,7 +417,7 @@ impl PyNonVariableTerm {
             }
 
             let result = self.inner.substitute(&rust_map)
-                .map_err(|e| PyValueError::new_err(e))?;
+                .map_err(PyValueError::new_err)?;
 
             // Convert back to Python
             if result.isa_variable() {
@@ -639,6 +714,18 @@ fn string_to_term(s: String) -> PyResult<PyObject> {
     })
 }
 
+// Helper function to wrap a Term trait object as a VariableImp or NonVariableTerm
+fn term_to_py(py: Python<'_>, term: &dyn Term) -> PyResult<PyObject> {
+    if term.isa_variable() {
+        let py_var = PyVariableImp {
+            inner: VariableImp::new(&term.to_string()),
+        };
+        Ok(py_var.into_py(py))
+    } else {
+        Ok(reconstruct_non_variable_term(term)?.into_py(py))
+    }
+}
+
 // Helper function to reconstruct a PyNonVariableTerm from a Term trait object
 fn reconstruct_non_variable_term(term: &dyn Term) -> PyResult<PyNonVariableTerm> {
     if term.isa_variable() {
@@ -764,14 +851,12 @@ pub fn register_terms_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<(
     m.add_class::<PyTermOperationImp>()?;
     
     // Export only clean names (without Py prefix)
-    m.add("VariableImp", m.getattr("PyVariableImp")?)?;
     m.add("NonVariableTerm", m.getattr("PyNonVariableTerm")?)?;
     m.add("Taylor", m.getattr("PyTaylor")?)?;
     m.add("TermOperationImp", m.getattr("PyTermOperationImp")?)?;
     
     // Remove the Py* names from the module to avoid confusion
     let module_dict = m.dict();
-    module_dict.del_item("PyVariableImp")?;
     module_dict.del_item("PyNonVariableTerm")?;
     module_dict.del_item("PyTaylor")?;
     module_dict.del_item("PyTermOperationImp")?;
@@ -2,6 +2,7 @@ use pyo3::prelude::*;
 use pyo3::exceptions::PyValueError;
 use uacalc::util::horner;
 use uacalc::util::array_string;
+use uacalc::util::random;
 use uacalc::util::permutation_generator;
 use uacalc::util::int_array::{IntArrayTrait, IntArray};
 use uacalc::util::sequence_generator::{
@@ -2226,7 +2227,26 @@ impl PyVirtualLists {
 }
 
 
+/// Seed the random source of all randomized routines.
+///
+/// After `set_global_seed(seed)` the routines that take no seed (random
+/// operations and algebras, random default value tables) give the same
+/// results on every run. `set_global_seed(None)` makes them unpredictable
+/// again.
+///
+/// Args:
+///     seed (Optional[int]): The seed, or None to clear it
+#[pyfunction]
+#[pyo3(signature = (seed))]
+pub fn set_global_seed(seed: Option<u64>) {
+    match seed {
+        Some(seed) => random::set_global_seed(seed),
+        None => random::clear_global_seed(),
+    }
+}
+
 pub fn register_util_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(set_global_seed, m)?)?;
     // Register classes internally but only export clean names
     m.add_class::<PyHorner>()?;
     m.add_class::<PySimpleList>()?;
@@ -2304,6 +2324,43 @@ pub fn register_util_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()
     module_dict.del_item("PyLeftSequenceIncrementor")?;
     module_dict.del_item("PyPartitionArrayIncrementor")?;
     module_dict.del_item("PyVirtualLists")?;
+
+    m.add_function(wrap_pyfunction!(encode_tuple, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_tuple, m)?)?;
     
     Ok(())
 }
+
+/// Encode a tuple in mixed radix, the first coordinate varying fastest, as
+/// for the elements of a direct product.
+///
+/// Args:
+///     tuple (List[int]): The coordinates, each below its radix
+///     radices (List[int]): The radix of each coordinate
+///
+/// Returns:
+///     int: The code, below the product of the radices
+///
+/// Raises:
+///     ValueError: If the lengths differ, a coordinate is out of range or
+///         the code does not fit in 128 bits
+#[pyfunction]
+fn encode_tuple(tuple: Vec<usize>, radices: Vec<usize>) -> PyResult<u128> {
+    uacalc::util::MixedRadix::new(radices).encode(&tuple).map_err(PyValueError::new_err)
+}
+
+/// Decode a mixed radix code into its tuple.
+///
+/// Args:
+///     code (int): The code
+///     radices (List[int]): The radix of each coordinate
+///
+/// Returns:
+///     List[int]: The coordinates
+///
+/// Raises:
+///     ValueError: If the code is not below the product of the radices
+#[pyfunction]
+fn decode_tuple(code: u128, radices: Vec<usize>) -> PyResult<Vec<usize>> {
+    uacalc::util::MixedRadix::new(radices).decode(code).map_err(PyValueError::new_err)
+}
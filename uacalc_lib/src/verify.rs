@@ -0,0 +1,122 @@
+//! Python wrapper for the cross-checks against brute force
+
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use uacalc::verify::{self, Discrepancy};
+use crate::progress::{with_progress, PyProgressReporter};
+
+/// Register the verify module.
+pub fn register_verify_module(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyDiscrepancy>()?;
+    m.add_function(wrap_pyfunction!(check_congruences, m)?)?;
+    m.add_function(wrap_pyfunction!(check_subuniverses, m)?)?;
+    m.add_function(wrap_pyfunction!(check_homomorphisms, m)?)?;
+    m.add_function(wrap_pyfunction!(check_algebra, m)?)?;
+    m.add("MAX_SIZE", verify::MAX_SIZE)?;
+    m.add("MAX_MAPS", verify::MAX_MAPS)?;
+    Ok(())
+}
+
+/// A difference between a fast algorithm and its brute force reference.
+#[pyclass(name = "Discrepancy", module = "uacalc_lib.verify")]
+#[derive(Clone)]
+pub struct PyDiscrepancy {
+    inner: Discrepancy,
+}
+
+#[pymethods]
+impl PyDiscrepancy {
+    /// "congruences", "subuniverses" or "homomorphisms".
+    #[getter]
+    fn check(&self) -> &'static str {
+        self.inner.check
+    }
+
+    /// The algorithm that disagreed with the reference.
+    #[getter]
+    fn algorithm(&self) -> String {
+        self.inner.algorithm.clone()
+    }
+
+    /// What differs, with a witness.
+    #[getter]
+    fn detail(&self) -> String {
+        self.inner.detail.clone()
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Discrepancy({})", self.inner)
+    }
+}
+
+fn to_py(discrepancies: Vec<Discrepancy>) -> Vec<PyDiscrepancy> {
+    discrepancies.into_iter().map(|inner| PyDiscrepancy { inner }).collect()
+}
+
+/// Compare the congruence lattice universe with the partitions compatible
+/// with every operation, found by brute force.
+///
+/// Args:
+///     algebra: An algebra with at most MAX_SIZE elements
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     List[Discrepancy]: The differences; empty if the two agree
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn check_congruences(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<Vec<PyDiscrepancy>> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    with_progress(py, progress, || verify::check_congruences(alg.as_ref()).map(to_py).map_err(PyValueError::new_err))
+}
+
+/// Compare the subalgebra lattice universe with the subsets closed under
+/// every operation, found by brute force.
+///
+/// Args:
+///     algebra: An algebra with at most MAX_SIZE elements
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     List[Discrepancy]: The differences; empty if the two agree
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn check_subuniverses(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<Vec<PyDiscrepancy>> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    with_progress(py, progress, || verify::check_subuniverses(alg.as_ref()).map(to_py).map_err(PyValueError::new_err))
+}
+
+/// Compare the homomorphism searches of every backend with all maps from
+/// a to b.
+///
+/// Args:
+///     a: The domain algebra
+///     b: The target algebra, with |b|^|a| at most MAX_MAPS
+///     progress (ProgressReporter, optional): Progress and cancellation
+///
+/// Returns:
+///     List[Discrepancy]: The differences; empty if the searches agree
+#[pyfunction]
+#[pyo3(signature = (a, b, progress=None))]
+fn check_homomorphisms(
+    py: Python<'_>,
+    a: &Bound<'_, PyAny>,
+    b: &Bound<'_, PyAny>,
+    progress: Option<&PyProgressReporter>,
+) -> PyResult<Vec<PyDiscrepancy>> {
+    let a = crate::alg::small_algebra_from_py(a)?;
+    let b = crate::alg::small_algebra_from_py(b)?;
+    with_progress(py, progress, || verify::check_homomorphisms(a.as_ref(), b.as_ref()).map(to_py).map_err(PyValueError::new_err))
+}
+
+/// Run check_congruences, check_subuniverses and check_homomorphisms from
+/// the algebra to itself.
+#[pyfunction]
+#[pyo3(signature = (algebra, progress=None))]
+fn check_algebra(py: Python<'_>, algebra: &Bound<'_, PyAny>, progress: Option<&PyProgressReporter>) -> PyResult<Vec<PyDiscrepancy>> {
+    let alg = crate::alg::small_algebra_from_py(algebra)?;
+    with_progress(py, progress, || verify::check_algebra(alg.as_ref()).map(to_py).map_err(PyValueError::new_err))
+}